@@ -95,11 +95,14 @@ pub struct Cli {
 /// - [`Run`][Commands::Run] - Build and execute
 /// - [`Check`][Commands::Check] - Type-check without building
 /// - [`Verify`][Commands::Verify] - Run Z3 static verification
+/// - [`License`][Commands::License] - Activate or release the verification license
 ///
 /// ## Package Registry
 /// - [`Publish`][Commands::Publish] - Upload package to registry
 /// - [`Login`][Commands::Login] - Authenticate with registry
 /// - [`Logout`][Commands::Logout] - Remove stored credentials
+/// - [`Yank`][Commands::Yank] - Withdraw a published version
+/// - [`Owner`][Commands::Owner] - Manage package owners and teams
 #[derive(Subcommand)]
 pub enum Commands {
     /// Create a new LOGOS project in a new directory.
@@ -116,10 +119,24 @@ pub enum Commands {
     /// cd my_project
     /// largo run
     /// ```
-    #[command(after_help = "Examples:\n  largo new hello\n  cd hello\n  largo run")]
+    ///
+    /// `--template` starts from a richer scaffold — example sources, a
+    /// golden-output test and a CI workflow. Built-ins: `cli`,
+    /// `web-service`, `library`, `verification-spec`, `learning-content`;
+    /// directories under `~/.config/logos/templates/` add more.
+    #[command(after_help = "Examples:\n  largo new hello\n  cd hello\n  largo run\n  largo new greeter --template cli\n  largo new --list-templates")]
     New {
         /// The project name, used for the directory and package name.
-        name: String,
+        #[arg(required_unless_present = "list_templates")]
+        name: Option<String>,
+
+        /// Scaffold from a named template instead of hello-world.
+        #[arg(long, short)]
+        template: Option<String>,
+
+        /// List the available templates and exit.
+        #[arg(long, conflicts_with = "template")]
+        list_templates: bool,
     },
 
     /// Initialize a LOGOS project in the current directory.
@@ -133,11 +150,16 @@ pub enum Commands {
     /// mkdir my_project && cd my_project
     /// largo init
     /// ```
-    #[command(after_help = "Examples:\n  mkdir app && cd app\n  largo init\n  largo init --name my_app")]
+    #[command(after_help = "Examples:\n  mkdir app && cd app\n  largo init\n  largo init --name my_app\n  largo init --template library")]
     Init {
         /// Project name. If omitted, uses the current directory name.
         #[arg(long)]
         name: Option<String>,
+
+        /// Scaffold from a named template (see `largo new --list-templates`).
+        /// Files that already exist are kept.
+        #[arg(long, short)]
+        template: Option<String>,
     },
 
     /// Build the current project.
@@ -176,6 +198,11 @@ pub enum Commands {
         #[arg(long)]
         license: Option<String>,
 
+        /// With `--verify`, ignore the verification cache and re-prove every
+        /// obligation.
+        #[arg(long, requires = "verify")]
+        fresh: bool,
+
         /// Build as a library instead of an executable.
         /// Generates `lib.rs` with `crate-type = ["cdylib"]` instead of a binary.
         #[arg(long)]
@@ -199,6 +226,11 @@ pub enum Commands {
         /// rustc-based Rust build.
         #[arg(long)]
         emit: Option<String>,
+
+        /// Enable features for `## When compiling with feature <name>:` sections.
+        /// Comma-separated; may be repeated.
+        #[arg(long, value_delimiter = ',')]
+        features: Vec<String>,
     },
 
     /// Run Z3 static verification without building.
@@ -207,7 +239,12 @@ pub enum Commands {
     /// using the Z3 SMT solver. This catches logical errors that would be
     /// impossible to detect through testing alone.
     ///
-    /// Requires a Pro+ license.
+    /// Requires a Pro+ license: a key, or a license file activated with
+    /// `largo license activate` (which needs no network).
+    ///
+    /// Proven obligations are cached in `.largo/verify-cache`, so a re-run
+    /// only sends the obligations whose code or context changed to Z3.
+    /// Independent obligations are proved in parallel, one solver per job.
     ///
     /// # Example
     ///
@@ -217,12 +254,43 @@ pub enum Commands {
     /// export LOGOS_LICENSE=sub_xxxxx
     /// largo verify
     /// ```
-    #[command(after_help = "Examples:\n  largo verify --license sub_xxxxx\n  LOGOS_LICENSE=sub_xxxxx largo verify")]
+    #[command(after_help = "Examples:\n  largo verify --license sub_xxxxx\n  LOGOS_LICENSE=sub_xxxxx largo verify\n  largo license activate sub_xxxxx && largo verify\n  largo verify --fresh\n  largo verify --jobs 8 --timeout 30 --budget 600")]
     Verify {
         /// License key for verification.
         /// Can also be set via the `LOGOS_LICENSE` environment variable.
+        /// Without one, the activated license file is used.
         #[arg(long)]
         license: Option<String>,
+
+        /// Ignore the verification cache and re-prove every obligation.
+        #[arg(long)]
+        fresh: bool,
+
+        /// Solver threads to prove obligations on. Defaults to the number of CPUs.
+        #[arg(long, short)]
+        jobs: Option<usize>,
+
+        /// Seconds Z3 may spend on any one obligation (default 10).
+        #[arg(long, value_name = "SECS")]
+        timeout: Option<u64>,
+
+        /// Seconds for the whole run; obligations not started in time are
+        /// reported as unchecked.
+        #[arg(long, value_name = "SECS")]
+        budget: Option<u64>,
+    },
+
+    /// Manage the verification license on this machine.
+    ///
+    /// `activate` exchanges a key for a signed license file bound to this
+    /// machine, so `largo verify` works offline (with a grace period past
+    /// expiry). Keys allow a limited number of activated machines;
+    /// `deactivate` frees this one. Air-gapped machines install a license
+    /// file from the dashboard with `activate --file`.
+    #[command(after_help = "Examples:\n  largo license activate sub_xxxxx\n  largo license activate --file license.json\n  largo license status\n  largo license deactivate")]
+    License {
+        #[command(subcommand)]
+        action: crate::commands::license::LicenseAction,
     },
 
     /// Build and run the current project.
@@ -239,8 +307,10 @@ pub enum Commands {
     /// largo run              # Debug mode (compile to Rust)
     /// largo run --release    # Release mode
     /// largo run --interpret  # Interpret directly (no compilation)
+    /// largo run --profile    # Print per-function timings on exit
+    /// largo run --log-level debug  # Show `Log debug` events too
     /// ```
-    #[command(after_help = "Examples:\n  largo run\n  largo run --release\n  largo run --interpret\n  largo run --emit wasm\n  largo run -- input.txt --program-flag")]
+    #[command(after_help = "Examples:\n  largo run\n  largo run --release\n  largo run --interpret\n  largo run --release --profile\n  largo run --emit wasm\n  largo run --log-level debug\n  largo run -- input.txt --program-flag")]
     Run {
         /// Build with optimizations enabled.
         #[arg(long, short)]
@@ -257,6 +327,16 @@ pub enum Commands {
         #[arg(long, conflicts_with = "interpret")]
         emit: Option<String>,
 
+        /// Instrument every LOGOS function with call counters and timers, then
+        /// print a flat profile and a call tree to stderr when the program exits.
+        #[arg(long, conflicts_with_all = ["interpret", "emit"])]
+        profile: bool,
+
+        /// The most verbose `Log` events to print (to stderr, or the console
+        /// on wasm). Defaults to `info`, or `LOGOS_LOG` when set.
+        #[arg(long, value_name = "LEVEL", value_parser = ["trace", "debug", "info", "warn", "error", "off"])]
+        log_level: Option<String>,
+
         /// Arguments to pass to the program.
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
@@ -321,12 +401,16 @@ pub enum Commands {
     /// largo publish              # Publish to default registry
     /// largo publish --dry-run    # Validate without uploading
     /// ```
-    #[command(after_help = "Examples:\n  largo publish --dry-run\n  largo publish\n  largo publish --allow-dirty")]
+    #[command(after_help = "Examples:\n  largo publish --dry-run\n  largo publish\n  largo publish --allow-dirty\n  largo publish --team core")]
     Publish {
         /// Registry URL. Defaults to `registry.logicaffeine.com`.
         #[arg(long)]
         registry: Option<String>,
 
+        /// Publish with the token stored for this team (see `largo login --team`).
+        #[arg(long)]
+        team: Option<String>,
+
         /// Perform all validation without actually uploading.
         /// Useful for testing the publish process.
         #[arg(long)]
@@ -355,13 +439,18 @@ pub enum Commands {
     /// ```bash
     /// largo login                       # Interactive prompt
     /// largo login --token tok_xxxxx     # Non-interactive
+    /// largo login --team core           # Store a team-scoped token
     /// ```
-    #[command(after_help = "Examples:\n  largo login\n  largo login --token lgr_xxxxx")]
+    #[command(after_help = "Examples:\n  largo login\n  largo login --token lgr_xxxxx\n  largo login --team core --token lgr_xxxxx")]
     Login {
         /// Registry URL. Defaults to `registry.logicaffeine.com`.
         #[arg(long)]
         registry: Option<String>,
 
+        /// Store the token as this team's instead of your own.
+        #[arg(long)]
+        team: Option<String>,
+
         /// API token. If omitted, prompts for input on stdin.
         #[arg(long)]
         token: Option<String>,
@@ -376,11 +465,48 @@ pub enum Commands {
     /// ```bash
     /// largo logout
     /// ```
-    #[command(after_help = "Examples:\n  largo logout\n  largo logout --registry https://registry.example.com")]
+    #[command(after_help = "Examples:\n  largo logout\n  largo logout --team core\n  largo logout --registry https://registry.example.com")]
     Logout {
         /// Registry URL. Defaults to `registry.logicaffeine.com`.
         #[arg(long)]
         registry: Option<String>,
+
+        /// Remove only this team's token.
+        #[arg(long)]
+        team: Option<String>,
+    },
+
+    /// Withdraw a published version from new dependency resolution.
+    ///
+    /// A yanked version stays downloadable for projects that already lock
+    /// it, so yanking never breaks existing builds. Inside a project, a bare
+    /// version refers to the project's own package.
+    #[command(after_help = "Examples:\n  largo yank 1.2.0\n  largo yank geometry@1.2.0\n  largo yank geometry@1.2.0 --undo\n  largo yank geometry@1.2.0 --team core")]
+    Yank {
+        /// `<name>@<version>`, or a version of the current project's package.
+        spec: String,
+
+        /// Un-yank the version, making it resolvable again.
+        #[arg(long)]
+        undo: bool,
+
+        /// Registry URL. Defaults to `registry.logicaffeine.com`.
+        #[arg(long)]
+        registry: Option<String>,
+
+        /// Act with the token stored for this team (see `largo login --team`).
+        #[arg(long)]
+        team: Option<String>,
+    },
+
+    /// Manage who may publish and yank a package.
+    ///
+    /// Owners are registry logins or teams (`team:<name>`); every token
+    /// issued to an owning team can act on the package.
+    #[command(after_help = "Examples:\n  largo owner list\n  largo owner add alice team:core\n  largo owner remove bob --package geometry\n  largo owner transfer team:core")]
+    Owner {
+        #[command(subcommand)]
+        action: crate::commands::owner::OwnerAction,
     },
 
     /// Diagnose the environment largo runs in.
@@ -410,8 +536,9 @@ pub enum Commands {
         #[arg(long)]
         logic: bool,
 
-        /// Initial logic output format.
-        #[arg(long, value_enum)]
+        /// Initial logic output format (unicode, latex, ascii, kripke, or a
+        /// registered custom format).
+        #[arg(long)]
         format: Option<crate::commands::logic::LogicFormat>,
 
         /// Load a saved session/program on startup.
@@ -460,13 +587,33 @@ pub enum Commands {
         json: bool,
     },
 
+    /// Export English theorems to Lean 4 or Coq.
+    ///
+    /// Writes every `## Theorem` block (Given/Prove) as one source file for
+    /// the chosen proof assistant: predicates and individuals become axioms,
+    /// premises become hypotheses, and each theorem is left for you to prove
+    /// there. Without FILE, uses the current project's entry.
+    #[command(after_help = "Examples:\n  largo export --prover lean4\n  largo export --prover coq socrates.lg\n  largo export --prover lean4 socrates.lg -o Socrates.lean")]
+    Export {
+        /// The proof assistant to target.
+        #[arg(long, value_enum)]
+        prover: crate::commands::export::ProverTarget,
+
+        /// A standalone source file (defaults to the project entry).
+        file: Option<PathBuf>,
+
+        /// Write to this path instead of stdout.
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+
     /// Translate English to First-Order Logic.
     ///
     /// Compiles a natural-language sentence to formal logic — the LOGOS
     /// logic mode from the terminal. Reads the sentence inline, from
     /// `--file`, or from piped stdin. Prints bare FOL on stdout, so output
     /// pipes cleanly into other tools.
-    #[command(after_help = "Examples:\n  largo logic \"Every woman loves a man.\"\n  largo logic \"Every woman loves a man.\" --all-readings\n  largo logic \"It might rain.\" --format kripke\n  echo \"Socrates is mortal.\" | largo logic\n  printf 'A farmer owns a donkey.\\nHe feeds it.' | largo logic --discourse")]
+    #[command(after_help = "Examples:\n  largo logic \"Every woman loves a man.\"\n  largo logic \"Every woman loves a man.\" --all-readings\n  largo logic \"It might rain.\" --format kripke\n  largo logic \"Every cat sleeps.\" --tree mermaid\n  echo \"Socrates is mortal.\" | largo logic\n  printf 'A farmer owns a donkey.\\nHe feeds it.' | largo logic --discourse")]
     Logic {
         /// The English sentence to translate.
         sentence: Option<String>,
@@ -475,8 +622,9 @@ pub enum Commands {
         #[arg(long, short, conflicts_with = "sentence")]
         file: Option<PathBuf>,
 
-        /// Output format for the logical form.
-        #[arg(long, value_enum, default_value = "unicode")]
+        /// Output format for the logical form: unicode, latex, ascii, kripke,
+        /// or the name of a registered custom format.
+        #[arg(long, default_value = "unicode")]
         format: crate::commands::logic::LogicFormat,
 
         /// Show every reading (quantifier scopes + parse forest), numbered.
@@ -491,6 +639,10 @@ pub enum Commands {
         /// shared anaphora context.
         #[arg(long)]
         discourse: bool,
+
+        /// Print the syntax tree as a diagram instead of the formula.
+        #[arg(long, value_enum, conflicts_with_all = ["all_readings", "discourse"])]
+        tree: Option<crate::commands::graph::DiagramFormat>,
     },
 
     /// Generate documentation from the project's `##` blocks.
@@ -505,6 +657,18 @@ pub enum Commands {
         out: Option<PathBuf>,
     },
 
+    /// Print the project's module dependency graph.
+    ///
+    /// Follows the imports linked from each module's abstract, starting at
+    /// the entry file, and prints the graph as Graphviz DOT or a Mermaid
+    /// flowchart on stdout. Edges are labeled with the import alias.
+    #[command(after_help = "Examples:\n  largo graph | dot -Tsvg > modules.svg\n  largo graph --format mermaid")]
+    Graph {
+        /// Diagram language to print.
+        #[arg(long, value_enum, default_value = "dot")]
+        format: crate::commands::graph::DiagramFormat,
+    },
+
     /// Add a dependency to Largo.toml.
     ///
     /// Accepts `name` (any version), `name@version`, or `logos:name` (the
@@ -575,10 +739,10 @@ pub enum Commands {
     ///
     /// Deletes the project's `target/` directory. With `--all`, also removes
     /// the `.logos-native/` compiled-function bundle cache produced by
-    /// `largo build --native-functions`.
+    /// `largo build --native-functions` and the `.largo/` verification cache.
     #[command(after_help = "Examples:\n  largo clean\n  largo clean --all")]
     Clean {
-        /// Also remove the `.logos-native/` bundle cache.
+        /// Also remove the `.logos-native/` bundle cache and `.largo/`.
         #[arg(long)]
         all: bool,
     },
@@ -595,13 +759,126 @@ pub enum Commands {
         shell: clap_complete::Shell,
     },
 
-    /// Reserved for the LOGOS test framework (coming in a future release).
-    #[command(hide = true)]
-    Test {
-        /// Ignored; the verb is reserved.
-        #[arg(trailing_var_arg = true, allow_hyphen_values = true, hide = true)]
+    /// Re-run a command whenever the project's sources change.
+    ///
+    /// Watches `Largo.toml` and every `.lg`/`.md` source, debounces bursts of
+    /// saves, and re-runs `largo check` (the default), `largo run` or `largo test`,
+    /// printing one summary line per run. A change during `watch run`
+    /// restarts the program.
+    #[command(after_help = "Examples:\n  largo watch\n  largo watch run\n  largo watch run -- input.txt\n  largo watch check --debounce 500")]
+    Watch {
+        /// The command to re-run.
+        #[arg(value_enum, default_value = "check")]
+        command: crate::commands::watch::WatchCommand,
+        /// Quiet period, in milliseconds, a burst of changes must settle for.
+        #[arg(long, default_value_t = 200, value_name = "MS")]
+        debounce: u64,
+        /// Arguments to pass to the program (`watch run` only).
+        #[arg(last = true)]
         args: Vec<String>,
     },
+
+    /// Keep a warm compiler running for editors and scripts.
+    ///
+    /// Starts a long-lived server on a local Unix socket that answers
+    /// `ping`, `compile`, `check`, `build` and `shutdown` requests as
+    /// newline-delimited JSON-RPC 2.0. The lexicon, multi-word-expression
+    /// trie and compile results stay cached between requests, so repeat
+    /// checks skip the cold start.
+    #[command(after_help = "Examples:\n  largo daemon &\n  largo daemon --status\n  largo daemon --socket /tmp/largo.sock\n  largo daemon --stop")]
+    Daemon {
+        /// Socket path (default: `largo-daemon.sock` in the runtime or temp dir).
+        #[arg(long)]
+        socket: Option<PathBuf>,
+        /// Ask the running daemon to shut down.
+        #[arg(long, conflicts_with = "status")]
+        stop: bool,
+        /// Report whether a daemon is listening, with its uptime.
+        #[arg(long)]
+        status: bool,
+    },
+
+    /// Shrink a failing program to a minimal one that still fails.
+    ///
+    /// Deletes statements, delta-debugging style, for as long as the program
+    /// still fails the way named by exactly one of the failure flags. Each
+    /// candidate runs under a timeout; the reduced program is written next
+    /// to the original as `<name>.min.lg` unless `--output` says otherwise.
+    #[command(
+        group(clap::ArgGroup::new("failure").required(true)),
+        after_help = "Examples:\n  largo bisect crash.lg --panic\n  largo bisect bug.lg --prints 41\n  largo bisect src/main.lg --compile-error \"not in scope\" -o small.lg"
+    )]
+    Bisect {
+        /// The LOGOS source file to reduce.
+        file: PathBuf,
+        /// Keep programs the compiler rejects (with TEXT in the error, if given).
+        #[arg(long, value_name = "TEXT", num_args = 0..=1, default_missing_value = "", group = "failure")]
+        compile_error: Option<String>,
+        /// Keep programs whose run ends in an error (with TEXT in it, if given).
+        #[arg(long, value_name = "TEXT", num_args = 0..=1, default_missing_value = "", group = "failure")]
+        error: Option<String>,
+        /// Keep programs whose output contains TEXT.
+        #[arg(long, value_name = "TEXT", group = "failure")]
+        prints: Option<String>,
+        /// Keep programs that crash the compiler or interpreter (with TEXT in
+        /// the panic message, if given).
+        #[arg(long, value_name = "TEXT", num_args = 0..=1, default_missing_value = "", group = "failure")]
+        panic: Option<String>,
+        /// Seconds a candidate may run before it counts as not failing.
+        #[arg(long, default_value_t = 10, value_name = "SECS")]
+        timeout: u64,
+        /// Where to write the reduced program.
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+
+    /// Measure how strongly the examples and contracts pin the program down.
+    ///
+    /// Applies one small mutation at a time to the entry's code — a
+    /// comparison flipped at its boundary, an integer constant off by one,
+    /// `and`/`or` swapped in a `Requires`/`Ensures`/`Assert` spec — and
+    /// re-runs `## Main` and the doc tests. Mutants nothing notices are
+    /// reported as survivors.
+    #[command(after_help = "Examples:\n  largo mutate\n  largo mutate --timeout 30\n  largo mutate -v")]
+    Mutate {
+        /// Seconds a mutant may run before it counts as caught (it no longer finishes).
+        #[arg(long, default_value_t = 10, value_name = "SECS")]
+        timeout: u64,
+    },
+
+    /// Tools for working on the compiler itself.
+    ///
+    /// `parser-coverage` parses a sentence corpus with the derivation trace
+    /// on and reports which grammar rules applied and which named branches
+    /// were taken — the generated corpus (every quantifier × verb class ×
+    /// construction pattern) plus the sentences of any files given.
+    #[command(after_help = "Examples:\n  largo dev parser-coverage\n  largo dev parser-coverage corpus.txt --per-pattern 0\n  largo dev parser-coverage --uncovered")]
+    Dev {
+        #[command(subcommand)]
+        action: crate::commands::dev::DevAction,
+    },
+
+    /// Run the examples written into documentation prose.
+    ///
+    /// Every fenced `logos` block in the project's `.lg` and `.md` sources is
+    /// executed through the interpreter; a `produces:` line after it states
+    /// the output it must print. Snippets without a `##` header run as
+    /// `## Main`, after the file's own function and type definitions.
+    #[command(after_help = "Examples:\n  largo test\n  largo test src/geometry.lg\n  largo test main.lg:12\n  largo test --coverage\n  largo test --differential\n  largo test --coverage --differential")]
+    Test {
+        /// Only run doc tests whose name (`file:line`) contains this text.
+        filter: Option<String>,
+        /// Count the lines the examples ran; write annotated sources and an
+        /// `lcov.info` to `target/coverage/`. With `--differential`, the
+        /// compiled runs are counted too.
+        #[arg(long)]
+        coverage: bool,
+        /// Also run each example as a compiled binary and fail when it does
+        /// not print what the interpreter printed; a diverging program is
+        /// reduced and saved to `target/differential/`.
+        #[arg(long)]
+        differential: bool,
+    },
 }
 
 /// Parse CLI arguments and execute the corresponding command.
@@ -634,11 +911,21 @@ pub fn run_cli() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
     ui::init(cli.color, cli.quiet, cli.verbose);
 
+    // The program reads its log level from `LOGOS_LOG`, whichever way it runs:
+    // a child process, the in-process interpreter, or node.
+    if let Commands::Run { log_level: Some(level), .. } = &cli.command {
+        std::env::set_var("LOGOS_LOG", level);
+    }
+
     match cli.command {
-        Commands::New { name } => commands::new::cmd_new(&name),
-        Commands::Init { name } => commands::new::cmd_init(name.as_deref()),
-        Commands::Build { release, verify, license, lib, target, native_functions, emit } => {
-            commands::build::cmd_build(release, verify, license, lib, target, native_functions, emit)
+        Commands::New { list_templates: true, .. } => commands::new::cmd_list_templates(),
+        Commands::New { name, template, .. } => {
+            commands::new::cmd_new(name.as_deref().unwrap_or_default(), template.as_deref())
+        }
+        Commands::Init { name, template } => commands::new::cmd_init(name.as_deref(), template.as_deref()),
+        Commands::Build { release, verify, license, fresh, lib, target, native_functions, emit, features } => {
+            let verify_options = commands::verify::VerifyOptions { fresh, ..Default::default() };
+            commands::build::cmd_build(release, verify, license, verify_options, lib, target, native_functions, emit, features)
         }
         Commands::Run { emit: Some(e), args, .. } if e == "wasm" => commands::run::cmd_run_wasm(&args, false),
         Commands::Run { emit: Some(e), args, .. } if e == "wasm-linked" => commands::run::cmd_run_wasm(&args, true),
@@ -646,34 +933,58 @@ pub fn run_cli() -> Result<(), Box<dyn std::error::Error>> {
             Err(format!("unknown --emit target '{e}' (expected 'wasm' or 'wasm-linked')").into())
         }
         Commands::Run { interpret, args, .. } if interpret => commands::run::cmd_run_interpret(&args),
-        Commands::Run { release, args, .. } => commands::run::cmd_run(release, &args),
+        Commands::Run { release, profile, args, .. } => commands::run::cmd_run(release, profile, &args),
         Commands::Check { deep } => commands::check::cmd_check(deep),
         Commands::Opts { file, json } => commands::opts::cmd_opts(&file, json),
-        Commands::Verify { license } => commands::verify::cmd_verify(license),
-        Commands::Publish { registry, dry_run, allow_dirty } => {
-            commands::publish::cmd_publish(registry.as_deref(), dry_run, allow_dirty)
+        Commands::Verify { license, fresh, jobs, timeout, budget } => commands::verify::cmd_verify(
+            license,
+            commands::verify::VerifyOptions { fresh, jobs, timeout, budget },
+        ),
+        Commands::License { action } => commands::license::cmd_license(action),
+        Commands::Publish { registry, team, dry_run, allow_dirty } => {
+            commands::publish::cmd_publish(registry.as_deref(), team.as_deref(), dry_run, allow_dirty)
+        }
+        Commands::Login { registry, team, token } => {
+            commands::publish::cmd_login(registry.as_deref(), team.as_deref(), token)
+        }
+        Commands::Logout { registry, team } => commands::publish::cmd_logout(registry.as_deref(), team.as_deref()),
+        Commands::Yank { spec, undo, registry, team } => {
+            commands::yank::cmd_yank(&spec, undo, registry.as_deref(), team.as_deref())
         }
-        Commands::Login { registry, token } => commands::publish::cmd_login(registry.as_deref(), token),
-        Commands::Logout { registry } => commands::publish::cmd_logout(registry.as_deref()),
+        Commands::Owner { action } => commands::owner::cmd_owner(action),
         Commands::Doctor { registry } => commands::doctor::cmd_doctor(registry),
         Commands::Repl { logic, format, load } => crate::repl::cmd_repl(logic, format, load),
         Commands::Sat { file, proof, stats } => commands::sat::cmd_sat(file, proof, stats),
         Commands::Prove { file, trace, json } => commands::prove::cmd_prove(file, trace, json),
-        Commands::Logic { sentence, file, format, all_readings, pragmatic, discourse } => {
-            commands::logic::cmd_logic(sentence, file, format, all_readings, pragmatic, discourse)
+        Commands::Export { prover, file, output } => commands::export::cmd_export(prover, file, output),
+        Commands::Logic { sentence, file, format, all_readings, pragmatic, discourse, tree } => {
+            commands::logic::cmd_logic(sentence, file, format, all_readings, pragmatic, discourse, tree)
         }
         Commands::Doc { out } => commands::doc::cmd_doc(out),
+        Commands::Graph { format } => commands::graph::cmd_graph(format),
         Commands::Add { spec, path, git } => commands::deps::cmd_add(spec, path, git),
         Commands::Remove { name } => commands::deps::cmd_remove(name),
         Commands::Fmt { paths, check } => commands::fmt::cmd_fmt(paths, check),
         Commands::Emit { target, file, output } => commands::emit::cmd_emit(target, file, output),
         Commands::Clean { all } => commands::clean::cmd_clean(all),
         Commands::Completions { shell } => commands::completions::cmd_completions(shell),
-        Commands::Test { .. } => Err(ui::CliError::with_hint(
-            "`largo test` is reserved for the LOGOS test framework (coming in a future release)",
-            "run `largo check` to validate your project today",
-        )
-        .exit_code(ui::EXIT_USAGE)
-        .into()),
+        Commands::Daemon { socket, stop, status } => commands::daemon::cmd_daemon(socket, stop, status),
+        Commands::Watch { command, debounce, args } => commands::watch::cmd_watch(command, debounce, &args),
+        Commands::Mutate { timeout } => commands::mutate::cmd_mutate(timeout),
+        Commands::Bisect { file, compile_error, error, prints, panic, timeout, output } => {
+            use commands::bisect::Failure;
+            let failure = match (compile_error, error, prints, panic) {
+                (Some(text), ..) => Failure::CompileError(text),
+                (_, Some(text), ..) => Failure::RunError(text),
+                (_, _, Some(text), _) => Failure::Prints(text),
+                (.., Some(text)) => Failure::Panic(text),
+                (None, None, None, None) => unreachable!("clap requires one failure flag"),
+            };
+            commands::bisect::cmd_bisect(file, failure, timeout, output)
+        }
+        Commands::Dev { action } => commands::dev::cmd_dev(action),
+        Commands::Test { filter, coverage, differential } => {
+            commands::test::cmd_test(filter.as_deref(), coverage, differential)
+        }
     }
 }
@@ -0,0 +1,242 @@
+//! `largo bisect` — shrink a failing program to the statements that matter.
+//!
+//! The reducer is delta debugging (ddmin) over the program's lines: chunks of
+//! statements are deleted for as long as what is left still fails the way
+//! the original did, halving the chunk size whenever no chunk can go, until
+//! no single statement can be deleted either. A deleted statement takes its
+//! indented block with it, so `If …:` and its body leave together. Section
+//! headers (`## To …`, `## Main`) are never deleted, so every candidate keeps
+//! the program's shape.
+//!
+//! The failure to preserve is named on the command line: the compiler
+//! rejecting the program, a run ending in an error, the output containing
+//! some text, or a panic anywhere in the compiler or interpreter. Each
+//! candidate runs on its own thread under a timeout — deleting `Set i to
+//! i + 1.` can leave a loop that never ends, and a candidate that never
+//! finishes does not count as failing.
+
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use crate::ui::{self, CliError};
+
+/// The way a program fails, which every reduction step must preserve. The
+/// text is a substring the error, output or panic message must contain; an
+/// empty text matches any.
+#[derive(Debug, Clone)]
+pub(crate) enum Failure {
+    /// The compiler rejects the program.
+    CompileError(String),
+    /// The interpreted run ends in an error.
+    RunError(String),
+    /// The interpreted run prints the text.
+    Prints(String),
+    /// The compiler or the interpreter panics.
+    Panic(String),
+}
+
+impl Failure {
+    /// Whether `program` fails this way. A panic propagates to the caller,
+    /// which only counts it for [`Failure::Panic`].
+    fn holds(&self, program: &str) -> bool {
+        let run = || futures::executor::block_on(logicaffeine_compile::interpret_for_ui(program));
+        match self {
+            Failure::CompileError(text) => {
+                let compiled = logicaffeine_compile::compile::compile_to_rust(program);
+                matches!(compiled, Err(e) if format!("{e:?}").contains(text.as_str()))
+            }
+            Failure::RunError(text) => run().error.is_some_and(|e| e.contains(text.as_str())),
+            Failure::Prints(text) => run().lines.join("\n").contains(text.as_str()),
+            Failure::Panic(_) => {
+                let _ = logicaffeine_compile::compile::compile_to_rust(program);
+                let _ = run();
+                false
+            }
+        }
+    }
+
+    /// `holds` on a thread of its own, giving up after `timeout`.
+    fn holds_within(&self, program: &str, timeout: Duration) -> bool {
+        let (tx, rx) = mpsc::channel();
+        let (failure, program) = (self.clone(), program.to_string());
+        // The abandoned thread of a run that never finishes ends with the process.
+        std::thread::spawn(move || {
+            let holds = match std::panic::catch_unwind(AssertUnwindSafe(|| failure.holds(&program))) {
+                Ok(holds) => holds,
+                Err(payload) => match &failure {
+                    Failure::Panic(text) => panic_message(payload.as_ref()).contains(text.as_str()),
+                    _ => false,
+                },
+            };
+            let _ = tx.send(holds);
+        });
+        rx.recv_timeout(timeout).unwrap_or(false)
+    }
+}
+
+/// The message a panic was raised with, when it carried one.
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload.downcast_ref::<String>().map_or("", String::as_str),
+    }
+}
+
+/// Shrink `program` to a smaller one that is still `interesting`, by ddmin
+/// over its statements (see the module docs). `program` itself is assumed
+/// interesting.
+pub(crate) fn reduce(program: &str, mut interesting: impl FnMut(&str) -> bool) -> String {
+    let lines: Vec<&str> = program.lines().collect();
+    let header = |i: usize| lines[i].starts_with("## ");
+    let indent = |i: usize| lines[i].len() - lines[i].trim_start().len();
+    // Where the block opened by line `i` ends: the next non-blank line that
+    // is not indented deeper, or a header.
+    let block_end = |i: usize| {
+        (i + 1..lines.len())
+            .find(|&j| !lines[j].trim().is_empty() && (header(j) || indent(j) <= indent(i)))
+            .unwrap_or(lines.len())
+    };
+    let render = |alive: &[bool]| {
+        let mut text = String::new();
+        for (line, &keep) in lines.iter().zip(alive) {
+            if keep {
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+        text
+    };
+
+    let mut alive = vec![true; lines.len()];
+    let compact: Vec<bool> = lines.iter().map(|l| !l.trim().is_empty()).collect();
+    if interesting(&render(&compact)) {
+        alive = compact;
+    }
+
+    let mut chunks = 2;
+    loop {
+        let candidates: Vec<usize> = (0..lines.len()).filter(|&i| alive[i] && !header(i)).collect();
+        if candidates.is_empty() {
+            break;
+        }
+        let chunks_now = chunks.min(candidates.len());
+        let size = candidates.len().div_ceil(chunks_now);
+        let mut reduced = false;
+        for chunk in candidates.chunks(size) {
+            let mut trial = alive.clone();
+            for &i in chunk {
+                trial[i..block_end(i)].iter_mut().for_each(|a| *a = false);
+            }
+            if interesting(&render(&trial)) {
+                alive = trial;
+                chunks = (chunks_now - 1).max(2);
+                reduced = true;
+                break;
+            }
+        }
+        if !reduced {
+            if chunks_now >= candidates.len() {
+                break;
+            }
+            chunks = (chunks_now * 2).min(candidates.len());
+        }
+    }
+    render(&alive)
+}
+
+/// `crash.lg` → `crash.min.lg`, next to the original.
+fn default_output(file: &Path) -> PathBuf {
+    let stem = file.file_stem().map_or_else(|| "program".into(), |s| s.to_string_lossy());
+    match file.extension() {
+        Some(ext) => file.with_file_name(format!("{stem}.min.{}", ext.to_string_lossy())),
+        None => file.with_file_name(format!("{stem}.min")),
+    }
+}
+
+/// Handle `largo bisect FILE <failure> [--timeout SECS] [-o OUT]`.
+pub(crate) fn cmd_bisect(
+    file: PathBuf,
+    failure: Failure,
+    timeout: u64,
+    output: Option<PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let source = std::fs::read_to_string(&file)
+        .map_err(|e| CliError::new(format!("cannot read {}: {e}", file.display())))?;
+    let timeout = Duration::from_secs(timeout);
+
+    // Candidates that panic are expected; keep their reports off the terminal.
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let reduced = if failure.holds_within(&source, timeout) {
+        ui::phase("Reducing", format!("{}: {} lines", file.display(), source.lines().count()));
+        let mut runs = 0;
+        let reduced = reduce(&source, |candidate| {
+            runs += 1;
+            failure.holds_within(candidate, timeout)
+        });
+        Some((reduced, runs))
+    } else {
+        None
+    };
+    std::panic::set_hook(hook);
+
+    let Some((reduced, runs)) = reduced else {
+        return Err(CliError::with_hint(
+            format!("{} does not fail that way, so there is nothing to preserve", file.display()),
+            "check the failure flag against what `largo run --interpret` or `largo build` reports",
+        )
+        .into());
+    };
+    let out = output.unwrap_or_else(|| default_output(&file));
+    std::fs::write(&out, &reduced).map_err(|e| CliError::new(format!("cannot write {}: {e}", out.display())))?;
+    ui::info(format!(
+        "reduced {} lines to {} in {runs} runs: {}",
+        source.lines().count(),
+        reduced.lines().count(),
+        out.display()
+    ));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROGRAM: &str = "## To double (n: Int) -> Int:\n    Return n * 2.\n\n## Main\nLet x be 1.\nShow x.\nShow double(21).\nShow 3.\n";
+
+    #[test]
+    fn reduction_keeps_only_the_failing_statement() {
+        let reduced = reduce(PROGRAM, |p| p.contains("Show double(21)."));
+        assert_eq!(reduced, "## To double (n: Int) -> Int:\n## Main\nShow double(21).\n");
+    }
+
+    #[test]
+    fn reduction_keeps_statements_that_fail_together() {
+        let reduced = reduce(PROGRAM, |p| p.contains("Return n * 2.") && p.contains("Show double(21)."));
+        assert_eq!(reduced, "## To double (n: Int) -> Int:\n    Return n * 2.\n## Main\nShow double(21).\n");
+    }
+
+    #[test]
+    fn a_deleted_statement_takes_its_block() {
+        let program = "## Main\nIf x > 1:\n    Show 1.\n    Show 2.\nShow 3.\n";
+        assert_eq!(reduce(program, |p| p.contains("Show 3.")), "## Main\nShow 3.\n");
+        assert_eq!(reduce(program, |p| p.contains("Show 2.")), "## Main\nIf x > 1:\n    Show 2.\n");
+    }
+
+    #[test]
+    fn failures_are_matched_by_their_text() {
+        let timeout = Duration::from_secs(10);
+        assert!(Failure::Prints("42".into()).holds_within("## Main\nShow 42.\n", timeout));
+        assert!(!Failure::Prints("41".into()).holds_within("## Main\nShow 42.\n", timeout));
+        assert!(Failure::RunError(String::new()).holds_within("## Main\nShow 1 / 0.\n", timeout));
+        assert!(!Failure::RunError(String::new()).holds_within("## Main\nShow 1.\n", timeout));
+    }
+
+    #[test]
+    fn output_lands_next_to_the_original() {
+        assert_eq!(default_output(Path::new("bugs/crash.lg")), PathBuf::from("bugs/crash.min.lg"));
+        assert_eq!(default_output(Path::new("notes.md")), PathBuf::from("notes.min.md"));
+    }
+}
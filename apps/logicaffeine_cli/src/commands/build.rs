@@ -4,7 +4,7 @@ use std::fs;
 
 use crate::commands::emit::emit_wasm_module;
 use crate::commands::require_project_root;
-use crate::commands::verify::run_verification;
+use crate::commands::verify::{run_verification, VerifyOptions};
 use crate::project::build::{self, BuildConfig, BuildError, CargoFailureKind};
 use crate::project::manifest::Manifest;
 use crate::ui::{self, CliError};
@@ -41,16 +41,18 @@ pub(crate) fn cmd_build(
     release: bool,
     verify: bool,
     license: Option<String>,
+    verify_options: VerifyOptions,
     lib: bool,
     target: Option<String>,
     native_functions: bool,
     emit: Option<String>,
+    features: Vec<String>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let project_root = require_project_root()?;
 
     // Run verification if requested
     if verify {
-        run_verification(&project_root, license.as_deref())?;
+        run_verification(&project_root, license.as_deref(), &verify_options)?;
     }
 
     // `--emit wasm`: compile the entry DIRECTLY to a `.wasm` via the built-in backend — no rustc,
@@ -58,8 +60,8 @@ pub(crate) fn cmd_build(
     // `print_*`/`args`/… imports are supplied by any wasm runtime or a ~10-line browser shim).
     if let Some(kind) = emit.as_deref() {
         match kind {
-            "wasm" => return emit_wasm_module(&project_root, false),
-            "wasm-linked" => return emit_wasm_module(&project_root, true),
+            "wasm" => return emit_wasm_module(&project_root, false, &features),
+            "wasm-linked" => return emit_wasm_module(&project_root, true, &features),
             _ => return Err(format!("unknown --emit target '{kind}' (expected 'wasm' or 'wasm-linked')").into()),
         }
     }
@@ -69,6 +71,8 @@ pub(crate) fn cmd_build(
         release,
         lib_mode: lib,
         target,
+        profile: false,
+        features,
     };
 
     let result = build::build(config).map_err(friendly_build_error)?;
@@ -1,5 +1,17 @@
 //! `largo check` — parse and type-check without producing a binary.
+//!
+//! Proofs finished in Lean 4 or Coq are discharged here too: each `.lean` /
+//! `.v` file under `proofs/` (typically a `largo export` file with some
+//! `sorry`s replaced) has its term proofs re-checked by the kernel against
+//! the project's `## Theorem` blocks.
 
+use std::path::Path;
+
+use logicaffeine_compile::loader::{trace_span, CompileTarget};
+use logicaffeine_language::check_exported_proofs;
+use logicaffeine_proof::export::{Prover, Verdict};
+
+use crate::commands::prove::split_theorem_blocks;
 use crate::commands::require_project_root;
 use crate::compile::compile_project;
 use crate::project::manifest::Manifest;
@@ -17,29 +29,14 @@ pub(crate) fn cmd_check(deep: bool) -> Result<(), Box<dyn std::error::Error>> {
 
     // Just compile to Rust without building (discard output, only care about success)
     if let Err(e) = compile_project(&entry_path) {
-        // Parse errors render with the caret excerpt + the Socratic
-        // explanation — never the raw Debug struct.
-        if let logicaffeine_compile::compile::CompileError::Parse(pe) = &e {
-            let source = std::fs::read_to_string(&entry_path).unwrap_or_default();
-            let interner = logicaffeine_language::Interner::new();
-            // The socratic explanation leads; the caret excerpt follows
-            // (dropping display_with_source's own `error:` first line — the
-            // CLI renderer adds the prefix).
-            let excerpt: String = pe
-                .display_with_source(&source)
-                .lines()
-                .skip(1)
-                .collect::<Vec<_>>()
-                .join("\n");
-            return Err(crate::ui::CliError::new(format!(
-                "{}\n{excerpt}",
-                logicaffeine_language::socratic_explanation(pe, &interner)
-            ))
-            .into());
+        if let Some(message) = describe_parse_failure(&e, &entry_path) {
+            return Err(crate::ui::CliError::new(message).into());
         }
         return Err(e.into());
     }
 
+    check_imported_proofs(&project_root, &entry_path)?;
+
     if deep {
         let source = std::fs::read_to_string(&entry_path)?;
         // Key the flycheck cache on the PROJECT PATH, not just the package
@@ -87,3 +84,99 @@ pub(crate) fn cmd_check(deep: bool) -> Result<(), Box<dyn std::error::Error>> {
     ui::info("Check passed");
     Ok(())
 }
+
+/// Re-check every proof file under `<project_root>/proofs/` against the
+/// theorem blocks of the entry file. Theorems a file leaves open are
+/// reported but not failures; a proof the kernel rejects is.
+fn check_imported_proofs(project_root: &Path, entry_path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let Ok(dir) = std::fs::read_dir(project_root.join("proofs")) else {
+        return Ok(());
+    };
+    let mut files: Vec<(std::path::PathBuf, Prover)> = dir
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter_map(|path| {
+            let prover = match path.extension()?.to_str()? {
+                "lean" => Prover::Lean4,
+                "v" => Prover::Coq,
+                _ => return None,
+            };
+            Some((path, prover))
+        })
+        .collect();
+    if files.is_empty() {
+        return Ok(());
+    }
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let source = std::fs::read_to_string(entry_path)?;
+    let theorems = split_theorem_blocks(&source).concat();
+    if theorems.is_empty() {
+        return Err(ui::CliError::with_hint(
+            format!("proofs/ has proof files but {} has no theorems", entry_path.display()),
+            "add the `## Theorem` blocks they prove, or remove the files",
+        )
+        .into());
+    }
+
+    let mut failed = 0;
+    for (path, prover) in files {
+        let finished = std::fs::read_to_string(&path)?;
+        let shown = path.strip_prefix(project_root).unwrap_or(&path).display().to_string();
+        let discharged = check_exported_proofs(&theorems, &finished, prover)
+            .map_err(|e| ui::CliError::new(format!("cannot check {shown}: {e}")))?;
+        for discharge in discharged {
+            match discharge.verdict {
+                Verdict::Discharged => ui::info(format!("Theorem {} discharged by {shown}", discharge.name)),
+                Verdict::Open => ui::info(format!("Theorem {} is still open in {shown}", discharge.name)),
+                Verdict::Rejected(reason) => {
+                    failed += 1;
+                    eprintln!("{shown}: theorem {}: {reason}\n", discharge.name);
+                }
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(format!("{failed} imported proof(s) failed to check").into());
+    }
+    Ok(())
+}
+
+/// A parse failure rendered the way `largo check` reports it: the Socratic
+/// explanation, then the caret excerpt — never the raw Debug struct. `None`
+/// for compile errors that are not parse errors. The error's span is traced
+/// back through the prelude and any template expansion to the line as
+/// written, with a note naming the template copy it came from.
+pub(crate) fn describe_parse_failure(
+    error: &logicaffeine_compile::compile::CompileError,
+    entry_path: &std::path::Path,
+) -> Option<String> {
+    let logicaffeine_compile::compile::CompileError::Parse(pe) = error else {
+        return None;
+    };
+    let source = std::fs::read_to_string(entry_path).unwrap_or_default();
+    let (span, copy) = trace_span(&source, &CompileTarget::host(), pe.span);
+    let traced = logicaffeine_language::ParseError { kind: pe.kind.clone(), span };
+    let mut message = explain_parse_error(&traced, &source);
+    if let Some(copy) = copy {
+        message.push_str(&format!("\n     = note: {}", copy.describe(&source)));
+    }
+    Some(message)
+}
+
+/// The Socratic explanation of `error`, then its caret excerpt into `source`.
+pub(crate) fn explain_parse_error(error: &logicaffeine_language::ParseError, source: &str) -> String {
+    let interner = logicaffeine_language::Interner::new();
+    // The socratic explanation leads; the caret excerpt follows (dropping
+    // display_with_source's own `error:` first line — the CLI renderer adds
+    // the prefix).
+    let excerpt: String = error
+        .display_with_source(source)
+        .lines()
+        .skip(1)
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "{}\n{excerpt}",
+        logicaffeine_language::socratic_explanation(error, &interner)
+    )
+}
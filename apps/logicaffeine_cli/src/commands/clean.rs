@@ -6,8 +6,8 @@ use crate::commands::require_project_root;
 use crate::ui;
 
 /// Handle `largo clean`: remove `target/`, and with `--all` also the
-/// `.logos-native/` compiled-function bundle cache. Removing nothing is
-/// still a success (idempotent).
+/// `.logos-native/` compiled-function bundle cache and the `.largo/`
+/// verification cache. Removing nothing is still a success (idempotent).
 pub(crate) fn cmd_clean(all: bool) -> Result<(), Box<dyn std::error::Error>> {
     let project_root = require_project_root()?;
 
@@ -21,11 +21,12 @@ pub(crate) fn cmd_clean(all: bool) -> Result<(), Box<dyn std::error::Error>> {
     }
 
     if all {
-        let native = project_root.join(".logos-native");
-        if native.exists() {
-            fs::remove_dir_all(&native)
-                .map_err(|e| format!("could not remove {}: {e}", native.display()))?;
-            removed.push(native);
+        for cache in [project_root.join(".logos-native"), project_root.join(".largo")] {
+            if cache.exists() {
+                fs::remove_dir_all(&cache)
+                    .map_err(|e| format!("could not remove {}: {e}", cache.display()))?;
+                removed.push(cache);
+            }
         }
     }
 
@@ -0,0 +1,431 @@
+//! `largo daemon` — a long-lived compile server for editors and scripts.
+//!
+//! Every plain `largo` invocation starts cold: the lexicon tables, the
+//! multi-word-expression trie and the stdlib prelude are rebuilt before the
+//! first token is read. The daemon pays that once, then serves requests over
+//! a local Unix socket for as long as it runs.
+//!
+//! The protocol is JSON-RPC 2.0, one message per line in each direction.
+//! A connection may carry any number of requests; each is answered in order.
+//!
+//! | Method | Params | Result |
+//! |--------|--------|--------|
+//! | `ping` | — | `{ version, uptime_ms, requests }` |
+//! | `compile` | `{ source }` | `{ rust, dependencies, metrics, cached }` |
+//! | `check` | `{ project }` | `{ ok: true, metrics }` |
+//! | `build` | `{ project, release? }` | `{ binary }` |
+//! | `shutdown` | — | `null`, then the daemon exits |
+//!
+//! `compile` results are cached by source text, so an editor re-requesting
+//! an unchanged buffer gets an instant answer. `check` and `build` always
+//! reread the project: imports may have changed on disk.
+//!
+//! Failures use the JSON-RPC error object: `-32700` unparseable line,
+//! `-32600` not a request, `-32601` unknown method, `-32602` bad params, and
+//! [`COMPILE_FAILED`] for a program that does not compile (the message is
+//! the same Socratic explanation `largo check` prints).
+//!
+//! ```bash
+//! largo daemon &                      # listens on the default socket
+//! echo '{"jsonrpc":"2.0","id":1,"method":"ping"}' | nc -U "$XDG_RUNTIME_DIR/largo-daemon.sock"
+//! largo daemon --stop
+//! ```
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+use serde_json::{json, Value};
+
+use crate::ui::{self, CliError};
+
+/// JSON-RPC error code for a program that failed to compile or check.
+pub const COMPILE_FAILED: i64 = -32000;
+
+/// How many `compile` results the daemon keeps before starting over.
+const COMPILE_CACHE_LIMIT: usize = 256;
+
+/// The socket `largo daemon` listens on when `--socket` is not given: the
+/// user's runtime directory when there is one, else the temp directory.
+pub fn default_socket_path() -> PathBuf {
+    dirs::runtime_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("largo-daemon.sock")
+}
+
+/// What the daemon keeps between requests.
+pub struct DaemonState {
+    started: Instant,
+    requests: AtomicU64,
+    /// Keyed by the whole source, so two programs can never share an entry.
+    compile_cache: Mutex<HashMap<String, Value>>,
+}
+
+/// The outcome of one request line: the reply to write back (none for a
+/// notification), and whether the daemon should stop afterwards.
+pub struct Reply {
+    pub response: Option<Value>,
+    pub shutdown: bool,
+}
+
+impl Default for DaemonState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DaemonState {
+    pub fn new() -> Self {
+        DaemonState {
+            started: Instant::now(),
+            requests: AtomicU64::new(0),
+            compile_cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Build the process-wide tables a cold compile would otherwise pay for
+    /// on the first request.
+    pub fn warm_up(&self) {
+        let _ = logicaffeine_language::mwe::shared_mwe_trie();
+        let _ = logicaffeine_language::compile("Every daemon is warm.");
+        let _ = logicaffeine_compile::compile::compile_program_full("## Main\nShow 1.\n");
+    }
+
+    /// Answer one line of the protocol.
+    pub fn handle_line(&self, line: &str) -> Reply {
+        let message: Value = match serde_json::from_str(line) {
+            Ok(message) => message,
+            Err(e) => {
+                return Reply {
+                    response: Some(error_response(Value::Null, -32700, format!("parse error: {e}"))),
+                    shutdown: false,
+                }
+            }
+        };
+        let id = message.get("id").cloned();
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            return Reply {
+                response: Some(error_response(
+                    id.unwrap_or(Value::Null),
+                    -32600,
+                    "invalid request: no method".to_string(),
+                )),
+                shutdown: false,
+            };
+        };
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        let shutdown = method == "shutdown";
+        let outcome = match method {
+            "ping" => Ok(self.ping()),
+            "compile" => self.compile(&params),
+            "check" => check(&params),
+            "build" => build(&params),
+            "shutdown" => Ok(Value::Null),
+            other => Err((-32601, format!("method not found: {other}"))),
+        };
+
+        // A request without an id is a notification: act, but don't answer.
+        let response = id.map(|id| match outcome {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message)) => error_response(id, code, message),
+        });
+        Reply { response, shutdown }
+    }
+
+    fn ping(&self) -> Value {
+        json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "uptime_ms": self.started.elapsed().as_millis() as u64,
+            "requests": self.requests.load(Ordering::Relaxed),
+        })
+    }
+
+    fn compile(&self, params: &Value) -> Result<Value, (i64, String)> {
+        let source = string_param(params, "source")?;
+        if let Some(hit) = self.compile_cache.lock().unwrap().get(source) {
+            let mut hit = hit.clone();
+            hit["cached"] = Value::Bool(true);
+            return Ok(hit);
+        }
+
+        let output = logicaffeine_compile::compile::compile_program_full(source)
+            .map_err(|e| (COMPILE_FAILED, super::check::explain_parse_error(&e, source)))?;
+        let result = json!({
+            "rust": output.rust_code,
+            "dependencies": output.dependencies.iter().map(|d| d.name.clone()).collect::<Vec<_>>(),
+            "metrics": metrics_json(&output.metrics),
+            "cached": false,
+        });
+
+        let mut cache = self.compile_cache.lock().unwrap();
+        if cache.len() >= COMPILE_CACHE_LIMIT {
+            cache.clear();
+        }
+        cache.insert(source.to_string(), result.clone());
+        Ok(result)
+    }
+}
+
+fn check(params: &Value) -> Result<Value, (i64, String)> {
+    let (_, entry) = project_entry(params)?;
+    match crate::compile::compile_project(&entry) {
+        Ok(output) => Ok(json!({ "ok": true, "metrics": metrics_json(&output.metrics) })),
+        Err(e) => Err((
+            COMPILE_FAILED,
+            super::check::describe_parse_failure(&e, &entry).unwrap_or_else(|| e.to_string()),
+        )),
+    }
+}
+
+fn build(params: &Value) -> Result<Value, (i64, String)> {
+    let (root, _) = project_entry(params)?;
+    let release = params.get("release").and_then(Value::as_bool).unwrap_or(false);
+    let config = crate::project::build::BuildConfig {
+        project_dir: root,
+        release,
+        lib_mode: false,
+        target: None,
+        profile: false,
+        features: Vec::new(),
+    };
+    crate::project::build::build(config)
+        .map(|result| json!({ "binary": result.binary_path }))
+        .map_err(|e| (COMPILE_FAILED, e.to_string()))
+}
+
+/// The project root (searching upward from `params.project`) and its entry file.
+fn project_entry(params: &Value) -> Result<(PathBuf, PathBuf), (i64, String)> {
+    let start = Path::new(string_param(params, "project")?);
+    let root = crate::project::build::find_project_root(start).ok_or_else(|| {
+        (-32602, format!("no Largo.toml at or above {}", start.display()))
+    })?;
+    let manifest = crate::project::manifest::Manifest::load(&root)
+        .map_err(|e| (COMPILE_FAILED, e.to_string()))?;
+    let entry = super::resolve_entry_path(&root, &manifest)
+        .map_err(|e| (COMPILE_FAILED, e.to_string()))?;
+    Ok((root, entry))
+}
+
+fn string_param<'a>(params: &'a Value, name: &str) -> Result<&'a str, (i64, String)> {
+    params
+        .get(name)
+        .and_then(Value::as_str)
+        .ok_or_else(|| (-32602, format!("invalid params: `{name}` must be a string")))
+}
+
+fn metrics_json(metrics: &logicaffeine_compile::metrics::CompileMetrics) -> Value {
+    metrics
+        .iter()
+        .map(|(phase, m)| {
+            let entry = json!({ "ms": m.duration.as_secs_f64() * 1000.0, "count": m.count });
+            (phase.name().to_string(), entry)
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+fn error_response(id: Value, code: i64, message: String) -> Value {
+    // Parse-error excerpts are painted for a terminal; a client wants text.
+    let message = anstream::adapter::strip_str(&message).to_string();
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+/// Handle `largo daemon [--socket PATH] [--stop | --status]`.
+pub(crate) fn cmd_daemon(
+    socket: Option<PathBuf>,
+    stop: bool,
+    status: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let socket = socket.unwrap_or_else(default_socket_path);
+    if stop || status {
+        let method = if stop { "shutdown" } else { "ping" };
+        let reply = unix::request(&socket, method).map_err(|e| {
+            CliError::with_hint(
+                format!("no daemon answered on {}: {e}", socket.display()),
+                "start one with `largo daemon`",
+            )
+        })?;
+        if status {
+            ui::info(reply);
+        } else {
+            ui::info(format!("Stopped the daemon on {}", socket.display()));
+        }
+        return Ok(());
+    }
+    unix::serve(&socket, DaemonState::new())
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::Path;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use super::DaemonState;
+    use crate::ui::{self, CliError};
+
+    pub(super) fn serve(socket: &Path, state: DaemonState) -> Result<(), Box<dyn std::error::Error>> {
+        if socket.exists() {
+            if UnixStream::connect(socket).is_ok() {
+                return Err(CliError::with_hint(
+                    format!("a daemon is already listening on {}", socket.display()),
+                    "stop it with `largo daemon --stop`, or pass another --socket",
+                )
+                .into());
+            }
+            // Left behind by a daemon that did not exit cleanly.
+            std::fs::remove_file(socket)?;
+        }
+        let listener = UnixListener::bind(socket)?;
+        state.warm_up();
+        ui::phase("Listening", socket.display());
+
+        let state = Arc::new(state);
+        let stopping = Arc::new(AtomicBool::new(false));
+        for stream in listener.incoming() {
+            if stopping.load(Ordering::SeqCst) {
+                break;
+            }
+            let Ok(stream) = stream else { continue };
+            let state = Arc::clone(&state);
+            let stopping = Arc::clone(&stopping);
+            let socket = socket.to_path_buf();
+            std::thread::spawn(move || {
+                if serve_connection(stream, &state) {
+                    stopping.store(true, Ordering::SeqCst);
+                    // Wake the accept loop so it sees the flag.
+                    let _ = UnixStream::connect(&socket);
+                }
+            });
+        }
+        let _ = std::fs::remove_file(socket);
+        Ok(())
+    }
+
+    /// Serve one client until it disconnects. Returns whether it asked the
+    /// daemon to shut down.
+    fn serve_connection(stream: UnixStream, state: &DaemonState) -> bool {
+        let Ok(mut writer) = stream.try_clone() else { return false };
+        for line in BufReader::new(stream).lines() {
+            let Ok(line) = line else { return false };
+            if line.trim().is_empty() {
+                continue;
+            }
+            let reply = state.handle_line(&line);
+            if let Some(response) = reply.response {
+                if writeln!(writer, "{response}").is_err() {
+                    return false;
+                }
+            }
+            if reply.shutdown {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Send one parameterless request and return its `result` as JSON text.
+    pub(super) fn request(socket: &Path, method: &str) -> std::io::Result<String> {
+        let mut stream = UnixStream::connect(socket)?;
+        writeln!(stream, r#"{{"jsonrpc":"2.0","id":1,"method":"{method}"}}"#)?;
+        let mut line = String::new();
+        BufReader::new(stream).read_line(&mut line)?;
+        let reply: serde_json::Value = serde_json::from_str(&line)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        Ok(reply["result"].to_string())
+    }
+}
+
+#[cfg(not(unix))]
+mod unix {
+    use std::path::Path;
+
+    use super::DaemonState;
+    use crate::ui::CliError;
+
+    fn unsupported() -> CliError {
+        CliError::new("`largo daemon` needs Unix domain sockets, which this platform lacks")
+    }
+
+    pub(super) fn serve(_: &Path, _: DaemonState) -> Result<(), Box<dyn std::error::Error>> {
+        Err(unsupported().into())
+    }
+
+    pub(super) fn request(_: &Path, _: &str) -> std::io::Result<String> {
+        Err(std::io::Error::new(std::io::ErrorKind::Unsupported, unsupported().message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(state: &DaemonState, line: &str) -> Value {
+        state.handle_line(line).response.expect("requests with an id are answered")
+    }
+
+    #[test]
+    fn ping_reports_the_version_and_counts_requests() {
+        let state = DaemonState::new();
+        call(&state, r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#);
+        let reply = call(&state, r#"{"jsonrpc":"2.0","id":2,"method":"ping"}"#);
+        assert_eq!(reply["id"], 2);
+        assert_eq!(reply["result"]["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(reply["result"]["requests"], 2);
+    }
+
+    #[test]
+    fn compile_answers_rust_and_caches_by_source() {
+        let state = DaemonState::new();
+        let request = json!({
+            "jsonrpc": "2.0", "id": 1, "method": "compile",
+            "params": { "source": "## Main\nShow 42.\n" },
+        })
+        .to_string();
+        let first = call(&state, &request);
+        assert!(first["result"]["rust"].as_str().unwrap().contains("fn main"), "{first}");
+        assert_eq!(first["result"]["cached"], false);
+        assert!(first["result"]["metrics"]["parse"]["count"].as_u64().unwrap() > 0);
+        let second = call(&state, &request);
+        assert_eq!(second["result"]["cached"], true);
+        assert_eq!(second["result"]["rust"], first["result"]["rust"]);
+
+        let other = call(&state, &request.replace("42", "43"));
+        assert_eq!(other["result"]["cached"], false);
+        assert!(other["result"]["rust"].as_str().unwrap().contains("43"), "{other}");
+    }
+
+    #[test]
+    fn failures_use_json_rpc_error_objects() {
+        let state = DaemonState::new();
+        let broken = call(
+            &state,
+            &json!({
+                "jsonrpc": "2.0", "id": 1, "method": "compile",
+                "params": { "source": "## Main\nLet be.\n" },
+            })
+            .to_string(),
+        );
+        assert_eq!(broken["error"]["code"], COMPILE_FAILED);
+        let unknown = call(&state, r#"{"jsonrpc":"2.0","id":2,"method":"launch"}"#);
+        assert_eq!(unknown["error"]["code"], -32601);
+        let bad_params = call(&state, r#"{"jsonrpc":"2.0","id":3,"method":"compile","params":{}}"#);
+        assert_eq!(bad_params["error"]["code"], -32602);
+        let garbage = call(&state, "{not json");
+        assert_eq!(garbage["error"]["code"], -32700);
+    }
+
+    #[test]
+    fn notifications_are_not_answered_and_shutdown_stops() {
+        let state = DaemonState::new();
+        let reply = state.handle_line(r#"{"jsonrpc":"2.0","method":"shutdown"}"#);
+        assert!(reply.response.is_none());
+        assert!(reply.shutdown);
+    }
+}
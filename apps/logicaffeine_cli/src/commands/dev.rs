@@ -0,0 +1,109 @@
+//! `largo dev` — tools for working on the compiler itself.
+//!
+//! `largo dev parser-coverage` parses a corpus with the derivation trace on
+//! and reports which grammar rules and named branches fired
+//! (`logicaffeine_language::coverage`). The corpus is the generated sentence
+//! corpus — every quantifier × verb class × construction pattern — plus the
+//! sentences of any files given, one per line, so a maintainer can see which
+//! constructions no test reaches and which rules only ever fail.
+
+use std::path::PathBuf;
+
+use logicaffeine_language::coverage::ParserCoverage;
+use logicaffeine_language::runtime_lexicon::LexiconIndex;
+use logicaffeine_language::test_utils::sentence_gen::SentenceGenerator;
+
+use crate::ui::{self, CliError};
+
+/// What `largo dev` does.
+#[derive(Debug, clap::Subcommand)]
+pub enum DevAction {
+    /// Report which parser rules and branches a sentence corpus exercises.
+    ParserCoverage {
+        /// Files of English sentences to parse as well, one per line; blank
+        /// lines and `#` comments are skipped.
+        files: Vec<PathBuf>,
+        /// Generated sentences per pattern; 0 parses only the files.
+        #[arg(long, default_value_t = 5, value_name = "N")]
+        per_pattern: usize,
+        /// Only list the rules and branches nothing exercised.
+        #[arg(long)]
+        uncovered: bool,
+    },
+}
+
+/// Handle `largo dev <action>`.
+pub(crate) fn cmd_dev(action: DevAction) -> Result<(), Box<dyn std::error::Error>> {
+    match action {
+        DevAction::ParserCoverage { files, per_pattern, uncovered } => {
+            cmd_parser_coverage(files, per_pattern, uncovered)
+        }
+    }
+}
+
+fn cmd_parser_coverage(
+    files: Vec<PathBuf>,
+    per_pattern: usize,
+    uncovered: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let lexicon = LexiconIndex::new();
+    let mut corpus: Vec<String> = SentenceGenerator::new(&lexicon)
+        .corpus(per_pattern)
+        .into_iter()
+        .map(|generated| generated.sentence)
+        .collect();
+    for file in &files {
+        let text = std::fs::read_to_string(file)
+            .map_err(|e| CliError::new(format!("cannot read {}: {e}", file.display())))?;
+        corpus.extend(corpus_sentences(&text).map(str::to_string));
+    }
+    if corpus.is_empty() {
+        return Err(CliError::with_hint(
+            "the corpus is empty",
+            "give sentence files, or a --per-pattern above 0 for generated sentences",
+        )
+        .into());
+    }
+
+    ui::phase("Parsing", format!("{} sentences", corpus.len()));
+    let mut coverage = ParserCoverage::new();
+    for sentence in &corpus {
+        let _ = coverage.record(sentence);
+    }
+
+    if !uncovered {
+        print!("{coverage}");
+        return Ok(());
+    }
+    let rules = coverage.unapplied_rules();
+    let branches = coverage.untaken_branches();
+    if rules.is_empty() && branches.is_empty() {
+        println!("Every rule applied and every branch was taken.");
+    }
+    if !rules.is_empty() {
+        println!("Rules never applied:");
+        rules.iter().for_each(|rule| println!("    {rule}"));
+    }
+    if !branches.is_empty() {
+        println!("Branches never taken:");
+        branches.iter().for_each(|branch| println!("    {branch}"));
+    }
+    Ok(())
+}
+
+/// The sentences of a corpus file: one per line, skipping blank lines and
+/// `#` comments.
+fn corpus_sentences(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corpus_files_skip_blanks_and_comments() {
+        let text = "# quantifiers\nEvery man is mortal.\n\n  Some dog barks.  \n# done\n";
+        assert_eq!(corpus_sentences(text).collect::<Vec<_>>(), ["Every man is mortal.", "Some dog barks."]);
+    }
+}
@@ -0,0 +1,219 @@
+//! `largo test --differential` — hold the two backends to the same answer.
+//!
+//! Every doc test runs once under the tree-walking interpreter (the oracle,
+//! `logicaffeine_compile::compile::tw_outcome`) and once as a compiled Rust
+//! binary. The runs agree when they print the same lines and both succeed or
+//! both fail; error messages are not compared, since the two backends word
+//! them differently.
+//!
+//! Under `--coverage` the binary is built unoptimized with a counter per
+//! statement (`logicaffeine_compile::coverage::compile_with_coverage`), and its
+//! counts join the interpreter's in the report.
+//!
+//! A diverging program is reduced before it is reported, by the `largo
+//! bisect` reducer with "still diverges" as the failure to preserve, and the
+//! minimal program is written to `target/differential/<test>.lg`. A deletion
+//! that breaks the program makes both backends reject it, which counts as
+//! agreement and is undone.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::commands::bisect::reduce;
+use crate::project::build::requires_component_is_safe;
+use crate::ui::CliError;
+
+use logicaffeine_compile::compile::{
+    compile_program_full, copy_runtime_crates, toml_feature_list, tw_outcome, CompileOutput, RunOutcome,
+};
+use logicaffeine_compile::coverage::{compile_with_coverage, CoverageReport};
+
+/// The package name of the scratch project every compiled run shares.
+const PACKAGE: &str = "logos_differential";
+
+/// Backend runs the reducer may spend on one diverging program. Each run is a
+/// cargo build, so a large program settles for a partial reduction.
+const MAX_REDUCTION_RUNS: usize = 200;
+
+/// A scratch cargo project under `target/differential/project`, rewritten for
+/// each program, so the runtime crates and their dependencies build once per
+/// session rather than once per doc test.
+pub(crate) struct CompiledBackend {
+    out_dir: PathBuf,
+    project: PathBuf,
+}
+
+impl CompiledBackend {
+    /// Create the scratch project for the project rooted at `root`.
+    pub(crate) fn new(root: &Path) -> Result<Self, CliError> {
+        let out_dir = root.join("target/differential");
+        let project = out_dir.join("project");
+        std::fs::create_dir_all(project.join("src"))
+            .map_err(|e| CliError::new(format!("cannot create {}: {e}", project.display())))?;
+        copy_runtime_crates(&project).map_err(|e| CliError::new(format!("cannot stage the runtime crates: {e}")))?;
+        Ok(CompiledBackend { out_dir, project })
+    }
+
+    /// Compile `program` to Rust, build it and run the binary. A program the
+    /// compiler rejects, or whose generated Rust does not build, fails with
+    /// that error, like a program the interpreter rejects.
+    pub(crate) fn run(&self, program: &str) -> RunOutcome {
+        match compile_program_full(program) {
+            Ok(output) => self.build_and_run(&output, None),
+            Err(e) => failed(format!("compile error: {e:?}")),
+        }
+    }
+
+    /// [`Self::run`] with the binary instrumented for coverage: also returns
+    /// the statement counts of the run, on the lines of `program`.
+    pub(crate) fn run_with_coverage(&self, program: &str) -> (RunOutcome, CoverageReport) {
+        let (output, probes) = match compile_with_coverage(program) {
+            Ok(compiled) => compiled,
+            Err(e) => return (failed(format!("compile error: {e:?}")), CoverageReport::default()),
+        };
+        let counts_file = self.out_dir.join("coverage.counts");
+        let _ = std::fs::remove_file(&counts_file);
+        let outcome = self.build_and_run(&output, Some(&counts_file));
+        let counts = std::fs::read_to_string(&counts_file).unwrap_or_default();
+        (outcome, probes.report(&counts))
+    }
+
+    /// Write `output` into the scratch project, build it and run the binary,
+    /// pointing `LOGOS_COVERAGE_FILE` at `counts_file` when given.
+    fn build_and_run(&self, output: &CompileOutput, counts_file: Option<&Path>) -> RunOutcome {
+        let mut manifest = format!(
+            "[package]\nname = \"{PACKAGE}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\n\
+logicaffeine-data = {{ path = \"./crates/logicaffeine_data\" }}\n\
+logicaffeine-system = {{ path = \"./crates/logicaffeine_system\", features = [{features}] }}\n\
+tokio = {{ version = \"1\", features = [\"rt-multi-thread\", \"macros\"] }}\n",
+            features = toml_feature_list(&output.system_features)
+        );
+        for dep in &output.dependencies {
+            if !requires_component_is_safe(&dep.name)
+                || !requires_component_is_safe(&dep.version)
+                || dep.features.iter().any(|f| !requires_component_is_safe(f))
+            {
+                return failed(format!("`## Requires` declaration for '{}' is not a valid dependency", dep.name));
+            }
+            let features: Vec<String> = dep.features.iter().map(|f| format!("\"{f}\"")).collect();
+            let _ = writeln!(
+                manifest,
+                "{} = {{ version = \"{}\", features = [{}] }}",
+                dep.name,
+                dep.version,
+                features.join(", ")
+            );
+        }
+        let written = std::fs::write(self.project.join("Cargo.toml"), manifest)
+            .and_then(|()| std::fs::write(self.project.join("src/main.rs"), &output.rust_code));
+        if let Err(e) = written {
+            return failed(format!("cannot write the scratch project: {e}"));
+        }
+
+        let build = Command::new("cargo")
+            .args(["build", "--quiet", "--color", "never"])
+            .current_dir(&self.project)
+            .output();
+        match build {
+            Ok(build) if build.status.success() => {}
+            Ok(build) => {
+                return failed(format!("generated Rust failed to build:\n{}", String::from_utf8_lossy(&build.stderr)))
+            }
+            Err(e) => return failed(format!("cannot run cargo: {e}")),
+        }
+
+        let mut binary = Command::new(self.project.join("target/debug").join(PACKAGE));
+        if let Some(counts_file) = counts_file {
+            binary.env("LOGOS_COVERAGE_FILE", counts_file);
+        }
+        match binary.output() {
+            Ok(run) => RunOutcome {
+                output: String::from_utf8_lossy(&run.stdout).into_owned(),
+                error: (!run.status.success()).then(|| String::from_utf8_lossy(&run.stderr).into_owned()),
+            },
+            Err(e) => failed(format!("cannot run the compiled binary: {e}")),
+        }
+    }
+
+    /// Run one doc test under both backends; `Err` carries the divergence
+    /// report, with the reduced program, when they disagree.
+    pub(crate) fn check(&self, name: &str, program: &str) -> Result<(), String> {
+        self.judge(name, program, &self.run(program))
+    }
+
+    /// [`Self::check`] against an instrumented binary; also returns the
+    /// compiled run's coverage, on the lines of `program`.
+    pub(crate) fn check_with_coverage(&self, name: &str, program: &str) -> (Result<(), String>, CoverageReport) {
+        let (compiled, coverage) = self.run_with_coverage(program);
+        (self.judge(name, program, &compiled), coverage)
+    }
+
+    /// Compare a compiled run of `program` with the interpreter's.
+    fn judge(&self, name: &str, program: &str, compiled: &RunOutcome) -> Result<(), String> {
+        let interpreted = tw_outcome(program);
+        if agree(&interpreted, compiled) {
+            return Ok(());
+        }
+
+        let mut runs = 0;
+        let reduced = reduce(program, |candidate| {
+            runs += 1;
+            runs <= MAX_REDUCTION_RUNS && !agree(&tw_outcome(candidate), &self.run(candidate))
+        });
+        let path = self.out_dir.join(format!("{}.lg", name.replace(['/', '\\', ':'], "_")));
+        let saved = match std::fs::write(&path, &reduced) {
+            Ok(()) => format!(" (saved to {})", path.display()),
+            Err(_) => String::new(),
+        };
+        Err(format!(
+            "the backends diverge\ninterpreter:\n{}\ncompiled binary:\n{}\nreduced program{saved}:\n{}",
+            describe(&interpreted),
+            describe(compiled),
+            reduced.trim_end()
+        ))
+    }
+}
+
+/// A run that stopped before the binary ran.
+fn failed(error: String) -> RunOutcome {
+    RunOutcome { output: String::new(), error: Some(error) }
+}
+
+/// Whether two runs agree: the same printed lines, and both succeed or both fail.
+fn agree(a: &RunOutcome, b: &RunOutcome) -> bool {
+    normalize(&a.output) == normalize(&b.output) && a.error.is_some() == b.error.is_some()
+}
+
+fn normalize(output: &str) -> String {
+    output.lines().map(str::trim_end).collect::<Vec<_>>().join("\n").trim().to_string()
+}
+
+/// A run as the report shows it: its output, then its error if it failed.
+fn describe(run: &RunOutcome) -> String {
+    let mut text = normalize(&run.output);
+    if let Some(error) = &run.error {
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        let _ = write!(text, "error: {}", error.trim());
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_agree_on_output_and_failure_not_on_wording() {
+        let run = |output: &str, error: Option<&str>| RunOutcome {
+            output: output.to_string(),
+            error: error.map(str::to_string),
+        };
+        assert!(agree(&run("1\n2", None), &run("1\n2\n", None)));
+        assert!(agree(&run("1", Some("overflow")), &run("1\n", Some("panicked at 'overflow'"))));
+        assert!(!agree(&run("1", None), &run("1", Some("panicked"))));
+        assert!(!agree(&run("1\n2", None), &run("1", None)));
+    }
+}
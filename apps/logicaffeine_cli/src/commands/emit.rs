@@ -9,6 +9,8 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use logicaffeine_compile::loader::{resolve_target_sections, CompileTarget};
+
 use crate::commands::require_project_root;
 use crate::project::manifest::Manifest;
 use crate::ui;
@@ -52,7 +54,7 @@ pub(crate) fn cmd_emit(
 
 /// The source file to emit from: an explicit FILE, or the project entry
 /// (with the standard `.md` fallback).
-fn resolve_entry(file: Option<PathBuf>) -> Result<PathBuf, Box<dyn std::error::Error>> {
+pub(crate) fn resolve_entry(file: Option<PathBuf>) -> Result<PathBuf, Box<dyn std::error::Error>> {
     match file {
         Some(f) => Ok(f),
         None => {
@@ -64,7 +66,7 @@ fn resolve_entry(file: Option<PathBuf>) -> Result<PathBuf, Box<dyn std::error::E
 }
 
 /// Print emitted code to stdout, or write it to `-o` (stdout stays quiet).
-fn write_emitted(code: &str, output: Option<&Path>) -> Result<(), Box<dyn std::error::Error>> {
+pub(crate) fn write_emitted(code: &str, output: Option<&Path>) -> Result<(), Box<dyn std::error::Error>> {
     match output {
         Some(path) => {
             fs::write(path, code)?;
@@ -96,7 +98,7 @@ fn emit_wasm_target(
             (source, out_dir.join(format!("{}.wasm", manifest.package.name)))
         }
     };
-    let wasm = compile_wasm_bytes(&source, linked)?;
+    let wasm = compile_wasm_bytes(&source, linked, &[])?;
     let wasm_path = output.unwrap_or(default_wasm_path);
     if let Some(parent) = wasm_path.parent() {
         if !parent.as_os_str().is_empty() {
@@ -117,8 +119,11 @@ fn emit_wasm_target(
     Ok(())
 }
 
-/// Compile LOGOS source to wasm bytes on the selected backend.
-fn compile_wasm_bytes(source: &str, linked: bool) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+/// Compile LOGOS source to wasm bytes on the selected backend. The module
+/// runs under a wasm engine, so its `## When compiling for web:` sections
+/// are the ones kept.
+fn compile_wasm_bytes(source: &str, linked: bool, features: &[String]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let source = &*resolve_target_sections(source, &CompileTarget::web().with_features(features));
     if linked {
         logicaffeine_compile::compile::compile_to_wasm_linked(source)
             .map_err(|e| format!("linked wasm build failed: {e:?}").into())
@@ -131,7 +136,11 @@ fn compile_wasm_bytes(source: &str, linked: bool) -> Result<Vec<u8>, Box<dyn std
 /// Compile the project entry DIRECTLY to a standalone `.wasm` via the built-in backend (no
 /// rustc/cargo/wasm-bindgen), writing `target/<name>.wasm` and its `<name>.mjs` host shim. Returns
 /// `(mjs_path, wasm_byte_len)`. Shared by `--emit wasm` on both `build` and `run`.
-pub(crate) fn build_wasm_module(project_root: &std::path::Path, linked: bool) -> Result<(std::path::PathBuf, usize), Box<dyn std::error::Error>> {
+pub(crate) fn build_wasm_module(
+    project_root: &std::path::Path,
+    linked: bool,
+    features: &[String],
+) -> Result<(std::path::PathBuf, usize), Box<dyn std::error::Error>> {
     let manifest = Manifest::load(project_root)?;
     let entry_path = crate::commands::resolve_entry_path(project_root, &manifest)?;
     let source = fs::read_to_string(&entry_path)?;
@@ -139,12 +148,7 @@ pub(crate) fn build_wasm_module(project_root: &std::path::Path, linked: bool) ->
     // spec). `--emit wasm-linked` instead links the real `logicaffeine_base::BigInt` runtime with
     // `rust-lld`, so an overflowing integer expression computes the exact big number (matching the
     // VM) — at the cost of needing the Rust toolchain + a wasm32 `base` build.
-    let wasm = if linked {
-        logicaffeine_compile::compile::compile_to_wasm_linked(&source)
-            .map_err(|e| format!("linked wasm build failed: {e:?}"))?
-    } else {
-        logicaffeine_compile::compile::compile_to_wasm(&source).map_err(|e| format!("wasm build failed: {e:?}"))?
-    };
+    let wasm = compile_wasm_bytes(&source, linked, features)?;
     let out_dir = project_root.join("target");
     fs::create_dir_all(&out_dir)?;
     let name = &manifest.package.name;
@@ -155,8 +159,12 @@ pub(crate) fn build_wasm_module(project_root: &std::path::Path, linked: bool) ->
 }
 
 /// `largo build --emit wasm[-linked]` — compile to a standalone `.wasm` + `.mjs` host shim.
-pub(crate) fn emit_wasm_module(project_root: &std::path::Path, linked: bool) -> Result<(), Box<dyn std::error::Error>> {
-    let (mjs, bytes) = build_wasm_module(project_root, linked)?;
+pub(crate) fn emit_wasm_module(
+    project_root: &std::path::Path,
+    linked: bool,
+    features: &[String],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (mjs, bytes) = build_wasm_module(project_root, linked, features)?;
     let wasm = mjs.with_extension("wasm");
     let tier = if linked { "wasm-linked, BigInt runtime" } else { "wasm, no rustc" };
     ui::info(format!("Built {} [{tier}, {bytes} bytes]", wasm.display()));
@@ -262,7 +270,7 @@ mod emit_wasm_tests {
         .unwrap();
         fs::write(dir.join("src/main.lg"), "## Main\n    Show 6 * 7.\n").unwrap();
 
-        emit_wasm_module(&dir, false).expect("emit wasm should succeed");
+        emit_wasm_module(&dir, false, &[]).expect("emit wasm should succeed");
 
         let wasm = fs::read(dir.join("target/emitted.wasm")).expect("output .wasm should exist");
         assert_eq!(&wasm[0..4], b"\0asm", "output must begin with the wasm magic");
@@ -0,0 +1,68 @@
+//! `largo export` — carry English theorems into a mature proof assistant.
+//!
+//! Every `## Theorem` block (Given/Prove) is compiled to first-order logic
+//! and written as one Lean 4 or Coq source file: the predicates and
+//! individuals it mentions become axioms over an opaque `Entity` sort, the
+//! premises become hypotheses, and each theorem is left open (`sorry` /
+//! `Admitted`) for the user to finish there.
+
+use std::fs;
+use std::path::PathBuf;
+
+use logicaffeine_language::export_theorems;
+use logicaffeine_proof::export::Prover;
+
+use crate::commands::emit::{resolve_entry, write_emitted};
+use crate::commands::prove::split_theorem_blocks;
+use crate::ui::CliError;
+
+/// The proof assistants `largo export` can target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum ProverTarget {
+    /// Lean 4 (`.lean`).
+    #[value(alias = "lean")]
+    Lean4,
+    /// Coq / Rocq (`.v`).
+    #[value(alias = "rocq")]
+    Coq,
+}
+
+impl From<ProverTarget> for Prover {
+    fn from(target: ProverTarget) -> Prover {
+        match target {
+            ProverTarget::Lean4 => Prover::Lean4,
+            ProverTarget::Coq => Prover::Coq,
+        }
+    }
+}
+
+/// Handle `largo export --prover <lean4|coq> [FILE] [-o PATH]`.
+pub(crate) fn cmd_export(
+    prover: ProverTarget,
+    file: Option<PathBuf>,
+    output: Option<PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let path = resolve_entry(file)?;
+    let source = fs::read_to_string(&path)
+        .map_err(|e| CliError::new(format!("cannot read {}: {e}", path.display())))?;
+
+    // Only the theorem blocks: code and prose around them need not compile.
+    let blocks = split_theorem_blocks(&source);
+    if blocks.is_empty() {
+        return Err(CliError::with_hint(
+            format!("no theorems found in {}", path.display()),
+            "add a `## Theorem` block with `Given:` and `Prove:` lines to export",
+        )
+        .into());
+    }
+
+    let theorems = blocks.concat();
+    let exported = export_theorems(&theorems, prover.into()).map_err(|e| {
+        CliError::new(format!(
+            "cannot export the theorems in {}\n{}",
+            path.display(),
+            e.display_with_source(&theorems)
+        ))
+    })?;
+    write_emitted(exported.trim_end(), output.as_deref())
+}
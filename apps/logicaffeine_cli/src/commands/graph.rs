@@ -0,0 +1,49 @@
+//! `largo graph` — the project's module dependency graph as a diagram.
+//!
+//! Starts at the entry file and follows the `[Alias](uri)` imports in each
+//! module's abstract through the same [`Loader`] the build uses, then prints
+//! the graph as Graphviz DOT or Mermaid on stdout — ready to pipe into
+//! `dot -Tsvg` or paste into a markdown page. Imports that cannot be loaded
+//! and import cycles are warned about on stderr; the graph is still drawn.
+
+use std::fs;
+
+use logicaffeine_compile::diagram::{modules_to_dot, modules_to_mermaid};
+
+use crate::commands::require_project_root;
+use crate::project::manifest::Manifest;
+use crate::project::Loader;
+use crate::ui::{self, CliError};
+
+/// The diagram languages `largo graph` and `largo logic --tree` emit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum DiagramFormat {
+    /// Graphviz DOT (`dot -Tsvg graph.dot`).
+    #[default]
+    Dot,
+    /// A Mermaid flowchart, for markdown that renders ```mermaid blocks.
+    Mermaid,
+}
+
+/// Handle `largo graph [--format dot|mermaid]`.
+pub(crate) fn cmd_graph(format: DiagramFormat) -> Result<(), Box<dyn std::error::Error>> {
+    let root = require_project_root()?;
+    let manifest = Manifest::load(&root)?;
+    let entry = crate::commands::resolve_entry_path(&root, &manifest)?;
+    let source = fs::read_to_string(&entry)
+        .map_err(|e| CliError::new(format!("cannot read {}: {e}", entry.display())))?;
+
+    let mut loader = Loader::new(root);
+    let graph = loader.module_graph(&entry, &source);
+    for missing in graph.unresolved() {
+        ui::warn(format!("{}: cannot load [{}]({}): {}", graph.name(missing.from), missing.alias, missing.uri, missing.error));
+    }
+    for cycle in graph.cycles() {
+        ui::warn(cycle);
+    }
+    match format {
+        DiagramFormat::Dot => print!("{}", modules_to_dot(&graph)),
+        DiagramFormat::Mermaid => print!("{}", modules_to_mermaid(&graph)),
+    }
+    Ok(())
+}
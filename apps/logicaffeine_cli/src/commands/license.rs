@@ -0,0 +1,123 @@
+//! `largo license` — activate, inspect and release the verification license.
+//!
+//! Activation trades a license key for a signed license file bound to this
+//! machine, after which `largo verify` runs offline. Air-gapped machines
+//! install a file downloaded from the dashboard with `activate --file`.
+
+use std::path::PathBuf;
+
+/// What `largo license` does.
+#[derive(Debug, clap::Subcommand)]
+pub enum LicenseAction {
+    /// Activate this machine and store a signed license file.
+    Activate {
+        /// License key (`sub_…`, or `pi_…` for lifetime purchases).
+        /// Defaults to `LOGOS_LICENSE`.
+        key: Option<String>,
+
+        /// Install a license file downloaded from the dashboard instead of
+        /// contacting the activation server (for air-gapped machines).
+        #[arg(long, conflicts_with = "key")]
+        file: Option<PathBuf>,
+    },
+    /// Show the activated license: plan, expiry, grace period and machine.
+    Status,
+    /// Release this machine's activation and delete the license file.
+    Deactivate {
+        /// Delete the local file even if the server cannot be reached
+        /// (free the seat from the account page afterwards).
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// Handle `largo license <activate|status|deactivate>`.
+#[cfg(feature = "verification")]
+pub(crate) fn cmd_license(action: LicenseAction) -> Result<(), Box<dyn std::error::Error>> {
+    use logicaffeine_verify::license::{format_date, LicenseClaims, LicenseStanding};
+    use logicaffeine_verify::LicenseValidator;
+
+    use crate::ui::{self, CliError};
+
+    let validator = LicenseValidator::new();
+    let path = validator.license_path().display().to_string();
+    let describe = |claims: &LicenseClaims| -> String {
+        let holder = claims.licensee.as_deref().map(|l| format!(" for {l}")).unwrap_or_default();
+        format!("{} plan{holder}", claims.plan)
+    };
+
+    match action {
+        LicenseAction::Activate { file: Some(file), .. } => {
+            let text = std::fs::read_to_string(&file)
+                .map_err(|e| CliError::new(format!("cannot read {}: {e}", file.display())))?;
+            let claims = validator.install(&text)?;
+            ui::phase("Installed", format!("{} license file at {path}", describe(&claims)));
+        }
+        LicenseAction::Activate { key, file: None } => {
+            let key = key.or_else(|| std::env::var("LOGOS_LICENSE").ok()).ok_or_else(|| {
+                CliError::with_hint(
+                    "no license key given",
+                    "pass the key (`largo license activate sub_…`) or set LOGOS_LICENSE; \
+                     get one at https://logicaffeine.com/pricing",
+                )
+                .exit_code(ui::EXIT_USAGE)
+            })?;
+            let claims = validator.activate(&key)?;
+            ui::phase("Activated", format!("{} on this machine", describe(&claims)));
+            if claims.max_machines > 0 {
+                ui::info(format!(
+                    "{} of {} machine activations in use",
+                    claims.active_machines, claims.max_machines
+                ));
+            }
+        }
+        LicenseAction::Status => {
+            let Some(claims) = validator.activated()? else {
+                println!("No license activated ({path} does not exist).");
+                println!("Run `largo license activate <key>` to verify offline.");
+                return Ok(());
+            };
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default();
+            println!("License:  {}", describe(&claims));
+            println!("Key:      {}", claims.key);
+            match claims.standing(now) {
+                LicenseStanding::Active => {
+                    println!("Status:   active until {}", format_date(claims.expires_at))
+                }
+                LicenseStanding::Grace { ends_at } => println!(
+                    "Status:   expired {}; offline grace period ends {}",
+                    format_date(claims.expires_at),
+                    format_date(ends_at)
+                ),
+                LicenseStanding::Expired { ended_at } => println!(
+                    "Status:   expired {}; grace period ended {}",
+                    format_date(claims.expires_at),
+                    format_date(ended_at)
+                ),
+            }
+            match &claims.machine_id {
+                Some(_) if claims.max_machines > 0 => println!(
+                    "Machine:  this machine ({} of {} activations)",
+                    claims.active_machines, claims.max_machines
+                ),
+                Some(_) => println!("Machine:  this machine"),
+                None => println!("Machine:  any (floating license)"),
+            }
+            println!("File:     {path}");
+        }
+        LicenseAction::Deactivate { force } => match validator.deactivate(force)? {
+            Some(claims) => ui::phase("Deactivated", format!("{} on this machine", describe(&claims))),
+            None => println!("No license activated on this machine."),
+        },
+    }
+    Ok(())
+}
+
+/// Stub for builds without the `verification` feature: explain how to get it.
+#[cfg(not(feature = "verification"))]
+pub(crate) fn cmd_license(_action: LicenseAction) -> Result<(), Box<dyn std::error::Error>> {
+    Err(crate::commands::verify::verification_unavailable().into())
+}
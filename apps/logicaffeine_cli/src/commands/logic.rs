@@ -1,19 +1,24 @@
 //! `largo logic` — English → First-Order Logic from the terminal.
 //!
 //! The headline LOGOS feature as a command: compile an English sentence to
-//! FOL in any output format, list every ambiguous reading, or run a whole
-//! discourse with shared anaphora context. Output is bare FOL on stdout —
-//! script-friendly by design.
+//! FOL in any output format, list every ambiguous reading, run a whole
+//! discourse with shared anaphora context, or draw the syntax tree as a
+//! DOT or Mermaid diagram. Output is bare text on stdout — script-friendly
+//! by design.
 
 use std::io::Read;
 use std::path::PathBuf;
 
+use logicaffeine_compile::diagram::{ast_to_dot, ast_to_mermaid};
 use logicaffeine_language::{compile::*, CompileOptions, OutputFormat};
 
+use crate::commands::graph::DiagramFormat;
 use crate::ui::{self, CliError};
 
-/// The `--format` choices, mapped onto [`OutputFormat`].
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+/// The `--format` choices, mapped onto [`OutputFormat`]: the built-in
+/// notations, or any formatter registered with
+/// [`register_formatter`](logicaffeine_language::formatter::register_formatter).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum LogicFormat {
     /// Unicode logic symbols: ∀, ∃, ∧, ∨, ¬, →.
     #[default]
@@ -24,6 +29,23 @@ pub enum LogicFormat {
     Ascii,
     /// Kripke semantics: modals lowered to explicit world quantification.
     Kripke,
+    /// A registered custom formatter, by name.
+    Custom(&'static str),
+}
+
+impl std::str::FromStr for LogicFormat {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match OutputFormat::from_name(name) {
+            Some(OutputFormat::Unicode) => Ok(LogicFormat::Unicode),
+            Some(OutputFormat::LaTeX) => Ok(LogicFormat::Latex),
+            Some(OutputFormat::SimpleFOL) => Ok(LogicFormat::Ascii),
+            Some(OutputFormat::Kripke) => Ok(LogicFormat::Kripke),
+            Some(OutputFormat::Custom(name)) => Ok(LogicFormat::Custom(name)),
+            None => Err(format!("unknown format `{name}` ({})", OutputFormat::names().join(" | "))),
+        }
+    }
 }
 
 impl From<LogicFormat> for OutputFormat {
@@ -33,12 +55,13 @@ impl From<LogicFormat> for OutputFormat {
             LogicFormat::Latex => OutputFormat::LaTeX,
             LogicFormat::Ascii => OutputFormat::SimpleFOL,
             LogicFormat::Kripke => OutputFormat::Kripke,
+            LogicFormat::Custom(name) => OutputFormat::Custom(name),
         }
     }
 }
 
 /// Handle `largo logic [SENTENCE] [--file F] [--format …] [--all-readings]
-/// [--pragmatic] [--discourse]`.
+/// [--pragmatic] [--discourse] [--tree dot|mermaid]`.
 pub(crate) fn cmd_logic(
     sentence: Option<String>,
     file: Option<PathBuf>,
@@ -46,9 +69,29 @@ pub(crate) fn cmd_logic(
     all_readings: bool,
     pragmatic: bool,
     discourse: bool,
+    tree: Option<DiagramFormat>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let input = read_input(sentence, file)?;
-    let options = CompileOptions { format: format.into(), pragmatic };
+
+    if let Some(tree) = tree {
+        let result = logicaffeine_compile::compile_for_ui(&input);
+        let ast = match (result.ast, result.error) {
+            (Some(ast), _) => ast,
+            (None, error) => {
+                return Err(CliError::new(format!(
+                    "could not parse the sentence\n{}",
+                    error.unwrap_or_else(|| "no syntax tree was produced".to_string())
+                ))
+                .into())
+            }
+        };
+        match tree {
+            DiagramFormat::Dot => print!("{}", ast_to_dot(&ast)),
+            DiagramFormat::Mermaid => print!("{}", ast_to_mermaid(&ast)),
+        }
+        return Ok(());
+    }
+    let options = CompileOptions { format: format.into(), pragmatic, ..Default::default() };
 
     if discourse {
         let sentences: Vec<&str> = input
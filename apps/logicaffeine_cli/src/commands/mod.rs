@@ -4,23 +4,35 @@
 //! parses arguments and dispatches into these modules; each handler owns the
 //! full behavior of its command and returns `Result<(), Box<dyn Error>>`.
 
+pub(crate) mod bisect;
 pub(crate) mod build;
 pub(crate) mod check;
 pub(crate) mod clean;
 pub(crate) mod completions;
+pub(crate) mod daemon;
 pub(crate) mod deps;
+pub(crate) mod dev;
+pub(crate) mod differential;
 pub(crate) mod doc;
 pub(crate) mod doctor;
 pub(crate) mod emit;
+pub(crate) mod export;
 pub(crate) mod fmt;
+pub(crate) mod graph;
+pub(crate) mod license;
 pub(crate) mod logic;
+pub(crate) mod mutate;
 pub(crate) mod new;
 pub(crate) mod opts;
+pub(crate) mod owner;
 pub(crate) mod prove;
 pub(crate) mod publish;
 pub(crate) mod run;
 pub(crate) mod sat;
+pub(crate) mod test;
 pub(crate) mod verify;
+pub(crate) mod watch;
+pub(crate) mod yank;
 
 use crate::ui::CliError;
 
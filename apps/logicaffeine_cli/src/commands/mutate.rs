@@ -0,0 +1,348 @@
+//! `largo mutate` — measure how much the tests and contracts actually pin down.
+//!
+//! Each mutant is the project entry with one small, systematic change: a
+//! comparison flipped at its boundary (`<` → `<=`, `is greater than` →
+//! `is at least`, `==` → `!=`), an integer constant moved by one, or `and`
+//! swapped with `or` inside a `Requires`/`Ensures`/`Assert` spec. The
+//! mutant is then run the way the project is checked: `## Main` through the
+//! interpreter (contracts are checked as it runs) and every doc test of
+//! `largo test`. A mutant is *caught* when anything observable changes — an
+//! output line, a contract violation, a failing example, or a run that no
+//! longer finishes in time. A mutant nothing notices has *survived*: the
+//! specs and examples would accept that wrong program too.
+//!
+//! Like `largo doc`, the mutator is a line scan over the source: only code
+//! lines inside `## Main` and `## To …` blocks are touched, never prose,
+//! string literals or fenced examples. A mutant that no longer parses is
+//! *unviable* and does not count toward the score.
+
+use std::path::Path;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use crate::commands::require_project_root;
+use crate::commands::test::{project_doc_tests, run_doc_test};
+use crate::project::manifest::Manifest;
+use crate::ui::{self, CliError};
+
+/// One candidate mutation: replace `source[start..end]` with `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Mutant {
+    line: usize,
+    start: usize,
+    end: usize,
+    replacement: String,
+    /// `` `<` → `<=` `` — what the report shows.
+    description: String,
+}
+
+impl Mutant {
+    fn apply(&self, source: &str) -> String {
+        format!("{}{}{}", &source[..self.start], self.replacement, &source[self.end..])
+    }
+}
+
+/// What running a program (original or mutant) let us observe.
+#[derive(Debug, PartialEq, Eq)]
+struct Observation {
+    /// `## Main`'s printed lines, or the error that stopped it.
+    main: Result<Vec<String>, String>,
+    /// The names of the doc tests that failed.
+    failing_examples: Vec<String>,
+}
+
+/// The fate of one mutant.
+#[derive(Debug, PartialEq, Eq)]
+enum Verdict {
+    /// Something observable changed; the reason says what.
+    Caught(String),
+    /// Nothing noticed the change.
+    Survived,
+}
+
+/// Boundary flips of the symbolic comparison operators.
+const OPERATOR_FLIPS: &[(&str, &str)] =
+    &[("<", "<="), ("<=", "<"), (">", ">="), (">=", ">"), ("==", "!="), ("!=", "==")];
+
+/// Boundary flips of the worded comparisons.
+const PHRASE_FLIPS: &[((&str, &str), &str)] = &[
+    (("less", "than"), "at most"),
+    (("at", "most"), "less than"),
+    (("greater", "than"), "at least"),
+    (("at", "least"), "greater than"),
+];
+
+/// Lines that state a contract: `and`/`or` are swapped only here.
+const SPEC_KEYWORDS: &[&str] = &["Requires", "Ensures", "Assert"];
+
+/// Every mutant of `source`, in source order.
+fn mutants(source: &str) -> Vec<Mutant> {
+    let mut out = Vec::new();
+    let mut offset = 0;
+    let mut in_code_block = false;
+    let mut in_fence = false;
+    for (index, line) in source.split_inclusive('\n').enumerate() {
+        let line_start = offset;
+        offset += line.len();
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix("## ") {
+            in_code_block = matches!(header.split_whitespace().next(), Some("Main" | "To"));
+            continue;
+        }
+        if !in_code_block {
+            continue;
+        }
+
+        let words = code_words(line);
+        let is_spec = SPEC_KEYWORDS.iter().any(|k| line.trim_start().starts_with(k));
+        let mut push = |start: usize, end: usize, replacement: String| {
+            let description = format!("`{}` → `{replacement}`", &line[start..end]);
+            out.push(Mutant {
+                line: index + 1,
+                start: line_start + start,
+                end: line_start + end,
+                replacement,
+                description,
+            });
+        };
+        for (i, &(start, end)) in words.iter().enumerate() {
+            let word = &line[start..end];
+            if let Some(&(_, flipped)) = OPERATOR_FLIPS.iter().find(|(op, _)| *op == word) {
+                push(start, end, flipped.to_string());
+            }
+            if let Some(&(next_start, next_end)) = words.get(i + 1) {
+                let pair = (word.to_lowercase(), line[next_start..next_end].to_lowercase());
+                if let Some(&(_, flipped)) =
+                    PHRASE_FLIPS.iter().find(|((x, y), _)| pair.0 == *x && pair.1 == *y)
+                {
+                    push(start, next_end, flipped.to_string());
+                }
+            }
+            if let Ok(n) = word.parse::<i64>() {
+                push(start, end, (n + 1).to_string());
+                push(start, end, (n - 1).to_string());
+            }
+            if is_spec && (word == "and" || word == "or") {
+                push(start, end, if word == "and" { "or" } else { "and" }.to_string());
+            }
+        }
+    }
+    out
+}
+
+/// The byte ranges of a code line's words. Brackets, commas and semicolons
+/// separate words like whitespace does, a sentence-final `.` or `:` is
+/// trimmed, and string literals are skipped.
+fn code_words(line: &str) -> Vec<(usize, usize)> {
+    const SEPARATORS: &[char] = &['(', ')', '[', ']', ',', ';'];
+    let mut words = Vec::new();
+    let mut in_string = false;
+    let mut word_start = None;
+    for (i, c) in line.char_indices().chain(std::iter::once((line.len(), ' '))) {
+        if c == '"' {
+            in_string = !in_string;
+            word_start = None;
+            continue;
+        }
+        if in_string {
+            continue;
+        }
+        match (c.is_whitespace() || SEPARATORS.contains(&c), word_start) {
+            (false, None) => word_start = Some(i),
+            (true, Some(start)) => {
+                word_start = None;
+                let word = line[start..i].trim_end_matches(['.', ':']);
+                if !word.is_empty() {
+                    words.push((start, start + word.len()));
+                }
+            }
+            _ => {}
+        }
+    }
+    words
+}
+
+/// Run `## Main` of `entry_source` and the project's doc tests, with the
+/// entry file read from `entry_source` instead of disk.
+fn observe(root: &Path, entry: &Path, entry_source: &str) -> Result<Observation, CliError> {
+    let main = futures::executor::block_on(logicaffeine_compile::interpret_for_ui(entry_source));
+    let main = match main.error {
+        Some(err) => Err(err),
+        None => Ok(main.lines),
+    };
+    let examples = project_doc_tests(root, |p| {
+        if p == entry {
+            Ok(entry_source.to_string())
+        } else {
+            std::fs::read_to_string(p)
+        }
+    })?;
+    let failing_examples =
+        examples.iter().filter(|t| run_doc_test(t).is_err()).map(|t| t.name.clone()).collect();
+    Ok(Observation { main, failing_examples })
+}
+
+/// Judge one mutant against the original program's observation.
+fn judge(baseline: &Observation, mutant: &Observation) -> Verdict {
+    if let Some(name) = mutant.failing_examples.first() {
+        return Verdict::Caught(format!("example {name} failed"));
+    }
+    match &mutant.main {
+        Err(err) => Verdict::Caught(format!("Main failed: {}", err.lines().next().unwrap_or(""))),
+        Ok(lines) if baseline.main.as_ref().ok() != Some(lines) => {
+            Verdict::Caught("Main printed different output".to_string())
+        }
+        Ok(_) => Verdict::Survived,
+    }
+}
+
+/// Handle `largo mutate [--timeout SECS]`.
+pub(crate) fn cmd_mutate(timeout: u64) -> Result<(), Box<dyn std::error::Error>> {
+    let root = require_project_root()?;
+    let manifest = Manifest::load(&root)?;
+    let entry = crate::commands::resolve_entry_path(&root, &manifest)?;
+    let source = std::fs::read_to_string(&entry)
+        .map_err(|e| CliError::new(format!("cannot read {}: {e}", entry.display())))?;
+    let rel = entry.strip_prefix(&root).unwrap_or(&entry).display().to_string();
+
+    let baseline = observe(&root, &entry, &source)?;
+    if let Err(err) = &baseline.main {
+        return Err(CliError::with_hint(
+            format!("the unmutated program fails, so its mutants prove nothing:\n{err}"),
+            "run `largo run --interpret` and fix the program first",
+        )
+        .into());
+    }
+    if let Some(name) = baseline.failing_examples.first() {
+        return Err(CliError::with_hint(
+            format!("the unmutated program fails its example {name}"),
+            "run `largo test` and fix the examples first",
+        )
+        .into());
+    }
+
+    let candidates = mutants(&source);
+    ui::phase("Mutating", format!("{rel}: {} mutants", candidates.len()));
+    let (mut caught, mut missed, mut unviable) = (0usize, Vec::new(), 0usize);
+    for mutant in &candidates {
+        let mutated = mutant.apply(&source);
+        let label = format!("{rel}:{} {}", mutant.line, mutant.description);
+        if logicaffeine_compile::compile::compile_to_rust(&mutated).is_err() {
+            unviable += 1;
+            if ui::verbosity() > 0 {
+                ui::info(format!("unviable {label}"));
+            }
+            continue;
+        }
+        // A mutant may never finish (`Set i to i + 0.` in a loop): run it on
+        // its own thread and count a run that outlives the timeout as caught.
+        // The abandoned thread ends with the process.
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn({
+            let (root, entry) = (root.clone(), entry.clone());
+            move || {
+                let _ = tx.send(observe(&root, &entry, &mutated));
+            }
+        });
+        let verdict = match rx.recv_timeout(Duration::from_secs(timeout)) {
+            Ok(observation) => judge(&baseline, &observation?),
+            Err(_) => Verdict::Caught(format!("no longer finishes within {timeout}s")),
+        };
+        match verdict {
+            Verdict::Caught(reason) => {
+                caught += 1;
+                ui::info(format!("caught   {label} ({reason})"));
+            }
+            Verdict::Survived => {
+                ui::info(format!("MISSED   {label}"));
+                missed.push(label);
+            }
+        }
+    }
+
+    let viable = caught + missed.len();
+    let score = if viable == 0 { 100 } else { caught * 100 / viable };
+    let summary = format!(
+        "mutation score: {score}% — {caught} caught, {} survived, {unviable} unviable",
+        missed.len()
+    );
+    if missed.is_empty() {
+        ui::info(format!("\n{summary}"));
+        return Ok(());
+    }
+    Err(CliError::with_hint(
+        summary,
+        "each survivor is a wrong program your examples and contracts accept: add an example that tells it apart, or tighten the contract",
+    )
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROGRAM: &str = "# Clamp\n\nKeeps a value under 10.\n\n\
+## To clamp (n: Int) -> Int:\n    Requires n is at least 0 and n is at most 100.\n    If n > 10:\n        Return 10.\n    Return n.\n\n\
+## Main\n    Show \"limit 10 < 11\".\n    Show clamp(12).\n";
+
+    fn descriptions(source: &str) -> Vec<(usize, String)> {
+        mutants(source).into_iter().map(|m| (m.line, m.description)).collect()
+    }
+
+    #[test]
+    fn code_lines_yield_boundary_constant_and_spec_mutants() {
+        let found = descriptions(PROGRAM);
+        assert_eq!(
+            found,
+            vec![
+                (6, "`at least` → `greater than`".to_string()),
+                (6, "`0` → `1`".to_string()),
+                (6, "`0` → `-1`".to_string()),
+                (6, "`and` → `or`".to_string()),
+                (6, "`at most` → `less than`".to_string()),
+                (6, "`100` → `101`".to_string()),
+                (6, "`100` → `99`".to_string()),
+                (7, "`>` → `>=`".to_string()),
+                (7, "`10` → `11`".to_string()),
+                (7, "`10` → `9`".to_string()),
+                (8, "`10` → `11`".to_string()),
+                (8, "`10` → `9`".to_string()),
+                (13, "`12` → `13`".to_string()),
+                (13, "`12` → `11`".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn applying_a_mutant_edits_exactly_its_span() {
+        let flip = mutants(PROGRAM).into_iter().find(|m| m.description == "`>` → `>=`").unwrap();
+        let mutated = flip.apply(PROGRAM);
+        assert!(mutated.contains("    If n >= 10:\n"), "{mutated}");
+        assert_eq!(mutated.len(), PROGRAM.len() + 1);
+    }
+
+    #[test]
+    fn prose_strings_and_fences_are_never_mutated() {
+        let src = "## Note\n\nUp to 10 < 20.\n\n## Main\n```logos\nShow 3.\n```\n    Show \"1 < 2\".\n";
+        assert!(mutants(src).is_empty(), "{:?}", mutants(src));
+    }
+
+    #[test]
+    fn only_observable_changes_catch_a_mutant() {
+        let baseline = Observation { main: Ok(vec!["10".into()]), failing_examples: vec![] };
+        let same = Observation { main: Ok(vec!["10".into()]), failing_examples: vec![] };
+        assert_eq!(judge(&baseline, &same), Verdict::Survived);
+        let changed = Observation { main: Ok(vec!["9".into()]), failing_examples: vec![] };
+        assert!(matches!(judge(&baseline, &changed), Verdict::Caught(_)));
+        let violated = Observation { main: Err("Requires failed".into()), failing_examples: vec![] };
+        assert!(matches!(judge(&baseline, &violated), Verdict::Caught(_)));
+        let example = Observation { main: Ok(vec!["10".into()]), failing_examples: vec!["a.md:3".into()] };
+        assert_eq!(judge(&baseline, &example), Verdict::Caught("example a.md:3 failed".into()));
+    }
+}
@@ -2,10 +2,11 @@
 
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::project::manifest::Manifest;
-use crate::ui::CliError;
+use crate::project::templates::{self, Template, TemplateError};
+use crate::ui::{self, CliError};
 
 /// Validate a project/package name: ASCII alphanumerics, `-` and `_`,
 /// starting with a letter. Everything else breaks a downstream layer —
@@ -25,15 +26,73 @@ fn validate_project_name(name: &str) -> Result<(), CliError> {
     }
 }
 
-/// Handle `largo new <name>`: scaffold a fresh project directory.
-pub(crate) fn cmd_new(name: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// Resolve `--template`, turning an unknown name into an error that lists
+/// the ones that exist.
+fn find_template(name: &str) -> Result<Template, CliError> {
+    Template::find(name).map_err(|e| match e {
+        TemplateError::Unknown(_) => {
+            let names: Vec<String> = templates::available().into_iter().map(|(n, _)| n).collect();
+            CliError::with_hint(e.to_string(), format!("available templates: {}", names.join(", ")))
+        }
+        TemplateError::Io(_) => CliError::new(e.to_string()),
+    })
+}
+
+/// Write `template`'s files into `dir`, then the standard `Largo.toml` and
+/// `.gitignore` wherever the template brings none. Files already present
+/// are left alone, so `largo init` never clobbers existing work.
+fn write_template(dir: &Path, name: &str, template: &Template) -> Result<(), Box<dyn std::error::Error>> {
+    let mut files = template.render(name);
+    if !template.provides("Largo.toml") {
+        files.push((PathBuf::from("Largo.toml"), Manifest::new(name).to_toml()?));
+    }
+    if !template.provides(".gitignore") {
+        files.push((PathBuf::from(".gitignore"), "/target\n".to_string()));
+    }
+    for (relative, contents) in files {
+        let path = dir.join(relative);
+        if path.exists() {
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+    }
+    Ok(())
+}
+
+/// Handle `largo new --list-templates`.
+pub(crate) fn cmd_list_templates() -> Result<(), Box<dyn std::error::Error>> {
+    for (name, local) in templates::available() {
+        let description = templates::BUILTIN
+            .iter()
+            .find(|t| t.name == name && !local)
+            .map_or("local template", |t| t.description);
+        ui::info(format!("{name:<20} {description}"));
+    }
+    Ok(())
+}
+
+/// Handle `largo new <name> [--template T]`: scaffold a fresh project directory.
+pub(crate) fn cmd_new(name: &str, template: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
     validate_project_name(name)?;
+    let template = template.map(find_template).transpose()?;
     let project_dir = PathBuf::from(name);
 
     if project_dir.exists() {
         return Err(format!("Directory '{}' already exists", project_dir.display()).into());
     }
 
+    if let Some(template) = template {
+        fs::create_dir_all(&project_dir)?;
+        write_template(&project_dir, name, &template)?;
+        println!("Created LOGOS project '{}' from the '{}' template", name, template.name);
+        println!("  cd {}", project_dir.display());
+        println!("  largo run");
+        return Ok(());
+    }
+
     // Create project structure
     fs::create_dir_all(&project_dir)?;
     fs::create_dir_all(project_dir.join("src"))?;
@@ -63,8 +122,8 @@ Show "Hello, world!".
     Ok(())
 }
 
-/// Handle `largo init [--name]`: scaffold in the current directory.
-pub(crate) fn cmd_init(name: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
+/// Handle `largo init [--name] [--template T]`: scaffold in the current directory.
+pub(crate) fn cmd_init(name: Option<&str>, template: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
     let current_dir = env::current_dir()?;
     let project_name = name
         .map(String::from)
@@ -81,6 +140,13 @@ pub(crate) fn cmd_init(name: Option<&str>) -> Result<(), Box<dyn std::error::Err
         return Err("Largo.toml already exists".into());
     }
 
+    if let Some(template) = template {
+        let template = find_template(template)?;
+        write_template(&current_dir, &project_name, &template)?;
+        println!("Initialized LOGOS project '{}' from the '{}' template", project_name, template.name);
+        return Ok(());
+    }
+
     // Create src directory if needed
     fs::create_dir_all(current_dir.join("src"))?;
 
@@ -0,0 +1,133 @@
+//! `largo owner` — manage who may publish and yank a package.
+//!
+//! Owners are registry logins (`alice`) or teams (`team:core`). A team owner
+//! lets every token issued to that team act on the package, which is how
+//! organizations avoid tying a package to one person's account.
+
+use crate::commands::publish::{explain_registry_error, require_token};
+use crate::commands::require_project_root;
+use crate::project::manifest::Manifest;
+use crate::project::registry::RegistryClient;
+use crate::ui::{self, CliError};
+
+/// What `largo owner` does.
+#[derive(Debug, clap::Subcommand)]
+pub enum OwnerAction {
+    /// List the package's owners.
+    List {
+        #[command(flatten)]
+        target: OwnerTarget,
+    },
+    /// Invite users or teams (`team:<name>`) as owners.
+    Add {
+        /// Registry logins, or `team:<name>` for teams.
+        #[arg(required = true)]
+        owners: Vec<String>,
+        #[command(flatten)]
+        target: OwnerTarget,
+    },
+    /// Remove users or teams from the owners. The last owner cannot be removed.
+    Remove {
+        /// Registry logins, or `team:<name>` for teams.
+        #[arg(required = true)]
+        owners: Vec<String>,
+        #[command(flatten)]
+        target: OwnerTarget,
+    },
+    /// Hand primary ownership to another user or team.
+    ///
+    /// The current primary owner stays on as a maintainer; remove them
+    /// afterwards to complete the handover.
+    Transfer {
+        /// The new primary owner: a registry login or `team:<name>`.
+        owner: String,
+        #[command(flatten)]
+        target: OwnerTarget,
+    },
+}
+
+/// The package and credentials an owner command acts with.
+#[derive(Debug, clap::Args)]
+pub struct OwnerTarget {
+    /// Package to manage. Defaults to the current project's package.
+    #[arg(long, short)]
+    package: Option<String>,
+
+    /// Registry URL. Defaults to `registry.logicaffeine.com`.
+    #[arg(long)]
+    registry: Option<String>,
+
+    /// Act with the token stored for this team (see `largo login --team`).
+    #[arg(long)]
+    team: Option<String>,
+}
+
+/// Handle `largo owner <list|add|remove|transfer>`.
+pub(crate) fn cmd_owner(action: OwnerAction) -> Result<(), Box<dyn std::error::Error>> {
+    let target = match &action {
+        OwnerAction::List { target }
+        | OwnerAction::Add { target, .. }
+        | OwnerAction::Remove { target, .. }
+        | OwnerAction::Transfer { target, .. } => target,
+    };
+    match &action {
+        OwnerAction::List { .. } => {}
+        OwnerAction::Add { owners, .. } | OwnerAction::Remove { owners, .. } => {
+            validate_owners(owners)?
+        }
+        OwnerAction::Transfer { owner, .. } => validate_owners(std::slice::from_ref(owner))?,
+    }
+    let package = match &target.package {
+        Some(name) => name.clone(),
+        None => Manifest::load(&require_project_root()?)?.package.name,
+    };
+    let registry_url = target.registry.as_deref().unwrap_or(RegistryClient::default_url());
+    let team = target.team.as_deref();
+    let client = RegistryClient::new(registry_url, &require_token(registry_url, team)?);
+    let explain = |e| explain_registry_error(e, &package, team);
+
+    match &action {
+        OwnerAction::List { .. } => {
+            let owners = client.list_owners(&package).map_err(explain)?;
+            for owner in owners {
+                match &owner.role {
+                    Some(role) => println!("{owner} ({role})"),
+                    None => println!("{owner}"),
+                }
+            }
+        }
+        OwnerAction::Add { owners, .. } => {
+            client.add_owners(&package, owners).map_err(explain)?;
+            ui::phase("Added", format!("{} as owners of {package}", owners.join(", ")));
+        }
+        OwnerAction::Remove { owners, .. } => {
+            client.remove_owners(&package, owners).map_err(explain)?;
+            ui::phase("Removed", format!("{} from the owners of {package}", owners.join(", ")));
+        }
+        OwnerAction::Transfer { owner, .. } => {
+            client.transfer_ownership(&package, owner).map_err(explain)?;
+            ui::phase("Transferred", format!("{package} to {owner}"));
+            ui::info(format!(
+                "the previous owner remains a maintainer; `largo owner remove <login> --package {package}` completes the handover"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Reject owner names the registry could never match before sending them.
+fn validate_owners(owners: &[String]) -> Result<(), CliError> {
+    for owner in owners {
+        let login = owner.strip_prefix("team:").unwrap_or(owner);
+        let valid = !login.is_empty()
+            && login.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(CliError::with_hint(
+                format!("invalid owner '{owner}'"),
+                "owners are registry logins (`alice`) or teams (`team:core`)",
+            )
+            .exit_code(ui::EXIT_USAGE));
+        }
+    }
+    Ok(())
+}
@@ -177,7 +177,7 @@ fn is_theorem_header(line: &str) -> bool {
 
 /// Split out each `## Theorem` block: from its header line to the next
 /// `## ` header (exclusive).
-fn split_theorem_blocks(source: &str) -> Vec<String> {
+pub(crate) fn split_theorem_blocks(source: &str) -> Vec<String> {
     let mut blocks: Vec<String> = Vec::new();
     let mut current: Option<String> = None;
     for line in source.lines() {
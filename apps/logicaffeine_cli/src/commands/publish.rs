@@ -4,15 +4,86 @@ use std::fs;
 use std::io::{self, Write};
 
 use crate::commands::require_project_root;
-use crate::project::credentials::{get_token, Credentials};
+use crate::project::credentials::{get_team_token, get_token, Credentials};
 use crate::project::manifest::Manifest;
 use crate::project::registry::{
-    create_tarball, is_git_dirty, PublishMetadata, RegistryClient,
+    create_tarball, is_git_dirty, PublishMetadata, RegistryClient, RegistryError,
 };
+use crate::ui::CliError;
+
+/// The token for `registry_url` — the team's when `team` is given — or an
+/// error saying how to log in.
+pub(crate) fn require_token(registry_url: &str, team: Option<&str>) -> Result<String, CliError> {
+    match team {
+        None => get_token(registry_url).ok_or_else(|| {
+            CliError::with_hint(
+                format!("no authentication token found for {registry_url}"),
+                "run `largo login` or set the LOGOS_TOKEN environment variable",
+            )
+        }),
+        Some(team) => get_team_token(registry_url, team).ok_or_else(|| {
+            let known = Credentials::load().unwrap_or_default();
+            let known = known.team_names(registry_url);
+            let hint = if known.is_empty() {
+                format!("run `largo login --team {team}` with a token issued to the team")
+            } else {
+                format!(
+                    "run `largo login --team {team}`; stored team tokens: {}",
+                    known.join(", ")
+                )
+            };
+            CliError::with_hint(format!("no token for team '{team}' on {registry_url}"), hint)
+        }),
+    }
+}
+
+/// Turn a failed registry request about `package` into an error that says
+/// what to do next.
+pub(crate) fn explain_registry_error(
+    e: RegistryError,
+    package: &str,
+    team: Option<&str>,
+) -> CliError {
+    match e {
+        RegistryError::Unauthorized => CliError::with_hint(
+            "the registry rejected the token (invalid, expired or revoked)",
+            match team {
+                Some(team) => format!("run `largo login --team {team}` with a fresh team token"),
+                None => "run `largo login` to store a fresh token".to_string(),
+            },
+        ),
+        RegistryError::Forbidden(message) => CliError::with_hint(
+            format!("access to '{package}' denied: {message}"),
+            match team {
+                Some(team) => format!(
+                    "team '{team}' must own '{package}'; an owner can add it with \
+                     `largo owner add team:{team} --package {package}`"
+                ),
+                None => format!(
+                    "ask an owner (see `largo owner list --package {package}`) to run \
+                     `largo owner add <your-login> --package {package}`, \
+                     or pass --team for a team that owns it"
+                ),
+            },
+        ),
+        RegistryError::NotFound(what) => CliError::with_hint(
+            format!("'{what}' was not found on the registry"),
+            "check the package name and version, and --registry if it lives elsewhere",
+        ),
+        RegistryError::Network(message) => CliError::with_hint(
+            format!("could not reach the registry: {message}"),
+            "check your connection; `largo doctor` tests registry reachability",
+        ),
+        e @ RegistryError::RateLimited { .. } => CliError::new(e.to_string()),
+        RegistryError::Server { message, .. } if !message.is_empty() => CliError::new(message),
+        e => CliError::new(e.to_string()),
+    }
+}
 
 /// Handle `largo publish`: tarball the project and upload it to the registry.
 pub(crate) fn cmd_publish(
     registry: Option<&str>,
+    team: Option<&str>,
     dry_run: bool,
     allow_dirty: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -29,13 +100,7 @@ pub(crate) fn cmd_publish(
     let registry_url = registry.unwrap_or(RegistryClient::default_url());
 
     // Get authentication token
-    let token = get_token(registry_url).ok_or_else(|| {
-        format!(
-            "No authentication token found for {}.\n\
-             Run 'largo login' or set LOGOS_TOKEN environment variable.",
-            registry_url
-        )
-    })?;
+    let token = require_token(registry_url, team)?;
 
     // Verify the package
     let entry_path = project_root.join(&manifest.package.entry);
@@ -106,8 +171,12 @@ pub(crate) fn cmd_publish(
 }
 
 /// Handle `largo login`: validate a token and store it in the credentials file.
+///
+/// With `--team`, the token is stored as that team's rather than the
+/// user's own.
 pub(crate) fn cmd_login(
     registry: Option<&str>,
+    team: Option<&str>,
     token: Option<String>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let registry_url = registry.unwrap_or(RegistryClient::default_url());
@@ -139,20 +208,43 @@ pub(crate) fn cmd_login(
 
     // Save to credentials file
     let mut creds = Credentials::load().unwrap_or_default();
-    creds.set_token(registry_url, &token);
+    match team {
+        Some(team) => creds.set_team_token(registry_url, team, &token),
+        None => creds.set_token(registry_url, &token),
+    }
     creds.save()?;
 
-    println!("Logged in as {} to {}", user_info.login, registry_url);
+    match team {
+        Some(team) => println!(
+            "Logged in as {} for team {} to {}",
+            user_info.login, team, registry_url
+        ),
+        None => println!("Logged in as {} to {}", user_info.login, registry_url),
+    }
 
     Ok(())
 }
 
 /// Handle `largo logout`: remove the stored token for a registry.
-pub(crate) fn cmd_logout(registry: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
+pub(crate) fn cmd_logout(
+    registry: Option<&str>,
+    team: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
     let registry_url = registry.unwrap_or(RegistryClient::default_url());
 
     let mut creds = Credentials::load().unwrap_or_default();
 
+    if let Some(team) = team {
+        if creds.get_team_token(registry_url, team).is_none() {
+            println!("No token for team {} on {}", team, registry_url);
+            return Ok(());
+        }
+        creds.remove_team_token(registry_url, team);
+        creds.save()?;
+        println!("Removed the token for team {} from {}", team, registry_url);
+        return Ok(());
+    }
+
     if creds.get_token(registry_url).is_none() {
         println!("Not logged in to {}", registry_url);
         return Ok(());
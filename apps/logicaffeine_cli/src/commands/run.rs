@@ -9,7 +9,10 @@ use crate::project::build::{self, BuildConfig};
 use crate::project::manifest::Manifest;
 
 /// Handle `largo run` (default path): build with cargo, then execute.
-pub(crate) fn cmd_run(release: bool, args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+///
+/// With `profile`, the build is instrumented and the program prints a flat
+/// profile and call tree of its LOGOS functions to stderr on exit.
+pub(crate) fn cmd_run(release: bool, profile: bool, args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
     let project_root = require_project_root()?;
 
     let config = BuildConfig {
@@ -17,6 +20,8 @@ pub(crate) fn cmd_run(release: bool, args: &[String]) -> Result<(), Box<dyn std:
         release,
         lib_mode: false,
         target: None,
+        profile,
+        features: Vec::new(),
     };
 
     let result = build::build(config).map_err(friendly_build_error)?;
@@ -82,7 +87,7 @@ pub(crate) fn cmd_run_interpret(args: &[String]) -> Result<(), Box<dyn std::erro
 /// shim (node), passing `args` to the program's `args()`. Compile-and-run with no Rust toolchain.
 pub(crate) fn cmd_run_wasm(args: &[String], linked: bool) -> Result<(), Box<dyn std::error::Error>> {
     let project_root = require_project_root()?;
-    let (mjs, _) = build_wasm_module(&project_root, linked)?;
+    let (mjs, _) = build_wasm_module(&project_root, linked, &[])?;
     let status = std::process::Command::new("node")
         .arg(&mjs)
         .args(args)
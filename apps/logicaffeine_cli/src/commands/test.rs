@@ -0,0 +1,359 @@
+//! `largo test` — run the examples written into documentation prose.
+//!
+//! A fenced ```` ```logos ```` block in any `.lg` or `.md` source of the
+//! project is a doc test, in the spirit of rustdoc: it is executed through
+//! the interpreter, and when a `produces:` annotation follows it, the printed
+//! output must match. The annotation is either inline or a fenced block:
+//!
+//! ````text
+//! ```logos
+//! Show double(21).
+//! ```
+//! produces: 42
+//! ````
+//!
+//! A snippet with no `##` header runs as the body of `## Main`, after the
+//! `## To …` and `## A … has` definitions of the file it is written in — so
+//! examples can call the functions they document. A snippet that brings its
+//! own `## Main` runs exactly as written. `logos,ignore` skips a block.
+//!
+//! With `--coverage`, every example runs with statement counters on (see
+//! `logicaffeine_compile::coverage`) and the counts of the definitions it ran
+//! are folded back onto the lines of the file they were written in: an
+//! annotated copy of each file and an `lcov.info` land in `target/coverage/`.
+//!
+//! With `--differential`, every example that passes also runs as a compiled
+//! binary and must print what the interpreter printed (see
+//! [`crate::commands::differential`]). With both flags the binary is
+//! instrumented too, and its counts are added to the interpreter's, so a line
+//! either tier ran counts as covered.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::commands::differential::CompiledBackend;
+use crate::commands::require_project_root;
+use crate::commands::watch::watched_files;
+use crate::ui::{self, CliError};
+
+use logicaffeine_compile::coverage::CoverageReport;
+
+/// One runnable example: where it was written, the program it runs, and the
+/// output its `produces:` annotation promises (if any).
+#[derive(Debug)]
+pub(crate) struct DocTest {
+    /// `src/main.lg:12` — the file (relative to the project) and fence line.
+    pub(crate) name: String,
+    /// The file the example was written in, relative to the project.
+    file: String,
+    program: String,
+    /// The file line of each leading program line (the copied definitions);
+    /// the example's own lines come after and map to nothing.
+    origins: Vec<usize>,
+    expected: Option<String>,
+}
+
+/// Extract every doc test from one source file, in order.
+fn extract_doc_tests(file: &str, source: &str) -> Vec<DocTest> {
+    let lines: Vec<&str> = source.lines().collect();
+    let (prelude, prelude_origins) = definitions(&lines);
+    let mut tests = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let Some(info) = fence_info(lines[i]) else {
+            i += 1;
+            continue;
+        };
+        let start = i + 1;
+        let (body, after) = fenced_body(&lines, start);
+        i = after;
+        let mut tags = info.split(',').map(str::trim);
+        if tags.next() != Some("logos") || tags.any(|t| t == "ignore") {
+            continue;
+        }
+
+        // `produces:` — inline, or a fenced block of its own.
+        let mut expected = None;
+        let mut next = i;
+        while next < lines.len() && lines[next].trim().is_empty() {
+            next += 1;
+        }
+        if let Some(rest) = lines.get(next).and_then(|l| l.trim().strip_prefix("produces:")) {
+            let rest = rest.trim();
+            if !rest.is_empty() {
+                expected = Some(rest.trim_matches('`').to_string());
+                i = next + 1;
+            } else if let Some(fence) = lines[next + 1..].iter().position(|l| !l.trim().is_empty()) {
+                let fence = next + 1 + fence;
+                if fence_info(lines[fence]).is_some() {
+                    let (output, after) = fenced_body(&lines, fence + 1);
+                    expected = Some(output);
+                    i = after;
+                }
+            }
+        }
+
+        let (program, origins) = if body.lines().any(|l| l.starts_with("## ")) {
+            (body, Vec::new())
+        } else {
+            (format!("{prelude}## Main\n{body}"), prelude_origins.clone())
+        };
+        tests.push(DocTest {
+            name: format!("{file}:{start}"),
+            file: file.to_string(),
+            program,
+            origins,
+            expected,
+        });
+    }
+    tests
+}
+
+/// The info string of an opening fence line (```` ```logos ```` → `logos`).
+fn fence_info(line: &str) -> Option<&str> {
+    line.trim_start().strip_prefix("```").map(str::trim)
+}
+
+/// The lines from `start` up to the closing fence, and the index just past it.
+fn fenced_body(lines: &[&str], start: usize) -> (String, usize) {
+    let mut body = String::new();
+    let mut i = start;
+    while i < lines.len() && fence_info(lines[i]).is_none() {
+        body.push_str(lines[i]);
+        body.push('\n');
+        i += 1;
+    }
+    (body, i + 1)
+}
+
+/// The file's `## To …` and `## A … has` blocks — headers and indented
+/// bodies, verbatim, outside fences; prose between them is left out. These
+/// are the definitions a snippet without its own headers can call. Returns
+/// the prelude and the file line each of its lines came from.
+fn definitions(lines: &[&str]) -> (String, Vec<usize>) {
+    let mut prelude = String::new();
+    let mut origins = Vec::new();
+    let mut in_fence = false;
+    let mut keep = false;
+    for (index, line) in lines.iter().enumerate() {
+        if fence_info(line).is_some() {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix("## ") {
+            keep = matches!(header.split_whitespace().next(), Some("To" | "A" | "An"));
+            if keep {
+                prelude.push_str(line);
+                prelude.push('\n');
+                origins.push(index + 1);
+            }
+        } else if keep && (line.trim().is_empty() || line.starts_with([' ', '\t'])) {
+            prelude.push_str(line);
+            prelude.push('\n');
+            origins.push(index + 1);
+        }
+    }
+    if !prelude.is_empty() {
+        prelude.push('\n');
+    }
+    (prelude, origins)
+}
+
+/// Every doc test in the project's `.lg` and `.md` sources, file by file in
+/// path order. `read` supplies each file's contents, so `largo mutate` can
+/// substitute a mutated source for the one on disk.
+pub(crate) fn project_doc_tests(
+    root: &Path,
+    mut read: impl FnMut(&Path) -> std::io::Result<String>,
+) -> Result<Vec<DocTest>, CliError> {
+    let mut files: Vec<PathBuf> = watched_files(root)
+        .into_iter()
+        .filter(|p| matches!(p.extension().and_then(|e| e.to_str()), Some("lg" | "md")))
+        .collect();
+    files.sort();
+
+    let mut tests = Vec::new();
+    for path in &files {
+        let source =
+            read(path).map_err(|e| CliError::new(format!("cannot read {}: {e}", path.display())))?;
+        let rel = path.strip_prefix(root).unwrap_or(path);
+        tests.extend(extract_doc_tests(&rel.display().to_string(), &source));
+    }
+    Ok(tests)
+}
+
+/// Run one doc test; `Err` carries the failure report.
+pub(crate) fn run_doc_test(test: &DocTest) -> Result<(), String> {
+    let result = futures::executor::block_on(logicaffeine_compile::interpret_for_ui(&test.program));
+    check_result(test, result)
+}
+
+/// [`run_doc_test`] with statement counters on: also returns the coverage of
+/// the definitions the example ran, on the lines of `test.file`.
+fn run_doc_test_with_coverage(test: &DocTest) -> (Result<(), String>, CoverageReport) {
+    let (result, run) = logicaffeine_compile::interpret_with_coverage(&test.program);
+    (check_result(test, result), on_file(test, &run))
+}
+
+/// A report on the lines of `test.program`, moved to the lines of the file
+/// its definitions were copied from.
+fn on_file(test: &DocTest, run: &CoverageReport) -> CoverageReport {
+    let mut coverage = CoverageReport::default();
+    for (&line, &count) in &run.lines {
+        if let Some(&origin) = test.origins.get(line - 1) {
+            coverage.record(origin, count);
+        }
+    }
+    coverage
+}
+
+/// Compare a finished run against the example's `produces:` annotation.
+fn check_result(test: &DocTest, result: logicaffeine_compile::InterpreterResult) -> Result<(), String> {
+    if let Some(err) = result.error {
+        return Err(err);
+    }
+    let Some(expected) = &test.expected else { return Ok(()) };
+    let actual = result.lines.join("\n");
+    let normalize = |s: &str| s.lines().map(str::trim_end).collect::<Vec<_>>().join("\n").trim().to_string();
+    if normalize(&actual) == normalize(expected) {
+        Ok(())
+    } else {
+        Err(format!("expected:\n{}\nprinted:\n{}", normalize(expected), normalize(&actual)))
+    }
+}
+
+/// Handle `largo test [FILTER] [--coverage] [--differential]`: run every doc
+/// test whose name contains `filter`, cargo-style, failing if any of them fails.
+pub(crate) fn cmd_test(
+    filter: Option<&str>,
+    coverage: bool,
+    differential: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let root = require_project_root()?;
+    let mut tests = project_doc_tests(&root, |p| std::fs::read_to_string(p))?;
+    tests.retain(|t| filter.map_or(true, |f| t.name.contains(f)));
+
+    let backend = if differential { Some(CompiledBackend::new(&root)?) } else { None };
+
+    ui::info(format!("running {} doc test{}", tests.len(), if tests.len() == 1 { "" } else { "s" }));
+    let mut failures = Vec::new();
+    let mut reports: BTreeMap<&str, CoverageReport> = BTreeMap::new();
+    for test in &tests {
+        let outcome = if coverage {
+            let (outcome, report) = run_doc_test_with_coverage(test);
+            reports.entry(&test.file).or_default().merge(&report);
+            outcome
+        } else {
+            run_doc_test(test)
+        };
+        let outcome = match &backend {
+            Some(backend) if coverage => outcome.and_then(|()| {
+                let (outcome, compiled) = backend.check_with_coverage(&test.name, &test.program);
+                reports.entry(&test.file).or_default().merge(&on_file(test, &compiled));
+                outcome
+            }),
+            Some(backend) => outcome.and_then(|()| backend.check(&test.name, &test.program)),
+            None => outcome,
+        };
+        match outcome {
+            Ok(()) => ui::info(format!("test {} ... ok", test.name)),
+            Err(report) => {
+                ui::info(format!("test {} ... FAILED", test.name));
+                failures.push((&test.name, report));
+            }
+        }
+    }
+    for (name, report) in &failures {
+        anstream::eprintln!("\n---- {name} ----\n{report}");
+    }
+
+    if coverage {
+        write_coverage(&root, &reports)?;
+    }
+
+    let passed = tests.len() - failures.len();
+    if failures.is_empty() {
+        ui::info(format!("\ntest result: ok. {passed} passed; 0 failed"));
+        return Ok(());
+    }
+    Err(CliError::with_hint(
+        format!("test result: FAILED. {passed} passed; {} failed", failures.len()),
+        "fix the example, or its `produces:` line, so the documentation stays true",
+    )
+    .into())
+}
+
+/// Print one summary line per file and write `target/coverage/`: an
+/// annotated `<file>.cov` per source and the combined `lcov.info`.
+fn write_coverage(root: &Path, reports: &BTreeMap<&str, CoverageReport>) -> Result<(), CliError> {
+    let out_dir = root.join("target/coverage");
+    let write = |path: &Path, contents: &str| {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| CliError::new(format!("cannot create {}: {e}", parent.display())))?;
+        }
+        std::fs::write(path, contents)
+            .map_err(|e| CliError::new(format!("cannot write {}: {e}", path.display())))
+    };
+
+    let mut lcov = String::new();
+    for (file, report) in reports.iter().filter(|(_, r)| r.coverable() > 0) {
+        let source = std::fs::read_to_string(root.join(file))
+            .map_err(|e| CliError::new(format!("cannot read {file}: {e}")))?;
+        write(&out_dir.join(format!("{file}.cov")), &report.annotate(&source))?;
+        lcov.push_str(&report.to_lcov(file));
+
+        let percent = report.covered() * 100 / report.coverable();
+        let mut line = format!("coverage: {file} {}/{} lines ({percent}%)", report.covered(), report.coverable());
+        let untested = report.uncovered();
+        if !untested.is_empty() {
+            let list: Vec<String> = untested.iter().map(usize::to_string).collect();
+            line.push_str(&format!("; never ran: {}", list.join(", ")));
+        }
+        ui::info(line);
+    }
+    write(&out_dir.join("lcov.info"), &lcov)?;
+    ui::info(format!("coverage report: {}", out_dir.display()));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODULE: &str = "# Doubling\n\n## To double (n: Int) -> Int:\n    Return n * 2.\n\n\
+Use it like this:\n\n```logos\nShow double(21).\n```\nproduces: 42\n\n\
+```logos\nShow 1.\nShow 2.\n```\n\nproduces:\n\n```\n1\n2\n```\n\n\
+```logos,ignore\nShow nothing.\n```\n\n```text\nnot code\n```\n\n## Main\n    Show 0.\n";
+
+    #[test]
+    fn fenced_logos_blocks_become_doc_tests() {
+        let tests = extract_doc_tests("src/main.lg", MODULE);
+        assert_eq!(tests.len(), 2, "{tests:?}");
+        assert_eq!(tests[0].name, "src/main.lg:8");
+        assert_eq!(tests[0].expected.as_deref(), Some("42"));
+        assert_eq!(tests[1].expected.as_deref(), Some("1\n2\n"));
+    }
+
+    #[test]
+    fn snippets_run_after_the_file_definitions() {
+        let tests = extract_doc_tests("src/main.lg", MODULE);
+        let program = &tests[0].program;
+        assert!(program.starts_with("## To double (n: Int) -> Int:\n    Return n * 2."), "{program}");
+        assert!(program.ends_with("## Main\nShow double(21).\n"), "{program}");
+        assert!(!program.contains("Show 0."), "the file's own Main stays out: {program}");
+        // The copied definition maps back to the file's lines 3 and 4.
+        assert_eq!(&tests[0].origins[..2], &[3, 4]);
+    }
+
+    #[test]
+    fn snippets_with_their_own_main_run_as_written() {
+        let src = "```logos\n## Main\nShow 7.\n```\n";
+        let tests = extract_doc_tests("notes.md", src);
+        assert_eq!(tests[0].program, "## Main\nShow 7.\n");
+        assert_eq!(tests[0].expected, None);
+    }
+}
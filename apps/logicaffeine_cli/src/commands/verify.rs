@@ -6,55 +6,120 @@ use std::env;
 use crate::commands::require_project_root;
 use crate::ui;
 
+/// How a verification run uses the cache and schedules the solver.
+#[derive(Debug, Default)]
+#[cfg_attr(not(feature = "verification"), allow(dead_code))]
+pub(crate) struct VerifyOptions {
+    /// Ignore the verification cache.
+    pub fresh: bool,
+    /// Solver threads; `None` uses every CPU.
+    pub jobs: Option<usize>,
+    /// Per-obligation timeout in seconds.
+    pub timeout: Option<u64>,
+    /// Wall-clock budget for the run in seconds.
+    pub budget: Option<u64>,
+}
+
 /// Handle `largo verify`: run verification against the current project.
-pub(crate) fn cmd_verify(license: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
+pub(crate) fn cmd_verify(
+    license: Option<String>,
+    options: VerifyOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
     let project_root = require_project_root()?;
 
-    run_verification(&project_root, license.as_deref())?;
+    run_verification(&project_root, license.as_deref(), &options)?;
     ui::info("Verification passed");
     Ok(())
 }
 
+/// Where proven obligations are remembered between runs.
+#[cfg(feature = "verification")]
+pub(crate) fn verify_cache_dir(project_root: &std::path::Path) -> std::path::PathBuf {
+    project_root.join(".largo").join("verify-cache")
+}
+
 /// Validate the license and run Z3 verification over the project entry.
+///
+/// Obligations proven by an earlier run are answered from the project's
+/// verification cache (unless `options.fresh`); the rest are proved in
+/// parallel under the options' jobs, timeout and budget.
 #[cfg(feature = "verification")]
 pub(crate) fn run_verification(
     project_root: &std::path::Path,
     license: Option<&str>,
+    options: &VerifyOptions,
 ) -> Result<(), Box<dyn std::error::Error>> {
     use crate::project::manifest::Manifest;
-    use logicaffeine_verify::{LicenseValidator, Verifier};
-    use std::fs;
+    use logicaffeine_verify::cache::ObligationCache;
+    use logicaffeine_verify::schedule::ScheduleConfig;
+    use logicaffeine_verify::{LicenseValidator, VerificationErrorKind};
+    use std::sync::Arc;
 
     // Get license key from argument or environment
     let license_key = license
         .map(String::from)
         .or_else(|| env::var("LOGOS_LICENSE").ok());
 
-    let license_key = license_key.ok_or(
-        "Verification requires a license key.\n\
-         Use --license <key> or set LOGOS_LICENSE environment variable.\n\
-         Get a license at https://logicaffeine.com/pricing",
-    )?;
-
-    // Validate license
+    // Validate license: the key if given, else the activated license file
     println!("Validating license...");
     let validator = LicenseValidator::new();
-    let plan = validator.validate(&license_key)?;
+    let plan = match license_key {
+        Some(key) => validator.validate(&key)?,
+        None => match validator.validate_activated() {
+            Err(e) if matches!(e.kind, VerificationErrorKind::LicenseRequired) => {
+                return Err("Verification requires a license.\n\
+                     Run `largo license activate <key>`, use --license <key>, \
+                     or set LOGOS_LICENSE environment variable.\n\
+                     Get a license at https://logicaffeine.com/pricing"
+                    .into())
+            }
+            result => result?,
+        },
+    };
     println!("License valid ({})", plan);
 
-    // Load and parse the project
     let manifest = Manifest::load(project_root)?;
-    let entry_path = project_root.join(&manifest.package.entry);
-    let _source = fs::read_to_string(&entry_path)?;
+    let entry_path = crate::commands::resolve_entry_path(project_root, &manifest)?;
+    let source = std::fs::read_to_string(&entry_path)?;
+
+    let cache_dir = verify_cache_dir(project_root);
+    let cache = Arc::new(if options.fresh {
+        ObligationCache::fresh(&cache_dir)
+    } else {
+        ObligationCache::open(&cache_dir)
+    });
 
-    // For now, just verify that Z3 works
-    // TODO: Implement full AST encoding in Phase 2
-    println!("Running Z3 verification...");
-    let verifier = Verifier::new();
+    let defaults = ScheduleConfig::default();
+    let schedule = ScheduleConfig {
+        jobs: options.jobs.unwrap_or(defaults.jobs).max(1),
+        timeout_ms: options.timeout.map_or(defaults.timeout_ms, |secs| secs.saturating_mul(1000)),
+        budget_ms: options.budget.map(|secs| secs.saturating_mul(1000)),
+    };
 
-    // Basic smoke test - verify that true is valid
-    verifier.check_bool(true)?;
+    println!("Running Z3 verification ({} job(s))...", schedule.jobs);
+    let outcome =
+        logicaffeine_compile::compile::verify_source(&source, Some(Arc::clone(&cache)), &schedule);
+    // Proofs found before a failure are still worth keeping.
+    if let Err(e) = cache.save() {
+        ui::warn(format!("could not write the verification cache in {}: {e}", cache_dir.display()));
+    }
+    outcome.map_err(|e| {
+        // Verification failures carry their own explanation; only genuine
+        // parse errors get the caret excerpt.
+        let message = match &e.kind {
+            logicaffeine_language::ParseErrorKind::Custom(message) => message.clone(),
+            _ => crate::commands::check::explain_parse_error(&e, &source),
+        };
+        crate::ui::CliError::new(message)
+    })?;
 
+    let stats = cache.stats();
+    let total = stats.hits + stats.misses;
+    if stats.hits > 0 {
+        println!("{total} obligation(s) verified ({} unchanged, skipped)", stats.hits);
+    } else {
+        println!("{total} obligation(s) verified");
+    }
     Ok(())
 }
 
@@ -63,11 +128,18 @@ pub(crate) fn run_verification(
 pub(crate) fn run_verification(
     _project_root: &std::path::Path,
     _license: Option<&str>,
+    _options: &VerifyOptions,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    Err(crate::ui::CliError::with_hint(
+    Err(verification_unavailable().into())
+}
+
+/// The error every verification entry point returns in builds without the
+/// `verification` feature.
+#[cfg(not(feature = "verification"))]
+pub(crate) fn verification_unavailable() -> crate::ui::CliError {
+    crate::ui::CliError::with_hint(
         "this largo build does not include Z3 verification",
         "install the full build: `curl -fsSL https://logicaffeine.com/install.sh | sh -s -- --full` \
          (or build from source with `cargo install logicaffeine-cli --features verification`)",
     )
-    .into())
 }
@@ -0,0 +1,301 @@
+//! `largo watch` — re-run `check`, `run` or `test` whenever the project changes.
+//!
+//! The watcher polls the project's sources (`Largo.toml` and every `.lg`,
+//! `.md` and `.toml` file outside `target/` and hidden directories) and
+//! compares content hashes, not just timestamps: an editor that rewrites a
+//! file without changing it, or a `touch`, does not trigger a rebuild.
+//! Changes are debounced — a burst of saves (format-on-save, a branch
+//! checkout) settles into one re-run listing every file that changed.
+//!
+//! Each run is a fresh `largo <command>` child process sharing the project's
+//! `target/` directory, so `watch run` reuses cargo's incremental artifacts
+//! exactly as a hand-typed `largo run` would, and a crash or a `panic!` in
+//! one run never takes the watcher down. A change that lands while
+//! `watch run` is still executing the previous program stops it and starts
+//! over.
+
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::commands::require_project_root;
+use crate::ui::{self, CliError};
+
+/// How often the watcher re-scans the project.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Files modified more recently than this are re-hashed even when their
+/// timestamp looks unchanged (see [`is_racy`]).
+const RACY_WINDOW: Duration = Duration::from_secs(2);
+
+/// The command `largo watch` re-runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum WatchCommand {
+    /// `largo check`: compile to Rust and report errors.
+    Check,
+    /// `largo run`: build and execute.
+    Run,
+    /// `largo test`: run the documentation examples.
+    Test,
+}
+
+impl WatchCommand {
+    fn verb(self) -> &'static str {
+        match self {
+            WatchCommand::Check => "check",
+            WatchCommand::Run => "run",
+            WatchCommand::Test => "test",
+        }
+    }
+}
+
+/// Content hashes of every watched file, refreshed by [`Snapshot::rescan`].
+#[derive(Debug, Default)]
+pub struct Snapshot {
+    root: PathBuf,
+    files: BTreeMap<PathBuf, (SystemTime, u64)>,
+}
+
+impl Snapshot {
+    /// Hash every watched file under `root`.
+    pub fn new(root: &Path) -> Self {
+        let mut snapshot = Snapshot { root: root.to_path_buf(), files: BTreeMap::new() };
+        snapshot.rescan();
+        snapshot
+    }
+
+    /// Re-scan the project and return the files (relative to the root) that
+    /// were added, removed, or whose content changed since the last scan.
+    /// Only files with a new (or very recent) modification time are re-read.
+    pub fn rescan(&mut self) -> Vec<PathBuf> {
+        let mut seen = BTreeMap::new();
+        let mut changed = Vec::new();
+        for path in watched_files(&self.root) {
+            let Ok(modified) = std::fs::metadata(&path).and_then(|m| m.modified()) else {
+                continue;
+            };
+            let hash = match self.files.get(&path) {
+                Some(&(previous, hash)) if previous == modified && !is_racy(modified) => hash,
+                _ => match std::fs::read(&path) {
+                    Ok(bytes) => content_hash(&bytes),
+                    Err(_) => continue,
+                },
+            };
+            if self.files.get(&path).map(|&(_, previous)| previous) != Some(hash) {
+                changed.push(path.clone());
+            }
+            seen.insert(path, (modified, hash));
+        }
+        changed.extend(self.files.keys().filter(|p| !seen.contains_key(*p)).cloned());
+        self.files = seen;
+        changed.sort();
+        changed
+            .into_iter()
+            .map(|p| p.strip_prefix(&self.root).map(Path::to_path_buf).unwrap_or(p))
+            .collect()
+    }
+}
+
+/// A timestamp too recent to trust: on filesystems with coarse mtimes, a
+/// second write in the same tick leaves it unchanged, so such files are
+/// re-read regardless.
+fn is_racy(modified: SystemTime) -> bool {
+    SystemTime::now().duration_since(modified).map_or(true, |age| age < RACY_WINDOW)
+}
+
+fn content_hash(bytes: &[u8]) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The sources a rebuild depends on: LOGOS files, markdown entries and
+/// manifests, skipping build output and hidden directories.
+pub(crate) fn watched_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else { continue };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if name.starts_with('.') {
+                continue;
+            }
+            match entry.file_type() {
+                Ok(t) if t.is_dir() => {
+                    if !(dir == root && name == "target") {
+                        pending.push(path);
+                    }
+                }
+                Ok(_) => {
+                    let ext = path.extension().and_then(|e| e.to_str());
+                    if matches!(ext, Some("lg" | "md" | "toml")) {
+                        files.push(path);
+                    }
+                }
+                Err(_) => {}
+            }
+        }
+    }
+    files
+}
+
+/// Handle `largo watch <command> [--debounce MS] [-- args...]`.
+pub(crate) fn cmd_watch(
+    command: WatchCommand,
+    debounce: u64,
+    args: &[String],
+) -> Result<(), Box<dyn std::error::Error>> {
+    if command != WatchCommand::Run && !args.is_empty() {
+        return Err(CliError::new("program arguments only apply to `largo watch run`")
+            .exit_code(ui::EXIT_USAGE)
+            .into());
+    }
+
+    let root = require_project_root()?;
+    let exe = std::env::current_exe()?;
+    let debounce = Duration::from_millis(debounce);
+    let mut snapshot = Snapshot::new(&root);
+    ui::phase("Watching", format!("{} (largo {})", root.display(), command.verb()));
+
+    let mut child = Some(spawn(&exe, &root, command, args)?);
+    let mut started = Instant::now();
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+
+        if let Some(running) = child.as_mut() {
+            if let Some(status) = running.try_wait()? {
+                report(command, status, started.elapsed());
+                child = None;
+            }
+        }
+
+        let mut changed = snapshot.rescan();
+        if changed.is_empty() {
+            continue;
+        }
+        // Debounce: wait for a quiet period, folding in every change that
+        // lands meanwhile.
+        let mut quiet_since = Instant::now();
+        while quiet_since.elapsed() < debounce {
+            std::thread::sleep(POLL_INTERVAL.min(debounce));
+            let more = snapshot.rescan();
+            if !more.is_empty() {
+                changed.extend(more);
+                quiet_since = Instant::now();
+            }
+        }
+        changed.sort();
+        changed.dedup();
+
+        if let Some(mut running) = child.take() {
+            let _ = running.kill();
+            let _ = running.wait();
+        }
+        ui::phase("Changed", describe_changes(&changed));
+        child = Some(spawn(&exe, &root, command, args)?);
+        started = Instant::now();
+    }
+}
+
+fn spawn(
+    exe: &Path,
+    root: &Path,
+    command: WatchCommand,
+    args: &[String],
+) -> std::io::Result<Child> {
+    let mut cmd = Command::new(exe);
+    cmd.current_dir(root).arg(command.verb());
+    if ui::is_quiet() {
+        cmd.arg("--quiet");
+    }
+    for _ in 0..ui::verbosity() {
+        cmd.arg("--verbose");
+    }
+    if !args.is_empty() {
+        cmd.arg("--").args(args);
+    }
+    cmd.spawn()
+}
+
+/// One line per finished run: what ran, whether it passed, how long it took.
+fn report(command: WatchCommand, status: std::process::ExitStatus, elapsed: Duration) {
+    let took = format!("{:.2}s", elapsed.as_secs_f64());
+    if status.success() {
+        ui::phase("Finished", format!("largo {} in {took}; waiting for changes", command.verb()));
+    } else {
+        let code = status.code().map_or_else(|| "a signal".to_string(), |c| format!("exit code {c}"));
+        ui::warn(format!("largo {} failed ({code}) in {took}; waiting for changes", command.verb()));
+    }
+}
+
+/// `src/main.lg`, or `src/a.lg, src/b.lg and 3 more`.
+fn describe_changes(changed: &[PathBuf]) -> String {
+    const SHOWN: usize = 3;
+    let names: Vec<String> =
+        changed.iter().take(SHOWN).map(|p| p.display().to_string()).collect();
+    match changed.len() {
+        n if n > SHOWN => format!("{} and {} more", names.join(", "), n - SHOWN),
+        _ => names.join(", "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("Largo.toml"), "[package]\nname = \"w\"\n").unwrap();
+        std::fs::write(dir.path().join("src/main.lg"), "## Main\nShow 1.\n").unwrap();
+        dir
+    }
+
+    #[test]
+    fn only_content_changes_are_reported() {
+        let dir = project();
+        let mut snapshot = Snapshot::new(dir.path());
+        assert!(snapshot.rescan().is_empty());
+
+        // Rewriting identical bytes is not a change, whatever the mtime says.
+        std::fs::write(dir.path().join("src/main.lg"), "## Main\nShow 1.\n").unwrap();
+        assert!(snapshot.rescan().is_empty());
+
+        std::fs::write(dir.path().join("src/main.lg"), "## Main\nShow 2.\n").unwrap();
+        assert_eq!(snapshot.rescan(), vec![PathBuf::from("src/main.lg")]);
+    }
+
+    #[test]
+    fn added_and_removed_files_are_reported() {
+        let dir = project();
+        let mut snapshot = Snapshot::new(dir.path());
+        std::fs::write(dir.path().join("src/geometry.lg"), "## Main\n").unwrap();
+        assert_eq!(snapshot.rescan(), vec![PathBuf::from("src/geometry.lg")]);
+        std::fs::remove_file(dir.path().join("src/main.lg")).unwrap();
+        assert_eq!(snapshot.rescan(), vec![PathBuf::from("src/main.lg")]);
+    }
+
+    #[test]
+    fn build_output_and_hidden_directories_are_ignored() {
+        let dir = project();
+        let mut snapshot = Snapshot::new(dir.path());
+        for ignored in ["target", ".git", ".logos-native"] {
+            std::fs::create_dir_all(dir.path().join(ignored)).unwrap();
+            std::fs::write(dir.path().join(ignored).join("x.toml"), "x").unwrap();
+        }
+        std::fs::write(dir.path().join("src/notes.txt"), "not a source").unwrap();
+        assert!(snapshot.rescan().is_empty());
+    }
+
+    #[test]
+    fn long_change_lists_are_summarized() {
+        let changed: Vec<PathBuf> = (0..5).map(|i| PathBuf::from(format!("src/{i}.lg"))).collect();
+        assert_eq!(describe_changes(&changed), "src/0.lg, src/1.lg, src/2.lg and 2 more");
+        assert_eq!(describe_changes(&changed[..1]), "src/0.lg");
+    }
+}
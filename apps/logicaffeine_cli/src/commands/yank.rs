@@ -0,0 +1,54 @@
+//! `largo yank` — withdraw a published version from new dependency resolution.
+//!
+//! Yanking never deletes anything: lockfiles that already pin the version
+//! keep downloading it, but no new resolution picks it. `--undo` reverses it.
+
+use crate::commands::publish::{explain_registry_error, require_token};
+use crate::commands::require_project_root;
+use crate::project::manifest::Manifest;
+use crate::project::registry::RegistryClient;
+use crate::ui::{self, CliError};
+
+/// Handle `largo yank <name@version | version> [--undo]`.
+pub(crate) fn cmd_yank(
+    spec: &str,
+    undo: bool,
+    registry: Option<&str>,
+    team: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (name, version) = parse_spec(spec)?;
+    let registry_url = registry.unwrap_or(RegistryClient::default_url());
+    let token = require_token(registry_url, team)?;
+    let client = RegistryClient::new(registry_url, &token);
+
+    let result = if undo {
+        client.unyank(&name, &version)
+    } else {
+        client.yank(&name, &version)
+    };
+    result.map_err(|e| explain_registry_error(e, &name, team))?;
+
+    let verb = if undo { "Unyanked" } else { "Yanked" };
+    ui::phase(verb, format!("{name}@{version} on {registry_url}"));
+    Ok(())
+}
+
+/// `name@version`, or a bare version of the current project's package.
+fn parse_spec(spec: &str) -> Result<(String, String), Box<dyn std::error::Error>> {
+    let (name, version) = match spec.split_once('@') {
+        Some((name, version)) => (name.to_string(), version.to_string()),
+        None => {
+            let manifest = Manifest::load(&require_project_root()?)?;
+            (manifest.package.name, spec.to_string())
+        }
+    };
+    if name.is_empty() || version.is_empty() || version.contains('@') {
+        return Err(CliError::with_hint(
+            format!("invalid version spec '{spec}'"),
+            "write `largo yank <name>@<version>`, or `largo yank <version>` inside the project",
+        )
+        .exit_code(ui::EXIT_USAGE)
+        .into());
+    }
+    Ok((name, version))
+}
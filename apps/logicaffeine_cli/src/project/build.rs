@@ -17,8 +17,9 @@
 //! │       ├── Cargo.toml
 //! │       ├── src/main.rs  # Generated Rust code
 //! │       └── target/      # Cargo's output
-//! └── release/
-//!     └── build/           # Generated Cargo project (release)
+//! ├── release/
+//! │   └── build/           # Generated Cargo project (release)
+//! └── extracted/           # Cached `proven` modules extracted from kernel definitions
 //! ```
 
 use std::fmt::Write as FmtWrite;
@@ -26,8 +27,11 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
-use crate::compile::compile_project;
-use logicaffeine_compile::compile::{copy_runtime_crates, CompileError};
+use crate::compile::{compile_project_cached, compile_project_profiled};
+use logicaffeine_compile::extraction::ExtractionCache;
+use logicaffeine_compile::loader::CompileTarget;
+use logicaffeine_compile::compile::{copy_runtime_crates, toml_feature_list, CompileError};
+use logicaffeine_compile::metrics::CompileMetrics;
 
 use super::manifest::{Manifest, ManifestError};
 
@@ -46,6 +50,8 @@ use super::manifest::{Manifest, ManifestError};
 ///     release: false,
 ///     lib_mode: false,
 ///     target: None,
+///     profile: false,
+///     features: Vec::new(),
 /// };
 ///
 /// let result = build(config)?;
@@ -62,6 +68,10 @@ pub struct BuildConfig {
     /// Target triple for cross-compilation (e.g., "wasm32-unknown-unknown").
     /// "wasm" is expanded to "wasm32-unknown-unknown".
     pub target: Option<String>,
+    /// If `true`, instrument every LOGOS function for `largo run --profile`.
+    pub profile: bool,
+    /// Features that enable `## When compiling with feature …` sections.
+    pub features: Vec<String>,
 }
 
 /// Result of a successful build operation.
@@ -272,6 +282,19 @@ pub fn build(config: BuildConfig) -> Result<BuildResult, BuildError> {
     )))
 }
 
+/// Warn about any LOGOS compile phase over [`CompileMetrics::SLOW_PHASE`];
+/// under `-v`, print the whole phase breakdown.
+fn report_compile_metrics(metrics: &CompileMetrics) {
+    for warning in metrics.slow_phase_warnings(CompileMetrics::SLOW_PHASE) {
+        crate::ui::warn(warning);
+    }
+    if crate::ui::verbosity() > 0 && !crate::ui::is_quiet() {
+        for line in metrics.to_string().lines() {
+            crate::ui::phase("Timing", line);
+        }
+    }
+}
+
 fn build_with_entry(
     config: &BuildConfig,
     manifest: &Manifest,
@@ -302,7 +325,16 @@ fn build_with_entry(
     fs::create_dir_all(&rust_project_dir).map_err(|e| BuildError::Io(e.to_string()))?;
 
     // Compile LOGOS to Rust using Phase 36 compile_project
-    let output = compile_project(entry_path)?;
+    let output = if config.profile {
+        compile_project_profiled(entry_path)?
+    } else {
+        let target = CompileTarget::for_triple(config.target.as_deref()).with_features(&config.features);
+        // Extracted kernel definitions outlive the clean build directory, so an
+        // unchanged proof is linked without replaying it.
+        let cache = ExtractionCache::new(target_dir.join("extracted"));
+        compile_project_cached(entry_path, &target, &cache)?
+    };
+    report_compile_metrics(&output.metrics);
 
     // Write generated Rust code
     let src_dir = rust_project_dir.join("src");
@@ -351,7 +383,11 @@ edition = "2021"
 
     let _ = writeln!(cargo_toml, "\n[dependencies]");
     let _ = writeln!(cargo_toml, "logicaffeine-data = {{ path = \"./crates/logicaffeine_data\" }}");
-    let _ = writeln!(cargo_toml, "logicaffeine-system = {{ path = \"./crates/logicaffeine_system\", features = [\"full\"] }}");
+    let _ = writeln!(
+        cargo_toml,
+        "logicaffeine-system = {{ path = \"./crates/logicaffeine_system\", features = [{}] }}",
+        toml_feature_list(&output.system_features)
+    );
     let _ = writeln!(cargo_toml, "tokio = {{ version = \"1\", features = [\"rt-multi-thread\", \"macros\"] }}");
 
     // Auto-inject wasm-bindgen when targeting wasm32
@@ -428,6 +464,12 @@ edition = "2021"
     if let Some(target) = resolved_target {
         cmd.arg("--target").arg(target);
     }
+    // `Join the swarm.` reads the project's discovery settings, baked in at
+    // compile time (the runtime crate picks them up with `option_env!`).
+    let network = &manifest.network;
+    cmd.env("LOGOS_MDNS", if network.mdns { "on" } else { "off" })
+        .env("LOGOS_BOOTSTRAP", network.bootstrap.join(","))
+        .env("LOGOS_RELAYS", network.relays.join(","));
     // Cargo sees a pipe on stderr and would disable its own color; pass the
     // resolved choice explicitly so cargo and largo agree.
     let color_arg = match anstream::AutoStream::choice(&std::io::stderr()) {
@@ -11,6 +11,16 @@
 //! 1. `LOGOS_TOKEN` environment variable (highest priority)
 //! 2. Credentials file entry for the registry URL
 //!
+//! # Team Tokens
+//!
+//! A token issued to a team (`largo login --team core`) is stored next to
+//! the personal one and selected with `--team` on registry commands, so one
+//! machine can act for several teams without logging in and out. Team
+//! lookups use [`get_team_token`], which prefers the stored team token over
+//! `LOGOS_TOKEN` — an explicit `--team` must not be overridden by a personal
+//! token in the environment — and falls back to `LOGOS_TOKEN` only when no
+//! team token is stored, so a CI job holding a team's token just exports it.
+//!
 //! # Security
 //!
 //! - Tokens are stored in plaintext (like cargo, npm, etc.)
@@ -31,12 +41,18 @@ use std::path::PathBuf;
 /// ```toml
 /// [registries]
 /// "https://registry.logicaffeine.com" = "tok_xxxxx"
+///
+/// [teams."https://registry.logicaffeine.com"]
+/// core = "tok_yyyyy"
 /// ```
 #[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
 pub struct Credentials {
     /// Map of registry URL to authentication token.
     #[serde(default)]
     pub registries: HashMap<String, String>,
+    /// Map of registry URL to team name to team-scoped token.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub teams: HashMap<String, HashMap<String, String>>,
 }
 
 impl Credentials {
@@ -97,6 +113,40 @@ impl Credentials {
     pub fn remove_token(&mut self, registry_url: &str) {
         self.registries.remove(registry_url);
     }
+
+    /// Get a team's token for a registry
+    pub fn get_team_token(&self, registry_url: &str, team: &str) -> Option<&str> {
+        self.teams.get(registry_url)?.get(team).map(|s| s.as_str())
+    }
+
+    /// Set a team's token for a registry
+    pub fn set_team_token(&mut self, registry_url: &str, team: &str, token: &str) {
+        self.teams
+            .entry(registry_url.to_string())
+            .or_default()
+            .insert(team.to_string(), token.to_string());
+    }
+
+    /// Remove a team's token for a registry
+    pub fn remove_team_token(&mut self, registry_url: &str, team: &str) {
+        if let Some(teams) = self.teams.get_mut(registry_url) {
+            teams.remove(team);
+            if teams.is_empty() {
+                self.teams.remove(registry_url);
+            }
+        }
+    }
+
+    /// Names of the teams with a stored token for a registry, sorted
+    pub fn team_names(&self, registry_url: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .teams
+            .get(registry_url)
+            .map(|t| t.keys().map(|s| s.as_str()).collect())
+            .unwrap_or_default();
+        names.sort_unstable();
+        names
+    }
 }
 
 /// Get the token for a registry, checking env var first then credentials file
@@ -114,6 +164,18 @@ pub fn get_token(registry_url: &str) -> Option<String> {
         .and_then(|c| c.get_token(registry_url).map(String::from))
 }
 
+/// Get a team's token for a registry, checking credentials file first then env var
+pub fn get_team_token(registry_url: &str, team: &str) -> Option<String> {
+    let stored = Credentials::load()
+        .ok()
+        .and_then(|c| c.get_team_token(registry_url, team).map(String::from));
+    if stored.is_some() {
+        return stored;
+    }
+
+    std::env::var("LOGOS_TOKEN").ok().filter(|token| !token.is_empty())
+}
+
 /// Get the path to the credentials file
 pub fn credentials_path() -> Option<PathBuf> {
     // Check LOGOS_CREDENTIALS_PATH env var first
@@ -18,6 +18,11 @@
 //! [dependencies]
 //! std = "logos:std"
 //! math = { path = "./math" }
+//!
+//! [network]
+//! mdns = true
+//! bootstrap = ["/ip4/203.0.113.7/tcp/8000/p2p/12D3KooW..."]
+//! relays = ["ws://relay.example.org:9000"]
 //! ```
 
 use serde::{Deserialize, Serialize};
@@ -36,6 +41,36 @@ pub struct Manifest {
     /// Map of dependency names to their specifications.
     #[serde(default)]
     pub dependencies: HashMap<String, DependencySpec>,
+    /// Peer discovery settings from the `[network]` section.
+    #[serde(default, skip_serializing_if = "NetworkConfig::is_default")]
+    pub network: NetworkConfig,
+}
+
+/// Peer discovery from the `[network]` section, used by
+/// `Join the swarm.` in the built program.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkConfig {
+    /// Find peers on the local network with mDNS. Defaults to true.
+    #[serde(default = "default_mdns")]
+    pub mdns: bool,
+    /// Multiaddrs of peers to dial when joining.
+    #[serde(default)]
+    pub bootstrap: Vec<String>,
+    /// WebSocket relays for reaching the swarm from behind NAT.
+    #[serde(default)]
+    pub relays: Vec<String>,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        NetworkConfig { mdns: default_mdns(), bootstrap: Vec::new(), relays: Vec::new() }
+    }
+}
+
+impl NetworkConfig {
+    fn is_default(&self) -> bool {
+        *self == NetworkConfig::default()
+    }
 }
 
 /// Package metadata from the `[package]` section.
@@ -118,6 +153,10 @@ fn default_entry() -> String {
     "src/main.lg".to_string()
 }
 
+fn default_mdns() -> bool {
+    true
+}
+
 /// Errors that can occur when loading a manifest
 #[derive(Debug)]
 pub enum ManifestError {
@@ -158,6 +197,7 @@ impl Manifest {
                 entry: default_entry(),
             },
             dependencies: HashMap::new(),
+            network: NetworkConfig::default(),
         }
     }
 
@@ -210,6 +250,23 @@ std = "logos:std"
         assert_eq!(manifest.package.name, "testproject");
         let toml = manifest.to_toml().expect("Should serialize");
         assert!(toml.contains("name = \"testproject\""));
+        assert!(!toml.contains("[network]"));
+    }
+
+    #[test]
+    fn parse_network_section() {
+        let toml = r#"
+[package]
+name = "swarm"
+
+[network]
+mdns = false
+bootstrap = ["/ip4/203.0.113.7/tcp/8000"]
+"#;
+        let manifest: Manifest = toml::from_str(toml).expect("Should parse network section");
+        assert!(!manifest.network.mdns);
+        assert_eq!(manifest.network.bootstrap, ["/ip4/203.0.113.7/tcp/8000"]);
+        assert!(manifest.network.relays.is_empty());
     }
 
     #[test]
@@ -13,6 +13,7 @@
 //! | [`build`][mod@build] | Compile and run LOGOS projects |
 //! | [`credentials`] | Store and retrieve API tokens |
 //! | [`registry`] | Communicate with the package registry |
+//! | [`templates`] | Built-in and local templates for `largo new --template` |
 //!
 //! # Re-exports
 //!
@@ -36,11 +37,12 @@ pub mod manifest;
 pub mod build;
 pub mod credentials;
 pub mod registry;
+pub mod templates;
 
 pub use manifest::{Manifest, ManifestError};
 pub use build::{
     build, classify_cargo_failure, find_project_root, run, BuildConfig, BuildError, BuildResult,
     CargoFailure, CargoFailureKind,
 };
-pub use credentials::{Credentials, get_token as get_registry_token};
+pub use credentials::{Credentials, get_team_token, get_token as get_registry_token};
 pub use registry::{RegistryClient, create_tarball, is_git_dirty};
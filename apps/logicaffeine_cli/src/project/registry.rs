@@ -34,6 +34,8 @@ const DEFAULT_REGISTRY_URL: &str = "https://registry.logicaffeine.com";
 /// Provides authenticated access to registry operations including:
 /// - Token validation
 /// - Package publishing
+/// - Yanking and un-yanking versions
+/// - Managing package owners (users and teams)
 ///
 /// # Authentication
 ///
@@ -205,6 +207,136 @@ impl RegistryClient {
 
         Ok(result)
     }
+
+    /// Yank a published version.
+    ///
+    /// A yanked version stays downloadable for lockfiles that already pin it,
+    /// but is hidden from new dependency resolution. Sends
+    /// `DELETE /packages/{name}/{version}/yank`.
+    ///
+    /// # Errors
+    ///
+    /// - [`RegistryError::Forbidden`] - The token does not own the package
+    /// - [`RegistryError::NotFound`] - No such package or version
+    pub fn yank(&self, name: &str, version: &str) -> Result<(), RegistryError> {
+        let url = format!("{}/packages/{}/{}/yank", self.base_url, name, version);
+        self.authorized(ureq::delete(&url))
+            .call()
+            .map_err(|e| status_error(e, &format!("{}@{}", name, version)))?;
+        Ok(())
+    }
+
+    /// Undo [`yank`](Self::yank). Sends `PUT /packages/{name}/{version}/unyank`.
+    pub fn unyank(&self, name: &str, version: &str) -> Result<(), RegistryError> {
+        let url = format!("{}/packages/{}/{}/unyank", self.base_url, name, version);
+        self.authorized(ureq::put(&url))
+            .call()
+            .map_err(|e| status_error(e, &format!("{}@{}", name, version)))?;
+        Ok(())
+    }
+
+    /// List the owners of a package. Sends `GET /packages/{name}/owners`.
+    pub fn list_owners(&self, name: &str) -> Result<Vec<Owner>, RegistryError> {
+        let url = format!("{}/packages/{}/owners", self.base_url, name);
+        let response = self.authorized(ureq::get(&url))
+            .call()
+            .map_err(|e| status_error(e, name))?;
+
+        let owners: OwnerList = response.into_json()
+            .map_err(|e| RegistryError::Network(e.to_string()))?;
+
+        Ok(owners.owners)
+    }
+
+    /// Add owners to a package. Sends `PUT /packages/{name}/owners`.
+    ///
+    /// Each entry is a registry login (`alice`) or a team (`team:core`).
+    pub fn add_owners(&self, name: &str, owners: &[String]) -> Result<(), RegistryError> {
+        let url = format!("{}/packages/{}/owners", self.base_url, name);
+        self.authorized(ureq::put(&url))
+            .send_json(serde_json::json!({ "owners": owners }))
+            .map_err(|e| status_error(e, name))?;
+        Ok(())
+    }
+
+    /// Remove owners from a package. Sends `DELETE /packages/{name}/owners`.
+    ///
+    /// The registry refuses to remove a package's last owner.
+    pub fn remove_owners(&self, name: &str, owners: &[String]) -> Result<(), RegistryError> {
+        let url = format!("{}/packages/{}/owners", self.base_url, name);
+        self.authorized(ureq::delete(&url))
+            .send_json(serde_json::json!({ "owners": owners }))
+            .map_err(|e| status_error(e, name))?;
+        Ok(())
+    }
+
+    /// Make `owner` the package's primary owner, keeping the current primary
+    /// owner on as a maintainer. Sends `POST /packages/{name}/owners/transfer`.
+    pub fn transfer_ownership(&self, name: &str, owner: &str) -> Result<(), RegistryError> {
+        let url = format!("{}/packages/{}/owners/transfer", self.base_url, name);
+        self.authorized(ureq::post(&url))
+            .send_json(serde_json::json!({ "owner": owner }))
+            .map_err(|e| status_error(e, name))?;
+        Ok(())
+    }
+
+    fn authorized(&self, request: ureq::Request) -> ureq::Request {
+        request.set("Authorization", &format!("Bearer {}", self.token))
+    }
+}
+
+/// Map a failed request to a [`RegistryError`].
+///
+/// The registry answers errors with `{"error": ..., "message": ...}`; the
+/// message is surfaced instead of the raw JSON. `target` names what the
+/// request was about (`name` or `name@version`) for 404s.
+fn status_error(e: ureq::Error, target: &str) -> RegistryError {
+    match e {
+        ureq::Error::Status(401, _) => RegistryError::Unauthorized,
+        ureq::Error::Status(404, r) => not_found(r.into_string().unwrap_or_default(), target),
+        ureq::Error::Status(429, r) => RegistryError::RateLimited {
+            retry_after: r.header("Retry-After").and_then(|s| s.trim().parse().ok()),
+        },
+        ureq::Error::Status(403, r) => {
+            RegistryError::Forbidden(server_message(r.into_string().unwrap_or_default()))
+        }
+        ureq::Error::Status(code, r) => RegistryError::Server {
+            status: code,
+            message: server_message(r.into_string().unwrap_or_default()),
+        },
+        e => RegistryError::Network(e.to_string()),
+    }
+}
+
+/// A 404 is only "no such package" when the registry says so: its router
+/// answers `Not found` for routes it does not serve (an older registry), and
+/// the owner routes report missing users and teams the same way.
+fn not_found(body: String, target: &str) -> RegistryError {
+    let error = serde_json::from_str::<serde_json::Value>(&body)
+        .ok()
+        .and_then(|json| json.get("error")?.as_str().map(String::from));
+    match error.as_deref() {
+        Some("Package not found") | Some("Version not found") => {
+            RegistryError::NotFound(target.to_string())
+        }
+        Some("Not found") => RegistryError::Server {
+            status: 404,
+            message: "the registry does not support this request; it may predate this version of largo".to_string(),
+        },
+        _ => RegistryError::Server { status: 404, message: server_message(body) },
+    }
+}
+
+/// The human-readable part of a registry error body.
+fn server_message(body: String) -> String {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(&body) else {
+        return body;
+    };
+    json.get("message")
+        .or_else(|| json.get("error"))
+        .and_then(|m| m.as_str())
+        .map(String::from)
+        .unwrap_or(body)
 }
 
 /// Create a gzipped tarball from a LOGOS project.
@@ -416,6 +548,42 @@ pub struct PublishResult {
     pub size: u64,
 }
 
+/// One owner of a package, as listed by `GET /packages/{name}/owners`.
+#[derive(Debug, serde::Deserialize)]
+pub struct Owner {
+    /// Registry login, or team name for team owners.
+    pub login: String,
+    /// Whether this owner is a user or a team.
+    #[serde(default)]
+    pub kind: OwnerKind,
+    /// Role on the package (`owner`, `maintainer` or `publisher`).
+    pub role: Option<String>,
+}
+
+impl std::fmt::Display for Owner {
+    /// Formats the owner the way `largo owner add` accepts it.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            OwnerKind::User => write!(f, "{}", self.login),
+            OwnerKind::Team => write!(f, "team:{}", self.login),
+        }
+    }
+}
+
+/// Whether a package owner is an individual or a team.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OwnerKind {
+    #[default]
+    User,
+    Team,
+}
+
+#[derive(serde::Deserialize)]
+struct OwnerList {
+    owners: Vec<Owner>,
+}
+
 // ============== Errors ==============
 
 /// Errors that can occur during registry API operations.
@@ -439,6 +607,13 @@ pub enum RegistryError {
     },
     /// The package tarball exceeds the size limit (HTTP 413).
     TooLarge,
+    /// The package or version does not exist (HTTP 404).
+    NotFound(String),
+    /// Too many requests (HTTP 429).
+    RateLimited {
+        /// Seconds until the registry accepts requests again, if it said.
+        retry_after: Option<u64>,
+    },
     /// Network or connection error.
     Network(String),
     /// The server returned an unexpected error.
@@ -473,6 +648,15 @@ impl std::fmt::Display for RegistryError {
                 version, name
             ),
             Self::TooLarge => write!(f, "Package too large. Maximum size is 10MB."),
+            Self::NotFound(what) => write!(f, "'{}' was not found on the registry.", what),
+            Self::RateLimited { retry_after: Some(secs) } => write!(
+                f,
+                "The registry is rate limiting requests. Try again in {} seconds.",
+                secs
+            ),
+            Self::RateLimited { retry_after: None } => {
+                write!(f, "The registry is rate limiting requests. Try again shortly.")
+            }
             Self::Network(e) => write!(f, "Network error: {}", e),
             Self::Server { status, message } => {
                 write!(f, "Registry returned error {}: {}", status, message)
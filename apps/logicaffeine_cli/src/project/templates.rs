@@ -0,0 +1,219 @@
+//! Project templates for `largo new --template` / `largo init --template`.
+//!
+//! A template is a set of files written into a fresh project, with every
+//! `{{name}}` replaced by the package name. The built-in templates live in
+//! `apps/logicaffeine_cli/templates/` and are embedded in the binary; each
+//! ships example sources, a golden-output test under `tests/` where the
+//! program's output is deterministic, and a GitHub Actions workflow.
+//!
+//! Users add their own templates as directories under [`templates_dir`]:
+//! `~/.config/logos/templates/<name>/` (or `$LOGOS_TEMPLATES_PATH/<name>/`).
+//! A local template with the same name as a built-in replaces it.
+//!
+//! A template that doesn't provide a `Largo.toml` gets the standard one from
+//! [`Manifest::new`](super::manifest::Manifest::new), and one without a
+//! `.gitignore` gets the standard `/target` ignore.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// One embedded file: its path inside the project and its contents.
+type EmbeddedFile = (&'static str, &'static str);
+
+/// A template compiled into `largo`.
+pub struct BuiltinTemplate {
+    pub name: &'static str,
+    pub description: &'static str,
+    files: &'static [EmbeddedFile],
+}
+
+macro_rules! embed {
+    ($template:literal: $($path:literal),+ $(,)?) => {
+        &[$(($path, include_str!(concat!("../../templates/", $template, "/", $path)))),+]
+    };
+}
+
+/// The templates every `largo` knows, in the order `--list-templates` shows them.
+pub const BUILTIN: &[BuiltinTemplate] = &[
+    BuiltinTemplate {
+        name: "cli",
+        description: "a command-line tool that reads its arguments",
+        files: embed!("cli":
+            "src/main.lg",
+            "tests/expected_output.txt",
+            ".github/workflows/ci.yml",
+        ),
+    },
+    BuiltinTemplate {
+        name: "web-service",
+        description: "a networked service that syncs shared state with its peers",
+        files: embed!("web-service":
+            "src/main.lg",
+            ".github/workflows/ci.yml",
+        ),
+    },
+    BuiltinTemplate {
+        name: "library",
+        description: "reusable functions with a self-testing `## Main`",
+        files: embed!("library":
+            "src/main.lg",
+            "tests/expected_output.txt",
+            ".github/workflows/ci.yml",
+        ),
+    },
+    BuiltinTemplate {
+        name: "verification-spec",
+        description: "theorems for `largo prove` and a program with contracts",
+        files: embed!("verification-spec":
+            "src/main.lg",
+            "tests/expected_output.txt",
+            ".github/workflows/ci.yml",
+        ),
+    },
+    BuiltinTemplate {
+        name: "learning-content",
+        description: "a literate Markdown lesson whose code runs",
+        files: embed!("learning-content":
+            "Largo.toml",
+            "src/main.md",
+            "tests/expected_output.txt",
+            ".github/workflows/ci.yml",
+        ),
+    },
+];
+
+/// Where local templates live: `$LOGOS_TEMPLATES_PATH` if set, else
+/// `<config dir>/logos/templates`.
+pub fn templates_dir() -> Option<PathBuf> {
+    if let Ok(path) = std::env::var("LOGOS_TEMPLATES_PATH") {
+        return Some(PathBuf::from(path));
+    }
+    dirs::config_dir().map(|p| p.join("logos").join("templates"))
+}
+
+/// A template resolved by name, ready to render.
+#[derive(Debug)]
+pub struct Template {
+    pub name: String,
+    /// Project-relative paths and their unrendered contents.
+    files: Vec<(PathBuf, String)>,
+}
+
+impl Template {
+    /// Look `name` up among the local templates, then the built-ins.
+    pub fn find(name: &str) -> Result<Template, TemplateError> {
+        if let Some(dir) = templates_dir().map(|d| d.join(name)).filter(|d| d.is_dir()) {
+            let mut files = Vec::new();
+            collect_files(&dir, &dir, &mut files)?;
+            return Ok(Template { name: name.to_string(), files });
+        }
+        BUILTIN
+            .iter()
+            .find(|t| t.name == name)
+            .map(|t| Template {
+                name: t.name.to_string(),
+                files: t.files.iter().map(|(p, c)| (PathBuf::from(p), c.to_string())).collect(),
+            })
+            .ok_or_else(|| TemplateError::Unknown(name.to_string()))
+    }
+
+    /// The template's files for a project named `package`.
+    pub fn render(&self, package: &str) -> Vec<(PathBuf, String)> {
+        self.files
+            .iter()
+            .map(|(path, contents)| (path.clone(), contents.replace("{{name}}", package)))
+            .collect()
+    }
+
+    /// Whether the template brings its own copy of `path`.
+    pub fn provides(&self, path: &str) -> bool {
+        self.files.iter().any(|(p, _)| p == Path::new(path))
+    }
+}
+
+fn collect_files(
+    root: &Path,
+    dir: &Path,
+    out: &mut Vec<(PathBuf, String)>,
+) -> Result<(), TemplateError> {
+    let io = |e: std::io::Error| TemplateError::Io(format!("{}: {e}", dir.display()));
+    let mut entries: Vec<_> = fs::read_dir(dir).map_err(io)?.collect::<Result<_, _>>().map_err(io)?;
+    entries.sort_by_key(|e| e.path());
+    for entry in entries {
+        let path = entry.path();
+        if entry.file_type().map_err(io)?.is_dir() {
+            collect_files(root, &path, out)?;
+        } else {
+            let contents = fs::read_to_string(&path).map_err(|e| {
+                TemplateError::Io(format!("{}: {e} (templates must be UTF-8 text)", path.display()))
+            })?;
+            let relative = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+            out.push((relative, contents));
+        }
+    }
+    Ok(())
+}
+
+/// Every template name `find` would accept: local ones (marked `true`),
+/// then the built-ins they don't shadow.
+pub fn available() -> Vec<(String, bool)> {
+    let mut names: Vec<(String, bool)> = templates_dir()
+        .and_then(|dir| fs::read_dir(dir).ok())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+        .map(|e| (e.file_name().to_string_lossy().into_owned(), true))
+        .collect();
+    names.sort();
+    for builtin in BUILTIN {
+        if !names.iter().any(|(n, _)| n == builtin.name) {
+            names.push((builtin.name.to_string(), false));
+        }
+    }
+    names
+}
+
+/// Errors that can occur when resolving a template.
+#[derive(Debug)]
+pub enum TemplateError {
+    /// No local or built-in template has this name.
+    Unknown(String),
+    /// A local template could not be read.
+    Io(String),
+}
+
+impl std::fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::Unknown(name) => write!(f, "unknown template '{}'", name),
+            TemplateError::Io(e) => write!(f, "could not read template: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_builtin_renders_its_package_name() {
+        for builtin in BUILTIN {
+            let files = Template::find(builtin.name).unwrap().render("acme");
+            assert!(files.iter().any(|(p, _)| p.starts_with("src")), "{}", builtin.name);
+            assert!(
+                files.iter().all(|(_, c)| !c.contains("{{name}}")),
+                "{} left a placeholder unrendered",
+                builtin.name
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_templates_are_reported_by_name() {
+        let err = Template::find("no-such-template").unwrap_err();
+        assert_eq!(err.to_string(), "unknown template 'no-such-template'");
+    }
+}
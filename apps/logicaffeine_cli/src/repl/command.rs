@@ -3,6 +3,8 @@
 
 use std::path::PathBuf;
 
+use logicaffeine_language::OutputFormat;
+
 use crate::commands::logic::LogicFormat;
 
 /// Which language the REPL is currently speaking.
@@ -35,7 +37,7 @@ pub enum MetaCommand {
     ModeShow,
     /// `:mode logic|imperative`, `:logic`, `:imperative` — switch modes.
     ModeSet(Mode),
-    /// `:format unicode|latex|ascii|kripke` — logic output format.
+    /// `:format unicode|latex|ascii|kripke|<custom>` — logic output format.
     Format(LogicFormat),
     /// `:readings` — every reading of the last logic sentence.
     Readings,
@@ -102,14 +104,8 @@ pub fn parse_meta(line: &str) -> Option<Result<MetaCommand, String>> {
             Some(other) => Err(format!("unknown mode `{other}` (logic | imperative)")),
         },
         "format" => match arg {
-            Some("unicode") => Ok(MetaCommand::Format(LogicFormat::Unicode)),
-            Some("latex") => Ok(MetaCommand::Format(LogicFormat::Latex)),
-            Some("ascii") => Ok(MetaCommand::Format(LogicFormat::Ascii)),
-            Some("kripke") => Ok(MetaCommand::Format(LogicFormat::Kripke)),
-            Some(other) => Err(format!(
-                "unknown format `{other}` (unicode | latex | ascii | kripke)"
-            )),
-            None => Err(":format needs an argument (unicode | latex | ascii | kripke)".into()),
+            Some(name) => name.parse().map(MetaCommand::Format),
+            None => Err(format!(":format needs an argument ({})", OutputFormat::names().join(" | "))),
         },
         "readings" => need_no_arg(MetaCommand::Readings),
         "discourse" => match arg {
@@ -152,8 +148,9 @@ Commands:
   :explain <word>           teach one construct (keyword, type, or ## block)
   :program                  the accumulated program       [imperative]
   :reset                    clear the current mode's session
-  :load <file>              load a saved program
-  :save <file>              save the session as a runnable program
+  :load <file>              load a saved program or discourse
+  :save <file>              save the session (a runnable program, or the
+                            logic discourse with its referents)
 
 Imperative statements end with a period; blocks (If …:, ## To …:) submit
 on a blank line. Note: each line re-runs the accumulated program, so
@@ -8,7 +8,8 @@
 //!   from program semantics).
 //! - **logic** (`logic>`): English sentences compile to FOL through a
 //!   persistent discourse [`Session`] (cross-sentence anaphora), with
-//!   `:format`, `:readings`, and `:discourse` control.
+//!   `:format`, `:readings`, and `:discourse` control. `:save` writes the
+//!   discourse as a session snapshot and `:load` resumes it.
 //!
 //! On a terminal the loop runs through rustyline (history, completion,
 //! Ctrl-C clears / Ctrl-D exits); on a pipe it degrades to a plain line
@@ -131,7 +132,7 @@ impl ReplState {
             MetaCommand::Readings => match &self.last_sentence {
                 Some(sentence) => {
                     let options =
-                        CompileOptions { format: self.format.into(), pragmatic: false };
+                        CompileOptions { format: self.format.into(), pragmatic: false, ..Default::default() };
                     let readings = collect_readings(sentence, options);
                     if readings.is_empty() {
                         print_error("no readings for the last sentence");
@@ -177,7 +178,7 @@ impl ReplState {
     fn save(&self, path: &Path) {
         let content = match self.mode {
             Mode::Imperative => self.imperative.source(),
-            Mode::Logic => self.logic.history(),
+            Mode::Logic => self.logic.to_json(),
         };
         match std::fs::write(path, content) {
             Ok(()) => anstream::println!("Saved {}", path.display()),
@@ -203,6 +204,16 @@ impl ReplState {
                     print_error(&err);
                 }
             }
+            // A saved discourse resumes with its referents; any other file is
+            // read as sentences, one per line.
+            Mode::Logic if source.trim_start().starts_with('{') => match Session::from_json(&source) {
+                Ok(mut session) => {
+                    session.set_format(self.format.into());
+                    anstream::println!("Restored {} sentences", session.turn_count());
+                    self.logic = session;
+                }
+                Err(e) => print_error(&format!("cannot load {}: {e}", path.display())),
+            },
             Mode::Logic => {
                 for line in source.lines().map(str::trim).filter(|l| !l.is_empty()) {
                     self.eval_logic(line);
@@ -26,7 +26,7 @@ pub const EXIT_OK: i32 = 0;
 /// Process exit code for a command failure (build error, proof failure,
 /// dirty `fmt --check`, parse error, ...).
 pub const EXIT_FAILURE: i32 = 1;
-/// Process exit code for a usage error (bad arguments, reserved verb).
+/// Process exit code for a usage error (bad arguments).
 /// Matches clap's own convention for argument errors.
 pub const EXIT_USAGE: i32 = 2;
 
@@ -109,6 +109,13 @@ pub fn phase(verb: &str, rest: impl fmt::Display) {
     }
 }
 
+/// Print a `warning:` line to stderr unless `--quiet` is active.
+pub fn warn(msg: impl fmt::Display) {
+    if !is_quiet() {
+        anstream::eprintln!("{WARN_STYLE}warning:{WARN_STYLE:#} {msg}");
+    }
+}
+
 /// A user-facing CLI error: a message, an optional `help:` hint, and the
 /// exit code the process should terminate with.
 ///
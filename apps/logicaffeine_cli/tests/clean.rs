@@ -31,13 +31,16 @@ fn clean_is_idempotent() {
     assert_eq!(second.status.code(), Some(0), "{}", stderr(&second));
 }
 
-/// `largo clean --all` also removes the `.logos-native/` bundle cache.
+/// `largo clean --all` also removes the `.logos-native/` bundle cache and
+/// the `.largo/` verification cache.
 #[test]
 fn clean_all_removes_native_bundle_cache() {
     let dir = tempdir().unwrap();
     scaffold(dir.path(), "clean_all");
     std::fs::create_dir_all(dir.path().join(".logos-native")).unwrap();
     std::fs::write(dir.path().join(".logos-native/cached.so"), b"x").unwrap();
+    std::fs::create_dir_all(dir.path().join(".largo/verify-cache")).unwrap();
+    std::fs::write(dir.path().join(".largo/verify-cache/obligations.json"), b"{}").unwrap();
 
     let plain = largo_in(dir.path(), &["clean"]);
     assert_eq!(plain.status.code(), Some(0), "{}", stderr(&plain));
@@ -45,6 +48,7 @@ fn clean_all_removes_native_bundle_cache() {
         dir.path().join(".logos-native").exists(),
         "plain clean must NOT touch .logos-native/"
     );
+    assert!(dir.path().join(".largo").exists(), "plain clean must NOT touch .largo/");
 
     let all = largo_in(dir.path(), &["clean", "--all"]);
     assert_eq!(all.status.code(), Some(0), "{}", stderr(&all));
@@ -52,6 +56,7 @@ fn clean_all_removes_native_bundle_cache() {
         !dir.path().join(".logos-native").exists(),
         "clean --all must remove .logos-native/"
     );
+    assert!(!dir.path().join(".largo").exists(), "clean --all must remove .largo/");
 }
 
 /// Outside a project, `largo clean` fails with the standard project error.
@@ -0,0 +1,107 @@
+//! `largo daemon` — the warm compile server over a Unix socket.
+#![cfg(unix)]
+
+mod common;
+
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::process::{Child, Stdio};
+use std::time::{Duration, Instant};
+
+use common::*;
+use serde_json::{json, Value};
+use tempfile::tempdir;
+
+fn spawn_daemon(socket: &Path) -> Child {
+    let child = largo()
+        .args(["daemon", "--socket"])
+        .arg(socket)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("largo daemon should spawn");
+    let deadline = Instant::now() + Duration::from_secs(60);
+    while UnixStream::connect(socket).is_err() {
+        assert!(Instant::now() < deadline, "daemon never listened on {}", socket.display());
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    child
+}
+
+/// Send one request line on `stream` and read its reply line.
+fn call(stream: &mut UnixStream, request: &str) -> Value {
+    writeln!(stream, "{request}").unwrap();
+    let mut line = String::new();
+    BufReader::new(stream.try_clone().unwrap()).read_line(&mut line).unwrap();
+    serde_json::from_str(&line).unwrap_or_else(|e| panic!("bad reply {line:?}: {e}"))
+}
+
+/// One connection carries a ping, a compile, and checks of a good and a
+/// broken project; `--stop` then shuts the daemon down and removes its socket.
+#[test]
+fn daemon_serves_requests_until_stopped() {
+    let dir = tempdir().unwrap();
+    let socket = dir.path().join("largo.sock");
+    let good = dir.path().join("good");
+    scaffold(&good, "daemon_good");
+    let bad = dir.path().join("bad");
+    scaffold(&bad, "daemon_bad");
+    std::fs::write(bad.join("src/main.lg"), "## Main\n\nLet be.\n").unwrap();
+
+    let mut daemon = spawn_daemon(&socket);
+    let mut stream = UnixStream::connect(&socket).unwrap();
+
+    let ping = call(&mut stream, r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#);
+    assert_eq!(ping["result"]["version"], env!("CARGO_PKG_VERSION"), "{ping}");
+
+    let compiled = call(
+        &mut stream,
+        &json!({
+            "jsonrpc": "2.0", "id": 2, "method": "compile",
+            "params": { "source": "## Main\nShow 7.\n" },
+        })
+        .to_string(),
+    );
+    assert!(compiled["result"]["rust"].as_str().unwrap().contains("fn main"), "{compiled}");
+
+    let checked = call(
+        &mut stream,
+        &json!({"jsonrpc":"2.0","id":3,"method":"check","params":{"project":good}})
+            .to_string(),
+    );
+    assert_eq!(checked["result"]["ok"], true, "{checked}");
+
+    let failed = call(
+        &mut stream,
+        &json!({"jsonrpc":"2.0","id":4,"method":"check","params":{"project":bad}})
+            .to_string(),
+    );
+    assert_eq!(failed["error"]["code"], -32000, "{failed}");
+    let message = failed["error"]["message"].as_str().unwrap();
+    assert!(message.contains("Let be."), "the caret excerpt quotes the line: {failed}");
+    assert!(!message.contains('\x1b'), "no terminal colors in JSON: {failed}");
+    drop(stream);
+
+    let status = largo().args(["daemon", "--status", "--socket"]).arg(&socket).output().unwrap();
+    assert_eq!(status.status.code(), Some(0), "{}", stderr(&status));
+
+    let stop = largo().args(["daemon", "--stop", "--socket"]).arg(&socket).output().unwrap();
+    assert_eq!(stop.status.code(), Some(0), "{}", stderr(&stop));
+    let deadline = Instant::now() + Duration::from_secs(30);
+    while daemon.try_wait().unwrap().is_none() {
+        assert!(Instant::now() < deadline, "daemon did not exit after --stop");
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    assert!(!socket.exists(), "the daemon removes its socket on exit");
+}
+
+/// `--status` with nothing listening fails with a hint to start one.
+#[test]
+fn status_without_a_daemon_fails() {
+    let dir = tempdir().unwrap();
+    let socket = dir.path().join("nobody.sock");
+    let out = largo().args(["daemon", "--status", "--socket"]).arg(&socket).output().unwrap();
+    assert_ne!(out.status.code(), Some(0));
+    assert!(stderr(&out).contains("largo daemon"), "{}", stderr(&out));
+}
@@ -0,0 +1,102 @@
+//! `largo test [FILTER]` — run the fenced `logos` examples in documentation
+//! prose and check them against their `produces:` annotations.
+
+mod common;
+
+use common::*;
+use tempfile::tempdir;
+
+const DOCUMENTED: &str = "# Doubling\n\n\
+## To double (n: Int) -> Int:\n    Return n * 2.\n\n\
+Doubling twenty-one:\n\n```logos\nShow double(21).\n```\nproduces: 42\n\n\
+## Main\n\n    Show double(1).\n";
+
+fn scaffold_documented(dir: &std::path::Path, main: &str) {
+    scaffold(dir, "doc_tests");
+    std::fs::write(dir.join("src/main.lg"), main).unwrap();
+}
+
+/// A passing example is reported `ok` and the run exits 0.
+#[test]
+fn passing_examples_succeed() {
+    let dir = tempdir().unwrap();
+    scaffold_documented(dir.path(), DOCUMENTED);
+    std::fs::write(
+        dir.path().join("README.md"),
+        "# Usage\n\n```logos\nLet total be 2 + 3.\nShow total.\n```\n\nproduces:\n\n```\n5\n```\n",
+    )
+    .unwrap();
+
+    let out = largo_in(dir.path(), &["test"]);
+    assert_eq!(out.status.code(), Some(0), "test: {}", stderr(&out));
+    let printed = strip_ansi(&stdout(&out));
+    assert!(printed.contains("running 2 doc tests"), "{printed}");
+    assert!(printed.contains("test README.md:3 ... ok"), "{printed}");
+    assert!(printed.contains("test src/main.lg:8 ... ok"), "{printed}");
+    assert!(printed.contains("test result: ok. 2 passed; 0 failed"), "{printed}");
+}
+
+/// A wrong `produces:` fails the run, naming the example and both outputs.
+#[test]
+fn mismatched_output_fails() {
+    let dir = tempdir().unwrap();
+    scaffold_documented(dir.path(), &DOCUMENTED.replace("produces: 42", "produces: 41"));
+
+    let out = largo_in(dir.path(), &["test"]);
+    assert_eq!(out.status.code(), Some(1));
+    let err = strip_ansi(&stderr(&out));
+    assert!(err.contains("---- src/main.lg:8 ----"), "{err}");
+    assert!(err.contains("expected:\n41\nprinted:\n42"), "{err}");
+    assert!(err.contains("test result: FAILED. 0 passed; 1 failed"), "{err}");
+}
+
+/// The filter selects examples by `file:line`.
+#[test]
+fn filter_selects_examples() {
+    let dir = tempdir().unwrap();
+    scaffold_documented(dir.path(), DOCUMENTED);
+    std::fs::write(dir.path().join("NOTES.md"), "```logos\nShow 1 / 0.\n```\n").unwrap();
+
+    let out = largo_in(dir.path(), &["test", "main.lg"]);
+    assert_eq!(out.status.code(), Some(0), "test: {}", stderr(&out));
+    assert!(strip_ansi(&stdout(&out)).contains("running 1 doc test\n"), "{}", stdout(&out));
+}
+
+/// `--coverage` folds the examples' runs onto the documented file: the
+/// branch no example takes is reported, annotated and exported to lcov.
+#[test]
+fn coverage_reports_the_untaken_branch() {
+    let dir = tempdir().unwrap();
+    scaffold_documented(
+        dir.path(),
+        "## To clamp (n: Int) -> Int:\n    If n > 10:\n        Return 10.\n    Return n.\n\n\
+```logos\nShow clamp(3).\n```\nproduces: 3\n\n## Main\n    Show clamp(1).\n",
+    );
+
+    let out = largo_in(dir.path(), &["test", "--coverage"]);
+    assert_eq!(out.status.code(), Some(0), "test: {}", stderr(&out));
+    let printed = strip_ansi(&stdout(&out));
+    assert!(printed.contains("coverage: src/main.lg 2/3 lines (66%); never ran: 3"), "{printed}");
+
+    let annotated = std::fs::read_to_string(dir.path().join("target/coverage/src/main.lg.cov")).unwrap();
+    assert!(annotated.contains("    #####:    3:         Return 10."), "{annotated}");
+    let lcov = std::fs::read_to_string(dir.path().join("target/coverage/lcov.info")).unwrap();
+    assert!(lcov.contains("SF:src/main.lg\nDA:2,1\nDA:3,0\nDA:4,1\nLH:2\nLF:3\n"), "{lcov}");
+}
+
+/// With `--differential` the compiled binaries count their statements too,
+/// and the lcov counts are the sum of both tiers.
+#[test]
+fn coverage_adds_the_compiled_runs() {
+    let dir = tempdir().unwrap();
+    scaffold_documented(
+        dir.path(),
+        "## To clamp (n: Int) -> Int:\n    If n > 10:\n        Return 10.\n    Return n.\n\n\
+```logos\nShow clamp(3).\n```\nproduces: 3\n\n## Main\n    Show clamp(1).\n",
+    );
+
+    let out = largo_in(dir.path(), &["test", "--coverage", "--differential"]);
+    assert_eq!(out.status.code(), Some(0), "test: {}", stderr(&out));
+    let lcov = std::fs::read_to_string(dir.path().join("target/coverage/lcov.info")).unwrap();
+    assert!(lcov.contains("SF:src/main.lg\nDA:2,2\nDA:3,0\nDA:4,2\nLH:2\nLF:3\n"), "{lcov}");
+}
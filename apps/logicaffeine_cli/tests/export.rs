@@ -0,0 +1,111 @@
+//! `largo export --prover <lean4|coq> [FILE] [-o PATH]` — English theorems
+//! written out for a proof assistant, predicates axiomatized and proofs open;
+//! and `largo check` re-checking the ones finished there under `proofs/`.
+
+mod common;
+
+use common::*;
+use tempfile::tempdir;
+
+const SOCRATES: &str = "\
+## Theorem: Socrates
+Given: Socrates is a man.
+Given: Every man is mortal.
+Prove: Socrates is mortal.
+Proof: Auto.
+";
+
+fn write_file(dir: &std::path::Path, name: &str, content: &str) -> std::path::PathBuf {
+    let path = dir.join(name);
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn lean4_file_on_stdout() {
+    let dir = tempdir().unwrap();
+    let file = write_file(dir.path(), "socrates.lg", SOCRATES);
+    let out = largo_in(dir.path(), &["export", "--prover", "lean4", file.to_str().unwrap()]);
+    assert_eq!(out.status.code(), Some(0), "export: {}", stderr(&out));
+    let text = stdout(&out);
+    assert!(text.contains("namespace Logos"), "{text}");
+    assert!(text.contains("axiom Entity : Type"), "{text}");
+    assert!(text.contains("axiom Socrates : Entity"), "{text}");
+    assert!(text.contains("theorem Socrates (h1 : "), "{text}");
+    assert!(text.contains("sorry"), "{text}");
+}
+
+#[test]
+fn coq_file_written_with_output() {
+    let dir = tempdir().unwrap();
+    let file = write_file(dir.path(), "socrates.lg", SOCRATES);
+    let target = dir.path().join("Socrates.v");
+    let out = largo_in(
+        dir.path(),
+        &["export", "--prover", "coq", file.to_str().unwrap(), "-o", target.to_str().unwrap()],
+    );
+    assert_eq!(out.status.code(), Some(0), "export: {}", stderr(&out));
+    let written = std::fs::read_to_string(&target).unwrap();
+    assert!(written.contains("Module Logos."), "{written}");
+    assert!(written.contains("Theorem Socrates"), "{written}");
+    assert!(written.contains("Admitted."), "{written}");
+}
+
+#[test]
+fn nothing_to_export_is_an_error() {
+    let dir = tempdir().unwrap();
+    let file = write_file(dir.path(), "main.lg", "## Main\nShow 1.\n");
+    let out = largo_in(dir.path(), &["export", "--prover", "lean4", file.to_str().unwrap()]);
+    assert_eq!(out.status.code(), Some(1));
+    assert!(strip_ansi(&stderr(&out)).contains("no theorems"), "{}", stderr(&out));
+}
+
+#[test]
+fn unknown_prover_is_refused() {
+    let dir = tempdir().unwrap();
+    let file = write_file(dir.path(), "socrates.lg", SOCRATES);
+    let out = largo_in(dir.path(), &["export", "--prover", "isabelle", file.to_str().unwrap()]);
+    assert_eq!(out.status.code(), Some(2), "clap rejects the value");
+    let err = strip_ansi(&stderr(&out));
+    assert!(err.contains("lean4") && err.contains("coq"), "lists the choices:\n{err}");
+}
+
+/// A project whose entry file states SOCRATES, exported to `proofs/`.
+fn exported_project(dir: &std::path::Path) -> std::path::PathBuf {
+    scaffold(dir, "socrates");
+    std::fs::write(dir.join("src/main.lg"), format!("# Main\n\n{SOCRATES}\n## Main\n\nShow 1.\n")).unwrap();
+    std::fs::create_dir(dir.join("proofs")).unwrap();
+    let target = dir.join("proofs/Socrates.lean");
+    let out = largo_in(dir, &["export", "--prover", "lean4", "-o", target.to_str().unwrap()]);
+    assert_eq!(out.status.code(), Some(0), "export: {}", stderr(&out));
+    target
+}
+
+#[test]
+fn check_discharges_a_finished_proof() {
+    let dir = tempdir().unwrap();
+    let target = exported_project(dir.path());
+    let exported = std::fs::read_to_string(&target).unwrap();
+    std::fs::write(&target, exported.replace("by\n  sorry", "h2 Socrates h1")).unwrap();
+
+    let out = largo_in(dir.path(), &["check"]);
+    assert_eq!(out.status.code(), Some(0), "check: {}", stderr(&out));
+    let all = strip_ansi(&format!("{}{}", stdout(&out), stderr(&out)));
+    assert!(all.contains("Theorem Socrates discharged by proofs/Socrates.lean"), "{all}");
+}
+
+#[test]
+fn check_rejects_a_wrong_proof_and_tolerates_an_open_one() {
+    let dir = tempdir().unwrap();
+    let target = exported_project(dir.path());
+    let out = largo_in(dir.path(), &["check"]);
+    assert_eq!(out.status.code(), Some(0), "an open theorem is not a failure: {}", stderr(&out));
+
+    let exported = std::fs::read_to_string(&target).unwrap();
+    std::fs::write(&target, exported.replace("by\n  sorry", "h1")).unwrap();
+    let out = largo_in(dir.path(), &["check"]);
+    assert_eq!(out.status.code(), Some(1));
+    let err = strip_ansi(&stderr(&out));
+    assert!(err.contains("theorem Socrates:"), "{err}");
+    assert!(err.contains("1 imported proof(s) failed to check"), "{err}");
+}
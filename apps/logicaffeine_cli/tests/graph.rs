@@ -0,0 +1,63 @@
+//! `largo graph [--format dot|mermaid]` — the project's module dependency graph.
+
+mod common;
+
+use common::*;
+use tempfile::tempdir;
+
+/// A project whose entry imports `geo.lg`, which imports `units.lg` — also
+/// imported by the entry directly.
+fn scaffold_modules(dir: &std::path::Path) {
+    scaffold(dir, "graph_demo");
+    std::fs::write(
+        dir.join("src/main.lg"),
+        "# Main\n\nUses [Geometry](geo.lg) and [Units](units.lg).\n\n## Main\n\nShow 1.\n",
+    )
+    .unwrap();
+    std::fs::write(dir.join("src/geo.lg"), "# Geo\n\nBuilt on [Units](units.lg).\n").unwrap();
+    std::fs::write(dir.join("src/units.lg"), "# Units\n\nNo imports.\n").unwrap();
+}
+
+/// The default is DOT: one node per module, one labeled edge per import.
+#[test]
+fn graph_prints_dot_by_default() {
+    let dir = tempdir().unwrap();
+    scaffold_modules(dir.path());
+
+    let out = largo_in(dir.path(), &["graph"]);
+    assert_eq!(out.status.code(), Some(0), "graph: {}", stderr(&out));
+    let dot = stdout(&out);
+    assert!(dot.starts_with("digraph modules {"), "{dot}");
+    for line in [
+        "n0 [label=\"main.lg\"];",
+        "n1 [label=\"geo.lg\"];",
+        "n2 [label=\"units.lg\"];",
+        "n0 -> n1 [label=\"Geometry\"];",
+        "n1 -> n2 [label=\"Units\"];",
+        "n0 -> n2 [label=\"Units\"];",
+    ] {
+        assert!(dot.contains(line), "missing {line}: {dot}");
+    }
+}
+
+/// `--format mermaid` prints the same graph as a flowchart.
+#[test]
+fn graph_prints_mermaid() {
+    let dir = tempdir().unwrap();
+    scaffold_modules(dir.path());
+
+    let out = largo_in(dir.path(), &["graph", "--format", "mermaid"]);
+    assert_eq!(out.status.code(), Some(0), "graph: {}", stderr(&out));
+    let mermaid = stdout(&out);
+    assert!(mermaid.starts_with("graph LR\n"), "{mermaid}");
+    assert!(mermaid.contains("n0 -->|\"Geometry\"| n1"), "{mermaid}");
+}
+
+/// Outside a project, `graph` fails with the standard hint.
+#[test]
+fn graph_outside_project_fails() {
+    let dir = tempdir().unwrap();
+    let out = largo_in(dir.path(), &["graph"]);
+    assert_eq!(out.status.code(), Some(1));
+    assert!(stderr(&out).contains("Largo.toml"), "{}", stderr(&out));
+}
@@ -0,0 +1,32 @@
+//! `largo license` — activation of the verification license.
+//!
+//! The activation flows themselves run only in `verification` builds (they
+//! are unit-tested in `logicaffeine_verify::license`); here the default
+//! build must point at the full one, and the CLI surface must hold.
+
+mod common;
+
+use common::*;
+use tempfile::tempdir;
+
+#[cfg(not(feature = "verification"))]
+#[test]
+fn license_commands_explain_how_to_get_verification() {
+    let dir = tempdir().unwrap();
+    for args in [&["license", "status"][..], &["license", "activate", "sub_x"][..]] {
+        let out = largo_in(dir.path(), args);
+        assert_eq!(out.status.code(), Some(1), "{args:?}");
+        let err = strip_ansi(&stderr(&out));
+        assert!(err.contains("does not include Z3 verification"), "{err}");
+        assert!(err.contains("--full"), "{err}");
+    }
+}
+
+/// A key and `--file` are two different activation paths.
+#[test]
+fn license_activate_takes_a_key_or_a_file() {
+    let dir = tempdir().unwrap();
+    let out = largo_in(dir.path(), &["license", "activate", "sub_x", "--file", "license.json"]);
+    assert_eq!(out.status.code(), Some(2));
+    assert!(stderr(&out).contains("cannot be used with"), "{}", stderr(&out));
+}
@@ -0,0 +1,51 @@
+//! `largo run --log-level` — `Log` events go to stderr, filtered by level,
+//! and never into the program's stdout.
+
+mod common;
+
+use std::path::Path;
+
+use common::*;
+use tempfile::tempdir;
+
+const PROGRAM: &str = "# Main\n\n## Main\n\nLet id be 42.\nLog info \"user joined\" with id.\nLog debug \"cache warmed\".\nShow \"done\".\n";
+
+fn logging_project(dir: &Path) {
+    scaffold(dir, "logs");
+    std::fs::write(dir.join("src/main.lg"), PROGRAM).unwrap();
+}
+
+fn run(dir: &Path, args: &[&str]) -> std::process::Output {
+    largo().args(args).env_remove("LOGOS_LOG").current_dir(dir).output().expect("largo should spawn")
+}
+
+#[test]
+fn info_is_the_default_level() {
+    let dir = tempdir().unwrap();
+    logging_project(dir.path());
+
+    let out = run(dir.path(), &["run", "--interpret"]);
+    assert_eq!(out.status.code(), Some(0), "{}", stderr(&out));
+    assert_eq!(stdout(&out), "done\n");
+    let err = stderr(&out);
+    assert!(err.contains("INFO") && err.contains("user joined id=42"), "{err}");
+    assert!(!err.contains("cache warmed"), "debug shown at the default level: {err}");
+}
+
+#[test]
+fn log_level_picks_what_is_printed() {
+    let dir = tempdir().unwrap();
+    logging_project(dir.path());
+
+    let debug = run(dir.path(), &["run", "--interpret", "--log-level", "debug"]);
+    assert_eq!(debug.status.code(), Some(0), "{}", stderr(&debug));
+    assert!(stderr(&debug).contains("cache warmed"), "{}", stderr(&debug));
+
+    let off = run(dir.path(), &["run", "--interpret", "--log-level", "off"]);
+    assert_eq!(off.status.code(), Some(0), "{}", stderr(&off));
+    assert_eq!(stdout(&off), "done\n");
+    assert!(!stderr(&off).contains("user joined"), "{}", stderr(&off));
+
+    let bogus = run(dir.path(), &["run", "--interpret", "--log-level", "loud"]);
+    assert_ne!(bogus.status.code(), Some(0));
+}
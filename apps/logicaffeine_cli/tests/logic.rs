@@ -17,7 +17,7 @@ const SENTENCE: &str = "Every cat sleeps.";
 fn oracle(format: OutputFormat) -> String {
     logicaffeine_language::compile::compile_with_options(
         SENTENCE,
-        CompileOptions { format, pragmatic: false },
+        CompileOptions { format, pragmatic: false, ..Default::default() },
     )
     .expect("oracle sentence must compile")
 }
@@ -106,7 +106,7 @@ fn empty_input_is_usage_error() {
 #[test]
 fn all_readings_match_library() {
     let ambiguous = "Every woman loves a man.";
-    let opts = CompileOptions { format: OutputFormat::Unicode, pragmatic: false };
+    let opts = CompileOptions { format: OutputFormat::Unicode, pragmatic: false, ..Default::default() };
     let mut expected: Vec<String> = Vec::new();
     for r in logicaffeine_language::compile::compile_all_scopes_with_options(ambiguous, opts)
         .unwrap_or_default()
@@ -200,3 +200,29 @@ fn discourse_conflicts_with_all_readings() {
         .unwrap();
     assert_eq!(out.status.code(), Some(2));
 }
+
+/// An unknown `--format` is a usage error that lists the formats there are.
+#[test]
+fn unknown_format_lists_the_choices() {
+    let out = largo()
+        .args(["logic", SENTENCE, "--format", "klingon"])
+        .output()
+        .unwrap();
+    assert_eq!(out.status.code(), Some(2));
+    let err = stderr(&out);
+    assert!(err.contains("unknown format `klingon`") && err.contains("kripke"), "{err}");
+}
+
+/// `--tree` prints the library's syntax tree as a diagram, not the formula.
+#[test]
+fn tree_prints_the_syntax_tree_diagram() {
+    let ast = logicaffeine_compile::compile_for_ui(SENTENCE).ast.expect("oracle sentence has a tree");
+    for (flag, expected) in [
+        ("dot", logicaffeine_compile::diagram::ast_to_dot(&ast)),
+        ("mermaid", logicaffeine_compile::diagram::ast_to_mermaid(&ast)),
+    ] {
+        let out = largo().args(["logic", SENTENCE, "--tree", flag]).output().unwrap();
+        assert_eq!(out.status.code(), Some(0), "--tree {flag}: {}", stderr(&out));
+        assert_eq!(stdout(&out), expected, "--tree {flag}");
+    }
+}
@@ -0,0 +1,40 @@
+//! `largo mutate` — mutants of the entry are run against `## Main` and the
+//! doc tests; survivors fail the run with a mutation score.
+
+mod common;
+
+use common::*;
+use tempfile::tempdir;
+
+fn scaffold_program(dir: &std::path::Path, main: &str) {
+    scaffold(dir, "mutate_demo");
+    std::fs::write(dir.join("src/main.lg"), main).unwrap();
+}
+
+/// Every mutant changes what `## Main` prints: the run passes at 100%.
+#[test]
+fn fully_pinned_program_scores_100() {
+    let dir = tempdir().unwrap();
+    scaffold_program(dir.path(), "## Main\n    Let total be 2 + 3.\n    Show total.\n");
+
+    let out = largo_in(dir.path(), &["mutate"]);
+    assert_eq!(out.status.code(), Some(0), "mutate: {}", stderr(&out));
+    let printed = strip_ansi(&stdout(&out));
+    assert!(printed.contains("caught   src/main.lg:2 `2` → `3`"), "{printed}");
+    assert!(printed.contains("mutation score: 100% — 4 caught, 0 survived, 0 unviable"), "{printed}");
+}
+
+/// A boundary nothing exercises survives and fails the run.
+#[test]
+fn untested_boundary_survives() {
+    let dir = tempdir().unwrap();
+    scaffold_program(
+        dir.path(),
+        "## To clamp (n: Int) -> Int:\n    If n > 10:\n        Return 10.\n    Return n.\n\n## Main\n    Show clamp(3).\n",
+    );
+
+    let out = largo_in(dir.path(), &["mutate"]);
+    assert_eq!(out.status.code(), Some(1));
+    assert!(strip_ansi(&stdout(&out)).contains("MISSED   src/main.lg:2 `>` → `>=`"), "{}", stdout(&out));
+    assert!(strip_ansi(&stderr(&out)).contains("survived"), "{}", stderr(&out));
+}
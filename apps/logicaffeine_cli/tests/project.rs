@@ -104,6 +104,7 @@ Let x be 42.
         release: false,
         lib_mode: false,
         target: None,
+        profile: false,
     };
 
     let result = build(config);
@@ -146,6 +147,7 @@ Let x be 1.
         release: false,
         lib_mode: false,
         target: None,
+        profile: false,
     };
 
     let result = build(config);
@@ -175,6 +177,7 @@ name = "empty"
         release: false,
         lib_mode: false,
         target: None,
+        profile: false,
     };
 
     let result = build(config);
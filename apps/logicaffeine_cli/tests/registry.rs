@@ -0,0 +1,167 @@
+//! `largo yank` / `largo owner` / team tokens — package lifecycle against a
+//! stub registry that answers one canned response per connection and hands
+//! back the request it received.
+
+mod common;
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::Path;
+use std::process::Command;
+use std::thread::JoinHandle;
+
+use common::*;
+use tempfile::tempdir;
+
+/// Serve one request with `status` and a JSON `body`; the handle yields the
+/// raw request (request line, headers, body).
+fn stub_registry(status: &str, body: &'static str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let status = status.to_string();
+    let handle = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut request = String::new();
+        let mut length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                length = value.trim().parse().unwrap();
+            }
+            request.push_str(&line);
+            if line == "\r\n" {
+                break;
+            }
+        }
+        let mut content = vec![0; length];
+        reader.read_exact(&mut content).unwrap();
+        request.push_str(&String::from_utf8_lossy(&content));
+        let reply = format!(
+            "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        reader.get_mut().write_all(reply.as_bytes()).unwrap();
+        request
+    });
+    (url, handle)
+}
+
+/// `largo` with credentials isolated to `dir` and no ambient token.
+fn largo_with_credentials(dir: &Path) -> Command {
+    let mut cmd = largo();
+    cmd.env("LOGOS_CREDENTIALS_PATH", dir.join("credentials.toml"))
+        .env_remove("LOGOS_TOKEN")
+        .current_dir(dir);
+    cmd
+}
+
+/// `largo yank name@version` sends an authenticated DELETE for that version,
+/// and `--undo` the matching PUT.
+#[test]
+fn yank_and_undo_target_the_version() {
+    let dir = tempdir().unwrap();
+    for (flags, expected) in [
+        (&[][..], "DELETE /packages/geometry/1.2.0/yank "),
+        (&["--undo"][..], "PUT /packages/geometry/1.2.0/unyank "),
+    ] {
+        let (url, server) = stub_registry("200 OK", r#"{"success":true}"#);
+        let out = largo_with_credentials(dir.path())
+            .args(["yank", "geometry@1.2.0", "--registry", &url])
+            .args(flags)
+            .env("LOGOS_TOKEN", "lgr_personal")
+            .output()
+            .unwrap();
+        assert_eq!(out.status.code(), Some(0), "{}", stderr(&out));
+        let request = server.join().unwrap();
+        assert!(request.starts_with(expected), "{request}");
+        assert!(request.contains("Bearer lgr_personal"), "{request}");
+    }
+}
+
+/// `--team` picks the team's stored token over a personal `LOGOS_TOKEN`,
+/// and team owners travel as `team:<name>`.
+#[test]
+fn owner_add_acts_with_the_team_token() {
+    let dir = tempdir().unwrap();
+    let (url, server) = stub_registry("200 OK", r#"{"success":true}"#);
+    std::fs::write(
+        dir.path().join("credentials.toml"),
+        format!("[registries]\n\"{url}\" = \"lgr_personal\"\n\n[teams.\"{url}\"]\ncore = \"lgr_team\"\n"),
+    )
+    .unwrap();
+    let out = largo_with_credentials(dir.path())
+        .args(["owner", "add", "alice", "team:core", "--package", "geometry"])
+        .args(["--registry", &url, "--team", "core"])
+        .env("LOGOS_TOKEN", "lgr_personal")
+        .output()
+        .unwrap();
+    assert_eq!(out.status.code(), Some(0), "{}", stderr(&out));
+    let request = server.join().unwrap();
+    assert!(request.starts_with("PUT /packages/geometry/owners "), "{request}");
+    assert!(request.contains("Bearer lgr_team"), "{request}");
+    assert!(request.contains(r#""owners":["alice","team:core"]"#), "{request}");
+}
+
+/// A 403 surfaces the registry's message and says who can grant access.
+#[test]
+fn forbidden_yank_explains_how_to_get_access() {
+    let dir = tempdir().unwrap();
+    let (url, server) = stub_registry(
+        "403 Forbidden",
+        r#"{"error":"Permission denied","message":"You do not own this package"}"#,
+    );
+    let out = largo_with_credentials(dir.path())
+        .args(["yank", "geometry@1.2.0", "--registry", &url])
+        .env("LOGOS_TOKEN", "lgr_personal")
+        .output()
+        .unwrap();
+    server.join().unwrap();
+    assert_eq!(out.status.code(), Some(1));
+    let err = strip_ansi(&stderr(&out));
+    assert!(err.contains("You do not own this package"), "{err}");
+    assert!(err.contains("largo owner add <your-login> --package geometry"), "{err}");
+}
+
+/// A 404 from a route the registry does not serve is not reported as a
+/// missing package.
+#[test]
+fn unserved_route_is_not_a_missing_package() {
+    let dir = tempdir().unwrap();
+    let (url, server) = stub_registry("404 Not Found", r#"{"error":"Not found"}"#);
+    let out = largo_with_credentials(dir.path())
+        .args(["owner", "list", "--package", "geometry", "--registry", &url])
+        .env("LOGOS_TOKEN", "lgr_personal")
+        .output()
+        .unwrap();
+    server.join().unwrap();
+    assert_eq!(out.status.code(), Some(1));
+    let err = strip_ansi(&stderr(&out));
+    assert!(!err.contains("was not found on the registry"), "{err}");
+    assert!(err.contains("does not support this request"), "{err}");
+}
+
+/// A team with no stored token fails before any request, naming the fix.
+#[test]
+fn missing_team_token_points_at_login() {
+    let dir = tempdir().unwrap();
+    let out = largo_with_credentials(dir.path())
+        .args(["owner", "list", "--package", "geometry", "--team", "core"])
+        .args(["--registry", "http://127.0.0.1:1"])
+        .output()
+        .unwrap();
+    assert_eq!(out.status.code(), Some(1));
+    let err = strip_ansi(&stderr(&out));
+    assert!(err.contains("no token for team 'core'"), "{err}");
+    assert!(err.contains("largo login --team core"), "{err}");
+}
+
+/// Malformed owners are a usage error, caught locally.
+#[test]
+fn invalid_owner_is_a_usage_error() {
+    let dir = tempdir().unwrap();
+    let out = largo_in(dir.path(), &["owner", "add", "not a login", "--package", "geometry"]);
+    assert_eq!(out.status.code(), Some(2));
+    assert!(strip_ansi(&stderr(&out)).contains("invalid owner 'not a login'"), "{}", stderr(&out));
+}
@@ -143,6 +143,25 @@ fn save_writes_runnable_program() {
     assert!(stdout(&run).lines().any(|l| l.trim() == "8"), "{}", stdout(&run));
 }
 
+/// In logic mode `:save` writes the discourse, and `:load` in a fresh REPL
+/// resumes it: a pronoun resolves exactly as it would have without the
+/// restart.
+#[test]
+fn saved_discourse_resumes_after_restart() {
+    let dir = tempdir().unwrap();
+    let saved = dir.path().join("discourse.json");
+    let first = repl(&format!("The boys lifted the piano.\n:save {}\n:quit\n", saved.display()), &["--logic"]);
+    assert_eq!(first.status.code(), Some(0), "repl: {}", stderr(&first));
+
+    let resumed = repl(&format!(":load {}\nThey smiled.\n:quit\n", saved.display()), &["--logic"]);
+    assert_eq!(resumed.status.code(), Some(0), "repl: {}", stderr(&resumed));
+    assert!(stdout(&resumed).contains("Restored 1 sentences"), "{}", stdout(&resumed));
+
+    let uninterrupted = repl("The boys lifted the piano.\nThey smiled.\n:quit\n", &["--logic"]);
+    let smiled = |text: String| text.lines().last().unwrap_or_default().to_string();
+    assert_eq!(smiled(stdout(&resumed)), smiled(stdout(&uninterrupted)));
+}
+
 /// `:vars` shows the bindings table.
 #[test]
 fn vars_shows_bindings() {
@@ -0,0 +1,112 @@
+//! `largo new --template` / `largo init --template` — project templates.
+
+mod common;
+
+use std::path::Path;
+
+use common::*;
+use tempfile::tempdir;
+
+const BUILTINS: [&str; 5] = ["cli", "web-service", "library", "verification-spec", "learning-content"];
+
+/// `largo new` with an empty local-template directory, so a developer's own
+/// templates never leak into the built-in checks.
+fn new_from(dir: &Path, name: &str, template: &str) {
+    let out = largo()
+        .args(["new", name, "--template", template])
+        .env("LOGOS_TEMPLATES_PATH", dir.join("no-local-templates"))
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert_eq!(out.status.code(), Some(0), "new --template {template}: {}", stderr(&out));
+}
+
+/// Every built-in template scaffolds a project that checks, ships a CI
+/// workflow, and — where it has a golden test — prints exactly its
+/// `tests/expected_output.txt`.
+#[test]
+fn every_builtin_template_checks_and_matches_its_golden_output() {
+    for template in BUILTINS {
+        let dir = tempdir().unwrap();
+        new_from(dir.path(), "acme", template);
+        let project = dir.path().join("acme");
+        assert!(project.join("Largo.toml").exists(), "{template}");
+        assert!(project.join(".gitignore").exists(), "{template}");
+        assert!(project.join(".github/workflows/ci.yml").exists(), "{template}");
+
+        let check = largo_in(&project, &["check"]);
+        assert_eq!(check.status.code(), Some(0), "{template} check: {}", stderr(&check));
+
+        let golden = project.join("tests/expected_output.txt");
+        if golden.exists() {
+            let run = largo_in(&project, &["run", "--interpret"]);
+            assert_eq!(run.status.code(), Some(0), "{template} run: {}", stderr(&run));
+            assert_eq!(stdout(&run), std::fs::read_to_string(golden).unwrap(), "{template}");
+        }
+    }
+}
+
+/// The verification template's theorems prove.
+#[test]
+fn verification_spec_theorems_prove() {
+    let dir = tempdir().unwrap();
+    new_from(dir.path(), "spec", "verification-spec");
+    let out = largo_in(&dir.path().join("spec"), &["prove"]);
+    assert_eq!(out.status.code(), Some(0), "{}", stderr(&out));
+}
+
+/// A directory under `$LOGOS_TEMPLATES_PATH` is a template: its files are
+/// copied with `{{name}}` rendered, and the standard manifest fills the gap.
+#[test]
+fn local_templates_extend_the_registry() {
+    let dir = tempdir().unwrap();
+    let local = dir.path().join("templates/house-style/src");
+    std::fs::create_dir_all(&local).unwrap();
+    std::fs::write(local.join("main.lg"), "# {{name}}\n\n## Main\nShow \"{{name}} v1\".\n").unwrap();
+
+    let listed = largo()
+        .args(["new", "--list-templates"])
+        .env("LOGOS_TEMPLATES_PATH", dir.path().join("templates"))
+        .output()
+        .unwrap();
+    assert!(stdout(&listed).contains("house-style"), "{}", stdout(&listed));
+    assert!(stdout(&listed).contains("verification-spec"), "{}", stdout(&listed));
+
+    let out = largo()
+        .args(["new", "shop", "--template", "house-style"])
+        .env("LOGOS_TEMPLATES_PATH", dir.path().join("templates"))
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert_eq!(out.status.code(), Some(0), "{}", stderr(&out));
+    let project = dir.path().join("shop");
+    let main = std::fs::read_to_string(project.join("src/main.lg")).unwrap();
+    assert!(main.contains("Show \"shop v1\"."), "{main}");
+    let manifest = std::fs::read_to_string(project.join("Largo.toml")).unwrap();
+    assert!(manifest.contains("name = \"shop\""), "{manifest}");
+}
+
+/// An unknown template fails before creating anything, and says what exists.
+#[test]
+fn unknown_template_lists_the_available_ones() {
+    let dir = tempdir().unwrap();
+    let out = largo_in(dir.path(), &["new", "x", "--template", "mainframe"]);
+    assert_eq!(out.status.code(), Some(1));
+    let err = strip_ansi(&stderr(&out));
+    assert!(err.contains("unknown template 'mainframe'"), "{err}");
+    assert!(err.contains("learning-content"), "{err}");
+    assert!(!dir.path().join("x").exists());
+}
+
+/// `largo init --template` adds the template around existing work without
+/// overwriting it.
+#[test]
+fn init_from_a_template_keeps_existing_files() {
+    let dir = tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("src")).unwrap();
+    std::fs::write(dir.path().join("src/main.lg"), "## Main\nShow 1.\n").unwrap();
+    let out = largo_in(dir.path(), &["init", "--name", "kept", "--template", "library"]);
+    assert_eq!(out.status.code(), Some(0), "{}", stderr(&out));
+    assert_eq!(std::fs::read_to_string(dir.path().join("src/main.lg")).unwrap(), "## Main\nShow 1.\n");
+    assert!(dir.path().join(".github/workflows/ci.yml").exists());
+}
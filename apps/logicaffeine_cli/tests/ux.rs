@@ -1,5 +1,5 @@
 //! UX substrate suite: help quality, global flags, color discipline,
-//! exit codes, and the reserved `test` verb.
+//! and exit codes.
 //!
 //! These tests define the CLI's polish contract:
 //! - bare `largo` gives a full command overview (exit 2)
@@ -8,7 +8,6 @@
 //! - `--quiet` / `--verbose` / `--color` are global flags
 //! - ANSI never leaks into pipes unless `--color always`; `NO_COLOR` wins
 //! - errors render as `error: <message>` with an actionable `help:` hint
-//! - `largo test` is reserved for the future LOGOS test framework
 
 mod common;
 
@@ -35,7 +34,7 @@ fn bare_largo_prints_command_overview() {
 }
 
 /// Every visible subcommand has an `about` string AND an Examples section
-/// (via `after_help`). Hidden commands (reserved verbs) still need `about`.
+/// (via `after_help`). Hidden commands still need `about`.
 /// This is a ratchet: any future command must arrive polished.
 #[test]
 fn every_subcommand_has_about_and_examples() {
@@ -314,27 +313,3 @@ fn check_deep_passes_on_a_clean_program() {
         stdout(&out)
     );
 }
-
-// ---------------------------------------------------------------------------
-// Reserved `test` verb
-// ---------------------------------------------------------------------------
-
-/// `largo test` is reserved: it exits 2 with a message pointing at the future
-/// test framework, and stays hidden from `--help`.
-#[test]
-fn test_verb_is_reserved_and_hidden() {
-    let out = largo().arg("test").output().expect("largo should spawn");
-    assert_eq!(out.status.code(), Some(2), "reserved verb is a usage error");
-    let err = strip_ansi(&stderr(&out));
-    assert!(
-        err.contains("reserved") && err.contains("test framework"),
-        "must explain the verb is reserved for the LOGOS test framework:\n{err}"
-    );
-
-    let cmd = logicaffeine_cli::cli::Cli::command();
-    let test_sub = cmd
-        .get_subcommands()
-        .find(|s| s.get_name() == "test")
-        .expect("the `test` verb must exist (reserved)");
-    assert!(test_sub.is_hide_set(), "`test` must be hidden from help");
-}
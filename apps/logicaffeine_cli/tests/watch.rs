@@ -0,0 +1,57 @@
+//! `largo watch` — re-run a command on every source change.
+
+mod common;
+
+use std::io::{BufRead, BufReader};
+use std::process::Stdio;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use common::*;
+use tempfile::tempdir;
+
+/// Wait (up to a generous deadline) for a stderr line containing `needle`.
+fn expect_line(lines: &mpsc::Receiver<String>, needle: &str) -> String {
+    loop {
+        match lines.recv_timeout(Duration::from_secs(120)) {
+            Ok(line) if strip_ansi(&line).contains(needle) => return strip_ansi(&line),
+            Ok(_) => {}
+            Err(e) => panic!("no line containing {needle:?}: {e}"),
+        }
+    }
+}
+
+/// `largo watch` checks once at startup, then again after an edit, naming
+/// the changed file and reporting the new failure.
+#[test]
+fn watch_rechecks_after_an_edit() {
+    let dir = tempdir().unwrap();
+    scaffold(dir.path(), "watched");
+    let mut watcher = largo()
+        .args(["watch", "--debounce", "50"])
+        .current_dir(dir.path())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("largo watch should spawn");
+    let stderr = watcher.stderr.take().unwrap();
+    let (tx, lines) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    expect_line(&lines, "Watching");
+    expect_line(&lines, "Finished");
+
+    std::fs::write(dir.path().join("src/main.lg"), "## Main\n\nLet be.\n").unwrap();
+    let changed = expect_line(&lines, "Changed");
+    assert!(changed.contains("src/main.lg"), "{changed}");
+    expect_line(&lines, "largo check failed");
+
+    watcher.kill().unwrap();
+    watcher.wait().unwrap();
+}
@@ -0,0 +1,48 @@
+// Compile Web Worker - runs the Studio's compile/interpret calls off the main thread
+//
+// The page posts its own wasm-bindgen glue URL and compiled WebAssembly.Module once
+// ({ init: { glue, module } }); the worker instantiates a private copy of the app
+// (whose `main` sees no window and returns) and then serves one request at a time:
+//   request:  { id, op, input }   op is a `worker_*` export of the app
+//   progress: { id, started }     the export is about to run (latency is timed from here)
+//          or { id, line }        a streaming export printed a line of output
+//   response: { id, result }      result is the export's JSON string
+//         or { id, panic }        the instance trapped and must be replaced
+//         or { id, failed }       the app could not be instantiated here
+//
+// Cancellation is the page's job: it never sends a second request while one is in
+// flight, and terminates this worker outright when the in-flight one is superseded.
+
+let ready = null;
+
+async function instantiate(init) {
+    const glue = await import(init.glue);
+    glue.initSync({ module: init.module });
+    return glue;
+}
+
+self.onmessage = async (event) => {
+    const msg = event.data;
+
+    if (msg.init) {
+        ready = instantiate(msg.init);
+        return;
+    }
+
+    let glue;
+    try {
+        glue = await ready;
+    } catch (e) {
+        self.postMessage({ id: msg.id, failed: String(e) });
+        return;
+    }
+
+    self.postMessage({ id: msg.id, started: true });
+    const emit = (line) => self.postMessage({ id: msg.id, line });
+    try {
+        const result = await glue[msg.op](msg.input, emit);
+        self.postMessage({ id: msg.id, result });
+    } catch (e) {
+        self.postMessage({ id: msg.id, panic: String(e) });
+    }
+};
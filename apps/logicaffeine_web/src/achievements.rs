@@ -178,7 +178,7 @@ pub fn unlock_achievement(progress: &mut UserProgress, achievement: &Achievement
         }
     }
 
-    if achievement.grants_freeze && progress.streak_freezes < 3 {
+    if achievement.grants_freeze && progress.streak_freezes < crate::game::MAX_STREAK_FREEZES {
         progress.streak_freezes += 1;
     }
 
@@ -0,0 +1,228 @@
+//! Structural diff of two syntax trees
+//!
+//! [`ast_diff`] lines two [`AstNode`] trees up and returns one merged tree,
+//! shaped like the second, in which every node says how it got there:
+//! - **Same**: present in both trees, unchanged
+//! - **Renamed**: the same place in both trees, but a different label (`∀x`
+//!   became `∃x`, `Dog(1)` became `Cat(1)`)
+//! - **Inserted**: only in the second tree
+//! - **Removed**: only in the first tree, kept in place so it can be shown
+//!   struck through
+//!
+//! Children are aligned by their longest common run of identical subtrees;
+//! what is left between matches is paired up by position and diffed again
+//! when the node types agree. A node that wraps the other tree whole — a
+//! negation put in front of a formula — shows as that one node inserted
+//! rather than the whole tree renamed.
+//!
+//! # Usage
+//!
+//! ```no_run
+//! use logicaffeine_web::ast_diff::{ast_diff, DiffKind};
+//! use logicaffeine_web::grader::formula_ast;
+//!
+//! let expected = formula_ast("∀x(Dog(x) → Bark(x))").unwrap();
+//! let answer = formula_ast("∃x(Dog(x) → Bark(x))").unwrap();
+//! let diff = ast_diff(&expected, &answer);
+//! assert_eq!(diff.kind, DiffKind::Renamed { from: "∀".to_string() });
+//! assert_eq!(diff.changes(), 1);
+//! ```
+
+use serde::Serialize;
+
+use crate::AstNode;
+
+/// How a node of the merged tree relates to the two trees diffed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DiffKind {
+    Same,
+    /// Relabeled; `from` is the label in the first tree.
+    Renamed { from: String },
+    Inserted,
+    Removed,
+}
+
+impl DiffKind {
+    /// The CSS class the diff view gives nodes of this kind.
+    pub fn class(&self) -> &'static str {
+        match self {
+            DiffKind::Same => "same",
+            DiffKind::Renamed { .. } => "renamed",
+            DiffKind::Inserted => "inserted",
+            DiffKind::Removed => "removed",
+        }
+    }
+}
+
+/// One node of the merged tree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AstDiff {
+    pub label: String,
+    pub node_type: String,
+    pub kind: DiffKind,
+    pub children: Vec<AstDiff>,
+}
+
+impl AstDiff {
+    /// Whether the two trees were identical.
+    pub fn is_identical(&self) -> bool {
+        self.changes() == 0
+    }
+
+    /// Nodes inserted, removed or renamed, counted through every subtree.
+    pub fn changes(&self) -> usize {
+        usize::from(self.kind != DiffKind::Same) + self.children.iter().map(AstDiff::changes).sum::<usize>()
+    }
+}
+
+/// Diff `a` against `b`: what it takes to turn the first tree into the
+/// second.
+pub fn ast_diff(a: &AstNode, b: &AstNode) -> AstDiff {
+    if a.label == b.label && a.node_type == b.node_type {
+        return node(b, DiffKind::Same, diff_children(&a.children, &b.children));
+    }
+    // `b` wraps `a` whole, or `a` wraps `b`.
+    if b.children.contains(a) {
+        let children = b.children.iter().map(|child| whole(child, if child == a { DiffKind::Same } else { DiffKind::Inserted })).collect();
+        return node(b, DiffKind::Inserted, children);
+    }
+    if a.children.contains(b) {
+        let children = a.children.iter().map(|child| whole(child, if child == b { DiffKind::Same } else { DiffKind::Removed })).collect();
+        return node(a, DiffKind::Removed, children);
+    }
+    node(b, DiffKind::Renamed { from: a.label.clone() }, diff_children(&a.children, &b.children))
+}
+
+fn node(source: &AstNode, kind: DiffKind, children: Vec<AstDiff>) -> AstDiff {
+    AstDiff { label: source.label.clone(), node_type: source.node_type.clone(), kind, children }
+}
+
+/// `source` and everything under it, all of one kind.
+fn whole(source: &AstNode, kind: DiffKind) -> AstDiff {
+    let children = source.children.iter().map(|child| whole(child, kind.clone())).collect();
+    node(source, kind, children)
+}
+
+/// Align two child lists on their longest common subsequence of identical
+/// subtrees, then diff what lies between the matches.
+fn diff_children(a: &[AstNode], b: &[AstNode]) -> Vec<AstDiff> {
+    // lcs[i][j]: the longest common subsequence of a[i..] and b[j..].
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+        }
+    }
+
+    let mut merged = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    let (mut gap_a, mut gap_b) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            diff_gap(&a[gap_a..i], &b[gap_b..j], &mut merged);
+            merged.push(whole(&b[j], DiffKind::Same));
+            i += 1;
+            j += 1;
+            (gap_a, gap_b) = (i, j);
+        } else if j < b.len() && (i == a.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            j += 1;
+        } else {
+            i += 1;
+        }
+    }
+    diff_gap(&a[gap_a..], &b[gap_b..], &mut merged);
+    merged
+}
+
+/// Children between two matches: paired by position where the node types
+/// agree, removed or inserted otherwise.
+fn diff_gap(a: &[AstNode], b: &[AstNode], merged: &mut Vec<AstDiff>) {
+    for k in 0..a.len().max(b.len()) {
+        match (a.get(k), b.get(k)) {
+            (Some(x), Some(y)) if x.node_type == y.node_type => merged.push(ast_diff(x, y)),
+            (x, y) => {
+                merged.extend(x.map(|x| whole(x, DiffKind::Removed)));
+                merged.extend(y.map(|y| whole(y, DiffKind::Inserted)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pred(name: &str) -> AstNode {
+        AstNode::leaf(&format!("{name}(1)"), "predicate")
+    }
+
+    fn op(label: &str, children: Vec<AstNode>) -> AstNode {
+        AstNode::with_children(label, "binary_op", children)
+    }
+
+    fn kinds(diff: &AstDiff) -> Vec<(String, &'static str)> {
+        let mut out = vec![(diff.label.clone(), diff.kind.class())];
+        for child in &diff.children {
+            out.extend(kinds(child));
+        }
+        out
+    }
+
+    #[test]
+    fn identical_trees_have_no_changes() {
+        let tree = op("∧", vec![pred("A"), pred("B")]);
+        let diff = ast_diff(&tree, &tree);
+        assert!(diff.is_identical());
+        assert!(kinds(&diff).iter().all(|(_, kind)| *kind == "same"));
+    }
+
+    #[test]
+    fn relabeled_nodes_are_renamed_in_place() {
+        let a = AstNode::with_children("∀x", "quantifier", vec![op("→", vec![pred("Dog"), pred("Bark")])]);
+        let b = AstNode::with_children("∃x", "quantifier", vec![op("∧", vec![pred("Dog"), pred("Bark")])]);
+        let diff = ast_diff(&a, &b);
+        assert_eq!(diff.kind, DiffKind::Renamed { from: "∀x".to_string() });
+        assert_eq!(
+            kinds(&diff),
+            [
+                ("∃x".to_string(), "renamed"),
+                ("∧".to_string(), "renamed"),
+                ("Dog(1)".to_string(), "same"),
+                ("Bark(1)".to_string(), "same"),
+            ]
+        );
+        assert_eq!(diff.changes(), 2);
+    }
+
+    #[test]
+    fn children_are_aligned_around_insertions_and_removals() {
+        let a = op("∧", vec![pred("A"), pred("B"), AstNode::leaf("x", "variable")]);
+        let b = op("∧", vec![pred("A"), AstNode::leaf("y", "constant"), pred("B")]);
+        assert_eq!(
+            kinds(&ast_diff(&a, &b)),
+            [
+                ("∧".to_string(), "same"),
+                ("A(1)".to_string(), "same"),
+                ("y".to_string(), "inserted"),
+                ("B(1)".to_string(), "same"),
+                ("x".to_string(), "removed"),
+            ]
+        );
+    }
+
+    #[test]
+    fn a_wrapping_node_is_one_insertion() {
+        let formula = op("∨", vec![pred("A"), pred("B")]);
+        let negated = AstNode::with_children("¬", "unary_op", vec![formula.clone()]);
+
+        let added = ast_diff(&formula, &negated);
+        assert_eq!(added.kind, DiffKind::Inserted);
+        assert_eq!(added.changes(), 1);
+
+        let dropped = ast_diff(&negated, &formula);
+        assert_eq!(dropped.kind, DiffKind::Removed);
+        assert_eq!(dropped.label, "¬");
+        assert_eq!(dropped.children[0].kind, DiffKind::Same);
+    }
+}
@@ -1,31 +1,59 @@
 //! Sound effect playback for gamification feedback.
 //!
 //! Provides audio cues for learning events like correct answers, XP gains,
-//! combo achievements, and streak status. Uses JavaScript interop on WASM
-//! targets; no-op on native targets for testing.
+//! combo achievements, and streak status, played through the Web Audio API on
+//! WASM targets and a no-op on native targets for testing.
+//!
+//! # Mixer
+//!
+//! Every sound runs through a gain graph — effect or music channel, then
+//! master — so volume changes apply to sounds already playing:
+//!
+//! ```text
+//! sprite ─▶ sfx gain ──┐
+//!                      ├─▶ master gain ─▶ speakers
+//! loop ──▶ music gain ─┘
+//! ```
+//!
+//! [`AudioSettings`] holds the channel volumes, the mute switch and the
+//! [`SoundTheme`], and persists them to LocalStorage.
+//!
+//! # Sprites
+//!
+//! A theme's effects live in one audio file, fetched and decoded once; each
+//! [`SoundEffect`] is a [`Sprite`] — a slice of that file. Playing a slice is
+//! a buffer-source start with no network or decode on the way, so feedback
+//! lands on the frame it was triggered. Effects triggered before the sprite
+//! sheet has decoded are dropped rather than played late.
+//!
+//! # Combos
+//!
+//! [`play_combo`] schedules a rising arpeggio on the audio clock
+//! ([`combo_cues`]): the notes are queued together and start sample-accurately,
+//! however busy the main thread is.
 //!
 //! # Usage
 //!
 //! ```no_run
-//! use logicaffeine_web::audio::{SoundEffect, play_sound};
+//! use logicaffeine_web::audio::{AudioSettings, Channel, SoundEffect, play_combo, play_sound};
 //!
 //! // Play a sound when the user answers correctly
 //! play_sound(SoundEffect::Correct);
 //!
-//! // Play combo sound for streak multipliers
-//! play_sound(SoundEffect::ComboUp);
-//! ```
-//!
-//! # JavaScript Integration
+//! // Third correct answer in a row: a three-note combo
+//! play_combo(3);
 //!
-//! On WASM targets, this module expects a global `window.playSound(name)` function
-//! to be defined in the host page. The function receives the sound effect name
-//! as a string (e.g., "correct", "combo_up").
+//! // Turn the effects down; saved and applied at once
+//! let mut settings = AudioSettings::load();
+//! settings.set_volume(Channel::Sfx, 0.4);
+//! ```
+
+use serde::{Deserialize, Serialize};
 
 /// Audio cues for gamification events.
 ///
-/// Each variant maps to a distinct sound file. The [`SoundEffect::as_str`] method
-/// returns the identifier passed to the JavaScript audio system.
+/// Each variant is a [`Sprite`] in the theme's sprite sheet. The
+/// [`SoundEffect::as_str`] method returns its stable identifier.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SoundEffect {
     /// Played when the user earns XP from any source.
@@ -51,10 +79,21 @@ pub enum SoundEffect {
 }
 
 impl SoundEffect {
+    /// Every effect, in sprite-sheet order.
+    pub const ALL: [SoundEffect; 10] = [
+        Self::XpGain,
+        Self::CriticalHit,
+        Self::ComboUp,
+        Self::ComboBreak,
+        Self::Achievement,
+        Self::LevelUp,
+        Self::StreakSaved,
+        Self::StreakLost,
+        Self::Correct,
+        Self::Incorrect,
+    ];
+
     /// Returns the string identifier for this sound effect.
-    ///
-    /// This identifier is passed to the JavaScript `playSound` function
-    /// and should match the audio file naming convention.
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::XpGain => "xp_gain",
@@ -69,33 +108,503 @@ impl SoundEffect {
             Self::Incorrect => "incorrect",
         }
     }
+
+    /// Where this effect sits in a sprite sheet. Every theme's sheet uses the
+    /// same layout: one slot of [`SPRITE_SLOT`] seconds per effect, in
+    /// [`SoundEffect::ALL`] order, each sound starting at its slot.
+    pub fn sprite(self) -> Sprite {
+        let slot = Self::ALL.iter().position(|&effect| effect == self).unwrap_or(0);
+        let duration = match self {
+            Self::Achievement | Self::LevelUp | Self::StreakLost => 1.2,
+            Self::CriticalHit | Self::StreakSaved => 0.8,
+            _ => 0.4,
+        };
+        Sprite { offset: slot as f64 * SPRITE_SLOT, duration }
+    }
+}
+
+/// Seconds each effect is given in a sprite sheet; sheets are padded with
+/// silence to this spacing.
+pub const SPRITE_SLOT: f64 = 1.5;
+
+/// A slice of a sprite sheet, in seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sprite {
+    pub offset: f64,
+    pub duration: f64,
+}
+
+/// The sound sets a learner can pick from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SoundTheme {
+    #[default]
+    Classic,
+    Soft,
+    Arcade,
+}
+
+impl SoundTheme {
+    pub const ALL: [SoundTheme; 3] = [Self::Classic, Self::Soft, Self::Arcade];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Classic => "classic",
+            Self::Soft => "soft",
+            Self::Arcade => "arcade",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Classic => "Classic",
+            Self::Soft => "Soft",
+            Self::Arcade => "Arcade",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|theme| theme.as_str() == name)
+    }
+
+    /// The theme's sprite sheet of effects.
+    pub fn sprite_url(self) -> String {
+        format!("/assets/sounds/{}/effects.mp3", self.as_str())
+    }
+
+    /// The theme's background music loop.
+    pub fn music_url(self) -> String {
+        format!("/assets/sounds/{}/music.mp3", self.as_str())
+    }
+}
+
+/// A mixer channel. Effects play on [`Channel::Sfx`] and the background loop
+/// on [`Channel::Music`]; both pass through [`Channel::Master`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Channel {
+    Master,
+    Sfx,
+    Music,
+}
+
+/// The learner's sound preferences.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioSettings {
+    #[serde(default)]
+    pub theme: SoundTheme,
+    #[serde(default = "full_volume")]
+    pub master: f32,
+    #[serde(default = "full_volume")]
+    pub sfx: f32,
+    #[serde(default = "half_volume")]
+    pub music: f32,
+    #[serde(default)]
+    pub muted: bool,
+    /// Whether the theme's background loop plays.
+    #[serde(default)]
+    pub music_enabled: bool,
+}
+
+fn full_volume() -> f32 {
+    1.0
+}
+
+fn half_volume() -> f32 {
+    0.5
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            theme: SoundTheme::default(),
+            master: full_volume(),
+            sfx: full_volume(),
+            music: half_volume(),
+            muted: false,
+            music_enabled: false,
+        }
+    }
+}
+
+impl AudioSettings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        {
+            crate::storage::load_audio_raw()
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default()
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Self::new()
+        }
+    }
+
+    pub fn save(&self) {
+        #[cfg(target_arch = "wasm32")]
+        {
+            if let Ok(json) = serde_json::to_string(self) {
+                crate::storage::save_audio_raw(&json);
+            }
+        }
+    }
+
+    /// The slider position of `channel`, from 0 to 1.
+    pub fn volume(&self, channel: Channel) -> f32 {
+        match channel {
+            Channel::Master => self.master,
+            Channel::Sfx => self.sfx,
+            Channel::Music => self.music,
+        }
+    }
+
+    /// What `channel` is actually heard at: its volume scaled by the master
+    /// volume, or silence while muted.
+    pub fn gain(&self, channel: Channel) -> f32 {
+        if self.muted {
+            return 0.0;
+        }
+        match channel {
+            Channel::Master => self.master,
+            _ => self.master * self.volume(channel),
+        }
+    }
+
+    /// Set `channel`'s volume, clamped to 0–1.
+    pub fn set_volume(&mut self, channel: Channel, volume: f32) {
+        let volume = if volume.is_finite() { volume.clamp(0.0, 1.0) } else { 0.0 };
+        match channel {
+            Channel::Master => self.master = volume,
+            Channel::Sfx => self.sfx = volume,
+            Channel::Music => self.music = volume,
+        }
+        self.changed();
+    }
+
+    pub fn set_muted(&mut self, muted: bool) {
+        self.muted = muted;
+        self.changed();
+    }
+
+    pub fn set_theme(&mut self, theme: SoundTheme) {
+        self.theme = theme;
+        self.changed();
+    }
+
+    pub fn set_music_enabled(&mut self, enabled: bool) {
+        self.music_enabled = enabled;
+        self.changed();
+    }
+
+    /// Persist the settings and hand them to the mixer.
+    fn changed(&self) {
+        self.save();
+        #[cfg(target_arch = "wasm32")]
+        wasm::apply(self);
+    }
+}
+
+/// One note of a combo arpeggio: when it starts, after the first, and the
+/// playback rate that pitches it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cue {
+    pub delay: f64,
+    pub rate: f32,
+}
+
+/// The most notes a combo arpeggio plays.
+pub const COMBO_MAX_NOTES: u32 = 5;
+
+/// Seconds between the notes of a combo arpeggio.
+pub const COMBO_NOTE_SPACING: f64 = 0.07;
+
+/// The notes [`play_combo`] schedules for the `combo`-th correct answer in a
+/// row: one note per answer up to [`COMBO_MAX_NOTES`], climbing a major
+/// arpeggio, with the whole figure starting a semitone higher for each answer
+/// past that so a long run keeps rising.
+pub fn combo_cues(combo: u32) -> Vec<Cue> {
+    const ARPEGGIO: [i32; COMBO_MAX_NOTES as usize] = [0, 4, 7, 12, 16];
+    let notes = combo.clamp(1, COMBO_MAX_NOTES) as usize;
+    let lift = combo.saturating_sub(COMBO_MAX_NOTES).min(12) as i32;
+    ARPEGGIO[..notes]
+        .iter()
+        .enumerate()
+        .map(|(i, &semitones)| Cue {
+            delay: i as f64 * COMBO_NOTE_SPACING,
+            rate: 2f32.powf((semitones + lift) as f32 / 12.0),
+        })
+        .collect()
 }
 
 #[cfg(target_arch = "wasm32")]
 mod wasm {
-    use super::SoundEffect;
-    use wasm_bindgen::prelude::*;
+    use super::{AudioSettings, Channel, Cue, SoundEffect, SoundTheme, Sprite};
+    use std::cell::RefCell;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+    use web_sys::{AudioBuffer, AudioBufferSourceNode, AudioContext, AudioContextState, GainNode};
+
+    /// Seconds a gain change glides over, so volume moves never click.
+    const GAIN_GLIDE: f64 = 0.015;
+
+    /// The audio graph, built on first use (browsers only let an
+    /// `AudioContext` start after a user gesture, which every cue follows).
+    struct Mixer {
+        context: AudioContext,
+        master: GainNode,
+        sfx: GainNode,
+        music: GainNode,
+        settings: AudioSettings,
+        effects: Option<(SoundTheme, AudioBuffer)>,
+        music_loop: Option<AudioBufferSourceNode>,
+        loading_effects: Option<SoundTheme>,
+    }
 
-    #[wasm_bindgen]
-    extern "C" {
-        #[wasm_bindgen(js_namespace = window, js_name = playSound)]
-        fn play_sound_js(effect: &str);
+    thread_local! {
+        static MIXER: RefCell<Option<Mixer>> = const { RefCell::new(None) };
     }
 
-    /// Plays the specified sound effect through the browser audio system.
-    pub fn play_sound(effect: SoundEffect) {
-        play_sound_js(effect.as_str());
+    fn build() -> Option<Mixer> {
+        let context = AudioContext::new().ok()?;
+        let master = context.create_gain().ok()?;
+        let sfx = context.create_gain().ok()?;
+        let music = context.create_gain().ok()?;
+        master.connect_with_audio_node(&context.destination()).ok()?;
+        sfx.connect_with_audio_node(&master).ok()?;
+        music.connect_with_audio_node(&master).ok()?;
+        Some(Mixer {
+            context,
+            master,
+            sfx,
+            music,
+            settings: AudioSettings::load(),
+            effects: None,
+            music_loop: None,
+            loading_effects: None,
+        })
+    }
+
+    /// Run `f` on the mixer, building it first if needed. A freshly built
+    /// mixer starts the music loop if the saved settings ask for it.
+    fn with_mixer<R>(f: impl FnOnce(&mut Mixer) -> R) -> Option<R> {
+        let (result, music) = MIXER.with(|cell| {
+            let mut slot = cell.borrow_mut();
+            let mut music = None;
+            if slot.is_none() {
+                *slot = build();
+                if let Some(mixer) = slot.as_mut() {
+                    set_gains(mixer);
+                    music = mixer.settings.music_enabled.then(|| (mixer.context.clone(), mixer.settings.theme));
+                }
+            }
+            let Some(mixer) = slot.as_mut() else {
+                return (None, None);
+            };
+            if mixer.context.state() == AudioContextState::Suspended {
+                let _ = mixer.context.resume();
+            }
+            (Some(f(mixer)), music)
+        });
+        if let Some((context, theme)) = music {
+            start_music(context, theme);
+        }
+        result
+    }
+
+    fn set_gains(mixer: &Mixer) {
+        let now = mixer.context.current_time();
+        for (node, channel) in [(&mixer.master, Channel::Master), (&mixer.sfx, Channel::Sfx), (&mixer.music, Channel::Music)] {
+            let gain = if channel == Channel::Master {
+                mixer.settings.gain(Channel::Master)
+            } else {
+                // The master node already scales by the master volume.
+                mixer.settings.volume(channel)
+            };
+            let _ = node.gain().set_target_at_time(gain, now, GAIN_GLIDE);
+        }
+    }
+
+    /// Take on new settings: gains glide to their new levels, a new theme's
+    /// sprites load, and the music loop starts, stops or changes theme.
+    pub fn apply(settings: &AudioSettings) {
+        let settings = settings.clone();
+        let restart = with_mixer(move |mixer| {
+            let music_changed = mixer.settings.music_enabled != settings.music_enabled
+                || mixer.settings.theme != settings.theme;
+            mixer.settings = settings;
+            set_gains(mixer);
+            ensure_effects(mixer);
+            if music_changed {
+                stop_music(mixer);
+            }
+            (music_changed && mixer.settings.music_enabled).then(|| (mixer.context.clone(), mixer.settings.theme))
+        });
+        if let Some(Some((context, theme))) = restart {
+            start_music(context, theme);
+        }
+    }
+
+    /// Start fetching the current theme's sprite sheet unless it is loaded or
+    /// on its way.
+    fn ensure_effects(mixer: &mut Mixer) {
+        let theme = mixer.settings.theme;
+        let loaded = mixer.effects.as_ref().is_some_and(|(loaded, _)| *loaded == theme);
+        if loaded || mixer.loading_effects == Some(theme) {
+            return;
+        }
+        mixer.loading_effects = Some(theme);
+        let context = mixer.context.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let buffer = decode(&context, &theme.sprite_url()).await;
+            MIXER.with(|cell| {
+                if let Some(mixer) = cell.borrow_mut().as_mut() {
+                    if mixer.loading_effects == Some(theme) {
+                        mixer.loading_effects = None;
+                        if let Some(buffer) = buffer {
+                            mixer.effects = Some((theme, buffer));
+                        }
+                    }
+                }
+            });
+        });
+    }
+
+    async fn decode(context: &AudioContext, url: &str) -> Option<AudioBuffer> {
+        let bytes = gloo_net::http::Request::get(url).send().await.ok()?.binary().await.ok()?;
+        let data = js_sys::Uint8Array::from(bytes.as_slice()).buffer();
+        let decoded = JsFuture::from(context.decode_audio_data(&data).ok()?).await.ok()?;
+        decoded.dyn_into::<AudioBuffer>().ok()
+    }
+
+    /// Queue `sprite` on the effects channel, `cue.delay` seconds from now.
+    fn start_sprite(mixer: &Mixer, sprite: Sprite, cue: Cue) -> Option<()> {
+        let (_, buffer) = mixer.effects.as_ref()?;
+        let source = mixer.context.create_buffer_source().ok()?;
+        source.set_buffer(Some(buffer));
+        source.playback_rate().set_value(cue.rate);
+        source.connect_with_audio_node(&mixer.sfx).ok()?;
+        let when = mixer.context.current_time() + cue.delay;
+        source
+            .start_with_when_and_grain_offset_and_grain_duration(when, sprite.offset, sprite.duration)
+            .ok()
+    }
+
+    /// Play `cues` of `effect`, all queued on the audio clock at once.
+    pub fn play_cues(effect: SoundEffect, cues: &[Cue]) {
+        with_mixer(|mixer| {
+            ensure_effects(mixer);
+            if mixer.settings.muted {
+                return;
+            }
+            for &cue in cues {
+                start_sprite(mixer, effect.sprite(), cue);
+            }
+        });
+    }
+
+    fn start_music(context: AudioContext, theme: SoundTheme) {
+        wasm_bindgen_futures::spawn_local(async move {
+            let Some(buffer) = decode(&context, &theme.music_url()).await else {
+                return;
+            };
+            MIXER.with(|cell| {
+                let mut slot = cell.borrow_mut();
+                let Some(mixer) = slot.as_mut() else {
+                    return;
+                };
+                // Settings may have moved on while the loop was loading.
+                if !mixer.settings.music_enabled || mixer.settings.theme != theme || mixer.music_loop.is_some() {
+                    return;
+                }
+                let Ok(source) = mixer.context.create_buffer_source() else {
+                    return;
+                };
+                source.set_buffer(Some(&buffer));
+                source.set_loop(true);
+                if source.connect_with_audio_node(&mixer.music).is_ok() && source.start().is_ok() {
+                    mixer.music_loop = Some(source);
+                }
+            });
+        });
+    }
+
+    fn stop_music(mixer: &mut Mixer) {
+        if let Some(source) = mixer.music_loop.take() {
+            let _ = source.stop();
+        }
     }
 }
 
-#[cfg(target_arch = "wasm32")]
-pub use wasm::play_sound;
+/// Plays a sound effect on the effects channel (no-op on non-WASM targets).
+pub fn play_sound(effect: SoundEffect) {
+    #[cfg(target_arch = "wasm32")]
+    wasm::play_cues(effect, &[Cue { delay: 0.0, rate: 1.0 }]);
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = effect;
+}
 
-/// Plays a sound effect (no-op on non-WASM targets).
-///
-/// On WASM targets, this calls the JavaScript `window.playSound()` function.
-/// On native targets, this is a no-op to allow testing without audio setup.
-#[cfg(not(target_arch = "wasm32"))]
-pub fn play_sound(_effect: SoundEffect) {
-    // No-op on non-wasm targets
+/// Plays the combo sound for the `combo`-th correct answer in a row — see
+/// [`combo_cues`] (no-op on non-WASM targets).
+pub fn play_combo(combo: u32) {
+    #[cfg(target_arch = "wasm32")]
+    wasm::play_cues(SoundEffect::ComboUp, &combo_cues(combo));
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = combo;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sprites_tile_the_sheet_without_overlap() {
+        let sprites: Vec<Sprite> = SoundEffect::ALL.iter().map(|effect| effect.sprite()).collect();
+        for (slot, sprite) in sprites.iter().enumerate() {
+            assert_eq!(sprite.offset, slot as f64 * SPRITE_SLOT);
+            assert!(sprite.duration > 0.0 && sprite.duration <= SPRITE_SLOT, "{sprite:?}");
+        }
+    }
+
+    #[test]
+    fn gains_scale_by_master_and_mute() {
+        let mut settings = AudioSettings::new();
+        settings.set_volume(Channel::Master, 0.5);
+        settings.set_volume(Channel::Sfx, 0.8);
+        settings.set_volume(Channel::Music, 7.0);
+        assert_eq!(settings.gain(Channel::Sfx), 0.4);
+        assert_eq!(settings.volume(Channel::Music), 1.0);
+        assert_eq!(settings.gain(Channel::Music), 0.5);
+
+        settings.set_muted(true);
+        assert_eq!(settings.gain(Channel::Sfx), 0.0);
+        assert_eq!(settings.volume(Channel::Sfx), 0.8, "muting keeps the slider");
+    }
+
+    #[test]
+    fn settings_round_trip_and_fill_missing_fields() {
+        let mut settings = AudioSettings::new();
+        settings.set_theme(SoundTheme::Arcade);
+        let json = serde_json::to_string(&settings).unwrap();
+        assert!(json.contains(r#""theme":"arcade""#), "{json}");
+        assert_eq!(serde_json::from_str::<AudioSettings>(&json).unwrap(), settings);
+
+        let old: AudioSettings = serde_json::from_str(r#"{"muted":true}"#).unwrap();
+        assert_eq!(old, AudioSettings { muted: true, ..AudioSettings::default() });
+        assert_eq!(SoundTheme::from_name("soft"), Some(SoundTheme::Soft));
+    }
+
+    #[test]
+    fn combos_add_notes_then_climb() {
+        let rates = |combo| combo_cues(combo).iter().map(|cue| cue.rate).collect::<Vec<_>>();
+        assert_eq!(rates(1), [1.0]);
+        assert_eq!(combo_cues(3).iter().map(|cue| cue.delay).collect::<Vec<_>>(), [0.0, COMBO_NOTE_SPACING, 2.0 * COMBO_NOTE_SPACING]);
+        assert_eq!(rates(5).last().copied(), Some(2f32.powf(16.0 / 12.0)));
+        assert_eq!(rates(7).len(), COMBO_MAX_NOTES as usize);
+        assert!(rates(7)[0] > rates(6)[0], "a longer run starts higher");
+        assert_eq!(rates(40), rates(17), "the climb tops out an octave up");
+    }
 }
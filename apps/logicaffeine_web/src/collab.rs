@@ -0,0 +1,622 @@
+//! Collaborative Studio Buffers
+//!
+//! Lets two people — a pair of learners, or a teacher and a student — edit the
+//! same Studio buffer live:
+//! - A [`CollabDoc`] keeps the buffer as a [`YATA`] text CRDT. Each local edit
+//!   is diffed against it and becomes a run insert and/or a range remove, so
+//!   every replica converges on the same text whatever order edits arrive in
+//! - Peers meet in a [`Room`] on the relay ([`RELAY_URL`]), which forwards each
+//!   [`Message`] to everyone else in the room and interprets none of them
+//! - Carets travel as [`Presence`], anchored to the character left of the
+//!   caret rather than an offset, so a peer's caret stays with its text while
+//!   others edit above it
+//! - Edits made offline stay in the document. Every (re)connect sends the
+//!   whole state, which merges idempotently with whatever the room holds
+//!
+//! # Usage
+//!
+//! ```
+//! use logicaffeine_web::collab::{Room, Session};
+//!
+//! let room = Room::random();
+//! let mut teacher = Session::host(room.clone(), 1, "## Main\nShow 1.");
+//! let mut student = Session::join(room, 2);
+//! for message in teacher.greeting() {
+//!     student.receive(message);
+//! }
+//! assert_eq!(student.text(), "## Main\nShow 1.");
+//!
+//! let edit = student.edit("## Main\nShow 2.").unwrap();
+//! teacher.receive(edit);
+//! assert_eq!(teacher.text(), "## Main\nShow 2.");
+//! ```
+
+use logicaffeine_data::crdt::sequence::{YATADelta, YataId};
+use logicaffeine_data::{DeltaCrdt, ReplicaId, VClock, YATA};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The WebSocket relay rooms live on; a room is `RELAY_URL/<room>`.
+pub const RELAY_URL: &str = "wss://api.logicaffeine.com/collab";
+
+/// First reconnect delay after the relay drops; doubles up to [`MAX_RETRY_MS`].
+pub const FIRST_RETRY_MS: u32 = 500;
+
+/// Longest wait between reconnect attempts.
+pub const MAX_RETRY_MS: u32 = 15_000;
+
+const ROOM_PREFIX: &str = "collab=";
+
+/// Length of a generated room name.
+const ROOM_LEN: usize = 16;
+
+/// Caret colours, picked per peer.
+const PALETTE: [&str; 6] = ["#e8590c", "#2f9e44", "#1c7ed6", "#ae3ec9", "#f08c00", "#0c8599"];
+
+/// The buffer changes one message carries.
+pub type Delta = YATADelta<char>;
+
+/// A collaboration room: everyone connected to the same room edits one buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Room(String);
+
+impl Room {
+    /// A fresh room with an unguessable name.
+    pub fn random() -> Self {
+        const ALPHABET: &[u8] = b"abcdefghijkmnpqrstuvwxyz23456789";
+        let mut rng = rand::thread_rng();
+        let name = (0..ROOM_LEN)
+            .map(|_| char::from(ALPHABET[rng.gen_range(0..ALPHABET.len())]))
+            .collect();
+        Room(name)
+    }
+
+    /// Parses a `collab=<room>` URL fragment, with or without its leading `#`.
+    pub fn parse(fragment: &str) -> Option<Self> {
+        let name = fragment.trim_start_matches('#').strip_prefix(ROOM_PREFIX)?;
+        let valid = !name.is_empty() && name.len() <= 64 && name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+        valid.then(|| Room(name.to_string()))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    /// `collab=<room>` for the URL fragment.
+    pub fn fragment(&self) -> String {
+        format!("{ROOM_PREFIX}{}", self.0)
+    }
+
+    /// Where to open the room's WebSocket.
+    pub fn relay_url(&self) -> String {
+        format!("{RELAY_URL}/{}", self.0)
+    }
+}
+
+/// Who a peer is and where their caret sits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Presence {
+    pub peer: ReplicaId,
+    pub name: String,
+    /// The character just left of the caret; `None` at the start of the buffer.
+    pub anchor: Option<YataId>,
+}
+
+impl Presence {
+    fn new(peer: ReplicaId) -> Self {
+        Self {
+            peer,
+            name: format!("Guest {:04X}", peer & 0xFFFF),
+            anchor: None,
+        }
+    }
+}
+
+/// What travels through the relay.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Message {
+    /// A peer (re)connected; everyone answers with their state and presence.
+    Hello { peer: ReplicaId },
+    /// Changes to the buffer.
+    Edit { delta: Delta },
+    /// A peer's name and caret.
+    Presence(Presence),
+    /// A peer left the room.
+    Bye { peer: ReplicaId },
+}
+
+impl Message {
+    pub fn encode(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn decode(text: &str) -> Result<Self, String> {
+        serde_json::from_str(text).map_err(|e| format!("Unreadable collaboration message: {e}"))
+    }
+}
+
+/// A Studio buffer as a text CRDT.
+#[derive(Debug, Clone)]
+pub struct CollabDoc {
+    crdt: YATA<char>,
+    /// The visible text, kept in step with `crdt`.
+    text: String,
+}
+
+impl CollabDoc {
+    pub fn new(replica: ReplicaId) -> Self {
+        Self {
+            crdt: YATA::new(replica),
+            text: String::new(),
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Bring the document to `text`, returning the change for peers, or
+    /// `None` if nothing changed.
+    ///
+    /// The edit is the span between the longest common prefix and suffix, so
+    /// typing, deleting and pasting each become one remove and/or one insert.
+    pub fn update(&mut self, text: &str) -> Option<Delta> {
+        if text == self.text {
+            return None;
+        }
+        let old: Vec<char> = self.text.chars().collect();
+        let new: Vec<char> = text.chars().collect();
+        let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+        let suffix = old[prefix..]
+            .iter()
+            .rev()
+            .zip(new[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        let removed = old.len() - prefix - suffix;
+        let mut delta = self.crdt.remove_range(prefix, removed);
+        let inserted = &new[prefix..new.len() - suffix];
+        if !inserted.is_empty() {
+            let run = self.crdt.insert_run(prefix, inserted.iter().copied());
+            delta.items.extend(run.items);
+            delta.clock = run.clock;
+        }
+        self.text = text.to_string();
+        Some(delta)
+    }
+
+    /// Merge a peer's change. Returns whether the text changed.
+    pub fn apply(&mut self, delta: &Delta) -> bool {
+        self.crdt.apply_delta(delta);
+        let text: String = self.crdt.iter().collect();
+        let changed = text != self.text;
+        self.text = text;
+        changed
+    }
+
+    /// The whole document as one delta.
+    pub fn state(&self) -> Delta {
+        self.crdt.delta_since(&VClock::new()).unwrap_or(YATADelta { items: Vec::new(), clock: 0 })
+    }
+
+    /// The anchor for a caret at char `offset`.
+    pub fn anchor_at(&self, offset: usize) -> Option<YataId> {
+        offset.checked_sub(1).and_then(|left| self.crdt.id_at(left))
+    }
+
+    /// The char offset of a caret at `anchor`. An anchor this replica hasn't
+    /// seen yet reads as the start of the buffer.
+    pub fn offset_of(&self, anchor: Option<YataId>) -> usize {
+        anchor.and_then(|id| self.crdt.index_after(id)).unwrap_or(0)
+    }
+}
+
+/// A peer's caret, placed in the local buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cursor {
+    pub peer: ReplicaId,
+    pub name: String,
+    pub color: &'static str,
+    /// Position in the buffer, in chars.
+    pub offset: usize,
+}
+
+/// The caret colour for `peer`.
+pub fn color_for(peer: ReplicaId) -> &'static str {
+    PALETTE[(peer % PALETTE.len() as u64) as usize]
+}
+
+/// How a peer's message played out locally.
+#[derive(Debug, Default)]
+pub struct Received {
+    /// Messages to send back to the room.
+    pub replies: Vec<Message>,
+    /// Whether the buffer text changed.
+    pub text_changed: bool,
+}
+
+/// One participant's side of a room: the shared buffer, this peer's presence,
+/// and what it knows of the others.
+#[derive(Debug, Clone)]
+pub struct Session {
+    room: Room,
+    doc: CollabDoc,
+    me: Presence,
+    peers: BTreeMap<ReplicaId, Presence>,
+}
+
+impl Session {
+    /// Open `room` around an existing buffer.
+    pub fn host(room: Room, peer: ReplicaId, text: &str) -> Self {
+        let mut session = Self::join(room, peer);
+        session.doc.update(text);
+        session
+    }
+
+    /// Join `room` with an empty buffer; its text arrives from the peers
+    /// already there.
+    pub fn join(room: Room, peer: ReplicaId) -> Self {
+        Self {
+            room,
+            doc: CollabDoc::new(peer),
+            me: Presence::new(peer),
+            peers: BTreeMap::new(),
+        }
+    }
+
+    pub fn room(&self) -> &Room {
+        &self.room
+    }
+
+    pub fn text(&self) -> &str {
+        self.doc.text()
+    }
+
+    /// What to send on every (re)connect: who this peer is, its whole buffer —
+    /// including anything edited offline — and its caret.
+    pub fn greeting(&self) -> Vec<Message> {
+        vec![
+            Message::Hello { peer: self.me.peer },
+            Message::Edit { delta: self.doc.state() },
+            Message::Presence(self.me.clone()),
+        ]
+    }
+
+    /// What to send when leaving the room.
+    pub fn farewell(&self) -> Message {
+        Message::Bye { peer: self.me.peer }
+    }
+
+    /// Record a local edit, returning the message for peers, or `None` if the
+    /// text didn't change.
+    pub fn edit(&mut self, text: &str) -> Option<Message> {
+        self.doc.update(text).map(|delta| Message::Edit { delta })
+    }
+
+    /// Record the local caret at char `offset`, returning the presence update
+    /// for peers if it moved.
+    pub fn move_caret(&mut self, offset: usize) -> Option<Message> {
+        let anchor = self.doc.anchor_at(offset);
+        if anchor == self.me.anchor {
+            return None;
+        }
+        self.me.anchor = anchor;
+        Some(Message::Presence(self.me.clone()))
+    }
+
+    /// Apply a message from a peer.
+    pub fn receive(&mut self, message: Message) -> Received {
+        let mut received = Received::default();
+        match message {
+            Message::Hello { peer } if peer != self.me.peer => {
+                received.replies.push(Message::Edit { delta: self.doc.state() });
+                received.replies.push(Message::Presence(self.me.clone()));
+            }
+            Message::Edit { delta } => received.text_changed = self.doc.apply(&delta),
+            Message::Presence(presence) if presence.peer != self.me.peer => {
+                self.peers.insert(presence.peer, presence);
+            }
+            Message::Bye { peer } => {
+                self.peers.remove(&peer);
+            }
+            Message::Hello { .. } | Message::Presence(_) => {}
+        }
+        received
+    }
+
+    /// The other peers' carets, where they sit in the buffer now.
+    pub fn cursors(&self) -> Vec<Cursor> {
+        self.peers
+            .values()
+            .map(|presence| Cursor {
+                peer: presence.peer,
+                name: presence.name.clone(),
+                color: color_for(presence.peer),
+                offset: self.doc.offset_of(presence.anchor),
+            })
+            .collect()
+    }
+
+    /// How many other peers are in the room.
+    pub fn peer_count(&self) -> usize {
+        self.peers.len()
+    }
+}
+
+/// What the relay connection reports.
+#[derive(Debug)]
+pub enum RelayEvent {
+    /// Connected, or reconnected after a drop; time to send the greeting.
+    Online,
+    /// The connection dropped; a reconnect is scheduled.
+    Offline,
+    Message(Message),
+}
+
+/// A live connection to a room on the relay.
+///
+/// Reconnects by itself after a drop, backing off from [`FIRST_RETRY_MS`] to
+/// [`MAX_RETRY_MS`]. Messages sent while offline are dropped — the greeting
+/// after the next [`RelayEvent::Online`] carries the whole buffer anyway.
+pub struct Relay {
+    #[cfg(target_arch = "wasm32")]
+    socket: std::rc::Rc<socket::Socket>,
+}
+
+impl Relay {
+    /// Connect to `room`, reporting every event to `on_event`.
+    pub fn connect(room: &Room, on_event: impl FnMut(RelayEvent) + 'static) -> Result<Self, String> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let socket = socket::Socket::new(room.relay_url(), Box::new(on_event));
+            socket.open()?;
+            Ok(Relay { socket })
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = (room, on_event);
+            Err("Live collaboration is only available in the browser".to_string())
+        }
+    }
+
+    pub fn send(&self, message: &Message) {
+        #[cfg(target_arch = "wasm32")]
+        self.socket.send(&message.encode());
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = message;
+    }
+
+    /// Leave the room for good: no more events, no reconnects.
+    pub fn close(self) {
+        #[cfg(target_arch = "wasm32")]
+        self.socket.close();
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod socket {
+    use super::{Message, RelayEvent, FIRST_RETRY_MS, MAX_RETRY_MS};
+    use std::cell::{Cell, RefCell};
+    use std::rc::{Rc, Weak};
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen::JsCast;
+
+    pub(super) struct Socket {
+        url: String,
+        ws: RefCell<Option<web_sys::WebSocket>>,
+        on_event: RefCell<Box<dyn FnMut(RelayEvent)>>,
+        retry_ms: Cell<u32>,
+        closed: Cell<bool>,
+        /// Shared by every WebSocket this connection opens, and kept as long
+        /// as one of them may call back.
+        callbacks: RefCell<Option<Callbacks>>,
+    }
+
+    struct Callbacks {
+        onopen: Closure<dyn FnMut(web_sys::Event)>,
+        onmessage: Closure<dyn FnMut(web_sys::MessageEvent)>,
+        onclose: Closure<dyn FnMut(web_sys::CloseEvent)>,
+    }
+
+    impl Callbacks {
+        fn new(socket: Weak<Socket>) -> Self {
+            let opened = socket.clone();
+            let received = socket.clone();
+            Self {
+                onopen: Closure::new(move |_: web_sys::Event| {
+                    if let Some(socket) = opened.upgrade() {
+                        socket.retry_ms.set(FIRST_RETRY_MS);
+                        socket.emit(RelayEvent::Online);
+                    }
+                }),
+                onmessage: Closure::new(move |event: web_sys::MessageEvent| {
+                    let Some(socket) = received.upgrade() else { return };
+                    // Anything that isn't one of ours is ignored
+                    if let Some(message) = event.data().as_string().and_then(|text| Message::decode(&text).ok()) {
+                        socket.emit(RelayEvent::Message(message));
+                    }
+                }),
+                onclose: Closure::new(move |_: web_sys::CloseEvent| {
+                    if let Some(socket) = socket.upgrade() {
+                        socket.dropped();
+                    }
+                }),
+            }
+        }
+    }
+
+    impl Socket {
+        pub(super) fn new(url: String, on_event: Box<dyn FnMut(RelayEvent)>) -> Rc<Self> {
+            Rc::new(Self {
+                url,
+                ws: RefCell::new(None),
+                on_event: RefCell::new(on_event),
+                retry_ms: Cell::new(FIRST_RETRY_MS),
+                closed: Cell::new(false),
+                callbacks: RefCell::new(None),
+            })
+        }
+
+        pub(super) fn open(self: &Rc<Self>) -> Result<(), String> {
+            let ws = web_sys::WebSocket::new(&self.url).map_err(|e| format!("Could not reach the relay: {e:?}"))?;
+            let mut callbacks = self.callbacks.borrow_mut();
+            let callbacks = callbacks.get_or_insert_with(|| Callbacks::new(Rc::downgrade(self)));
+            ws.set_onopen(Some(callbacks.onopen.as_ref().unchecked_ref()));
+            ws.set_onmessage(Some(callbacks.onmessage.as_ref().unchecked_ref()));
+            ws.set_onclose(Some(callbacks.onclose.as_ref().unchecked_ref()));
+            *self.ws.borrow_mut() = Some(ws);
+            Ok(())
+        }
+
+        pub(super) fn send(&self, text: &str) {
+            if let Some(ws) = self.ws.borrow().as_ref() {
+                if ws.ready_state() == web_sys::WebSocket::OPEN {
+                    let _ = ws.send_with_str(text);
+                }
+            }
+        }
+
+        pub(super) fn close(&self) {
+            self.closed.set(true);
+            if let Some(ws) = self.ws.borrow_mut().take() {
+                // Detach first: the callbacks go away with this connection
+                ws.set_onopen(None);
+                ws.set_onmessage(None);
+                ws.set_onclose(None);
+                let _ = ws.close();
+            }
+        }
+
+        fn emit(&self, event: RelayEvent) {
+            (self.on_event.borrow_mut())(event);
+        }
+
+        fn dropped(self: &Rc<Self>) {
+            self.ws.borrow_mut().take();
+            if self.closed.get() {
+                return;
+            }
+            self.emit(RelayEvent::Offline);
+            let delay = self.retry_ms.get();
+            self.retry_ms.set((delay * 2).min(MAX_RETRY_MS));
+            let socket = Rc::downgrade(self);
+            gloo_timers::callback::Timeout::new(delay, move || {
+                let Some(socket) = socket.upgrade() else { return };
+                if !socket.closed.get() && socket.open().is_err() {
+                    socket.dropped();
+                }
+            })
+            .forget();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deliver every message to every other session, until nobody has anything to say.
+    fn relay(sessions: &mut [&mut Session], mut outbox: Vec<(usize, Message)>) {
+        while let Some((from, message)) = outbox.pop() {
+            for (to, session) in sessions.iter_mut().enumerate() {
+                if to != from {
+                    let received = session.receive(message.clone());
+                    outbox.extend(received.replies.into_iter().map(|reply| (to, reply)));
+                }
+            }
+        }
+    }
+
+    /// Session `from` (re)connects and greets the room.
+    fn greet(sessions: &mut [&mut Session], from: usize) {
+        let outbox = sessions[from].greeting().into_iter().map(|m| (from, m)).collect();
+        relay(sessions, outbox);
+    }
+
+    #[test]
+    fn test_joining_receives_the_hosts_buffer() {
+        let room = Room::random();
+        let mut host = Session::host(room.clone(), 1, "Every cat sleeps.");
+        let mut guest = Session::join(room, 2);
+        greet(&mut [&mut host, &mut guest], 1);
+        assert_eq!(guest.text(), "Every cat sleeps.");
+        assert_eq!(host.peer_count(), 1);
+        assert_eq!(guest.peer_count(), 1);
+    }
+
+    #[test]
+    fn test_concurrent_edits_converge() {
+        let room = Room::random();
+        let mut a = Session::host(room.clone(), 1, "Show 1.\nShow 2.\n");
+        let mut b = Session::join(room, 2);
+        greet(&mut [&mut a, &mut b], 1);
+
+        let from_a = a.edit("Show 10.\nShow 2.\n").unwrap();
+        let from_b = b.edit("Show 1.\nShow 2.\nShow 3.\n").unwrap();
+        a.receive(from_b);
+        b.receive(from_a);
+        assert_eq!(a.text(), "Show 10.\nShow 2.\nShow 3.\n");
+        assert_eq!(a.text(), b.text());
+    }
+
+    #[test]
+    fn test_offline_edits_merge_on_reconnect() {
+        let room = Room::random();
+        let mut a = Session::host(room.clone(), 1, "abc");
+        let mut b = Session::join(room, 2);
+        greet(&mut [&mut a, &mut b], 1);
+
+        // Both edit while the relay is down; nothing is delivered
+        a.edit("xabc");
+        b.edit("ab");
+        // b reconnects
+        greet(&mut [&mut a, &mut b], 1);
+        assert_eq!(a.text(), "xab");
+        assert_eq!(b.text(), "xab");
+    }
+
+    #[test]
+    fn test_remote_carets_stay_with_their_text() {
+        let room = Room::random();
+        let mut a = Session::host(room.clone(), 1, "one\ntwo\n");
+        let mut b = Session::join(room, 2);
+        greet(&mut [&mut a, &mut b], 1);
+
+        // b parks its caret after "two"
+        let moved = b.move_caret(7).unwrap();
+        a.receive(moved);
+        assert_eq!(a.cursors()[0].offset, 7);
+
+        // a inserts a line above it; the caret moves down with "two"
+        a.edit("zero\none\ntwo\n");
+        assert_eq!(a.cursors()[0].offset, 12);
+        assert_eq!(a.cursors()[0].color, color_for(2));
+    }
+
+    #[test]
+    fn test_messages_roundtrip_through_json() {
+        let mut session = Session::host(Room::random(), 7, "Hi");
+        let edit = session.edit("Hi!").unwrap();
+        let decoded = Message::decode(&edit.encode()).unwrap();
+        let mut other = Session::join(session.room().clone(), 8);
+        for message in session.greeting() {
+            other.receive(Message::decode(&message.encode()).unwrap());
+        }
+        other.receive(decoded);
+        assert_eq!(other.text(), "Hi!");
+        assert!(Message::decode("{\"type\":\"shout\"}").is_err());
+    }
+
+    #[test]
+    fn test_room_fragments() {
+        let room = Room::random();
+        assert_eq!(Room::parse(&format!("#{}", room.fragment())), Some(room.clone()));
+        assert!(room.relay_url().starts_with(RELAY_URL));
+        assert_eq!(Room::parse("#collab="), None);
+        assert_eq!(Room::parse("#collab=../x"), None);
+        assert_eq!(Room::parse("#code=abc"), None);
+    }
+}
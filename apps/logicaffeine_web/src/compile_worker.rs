@@ -0,0 +1,475 @@
+//! Compile Worker Bridge
+//!
+//! Runs the Studio's compiles, Rust previews and interpreter runs in a
+//! dedicated web worker, so a long compile or a slow program never blocks the
+//! page:
+//! - The worker (`/assets/compile-worker.js`) instantiates its own copy of
+//!   this app from the page's already-compiled wasm module, and calls the
+//!   `worker_*` exports below, which answer in JSON
+//! - Requests travel on a [`Channel`]. A newer request supersedes an older one
+//!   on the same channel: a queued request is dropped unrun, and a running one
+//!   is stopped by terminating the worker (a fresh one takes its place), so
+//!   only the latest edit's result ever lands
+//! - Where no worker can run — native builds, SSR, a browser without module
+//!   workers — the call runs inline on the current thread instead
+//!
+//! The Studio's Run streams its output back a line at a time. Its file I/O goes
+//! through the worker's own VFS handle, which opens the same OPFS (or
+//! IndexedDB) storage as the page's.
+//!
+//! # Usage
+//!
+//! ```no_run
+//! # async fn demo() {
+//! use logicaffeine_web::compile_worker;
+//!
+//! // `None`: a newer compile on the same channel replaced this one
+//! if let Some(result) = compile_worker::compile("Every cat sleeps.".to_string()).await {
+//!     println!("{:?}", result.logic);
+//! }
+//! # }
+//! ```
+
+use crate::telemetry::timed;
+use logicaffeine_compile::{CompileResult, InterpreterResult, RustPreview};
+use logicaffeine_system::fs::Vfs;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// A lane of superseding requests: each holds at most one live request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Channel {
+    Compile,
+    Interpret,
+    Preview,
+    Run,
+}
+
+/// A program for the Studio's Run button.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RunRequest {
+    pub code: String,
+    /// The file `code` came from, when it runs as a project rooted there.
+    pub entry: Option<String>,
+    /// The project's sources by path, for the entry's imports.
+    pub files: HashMap<PathBuf, String>,
+}
+
+/// Compile English for the Studio (see [`logicaffeine_compile::compile_for_ui`]),
+/// or `None` when a newer compile superseded this one.
+pub async fn compile(input: String) -> Option<CompileResult> {
+    compile_timed(input).await.map(|(result, _)| result)
+}
+
+/// [`compile`], with how long the compile took in milliseconds. The clock
+/// starts at the worker's first message for the request, so neither the wait
+/// behind other requests nor a fresh worker's start-up is counted.
+pub async fn compile_timed(input: String) -> Option<(CompileResult, f64)> {
+    match offload(Channel::Compile, "worker_compile_for_ui", &input, compile_failed, None).await {
+        Offloaded::Done(result, ms) => Some((result, ms)),
+        Offloaded::Superseded => None,
+        Offloaded::Inline => Some(timed(|| logicaffeine_compile::compile_for_ui(&input))),
+    }
+}
+
+/// Run a LOGOS program on the Studio's low-latency baseline tier (see
+/// [`logicaffeine_compile::interpret_for_ui_baseline`]), or `None` when a newer
+/// run superseded this one.
+pub async fn interpret(code: String) -> Option<InterpreterResult> {
+    match offload(Channel::Interpret, "worker_interpret", &code, interpret_failed, None).await {
+        Offloaded::Done(result, _) => Some(result),
+        Offloaded::Superseded => None,
+        Offloaded::Inline => Some(logicaffeine_compile::interpret_for_ui_baseline(&code).await),
+    }
+}
+
+/// The side-by-side Rust preview (see
+/// [`logicaffeine_compile::generate_rust_preview`]), with the compile error as
+/// text, or `None` when a newer edit superseded this one.
+pub async fn rust_preview(code: String) -> Option<Result<RustPreview, String>> {
+    match offload(Channel::Preview, "worker_rust_preview", &code, Err, None).await {
+        Offloaded::Done(result, _) => Some(result),
+        Offloaded::Superseded => None,
+        Offloaded::Inline => Some(preview_inline(&code)),
+    }
+}
+
+/// Run a program with file I/O, handing each output line to `on_line` as it
+/// is printed, or `None` when a newer run superseded this one. `vfs` serves
+/// the file I/O only when the run falls back inline.
+pub async fn run<F>(request: RunRequest, vfs: Option<Arc<dyn Vfs>>, on_line: F) -> Option<InterpreterResult>
+where
+    F: FnMut(String) + 'static,
+{
+    let on_line = Rc::new(RefCell::new(on_line));
+    let forward = {
+        let on_line = on_line.clone();
+        Box::new(move |line: String| (on_line.borrow_mut())(line)) as Box<dyn FnMut(String)>
+    };
+    let input = serde_json::to_string(&request).unwrap_or_default();
+    match offload(Channel::Run, "worker_run", &input, interpret_failed, Some(forward)).await {
+        Offloaded::Done(result, _) => Some(result),
+        Offloaded::Superseded => None,
+        Offloaded::Inline => Some(run_program(request, on_line, vfs).await),
+    }
+}
+
+async fn run_program<F>(request: RunRequest, on_line: Rc<RefCell<F>>, vfs: Option<Arc<dyn Vfs>>) -> InterpreterResult
+where
+    F: FnMut(String) + 'static,
+{
+    match request.entry {
+        Some(entry) => logicaffeine_compile::interpret_streaming_project(&entry, request.files, on_line, vfs).await,
+        None => logicaffeine_compile::interpret_streaming_with_vfs(&request.code, on_line, vfs).await,
+    }
+}
+
+fn preview_inline(code: &str) -> Result<RustPreview, String> {
+    logicaffeine_compile::generate_rust_preview(code).map_err(|e| format!("Compile error: {:?}", e))
+}
+
+fn compile_failed(error: String) -> CompileResult {
+    CompileResult {
+        logic: None,
+        simple_logic: None,
+        kripke_logic: None,
+        ast: None,
+        readings: Vec::new(),
+        simple_readings: Vec::new(),
+        kripke_readings: Vec::new(),
+        tokens: Vec::new(),
+        error: Some(error),
+    }
+}
+
+fn interpret_failed(error: String) -> InterpreterResult {
+    InterpreterResult { lines: Vec::new(), error: Some(error), memory: None, limit_exceeded: None }
+}
+
+enum Offloaded<T> {
+    /// The answer, and the milliseconds since the worker started on it.
+    Done(T, f64),
+    Superseded,
+    /// No worker: run it here.
+    Inline,
+}
+
+async fn offload<T: DeserializeOwned>(
+    channel: Channel,
+    op: &'static str,
+    input: &str,
+    failed: fn(String) -> T,
+    on_line: Option<Box<dyn FnMut(String)>>,
+) -> Offloaded<T> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        match bridge::request(channel, op, input.to_string(), on_line).await {
+            bridge::Reply::Done(json, ms) => Offloaded::Done(
+                serde_json::from_str(&json).unwrap_or_else(|e| failed(format!("Unreadable worker reply: {e}"))),
+                ms,
+            ),
+            bridge::Reply::Crashed(message, ms) => {
+                Offloaded::Done(failed(format!("The compiler crashed: {message}")), ms)
+            }
+            bridge::Reply::Superseded => Offloaded::Superseded,
+            bridge::Reply::Unavailable => Offloaded::Inline,
+        }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (channel, op, input, failed, on_line);
+        Offloaded::Inline
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// Worker side: the exports `compile-worker.js` calls
+// ═══════════════════════════════════════════════════════════════════
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn worker_compile_for_ui(input: String) -> String {
+    serde_json::to_string(&logicaffeine_compile::compile_for_ui(&input)).unwrap_or_default()
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn worker_rust_preview(code: String) -> String {
+    serde_json::to_string(&preview_inline(&code)).unwrap_or_default()
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub async fn worker_interpret(code: String) -> String {
+    serde_json::to_string(&logicaffeine_compile::interpret_for_ui_baseline(&code).await).unwrap_or_default()
+}
+
+/// Runs a [`RunRequest`], calling `emit` with each output line.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub async fn worker_run(input: String, emit: js_sys::Function) -> String {
+    let result = match serde_json::from_str::<RunRequest>(&input) {
+        Ok(request) => {
+            let on_line = Rc::new(RefCell::new(move |line: String| {
+                let _ = emit.call1(&wasm_bindgen::JsValue::NULL, &line.into());
+            }));
+            run_program(request, on_line, worker_vfs().await).await
+        }
+        Err(e) => interpret_failed(format!("Unreadable run request: {e}")),
+    };
+    serde_json::to_string(&result).unwrap_or_default()
+}
+
+/// This worker's VFS, opened on its first run and kept for the next.
+#[cfg(target_arch = "wasm32")]
+async fn worker_vfs() -> Option<Arc<dyn Vfs>> {
+    use logicaffeine_system::fs::{get_platform_vfs_with_fallback, WebVfs};
+
+    thread_local! {
+        static VFS: RefCell<Option<WebVfs>> = RefCell::new(None);
+    }
+    let vfs = match VFS.with(|vfs| vfs.borrow().clone()) {
+        Some(vfs) => vfs,
+        None => {
+            let vfs = get_platform_vfs_with_fallback().await.ok()?;
+            VFS.with(|cached| *cached.borrow_mut() = Some(vfs.clone()));
+            vfs
+        }
+    };
+    Some(Arc::new(vfs))
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// Page side: one worker, one request in flight
+// ═══════════════════════════════════════════════════════════════════
+
+#[cfg(target_arch = "wasm32")]
+mod bridge {
+    use super::Channel;
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::rc::{Rc, Weak};
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    const WORKER_URL: &str = "/assets/compile-worker.js";
+
+    /// How a request ended.
+    pub(super) enum Reply {
+        /// The export's JSON answer, and the milliseconds since the worker
+        /// reported starting on it.
+        Done(String, f64),
+        Superseded,
+        /// The worker's instance trapped; it has been replaced.
+        Crashed(String, f64),
+        /// No worker can run in this page.
+        Unavailable,
+    }
+
+    struct Job {
+        id: u32,
+        channel: Channel,
+        op: &'static str,
+        input: String,
+        /// Where the lines a streaming request prints go.
+        on_line: Option<Box<dyn FnMut(String)>>,
+        /// When the worker reported starting on this request.
+        started: Option<f64>,
+        slot: Rc<RefCell<Option<Reply>>>,
+        resolve: js_sys::Function,
+    }
+
+    impl Job {
+        fn elapsed(&self) -> f64 {
+            self.started.map_or(0.0, |started| js_sys::Date::now() - started)
+        }
+
+        fn finish(self, reply: Reply) {
+            *self.slot.borrow_mut() = Some(reply);
+            let _ = self.resolve.call0(&JsValue::NULL);
+        }
+    }
+
+    struct Bridge {
+        /// The `{ glue, module }` a worker instantiates from; `None` once the
+        /// worker is known not to work here.
+        init: Option<JsValue>,
+        worker: Option<web_sys::Worker>,
+        /// Shared by every worker this bridge spawns, and never dropped: it may
+        /// be the very callback that is running when a worker is replaced.
+        onmessage: Option<Closure<dyn FnMut(web_sys::MessageEvent)>>,
+        in_flight: Option<Job>,
+        queue: VecDeque<Job>,
+        next_id: u32,
+    }
+
+    thread_local! {
+        static BRIDGE: Rc<RefCell<Bridge>> = Rc::new(RefCell::new(Bridge {
+            init: init_message(),
+            worker: None,
+            onmessage: None,
+            in_flight: None,
+            queue: VecDeque::new(),
+            next_id: 0,
+        }));
+    }
+
+    /// The URL of this app's wasm-bindgen glue. dx content-hashes its name
+    /// (`logicaffeine-web-<hash>.js`, see `public/_headers`), so it is looked up
+    /// among the resources the page has loaded.
+    fn glue_url() -> Option<String> {
+        let performance = web_sys::window()?.performance()?;
+        performance
+            .get_entries_by_type("resource")
+            .iter()
+            .filter_map(|entry| entry.dyn_into::<web_sys::PerformanceEntry>().ok())
+            .map(|entry| entry.name())
+            .find(|url| {
+                let file = url.split('?').next().unwrap_or(url).rsplit('/').next().unwrap_or("");
+                file.starts_with("logicaffeine-web") && file.ends_with(".js")
+            })
+    }
+
+    fn init_message() -> Option<JsValue> {
+        let init = js_sys::Object::new();
+        js_sys::Reflect::set(&init, &"glue".into(), &glue_url()?.into()).ok()?;
+        js_sys::Reflect::set(&init, &"module".into(), &wasm_bindgen::module()).ok()?;
+        Some(init.into())
+    }
+
+    pub(super) async fn request(
+        channel: Channel,
+        op: &'static str,
+        input: String,
+        on_line: Option<Box<dyn FnMut(String)>>,
+    ) -> Reply {
+        let slot = Rc::new(RefCell::new(None));
+        let mut resolve = None;
+        let done = js_sys::Promise::new(&mut |res, _| resolve = Some(res));
+        let Some(resolve) = resolve else { return Reply::Unavailable };
+        BRIDGE.with(|handle| {
+            let mut bridge = handle.borrow_mut();
+            let id = bridge.next_id;
+            bridge.next_id = id.wrapping_add(1);
+            let job = Job { id, channel, op, input, on_line, started: None, slot: slot.clone(), resolve };
+            bridge.submit(Rc::downgrade(handle), job);
+        });
+        let _ = JsFuture::from(done).await;
+        let reply = slot.borrow_mut().take();
+        reply.unwrap_or(Reply::Unavailable)
+    }
+
+    impl Bridge {
+        fn submit(&mut self, handle: Weak<RefCell<Bridge>>, job: Job) {
+            // A queued request on this channel is dropped without ever running
+            if let Some(stale) = self.queue.iter().position(|queued| queued.channel == job.channel) {
+                if let Some(stale) = self.queue.remove(stale) {
+                    stale.finish(Reply::Superseded);
+                }
+            }
+            // A running one can't be interrupted: replace the worker under it
+            if self.in_flight.as_ref().is_some_and(|running| running.channel == job.channel) {
+                self.kill();
+                if let Some(stale) = self.in_flight.take() {
+                    stale.finish(Reply::Superseded);
+                }
+            }
+            self.queue.push_back(job);
+            self.dispatch(handle);
+        }
+
+        fn kill(&mut self) {
+            if let Some(worker) = self.worker.take() {
+                worker.terminate();
+            }
+        }
+
+        fn spawn(&mut self, handle: Weak<RefCell<Bridge>>) -> bool {
+            let Some(init) = self.init.clone() else { return false };
+            let opts = web_sys::WorkerOptions::new();
+            opts.set_type(web_sys::WorkerType::Module);
+            let Ok(worker) = web_sys::Worker::new_with_options(WORKER_URL, &opts) else {
+                self.init = None;
+                return false;
+            };
+            let onmessage = self.onmessage.get_or_insert_with(|| {
+                Closure::new(move |event: web_sys::MessageEvent| {
+                    if let Some(bridge) = handle.upgrade() {
+                        let weak = Rc::downgrade(&bridge);
+                        bridge.borrow_mut().receive(weak, event.data());
+                    }
+                })
+            });
+            worker.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+            let message = js_sys::Object::new();
+            let _ = js_sys::Reflect::set(&message, &"init".into(), &init);
+            if worker.post_message(&message).is_err() {
+                worker.terminate();
+                self.init = None;
+                return false;
+            }
+            self.worker = Some(worker);
+            true
+        }
+
+        /// Send the next queued request if the worker is idle.
+        fn dispatch(&mut self, handle: Weak<RefCell<Bridge>>) {
+            if self.in_flight.is_some() || self.queue.is_empty() {
+                return;
+            }
+            if self.worker.is_none() && !self.spawn(handle.clone()) {
+                for job in self.queue.drain(..) {
+                    job.finish(Reply::Unavailable);
+                }
+                return;
+            }
+            let Some(job) = self.queue.pop_front() else { return };
+            let message = js_sys::Object::new();
+            let _ = js_sys::Reflect::set(&message, &"id".into(), &job.id.into());
+            let _ = js_sys::Reflect::set(&message, &"op".into(), &job.op.into());
+            let _ = js_sys::Reflect::set(&message, &"input".into(), &job.input.as_str().into());
+            match self.worker.as_ref().map(|worker| worker.post_message(&message)) {
+                Some(Ok(())) => self.in_flight = Some(job),
+                _ => job.finish(Reply::Unavailable),
+            }
+        }
+
+        fn receive(&mut self, handle: Weak<RefCell<Bridge>>, data: JsValue) {
+            let field = |key: &str| js_sys::Reflect::get(&data, &key.into()).ok();
+            let id = field("id").and_then(|v| v.as_f64()).map(|n| n as u32);
+            let Some(job) = self.in_flight.as_mut().filter(|job| id == Some(job.id)) else { return };
+            // Progress: the request has started, or printed a line
+            if field("started").is_some_and(|v| v.is_truthy()) {
+                job.started = Some(js_sys::Date::now());
+                return;
+            }
+            if let Some(line) = field("line").and_then(|v| v.as_string()) {
+                if let Some(on_line) = job.on_line.as_mut() {
+                    on_line(line);
+                }
+                return;
+            }
+            let Some(job) = self.in_flight.take() else { return };
+            let ms = job.elapsed();
+            if let Some(json) = field("result").and_then(|v| v.as_string()) {
+                job.finish(Reply::Done(json, ms));
+            } else if let Some(message) = field("panic").and_then(|v| v.as_string()) {
+                // A trapped instance is unusable; the next request spawns afresh
+                self.kill();
+                job.finish(Reply::Crashed(message, ms));
+            } else {
+                // The app could not be instantiated in a worker: stay inline from now on
+                self.kill();
+                self.init = None;
+                job.finish(Reply::Unavailable);
+            }
+            self.dispatch(handle);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use include_dir::{include_dir, Dir};
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::sync::RwLock;
 
 // Curriculum content lives under assets/curriculum/ (01_first-steps/, 02_building-blocks/, etc.)
 // — scoped so images and other assets never ride into the wasm binary.
@@ -41,6 +42,82 @@ pub struct ExerciseConfig {
     pub options: Option<Vec<String>>,
     #[serde(default)]
     pub correct: Option<usize>,
+    /// Instruction text in other languages, keyed by locale code (`"es"`,
+    /// `"ar"`). The logic itself — template, options, answers — is shared.
+    #[serde(default)]
+    pub translations: HashMap<String, ExerciseText>,
+}
+
+/// The locale exercise text is written in.
+pub const SOURCE_LOCALE: &str = "en";
+
+/// An exercise's instruction text in one locale. A field left out falls back
+/// to the English source.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+pub struct ExerciseText {
+    #[serde(default)]
+    pub prompt: Option<String>,
+    #[serde(default)]
+    pub hint: Option<String>,
+    #[serde(default)]
+    pub explanation: Option<String>,
+}
+
+impl ExerciseConfig {
+    /// The translation for `locale`: an exact match (`"es-MX"`), else its
+    /// language (`"es"`).
+    fn text_for(&self, locale: &str) -> Option<&ExerciseText> {
+        self.translations.get(locale).or_else(|| {
+            let language = locale.split(['-', '_']).next()?;
+            self.translations.get(language)
+        })
+    }
+
+    /// This exercise with its prompt, hint and explanation in `locale` where
+    /// translated, and in English where not.
+    pub fn localized(&self, locale: &str) -> ExerciseConfig {
+        let mut exercise = self.clone();
+        if let Some(text) = self.text_for(locale) {
+            if let Some(prompt) = &text.prompt {
+                exercise.prompt = prompt.clone();
+            }
+            if text.hint.is_some() {
+                exercise.hint = text.hint.clone();
+            }
+            if text.explanation.is_some() {
+                exercise.explanation = text.explanation.clone();
+            }
+        }
+        exercise
+    }
+
+    /// The fields with English text but no translation into `locale`.
+    pub fn untranslated(&self, locale: &str) -> Vec<&'static str> {
+        if locale == SOURCE_LOCALE {
+            return Vec::new();
+        }
+        let text = self.text_for(locale);
+        let mut missing = Vec::new();
+        if text.and_then(|t| t.prompt.as_ref()).is_none() {
+            missing.push("prompt");
+        }
+        if self.hint.is_some() && text.and_then(|t| t.hint.as_ref()).is_none() {
+            missing.push("hint");
+        }
+        if self.explanation.is_some() && text.and_then(|t| t.explanation.as_ref()).is_none() {
+            missing.push("explanation");
+        }
+        missing
+    }
+}
+
+/// An exercise field that has no translation into some locale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslationGap {
+    pub era_id: String,
+    pub module_id: String,
+    pub exercise_id: String,
+    pub field: &'static str,
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
@@ -108,16 +185,19 @@ pub struct Section {
     pub key_symbols: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Module {
     pub meta: ModuleMeta,
+    #[serde(default)]
     pub exercises: Vec<ExerciseConfig>,
+    #[serde(default)]
     pub sections: Vec<Section>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Era {
     pub meta: EraMeta,
+    #[serde(default)]
     pub modules: Vec<Module>,
 }
 
@@ -126,13 +206,165 @@ pub struct Curriculum {
     pub eras: Vec<Era>,
 }
 
+impl Curriculum {
+    /// Layer a pack over this curriculum. An era or module whose id is already
+    /// present replaces it; a new id is added in `order` position. Eras in a
+    /// pack need only carry the modules they change.
+    pub fn apply_pack(&mut self, pack: &CurriculumPack) {
+        for era in &pack.eras {
+            match self.eras.iter_mut().find(|e| e.meta.id == era.meta.id) {
+                Some(existing) => {
+                    existing.meta = era.meta.clone();
+                    for module in &era.modules {
+                        match existing.modules.iter_mut().find(|m| m.meta.id == module.meta.id) {
+                            Some(m) => *m = module.clone(),
+                            None => existing.modules.push(module.clone()),
+                        }
+                    }
+                    existing.modules.sort_by_key(|m| m.meta.order);
+                }
+                None => self.eras.push(era.clone()),
+            }
+        }
+        self.eras.sort_by_key(|e| e.meta.order);
+    }
+}
+
+/// Curriculum content shipped outside the wasm bundle.
+///
+/// Fetched from [`CURRICULUM_PACK_URL`] by [`refresh_curriculum_pack`] and
+/// layered over the embedded curriculum, so content fixes and new eras go
+/// live without an app release:
+///
+/// ```json
+/// { "version": 3, "eras": [ { "meta": { "id": "first-steps", ... },
+///   "modules": [ { "meta": { ... }, "exercises": [ ... ], "sections": [ ... ] } ] } ] }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct CurriculumPack {
+    /// Monotonic pack version; an older pack never replaces a newer one.
+    pub version: u64,
+    #[serde(default)]
+    pub eras: Vec<Era>,
+}
+
+impl CurriculumPack {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let mut pack: CurriculumPack =
+            serde_json::from_str(json).map_err(|e| format!("parsing curriculum pack: {e}"))?;
+        for module in pack.eras.iter_mut().flat_map(|e| e.modules.iter_mut()) {
+            module.exercises.sort_by(|a, b| a.id.cmp(&b.id));
+            module.sections.sort_by_key(|s| s.order);
+        }
+        Ok(pack)
+    }
+}
+
+/// Where [`refresh_curriculum_pack`] looks for content updates, next to the
+/// app bundle like the rest of `/data/`. Staged from `curriculum-pack.json` by
+/// `scripts/stage-web-data.sh`.
+pub const CURRICULUM_PACK_URL: &str = "/data/curriculum-pack.json";
+
+// Set by the first `refresh_curriculum_pack` of the session; every mount of
+// a `LexiconGate` calls it, but the pack is revalidated only once.
+#[cfg(target_arch = "wasm32")]
+static PACK_REFRESHED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+// The pack every `ContentEngine::new()` layers over the embedded curriculum.
+// Swapping it is the hot reload: pages pick the new content up on their next
+// render.
+static INSTALLED_PACK: RwLock<Option<CurriculumPack>> = RwLock::new(None);
+
+/// Make `pack` the live content pack. Returns `false`, leaving the current
+/// pack in place, if `pack` is older than it.
+pub fn install_pack(pack: CurriculumPack) -> bool {
+    let Ok(mut installed) = INSTALLED_PACK.write() else {
+        return false;
+    };
+    if installed.as_ref().is_some_and(|p| p.version > pack.version) {
+        return false;
+    }
+    *installed = Some(pack);
+    true
+}
+
+/// The version of the live content pack, if one is installed.
+pub fn installed_pack_version() -> Option<u64> {
+    INSTALLED_PACK.read().ok()?.as_ref().map(|p| p.version)
+}
+
+/// The last fetched pack as cached in LocalStorage, keyed by its ETag so the
+/// next refresh can be a conditional request.
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Clone, serde::Serialize, Deserialize)]
+struct PackCache {
+    etag: Option<String>,
+    body: String,
+}
+
+/// Install the cached pack, then revalidate it against `url` and install
+/// whatever is newer. Resolves to the live pack version. Only the first call
+/// of a session fetches; later calls resolve to the live version at once.
+/// Native builds have no remote content and resolve to `Ok(None)` at once.
+///
+/// Failure is never fatal to the caller: the cached or embedded curriculum
+/// stays live.
+pub async fn refresh_curriculum_pack(url: &str) -> Result<Option<u64>, String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if PACK_REFRESHED.swap(true, std::sync::atomic::Ordering::Relaxed) {
+            return Ok(installed_pack_version());
+        }
+        let cached: Option<PackCache> = crate::storage::load_curriculum_pack()
+            .and_then(|json| serde_json::from_str(&json).ok());
+        if let Some(pack) = cached.as_ref().and_then(|c| CurriculumPack::from_json(&c.body).ok()) {
+            install_pack(pack);
+        }
+
+        let mut request = gloo_net::http::Request::get(url);
+        if let Some(etag) = cached.as_ref().and_then(|c| c.etag.as_deref()) {
+            request = request.header("If-None-Match", etag);
+        }
+        let response = request.send().await.map_err(|e| format!("fetching {url}: {e}"))?;
+        if response.status() == 304 {
+            return Ok(installed_pack_version());
+        }
+        if !response.ok() {
+            return Err(format!("fetching {url}: HTTP {}", response.status()));
+        }
+        let etag = response.headers().get("ETag");
+        let body = response.text().await.map_err(|e| format!("reading {url}: {e}"))?;
+        if install_pack(CurriculumPack::from_json(&body)?) {
+            if let Ok(json) = serde_json::to_string(&PackCache { etag, body }) {
+                crate::storage::save_curriculum_pack(&json);
+            }
+        }
+        Ok(installed_pack_version())
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = url;
+        Ok(None)
+    }
+}
+
 pub struct ContentEngine {
     pub curriculum: Curriculum,
 }
 
 impl ContentEngine {
+    /// The embedded curriculum with the installed pack, if any, layered on top.
     pub fn new() -> Self {
-        let curriculum = Self::load_curriculum();
+        match INSTALLED_PACK.read().ok().as_ref().and_then(|p| p.as_ref()) {
+            Some(pack) => Self::with_pack(pack),
+            None => Self { curriculum: Self::load_curriculum() },
+        }
+    }
+
+    /// The embedded curriculum with `pack` layered on top.
+    pub fn with_pack(pack: &CurriculumPack) -> Self {
+        let mut curriculum = Self::load_curriculum();
+        curriculum.apply_pack(pack);
         Self { curriculum }
     }
 
@@ -223,6 +455,25 @@ impl ContentEngine {
             .find(|e| e.id == exercise_id)
     }
 
+    /// Every exercise field left untranslated into `locale`, in curriculum
+    /// order — the checklist for a translator.
+    pub fn translation_gaps(&self, locale: &str) -> Vec<TranslationGap> {
+        let mut gaps = Vec::new();
+        for era in &self.curriculum.eras {
+            for module in &era.modules {
+                for exercise in &module.exercises {
+                    gaps.extend(exercise.untranslated(locale).into_iter().map(|field| TranslationGap {
+                        era_id: era.meta.id.clone(),
+                        module_id: module.meta.id.clone(),
+                        exercise_id: exercise.id.clone(),
+                        field,
+                    }));
+                }
+            }
+        }
+        gaps
+    }
+
     pub fn eras(&self) -> &[Era] {
         &self.curriculum.eras
     }
@@ -387,4 +638,110 @@ mod tests {
             assert!(engine.get_module("mastery", module).is_some(), "mastery/{} should exist", module);
         }
     }
+
+    const PACK: &str = r#"{
+        "version": 2,
+        "eras": [
+            {
+                "meta": { "id": "first-steps", "title": "First Steps", "description": "Fixed.", "order": 1 },
+                "modules": [
+                    {
+                        "meta": { "id": "introduction", "title": "Introduction (revised)", "pedagogy": "", "order": 1 },
+                        "exercises": [
+                            { "id": "I_2", "type": "multiple_choice", "difficulty": 1, "prompt": "b", "options": ["x"], "correct": 0 },
+                            { "id": "I_1", "type": "multiple_choice", "difficulty": 1, "prompt": "a", "options": ["x"], "correct": 0 }
+                        ]
+                    }
+                ]
+            },
+            {
+                "meta": { "id": "frontier", "title": "Frontier", "description": "New era.", "order": 99 }
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_pack_replaces_module_and_adds_era() {
+        let pack = CurriculumPack::from_json(PACK).unwrap();
+        let engine = ContentEngine::with_pack(&pack);
+
+        let intro = engine.get_module("first-steps", "introduction").unwrap();
+        assert_eq!(intro.meta.title, "Introduction (revised)");
+        assert_eq!(intro.exercises.iter().map(|e| e.id.as_str()).collect::<Vec<_>>(), ["I_1", "I_2"]);
+        assert!(engine.get_module("first-steps", "syllogistic").is_some(), "untouched modules stay");
+        assert_eq!(engine.eras().last().unwrap().meta.id, "frontier");
+    }
+
+    #[test]
+    fn test_malformed_pack_is_rejected() {
+        assert!(CurriculumPack::from_json(r#"{ "eras": [] }"#).is_err(), "version is required");
+    }
+
+    #[test]
+    fn test_shipped_pack_parses() {
+        let pack = CurriculumPack::from_json(include_str!("../curriculum-pack.json")).unwrap();
+        assert!(pack.version >= 1);
+    }
+
+    #[test]
+    fn test_localized_exercise_falls_back_to_english() {
+        let exercise: ExerciseConfig = serde_json::from_str(
+            r#"{
+                "id": "T_1", "type": "translation", "difficulty": 1,
+                "prompt": "Translate:", "hint": "Look for the verb.", "explanation": "Verbs are predicates.",
+                "translations": { "es": { "prompt": "Traduce:", "hint": "Busca el verbo." } }
+            }"#,
+        )
+        .unwrap();
+
+        let spanish = exercise.localized("es-MX");
+        assert_eq!(spanish.prompt, "Traduce:");
+        assert_eq!(spanish.hint.as_deref(), Some("Busca el verbo."));
+        assert_eq!(spanish.explanation.as_deref(), Some("Verbs are predicates."), "untranslated fields stay English");
+        assert_eq!(exercise.localized("ar").prompt, "Translate:");
+
+        assert_eq!(exercise.untranslated("es"), ["explanation"]);
+        assert_eq!(exercise.untranslated("ar"), ["prompt", "hint", "explanation"]);
+        assert!(exercise.untranslated(SOURCE_LOCALE).is_empty());
+    }
+
+    #[test]
+    fn test_translation_gaps_skip_translated_exercises() {
+        let engine = ContentEngine::new();
+        let intro = engine.get_exercise("first-steps", "introduction", "I_1.1").unwrap();
+        assert!(intro.untranslated("es").is_empty(), "I_1.1 ships with Spanish text");
+
+        let gaps = engine.translation_gaps("es");
+        assert!(!gaps.iter().any(|gap| gap.exercise_id == "I_1.1"));
+        assert!(gaps.iter().any(|gap| gap.exercise_id == "A_1.1" && gap.field == "explanation"));
+        assert!(engine.translation_gaps(SOURCE_LOCALE).is_empty());
+    }
+
+    /// A locale an exercise is translated into must cover every field the
+    /// English has, and nothing it lacks — a half-translated exercise shows
+    /// mixed languages, and an orphaned field is never displayed.
+    #[test]
+    fn test_started_translations_are_complete() {
+        let engine = ContentEngine::new();
+        let mut problems = Vec::new();
+        for era in engine.eras() {
+            for module in &era.modules {
+                for exercise in &module.exercises {
+                    let path = format!("{}/{}/{}", era.meta.id, module.meta.id, exercise.id);
+                    for (locale, text) in &exercise.translations {
+                        for field in exercise.untranslated(locale) {
+                            problems.push(format!("{path}: {locale} is missing {field}"));
+                        }
+                        if text.hint.is_some() && exercise.hint.is_none() {
+                            problems.push(format!("{path}: {locale} has a hint the English lacks"));
+                        }
+                        if text.explanation.is_some() && exercise.explanation.is_none() {
+                            problems.push(format!("{path}: {locale} has an explanation the English lacks"));
+                        }
+                    }
+                }
+            }
+        }
+        assert!(problems.is_empty(), "incomplete translations:\n{}", problems.join("\n"));
+    }
 }
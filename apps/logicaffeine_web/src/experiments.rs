@@ -0,0 +1,413 @@
+//! A/B Experiments for Learning Features
+//!
+//! Assigns each learner one arm of every running experiment and logs the
+//! outcomes that tell the arms apart:
+//! - Bucketing hashes `experiment:anon_id`, so an assignment is stable
+//!   across sessions and builds and needs no server
+//! - The anonymous ID is random, generated on first use and kept in LocalStorage
+//! - Outcomes are logged locally, in chunks of [`CHUNK_EVENTS`] so that logging
+//!   one rewrites only the newest chunk, and only leave the browser through
+//!   [`Experiments::export_json`]
+
+use crate::srs::{leitner_update, sm2_update, ResponseQuality};
+use crate::progress::SrsData;
+use crate::tutor::Mistake;
+use serde::{Deserialize, Serialize};
+
+/// Oldest events are dropped past this many, to bound LocalStorage use
+pub const MAX_EVENTS: usize = 5000;
+
+/// Events per stored chunk of the log; the oldest chunk is dropped whole
+pub const CHUNK_EVENTS: usize = 250;
+
+/// A running experiment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Experiment {
+    HintStyle,
+    XpMultiplier,
+    Scheduler,
+}
+
+impl Experiment {
+    /// Stable ID, hashed into the bucket: renaming it reshuffles every learner
+    pub fn id(self) -> &'static str {
+        match self {
+            Experiment::HintStyle => "hint_style",
+            Experiment::XpMultiplier => "xp_multiplier",
+            Experiment::Scheduler => "scheduler",
+        }
+    }
+
+    /// Arm labels, in bucket order
+    pub fn arms(self) -> &'static [&'static str] {
+        match self {
+            Experiment::HintStyle => &["direct", "socratic"],
+            Experiment::XpMultiplier => &["1x", "1.5x"],
+            Experiment::Scheduler => &["sm2", "leitner"],
+        }
+    }
+
+    pub fn all() -> [Experiment; 3] {
+        [Experiment::HintStyle, Experiment::XpMultiplier, Experiment::Scheduler]
+    }
+}
+
+/// How a hint is phrased
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HintStyle {
+    /// The exercise's hint text, as written
+    Direct,
+    /// A leading question in the style of the [`tutor`](crate::tutor)
+    Socratic,
+}
+
+impl HintStyle {
+    /// `hint` as this arm shows it. A Socratic hint keeps only the questions
+    /// the hint asks; one that asks none becomes the tutor's opening question.
+    pub fn phrase(self, hint: &str) -> String {
+        match self {
+            HintStyle::Direct => hint.to_string(),
+            HintStyle::Socratic => {
+                let questions: Vec<&str> = sentences(hint).filter(|s| s.ends_with('?')).collect();
+                if questions.is_empty() {
+                    Mistake::Other.follow_up()
+                } else {
+                    questions.join(" ")
+                }
+            }
+        }
+    }
+}
+
+/// The sentences of `text`, each ending at a `.`, `?` or `!` followed by a space
+fn sentences(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text.trim();
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let end = rest
+            .char_indices()
+            .find(|&(i, c)| matches!(c, '.' | '?' | '!') && rest[i + 1..].starts_with(' '))
+            .map_or(rest.len(), |(i, _)| i + 1);
+        let (sentence, tail) = rest.split_at(end);
+        rest = tail.trim_start();
+        Some(sentence)
+    })
+}
+
+/// Which spaced-repetition algorithm schedules reviews
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheduler {
+    Sm2,
+    Leitner,
+}
+
+impl Scheduler {
+    pub fn update(self, srs: &mut SrsData, quality: ResponseQuality) {
+        match self {
+            Scheduler::Sm2 => sm2_update(srs, quality),
+            Scheduler::Leitner => leitner_update(srs, quality),
+        }
+    }
+}
+
+/// The arm `anon_id` falls into, out of `arms`
+pub fn bucket(experiment_id: &str, anon_id: &str, arms: usize) -> usize {
+    if arms == 0 {
+        return 0;
+    }
+    (hash(format!("{experiment_id}:{anon_id}").as_bytes()) % arms as u64) as usize
+}
+
+/// FNV-1a followed by the MurmurHash3 finalizer. FNV alone keeps the low bits
+/// of its input's parity, so a two-arm split would correlate across experiments.
+fn hash(bytes: &[u8]) -> u64 {
+    let mut hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    hash ^ (hash >> 33)
+}
+
+/// A measured outcome
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Outcome {
+    Attempt { exercise_id: String, correct: bool, first_try: bool },
+    HintShown { exercise_id: String },
+    Review { exercise_id: String, correct: bool },
+    ModuleCompleted { module_id: String, stars: u8 },
+}
+
+/// An outcome with the time it happened
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutcomeEvent {
+    /// Milliseconds since the Unix epoch (0 on native builds)
+    pub at_ms: f64,
+    pub outcome: Outcome,
+}
+
+/// One learner's assignments, and where their outcome log is stored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Experiments {
+    pub anon_id: String,
+    /// The oldest chunk of the log still kept
+    #[serde(default)]
+    first_chunk: u64,
+    /// The chunk being filled
+    #[serde(default)]
+    last_chunk: u64,
+    /// Events in the chunk being filled
+    #[serde(default)]
+    last_len: usize,
+}
+
+impl Experiments {
+    pub fn new(anon_id: &str) -> Self {
+        Self {
+            anon_id: anon_id.to_string(),
+            first_chunk: 0,
+            last_chunk: 0,
+            last_len: 0,
+        }
+    }
+
+    /// The stored state, or a fresh anonymous ID on first use
+    pub fn load() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        {
+            crate::storage::load_experiments_raw()
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_else(|| {
+                    let random = || (js_sys::Math::random() * u32::MAX as f64) as u32;
+                    let experiments = Self::new(&format!("{:08x}{:08x}", random(), random()));
+                    experiments.save();
+                    experiments
+                })
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Self::new("native")
+        }
+    }
+
+    pub fn save(&self) {
+        #[cfg(target_arch = "wasm32")]
+        {
+            if let Ok(json) = serde_json::to_string(self) {
+                crate::storage::save_experiments_raw(&json);
+            }
+        }
+    }
+
+    /// The arm this learner is in, as an index into [`Experiment::arms`]
+    pub fn arm(&self, experiment: Experiment) -> usize {
+        bucket(experiment.id(), &self.anon_id, experiment.arms().len())
+    }
+
+    pub fn hint_style(&self) -> HintStyle {
+        match self.arm(Experiment::HintStyle) {
+            0 => HintStyle::Direct,
+            _ => HintStyle::Socratic,
+        }
+    }
+
+    /// Factor applied to earned XP
+    pub fn xp_multiplier(&self) -> f64 {
+        match self.arm(Experiment::XpMultiplier) {
+            0 => 1.0,
+            _ => 1.5,
+        }
+    }
+
+    pub fn scheduler(&self) -> Scheduler {
+        match self.arm(Experiment::Scheduler) {
+            0 => Scheduler::Sm2,
+            _ => Scheduler::Leitner,
+        }
+    }
+
+    /// Log an outcome: append it to the newest chunk, starting a new chunk
+    /// (and dropping the oldest past [`MAX_EVENTS`]) when that one is full
+    pub fn record(&mut self, outcome: Outcome) {
+        #[cfg(target_arch = "wasm32")]
+        let at_ms = js_sys::Date::now();
+        #[cfg(not(target_arch = "wasm32"))]
+        let at_ms = 0.0;
+
+        let Ok(line) = serde_json::to_string(&OutcomeEvent { at_ms, outcome }) else { return };
+        if self.last_len == CHUNK_EVENTS {
+            self.last_chunk += 1;
+            self.last_len = 0;
+            while (self.last_chunk - self.first_chunk + 1) as usize * CHUNK_EVENTS > MAX_EVENTS {
+                self.remove_chunk(self.first_chunk);
+                self.first_chunk += 1;
+            }
+        }
+        let mut chunk = self.load_chunk(self.last_chunk).unwrap_or_default();
+        chunk.push_str(&line);
+        chunk.push('\n');
+        self.save_chunk(self.last_chunk, &chunk);
+        self.last_len += 1;
+        self.save();
+    }
+
+    /// The outcome log, oldest first
+    pub fn events(&self) -> Vec<OutcomeEvent> {
+        (self.first_chunk..=self.last_chunk)
+            .filter_map(|chunk| self.load_chunk(chunk))
+            .flat_map(|chunk| {
+                chunk
+                    .lines()
+                    .filter_map(|line| serde_json::from_str(line).ok())
+                    .collect::<Vec<OutcomeEvent>>()
+            })
+            .collect()
+    }
+
+    /// Assignments and the outcome log as pretty JSON, for the learner to
+    /// download and send in
+    pub fn export_json(&self) -> String {
+        let assignments: serde_json::Map<String, serde_json::Value> = Experiment::all()
+            .iter()
+            .map(|&e| (e.id().to_string(), e.arms()[self.arm(e)].into()))
+            .collect();
+        let export = serde_json::json!({
+            "anon_id": self.anon_id,
+            "assignments": assignments,
+            "events": self.events(),
+        });
+        serde_json::to_string_pretty(&export).unwrap_or_default()
+    }
+
+    /// Clears the outcome log, keeping the assignments
+    pub fn clear_events(&mut self) {
+        for chunk in self.first_chunk..=self.last_chunk {
+            self.remove_chunk(chunk);
+        }
+        self.first_chunk = self.last_chunk;
+        self.last_len = 0;
+        self.save();
+    }
+
+    // Chunk `n` of the log, one JSON event per line. Native builds have no
+    // LocalStorage and keep the chunks in memory.
+
+    #[cfg(target_arch = "wasm32")]
+    fn load_chunk(&self, n: u64) -> Option<String> {
+        crate::storage::load_experiment_events_raw(n)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn save_chunk(&self, n: u64, lines: &str) {
+        crate::storage::save_experiment_events_raw(n, lines);
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn remove_chunk(&self, n: u64) {
+        crate::storage::remove_experiment_events_raw(n);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_chunk(&self, n: u64) -> Option<String> {
+        NATIVE_CHUNKS.with(|chunks| chunks.borrow().get(&(self.anon_id.clone(), n)).cloned())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn save_chunk(&self, n: u64, lines: &str) {
+        NATIVE_CHUNKS.with(|chunks| chunks.borrow_mut().insert((self.anon_id.clone(), n), lines.to_string()));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn remove_chunk(&self, n: u64) {
+        NATIVE_CHUNKS.with(|chunks| chunks.borrow_mut().remove(&(self.anon_id.clone(), n)));
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+thread_local! {
+    static NATIVE_CHUNKS: std::cell::RefCell<std::collections::HashMap<(String, u64), String>> =
+        std::cell::RefCell::new(std::collections::HashMap::new());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucketing_is_deterministic() {
+        for id in ["a1", "b2", "c3"] {
+            assert_eq!(bucket("hint_style", id, 2), bucket("hint_style", id, 2));
+        }
+        assert_eq!(bucket("hint_style", "anyone", 0), 0);
+    }
+
+    #[test]
+    fn test_buckets_are_roughly_even() {
+        let ids: Vec<String> = (0..1000).map(|i| format!("learner-{i}")).collect();
+        let in_second = ids.iter().filter(|id| bucket("xp_multiplier", id, 2) == 1).count();
+        assert!((400..=600).contains(&in_second), "got {in_second} of 1000");
+    }
+
+    #[test]
+    fn test_experiments_bucket_independently() {
+        let ids: Vec<String> = (0..200).map(|i| format!("learner-{i}")).collect();
+        let same = ids
+            .iter()
+            .filter(|id| bucket("hint_style", id, 2) == bucket("scheduler", id, 2))
+            .count();
+        assert!(same < 160, "arms should not be correlated across experiments ({same}/200)");
+    }
+
+    #[test]
+    fn test_event_log_is_bounded() {
+        let mut experiments = Experiments::new("x");
+        for i in 0..MAX_EVENTS + 10 {
+            experiments.record(Outcome::HintShown { exercise_id: i.to_string() });
+        }
+        let events = experiments.events();
+        assert_eq!(events.len(), MAX_EVENTS - CHUNK_EVENTS + 10, "the oldest chunk goes whole");
+        assert_eq!(events[0].outcome, Outcome::HintShown { exercise_id: CHUNK_EVENTS.to_string() });
+        assert_eq!(
+            events.last().unwrap().outcome,
+            Outcome::HintShown { exercise_id: (MAX_EVENTS + 9).to_string() }
+        );
+    }
+
+    #[test]
+    fn test_log_survives_reload_and_clears() {
+        let mut experiments = Experiments::new("reloaded");
+        for i in 0..CHUNK_EVENTS + 1 {
+            experiments.record(Outcome::HintShown { exercise_id: i.to_string() });
+        }
+        let reloaded: Experiments = serde_json::from_str(&serde_json::to_string(&experiments).unwrap()).unwrap();
+        assert_eq!(reloaded.events().len(), CHUNK_EVENTS + 1);
+
+        experiments.clear_events();
+        assert!(experiments.events().is_empty());
+        experiments.record(Outcome::HintShown { exercise_id: "after".to_string() });
+        assert_eq!(experiments.events().len(), 1);
+    }
+
+    #[test]
+    fn test_socratic_hints_keep_only_questions() {
+        let hint = "Identify the main connective first. What's the primary structure of this sentence?";
+        assert_eq!(HintStyle::Direct.phrase(hint), hint);
+        assert_eq!(HintStyle::Socratic.phrase(hint), "What's the primary structure of this sentence?");
+        assert_eq!(HintStyle::Socratic.phrase("Look for the verb."), Mistake::Other.follow_up());
+    }
+
+    #[test]
+    fn test_export_includes_assignments() {
+        let mut experiments = Experiments::new("learner-7");
+        experiments.record(Outcome::Attempt { exercise_id: "A_1.1".to_string(), correct: true, first_try: true });
+        let export: serde_json::Value = serde_json::from_str(&experiments.export_json()).unwrap();
+        let arm = Experiment::Scheduler.arms()[experiments.arm(Experiment::Scheduler)];
+        assert_eq!(export["assignments"]["scheduler"], arm);
+        assert_eq!(export["events"][0]["outcome"]["kind"], "attempt");
+    }
+}
@@ -1,4 +1,8 @@
-use crate::progress::UserProgress;
+use crate::generator::{AnswerType, Challenge, ExamQuestion};
+use crate::grader::{check_answer, GradeResult};
+use crate::progress::{ConceptScore, ExamResult, UserProgress};
+use logicaffeine_language::compile_with_trace;
+use logicaffeine_language::trace::{RuleApplication, RuleOutcome};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -52,35 +56,118 @@ pub enum StreakStatus {
     Lost { was: u32 },
 }
 
+/// Most streak freezes a learner can bank.
+pub const MAX_STREAK_FREEZES: u8 = 3;
+
+/// XP that earns one streak freeze.
+pub const XP_PER_FREEZE: u64 = 500;
+
+/// Count today as a day of practice. A gap of missed days is bridged when
+/// the learner has a freeze for every missed day (each one is spent);
+/// otherwise the streak starts over. Practicing ends a vacation first, so
+/// the days away are never counted as missed.
 pub fn update_streak(progress: &mut UserProgress, today: &str) -> StreakStatus {
-    match &progress.last_streak_date {
+    if progress.vacation_since.is_some() {
+        end_vacation(progress, today);
+    }
+    let gap = match &progress.last_streak_date {
         None => {
             progress.streak_days = 1;
             progress.last_streak_date = Some(today.to_string());
-            StreakStatus::Active { days: 1 }
-        }
-        Some(last) if last == today => {
-            StreakStatus::Active { days: progress.streak_days }
+            return StreakStatus::Active { days: 1 };
         }
-        Some(last) if is_yesterday(last, today) => {
+        Some(last) if last == today => return StreakStatus::Active { days: progress.streak_days },
+        Some(last) => days_between(last, today),
+    };
+    progress.last_streak_date = Some(today.to_string());
+    match gap {
+        Some(1) => {
             progress.streak_days += 1;
-            progress.last_streak_date = Some(today.to_string());
             StreakStatus::Active { days: progress.streak_days }
         }
-        Some(last) if is_two_days_ago(last, today) && progress.streak_freezes > 0 => {
-            progress.streak_freezes -= 1;
-            progress.last_streak_date = Some(today.to_string());
+        Some(gap) if gap > 1 && gap - 1 <= progress.streak_freezes as i64 => {
+            progress.streak_freezes -= (gap - 1) as u8;
             StreakStatus::Frozen
         }
-        Some(_) => {
+        _ => {
             let was = progress.streak_days;
             progress.streak_days = 1;
-            progress.last_streak_date = Some(today.to_string());
             StreakStatus::Lost { was }
         }
     }
 }
 
+/// Go on vacation: from `today` until the learner practices again (or calls
+/// [`end_vacation`]), neither the streak nor SRS reviews fall due. Already
+/// being on vacation keeps the original start.
+pub fn start_vacation(progress: &mut UserProgress, today: &str) {
+    if progress.vacation_since.is_none() {
+        progress.vacation_since = Some(today.to_string());
+    }
+}
+
+/// Come back from vacation: the streak and every scheduled review move
+/// forward by the days spent away, as if they had been paused. Returns those
+/// days (0 when not on vacation).
+pub fn end_vacation(progress: &mut UserProgress, today: &str) -> u32 {
+    let Some(since) = progress.vacation_since.take() else {
+        return 0;
+    };
+    let away = days_between(&since, today).unwrap_or(0).max(0);
+    if away == 0 {
+        return 0;
+    }
+    let shift = |date: &mut Option<String>| {
+        if let Some(days) = date.as_deref().and_then(date_to_days) {
+            *date = Some(days_to_date(days + away));
+        }
+    };
+    shift(&mut progress.last_streak_date);
+    for exercise in progress.exercises.values_mut() {
+        shift(&mut exercise.srs.next_review);
+    }
+    away as u32
+}
+
+/// What banking some XP did to the learner's day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XpRecord {
+    /// XP earned so far today, including this award.
+    pub today: u64,
+    /// The learner's daily goal.
+    pub goal: u32,
+    /// This award is the one that reached today's goal.
+    pub goal_reached: bool,
+    /// Streak freezes this award earned.
+    pub freezes_earned: u8,
+}
+
+/// Bank `amount` XP earned on `today`: it counts towards the daily goal, and
+/// every [`XP_PER_FREEZE`] XP of lifetime total earns a streak freeze, up to
+/// [`MAX_STREAK_FREEZES`] banked. Saves the progress.
+pub fn record_xp(progress: &mut UserProgress, amount: u64, today: &str) -> XpRecord {
+    if progress.xp_today_date.as_deref() != Some(today) {
+        progress.xp_today = 0;
+        progress.xp_today_date = Some(today.to_string());
+    }
+    let goal = progress.daily_goal() as u64;
+    let before = progress.xp_today;
+    progress.xp_today += amount;
+
+    let crossed = (progress.xp + amount) / XP_PER_FREEZE - progress.xp / XP_PER_FREEZE;
+    let room = MAX_STREAK_FREEZES.saturating_sub(progress.streak_freezes);
+    let freezes_earned = crossed.min(room as u64) as u8;
+    progress.streak_freezes += freezes_earned;
+
+    progress.add_xp(amount);
+    XpRecord {
+        today: progress.xp_today,
+        goal: goal as u32,
+        goal_reached: before < goal && progress.xp_today >= goal,
+        freezes_earned,
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComboResult {
     pub new_combo: u32,
@@ -103,6 +190,230 @@ pub fn update_combo(progress: &mut UserProgress, correct: bool) -> ComboResult {
     }
 }
 
+/// Whether answers count. In [`PlayMode::Practice`] any exercise can be
+/// replayed with every hint open and nothing at stake: no XP, no streak, no
+/// combo, no attempt penalties, and the solution can be walked through
+/// without forfeiting anything.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlayMode {
+    #[default]
+    Scored,
+    Practice,
+}
+
+impl PlayMode {
+    pub fn is_practice(self) -> bool {
+        self == PlayMode::Practice
+    }
+
+    /// Whether correct answers earn XP and count towards the streak.
+    pub fn awards_xp(self) -> bool {
+        self == PlayMode::Scored
+    }
+
+    /// Whether hints are open without asking.
+    pub fn hints_open(self) -> bool {
+        self == PlayMode::Practice
+    }
+}
+
+/// The sandbox grader: grade `answer` against any kind of challenge without
+/// touching progress. Free-form answers go through [`check_answer`]; a
+/// multiple-choice answer is the option's text or its 1-based number; an
+/// ambiguity answer lists readings one per line and is correct once every
+/// reading is among them.
+pub fn practice_grade(challenge: &Challenge, answer: &str) -> GradeResult {
+    match &challenge.answer {
+        AnswerType::FreeForm { golden_logic } => check_answer(answer, golden_logic),
+        AnswerType::MultipleChoice { options, correct_index } => {
+            let answer = answer.trim();
+            let chosen = answer
+                .parse::<usize>()
+                .ok()
+                .and_then(|number| number.checked_sub(1))
+                .or_else(|| options.iter().position(|option| option.trim() == answer));
+            if chosen == Some(*correct_index) {
+                GradeResult::correct()
+            } else {
+                GradeResult::incorrect("Not that one. Compare each option with the sentence.".to_string())
+            }
+        }
+        AnswerType::Ambiguity { readings } => {
+            let lines: Vec<&str> = answer.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+            let found = readings
+                .iter()
+                .filter(|reading| lines.iter().any(|line| check_answer(line, reading).correct))
+                .count();
+            if found == readings.len() {
+                GradeResult::correct()
+            } else if found > 0 {
+                GradeResult::partial(
+                    format!("{found} of {} readings. Look for another scope order.", readings.len()),
+                    (found * 100 / readings.len()) as u32,
+                )
+            } else {
+                GradeResult::incorrect("None of these is a reading of the sentence.".to_string())
+            }
+        }
+    }
+}
+
+/// One grammar rule the parser applied on the way to the expected formula.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivationStep {
+    /// Nesting under earlier steps, for indentation.
+    pub depth: usize,
+    pub rule: &'static str,
+    /// The words the rule covered.
+    pub text: String,
+}
+
+/// "Show solution with explanation": the expected answer, and how the
+/// sentence derives it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolutionWalkthrough {
+    /// The accepted answers: the formula, the correct option, or every reading.
+    pub answers: Vec<String>,
+    /// The grammar rules that built the reading, outermost first. Dead ends
+    /// the parser backtracked out of are left out.
+    pub steps: Vec<DerivationStep>,
+    pub explanation: Option<String>,
+}
+
+/// Walk through `challenge`'s solution using the derivation trace of its
+/// sentence. Steps are empty when the sentence is not one the parser reads
+/// (a multiple-choice prompt, say).
+pub fn solution_walkthrough(challenge: &Challenge) -> SolutionWalkthrough {
+    let answers = match &challenge.answer {
+        AnswerType::FreeForm { golden_logic } => vec![golden_logic.clone()],
+        AnswerType::MultipleChoice { options, correct_index } => options.get(*correct_index).cloned().into_iter().collect(),
+        AnswerType::Ambiguity { readings } => readings.clone(),
+    };
+    let (result, trace) = compile_with_trace(&challenge.sentence);
+    let mut steps = Vec::new();
+    if result.is_ok() {
+        for root in &trace.roots {
+            collect_steps(root, "", 0, &mut steps);
+        }
+    }
+    SolutionWalkthrough { answers, steps, explanation: challenge.explanation.clone() }
+}
+
+/// The applied rules under `node`. A rule covering the same words as its
+/// parent refines that step rather than repeating it: the step takes the
+/// inner, more specific rule's name.
+fn collect_steps(node: &RuleApplication, parent_text: &str, depth: usize, steps: &mut Vec<DerivationStep>) {
+    if node.outcome != RuleOutcome::Applied {
+        return;
+    }
+    let (text, depth) = if node.text.is_empty() {
+        (parent_text, depth)
+    } else if node.text == parent_text {
+        if let Some(step) = steps.last_mut().filter(|step| step.text == node.text) {
+            step.rule = node.rule;
+        }
+        (parent_text, depth)
+    } else {
+        steps.push(DerivationStep { depth, rule: node.rule, text: node.text.clone() });
+        (node.text.as_str(), depth + 1)
+    };
+    for child in &node.children {
+        collect_steps(child, text, depth, steps);
+    }
+}
+
+/// Questions in an exam unless the learner picks otherwise.
+pub const EXAM_LENGTH: usize = 20;
+
+/// Exam lengths offered.
+pub const EXAM_LENGTH_CHOICES: [usize; 3] = [10, 20, 30];
+
+/// Time an exam allows per question.
+pub const EXAM_SECONDS_PER_QUESTION: u32 = 60;
+
+/// A timed exam in progress: fixed questions, no hints, no feedback until it
+/// is finished, and nothing at stake but its own [`ExamResult`]. Time is
+/// passed in as seconds since the exam started, so the caller owns the clock.
+#[derive(Debug, Clone)]
+pub struct Exam {
+    pub questions: Vec<ExamQuestion>,
+    /// IDs of the modules the questions were drawn from.
+    pub modules: Vec<String>,
+    pub time_limit_secs: u32,
+    answers: Vec<Option<String>>,
+}
+
+impl Exam {
+    /// An exam over `questions`, allowed [`EXAM_SECONDS_PER_QUESTION`] each.
+    pub fn new(questions: Vec<ExamQuestion>, modules: Vec<String>) -> Self {
+        let time_limit_secs = questions.len() as u32 * EXAM_SECONDS_PER_QUESTION;
+        let answers = vec![None; questions.len()];
+        Self { questions, modules, time_limit_secs, answers }
+    }
+
+    /// Record the answer to question `index`, replacing any earlier one.
+    /// Returns `false`, recording nothing, once time is up.
+    pub fn answer(&mut self, index: usize, answer: &str, elapsed_secs: u32) -> bool {
+        if self.is_over(elapsed_secs) {
+            return false;
+        }
+        match self.answers.get_mut(index) {
+            Some(slot) => {
+                let answer = answer.trim();
+                *slot = (!answer.is_empty()).then(|| answer.to_string());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The answer given to question `index`, if any.
+    pub fn answer_to(&self, index: usize) -> Option<&str> {
+        self.answers.get(index)?.as_deref()
+    }
+
+    pub fn answered(&self) -> usize {
+        self.answers.iter().filter(|answer| answer.is_some()).count()
+    }
+
+    pub fn time_left(&self, elapsed_secs: u32) -> u32 {
+        self.time_limit_secs.saturating_sub(elapsed_secs)
+    }
+
+    pub fn is_over(&self, elapsed_secs: u32) -> bool {
+        elapsed_secs >= self.time_limit_secs
+    }
+
+    /// Grade the exam with [`practice_grade`]. Only fully correct answers
+    /// score; unanswered questions count as wrong.
+    pub fn finish(&self, date: &str, elapsed_secs: u32) -> ExamResult {
+        let mut concepts: Vec<ConceptScore> = Vec::new();
+        let mut correct = 0;
+        for (question, answer) in self.questions.iter().zip(&self.answers) {
+            let right = answer.as_deref().is_some_and(|answer| practice_grade(&question.challenge, answer).correct);
+            correct += usize::from(right);
+            let index = match concepts.iter().position(|score| score.concept == question.concept) {
+                Some(index) => index,
+                None => {
+                    concepts.push(ConceptScore { concept: question.concept.clone(), questions: 0, correct: 0 });
+                    concepts.len() - 1
+                }
+            };
+            concepts[index].questions += 1;
+            concepts[index].correct += usize::from(right);
+        }
+        ExamResult {
+            date: date.to_string(),
+            modules: self.modules.clone(),
+            questions: self.questions.len(),
+            correct,
+            time_limit_secs: self.time_limit_secs,
+            time_taken_secs: elapsed_secs.min(self.time_limit_secs),
+            concepts,
+        }
+    }
+}
+
 pub fn level_title(level: u32) -> &'static str {
     match level {
         1 => "Novice",
@@ -151,42 +462,65 @@ pub fn check_level_up_freeze_grants(old_level: u32, new_level: u32) -> Option<Fr
 }
 
 pub fn is_sunday(date: &str) -> bool {
-    if let Ok(num) = parse_date_to_days(date) {
-        (num + 4) % 7 == 0
-    } else {
-        false
-    }
+    // 1970-01-04 was a Sunday.
+    date_to_days(date).is_some_and(|days| (days - 3).rem_euclid(7) == 0)
 }
 
 fn is_yesterday(last: &str, today: &str) -> bool {
-    if let (Ok(l), Ok(t)) = (parse_date_to_days(last), parse_date_to_days(today)) {
-        t - l == 1
-    } else {
-        false
-    }
+    days_between(last, today) == Some(1)
 }
 
-fn is_two_days_ago(last: &str, today: &str) -> bool {
-    if let (Ok(l), Ok(t)) = (parse_date_to_days(last), parse_date_to_days(today)) {
-        t - l == 2
-    } else {
-        false
+/// Today's local date as `YYYY-MM-DD`.
+pub fn today() -> String {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let now = js_sys::Date::new_0();
+        format!("{:04}-{:02}-{:02}", now.get_full_year(), now.get_month() + 1, now.get_date())
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        days_to_date((secs / 86_400) as i64)
     }
 }
 
-fn parse_date_to_days(date: &str) -> Result<i64, ()> {
-    let parts: Vec<&str> = date.split('-').collect();
-    if parts.len() != 3 {
-        return Err(());
-    }
+/// Whole days from `from` to `to` (negative when `to` is earlier).
+pub(crate) fn days_between(from: &str, to: &str) -> Option<i64> {
+    Some(date_to_days(to)? - date_to_days(from)?)
+}
 
-    let year: i64 = parts[0].parse().map_err(|_| ())?;
-    let month: i64 = parts[1].parse().map_err(|_| ())?;
-    let day: i64 = parts[2].parse().map_err(|_| ())?;
+/// Days since 1970-01-01 of a `YYYY-MM-DD` date, on the proleptic Gregorian
+/// calendar.
+pub(crate) fn date_to_days(date: &str) -> Option<i64> {
+    let mut parts = date.split('-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Some(era * 146_097 + day_of_era - 719_468)
+}
 
-    let days = year * 365 + (year / 4) - (year / 100) + (year / 400)
-        + (month * 30) + day;
-    Ok(days)
+/// The `YYYY-MM-DD` date `days` after 1970-01-01.
+pub(crate) fn days_to_date(days: i64) -> String {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
 }
 
 #[cfg(test)]
@@ -287,4 +621,167 @@ mod tests {
         assert!(is_yesterday("2025-01-01", "2025-01-02"));
         assert!(!is_yesterday("2025-01-01", "2025-01-03"));
     }
+
+    #[test]
+    fn test_is_yesterday_across_month_and_year_ends() {
+        assert!(is_yesterday("2025-01-31", "2025-02-01"));
+        assert!(is_yesterday("2024-02-28", "2024-02-29"));
+        assert!(is_yesterday("2024-12-31", "2025-01-01"));
+        assert!(is_sunday("2025-01-05"));
+        assert!(!is_sunday("2025-01-06"));
+        assert_eq!(days_to_date(date_to_days("2024-02-29").unwrap() + 366), "2025-03-01");
+    }
+
+    #[test]
+    fn test_freezes_bridge_each_missed_day() {
+        let mut progress = UserProgress::new();
+        progress.streak_days = 10;
+        progress.streak_freezes = 2;
+        progress.last_streak_date = Some("2025-01-01".to_string());
+
+        // Two missed days, two freezes: the streak survives.
+        assert_eq!(update_streak(&mut progress, "2025-01-04"), StreakStatus::Frozen);
+        assert_eq!((progress.streak_days, progress.streak_freezes), (10, 0));
+        assert_eq!(update_streak(&mut progress, "2025-01-05"), StreakStatus::Active { days: 11 });
+
+        // One missed day with no freezes left: it is lost.
+        assert_eq!(update_streak(&mut progress, "2025-01-07"), StreakStatus::Lost { was: 11 });
+        assert_eq!(progress.streak_days, 1);
+    }
+
+    #[test]
+    fn test_vacation_pauses_streak_and_reviews() {
+        let mut progress = UserProgress::new();
+        progress.streak_days = 4;
+        progress.last_streak_date = Some("2025-01-30".to_string());
+        progress.record_attempt("ex", true);
+        progress.exercises.get_mut("ex").unwrap().srs.next_review = Some("2025-02-02".to_string());
+
+        start_vacation(&mut progress, "2025-01-31");
+        start_vacation(&mut progress, "2025-02-03");
+        assert_eq!(update_streak(&mut progress, "2025-02-10"), StreakStatus::Active { days: 5 });
+        assert!(progress.vacation_since.is_none());
+        assert_eq!(progress.exercises["ex"].srs.next_review.as_deref(), Some("2025-02-12"));
+        assert_eq!(end_vacation(&mut progress, "2025-02-11"), 0);
+    }
+
+    #[test]
+    fn test_record_xp_tracks_daily_goal_and_earns_freezes() {
+        let mut progress = UserProgress::new();
+        progress.set_daily_goal(30);
+        progress.xp = XP_PER_FREEZE - 10;
+
+        let first = record_xp(&mut progress, 20, "2025-01-01");
+        assert_eq!((first.today, first.goal_reached, first.freezes_earned), (20, false, 1));
+        let second = record_xp(&mut progress, 15, "2025-01-01");
+        assert_eq!((second.today, second.goal_reached), (35, true));
+        assert!(!record_xp(&mut progress, 5, "2025-01-01").goal_reached);
+
+        // A new day starts from zero; the freeze bank stops at its cap.
+        progress.streak_freezes = MAX_STREAK_FREEZES;
+        let next_day = record_xp(&mut progress, 2 * XP_PER_FREEZE, "2025-01-02");
+        assert_eq!((next_day.today, next_day.freezes_earned), (2 * XP_PER_FREEZE, 0));
+    }
+
+    fn challenge(sentence: &str, answer: AnswerType) -> Challenge {
+        Challenge {
+            exercise_id: "practice".to_string(),
+            prompt: "Translate".to_string(),
+            sentence: sentence.to_string(),
+            answer,
+            hint: None,
+            explanation: Some("Every introduces a universal.".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_practice_grade_covers_every_answer_type() {
+        let choice = challenge(
+            "Which is right?",
+            AnswerType::MultipleChoice { options: vec!["P".to_string(), "Q".to_string()], correct_index: 1 },
+        );
+        assert!(practice_grade(&choice, "Q").correct);
+        assert!(practice_grade(&choice, "2").correct);
+        assert!(!practice_grade(&choice, "1").correct);
+
+        let readings = challenge(
+            "Every man loves a woman.",
+            AnswerType::Ambiguity { readings: vec!["P(a)".to_string(), "Q(b)".to_string()] },
+        );
+        let half = practice_grade(&readings, "P(a)");
+        assert!(half.partial && !half.correct, "{half:?}");
+        assert!(practice_grade(&readings, "Q(b)\nP(a)").correct);
+    }
+
+    #[test]
+    fn test_solution_walkthrough_follows_the_derivation() {
+        let golden_logic = logicaffeine_language::compile("Every man is mortal.").unwrap();
+        let walkthrough = solution_walkthrough(&challenge(
+            "Every man is mortal.",
+            AnswerType::FreeForm { golden_logic: golden_logic.clone() },
+        ));
+        assert_eq!(walkthrough.answers, vec![golden_logic]);
+        assert_eq!(walkthrough.explanation.as_deref(), Some("Every introduces a universal."));
+        let steps = &walkthrough.steps;
+        assert!(steps[0].depth == 0 && steps[0].text.starts_with("Every man"), "{steps:?}");
+        assert!(steps.iter().any(|step| step.depth > 0 && step.text.len() < steps[0].text.len()), "{steps:?}");
+        // A rule over its parent's words refines the parent's step instead of repeating it.
+        for pair in steps.windows(2) {
+            assert!(pair[1].depth <= pair[0].depth || pair[1].text != pair[0].text, "{pair:?}");
+        }
+    }
+
+    #[test]
+    fn test_play_mode_stakes() {
+        assert!(PlayMode::default().awards_xp());
+        assert!(!PlayMode::Practice.awards_xp());
+        assert!(PlayMode::Practice.hints_open());
+    }
+
+    fn exam_question(concept: &str, options: &[&str], correct_index: usize) -> ExamQuestion {
+        ExamQuestion {
+            module_id: concept.to_lowercase(),
+            concept: concept.to_string(),
+            challenge: challenge(
+                "Pick one",
+                AnswerType::MultipleChoice { options: options.iter().map(|o| o.to_string()).collect(), correct_index },
+            ),
+        }
+    }
+
+    #[test]
+    fn test_exam_scores_by_concept_and_closes_on_time() {
+        let mut exam = Exam::new(
+            vec![
+                exam_question("Quantifiers", &["A", "B"], 0),
+                exam_question("Negation", &["C", "D"], 1),
+                exam_question("Quantifiers", &["E", "F"], 1),
+            ],
+            vec!["quantifiers".to_string(), "negation".to_string()],
+        );
+        assert_eq!(exam.time_limit_secs, 3 * EXAM_SECONDS_PER_QUESTION);
+
+        assert!(exam.answer(0, "A", 10));
+        assert!(exam.answer(1, "C", 20));
+        assert!(exam.answer(1, "D", 30), "answers can be changed while time remains");
+        assert_eq!(exam.answer_to(1), Some("D"));
+        assert!(!exam.answer(2, "F", exam.time_limit_secs), "no answers after time is up");
+        assert_eq!(exam.answered(), 2);
+
+        let result = exam.finish("2025-03-01", 999);
+        assert_eq!((result.questions, result.correct, result.percent()), (3, 2, 66));
+        assert_eq!(result.time_taken_secs, exam.time_limit_secs);
+        assert_eq!(
+            result.concepts,
+            [
+                ConceptScore { concept: "Quantifiers".to_string(), questions: 2, correct: 1 },
+                ConceptScore { concept: "Negation".to_string(), questions: 1, correct: 1 },
+            ]
+        );
+
+        let mut progress = UserProgress::new();
+        progress.record_exam(result);
+        assert_eq!(progress.exam_results.len(), 1);
+        assert_eq!(progress.xp, 0, "exams are scored apart from XP");
+    }
 }
@@ -54,8 +54,10 @@
 //! }
 //! ```
 
-use crate::content::{ExerciseConfig, ExerciseType};
+use crate::content::{ExerciseConfig, ExerciseType, Module};
+use logicaffeine_language::lexicon::VerbClass;
 use logicaffeine_language::runtime_lexicon::{LexiconIndex, pluralize, present_3s, past_tense, gerund};
+use logicaffeine_language::test_utils::sentence_gen::{Construction, Quantifier, SentenceGenerator};
 use logicaffeine_language::{compile, compile_all_scopes};
 use rand::Rng;
 use rand::seq::SliceRandom;
@@ -73,6 +75,9 @@ static LEXICON: LazyLock<LexiconIndex> = LazyLock::new(LexiconIndex::new);
 #[cfg(target_arch = "wasm32")]
 static LEXICON: OnceLock<LexiconIndex> = OnceLock::new();
 
+// The sentence generator over the pinned lexicon, indexed on the first drill.
+static SENTENCES: std::sync::OnceLock<SentenceGenerator<'static>> = std::sync::OnceLock::new();
+
 /// The pinned lexicon. `None` only on wasm before [`ensure_lexicon`] resolves —
 /// the `LexiconGate` component holds Learn content back until it has.
 pub fn lexicon() -> Option<&'static LexiconIndex> {
@@ -147,6 +152,27 @@ pub enum AnswerType {
     },
 }
 
+/// One question of an exam: a challenge, and the concept — the module — it
+/// tests.
+#[derive(Debug, Clone)]
+pub struct ExamQuestion {
+    pub module_id: String,
+    /// The module's title, which the score report groups by.
+    pub concept: String,
+    pub challenge: Challenge,
+}
+
+impl AnswerType {
+    /// Stable identifier for the answer format
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AnswerType::FreeForm { .. } => "free_form",
+            AnswerType::MultipleChoice { .. } => "multiple_choice",
+            AnswerType::Ambiguity { .. } => "ambiguity",
+        }
+    }
+}
+
 impl Generator {
     /// How many sentences a generator may draw before giving up on an
     /// exercise. Lexicon pools contain words the compiler rejects in some
@@ -175,6 +201,81 @@ impl Generator {
         }
     }
 
+    /// Assembles an exam of `length` questions drawn evenly from `modules`,
+    /// in shuffled order and without hints.
+    ///
+    /// Modules take turns contributing a question, each working through its
+    /// exercises in a shuffled order and starting over (with fresh sentences)
+    /// once they run out. A module none of whose exercises generate drops out;
+    /// the exam comes up short only when every module has.
+    pub fn generate_exam(&self, modules: &[&Module], length: usize, rng: &mut impl Rng) -> Vec<ExamQuestion> {
+        let mut decks: Vec<(&Module, Vec<&ExerciseConfig>, usize)> = modules
+            .iter()
+            .filter(|module| !module.exercises.is_empty())
+            .map(|&module| {
+                let mut deck: Vec<&ExerciseConfig> = module.exercises.iter().collect();
+                deck.shuffle(rng);
+                (module, deck, 0)
+            })
+            .collect();
+
+        let mut questions = Vec::with_capacity(length);
+        let mut turn = 0;
+        while questions.len() < length && !decks.is_empty() {
+            let slot = turn % decks.len();
+            let (module, deck, cursor) = &mut decks[slot];
+            // One pass through the deck looking for an exercise that generates.
+            let found = (0..deck.len()).find_map(|_| {
+                let exercise = deck[*cursor % deck.len()];
+                *cursor += 1;
+                self.generate(exercise, rng)
+            });
+            match found {
+                Some(mut challenge) => {
+                    challenge.hint = None;
+                    questions.push(ExamQuestion {
+                        module_id: module.meta.id.clone(),
+                        concept: module.meta.title.clone(),
+                        challenge,
+                    });
+                    turn += 1;
+                }
+                None => {
+                    decks.remove(slot);
+                }
+            }
+        }
+        questions.shuffle(rng);
+        questions
+    }
+
+    /// Generates a translation drill for one sentence pattern, with words
+    /// from the sentence generator rather than a curriculum template — fresh
+    /// practice for any quantifier × verb class × construction.
+    ///
+    /// Returns `None` for a pattern the generator has no sentences for, or
+    /// a sentence the compiler rejects.
+    pub fn generate_drill(
+        &self,
+        quantifier: Quantifier,
+        class: VerbClass,
+        construction: Construction,
+        rng: &mut impl Rng,
+    ) -> Option<Challenge> {
+        let sentences = SENTENCES.get_or_init(|| SentenceGenerator::new(self.lexicon));
+        let generated = sentences.sentence(quantifier, class, construction, rng.gen_range(0..1024))?;
+        let golden_logic = compile(&generated.sentence).ok()?;
+
+        Some(Challenge {
+            exercise_id: format!("drill:{quantifier:?}-{class:?}-{construction:?}"),
+            prompt: "Translate this sentence into first-order logic.".to_string(),
+            sentence: generated.sentence,
+            answer: AnswerType::FreeForm { golden_logic },
+            hint: None,
+            explanation: Some(format!("Without events, the reading is {}", generated.expected_fol)),
+        })
+    }
+
     fn generate_translation(&self, exercise: &ExerciseConfig, rng: &mut impl Rng) -> Option<Challenge> {
         let template = exercise.template.as_ref()?;
 
@@ -400,6 +501,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_generate_drill() {
+        let generator = Generator::new();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let challenge = generator
+            .generate_drill(Quantifier::Every, VerbClass::Activity, Construction::ObjectSome, &mut rng)
+            .expect("Every × Activity × ObjectSome should generate");
+        assert!(challenge.sentence.starts_with("Every "), "{}", challenge.sentence);
+        assert!(matches!(&challenge.answer, AnswerType::FreeForm { golden_logic } if golden_logic.contains('∀')));
+
+        let none = generator.generate_drill(Quantifier::No, VerbClass::Activity, Construction::Negated, &mut rng);
+        assert!(none.is_none(), "double negatives are not drilled");
+    }
+
     #[test]
     fn test_generate_multiple_choice() {
         let engine = ContentEngine::new();
@@ -463,6 +579,25 @@ mod tests {
         assert_eq!(result1, result2, "Same seed should produce same output");
     }
 
+    #[test]
+    fn test_generate_exam_spreads_questions_across_modules() {
+        let engine = ContentEngine::new();
+        let generator = Generator::new();
+        let mut rng = StdRng::seed_from_u64(7);
+        let modules: Vec<&Module> = engine.eras()[0].modules.iter().take(2).collect();
+        assert_eq!(modules.len(), 2, "the first era should have two modules");
+
+        let exam = generator.generate_exam(&modules, 12, &mut rng);
+        assert_eq!(exam.len(), 12);
+        assert!(exam.iter().all(|question| question.challenge.hint.is_none()), "exams carry no hints");
+        for module in &modules {
+            let count = exam.iter().filter(|question| question.module_id == module.meta.id).count();
+            assert_eq!(count, 6, "{} should get half the questions", module.meta.id);
+            assert!(exam.iter().any(|question| question.concept == module.meta.title));
+        }
+        assert!(generator.generate_exam(&[], 5, &mut rng).is_empty());
+    }
+
     #[test]
     fn test_all_introduction_exercises() {
         let engine = ContentEngine::new();
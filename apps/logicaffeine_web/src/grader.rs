@@ -1,3 +1,7 @@
+use logicaffeine_compile::{analyze_argument, ArgumentVerdict};
+
+use crate::AstNode;
+
 
 #[derive(Debug, Clone)]
 pub struct GradeResult {
@@ -79,7 +83,42 @@ pub fn check_answer(user_input: &str, expected: &str) -> GradeResult {
     }
 }
 
-fn normalize_logic(input: &str) -> String {
+/// Grades a learner's valid/invalid judgment of an English argument. A wrong
+/// "valid" names the fallacy the argument commits, when its form matches one.
+pub fn check_validity_judgment(premises: &[&str], conclusion: &str, judged_valid: bool) -> GradeResult {
+    let report = analyze_argument(premises, conclusion);
+    let valid = match report.verdict {
+        ArgumentVerdict::Valid => true,
+        ArgumentVerdict::Invalid => false,
+        ArgumentVerdict::Unknown => {
+            return GradeResult::incorrect(
+                "Internal error: could not decide the argument.".to_string(),
+            )
+        }
+    };
+
+    if judged_valid == valid {
+        return GradeResult::correct();
+    }
+    if valid {
+        return GradeResult::incorrect(
+            "Not quite. The premises do guarantee the conclusion — try chaining them.".to_string(),
+        );
+    }
+    match report.fallacies.first() {
+        Some(found) => GradeResult::incorrect(format!(
+            "This is {} ({}). {}",
+            found.fallacy.name(),
+            found.fallacy.pattern(),
+            found.explanation
+        )),
+        None => GradeResult::incorrect(
+            "Not quite. The premises can all be true while the conclusion is false.".to_string(),
+        ),
+    }
+}
+
+pub(crate) fn normalize_logic(input: &str) -> String {
     let mut result = input.to_string();
 
     result = result.replace("\\forall", "∀");
@@ -122,6 +161,13 @@ enum NormalizedKind {
 fn parse_to_normalized_ast(input: &str) -> Option<NormalizedExpr> {
     let normalized = normalize_logic(input);
 
+    // Redundant outer parentheses, as in the compiler's `∀x((A(x) → B(x)))`.
+    if let Some(inner) = extract_balanced(&normalized) {
+        if inner.len() + 2 == normalized.len() {
+            return parse_to_normalized_ast(&inner);
+        }
+    }
+
     if normalized.starts_with('∀') || normalized.starts_with('∃') {
         let quantifier = if normalized.starts_with('∀') { "∀" } else { "∃" };
         let rest = &normalized[quantifier.len()..];
@@ -190,6 +236,26 @@ fn parse_to_normalized_ast(input: &str) -> Option<NormalizedExpr> {
     })
 }
 
+/// A formula's syntax tree as the grader reads it, for
+/// [`ast_diff`](crate::ast_diff::ast_diff) to set an answer against the
+/// expected formula. Quantifiers are labeled without their variable, so a
+/// renamed bound variable is no difference.
+pub fn formula_ast(input: &str) -> Option<AstNode> {
+    parse_to_normalized_ast(input).map(|expr| to_ast_node(&expr))
+}
+
+fn to_ast_node(expr: &NormalizedExpr) -> AstNode {
+    match &expr.kind {
+        NormalizedKind::Predicate { name, arity } => AstNode::leaf(&format!("{name}({arity})"), "predicate"),
+        NormalizedKind::Quantifier { kind, body } => AstNode::with_children(kind, "quantifier", vec![to_ast_node(body)]),
+        NormalizedKind::Binary { op, left, right } => {
+            AstNode::with_children(op, "binary_op", vec![to_ast_node(left), to_ast_node(right)])
+        }
+        NormalizedKind::Unary { op, operand } => AstNode::with_children(op, "unary_op", vec![to_ast_node(operand)]),
+        NormalizedKind::Atom(atom) => AstNode::leaf(atom, "constant"),
+    }
+}
+
 fn extract_balanced(s: &str) -> Option<String> {
     if !s.starts_with('(') {
         return None;
@@ -198,7 +264,7 @@ fn extract_balanced(s: &str) -> Option<String> {
     let mut depth = 0;
     let mut end = 0;
 
-    for (i, c) in s.chars().enumerate() {
+    for (i, c) in s.char_indices() {
         match c {
             '(' => depth += 1,
             ')' => {
@@ -300,6 +366,17 @@ fn structural_similarity(a: &NormalizedExpr, b: &NormalizedExpr) -> f64 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_formula_ast_shapes() {
+        let ast = formula_ast("∀x(Dog(x) → ¬Bark(x))").unwrap();
+        assert_eq!((ast.label.as_str(), ast.node_type.as_str()), ("∀", "quantifier"));
+        let body = &ast.children[0];
+        assert_eq!(body.label, "→");
+        assert_eq!(body.children[0], AstNode::leaf("Dog(1)", "predicate"));
+        assert_eq!(body.children[1].label, "¬");
+        assert_eq!(formula_ast("∀y(Dog(y) → ¬Bark(y))"), Some(ast), "bound variables are not compared");
+    }
+
     #[test]
     fn test_exact_match() {
         let result = check_answer("∀x(D(x) → B(x))", "∀x(D(x) → B(x))");
@@ -362,4 +439,31 @@ mod tests {
         let normalized = normalize_logic("A <-> B");
         assert_eq!(normalized, "A↔B");
     }
+
+    #[test]
+    fn test_redundant_parentheses() {
+        let result = check_answer("∀x(Man(x) → Mortal(x))", "∀x((Man(x) → Mortal(x)))");
+        assert!(result.correct, "Redundant outer parentheses should not matter");
+    }
+
+    #[test]
+    fn test_validity_judgment_names_the_fallacy() {
+        let result = check_validity_judgment(
+            &["If it rains, the ground is wet.", "The ground is wet."],
+            "It rains.",
+            true,
+        );
+        assert!(!result.correct);
+        assert!(result.feedback.contains("affirming the consequent"), "{}", result.feedback);
+    }
+
+    #[test]
+    fn test_validity_judgment_accepts_modus_ponens() {
+        let result = check_validity_judgment(
+            &["If it rains, the ground is wet.", "It rains."],
+            "The ground is wet.",
+            true,
+        );
+        assert!(result.correct);
+    }
 }
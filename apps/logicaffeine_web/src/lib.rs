@@ -5,16 +5,25 @@ pub use logicaffeine_compile::AstNode;
 
 // Game/learning modules
 pub mod achievements;
+pub mod ast_diff;
 pub mod audio;
+pub mod collab;
+pub mod compile_worker;
 pub mod content;
+pub mod experiments;
 pub mod game;
 pub mod generator;
 pub mod grader;
 pub mod learn_state;
+pub mod notebook;
 pub mod progress;
+pub mod share;
 pub mod srs;
+pub mod stats;
 pub mod storage;
 pub mod struggle;
+pub mod telemetry;
+pub mod tutor;
 pub mod unlock;
 
 // SEO and sitemap
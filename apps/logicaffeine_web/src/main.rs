@@ -19,6 +19,18 @@ use logicaffeine_web::App;
 fn main() {
     use dioxus::prelude::*;
 
+    // Bound every run of a visitor's program, on the page and in the compile
+    // worker alike, so an infinite loop stops with an error.
+    #[cfg(target_arch = "wasm32")]
+    logicaffeine_compile::set_execution_limits(logicaffeine_compile::ExecutionLimits::browser());
+
+    // The compile worker instantiates this same module: there is no window to
+    // render into, only the `compile_worker` exports to serve.
+    #[cfg(target_arch = "wasm32")]
+    if web_sys::window().is_none() {
+        return;
+    }
+
     // The prerender pass writes into a SIBLING of public/ rather than public/
     // itself: dx finishes the client build after the prerender and rewrites
     // public/index.html (the shell), which would clobber the prerendered `/`.
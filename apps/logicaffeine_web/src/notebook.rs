@@ -0,0 +1,368 @@
+//! Notebooks
+//!
+//! An ordered list of cells that build on each other — where the Guide's
+//! examples each run alone, a notebook's later cells see what earlier ones
+//! defined:
+//! - **Code** cells are LOGOS. Every earlier cell's definitions (`## To`,
+//!   `## A … has:`) and statements stay in scope, so a cell can use a variable
+//!   or function introduced cells ago
+//! - **Logic** cells are English, one sentence per line, translated in one
+//!   shared [`Session`] — a pronoun resolves to someone named cells earlier
+//! - **Markdown** cells are prose between them
+//!
+//! Code cells share state by replay: cell *n* runs as one program made of
+//! every earlier code cell that succeeded plus its own, and shows only the
+//! output lines its own statements added. A failing cell is left out of the
+//! programs after it, so one typo never poisons the rest of the notebook.
+//!
+//! Logic cells share state by snapshot instead: [`Checkpoints`] keeps the
+//! session as it stood after each logic cell, so a rerun restores it at the
+//! first edited cell rather than translating every sentence again.
+//!
+//! Notebooks persist as JSON ([`Notebook::to_json`]) — the file the page saves
+//! under [`NOTEBOOK_DIR`] in the VFS is also the portable export format.
+//!
+//! # Usage
+//!
+//! ```no_run
+//! use logicaffeine_web::notebook::{Cell, CellKind, Notebook};
+//!
+//! let mut notebook = Notebook::new("Squares");
+//! notebook.cells.push(Cell::new(CellKind::Code, "Let n be 7."));
+//! notebook.cells.push(Cell::new(CellKind::Code, "Show n * n."));
+//! assert_eq!(notebook.run()[1].lines, vec!["49"]);
+//!
+//! let json = notebook.to_json();
+//! assert_eq!(Notebook::from_json(&json).unwrap(), notebook);
+//! ```
+
+use logicaffeine_compile::interpret_for_ui_sync;
+use logicaffeine_language::Session;
+use serde::{Deserialize, Serialize};
+
+/// Version of the notebook layout; files with another version are rejected.
+pub const NOTEBOOK_VERSION: u32 = 1;
+
+/// VFS directory notebooks are saved in.
+pub const NOTEBOOK_DIR: &str = "/notebooks";
+
+/// File extension of a saved notebook.
+pub const NOTEBOOK_EXTENSION: &str = ".notebook.json";
+
+/// What a cell holds, and so how it runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CellKind {
+    Code,
+    Logic,
+    Markdown,
+}
+
+impl CellKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            CellKind::Code => "Code",
+            CellKind::Logic => "Logic",
+            CellKind::Markdown => "Markdown",
+        }
+    }
+}
+
+/// One cell of a notebook.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cell {
+    pub kind: CellKind,
+    pub source: String,
+}
+
+impl Cell {
+    pub fn new(kind: CellKind, source: impl Into<String>) -> Self {
+        Self { kind, source: source.into() }
+    }
+}
+
+/// What running a cell produced. Markdown cells produce nothing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CellOutput {
+    pub lines: Vec<String>,
+    pub error: Option<String>,
+}
+
+/// A titled, ordered list of cells.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Notebook {
+    pub version: u32,
+    pub title: String,
+    pub cells: Vec<Cell>,
+}
+
+impl Notebook {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self { version: NOTEBOOK_VERSION, title: title.into(), cells: Vec::new() }
+    }
+
+    /// The notebook a first visit opens: one cell of each kind, with later
+    /// cells leaning on earlier ones.
+    pub fn starter() -> Self {
+        let mut notebook = Self::new("My first notebook");
+        notebook.cells = vec![
+            Cell::new(
+                CellKind::Markdown,
+                "# A notebook\nCells run **in order** and share what they define. Edit any cell, then press Run all.",
+            ),
+            Cell::new(CellKind::Code, "## To square (n: Int) -> Int:\n    Return n * n.\n\nLet side be 12."),
+            Cell::new(CellKind::Code, "Show square(side)."),
+            Cell::new(CellKind::Logic, "A farmer owns a donkey."),
+            Cell::new(CellKind::Logic, "He feeds it."),
+        ];
+        notebook
+    }
+
+    /// The portable JSON form, as saved to the VFS and exported.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("a notebook is plain data")
+    }
+
+    /// Read a notebook from its JSON form.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let notebook: Notebook = serde_json::from_str(json).map_err(|e| format!("Not a notebook: {e}"))?;
+        if notebook.version != NOTEBOOK_VERSION {
+            return Err(format!(
+                "Notebook version {} is not supported (expected {NOTEBOOK_VERSION})",
+                notebook.version
+            ));
+        }
+        Ok(notebook)
+    }
+
+    /// The VFS path this notebook saves to, derived from its title.
+    pub fn path(&self) -> String {
+        format!("{NOTEBOOK_DIR}/{}{NOTEBOOK_EXTENSION}", slug(&self.title))
+    }
+
+    /// Run every cell in order, one output per cell.
+    pub fn run(&self) -> Vec<CellOutput> {
+        self.run_resuming(&mut Checkpoints::default())
+    }
+
+    /// Like [`Notebook::run`], but logic cells unchanged since the run that
+    /// filled `checkpoints` keep their output, and the session resumes from
+    /// the snapshot after the last of them.
+    pub fn run_resuming(&self, checkpoints: &mut Checkpoints) -> Vec<CellOutput> {
+        let kept = checkpoints
+            .logic
+            .iter()
+            .zip(self.cells.iter().filter(|cell| cell.kind == CellKind::Logic))
+            .take_while(|(checkpoint, cell)| checkpoint.source == cell.source)
+            .count();
+        checkpoints.logic.truncate(kept);
+        let mut session = match checkpoints.logic.last() {
+            None => Session::new(),
+            Some(checkpoint) => match Session::from_bytes(&checkpoint.session) {
+                Ok(session) => session,
+                Err(_) => {
+                    checkpoints.logic.clear();
+                    Session::new()
+                }
+            },
+        };
+        let mut logic_cells = 0;
+        let mut definitions = String::new();
+        let mut statements = String::new();
+        let mut shown = 0;
+        self.cells
+            .iter()
+            .map(|cell| match cell.kind {
+                CellKind::Markdown => CellOutput::default(),
+                CellKind::Logic => {
+                    logic_cells += 1;
+                    if let Some(checkpoint) = checkpoints.logic.get(logic_cells - 1) {
+                        return checkpoint.output.clone();
+                    }
+                    let output = run_logic(&mut session, &cell.source);
+                    checkpoints.logic.push(LogicCheckpoint {
+                        source: cell.source.clone(),
+                        output: output.clone(),
+                        session: session.to_bytes(),
+                    });
+                    output
+                }
+                CellKind::Code => {
+                    let (defs, stmts) = split_code(&cell.source);
+                    let program_defs = format!("{definitions}{defs}");
+                    let program_stmts = format!("{statements}{stmts}");
+                    let result = interpret_for_ui_sync(&program(&program_defs, &program_stmts));
+                    let lines = result.lines.get(shown..).unwrap_or_default().to_vec();
+                    if result.error.is_none() {
+                        definitions = program_defs;
+                        statements = program_stmts;
+                        shown = result.lines.len();
+                    }
+                    CellOutput { lines, error: result.error }
+                }
+            })
+            .collect()
+    }
+}
+
+/// The discourse after each logic cell of a run, for [`Notebook::run_resuming`].
+#[derive(Debug, Clone, Default)]
+pub struct Checkpoints {
+    logic: Vec<LogicCheckpoint>,
+}
+
+#[derive(Debug, Clone)]
+struct LogicCheckpoint {
+    source: String,
+    output: CellOutput,
+    /// The session after this cell, as a binary snapshot.
+    session: Vec<u8>,
+}
+
+/// The file-name stem for a title: lowercase letters and digits, runs of
+/// anything else collapsed to one `-`.
+fn slug(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() { "untitled".to_string() } else { slug.to_string() }
+}
+
+/// Split a code cell into its definition blocks and its `## Main`
+/// statements. Lines before any header are statements.
+fn split_code(source: &str) -> (String, String) {
+    let mut definitions = String::new();
+    let mut statements = String::new();
+    let mut in_main = true;
+    for line in source.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("##") {
+            in_main = trimmed.trim_start_matches('#').trim() == "Main";
+            if in_main {
+                continue;
+            }
+        }
+        let target = if in_main { &mut statements } else { &mut definitions };
+        target.push_str(line);
+        target.push('\n');
+    }
+    (definitions, statements)
+}
+
+/// The program a code cell runs as. A notebook with no statements yet still
+/// runs, so its definitions are checked.
+fn program(definitions: &str, statements: &str) -> String {
+    let body = if statements.trim().is_empty() { "Return.\n" } else { statements };
+    format!("{definitions}\n## Main\n{body}")
+}
+
+/// Translate a logic cell's sentences, one per line, stopping at the first
+/// that does not parse.
+fn run_logic(session: &mut Session, source: &str) -> CellOutput {
+    let mut output = CellOutput::default();
+    for sentence in source.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        match session.eval(sentence) {
+            Ok(logic) => output.lines.push(logic),
+            Err(e) => {
+                output.error = Some(e.display_with_source(sentence));
+                break;
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notebook(cells: &[(CellKind, &str)]) -> Notebook {
+        let mut notebook = Notebook::new("Test");
+        notebook.cells = cells.iter().map(|&(kind, source)| Cell::new(kind, source)).collect();
+        notebook
+    }
+
+    #[test]
+    fn code_cells_build_on_earlier_cells() {
+        let outputs = notebook(&[
+            (CellKind::Code, "## To double (n: Int) -> Int:\n    Return n * 2."),
+            (CellKind::Markdown, "Now use it."),
+            (CellKind::Code, "Let x be 21.\nShow x."),
+            (CellKind::Code, "Show double(x)."),
+        ])
+        .run();
+        assert_eq!(outputs[0], CellOutput::default());
+        assert_eq!(outputs[1], CellOutput::default());
+        assert_eq!(outputs[2].lines, vec!["21"]);
+        assert_eq!(outputs[3].lines, vec!["42"], "{:?}", outputs[3].error);
+    }
+
+    #[test]
+    fn a_failing_cell_is_left_out_of_later_cells() {
+        let outputs = notebook(&[
+            (CellKind::Code, "Let x be 1."),
+            (CellKind::Code, "Show y."),
+            (CellKind::Code, "Show x + 1."),
+        ])
+        .run();
+        assert!(outputs[1].error.is_some());
+        assert_eq!(outputs[2].lines, vec!["2"]);
+        assert_eq!(outputs[2].error, None);
+    }
+
+    #[test]
+    fn logic_cells_share_one_session() {
+        let outputs = notebook(&[
+            (CellKind::Logic, "A man entered."),
+            (CellKind::Code, "Show 1."),
+            (CellKind::Logic, "He sat."),
+        ])
+        .run();
+        assert!(outputs[0].lines[0].contains("Man"), "{:?}", outputs[0]);
+        assert!(outputs[2].lines[0].contains("Sit"), "{:?}", outputs[2]);
+        assert!(!outputs[2].lines[0].contains('?'), "He should resolve: {:?}", outputs[2]);
+    }
+
+    #[test]
+    fn rerun_resumes_the_session_at_the_first_edited_cell() {
+        let mut checkpoints = Checkpoints::default();
+        let mut notebook = notebook(&[
+            (CellKind::Logic, "A farmer owns a donkey."),
+            (CellKind::Logic, "He feeds it."),
+            (CellKind::Logic, "It sleeps."),
+        ]);
+        let first = notebook.run_resuming(&mut checkpoints);
+        assert_eq!(first, notebook.run());
+
+        notebook.cells[2].source = "It sleeps.\nHe smiles.".to_string();
+        let resumed = notebook.run_resuming(&mut checkpoints);
+        assert_eq!(resumed, notebook.run());
+        assert_eq!(resumed[..2], first[..2]);
+        assert!(!resumed[2].lines[1].contains('?'), "He should resolve: {:?}", resumed[2]);
+        assert_eq!(checkpoints.logic.len(), 3);
+    }
+
+    #[test]
+    fn json_round_trips_and_checks_its_version() {
+        let original = Notebook::starter();
+        let json = original.to_json();
+        assert!(json.contains(r#""kind": "markdown""#), "{json}");
+        assert_eq!(Notebook::from_json(&json).unwrap(), original);
+
+        let future = json.replace(r#""version": 1"#, r#""version": 2"#);
+        assert!(Notebook::from_json(&future).unwrap_err().contains("version 2"));
+        assert!(Notebook::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn paths_come_from_titles() {
+        assert_eq!(Notebook::new("My First Notebook!").path(), "/notebooks/my-first-notebook.notebook.json");
+        assert_eq!(Notebook::new("  ").path(), "/notebooks/untitled.notebook.json");
+    }
+}
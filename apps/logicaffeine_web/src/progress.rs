@@ -23,8 +23,31 @@ pub struct UserProgress {
     pub title: Option<String>,
     #[serde(default)]
     pub last_weekly_freeze_date: Option<String>,
+    /// Daily XP goal the learner chose; `None` uses [`DEFAULT_DAILY_GOAL`].
+    #[serde(default)]
+    pub daily_goal: Option<u32>,
+    /// XP earned on `xp_today_date`.
+    #[serde(default)]
+    pub xp_today: u64,
+    #[serde(default)]
+    pub xp_today_date: Option<String>,
+    /// The day vacation mode started, while it is on.
+    #[serde(default)]
+    pub vacation_since: Option<String>,
+    /// Every spaced-repetition review, oldest first.
+    #[serde(default)]
+    pub review_log: Vec<ReviewRecord>,
+    /// Finished exams, oldest first. Exams earn no XP; this is their record.
+    #[serde(default)]
+    pub exam_results: Vec<ExamResult>,
 }
 
+/// The daily XP goal of a learner who has not picked one.
+pub const DEFAULT_DAILY_GOAL: u32 = 30;
+
+/// The daily XP goals offered, from casual to intense.
+pub const DAILY_GOAL_CHOICES: [u32; 4] = [10, 30, 50, 100];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExerciseProgress {
     pub exercise_id: String,
@@ -53,6 +76,45 @@ impl Default for SrsData {
     }
 }
 
+/// One spaced-repetition review of one card.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewRecord {
+    pub date: String,
+    pub exercise_id: String,
+    pub correct: bool,
+    /// Days until the next review this answer scheduled.
+    pub interval: u32,
+}
+
+/// The score report of one finished exam.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExamResult {
+    pub date: String,
+    /// IDs of the modules the exam drew from.
+    pub modules: Vec<String>,
+    pub questions: usize,
+    pub correct: usize,
+    pub time_limit_secs: u32,
+    pub time_taken_secs: u32,
+    /// The score on each concept, in the order concepts first appeared.
+    pub concepts: Vec<ConceptScore>,
+}
+
+impl ExamResult {
+    /// The score as a whole percentage.
+    pub fn percent(&self) -> u32 {
+        if self.questions == 0 { 0 } else { (self.correct * 100 / self.questions) as u32 }
+    }
+}
+
+/// Questions on one concept and how many were answered correctly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConceptScore {
+    pub concept: String,
+    pub questions: usize,
+    pub correct: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModuleProgress {
     pub module_id: String,
@@ -131,6 +193,31 @@ impl UserProgress {
         self.save();
     }
 
+    pub fn daily_goal(&self) -> u32 {
+        self.daily_goal.unwrap_or(DEFAULT_DAILY_GOAL)
+    }
+
+    /// Set the daily XP goal; a goal of zero is raised to one.
+    pub fn set_daily_goal(&mut self, xp: u32) {
+        self.daily_goal = Some(xp.max(1));
+        self.save();
+    }
+
+    /// XP earned on `today`.
+    pub fn xp_earned_on(&self, today: &str) -> u64 {
+        if self.xp_today_date.as_deref() == Some(today) { self.xp_today } else { 0 }
+    }
+
+    pub fn on_vacation(&self) -> bool {
+        self.vacation_since.is_some()
+    }
+
+    /// Keep a finished exam's report.
+    pub fn record_exam(&mut self, result: ExamResult) {
+        self.exam_results.push(result);
+        self.save();
+    }
+
     pub fn get_exercise_progress(&self, exercise_id: &str) -> Option<&ExerciseProgress> {
         self.exercises.get(exercise_id)
     }
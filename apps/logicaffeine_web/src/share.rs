@@ -0,0 +1,290 @@
+//! Shareable Studio Links
+//!
+//! Packs the Studio's buffers and view options into the URL fragment, so a
+//! link reproduces what its author was looking at:
+//! - A [`Snippet`] is serialized to JSON, deflated, and base64url-encoded into
+//!   `#code=…`. The fragment never reaches a server
+//! - Snippets too long for a comfortable URL are stored through the paste
+//!   service instead, and the link carries only `#paste=<id>`
+//! - Decoding caps the inflated size, so a crafted link can't balloon memory
+//!
+//! # Usage
+//!
+//! ```no_run
+//! use logicaffeine_web::share::{ShareLink, Snippet, SharedFile};
+//!
+//! let snippet = Snippet::new("code", vec![SharedFile::new("main.logos", "## Main\nShow 42.")]);
+//! let fragment = snippet.fragment().expect("small snippets fit in the URL");
+//! assert_eq!(ShareLink::parse(&fragment).and_then(|link| link.inline()).unwrap(), snippet);
+//! ```
+
+use miniz_oxide::deflate::compress_to_vec;
+use miniz_oxide::inflate::decompress_to_vec_with_limit;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Version of the snippet layout; links with another version are rejected.
+pub const SNIPPET_VERSION: u32 = 1;
+
+/// Where oversized snippets are stored and fetched.
+pub const PASTE_URL: &str = "https://api.logicaffeine.com/paste";
+
+/// Longest `#code=` fragment put in a link; larger snippets go to the paste service.
+pub const MAX_FRAGMENT_LEN: usize = 2000;
+
+/// Largest snippet a link may inflate to.
+const MAX_SNIPPET_BYTES: usize = 1 << 20;
+
+const CODE_PREFIX: &str = "code=";
+const PASTE_PREFIX: &str = "paste=";
+
+/// One shared buffer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SharedFile {
+    pub name: String,
+    pub content: String,
+}
+
+impl SharedFile {
+    pub fn new(name: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            content: content.into(),
+        }
+    }
+}
+
+/// Everything a link restores: the Studio mode, its buffers, and view options
+/// such as the logic output format.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snippet {
+    pub version: u32,
+    pub mode: String,
+    pub files: Vec<SharedFile>,
+    /// Index into `files` of the buffer shown first.
+    #[serde(default)]
+    pub active: usize,
+    #[serde(default)]
+    pub options: BTreeMap<String, String>,
+}
+
+impl Snippet {
+    pub fn new(mode: impl Into<String>, files: Vec<SharedFile>) -> Self {
+        Self {
+            version: SNIPPET_VERSION,
+            mode: mode.into(),
+            files,
+            active: 0,
+            options: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_option(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.options.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn active_file(&self) -> Option<&SharedFile> {
+        self.files.get(self.active)
+    }
+
+    /// The compressed, URL-safe payload.
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        base64url_encode(&compress_to_vec(&json, 9))
+    }
+
+    /// Reverses [`Snippet::encode`].
+    pub fn decode(payload: &str) -> Result<Self, String> {
+        let compressed = base64url_decode(payload).ok_or("The link is not valid base64")?;
+        let json = decompress_to_vec_with_limit(&compressed, MAX_SNIPPET_BYTES)
+            .map_err(|_| "The link is corrupted or too large".to_string())?;
+        let snippet: Snippet = serde_json::from_slice(&json).map_err(|e| format!("The link is not a snippet: {e}"))?;
+        if snippet.version != SNIPPET_VERSION {
+            return Err(format!("Unsupported snippet version {}", snippet.version));
+        }
+        Ok(snippet)
+    }
+
+    /// `code=…` for the URL fragment, or `None` if the snippet needs the paste service.
+    pub fn fragment(&self) -> Option<String> {
+        let fragment = format!("{CODE_PREFIX}{}", self.encode());
+        (fragment.len() <= MAX_FRAGMENT_LEN).then_some(fragment)
+    }
+
+    /// Stores the snippet through [`PASTE_URL`] and returns its `paste=<id>` fragment.
+    pub async fn upload(&self) -> Result<String, String> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            #[derive(Deserialize)]
+            struct Created {
+                id: String,
+            }
+
+            let response = gloo_net::http::Request::post(PASTE_URL)
+                .header("Content-Type", "text/plain")
+                .body(self.encode())
+                .map_err(|e| e.to_string())?
+                .send()
+                .await
+                .map_err(|e| format!("storing snippet: {e}"))?;
+            if !response.ok() {
+                return Err(format!("storing snippet: HTTP {}", response.status()));
+            }
+            let created: Created = response.json().await.map_err(|e| format!("storing snippet: {e}"))?;
+            Ok(format!("{PASTE_PREFIX}{}", created.id))
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Err("The paste service is only reachable from the browser".to_string())
+        }
+    }
+}
+
+/// A parsed share fragment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShareLink {
+    /// The snippet itself, encoded in the fragment.
+    Inline(String),
+    /// The ID of a snippet held by the paste service.
+    Paste(String),
+}
+
+impl ShareLink {
+    /// Parses a URL fragment, with or without its leading `#`.
+    pub fn parse(fragment: &str) -> Option<Self> {
+        let fragment = fragment.trim_start_matches('#');
+        if let Some(payload) = fragment.strip_prefix(CODE_PREFIX) {
+            return (!payload.is_empty()).then(|| ShareLink::Inline(payload.to_string()));
+        }
+        let id = fragment.strip_prefix(PASTE_PREFIX)?;
+        let valid = !id.is_empty() && id.len() <= 64 && id.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+        valid.then(|| ShareLink::Paste(id.to_string()))
+    }
+
+    /// Decodes an inline link; `None` for paste links and bad payloads.
+    pub fn inline(&self) -> Option<Snippet> {
+        match self {
+            ShareLink::Inline(payload) => Snippet::decode(payload).ok(),
+            ShareLink::Paste(_) => None,
+        }
+    }
+
+    /// Resolves the link to its snippet, fetching from the paste service if needed.
+    pub async fn resolve(&self) -> Result<Snippet, String> {
+        match self {
+            ShareLink::Inline(payload) => Snippet::decode(payload),
+            ShareLink::Paste(id) => {
+                #[cfg(target_arch = "wasm32")]
+                {
+                    let response = gloo_net::http::Request::get(&format!("{PASTE_URL}/{id}"))
+                        .send()
+                        .await
+                        .map_err(|e| format!("fetching snippet: {e}"))?;
+                    if !response.ok() {
+                        return Err(format!("fetching snippet: HTTP {}", response.status()));
+                    }
+                    let payload = response.text().await.map_err(|e| format!("fetching snippet: {e}"))?;
+                    Snippet::decode(payload.trim())
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    Err(format!("Snippet {id} can only be fetched from the browser"))
+                }
+            }
+        }
+    }
+}
+
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Unpadded base64url, the alphabet that survives URLs untouched.
+fn base64url_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            out.push(BASE64URL[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+fn base64url_decode(text: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(text.len() * 3 / 4);
+    for chunk in text.as_bytes().chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut n = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            let value = BASE64URL.iter().position(|&b| b == c)? as u32;
+            n |= value << (18 - 6 * i);
+        }
+        for i in 0..chunk.len() - 1 {
+            out.push((n >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Snippet {
+        Snippet::new(
+            "code",
+            vec![
+                SharedFile::new("main.logos", "# Main\n\nUses [Geometry](file:./geo.md).\n\n## Main\nShow 42."),
+                SharedFile::new("geo.md", "# Geometry\n\n## Definition\nA Point has:\n    an x, which is Int."),
+            ],
+        )
+        .with_option("format", "SimpleFOL")
+    }
+
+    #[test]
+    fn test_snippet_roundtrip_through_fragment() {
+        let snippet = sample();
+        let fragment = snippet.fragment().expect("fits in a URL");
+        assert!(fragment.bytes().all(|b| b.is_ascii_alphanumeric() || b"=-_".contains(&b)));
+        let link = ShareLink::parse(&format!("#{fragment}")).unwrap();
+        assert_eq!(link.inline(), Some(snippet));
+    }
+
+    #[test]
+    fn test_base64url_roundtrip() {
+        for len in 0..8 {
+            let bytes: Vec<u8> = (0..len).map(|i| (i * 37 + 200) as u8).collect();
+            assert_eq!(base64url_decode(&base64url_encode(&bytes)), Some(bytes));
+        }
+        assert_eq!(base64url_encode(b"Man"), "TWFu");
+        assert_eq!(base64url_decode("A"), None);
+        assert_eq!(base64url_decode("TW+u"), None);
+    }
+
+    #[test]
+    fn test_oversized_snippets_need_the_paste_service() {
+        // Pseudo-random text barely compresses
+        let mut seed = 7u64;
+        let noise: String = (0..4000)
+            .map(|_| {
+                seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                char::from(b'a' + (seed >> 59) as u8 % 26)
+            })
+            .collect();
+        let snippet = Snippet::new("logic", vec![SharedFile::new("big.logic", noise)]);
+        assert_eq!(snippet.fragment(), None);
+        assert_eq!(Snippet::decode(&snippet.encode()), Ok(snippet));
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_links() {
+        assert_eq!(ShareLink::parse("#paste=abc_123"), Some(ShareLink::Paste("abc_123".into())));
+        assert_eq!(ShareLink::parse("#paste=../etc"), None);
+        assert_eq!(ShareLink::parse("#code="), None);
+        assert_eq!(ShareLink::parse("#section-2"), None);
+        assert!(Snippet::decode("not*base64").is_err());
+        assert!(Snippet::decode(&base64url_encode(b"plain bytes")).is_err());
+    }
+}
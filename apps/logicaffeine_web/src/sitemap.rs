@@ -31,8 +31,10 @@ pub fn get_static_routes() -> Vec<SitemapEntry> {
         ("/", "weekly", 1.0),
         ("/guide", "monthly", 0.9),
         ("/learn", "monthly", 0.9),
+        ("/exam", "monthly", 0.6),
         ("/crates", "monthly", 0.8),
         ("/studio", "weekly", 0.8),
+        ("/notebook", "monthly", 0.6),
         ("/benchmarks", "weekly", 0.8),
         ("/pricing", "monthly", 0.8),
         ("/roadmap", "monthly", 0.7),
@@ -130,8 +132,9 @@ mod tests {
         use dioxus::prelude::Routable;
         use dioxus::router::routable::SegmentType;
 
-        // Session-specific pages that must NOT be crawled or prerendered.
-        const EXCLUDED: &[&str] = &["/success"];
+        // Session-specific pages and iframe-only widgets that must NOT be
+        // crawled or prerendered.
+        const EXCLUDED: &[&str] = &["/success", "/embed"];
 
         let sitemapped: std::collections::BTreeSet<String> =
             prerender_routes().into_iter().collect();
@@ -1,4 +1,5 @@
-use crate::progress::SrsData;
+use crate::generator::{AnswerType, Challenge};
+use crate::progress::{ExerciseProgress, ReviewRecord, SrsData, UserProgress};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ResponseQuality {
@@ -48,6 +49,22 @@ pub fn sm2_update(srs: &mut SrsData, quality: ResponseQuality) {
     }
 }
 
+/// Leitner-box scheduling: each correct answer moves the card up a box and
+/// doubles its interval (capped at [`LEITNER_MAX_INTERVAL`] days); a miss sends
+/// it back to the first box. The ease factor is left untouched.
+pub fn leitner_update(srs: &mut SrsData, quality: ResponseQuality) {
+    if quality.is_correct() {
+        srs.repetitions += 1;
+        srs.interval = (1u32 << (srs.repetitions - 1).min(31)).min(LEITNER_MAX_INTERVAL);
+    } else {
+        srs.repetitions = 0;
+        srs.interval = 1;
+    }
+}
+
+/// The longest interval a Leitner box schedules, in days.
+pub const LEITNER_MAX_INTERVAL: u32 = 32;
+
 pub fn calculate_next_review(current_date: &str, interval_days: u32) -> String {
     if let Ok(date) = parse_date(current_date) {
         let next = date + interval_days as i64;
@@ -70,6 +87,133 @@ pub fn is_due(next_review: Option<&str>, today: &str) -> bool {
     }
 }
 
+/// Anki deck the exported cards land in.
+pub const ANKI_DECK: &str = "Logicaffeine";
+
+/// The learner's studied cards as an Anki plain-text import (tab-separated,
+/// with Anki's `#` file headers): one Basic note per exercise they have
+/// progress on, front the English prompt and sentence, back the expected
+/// logic. The exercise ID is the note's GUID, so re-importing updates notes
+/// instead of duplicating them, and the scheduling state rides along as tags —
+/// `srs::due` or `srs::learned`, then `ease::`, `interval::`, `reps::` and
+/// `next::`. `challenges` supplies each exercise's rendered content;
+/// exercises without one are skipped.
+pub fn export_anki(progress: &UserProgress, challenges: &[Challenge], today: &str) -> String {
+    let mut out = format!(
+        "#separator:tab\n#html:false\n#notetype:Basic\n#deck:{ANKI_DECK}\n#guid column:1\n#tags column:4\n"
+    );
+    let mut cards: Vec<(&ExerciseProgress, &Challenge)> = challenges
+        .iter()
+        .filter_map(|challenge| Some((progress.get_exercise_progress(&challenge.exercise_id)?, challenge)))
+        .collect();
+    cards.sort_by(|a, b| a.0.exercise_id.cmp(&b.0.exercise_id));
+    cards.dedup_by(|a, b| a.0.exercise_id == b.0.exercise_id);
+
+    for (exercise, challenge) in cards {
+        let front = if challenge.sentence == challenge.prompt {
+            challenge.prompt.clone()
+        } else {
+            format!("{} \u{2014} {}", challenge.prompt, challenge.sentence)
+        };
+        let back = match &challenge.answer {
+            AnswerType::FreeForm { golden_logic } => golden_logic.clone(),
+            AnswerType::MultipleChoice { options, correct_index } => {
+                options.get(*correct_index).cloned().unwrap_or_default()
+            }
+            AnswerType::Ambiguity { readings } => readings.join(" | "),
+        };
+        let srs = &exercise.srs;
+        let state = if is_due(srs.next_review.as_deref(), today) { "due" } else { "learned" };
+        let mut tags = format!(
+            "logicaffeine srs::{state} ease::{:.2} interval::{} reps::{}",
+            srs.ease_factor, srs.interval, srs.repetitions
+        );
+        if let Some(next) = &srs.next_review {
+            tags.push_str(&format!(" next::{next}"));
+        }
+        out.push_str(&format!(
+            "{}\t{}\t{}\t{tags}\n",
+            anki_field(&exercise.exercise_id),
+            anki_field(&front),
+            anki_field(&back)
+        ));
+    }
+    out
+}
+
+/// A field with the characters that would break a tab-separated row
+/// flattened to spaces.
+fn anki_field(text: &str) -> String {
+    text.replace(['\t', '\n', '\r'], " ")
+}
+
+/// Review one card on `date`: count the attempt, reschedule it through SM-2
+/// from that date, and append the review to the learner's review log. The
+/// caller saves.
+pub fn review_card(progress: &mut UserProgress, exercise_id: &str, quality: ResponseQuality, date: &str) {
+    let exercise = progress.exercises.entry(exercise_id.to_string()).or_insert_with(|| ExerciseProgress {
+        exercise_id: exercise_id.to_string(),
+        attempts: 0,
+        correct_count: 0,
+        last_attempt: None,
+        srs: SrsData::default(),
+    });
+    exercise.attempts += 1;
+    if quality.is_correct() {
+        exercise.correct_count += 1;
+    }
+    exercise.last_attempt = Some(date.to_string());
+    sm2_update(&mut exercise.srs, quality);
+    exercise.srs.next_review = Some(calculate_next_review(date, exercise.srs.interval));
+    progress.review_log.push(ReviewRecord {
+        date: date.to_string(),
+        exercise_id: exercise_id.to_string(),
+        correct: quality.is_correct(),
+        interval: exercise.srs.interval,
+    });
+}
+
+/// Replay review history recorded elsewhere (say, in Anki) into the
+/// learner's progress. Each line is `exercise-id<TAB>YYYY-MM-DD<TAB>button`,
+/// where the button is Anki's 1 (Again), 2 (Hard), 3 (Good) or 4 (Easy);
+/// blank lines and `#` lines are skipped. Reviews are applied oldest first
+/// through [`review_card`], each rescheduling the card from its own date.
+/// Returns how many reviews were applied; a malformed line rejects the whole
+/// history and leaves `progress` untouched. The caller saves.
+pub fn import_review_history(progress: &mut UserProgress, history: &str) -> Result<usize, String> {
+    let mut reviews = Vec::new();
+    for (index, line) in history.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split('\t').map(str::trim).collect();
+        let [exercise_id, date, button] = fields[..] else {
+            return Err(format!("line {}: expected 3 tab-separated fields, found {}", index + 1, fields.len()));
+        };
+        let day = match parse_date(date) {
+            Ok(day) if (1..=12).contains(&(day % 10000 / 100)) && (1..=31).contains(&(day % 100)) => day,
+            _ => return Err(format!("line {}: '{date}' is not a YYYY-MM-DD date", index + 1)),
+        };
+        let quality = match button {
+            "1" => ResponseQuality::Incorrect,
+            "2" => ResponseQuality::CorrectDifficult,
+            "3" => ResponseQuality::CorrectHesitation,
+            "4" => ResponseQuality::Perfect,
+            _ => return Err(format!("line {}: '{button}' is not an Anki answer button (1-4)", index + 1)),
+        };
+        reviews.push((exercise_id, day, quality));
+    }
+    // Order by the parsed day, since dates may be unpadded (`2025-1-8`); the
+    // sort is stable, keeping same-day reviews in file order.
+    reviews.sort_by_key(|review| review.1);
+
+    for &(exercise_id, day, quality) in &reviews {
+        review_card(progress, exercise_id, quality, &format_date(day));
+    }
+    Ok(reviews.len())
+}
+
 fn parse_date(date_str: &str) -> Result<i64, ()> {
     let parts: Vec<&str> = date_str.split('-').collect();
     if parts.len() != 3 {
@@ -196,4 +340,89 @@ mod tests {
         assert!(ResponseQuality::CorrectHesitation.is_correct());
         assert!(ResponseQuality::Perfect.is_correct());
     }
+
+    #[test]
+    fn test_leitner_doubles_then_resets() {
+        let mut srs = SrsData::default();
+        let intervals: Vec<u32> = (0..7)
+            .map(|_| {
+                leitner_update(&mut srs, ResponseQuality::Perfect);
+                srs.interval
+            })
+            .collect();
+        assert_eq!(intervals, [1, 2, 4, 8, 16, 32, 32]);
+
+        leitner_update(&mut srs, ResponseQuality::Incorrect);
+        assert_eq!((srs.repetitions, srs.interval), (0, 1));
+    }
+
+    fn challenge(exercise_id: &str, sentence: &str, golden_logic: &str) -> Challenge {
+        Challenge {
+            exercise_id: exercise_id.to_string(),
+            prompt: "Translate".to_string(),
+            sentence: sentence.to_string(),
+            answer: AnswerType::FreeForm { golden_logic: golden_logic.to_string() },
+            hint: None,
+            explanation: None,
+        }
+    }
+
+    #[test]
+    fn test_export_anki_rows_carry_content_and_schedule() {
+        let mut progress = UserProgress::new();
+        import_review_history(&mut progress, "a_1\t2025-01-01\t4\nb_2\t2025-01-10\t3\n").unwrap();
+        let challenges = [
+            challenge("b_2", "Every cat sleeps.", "\u{2200}x(Cat(x) \u{2192} Sleep(x))"),
+            challenge("a_1", "John runs.", "Run(J)"),
+            challenge("c_3", "Never studied.", "P"),
+        ];
+        let tsv = export_anki(&progress, &challenges, "2025-01-05");
+        let rows: Vec<&str> = tsv.lines().filter(|l| !l.starts_with('#')).collect();
+        assert!(tsv.starts_with("#separator:tab\n#html:false\n"), "{tsv}");
+        assert_eq!(
+            rows,
+            [
+                "a_1\tTranslate \u{2014} John runs.\tRun(J)\tlogicaffeine srs::due ease::2.60 interval::1 reps::1 next::2025-01-02",
+                "b_2\tTranslate \u{2014} Every cat sleeps.\t\u{2200}x(Cat(x) \u{2192} Sleep(x))\tlogicaffeine srs::learned ease::2.50 interval::1 reps::1 next::2025-01-11",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_import_review_history_orders_unpadded_dates_by_day() {
+        let mut progress = UserProgress::new();
+        import_review_history(&mut progress, "x\t2025-1-10\t1\nx\t2025-1-8\t4\nx\t2025-01-9\t4\n").unwrap();
+        let logged: Vec<&str> = progress.review_log.iter().map(|r| r.date.as_str()).collect();
+        assert_eq!(logged, ["2025-01-08", "2025-01-09", "2025-01-10"]);
+        assert!(import_review_history(&mut progress, "x\t2025-13-01\t4\n").is_err());
+    }
+
+    #[test]
+    fn test_import_review_history_replays_in_date_order() {
+        let mut progress = UserProgress::new();
+        let applied = import_review_history(
+            &mut progress,
+            "# exported from Anki\nx\t2025-01-08\t1\nx\t2025-01-01\t4\n\nx\t2025-01-02\t4\n",
+        )
+        .unwrap();
+        assert_eq!(applied, 3);
+        let exercise = progress.get_exercise_progress("x").unwrap();
+        assert_eq!((exercise.attempts, exercise.correct_count), (3, 2));
+        // Two correct reviews then a lapse on the 8th: back to a one-day interval.
+        assert_eq!(exercise.srs.repetitions, 0);
+        assert_eq!(exercise.srs.next_review.as_deref(), Some("2025-01-09"));
+        assert_eq!(exercise.last_attempt.as_deref(), Some("2025-01-08"));
+        let logged: Vec<(&str, bool)> = progress.review_log.iter().map(|r| (r.date.as_str(), r.correct)).collect();
+        assert_eq!(logged, [("2025-01-01", true), ("2025-01-02", true), ("2025-01-08", false)]);
+    }
+
+    #[test]
+    fn test_import_review_history_rejects_bad_lines_whole() {
+        let mut progress = UserProgress::new();
+        let err = import_review_history(&mut progress, "x\t2025-01-01\t4\nx\t2025-01-02\t5\n").unwrap_err();
+        assert!(err.contains("line 2"), "{err}");
+        assert!(progress.exercises.is_empty());
+        assert!(import_review_history(&mut progress, "x\tyesterday\t3").is_err());
+        assert!(import_review_history(&mut progress, "x\t2025-01-01").is_err());
+    }
 }
@@ -0,0 +1,278 @@
+//! Review statistics
+//!
+//! Everything the Profile page's review dashboard shows, computed from the
+//! learner's review log ([`UserProgress::review_log`]):
+//! - **Retention**: the share of reviews answered correctly, overall and by
+//!   the interval the card was reviewed at — a card answered after a 30-day
+//!   gap says more about memory than one answered the day after it was learned
+//! - **Interval growth**: the mean interval scheduled after a card's 1st,
+//!   2nd, 3rd… review, which is how fast the deck is maturing
+//! - **Heatmap**: reviews per day over the last [`HEATMAP_WEEKS`] weeks, laid
+//!   out Sunday-first in week columns like a GitHub contribution graph
+//!
+//! [`ReviewStats::to_json`] is the export format.
+//!
+//! # Usage
+//!
+//! ```no_run
+//! use logicaffeine_web::progress::UserProgress;
+//! use logicaffeine_web::srs::{review_card, ResponseQuality};
+//! use logicaffeine_web::stats::ReviewStats;
+//!
+//! let mut progress = UserProgress::new();
+//! review_card(&mut progress, "a_1", ResponseQuality::Perfect, "2025-01-01");
+//! review_card(&mut progress, "a_1", ResponseQuality::Incorrect, "2025-01-02");
+//!
+//! let stats = ReviewStats::compute(&progress, "2025-01-02");
+//! assert_eq!(stats.total_reviews, 2);
+//! assert_eq!(stats.retention, Some(0.5));
+//! ```
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::game::{date_to_days, days_to_date};
+use crate::progress::UserProgress;
+
+/// Weeks of history the heatmap covers.
+pub const HEATMAP_WEEKS: usize = 53;
+
+/// Reviews past this count are left off the interval growth curve.
+pub const GROWTH_MAX_REVIEWS: usize = 12;
+
+/// Retention buckets by the interval a card was reviewed at: label and the
+/// inclusive day range. Interval 0 is a card's first review.
+const RETENTION_BUCKETS: [(&str, u32, u32); 5] = [
+    ("New", 0, 0),
+    ("1 day", 1, 1),
+    ("2–6 days", 2, 6),
+    ("7–20 days", 7, 20),
+    ("21+ days", 21, u32::MAX),
+];
+
+/// Correct answers among the reviews in one interval bucket.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RetentionBucket {
+    pub label: &'static str,
+    pub reviews: usize,
+    pub correct: usize,
+}
+
+impl RetentionBucket {
+    /// The share answered correctly, or `None` without reviews.
+    pub fn rate(&self) -> Option<f64> {
+        (self.reviews > 0).then(|| self.correct as f64 / self.reviews as f64)
+    }
+}
+
+/// The mean interval scheduled after a card's `review`-th review, over the
+/// `cards` reviewed at least that often.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GrowthPoint {
+    pub review: usize,
+    pub mean_interval: f64,
+    pub cards: usize,
+}
+
+/// One day of the heatmap: its review count and a shade from 0 (none) to 4
+/// (the busiest days).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HeatmapDay {
+    pub date: String,
+    pub count: usize,
+    pub level: u8,
+}
+
+/// The review dashboard's figures.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReviewStats {
+    pub today: String,
+    pub total_reviews: usize,
+    pub cards_reviewed: usize,
+    /// The share of all reviews answered correctly, or `None` without reviews.
+    pub retention: Option<f64>,
+    pub retention_by_interval: Vec<RetentionBucket>,
+    pub interval_growth: Vec<GrowthPoint>,
+    /// Days with at least one review, in the heatmap's window.
+    pub active_days: usize,
+    /// Every day of the heatmap, oldest first; the first is a Sunday, so day
+    /// `i` sits in week column `i / 7`, weekday row `i % 7`.
+    pub heatmap: Vec<HeatmapDay>,
+}
+
+impl ReviewStats {
+    /// The statistics of `progress`'s review log as of `today`.
+    pub fn compute(progress: &UserProgress, today: &str) -> Self {
+        let log = &progress.review_log;
+
+        // Reviews in date order (stable, so same-day reviews keep log order),
+        // then each card's intervals in the order it saw them.
+        let mut ordered: Vec<_> = log.iter().collect();
+        ordered.sort_by(|x, y| x.date.cmp(&y.date));
+        let mut per_card: HashMap<&str, Vec<u32>> = HashMap::new();
+        let mut buckets: Vec<RetentionBucket> = RETENTION_BUCKETS
+            .iter()
+            .map(|&(label, _, _)| RetentionBucket { label, reviews: 0, correct: 0 })
+            .collect();
+        for record in &ordered {
+            let intervals = per_card.entry(record.exercise_id.as_str()).or_default();
+            let reviewed_at = intervals.last().copied().unwrap_or(0);
+            if let Some(bucket) = RETENTION_BUCKETS
+                .iter()
+                .position(|&(_, low, high)| (low..=high).contains(&reviewed_at))
+            {
+                buckets[bucket].reviews += 1;
+                buckets[bucket].correct += usize::from(record.correct);
+            }
+            intervals.push(record.interval);
+        }
+
+        let interval_growth = (0..GROWTH_MAX_REVIEWS)
+            .map_while(|n| {
+                let at_n: Vec<u32> = per_card.values().filter_map(|intervals| intervals.get(n).copied()).collect();
+                (!at_n.is_empty()).then(|| GrowthPoint {
+                    review: n + 1,
+                    mean_interval: at_n.iter().map(|&days| days as f64).sum::<f64>() / at_n.len() as f64,
+                    cards: at_n.len(),
+                })
+            })
+            .collect();
+
+        let correct = log.iter().filter(|record| record.correct).count();
+        let heatmap = heatmap(log.iter().map(|record| record.date.as_str()), today);
+        Self {
+            today: today.to_string(),
+            total_reviews: log.len(),
+            cards_reviewed: per_card.len(),
+            retention: (!log.is_empty()).then(|| correct as f64 / log.len() as f64),
+            retention_by_interval: buckets,
+            interval_growth,
+            active_days: heatmap.iter().filter(|day| day.count > 0).count(),
+            heatmap,
+        }
+    }
+
+    /// The figures as pretty-printed JSON, for export.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("review stats are plain data")
+    }
+}
+
+/// Count `dates` per day over the [`HEATMAP_WEEKS`] weeks ending on `today`,
+/// starting on a Sunday. Shades split the busiest day's count into quarters.
+fn heatmap<'a>(dates: impl Iterator<Item = &'a str>, today: &str) -> Vec<HeatmapDay> {
+    let Some(end) = date_to_days(today) else {
+        return Vec::new();
+    };
+    // 1970-01-01 was a Thursday: weekday 0 is Sunday.
+    let weekday = (end + 4).rem_euclid(7);
+    let start = end - weekday - (HEATMAP_WEEKS as i64 - 1) * 7;
+
+    let mut counts = vec![0usize; (end - start + 1) as usize];
+    for day in dates.filter_map(date_to_days) {
+        if (start..=end).contains(&day) {
+            counts[(day - start) as usize] += 1;
+        }
+    }
+    let busiest = counts.iter().copied().max().unwrap_or(0).max(1);
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(offset, count)| HeatmapDay {
+            date: days_to_date(start + offset as i64),
+            count,
+            level: if count == 0 { 0 } else { (count * 4).div_ceil(busiest).clamp(1, 4) as u8 },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::srs::{review_card, ResponseQuality};
+
+    fn reviewed(reviews: &[(&str, &str, bool)]) -> UserProgress {
+        let mut progress = UserProgress::new();
+        for &(id, date, correct) in reviews {
+            let quality = if correct { ResponseQuality::Perfect } else { ResponseQuality::Incorrect };
+            review_card(&mut progress, id, quality, date);
+        }
+        progress
+    }
+
+    #[test]
+    fn retention_is_bucketed_by_the_interval_reviewed_at() {
+        let progress = reviewed(&[
+            ("x", "2025-01-01", true),  // new
+            ("x", "2025-01-02", true),  // after 1 day, schedules 6
+            ("x", "2025-01-08", false), // after 6 days
+            ("y", "2025-01-08", false), // new
+        ]);
+        let stats = ReviewStats::compute(&progress, "2025-01-08");
+        assert_eq!(stats.total_reviews, 4);
+        assert_eq!(stats.cards_reviewed, 2);
+        assert_eq!(stats.retention, Some(0.5));
+        let rates: Vec<(&str, Option<f64>)> =
+            stats.retention_by_interval.iter().map(|bucket| (bucket.label, bucket.rate())).collect();
+        assert_eq!(
+            rates,
+            [("New", Some(0.5)), ("1 day", Some(1.0)), ("2–6 days", Some(0.0)), ("7–20 days", None), ("21+ days", None)]
+        );
+    }
+
+    #[test]
+    fn interval_growth_averages_each_review_number() {
+        let progress = reviewed(&[
+            ("x", "2025-01-01", true),
+            ("x", "2025-01-02", true),
+            ("y", "2025-01-01", false),
+        ]);
+        let growth = ReviewStats::compute(&progress, "2025-01-02").interval_growth;
+        assert_eq!(
+            growth,
+            [
+                GrowthPoint { review: 1, mean_interval: 1.0, cards: 2 },
+                GrowthPoint { review: 2, mean_interval: 6.0, cards: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn heatmap_spans_whole_weeks_ending_today() {
+        let progress = reviewed(&[
+            ("x", "2025-01-01", true),
+            ("y", "2025-01-01", true),
+            ("z", "2025-01-01", true),
+            ("x", "2025-01-03", true),
+            ("x", "2023-06-01", true),
+        ]);
+        // 2025-01-03 is a Friday.
+        let stats = ReviewStats::compute(&progress, "2025-01-03");
+        let heatmap = &stats.heatmap;
+        assert_eq!(heatmap.len(), (HEATMAP_WEEKS - 1) * 7 + 6);
+        assert!(crate::game::is_sunday(&heatmap[0].date), "{}", heatmap[0].date);
+        assert_eq!(heatmap.last().unwrap().date, "2025-01-03");
+        let busy: Vec<(&str, usize, u8)> = heatmap
+            .iter()
+            .filter(|day| day.count > 0)
+            .map(|day| (day.date.as_str(), day.count, day.level))
+            .collect();
+        assert_eq!(busy, [("2025-01-01", 3, 4), ("2025-01-03", 1, 2)]);
+        assert_eq!(stats.active_days, 2);
+    }
+
+    #[test]
+    fn json_export_has_every_figure() {
+        let stats = ReviewStats::compute(&reviewed(&[("x", "2025-01-01", true)]), "2025-01-01");
+        let json: serde_json::Value = serde_json::from_str(&stats.to_json()).unwrap();
+        assert_eq!(json["total_reviews"], 1);
+        assert_eq!(json["retention"], 1.0);
+        assert_eq!(json["interval_growth"][0]["mean_interval"], 1.0);
+        assert_eq!(json["heatmap"].as_array().unwrap().last().unwrap()["date"], "2025-01-01");
+
+        let empty = ReviewStats::compute(&UserProgress::new(), "2025-01-01");
+        assert_eq!(empty.retention, None);
+        assert!(empty.interval_growth.is_empty());
+    }
+}
@@ -33,6 +33,31 @@ use wasm_bindgen::prelude::*;
 /// LocalStorage key for user progress data.
 const PROGRESS_KEY: &str = "logos_user_progress";
 
+/// LocalStorage key for the cached remote curriculum pack and its ETag.
+const CURRICULUM_PACK_KEY: &str = "logos_curriculum_pack";
+
+/// LocalStorage key for the anonymous experiment ID and where the outcome log is.
+const EXPERIMENTS_KEY: &str = "logos_experiments";
+
+/// LocalStorage key prefix for the experiment outcome log: chunk `n` is kept
+/// under `logos_experiment_events_<n>`.
+const EXPERIMENT_EVENTS_PREFIX: &str = "logos_experiment_events_";
+
+/// LocalStorage key for the telemetry opt-in flag and aggregated counts.
+const TELEMETRY_KEY: &str = "logos_telemetry";
+
+/// LocalStorage key for the chosen UI locale.
+const LOCALE_KEY: &str = "logos_locale";
+
+/// LocalStorage key for the sound mixer settings.
+const AUDIO_KEY: &str = "logos_audio";
+
+/// LocalStorage key for the Studio's open tabs. File contents live in the
+/// Studio's virtual file system (OPFS, or IndexedDB where OPFS is missing),
+/// and unsaved buffers in IndexedDB (`save_studio_drafts_raw`), not
+/// here; this only remembers which files were open.
+const STUDIO_TABS_KEY: &str = "logos_studio_tabs";
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = localStorage, js_name = getItem)]
@@ -68,3 +93,120 @@ pub fn save_raw(json: &str) {
 pub fn clear() {
     local_storage_remove(PROGRESS_KEY);
 }
+
+/// Loads the cached curriculum pack (see [`crate::content::refresh_curriculum_pack`]).
+///
+/// Returns `None` if no pack has been fetched yet.
+pub fn load_curriculum_pack() -> Option<String> {
+    local_storage_get(CURRICULUM_PACK_KEY)
+}
+
+/// Caches a fetched curriculum pack together with its ETag.
+pub fn save_curriculum_pack(json: &str) {
+    local_storage_set(CURRICULUM_PACK_KEY, json);
+}
+
+/// Loads the raw JSON experiment state (see [`crate::experiments`]).
+pub fn load_experiments_raw() -> Option<String> {
+    local_storage_get(EXPERIMENTS_KEY)
+}
+
+/// Saves the JSON experiment state.
+pub fn save_experiments_raw(json: &str) {
+    local_storage_set(EXPERIMENTS_KEY, json);
+}
+
+/// Loads chunk `n` of the experiment outcome log, one JSON event per line.
+pub fn load_experiment_events_raw(n: u64) -> Option<String> {
+    local_storage_get(&format!("{EXPERIMENT_EVENTS_PREFIX}{n}"))
+}
+
+/// Saves chunk `n` of the experiment outcome log.
+pub fn save_experiment_events_raw(n: u64, lines: &str) {
+    local_storage_set(&format!("{EXPERIMENT_EVENTS_PREFIX}{n}"), lines);
+}
+
+/// Removes chunk `n` of the experiment outcome log.
+pub fn remove_experiment_events_raw(n: u64) {
+    local_storage_remove(&format!("{EXPERIMENT_EVENTS_PREFIX}{n}"));
+}
+
+/// Loads the raw JSON telemetry state (see [`crate::telemetry`]).
+pub fn load_telemetry_raw() -> Option<String> {
+    local_storage_get(TELEMETRY_KEY)
+}
+
+/// Saves the JSON telemetry state.
+pub fn save_telemetry_raw(json: &str) {
+    local_storage_set(TELEMETRY_KEY, json);
+}
+
+/// Loads the chosen UI locale's language tag (see [`crate::ui::i18n`]).
+pub fn load_locale_raw() -> Option<String> {
+    local_storage_get(LOCALE_KEY)
+}
+
+/// Saves the chosen UI locale's language tag.
+pub fn save_locale_raw(code: &str) {
+    local_storage_set(LOCALE_KEY, code);
+}
+
+/// Loads the raw JSON sound settings (see [`crate::audio::AudioSettings`]).
+pub fn load_audio_raw() -> Option<String> {
+    local_storage_get(AUDIO_KEY)
+}
+
+/// Saves the JSON sound settings.
+pub fn save_audio_raw(json: &str) {
+    local_storage_set(AUDIO_KEY, json);
+}
+
+/// Loads the raw JSON Studio tab session (see [`crate::ui::state::TabSession`]).
+pub fn load_studio_tabs_raw() -> Option<String> {
+    local_storage_get(STUDIO_TABS_KEY)
+}
+
+/// Saves the JSON Studio tab session.
+pub fn save_studio_tabs_raw(json: &str) {
+    local_storage_set(STUDIO_TABS_KEY, json);
+}
+
+/// Where the Studio keeps its unsaved buffers, in the IndexedDB-backed VFS.
+/// Dot entries are hidden from the Studio's file tree.
+#[cfg(target_arch = "wasm32")]
+const STUDIO_DRAFTS_PATH: &str = "/.studio/drafts.json";
+
+#[cfg(target_arch = "wasm32")]
+thread_local! {
+    static DRAFTS_DB: std::cell::RefCell<Option<logicaffeine_system::fs::IndexedDbVfs>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// The IndexedDB VFS, opened once per session.
+#[cfg(target_arch = "wasm32")]
+async fn drafts_db() -> Result<logicaffeine_system::fs::IndexedDbVfs, String> {
+    if let Some(db) = DRAFTS_DB.with(|db| db.borrow().clone()) {
+        return Ok(db);
+    }
+    let db = logicaffeine_system::fs::IndexedDbVfs::new().await.map_err(|e| format!("{e:?}"))?;
+    DRAFTS_DB.with(|cached| *cached.borrow_mut() = Some(db.clone()));
+    Ok(db)
+}
+
+/// Loads the raw JSON Studio drafts (see [`crate::ui::state::OpenTabs::drafts`])
+/// from IndexedDB.
+///
+/// Unlike the rest of this module these live in IndexedDB, since open buffers
+/// can outgrow LocalStorage's quota.
+#[cfg(target_arch = "wasm32")]
+pub async fn load_studio_drafts_raw() -> Option<String> {
+    use logicaffeine_system::fs::Vfs;
+    drafts_db().await.ok()?.read_to_string(STUDIO_DRAFTS_PATH).await.ok()
+}
+
+/// Saves the JSON Studio drafts to IndexedDB.
+#[cfg(target_arch = "wasm32")]
+pub async fn save_studio_drafts_raw(json: &str) -> Result<(), String> {
+    use logicaffeine_system::fs::Vfs;
+    drafts_db().await?.write(STUDIO_DRAFTS_PATH, json.as_bytes()).await.map_err(|e| format!("{e:?}"))
+}
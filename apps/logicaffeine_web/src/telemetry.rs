@@ -0,0 +1,246 @@
+//! Opt-in Usage Telemetry
+//!
+//! Aggregates anonymous counts locally and uploads them only after the learner
+//! opts in:
+//! - Only counters are kept: exercise completions by answer type, errors by
+//!   category, and a histogram of compile latency. No IDs, no timestamps, no
+//!   text the learner typed
+//! - Counter keys are fixed identifiers; anything else is counted as `other`
+//! - [`Telemetry::preview`] is byte-for-byte the body [`Telemetry::upload`]
+//!   would send, so the settings page can show exactly what leaves the browser
+//! - Counts reset after each successful upload
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Version of the report layout; the receiving endpoint rejects others.
+pub const TELEMETRY_SCHEMA: u32 = 1;
+
+/// Where opted-in reports are POSTed.
+pub const TELEMETRY_URL: &str = "https://api.logicaffeine.com/telemetry";
+
+/// Upper bounds of the compile-latency buckets, in milliseconds; the last
+/// bucket is open-ended.
+const LATENCY_BUCKETS_MS: [(f64, &str); 5] = [
+    (10.0, "lt_10"),
+    (50.0, "lt_50"),
+    (100.0, "lt_100"),
+    (500.0, "lt_500"),
+    (1000.0, "lt_1000"),
+];
+const LATENCY_OVERFLOW: &str = "ge_1000";
+
+/// What an upload sends
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelemetryReport {
+    pub schema: u32,
+    pub exercises_completed: BTreeMap<String, u64>,
+    pub errors: BTreeMap<String, u64>,
+    pub compile_latency_ms: BTreeMap<String, u64>,
+}
+
+impl Default for TelemetryReport {
+    fn default() -> Self {
+        Self {
+            schema: TELEMETRY_SCHEMA,
+            exercises_completed: BTreeMap::new(),
+            errors: BTreeMap::new(),
+            compile_latency_ms: BTreeMap::new(),
+        }
+    }
+}
+
+impl TelemetryReport {
+    pub fn is_empty(&self) -> bool {
+        self.exercises_completed.is_empty() && self.errors.is_empty() && self.compile_latency_ms.is_empty()
+    }
+}
+
+/// The opt-in flag and the counts gathered since the last upload
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Telemetry {
+    #[serde(default)]
+    pub opted_in: bool,
+    #[serde(default)]
+    pub report: TelemetryReport,
+}
+
+impl Telemetry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        {
+            crate::storage::load_telemetry_raw()
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default()
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Self::new()
+        }
+    }
+
+    pub fn save(&self) {
+        #[cfg(target_arch = "wasm32")]
+        {
+            if let Ok(json) = serde_json::to_string(self) {
+                crate::storage::save_telemetry_raw(&json);
+            }
+        }
+    }
+
+    pub fn set_opt_in(&mut self, opted_in: bool) {
+        self.opted_in = opted_in;
+        self.save();
+    }
+
+    /// Count a completed exercise of `kind` (e.g. `"free_form"`)
+    pub fn record_completion(&mut self, kind: &str) {
+        *self.report.exercises_completed.entry(counter_key(kind)).or_insert(0) += 1;
+        self.save();
+    }
+
+    /// Count an error in `category` (e.g. `"wrong_quantifier"`, `"compile_error"`)
+    pub fn record_error(&mut self, category: &str) {
+        *self.report.errors.entry(counter_key(category)).or_insert(0) += 1;
+        self.save();
+    }
+
+    /// Count one compile call that took `ms` milliseconds
+    pub fn record_compile_latency(&mut self, ms: f64) {
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .find(|(bound, _)| ms < *bound)
+            .map_or(LATENCY_OVERFLOW, |(_, label)| label);
+        *self.report.compile_latency_ms.entry(bucket.to_string()).or_insert(0) += 1;
+        self.save();
+    }
+
+    /// Whether [`upload`](Self::upload) would send anything
+    pub fn ready_to_upload(&self) -> bool {
+        self.opted_in && !self.report.is_empty()
+    }
+
+    /// Exactly the body an upload would send
+    pub fn preview(&self) -> String {
+        serde_json::to_string_pretty(&self.report).unwrap_or_default()
+    }
+
+    /// Send the report to [`TELEMETRY_URL`] and reset the counts. Resolves to
+    /// `Ok(false)` without sending anything unless the learner opted in and
+    /// there is something to send.
+    pub async fn upload(&mut self) -> Result<bool, String> {
+        if !self.ready_to_upload() {
+            return Ok(false);
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let response = gloo_net::http::Request::post(TELEMETRY_URL)
+                .header("Content-Type", "application/json")
+                .body(self.preview())
+                .map_err(|e| e.to_string())?
+                .send()
+                .await
+                .map_err(|e| format!("uploading telemetry: {e}"))?;
+            if !response.ok() {
+                return Err(format!("uploading telemetry: HTTP {}", response.status()));
+            }
+            self.report = TelemetryReport::default();
+            self.save();
+            Ok(true)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Ok(false)
+        }
+    }
+}
+
+/// `key` if it is a plain identifier, `"other"` otherwise, so free text can
+/// never become a counter name.
+fn counter_key(key: &str) -> String {
+    let plain = !key.is_empty()
+        && key.len() <= 64
+        && key.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_');
+    if plain {
+        key.to_string()
+    } else {
+        "other".to_string()
+    }
+}
+
+/// Run `f`, returning its result and how long it took in milliseconds.
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, f64) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let start = js_sys::Date::now();
+        let value = f();
+        (value, js_sys::Date::now() - start)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let start = std::time::Instant::now();
+        let value = f();
+        (value, start.elapsed().as_secs_f64() * 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_aggregate() {
+        let mut telemetry = Telemetry::new();
+        telemetry.record_completion("free_form");
+        telemetry.record_completion("free_form");
+        telemetry.record_error("wrong_quantifier");
+        assert_eq!(telemetry.report.exercises_completed["free_form"], 2);
+        assert_eq!(telemetry.report.errors["wrong_quantifier"], 1);
+    }
+
+    #[test]
+    fn test_free_text_never_becomes_a_key() {
+        let mut telemetry = Telemetry::new();
+        telemetry.record_error("Every man is mortal.");
+        assert_eq!(telemetry.report.errors.keys().collect::<Vec<_>>(), ["other"]);
+    }
+
+    #[test]
+    fn test_latency_buckets() {
+        let mut telemetry = Telemetry::new();
+        for ms in [3.0, 10.0, 250.0, 4000.0] {
+            telemetry.record_compile_latency(ms);
+        }
+        let keys: Vec<&str> = telemetry.report.compile_latency_ms.keys().map(String::as_str).collect();
+        assert_eq!(keys, ["ge_1000", "lt_10", "lt_50", "lt_500"]);
+    }
+
+    #[test]
+    fn test_preview_is_the_report() {
+        let mut telemetry = Telemetry::new();
+        telemetry.record_completion("multiple_choice");
+        let sent: TelemetryReport = serde_json::from_str(&telemetry.preview()).unwrap();
+        assert_eq!(sent, telemetry.report);
+        assert_eq!(sent.schema, TELEMETRY_SCHEMA);
+    }
+
+    #[test]
+    fn test_no_upload_without_opt_in() {
+        let mut telemetry = Telemetry::new();
+        telemetry.record_completion("free_form");
+        assert!(!telemetry.ready_to_upload());
+        telemetry.set_opt_in(true);
+        assert!(telemetry.ready_to_upload());
+    }
+
+    #[test]
+    fn test_nothing_to_upload_when_empty() {
+        let mut telemetry = Telemetry::new();
+        telemetry.set_opt_in(true);
+        assert!(!telemetry.ready_to_upload());
+    }
+}
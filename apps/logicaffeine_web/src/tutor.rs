@@ -0,0 +1,387 @@
+//! Socratic Dialogue Tutor
+//!
+//! Walks a learner through one argument as a guided conversation instead of
+//! a single exercise:
+//! 1. Identify the premises (which sentences support, which one is supported)
+//! 2. Formalize each premise, one at a time, graded by [`check_answer`]
+//! 3. Judge whether the argument is valid, graded by [`check_validity_judgment`]
+//!
+//! A wrong answer is classified into a [`Mistake`] and the tutor branches on
+//! it: each kind gets its own leading question rather than the answer. After
+//! [`Tutor::MAX_ATTEMPTS`] misses on one step the tutor shows the answer and
+//! moves on, so the dialogue always reaches the end.
+
+use crate::grader::{check_answer, check_validity_judgment, normalize_logic, GradeResult};
+use logicaffeine_compile::{analyze_argument, Fallacy};
+use logicaffeine_language::compile_simple;
+
+/// Where the dialogue stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    IdentifyPremises,
+    /// Formalizing the premise at this index (in premise order)
+    Formalize(usize),
+    JudgeValidity,
+    Complete,
+}
+
+/// The kinds of mistake the tutor recognizes and branches on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mistake {
+    /// Picked the wrong sentences as premises
+    MisidentifiedPremises,
+    /// The formula did not parse
+    Unparsable,
+    /// ∀ where ∃ belongs, or the reverse
+    WrongQuantifier,
+    /// `∀x(A(x) ∧ B(x))` for "every A is B"
+    ConjunctionForConditional,
+    /// `∃x(A(x) → B(x))` for "some A is B"
+    ConditionalForConjunction,
+    /// A negation dropped or added
+    Negation,
+    /// A formalization wrong in some other way
+    Other,
+    /// Called an invalid argument valid; carries the fallacy its form matches
+    AcceptedInvalid(Option<Fallacy>),
+    /// Called a valid argument invalid
+    RejectedValid,
+}
+
+impl Mistake {
+    /// Classify a wrong formalization by which single repair makes it right.
+    pub fn classify_formalization(answer: &str, expected: &str) -> Self {
+        let answer = normalize_logic(answer);
+        if check_answer(&answer, expected).feedback.starts_with("Could not parse") {
+            return Mistake::Unparsable;
+        }
+        let repaired = |from: &[(char, char)]| {
+            let swapped: String = answer
+                .chars()
+                .map(|c| from.iter().find(|(a, _)| *a == c).map_or(c, |(_, b)| *b))
+                .collect();
+            check_answer(&swapped, expected).correct
+        };
+        if repaired(&[('∀', '∃'), ('∃', '∀')]) {
+            Mistake::WrongQuantifier
+        } else if answer.starts_with('∀') && repaired(&[('∧', '→')]) {
+            Mistake::ConjunctionForConditional
+        } else if answer.starts_with('∃') && repaired(&[('→', '∧')]) {
+            Mistake::ConditionalForConjunction
+        } else if answer.matches('¬').count() != normalize_logic(expected).matches('¬').count() {
+            Mistake::Negation
+        } else {
+            Mistake::Other
+        }
+    }
+
+    /// Stable identifier, used as a telemetry category
+    pub fn id(&self) -> &'static str {
+        match self {
+            Mistake::MisidentifiedPremises => "misidentified_premises",
+            Mistake::Unparsable => "unparsable",
+            Mistake::WrongQuantifier => "wrong_quantifier",
+            Mistake::ConjunctionForConditional => "conjunction_for_conditional",
+            Mistake::ConditionalForConjunction => "conditional_for_conjunction",
+            Mistake::Negation => "negation",
+            Mistake::Other => "other",
+            Mistake::AcceptedInvalid(_) => "accepted_invalid",
+            Mistake::RejectedValid => "rejected_valid",
+        }
+    }
+
+    /// The tutor's follow-up question for this mistake
+    pub fn follow_up(&self) -> String {
+        match self {
+            Mistake::MisidentifiedPremises => {
+                "Which sentence is the argument trying to convince you of? That one is the conclusion; everything offered in its support is a premise.".to_string()
+            }
+            Mistake::Unparsable => {
+                "I couldn't read that formula. Check that every quantifier has a variable and every parenthesis is closed — what does it look like written out again?".to_string()
+            }
+            Mistake::WrongQuantifier => {
+                "Does the sentence talk about every such thing, or claim that at least one exists?".to_string()
+            }
+            Mistake::ConjunctionForConditional => {
+                "Your formula says everything is both. Does the sentence claim that about everything, or only about the things that meet the first condition?".to_string()
+            }
+            Mistake::ConditionalForConjunction => {
+                "A conditional is true of anything that fails its antecedent. Would your formula be true in a world with none of these things at all?".to_string()
+            }
+            Mistake::Negation => "Read the sentence again: what exactly is being denied?".to_string(),
+            Mistake::Other => "Which words in the sentence became which symbols? Try building it up piece by piece.".to_string(),
+            Mistake::AcceptedInvalid(Some(fallacy)) => format!(
+                "Compare the shape of the argument with {}: {}. Can you imagine the premises true and the conclusion false?",
+                fallacy.name(),
+                fallacy.pattern()
+            ),
+            Mistake::AcceptedInvalid(None) => {
+                "Can you imagine a situation where every premise is true but the conclusion is false?".to_string()
+            }
+            Mistake::RejectedValid => {
+                "Try to build a situation where the premises hold and the conclusion fails. What stops you?".to_string()
+            }
+        }
+    }
+}
+
+/// Who said a line of the dialogue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Speaker {
+    Tutor,
+    Learner,
+}
+
+/// One line of the dialogue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Turn {
+    pub speaker: Speaker,
+    pub text: String,
+}
+
+/// A dialogue over one argument
+#[derive(Debug, Clone)]
+pub struct Tutor {
+    /// The argument's sentences, in the order the learner sees them
+    sentences: Vec<String>,
+    /// Index into `sentences` of the conclusion
+    conclusion: usize,
+    /// Golden formalization of each premise, in premise order
+    golden: Vec<String>,
+    stage: Stage,
+    attempts: u32,
+    transcript: Vec<Turn>,
+    mistakes: Vec<Mistake>,
+}
+
+impl Tutor {
+    /// Wrong answers on one step before the tutor shows the answer
+    pub const MAX_ATTEMPTS: u32 = 3;
+
+    /// Start a dialogue over `sentences`, of which the one at `conclusion` is
+    /// the conclusion. `None` if a premise does not compile on its own.
+    pub fn new(sentences: &[&str], conclusion: usize) -> Option<Self> {
+        if conclusion >= sentences.len() {
+            return None;
+        }
+        let golden = sentences
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != conclusion)
+            .map(|(_, s)| compile_simple(s).ok())
+            .collect::<Option<Vec<String>>>()?;
+        let mut tutor = Self {
+            sentences: sentences.iter().map(|s| s.to_string()).collect(),
+            conclusion,
+            golden,
+            stage: Stage::IdentifyPremises,
+            attempts: 0,
+            transcript: Vec::new(),
+            mistakes: Vec::new(),
+        };
+        tutor.ask();
+        Some(tutor)
+    }
+
+    pub fn stage(&self) -> Stage {
+        self.stage
+    }
+
+    pub fn sentences(&self) -> &[String] {
+        &self.sentences
+    }
+
+    pub fn transcript(&self) -> &[Turn] {
+        &self.transcript
+    }
+
+    /// Every mistake made so far, in order
+    pub fn mistakes(&self) -> &[Mistake] {
+        &self.mistakes
+    }
+
+    /// The premises, in the order they appear
+    pub fn premises(&self) -> Vec<&str> {
+        self.sentences
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != self.conclusion)
+            .map(|(_, s)| s.as_str())
+            .collect()
+    }
+
+    /// Answer the identification step with the indices (into
+    /// [`sentences`](Self::sentences)) the learner takes to be premises.
+    pub fn identify_premises(&mut self, chosen: &[usize]) -> GradeResult {
+        if self.stage != Stage::IdentifyPremises {
+            return GradeResult::incorrect("That step is already done.".to_string());
+        }
+        let picked: Vec<&str> = chosen.iter().filter_map(|&i| self.sentences.get(i)).map(|s| s.as_str()).collect();
+        self.say(Speaker::Learner, format!("The premises are: {}", picked.join(" ")));
+
+        let mut chosen = chosen.to_vec();
+        chosen.sort_unstable();
+        chosen.dedup();
+        let expected: Vec<usize> = (0..self.sentences.len()).filter(|&i| i != self.conclusion).collect();
+        if chosen == expected {
+            self.advance(Stage::Formalize(0))
+        } else {
+            let answer = format!("The conclusion is \"{}\"; the rest are premises.", self.sentences[self.conclusion]);
+            self.miss(Mistake::MisidentifiedPremises, Stage::Formalize(0), answer)
+        }
+    }
+
+    /// Answer the current formalization step with a formula.
+    pub fn formalize(&mut self, answer: &str) -> GradeResult {
+        let Stage::Formalize(premise) = self.stage else {
+            return GradeResult::incorrect("There is no premise to formalize right now.".to_string());
+        };
+        self.say(Speaker::Learner, answer.to_string());
+
+        let expected = self.golden[premise].clone();
+        let next = if premise + 1 < self.golden.len() {
+            Stage::Formalize(premise + 1)
+        } else {
+            Stage::JudgeValidity
+        };
+        let result = check_answer(answer, &expected);
+        if result.correct {
+            self.advance(next)
+        } else {
+            let mistake = Mistake::classify_formalization(answer, &expected);
+            let mut graded = self.miss(mistake, next, format!("One formalization is {expected}."));
+            graded.score = graded.score.max(result.score);
+            graded.partial = result.partial;
+            graded
+        }
+    }
+
+    /// Answer the final step: is the argument valid?
+    pub fn judge_validity(&mut self, valid: bool) -> GradeResult {
+        if self.stage != Stage::JudgeValidity {
+            return GradeResult::incorrect("Formalize the premises first.".to_string());
+        }
+        self.say(Speaker::Learner, if valid { "It's valid." } else { "It's invalid." }.to_string());
+
+        let conclusion = self.sentences[self.conclusion].clone();
+        let premises: Vec<String> = self.premises().into_iter().map(String::from).collect();
+        let premises: Vec<&str> = premises.iter().map(String::as_str).collect();
+        let result = check_validity_judgment(&premises, &conclusion, valid);
+        if result.correct {
+            return self.advance(Stage::Complete);
+        }
+        let mistake = if valid {
+            let report = analyze_argument(&premises, &conclusion);
+            Mistake::AcceptedInvalid(report.fallacies.first().map(|m| m.fallacy))
+        } else {
+            Mistake::RejectedValid
+        };
+        self.miss(mistake, Stage::Complete, result.feedback)
+    }
+
+    /// Move to `next` and ask its question.
+    fn advance(&mut self, next: Stage) -> GradeResult {
+        self.say(Speaker::Tutor, "Right.".to_string());
+        self.stage = next;
+        self.attempts = 0;
+        self.ask();
+        GradeResult::correct()
+    }
+
+    /// Record a mistake and branch on it; once the step has been missed
+    /// [`MAX_ATTEMPTS`](Self::MAX_ATTEMPTS) times, show `answer` and move to
+    /// `next` instead.
+    fn miss(&mut self, mistake: Mistake, next: Stage, answer: String) -> GradeResult {
+        self.mistakes.push(mistake);
+        self.attempts += 1;
+        if self.attempts >= Self::MAX_ATTEMPTS || self.stage == Stage::JudgeValidity {
+            self.say(Speaker::Tutor, answer.clone());
+            self.stage = next;
+            self.attempts = 0;
+            self.ask();
+            return GradeResult::incorrect(answer);
+        }
+        let question = mistake.follow_up();
+        self.say(Speaker::Tutor, question.clone());
+        GradeResult::incorrect(question)
+    }
+
+    /// Pose the question for the current stage.
+    fn ask(&mut self) {
+        let question = match self.stage {
+            Stage::IdentifyPremises => format!(
+                "Here is an argument: {} Which of these sentences are its premises?",
+                self.sentences.join(" ")
+            ),
+            Stage::Formalize(i) => format!("How would you write \"{}\" in logic?", self.premises()[i]),
+            Stage::JudgeValidity => format!(
+                "Now suppose every premise is true. Must \"{}\" be true as well — is the argument valid?",
+                self.sentences[self.conclusion]
+            ),
+            Stage::Complete => "That's the whole argument. Well reasoned.".to_string(),
+        };
+        self.say(Speaker::Tutor, question);
+    }
+
+    fn say(&mut self, speaker: Speaker, text: String) {
+        self.transcript.push(Turn { speaker, text });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn socrates() -> Tutor {
+        Tutor::new(&["Every man is mortal.", "Socrates is a man.", "Socrates is mortal."], 2).unwrap()
+    }
+
+    #[test]
+    fn test_dialogue_opens_with_a_question() {
+        let tutor = socrates();
+        assert_eq!(tutor.stage(), Stage::IdentifyPremises);
+        assert_eq!(tutor.transcript()[0].speaker, Speaker::Tutor);
+    }
+
+    #[test]
+    fn test_correct_answers_walk_to_the_end() {
+        let mut tutor = socrates();
+        assert!(tutor.identify_premises(&[0, 1]).correct);
+        assert_eq!(tutor.stage(), Stage::Formalize(0));
+        assert!(tutor.formalize("∀x(Man(x) → Mortal(x))").correct);
+        assert!(tutor.formalize("Man(Socrates)").correct);
+        assert!(tutor.judge_validity(true).correct);
+        assert_eq!(tutor.stage(), Stage::Complete);
+        assert!(tutor.mistakes().is_empty());
+    }
+
+    #[test]
+    fn test_conjunction_under_universal_branches() {
+        let mut tutor = socrates();
+        tutor.identify_premises(&[0, 1]);
+        let result = tutor.formalize("∀x(Man(x) ∧ Mortal(x))");
+        assert!(!result.correct);
+        assert_eq!(tutor.mistakes(), &[Mistake::ConjunctionForConditional]);
+        assert_eq!(tutor.stage(), Stage::Formalize(0), "a miss stays on the step");
+    }
+
+    #[test]
+    fn test_repeated_misses_reveal_the_answer() {
+        let mut tutor = socrates();
+        for _ in 0..Tutor::MAX_ATTEMPTS {
+            tutor.identify_premises(&[2]);
+        }
+        assert_eq!(tutor.stage(), Stage::Formalize(0));
+    }
+
+    #[test]
+    fn test_accepting_a_fallacy_names_it() {
+        let mut tutor = Tutor::new(&["Every man is mortal.", "Socrates is mortal.", "Socrates is a man."], 2).unwrap();
+        tutor.identify_premises(&[0, 1]);
+        tutor.formalize("∀x(Man(x) → Mortal(x))");
+        tutor.formalize("Mortal(Socrates)");
+        assert!(!tutor.judge_validity(true).correct);
+        assert_eq!(tutor.mistakes(), &[Mistake::AcceptedInvalid(Some(Fallacy::AffirmingTheConsequent))]);
+        assert_eq!(tutor.stage(), Stage::Complete);
+    }
+}
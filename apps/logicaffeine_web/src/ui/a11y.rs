@@ -0,0 +1,527 @@
+//! Accessibility layer.
+//!
+//! Shared pieces that make the app usable with a screen reader or a keyboard alone:
+//! - [`speak_formula`] - A formula read out in words, for `aria-label`
+//! - [`formula_mathml`] - The same formula as presentation MathML
+//! - [`Formula`] - Renders a formula through one of the two paths, with the
+//!   highlighted glyphs hidden from assistive tech
+//! - [`A11yState`] - Contrast theme and formula rendering preference, persisted
+//!   to localStorage like [`ThemeState`](crate::ui::theme_state::ThemeState)
+//! - [`learn_key`] - The keyboard map of the Learn flow
+//!
+//! # Usage
+//!
+//! ```no_run
+//! # use dioxus::prelude::*;
+//! use logicaffeine_web::ui::a11y::{A11yState, Formula};
+//!
+//! # fn Example() -> Element {
+//! // Provide at app root
+//! use_context_provider(A11yState::new);
+//!
+//! rsx! {
+//!     Formula { text: "∀x(Man(x) → Mortal(x))".to_string() }
+//! }
+//! # }
+//! ```
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use gloo_storage::{LocalStorage, Storage};
+use crate::ui::components::logic_output::highlight_logic;
+
+const A11Y_STORAGE_KEY: &str = "logicaffeine-a11y";
+
+/// Styles shared by every page: the screen-reader-only utility class and
+/// focus rings that survive the themes' custom button styles.
+pub const A11Y_STYLE: &str = r#"
+.sr-only {
+    position: absolute;
+    width: 1px;
+    height: 1px;
+    padding: 0;
+    margin: -1px;
+    overflow: hidden;
+    clip: rect(0, 0, 0, 0);
+    white-space: nowrap;
+    border: 0;
+}
+
+a:focus-visible,
+button:focus-visible,
+[role="button"]:focus-visible,
+[tabindex]:focus-visible {
+    outline: 2px solid var(--accent-primary);
+    outline-offset: 2px;
+}
+
+.formula {
+    font-family: var(--font-mono);
+}
+"#;
+
+/// Overrides applied on top of the theme in high-contrast mode.
+const HIGH_CONTRAST_STYLE: &str = r#"
+:root {
+    --text-primary: #ffffff;
+    --text-secondary: #ffffff;
+    --text-tertiary: #f0f0f0;
+    --text-muted: #f0f0f0;
+    --text-placeholder: #d8d8d8;
+    --accent-primary: #ffd60a;
+    --accent-secondary: #7dd3fc;
+    --accent-primary-rgb: 255, 214, 10;
+    --accent-secondary-rgb: 125, 211, 252;
+    --accent-border: #ffd60a;
+    --color-success: #6ee7b7;
+    --color-error: #fca5a5;
+    --bg-gradient-start: #000000;
+    --bg-gradient-mid: #000000;
+    --bg-gradient-end: #000000;
+}
+
+button,
+input,
+textarea,
+.card,
+.problem-card {
+    border-color: #ffffff !important;
+}
+
+a:focus-visible,
+button:focus-visible,
+[role="button"]:focus-visible,
+[tabindex]:focus-visible {
+    outline: 3px solid #ffd60a;
+    outline-offset: 3px;
+}
+
+.logic-quantifier,
+.logic-connective,
+.logic-variable,
+.logic-predicate,
+.logic-constant,
+.logic-paren {
+    color: #ffffff !important;
+    font-weight: 600;
+}
+"#;
+
+/// Contrast theme, layered over the color [`Theme`](crate::ui::theme_state::Theme).
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub enum Contrast {
+    #[default]
+    Standard,
+    /// White text on black, a single bright accent, bold formula glyphs
+    High,
+}
+
+impl Contrast {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Contrast::Standard => "Standard",
+            Contrast::High => "High contrast",
+        }
+    }
+
+    /// Returns the data-contrast attribute value.
+    pub fn data_attr(&self) -> &'static str {
+        match self {
+            Contrast::Standard => "standard",
+            Contrast::High => "high",
+        }
+    }
+
+    /// Returns the CSS layered over the theme, empty for the standard theme.
+    pub fn css(&self) -> &'static str {
+        match self {
+            Contrast::Standard => "",
+            Contrast::High => HIGH_CONTRAST_STYLE,
+        }
+    }
+}
+
+/// How formulas are exposed to assistive tech.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub enum FormulaSpeech {
+    /// A spoken-English `aria-label` ("for all x, Man of x implies Mortal of x")
+    #[default]
+    Label,
+    /// MathML, for screen readers that can walk a formula term by term
+    MathMl,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+struct A11yPrefs {
+    #[serde(default)]
+    contrast: Contrast,
+    #[serde(default)]
+    formula_speech: FormulaSpeech,
+}
+
+/// Global accessibility preferences with localStorage persistence.
+#[derive(Clone, Copy)]
+pub struct A11yState {
+    prefs: Signal<A11yPrefs>,
+}
+
+impl A11yState {
+    /// Creates a new A11yState, loading from localStorage if available.
+    pub fn new() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        let initial = LocalStorage::get::<A11yPrefs>(A11Y_STORAGE_KEY).ok().unwrap_or_default();
+        #[cfg(not(target_arch = "wasm32"))]
+        let initial = A11yPrefs::default();
+
+        Self {
+            prefs: Signal::new(initial),
+        }
+    }
+
+    pub fn contrast(&self) -> Contrast {
+        self.prefs.read().contrast
+    }
+
+    pub fn formula_speech(&self) -> FormulaSpeech {
+        self.prefs.read().formula_speech
+    }
+
+    /// Sets the contrast theme and persists to localStorage.
+    pub fn set_contrast(&mut self, contrast: Contrast) {
+        self.prefs.write().contrast = contrast;
+        self.persist();
+    }
+
+    /// Sets the formula rendering path and persists to localStorage.
+    pub fn set_formula_speech(&mut self, speech: FormulaSpeech) {
+        self.prefs.write().formula_speech = speech;
+        self.persist();
+    }
+
+    fn persist(&self) {
+        #[cfg(target_arch = "wasm32")]
+        let _ = LocalStorage::set(A11Y_STORAGE_KEY, *self.prefs.read());
+    }
+}
+
+impl Default for A11yState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A lexical piece of a formula.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// ∀ or ∃
+    Quantifier(char),
+    /// A logical operator or relation symbol
+    Operator(char),
+    Open,
+    Close,
+    Comma,
+    /// A predicate, constant or variable name
+    Name(String),
+    Number(String),
+    Other(char),
+}
+
+fn tokenize(formula: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = formula.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '∀' | '∃' => Token::Quantifier(c),
+            '¬' | '∧' | '∨' | '→' | '↔' | '⊥' | '⊤' | '=' | '≠' | '□' | '◇' => Token::Operator(c),
+            '(' | '[' => Token::Open,
+            ')' | ']' => Token::Close,
+            ',' => Token::Comma,
+            c if c.is_alphabetic() => {
+                let mut name = String::from(c);
+                while let Some(&next) = chars.peek() {
+                    if next.is_alphanumeric() || next == '_' {
+                        name.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                Token::Name(name)
+            }
+            c if c.is_ascii_digit() => {
+                let mut number = String::from(c);
+                while let Some(&next) = chars.peek() {
+                    if next.is_ascii_digit() || next == '.' {
+                        number.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                Token::Number(number)
+            }
+            c => Token::Other(c),
+        };
+        tokens.push(token);
+    }
+    tokens
+}
+
+fn operator_words(op: char) -> &'static str {
+    match op {
+        '¬' => "not",
+        '∧' => "and",
+        '∨' => "or",
+        '→' => "implies",
+        '↔' => "if and only if",
+        '⊥' => "contradiction",
+        '⊤' => "true",
+        '=' => "equals",
+        '≠' => "does not equal",
+        '□' => "necessarily",
+        '◇' => "possibly",
+        _ => "",
+    }
+}
+
+/// Reads a formula out in words.
+///
+/// Predicate applications become "Man of x", quantifiers "for all x," and
+/// "there exists x such that". The parentheses scoping a quantifier are implied
+/// by the comma and left unspoken; every other grouping is read as
+/// "open paren" / "close paren" so the structure stays unambiguous.
+pub fn speak_formula(formula: &str) -> String {
+    let tokens = tokenize(formula);
+    let mut words: Vec<String> = Vec::new();
+    // For each open parenthesis: whether its closing one should be spoken
+    let mut spoken_parens: Vec<bool> = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        match &tokens[i] {
+            Token::Quantifier(q) => {
+                let lead = if *q == '∀' { "for all" } else { "there exists" };
+                if let Some(Token::Name(var)) = tokens.get(i + 1) {
+                    let tail = if *q == '∀' { "," } else { " such that" };
+                    words.push(format!("{lead} {var}{tail}"));
+                    i += 1;
+                    if tokens.get(i + 1) == Some(&Token::Open) {
+                        spoken_parens.push(false);
+                        i += 1;
+                    }
+                } else {
+                    words.push(lead.to_string());
+                }
+            }
+            Token::Operator(op) => words.push(operator_words(*op).to_string()),
+            Token::Name(name) => {
+                if tokens.get(i + 1) == Some(&Token::Open) {
+                    words.push(format!("{name} of"));
+                    spoken_parens.push(false);
+                    i += 1;
+                } else {
+                    words.push(name.clone());
+                }
+            }
+            Token::Number(number) => words.push(number.clone()),
+            Token::Open => {
+                words.push("open paren".to_string());
+                spoken_parens.push(true);
+            }
+            Token::Close => {
+                if spoken_parens.pop().unwrap_or(true) {
+                    words.push("close paren".to_string());
+                }
+            }
+            Token::Comma => {
+                if let Some(last) = words.last_mut() {
+                    last.push(',');
+                }
+            }
+            Token::Other(c) => words.push(c.to_string()),
+        }
+        i += 1;
+    }
+    words.join(" ")
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Renders a formula as presentation MathML, with the spoken form as `alttext`.
+pub fn formula_mathml(formula: &str) -> String {
+    let mut body = String::new();
+    for token in tokenize(formula) {
+        let element = match token {
+            Token::Quantifier(c) | Token::Operator(c) | Token::Other(c) => format!("<mo>{}</mo>", escape_xml(&c.to_string())),
+            Token::Open => "<mo>(</mo>".to_string(),
+            Token::Close => "<mo>)</mo>".to_string(),
+            Token::Comma => "<mo>,</mo>".to_string(),
+            Token::Name(name) if name.chars().count() > 1 => format!("<mi mathvariant=\"normal\">{}</mi>", escape_xml(&name)),
+            Token::Name(name) => format!("<mi>{}</mi>", escape_xml(&name)),
+            Token::Number(number) => format!("<mn>{number}</mn>"),
+        };
+        body.push_str(&element);
+    }
+    format!(
+        r#"<math xmlns="http://www.w3.org/1998/Math/MathML" alttext="{}"><mrow>{body}</mrow></math>"#,
+        escape_xml(&speak_formula(formula))
+    )
+}
+
+/// A logical formula, highlighted for sighted readers and exposed to assistive
+/// tech through the learner's chosen [`FormulaSpeech`] path.
+#[component]
+pub fn Formula(text: String) -> Element {
+    let speech = try_use_context::<A11yState>()
+        .map(|state| state.formula_speech())
+        .unwrap_or_default();
+    let highlighted = highlight_logic(&text);
+
+    match speech {
+        FormulaSpeech::Label => {
+            let label = speak_formula(&text);
+            rsx! {
+                span { class: "formula", role: "math", aria_label: "{label}",
+                    span { aria_hidden: "true", dangerous_inner_html: "{highlighted}" }
+                }
+            }
+        }
+        FormulaSpeech::MathMl => {
+            let mathml = formula_mathml(&text);
+            rsx! {
+                span { class: "formula",
+                    span { aria_hidden: "true", dangerous_inner_html: "{highlighted}" }
+                    span { class: "sr-only", dangerous_inner_html: "{mathml}" }
+                }
+            }
+        }
+    }
+}
+
+/// An action of the Learn flow reachable from the keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LearnKey {
+    /// Check the answer, or go on to the next exercise once it is checked
+    Primary,
+    /// Pick the multiple-choice option at this index
+    Choose(usize),
+    /// Move the multiple-choice selection by this many options
+    Move(isize),
+    /// Reveal the hint
+    Hint,
+}
+
+/// Maps a key press in an exercise to a Learn action.
+///
+/// `key` is the DOM key name (`"Enter"`, `"ArrowDown"`, `"2"`) and `command`
+/// whether Ctrl or Cmd is held. While the learner is typing a free-form answer
+/// (`typing`), only Enter is taken, so digits and letters reach the input.
+/// Elsewhere a plain Enter is left to the focused button, and Ctrl+Enter is
+/// the primary action.
+pub fn learn_key(key: &str, command: bool, typing: bool) -> Option<LearnKey> {
+    match key {
+        "Enter" if typing || command => Some(LearnKey::Primary),
+        _ if typing || command => None,
+        "ArrowDown" | "ArrowRight" => Some(LearnKey::Move(1)),
+        "ArrowUp" | "ArrowLeft" => Some(LearnKey::Move(-1)),
+        "h" | "H" | "?" => Some(LearnKey::Hint),
+        _ => match key.parse::<usize>() {
+            Ok(n @ 1..=9) => Some(LearnKey::Choose(n - 1)),
+            _ => None,
+        },
+    }
+}
+
+/// Clicks the first element matching `selector`, so a keyboard shortcut runs
+/// exactly what the button it stands for runs.
+pub fn click(selector: &str) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use wasm_bindgen::JsCast;
+        if let Some(element) = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.query_selector(selector).ok().flatten())
+            .and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok())
+        {
+            element.click();
+        }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = selector;
+}
+
+/// Moves keyboard focus to the first element matching `selector`.
+pub fn focus(selector: &str) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use wasm_bindgen::JsCast;
+        if let Some(element) = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.query_selector(selector).ok().flatten())
+            .and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok())
+        {
+            let _ = element.focus();
+        }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = selector;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_speak_universal() {
+        assert_eq!(
+            speak_formula("∀x(Man(x) → Mortal(x))"),
+            "for all x, Man of x implies Mortal of x"
+        );
+    }
+
+    #[test]
+    fn test_speak_existential_with_grouping() {
+        assert_eq!(
+            speak_formula("∃y(Dog(y) ∧ ¬(Barks(y) ∨ Bites(y)))"),
+            "there exists y such that Dog of y and not open paren Barks of y or Bites of y close paren"
+        );
+    }
+
+    #[test]
+    fn test_speak_relation_arguments() {
+        assert_eq!(speak_formula("Loves(John, Mary)"), "Loves of John, Mary");
+    }
+
+    #[test]
+    fn test_mathml_structure() {
+        let mathml = formula_mathml("∀x(P(x) ∧ x = 2)");
+        assert!(mathml.starts_with("<math "));
+        assert!(mathml.contains(r#"alttext="for all x, P of x and x equals 2""#));
+        assert!(mathml.contains("<mo>∀</mo><mi>x</mi><mo>(</mo>"));
+        assert!(mathml.contains("<mn>2</mn>"));
+        assert!(mathml.ends_with("</mrow></math>"));
+    }
+
+    #[test]
+    fn test_mathml_escapes_markup() {
+        assert!(formula_mathml("a < b").contains("<mo>&lt;</mo>"));
+    }
+
+    #[test]
+    fn test_learn_keys() {
+        assert_eq!(learn_key("Enter", false, true), Some(LearnKey::Primary));
+        assert_eq!(learn_key("2", false, true), None);
+        assert_eq!(learn_key("2", false, false), Some(LearnKey::Choose(1)));
+        assert_eq!(learn_key("0", false, false), None);
+        assert_eq!(learn_key("ArrowUp", false, false), Some(LearnKey::Move(-1)));
+        assert_eq!(learn_key("h", false, false), Some(LearnKey::Hint));
+        assert_eq!(learn_key("Enter", false, false), None);
+        assert_eq!(learn_key("Enter", true, false), Some(LearnKey::Primary));
+    }
+}
@@ -8,6 +8,8 @@
 //! The [`App`] component provides these global contexts:
 //! - [`LicenseState`] - License key validation and plan tiers
 //! - [`RegistryAuthState`] - GitHub authentication for package registry
+//! - [`A11yState`] - Contrast theme and formula rendering for assistive tech
+//! - [`I18nState`] - UI locale and text direction
 //!
 //! # Global Styles
 //!
@@ -23,6 +25,9 @@ use crate::ui::router::Route;
 use crate::ui::state::{LicenseState, RegistryAuthState};
 use crate::ui::theme;
 use crate::ui::theme_state::{ThemeState, theme_css};
+use crate::ui::a11y::{A11yState, A11Y_STYLE};
+use crate::ui::i18n::I18nState;
+use crate::ui::custom_theme::syntax_css;
 
 /// Global CSS including design tokens, reset styles, and common component styles.
 const GLOBAL_STYLE: &str = r#"
@@ -278,6 +283,26 @@ a {
     margin: var(--spacing-md) 0;
 }
 
+/* Practice-mode solution walkthrough: one derivation step per line */
+.walkthrough-steps {
+    list-style: none;
+    margin: var(--spacing-sm) 0 0;
+    padding: 0;
+    font-size: var(--font-body-sm);
+}
+
+.walkthrough-step {
+    padding: 2px 0;
+    color: var(--text-secondary);
+}
+
+.walkthrough-rule {
+    display: inline-block;
+    min-width: 120px;
+    font-weight: 600;
+    color: var(--text-primary);
+}
+
 /* Socratic hint box */
 .socratic-hint-box {
     margin-top: var(--spacing-lg);
@@ -393,10 +418,14 @@ a {
 /// - [`LicenseState`] - Manages subscription validation
 /// - [`RegistryAuthState`] - Manages GitHub OAuth for package registry
 /// - [`ThemeState`] - Manages theme selection with localStorage persistence
+/// - [`A11yState`] - Manages contrast and formula speech preferences
+/// - [`I18nState`] - Manages the UI locale
 pub fn App() -> Element {
     let license_state = use_context_provider(LicenseState::new);
     let _registry_auth = use_context_provider(RegistryAuthState::new);
     let theme_state = use_context_provider(ThemeState::new);
+    let a11y_state = use_context_provider(A11yState::new);
+    let i18n_state = use_context_provider(I18nState::new);
 
     use_effect(move || {
         let mut license_state = license_state.clone();
@@ -410,12 +439,23 @@ pub fn App() -> Element {
     // Generate dynamic theme CSS
     let current_theme = theme_state.current();
     let dynamic_theme_css = theme_css(current_theme);
+    let custom_theme_css = theme_state.custom().map(|t| t.css()).unwrap_or_default();
+    let syntax_colors_css = syntax_css();
+    let contrast = a11y_state.contrast();
+    let locale = i18n_state.locale();
 
     rsx! {
         style { "{GLOBAL_STYLE}" }
         style { "{dynamic_theme_css}" }
+        style { "{syntax_colors_css}" }
+        style { "{custom_theme_css}" }
+        style { "{A11Y_STYLE}" }
+        style { "{contrast.css()}" }
         div {
             "data-theme": "{current_theme.data_attr()}",
+            "data-contrast": "{contrast.data_attr()}",
+            lang: "{locale.code()}",
+            dir: "{locale.dir()}",
             id: "app-root",
             // No explicit SuspenseBoundary: lazy route chunks suspend against
             // the implicit root boundary (the wasm-split harness shape). The
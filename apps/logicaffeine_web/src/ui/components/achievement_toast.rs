@@ -12,6 +12,7 @@ use dioxus::prelude::*;
 use crate::achievements::Achievement;
 use crate::audio::{SoundEffect, play_sound};
 use crate::ui::components::icon::{Icon, IconVariant, IconSize};
+use crate::ui::i18n::I18nState;
 
 const ACHIEVEMENT_STYLE: &str = r#"
 .achievement-overlay {
@@ -139,6 +140,7 @@ const ACHIEVEMENT_STYLE: &str = r#"
 
 #[component]
 pub fn AchievementToast(achievement: &'static Achievement, on_dismiss: EventHandler<()>) -> Element {
+    let i18n = use_context::<I18nState>();
     use_effect(move || {
         play_sound(SoundEffect::Achievement);
     });
@@ -165,20 +167,20 @@ pub fn AchievementToast(achievement: &'static Achievement, on_dismiss: EventHand
                 div { class: "achievement-icon",
                     Icon { variant: IconVariant::Trophy, size: IconSize::XXLarge, color: "#fbbf24" }
                 }
-                div { class: "achievement-label", "Achievement Unlocked" }
+                div { class: "achievement-label", {i18n.t("achievement-unlocked")} }
                 div { class: "achievement-title", "{achievement.title}" }
                 div { class: "achievement-description", "{achievement.description}" }
                 div { class: "achievement-reward", "+{achievement.xp_reward} XP" }
                 if let Some(title) = achievement.unlocks_title {
                     div { class: "achievement-title-unlock",
-                        "Title Unlocked: {title}"
+                        {i18n.t_with("achievement-title-unlocked", &[("title", title)])}
                     }
                 }
                 if achievement.grants_freeze {
                     div { class: "achievement-title-unlock",
                         style: "display: flex; align-items: center; gap: 8px; justify-content: center;",
                         Icon { variant: IconVariant::Shield, size: IconSize::Medium, color: "#38bdf8" }
-                        "+1 Streak Freeze!"
+                        {i18n.t("achievement-streak-freeze")}
                     }
                 }
                 button {
@@ -187,7 +189,7 @@ pub fn AchievementToast(achievement: &'static Achievement, on_dismiss: EventHand
                         e.stop_propagation();
                         on_dismiss.call(());
                     },
-                    "Continue"
+                    {i18n.t("achievement-continue")}
                 }
             }
         }
@@ -0,0 +1,120 @@
+//! Structural diff of two syntax trees.
+//!
+//! Renders an [`AstDiff`] as a single tree: inserted nodes in green, removed
+//! nodes struck through in red, renamed nodes in amber with their old label.
+//! Used to show a learner how their formula differs from the expected one,
+//! or how two readings of an ambiguous sentence differ.
+//!
+//! # Props
+//!
+//! - `diff` - The merged tree from [`ast_diff`](crate::ast_diff::ast_diff)
+//! - `before` / `after` - What the two trees were, for the legend
+
+use dioxus::prelude::*;
+use crate::ast_diff::{AstDiff, DiffKind};
+
+const DIFF_STYLE: &str = r#"
+.ast-diff {
+    margin-top: 12px;
+    padding: 12px 14px;
+    border: 1px solid rgba(255, 255, 255, 0.08);
+    border-radius: 10px;
+    background: rgba(0, 0, 0, 0.2);
+    font-size: 13px;
+}
+
+.ast-diff-legend {
+    display: flex;
+    flex-wrap: wrap;
+    gap: 12px;
+    margin-bottom: 8px;
+    color: #9ca3af;
+    font-size: 12px;
+}
+
+.ast-diff-node {
+    margin-left: 16px;
+}
+
+.ast-diff-root {
+    margin-left: 0;
+}
+
+.ast-diff-label {
+    display: inline-flex;
+    align-items: baseline;
+    gap: 6px;
+    padding: 2px 6px;
+    border-radius: 4px;
+    font-family: 'SF Mono', 'Fira Code', 'Consolas', monospace;
+}
+
+.ast-diff-label .from {
+    color: #9ca3af;
+    text-decoration: line-through;
+}
+
+.ast-diff-label.inserted {
+    background: rgba(74, 222, 128, 0.15);
+    color: #4ade80;
+}
+
+.ast-diff-label.removed {
+    background: rgba(248, 113, 113, 0.12);
+    color: #f87171;
+    text-decoration: line-through;
+}
+
+.ast-diff-label.renamed {
+    background: rgba(251, 191, 36, 0.15);
+    color: #fbbf24;
+}
+
+.ast-diff-same-note {
+    color: #4ade80;
+}
+"#;
+
+#[component]
+pub fn AstDiffView(diff: AstDiff, before: &'static str, after: &'static str) -> Element {
+    rsx! {
+        style { "{DIFF_STYLE}" }
+        div { class: "ast-diff", role: "figure", aria_label: "Structure of {before} compared with {after}",
+            if diff.is_identical() {
+                p { class: "ast-diff-same-note", "{before} and {after} have the same structure." }
+            } else {
+                div { class: "ast-diff-legend",
+                    span { class: "ast-diff-label inserted", "only in {after}" }
+                    span { class: "ast-diff-label removed", "only in {before}" }
+                    span { class: "ast-diff-label renamed", "changed" }
+                }
+            }
+            DiffNode { diff: diff.clone(), is_root: true }
+        }
+    }
+}
+
+#[component]
+fn DiffNode(diff: AstDiff, is_root: bool) -> Element {
+    let node_class = if is_root { "ast-diff-node ast-diff-root" } else { "ast-diff-node" };
+    let label_class = format!("ast-diff-label {}", diff.kind.class());
+    let from = match &diff.kind {
+        DiffKind::Renamed { from } => Some(from.clone()),
+        _ => None,
+    };
+
+    rsx! {
+        div { class: "{node_class}",
+            span { class: "{label_class}", title: "{diff.node_type}",
+                if let Some(from) = from {
+                    span { class: "from", "{from}" }
+                    "\u{2192}"
+                }
+                "{diff.label}"
+            }
+            for child in diff.children.iter() {
+                DiffNode { diff: child.clone(), is_root: false }
+            }
+        }
+    }
+}
@@ -110,14 +110,7 @@ const TREE_STYLE: &str = r#"
     white-space: nowrap;
 }
 
-.ast-node-type.quantifier { background: rgba(198, 120, 221, 0.2); color: #c678dd; }
-.ast-node-type.predicate { background: rgba(152, 195, 121, 0.2); color: #98c379; }
-.ast-node-type.binary_op { background: rgba(198, 120, 221, 0.2); color: #c678dd; }
-.ast-node-type.unary_op { background: rgba(224, 108, 117, 0.2); color: #e06c75; }
-.ast-node-type.constant { background: rgba(229, 192, 123, 0.2); color: #e5c07b; }
-.ast-node-type.variable { background: rgba(97, 175, 239, 0.2); color: #61afef; }
-.ast-node-type.modal { background: rgba(86, 182, 194, 0.2); color: #56b6c2; }
-.ast-node-type.lambda { background: rgba(224, 108, 117, 0.2); color: #e06c75; }
+/* Per-node-type badge colors come from the theme: see ui::custom_theme::syntax_css */
 
 .ast-children {
     display: none;
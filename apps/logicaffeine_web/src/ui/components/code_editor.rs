@@ -21,6 +21,8 @@
 //! - `language` - Syntax highlighting mode
 //! - `placeholder` - Optional placeholder text
 //! - `readonly` - Whether editing is disabled
+//! - `carets` - Other people's carets to draw, when collaborating
+//! - `on_caret` - Callback with the local caret's position (in chars) as it moves
 
 use dioxus::prelude::*;
 
@@ -53,6 +55,21 @@ struct Token {
     kind: TokenKind,
 }
 
+/// A collaborator's caret, drawn into the highlight layer.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RemoteCaret {
+    /// Position in the buffer, in chars.
+    pub offset: usize,
+    pub name: String,
+    pub color: String,
+}
+
+/// What the highlight layer renders: text, or a caret between characters.
+enum Piece {
+    Text(Token),
+    Caret(usize),
+}
+
 const CODE_EDITOR_STYLE: &str = r#"
 .code-editor {
     display: flex;
@@ -123,16 +140,38 @@ const CODE_EDITOR_STYLE: &str = r#"
     display: none;
 }
 
-/* Syntax highlighting colors - no font-weight/style changes to keep heights identical */
-.tok-keyword { color: #c678dd; }
-.tok-type { color: #e5c07b; }
-.tok-string { color: #98c379; }
-.tok-number { color: #d19a66; }
-.tok-comment { color: #5c6370; }
-.tok-operator { color: #56b6c2; }
-.tok-punctuation { color: #abb2bf; }
-.tok-identifier { color: #e8eaed; }
-.tok-builtin { color: #61afef; }
+/* Collaborators' carets: zero-width, so the overlay stays aligned with the textarea */
+.remote-caret {
+    position: relative;
+}
+
+.remote-caret::before {
+    content: "";
+    position: absolute;
+    left: -1px;
+    top: 0;
+    height: 1.6em;
+    border-left: 2px solid currentColor;
+}
+
+.remote-caret-label {
+    position: absolute;
+    left: -1px;
+    bottom: 1.5em;
+    padding: 0 4px;
+    border-radius: 3px 3px 3px 0;
+    font-size: 10px;
+    line-height: 1.4;
+    white-space: nowrap;
+    background: currentColor;
+}
+
+.remote-caret-label span {
+    color: #fff;
+}
+
+/* Syntax highlighting colors (.tok-*) come from the theme: see ui::custom_theme::syntax_css.
+   No font-weight/style changes there, to keep heights identical. */
 
 /* Mobile optimizations */
 @media (max-width: 768px) {
@@ -359,6 +398,63 @@ fn token_class(kind: TokenKind) -> &'static str {
     }
 }
 
+/// Split tokens around the carets, in buffer order.
+fn place_carets(tokens: Vec<Token>, carets: &[RemoteCaret]) -> Vec<Piece> {
+    let mut order: Vec<usize> = (0..carets.len()).collect();
+    order.sort_by_key(|&i| carets[i].offset);
+    let mut order = order.into_iter().peekable();
+
+    let mut pieces = Vec::with_capacity(tokens.len() + carets.len());
+    let mut at = 0;
+    for token in tokens {
+        let mut text = token.text.as_str();
+        while let Some(&i) = order.peek() {
+            let split = carets[i].offset.saturating_sub(at);
+            // A caret past this token waits for a later one
+            let Some((byte, _)) = text.char_indices().nth(split) else { break };
+            if byte > 0 {
+                pieces.push(Piece::Text(Token { text: text[..byte].to_string(), kind: token.kind }));
+            }
+            pieces.push(Piece::Caret(i));
+            at += split;
+            text = &text[byte..];
+            order.next();
+        }
+        at += text.chars().count();
+        if !text.is_empty() {
+            pieces.push(Piece::Text(Token { text: text.to_string(), kind: token.kind }));
+        }
+    }
+    // Carets at (or past) the end of the buffer
+    pieces.extend(order.map(Piece::Caret));
+    pieces
+}
+
+/// The char offset of a UTF-16 offset, as textareas report selections.
+fn char_offset(text: &str, utf16: usize) -> usize {
+    let mut units = 0;
+    text.chars()
+        .take_while(|c| {
+            units += c.len_utf16();
+            units <= utf16
+        })
+        .count()
+}
+
+/// The focused textarea's caret, in chars.
+fn focused_caret() -> Option<usize> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use wasm_bindgen::JsCast;
+        let element = web_sys::window()?.document()?.active_element()?;
+        let textarea = element.dyn_into::<web_sys::HtmlTextAreaElement>().ok()?;
+        let start = textarea.selection_start().ok()??;
+        Some(char_offset(&textarea.value(), start as usize))
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    None
+}
+
 /// Syntax-highlighted code editor component.
 #[component]
 pub fn CodeEditor(
@@ -369,8 +465,16 @@ pub fn CodeEditor(
     placeholder: String,
     #[props(default = false)]
     readonly: bool,
+    #[props(default)]
+    carets: Vec<RemoteCaret>,
+    on_caret: Option<EventHandler<usize>>,
 ) -> Element {
-    let tokens = tokenize(&value, language);
+    let pieces = place_carets(tokenize(&value, language), &carets);
+    let report_caret = move || {
+        if let (Some(on_caret), Some(offset)) = (on_caret, focused_caret()) {
+            on_caret.call(offset);
+        }
+    };
 
     rsx! {
         style { "{CODE_EDITOR_STYLE}" }
@@ -379,16 +483,31 @@ pub fn CodeEditor(
             div { class: "code-editor-input",
                 // Highlighted overlay
                 div { class: "code-editor-highlight",
-                    for token in tokens {
-                        if token.text.chars().all(|c| c.is_whitespace()) {
-                            // Output whitespace as raw text to match textarea rendering
-                            "{token.text}"
-                        } else {
-                            span {
-                                class: "{token_class(token.kind)}",
-                                "{token.text}"
+                    for piece in pieces {
+                        {match piece {
+                            Piece::Text(token) => rsx! {
+                                if token.text.chars().all(|c| c.is_whitespace()) {
+                                    // Output whitespace as raw text to match textarea rendering
+                                    "{token.text}"
+                                } else {
+                                    span {
+                                        class: "{token_class(token.kind)}",
+                                        "{token.text}"
+                                    }
+                                }
+                            },
+                            Piece::Caret(i) => {
+                                let caret = &carets[i];
+                                rsx! {
+                                    span {
+                                        class: "remote-caret",
+                                        style: "color: {caret.color}",
+                                        title: "{caret.name}",
+                                        span { class: "remote-caret-label", span { "{caret.name}" } }
+                                    }
+                                }
                             }
-                        }
+                        }}
                     }
                 }
 
@@ -401,8 +520,11 @@ pub fn CodeEditor(
                     spellcheck: "false",
                     autocomplete: "off",
                     autocapitalize: "off",
+                    onkeyup: move |_| report_caret(),
+                    onclick: move |_| report_caret(),
                     oninput: move |evt| {
                         on_change.call(evt.value());
+                        report_caret();
                         // Sync scroll after input in case highlight re-renders
                         #[cfg(target_arch = "wasm32")]
                         {
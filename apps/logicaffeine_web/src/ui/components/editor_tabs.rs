@@ -0,0 +1,160 @@
+//! Tab bar for the Studio's open files.
+//!
+//! One tab per open file in the virtual file system. A dot marks a buffer with
+//! unsaved edits; the Save button writes the active buffer back to the VFS.
+//!
+//! # Props
+//!
+//! - `tabs` - The open files, as an [`OpenTabs`]
+//! - `on_select` - Callback with the path of the clicked tab
+//! - `on_close` - Callback with the path of the tab to close
+//! - `on_save` - Callback with the path of the active tab to save
+
+use dioxus::prelude::*;
+use crate::ui::state::OpenTabs;
+
+const EDITOR_TABS_STYLE: &str = r#"
+.editor-tabs {
+    display: flex;
+    align-items: stretch;
+    height: 34px;
+    background: #12161c;
+    border-bottom: 1px solid rgba(255, 255, 255, 0.08);
+    overflow-x: auto;
+    flex-shrink: 0;
+}
+
+.editor-tab {
+    display: flex;
+    align-items: center;
+    gap: 6px;
+    padding-inline: 12px 6px;
+    border: none;
+    border-inline-end: 1px solid rgba(255, 255, 255, 0.06);
+    background: transparent;
+    color: rgba(229, 231, 235, 0.56);
+    font-size: 12px;
+    cursor: pointer;
+    white-space: nowrap;
+}
+
+.editor-tab:hover {
+    color: rgba(229, 231, 235, 0.85);
+    background: rgba(255, 255, 255, 0.03);
+}
+
+.editor-tab.active {
+    color: #e5e7eb;
+    background: rgba(255, 255, 255, 0.06);
+    box-shadow: inset 0 -2px 0 #667eea;
+}
+
+.editor-tab-dirty {
+    width: 6px;
+    height: 6px;
+    border-radius: 50%;
+    background: #fbbf24;
+}
+
+.editor-tab-close {
+    border: none;
+    background: transparent;
+    color: inherit;
+    font-size: 14px;
+    line-height: 1;
+    padding: 2px 4px;
+    border-radius: 4px;
+    cursor: pointer;
+    opacity: 0.6;
+}
+
+.editor-tab-close:hover {
+    opacity: 1;
+    background: rgba(255, 255, 255, 0.08);
+}
+
+.editor-tabs-save {
+    margin-inline-start: auto;
+    padding: 0 12px;
+    border: none;
+    background: transparent;
+    color: #667eea;
+    font-size: 12px;
+    font-weight: 600;
+    cursor: pointer;
+}
+
+.editor-tabs-save:disabled {
+    color: rgba(229, 231, 235, 0.3);
+    cursor: default;
+}
+"#;
+
+#[component]
+pub fn EditorTabs(
+    tabs: OpenTabs,
+    on_select: EventHandler<String>,
+    on_close: EventHandler<String>,
+    on_save: EventHandler<String>,
+) -> Element {
+    if tabs.tabs.is_empty() {
+        return rsx! {};
+    }
+
+    let active = tabs.active_tab().cloned();
+
+    rsx! {
+        style { "{EDITOR_TABS_STYLE}" }
+        div { class: "editor-tabs", role: "tablist", aria_label: "Open files",
+            for (i, tab) in tabs.tabs.iter().enumerate() {
+                {
+                    let is_active = tabs.active == Some(i);
+                    let select_path = tab.path.clone();
+                    let close_path = tab.path.clone();
+                    rsx! {
+                        div {
+                            key: "{tab.path}",
+                            class: if is_active { "editor-tab active" } else { "editor-tab" },
+                            role: "tab",
+                            tabindex: "0",
+                            title: "{tab.path}",
+                            aria_selected: if is_active { "true" } else { "false" },
+                            onclick: move |_| on_select.call(select_path.clone()),
+                            onkeydown: {
+                                let path = tab.path.clone();
+                                move |evt: KeyboardEvent| {
+                                    if evt.key() == Key::Enter {
+                                        on_select.call(path.clone());
+                                    }
+                                }
+                            },
+                            span { "{tab.name()}" }
+                            if tab.dirty {
+                                span { class: "editor-tab-dirty", aria_label: "Unsaved changes" }
+                            }
+                            button {
+                                class: "editor-tab-close",
+                                aria_label: "Close {tab.name()}",
+                                onclick: move |evt| {
+                                    evt.stop_propagation();
+                                    on_close.call(close_path.clone());
+                                },
+                                "×"
+                            }
+                        }
+                    }
+                }
+            }
+            button {
+                class: "editor-tabs-save",
+                disabled: !active.as_ref().is_some_and(|t| t.dirty),
+                onclick: move |_| {
+                    if let Some(tab) = active.as_ref() {
+                        on_save.call(tab.path.clone());
+                    }
+                },
+                "Save"
+            }
+        }
+    }
+}
@@ -9,6 +9,8 @@
 use dioxus::prelude::*;
 use crate::ui::components::icon::{Icon, IconVariant, IconSize};
 use crate::ui::components::theme_picker::ThemePicker;
+use crate::ui::components::locale_picker::LocalePicker;
+use crate::ui::i18n::I18nState;
 use crate::ui::components::main_nav::LOGO_SVG;
 
 const FOOTER_STYLES: &str = r#"
@@ -296,6 +298,8 @@ pub fn Footer(
     variant: FooterVariant,
 ) -> Element {
     let current_year = 2026; // Static for now, could use chrono
+    let i18n = use_context::<I18nState>();
+    let rights = i18n.t_with("footer-rights", &[("year", &current_year.to_string())]);
 
     if variant == FooterVariant::Minimal {
         return rsx! {
@@ -303,12 +307,10 @@ pub fn Footer(
             footer { class: "site-footer",
                 div { class: "footer-container",
                     div { class: "footer-bottom",
-                        span { class: "footer-copyright",
-                            "© {current_year} Brahmastra Labs. All rights reserved."
-                        }
+                        span { class: "footer-copyright", "{rights}" }
                         div { class: "footer-bottom-links",
-                            Link { to: "/privacy", class: "footer-bottom-link", "Privacy Policy" }
-                            Link { to: "/terms", class: "footer-bottom-link", "Terms of Service" }
+                            Link { to: "/privacy", class: "footer-bottom-link", {i18n.t("footer-privacy-policy")} }
+                            Link { to: "/terms", class: "footer-bottom-link", {i18n.t("footer-terms-of-service")} }
                         }
                     }
                 }
@@ -400,17 +402,19 @@ pub fn Footer(
 
                 // Bottom bar
                 div { class: "footer-bottom",
-                    span { class: "footer-copyright",
-                        "© {current_year} Brahmastra Labs. All rights reserved."
-                    }
+                    span { class: "footer-copyright", "{rights}" }
                     div { class: "footer-bottom-right",
                         div { class: "footer-theme-section",
-                            span { class: "footer-theme-label", "Theme:" }
+                            span { class: "footer-theme-label", {i18n.t("footer-theme")} }
                             ThemePicker {}
                         }
+                        div { class: "footer-theme-section",
+                            span { class: "footer-theme-label", {i18n.t("footer-language")} }
+                            LocalePicker {}
+                        }
                         div { class: "footer-bottom-links",
-                            Link { to: "/privacy", class: "footer-bottom-link", "Privacy" }
-                            Link { to: "/terms", class: "footer-bottom-link", "Terms" }
+                            Link { to: "/privacy", class: "footer-bottom-link", {i18n.t("footer-privacy")} }
+                            Link { to: "/terms", class: "footer-bottom-link", {i18n.t("footer-terms")} }
                         }
                     }
                 }
@@ -0,0 +1,214 @@
+//! The Studio **grammar playground** — a Logic-mode developer drawer that puts
+//! the parser's interpretive modes on switches. Each toggle recompiles the
+//! sentence at once through `logicaffeine_language::compile_with_modes`, and
+//! the result is set against the parser's default reading and the ranked
+//! readings the Studio itself shows, so a linguist can see what a mode
+//! changes without writing Rust.
+//!
+//! Docked below the editor/output like the Code-mode `DebugDrawer`, and just
+//! as additive: closing it leaves the rest of the Studio untouched.
+
+use dioxus::prelude::*;
+use logicaffeine_language::{
+    compile_forest, compile_with_modes, socratic_explanation, Interner, ModalPreference, ModesReading,
+    NegativeScopeMode, OutputFormat, ParserModes,
+};
+
+/// An on/off mode: label, hint, getter, setter.
+type Switch = (&'static str, &'static str, fn(&ParserModes) -> bool, fn(&mut ParserModes, bool));
+
+const SWITCHES: [Switch; 5] = [
+    ("Noun priority", "Read noun/verb-ambiguous words as nouns", |m| m.noun_priority, |m, on| m.noun_priority = on),
+    ("PP \u{2192} noun", "Attach prepositional phrases to the nearest noun", |m| m.pp_attach_to_noun, |m, on| m.pp_attach_to_noun = on),
+    ("Collective", "Read plural subjects as groups", |m| m.collective, |m, on| m.collective = on),
+    ("Event reading", "Let adjectives modify the event of an agentive noun", |m| m.event_reading, |m, on| m.event_reading = on),
+    ("All scopings", "List every quantifier scoping of the parse", |m| m.all_scopings, |m, on| m.all_scopings = on),
+];
+
+const NEGATION: [(&str, NegativeScopeMode); 3] = [
+    ("Surface", NegativeScopeMode::Surface),
+    ("Narrow", NegativeScopeMode::Narrow),
+    ("Wide", NegativeScopeMode::Wide),
+];
+
+const MODALS: [(&str, ModalPreference); 3] = [
+    ("Default", ModalPreference::Default),
+    ("Epistemic", ModalPreference::Epistemic),
+    ("Deontic", ModalPreference::Deontic),
+];
+
+/// The bottom grammar drawer. `sentence` is the Logic-mode input; `on_close`
+/// hides the drawer.
+#[component]
+pub fn GrammarPlayground(sentence: String, on_close: EventHandler<()>) -> Element {
+    let mut modes = use_signal(ParserModes::default);
+    let current = modes();
+
+    let sentence = sentence.trim().to_string();
+    let default_reading = read(&sentence, ParserModes::default());
+    let result = read(&sentence, current);
+    let ranked = if sentence.is_empty() { Vec::new() } else { compile_forest(&sentence) };
+
+    let reading = result.as_ref().ok().map(|r| r.reading.clone());
+    let changed = match (&reading, &default_reading) {
+        (Some(reading), Ok(default)) => *reading != default.reading,
+        _ => false,
+    };
+    let ranked_position = reading.as_ref().and_then(|r| ranked.iter().position(|ranked| ranked == r));
+
+    rsx! {
+        style { "{GRAMMAR_PLAYGROUND_STYLE}" }
+        div { class: "gp-drawer",
+            div { class: "gp-bar",
+                span { class: "gp-title", "Grammar" }
+                div { class: "gp-switches",
+                    for (label, hint, get, set) in SWITCHES {
+                        button {
+                            class: if get(&current) { "gp-switch on" } else { "gp-switch" },
+                            title: "{hint}",
+                            onclick: move |_| {
+                                let mut next = modes();
+                                let on = !get(&next);
+                                set(&mut next, on);
+                                modes.set(next);
+                            },
+                            span { class: "gp-knob" }
+                            "{label}"
+                        }
+                    }
+                }
+                div { class: "gp-group", title: "Where negation scopes against quantifiers, modals and neg-raising verbs",
+                    span { class: "gp-group-label", "Negation" }
+                    for (label, mode) in NEGATION {
+                        button {
+                            class: if current.negative_scope == mode { "gp-seg active" } else { "gp-seg" },
+                            onclick: move |_| modes.set(ParserModes { negative_scope: mode, ..modes() }),
+                            "{label}"
+                        }
+                    }
+                }
+                div { class: "gp-group", title: "How may, can and could are read",
+                    span { class: "gp-group-label", "Modals" }
+                    for (label, preference) in MODALS {
+                        button {
+                            class: if current.modal_preference == preference { "gp-seg active" } else { "gp-seg" },
+                            onclick: move |_| modes.set(ParserModes { modal_preference: preference, ..modes() }),
+                            "{label}"
+                        }
+                    }
+                }
+                button { class: "gp-reset", disabled: current == ParserModes::default(),
+                    onclick: move |_| modes.set(ParserModes::default()), "Reset" }
+                button { class: "gp-close", title: "Close the grammar playground",
+                    onclick: move |_| on_close.call(()), "\u{2715}" }
+            }
+
+            div { class: "gp-body",
+                if sentence.is_empty() {
+                    div { class: "gp-empty", "Type an English sentence to explore its readings." }
+                } else {
+                    div { class: "gp-row",
+                        span { class: "gp-label", "These modes" }
+                        match &result {
+                            Ok(r) => rsx! { code { class: if changed { "gp-fol changed" } else { "gp-fol" }, "{r.reading}" } },
+                            Err(advice) => rsx! { span { class: "gp-error", "{advice}" } },
+                        }
+                    }
+                    div { class: "gp-row",
+                        span { class: "gp-label", "Parser default" }
+                        match &default_reading {
+                            Ok(r) => rsx! { code { class: "gp-fol", "{r.reading}" } },
+                            Err(advice) => rsx! { span { class: "gp-error", "{advice}" } },
+                        }
+                        if reading.is_some() && default_reading.is_ok() {
+                            span { class: if changed { "gp-badge changed" } else { "gp-badge" },
+                                if changed { "changed" } else { "same" }
+                            }
+                        }
+                    }
+                    if !ranked.is_empty() {
+                        div { class: "gp-section", "Ranked readings" }
+                        for (i, ranked_reading) in ranked.iter().enumerate() {
+                            div { class: if ranked_position == Some(i) { "gp-reading match" } else { "gp-reading" },
+                                span { class: "gp-index", "{i + 1}" }
+                                code { "{ranked_reading}" }
+                                if ranked_position == Some(i) {
+                                    span { class: "gp-badge match", "this reading" }
+                                }
+                            }
+                        }
+                        if reading.is_some() && ranked_position.is_none() {
+                            div { class: "gp-empty", "These modes give a reading the ranking does not offer." }
+                        }
+                    }
+                    if let Ok(r) = &result {
+                        if current.all_scopings {
+                            div { class: "gp-section", "Scopings ({r.scopings.len()})" }
+                            for (i, scoping) in r.scopings.iter().enumerate() {
+                                div { class: "gp-reading",
+                                    span { class: "gp-index", "{i + 1}" }
+                                    code { "{scoping}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `sentence` compiled in `modes`, or the Socratic explanation of why it
+/// does not parse.
+fn read(sentence: &str, modes: ParserModes) -> Result<ModesReading, String> {
+    compile_with_modes(sentence, modes, OutputFormat::Unicode)
+        .map_err(|e| socratic_explanation(&e, &Interner::new()))
+}
+
+const GRAMMAR_PLAYGROUND_STYLE: &str = r#"
+.gp-drawer { display:flex; flex-direction:column; background:var(--studio-panel-bg,#12161c);
+  border-top:1px solid var(--studio-border,rgba(255,255,255,0.08)); color:var(--studio-text,#e8eaed);
+  font-size:13px; max-height:320px; min-height:140px; flex-shrink:0; }
+.gp-bar { display:flex; align-items:center; gap:12px; padding:6px 12px; flex-wrap:wrap;
+  border-bottom:1px solid var(--studio-border,rgba(255,255,255,0.08)); }
+.gp-title { font-weight:600; }
+.gp-switches { display:flex; gap:4px; flex-wrap:wrap; }
+.gp-switch { display:inline-flex; align-items:center; gap:6px; background:rgba(255,255,255,0.05);
+  border:1px solid var(--studio-border,rgba(255,255,255,0.08)); color:var(--studio-text-secondary,#9ca3af);
+  border-radius:6px; padding:3px 8px; cursor:pointer; font-size:12px; transition:all .12s ease; }
+.gp-switch:hover { background:rgba(255,255,255,0.1); }
+.gp-knob { width:22px; height:12px; border-radius:6px; background:rgba(255,255,255,0.15); position:relative; transition:background .12s; }
+.gp-knob::after { content:''; position:absolute; top:2px; left:2px; width:8px; height:8px; border-radius:50%;
+  background:#9ca3af; transition:left .12s, background .12s; }
+.gp-switch.on { color:var(--studio-text,#e8eaed); border-color:var(--studio-accent,#667eea); }
+.gp-switch.on .gp-knob { background:rgba(102,126,234,0.5); }
+.gp-switch.on .gp-knob::after { left:12px; background:#e8eaed; }
+.gp-group { display:inline-flex; align-items:center; gap:2px; }
+.gp-group-label { color:var(--studio-text-muted,#6b7280); font-size:11px; text-transform:uppercase; letter-spacing:0.5px; margin-right:4px; }
+.gp-seg { background:transparent; border:1px solid var(--studio-border,rgba(255,255,255,0.08));
+  color:var(--studio-text-secondary,#9ca3af); padding:3px 8px; cursor:pointer; font-size:12px; }
+.gp-seg:first-of-type { border-radius:6px 0 0 6px; }
+.gp-seg:last-child { border-radius:0 6px 6px 0; }
+.gp-seg.active { background:rgba(102,126,234,0.3); color:var(--studio-text,#e8eaed); border-color:var(--studio-accent,#667eea); }
+.gp-reset { margin-left:auto; background:rgba(255,255,255,0.05); border:1px solid var(--studio-border,rgba(255,255,255,0.08));
+  color:var(--studio-text,#e8eaed); border-radius:6px; padding:3px 8px; cursor:pointer; font-size:12px; }
+.gp-reset:disabled { opacity:0.35; cursor:default; }
+.gp-close { background:transparent; border:none; color:var(--studio-text-muted,#6b7280); cursor:pointer; font-size:14px; }
+.gp-close:hover { color:var(--studio-text,#e8eaed); }
+.gp-body { flex:1; min-height:0; overflow:auto; padding:8px 12px; }
+.gp-row { display:flex; align-items:baseline; gap:10px; padding:3px 0; }
+.gp-label { color:var(--studio-text-muted,#6b7280); min-width:104px; font-size:12px; }
+.gp-fol, .gp-reading code { font-family:ui-monospace,monospace; font-size:12px; white-space:pre-wrap; }
+.gp-fol.changed { color:#a5b4fc; }
+.gp-error { color:#f87171; font-size:12px; }
+.gp-empty { color:var(--studio-text-muted,#6b7280); font-style:italic; padding:4px 0; }
+.gp-badge { font-size:10px; text-transform:uppercase; letter-spacing:0.5px; padding:1px 6px; border-radius:4px;
+  background:rgba(255,255,255,0.06); color:var(--studio-text-muted,#6b7280); }
+.gp-badge.changed { background:rgba(102,126,234,0.25); color:#a5b4fc; }
+.gp-badge.match { background:rgba(74,222,128,0.2); color:#4ade80; margin-left:auto; }
+.gp-section { margin-top:8px; padding-bottom:2px; color:var(--studio-text-muted,#6b7280); font-size:11px;
+  text-transform:uppercase; letter-spacing:0.5px; }
+.gp-reading { display:flex; align-items:baseline; gap:8px; padding:2px 4px; border-radius:4px; }
+.gp-reading.match { background:rgba(74,222,128,0.08); box-shadow:inset 2px 0 0 #4ade80; }
+.gp-index { color:var(--studio-text-muted,#6b7280); min-width:14px; font-size:11px; }
+"#;
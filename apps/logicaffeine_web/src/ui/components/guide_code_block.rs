@@ -3,13 +3,15 @@
 //! Features:
 //! - Editable code area
 //! - Run button (Logic mode: FOL output, Imperative mode: interpreter)
+//! - Memory button (Imperative mode: per-statement Seq/Map/Text usage)
 //! - Copy button
 //! - Reset button
 //! - Output panel
 
 use dioxus::prelude::*;
 use crate::ui::pages::guide::content::ExampleMode;
-use logicaffeine_compile::{compile_for_ui, interpret_for_ui};
+use logicaffeine_compile::interpreter::InterpreterResult;
+use logicaffeine_compile::{compile_for_ui, interpret_for_ui, interpret_with_memory_report, MemoryReport};
 
 const CODE_BLOCK_STYLE: &str = r#"
 .guide-code-block {
@@ -192,6 +194,36 @@ const CODE_BLOCK_STYLE: &str = r#"
     100% { opacity: 0; }
 }
 
+.guide-code-memory {
+    padding: 0 16px 16px;
+    overflow-x: auto;
+}
+
+.guide-code-memory table {
+    width: 100%;
+    border-collapse: collapse;
+    font-family: ui-monospace, SFMono-Regular, 'SF Mono', Menlo, Monaco, monospace;
+    font-size: 12px;
+    color: rgba(229,231,235,0.8);
+}
+
+.guide-code-memory th,
+.guide-code-memory td {
+    padding: 4px 8px;
+    text-align: end;
+    border-bottom: 1px solid rgba(255,255,255,0.06);
+}
+
+.guide-code-memory th:first-child,
+.guide-code-memory td:first-child {
+    text-align: start;
+}
+
+.guide-code-memory th {
+    font-weight: 600;
+    color: rgba(229,231,235,0.5);
+}
+
 .guide-code-placeholder {
     padding: 24px 16px;
     text-align: center;
@@ -200,6 +232,17 @@ const CODE_BLOCK_STYLE: &str = r#"
 }
 "#;
 
+/// The output panel's text and style class for an interpreter run.
+fn describe_result(result: &InterpreterResult) -> (String, &'static str) {
+    if let Some(err) = &result.error {
+        (err.clone(), "error")
+    } else if result.lines.is_empty() {
+        ("(no output)".to_string(), "info")
+    } else {
+        (result.lines.join("\n"), "success")
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct GuideCodeBlockProps {
     pub id: String,
@@ -216,6 +259,7 @@ pub fn GuideCodeBlock(props: GuideCodeBlockProps) -> Element {
     let mut is_running = use_signal(|| false);
     let mut show_copied = use_signal(|| false);
     let mut has_run = use_signal(|| false);
+    let mut memory = use_signal(|| None::<MemoryReport>);
 
     let initial_code = props.initial_code.clone();
     let mode = props.mode;
@@ -227,6 +271,7 @@ pub fn GuideCodeBlock(props: GuideCodeBlockProps) -> Element {
         has_run.set(true);
 
         let current_code = code.read().clone();
+        memory.set(None);
 
         match mode {
             ExampleMode::Logic => {
@@ -247,16 +292,9 @@ pub fn GuideCodeBlock(props: GuideCodeBlockProps) -> Element {
                 // Phase 55: interpret_for_ui is now async for VFS support
                 spawn(async move {
                     let result = interpret_for_ui(&current_code).await;
-                    if let Some(err) = result.error {
-                        output.set(err);
-                        output_type.set("error".to_string());
-                    } else if result.lines.is_empty() {
-                        output.set("(no output)".to_string());
-                        output_type.set("info".to_string());
-                    } else {
-                        output.set(result.lines.join("\n"));
-                        output_type.set("success".to_string());
-                    }
+                    let (text, kind) = describe_result(&result);
+                    output.set(text);
+                    output_type.set(kind.to_string());
                     is_running.set(false);
                 });
                 return;
@@ -266,6 +304,16 @@ pub fn GuideCodeBlock(props: GuideCodeBlockProps) -> Element {
         is_running.set(false);
     };
 
+    // Memory handler: run on the tree-walker and report Seq/Map/Text usage
+    let handle_memory = move |_| {
+        has_run.set(true);
+        let result = interpret_with_memory_report(&code.read());
+        let (text, kind) = describe_result(&result);
+        output.set(text);
+        output_type.set(kind.to_string());
+        memory.set(result.memory);
+    };
+
     // Copy handler
     let handle_copy = move |_| {
         let code_to_copy = code.read().clone();
@@ -298,6 +346,7 @@ pub fn GuideCodeBlock(props: GuideCodeBlockProps) -> Element {
         move |_| {
             code.set(initial.clone());
             output.set(String::new());
+            memory.set(None);
             has_run.set(false);
         }
     };
@@ -346,6 +395,15 @@ pub fn GuideCodeBlock(props: GuideCodeBlockProps) -> Element {
                             "{mode_label}"
                         }
                     }
+                    if mode == ExampleMode::Imperative {
+                        button {
+                            class: "guide-code-btn",
+                            onclick: handle_memory,
+                            disabled: *is_running.read(),
+                            title: "Show how many Seq, Map and Text values each statement creates",
+                            "Memory"
+                        }
+                    }
                     button {
                         class: "guide-code-btn",
                         onclick: handle_copy,
@@ -386,6 +444,49 @@ pub fn GuideCodeBlock(props: GuideCodeBlockProps) -> Element {
                         class: "guide-code-output-content {output_type}",
                         "{output}"
                     }
+                    if let Some(report) = memory() {
+                        MemoryTable { report }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Per-statement memory usage, one row per top-level statement.
+#[component]
+fn MemoryTable(report: MemoryReport) -> Element {
+    if report.statements.is_empty() {
+        return rsx! {
+            div { class: "guide-code-memory guide-code-placeholder", "No Seq, Map or Text values were created." }
+        };
+    }
+    rsx! {
+        div { class: "guide-code-memory",
+            table {
+                thead {
+                    tr {
+                        th { "Statement" }
+                        th { "Seqs" }
+                        th { "Seq peak" }
+                        th { "Maps" }
+                        th { "Map peak" }
+                        th { "Texts" }
+                        th { "Text peak (bytes)" }
+                    }
+                }
+                tbody {
+                    for stmt in report.statements.iter() {
+                        tr { key: "{stmt.index}",
+                            td { "{stmt.label}" }
+                            td { "{stmt.seqs.allocations}" }
+                            td { "{stmt.seqs.peak_len}" }
+                            td { "{stmt.maps.allocations}" }
+                            td { "{stmt.maps.peak_len}" }
+                            td { "{stmt.texts.allocations}" }
+                            td { "{stmt.texts.peak_len}" }
+                        }
+                    }
                 }
             }
         }
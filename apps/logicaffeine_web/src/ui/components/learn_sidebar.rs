@@ -84,7 +84,7 @@ const SIDEBAR_STYLE: &str = r#"
     text-decoration: none;
     transition: all 0.18s ease;
     cursor: pointer;
-    border-left: 3px solid transparent;
+    border-inline-start: 3px solid transparent;
 }
 
 .learn-sidebar-module:hover {
@@ -95,7 +95,7 @@ const SIDEBAR_STYLE: &str = r#"
 .learn-sidebar-module.active {
     background: rgba(96,165,250,0.15);
     color: var(--color-accent-blue);
-    border-left-color: var(--color-accent-blue);
+    border-inline-start-color: var(--color-accent-blue);
     font-weight: 600;
 }
 
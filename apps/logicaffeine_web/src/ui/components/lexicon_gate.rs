@@ -2,8 +2,11 @@
 //!
 //! On wasm the lexicon arrives via `/data/lexicon.json` (see
 //! [`crate::generator::ensure_lexicon`]); this gate holds its children back until
-//! the index is pinned so `Generator::new()` is always safe inside. Native builds
-//! resolve instantly, so tests and prerendering render children directly.
+//! the index is pinned so `Generator::new()` is always safe inside. It also
+//! revalidates the remote curriculum pack
+//! ([`crate::content::refresh_curriculum_pack`]) so the Learn pages inside render
+//! the latest content. Native builds resolve instantly, so tests and prerendering
+//! render children directly.
 
 use dioxus::prelude::*;
 
@@ -38,7 +41,12 @@ const LEXICON_GATE_STYLE: &str = r#"
 
 #[component]
 pub fn LexiconGate(children: Element) -> Element {
-    let mut ready = use_resource(|| crate::generator::ensure_lexicon());
+    let mut ready = use_resource(|| async {
+        crate::generator::ensure_lexicon().await?;
+        // Best effort: without the pack, the cached or embedded curriculum shows.
+        let _ = crate::content::refresh_curriculum_pack(crate::content::CURRICULUM_PACK_URL).await;
+        Ok::<(), String>(())
+    });
     let state = ready.read_unchecked();
     match &*state {
         Some(Ok(())) => rsx! {
@@ -0,0 +1,65 @@
+//! UI language selector.
+//!
+//! A native `select` over the supported locales; choosing one switches every
+//! catalog-backed string and the page direction at once.
+//!
+//! # Usage
+//!
+//! ```no_run
+//! # use dioxus::prelude::*;
+//! use logicaffeine_web::ui::components::locale_picker::LocalePicker;
+//!
+//! # fn Example() -> Element {
+//! rsx! {
+//!     LocalePicker {}
+//! }
+//! # }
+//! ```
+
+use dioxus::prelude::*;
+use crate::ui::i18n::{I18nState, Locale};
+
+const LOCALE_PICKER_STYLE: &str = r#"
+.locale-select {
+    padding: 6px 10px;
+    background: rgba(255, 255, 255, 0.04);
+    border: 1px solid rgba(255, 255, 255, 0.08);
+    border-radius: 10px;
+    color: var(--text-secondary);
+    font-size: 14px;
+    cursor: pointer;
+}
+
+.locale-select option {
+    background: #12161f;
+    color: var(--text-primary);
+}
+"#;
+
+#[component]
+pub fn LocalePicker() -> Element {
+    let mut i18n = use_context::<I18nState>();
+    let current = i18n.locale();
+
+    rsx! {
+        style { "{LOCALE_PICKER_STYLE}" }
+        select {
+            class: "locale-select",
+            aria_label: i18n.t("profile-language"),
+            onchange: move |e| {
+                if let Some(locale) = Locale::from_code(&e.value()) {
+                    i18n.set_locale(locale);
+                }
+            },
+            for locale in Locale::all() {
+                option {
+                    key: "{locale.code()}",
+                    value: "{locale.code()}",
+                    lang: "{locale.code()}",
+                    selected: locale == current,
+                    "{locale.native_name()}"
+                }
+            }
+        }
+    }
+}
@@ -23,6 +23,7 @@
 //! - Constants (capitalized names): yellow
 
 use dioxus::prelude::*;
+use crate::ui::a11y::Formula;
 
 const OUTPUT_STYLE: &str = r#"
 .logic-output-container {
@@ -203,11 +204,13 @@ pub fn LogicOutput(
 
         div { class: "logic-output-container",
             if total_readings > 1 {
-                div { class: "reading-selector",
+                div { class: "reading-selector", role: "group", aria_label: "Readings",
                     span { "Reading" }
                     for i in 0..total_readings {
                         button {
                             class: if *current_reading.read() == i { "reading-btn active" } else { "reading-btn" },
+                            aria_label: "Reading {i + 1} of {total_readings}",
+                            aria_pressed: "{*current_reading.read() == i}",
                             onclick: move |_| current_reading.set(i),
                             "{i + 1}"
                         }
@@ -217,17 +220,24 @@ pub fn LogicOutput(
             }
 
             if let Some(err) = &error {
-                div { class: "logic-display error",
+                div { class: "logic-display error", role: "alert",
                     "{err}"
                 }
             } else if formatted_output.is_empty() {
                 div { class: "logic-display empty",
                     "Type a sentence to see its logical form..."
                 }
-            } else {
+            } else if format == OutputFormat::LaTeX {
                 div { class: "logic-display",
                     dangerous_inner_html: highlight_logic(&formatted_output)
                 }
+            } else {
+                div { class: "logic-display",
+                    for (i, line) in formatted_output.lines().enumerate() {
+                        if i > 0 { br {} }
+                        Formula { text: line.to_string() }
+                    }
+                }
             }
         }
     }
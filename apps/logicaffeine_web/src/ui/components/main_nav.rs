@@ -12,6 +12,7 @@ use crate::ui::router::Route;
 use crate::ui::components::icon::{Icon, IconVariant, IconSize};
 use crate::content::ContentEngine;
 use crate::ui::pages::guide::content::SECTIONS;
+use crate::ui::i18n::I18nState;
 
 /// Embedded logo SVG — the single embedded copy, shared by nav and footer.
 pub(crate) const LOGO_SVG: &str = include_str!("../../../assets/logo.svg");
@@ -43,6 +44,8 @@ impl ActivePage {
             Route::Learn {} => ActivePage::Learn,
             Route::Studio { .. } => ActivePage::Studio,
             Route::Workspace { .. } => ActivePage::Studio,
+            Route::Notebook {} => ActivePage::Studio,
+            Route::Exam {} => ActivePage::Learn,
             Route::Roadmap {} => ActivePage::Roadmap,
             Route::Pricing {} => ActivePage::Pricing,
             Route::Success { .. } => ActivePage::Pricing,
@@ -259,14 +262,14 @@ const MAIN_NAV_STYLE: &str = r#"
 .mobile-drawer {
     position: fixed;
     top: 0;
-    right: 0;
+    inset-inline-end: 0;
     width: 320px;
     max-width: 85vw;
     height: 100vh;
     background: rgba(12, 16, 24, 0.98);
     backdrop-filter: blur(20px);
     -webkit-backdrop-filter: blur(20px);
-    border-left: 1px solid rgba(255,255,255,0.08);
+    border-inline-start: 1px solid rgba(255,255,255,0.08);
     z-index: 1000;
     transform: translateX(100%);
     transition: transform 0.3s ease;
@@ -278,6 +281,10 @@ const MAIN_NAV_STYLE: &str = r#"
     transform: translateX(0);
 }
 
+[dir="rtl"] .mobile-drawer:not(.open) {
+    transform: translateX(-100%);
+}
+
 .mobile-drawer-header {
     display: flex;
     align-items: center;
@@ -369,9 +376,10 @@ const MAIN_NAV_STYLE: &str = r#"
 }
 
 .mobile-nav-tree-inner {
-    padding: var(--spacing-xs) 0 var(--spacing-sm) var(--spacing-xl);
-    border-left: 2px solid rgba(255,255,255,0.06);
-    margin-left: var(--spacing-lg);
+    padding-block: var(--spacing-xs) var(--spacing-sm);
+    padding-inline: var(--spacing-xl) 0;
+    border-inline-start: 2px solid rgba(255,255,255,0.06);
+    margin-inline-start: var(--spacing-lg);
 }
 
 .mobile-nav-item {
@@ -473,6 +481,7 @@ pub fn MainNav(
     #[props(default = true)]
     show_nav_links: bool,
 ) -> Element {
+    let i18n = use_context::<I18nState>();
     let mut drawer_open = use_signal(|| false);
     let mut guide_expanded = use_signal(|| false);
     let mut learn_expanded = use_signal(|| false);
@@ -512,7 +521,7 @@ pub fn MainNav(
                         if let Some(sub) = subtitle {
                             span { class: "main-nav-brand-subtitle", "{sub}" }
                         } else {
-                            span { class: "main-nav-brand-subtitle", "Debug your thoughts." }
+                            span { class: "main-nav-brand-subtitle", {i18n.t("nav-tagline")} }
                         }
                     }
                 }
@@ -523,42 +532,42 @@ pub fn MainNav(
                         Link {
                             to: Route::Guide {},
                             class: if active == ActivePage::Guide { "main-nav-link active" } else { "main-nav-link" },
-                            "Syntax Guide"
+                            {i18n.t("nav-guide")}
                         }
                         Link {
                             to: Route::Crates {},
                             class: if active == ActivePage::Crates { "main-nav-link active" } else { "main-nav-link" },
-                            "Crates"
+                            {i18n.t("nav-crates")}
                         }
                         Link {
                             to: Route::Learn {},
                             class: if active == ActivePage::Learn { "main-nav-link active" } else { "main-nav-link" },
-                            "Learn Logic"
+                            {i18n.t("nav-learn")}
                         }
                         Link {
                             to: Route::Studio { file: None },
                             class: if active == ActivePage::Studio { "main-nav-link active" } else { "main-nav-link" },
-                            "Studio"
+                            {i18n.t("nav-studio")}
                         }
                         Link {
                             to: Route::Roadmap {},
                             class: if active == ActivePage::Roadmap { "main-nav-link active" } else { "main-nav-link" },
-                            "Roadmap"
+                            {i18n.t("nav-roadmap")}
                         }
                         Link {
                             to: Route::Pricing {},
                             class: if active == ActivePage::Pricing { "main-nav-link active" } else { "main-nav-link" },
-                            "Contact"
+                            {i18n.t("nav-contact")}
                         }
                         Link {
                             to: Route::News { tag: None },
                             class: if active == ActivePage::News { "main-nav-link active" } else { "main-nav-link" },
-                            "News"
+                            {i18n.t("nav-news")}
                         }
                         Link {
                             to: Route::Benchmarks {},
                             class: if active == ActivePage::Benchmarks { "main-nav-link active" } else { "main-nav-link" },
-                            "Benchmarks"
+                            {i18n.t("nav-benchmarks")}
                         }
                     }
                 }
@@ -570,7 +579,7 @@ pub fn MainNav(
                         href: "https://github.com/Brahmastra-Labs/logicaffeine",
                         target: "_blank",
                         class: "main-nav-btn main-nav-btn-icon",
-                        title: "View on GitHub",
+                        title: i18n.t("nav-github-title"),
                         svg {
                             xmlns: "http://www.w3.org/2000/svg",
                             view_box: "0 0 24 24",
@@ -583,7 +592,7 @@ pub fn MainNav(
                     Link {
                         to: Route::Profile {},
                         class: if active == ActivePage::Profile { "main-nav-btn main-nav-btn-icon active" } else { "main-nav-btn main-nav-btn-icon" },
-                        title: "Your Profile",
+                        title: i18n.t("nav-profile-title"),
                         svg {
                             xmlns: "http://www.w3.org/2000/svg",
                             view_box: "0 0 24 24",
@@ -595,6 +604,8 @@ pub fn MainNav(
                     // Mobile menu button
                     button {
                         class: "mobile-menu-btn",
+                        aria_label: i18n.t("nav-open-menu"),
+                        aria_expanded: "{drawer_open()}",
                         onclick: move |_| drawer_open.set(true),
                         Icon { variant: IconVariant::Menu, size: IconSize::Medium }
                     }
@@ -614,9 +625,10 @@ pub fn MainNav(
 
             // Header
             div { class: "mobile-drawer-header",
-                span { class: "mobile-drawer-title", "Navigation" }
+                span { class: "mobile-drawer-title", {i18n.t("nav-drawer-title")} }
                 button {
                     class: "mobile-drawer-close",
+                    aria_label: i18n.t("nav-close-menu"),
                     onclick: move |_| drawer_open.set(false),
                     Icon { variant: IconVariant::Close, size: IconSize::Medium }
                 }
@@ -636,7 +648,7 @@ pub fn MainNav(
                             span { class: "mobile-nav-header-icon",
                                 Icon { variant: IconVariant::Book, size: IconSize::Medium }
                             }
-                            span { class: "mobile-nav-header-title", "Syntax Guide" }
+                            span { class: "mobile-nav-header-title", {i18n.t("nav-guide")} }
                         }
                         span {
                             class: if *guide_expanded.read() { "mobile-nav-header-chevron expanded" } else { "mobile-nav-header-chevron" },
@@ -678,7 +690,7 @@ pub fn MainNav(
                             span { class: "mobile-nav-header-icon",
                                 Icon { variant: IconVariant::GraduationCap, size: IconSize::Medium }
                             }
-                            span { class: "mobile-nav-header-title", "Learn Logic" }
+                            span { class: "mobile-nav-header-title", {i18n.t("nav-learn")} }
                         }
                         span {
                             class: if *learn_expanded.read() { "mobile-nav-header-chevron expanded" } else { "mobile-nav-header-chevron" },
@@ -718,7 +730,7 @@ pub fn MainNav(
                     span { class: "mobile-nav-link-icon",
                         Icon { variant: IconVariant::Beaker, size: IconSize::Medium }
                     }
-                    "Studio"
+                    {i18n.t("nav-studio")}
                 }
                 Link {
                     to: Route::Crates {},
@@ -727,7 +739,7 @@ pub fn MainNav(
                     span { class: "mobile-nav-link-icon",
                         Icon { variant: IconVariant::Package, size: IconSize::Medium }
                     }
-                    "Crates"
+                    {i18n.t("nav-crates")}
                 }
                 Link {
                     to: Route::Roadmap {},
@@ -736,7 +748,7 @@ pub fn MainNav(
                     span { class: "mobile-nav-link-icon",
                         Icon { variant: IconVariant::Map, size: IconSize::Medium }
                     }
-                    "Roadmap"
+                    {i18n.t("nav-roadmap")}
                 }
                 Link {
                     to: Route::Pricing {},
@@ -745,7 +757,7 @@ pub fn MainNav(
                     span { class: "mobile-nav-link-icon",
                         Icon { variant: IconVariant::Diamond, size: IconSize::Medium }
                     }
-                    "Contact"
+                    {i18n.t("nav-contact")}
                 }
                 Link {
                     to: Route::News { tag: None },
@@ -754,7 +766,7 @@ pub fn MainNav(
                     span { class: "mobile-nav-link-icon",
                         Icon { variant: IconVariant::Newspaper, size: IconSize::Medium }
                     }
-                    "News"
+                    {i18n.t("nav-news")}
                 }
                 Link {
                     to: Route::Benchmarks {},
@@ -763,7 +775,7 @@ pub fn MainNav(
                     span { class: "mobile-nav-link-icon",
                         Icon { variant: IconVariant::Lightning, size: IconSize::Medium }
                     }
-                    "Benchmarks"
+                    {i18n.t("nav-benchmarks")}
                 }
 
                 div { class: "mobile-nav-divider" }
@@ -775,7 +787,7 @@ pub fn MainNav(
                     span { class: "mobile-nav-link-icon",
                         Icon { variant: IconVariant::User, size: IconSize::Medium }
                     }
-                    "Profile"
+                    {i18n.t("nav-profile")}
                 }
                 a {
                     href: "https://github.com/Brahmastra-Labs/logicaffeine",
@@ -784,7 +796,7 @@ pub fn MainNav(
                     span { class: "mobile-nav-link-icon",
                         Icon { variant: IconVariant::Github, size: IconSize::Medium }
                     }
-                    "GitHub"
+                    {i18n.t("nav-github")}
                 }
             }
         }
@@ -23,16 +23,20 @@
 //! # Studio (Playground)
 //! - [`mode_toggle`] - Logic/Code/Math mode switcher
 //! - [`file_browser`] - Virtual file system tree
+//! - [`editor_tabs`] - Tab bar for open files
 //! - [`code_editor`] - Monaco-style code editing
 //! - [`formula_editor`] - LaTeX formula input with preview
 //! - [`repl_output`] - REPL history display
 //! - [`proof_panel`] - Proof tactic interface
+//! - [`grammar_playground`] - Parser mode switches with live reading comparison
 //!
 //! # Output & Visualization
 //! - [`logic_output`] - FOL expression display with formatting
 //! - [`ast_tree`] - Interactive syntax tree visualization
+//! - [`ast_diff_view`] - Two syntax trees merged, with changed nodes highlighted
 //! - [`katex`] - LaTeX math rendering
 //! - [`context_view`] - Proof context display
+//! - [`rust_preview`] - Side-by-side LOGOS → Rust with line mapping and diff
 //!
 //! # Form Elements
 //! - [`input`] - Styled text input
@@ -45,6 +49,7 @@ pub mod input;
 pub mod editor;
 pub mod logic_output;
 pub mod ast_tree;
+pub mod ast_diff_view;
 pub mod socratic_guide;
 pub mod katex;
 pub mod mixed_text;
@@ -65,14 +70,19 @@ pub mod footer;
 pub mod page_layout;
 pub mod icon;
 pub mod theme_picker;
+pub mod locale_picker;
+pub mod theme_editor;
 
 // Studio components
 pub mod mode_toggle;
 pub mod file_browser;
+pub mod editor_tabs;
 pub mod repl_output;
 pub mod context_view;
 pub mod symbol_palette;
 pub mod formula_editor;
 pub mod code_editor;
+pub mod rust_preview;
 pub mod proof_panel;
 pub mod debug_drawer;
+pub mod grammar_playground;
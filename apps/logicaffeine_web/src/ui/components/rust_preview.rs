@@ -0,0 +1,202 @@
+//! Side-by-side LOGOS → Rust preview.
+//!
+//! Shows the LOGOS source next to the Rust it compiles to. Clicking a LOGOS
+//! line highlights the Rust lines generated from it (and hovering a Rust line
+//! points back at its sentence), using the line map from
+//! [`generate_rust_preview`](logicaffeine_compile::generate_rust_preview).
+//! Rust lines that changed since the previous compile are marked, so an edit's
+//! effect on the output is visible at a glance.
+//!
+//! # Props
+//!
+//! - `source` - The LOGOS source the preview was compiled from
+//! - `preview` - The generated Rust and its line map
+//! - `previous` - The Rust from the compile before, to diff against
+//! - `error` - The latest compile error, shown above the last good preview
+
+use dioxus::prelude::*;
+use logicaffeine_compile::RustPreview;
+
+const RUST_PREVIEW_STYLE: &str = r#"
+.rust-preview {
+    display: grid;
+    grid-template-columns: minmax(0, 2fr) minmax(0, 3fr);
+    height: 100%;
+    font-family: var(--font-mono, monospace);
+    font-size: 12px;
+    line-height: 1.6;
+}
+
+.rust-preview-column {
+    overflow: auto;
+    padding: 8px 0;
+}
+
+.rust-preview-column + .rust-preview-column {
+    border-inline-start: 1px solid rgba(255, 255, 255, 0.08);
+}
+
+.rust-preview-line {
+    display: flex;
+    white-space: pre;
+    padding-inline-end: 12px;
+    border-inline-start: 2px solid transparent;
+}
+
+.rust-preview-line .ln {
+    flex-shrink: 0;
+    width: 3.5em;
+    padding-inline-end: 8px;
+    text-align: end;
+    color: rgba(229, 231, 235, 0.3);
+    user-select: none;
+}
+
+.rust-preview-logos .rust-preview-line {
+    cursor: pointer;
+}
+
+.rust-preview-logos .rust-preview-line:hover {
+    background: rgba(255, 255, 255, 0.04);
+}
+
+.rust-preview-line.changed {
+    border-inline-start-color: #fbbf24;
+    background: rgba(251, 191, 36, 0.06);
+}
+
+.rust-preview-line.linked {
+    background: rgba(102, 126, 234, 0.22);
+}
+
+.rust-preview-error {
+    grid-column: 1 / -1;
+    padding: 6px 12px;
+    color: #f87171;
+    border-bottom: 1px solid rgba(255, 255, 255, 0.08);
+    white-space: pre-wrap;
+}
+"#;
+
+/// For each line of `new`, whether it is absent from a longest common
+/// subsequence with `old` — i.e. added or edited since `old`.
+pub fn changed_lines(old: &str, new: &str) -> Vec<bool> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    if old.is_empty() {
+        return vec![false; new.len()];
+    }
+
+    // lcs[i][j]: LCS length of old[i..] and new[j..]
+    let mut lcs = vec![vec![0u32; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut changed = vec![true; new.len()];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            changed[j] = false;
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    changed
+}
+
+#[component]
+pub fn RustPreviewPane(
+    source: String,
+    preview: RustPreview,
+    previous: String,
+    error: Option<String>,
+) -> Element {
+    let mut selected = use_signal(|| None::<u32>);
+    let mut hovered = use_signal(|| None::<u32>);
+
+    let changed = changed_lines(&previous, &preview.rust);
+    let linked: Vec<u32> = selected().map(|line| preview.rust_lines_for(line)).unwrap_or_default();
+    let pointed = hovered().and_then(|line| preview.logos_lines_for(line));
+
+    rsx! {
+        style { "{RUST_PREVIEW_STYLE}" }
+        div { class: "rust-preview",
+            if let Some(message) = error {
+                div { class: "rust-preview-error", role: "alert", "{message}" }
+            }
+            div { class: "rust-preview-column rust-preview-logos", aria_label: "LOGOS source",
+                for (i, text) in source.lines().enumerate() {
+                    {
+                        let line = i as u32 + 1;
+                        let is_linked = selected() == Some(line)
+                            || pointed.is_some_and(|(first, last)| (first..=last).contains(&line));
+                        rsx! {
+                            div {
+                                key: "{line}",
+                                class: if is_linked { "rust-preview-line linked" } else { "rust-preview-line" },
+                                onclick: move |_| {
+                                    let next = if selected() == Some(line) { None } else { Some(line) };
+                                    selected.set(next);
+                                },
+                                span { class: "ln", "{line}" }
+                                span { "{text}" }
+                            }
+                        }
+                    }
+                }
+            }
+            div { class: "rust-preview-column", aria_label: "Generated Rust",
+                for (i, text) in preview.rust.lines().enumerate() {
+                    {
+                        let line = i as u32 + 1;
+                        let class = match (linked.contains(&line), changed.get(i).copied().unwrap_or(false)) {
+                            (true, _) => "rust-preview-line linked",
+                            (false, true) => "rust-preview-line changed",
+                            (false, false) => "rust-preview-line",
+                        };
+                        rsx! {
+                            div {
+                                key: "{line}",
+                                class: "{class}",
+                                onmouseenter: move |_| hovered.set(Some(line)),
+                                onmouseleave: move |_| hovered.set(None),
+                                span { class: "ln", "{line}" }
+                                span { "{text}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed_lines_marks_edits_and_insertions() {
+        let old = "fn main() {\n    let a = 1;\n    show(&a);\n}";
+        let new = "fn main() {\n    let a = 2;\n    let b = a;\n    show(&a);\n}";
+        assert_eq!(changed_lines(old, new), vec![false, true, true, false, false]);
+    }
+
+    #[test]
+    fn test_changed_lines_without_a_previous_compile() {
+        assert_eq!(changed_lines("", "a\nb"), vec![false, false]);
+        assert_eq!(changed_lines("a\nb", "a\nb"), vec![false, false]);
+        assert_eq!(changed_lines("a\nb", ""), Vec::<bool>::new());
+    }
+}
@@ -12,6 +12,7 @@
 use dioxus::prelude::*;
 use crate::game::StreakStatus;
 use crate::ui::components::icon::{Icon, IconVariant, IconSize};
+use crate::ui::i18n::I18nState;
 
 const STREAK_STYLE: &str = r#"
 .streak-display {
@@ -87,18 +88,19 @@ const STREAK_STYLE: &str = r#"
 
 #[component]
 pub fn StreakDisplay(streak: u32, status: StreakStatus, freezes: u8) -> Element {
+    let i18n = use_context::<I18nState>();
     let (class, icon_variant, icon_color, text) = match status {
         StreakStatus::Active { days } => {
-            ("streak-display streak-active", IconVariant::Fire, "#f97316", format!("{} day streak", days))
+            ("streak-display streak-active", IconVariant::Fire, "#f97316", i18n.t_with("streak-active", &[("days", &days.to_string())]))
         }
         StreakStatus::AtRisk => {
-            ("streak-display streak-at-risk", IconVariant::Warning, "#f87171", "Streak at risk!".to_string())
+            ("streak-display streak-at-risk", IconVariant::Warning, "#f87171", i18n.t("streak-at-risk"))
         }
         StreakStatus::Frozen => {
-            ("streak-display streak-frozen", IconVariant::Shield, "#38bdf8", format!("{} days (frozen)", streak))
+            ("streak-display streak-frozen", IconVariant::Shield, "#38bdf8", i18n.t_with("streak-frozen", &[("days", &streak.to_string())]))
         }
         StreakStatus::Lost { was } => {
-            ("streak-display streak-lost", IconVariant::HeartBroken, "#9ca3af", format!("Lost {} day streak", was))
+            ("streak-display streak-lost", IconVariant::HeartBroken, "#9ca3af", i18n.t_with("streak-lost", &[("days", &was.to_string())]))
         }
     };
 
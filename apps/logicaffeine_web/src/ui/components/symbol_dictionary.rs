@@ -235,12 +235,24 @@ pub fn SymbolDictionary(props: SymbolDictionaryProps) -> Element {
                 span { "Symbol Dictionary" }
                 span {
                     class: "symbol-dict-toggle",
+                    role: "button",
+                    tabindex: "0",
+                    aria_expanded: "{!*is_collapsed.read()}",
+                    aria_label: if *is_collapsed.read() { "Show symbol dictionary" } else { "Hide symbol dictionary" },
                     onclick: move |e| {
                         e.stop_propagation();
                         let current = *is_collapsed.read();
                         is_collapsed.set(!current);
                     },
-                    "▼"
+                    onkeydown: move |e| {
+                        if e.key() == Key::Enter || e.key() == Key::Character(" ".to_string()) {
+                            e.prevent_default();
+                            e.stop_propagation();
+                            let current = *is_collapsed.read();
+                            is_collapsed.set(!current);
+                        }
+                    },
+                    span { aria_hidden: "true", "▼" }
                 }
             }
 
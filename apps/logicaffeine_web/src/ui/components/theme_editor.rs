@@ -0,0 +1,331 @@
+//! Custom theme editor with live preview.
+//!
+//! Edits a [`CustomTheme`] draft: surface colors, English token colors by
+//! [`TokenCategory`], code editor colors, and syntax-tree badge colors. The preview pane scopes the
+//! draft's custom properties to itself, so nothing changes page-wide until
+//! the learner applies it. Themes move in and out as JSON through the
+//! import/export box.
+//!
+//! # Usage
+//!
+//! ```no_run
+//! # use dioxus::prelude::*;
+//! use logicaffeine_web::ui::components::theme_editor::ThemeEditor;
+//!
+//! # fn Example() -> Element {
+//! rsx! {
+//!     ThemeEditor {}
+//! }
+//! # }
+//! ```
+
+use dioxus::prelude::*;
+use logicaffeine_compile::{compile_for_ui, TokenCategory};
+use crate::ui::components::code_editor::{CodeView, Language};
+use crate::ui::custom_theme::{
+    default_ast_color, default_code_color, default_token_color, token_css_name, CustomTheme, AST_NODE_TYPES,
+    CODE_TOKEN_KINDS,
+};
+use crate::ui::i18n::I18nState;
+use crate::ui::theme_state::ThemeState;
+
+const PREVIEW_SENTENCE: &str = "Every tall farmer who owns a donkey must not beat it.";
+
+const PREVIEW_CODE: &str = "## Main\n-- Count the herd\nLet herd: Seq of Int be [3, 5].\nIf length of herd > 1:\n    Show \"a herd\".";
+
+const THEME_EDITOR_STYLE: &str = r#"
+.theme-editor {
+    display: grid;
+    grid-template-columns: minmax(0, 1fr) minmax(0, 1fr);
+    gap: var(--spacing-xl);
+}
+
+.theme-editor-group h3 {
+    font-size: var(--font-caption-md);
+    color: var(--text-tertiary);
+    text-transform: uppercase;
+    letter-spacing: 0.5px;
+    margin: var(--spacing-lg) 0 var(--spacing-sm);
+}
+
+.theme-editor-row {
+    display: flex;
+    align-items: center;
+    justify-content: space-between;
+    gap: var(--spacing-sm);
+    padding: 4px 0;
+    color: var(--text-secondary);
+    font-size: var(--font-caption-md);
+}
+
+.theme-editor-row input[type="color"] {
+    width: 36px;
+    height: 24px;
+    border: none;
+    background: none;
+    cursor: pointer;
+}
+
+.theme-editor-name {
+    width: 100%;
+    padding: 8px 12px;
+    background: rgba(255, 255, 255, 0.04);
+    border: 1px solid rgba(255, 255, 255, 0.1);
+    border-radius: var(--radius-md);
+    color: var(--text-primary);
+}
+
+.theme-preview {
+    border-radius: var(--radius-lg);
+    padding: var(--spacing-lg);
+    background: linear-gradient(135deg, var(--bg-gradient-start), var(--bg-gradient-mid), var(--bg-gradient-end));
+    border: 1px solid var(--accent-border, rgba(255, 255, 255, 0.1));
+    color: var(--text-primary);
+}
+
+.theme-preview-title {
+    color: var(--accent-primary);
+    font-weight: 600;
+    margin-bottom: var(--spacing-sm);
+}
+
+.theme-preview-sentence {
+    font-family: var(--font-mono);
+    line-height: 1.8;
+    margin-bottom: var(--spacing-md);
+}
+
+.theme-preview-badges {
+    display: flex;
+    flex-wrap: wrap;
+    gap: 6px;
+}
+
+.theme-editor-actions {
+    display: flex;
+    flex-wrap: wrap;
+    gap: var(--spacing-sm);
+    margin-top: var(--spacing-lg);
+}
+
+.theme-editor-json {
+    width: 100%;
+    min-height: 160px;
+    margin-top: var(--spacing-md);
+    padding: var(--spacing-md);
+    background: rgba(0, 0, 0, 0.3);
+    border: 1px solid rgba(255, 255, 255, 0.1);
+    border-radius: var(--radius-md);
+    color: var(--text-secondary);
+    font-family: var(--font-mono);
+    font-size: 12px;
+}
+
+.theme-editor-error {
+    color: var(--color-error);
+    font-size: var(--font-caption-md);
+    margin-top: var(--spacing-sm);
+}
+
+@media (max-width: 768px) {
+    .theme-editor {
+        grid-template-columns: 1fr;
+    }
+}
+"#;
+
+/// `<input type="color">` only takes `#rrggbb`; widen `#rgb` and drop alpha.
+fn color_input_value(color: &str) -> String {
+    let hex = color.trim_start_matches('#');
+    match hex.len() {
+        3 => hex.chars().flat_map(|c| [c, c]).fold("#".to_string(), |mut s, c| {
+            s.push(c);
+            s
+        }),
+        8 => format!("#{}", &hex[..6]),
+        _ => color.to_string(),
+    }
+}
+
+#[component]
+pub fn ThemeEditor() -> Element {
+    let mut theme_state = use_context::<ThemeState>();
+    let i18n = use_context::<I18nState>();
+    let mut draft = use_signal(|| theme_state.custom().unwrap_or_else(CustomTheme::starter));
+    let mut json_text = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+    let sample_tokens = use_hook(|| compile_for_ui(PREVIEW_SENTENCE).tokens);
+
+    let preview_vars = draft.read().css_variables();
+    let surfaces: Vec<(&'static str, Option<String>)> = draft
+        .read()
+        .surfaces
+        .fields()
+        .into_iter()
+        .map(|(name, value)| (name, value.clone()))
+        .collect();
+
+    rsx! {
+        style { "{THEME_EDITOR_STYLE}" }
+        div { class: "theme-editor",
+            div {
+                input {
+                    class: "theme-editor-name",
+                    r#type: "text",
+                    aria_label: i18n.t("theme-editor-name"),
+                    value: "{draft.read().name}",
+                    oninput: move |e| draft.write().name = e.value(),
+                }
+
+                div { class: "theme-editor-group",
+                    h3 { {i18n.t("theme-editor-surfaces")} }
+                    for (field, value) in surfaces {
+                        label { class: "theme-editor-row", key: "{field}",
+                            span { "{field}" }
+                            input {
+                                r#type: "color",
+                                value: color_input_value(value.as_deref().unwrap_or("#000000")),
+                                oninput: move |e| {
+                                    if let Some(slot) = draft.write().surfaces.field_mut(field) {
+                                        *slot = Some(e.value());
+                                    }
+                                },
+                            }
+                        }
+                    }
+                }
+
+                div { class: "theme-editor-group",
+                    h3 { {i18n.t("theme-editor-tokens")} }
+                    for category in TokenCategory::ALL {
+                        {
+                            let color = draft.read().tokens.get(&category).cloned()
+                                .unwrap_or_else(|| default_token_color(category).to_string());
+                            let name = token_css_name(category);
+                            rsx! {
+                                label { class: "theme-editor-row", key: "{name}",
+                                    span { class: "token-{name}", "{category:?}" }
+                                    input {
+                                        r#type: "color",
+                                        value: color_input_value(&color),
+                                        oninput: move |e| {
+                                            draft.write().tokens.insert(category, e.value());
+                                        },
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "theme-editor-group",
+                    h3 { {i18n.t("theme-editor-code")} }
+                    for kind in CODE_TOKEN_KINDS {
+                        {
+                            let color = draft.read().code.get(kind).cloned()
+                                .unwrap_or_else(|| default_code_color(kind).to_string());
+                            rsx! {
+                                label { class: "theme-editor-row", key: "{kind}",
+                                    span { class: "tok-{kind}", "{kind}" }
+                                    input {
+                                        r#type: "color",
+                                        value: color_input_value(&color),
+                                        oninput: move |e| {
+                                            draft.write().code.insert(kind.to_string(), e.value());
+                                        },
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "theme-editor-group",
+                    h3 { {i18n.t("theme-editor-ast")} }
+                    for node_type in AST_NODE_TYPES {
+                        {
+                            let color = draft.read().ast.get(node_type).cloned()
+                                .unwrap_or_else(|| default_ast_color(node_type).to_string());
+                            rsx! {
+                                label { class: "theme-editor-row", key: "{node_type}",
+                                    span { "{node_type}" }
+                                    input {
+                                        r#type: "color",
+                                        value: color_input_value(&color),
+                                        oninput: move |e| {
+                                            draft.write().ast.insert(node_type.to_string(), e.value());
+                                        },
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div {
+                div { class: "theme-preview", style: "{preview_vars}", aria_label: i18n.t("theme-editor-preview"),
+                    div { class: "theme-preview-title", "{draft.read().name}" }
+                    div { class: "theme-preview-sentence",
+                        for (i, token) in sample_tokens.iter().enumerate() {
+                            span { key: "{i}", class: "token-{token_css_name(token.category)}", "{token.text} " }
+                        }
+                    }
+                    CodeView { code: PREVIEW_CODE.to_string(), language: Language::Logos }
+                    div { class: "theme-preview-badges",
+                        for node_type in AST_NODE_TYPES {
+                            span { key: "{node_type}", class: "ast-node-type {node_type}", "{node_type}" }
+                        }
+                    }
+                }
+
+                div { class: "theme-editor-actions",
+                    button {
+                        class: "btn-primary",
+                        onclick: move |_| {
+                            let theme = draft.read().clone();
+                            error.set(theme_state.set_custom(theme).err());
+                        },
+                        {i18n.t("theme-editor-apply")}
+                    }
+                    button {
+                        onclick: move |_| {
+                            theme_state.clear_custom();
+                            draft.set(CustomTheme::starter());
+                            error.set(None);
+                        },
+                        {i18n.t("theme-editor-reset")}
+                    }
+                    button {
+                        onclick: move |_| json_text.set(draft.read().to_json()),
+                        {i18n.t("theme-editor-export")}
+                    }
+                    button {
+                        onclick: move |_| {
+                            match CustomTheme::from_json(&json_text.read()) {
+                                Ok(theme) => {
+                                    draft.set(theme);
+                                    error.set(None);
+                                }
+                                Err(e) => error.set(Some(e)),
+                            }
+                        },
+                        {i18n.t("theme-editor-import")}
+                    }
+                }
+
+                textarea {
+                    class: "theme-editor-json",
+                    aria_label: i18n.t("theme-editor-json"),
+                    spellcheck: "false",
+                    value: "{json_text}",
+                    oninput: move |e| json_text.set(e.value()),
+                }
+
+                if let Some(message) = error() {
+                    div { class: "theme-editor-error", role: "alert", "{message}" }
+                }
+            }
+        }
+    }
+}
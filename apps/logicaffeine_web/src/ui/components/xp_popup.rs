@@ -16,6 +16,7 @@
 use dioxus::prelude::*;
 use crate::game::XpReward;
 use crate::audio::{SoundEffect, play_sound};
+use crate::ui::i18n::I18nState;
 
 const XP_POPUP_STYLE: &str = r#"
 .xp-popup {
@@ -85,6 +86,7 @@ const XP_POPUP_STYLE: &str = r#"
 
 #[component]
 pub fn XpPopup(reward: XpReward, on_dismiss: EventHandler<()>) -> Element {
+    let i18n = use_context::<I18nState>();
     use_effect(move || {
         if reward.is_critical {
             play_sound(SoundEffect::CriticalHit);
@@ -112,30 +114,30 @@ pub fn XpPopup(reward: XpReward, on_dismiss: EventHandler<()>) -> Element {
                 div { class: "{total_class}", "+{reward.total} XP" }
                 div { class: "xp-breakdown",
                     div { class: "xp-line",
-                        span { "Base" }
+                        span { {i18n.t("xp-base")} }
                         span { "+{reward.base}" }
                     }
                     if reward.combo_bonus > 0 {
                         div { class: "xp-line combo",
-                            span { "Combo Bonus" }
+                            span { {i18n.t("xp-combo-bonus")} }
                             span { "+{reward.combo_bonus}" }
                         }
                     }
                     if reward.streak_bonus > 0 {
                         div { class: "xp-line streak",
-                            span { "Streak Bonus" }
+                            span { {i18n.t("xp-streak-bonus")} }
                             span { "+{reward.streak_bonus}" }
                         }
                     }
                     if reward.first_try_bonus > 0 {
                         div { class: "xp-line first-try",
-                            span { "First Try" }
+                            span { {i18n.t("xp-first-try")} }
                             span { "+{reward.first_try_bonus}" }
                         }
                     }
                     if reward.critical_bonus > 0 {
                         div { class: "xp-line critical",
-                            span { "CRITICAL!" }
+                            span { {i18n.t("xp-critical")} }
                             span { "+{reward.critical_bonus}" }
                         }
                     }
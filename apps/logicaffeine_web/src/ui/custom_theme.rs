@@ -0,0 +1,397 @@
+//! User-defined themes.
+//!
+//! A custom theme is a JSON document layered over the active preset
+//! [`Theme`](crate::ui::theme_state::Theme):
+//!
+//! ```json
+//! {
+//!   "name": "Solarized",
+//!   "surfaces": { "accent_primary": "#268bd2", "text_primary": "#fdf6e3" },
+//!   "tokens": { "Quantifier": "#d33682", "Noun": "#859900" },
+//!   "code": { "keyword": "#cb4b16" },
+//!   "ast": { "predicate": "#b58900" }
+//! }
+//! ```
+//!
+//! - `surfaces` override the page's CSS custom properties
+//! - `tokens` color English tokens, keyed by [`TokenCategory`]
+//! - `code` color the code editor's tokens, keyed by kind ([`CODE_TOKEN_KINDS`])
+//! - `ast` color syntax-tree node badges, keyed by node type ([`AST_NODE_TYPES`])
+//!
+//! [`syntax_css`] is installed page-wide by the app, so the applied theme's
+//! colors reach the Studio editor and every code view, not just the preview.
+//!
+//! Every field is optional. Colors must be `#rgb`, `#rrggbb` or `#rrggbbaa`:
+//! they end up inside a `<style>` element, so nothing else is accepted.
+
+use logicaffeine_compile::TokenCategory;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Node types the syntax tree renders, as they appear in [`AstNode::node_type`](logicaffeine_compile::AstNode).
+pub const AST_NODE_TYPES: [&str; 8] = [
+    "quantifier",
+    "predicate",
+    "binary_op",
+    "unary_op",
+    "constant",
+    "variable",
+    "modal",
+    "lambda",
+];
+
+/// Token kinds the code editor highlights, as in its `tok-*` classes.
+pub const CODE_TOKEN_KINDS: [&str; 9] = [
+    "keyword",
+    "type",
+    "string",
+    "number",
+    "comment",
+    "operator",
+    "punctuation",
+    "identifier",
+    "builtin",
+];
+
+/// Page colors a theme can override. Unset fields keep the preset's value.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Surfaces {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background_mid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent_primary: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent_secondary: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_primary: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_secondary: Option<String>,
+}
+
+impl Surfaces {
+    /// Field names paired with their values, in display order.
+    pub fn fields(&self) -> [(&'static str, &Option<String>); 6] {
+        [
+            ("background", &self.background),
+            ("background_mid", &self.background_mid),
+            ("accent_primary", &self.accent_primary),
+            ("accent_secondary", &self.accent_secondary),
+            ("text_primary", &self.text_primary),
+            ("text_secondary", &self.text_secondary),
+        ]
+    }
+
+    /// The field called `name`, for editors that address fields by name.
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut Option<String>> {
+        match name {
+            "background" => Some(&mut self.background),
+            "background_mid" => Some(&mut self.background_mid),
+            "accent_primary" => Some(&mut self.accent_primary),
+            "accent_secondary" => Some(&mut self.accent_secondary),
+            "text_primary" => Some(&mut self.text_primary),
+            "text_secondary" => Some(&mut self.text_secondary),
+            _ => None,
+        }
+    }
+}
+
+/// A user-created theme.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomTheme {
+    pub name: String,
+    #[serde(default)]
+    pub surfaces: Surfaces,
+    #[serde(default)]
+    pub tokens: BTreeMap<TokenCategory, String>,
+    #[serde(default)]
+    pub code: BTreeMap<String, String>,
+    #[serde(default)]
+    pub ast: BTreeMap<String, String>,
+}
+
+impl CustomTheme {
+    /// A starting point for the editor: the default syntax colors, no surface overrides.
+    pub fn starter() -> Self {
+        let tokens = TokenCategory::ALL
+            .iter()
+            .map(|&c| (c, default_token_color(c).to_string()))
+            .collect();
+        let code = CODE_TOKEN_KINDS
+            .iter()
+            .map(|&k| (k.to_string(), default_code_color(k).to_string()))
+            .collect();
+        let ast = AST_NODE_TYPES
+            .iter()
+            .map(|&t| (t.to_string(), default_ast_color(t).to_string()))
+            .collect();
+        Self {
+            name: "My Theme".to_string(),
+            surfaces: Surfaces::default(),
+            tokens,
+            code,
+            ast,
+        }
+    }
+
+    /// Parses and validates an imported theme.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let theme: CustomTheme = serde_json::from_str(json).map_err(|e| format!("Invalid theme JSON: {e}"))?;
+        theme.validate()?;
+        Ok(theme)
+    }
+
+    /// Pretty JSON, for export.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Checks the name and every color.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Theme needs a name".to_string());
+        }
+        for (field, value) in self.surfaces.fields() {
+            if let Some(color) = value {
+                check_color(color).map_err(|e| format!("surfaces.{field}: {e}"))?;
+            }
+        }
+        for (category, color) in &self.tokens {
+            check_color(color).map_err(|e| format!("tokens.{category:?}: {e}"))?;
+        }
+        for (kind, color) in &self.code {
+            if !CODE_TOKEN_KINDS.contains(&kind.as_str()) {
+                return Err(format!("code.{kind}: unknown token kind (expected one of {})", CODE_TOKEN_KINDS.join(", ")));
+            }
+            check_color(color).map_err(|e| format!("code.{kind}: {e}"))?;
+        }
+        for (node_type, color) in &self.ast {
+            if !AST_NODE_TYPES.contains(&node_type.as_str()) {
+                return Err(format!("ast.{node_type}: unknown node type (expected one of {})", AST_NODE_TYPES.join(", ")));
+            }
+            check_color(color).map_err(|e| format!("ast.{node_type}: {e}"))?;
+        }
+        Ok(())
+    }
+
+    /// CSS custom property declarations for this theme, without a selector,
+    /// so they can go in `:root` or an element's `style` attribute.
+    /// Invalid colors are skipped.
+    pub fn css_variables(&self) -> String {
+        let mut vars = Vec::new();
+        let s = &self.surfaces;
+        if let Some(c) = &s.background {
+            declare(&mut vars, "bg-gradient-start", c);
+            declare(&mut vars, "bg-gradient-end", c);
+        }
+        if let Some(c) = s.background_mid.as_ref().or(s.background.as_ref()) {
+            declare(&mut vars, "bg-gradient-mid", c);
+        }
+        if let Some(c) = &s.text_primary {
+            declare(&mut vars, "text-primary", c);
+        }
+        if let Some(c) = &s.text_secondary {
+            declare(&mut vars, "text-secondary", c);
+        }
+        for (name, color) in [("accent-primary", &s.accent_primary), ("accent-secondary", &s.accent_secondary)] {
+            if let Some(c) = color {
+                declare(&mut vars, name, c);
+                if let Some((r, g, b)) = rgb(c) {
+                    vars.push(format!("--{name}-rgb: {r}, {g}, {b};"));
+                }
+            }
+        }
+        if let Some((r, g, b)) = s.accent_primary.as_deref().and_then(rgb) {
+            vars.push(format!("--accent-border: rgba({r}, {g}, {b}, 0.3);"));
+            vars.push(format!("--accent-glow: rgba({r}, {g}, {b}, 0.2);"));
+        }
+
+        for (category, color) in &self.tokens {
+            declare(&mut vars, &format!("token-{}", token_css_name(*category)), color);
+        }
+        for (kind, color) in &self.code {
+            if CODE_TOKEN_KINDS.contains(&kind.as_str()) {
+                declare(&mut vars, &format!("code-{kind}"), color);
+            }
+        }
+        for (node_type, color) in &self.ast {
+            if AST_NODE_TYPES.contains(&node_type.as_str()) {
+                declare(&mut vars, &format!("ast-{node_type}"), color);
+            }
+        }
+
+        vars.join("\n")
+    }
+
+    /// A stylesheet applying the theme to the whole page.
+    pub fn css(&self) -> String {
+        format!(":root {{\n{}\n}}", self.css_variables())
+    }
+}
+
+/// The suffix of a token's CSS class and custom property: `token-quantifier`.
+pub fn token_css_name(category: TokenCategory) -> &'static str {
+    match category {
+        TokenCategory::Quantifier => "quantifier",
+        TokenCategory::Noun => "noun",
+        TokenCategory::Verb => "verb",
+        TokenCategory::Adjective => "adjective",
+        TokenCategory::Connective => "connective",
+        TokenCategory::Determiner => "determiner",
+        TokenCategory::Preposition => "preposition",
+        TokenCategory::Pronoun => "pronoun",
+        TokenCategory::Modal => "modal",
+        TokenCategory::Punctuation => "punctuation",
+        TokenCategory::Proper => "proper",
+        TokenCategory::Other => "other",
+    }
+}
+
+/// Built-in token color, used when no custom theme sets one.
+pub fn default_token_color(category: TokenCategory) -> &'static str {
+    match category {
+        TokenCategory::Quantifier | TokenCategory::Connective => "#c678dd",
+        TokenCategory::Noun => "#98c379",
+        TokenCategory::Verb => "#61afef",
+        TokenCategory::Adjective => "#d19a66",
+        TokenCategory::Determiner | TokenCategory::Preposition => "#abb2bf",
+        TokenCategory::Pronoun => "#56b6c2",
+        TokenCategory::Modal => "#e06c75",
+        TokenCategory::Punctuation => "#5c6370",
+        TokenCategory::Proper => "#e5c07b",
+        TokenCategory::Other => "#e8eaed",
+    }
+}
+
+/// Built-in code editor color, used when no custom theme sets one.
+pub fn default_code_color(kind: &str) -> &'static str {
+    match kind {
+        "keyword" => "#c678dd",
+        "type" => "#e5c07b",
+        "string" => "#98c379",
+        "number" => "#d19a66",
+        "comment" => "#5c6370",
+        "operator" => "#56b6c2",
+        "punctuation" => "#abb2bf",
+        "builtin" => "#61afef",
+        _ => "#e8eaed",
+    }
+}
+
+/// Built-in AST badge color, used when no custom theme sets one.
+pub fn default_ast_color(node_type: &str) -> &'static str {
+    match node_type {
+        "quantifier" | "binary_op" => "#c678dd",
+        "predicate" => "#98c379",
+        "unary_op" | "lambda" => "#e06c75",
+        "constant" => "#e5c07b",
+        "variable" => "#61afef",
+        "modal" => "#56b6c2",
+        _ => "#888888",
+    }
+}
+
+/// Stylesheet for `.token-*` spans, the code editor's `.tok-*` spans and
+/// `.ast-node-type.*` badges, reading the theme's custom properties with the
+/// built-in colors as fallbacks.
+pub fn syntax_css() -> String {
+    let mut css = String::new();
+    for category in TokenCategory::ALL {
+        let name = token_css_name(category);
+        css.push_str(&format!(
+            ".token-{name} {{ color: var(--token-{name}, {}); }}\n",
+            default_token_color(category)
+        ));
+    }
+    for kind in CODE_TOKEN_KINDS {
+        css.push_str(&format!(".tok-{kind} {{ color: var(--code-{kind}, {}); }}\n", default_code_color(kind)));
+    }
+    for node_type in AST_NODE_TYPES {
+        css.push_str(&format!(
+            ".ast-node-type.{node_type} {{ color: var(--ast-{node_type}, {c}); background: color-mix(in srgb, var(--ast-{node_type}, {c}) 20%, transparent); }}\n",
+            c = default_ast_color(node_type)
+        ));
+    }
+    css
+}
+
+/// Appends `--name: color;`, skipping colors that fail [`check_color`].
+fn declare(vars: &mut Vec<String>, name: &str, color: &str) {
+    if check_color(color).is_ok() {
+        vars.push(format!("--{name}: {color};"));
+    }
+}
+
+fn check_color(color: &str) -> Result<(), String> {
+    let hex = color.strip_prefix('#').unwrap_or("");
+    if matches!(hex.len(), 3 | 6 | 8) && hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        Ok(())
+    } else {
+        Err(format!("`{color}` is not a hex color like #1e90ff"))
+    }
+}
+
+fn rgb(color: &str) -> Option<(u8, u8, u8)> {
+    check_color(color).ok()?;
+    let hex = &color[1..];
+    let channel = |i: usize, width: usize| {
+        let digits = &hex[i * width..i * width + width];
+        let value = u8::from_str_radix(digits, 16).ok()?;
+        Some(if width == 1 { value * 17 } else { value })
+    };
+    let width = if hex.len() == 3 { 1 } else { 2 };
+    Some((channel(0, width)?, channel(1, width)?, channel(2, width)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut theme = CustomTheme::starter();
+        theme.surfaces.accent_primary = Some("#268bd2".to_string());
+        let parsed = CustomTheme::from_json(&theme.to_json()).unwrap();
+        assert_eq!(parsed, theme);
+    }
+
+    #[test]
+    fn test_partial_theme_parses() {
+        let theme = CustomTheme::from_json(r##"{"name": "Dim", "tokens": {"Noun": "#aaa"}}"##).unwrap();
+        assert_eq!(theme.tokens[&TokenCategory::Noun], "#aaa");
+        assert!(theme.ast.is_empty());
+    }
+
+    #[test]
+    fn test_rejects_non_hex_colors() {
+        let err = CustomTheme::from_json(r#"{"name": "x", "tokens": {"Verb": "red; } body { display: none"}}"#).unwrap_err();
+        assert!(err.starts_with("tokens.Verb"), "{err}");
+        assert!(CustomTheme::from_json(r##"{"name": "x", "ast": {"sentence": "#fff"}}"##).is_err());
+        assert!(CustomTheme::from_json(r##"{"name": "x", "code": {"macro": "#fff"}}"##).is_err());
+        assert!(CustomTheme::from_json(r##"{"name": " "}"##).is_err());
+    }
+
+    #[test]
+    fn test_css_variables() {
+        let mut theme = CustomTheme::from_json(r##"{"name": "x", "ast": {"modal": "#123456"}, "code": {"keyword": "#abcdef"}}"##).unwrap();
+        theme.surfaces.accent_primary = Some("#f80".to_string());
+        let css = theme.css_variables();
+        assert!(css.contains("--accent-primary: #f80;"));
+        assert!(css.contains("--accent-primary-rgb: 255, 136, 0;"));
+        assert!(css.contains("--ast-modal: #123456;"));
+        assert!(css.contains("--code-keyword: #abcdef;"));
+        assert!(!css.contains("--text-primary"));
+    }
+
+    #[test]
+    fn test_syntax_css_covers_every_category() {
+        let css = syntax_css();
+        for category in TokenCategory::ALL {
+            assert!(css.contains(&format!(".token-{} ", token_css_name(category))));
+        }
+        for kind in CODE_TOKEN_KINDS {
+            assert!(css.contains(&format!(".tok-{kind} {{ color: var(--code-{kind}, ")));
+        }
+    }
+}
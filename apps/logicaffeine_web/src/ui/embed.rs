@@ -0,0 +1,281 @@
+//! Embeddable read-only example widget.
+//!
+//! `/embed?example=examples/code/fibonacci.logos` renders one LOGOS example with
+//! Run and Compile buttons and nothing else: no navigation, no footer, no file
+//! browser. It fills its frame, so course pages and blog posts can drop it into
+//! an `<iframe>`:
+//! - The source is a shipped Code example, or a shared `#code=…` snippet (see
+//!   [`crate::share`])
+//! - Run streams through [`interpret_for_ui`]; Compile shows the generated Rust
+//! - The code is read-only; "Open in Studio" hands it to the full editor
+//!
+//! # Usage
+//!
+//! ```no_run
+//! use logicaffeine_web::ui::embed::iframe_snippet;
+//!
+//! let html = iframe_snippet("/examples/code/fibonacci.logos");
+//! assert!(html.starts_with("<iframe"));
+//! ```
+
+use dioxus::prelude::*;
+use logicaffeine_compile::{generate_rust_code, interpret_for_ui};
+use crate::share::ShareLink;
+use crate::ui::components::code_editor::{CodeView, Language};
+use crate::ui::examples::{ExampleSpec, ALL_CODE_EXAMPLES};
+use crate::ui::router::studio_file_url;
+
+const SITE_URL: &str = "https://logicaffeine.com";
+
+const EMBED_STYLE: &str = r#"
+.embed {
+    display: flex;
+    flex-direction: column;
+    height: 100vh;
+    min-height: 240px;
+    background: #0f1318;
+    color: #e5e7eb;
+    font-family: var(--font-sans, system-ui, sans-serif);
+    overflow: hidden;
+}
+
+.embed-toolbar {
+    display: flex;
+    align-items: center;
+    gap: 8px;
+    padding: 6px 10px;
+    border-bottom: 1px solid rgba(255, 255, 255, 0.08);
+    flex-shrink: 0;
+}
+
+.embed-title {
+    flex: 1;
+    font-size: 12px;
+    color: rgba(229, 231, 235, 0.6);
+    overflow: hidden;
+    text-overflow: ellipsis;
+    white-space: nowrap;
+}
+
+.embed-btn {
+    padding: 4px 12px;
+    border: none;
+    border-radius: 6px;
+    background: linear-gradient(135deg, #667eea 0%, #764ba2 100%);
+    color: white;
+    font-size: 12px;
+    font-weight: 600;
+    cursor: pointer;
+}
+
+.embed-btn.secondary {
+    background: rgba(255, 255, 255, 0.08);
+}
+
+.embed-btn:disabled {
+    opacity: 0.5;
+    cursor: default;
+}
+
+.embed-open {
+    font-size: 12px;
+    color: #667eea;
+    text-decoration: none;
+}
+
+.embed-code {
+    flex: 1;
+    min-height: 0;
+    overflow: auto;
+}
+
+.embed-output {
+    flex-shrink: 0;
+    max-height: 40%;
+    overflow: auto;
+    padding: 8px 12px;
+    border-top: 1px solid rgba(255, 255, 255, 0.08);
+    font-family: var(--font-mono, monospace);
+    font-size: 13px;
+    white-space: pre-wrap;
+}
+
+.embed-output .error {
+    color: #f87171;
+}
+
+.embed-empty {
+    margin: auto;
+    color: rgba(229, 231, 235, 0.6);
+    font-size: 14px;
+}
+"#;
+
+/// The shipped Code example at `path`, with or without its leading slash.
+pub fn find_example(path: &str) -> Option<&'static ExampleSpec> {
+    let path = format!("/{}", path.trim_start_matches('/'));
+    ALL_CODE_EXAMPLES.iter().find(|spec| spec.vfs_path == path)
+}
+
+/// The `/embed` URL for an example.
+pub fn embed_url(path: &str) -> String {
+    format!("{SITE_URL}/embed?example={}", path.trim_start_matches('/'))
+}
+
+/// Ready-to-paste `<iframe>` markup for an example.
+pub fn iframe_snippet(path: &str) -> String {
+    let title = path.rsplit('/').next().unwrap_or(path);
+    format!(
+        r#"<iframe src="{}" title="LOGOS example: {title}" width="100%" height="360" style="border:0;border-radius:8px" loading="lazy"></iframe>"#,
+        embed_url(path)
+    )
+}
+
+/// The share link in the page's URL fragment, if any.
+fn location_share_link() -> Option<ShareLink> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let hash = web_sys::window()?.location().hash().ok()?;
+        ShareLink::parse(&hash)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// What the output area is showing.
+#[derive(Clone, PartialEq)]
+enum EmbedOutput {
+    Idle,
+    Running,
+    Ran { lines: Vec<String>, error: Option<String> },
+    Rust(Result<String, String>),
+}
+
+#[component]
+pub fn Embed(example: Option<String>) -> Element {
+    let spec = example.as_deref().and_then(find_example);
+    let mut source = use_signal(|| spec.map(|s| (s.vfs_path.to_string(), s.source.to_string())));
+    let mut output = use_signal(|| EmbedOutput::Idle);
+
+    // A shared snippet in the fragment stands in when no example is named
+    use_effect(move || {
+        if source.peek().is_some() {
+            return;
+        }
+        if let Some(link) = location_share_link() {
+            spawn(async move {
+                if let Ok(snippet) = link.resolve().await {
+                    if let Some(file) = snippet.active_file() {
+                        source.set(Some((file.name.clone(), file.content.clone())));
+                    }
+                }
+            });
+        }
+    });
+
+    let Some((path, code)) = source() else {
+        return rsx! {
+            style { "{EMBED_STYLE}" }
+            div { class: "embed",
+                div { class: "embed-empty", "No LOGOS example to show." }
+            }
+        };
+    };
+
+    let title = path.rsplit('/').next().unwrap_or(&path).to_string();
+    let studio_href = if spec.is_some() { studio_file_url(&path) } else { "/studio".to_string() };
+    let running = output() == EmbedOutput::Running;
+
+    let run_code = code.clone();
+    let compile_code = code.clone();
+
+    rsx! {
+        style { "{EMBED_STYLE}" }
+        div { class: "embed",
+            div { class: "embed-toolbar",
+                span { class: "embed-title", "{title}" }
+                button {
+                    class: "embed-btn",
+                    disabled: running,
+                    onclick: move |_| {
+                        let code = run_code.clone();
+                        output.set(EmbedOutput::Running);
+                        spawn(async move {
+                            let result = interpret_for_ui(&code).await;
+                            output.set(EmbedOutput::Ran { lines: result.lines, error: result.error });
+                        });
+                    },
+                    "\u{25B6} Run"
+                }
+                button {
+                    class: "embed-btn secondary",
+                    disabled: running,
+                    onclick: move |_| {
+                        let rust = generate_rust_code(&compile_code).map_err(|e| format!("Compile error: {e:?}"));
+                        output.set(EmbedOutput::Rust(rust));
+                    },
+                    "Compile"
+                }
+                a {
+                    class: "embed-open",
+                    href: "{studio_href}",
+                    target: "_blank",
+                    rel: "noopener",
+                    "Open in Studio \u{2197}"
+                }
+            }
+            div { class: "embed-code",
+                CodeView { code: code.clone(), language: Language::Logos }
+            }
+            match output() {
+                EmbedOutput::Idle => rsx! {},
+                EmbedOutput::Running => rsx! {
+                    div { class: "embed-output", role: "status", "Running\u{2026}" }
+                },
+                EmbedOutput::Ran { lines, error } => rsx! {
+                    div { class: "embed-output", role: "log", aria_live: "polite",
+                        for (i, line) in lines.iter().enumerate() {
+                            div { key: "{i}", "{line}" }
+                        }
+                        if let Some(error) = error {
+                            div { class: "error", role: "alert", "{error}" }
+                        }
+                    }
+                },
+                EmbedOutput::Rust(Ok(rust)) => rsx! {
+                    div { class: "embed-output",
+                        CodeView { code: rust, language: Language::Rust }
+                    }
+                },
+                EmbedOutput::Rust(Err(error)) => rsx! {
+                    div { class: "embed-output",
+                        div { class: "error", role: "alert", "{error}" }
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_example_with_or_without_slash() {
+        let spec = find_example("examples/code/fibonacci.logos").expect("fibonacci ships");
+        assert_eq!(spec.vfs_path, "/examples/code/fibonacci.logos");
+        assert!(find_example("/examples/code/fibonacci.logos").is_some());
+        assert!(find_example("examples/logic/simple-sentences.logic").is_none());
+        assert!(find_example("../../etc/passwd").is_none());
+    }
+
+    #[test]
+    fn test_iframe_snippet_points_at_embed_route() {
+        let html = iframe_snippet("/examples/code/fibonacci.logos");
+        assert!(html.contains(r#"src="https://logicaffeine.com/embed?example=examples/code/fibonacci.logos""#));
+        assert!(html.contains(r#"title="LOGOS example: fibonacci.logos""#));
+    }
+}
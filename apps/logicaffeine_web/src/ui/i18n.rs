@@ -0,0 +1,325 @@
+//! Internationalized UI strings.
+//!
+//! UI text lives in Fluent-style message catalogs, one per locale, under
+//! `src/ui/locales/`:
+//!
+//! ```text
+//! # Comments start with a hash
+//! nav-learn = Learn Logic
+//! lesson-exercise-label = Exercise { $current } of { $total }
+//! ```
+//!
+//! - A message is `id = value`; indented lines continue the value on a new line
+//! - `{ $name }` is a placeable, filled from the arguments at lookup
+//! - A message missing from a catalog falls back to English, then to its ID,
+//!   so a half-translated catalog never shows a blank
+//! - [`I18nState`] holds the active [`Locale`], persisted via
+//!   [`storage`](crate::storage); the app root sets `lang` and `dir` from it,
+//!   so right-to-left locales mirror the layout
+//!
+//! # Usage
+//!
+//! ```no_run
+//! # use dioxus::prelude::*;
+//! use logicaffeine_web::ui::i18n::I18nState;
+//!
+//! # fn Example() -> Element {
+//! let i18n = use_context::<I18nState>();
+//! rsx! {
+//!     h2 { {i18n.t("profile-accessibility")} }
+//!     p { {i18n.t_with("lesson-exercise-label", &[("current", "3"), ("total", "10")])} }
+//! }
+//! # }
+//! ```
+//!
+//! # Scope
+//!
+//! The catalogs cover the site chrome and the learner loop, and nothing else:
+//! the navigation bar and footer, the Learn page and its exercise panel,
+//! lessons, review, exams, the profile page, the theme editor, and the XP,
+//! streak and achievement popups. Text there must come from a catalog.
+//!
+//! Everything else is deliberately English-only and out of scope for the
+//! catalogs, not partly migrated: the landing, pricing, news, guide,
+//! benchmarks, roadmap, success, privacy and terms pages (long-form copy
+//! and legal text, which need translating as documents, not as UI
+//! messages); the developer tools (Studio, notebook, workspace, registry
+//! and crates pages); `MainNav` subtitles and SEO breadcrumbs (both
+//! `&'static str`); the curriculum's era and module titles; AST diff
+//! labels; and struggle messages. Bringing any of them in is new work.
+
+use dioxus::prelude::*;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// A supported UI language.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+pub enum Locale {
+    #[default]
+    En,
+    Es,
+    Ar,
+}
+
+impl Locale {
+    /// BCP 47 language tag, used for the `lang` attribute and in storage.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+            Locale::Ar => "ar",
+        }
+    }
+
+    /// The language's name in that language, for the switcher.
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Es => "Español",
+            Locale::Ar => "العربية",
+        }
+    }
+
+    /// Text direction, for the `dir` attribute.
+    pub fn dir(&self) -> &'static str {
+        match self {
+            Locale::Ar => "rtl",
+            Locale::En | Locale::Es => "ltr",
+        }
+    }
+
+    /// Parses a language tag, ignoring any region (`es-MX` is Spanish).
+    pub fn from_code(code: &str) -> Option<Locale> {
+        let language = code.split(['-', '_']).next().unwrap_or("").to_ascii_lowercase();
+        Locale::all().find(|l| l.code() == language)
+    }
+
+    /// Returns an iterator over all locales.
+    pub fn all() -> impl Iterator<Item = Locale> {
+        [Locale::En, Locale::Es, Locale::Ar].into_iter()
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Locale::En => include_str!("locales/en.ftl"),
+            Locale::Es => include_str!("locales/es.ftl"),
+            Locale::Ar => include_str!("locales/ar.ftl"),
+        }
+    }
+}
+
+/// A parsed message catalog.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Catalog {
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    /// Parses catalog source. Errors name the offending line.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut messages = HashMap::new();
+        let mut current: Option<String> = None;
+
+        for (n, line) in source.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            if line.starts_with([' ', '\t']) {
+                let id = current
+                    .as_ref()
+                    .ok_or_else(|| format!("line {}: continuation without a message", n + 1))?;
+                let value: &mut String = messages.get_mut(id).expect("current message was inserted");
+                if !value.is_empty() {
+                    value.push('\n');
+                }
+                value.push_str(trimmed);
+                continue;
+            }
+            let (id, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `id = value`", n + 1))?;
+            let id = id.trim();
+            let valid_id = id.starts_with(|c: char| c.is_ascii_alphabetic())
+                && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+            if !valid_id {
+                return Err(format!("line {}: invalid message id `{id}`", n + 1));
+            }
+            if messages.insert(id.to_string(), value.trim().to_string()).is_some() {
+                return Err(format!("line {}: duplicate message `{id}`", n + 1));
+            }
+            current = Some(id.to_string());
+        }
+
+        Ok(Self { messages })
+    }
+
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.messages.get(id).map(String::as_str)
+    }
+
+    /// Message IDs, in no particular order.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.messages.keys().map(String::as_str)
+    }
+}
+
+/// The built-in catalog for `locale`, parsed on first use.
+pub fn catalog(locale: Locale) -> &'static Catalog {
+    static CATALOGS: OnceLock<HashMap<Locale, Catalog>> = OnceLock::new();
+    let catalogs = CATALOGS.get_or_init(|| {
+        Locale::all()
+            .map(|l| (l, Catalog::parse(l.source()).unwrap_or_default()))
+            .collect()
+    });
+    &catalogs[&locale]
+}
+
+/// Looks up `id` for `locale` and fills its placeables from `args`.
+pub fn translate(locale: Locale, id: &str, args: &[(&str, &str)]) -> String {
+    let pattern = catalog(locale)
+        .get(id)
+        .or_else(|| catalog(Locale::En).get(id))
+        .unwrap_or(id);
+    format_pattern(pattern, args)
+}
+
+/// Replaces each `{ $name }` with its argument; unknown names are left as written.
+fn format_pattern(pattern: &str, args: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find('}') else {
+            out.push_str(&rest[start..]);
+            return out;
+        };
+        let placeable = &rest[start..start + len + 1];
+        let name = placeable[1..placeable.len() - 1].trim().trim_start_matches('$');
+        match args.iter().find(|(key, _)| *key == name) {
+            Some((_, value)) => out.push_str(value),
+            None => out.push_str(placeable),
+        }
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Global locale state with persistence.
+#[derive(Clone, Copy)]
+pub struct I18nState {
+    locale: Signal<Locale>,
+}
+
+impl I18nState {
+    /// Creates a new I18nState from the stored choice, else the browser's
+    /// language. Server renders always use English.
+    pub fn new() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        let initial = crate::storage::load_locale_raw()
+            .and_then(|code| Locale::from_code(&code))
+            .or_else(|| {
+                web_sys::window()
+                    .and_then(|w| w.navigator().language())
+                    .and_then(|tag| Locale::from_code(&tag))
+            })
+            .unwrap_or_default();
+        #[cfg(not(target_arch = "wasm32"))]
+        let initial = Locale::default();
+
+        Self {
+            locale: Signal::new(initial),
+        }
+    }
+
+    /// Returns the active locale.
+    pub fn locale(&self) -> Locale {
+        *self.locale.read()
+    }
+
+    /// Sets the locale and persists it.
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.locale.set(locale);
+        #[cfg(target_arch = "wasm32")]
+        crate::storage::save_locale_raw(locale.code());
+    }
+
+    /// The message `id` in the active locale.
+    pub fn t(&self, id: &str) -> String {
+        translate(self.locale(), id, &[])
+    }
+
+    /// The message `id` in the active locale, with its placeables filled.
+    pub fn t_with(&self, id: &str, args: &[(&str, &str)]) -> String {
+        translate(self.locale(), id, args)
+    }
+}
+
+impl Default for I18nState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_catalogs_parse() {
+        for locale in Locale::all() {
+            if let Err(e) = Catalog::parse(locale.source()) {
+                panic!("{} catalog: {e}", locale.code());
+            }
+        }
+    }
+
+    #[test]
+    fn test_catalogs_cover_english() {
+        let english = catalog(Locale::En);
+        for locale in Locale::all() {
+            let mut missing: Vec<&str> = english.ids().filter(|id| catalog(locale).get(id).is_none()).collect();
+            missing.sort();
+            assert!(missing.is_empty(), "{} catalog is missing {missing:?}", locale.code());
+        }
+    }
+
+    #[test]
+    fn test_parse_multiline_and_comments() {
+        let catalog = Catalog::parse("# greeting\nhello = Hello,\n    world\n\nbye = Bye").unwrap();
+        assert_eq!(catalog.get("hello"), Some("Hello,\nworld"));
+        assert_eq!(catalog.get("bye"), Some("Bye"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Catalog::parse("no equals sign").is_err());
+        assert!(Catalog::parse("a = 1\na = 2").is_err());
+        assert!(Catalog::parse("  orphan").is_err());
+        assert!(Catalog::parse("9lives = x").is_err());
+    }
+
+    #[test]
+    fn test_placeables() {
+        assert_eq!(
+            format_pattern("Exercise { $current } of {$total}", &[("current", "3"), ("total", "10")]),
+            "Exercise 3 of 10"
+        );
+        assert_eq!(format_pattern("Hi { $name }", &[]), "Hi { $name }");
+    }
+
+    #[test]
+    fn test_unknown_message_shows_its_id() {
+        assert_eq!(translate(Locale::Ar, "no-such-message", &[]), "no-such-message");
+        assert_eq!(translate(Locale::Es, "lesson-exercise-label", &[("current", "2"), ("total", "5")]), "Ejercicio 2 de 5");
+    }
+
+    #[test]
+    fn test_locale_codes() {
+        assert_eq!(Locale::from_code("es-MX"), Some(Locale::Es));
+        assert_eq!(Locale::from_code("AR"), Some(Locale::Ar));
+        assert_eq!(Locale::from_code("fr"), None);
+        assert_eq!(Locale::Ar.dir(), "rtl");
+    }
+}
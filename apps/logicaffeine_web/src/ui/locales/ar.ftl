@@ -0,0 +1,209 @@
+# العربية — a right-to-left locale; the app root sets dir="rtl" for it.
+
+## التنقل
+nav-tagline = صحّح أفكارك.
+nav-guide = دليل الصياغة
+nav-crates = الحزم
+nav-learn = تعلّم المنطق
+nav-studio = الاستوديو
+nav-roadmap = خارطة الطريق
+nav-contact = تواصل معنا
+nav-news = الأخبار
+nav-benchmarks = المقاييس
+nav-profile = الملف الشخصي
+nav-github = GitHub
+nav-github-title = عرض على GitHub
+nav-profile-title = ملفك الشخصي
+nav-drawer-title = التنقل
+nav-open-menu = فتح القائمة
+nav-close-menu = إغلاق القائمة
+
+## التذييل
+footer-theme = السمة:
+footer-language = اللغة:
+footer-privacy = الخصوصية
+footer-terms = الشروط
+footer-privacy-policy = سياسة الخصوصية
+footer-terms-of-service = شروط الخدمة
+footer-rights = © { $year } Brahmastra Labs. جميع الحقوق محفوظة.
+
+## الدرس
+lesson-exercise-label = التمرين { $current } من { $total }
+lesson-answer-placeholder = اكتب إجابتك بمنطق الرتبة الأولى...
+lesson-answer-label = إجابتك بمنطق الرتبة الأولى
+lesson-choices-label = خيارات الإجابة
+lesson-show-hint = أظهر تلميحًا
+lesson-check = تحقّق من الإجابة
+lesson-next = المسألة التالية
+lesson-complete-module = أكمل الوحدة
+lesson-correct = صحيح!
+lesson-not-quite = ليس تمامًا.
+lesson-good-analysis = تحليل جيد!
+lesson-explanation = الشرح:
+lesson-loading = جارٍ تحميل التمارين...
+
+## التعلّم
+learn-badge = منهج تفاعلي
+learn-title = تعلّم المنطق
+learn-lead = أتقن منطق الرتبة الأولى عبر تحديات متدرّجة. ابدأ بالأساسيات وتقدّم حتى الاستدلال المتقدّم.
+learn-exam-link = خُض اختبارًا موقوتًا
+learn-exercise-count = { $count } تمارين
+learn-try-example = جرّب مثالًا
+learn-example = مثال
+learn-start = ابدأ التعلّم
+learn-daily-goal = الهدف اليومي
+learn-today = اليوم
+learn-streak-freezes = { $count } من حُماة السلسلة
+learn-vacation-title = أوقف سلسلتك ومراجعاتك مؤقتًا أثناء غيابك
+learn-vacation-start = ابدأ الإجازة
+learn-vacation-end = أنهِ الإجازة
+learn-tab-lesson = الدرس
+learn-tab-examples = أمثلة
+learn-tab-practice = تدريب
+learn-tab-test = اختبار
+learn-try-examples = جرّب الأمثلة
+learn-lesson-coming-soon = محتوى الدرس قادم قريبًا. انقر «أمثلة» لترى عروضًا تفاعلية.
+learn-examples-title = أمثلة تفاعلية
+learn-examples-lead = جرّب ترجمة الجمل وشاهد كيف تعمل الرموز المنطقية. يشرح قاموس الرموز كل رمز مستخدم.
+learn-try-it = جرّبه
+learn-start-practice = ابدأ التدريب
+learn-stat-streak = سلسلة
+learn-stat-correct = صحيحة
+learn-test-complete = اكتمل الاختبار!
+learn-test-grade = التقدير: { $grade } ({ $percent }٪)
+learn-back-to-practice = العودة إلى التدريب
+learn-retake-test = أعد الاختبار
+learn-test-mode = وضع الاختبار
+learn-question-label = السؤال { $current } من { $total }
+learn-correct-count = { $count } صحيحة
+learn-answer-placeholder = اكتب ترجمتك المنطقية... (Enter للإرسال)
+learn-check = تحقّق
+learn-correct-no-stakes = صحيح! (تدريب بلا رهان)
+learn-correct-revealed = صحيح! (بلا نقاط: كُشفت الإجابة)
+learn-correct-no-xp = صحيح! (بلا نقاط: محاولات كثيرة)
+learn-correct-completed = صحيح! (مكتمل سابقًا)
+learn-correct-xp = صحيح! +{ $xp } XP
+learn-correct-combo = صحيح! +{ $xp } XP (تتابع ×{ $multiplier })
+learn-penalty = (-5 XP، يتبقّى { $remaining })
+learn-penalty-none = (لم تتبقَّ نقاط)
+learn-reading = القراءة { $number }
+learn-socrates-says = سقراط يقول...
+learn-no-stakes = تدريب بلا رهان
+learn-no-stakes-on = تدريب بلا رهان: مفعّل
+learn-show-solution = أظهر الحل
+learn-show-answer = أظهر الإجابة (بلا نقاط)
+learn-symbol-dictionary = قاموس الرموز
+learn-hint = تلميح
+learn-no-hint = لا يوجد تلميح لهذا التمرين.
+learn-correct-answer = الإجابة الصحيحة
+learn-walkthrough = كيف تُشتق من الجملة
+learn-no-logic = لا يوجد ناتج منطقي لتحليله.
+learn-submit-answer = أرسل الإجابة
+learn-exit-test = اخرج من الاختبار
+learn-skip = تخطَّ
+learn-next-exercise = التمرين التالي
+learn-take-test = خُض الاختبار
+learn-no-exercises = لا توجد تمارين لهذه الوحدة بعد.
+learn-total-loaded = المحمَّل: { $count }
+
+## المراجعة
+review-title = المراجعة اليومية
+review-count = { $current } من { $total }
+review-caught-up = لا شيء متبقٍّ!
+review-nothing-due = لا توجد تمارين مستحقة للمراجعة الآن.
+review-back-home = العودة إلى الرئيسية →
+review-complete = اكتملت المراجعة!
+review-complete-count = راجعت { $count } عناصر.
+review-answer-placeholder = اكتب إجابتك...
+review-hard = صعب
+review-good = جيد
+review-again = مجددًا
+review-easy = سهل
+review-loading = جارٍ التحميل...
+
+## الاختبار
+exam-title = محاكاة الاختبار
+exam-lead = اختبار موقوت في الوحدات التي تختارها. لا تلميحات ولا تصحيح حتى النهاية؛ يقيّمك التقرير حسب المفهوم ولا يُحتسب ضمن نقاطك.
+exam-questions = الأسئلة
+exam-minutes = { $minutes } دقيقة
+exam-start = ابدأ الاختبار
+exam-lexicon-failed = تعذّر تحميل بنك الكلمات: { $error }
+exam-no-questions = لا تحتوي أي من الوحدات المختارة على أسئلة يمكن للاختبار استخدامها.
+exam-progress = السؤال { $current } من { $total } · أُجيب عن { $answered }
+exam-readings-placeholder = قراءة واحدة في كل سطر
+exam-answer-placeholder = إجابتك
+exam-previous = السابق
+exam-finish = أنهِ الاختبار
+exam-next = التالي
+exam-report = تقرير الاختبار
+exam-summary = { $correct } من { $questions } صحيحة في { $time } (الحد { $limit })
+exam-new = اختبار جديد
+exam-copy-report = انسخ التقرير بصيغة JSON
+exam-report-copied = نُسخ التقرير بصيغة JSON
+exam-concept = المفهوم
+exam-correct = الصحيحة
+exam-score = النتيجة
+exam-past = الاختبارات السابقة
+exam-date = التاريخ
+exam-modules = الوحدات
+exam-time = الوقت
+
+## المكافآت
+xp-base = الأساس
+xp-combo-bonus = مكافأة التتابع
+xp-streak-bonus = مكافأة السلسلة
+xp-first-try = من المحاولة الأولى
+xp-critical = ضربة حاسمة!
+streak-active = سلسلة { $days } يوم
+streak-at-risk = السلسلة في خطر!
+streak-frozen = { $days } يوم (مجمّدة)
+streak-lost = ضاعت سلسلة { $days } يوم
+achievement-unlocked = فُتح إنجاز
+achievement-title-unlocked = فُتح لقب: { $title }
+achievement-streak-freeze = +1 حامٍ للسلسلة!
+achievement-continue = متابعة
+
+## الملف الشخصي
+profile-accessibility = إمكانية الوصول
+profile-high-contrast = ألوان عالية التباين
+profile-mathml = اعرض الصيغ لقارئات الشاشة بصيغة MathML بدلًا من النص المنطوق
+profile-language = اللغة
+profile-sound = الصوت
+profile-sound-theme = سمة الصوت
+profile-sound-master = الرئيسي
+profile-sound-effects = المؤثرات
+profile-sound-music = الموسيقى
+profile-sound-mute = كتم جميع الأصوات
+profile-sound-music-on = تشغيل موسيقى الخلفية
+profile-sound-test = تجربة الصوت
+profile-usage-data = بيانات الاستخدام
+profile-telemetry-opt-in = شارك إحصاءات استخدام مجهولة الهوية للمساعدة في تحسين الدروس
+profile-telemetry-explain = هذا كل ما سيُرسل. لا شيء يغادر متصفحك ما لم تحدد المربع.
+profile-telemetry-send = أرسل الآن
+profile-experiments-explain = دروسك جزء من بعض التجارب التي تقارن أساليب التلميح ومكافآت نقاط الخبرة وجداول المراجعة. يبقى سجلها في هذا المتصفح؛ انسخه لإرساله.
+profile-experiments-copy = انسخ سجل التجارب
+profile-experiments-clear = امسح سجل التجارب
+profile-experiments-copied = نُسخ سجل التجارب بصيغة JSON
+profile-experiments-cleared = مُسح سجل التجارب
+profile-anki-explain = انقل بطاقاتك إلى Anki: انسخ رزمة بكل تمرين درسته، أو الصق سجل مراجعات مُصدَّرًا من Anki (معرّف التمرين والتاريخ وزر الإجابة، مفصولة بعلامات جدولة) لإعادة تطبيقه هنا.
+profile-anki-copy = انسخ رزمة Anki
+profile-anki-copied = نُسخت رزمة Anki من { $count } بطاقة
+profile-anki-export-failed = تعذّر إنشاء رزمة Anki: { $error }
+profile-anki-placeholder = معرّف-التمرين<TAB>YYYY-MM-DD<TAB>1-4
+profile-anki-import = استورد سجل المراجعات
+profile-anki-imported = أُعيد تطبيق { $count } مراجعة
+profile-anki-import-failed = لم يُستورد سجل المراجعات: { $error }
+
+## محرر السمات
+theme-editor-title = سمة مخصصة
+theme-editor-name = اسم السمة
+theme-editor-surfaces = الأسطح
+theme-editor-tokens = كلمات الجملة
+theme-editor-code = الشيفرة
+theme-editor-ast = شجرة الصياغة
+theme-editor-preview = معاينة السمة
+theme-editor-apply = تطبيق
+theme-editor-reset = العودة إلى السمة الجاهزة
+theme-editor-export = تصدير JSON
+theme-editor-import = استيراد JSON
+theme-editor-json = JSON السمة
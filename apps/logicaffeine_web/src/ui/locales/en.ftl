@@ -0,0 +1,209 @@
+# English — the reference catalog. Every other catalog carries these IDs.
+
+## Navigation
+nav-tagline = Debug your thoughts.
+nav-guide = Syntax Guide
+nav-crates = Crates
+nav-learn = Learn Logic
+nav-studio = Studio
+nav-roadmap = Roadmap
+nav-contact = Contact
+nav-news = News
+nav-benchmarks = Benchmarks
+nav-profile = Profile
+nav-github = GitHub
+nav-github-title = View on GitHub
+nav-profile-title = Your Profile
+nav-drawer-title = Navigation
+nav-open-menu = Open menu
+nav-close-menu = Close menu
+
+## Footer
+footer-theme = Theme:
+footer-language = Language:
+footer-privacy = Privacy
+footer-terms = Terms
+footer-privacy-policy = Privacy Policy
+footer-terms-of-service = Terms of Service
+footer-rights = © { $year } Brahmastra Labs. All rights reserved.
+
+## Lesson
+lesson-exercise-label = Exercise { $current } of { $total }
+lesson-answer-placeholder = Enter your answer in FOL...
+lesson-answer-label = Your answer in first-order logic
+lesson-choices-label = Answer choices
+lesson-show-hint = Show Hint
+lesson-check = Check Answer
+lesson-next = Next Problem
+lesson-complete-module = Complete Module
+lesson-correct = Correct!
+lesson-not-quite = Not quite.
+lesson-good-analysis = Good analysis!
+lesson-explanation = Explanation:
+lesson-loading = Loading exercises...
+
+## Learn
+learn-badge = Interactive Curriculum
+learn-title = Learn Logic
+learn-lead = Master first-order logic through progressive challenges. Start with the basics and work your way up to advanced reasoning.
+learn-exam-link = Take a timed exam
+learn-exercise-count = { $count } exercises
+learn-try-example = Try an Example
+learn-example = Example
+learn-start = Start Learning
+learn-daily-goal = Daily goal
+learn-today = Today
+learn-streak-freezes = { $count } streak freezes
+learn-vacation-title = Pause your streak and reviews while you are away
+learn-vacation-start = Start vacation
+learn-vacation-end = End vacation
+learn-tab-lesson = Lesson
+learn-tab-examples = Examples
+learn-tab-practice = Practice
+learn-tab-test = Test
+learn-try-examples = Try Examples
+learn-lesson-coming-soon = Lesson content coming soon. Click Examples to see interactive demos.
+learn-examples-title = Interactive Examples
+learn-examples-lead = Try translating sentences and see how logic notation works. The symbol dictionary will explain each symbol used.
+learn-try-it = Try it
+learn-start-practice = Start Practice
+learn-stat-streak = streak
+learn-stat-correct = correct
+learn-test-complete = Test Complete!
+learn-test-grade = Grade: { $grade } ({ $percent }%)
+learn-back-to-practice = Back to Practice
+learn-retake-test = Retake Test
+learn-test-mode = TEST MODE
+learn-question-label = Question { $current } of { $total }
+learn-correct-count = { $count } correct
+learn-answer-placeholder = Enter your logic translation... (Enter to submit)
+learn-check = Check
+learn-correct-no-stakes = Correct! (no-stakes practice)
+learn-correct-revealed = Correct! (no XP - answer was revealed)
+learn-correct-no-xp = Correct! (no XP - too many attempts)
+learn-correct-completed = Correct! (already completed)
+learn-correct-xp = Correct! +{ $xp } XP
+learn-correct-combo = Correct! +{ $xp } XP ({ $multiplier }x combo)
+learn-penalty = (-5 XP, { $remaining } remaining)
+learn-penalty-none = (no XP remaining)
+learn-reading = Reading { $number }
+learn-socrates-says = Socrates says...
+learn-no-stakes = No-stakes practice
+learn-no-stakes-on = No-stakes practice: on
+learn-show-solution = Show Solution
+learn-show-answer = Show Answer (No XP)
+learn-symbol-dictionary = Symbol Dictionary
+learn-hint = Hint
+learn-no-hint = No hint available for this exercise.
+learn-correct-answer = Correct Answer
+learn-walkthrough = How the sentence derives it
+learn-no-logic = No logic output to analyze.
+learn-submit-answer = Submit Answer
+learn-exit-test = Exit Test
+learn-skip = Skip
+learn-next-exercise = Next Exercise
+learn-take-test = Take Test
+learn-no-exercises = No exercises available for this module yet.
+learn-total-loaded = Total loaded: { $count }
+
+## Review
+review-title = Daily Review
+review-count = { $current } of { $total }
+review-caught-up = All caught up!
+review-nothing-due = No exercises are due for review right now.
+review-back-home = ← Back to Home
+review-complete = Review Complete!
+review-complete-count = You reviewed { $count } items.
+review-answer-placeholder = Enter your answer...
+review-hard = Hard
+review-good = Good
+review-again = Again
+review-easy = Easy
+review-loading = Loading...
+
+## Exam
+exam-title = Exam simulation
+exam-lead = A timed test across the modules you choose. There are no hints and no feedback until the end; the report scores you by concept and is kept apart from your XP.
+exam-questions = Questions
+exam-minutes = { $minutes } minutes
+exam-start = Start exam
+exam-lexicon-failed = The word bank failed to load: { $error }
+exam-no-questions = None of the selected modules have questions an exam can use.
+exam-progress = Question { $current } of { $total } · { $answered } answered
+exam-readings-placeholder = One reading per line
+exam-answer-placeholder = Your answer
+exam-previous = Previous
+exam-finish = Finish exam
+exam-next = Next
+exam-report = Exam report
+exam-summary = { $correct } of { $questions } correct in { $time } (limit { $limit })
+exam-new = New exam
+exam-copy-report = Copy report JSON
+exam-report-copied = Report JSON copied
+exam-concept = Concept
+exam-correct = Correct
+exam-score = Score
+exam-past = Past exams
+exam-date = Date
+exam-modules = Modules
+exam-time = Time
+
+## Rewards
+xp-base = Base
+xp-combo-bonus = Combo Bonus
+xp-streak-bonus = Streak Bonus
+xp-first-try = First Try
+xp-critical = CRITICAL!
+streak-active = { $days }-day streak
+streak-at-risk = Streak at risk!
+streak-frozen = { $days } days (frozen)
+streak-lost = Lost { $days }-day streak
+achievement-unlocked = Achievement Unlocked
+achievement-title-unlocked = Title Unlocked: { $title }
+achievement-streak-freeze = +1 Streak Freeze!
+achievement-continue = Continue
+
+## Profile
+profile-accessibility = Accessibility
+profile-high-contrast = High-contrast colors
+profile-mathml = Expose formulas to screen readers as MathML instead of spoken text
+profile-language = Language
+profile-sound = Sound
+profile-sound-theme = Sound theme
+profile-sound-master = Master
+profile-sound-effects = Effects
+profile-sound-music = Music
+profile-sound-mute = Mute all sounds
+profile-sound-music-on = Play background music
+profile-sound-test = Test sound
+profile-usage-data = Usage Data
+profile-telemetry-opt-in = Share anonymous usage counts to help improve the lessons
+profile-telemetry-explain = This is everything that would be sent. Nothing leaves your browser unless the box is checked.
+profile-telemetry-send = Send now
+profile-experiments-explain = Your lessons are part of a few experiments comparing hint styles, XP rewards and review schedules. Their log stays in this browser; copy it to send it in.
+profile-experiments-copy = Copy experiment log
+profile-experiments-clear = Clear experiment log
+profile-experiments-copied = Experiment log copied as JSON
+profile-experiments-cleared = Experiment log cleared
+profile-anki-explain = Take your cards to Anki: copy a deck of every exercise you have studied, or paste review history exported from Anki (exercise ID, date and answer button, tab-separated) to replay it here.
+profile-anki-copy = Copy Anki deck
+profile-anki-copied = Anki deck of { $count } cards copied
+profile-anki-export-failed = The Anki deck could not be built: { $error }
+profile-anki-placeholder = exercise-id<TAB>YYYY-MM-DD<TAB>1-4
+profile-anki-import = Import review history
+profile-anki-imported = Replayed { $count } reviews
+profile-anki-import-failed = The review history was not imported: { $error }
+
+## Theme editor
+theme-editor-title = Custom Theme
+theme-editor-name = Theme name
+theme-editor-surfaces = Surfaces
+theme-editor-tokens = Sentence tokens
+theme-editor-code = Code
+theme-editor-ast = Syntax tree
+theme-editor-preview = Theme preview
+theme-editor-apply = Apply
+theme-editor-reset = Reset to preset
+theme-editor-export = Export JSON
+theme-editor-import = Import JSON
+theme-editor-json = Theme JSON
@@ -0,0 +1,209 @@
+# Español
+
+## Navegación
+nav-tagline = Depura tus pensamientos.
+nav-guide = Guía de sintaxis
+nav-crates = Crates
+nav-learn = Aprende lógica
+nav-studio = Studio
+nav-roadmap = Hoja de ruta
+nav-contact = Contacto
+nav-news = Noticias
+nav-benchmarks = Benchmarks
+nav-profile = Perfil
+nav-github = GitHub
+nav-github-title = Ver en GitHub
+nav-profile-title = Tu perfil
+nav-drawer-title = Navegación
+nav-open-menu = Abrir menú
+nav-close-menu = Cerrar menú
+
+## Pie de página
+footer-theme = Tema:
+footer-language = Idioma:
+footer-privacy = Privacidad
+footer-terms = Términos
+footer-privacy-policy = Política de privacidad
+footer-terms-of-service = Términos del servicio
+footer-rights = © { $year } Brahmastra Labs. Todos los derechos reservados.
+
+## Lección
+lesson-exercise-label = Ejercicio { $current } de { $total }
+lesson-answer-placeholder = Escribe tu respuesta en lógica de primer orden...
+lesson-answer-label = Tu respuesta en lógica de primer orden
+lesson-choices-label = Opciones de respuesta
+lesson-show-hint = Ver pista
+lesson-check = Comprobar
+lesson-next = Siguiente problema
+lesson-complete-module = Completar módulo
+lesson-correct = ¡Correcto!
+lesson-not-quite = No exactamente.
+lesson-good-analysis = ¡Buen análisis!
+lesson-explanation = Explicación:
+lesson-loading = Cargando ejercicios...
+
+## Aprender
+learn-badge = Currículo interactivo
+learn-title = Aprende lógica
+learn-lead = Domina la lógica de primer orden con retos progresivos. Empieza por lo básico y avanza hasta el razonamiento avanzado.
+learn-exam-link = Haz un examen cronometrado
+learn-exercise-count = { $count } ejercicios
+learn-try-example = Prueba un ejemplo
+learn-example = Ejemplo
+learn-start = Empezar
+learn-daily-goal = Meta diaria
+learn-today = Hoy
+learn-streak-freezes = { $count } protectores de racha
+learn-vacation-title = Pausa tu racha y tus repasos mientras estás fuera
+learn-vacation-start = Empezar vacaciones
+learn-vacation-end = Terminar vacaciones
+learn-tab-lesson = Lección
+learn-tab-examples = Ejemplos
+learn-tab-practice = Práctica
+learn-tab-test = Prueba
+learn-try-examples = Ver ejemplos
+learn-lesson-coming-soon = El contenido de la lección llegará pronto. Pulsa Ejemplos para ver demostraciones interactivas.
+learn-examples-title = Ejemplos interactivos
+learn-examples-lead = Traduce oraciones y descubre cómo funciona la notación lógica. El diccionario de símbolos explica cada símbolo usado.
+learn-try-it = Pruébalo
+learn-start-practice = Empezar a practicar
+learn-stat-streak = racha
+learn-stat-correct = correctas
+learn-test-complete = ¡Prueba completada!
+learn-test-grade = Nota: { $grade } ({ $percent } %)
+learn-back-to-practice = Volver a la práctica
+learn-retake-test = Repetir la prueba
+learn-test-mode = MODO PRUEBA
+learn-question-label = Pregunta { $current } de { $total }
+learn-correct-count = { $count } correctas
+learn-answer-placeholder = Escribe tu traducción lógica... (Intro para enviar)
+learn-check = Comprobar
+learn-correct-no-stakes = ¡Correcto! (práctica sin riesgo)
+learn-correct-revealed = ¡Correcto! (sin XP: se mostró la respuesta)
+learn-correct-no-xp = ¡Correcto! (sin XP: demasiados intentos)
+learn-correct-completed = ¡Correcto! (ya completado)
+learn-correct-xp = ¡Correcto! +{ $xp } XP
+learn-correct-combo = ¡Correcto! +{ $xp } XP (combo x{ $multiplier })
+learn-penalty = (-5 XP, quedan { $remaining })
+learn-penalty-none = (no quedan XP)
+learn-reading = Lectura { $number }
+learn-socrates-says = Sócrates dice...
+learn-no-stakes = Práctica sin riesgo
+learn-no-stakes-on = Práctica sin riesgo: activada
+learn-show-solution = Ver solución
+learn-show-answer = Ver respuesta (sin XP)
+learn-symbol-dictionary = Diccionario de símbolos
+learn-hint = Pista
+learn-no-hint = Este ejercicio no tiene pista.
+learn-correct-answer = Respuesta correcta
+learn-walkthrough = Cómo se deriva de la oración
+learn-no-logic = No hay salida lógica que analizar.
+learn-submit-answer = Enviar respuesta
+learn-exit-test = Salir de la prueba
+learn-skip = Saltar
+learn-next-exercise = Siguiente ejercicio
+learn-take-test = Hacer la prueba
+learn-no-exercises = Este módulo aún no tiene ejercicios.
+learn-total-loaded = Total cargado: { $count }
+
+## Repaso
+review-title = Repaso diario
+review-count = { $current } de { $total }
+review-caught-up = ¡Todo al día!
+review-nothing-due = No hay ejercicios pendientes de repaso ahora mismo.
+review-back-home = ← Volver al inicio
+review-complete = ¡Repaso completado!
+review-complete-count = Repasaste { $count } elementos.
+review-answer-placeholder = Escribe tu respuesta...
+review-hard = Difícil
+review-good = Bien
+review-again = Otra vez
+review-easy = Fácil
+review-loading = Cargando...
+
+## Examen
+exam-title = Simulacro de examen
+exam-lead = Una prueba cronometrada sobre los módulos que elijas. No hay pistas ni correcciones hasta el final; el informe te puntúa por concepto y no afecta a tu XP.
+exam-questions = Preguntas
+exam-minutes = { $minutes } minutos
+exam-start = Empezar examen
+exam-lexicon-failed = No se pudo cargar el banco de palabras: { $error }
+exam-no-questions = Ninguno de los módulos elegidos tiene preguntas que un examen pueda usar.
+exam-progress = Pregunta { $current } de { $total } · { $answered } respondidas
+exam-readings-placeholder = Una lectura por línea
+exam-answer-placeholder = Tu respuesta
+exam-previous = Anterior
+exam-finish = Terminar examen
+exam-next = Siguiente
+exam-report = Informe del examen
+exam-summary = { $correct } de { $questions } correctas en { $time } (límite { $limit })
+exam-new = Nuevo examen
+exam-copy-report = Copiar informe JSON
+exam-report-copied = Informe JSON copiado
+exam-concept = Concepto
+exam-correct = Correctas
+exam-score = Puntuación
+exam-past = Exámenes anteriores
+exam-date = Fecha
+exam-modules = Módulos
+exam-time = Tiempo
+
+## Recompensas
+xp-base = Base
+xp-combo-bonus = Bono de combo
+xp-streak-bonus = Bono de racha
+xp-first-try = Al primer intento
+xp-critical = ¡CRÍTICO!
+streak-active = Racha de { $days } días
+streak-at-risk = ¡Racha en peligro!
+streak-frozen = { $days } días (congelada)
+streak-lost = Racha de { $days } días perdida
+achievement-unlocked = Logro desbloqueado
+achievement-title-unlocked = Título desbloqueado: { $title }
+achievement-streak-freeze = ¡+1 protector de racha!
+achievement-continue = Continuar
+
+## Perfil
+profile-accessibility = Accesibilidad
+profile-high-contrast = Colores de alto contraste
+profile-mathml = Presentar las fórmulas a los lectores de pantalla como MathML en lugar de texto hablado
+profile-language = Idioma
+profile-sound = Sonido
+profile-sound-theme = Tema de sonido
+profile-sound-master = General
+profile-sound-effects = Efectos
+profile-sound-music = Música
+profile-sound-mute = Silenciar todos los sonidos
+profile-sound-music-on = Reproducir música de fondo
+profile-sound-test = Probar sonido
+profile-usage-data = Datos de uso
+profile-telemetry-opt-in = Compartir recuentos de uso anónimos para mejorar las lecciones
+profile-telemetry-explain = Esto es todo lo que se enviaría. Nada sale de tu navegador a menos que marques la casilla.
+profile-telemetry-send = Enviar ahora
+profile-experiments-explain = Tus lecciones forman parte de algunos experimentos que comparan estilos de pista, recompensas de XP y calendarios de repaso. Su registro se queda en este navegador; cópialo para enviarlo.
+profile-experiments-copy = Copiar registro de experimentos
+profile-experiments-clear = Borrar registro de experimentos
+profile-experiments-copied = Registro de experimentos copiado como JSON
+profile-experiments-cleared = Registro de experimentos borrado
+profile-anki-explain = Lleva tus tarjetas a Anki: copia un mazo con cada ejercicio que has estudiado o pega un historial de repasos exportado de Anki (ID del ejercicio, fecha y botón de respuesta, separados por tabuladores) para reproducirlo aquí.
+profile-anki-copy = Copiar mazo de Anki
+profile-anki-copied = Mazo de Anki con { $count } tarjetas copiado
+profile-anki-export-failed = No se pudo crear el mazo de Anki: { $error }
+profile-anki-placeholder = id-del-ejercicio<TAB>AAAA-MM-DD<TAB>1-4
+profile-anki-import = Importar historial de repasos
+profile-anki-imported = Se reprodujeron { $count } repasos
+profile-anki-import-failed = No se importó el historial de repasos: { $error }
+
+## Editor de temas
+theme-editor-title = Tema personalizado
+theme-editor-name = Nombre del tema
+theme-editor-surfaces = Superficies
+theme-editor-tokens = Palabras de la oración
+theme-editor-code = Código
+theme-editor-ast = Árbol sintáctico
+theme-editor-preview = Vista previa del tema
+theme-editor-apply = Aplicar
+theme-editor-reset = Volver al predefinido
+theme-editor-export = Exportar JSON
+theme-editor-import = Importar JSON
+theme-editor-json = JSON del tema
@@ -1,3 +1,4 @@
+pub mod a11y;
 pub mod app;
 pub mod data_fetch;
 pub mod state;
@@ -7,8 +8,11 @@ pub mod router;
 pub mod pages;
 pub mod theme;
 pub mod theme_state;
+pub mod custom_theme;
+pub mod i18n;
 pub mod responsive;
 pub mod examples;
+pub mod embed;
 pub mod seo;
 
 pub use app::App;
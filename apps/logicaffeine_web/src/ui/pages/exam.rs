@@ -0,0 +1,612 @@
+//! Exam simulation page.
+//!
+//! A fixed-length, timed test across the modules the learner picks (see
+//! [`crate::game::Exam`]):
+//! - **Setup** picks modules and a length; the time limit follows from it
+//! - **Exam** shows one question at a time with no hints and no feedback;
+//!   answers can be changed until time is up, which ends the exam
+//! - **Report** scores each concept and keeps the result in
+//!   [`UserProgress::exam_results`], apart from XP
+//!
+//! # Route
+//!
+//! Accessed via [`Route::Exam`](crate::ui::router::Route::Exam).
+
+use std::collections::BTreeSet;
+
+use dioxus::prelude::*;
+#[cfg(all(feature = "split", target_arch = "wasm32"))]
+use dioxus::wasm_split;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use crate::content::{ContentEngine, Module};
+use crate::game::{Exam as ExamSession, EXAM_LENGTH, EXAM_LENGTH_CHOICES, EXAM_SECONDS_PER_QUESTION};
+use crate::generator::{AnswerType, Generator};
+use crate::progress::{ExamResult, UserProgress};
+use crate::ui::components::main_nav::{MainNav, ActivePage};
+use crate::ui::components::footer::Footer;
+use crate::ui::i18n::I18nState;
+use crate::ui::seo::{JsonLdMultiple, PageHead, organization_schema, breadcrumb_schema, webpage_schema, BreadcrumbItem, pages as seo_pages};
+
+const EXAM_STYLE: &str = r#"
+.exam-page {
+    min-height: 100vh;
+    color: var(--text-primary);
+    background: linear-gradient(180deg, #070a12, #0b1022 55%, #070a12);
+    font-family: var(--font-sans);
+}
+
+.exam-main {
+    max-width: 820px;
+    margin: 0 auto;
+    padding: 32px var(--spacing-xl) 80px;
+}
+
+.exam-main h1 {
+    margin: 0 0 8px;
+    font-size: 28px;
+}
+
+.exam-lead {
+    margin: 0 0 24px;
+    color: var(--text-secondary);
+    line-height: 1.6;
+}
+
+.exam-era {
+    margin-bottom: 16px;
+}
+
+.exam-era h2 {
+    margin: 0 0 8px;
+    font-size: 15px;
+    color: #a5b4fc;
+}
+
+.exam-modules {
+    display: flex;
+    flex-wrap: wrap;
+    gap: 8px;
+}
+
+.exam-module {
+    display: flex;
+    align-items: center;
+    gap: 6px;
+    padding: 6px 10px;
+    border: 1px solid rgba(255, 255, 255, 0.1);
+    border-radius: 8px;
+    background: rgba(255, 255, 255, 0.04);
+    font-size: 14px;
+    cursor: pointer;
+}
+
+.exam-controls {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    gap: 12px;
+    margin: 24px 0;
+    color: var(--text-secondary);
+    font-size: 14px;
+}
+
+.exam-controls select {
+    padding: 6px 10px;
+    border: 1px solid rgba(255, 255, 255, 0.1);
+    border-radius: 6px;
+    background: #0f1419;
+    color: var(--text-primary);
+}
+
+.exam-btn {
+    padding: 8px 16px;
+    border: 1px solid rgba(255, 255, 255, 0.1);
+    border-radius: 8px;
+    background: rgba(255, 255, 255, 0.06);
+    color: var(--text-secondary);
+    font-size: 14px;
+    cursor: pointer;
+}
+
+.exam-btn:hover:not(:disabled) {
+    border-color: rgba(255, 255, 255, 0.2);
+    color: var(--text-primary);
+}
+
+.exam-btn:disabled {
+    opacity: 0.4;
+    cursor: not-allowed;
+}
+
+.exam-btn.primary {
+    border: none;
+    background: linear-gradient(135deg, #667eea 0%, #764ba2 100%);
+    color: white;
+    font-weight: 600;
+}
+
+.exam-status {
+    margin: 0 0 12px;
+    font-size: 13px;
+    color: var(--text-secondary);
+}
+
+.exam-bar {
+    display: flex;
+    justify-content: space-between;
+    align-items: center;
+    margin-bottom: 16px;
+    font-size: 14px;
+    color: var(--text-secondary);
+}
+
+.exam-timer {
+    font-family: 'SF Mono', 'Fira Code', monospace;
+    font-size: 18px;
+    color: #e5e7eb;
+}
+
+.exam-timer.low {
+    color: #f87171;
+}
+
+.exam-question {
+    padding: 20px;
+    border: 1px solid rgba(255, 255, 255, 0.08);
+    border-radius: 12px;
+    background: rgba(255, 255, 255, 0.03);
+}
+
+.exam-concept {
+    font-size: 12px;
+    text-transform: uppercase;
+    letter-spacing: 0.05em;
+    color: #a5b4fc;
+}
+
+.exam-prompt {
+    margin: 8px 0;
+    color: var(--text-secondary);
+}
+
+.exam-sentence {
+    margin: 0 0 16px;
+    font-size: 20px;
+    line-height: 1.5;
+}
+
+.exam-question input,
+.exam-question textarea {
+    width: 100%;
+    box-sizing: border-box;
+    padding: 10px 12px;
+    border: 1px solid rgba(255, 255, 255, 0.1);
+    border-radius: 8px;
+    background: #0f1419;
+    color: #e5e7eb;
+    font-family: 'SF Mono', 'Fira Code', monospace;
+    font-size: 15px;
+}
+
+.exam-options {
+    display: flex;
+    flex-direction: column;
+    gap: 8px;
+}
+
+.exam-option {
+    text-align: left;
+    padding: 10px 14px;
+    border: 1px solid rgba(255, 255, 255, 0.1);
+    border-radius: 8px;
+    background: rgba(255, 255, 255, 0.04);
+    color: var(--text-primary);
+    font-family: 'SF Mono', 'Fira Code', monospace;
+    font-size: 14px;
+    cursor: pointer;
+}
+
+.exam-option.selected {
+    border-color: #667eea;
+    background: rgba(102, 126, 234, 0.18);
+}
+
+.exam-nav {
+    display: flex;
+    justify-content: space-between;
+    gap: 8px;
+    margin-top: 16px;
+}
+
+.exam-score {
+    margin: 0 0 4px;
+    font-size: 48px;
+    font-weight: 700;
+    color: #4ade80;
+}
+
+.exam-table {
+    width: 100%;
+    margin: 16px 0 24px;
+    border-collapse: collapse;
+    font-size: 14px;
+}
+
+.exam-table th,
+.exam-table td {
+    padding: 8px 10px;
+    border-bottom: 1px solid rgba(255, 255, 255, 0.06);
+    text-align: left;
+}
+
+.exam-table th {
+    color: var(--text-secondary);
+    font-weight: 500;
+}
+"#;
+
+/// Which view the page shows.
+#[derive(Clone, PartialEq)]
+enum Stage {
+    Setup,
+    Running,
+    Report(ExamResult),
+}
+
+#[component(lazy)]
+pub fn Exam() -> Element {
+    let i18n = use_context::<I18nState>();
+    let mut stage = use_signal(|| Stage::Setup);
+    let mut selected = use_signal(BTreeSet::<String>::new);
+    let mut length = use_signal(|| EXAM_LENGTH);
+    let mut session = use_signal(|| None::<ExamSession>);
+    let mut current = use_signal(|| 0usize);
+    let mut started_at = use_signal(|| 0.0f64);
+    let mut elapsed = use_signal(|| 0u32);
+    let mut status = use_signal(|| None::<String>);
+    let mut learner = use_signal(UserProgress::load);
+
+    let engine = ContentEngine::new();
+
+    let mut finish = move || {
+        let Some(exam) = session.peek().clone() else {
+            return;
+        };
+        let result = exam.finish(&crate::game::today(), *elapsed.peek());
+        learner.write().record_exam(result.clone());
+        session.set(None);
+        stage.set(Stage::Report(result));
+    };
+
+    // The clock ticks once a second while an exam runs, and closes it at zero.
+    #[cfg(target_arch = "wasm32")]
+    use_future(move || async move {
+        loop {
+            gloo_timers::future::TimeoutFuture::new(1000).await;
+            if *stage.peek() != Stage::Running {
+                continue;
+            }
+            let secs = ((now_ms() - *started_at.peek()) / 1000.0).max(0.0) as u32;
+            elapsed.set(secs);
+            if session.peek().as_ref().is_some_and(|exam| exam.is_over(secs)) {
+                finish();
+            }
+        }
+    });
+    // The word bank may still be loading when Start is pressed.
+    let start = move |_| {
+        spawn(async move {
+            if let Err(e) = crate::generator::ensure_lexicon().await {
+                status.set(Some(i18n.t_with("exam-lexicon-failed", &[("error", &e.to_string())])));
+                return;
+            }
+            let picked = selected.peek().clone();
+            let engine = ContentEngine::new();
+            let modules: Vec<&Module> = engine
+                .eras()
+                .iter()
+                .flat_map(|era| &era.modules)
+                .filter(|module| picked.contains(&module.meta.id))
+                .collect();
+            let mut rng = StdRng::seed_from_u64(now_ms() as u64);
+            let questions = Generator::new().generate_exam(&modules, *length.peek(), &mut rng);
+            if questions.is_empty() {
+                status.set(Some(i18n.t("exam-no-questions")));
+                return;
+            }
+            let ids = modules.iter().map(|module| module.meta.id.clone()).collect();
+            session.set(Some(ExamSession::new(questions, ids)));
+            current.set(0);
+            started_at.set(now_ms());
+            elapsed.set(0);
+            status.set(None);
+            stage.set(Stage::Running);
+        });
+    };
+
+    let mut submit = move |index: usize, answer: String| {
+        let secs = *elapsed.peek();
+        let accepted = session.write().as_mut().is_some_and(|exam| exam.answer(index, &answer, secs));
+        if !accepted {
+            finish();
+        }
+    };
+
+    let body = match stage() {
+        Stage::Setup => {
+            let picked = selected();
+            let limit_minutes = length() as u32 * EXAM_SECONDS_PER_QUESTION / 60;
+            rsx! {
+                h1 { {i18n.t("exam-title")} }
+                p { class: "exam-lead", {i18n.t("exam-lead")} }
+                for era in engine.eras().iter().filter(|era| !era.modules.is_empty()) {
+                    div { key: "{era.meta.id}", class: "exam-era",
+                        h2 { "{era.meta.title}" }
+                        div { class: "exam-modules",
+                            for module in era.modules.iter() {
+                                label { key: "{module.meta.id}", class: "exam-module",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: picked.contains(&module.meta.id),
+                                        onchange: {
+                                            let id = module.meta.id.clone();
+                                            move |_| {
+                                                let mut set = selected.write();
+                                                if !set.remove(&id) {
+                                                    set.insert(id.clone());
+                                                }
+                                            }
+                                        },
+                                    }
+                                    "{module.meta.title}"
+                                }
+                            }
+                        }
+                    }
+                }
+                div { class: "exam-controls",
+                    label { r#for: "exam-length", {i18n.t("exam-questions")} }
+                    select {
+                        id: "exam-length",
+                        value: "{length()}",
+                        onchange: move |e| {
+                            if let Ok(n) = e.value().parse() {
+                                length.set(n);
+                            }
+                        },
+                        for n in EXAM_LENGTH_CHOICES {
+                            option { key: "{n}", value: "{n}", selected: n == length(), "{n}" }
+                        }
+                    }
+                    span { {i18n.t_with("exam-minutes", &[("minutes", &limit_minutes.to_string())])} }
+                    button {
+                        class: "exam-btn primary",
+                        disabled: picked.is_empty(),
+                        onclick: start,
+                        {i18n.t("exam-start")}
+                    }
+                }
+                if let Some(message) = status() {
+                    p { class: "exam-status", role: "status", "{message}" }
+                }
+                PastResults { results: learner().exam_results.clone() }
+            }
+        }
+        Stage::Running => {
+            let Some(exam) = session() else {
+                return rsx! {};
+            };
+            let index = current().min(exam.questions.len() - 1);
+            let question = &exam.questions[index];
+            let given = exam.answer_to(index).unwrap_or_default().to_string();
+            let left = exam.time_left(elapsed());
+            let total = exam.questions.len();
+            rsx! {
+                div { class: "exam-bar",
+                    span {
+                        {i18n.t_with("exam-progress", &[
+                            ("current", &(index + 1).to_string()),
+                            ("total", &total.to_string()),
+                            ("answered", &exam.answered().to_string()),
+                        ])}
+                    }
+                    span {
+                        class: if left < 60 { "exam-timer low" } else { "exam-timer" },
+                        role: "timer",
+                        {clock(left)}
+                    }
+                }
+                div { class: "exam-question",
+                    div { class: "exam-concept", "{question.concept}" }
+                    p { class: "exam-prompt", "{question.challenge.prompt}" }
+                    p { class: "exam-sentence", "{question.challenge.sentence}" }
+                    match &question.challenge.answer {
+                        AnswerType::MultipleChoice { options, .. } => rsx! {
+                            div { class: "exam-options",
+                                for (i, option) in options.iter().enumerate() {
+                                    button {
+                                        key: "{i}",
+                                        // Submitted by number, so an option's own text is never read as one.
+                                        class: if given == (i + 1).to_string() { "exam-option selected" } else { "exam-option" },
+                                        onclick: move |_| submit(index, (i + 1).to_string()),
+                                        "{option}"
+                                    }
+                                }
+                            }
+                        },
+                        AnswerType::Ambiguity { .. } => rsx! {
+                            textarea {
+                                key: "{index}",
+                                rows: 4,
+                                placeholder: i18n.t("exam-readings-placeholder"),
+                                value: "{given}",
+                                oninput: move |e| submit(index, e.value()),
+                            }
+                        },
+                        _ => rsx! {
+                            input {
+                                key: "{index}",
+                                placeholder: i18n.t("exam-answer-placeholder"),
+                                value: "{given}",
+                                oninput: move |e| submit(index, e.value()),
+                            }
+                        },
+                    }
+                }
+                div { class: "exam-nav",
+                    button {
+                        class: "exam-btn",
+                        disabled: index == 0,
+                        onclick: move |_| current.set(index.saturating_sub(1)),
+                        {i18n.t("exam-previous")}
+                    }
+                    button { class: "exam-btn primary", onclick: move |_| finish(), {i18n.t("exam-finish")} }
+                    button {
+                        class: "exam-btn",
+                        disabled: index + 1 == total,
+                        onclick: move |_| current.set(index + 1),
+                        {i18n.t("exam-next")}
+                    }
+                }
+            }
+        }
+        Stage::Report(result) => {
+            let json = serde_json::to_string_pretty(&result).unwrap_or_default();
+            rsx! {
+                h1 { {i18n.t("exam-report")} }
+                p { class: "exam-score", "{result.percent()}%" }
+                p { class: "exam-lead",
+                    {i18n.t_with("exam-summary", &[
+                        ("correct", &result.correct.to_string()),
+                        ("questions", &result.questions.to_string()),
+                        ("time", &clock(result.time_taken_secs)),
+                        ("limit", &clock(result.time_limit_secs)),
+                    ])}
+                }
+                ConceptTable { result: result.clone() }
+                div { class: "exam-controls",
+                    button { class: "exam-btn primary", onclick: move |_| stage.set(Stage::Setup), {i18n.t("exam-new")} }
+                    button {
+                        class: "exam-btn",
+                        onclick: move |_| {
+                            #[cfg(target_arch = "wasm32")]
+                            if let Some(window) = web_sys::window() {
+                                let _ = window.navigator().clipboard().write_text(&json);
+                            }
+                            #[cfg(not(target_arch = "wasm32"))]
+                            let _ = &json;
+                            status.set(Some(i18n.t("exam-report-copied")));
+                        },
+                        {i18n.t("exam-copy-report")}
+                    }
+                }
+                if let Some(message) = status() {
+                    p { class: "exam-status", role: "status", "{message}" }
+                }
+            }
+        }
+    };
+
+    rsx! {
+        PageHead {
+            title: seo_pages::EXAM.title,
+            description: seo_pages::EXAM.description,
+            canonical_path: seo_pages::EXAM.canonical_path,
+        }
+        style { "{EXAM_STYLE}" }
+        JsonLdMultiple { schemas: vec![
+            organization_schema(),
+            webpage_schema(seo_pages::EXAM.title, seo_pages::EXAM.description, seo_pages::EXAM.canonical_path),
+            breadcrumb_schema(&[
+                BreadcrumbItem { name: "Home", path: "/" },
+                BreadcrumbItem { name: "Learn", path: "/learn" },
+                BreadcrumbItem { name: "Exam", path: "/exam" },
+            ]),
+        ] }
+
+        div { class: "exam-page",
+            MainNav { active: ActivePage::Learn, subtitle: Some("Exam") }
+
+            main { class: "exam-main",
+                {body}
+            }
+
+            Footer {}
+        }
+    }
+}
+
+/// A finished exam's score on each concept.
+#[component]
+fn ConceptTable(result: ExamResult) -> Element {
+    let i18n = use_context::<I18nState>();
+    rsx! {
+        table { class: "exam-table",
+            thead {
+                tr {
+                    th { {i18n.t("exam-concept")} }
+                    th { {i18n.t("exam-correct")} }
+                    th { {i18n.t("exam-score")} }
+                }
+            }
+            tbody {
+                for score in result.concepts.iter() {
+                    tr { key: "{score.concept}",
+                        td { "{score.concept}" }
+                        td { "{score.correct} / {score.questions}" }
+                        td { "{score.correct * 100 / score.questions.max(1)}%" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Earlier exam results, newest first.
+#[component]
+fn PastResults(results: Vec<ExamResult>) -> Element {
+    let i18n = use_context::<I18nState>();
+    if results.is_empty() {
+        return rsx! {};
+    }
+    rsx! {
+        h2 { {i18n.t("exam-past")} }
+        table { class: "exam-table",
+            thead {
+                tr {
+                    th { {i18n.t("exam-date")} }
+                    th { {i18n.t("exam-modules")} }
+                    th { {i18n.t("exam-score")} }
+                    th { {i18n.t("exam-time")} }
+                }
+            }
+            tbody {
+                for (i, result) in results.iter().enumerate().rev() {
+                    tr { key: "{i}",
+                        td { "{result.date}" }
+                        td { "{result.modules.len()}" }
+                        td { "{result.correct} / {result.questions} ({result.percent()}%)" }
+                        td { {clock(result.time_taken_secs)} }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Seconds as `m:ss`.
+fn clock(secs: u32) -> String {
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+fn now_ms() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::now()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        0.0
+    }
+}
@@ -6,7 +6,12 @@
 //! - Module-based curriculum with progressive unlocking
 //! - Interactive exercises with immediate grading
 //! - Struggle detection and adaptive hints
+//! - Structural diffs of a wrong answer against the expected formula, and of
+//!   an ambiguous sentence's readings
+//! - No-stakes practice: hints open, nothing scored, solutions walked through
+//!   along the sentence's derivation trace
 //! - Spaced repetition scheduling (SM-2)
+//! - A link to the timed exam ([`Route::Exam`](crate::ui::router::Route::Exam))
 //! - XP rewards and achievement notifications
 //!
 //! # Layout
@@ -23,6 +28,8 @@ use dioxus::prelude::*;
 #[cfg(all(feature = "split", target_arch = "wasm32"))]
 use dioxus::wasm_split;
 use crate::ui::components::main_nav::{MainNav, ActivePage};
+use crate::ui::router::Route;
+use crate::ui::i18n::I18nState;
 use crate::ui::components::learn_sidebar::{LearnSidebar, ModuleInfo};
 use crate::ui::components::symbol_dictionary::SymbolDictionary;
 use crate::ui::components::vocab_reference::VocabReference;
@@ -33,9 +40,13 @@ use crate::ui::pages::guide::content::ExampleMode;
 use crate::ui::seo::{JsonLdMultiple, PageHead, organization_schema, course_schema, breadcrumb_schema, BreadcrumbItem, pages as seo_pages};
 use crate::content::ContentEngine;
 use crate::generator::{Generator, AnswerType, Challenge};
-use crate::grader::check_answer;
+use crate::grader::{check_answer, formula_ast};
+use crate::ast_diff::ast_diff;
+use crate::ui::components::ast_diff_view::AstDiffView;
 use crate::struggle::StruggleDetector;
-use crate::progress::UserProgress;
+use crate::audio::play_combo;
+use crate::game::{end_vacation, record_xp, solution_walkthrough, start_vacation, update_streak, PlayMode};
+use crate::progress::{UserProgress, DAILY_GOAL_CHOICES};
 use rand::SeedableRng;
 use rand::rngs::StdRng;
 use std::collections::HashSet;
@@ -78,6 +89,18 @@ const LEARN_STYLE: &str = r#"
     margin: 0;
 }
 
+.learn-exam-link {
+    display: inline-block;
+    margin-top: 16px;
+    color: #a5b4fc;
+    font-size: 14px;
+    text-decoration: none;
+}
+
+.learn-exam-link:hover {
+    text-decoration: underline;
+}
+
 .learn-hero-badge {
     display: inline-flex;
     align-items: center;
@@ -100,6 +123,59 @@ const LEARN_STYLE: &str = r#"
     box-shadow: 0 0 0 4px rgba(34,197,94,0.15);
 }
 
+/* Daily goal */
+.learn-daily {
+    margin-top: var(--spacing-xl);
+    max-width: 420px;
+    padding: var(--spacing-lg);
+    border-radius: var(--radius-lg);
+    background: rgba(255,255,255,0.04);
+    border: 1px solid rgba(255,255,255,0.08);
+    display: flex;
+    flex-direction: column;
+    gap: var(--spacing-sm);
+    font-size: var(--font-caption-md);
+    color: var(--text-secondary);
+}
+
+.learn-daily-row {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    justify-content: space-between;
+    gap: var(--spacing-sm);
+}
+
+.learn-daily-value {
+    font-weight: 700;
+    color: var(--text-primary);
+}
+
+.learn-daily-bar {
+    height: 8px;
+    border-radius: var(--radius-full);
+    background: rgba(255,255,255,0.08);
+    overflow: hidden;
+}
+
+.learn-daily-fill {
+    height: 100%;
+    background: var(--color-success);
+    transition: width 0.3s ease;
+}
+
+.learn-daily select,
+.learn-daily button {
+    margin-left: var(--spacing-sm);
+    padding: 2px 8px;
+    border-radius: var(--radius-sm);
+    border: 1px solid rgba(255,255,255,0.12);
+    background: transparent;
+    color: var(--text-secondary);
+    font-size: var(--font-caption-md);
+    cursor: pointer;
+}
+
 /* Layout */
 .learn-layout {
     max-width: 1280px;
@@ -1197,6 +1273,7 @@ type ExpandedModuleKey = Option<(String, String)>;
 
 #[component(lazy)]
 pub fn Learn() -> Element {
+    let i18n = use_context::<I18nState>();
     let mut active_module = use_signal(|| None::<String>);
     // Expanded module state: which module is currently expanded inline
     let mut expanded_module = use_signal::<ExpandedModuleKey>(|| None);
@@ -1205,6 +1282,10 @@ pub fn Learn() -> Element {
 
     let eras = get_curriculum_data();
 
+    // The learner's saved progress, shared by the daily goal card and the
+    // exercise panels that bank XP into it
+    use_context_provider(|| Signal::new(UserProgress::load()));
+
     // For module unlock checking
     let content_engine = ContentEngine::new();
     let user_progress = UserProgress::new(); // TODO: Load from storage
@@ -1335,12 +1416,12 @@ pub fn Learn() -> Element {
             header { class: "learn-hero",
                 div { class: "learn-hero-badge",
                     div { class: "dot" }
-                    span { "Interactive Curriculum" }
-                }
-                h1 { "Learn Logic" }
-                p {
-                    "Master first-order logic through progressive challenges. Start with the basics and work your way up to advanced reasoning."
+                    span { {i18n.t("learn-badge")} }
                 }
+                h1 { {i18n.t("learn-title")} }
+                p { {i18n.t("learn-lead")} }
+                DailyGoalCard {}
+                Link { class: "learn-exam-link", to: Route::Exam {}, {i18n.t("learn-exam-link")} " \u{2192}" }
             }
 
             // Main layout
@@ -1501,7 +1582,7 @@ pub fn Learn() -> Element {
                                                             }
 
                                                             div { class: "learn-module-meta",
-                                                                span { class: "learn-exercise-count", "{module.exercise_count} exercises" }
+                                                                span { class: "learn-exercise-count", {i18n.t_with("learn-exercise-count", &[("count", &module.exercise_count.to_string())])} }
                                                                 div { class: "learn-difficulty",
                                                                     for i in 1..=5u8 {
                                                                         div {
@@ -1516,10 +1597,10 @@ pub fn Learn() -> Element {
                                                         if !is_expanded {
                                                             if let Some(preview) = module.preview_code {
                                                                 div { class: "learn-module-preview",
-                                                                    div { class: "learn-preview-label", "Try an Example" }
+                                                                    div { class: "learn-preview-label", {i18n.t("learn-try-example")} }
                                                                     GuideCodeBlock {
                                                                         id: format!("preview-{}", module.id),
-                                                                        label: "Example".to_string(),
+                                                                        label: i18n.t("learn-example"),
                                                                         mode: ExampleMode::Logic,
                                                                         initial_code: preview.to_string(),
                                                                     }
@@ -1537,7 +1618,7 @@ pub fn Learn() -> Element {
                                                                             expanded_module.set(Some((era.clone(), module.clone())));
                                                                         }
                                                                     },
-                                                                    "Start Learning"
+                                                                    {i18n.t("learn-start")}
                                                                 }
                                                             }
                                                         }
@@ -1618,12 +1699,95 @@ fn InteractiveExercisePanel(era_id: String, module_id: String) -> Element {
     }
 }
 
+/// Today's XP against the learner's daily goal, their streak and banked
+/// freezes, and the vacation switch.
+#[component]
+fn DailyGoalCard() -> Element {
+    let i18n = use_context::<I18nState>();
+    let mut learner = use_context::<Signal<UserProgress>>();
+    let today = crate::game::today();
+    let (goal, earned, streak, freezes, on_vacation) = {
+        let progress = learner.read();
+        (
+            progress.daily_goal(),
+            progress.xp_earned_on(&today),
+            progress.streak_days,
+            progress.streak_freezes,
+            progress.on_vacation(),
+        )
+    };
+    let percent = (earned * 100 / goal.max(1) as u64).min(100);
+
+    rsx! {
+        div { class: "learn-daily", role: "group", aria_label: i18n.t("learn-daily-goal"),
+            div { class: "learn-daily-row",
+                span { {i18n.t("learn-today")} }
+                span { class: "learn-daily-value", "{earned} / {goal} XP" }
+            }
+            div {
+                class: "learn-daily-bar",
+                role: "progressbar",
+                aria_valuemin: "0",
+                aria_valuemax: "100",
+                aria_valuenow: "{percent}",
+                div { class: "learn-daily-fill", style: "width: {percent}%;" }
+            }
+            div { class: "learn-daily-row",
+                span { "\u{1F525} " {i18n.t_with("streak-active", &[("days", &streak.to_string())])} }
+                span { "\u{2744}\u{FE0F} " {i18n.t_with("learn-streak-freezes", &[("count", &freezes.to_string())])} }
+            }
+            div { class: "learn-daily-row",
+                label {
+                    {i18n.t("learn-daily-goal")}
+                    select {
+                        onchange: move |e| {
+                            if let Ok(xp) = e.value().parse() {
+                                learner.write().set_daily_goal(xp);
+                            }
+                        },
+                        for choice in DAILY_GOAL_CHOICES {
+                            option { value: "{choice}", selected: choice == goal, "{choice} XP" }
+                        }
+                    }
+                }
+                button {
+                    title: i18n.t("learn-vacation-title"),
+                    onclick: move |_| {
+                        let today = crate::game::today();
+                        let mut progress = learner.write();
+                        if progress.on_vacation() {
+                            end_vacation(&mut progress, &today);
+                        } else {
+                            start_vacation(&mut progress, &today);
+                        }
+                        progress.save();
+                    },
+                    if on_vacation { {i18n.t("learn-vacation-end")} } else { {i18n.t("learn-vacation-start")} }
+                }
+            }
+        }
+    }
+}
+
+/// Bank practice XP into the learner's saved progress: the day counts
+/// towards the streak, and the XP towards today's goal and streak freezes.
+/// `combo` is the run of correct answers this one extends, which the combo
+/// sound climbs with.
+fn bank_xp(mut learner: Signal<UserProgress>, xp: u32, combo: u32) {
+    play_combo(combo);
+    let today = crate::game::today();
+    let mut progress = learner.write();
+    update_streak(&mut progress, &today);
+    record_xp(&mut progress, xp as u64, &today);
+}
+
 #[component]
 fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
     use crate::content::{ContentBlock, Section};
 
     let engine = ContentEngine::new();
     let generator = Generator::new();
+    let learner = use_context::<Signal<UserProgress>>();
 
     // Content view state (Lesson vs Practice)
     let mut content_view = use_signal(ContentView::default);
@@ -1637,6 +1801,8 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
     let mut streak = use_signal(|| 0u32);
     let mut correct_count = use_signal(|| 0u32);
     let mut practice_mode = use_signal(PracticeMode::default);
+    // No-stakes practice: replay anything, hints open, no XP or penalties
+    let mut play_mode = use_signal(PlayMode::default);
     // Track wrong attempts per exercise - each wrong costs 5 XP, max 10 XP available per exercise
     // After 2 wrong attempts, no XP can be earned from that exercise
     let mut exercise_attempts = use_signal(|| std::collections::HashMap::<usize, u32>::new());
@@ -1673,6 +1839,10 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
         { 42u64 }
     });
 
+    // Exercise instructions follow the UI language where translated
+    let i18n = use_context::<I18nState>();
+    let locale = i18n.locale();
+
     // Generate challenge from exercise using stable seed
     let module_opt = engine.get_module(&era_id, &module_id);
     let current_challenge: Option<Challenge> = module_opt.as_ref().and_then(|module| {
@@ -1682,7 +1852,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
             // Use exercise index as part of seed to get different sentences per exercise
             // but stable within the same exercise
             let mut rng = StdRng::seed_from_u64(seed.wrapping_add((idx * 1000) as u64));
-            generator.generate(ex, &mut rng)
+            generator.generate(&ex.localized(locale.code()), &mut rng)
         })
     });
 
@@ -1705,12 +1875,26 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
         }
     });
 
+    // After a wrong translation, how the answer's structure differs from the expected formula
+    let answer_diff = match (feedback.read().as_ref(), current_challenge.as_ref().map(|ch| &ch.answer)) {
+        (Some((false, _)), Some(AnswerType::FreeForm { golden_logic })) => {
+            formula_ast(golden_logic).zip(formula_ast(&user_answer.read())).map(|(expected, given)| ast_diff(&expected, &given))
+        }
+        _ => None,
+    };
+
     // Get hint from exercise
     let hint_text = current_challenge.as_ref().and_then(|ch| ch.hint.clone());
 
     // Test mode constants
     let test_total = 10usize;
     let is_test_mode = *practice_mode.read() == PracticeMode::Test;
+    let no_stakes = !is_test_mode && play_mode.read().is_practice();
+    let walkthrough = if no_stakes && reveal_state.read().answer {
+        current_challenge.as_ref().map(solution_walkthrough)
+    } else {
+        None
+    };
     let current_test_q = *test_question.read();
 
     // Get sections for lesson content
@@ -1727,12 +1911,12 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                 button {
                     class: if current_view == ContentView::Lesson { "content-tab-btn active" } else { "content-tab-btn" },
                     onclick: move |_| content_view.set(ContentView::Lesson),
-                    "Lesson"
+                    {i18n.t("learn-tab-lesson")}
                 }
                 button {
                     class: if current_view == ContentView::Examples { "content-tab-btn active" } else { "content-tab-btn" },
                     onclick: move |_| content_view.set(ContentView::Examples),
-                    "Examples"
+                    {i18n.t("learn-tab-examples")}
                 }
                 button {
                     class: if current_view == ContentView::Practice { "content-tab-btn practice active" } else { "content-tab-btn practice" },
@@ -1740,7 +1924,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                         content_view.set(ContentView::Practice);
                         practice_mode.set(PracticeMode::Practice);
                     },
-                    "Practice"
+                    {i18n.t("learn-tab-practice")}
                 }
                 button {
                     class: if current_view == ContentView::Test { "content-tab-btn test active" } else { "content-tab-btn test" },
@@ -1753,7 +1937,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                         user_answer.set(String::new());
                         feedback.set(None);
                     },
-                    "Test"
+                    {i18n.t("learn-tab-test")}
                 }
             }
 
@@ -1866,12 +2050,12 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                             button {
                                 class: "learn-action-btn primary",
                                 onclick: move |_| content_view.set(ContentView::Examples),
-                                "Try Examples →"
+                                {i18n.t("learn-try-examples")} " →"
                             }
                         }
                     } else {
                         p { style: "color: var(--text-tertiary); text-align: center; padding: var(--spacing-xl);",
-                            "Lesson content coming soon. Click Examples to see interactive demos."
+                            {i18n.t("learn-lesson-coming-soon")}
                         }
                     }
                 }
@@ -1879,8 +2063,8 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                 // Examples view - Interactive code execution
                 div { class: "tab-panel-examples",
                     div { class: "examples-intro",
-                        h3 { "Interactive Examples" }
-                        p { "Try translating sentences and see how logic notation works. The symbol dictionary will explain each symbol used." }
+                        h3 { {i18n.t("learn-examples-title")} }
+                        p { {i18n.t("learn-examples-lead")} }
                     }
 
                     // Example sentences to try
@@ -1901,7 +2085,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                         div { class: "example-sentence", "{sentence}" }
                                         GuideCodeBlock {
                                             id: id.to_string(),
-                                            label: "Try it".to_string(),
+                                            label: i18n.t("learn-try-it"),
                                             mode: ExampleMode::Logic,
                                             initial_code: sentence.to_string(),
                                         }
@@ -1919,7 +2103,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                 content_view.set(ContentView::Practice);
                                 practice_mode.set(PracticeMode::Practice);
                             },
-                            "Start Practice →"
+                            {i18n.t("learn-start-practice")} " →"
                         }
                     }
                 }
@@ -1944,7 +2128,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                             rsx! {
                                 div { class: "mode-stat combo",
                                     span { class: "mode-stat-value streak", "{s}" }
-                                    span { class: "mode-stat-label", " streak " }
+                                    span { class: "mode-stat-label", " " {i18n.t("learn-stat-streak")} " " }
                                     span { class: "combo-multiplier", "({mult})" }
                                 }
                             }
@@ -1952,7 +2136,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                     }
                     div { class: "mode-stat",
                         span { class: "mode-stat-value", "{correct_count}" }
-                        span { class: "mode-stat-label", " correct" }
+                        span { class: "mode-stat-label", " " {i18n.t("learn-stat-correct")} }
                     }
                 }
 
@@ -1963,7 +2147,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                     div { class: "test-results",
                         style: "text-align: center; padding: var(--spacing-xxl);",
                         h3 { style: "margin-bottom: var(--spacing-lg); font-size: var(--font-heading-lg);",
-                            "Test Complete!"
+                            {i18n.t("learn-test-complete")}
                         }
                         {
                             let answers = test_answers.read();
@@ -1975,7 +2159,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                     "{correct}/{test_total}"
                                 }
                                 div { style: "font-size: var(--font-heading-lg); color: var(--text-secondary); margin: var(--spacing-md) 0;",
-                                    "Grade: {grade} ({pct}%)"
+                                    {i18n.t_with("learn-test-grade", &[("grade", grade), ("percent", &pct.to_string())])}
                                 }
                             }
                         }
@@ -1986,7 +2170,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                     practice_mode.set(PracticeMode::Practice);
                                     test_complete.set(false);
                                 },
-                                "Back to Practice"
+                                {i18n.t("learn-back-to-practice")}
                             }
                             button {
                                 class: "learn-action-btn primary",
@@ -1997,15 +2181,15 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                     user_answer.set(String::new());
                                     feedback.set(None);
                                 },
-                                "Retake Test"
+                                {i18n.t("learn-retake-test")}
                             }
                         }
                     }
                 } else {
                     // Test mode progress
                     div { class: "exercise-progress",
-                        div { class: "exercise-mode-badge test", "TEST MODE" }
-                        span { "Question {current_test_q + 1} of {test_total}" }
+                        div { class: "exercise-mode-badge test", {i18n.t("learn-test-mode")} }
+                        span { {i18n.t_with("learn-question-label", &[("current", &(current_test_q + 1).to_string()), ("total", &test_total.to_string())])} }
                         div { class: "progress-bar",
                             div {
                                 class: "progress-fill",
@@ -2017,7 +2201,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
             } else {
                 // Practice mode progress
                 div { class: "exercise-progress",
-                    span { "Exercise {current_idx + 1} of {total_exercises}" }
+                    span { {i18n.t_with("lesson-exercise-label", &[("current", &(current_idx + 1).to_string()), ("total", &total_exercises.to_string())])} }
                     div { class: "progress-bar",
                         div {
                             class: "progress-fill",
@@ -2027,7 +2211,9 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                     span { class: "practice-score", "+{score} XP" }
                     if *correct_count.read() > 0 {
                         span { style: "color: var(--color-success); font-size: var(--font-caption-md);",
-                            " ({correct_count} correct)"
+                            " ("
+                            {i18n.t_with("learn-correct-count", &[("count", &correct_count.to_string())])}
+                            ")"
                         }
                     }
                 }
@@ -2050,7 +2236,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                         None => "exercise-input",
                                     },
                                     r#type: "text",
-                                    placeholder: "Enter your logic translation... (Enter to submit)",
+                                    placeholder: i18n.t("learn-answer-placeholder"),
                                     value: "{user_answer}",
                                     oninput: {
                                         move |e: Event<FormData>| {
@@ -2074,11 +2260,19 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                                             let answer_was_revealed = answer_revealed_exercises.read().contains(&current_idx);
                                                             let already_completed = if is_test_mode { false } else { completed_exercises.read().contains(&current_idx) };
 
-                                                            if answer_was_revealed {
+                                                            if no_stakes {
+
+                                                                let cc = *correct_count.read();
+
+                                                                correct_count.set(cc + 1);
+
+                                                                feedback.set(Some((true, i18n.t("learn-correct-no-stakes"))));
+
+                                                            } else if answer_was_revealed {
                                                                 let cc = *correct_count.read();
                                                                 correct_count.set(cc + 1);
                                                                 completed_exercises.write().insert(current_idx);
-                                                                feedback.set(Some((true, "Correct! (no XP - answer was revealed)".to_string())));
+                                                                feedback.set(Some((true, i18n.t("learn-correct-revealed"))));
                                                             } else if !already_completed {
                                                                 let wrong_count = *exercise_attempts.read().get(&current_idx).unwrap_or(&0);
                                                                 let base_xp = 10u32.saturating_sub(wrong_count * 5);
@@ -2089,32 +2283,35 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                                                     let multiplier = match cs { 0 => 1.0, 1 => 1.25, 2 => 1.5, 3 => 1.75, _ => 2.0 };
                                                                     let xp = ((base_xp as f64) * multiplier).round() as u32;
                                                                     score.set(sc + xp);
+                                                                    bank_xp(learner, xp, cs + 1);
                                                                     streak.set(cs + 1);
                                                                     correct_count.set(cc + 1);
                                                                     completed_exercises.write().insert(current_idx);
-                                                                    let msg = if multiplier > 1.0 { format!("Correct! +{} XP ({}x combo)", xp, multiplier) } else { format!("Correct! +{} XP", xp) };
+                                                                    let msg = if multiplier > 1.0 { i18n.t_with("learn-correct-combo", &[("xp", &xp.to_string()), ("multiplier", &multiplier.to_string())]) } else { i18n.t_with("learn-correct-xp", &[("xp", &xp.to_string())]) };
                                                                     feedback.set(Some((true, msg)));
                                                                 } else {
                                                                     let cc = *correct_count.read();
                                                                     correct_count.set(cc + 1);
                                                                     completed_exercises.write().insert(current_idx);
-                                                                    feedback.set(Some((true, "Correct! (no XP - too many attempts)".to_string())));
+                                                                    feedback.set(Some((true, i18n.t("learn-correct-no-xp"))));
                                                                 }
                                                             } else {
-                                                                feedback.set(Some((true, "Correct! (already completed)".to_string())));
+                                                                feedback.set(Some((true, i18n.t("learn-correct-completed"))));
                                                             }
                                                             struggle_detector.write().record_correct_attempt();
                                                             show_socratic_hint.set(false);
                                                         } else {
                                                             // Wrong answer
                                                             let attempts = exercise_attempts.read().get(&current_idx).copied().unwrap_or(0);
-                                                            exercise_attempts.write().insert(current_idx, attempts + 1);
+                                                            if !no_stakes { exercise_attempts.write().insert(current_idx, attempts + 1); }
                                                             let remaining = 10u32.saturating_sub((attempts + 1) * 5);
-                                                            let penalty_msg = if remaining > 0 { format!(" (-5 XP, {} remaining)", remaining) } else { " (no XP remaining)".to_string() };
+                                                            let penalty_msg = if no_stakes { String::new() } else if remaining > 0 { format!(" {}", i18n.t_with("learn-penalty", &[("remaining", &remaining.to_string())])) } else { format!(" {}", i18n.t("learn-penalty-none")) };
                                                             feedback.set(Some((false, format!("{}{}", result.feedback, penalty_msg))));
                                                             struggle_detector.write().record_wrong_attempt();
                                                             show_socratic_hint.set(true);
-                                                            streak.set(0);
+                                                            if !no_stakes {
+                                                                streak.set(0);
+                                                            }
                                                             if !is_test_mode {
                                                                 let mut queue = retry_queue.write();
                                                                 if !queue.contains(&current_idx) { queue.push_back(current_idx); }
@@ -2147,12 +2344,20 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                                             completed_exercises.read().contains(&current_idx)
                                                         };
 
-                                                        if answer_was_revealed {
+                                                        if no_stakes {
+
+                                                            let cc = *correct_count.read();
+
+                                                            correct_count.set(cc + 1);
+
+                                                            feedback.set(Some((true, i18n.t("learn-correct-no-stakes"))));
+
+                                                        } else if answer_was_revealed {
                                                             // Answer was revealed - no XP
                                                             let current_correct = *correct_count.read();
                                                             correct_count.set(current_correct + 1);
                                                             completed_exercises.write().insert(current_idx);
-                                                            feedback.set(Some((true, "Correct! (no XP - answer was revealed)".to_string())));
+                                                            feedback.set(Some((true, i18n.t("learn-correct-revealed"))));
                                                         } else if !already_completed {
                                                             // Calculate XP based on wrong attempts (each wrong costs 5 XP)
                                                             let wrong_count = *exercise_attempts.read().get(&current_idx).unwrap_or(&0);
@@ -2174,14 +2379,15 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                                                 let xp = ((base_xp as f64) * multiplier).round() as u32;
 
                                                                 score.set(current_score + xp);
+                                                                bank_xp(learner, xp, current_streak + 1);
                                                                 streak.set(current_streak + 1);
                                                                 correct_count.set(current_correct + 1);
                                                                 completed_exercises.write().insert(current_idx);
 
                                                                 let msg = if multiplier > 1.0 {
-                                                                    format!("Correct! +{} XP ({}x combo)", xp, multiplier)
+                                                                    i18n.t_with("learn-correct-combo", &[("xp", &xp.to_string()), ("multiplier", &multiplier.to_string())])
                                                                 } else {
-                                                                    format!("Correct! +{} XP", xp)
+                                                                    i18n.t_with("learn-correct-xp", &[("xp", &xp.to_string())])
                                                                 };
                                                                 feedback.set(Some((true, msg)));
                                                             } else {
@@ -2189,30 +2395,36 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                                                 let current_correct = *correct_count.read();
                                                                 correct_count.set(current_correct + 1);
                                                                 completed_exercises.write().insert(current_idx);
-                                                                feedback.set(Some((true, "Correct! (no XP - too many attempts)".to_string())));
+                                                                feedback.set(Some((true, i18n.t("learn-correct-no-xp"))));
                                                             }
                                                         } else {
                                                             // Already earned XP for this exercise
-                                                            feedback.set(Some((true, "Correct! (already completed)".to_string())));
+                                                            feedback.set(Some((true, i18n.t("learn-correct-completed"))));
                                                         }
                                                         struggle_detector.write().record_correct_attempt();
                                                         show_socratic_hint.set(false);
                                                     } else {
                                                         // Wrong answer - record attempt and show feedback
                                                         let attempts = exercise_attempts.read().get(&current_idx).copied().unwrap_or(0);
-                                                        exercise_attempts.write().insert(current_idx, attempts + 1);
+                                                        if !no_stakes {
+                                                            exercise_attempts.write().insert(current_idx, attempts + 1);
+                                                        }
 
                                                         let remaining = 10u32.saturating_sub((attempts + 1) * 5);
-                                                        let penalty_msg = if remaining > 0 {
-                                                            format!(" (-5 XP, {} remaining)", remaining)
+                                                        let penalty_msg = if no_stakes {
+                                                            String::new()
+                                                        } else if remaining > 0 {
+                                                            format!(" {}", i18n.t_with("learn-penalty", &[("remaining", &remaining.to_string())]))
                                                         } else {
-                                                            " (no XP remaining)".to_string()
+                                                            format!(" {}", i18n.t("learn-penalty-none"))
                                                         };
 
                                                         feedback.set(Some((false, format!("{}{}", result.feedback, penalty_msg))));
                                                         struggle_detector.write().record_wrong_attempt();
                                                         show_socratic_hint.set(true);
-                                                        streak.set(0);
+                                                        if !no_stakes {
+                                                            streak.set(0);
+                                                        }
 
                                                         // In practice mode, add wrong exercise to retry queue (at +3 position)
                                                         if !is_test_mode {
@@ -2227,7 +2439,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                             }
                                         }
                                     },
-                                    "Check"
+                                    {i18n.t("learn-check")}
                                 }
                             }
                         },
@@ -2267,12 +2479,20 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                                                 completed_exercises.read().contains(&current_idx)
                                                             };
 
-                                                            if answer_was_revealed {
+                                                            if no_stakes {
+
+                                                                let cc = *correct_count.read();
+
+                                                                correct_count.set(cc + 1);
+
+                                                                feedback.set(Some((true, i18n.t("learn-correct-no-stakes"))));
+
+                                                            } else if answer_was_revealed {
                                                                 // Answer was revealed - no XP
                                                                 let current_correct = *correct_count.read();
                                                                 correct_count.set(current_correct + 1);
                                                                 completed_exercises.write().insert(current_idx);
-                                                                feedback.set(Some((true, "Correct! (no XP - answer was revealed)".to_string())));
+                                                                feedback.set(Some((true, i18n.t("learn-correct-revealed"))));
                                                             } else if !already_completed {
                                                                 // Calculate XP based on wrong attempts (each wrong costs 5 XP)
                                                                 let wrong_count = *exercise_attempts.read().get(&current_idx).unwrap_or(&0);
@@ -2294,14 +2514,15 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                                                     let xp = ((base_xp as f64) * multiplier).round() as u32;
 
                                                                     score.set(current_score + xp);
+                                                                    bank_xp(learner, xp, current_streak + 1);
                                                                     streak.set(current_streak + 1);
                                                                     correct_count.set(current_correct + 1);
                                                                     completed_exercises.write().insert(current_idx);
 
                                                                     let msg = if multiplier > 1.0 {
-                                                                        format!("Correct! +{} XP ({}x combo)", xp, multiplier)
+                                                                        i18n.t_with("learn-correct-combo", &[("xp", &xp.to_string()), ("multiplier", &multiplier.to_string())])
                                                                     } else {
-                                                                        format!("Correct! +{} XP", xp)
+                                                                        i18n.t_with("learn-correct-xp", &[("xp", &xp.to_string())])
                                                                     };
                                                                     feedback.set(Some((true, msg)));
                                                                 } else {
@@ -2309,29 +2530,35 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                                                     let current_correct = *correct_count.read();
                                                                     correct_count.set(current_correct + 1);
                                                                     completed_exercises.write().insert(current_idx);
-                                                                    feedback.set(Some((true, "Correct! (no XP - too many attempts)".to_string())));
+                                                                    feedback.set(Some((true, i18n.t("learn-correct-no-xp"))));
                                                                 }
                                                             } else {
-                                                                feedback.set(Some((true, "Correct! (already completed)".to_string())));
+                                                                feedback.set(Some((true, i18n.t("learn-correct-completed"))));
                                                             }
                                                             struggle_detector.write().record_correct_attempt();
                                                             show_socratic_hint.set(false);
                                                         } else {
                                                             // Wrong answer - record attempt and show feedback
                                                             let attempts = exercise_attempts.read().get(&current_idx).copied().unwrap_or(0);
-                                                            exercise_attempts.write().insert(current_idx, attempts + 1);
+                                                            if !no_stakes {
+                                                                exercise_attempts.write().insert(current_idx, attempts + 1);
+                                                            }
 
                                                             let remaining = 10u32.saturating_sub((attempts + 1) * 5);
-                                                            let penalty_msg = if remaining > 0 {
-                                                                format!(" (-5 XP, {} remaining)", remaining)
+                                                            let penalty_msg = if no_stakes {
+                                                                String::new()
+                                                            } else if remaining > 0 {
+                                                                format!(" {}", i18n.t_with("learn-penalty", &[("remaining", &remaining.to_string())]))
                                                             } else {
-                                                                " (no XP remaining)".to_string()
+                                                                format!(" {}", i18n.t("learn-penalty-none"))
                                                             };
 
-                                                            feedback.set(Some((false, format!("Not quite.{}", penalty_msg))));
+                                                            feedback.set(Some((false, format!("{}{}", i18n.t("lesson-not-quite"), penalty_msg))));
                                                             struggle_detector.write().record_wrong_attempt();
                                                             show_socratic_hint.set(true);
-                                                            streak.set(0);
+                                                            if !no_stakes {
+                                                                streak.set(0);
+                                                            }
 
                                                             // In practice mode, add wrong exercise to retry queue
                                                             if !is_test_mode {
@@ -2355,10 +2582,15 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                 p { class: "exercise-prompt", "This sentence has {readings.len()} possible interpretations:" }
                                 for (i, reading) in readings.iter().enumerate() {
                                     div { class: "revealed-logic",
-                                        span { class: "revealed-label", "Reading {i + 1}" }
+                                        span { class: "revealed-label", {i18n.t_with("learn-reading", &[("number", &(i + 1).to_string())])} }
                                         "{reading}"
                                     }
                                 }
+                                if let [first, second, ..] = readings.as_slice() {
+                                    if let (Some(a), Some(b)) = (formula_ast(first), formula_ast(second)) {
+                                        AstDiffView { diff: ast_diff(&a, &b), before: "Reading 1", after: "Reading 2" }
+                                    }
+                                }
                             }
                         },
                     }
@@ -2370,13 +2602,17 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                             "{msg}"
                         }
                     }
+                    if let Some(diff) = answer_diff {
+                        AstDiffView { diff, before: "the expected formula", after: "your answer" }
+                    }
 
                     // Socratic hint (triggered by wrong answer or inactivity) - NOT in test mode
                     if !is_test_mode && *show_socratic_hint.read() && hint_text.is_some() {
                         div { class: "socratic-hint-box",
                             div { class: "hint-header",
                                 Icon { variant: IconVariant::Owl, size: IconSize::Medium, color: "#a78bfa" }
-                                " Socrates says..."
+                                " "
+                                {i18n.t("learn-socrates-says")}
                             }
                             div { class: "hint-text",
                                 if let Some(reason) = struggle_detector.read().reason() {
@@ -2393,6 +2629,19 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                     if !is_test_mode {
                     div { class: "reveal-section",
                         div { class: "reveal-buttons",
+                            // No-stakes practice: replay freely with hints open and nothing scored
+                            button {
+                                class: if no_stakes { "reveal-btn active" } else { "reveal-btn" },
+                                onclick: move |_| {
+                                    let next = if play_mode.read().is_practice() { PlayMode::Scored } else { PlayMode::Practice };
+                                    play_mode.set(next);
+                                    feedback.set(None);
+                                },
+                                Icon { variant: IconVariant::Target, size: IconSize::Small }
+                                " "
+                                if no_stakes { {i18n.t("learn-no-stakes-on")} } else { {i18n.t("learn-no-stakes")} }
+                            }
+
                             // Show Hint button - toggles independently
                             button {
                                 class: if reveal_state.read().hint { "reveal-btn active" } else { "reveal-btn" },
@@ -2401,7 +2650,8 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                     reveal_state.write().hint = !current;
                                 },
                                 Icon { variant: IconVariant::Lightning, size: IconSize::Small }
-                                " Show Hint"
+                                " "
+                                {i18n.t("lesson-show-hint")}
                             }
 
                             // Show Answer button - toggles independently, forfeits XP when revealed
@@ -2415,7 +2665,8 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                     }
                                     reveal_state.write().answer = !current;
                                 },
-                                "✓ Show Answer (No XP)"
+                                "✓ "
+                                if no_stakes { {i18n.t("learn-show-solution")} } else { {i18n.t("learn-show-answer")} }
                             }
 
                             // Symbol Dictionary button (only for FreeForm/Ambiguity) - toggles independently
@@ -2427,27 +2678,48 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                         reveal_state.write().symbol_dictionary = !current;
                                     },
                                     Icon { variant: IconVariant::Book, size: IconSize::Small }
-                                    " Symbol Dictionary"
+                                    " "
+                                    {i18n.t("learn-symbol-dictionary")}
                                 }
                             }
                         }
 
                         // Stacked revealed content - each section shows independently
-                        if reveal_state.read().hint {
+                        if reveal_state.read().hint || play_mode.read().hints_open() {
                             div { class: "revealed-content",
-                                div { class: "revealed-label", "Hint" }
+                                div { class: "revealed-label", {i18n.t("learn-hint")} }
                                 if let Some(hint) = hint_text.as_ref() {
                                     p { "{hint}" }
                                 } else {
-                                    p { "No hint available for this exercise." }
+                                    p { {i18n.t("learn-no-hint")} }
                                 }
                             }
                         }
 
                         if reveal_state.read().answer {
                             div { class: "revealed-content",
-                                div { class: "revealed-label", "Correct Answer" }
-                                if let Some(answer) = golden_answer.as_ref() {
+                                div { class: "revealed-label", {i18n.t("learn-correct-answer")} }
+                                if let Some(solution) = walkthrough.as_ref() {
+                                    for answer in solution.answers.iter() {
+                                        div { class: "revealed-logic", "{answer}" }
+                                    }
+                                    if !solution.steps.is_empty() {
+                                        div { class: "revealed-label", {i18n.t("learn-walkthrough")} }
+                                        ol { class: "walkthrough-steps",
+                                            for step in solution.steps.iter() {
+                                                li {
+                                                    class: "walkthrough-step",
+                                                    style: "padding-left: {step.depth * 16}px;",
+                                                    span { class: "walkthrough-rule", "{step.rule}" }
+                                                    "{step.text}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                    if let Some(explanation) = solution.explanation.as_ref() {
+                                        p { style: "margin-top: 12px; color: var(--text-secondary);", "{explanation}" }
+                                    }
+                                } else if let Some(answer) = golden_answer.as_ref() {
                                     div { class: "revealed-logic", "{answer}" }
                                     // Show explanation if available
                                     if let Some(explanation) = challenge.explanation.as_ref() {
@@ -2462,7 +2734,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                 if let Some(answer) = golden_answer.as_ref() {
                                     SymbolDictionary { logic: answer.clone() }
                                 } else {
-                                    p { "No logic output to analyze." }
+                                    p { {i18n.t("learn-no-logic")} }
                                 }
                             }
                         }
@@ -2506,14 +2778,14 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                         feedback.set(None);
                                     }
                                 },
-                                "Submit Answer →"
+                                {i18n.t("learn-submit-answer")} " →"
                             }
                             button {
                                 class: "learn-action-btn secondary",
                                 onclick: move |_| {
                                     practice_mode.set(PracticeMode::Practice);
                                 },
-                                "Exit Test"
+                                {i18n.t("learn-exit-test")}
                             }
                         } else {
                         // Practice mode buttons
@@ -2536,7 +2808,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                     show_socratic_hint.set(false);
                                 }
                             },
-                            "Skip →"
+                            {i18n.t("learn-skip")} " →"
                         }
 
                         if feedback.read().as_ref().map(|(c, _)| *c).unwrap_or(false) {
@@ -2558,7 +2830,7 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                         show_socratic_hint.set(false);
                                     }
                                 },
-                                "Next Exercise →"
+                                {i18n.t("learn-next-exercise")} " →"
                             }
                         }
 
@@ -2577,7 +2849,8 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                                     reveal_state.write().reset();
                                 },
                                 Icon { variant: IconVariant::Target, size: IconSize::Small }
-                                " Take Test"
+                                " "
+                                {i18n.t("learn-take-test")}
                             }
                         }
                         } // end else (practice mode)
@@ -2585,9 +2858,9 @@ fn InteractiveExercisePanelInner(era_id: String, module_id: String) -> Element {
                 }
             } else {
                 div { style: "text-align: center; padding: var(--spacing-xl); color: var(--text-secondary);",
-                    p { "No exercises available for this module yet." }
+                    p { {i18n.t("learn-no-exercises")} }
                     p { style: "font-size: var(--font-caption-md); margin-top: var(--spacing-md);",
-                        "Total loaded: {total_exercises}"
+                        {i18n.t_with("learn-total-loaded", &[("count", &total_exercises.to_string())])}
                     }
                 }
             }
@@ -12,6 +12,11 @@ use crate::content::ContentEngine;
 use crate::generator::{Generator, Challenge, AnswerType};
 use crate::grader::{check_answer, GradeResult};
 use crate::progress::UserProgress;
+use crate::experiments::{Experiments, Outcome};
+use crate::telemetry::Telemetry;
+use crate::tutor::Mistake;
+use crate::ui::a11y::{click, focus, learn_key, Formula, LearnKey};
+use crate::ui::i18n::I18nState;
 use crate::game::{XpReward, ComboResult, calculate_xp_reward, update_combo};
 use crate::achievements::{Achievement, check_achievements, unlock_achievement};
 use crate::audio::{SoundEffect, play_sound};
@@ -206,7 +211,7 @@ const LESSON_STYLE: &str = r#"
     border-radius: var(--radius-lg);
     color: var(--text-primary);
     font-size: var(--font-body-md);
-    text-align: left;
+    text-align: start;
     cursor: pointer;
     transition: all 0.2s ease;
 }
@@ -503,8 +508,8 @@ const LESSON_STYLE: &str = r#"
     color: var(--text-secondary);
     font-size: var(--font-caption-lg);
     line-height: 1.5;
-    padding-left: var(--spacing-lg);
-    border-left: 2px solid rgba(102, 126, 234, 0.3);
+    padding-inline-start: var(--spacing-lg);
+    border-inline-start: 2px solid rgba(102, 126, 234, 0.3);
 }
 
 .textbook-nav {
@@ -558,6 +563,7 @@ pub fn Lesson(era: String, module: String, mode: String) -> Element {
 #[component]
 fn LessonInner(era: String, module: String, mode: String) -> Element {
     let session_mode = SessionMode::from_str(&mode);
+    let i18n = use_context::<I18nState>();
 
     let mut current_index = use_signal(|| 0usize);
     let mut score = use_signal(|| 0u32);
@@ -578,6 +584,7 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
     let mut current_achievement = use_signal(|| None::<&'static Achievement>);
     let mut first_try_tracker = use_signal(|| std::collections::HashSet::<usize>::new());
     let mut mistakes_in_module = use_signal(|| 0u32);
+    let hint_style = use_hook(|| Experiments::load().hint_style());
 
     let engine = ContentEngine::new();
     let generator = Generator::new();
@@ -800,9 +807,18 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                     } else if let Some(challenge) = challenges_read.get(current) {
                         let prompt = challenge.prompt.clone();
                         let sentence = challenge.sentence.clone();
-                        let hint_text = challenge.hint.clone();
+                        let hint_text = challenge.hint.as_deref().map(|hint| hint_style.phrase(hint));
                         let explanation_text = challenge.explanation.clone();
                         let exercise_id = challenge.exercise_id.clone();
+                        let hint_exercise = exercise_id.clone();
+                        let module_for_outcome = module.clone();
+                        let mut reveal_hint = move || {
+                            if !show_hint() {
+                                show_hint.set(true);
+                                Experiments::load().record(Outcome::HintShown { exercise_id: hint_exercise.clone() });
+                            }
+                        };
+                        let mut reveal_hint_on_click = reveal_hint.clone();
 
                         let input_class = if submitted() {
                             if grade_result().map(|r| r.correct).unwrap_or(false) {
@@ -814,8 +830,43 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                             "answer-input"
                         };
 
+                        let typing = matches!(challenge.answer, AnswerType::FreeForm { .. });
+                        let option_count = match &challenge.answer {
+                            AnswerType::MultipleChoice { options, .. } => options.len(),
+                            _ => 0,
+                        };
+                        let has_hint = session_mode.shows_hints() && hint_text.is_some();
+
                         rsx! {
-                            div { class: "problem-card",
+                            div {
+                                class: "problem-card",
+                                role: "group",
+                                aria_label: i18n.t_with("lesson-exercise-label", &[("current", &(current + 1).to_string()), ("total", &total_exercises.to_string())]),
+                                onkeydown: move |e: KeyboardEvent| {
+                                    let command = e.modifiers().ctrl() || e.modifiers().meta();
+                                    let Some(action) = learn_key(&e.key().to_string(), command, typing) else {
+                                        return;
+                                    };
+                                    match action {
+                                        LearnKey::Primary => {
+                                            e.prevent_default();
+                                            click(if submitted() { ".problem-card .next-btn" } else { ".problem-card .submit-btn" });
+                                        }
+                                        LearnKey::Choose(i) if !submitted() && i < option_count => {
+                                            selected_choice.set(Some(i));
+                                            focus(&format!(".problem-card .choice-btn:nth-child({})", i + 1));
+                                        }
+                                        LearnKey::Move(delta) if !submitted() && option_count > 0 => {
+                                            e.prevent_default();
+                                            let from = selected_choice().map_or(if delta > 0 { -1 } else { 0 }, |i| i as isize);
+                                            let i = (from + delta).rem_euclid(option_count as isize) as usize;
+                                            selected_choice.set(Some(i));
+                                            focus(&format!(".problem-card .choice-btn:nth-child({})", i + 1));
+                                        }
+                                        LearnKey::Hint if has_hint && !submitted() => reveal_hint(),
+                                        _ => {}
+                                    }
+                                },
                                 if user_combo > 0 {
                                     div { class: "combo-row",
                                         ComboIndicator {
@@ -834,7 +885,8 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                                         input {
                                             class: "{input_class}",
                                             r#type: "text",
-                                            placeholder: "Enter your answer in FOL...",
+                                            placeholder: i18n.t("lesson-answer-placeholder"),
+                                            aria_label: i18n.t("lesson-answer-label"),
                                             value: "{answer}",
                                             disabled: submitted(),
                                             oninput: move |e| answer.set(e.value()),
@@ -845,7 +897,7 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                                         let opts = options.clone();
                                         let show_result_colors = session_mode.shows_immediate_feedback();
                                         rsx! {
-                                            div { class: "multiple-choice",
+                                            div { class: "multiple-choice", role: "radiogroup", aria_label: i18n.t("lesson-choices-label"),
                                                 for (i, option) in opts.iter().enumerate() {
                                                     {
                                                         let btn_class = if submitted() && show_result_colors {
@@ -864,6 +916,9 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                                                         rsx! {
                                                             button {
                                                                 class: "{btn_class}",
+                                                                role: "radio",
+                                                                aria_checked: "{selected_choice() == Some(i)}",
+                                                                aria_keyshortcuts: "{i + 1}",
                                                                 disabled: submitted(),
                                                                 onclick: move |_| selected_choice.set(Some(i)),
                                                                 MixedText { content: option.clone() }
@@ -879,7 +934,7 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                                         rsx! {
                                             div { class: "reading-list",
                                                 for reading in rds.iter() {
-                                                    div { class: "reading-item", "{reading}" }
+                                                    div { class: "reading-item", Formula { text: reading.clone() } }
                                                 }
                                             }
                                         }
@@ -897,7 +952,7 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                                                 "feedback-box feedback-incorrect"
                                             };
                                             rsx! {
-                                                div { class: "{fb_class}", "{result.feedback}" }
+                                                div { class: "{fb_class}", role: "status", aria_live: "polite", "{result.feedback}" }
                                             }
                                         }
                                     }
@@ -906,22 +961,23 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                                 if session_mode.shows_explanation() && submitted() && !grade_result().map(|r| r.correct).unwrap_or(true) {
                                     if let Some(ref expl) = explanation_text {
                                         div { class: "explanation-box",
-                                            strong { "Explanation: " }
+                                            strong { {i18n.t("lesson-explanation")} " " }
                                             MixedText { content: expl.clone() }
                                         }
                                     }
                                 }
 
                                 if session_mode.shows_hints() && show_hint() && hint_text.is_some() {
-                                    div { class: "hint-box", "{hint_text.as_ref().unwrap()}" }
+                                    div { class: "hint-box", aria_live: "polite", "{hint_text.as_ref().unwrap()}" }
                                 }
 
                                 div { class: "action-row",
                                     if session_mode.shows_hints() && !submitted() && hint_text.is_some() {
                                         button {
                                             class: "hint-btn",
-                                            onclick: move |_| show_hint.set(true),
-                                            "Show Hint"
+                                            aria_keyshortcuts: "h",
+                                            onclick: move |_| reveal_hint_on_click(),
+                                            {i18n.t("lesson-show-hint")}
                                         }
                                     } else {
                                         div {}
@@ -931,6 +987,12 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                                         button {
                                             class: "next-btn",
                                             onclick: move |_| {
+                                                if current + 1 >= total_exercises {
+                                                    Experiments::load().record(Outcome::ModuleCompleted {
+                                                        module_id: module_for_outcome.clone(),
+                                                        stars: module_stars(mistakes_in_module(), total_exercises),
+                                                    });
+                                                }
                                                 current_index.set(current_index() + 1);
                                                 answer.set(String::new());
                                                 selected_choice.set(None);
@@ -939,9 +1001,9 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                                                 show_hint.set(false);
                                             },
                                             if current + 1 >= total_exercises {
-                                                "Complete Module"
+                                                {i18n.t("lesson-complete-module")}
                                             } else {
-                                                "Next Problem"
+                                                {i18n.t("lesson-next")}
                                             }
                                         }
                                     } else {
@@ -958,6 +1020,7 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                                             rsx! {
                                                 button {
                                                     class: "submit-btn",
+                                                    aria_keyshortcuts: "Control+Enter",
                                                     disabled: !can_submit,
                                                     onclick: move |_| {
                                                         let is_correct = match &answer_clone {
@@ -980,14 +1043,14 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                                                                         correct: true,
                                                                         partial: false,
                                                                         score: 100,
-                                                                        feedback: "Correct!".to_string(),
+                                                                        feedback: i18n.t("lesson-correct"),
                                                                     }
                                                                 } else {
                                                                     GradeResult {
                                                                         correct: false,
                                                                         partial: false,
                                                                         score: 0,
-                                                                        feedback: "Not quite.".to_string(),
+                                                                        feedback: i18n.t("lesson-not-quite"),
                                                                     }
                                                                 };
                                                                 grade_result.set(Some(result));
@@ -998,7 +1061,7 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                                                                     correct: true,
                                                                     partial: false,
                                                                     score: 100,
-                                                                    feedback: "Good analysis!".to_string(),
+                                                                    feedback: i18n.t("lesson-good-analysis"),
                                                                 }));
                                                                 score.set(score() + 100);
                                                                 true
@@ -1012,13 +1075,30 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                                                             let mut prog = progress.write();
                                                             prog.record_attempt(&ex_id, is_correct);
 
+                                                            let mut experiments = Experiments::load();
+                                                            experiments.record(Outcome::Attempt {
+                                                                exercise_id: ex_id.clone(),
+                                                                correct: is_correct,
+                                                                first_try: is_first_try,
+                                                            });
+
+                                                            let mut telemetry = Telemetry::load();
+                                                            if is_correct {
+                                                                telemetry.record_completion(answer_clone.kind());
+                                                            } else if let AnswerType::FreeForm { golden_logic } = &answer_clone {
+                                                                let mistake = Mistake::classify_formalization(&answer.read(), golden_logic);
+                                                                telemetry.record_error(mistake.id());
+                                                            } else {
+                                                                telemetry.record_error(answer_clone.kind());
+                                                            }
+
                                                             let cr = update_combo(&mut prog, is_correct);
                                                             combo_result.set(cr.clone());
 
                                                             if is_correct {
                                                                 play_sound(SoundEffect::Correct);
 
-                                                                let xp_mult = session_mode.xp_multiplier();
+                                                                let xp_mult = session_mode.xp_multiplier() * experiments.xp_multiplier();
                                                                 if xp_mult > 0.0 {
                                                                     let rng_seed = (prog.xp + current as u64) % 100;
                                                                     let mut reward = calculate_xp_reward(
@@ -1061,7 +1141,7 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
 
                                                         submitted.set(true);
                                                     },
-                                                    "Check Answer"
+                                                    {i18n.t("lesson-check")}
                                                 }
                                             }
                                         }
@@ -1072,7 +1152,7 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
                     } else {
                         rsx! {
                             div { class: "problem-card",
-                                p { "Loading exercises..." }
+                                p { {i18n.t("lesson-loading")} }
                             }
                         }
                     }
@@ -1081,3 +1161,13 @@ fn LessonInner(era: String, module: String, mode: String) -> Element {
         }
     }
 }
+
+/// Stars for a finished module: three when flawless, two with mistakes on at
+/// most a fifth of the exercises, one otherwise.
+fn module_stars(mistakes: u32, exercises: usize) -> u8 {
+    match mistakes as usize {
+        0 => 3,
+        m if m * 5 <= exercises => 2,
+        _ => 1,
+    }
+}
@@ -13,6 +13,8 @@
 //! ## Studio & Tools
 //! - [`Studio`] - Multi-mode playground for Logic, Code, and Math
 //! - [`Workspace`] - Subject-specific workspace with sidebar and inspector
+//! - [`Notebook`] - Cells of code, logic, and prose that build on each other
+//! - [`Exam`] - Timed test across chosen modules, scored by concept
 //!
 //! ## Marketing & Info
 //! - [`Landing`] - Marketing homepage with feature highlights
@@ -51,6 +53,8 @@ pub mod crates;
 pub mod profile;
 pub mod news;
 pub mod benchmarks;
+pub mod notebook;
+pub mod exam;
 
 pub use landing::Landing;
 pub use learn::Learn;
@@ -66,3 +70,5 @@ pub use crates::Crates;
 pub use profile::Profile;
 pub use news::{News, NewsArticle};
 pub use benchmarks::Benchmarks;
+pub use notebook::Notebook;
+pub use exam::Exam;
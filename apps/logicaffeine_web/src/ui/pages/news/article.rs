@@ -392,8 +392,9 @@ pub fn NewsArticle(slug: String) -> Element {
     }
 }
 
-/// Simple markdown to HTML converter for article content
-fn markdown_to_html(markdown: &str) -> String {
+/// Simple markdown to HTML converter for article content (and notebook
+/// Markdown cells)
+pub(crate) fn markdown_to_html(markdown: &str) -> String {
     let mut html = String::new();
     let mut in_code_block = false;
     let mut in_list = false;
@@ -428,15 +429,15 @@ fn markdown_to_html(markdown: &str) -> String {
 
         // Headers
         if trimmed.starts_with("### ") {
-            html.push_str(&format!("<h3>{}</h3>\n", &trimmed[4..]));
+            html.push_str(&format!("<h3>{}</h3>\n", inline_markdown(&trimmed[4..])));
         } else if trimmed.starts_with("## ") {
-            html.push_str(&format!("<h2>{}</h2>\n", &trimmed[3..]));
+            html.push_str(&format!("<h2>{}</h2>\n", inline_markdown(&trimmed[3..])));
         } else if trimmed.starts_with("# ") {
-            html.push_str(&format!("<h1>{}</h1>\n", &trimmed[2..]));
+            html.push_str(&format!("<h1>{}</h1>\n", inline_markdown(&trimmed[2..])));
         }
         // Blockquotes
         else if trimmed.starts_with("> ") {
-            html.push_str(&format!("<blockquote>{}</blockquote>\n", &trimmed[2..]));
+            html.push_str(&format!("<blockquote>{}</blockquote>\n", inline_markdown(&trimmed[2..])));
         }
         // Unordered lists
         else if trimmed.starts_with("- ") || trimmed.starts_with("* ") {
@@ -510,7 +511,13 @@ fn inline_markdown(text: &str) -> String {
                 let end = mid + 2 + end;
                 let text = &result[start + 1..mid];
                 let url = &result[mid + 2..end];
-                result = format!("{}<a href=\"{}\">{}</a>{}", &result[..start], url, text, &result[end + 1..]);
+                // Only web and in-site links: notebooks are user content
+                let link = if ["https://", "http://", "/", "#"].iter().any(|p| url.starts_with(p)) {
+                    format!("<a href=\"{}\">{}</a>", url.replace('"', "&quot;"), text)
+                } else {
+                    text.to_string()
+                };
+                result = format!("{}{}{}", &result[..start], link, &result[end + 1..]);
                 continue;
             }
         }
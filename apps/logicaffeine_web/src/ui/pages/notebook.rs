@@ -0,0 +1,480 @@
+//! REPL-style notebook page.
+//!
+//! Cells of LOGOS code, English logic, and Markdown that run top to bottom and
+//! build on each other (see [`crate::notebook`]):
+//! - **Run all** evaluates every cell; Shift+Enter in any cell does the same
+//! - **Save** writes the notebook to the VFS under `/notebooks`, and the saved
+//!   list reopens it
+//! - **Export** copies the notebook's portable JSON; **Import** loads one
+//!
+//! # Route
+//!
+//! Accessed via [`Route::Notebook`](crate::ui::router::Route::Notebook).
+
+use dioxus::prelude::*;
+#[cfg(all(feature = "split", target_arch = "wasm32"))]
+use dioxus::wasm_split;
+use crate::notebook::{Cell, CellKind, CellOutput, Checkpoints, Notebook as NotebookData};
+use crate::ui::components::main_nav::{MainNav, ActivePage};
+use crate::ui::components::footer::Footer;
+use crate::ui::pages::news::article::markdown_to_html;
+use crate::ui::seo::{JsonLdMultiple, PageHead, organization_schema, breadcrumb_schema, webpage_schema, BreadcrumbItem, pages as seo_pages};
+#[cfg(target_arch = "wasm32")]
+use crate::notebook::{NOTEBOOK_DIR, NOTEBOOK_EXTENSION};
+#[cfg(target_arch = "wasm32")]
+use logicaffeine_system::fs::{get_platform_vfs_with_fallback, Vfs, WebVfs};
+
+const NOTEBOOK_STYLE: &str = r#"
+.notebook-page {
+    min-height: 100vh;
+    color: var(--text-primary);
+    background: linear-gradient(180deg, #070a12, #0b1022 55%, #070a12);
+    font-family: var(--font-sans);
+}
+
+.notebook-main {
+    max-width: 960px;
+    margin: 0 auto;
+    padding: 32px var(--spacing-xl) 80px;
+}
+
+.notebook-toolbar {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    gap: 8px;
+    margin-bottom: 12px;
+}
+
+.notebook-title {
+    flex: 1;
+    min-width: 200px;
+    padding: 8px 12px;
+    border: 1px solid rgba(255, 255, 255, 0.1);
+    border-radius: 8px;
+    background: rgba(255, 255, 255, 0.04);
+    color: var(--text-primary);
+    font-size: 18px;
+    font-weight: 600;
+}
+
+.notebook-btn {
+    padding: 6px 12px;
+    border: 1px solid rgba(255, 255, 255, 0.1);
+    border-radius: 6px;
+    background: rgba(255, 255, 255, 0.06);
+    color: var(--text-secondary);
+    font-size: 13px;
+    cursor: pointer;
+}
+
+.notebook-btn:hover {
+    border-color: rgba(255, 255, 255, 0.2);
+    color: var(--text-primary);
+}
+
+.notebook-btn.primary {
+    border: none;
+    background: linear-gradient(135deg, #667eea 0%, #764ba2 100%);
+    color: white;
+    font-weight: 600;
+}
+
+.notebook-status {
+    margin: 0 0 12px;
+    font-size: 13px;
+    color: var(--text-secondary);
+}
+
+.notebook-saved {
+    display: flex;
+    flex-wrap: wrap;
+    gap: 6px;
+    margin-bottom: 16px;
+    font-size: 13px;
+    color: var(--text-secondary);
+}
+
+.notebook-import textarea,
+.notebook-cell textarea {
+    width: 100%;
+    box-sizing: border-box;
+    padding: 10px 12px;
+    border: none;
+    background: #0f1419;
+    color: #e5e7eb;
+    font-family: 'SF Mono', 'Fira Code', monospace;
+    font-size: 13px;
+    line-height: 1.5;
+    resize: vertical;
+}
+
+.notebook-import {
+    margin-bottom: 16px;
+}
+
+.notebook-cell {
+    margin-bottom: 16px;
+    border: 1px solid rgba(255, 255, 255, 0.08);
+    border-radius: 10px;
+    overflow: hidden;
+    background: rgba(255, 255, 255, 0.02);
+}
+
+.notebook-cell-header {
+    display: flex;
+    align-items: center;
+    gap: 6px;
+    padding: 6px 10px;
+    border-bottom: 1px solid rgba(255, 255, 255, 0.06);
+    font-size: 12px;
+    color: var(--text-secondary);
+}
+
+.notebook-cell-header .spacer {
+    flex: 1;
+}
+
+.notebook-cell-header select,
+.notebook-cell-header button {
+    padding: 2px 8px;
+    border: 1px solid rgba(255, 255, 255, 0.1);
+    border-radius: 4px;
+    background: transparent;
+    color: var(--text-secondary);
+    font-size: 12px;
+    cursor: pointer;
+}
+
+.notebook-output {
+    padding: 8px 12px;
+    border-top: 1px solid rgba(255, 255, 255, 0.06);
+    font-family: 'SF Mono', 'Fira Code', monospace;
+    font-size: 13px;
+    white-space: pre-wrap;
+    color: #4ade80;
+}
+
+.notebook-output .error {
+    color: #f87171;
+}
+
+.notebook-prose {
+    padding: 4px 16px;
+    line-height: 1.6;
+}
+
+.notebook-add {
+    display: flex;
+    gap: 8px;
+}
+"#;
+
+#[component(lazy)]
+pub fn Notebook() -> Element {
+    let mut notebook = use_signal(NotebookData::starter);
+    let mut outputs = use_signal(Vec::<CellOutput>::new);
+    // The logic session after each cell, so Run all only translates what changed
+    let mut checkpoints = use_signal(Checkpoints::default);
+    let mut status = use_signal(|| None::<String>);
+    let mut saved = use_signal(Vec::<String>::new);
+    let mut import_open = use_signal(|| false);
+    let mut import_text = use_signal(String::new);
+    #[cfg(target_arch = "wasm32")]
+    let mut vfs_handle = use_signal(|| None::<WebVfs>);
+
+    // The saved list comes from the VFS once it is up
+    #[cfg(target_arch = "wasm32")]
+    use_effect(move || {
+        spawn(async move {
+            match get_platform_vfs_with_fallback().await {
+                Ok(vfs) => {
+                    let _ = vfs.create_dir_all(NOTEBOOK_DIR).await;
+                    saved.set(list_saved(&vfs).await);
+                    vfs_handle.set(Some(vfs));
+                }
+                Err(e) => status.set(Some(format!("Saving is unavailable: {e:?}"))),
+            }
+        });
+    });
+
+    let mut run_all = move || {
+        outputs.set(notebook.peek().run_resuming(&mut checkpoints.write()));
+        status.set(None);
+    };
+
+    let mut edit_cells = move |edit: &dyn Fn(&mut Vec<Cell>)| {
+        edit(&mut notebook.write().cells);
+        outputs.set(Vec::new());
+    };
+
+    let save = move |_| {
+        let current = notebook.peek().clone();
+        #[cfg(target_arch = "wasm32")]
+        spawn(async move {
+            let Some(vfs) = vfs_handle.peek().clone() else {
+                status.set(Some("Saving is unavailable".to_string()));
+                return;
+            };
+            let path = current.path();
+            match vfs.write(&path, current.to_json().as_bytes()).await {
+                Ok(()) => {
+                    saved.set(list_saved(&vfs).await);
+                    status.set(Some(format!("Saved to {path}")));
+                }
+                Err(e) => status.set(Some(format!("Failed to save notebook: {e:?}"))),
+            }
+        });
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = current;
+            status.set(Some("VFS not available on native".to_string()));
+        }
+    };
+
+    let export = move |_| {
+        let json = notebook.peek().to_json();
+        #[cfg(target_arch = "wasm32")]
+        if let Some(window) = web_sys::window() {
+            let _ = window.navigator().clipboard().write_text(&json);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = json;
+        status.set(Some("Notebook JSON copied".to_string()));
+    };
+
+    let import = move |_| match NotebookData::from_json(&import_text.peek()) {
+        Ok(loaded) => {
+            notebook.set(loaded);
+            outputs.set(Vec::new());
+            import_open.set(false);
+            import_text.set(String::new());
+            status.set(None);
+        }
+        Err(e) => status.set(Some(e)),
+    };
+
+    let current = notebook();
+    let results = outputs();
+
+    rsx! {
+        PageHead {
+            title: seo_pages::NOTEBOOK.title,
+            description: seo_pages::NOTEBOOK.description,
+            canonical_path: seo_pages::NOTEBOOK.canonical_path,
+        }
+        style { "{NOTEBOOK_STYLE}" }
+        JsonLdMultiple { schemas: vec![
+            organization_schema(),
+            webpage_schema(seo_pages::NOTEBOOK.title, seo_pages::NOTEBOOK.description, seo_pages::NOTEBOOK.canonical_path),
+            breadcrumb_schema(&[
+                BreadcrumbItem { name: "Home", path: "/" },
+                BreadcrumbItem { name: "Notebook", path: "/notebook" },
+            ]),
+        ] }
+
+        div { class: "notebook-page",
+            MainNav { active: ActivePage::Studio, subtitle: Some("Notebook") }
+
+            main { class: "notebook-main",
+                div { class: "notebook-toolbar",
+                    input {
+                        class: "notebook-title",
+                        aria_label: "Notebook title",
+                        value: "{current.title}",
+                        oninput: move |e| notebook.write().title = e.value(),
+                    }
+                    button { class: "notebook-btn primary", onclick: move |_| run_all(), "\u{25B6} Run all" }
+                    button {
+                        class: "notebook-btn",
+                        onclick: move |_| {
+                            let mut fresh = NotebookData::new("Untitled notebook");
+                            fresh.cells.push(Cell::new(CellKind::Code, ""));
+                            notebook.set(fresh);
+                            outputs.set(Vec::new());
+                        },
+                        "New"
+                    }
+                    button { class: "notebook-btn", onclick: save, "Save" }
+                    button { class: "notebook-btn", onclick: export, "Export" }
+                    button {
+                        class: "notebook-btn",
+                        onclick: move |_| import_open.toggle(),
+                        "Import"
+                    }
+                }
+
+                if let Some(message) = status() {
+                    p { class: "notebook-status", role: "status", "{message}" }
+                }
+
+                if !saved().is_empty() {
+                    div { class: "notebook-saved",
+                        span { "Saved:" }
+                        for path in saved() {
+                            button {
+                                key: "{path}",
+                                class: "notebook-btn",
+                                onclick: {
+                                    let path = path.clone();
+                                    move |_| {
+                                        #[cfg(target_arch = "wasm32")]
+                                        open_saved(path.clone(), vfs_handle, notebook, outputs, status);
+                                        #[cfg(not(target_arch = "wasm32"))]
+                                        let _ = &path;
+                                    }
+                                },
+                                {saved_name(&path)}
+                            }
+                        }
+                    }
+                }
+
+                if import_open() {
+                    div { class: "notebook-import",
+                        textarea {
+                            rows: "8",
+                            placeholder: "Paste notebook JSON",
+                            aria_label: "Notebook JSON to import",
+                            value: "{import_text}",
+                            oninput: move |e| import_text.set(e.value()),
+                        }
+                        button { class: "notebook-btn primary", onclick: import, "Load" }
+                    }
+                }
+
+                for (i, cell) in current.cells.iter().enumerate() {
+                    div { key: "{i}", class: "notebook-cell",
+                        div { class: "notebook-cell-header",
+                            select {
+                                aria_label: "Cell kind",
+                                onchange: move |e| {
+                                    let kind = match e.value().as_str() {
+                                        "logic" => CellKind::Logic,
+                                        "markdown" => CellKind::Markdown,
+                                        _ => CellKind::Code,
+                                    };
+                                    edit_cells(&|cells| cells[i].kind = kind);
+                                },
+                                for kind in [CellKind::Code, CellKind::Logic, CellKind::Markdown] {
+                                    option {
+                                        value: "{kind.label().to_lowercase()}",
+                                        selected: kind == cell.kind,
+                                        "{kind.label()}"
+                                    }
+                                }
+                            }
+                            span { class: "spacer", "[{i + 1}]" }
+                            button {
+                                title: "Move up",
+                                disabled: i == 0,
+                                onclick: move |_| edit_cells(&|cells| cells.swap(i - 1, i)),
+                                "\u{2191}"
+                            }
+                            button {
+                                title: "Move down",
+                                disabled: i + 1 == current.cells.len(),
+                                onclick: move |_| edit_cells(&|cells| cells.swap(i, i + 1)),
+                                "\u{2193}"
+                            }
+                            button {
+                                title: "Delete cell",
+                                onclick: move |_| edit_cells(&|cells| { cells.remove(i); }),
+                                "\u{2715}"
+                            }
+                        }
+                        textarea {
+                            rows: "{cell.source.lines().count().max(2)}",
+                            spellcheck: "false",
+                            aria_label: "Cell {i + 1} source",
+                            value: "{cell.source}",
+                            oninput: move |e| notebook.write().cells[i].source = e.value(),
+                            onkeydown: move |e: KeyboardEvent| {
+                                if e.key() == Key::Enter && e.modifiers().shift() {
+                                    e.prevent_default();
+                                    run_all();
+                                }
+                            },
+                        }
+                        if cell.kind == CellKind::Markdown {
+                            div { class: "notebook-prose", dangerous_inner_html: markdown_to_html(&cell.source) }
+                        }
+                        if let Some(output) = results.get(i).filter(|o| !o.lines.is_empty() || o.error.is_some()) {
+                            div { class: "notebook-output", role: "log",
+                                for (n, line) in output.lines.iter().enumerate() {
+                                    div { key: "{n}", "{line}" }
+                                }
+                                if let Some(error) = &output.error {
+                                    div { class: "error", role: "alert", "{error}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "notebook-add",
+                    for kind in [CellKind::Code, CellKind::Logic, CellKind::Markdown] {
+                        button {
+                            key: "{kind.label()}",
+                            class: "notebook-btn",
+                            onclick: move |_| edit_cells(&|cells| cells.push(Cell::new(kind, ""))),
+                            "+ {kind.label()}"
+                        }
+                    }
+                }
+            }
+
+            Footer {}
+        }
+    }
+}
+
+/// The paths of the notebooks saved in the VFS, sorted.
+#[cfg(target_arch = "wasm32")]
+async fn list_saved(vfs: &WebVfs) -> Vec<String> {
+    let mut paths: Vec<String> = vfs
+        .list_dir(NOTEBOOK_DIR)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|entry| !entry.is_directory && entry.name.ends_with(NOTEBOOK_EXTENSION))
+        .map(|entry| format!("{NOTEBOOK_DIR}/{}", entry.name))
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Replace the open notebook with the one saved at `path`.
+#[cfg(target_arch = "wasm32")]
+fn open_saved(
+    path: String,
+    vfs_handle: Signal<Option<WebVfs>>,
+    mut notebook: Signal<NotebookData>,
+    mut outputs: Signal<Vec<CellOutput>>,
+    mut status: Signal<Option<String>>,
+) {
+    spawn(async move {
+        let Some(vfs) = vfs_handle.peek().clone() else {
+            return;
+        };
+        let loaded = vfs
+            .read_to_string(&path)
+            .await
+            .map_err(|e| format!("Failed to read {path}: {e:?}"))
+            .and_then(|json| NotebookData::from_json(&json));
+        match loaded {
+            Ok(loaded) => {
+                notebook.set(loaded);
+                outputs.set(Vec::new());
+                status.set(None);
+            }
+            Err(e) => status.set(Some(e)),
+        }
+    });
+}
+
+/// A saved notebook's name as listed: its file name without the extension.
+fn saved_name(path: &str) -> String {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    name.strip_suffix(crate::notebook::NOTEBOOK_EXTENSION).unwrap_or(name).to_string()
+}
@@ -4,6 +4,10 @@
 //!
 //! - **Stats**: Total XP, level, streak days, modules completed
 //! - **Era Progress**: Progress bars for each curriculum era
+//! - **Reviews**: Review heatmap, retention by interval and interval growth,
+//!   exportable as JSON
+//! - **Settings**: Accessibility, sound theme and volume mixer, custom theme
+//!   and usage data
 //! - **Achievements**: Earned and locked achievement badges
 //!
 //! # Route
@@ -18,7 +22,17 @@ use crate::ui::components::footer::Footer;
 use crate::ui::components::icon::{Icon, IconVariant, IconSize};
 use crate::ui::seo::{JsonLdMultiple, PageHead, organization_schema, profile_page_schema, breadcrumb_schema, BreadcrumbItem, pages as seo_pages};
 use crate::progress::UserProgress;
+use crate::stats::ReviewStats;
+use crate::audio::{play_combo, AudioSettings, Channel, SoundTheme};
 use crate::content::ContentEngine;
+use crate::generator::{Challenge, Generator};
+use crate::srs::{export_anki, import_review_history};
+use crate::telemetry::Telemetry;
+use crate::experiments::Experiments;
+use crate::ui::a11y::{A11yState, Contrast, FormulaSpeech};
+use crate::ui::components::locale_picker::LocalePicker;
+use crate::ui::components::theme_editor::ThemeEditor;
+use crate::ui::i18n::I18nState;
 
 const PROFILE_STYLE: &str = r#"
 .profile-page {
@@ -174,6 +188,55 @@ const PROFILE_STYLE: &str = r#"
     color: var(--text-tertiary);
 }
 
+.review-heatmap {
+    display: grid;
+    grid-template-rows: repeat(7, 11px);
+    grid-auto-flow: column;
+    grid-auto-columns: 11px;
+    gap: 3px;
+    overflow-x: auto;
+    padding-bottom: var(--spacing-sm);
+    margin-bottom: var(--spacing-lg);
+}
+
+.heat-cell {
+    border-radius: 2px;
+    background: rgba(255, 255, 255, 0.06);
+}
+
+.heat-cell.level-1 { background: rgba(74, 222, 128, 0.25); }
+.heat-cell.level-2 { background: rgba(74, 222, 128, 0.45); }
+.heat-cell.level-3 { background: rgba(74, 222, 128, 0.7); }
+.heat-cell.level-4 { background: #4ade80; }
+
+.review-figures {
+    display: grid;
+    grid-template-columns: repeat(auto-fit, minmax(240px, 1fr));
+    gap: var(--spacing-lg);
+}
+
+.review-row {
+    display: grid;
+    grid-template-columns: 90px 1fr 90px;
+    align-items: center;
+    gap: var(--spacing-sm);
+    margin-bottom: var(--spacing-xs);
+}
+
+.review-row .progress-bar-container {
+    margin-bottom: 0;
+}
+
+.review-export {
+    padding: 8px 20px;
+    border-radius: var(--radius-md);
+    border: 1px solid rgba(255, 255, 255, 0.2);
+    background: rgba(255, 255, 255, 0.08);
+    color: var(--text-primary);
+    cursor: pointer;
+    margin-top: var(--spacing-md);
+}
+
 .achievements-grid {
     display: grid;
     grid-template-columns: repeat(auto-fill, minmax(140px, 1fr));
@@ -215,12 +278,71 @@ const PROFILE_STYLE: &str = r#"
     padding: var(--spacing-xxl);
     color: var(--text-tertiary);
 }
+
+.a11y-option,
+.telemetry-opt-in {
+    display: flex;
+    align-items: center;
+    gap: var(--spacing-sm);
+    color: var(--text-primary);
+    margin-bottom: var(--spacing-md);
+    cursor: pointer;
+}
+
+.sound-mixer {
+    display: grid;
+    grid-template-columns: 120px minmax(0, 320px) 48px;
+    align-items: center;
+    gap: var(--spacing-sm) var(--spacing-md);
+    margin-bottom: var(--spacing-md);
+    color: var(--text-primary);
+}
+
+.sound-mixer input[type="range"] {
+    width: 100%;
+}
+
+.anki-history {
+    display: block;
+    width: 100%;
+    box-sizing: border-box;
+    margin-top: var(--spacing-md);
+    padding: 10px 12px;
+    border: 1px solid rgba(255, 255, 255, 0.1);
+    border-radius: var(--radius-md);
+    background: rgba(255, 255, 255, 0.04);
+    color: var(--text-primary);
+    font-family: monospace;
+}
+
+.telemetry-preview {
+    background: rgba(255, 255, 255, 0.04);
+    border: 1px solid rgba(255, 255, 255, 0.08);
+    border-radius: var(--radius-lg);
+    padding: var(--spacing-lg);
+    font-size: var(--font-caption-md);
+    color: var(--text-secondary);
+    overflow-x: auto;
+}
+
+.telemetry-send {
+    padding: 8px 20px;
+    border-radius: var(--radius-md);
+    border: 1px solid rgba(255, 255, 255, 0.2);
+    background: rgba(255, 255, 255, 0.08);
+    color: var(--text-primary);
+    cursor: pointer;
+}
 "#;
 
 #[component(lazy)]
 pub fn Profile() -> Element {
-    let progress = UserProgress::new(); // TODO: Load from storage
+    let progress = UserProgress::load();
     let engine = ContentEngine::new();
+    let mut telemetry = use_signal(Telemetry::load);
+    let mut sound = use_signal(AudioSettings::load);
+    let mut a11y = use_context::<A11yState>();
+    let i18n = use_context::<I18nState>();
 
     // Calculate totals
     let _total_exercises: usize = engine.eras()
@@ -232,6 +354,80 @@ pub fn Profile() -> Element {
     let completed_modules = progress.modules.values().filter(|m| m.completed).count();
     let total_modules: usize = engine.eras().iter().map(|e| e.modules.len()).sum();
 
+    let reviews = ReviewStats::compute(&progress, &crate::game::today());
+    let longest_growth = reviews.interval_growth.iter().map(|p| p.mean_interval).fold(1.0, f64::max);
+    let mut export_status = use_signal(|| None::<&'static str>);
+    let export_json = reviews.to_json();
+    let export = move |_| {
+        #[cfg(target_arch = "wasm32")]
+        if let Some(window) = web_sys::window() {
+            let _ = window.navigator().clipboard().write_text(&export_json);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = &export_json;
+        export_status.set(Some("Review statistics copied as JSON"));
+    };
+    let mut anki_status = use_signal(|| None::<String>);
+    let mut anki_history = use_signal(String::new);
+    // The profile page sits outside the `LexiconGate`, so the export pins the
+    // lexicon itself before rendering each studied exercise's card.
+    let copy_anki_deck = move |_| {
+        spawn(async move {
+            if let Err(error) = crate::generator::ensure_lexicon().await {
+                anki_status.set(Some(i18n.t_with("profile-anki-export-failed", &[("error", &error)])));
+                return;
+            }
+            let progress = UserProgress::load();
+            let engine = ContentEngine::new();
+            let generator = Generator::new();
+            let mut rng = rand::thread_rng();
+            let challenges: Vec<Challenge> = engine.eras()
+                .iter()
+                .flat_map(|e| e.modules.iter())
+                .flat_map(|m| m.exercises.iter())
+                .filter(|exercise| progress.get_exercise_progress(&exercise.id).is_some())
+                .filter_map(|exercise| generator.generate(exercise, &mut rng))
+                .collect();
+            let tsv = export_anki(&progress, &challenges, &crate::game::today());
+            #[cfg(target_arch = "wasm32")]
+            if let Some(window) = web_sys::window() {
+                let _ = window.navigator().clipboard().write_text(&tsv);
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = tsv;
+            let count = challenges.len().to_string();
+            anki_status.set(Some(i18n.t_with("profile-anki-copied", &[("count", &count)])));
+        });
+    };
+    let import_anki_history = move |_| {
+        let history = anki_history();
+        let mut progress = UserProgress::load();
+        let status = match import_review_history(&mut progress, &history) {
+            Ok(applied) => {
+                progress.save();
+                anki_history.set(String::new());
+                i18n.t_with("profile-anki-imported", &[("count", &applied.to_string())])
+            }
+            Err(error) => i18n.t_with("profile-anki-import-failed", &[("error", &error)]),
+        };
+        anki_status.set(Some(status));
+    };
+    let mut experiments_status = use_signal(|| None::<&'static str>);
+    let copy_experiments = move |_| {
+        let json = Experiments::load().export_json();
+        #[cfg(target_arch = "wasm32")]
+        if let Some(window) = web_sys::window() {
+            let _ = window.navigator().clipboard().write_text(&json);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = json;
+        experiments_status.set(Some("profile-experiments-copied"));
+    };
+    let clear_experiments = move |_| {
+        Experiments::load().clear_events();
+        experiments_status.set(Some("profile-experiments-cleared"));
+    };
+
     let breadcrumbs = vec![
         BreadcrumbItem { name: "Home", path: "/" },
         BreadcrumbItem { name: "Profile", path: "/profile" },
@@ -316,6 +512,87 @@ pub fn Profile() -> Element {
                     }
                 }
 
+                // Reviews: heatmap, retention and interval growth
+                div { class: "profile-section",
+                    h2 { class: "profile-section-title", "Reviews" }
+
+                    div { class: "review-heatmap",
+                        for day in reviews.heatmap.iter() {
+                            div {
+                                class: "heat-cell level-{day.level}",
+                                title: "{day.count} reviews on {day.date}",
+                            }
+                        }
+                    }
+                    p { class: "progress-text",
+                        "{reviews.total_reviews} reviews of {reviews.cards_reviewed} cards, on {reviews.active_days} days this year"
+                    }
+
+                    if reviews.total_reviews > 0 {
+                        div { class: "review-figures",
+                            div { class: "progress-card",
+                                div { class: "progress-era-name",
+                                    {format!("Retention {:.0}%", reviews.retention.unwrap_or(0.0) * 100.0)}
+                                }
+                                for bucket in reviews.retention_by_interval.iter().filter(|b| b.reviews > 0) {
+                                    {
+                                        let percent = bucket.rate().unwrap_or(0.0) * 100.0;
+                                        rsx! {
+                                            div { class: "review-row",
+                                                span { class: "progress-text", "{bucket.label}" }
+                                                div { class: "progress-bar-container",
+                                                    div { class: "progress-bar", style: "width: {percent:.0}%;" }
+                                                }
+                                                span { class: "progress-text", "{percent:.0}% of {bucket.reviews}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            div { class: "progress-card",
+                                div { class: "progress-era-name", "Interval growth" }
+                                for point in reviews.interval_growth.iter() {
+                                    {
+                                        let width = point.mean_interval / longest_growth * 100.0;
+                                        rsx! {
+                                            div { class: "review-row",
+                                                span { class: "progress-text", "Review {point.review}" }
+                                                div { class: "progress-bar-container",
+                                                    div { class: "progress-bar", style: "width: {width:.0}%;" }
+                                                }
+                                                span { class: "progress-text", "{point.mean_interval:.1} days" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    } else {
+                        div { class: "empty-state",
+                            p { "Review cards in spaced repetition to fill in your heatmap." }
+                        }
+                    }
+
+                    button { class: "review-export", onclick: export, "Export JSON" }
+                    if let Some(status) = export_status() {
+                        p { class: "progress-text", "{status}" }
+                    }
+
+                    p { class: "progress-text", {i18n.t("profile-anki-explain")} }
+                    button { class: "review-export", onclick: copy_anki_deck, {i18n.t("profile-anki-copy")} }
+                    textarea {
+                        class: "anki-history",
+                        rows: 4,
+                        placeholder: i18n.t("profile-anki-placeholder"),
+                        value: "{anki_history}",
+                        oninput: move |e| anki_history.set(e.value()),
+                    }
+                    button { class: "review-export", onclick: import_anki_history, {i18n.t("profile-anki-import")} }
+                    if let Some(status) = anki_status() {
+                        p { class: "progress-text", "{status}" }
+                    }
+                }
+
                 // Achievements
                 div { class: "profile-section",
                     h2 { class: "profile-section-title", "Achievements" }
@@ -365,6 +642,154 @@ pub fn Profile() -> Element {
                         }
                     }
                 }
+
+                // Accessibility preferences
+                div { class: "profile-section",
+                    h2 { class: "profile-section-title", {i18n.t("profile-accessibility")} }
+                    label { class: "a11y-option",
+                        input {
+                            r#type: "checkbox",
+                            checked: a11y.contrast() == Contrast::High,
+                            onchange: move |_| {
+                                let next = if a11y.contrast() == Contrast::High { Contrast::Standard } else { Contrast::High };
+                                a11y.set_contrast(next);
+                            },
+                        }
+                        span { {i18n.t("profile-high-contrast")} }
+                    }
+                    label { class: "a11y-option",
+                        input {
+                            r#type: "checkbox",
+                            checked: a11y.formula_speech() == FormulaSpeech::MathMl,
+                            onchange: move |_| {
+                                let next = if a11y.formula_speech() == FormulaSpeech::MathMl { FormulaSpeech::Label } else { FormulaSpeech::MathMl };
+                                a11y.set_formula_speech(next);
+                            },
+                        }
+                        span { {i18n.t("profile-mathml")} }
+                    }
+                    label { class: "a11y-option",
+                        span { {i18n.t("profile-language")} }
+                        LocalePicker {}
+                    }
+                }
+
+                // Sound: theme and mixer, saved and applied as they change
+                div { class: "profile-section",
+                    h2 { class: "profile-section-title", {i18n.t("profile-sound")} }
+                    div { class: "sound-mixer",
+                        label { r#for: "sound-theme", {i18n.t("profile-sound-theme")} }
+                        select {
+                            id: "sound-theme",
+                            value: "{sound.read().theme.as_str()}",
+                            onchange: move |e| {
+                                if let Some(theme) = SoundTheme::from_name(&e.value()) {
+                                    sound.write().set_theme(theme);
+                                }
+                            },
+                            for theme in SoundTheme::ALL {
+                                option { value: "{theme.as_str()}", "{theme.label()}" }
+                            }
+                        }
+                        span {}
+                        for (channel, label) in [
+                            (Channel::Master, "profile-sound-master"),
+                            (Channel::Sfx, "profile-sound-effects"),
+                            (Channel::Music, "profile-sound-music"),
+                        ] {
+                            {
+                                let percent = (sound.read().volume(channel) * 100.0).round() as u32;
+                                rsx! {
+                                    label { r#for: "sound-{label}", {i18n.t(label)} }
+                                    input {
+                                        id: "sound-{label}",
+                                        r#type: "range",
+                                        min: "0",
+                                        max: "100",
+                                        value: "{percent}",
+                                        oninput: move |e| {
+                                            if let Ok(percent) = e.value().parse::<f32>() {
+                                                sound.write().set_volume(channel, percent / 100.0);
+                                            }
+                                        },
+                                    }
+                                    span { class: "progress-text", "{percent}%" }
+                                }
+                            }
+                        }
+                    }
+                    label { class: "a11y-option",
+                        input {
+                            r#type: "checkbox",
+                            checked: sound.read().muted,
+                            onchange: move |_| {
+                                let muted = !sound.read().muted;
+                                sound.write().set_muted(muted);
+                            },
+                        }
+                        span { {i18n.t("profile-sound-mute")} }
+                    }
+                    label { class: "a11y-option",
+                        input {
+                            r#type: "checkbox",
+                            checked: sound.read().music_enabled,
+                            onchange: move |_| {
+                                let on = !sound.read().music_enabled;
+                                sound.write().set_music_enabled(on);
+                            },
+                        }
+                        span { {i18n.t("profile-sound-music-on")} }
+                    }
+                    button {
+                        class: "telemetry-send",
+                        onclick: move |_| play_combo(3),
+                        {i18n.t("profile-sound-test")}
+                    }
+                }
+
+                // Custom theme: palette and syntax colors, with live preview
+                div { class: "profile-section",
+                    h2 { class: "profile-section-title", {i18n.t("theme-editor-title")} }
+                    ThemeEditor {}
+                }
+
+                // Usage data: opt-in, with the exact upload shown
+                div { class: "profile-section",
+                    h2 { class: "profile-section-title", {i18n.t("profile-usage-data")} }
+                    label { class: "telemetry-opt-in",
+                        input {
+                            r#type: "checkbox",
+                            checked: telemetry.read().opted_in,
+                            onchange: move |_| {
+                                let on = !telemetry.read().opted_in;
+                                telemetry.write().set_opt_in(on);
+                            },
+                        }
+                        span { {i18n.t("profile-telemetry-opt-in")} }
+                    }
+                    p { class: "progress-text", {i18n.t("profile-telemetry-explain")} }
+                    pre { class: "telemetry-preview", "{telemetry.read().preview()}" }
+                    if telemetry.read().ready_to_upload() {
+                        button {
+                            class: "telemetry-send",
+                            onclick: move |_| {
+                                spawn(async move {
+                                    let mut current = telemetry.read().clone();
+                                    if let Ok(true) = current.upload().await {
+                                        telemetry.set(current);
+                                    }
+                                });
+                            },
+                            {i18n.t("profile-telemetry-send")}
+                        }
+                    }
+                    p { class: "progress-text", {i18n.t("profile-experiments-explain")} }
+                    button { class: "telemetry-send", onclick: copy_experiments, {i18n.t("profile-experiments-copy")} }
+                    button { class: "telemetry-send", onclick: clear_experiments, {i18n.t("profile-experiments-clear")} }
+                    if let Some(status) = experiments_status() {
+                        p { class: "progress-text", {i18n.t(status)} }
+                    }
+                }
             }
 
             Footer {}
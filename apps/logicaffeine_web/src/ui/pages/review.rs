@@ -12,10 +12,12 @@ use crate::content::ContentEngine;
 use crate::generator::{Generator, Challenge, AnswerType};
 use crate::grader::{check_answer, GradeResult};
 use crate::progress::UserProgress;
-use crate::srs::{ResponseQuality, sm2_update, calculate_next_review, is_due};
+use crate::srs::{ResponseQuality, calculate_next_review, is_due};
+use crate::experiments::{Experiments, Outcome};
 use crate::game::{XpReward, ComboResult, StreakStatus, calculate_xp_reward, update_combo, update_streak};
 use crate::achievements::{Achievement, check_achievements, unlock_achievement};
 use crate::audio::{SoundEffect, play_sound};
+use crate::ui::i18n::I18nState;
 
 const REVIEW_STYLE: &str = r#"
 .review-container {
@@ -281,6 +283,7 @@ fn ReviewInner() -> Element {
     let mut grade_result = use_signal(|| None::<GradeResult>);
     let mut due_challenges = use_signal(Vec::<(String, Challenge)>::new);
     let mut initialized = use_signal(|| false);
+    let i18n = use_context::<I18nState>();
     let mut progress = use_signal(UserProgress::load);
 
     let mut show_xp_popup = use_signal(|| false);
@@ -373,12 +376,11 @@ fn ReviewInner() -> Element {
         div { class: "review-container",
             header { class: "review-header",
                 div {
-                    span { class: "review-title", "Daily Review" }
+                    span { class: "review-title", {i18n.t("review-title")} }
                     span { class: "review-count",
                         if total_due > 0 {
-                            " • {current + 1} of {total_due}"
-                        } else {
-                            ""
+                            " • "
+                            {i18n.t_with("review-count", &[("current", &(current + 1).to_string()), ("total", &total_due.to_string())])}
                         }
                     }
                 }
@@ -401,24 +403,24 @@ fn ReviewInner() -> Element {
                     if total_due == 0 {
                         rsx! {
                             div { class: "review-card empty-state",
-                                h2 { "All caught up!" }
-                                p { "No exercises are due for review right now." }
+                                h2 { {i18n.t("review-caught-up")} }
+                                p { {i18n.t("review-nothing-due")} }
                                 Link {
                                     class: "back-btn",
                                     to: Route::Landing {},
-                                    "← Back to Home"
+                                    {i18n.t("review-back-home")}
                                 }
                             }
                         }
                     } else if current >= total_due {
                         rsx! {
                             div { class: "review-card empty-state",
-                                h2 { "Review Complete!" }
-                                p { "You reviewed {total_due} items." }
+                                h2 { {i18n.t("review-complete")} }
+                                p { {i18n.t_with("review-complete-count", &[("count", &total_due.to_string())])} }
                                 Link {
                                     class: "back-btn",
                                     to: Route::Landing {},
-                                    "← Back to Home"
+                                    {i18n.t("review-back-home")}
                                 }
                             }
                         }
@@ -457,7 +459,7 @@ fn ReviewInner() -> Element {
                                         input {
                                             class: "{input_class}",
                                             r#type: "text",
-                                            placeholder: "Enter your answer...",
+                                            placeholder: i18n.t("review-answer-placeholder"),
                                             value: "{answer}",
                                             disabled: submitted(),
                                             oninput: move |e| answer.set(e.value()),
@@ -533,7 +535,7 @@ fn ReviewInner() -> Element {
                                                         record_srs(&mut progress, &ex_id_clone, ResponseQuality::CorrectDifficult);
                                                         advance_review(&mut current_index, &mut answer, &mut selected_choice, &mut submitted, &mut grade_result);
                                                     },
-                                                    "Hard"
+                                                    {i18n.t("review-hard")}
                                                 }
                                                 button {
                                                     class: "srs-btn good",
@@ -542,7 +544,7 @@ fn ReviewInner() -> Element {
                                                         record_srs(&mut progress, &ex_id, quality);
                                                         advance_review(&mut current_index, &mut answer, &mut selected_choice, &mut submitted, &mut grade_result);
                                                     },
-                                                    if is_correct { "Good" } else { "Again" }
+                                                    if is_correct { {i18n.t("review-good")} } else { {i18n.t("review-again")} }
                                                 }
                                                 if is_correct {
                                                     button {
@@ -554,7 +556,7 @@ fn ReviewInner() -> Element {
                                                                 advance_review(&mut current_index, &mut answer, &mut selected_choice, &mut submitted, &mut grade_result);
                                                             }
                                                         },
-                                                        "Easy"
+                                                        {i18n.t("review-easy")}
                                                     }
                                                 }
                                             }
@@ -589,14 +591,14 @@ fn ReviewInner() -> Element {
                                                                         correct: true,
                                                                         partial: false,
                                                                         score: 100,
-                                                                        feedback: "Correct!".to_string(),
+                                                                        feedback: i18n.t("lesson-correct"),
                                                                     }
                                                                 } else {
                                                                     GradeResult {
                                                                         correct: false,
                                                                         partial: false,
                                                                         score: 0,
-                                                                        feedback: "Not quite.".to_string(),
+                                                                        feedback: i18n.t("lesson-not-quite"),
                                                                     }
                                                                 };
                                                                 grade_result.set(Some(result));
@@ -607,7 +609,7 @@ fn ReviewInner() -> Element {
                                                                     correct: true,
                                                                     partial: false,
                                                                     score: 100,
-                                                                    feedback: "Good analysis!".to_string(),
+                                                                    feedback: i18n.t("lesson-good-analysis"),
                                                                 }));
                                                                 true
                                                             }
@@ -655,7 +657,7 @@ fn ReviewInner() -> Element {
 
                                                         submitted.set(true);
                                                     },
-                                                    "Check Answer"
+                                                    {i18n.t("lesson-check")}
                                                 }
                                             }
                                         }
@@ -666,7 +668,7 @@ fn ReviewInner() -> Element {
                     } else {
                         rsx! {
                             div { class: "review-card",
-                                p { "Loading..." }
+                                p { {i18n.t("review-loading")} }
                             }
                         }
                     }
@@ -682,10 +684,13 @@ fn record_srs(progress: &mut Signal<UserProgress>, exercise_id: &str, quality: R
 
     user_progress.record_attempt(exercise_id, quality.is_correct());
 
+    // The scheduler is the learner's arm of the scheduler experiment
+    let mut experiments = Experiments::load();
     if let Some(ep) = user_progress.exercises.get_mut(exercise_id) {
-        sm2_update(&mut ep.srs, quality);
+        experiments.scheduler().update(&mut ep.srs, quality);
         ep.srs.next_review = Some(calculate_next_review(&today, ep.srs.interval));
     }
+    experiments.record(Outcome::Review { exercise_id: exercise_id.to_string(), correct: quality.is_correct() });
 
     user_progress.save();
 }
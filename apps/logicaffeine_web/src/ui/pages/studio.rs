@@ -22,11 +22,10 @@
 use dioxus::prelude::*;
 #[cfg(all(feature = "split", target_arch = "wasm32"))]
 use dioxus::wasm_split;
-use std::cell::RefCell;
 use logicaffeine_compile::{
-    compile_for_ui, compile_for_proof, compile_theorem_for_ui, generate_rust_code,
+    compile_for_proof, compile_theorem_for_ui, generate_rust_code,
     extract_math_rust, extract_logic_rust,
-    interpret_for_ui_baseline, interpret_streaming_with_vfs, CompileResult, ProofCompileResult,
+    CompileResult, ProofCompileResult, RustPreview,
     TheoremCompileResult, SolvedGrid,
     interpreter::InterpreterResult,
 };
@@ -43,21 +42,34 @@ use crate::ui::components::symbol_dictionary::SymbolDictionary;
 use crate::ui::seo::{JsonLdMultiple, PageHead, organization_schema, software_application_schema, breadcrumb_schema, BreadcrumbItem, pages as seo_pages};
 use crate::ui::components::mode_toggle::ModeToggle;
 use crate::ui::components::file_browser::FileBrowser;
+use crate::ui::components::editor_tabs::EditorTabs;
 use crate::ui::components::repl_output::ReplOutput;
 use crate::ui::components::context_view::{ContextView, ContextEntry, EntryKind};
-use crate::ui::components::code_editor::{CodeEditor, CodeView, Language};
+use crate::ui::components::code_editor::{CodeEditor, CodeView, Language, RemoteCaret};
+use crate::ui::components::rust_preview::RustPreviewPane;
 use crate::ui::components::proof_panel::{ProofPanel, ProofStatus, Tactic};
 use crate::ui::components::debug_drawer::{DebugDrawer, IC_BUG};
-use crate::ui::state::{StudioMode, FileNode, ReplLine};
+use crate::ui::components::grammar_playground::GrammarPlayground;
+use crate::ui::state::{StudioMode, FileNode, ReplLine, OpenTabs};
+#[cfg(target_arch = "wasm32")]
+use crate::ui::state::TabSession;
+use logicaffeine_language::analysis::scan_dependencies;
+use std::collections::HashMap;
+use std::path::PathBuf;
 use crate::ui::responsive::{MOBILE_BASE_STYLES, MOBILE_TAB_BAR_STYLES};
 use logicaffeine_kernel::interface::Repl;
 use crate::ui::examples::seed_examples;
+use crate::compile_worker;
+use crate::telemetry::Telemetry;
+use crate::share::{SharedFile, Snippet};
+use crate::collab;
+#[cfg(target_arch = "wasm32")]
+use crate::share::ShareLink;
 #[cfg(target_arch = "wasm32")]
 use logicaffeine_system::fs::{get_platform_vfs_with_fallback, WebVfs};
 use logicaffeine_system::fs::{get_platform_vfs, Vfs, DirEntry, VfsResult};
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::JsValue;
-use std::rc::Rc;
 
 /// Parse math code into complete statements.
 ///
@@ -273,6 +285,10 @@ async fn load_dir_recursive<V: Vfs>(vfs: &V, path: &str, parent: &mut FileNode)
     let entries = vfs.list_dir(path).await?;
 
     for entry in entries {
+        // Dot entries are the Studio's own bookkeeping, such as unsaved drafts
+        if entry.name.starts_with('.') {
+            continue;
+        }
         let full_path = if path == "/" {
             format!("/{}", entry.name)
         } else {
@@ -312,6 +328,34 @@ fn count_files(node: &FileNode) -> usize {
     count
 }
 
+/// Name of a logic output format in share links
+fn output_format_name(format: OutputFormat) -> &'static str {
+    match format {
+        OutputFormat::Unicode => "unicode",
+        OutputFormat::SimpleFOL => "simple",
+        OutputFormat::LaTeX => "latex",
+        OutputFormat::Kripke => "kripke",
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn output_format_from_name(name: &str) -> Option<OutputFormat> {
+    [OutputFormat::Unicode, OutputFormat::SimpleFOL, OutputFormat::LaTeX, OutputFormat::Kripke]
+        .into_iter()
+        .find(|f| output_format_name(*f) == name)
+}
+
+/// Collect the paths of every file under `dir` in the tree
+fn files_under(node: &FileNode, dir: &str, out: &mut Vec<String>) {
+    for child in &node.children {
+        if child.is_directory {
+            files_under(child, dir, out);
+        } else if child.path.starts_with(dir) {
+            out.push(child.path.clone());
+        }
+    }
+}
+
 /// Format a DerivationTree as HTML for the proof panel
 fn format_derivation_html(tree: &DerivationTree) -> String {
     fn format_node(tree: &DerivationTree, depth: usize) -> String {
@@ -429,12 +473,14 @@ fn theorem_proof_hint(result: &TheoremCompileResult) -> String {
     }
 }
 
-/// Code mode output toggle - interpret output vs generated Rust
+/// Code mode output toggle - interpret output vs generated Rust, or the
+/// side-by-side preview that recompiles as the source changes (Code mode only)
 #[derive(Clone, Copy, PartialEq, Eq, Default)]
 enum CodeOutputMode {
     #[default]
     Interpret,
     Rust,
+    Preview,
 }
 
 /// Logic mode output view — FOL interpretation or generated Rust. (SVA synthesis lives in
@@ -546,6 +592,12 @@ const STUDIO_STYLE: &str = r#"
     color: rgba(255, 255, 255, 0.9);
 }
 
+.share-status {
+    margin-inline-start: 8px;
+    font-size: 12px;
+    color: rgba(255, 255, 255, 0.6);
+}
+
 /* Main content area with optional sidebar */
 .studio-content {
     flex: 1;
@@ -1783,6 +1835,16 @@ fn sync_studio_url(vfs_path: &str) {
     crate::ui::router::replace_bar_url(&crate::ui::router::studio_file_url(vfs_path));
 }
 
+/// The toolbar status for a collaboration room.
+fn collab_status(session: &collab::Session, online: bool) -> String {
+    match (online, session.peer_count()) {
+        (false, _) => "Offline — reconnecting…".to_string(),
+        (true, 0) => "Live — waiting for others".to_string(),
+        (true, 1) => "Live with 1 other".to_string(),
+        (true, n) => format!("Live with {n} others"),
+    }
+}
+
 #[component(lazy)]
 pub fn Studio(file: Option<String>) -> Element {
     // Mode state
@@ -1792,6 +1854,16 @@ pub fn Studio(file: Option<String>) -> Element {
     let mut sidebar_open = use_signal(|| true);
     let mut file_tree = use_signal(FileNode::root); // Start empty - no fallback, show real errors
     let mut current_file = use_signal(|| None::<String>);
+    let mut tabs = use_signal(OpenTabs::default);
+    // Set once the last session's tabs are back, so saving can't clobber them first
+    let mut tabs_restored = use_signal(|| false);
+    let mut share_status = use_signal(|| None::<String>);
+    // Live collaboration on the Code buffer: the room session and its relay
+    let mut collab = use_signal(|| None::<collab::Session>);
+    let mut relay = use_signal(|| None::<collab::Relay>);
+    // The tab the room edits; other tabs' edits never reach the room
+    let mut collab_file = use_signal(|| None::<String>);
+    let mut collab_online = use_signal(|| false);
     let mut vfs_error = use_signal(|| None::<String>); // Track VFS errors for display
     let mut vfs_is_fallback = use_signal(|| false); // Track if using IndexedDB fallback
 
@@ -1828,10 +1900,19 @@ pub fn Studio(file: Option<String>) -> Element {
     let mut interpreter_result = use_signal(|| InterpreterResult {
         lines: vec![],
         error: None,
+        memory: None,
+        limit_exceeded: None,
     });
     let mut generated_rust = use_signal(String::new);
+    // Side-by-side preview, the Rust it replaced (for the diff), and the
+    // latest compile error while the buffer doesn't parse
+    let mut rust_preview = use_signal(RustPreview::default);
+    let mut previous_rust = use_signal(String::new);
+    let mut preview_error = use_signal(|| None::<String>);
     // Code-mode debugger drawer (bottom-docked, additive — see `DebugDrawer`).
     let mut debugging = use_signal(|| false);
+    // Logic-mode grammar playground drawer (parser mode switches).
+    let mut grammar_open = use_signal(|| false);
 
     // Math mode state (vernacular/theorem proving)
     let mut math_input = use_signal(String::new);
@@ -1890,6 +1971,51 @@ pub fn Studio(file: Option<String>) -> Element {
     #[cfg(target_arch = "wasm32")]
     let mut vfs_handle = use_signal(|| None::<WebVfs>);
 
+    // Enter a collaboration room around the current tab: keep its session and
+    // connect to the relay, greeting the room on every (re)connect and merging
+    // what peers send into that tab, whichever tab is showing
+    let mut start_collab = move |session: collab::Session| {
+        let room = session.room().clone();
+        collab.set(Some(session));
+        collab_file.set(current_file.peek().clone());
+        collab_online.set(false);
+        let connected = collab::Relay::connect(&room, move |event| match event {
+            collab::RelayEvent::Online => {
+                collab_online.set(true);
+                let greeting = collab.peek().as_ref().map(|session| session.greeting()).unwrap_or_default();
+                if let Some(relay) = relay.peek().as_ref() {
+                    greeting.iter().for_each(|message| relay.send(message));
+                }
+            }
+            collab::RelayEvent::Offline => collab_online.set(false),
+            collab::RelayEvent::Message(message) => {
+                let Some(received) = collab.write().as_mut().map(|session| session.receive(message)) else {
+                    return;
+                };
+                if received.text_changed {
+                    let text = collab.peek().as_ref().map(|session| session.text().to_string()).unwrap_or_default();
+                    let file = collab_file.peek().clone();
+                    if let Some(path) = &file {
+                        tabs.write().edit(path, &text);
+                    }
+                    if *current_file.peek() == file {
+                        code_input.set(text);
+                    }
+                }
+                if let Some(relay) = relay.peek().as_ref() {
+                    received.replies.iter().for_each(|message| relay.send(message));
+                }
+            }
+        });
+        match connected {
+            Ok(connection) => relay.set(Some(connection)),
+            Err(e) => {
+                collab.set(None);
+                share_status.set(Some(e));
+            }
+        }
+    };
+
     // Initialize VFS and seed examples on mount
     #[cfg(target_arch = "wasm32")]
     use_effect(move || {
@@ -1930,7 +2056,27 @@ pub fn Studio(file: Option<String>) -> Element {
                         }
                     }
 
-                    // Open the file from the route's `file` query prop, or the default
+                    // Reopen the tabs from the last session, with their unsaved edits
+                    let session = crate::storage::load_studio_tabs_raw()
+                        .and_then(|json| serde_json::from_str::<TabSession>(&json).ok())
+                        .unwrap_or_default();
+                    for path in &session.paths {
+                        if let Ok(content) = vfs.read_to_string(path).await {
+                            tabs.write().open(path.clone(), content);
+                        }
+                    }
+                    let drafts = crate::storage::load_studio_drafts_raw()
+                        .await
+                        .and_then(|json| serde_json::from_str(&json).ok())
+                        .unwrap_or_default();
+                    tabs.write().restore_drafts(drafts);
+                    tabs_restored.set(true);
+
+                    // Open the file from the route's `file` query prop, else the
+                    // last session's active tab, else the default
+                    let restored_active = session
+                        .active
+                        .filter(|path| tabs.peek().tabs.iter().any(|t| &t.path == path));
                     let file_to_load = file
                         .map(|f| {
                             // Normalize path - ensure it starts with /
@@ -1940,10 +2086,69 @@ pub fn Studio(file: Option<String>) -> Element {
                                 format!("/{}", f)
                             }
                         })
+                        .or(restored_active)
                         .unwrap_or_else(|| "/examples/logic/prover-demo.logic".to_string());
+                    // An open tab's buffer, unsaved edits included, wins over the file
+                    let buffered = tabs
+                        .peek()
+                        .tabs
+                        .iter()
+                        .find(|t| t.path == file_to_load)
+                        .map(|t| t.content.clone());
+                    let file_content = match buffered {
+                        Some(content) => Ok(content),
+                        None => vfs.read_to_string(&file_to_load).await,
+                    };
+
+                    // A shared link takes the place of the route's file
+                    let hash = web_sys::window()
+                        .and_then(|w| w.location().hash().ok())
+                        .unwrap_or_default();
+                    let link = ShareLink::parse(&hash);
+                    let room = collab::Room::parse(&hash);
+                    let shared = match link {
+                        Some(link) => match link.resolve().await {
+                            Ok(snippet) => Some(snippet),
+                            Err(e) => {
+                                vfs_error.set(Some(format!("Failed to open shared link: {e}")));
+                                None
+                            }
+                        },
+                        None => None,
+                    };
 
-                    // Load the file and detect mode from path/extension
-                    if let Ok(content) = vfs.read_to_string(&file_to_load).await {
+                    if let Some(snippet) = shared {
+                        // Shared buffers open as unsaved tabs under /shared
+                        for shared_file in &snippet.files {
+                            let name = shared_file.name.rsplit('/').next().unwrap_or(&shared_file.name);
+                            tabs.write().open_new(format!("/shared/{name}"), shared_file.content.clone());
+                        }
+                        if let Some(active) = snippet.active_file() {
+                            let name = active.name.rsplit('/').next().unwrap_or(&active.name);
+                            let path = format!("/shared/{name}");
+                            tabs.write().open(path.clone(), active.content.clone());
+                            current_file.set(Some(path));
+                            let shared_mode = StudioMode::from_extension(&snippet.mode).unwrap_or_default();
+                            mode.set(shared_mode);
+                            match shared_mode {
+                                StudioMode::Logic => input.set(active.content.clone()),
+                                StudioMode::Code => code_input.set(active.content.clone()),
+                                StudioMode::Math => math_input.set(active.content.clone()),
+                                StudioMode::Hardware => hw_input.set(active.content.clone()),
+                            }
+                        }
+                        if let Some(shared_format) = snippet.options.get("format").and_then(|name| output_format_from_name(name)) {
+                            format.set(shared_format);
+                        }
+                    } else if let Some(room) = room {
+                        // A room link joins with an empty buffer; the room fills it in
+                        let path = "/shared/collab.logos".to_string();
+                        tabs.write().open_new(path.clone(), String::new());
+                        current_file.set(Some(path));
+                        mode.set(StudioMode::Code);
+                        start_collab(collab::Session::join(room, logicaffeine_data::generate_replica_id()));
+                    } else if let Ok(content) = file_content {
+                        let content = tabs.write().open(file_to_load.clone(), content);
                         current_file.set(Some(file_to_load.clone()));
                         sync_studio_url(&file_to_load);
 
@@ -1958,8 +2163,9 @@ pub fn Studio(file: Option<String>) -> Element {
                             // Code mode - auto-run
                             mode.set(StudioMode::Code);
                             code_input.set(content.clone());
-                            let interp_result = interpret_for_ui_baseline(&content).await;
-                            interpreter_result.set(interp_result);
+                            if let Some(interp_result) = compile_worker::interpret(content).await {
+                                interpreter_result.set(interp_result);
+                            }
                         } else {
                             // Logic mode (default, handles .logic files)
                             mode.set(StudioMode::Logic);
@@ -2022,8 +2228,9 @@ pub fn Studio(file: Option<String>) -> Element {
 
                                 if !sentences.is_empty() {
                                     let all_text = sentences.join("\n");
-                                    let compiled = compile_for_ui(&all_text);
-                                    result.set(compiled);
+                                    if let Some(compiled) = compile_worker::compile(all_text).await {
+                                        result.set(compiled);
+                                    }
 
                                     let first_sentence = sentences[0];
                                     let proof_result = compile_for_proof(first_sentence);
@@ -2071,17 +2278,85 @@ pub fn Studio(file: Option<String>) -> Element {
         });
     }
 
+    // Remember which files were open, and their unsaved edits, for the next
+    // visit. Drafts go to IndexedDB once typing pauses, not per keystroke.
+    #[cfg(target_arch = "wasm32")]
+    let mut drafts_generation = use_signal(|| 0u64);
+    #[cfg(target_arch = "wasm32")]
+    use_effect(move || {
+        if !tabs_restored() {
+            return;
+        }
+        let open = tabs.read();
+        if let Ok(json) = serde_json::to_string(&open.session()) {
+            crate::storage::save_studio_tabs_raw(&json);
+        }
+        let Ok(drafts) = serde_json::to_string(&open.drafts()) else {
+            return;
+        };
+        drop(open);
+        let generation = *drafts_generation.peek() + 1;
+        drafts_generation.set(generation);
+        spawn(async move {
+            gloo_timers::future::TimeoutFuture::new(500).await;
+            if *drafts_generation.peek() != generation {
+                return;
+            }
+            if let Err(e) = crate::storage::save_studio_drafts_raw(&drafts).await {
+                vfs_error.set(Some(format!("Failed to keep unsaved edits: {e}")));
+            }
+        });
+    });
+
+    // Side-by-side preview: recompile once typing pauses, not per keystroke.
+    // A newer edit bumps the generation and the stale compile is dropped.
+    let mut preview_generation = use_signal(|| 0u64);
+    use_effect(move || {
+        if code_output_mode() != CodeOutputMode::Preview {
+            return;
+        }
+        let code = code_input();
+        let generation = *preview_generation.peek() + 1;
+        preview_generation.set(generation);
+        spawn(async move {
+            #[cfg(target_arch = "wasm32")]
+            gloo_timers::future::TimeoutFuture::new(300).await;
+            if *preview_generation.peek() != generation {
+                return;
+            }
+            // Compiled in the worker; a newer edit's compile cancels this one
+            match compile_worker::rust_preview(code).await {
+                Some(Ok(preview)) => {
+                    let old = rust_preview.peek().rust.clone();
+                    if old != preview.rust {
+                        previous_rust.set(old);
+                    }
+                    rust_preview.set(preview);
+                    preview_error.set(None);
+                }
+                Some(Err(e)) => preview_error.set(Some(e)),
+                None => {}
+            }
+        });
+    });
+
     // Logic mode input handler - compiles for both UI and proof engine
     // Logic mode keystroke handler: just store the text. The heavy compile/prove/grid runs
     // on the Execute button (`handle_logic_execute`), mirroring Code mode's Run — so typing
     // never triggers a per-keystroke solve.
     let handle_logic_input = move |new_value: String| {
+        tabs.write().edit_active(&new_value);
         input.set(new_value);
     };
 
     // Code mode: Run button handler (interpret) with streaming output
     let handle_code_run = move |_| {
         let code = code_input.read().clone();
+        // A file that imports others runs as a project rooted at its directory
+        let entry = current_file
+            .read()
+            .clone()
+            .filter(|_| !scan_dependencies(&code).is_empty());
         // Switch to Output tab (Panel2) on mobile and switch to Output mode
         active_tab.set(MobileTab::Panel2);
         code_output_mode.set(CodeOutputMode::Interpret);
@@ -2089,16 +2364,12 @@ pub fn Studio(file: Option<String>) -> Element {
         interpreter_result.set(InterpreterResult {
             lines: vec![],
             error: None,
+            memory: None,
+            limit_exceeded: None,
         });
         spawn(async move {
-            // Create streaming callback that updates the signal as output arrives
-            let callback = Rc::new(RefCell::new(move |line: String| {
-                // Update the signal with the new line
-                interpreter_result.write().lines.push(line);
-            }));
-
-            // Route the interpreter's file I/O to the Studio's VFS (OPFS/IndexedDB),
-            // so the standard-library I/O vocabulary works in the browser. The
+            // The page's VFS (OPFS/IndexedDB) reads the project's files, and serves the
+            // program's own file I/O when the run can't go to the compile worker. The
             // worker-backed handle is wasm-only; the native build does no file I/O.
             #[cfg(target_arch = "wasm32")]
             let vfs = vfs_handle
@@ -2107,9 +2378,30 @@ pub fn Studio(file: Option<String>) -> Element {
                 .map(|w| std::sync::Arc::new(w) as std::sync::Arc<dyn Vfs>);
             #[cfg(not(target_arch = "wasm32"))]
             let vfs: Option<std::sync::Arc<dyn Vfs>> = None;
-            let result = interpret_streaming_with_vfs(&code, callback, vfs).await;
-            // Set final result (includes any error)
-            interpreter_result.set(result);
+            let mut request = compile_worker::RunRequest { code, entry: None, files: HashMap::new() };
+            if let Some(entry) = entry {
+                // Imports resolve against the project's files, with open tabs'
+                // unsaved buffers taking precedence over what is on disk
+                let dir = format!("{}/", entry.rsplit_once('/').map_or("", |(dir, _)| dir));
+                let mut paths = Vec::new();
+                files_under(&file_tree.peek(), &dir, &mut paths);
+                if let Some(vfs) = vfs.as_ref() {
+                    for path in paths {
+                        if let Ok(content) = vfs.read_to_string(&path).await {
+                            request.files.insert(PathBuf::from(path), content);
+                        }
+                    }
+                }
+                tabs.peek().overlay(&mut request.files);
+                request.files.insert(PathBuf::from(&entry), request.code.clone());
+                request.entry = Some(entry);
+            }
+            // Output streams in as the program prints; a newer Run supersedes this one
+            let on_line = move |line: String| interpreter_result.write().lines.push(line);
+            if let Some(result) = compile_worker::run(request, vfs, on_line).await {
+                // Set final result (includes any error)
+                interpreter_result.set(result);
+            }
         });
     };
 
@@ -2133,6 +2425,8 @@ pub fn Studio(file: Option<String>) -> Element {
                     interpreter_result.set(InterpreterResult {
                         lines: vec![],
                         error: Some(format!("Compile error: {:?}", e)),
+                        memory: None,
+                        limit_exceeded: None,
                     });
                     code_output_mode.set(CodeOutputMode::Interpret);
                 }
@@ -2275,21 +2569,29 @@ pub fn Studio(file: Option<String>) -> Element {
 
             if !sentences.is_empty() {
                 let all_text = sentences.join("\n");
-                let compiled = compile_for_ui(&all_text);
-                result.set(compiled);
-
-                knowledge_base.write().clear();
-
-                let first_sentence = sentences[0];
-                let proof_result = compile_for_proof(first_sentence);
-                if let Some(expr) = proof_result.proof_expr {
-                    current_proof_expr.set(Some(expr));
-                    proof_status.set(ProofStatus::Idle);
-                    proof_hint.set(Some("Enter premises or click Auto to prove.".to_string()));
-                } else if let Some(err) = proof_result.error {
-                    current_proof_expr.set(None);
-                    proof_status.set(ProofStatus::Failed(err));
-                }
+                let first_sentence = sentences[0].to_string();
+                spawn(async move {
+                    // Compiled off the main thread; a newer Execute supersedes this one
+                    let Some((compiled, ms)) = compile_worker::compile_timed(all_text).await else { return };
+                    let mut telemetry = Telemetry::load();
+                    telemetry.record_compile_latency(ms);
+                    if compiled.error.is_some() {
+                        telemetry.record_error("compile_error");
+                    }
+                    result.set(compiled);
+
+                    knowledge_base.write().clear();
+
+                    let proof_result = compile_for_proof(&first_sentence);
+                    if let Some(expr) = proof_result.proof_expr {
+                        current_proof_expr.set(Some(expr));
+                        proof_status.set(ProofStatus::Idle);
+                        proof_hint.set(Some("Enter premises or click Auto to prove.".to_string()));
+                    } else if let Some(err) = proof_result.error {
+                        current_proof_expr.set(None);
+                        proof_status.set(ProofStatus::Failed(err));
+                    }
+                });
             } else {
                 result.set(CompileResult {
                     logic: None,
@@ -2964,6 +3266,360 @@ pub fn Studio(file: Option<String>) -> Element {
         StudioMode::Hardware => ("\u{270F}", "Spec", "\u{22A8}", "SVA", "\u{25A6}", "Graph"),
     };
 
+    // Opening a file (from the browser or a tab) loads it into its mode's editor
+    let open_file = EventHandler::new(move |path: String| {
+        // Close sidebar on mobile
+        #[cfg(target_arch = "wasm32")]
+        {
+            let window = web_sys::window().unwrap();
+            let width = window.inner_width().unwrap().as_f64().unwrap_or(1024.0);
+            if width <= 768.0 {
+                sidebar_open.set(false);
+            }
+        }
+        current_file.set(Some(path.clone()));
+
+        // Update URL with file parameter for shareable links
+        #[cfg(target_arch = "wasm32")]
+        sync_studio_url(&path);
+
+        // Load file content from VFS
+        #[cfg(target_arch = "wasm32")]
+        {
+            let path_clone = path.clone();
+            spawn(async move {
+                // Reuse the cached VFS (one worker) instead of spawning a
+                // fresh one per file switch; acquire once if not ready yet.
+                // Bind the clone first so the peek() read-guard drops before
+                // the None arm calls vfs_handle.set() (avoids a borrow panic).
+                let cached = vfs_handle.peek().clone();
+                let vfs_result = match cached {
+                    Some(vfs) => Ok(vfs),
+                    None => get_platform_vfs_with_fallback().await.map(|vfs| {
+                        vfs_handle.set(Some(vfs.clone()));
+                        vfs
+                    }),
+                };
+                match vfs_result {
+                    Ok(vfs) => {
+                        // An open tab's buffer wins over the file, which may not exist yet
+                        let buffered = tabs
+                            .peek()
+                            .tabs
+                            .iter()
+                            .find(|t| t.path == path_clone)
+                            .map(|t| t.content.clone());
+                        let read = match buffered {
+                            Some(content) => Ok(content),
+                            None => vfs.read_to_string(&path_clone).await,
+                        };
+                        match read {
+                            Ok(content) => {
+                                // An open tab keeps its buffer, so unsaved edits survive a switch
+                                let content = tabs.write().open(path_clone.clone(), content);
+                                // Load into appropriate editor based on file path/extension
+                                // Math files are .logos but in /examples/math/ directory
+                                let ext = path_clone.rsplit('.').next().unwrap_or("").to_lowercase();
+                                let is_math_dir = path_clone.contains("/math/") || path_clone.contains("/examples/math");
+                                let is_hardware_dir = path_clone.contains("/hardware/") || path_clone.contains("/examples/hardware");
+
+                                // Check for math directory first (takes precedence over .logos extension)
+                                if is_math_dir || ext == "math" || ext == "vernac" {
+                                    // Switch to Math mode and Output tab
+                                    mode.set(StudioMode::Math);
+                                    active_tab.set(MobileTab::Panel2);
+                                    math_input.set(content);
+                                } else if ext == "logic" {
+                                    // Switch to Logic mode and Output tab
+                                    mode.set(StudioMode::Logic);
+                                    active_tab.set(MobileTab::Panel2);
+
+                                    // Load into editor
+                                    input.set(content.clone());
+
+                                    // Check if this is a theorem file
+                                    if content.contains("## Theorem:") {
+                                        // Handle as theorem block with prover syntax
+                                        let theorem_result = compile_theorem_for_ui(&content);
+
+                                        if let Some(err) = theorem_result.error {
+                                            // Parsing failed
+                                            result.set(CompileResult {
+                                                logic: None,
+                                                simple_logic: None,
+                                                kripke_logic: None,
+                                                ast: None,
+                                                readings: Vec::new(),
+                                                simple_readings: Vec::new(),
+                                                kripke_readings: Vec::new(),
+                                                tokens: Vec::new(),
+                                                error: Some(err.clone()),
+                                            });
+                                            proof_status.set(ProofStatus::Failed(err));
+                                            current_proof_expr.set(None);
+                                            knowledge_base.write().clear();
+                                        } else {
+                                            // Successfully parsed theorem
+                                            result.set(CompileResult {
+                                                logic: theorem_result.goal_string.clone(),
+                                                simple_logic: theorem_result.goal_string.clone(),
+                                                kripke_logic: None,
+                                                ast: None,
+                                                readings: Vec::new(),
+                                                simple_readings: Vec::new(),
+                                                kripke_readings: Vec::new(),
+                                                tokens: Vec::new(),
+                                                error: None,
+                                            });
+
+                                            // Set up knowledge base from premises
+                                            knowledge_base.write().clear();
+                                            for premise in &theorem_result.premises {
+                                                knowledge_base.write().push(premise.clone());
+                                            }
+
+                                            // Set goal for proof engine
+                                            if let Some(goal) = theorem_result.goal.clone() {
+                                                current_proof_expr.set(Some(goal));
+                                            }
+
+                                            // Solved grid / wh-answer / derivation
+                                            let html = theorem_proof_html(&theorem_result);
+                                            if !html.is_empty() {
+                                                proof_text.set(html);
+                                                proof_status.set(if theorem_result.verified {
+                                                    ProofStatus::Success
+                                                } else {
+                                                    ProofStatus::Idle
+                                                });
+                                                proof_hint.set(Some(theorem_proof_hint(&theorem_result)));
+                                            } else {
+                                                proof_status.set(ProofStatus::Idle);
+                                                proof_hint.set(Some(format!(
+                                                    "Theorem '{}' ready. {} premise(s) loaded.",
+                                                    theorem_result.name,
+                                                    knowledge_base.read().len()
+                                                )));
+                                                proof_text.set(String::new());
+                                            }
+                                        }
+                                    } else {
+                                        // Filter out markdown headers (#) and LOGOS comments (--)
+                                        let sentences: Vec<&str> = content
+                                            .lines()
+                                            .filter(|line| {
+                                                let trimmed = line.trim();
+                                                !trimmed.is_empty()
+                                                && !trimmed.starts_with('#')
+                                                && !trimmed.starts_with("--")
+                                            })
+                                            .collect();
+
+                                        if !sentences.is_empty() {
+                                            // Join all sentences and compile together
+                                            let all_text = sentences.join("\n");
+                                            if let Some(compiled) = compile_worker::compile(all_text).await {
+                                                result.set(compiled);
+                                            }
+
+                                            // Use first sentence for proof engine
+                                            let first_sentence = sentences[0];
+                                            let proof_result = compile_for_proof(first_sentence);
+                                            if let Some(expr) = proof_result.proof_expr {
+                                                current_proof_expr.set(Some(expr));
+                                            }
+                                        }
+                                    }
+                                } else if ext == "logos" {
+                                    // Switch to Code mode and Output tab
+                                    mode.set(StudioMode::Code);
+                                    active_tab.set(MobileTab::Panel2);
+
+                                    code_input.set(content.clone());
+                                    // Auto-run the code
+                                    if let Some(interp_result) = compile_worker::interpret(content).await {
+                                        interpreter_result.set(interp_result);
+                                    }
+                                } else if is_hardware_dir || ext == "hw" {
+                                    // Switch to Hardware mode and synthesize the spec.
+                                    mode.set(StudioMode::Hardware);
+                                    active_tab.set(MobileTab::Panel2);
+                                    hw_input.set(content.clone());
+                                    load_hardware_spec(
+                                        &content,
+                                        hw_sva, hw_psl, hw_signals, hw_proof, hw_proof_ok, hw_counterexample, hw_kg, hw_error,
+                                    );
+                                } else {
+                                    // Default: load based on current mode, switch to output tab
+                                    active_tab.set(MobileTab::Panel2);
+                                    let current_mode = *mode.read();
+                                    match current_mode {
+                                        StudioMode::Logic => {
+                                            input.set(content.clone());
+                                            if let Some(compiled) = compile_worker::compile(content).await {
+                                                result.set(compiled);
+                                            }
+                                        }
+                                        StudioMode::Code => {
+                                            code_input.set(content.clone());
+                                            if let Some(interp_result) = compile_worker::interpret(content).await {
+                                                interpreter_result.set(interp_result);
+                                            }
+                                        }
+                                        StudioMode::Math => math_input.set(content),
+                                        StudioMode::Hardware => {
+                                            hw_input.set(content.clone());
+                                            load_hardware_spec(
+                                                &content,
+                                                hw_sva, hw_psl, hw_signals, hw_proof, hw_proof_ok, hw_counterexample, hw_kg, hw_error,
+                                            );
+                                        }
+                                    }
+                                }
+                            }
+                            Err(e) => {
+                                vfs_error.set(Some(format!("Failed to read file: {:?}", e)));
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        vfs_error.set(Some(format!("VFS INIT FAILED: {:?}", e)));
+                    }
+                }
+            });
+        }
+    });
+
+    // Share packs the open buffers into the URL, falling back to the paste service
+    let handle_share = move |_| {
+        let current_mode = *mode.read();
+        let buffer = match current_mode {
+            StudioMode::Logic => input.read().clone(),
+            StudioMode::Code => code_input.read().clone(),
+            StudioMode::Math => math_input.read().clone(),
+            StudioMode::Hardware => hw_input.read().clone(),
+        };
+        let open = tabs.read().clone();
+        let mut snippet = if open.tabs.is_empty() {
+            Snippet::new(
+                current_mode.extension(),
+                vec![SharedFile::new(format!("untitled.{}", current_mode.extension()), buffer)],
+            )
+        } else {
+            let mut snippet = Snippet::new(
+                current_mode.extension(),
+                open.tabs.iter().map(|t| SharedFile::new(t.name(), t.content.clone())).collect(),
+            );
+            snippet.active = open.active.unwrap_or(0);
+            snippet
+        };
+        if current_mode == StudioMode::Logic {
+            snippet = snippet.with_option("format", output_format_name(*format.read()));
+        }
+        share_status.set(Some("Creating link…".to_string()));
+        spawn(async move {
+            let fragment = match snippet.fragment() {
+                Some(fragment) => Ok(fragment),
+                None => snippet.upload().await,
+            };
+            match fragment {
+                Ok(fragment) => {
+                    #[cfg(target_arch = "wasm32")]
+                    if let Some(window) = web_sys::window() {
+                        let _ = window.location().set_hash(&fragment);
+                        if let Ok(href) = window.location().href() {
+                            let _ = window.navigator().clipboard().write_text(&href);
+                        }
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    let _ = fragment;
+                    share_status.set(Some("Link copied".to_string()));
+                }
+                Err(e) => share_status.set(Some(e)),
+            }
+        });
+    };
+
+    // Leave the collaboration room, saying goodbye to the peers
+    let mut leave_collab = move || {
+        let Some(session) = collab.write().take() else {
+            return;
+        };
+        if let Some(connection) = relay.write().take() {
+            connection.send(&session.farewell());
+            connection.close();
+        }
+        collab_file.set(None);
+        #[cfg(target_arch = "wasm32")]
+        if let Some(window) = web_sys::window() {
+            let _ = window.location().set_hash("");
+        }
+        share_status.set(None);
+    };
+
+    // Collaborate opens a room around the Code buffer and copies its link;
+    // pressed again, it leaves the room
+    let handle_collab = move |_| {
+        if collab.peek().is_some() {
+            leave_collab();
+            return;
+        }
+        let room = collab::Room::random();
+        mode.set(StudioMode::Code);
+        let text = code_input.peek().clone();
+        start_collab(collab::Session::host(room.clone(), logicaffeine_data::generate_replica_id(), &text));
+        if collab.peek().is_none() {
+            return;
+        }
+        #[cfg(target_arch = "wasm32")]
+        if let Some(window) = web_sys::window() {
+            let _ = window.location().set_hash(&room.fragment());
+            if let Ok(href) = window.location().href() {
+                let _ = window.navigator().clipboard().write_text(&href);
+            }
+        }
+        share_status.set(Some("Collaboration link copied".to_string()));
+    };
+
+    // Closing the active tab switches to its neighbour; closing another tab
+    // leaves the active one, and its output, as they are
+    let close_tab = move |path: String| {
+        // Closing the room's tab leaves the room
+        if collab_file.peek().as_deref() == Some(path.as_str()) {
+            leave_collab();
+        }
+        let active = tabs.peek().active_tab().map(|t| t.path.clone());
+        let next = tabs.write().close(&path);
+        match next {
+            Some(next) if Some(&next) != active.as_ref() => open_file.call(next),
+            Some(_) => {}
+            None => current_file.set(None),
+        }
+    };
+
+    // Save writes the tab's buffer back to the VFS
+    let save_tab = move |path: String| {
+        let Some(content) = tabs.read().tabs.iter().find(|t| t.path == path).map(|t| t.content.clone()) else {
+            return;
+        };
+        #[cfg(target_arch = "wasm32")]
+        spawn(async move {
+            let cached = vfs_handle.peek().clone();
+            let Some(vfs) = cached else {
+                return;
+            };
+            match vfs.write(&path, content.as_bytes()).await {
+                Ok(()) => tabs.write().mark_saved(&path),
+                Err(e) => vfs_error.set(Some(format!("Failed to save file: {:?}", e))),
+            }
+        });
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = content;
+            vfs_error.set(Some("VFS not available on native".to_string()));
+        }
+    };
+
     rsx! {
         PageHead {
             title: seo_pages::STUDIO.title,
@@ -3005,6 +3661,25 @@ pub fn Studio(file: Option<String>) -> Element {
                         title: "Toggle file browser",
                         if *sidebar_open.read() { "\u{2630}" } else { "\u{1F4C1}" }
                     }
+                    button {
+                        class: "sidebar-toggle-btn",
+                        onclick: handle_share,
+                        title: "Copy a shareable link",
+                        aria_label: "Copy a shareable link",
+                        "\u{1F517}"
+                    }
+                    button {
+                        class: "sidebar-toggle-btn",
+                        onclick: handle_collab,
+                        title: if collab.read().is_some() { "Leave the collaboration room" } else { "Edit this code live with others" },
+                        aria_label: if collab.read().is_some() { "Leave the collaboration room" } else { "Edit this code live with others" },
+                        "\u{1F465}"
+                    }
+                    if let Some(session) = collab.read().as_ref() {
+                        span { class: "share-status", role: "status", "{collab_status(session, collab_online())}" }
+                    } else if let Some(status) = share_status.read().as_ref() {
+                        span { class: "share-status", role: "status", "{status}" }
+                    }
                 }
                 div { class: "studio-toolbar-center",
                     span { class: "mode-label", "Mode:" }
@@ -3024,6 +3699,15 @@ pub fn Studio(file: Option<String>) -> Element {
                             "\u{23F3} Working\u{2026}"
                         }
                     }
+                    if current_mode == StudioMode::Logic {
+                        button {
+                            class: "execute-btn",
+                            title: "Toggle the parser modes and compare readings",
+                            onclick: move |_| grammar_open.set(!grammar_open()),
+                            span { class: "desktop-label", "Grammar" }
+                            span { class: "mobile-label", "Grammar" }
+                        }
+                    }
                     if current_mode == StudioMode::Code {
                         button {
                             class: "execute-btn",
@@ -3165,211 +3849,7 @@ pub fn Studio(file: Option<String>) -> Element {
                         show_private_mode: *vfs_is_fallback.read(),
                         tree: file_tree.read().clone(),
                         selected_path: current_file.read().clone(),
-                        on_select: EventHandler::new(move |path: String| {
-                            // Close sidebar on mobile
-                            #[cfg(target_arch = "wasm32")]
-                            {
-                                let window = web_sys::window().unwrap();
-                                let width = window.inner_width().unwrap().as_f64().unwrap_or(1024.0);
-                                if width <= 768.0 {
-                                    sidebar_open.set(false);
-                                }
-                            }
-                            current_file.set(Some(path.clone()));
-
-                            // Update URL with file parameter for shareable links
-                            #[cfg(target_arch = "wasm32")]
-                            sync_studio_url(&path);
-
-                            // Load file content from VFS
-                            #[cfg(target_arch = "wasm32")]
-                            {
-                                let path_clone = path.clone();
-                                spawn(async move {
-                                    // Reuse the cached VFS (one worker) instead of spawning a
-                                    // fresh one per file switch; acquire once if not ready yet.
-                                    // Bind the clone first so the peek() read-guard drops before
-                                    // the None arm calls vfs_handle.set() (avoids a borrow panic).
-                                    let cached = vfs_handle.peek().clone();
-                                    let vfs_result = match cached {
-                                        Some(vfs) => Ok(vfs),
-                                        None => get_platform_vfs_with_fallback().await.map(|vfs| {
-                                            vfs_handle.set(Some(vfs.clone()));
-                                            vfs
-                                        }),
-                                    };
-                                    match vfs_result {
-                                        Ok(vfs) => {
-                                            match vfs.read_to_string(&path_clone).await {
-                                                Ok(content) => {
-                                            // Load into appropriate editor based on file path/extension
-                                            // Math files are .logos but in /examples/math/ directory
-                                            let ext = path_clone.rsplit('.').next().unwrap_or("").to_lowercase();
-                                            let is_math_dir = path_clone.contains("/math/") || path_clone.contains("/examples/math");
-                                            let is_hardware_dir = path_clone.contains("/hardware/") || path_clone.contains("/examples/hardware");
-
-                                            // Check for math directory first (takes precedence over .logos extension)
-                                            if is_math_dir || ext == "math" || ext == "vernac" {
-                                                // Switch to Math mode and Output tab
-                                                mode.set(StudioMode::Math);
-                                                active_tab.set(MobileTab::Panel2);
-                                                math_input.set(content);
-                                            } else if ext == "logic" {
-                                                    // Switch to Logic mode and Output tab
-                                                    mode.set(StudioMode::Logic);
-                                                    active_tab.set(MobileTab::Panel2);
-
-                                                    // Load into editor
-                                                    input.set(content.clone());
-
-                                                    // Check if this is a theorem file
-                                                    if content.contains("## Theorem:") {
-                                                        // Handle as theorem block with prover syntax
-                                                        let theorem_result = compile_theorem_for_ui(&content);
-
-                                                        if let Some(err) = theorem_result.error {
-                                                            // Parsing failed
-                                                            result.set(CompileResult {
-                                                                logic: None,
-                                                                simple_logic: None,
-                                                                kripke_logic: None,
-                                                                ast: None,
-                                                                readings: Vec::new(),
-                                                                simple_readings: Vec::new(),
-                                                                kripke_readings: Vec::new(),
-                                                                tokens: Vec::new(),
-                                                                error: Some(err.clone()),
-                                                            });
-                                                            proof_status.set(ProofStatus::Failed(err));
-                                                            current_proof_expr.set(None);
-                                                            knowledge_base.write().clear();
-                                                        } else {
-                                                            // Successfully parsed theorem
-                                                            result.set(CompileResult {
-                                                                logic: theorem_result.goal_string.clone(),
-                                                                simple_logic: theorem_result.goal_string.clone(),
-                                                                kripke_logic: None,
-                                                                ast: None,
-                                                                readings: Vec::new(),
-                                                                simple_readings: Vec::new(),
-                                                                kripke_readings: Vec::new(),
-                                                                tokens: Vec::new(),
-                                                                error: None,
-                                                            });
-
-                                                            // Set up knowledge base from premises
-                                                            knowledge_base.write().clear();
-                                                            for premise in &theorem_result.premises {
-                                                                knowledge_base.write().push(premise.clone());
-                                                            }
-
-                                                            // Set goal for proof engine
-                                                            if let Some(goal) = theorem_result.goal.clone() {
-                                                                current_proof_expr.set(Some(goal));
-                                                            }
-
-                                                            // Solved grid / wh-answer / derivation
-                                                            let html = theorem_proof_html(&theorem_result);
-                                                            if !html.is_empty() {
-                                                                proof_text.set(html);
-                                                                proof_status.set(if theorem_result.verified {
-                                                                    ProofStatus::Success
-                                                                } else {
-                                                                    ProofStatus::Idle
-                                                                });
-                                                                proof_hint.set(Some(theorem_proof_hint(&theorem_result)));
-                                                            } else {
-                                                                proof_status.set(ProofStatus::Idle);
-                                                                proof_hint.set(Some(format!(
-                                                                    "Theorem '{}' ready. {} premise(s) loaded.",
-                                                                    theorem_result.name,
-                                                                    knowledge_base.read().len()
-                                                                )));
-                                                                proof_text.set(String::new());
-                                                            }
-                                                        }
-                                                    } else {
-                                                        // Filter out markdown headers (#) and LOGOS comments (--)
-                                                        let sentences: Vec<&str> = content
-                                                            .lines()
-                                                            .filter(|line| {
-                                                                let trimmed = line.trim();
-                                                                !trimmed.is_empty()
-                                                                && !trimmed.starts_with('#')
-                                                                && !trimmed.starts_with("--")
-                                                            })
-                                                            .collect();
-
-                                                        if !sentences.is_empty() {
-                                                            // Join all sentences and compile together
-                                                            let all_text = sentences.join("\n");
-                                                            let compiled = compile_for_ui(&all_text);
-                                                            result.set(compiled);
-
-                                                            // Use first sentence for proof engine
-                                                            let first_sentence = sentences[0];
-                                                            let proof_result = compile_for_proof(first_sentence);
-                                                            if let Some(expr) = proof_result.proof_expr {
-                                                                current_proof_expr.set(Some(expr));
-                                                            }
-                                                        }
-                                                    }
-                                            } else if ext == "logos" {
-                                                // Switch to Code mode and Output tab
-                                                mode.set(StudioMode::Code);
-                                                active_tab.set(MobileTab::Panel2);
-
-                                                code_input.set(content.clone());
-                                                // Auto-run the code
-                                                let interp_result = interpret_for_ui_baseline(&content).await;
-                                                interpreter_result.set(interp_result);
-                                            } else if is_hardware_dir || ext == "hw" {
-                                                // Switch to Hardware mode and synthesize the spec.
-                                                mode.set(StudioMode::Hardware);
-                                                active_tab.set(MobileTab::Panel2);
-                                                hw_input.set(content.clone());
-                                                load_hardware_spec(
-                                                    &content,
-                                                    hw_sva, hw_psl, hw_signals, hw_proof, hw_proof_ok, hw_counterexample, hw_kg, hw_error,
-                                                );
-                                            } else {
-                                                // Default: load based on current mode, switch to output tab
-                                                active_tab.set(MobileTab::Panel2);
-                                                let current_mode = *mode.read();
-                                                match current_mode {
-                                                    StudioMode::Logic => {
-                                                        input.set(content.clone());
-                                                        let compiled = compile_for_ui(&content);
-                                                        result.set(compiled);
-                                                    }
-                                                    StudioMode::Code => {
-                                                        code_input.set(content.clone());
-                                                        let interp_result = interpret_for_ui_baseline(&content).await;
-                                                        interpreter_result.set(interp_result);
-                                                    }
-                                                    StudioMode::Math => math_input.set(content),
-                                                    StudioMode::Hardware => {
-                                                        hw_input.set(content.clone());
-                                                        load_hardware_spec(
-                                                            &content,
-                                                            hw_sva, hw_psl, hw_signals, hw_proof, hw_proof_ok, hw_counterexample, hw_kg, hw_error,
-                                                        );
-                                                    }
-                                                }
-                                            }
-                                                }
-                                                Err(e) => {
-                                                    vfs_error.set(Some(format!("Failed to read file: {:?}", e)));
-                                                }
-                                            }
-                                        }
-                                        Err(e) => {
-                                            vfs_error.set(Some(format!("VFS INIT FAILED: {:?}", e)));
-                                        }
-                                    }
-                                });
-                            }
-                        }),
+                        on_select: open_file,
                         on_toggle_dir: EventHandler::new(move |path: String| {
                             if let Some(node) = file_tree.write().find_mut(&path) {
                                 node.toggle_expanded();
@@ -3395,6 +3875,13 @@ pub fn Studio(file: Option<String>) -> Element {
                         class: "{panel1_class}",
                         style: "{panel1_style}",
 
+                        EditorTabs {
+                            tabs: tabs.read().clone(),
+                            on_select: open_file,
+                            on_close: close_tab,
+                            on_save: save_tab,
+                        }
+
                         match current_mode {
                             StudioMode::Logic => rsx! {
                                 div {
@@ -3432,7 +3919,32 @@ pub fn Studio(file: Option<String>) -> Element {
                                 div { class: "panel-content",
                                     CodeEditor {
                                         value: code_input.read().clone(),
-                                        on_change: move |v| code_input.set(v),
+                                        on_change: move |v: String| {
+                                            tabs.write().edit_active(&v);
+                                            if *current_file.peek() == *collab_file.peek() {
+                                                let edit = collab.write().as_mut().and_then(|session| session.edit(&v));
+                                                if let (Some(edit), Some(relay)) = (edit, relay.peek().as_ref()) {
+                                                    relay.send(&edit);
+                                                }
+                                            }
+                                            code_input.set(v);
+                                        },
+                                        carets: collab.read().as_ref().filter(|_| *current_file.read() == *collab_file.read()).map(|session| {
+                                            session.cursors().into_iter().map(|cursor| RemoteCaret {
+                                                offset: cursor.offset,
+                                                name: cursor.name,
+                                                color: cursor.color.to_string(),
+                                            }).collect()
+                                        }).unwrap_or_default(),
+                                        on_caret: move |offset: usize| {
+                                            if *current_file.peek() != *collab_file.peek() {
+                                                return;
+                                            }
+                                            let moved = collab.write().as_mut().and_then(|session| session.move_caret(offset));
+                                            if let (Some(moved), Some(relay)) = (moved, relay.peek().as_ref()) {
+                                                relay.send(&moved);
+                                            }
+                                        },
                                         language: Language::Logos,
                                         placeholder: "-- Imperative LOGOS code\n\n## Main\n\nLet x be 1.\nLet y be 2.\nShow x + y.".to_string(),
                                     }
@@ -3450,7 +3962,10 @@ pub fn Studio(file: Option<String>) -> Element {
                                 div { class: "panel-content",
                                     CodeEditor {
                                         value: math_input.read().clone(),
-                                        on_change: move |v| math_input.set(v),
+                                        on_change: move |v: String| {
+                                            tabs.write().edit_active(&v);
+                                            math_input.set(v);
+                                        },
                                         language: Language::Vernacular,
                                         placeholder: "-- Define natural numbers\nInductive Nat := Zero : Nat | Succ : Nat -> Nat.\n\nDefinition one : Nat := Succ Zero.\n\nCheck one.".to_string(),
                                     }
@@ -3468,7 +3983,10 @@ pub fn Studio(file: Option<String>) -> Element {
                                 div { class: "panel-content",
                                     LiveEditor {
                                         value: hw_input.read().clone(),
-                                        on_change: move |v: String| hw_input.set(v),
+                                        on_change: move |v: String| {
+                                            tabs.write().edit_active(&v);
+                                            hw_input.set(v);
+                                        },
                                         placeholder: Some("Describe hardware in English (\"Always, if request is high, then acknowledge is high.\"), design a signal plan (\"NS-left conflicts with the EW crossing.\"), or paste Verilog (module \u{2026} endmodule) to model-check.".to_string()),
                                     }
                                 }
@@ -3619,6 +4137,12 @@ pub fn Studio(file: Option<String>) -> Element {
                                             onclick: move |_| code_output_mode.set(CodeOutputMode::Rust),
                                             "Rust"
                                         }
+                                        button {
+                                            class: if current_code_output_mode == CodeOutputMode::Preview { "output-mode-btn active" } else { "output-mode-btn" },
+                                            title: "LOGOS and Rust side by side, updated as you type",
+                                            onclick: move |_| code_output_mode.set(CodeOutputMode::Preview),
+                                            "Side by side"
+                                        }
                                     }
                                 }
                                 div { class: "panel-content",
@@ -3642,6 +4166,13 @@ pub fn Studio(file: Option<String>) -> Element {
                                                 }
                                             }
                                         }
+                                    } else if current_code_output_mode == CodeOutputMode::Preview {
+                                        RustPreviewPane {
+                                            source: code_input.read().clone(),
+                                            preview: rust_preview.read().clone(),
+                                            previous: previous_rust.read().clone(),
+                                            error: preview_error.read().clone(),
+                                        }
                                     } else {
                                         {
                                             let rust_code = generated_rust.read().clone();
@@ -3989,6 +4520,14 @@ pub fn Studio(file: Option<String>) -> Element {
                     on_close: move |_| debugging.set(false),
                 }
             }
+
+            // Logic-mode grammar playground — bottom-docked, additive like the debugger.
+            if grammar_open() && current_mode == StudioMode::Logic {
+                GrammarPlayground {
+                    sentence: input.read().clone(),
+                    on_close: move |_| grammar_open.set(false),
+                }
+            }
         }
     }
 }
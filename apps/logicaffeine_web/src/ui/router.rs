@@ -10,6 +10,9 @@
 //! | `/` | [`Landing`] | Marketing homepage |
 //! | `/learn` | [`Learn`] | Main learning interface with curriculum |
 //! | `/studio?:file` | [`Studio`] | Playground for experimentation |
+//! | `/embed?:example` | [`Embed`] | Chrome-less example widget for iframes |
+//! | `/notebook` | [`Notebook`] | Cells that build on each other |
+//! | `/exam` | [`Exam`] | Timed exam across chosen modules |
 //! | `/profile` | [`Profile`] | User settings and progress |
 //! | `/pricing` | [`Pricing`] | Subscription plans |
 //! | `/guide` | [`Guide`] | Documentation and tutorials |
@@ -43,8 +46,9 @@
 //! `query_scraping_is_forbidden` lock enforces this.
 
 use dioxus::prelude::*;
-use crate::ui::pages::{Landing, Learn, Pricing, Privacy, Profile, Roadmap, Success, Terms, Workspace, Studio, Guide, Crates, News, NewsArticle, Benchmarks};
+use crate::ui::pages::{Landing, Learn, Pricing, Privacy, Profile, Roadmap, Success, Terms, Workspace, Studio, Guide, Crates, News, NewsArticle, Benchmarks, Notebook, Exam};
 use crate::ui::pages::registry::{Registry, PackageDetail};
+use crate::ui::embed::Embed;
 
 /// Application routes.
 ///
@@ -101,6 +105,25 @@ pub enum Route {
         file: Option<String>,
     },
 
+    /// Embeddable example widget at `/embed`.
+    ///
+    /// Renders one Code example, named like the Studio's `file` parameter,
+    /// e.g. `/embed?example=examples/code/fibonacci.logos`, with no
+    /// navigation so it fits an `<iframe>`.
+    #[route("/embed?:example")]
+    Embed {
+        /// VFS path of the example to show, if any.
+        example: Option<String>,
+    },
+
+    /// Notebook of code, logic, and Markdown cells at `/notebook`.
+    #[route("/notebook")]
+    Notebook {},
+
+    /// Timed exam simulation across chosen modules at `/exam`.
+    #[route("/exam")]
+    Exam {},
+
     /// Main learning interface at `/learn`.
     ///
     /// All learning happens here - curriculum browsing, exercises, and review.
@@ -436,6 +436,20 @@ pub mod pages {
         canonical_path: "/benchmarks",
         og_image: Some("/assets/OG-photo.png"),
     };
+
+    pub const NOTEBOOK: PageMeta = PageMeta {
+        title: "Notebook | LOGICAFFEINE",
+        description: "A LOGOS notebook: code, logic, and Markdown cells that run in order and build on each other, saved in your browser.",
+        canonical_path: "/notebook",
+        og_image: Some("/assets/OG-photo.png"),
+    };
+
+    pub const EXAM: PageMeta = PageMeta {
+        title: "Exam Simulation | LOGICAFFEINE",
+        description: "A timed logic exam across the modules you choose, with no hints and a score report by concept.",
+        canonical_path: "/exam",
+        og_image: Some("/assets/OG-photo.png"),
+    };
 }
 
 /// Per-page head tags: title, description, Open Graph, Twitter Card, canonical.
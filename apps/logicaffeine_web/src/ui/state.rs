@@ -13,6 +13,7 @@
 //! | [`AppState`] | Chat history for the REPL interface |
 //! | [`StudioMode`] | Active mode in the Studio playground |
 //! | [`FileNode`] | Virtual file system tree for Studio |
+//! | [`OpenTabs`] | Studio editor tabs with unsaved buffers |
 //! | [`MathDocument`] | Math mode formula collection |
 //!
 //! # License Tiers
@@ -28,6 +29,7 @@
 
 use dioxus::prelude::*;
 use logicaffeine_language::{compile_with_options, CompileOptions, OutputFormat, Interner, socratic_explanation};
+use std::collections::{BTreeMap, HashMap};
 
 /// API endpoint for license validation.
 const LICENSE_VALIDATOR_URL: &str = "https://api.logicaffeine.com/validate";
@@ -303,7 +305,7 @@ impl AppState {
     }
 
     fn process_logic(&mut self, input: String) {
-        let options = CompileOptions { format: OutputFormat::Unicode, pragmatic: false };
+        let options = CompileOptions { format: OutputFormat::Unicode, pragmatic: false, ..Default::default() };
 
         let response = match compile_with_options(&input, options) {
             Ok(logic) => ChatMessage {
@@ -621,6 +623,146 @@ impl FileNode {
     }
 }
 
+/// An open file in the Studio editor.
+#[derive(Clone, PartialEq, Debug)]
+pub struct EditorTab {
+    /// Full path from VFS root.
+    pub path: String,
+    /// The editor buffer, which may differ from the file on disk.
+    pub content: String,
+    /// True if the buffer has edits not yet written to the VFS.
+    pub dirty: bool,
+}
+
+impl EditorTab {
+    /// The file name shown on the tab.
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or(&self.path)
+    }
+}
+
+/// The Studio's open files, in tab order, and which one is being edited.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct OpenTabs {
+    pub tabs: Vec<EditorTab>,
+    pub active: Option<usize>,
+}
+
+/// The persisted part of [`OpenTabs`]: which files were open, not their buffers.
+#[derive(Clone, PartialEq, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct TabSession {
+    pub paths: Vec<String>,
+    pub active: Option<String>,
+}
+
+impl OpenTabs {
+    /// Opens `path` and makes it active, returning the buffer to edit.
+    ///
+    /// An already-open tab keeps its buffer, so unsaved edits survive
+    /// switching away and back; `content` is only used for a new tab.
+    pub fn open(&mut self, path: String, content: String) -> String {
+        let index = match self.tabs.iter().position(|t| t.path == path) {
+            Some(i) => i,
+            None => {
+                self.tabs.push(EditorTab { path, content, dirty: false });
+                self.tabs.len() - 1
+            }
+        };
+        self.active = Some(index);
+        self.tabs[index].content.clone()
+    }
+
+    /// Opens a buffer with no file behind it yet, such as one from a shared
+    /// link. It starts dirty, so Save writes it to the VFS.
+    pub fn open_new(&mut self, path: String, content: String) {
+        self.open(path, content);
+        if let Some(tab) = self.active.and_then(|i| self.tabs.get_mut(i)) {
+            tab.dirty = true;
+        }
+    }
+
+    /// Closes `path`, returning the path of the tab that becomes active.
+    pub fn close(&mut self, path: &str) -> Option<String> {
+        let index = self.tabs.iter().position(|t| t.path == path)?;
+        self.tabs.remove(index);
+        self.active = match self.active {
+            _ if self.tabs.is_empty() => None,
+            Some(a) if a > index => Some(a - 1),
+            Some(a) if a == index => Some(index.min(self.tabs.len() - 1)),
+            other => other,
+        };
+        self.active_tab().map(|t| t.path.clone())
+    }
+
+    pub fn active_tab(&self) -> Option<&EditorTab> {
+        self.active.and_then(|i| self.tabs.get(i))
+    }
+
+    /// Records an edit to the active tab's buffer.
+    pub fn edit_active(&mut self, content: &str) {
+        if let Some(tab) = self.active.and_then(|i| self.tabs.get_mut(i)) {
+            if tab.content != content {
+                tab.content = content.to_string();
+                tab.dirty = true;
+            }
+        }
+    }
+
+    /// Records an edit to `path`'s buffer, whether or not it is the active tab.
+    pub fn edit(&mut self, path: &str, content: &str) {
+        if let Some(tab) = self.tabs.iter_mut().find(|t| t.path == path) {
+            if tab.content != content {
+                tab.content = content.to_string();
+                tab.dirty = true;
+            }
+        }
+    }
+
+    /// Marks `path` as written to the VFS.
+    pub fn mark_saved(&mut self, path: &str) {
+        if let Some(tab) = self.tabs.iter_mut().find(|t| t.path == path) {
+            tab.dirty = false;
+        }
+    }
+
+    /// Overlays every open buffer onto `files`, so a project run sees unsaved edits.
+    pub fn overlay(&self, files: &mut HashMap<std::path::PathBuf, String>) {
+        for tab in &self.tabs {
+            files.insert(std::path::PathBuf::from(&tab.path), tab.content.clone());
+        }
+    }
+
+    pub fn session(&self) -> TabSession {
+        TabSession {
+            paths: self.tabs.iter().map(|t| t.path.clone()).collect(),
+            active: self.active_tab().map(|t| t.path.clone()),
+        }
+    }
+
+    /// The unsaved buffers, by path, for [`crate::storage::save_studio_drafts_raw`].
+    pub fn drafts(&self) -> BTreeMap<String, String> {
+        self.tabs
+            .iter()
+            .filter(|t| t.dirty)
+            .map(|t| (t.path.clone(), t.content.clone()))
+            .collect()
+    }
+
+    /// Puts back the buffers that were unsaved when the last session ended,
+    /// as dirty tabs, without changing the active tab.
+    pub fn restore_drafts(&mut self, drafts: BTreeMap<String, String>) {
+        for (path, content) in drafts {
+            match self.tabs.iter_mut().find(|t| t.path == path) {
+                Some(tab) => {
+                    tab.content = content;
+                    tab.dirty = true;
+                }
+                None => self.tabs.push(EditorTab { path, content, dirty: true }),
+            }
+        }
+    }
+}
+
 /// A line in the REPL output history.
 #[derive(Clone, PartialEq, Debug)]
 pub struct ReplLine {
@@ -724,6 +866,74 @@ mod tests {
         assert!(LicensePlan::Enterprise.is_paid());
     }
 
+    #[test]
+    fn test_open_tabs_keep_unsaved_buffers() {
+        let mut tabs = OpenTabs::default();
+        tabs.open("/a.logos".to_string(), "A".to_string());
+        tabs.edit_active("A edited");
+        tabs.open("/b.logos".to_string(), "B".to_string());
+        assert_eq!(tabs.tabs.len(), 2);
+
+        // Reopening returns the edited buffer, not the file on disk
+        let buffer = tabs.open("/a.logos".to_string(), "A".to_string());
+        assert_eq!(buffer, "A edited");
+        assert!(tabs.active_tab().unwrap().dirty);
+        tabs.mark_saved("/a.logos");
+        assert!(!tabs.active_tab().unwrap().dirty);
+
+        let mut files = HashMap::new();
+        tabs.overlay(&mut files);
+        assert_eq!(files[&std::path::PathBuf::from("/a.logos")], "A edited");
+    }
+
+    #[test]
+    fn test_edit_reaches_a_background_tab() {
+        let mut tabs = OpenTabs::default();
+        tabs.open("/shared/collab.logos".to_string(), "Show 1.".to_string());
+        tabs.open("/b.logos".to_string(), "B".to_string());
+        tabs.edit("/shared/collab.logos", "Show 2.");
+
+        assert_eq!(tabs.active_tab().unwrap().content, "B");
+        assert!(!tabs.active_tab().unwrap().dirty);
+        assert_eq!(tabs.open("/shared/collab.logos".to_string(), String::new()), "Show 2.");
+        assert!(tabs.active_tab().unwrap().dirty);
+    }
+
+    #[test]
+    fn test_close_tab_activates_neighbor() {
+        let mut tabs = OpenTabs::default();
+        for path in ["/a", "/b", "/c"] {
+            tabs.open(path.to_string(), String::new());
+        }
+        tabs.open("/b".to_string(), String::new());
+        assert_eq!(tabs.close("/b").as_deref(), Some("/c"));
+        assert_eq!(tabs.close("/a").as_deref(), Some("/c"));
+        assert_eq!(tabs.active, Some(0));
+        assert_eq!(tabs.session().paths, vec!["/c".to_string()]);
+        assert_eq!(tabs.close("/c"), None);
+        assert_eq!(tabs.active, None);
+
+        tabs.open_new("/shared/main.logos".to_string(), "Show 1.".to_string());
+        assert!(tabs.active_tab().unwrap().dirty);
+    }
+
+    #[test]
+    fn test_drafts_round_trip_unsaved_buffers() {
+        let mut tabs = OpenTabs::default();
+        tabs.open("/a.logos".to_string(), "A".to_string());
+        tabs.edit_active("A edited");
+        tabs.open("/b.logos".to_string(), "B".to_string());
+        let drafts = tabs.drafts();
+        assert_eq!(drafts.len(), 1);
+
+        let mut restored = OpenTabs::default();
+        restored.open("/b.logos".to_string(), "B".to_string());
+        restored.restore_drafts(drafts);
+        assert_eq!(restored.active_tab().unwrap().path, "/b.logos");
+        assert_eq!(restored.open("/a.logos".to_string(), "A".to_string()), "A edited");
+        assert!(restored.active_tab().unwrap().dirty);
+    }
+
     #[test]
     fn test_studio_mode_hardware() {
         // Hardware is a first-class Studio mode alongside Logic/Code/Math.
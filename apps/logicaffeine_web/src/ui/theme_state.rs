@@ -9,6 +9,9 @@
 //! - Forest: Deep green earth tones
 //! - Ember: Red/orange fire tones
 //!
+//! A user-defined [`CustomTheme`] can be layered over the preset; see
+//! [`crate::ui::custom_theme`].
+//!
 //! # Usage
 //!
 //! ```no_run
@@ -31,8 +34,10 @@ use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 #[cfg(target_arch = "wasm32")]
 use gloo_storage::{LocalStorage, Storage};
+use crate::ui::custom_theme::CustomTheme;
 
 const THEME_STORAGE_KEY: &str = "logicaffeine-theme";
+const CUSTOM_THEME_STORAGE_KEY: &str = "logicaffeine-custom-theme";
 
 /// Available theme variants.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
@@ -196,6 +201,8 @@ impl Theme {
 #[derive(Clone, Copy)]
 pub struct ThemeState {
     current: Signal<Theme>,
+    /// A user-defined theme layered over `current`, if one is applied
+    custom: Signal<Option<CustomTheme>>,
 }
 
 impl ThemeState {
@@ -208,8 +215,16 @@ impl ThemeState {
         #[cfg(not(target_arch = "wasm32"))]
         let initial = Theme::default();
 
+        #[cfg(target_arch = "wasm32")]
+        let custom = LocalStorage::get::<CustomTheme>(CUSTOM_THEME_STORAGE_KEY)
+            .ok()
+            .filter(|theme| theme.validate().is_ok());
+        #[cfg(not(target_arch = "wasm32"))]
+        let custom = None;
+
         Self {
             current: Signal::new(initial),
+            custom: Signal::new(custom),
         }
     }
 
@@ -218,13 +233,39 @@ impl ThemeState {
         *self.current.read()
     }
 
-    /// Sets the theme and persists to localStorage.
+    /// Sets the theme and persists to localStorage. Picking a preset drops
+    /// any custom theme, so the choice is visible.
     pub fn set_theme(&mut self, theme: Theme) {
         self.current.set(theme);
+        self.clear_custom();
         #[cfg(target_arch = "wasm32")]
         let _ = LocalStorage::set(THEME_STORAGE_KEY, theme);
     }
 
+    /// Returns the applied custom theme, if any.
+    pub fn custom(&self) -> Option<CustomTheme> {
+        self.custom.read().clone()
+    }
+
+    /// Applies a custom theme over the current preset and persists it.
+    /// Rejects themes that fail [`CustomTheme::validate`].
+    pub fn set_custom(&mut self, theme: CustomTheme) -> Result<(), String> {
+        theme.validate()?;
+        #[cfg(target_arch = "wasm32")]
+        let _ = LocalStorage::set(CUSTOM_THEME_STORAGE_KEY, &theme);
+        self.custom.set(Some(theme));
+        Ok(())
+    }
+
+    /// Removes the custom theme, back to the plain preset.
+    pub fn clear_custom(&mut self) {
+        if self.custom.peek().is_some() {
+            self.custom.set(None);
+        }
+        #[cfg(target_arch = "wasm32")]
+        LocalStorage::delete(CUSTOM_THEME_STORAGE_KEY);
+    }
+
     /// Cycles to the next theme.
     pub fn cycle_theme(&mut self) {
         let next = match self.current() {
@@ -335,7 +376,7 @@ pub fn theme_css(theme: Theme) -> String {
         .learn-sidebar-module.active {{
             background: rgba(var(--accent-primary-rgb), 0.15) !important;
             color: var(--accent-primary) !important;
-            border-left-color: var(--accent-primary) !important;
+            border-inline-start-color: var(--accent-primary) !important;
         }}
 
         /* Focus rings */
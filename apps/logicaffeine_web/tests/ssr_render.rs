@@ -42,6 +42,8 @@ fn prerendered_pages_carry_their_content() {
         ("/learn", "Learn Logic"),
         ("/benchmarks", "English-level readability"),
         ("/studio", "LOGICAFFEINE Studio"),
+        ("/notebook", "My first notebook"),
+        ("/exam", "Exam simulation"),
         ("/registry", "Package Registry"),
         ("/profile", "Logic Learner"),
     ] {
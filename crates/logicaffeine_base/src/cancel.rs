@@ -0,0 +1,80 @@
+//! Cooperative cancellation for long-running passes.
+//!
+//! A [`CancellationToken`] is a shared flag: the side that owns a request
+//! (an LSP server whose document changed, a web worker whose input was
+//! superseded) calls [`cancel`](CancellationToken::cancel), and the pass doing
+//! the work — the lexer, the parser, scope enumeration — polls
+//! [`is_cancelled`](CancellationToken::is_cancelled) at its natural checkpoints
+//! and stops early. Nothing is interrupted; a pass that never polls simply
+//! runs to completion.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A cloneable cancel flag; every clone observes the same cancellation.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// A fresh, uncancelled token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation. Idempotent.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// `Err(Cancelled)` once cancellation was requested, for `?` at checkpoints.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The error a pass returns when its token was cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("operation cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_one_flag() {
+        let token = CancellationToken::new();
+        let observer = token.clone();
+        assert!(!observer.is_cancelled());
+        assert_eq!(observer.check(), Ok(()));
+        token.cancel();
+        assert!(observer.is_cancelled());
+        assert_eq!(observer.check(), Err(Cancelled));
+    }
+
+    #[test]
+    fn cancellation_crosses_threads() {
+        let token = CancellationToken::new();
+        let remote = token.clone();
+        std::thread::spawn(move || remote.cancel()).join().unwrap();
+        assert!(token.is_cancelled());
+    }
+}
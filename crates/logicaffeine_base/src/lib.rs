@@ -2,12 +2,14 @@
 #![doc = include_str!("../README.md")]
 
 pub mod arena;
+pub mod cancel;
 pub mod describe;
 pub mod dimension;
 pub mod hash;
 pub mod intern;
 pub mod money;
 pub mod numeric;
+pub mod pattern;
 pub mod quantity;
 pub mod sha_ops;
 pub mod span;
@@ -18,6 +20,7 @@ pub mod error;
 pub mod union_find;
 
 pub use arena::Arena;
+pub use cancel::{CancellationToken, Cancelled};
 pub use dimension::{BaseDim, Dimension, Exp};
 pub use quantity::{Quantity, Unit};
 pub use intern::{Interner, Symbol, SymbolEq};
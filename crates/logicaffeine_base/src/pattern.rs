@@ -0,0 +1,869 @@
+//! Pattern — the regular-expression dialect of the imperative language's `Pattern` type.
+//!
+//! This module is the *authority* on what a pattern means. It parses the dialect, reports a
+//! precise error for anything outside it (the compiler calls [`Pattern::parse`] on every
+//! `the pattern "…"` literal, so a bad pattern is a compile-time error, not a runtime one), and
+//! renders the parsed tree back out as canonical `regex`-crate syntax. Native tiers hand that
+//! canonical form to the `regex` crate; wasm, where we keep the bundle lean, runs the bounded
+//! backtracker below on the same tree. Because both engines are fed the *parsed* pattern rather
+//! than the user's text, they cannot disagree about what a quirky escape means.
+//!
+//! The dialect is the portable core every regex user already knows:
+//! - literals, `.` (any character but newline), `^` / `$` (start / end of the text);
+//! - classes `[a-z]`, `[^,;]` and the ASCII shorthands `\d \w \s` (and `\D \W \S`);
+//! - groups `( )`, non-capturing `(?: )`, named `(?<name> )` / `(?P<name> )`, alternation `|`;
+//! - quantifiers `* + ?` and `{n}`, `{n,}`, `{n,m}` (at most 1000), each with a lazy `?` form.
+//!
+//! Matching is leftmost-first (Perl semantics), which is what the `regex` crate implements for
+//! the same syntax, so a match and its groups are identical on every tier.
+
+use core::fmt;
+use std::collections::HashSet;
+
+/// The largest count a `{n,m}` quantifier may use.
+const MAX_REPEAT: u32 = 1000;
+/// The largest compiled program we accept — keeps a `(a{1000}){1000}` from exhausting memory.
+const MAX_PROGRAM: usize = 100_000;
+
+/// A pattern outside the dialect, with the 1-based character position it went wrong at.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PatternError {
+    pub message: String,
+    pub position: usize,
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at character {})", self.message, self.position)
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+/// The byte span of every group of one match; index 0 is the whole match.
+pub type GroupSpans = Vec<Option<(usize, usize)>>;
+
+#[derive(Clone, Debug)]
+struct Class {
+    negated: bool,
+    ranges: Vec<(char, char)>,
+}
+
+impl Class {
+    fn contains(&self, c: char) -> bool {
+        self.ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi) != self.negated
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Node {
+    Empty,
+    Char(char),
+    Any,
+    Class(Class),
+    Start,
+    End,
+    Group(Box<Node>, Option<usize>),
+    Concat(Vec<Node>),
+    Alt(Vec<Node>),
+    Repeat { node: Box<Node>, min: u32, max: Option<u32>, greedy: bool },
+}
+
+#[derive(Clone, Debug)]
+enum Inst {
+    Char(char),
+    Any,
+    Class(usize),
+    Start,
+    End,
+    Save(usize),
+    Split(usize, usize),
+    Jmp(usize),
+    Match,
+}
+
+/// A parsed, validated pattern: its source, canonical `regex` syntax, group names, and the
+/// compiled program the portable matcher runs.
+#[derive(Clone, Debug)]
+pub struct Pattern {
+    source: String,
+    canonical: String,
+    names: Vec<Option<String>>,
+    classes: Vec<Class>,
+    program: Vec<Inst>,
+}
+
+impl Pattern {
+    /// Parse and compile `source`, or explain why it is not a pattern.
+    pub fn parse(source: &str) -> Result<Pattern, PatternError> {
+        let mut parser = Parser { chars: source.chars().collect(), pos: 0, names: vec![None] };
+        let tree = parser.parse_alt()?;
+        if parser.pos < parser.chars.len() {
+            return Err(parser.error("unmatched ')'"));
+        }
+        let mut canonical = String::new();
+        render(&tree, &parser.names, &mut canonical);
+        let mut compiler = Compiler { program: Vec::new(), classes: Vec::new() };
+        compiler.emit(Inst::Save(0));
+        compiler.compile(&tree);
+        compiler.emit(Inst::Save(1));
+        compiler.emit(Inst::Match);
+        if compiler.program.len() > MAX_PROGRAM {
+            return Err(PatternError { message: "pattern is too large".to_string(), position: 1 });
+        }
+        Ok(Pattern {
+            source: source.to_string(),
+            canonical,
+            names: parser.names,
+            classes: compiler.classes,
+            program: compiler.program,
+        })
+    }
+
+    /// The pattern as it was written.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// The pattern in canonical `regex`-crate syntax (every literal escaped, every class explicit).
+    pub fn to_regex_syntax(&self) -> &str {
+        &self.canonical
+    }
+
+    /// The number of groups, counting the whole match as group 0.
+    pub fn group_count(&self) -> usize {
+        self.names.len()
+    }
+
+    /// The name of each group by index (`None` for group 0 and unnamed groups).
+    pub fn group_names(&self) -> &[Option<String>] {
+        &self.names
+    }
+
+    /// The named groups, in the order they open.
+    pub fn named_groups(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().filter_map(|n| n.as_deref())
+    }
+
+    /// Does the pattern match anywhere in `text`?
+    pub fn is_match(&self, text: &str) -> bool {
+        self.captures_at(text, 0).is_some()
+    }
+
+    /// The leftmost-first match starting at or after byte offset `from`, with every group's span.
+    /// `^` still means the start of `text`, not of the search.
+    pub fn captures_at(&self, text: &str, from: usize) -> Option<GroupSpans> {
+        let mut visited = HashSet::new();
+        let mut slots = vec![None; self.names.len() * 2];
+        let mut start = from;
+        loop {
+            if self.run(text, start, &mut slots, &mut visited) {
+                return Some(
+                    (0..self.names.len())
+                        .map(|g| match (slots[2 * g], slots[2 * g + 1]) {
+                            (Some(s), Some(e)) => Some((s, e)),
+                            _ => None,
+                        })
+                        .collect(),
+                );
+            }
+            match text[start..].chars().next() {
+                Some(c) => start += c.len_utf8(),
+                None => return None,
+            }
+        }
+    }
+
+    /// Replace every non-overlapping match in `text`, expanding `template` (see [`replace_all_with`]).
+    pub fn replace_all(&self, text: &str, template: &str) -> String {
+        replace_all_with(text, template, &self.names, |at| self.captures_at(text, at))
+    }
+
+    /// Bounded backtracking from `start`. A `(pc, pos)` state that failed once fails again — the
+    /// outcome does not depend on how we got there — so `visited` makes the search linear in
+    /// `program × text` and stops empty loops like `(a*)*` from spinning.
+    fn run(
+        &self,
+        text: &str,
+        start: usize,
+        slots: &mut [Option<usize>],
+        visited: &mut HashSet<(usize, usize)>,
+    ) -> bool {
+        enum Frame {
+            Step(usize, usize),
+            Restore(usize, Option<usize>),
+        }
+        slots.iter_mut().for_each(|s| *s = None);
+        let mut stack = vec![Frame::Step(0, start)];
+        while let Some(frame) = stack.pop() {
+            let (mut pc, mut pos) = match frame {
+                Frame::Step(pc, pos) => (pc, pos),
+                Frame::Restore(slot, old) => {
+                    slots[slot] = old;
+                    continue;
+                }
+            };
+            loop {
+                if !visited.insert((pc, pos)) {
+                    break;
+                }
+                match &self.program[pc] {
+                    Inst::Char(want) => match text[pos..].chars().next() {
+                        Some(c) if c == *want => {
+                            pos += c.len_utf8();
+                            pc += 1;
+                        }
+                        _ => break,
+                    },
+                    Inst::Any => match text[pos..].chars().next() {
+                        Some(c) if c != '\n' => {
+                            pos += c.len_utf8();
+                            pc += 1;
+                        }
+                        _ => break,
+                    },
+                    Inst::Class(i) => match text[pos..].chars().next() {
+                        Some(c) if self.classes[*i].contains(c) => {
+                            pos += c.len_utf8();
+                            pc += 1;
+                        }
+                        _ => break,
+                    },
+                    Inst::Start if pos == 0 => pc += 1,
+                    Inst::End if pos == text.len() => pc += 1,
+                    Inst::Start | Inst::End => break,
+                    Inst::Save(slot) => {
+                        stack.push(Frame::Restore(*slot, slots[*slot]));
+                        slots[*slot] = Some(pos);
+                        pc += 1;
+                    }
+                    Inst::Split(first, second) => {
+                        stack.push(Frame::Step(*second, pos));
+                        pc = *first;
+                    }
+                    Inst::Jmp(to) => pc = *to,
+                    Inst::Match => return true,
+                }
+            }
+        }
+        false
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// Replace every non-overlapping match of a search in `text`, expanding `template` for each.
+///
+/// `search(at)` returns the next match starting at or after byte `at`. An empty match that
+/// touches the end of the previous match is skipped (the `regex` crate's rule), so `a*` over
+/// `"baaac"` yields `-b-c-`. Shared by both engines so iteration agrees on every tier.
+///
+/// In the template `$1` / `${1}` is a group by number, `$name` / `${name}` a group by name, and
+/// `$$` a literal `$`; a group that did not take part (or does not exist) expands to nothing.
+pub fn replace_all_with(
+    text: &str,
+    template: &str,
+    names: &[Option<String>],
+    mut search: impl FnMut(usize) -> Option<GroupSpans>,
+) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut at = 0;
+    let mut last_end = None;
+    while at <= text.len() {
+        let Some(groups) = search(at) else { break };
+        let Some((start, end)) = groups[0] else { break };
+        if start == end && last_end == Some(end) {
+            match text[end..].chars().next() {
+                Some(c) => {
+                    at = end + c.len_utf8();
+                    continue;
+                }
+                None => break,
+            }
+        }
+        out.push_str(&text[copied..start]);
+        expand_template(template, text, &groups, names, &mut out);
+        copied = end;
+        last_end = Some(end);
+        at = end;
+    }
+    out.push_str(&text[copied..]);
+    out
+}
+
+/// Expand one replacement `template` for a match (see [`replace_all_with`] for the syntax).
+pub fn expand_template(
+    template: &str,
+    text: &str,
+    groups: &[Option<(usize, usize)>],
+    names: &[Option<String>],
+    out: &mut String,
+) {
+    let mut rest = template;
+    while let Some(dollar) = rest.find('$') {
+        out.push_str(&rest[..dollar]);
+        rest = &rest[dollar + 1..];
+        if let Some(after) = rest.strip_prefix('$') {
+            out.push('$');
+            rest = after;
+            continue;
+        }
+        let (name, after) = if let Some(braced) = rest.strip_prefix('{') {
+            match braced.find('}') {
+                Some(close) => (&braced[..close], &braced[close + 1..]),
+                None => ("", rest),
+            }
+        } else {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            (&rest[..len], &rest[len..])
+        };
+        if name.is_empty() {
+            out.push('$');
+            continue;
+        }
+        rest = after;
+        let index = match name.parse::<usize>() {
+            Ok(n) => Some(n),
+            Err(_) => names.iter().position(|n| n.as_deref() == Some(name)),
+        };
+        if let Some(Some((s, e))) = index.and_then(|i| groups.get(i)) {
+            out.push_str(&text[*s..*e]);
+        }
+    }
+    out.push_str(rest);
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    names: Vec<Option<String>>,
+}
+
+impl Parser {
+    fn error(&self, message: &str) -> PatternError {
+        PatternError { message: message.to_string(), position: self.pos + 1 }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_alt(&mut self) -> Result<Node, PatternError> {
+        let mut branches = vec![self.parse_concat()?];
+        while self.eat('|') {
+            branches.push(self.parse_concat()?);
+        }
+        Ok(if branches.len() == 1 { branches.pop().unwrap() } else { Node::Alt(branches) })
+    }
+
+    fn parse_concat(&mut self) -> Result<Node, PatternError> {
+        let mut items = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.parse_atom()?;
+            items.push(self.parse_quantifier(atom)?);
+        }
+        Ok(match items.len() {
+            0 => Node::Empty,
+            1 => items.pop().unwrap(),
+            _ => Node::Concat(items),
+        })
+    }
+
+    fn parse_quantifier(&mut self, atom: Node) -> Result<Node, PatternError> {
+        let at = self.pos;
+        let (min, max) = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => return self.parse_counted(atom),
+            _ => return Ok(atom),
+        };
+        self.pos += 1;
+        self.finish_repeat(atom, min, max, at)
+    }
+
+    fn parse_counted(&mut self, atom: Node) -> Result<Node, PatternError> {
+        let at = self.pos;
+        self.pos += 1;
+        let min = self.parse_count()?.ok_or_else(|| self.error("expected a count after '{'"))?;
+        let max = if self.eat(',') { self.parse_count()? } else { Some(min) };
+        if !self.eat('}') {
+            return Err(self.error("expected '}' to close the repetition"));
+        }
+        if max.is_some_and(|m| m < min) {
+            return Err(PatternError {
+                message: "repetition range is backwards".to_string(),
+                position: at + 1,
+            });
+        }
+        self.finish_repeat(atom, min, max, at)
+    }
+
+    fn parse_count(&mut self) -> Result<Option<u32>, PatternError> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Ok(None);
+        }
+        let digits: String = self.chars[start..self.pos].iter().collect();
+        match digits.parse::<u32>() {
+            Ok(n) if n <= MAX_REPEAT => Ok(Some(n)),
+            _ => Err(PatternError {
+                message: format!("repetition count exceeds {}", MAX_REPEAT),
+                position: start + 1,
+            }),
+        }
+    }
+
+    fn finish_repeat(&mut self, atom: Node, min: u32, max: Option<u32>, at: usize) -> Result<Node, PatternError> {
+        let invalid = match atom {
+            Node::Start | Node::End => Some("an anchor cannot be repeated"),
+            Node::Repeat { .. } => Some("a repetition cannot be repeated without a group"),
+            _ => None,
+        };
+        if let Some(message) = invalid {
+            return Err(PatternError { message: message.to_string(), position: at + 1 });
+        }
+        let greedy = !self.eat('?');
+        if matches!(self.peek(), Some('*' | '+' | '?' | '{')) {
+            return Err(self.error("a repetition cannot be repeated without a group"));
+        }
+        Ok(Node::Repeat { node: Box::new(atom), min, max, greedy })
+    }
+
+    fn parse_atom(&mut self) -> Result<Node, PatternError> {
+        let c = self.peek().unwrap();
+        match c {
+            '*' | '+' | '?' | '{' => Err(self.error("repetition operator with nothing to repeat")),
+            '.' => {
+                self.pos += 1;
+                Ok(Node::Any)
+            }
+            '^' => {
+                self.pos += 1;
+                Ok(Node::Start)
+            }
+            '$' => {
+                self.pos += 1;
+                Ok(Node::End)
+            }
+            '[' => self.parse_class(),
+            '(' => self.parse_group(),
+            '\\' => self.parse_escape(),
+            _ => {
+                self.pos += 1;
+                Ok(Node::Char(c))
+            }
+        }
+    }
+
+    fn parse_group(&mut self) -> Result<Node, PatternError> {
+        let open = self.pos;
+        self.pos += 1;
+        let index = if self.eat('?') {
+            if self.eat(':') {
+                None
+            } else if self.eat('<') || (self.eat('P') && self.eat('<')) {
+                let name = self.parse_group_name()?;
+                self.names.push(Some(name));
+                Some(self.names.len() - 1)
+            } else {
+                return Err(self.error("unsupported group syntax; use (?:…) or (?<name>…)"));
+            }
+        } else {
+            self.names.push(None);
+            Some(self.names.len() - 1)
+        };
+        let inner = self.parse_alt()?;
+        if !self.eat(')') {
+            return Err(PatternError { message: "unclosed group".to_string(), position: open + 1 });
+        }
+        Ok(Node::Group(Box::new(inner), index))
+    }
+
+    fn parse_group_name(&mut self) -> Result<String, PatternError> {
+        let start = self.pos;
+        let mut name = String::new();
+        while let Some(c) = self.peek() {
+            if c == '>' {
+                break;
+            }
+            let valid = c == '_' || c.is_ascii_alphabetic() || (!name.is_empty() && c.is_ascii_digit());
+            if !valid {
+                return Err(self.error("group names are letters, digits and '_', starting with a letter"));
+            }
+            name.push(c);
+            self.pos += 1;
+        }
+        if !self.eat('>') {
+            return Err(self.error("expected '>' to close the group name"));
+        }
+        if name.is_empty() {
+            return Err(PatternError { message: "empty group name".to_string(), position: start + 1 });
+        }
+        if self.names.iter().any(|n| n.as_deref() == Some(name.as_str())) {
+            return Err(PatternError {
+                message: format!("duplicate group name '{}'", name),
+                position: start + 1,
+            });
+        }
+        Ok(name)
+    }
+
+    fn parse_escape(&mut self) -> Result<Node, PatternError> {
+        match self.parse_escape_item()? {
+            Ok(c) => Ok(Node::Char(c)),
+            Err(class) => Ok(Node::Class(class)),
+        }
+    }
+
+    /// One escape: a literal character (`Ok`) or a shorthand class (`Err`).
+    fn parse_escape_item(&mut self) -> Result<Result<char, Class>, PatternError> {
+        self.pos += 1;
+        let Some(c) = self.peek() else {
+            return Err(self.error("pattern ends with a lone '\\'"));
+        };
+        let item = match c {
+            'n' => Ok('\n'),
+            't' => Ok('\t'),
+            'r' => Ok('\r'),
+            'd' | 'D' | 'w' | 'W' | 's' | 'S' => Err(shorthand(c)),
+            c if c.is_ascii_punctuation() || c == ' ' => Ok(c),
+            _ => return Err(PatternError {
+                message: format!("unsupported escape '\\{}'", c),
+                position: self.pos,
+            }),
+        };
+        self.pos += 1;
+        Ok(item)
+    }
+
+    fn parse_class(&mut self) -> Result<Node, PatternError> {
+        let open = self.pos;
+        self.pos += 1;
+        let negated = self.eat('^');
+        let mut ranges = Vec::new();
+        let mut first = true;
+        loop {
+            let Some(c) = self.peek() else {
+                return Err(PatternError { message: "unclosed character class".to_string(), position: open + 1 });
+            };
+            if c == ']' && !first {
+                self.pos += 1;
+                break;
+            }
+            first = false;
+            let lo = if c == '\\' {
+                match self.parse_escape_item()? {
+                    Ok(c) => c,
+                    Err(class) => {
+                        ranges.extend(class_ranges(&class));
+                        continue;
+                    }
+                }
+            } else {
+                self.pos += 1;
+                c
+            };
+            let is_range = self.peek() == Some('-') && !matches!(self.chars.get(self.pos + 1), Some(']') | None);
+            if !is_range {
+                ranges.push((lo, lo));
+                continue;
+            }
+            self.pos += 1;
+            let hi = if self.peek() == Some('\\') {
+                match self.parse_escape_item()? {
+                    Ok(c) => c,
+                    Err(_) => return Err(self.error("a class shorthand cannot end a range")),
+                }
+            } else {
+                let c = self.peek().unwrap();
+                self.pos += 1;
+                c
+            };
+            if hi < lo {
+                return Err(self.error("character range is backwards"));
+            }
+            ranges.push((lo, hi));
+        }
+        Ok(Node::Class(Class { negated, ranges }))
+    }
+}
+
+/// `\d \w \s` (ASCII) and their negations.
+fn shorthand(c: char) -> Class {
+    let ranges = match c.to_ascii_lowercase() {
+        'd' => vec![('0', '9')],
+        'w' => vec![('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')],
+        _ => vec![('\t', '\r'), (' ', ' ')],
+    };
+    Class { negated: c.is_ascii_uppercase(), ranges }
+}
+
+/// The positive ranges a class covers — a negated shorthand inside `[…]` becomes its complement.
+fn class_ranges(class: &Class) -> Vec<(char, char)> {
+    if !class.negated {
+        return class.ranges.clone();
+    }
+    let mut sorted = class.ranges.clone();
+    sorted.sort();
+    let mut out = Vec::new();
+    let mut next = 0u32;
+    for (lo, hi) in sorted {
+        if (lo as u32) > next {
+            out.push((char::from_u32(next).unwrap(), char::from_u32(lo as u32 - 1).unwrap()));
+        }
+        next = next.max(hi as u32 + 1);
+    }
+    if next <= char::MAX as u32 {
+        out.push((char::from_u32(next).unwrap(), char::MAX));
+    }
+    out
+}
+
+/// Write a literal character so the `regex` crate reads it as exactly that character.
+fn render_char(c: char, out: &mut String) {
+    if c.is_alphanumeric() || c == '_' || c == ' ' {
+        out.push(c);
+    } else {
+        out.push_str(&format!("\\x{{{:X}}}", c as u32));
+    }
+}
+
+fn render(node: &Node, names: &[Option<String>], out: &mut String) {
+    match node {
+        Node::Empty => {}
+        Node::Char(c) => render_char(*c, out),
+        Node::Any => out.push('.'),
+        Node::Start => out.push('^'),
+        Node::End => out.push('$'),
+        Node::Class(class) => {
+            out.push('[');
+            if class.negated {
+                out.push('^');
+            }
+            for &(lo, hi) in &class.ranges {
+                out.push_str(&format!("\\x{{{:X}}}", lo as u32));
+                if hi != lo {
+                    out.push_str(&format!("-\\x{{{:X}}}", hi as u32));
+                }
+            }
+            out.push(']');
+        }
+        Node::Group(inner, index) => {
+            out.push('(');
+            match index.map(|g| &names[g]) {
+                None => out.push_str("?:"),
+                Some(Some(name)) => out.push_str(&format!("?P<{}>", name)),
+                Some(None) => {}
+            }
+            render(inner, names, out);
+            out.push(')');
+        }
+        Node::Concat(items) => items.iter().for_each(|n| render(n, names, out)),
+        Node::Alt(branches) => {
+            for (i, branch) in branches.iter().enumerate() {
+                if i > 0 {
+                    out.push('|');
+                }
+                render(branch, names, out);
+            }
+        }
+        Node::Repeat { node, min, max, greedy } => {
+            out.push_str("(?:");
+            render(node, names, out);
+            out.push(')');
+            match max {
+                Some(max) if max == min => out.push_str(&format!("{{{}}}", min)),
+                Some(max) => out.push_str(&format!("{{{},{}}}", min, max)),
+                None => out.push_str(&format!("{{{},}}", min)),
+            }
+            if !greedy {
+                out.push('?');
+            }
+        }
+    }
+}
+
+/// Lowers the tree to the backtracker's program; repetitions are unrolled into copies.
+struct Compiler {
+    program: Vec<Inst>,
+    classes: Vec<Class>,
+}
+
+impl Compiler {
+    fn emit(&mut self, inst: Inst) -> usize {
+        self.program.push(inst);
+        self.program.len() - 1
+    }
+
+    fn compile(&mut self, node: &Node) {
+        if self.program.len() > MAX_PROGRAM {
+            return;
+        }
+        match node {
+            Node::Empty => {}
+            Node::Char(c) => {
+                self.emit(Inst::Char(*c));
+            }
+            Node::Any => {
+                self.emit(Inst::Any);
+            }
+            Node::Start => {
+                self.emit(Inst::Start);
+            }
+            Node::End => {
+                self.emit(Inst::End);
+            }
+            Node::Class(class) => {
+                self.classes.push(class.clone());
+                self.emit(Inst::Class(self.classes.len() - 1));
+            }
+            Node::Group(inner, index) => match index {
+                Some(g) => {
+                    self.emit(Inst::Save(2 * g));
+                    self.compile(inner);
+                    self.emit(Inst::Save(2 * g + 1));
+                }
+                None => self.compile(inner),
+            },
+            Node::Concat(items) => items.iter().for_each(|n| self.compile(n)),
+            Node::Alt(branches) => {
+                let mut exits = Vec::new();
+                for (i, branch) in branches.iter().enumerate() {
+                    if i + 1 < branches.len() {
+                        let split = self.emit(Inst::Split(0, 0));
+                        self.compile(branch);
+                        exits.push(self.emit(Inst::Jmp(0)));
+                        let next = self.program.len();
+                        self.program[split] = Inst::Split(split + 1, next);
+                    } else {
+                        self.compile(branch);
+                    }
+                }
+                let end = self.program.len();
+                for exit in exits {
+                    self.program[exit] = Inst::Jmp(end);
+                }
+            }
+            Node::Repeat { node, min, max, greedy } => {
+                for _ in 0..*min {
+                    self.compile(node);
+                }
+                match max {
+                    None => {
+                        let split = self.emit(Inst::Split(0, 0));
+                        self.compile(node);
+                        self.emit(Inst::Jmp(split));
+                        let out = self.program.len();
+                        self.program[split] = self.split(split + 1, out, *greedy);
+                    }
+                    Some(max) => {
+                        let mut splits = Vec::new();
+                        for _ in *min..*max {
+                            splits.push(self.emit(Inst::Split(0, 0)));
+                            self.compile(node);
+                        }
+                        let out = self.program.len();
+                        for split in splits {
+                            self.program[split] = self.split(split + 1, out, *greedy);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn split(&self, body: usize, out: usize, greedy: bool) -> Inst {
+        if greedy {
+            Inst::Split(body, out)
+        } else {
+            Inst::Split(out, body)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'t>(pattern: &str, text: &'t str) -> Option<&'t str> {
+        let p = Pattern::parse(pattern).unwrap();
+        p.captures_at(text, 0).map(|g| {
+            let (s, e) = g[0].unwrap();
+            &text[s..e]
+        })
+    }
+
+    #[test]
+    fn leftmost_first_matching() {
+        assert_eq!(find(r"\d+", "abc 123 45"), Some("123"));
+        assert_eq!(find("a|ab", "ab"), Some("a"));
+        assert_eq!(find("a+?", "aaa"), Some("a"));
+        assert_eq!(find("^x", "yx"), None);
+        assert_eq!(find("o$", "foo"), Some("o"));
+        assert_eq!(find("[^,]+", ",,hello,"), Some("hello"));
+        assert_eq!(find(r"a{2,3}", "aaaa"), Some("aaa"));
+        assert_eq!(find("h.llo", "héllo"), Some("héllo"));
+        assert_eq!(find("(a*)*b", "aaab"), Some("aaab"));
+    }
+
+    #[test]
+    fn named_groups_and_spans() {
+        let p = Pattern::parse(r"(?<year>\d{4})-(?P<month>\d\d)(x)?").unwrap();
+        assert_eq!(p.named_groups().collect::<Vec<_>>(), ["year", "month"]);
+        assert_eq!(p.group_count(), 4);
+        let g = p.captures_at("on 2024-06!", 0).unwrap();
+        assert_eq!(g[1], Some((3, 7)));
+        assert_eq!(g[2], Some((8, 10)));
+        assert_eq!(g[3], None);
+    }
+
+    #[test]
+    fn replace_expands_templates_and_skips_touching_empty_matches() {
+        let p = Pattern::parse(r"(?<k>\w+)=(\d+)").unwrap();
+        assert_eq!(p.replace_all("a=1, b=22", "${2}:$k $$"), "1:a $, 22:b $");
+        assert_eq!(Pattern::parse("a*").unwrap().replace_all("baaac", "-"), "-b-c-");
+        assert_eq!(Pattern::parse("x").unwrap().replace_all("none", "y"), "none");
+    }
+
+    #[test]
+    fn errors_carry_positions() {
+        let err = |s: &str| Pattern::parse(s).unwrap_err();
+        assert_eq!(err("(ab").message, "unclosed group");
+        assert_eq!(err("ab)").position, 3);
+        assert_eq!(err("*a").message, "repetition operator with nothing to repeat");
+        assert_eq!(err("[z-a]").message, "character range is backwards");
+        assert_eq!(err(r"\b").message, "unsupported escape '\\b'");
+        assert_eq!(err("(?<n>a)(?<n>b)").message, "duplicate group name 'n'");
+        assert_eq!(err("a{2000}").message, "repetition count exceeds 1000");
+        assert_eq!(err("a**").message, "a repetition cannot be repeated without a group");
+    }
+
+    #[test]
+    fn canonical_syntax_escapes_every_literal() {
+        let p = Pattern::parse(r"(?<n>a\.b)[\d-]|c?").unwrap();
+        assert_eq!(p.to_regex_syntax(), r"(?P<n>a\x{2E}b)[\x{30}-\x{39}\x{2D}]|(?:c){0,1}");
+    }
+}
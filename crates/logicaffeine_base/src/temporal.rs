@@ -768,6 +768,68 @@ pub fn format_time_of_day(nanos_from_midnight: i64) -> String {
     s
 }
 
+const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"];
+
+/// Format a SmoothUTC instant with a `strftime`-style pattern — the renderer behind
+/// `<moment> formatted as "%d %B %Y"`. Supported directives:
+///
+/// | | |
+/// |---|---|
+/// | `%Y` `%y` | year, two-digit year |
+/// | `%m` `%d` `%e` | month, day, space-padded day |
+/// | `%B` `%b` | month name, abbreviated month name |
+/// | `%A` `%a` | weekday name, abbreviated weekday name |
+/// | `%H` `%I` `%p` | 24-hour hour, 12-hour hour, `AM`/`PM` |
+/// | `%M` `%S` `%f` | minute, second, nanoseconds (9 digits) |
+/// | `%j` `%V` | day of year, ISO week number |
+/// | `%F` `%T` `%%` | `%Y-%m-%d`, `%H:%M:%S`, a literal `%` |
+///
+/// An unknown or dangling directive is an `Err` naming it.
+pub fn format_pattern(instant_ns: i64, pattern: &str) -> Result<String, String> {
+    use std::fmt::Write;
+    let c = civil_from_unix_nanos(instant_ns);
+    let days = instant_ns.div_euclid(NANOS_PER_DAY);
+    let month = MONTH_NAMES[c.month as usize - 1];
+    let weekday = WEEKDAY_NAMES[weekday_from_days(days) as usize];
+    let mut out = String::with_capacity(pattern.len() + 16);
+    let mut chars = pattern.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '%' {
+            out.push(ch);
+            continue;
+        }
+        let _ = match chars.next() {
+            Some('Y') => write!(out, "{:04}", c.year),
+            Some('y') => write!(out, "{:02}", c.year.rem_euclid(100)),
+            Some('m') => write!(out, "{:02}", c.month),
+            Some('d') => write!(out, "{:02}", c.day),
+            Some('e') => write!(out, "{:2}", c.day),
+            Some('B') => write!(out, "{}", month),
+            Some('b') => write!(out, "{}", &month[..3]),
+            Some('A') => write!(out, "{}", weekday),
+            Some('a') => write!(out, "{}", &weekday[..3]),
+            Some('H') => write!(out, "{:02}", c.hour),
+            Some('I') => write!(out, "{:02}", (c.hour + 11) % 12 + 1),
+            Some('p') => write!(out, "{}", if c.hour < 12 { "AM" } else { "PM" }),
+            Some('M') => write!(out, "{:02}", c.minute),
+            Some('S') => write!(out, "{:02}", c.second),
+            Some('f') => write!(out, "{:09}", c.nanosecond),
+            Some('j') => write!(out, "{:03}", day_of_year(c.year, c.month, c.day)),
+            Some('V') => write!(out, "{:02}", iso_week_from_days(days).1),
+            Some('F') => write!(out, "{:04}-{:02}-{:02}", c.year, c.month, c.day),
+            Some('T') => write!(out, "{:02}:{:02}:{:02}", c.hour, c.minute, c.second),
+            Some('%') => write!(out, "%"),
+            Some(other) => return Err(format!("unknown format directive `%{}`", other)),
+            None => return Err("the format pattern ends with a lone `%`".to_string()),
+        };
+    }
+    Ok(out)
+}
+
 /// Parse an **RFC 3339 / ISO 8601** timestamp into a SmoothUTC instant (nanoseconds since epoch).
 /// Accepts a `Z` zone or a numeric `±HH:MM` offset (normalized to UTC); `None` on malformed input.
 pub fn parse_rfc3339(s: &str) -> Option<i64> {
@@ -1036,6 +1098,21 @@ mod tests {
         assert_eq!(years("2024-01-01T00:00:00Z", "2020-01-01T00:00:00Z"), -4);
     }
 
+    #[test]
+    fn format_pattern_renders_every_directive() {
+        let t = unix_nanos_from_civil(CivilDateTime {
+            year: 2026, month: 3, day: 5, hour: 16, minute: 7, second: 9, nanosecond: 42,
+        });
+        assert_eq!(format_pattern(t, "%A, %d %B %Y").unwrap(), "Thursday, 05 March 2026");
+        assert_eq!(format_pattern(t, "%a %b %e %y").unwrap(), "Thu Mar  5 26");
+        assert_eq!(format_pattern(t, "%I:%M %p").unwrap(), "04:07 PM");
+        assert_eq!(format_pattern(t, "%F %T.%f").unwrap(), "2026-03-05 16:07:09.000000042");
+        assert_eq!(format_pattern(t, "day %j, week %V, 100%%").unwrap(), "day 064, week 10, 100%");
+        assert_eq!(format_pattern(0, "%I %p").unwrap(), "12 AM");
+        assert!(format_pattern(t, "%Q").unwrap_err().contains("%Q"));
+        assert!(format_pattern(t, "50%").is_err());
+    }
+
     #[test]
     fn time_of_day_formats_losslessly_to_the_nanosecond() {
         let h = 3_600 * NANOS_PER_SECOND;
@@ -169,6 +169,11 @@ fn collect_calls_from_stmt(stmt: &Stmt<'_>, calls: &mut HashSet<Symbol>) {
             collect_calls_from_stmts(tasks, calls);
         }
         Stmt::Zone { body, .. } => collect_calls_from_stmts(body, calls),
+        Stmt::Sip { file, sources, progress } => {
+            calls.extend(*progress);
+            collect_calls_from_expr(file, calls);
+            collect_calls_from_expr(sources, calls);
+        }
         _ => {}
     }
 }
@@ -22,7 +22,7 @@ use std::collections::{HashMap, HashSet};
 
 use crate::analysis::unify::{InferType, TyVar, TypeScheme, TypeError, UnificationTable, infer_to_logos, unify_numeric};
 use crate::analysis::{FnSig, LogosType, TypeDef, TypeEnv, TypeRegistry};
-use crate::ast::stmt::{BinaryOpKind, Expr, Pattern, Stmt};
+use crate::ast::stmt::{BinaryOpKind, CollectionKind, Expr, Pattern, Stmt};
 use crate::intern::{Interner, Symbol};
 
 // ============================================================================
@@ -239,6 +239,60 @@ impl<'r> CheckEnv<'r> {
         Ok(self.table.zonk(expected))
     }
 
+    /// Bind the loop variable(s) of `Repeat for <pattern> in <iterable>` (or of a
+    /// comprehension) to the iterable's element type.
+    fn bind_loop_pattern(&mut self, pattern: &Pattern, iterable: &Expr) -> Result<(), TypeError> {
+        let iterable_ty = self.infer_expr(iterable)?;
+        let elem_ty = match self.table.zonk(&iterable_ty) {
+            InferType::Seq(inner) | InferType::Set(inner) => *inner,
+            // A Map yields (key, value) tuples per entry at runtime
+            // (semantics/collections.rs `iteration_snapshot`), NOT bare
+            // keys. InferType has no tuple type, so a single loop
+            // variable binds to Unknown — sound (it cannot drive a wrong
+            // specialization), unlike the bare key type `K`.
+            InferType::Map(_, _) => InferType::Unknown,
+            _ => InferType::Unknown,
+        };
+        match pattern {
+            Pattern::Identifier(sym) => self.bind_var(*sym, elem_ty),
+            Pattern::Tuple(syms) => {
+                for sym in syms {
+                    self.bind_var(*sym, InferType::Unknown);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The collection a comprehension builds, its binder scoped to the element, value and
+    /// filter expressions.
+    fn infer_comprehension(
+        &mut self,
+        kind: CollectionKind,
+        element: &Expr,
+        value: Option<&Expr>,
+        pattern: &Pattern,
+        iterable: &Expr,
+        filter: Option<&Expr>,
+    ) -> Result<InferType, TypeError> {
+        self.bind_loop_pattern(pattern, iterable)?;
+        if let Some(filter) = filter {
+            self.infer_expr(filter)?;
+        }
+        let element_ty = self.infer_expr(element)?;
+        Ok(match kind {
+            CollectionKind::Seq => InferType::Seq(Box::new(element_ty)),
+            CollectionKind::Set => InferType::Set(Box::new(element_ty)),
+            CollectionKind::Map => {
+                let value_ty = match value {
+                    Some(value) => self.infer_expr(value)?,
+                    None => self.table.fresh(),
+                };
+                InferType::Map(Box::new(element_ty), Box::new(value_ty))
+            }
+        })
+    }
+
     /// Infer the type of an expression (synthesis mode).
     fn infer_expr(&mut self, expr: &Expr) -> Result<InferType, TypeError> {
         match expr {
@@ -317,11 +371,11 @@ impl<'r> CheckEnv<'r> {
                         let key = type_args
                             .first()
                             .map(|t| InferType::from_type_expr(t, self.interner))
-                            .unwrap_or(InferType::String);
+                            .unwrap_or_else(|| self.table.fresh());
                         let val = type_args
                             .get(1)
                             .map(|t| InferType::from_type_expr(t, self.interner))
-                            .unwrap_or(InferType::String);
+                            .unwrap_or_else(|| self.table.fresh());
                         Ok(InferType::Map(Box::new(key), Box::new(val)))
                     }
                     "Set" | "HashSet" => {
@@ -349,6 +403,13 @@ impl<'r> CheckEnv<'r> {
 
             Expr::InterpolatedString(_) => Ok(InferType::String),
 
+            Expr::Comprehension { kind, element, value, pattern, iterable, filter } => {
+                self.push_scope();
+                let collected = self.infer_comprehension(*kind, element, *value, pattern, iterable, *filter);
+                self.pop_scope();
+                collected
+            }
+
             Expr::Slice { collection, .. } => self.infer_expr(collection),
 
             Expr::Union { left, .. } | Expr::Intersection { left, .. } => {
@@ -448,6 +509,22 @@ impl<'r> CheckEnv<'r> {
                     Ok(InferType::Unknown)
                 }
             }
+            // Sorting keeps the Seq's type.
+            "sorted_by" | "largest_of" | "smallest_of" => match args.first() {
+                Some(first) => {
+                    let ty = self.infer_expr(first)?;
+                    match self.table.resolve(&ty) {
+                        seq @ InferType::Seq(_) => Ok(seq),
+                        _ => Ok(InferType::Unknown),
+                    }
+                }
+                None => Ok(InferType::Unknown),
+            },
+            // An updated copy has the record's type.
+            "with_field" => match args.first() {
+                Some(first) => self.infer_expr(first),
+                None => Ok(InferType::Unknown),
+            },
             _ => {
                 if let Some(rec) = self.functions.get(&function).cloned() {
                     // Instantiate the scheme: each call site gets fresh type variables
@@ -762,25 +839,7 @@ impl<'r> CheckEnv<'r> {
             }
 
             Stmt::Repeat { pattern, iterable, body } => {
-                let iterable_ty = self.infer_expr(iterable)?;
-                let elem_ty = match self.table.zonk(&iterable_ty) {
-                    InferType::Seq(inner) | InferType::Set(inner) => *inner,
-                    // A Map yields (key, value) tuples per entry at runtime
-                    // (semantics/collections.rs `iteration_snapshot`), NOT bare
-                    // keys. InferType has no tuple type, so a single loop
-                    // variable binds to Unknown — sound (it cannot drive a wrong
-                    // specialization), unlike the bare key type `K`.
-                    InferType::Map(_, _) => InferType::Unknown,
-                    _ => InferType::Unknown,
-                };
-                match pattern {
-                    Pattern::Identifier(sym) => self.bind_var(*sym, elem_ty),
-                    Pattern::Tuple(syms) => {
-                        for sym in syms {
-                            self.bind_var(*sym, InferType::Unknown);
-                        }
-                    }
-                }
+                self.bind_loop_pattern(pattern, iterable)?;
                 for s in *body {
                     self.infer_stmt(s)?;
                 }
@@ -806,6 +865,44 @@ impl<'r> CheckEnv<'r> {
                 Ok(())
             }
 
+            Stmt::Splice { body } => {
+                for s in *body {
+                    self.infer_stmt(s)?;
+                }
+                Ok(())
+            }
+
+            // A fill into a collection whose element type is still open (`a new Seq` with no
+            // `of`) fixes that type from the value stored. A collection with a declared type
+            // is left to the other checks.
+            Stmt::Push { value, collection } | Stmt::Add { value, collection } => {
+                if let Ok(coll_ty) = self.infer_expr(collection) {
+                    if let InferType::Seq(elem) | InferType::Set(elem) = self.table.resolve(&coll_ty) {
+                        if matches!(*elem, InferType::Var(_)) {
+                            if let Ok(value_ty) = self.infer_expr(value) {
+                                self.table.unify(&elem, &value_ty).ok();
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            }
+
+            Stmt::SetIndex { collection, index, value } => {
+                if let Ok(coll_ty) = self.infer_expr(collection) {
+                    if let InferType::Map(key, val) = self.table.resolve(&coll_ty) {
+                        for (slot, expr) in [(key, index), (val, value)] {
+                            if matches!(*slot, InferType::Var(_)) {
+                                if let Ok(ty) = self.infer_expr(expr) {
+                                    self.table.unify(&slot, &ty).ok();
+                                }
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            }
+
             Stmt::Inspect { target, arms, .. } => {
                 let _target_ty = self.infer_expr(target)?;
                 for arm in arms {
@@ -823,8 +920,13 @@ impl<'r> CheckEnv<'r> {
                 Ok(())
             }
 
-            Stmt::ReadFrom { var, .. } => {
-                self.bind_var(*var, InferType::String);
+            Stmt::ReadFrom { var, source } => {
+                let ty = match source {
+                    crate::ast::stmt::ReadSource::Settings { type_name, .. }
+                    | crate::ast::stmt::ReadSource::Arguments { type_name } => InferType::UserDefined(*type_name),
+                    _ => InferType::String,
+                };
+                self.bind_var(*var, ty);
                 Ok(())
             }
 
@@ -1761,4 +1863,84 @@ mod tests {
              the loop var must not be typed as the bare key K"
         );
     }
+
+    /// An untyped `a new Seq/Map` filled in a loop takes its type from the fill.
+    #[test]
+    fn untyped_collections_take_their_type_from_the_fill() {
+        let mut interner = mk_interner();
+        let xs = interner.intern("xs");
+        let ages = interner.intern("ages");
+        let n = interner.intern("n");
+        let seq_sym = interner.intern("Seq");
+        let map_sym = interner.intern("Map");
+        let name = interner.intern("ann");
+
+        let new_seq = Expr::New { type_name: seq_sym, type_args: vec![], init_fields: vec![] };
+        let new_map = Expr::New { type_name: map_sym, type_args: vec![], init_fields: vec![] };
+        let xs_ref = Expr::Identifier(xs);
+        let ages_ref = Expr::Identifier(ages);
+        let n_ref = Expr::Identifier(n);
+        let range = Expr::Range { start: &Expr::Literal(Literal::Number(1)), end: &Expr::Literal(Literal::Number(3)) };
+        let key = Expr::Literal(Literal::Text(name));
+        let body = [
+            Stmt::Push { value: &n_ref, collection: &xs_ref },
+            Stmt::SetIndex { collection: &ages_ref, index: &key, value: &n_ref },
+        ];
+        let lowered = [
+            Stmt::Let { var: xs, ty: None, value: &new_seq, mutable: true },
+            Stmt::Let { var: ages, ty: None, value: &new_map, mutable: true },
+            Stmt::Repeat { pattern: Pattern::Identifier(n), iterable: &range, body: &body },
+        ];
+        let stmts = [Stmt::Splice { body: &lowered }];
+        let env = run(&stmts, &interner);
+
+        assert_eq!(env.lookup(xs), &LogosType::Seq(Box::new(LogosType::Int)));
+        assert_eq!(
+            env.lookup(ages),
+            &LogosType::Map(Box::new(LogosType::String), Box::new(LogosType::Int))
+        );
+    }
+
+    /// A comprehension's collection type comes from its element (and value) expressions,
+    /// with the binder typed from the iterable.
+    #[test]
+    fn comprehension_takes_its_type_from_the_element() {
+        let mut interner = mk_interner();
+        let squares = interner.intern("squares");
+        let names = interner.intern("names");
+        let n = interner.intern("n");
+        let ann = interner.intern("ann");
+
+        let n_ref = Expr::Identifier(n);
+        let square = Expr::BinaryOp { op: BinaryOpKind::Multiply, left: &n_ref, right: &n_ref };
+        let range = Expr::Range { start: &Expr::Literal(Literal::Number(1)), end: &Expr::Literal(Literal::Number(3)) };
+        let key = Expr::Literal(Literal::Text(ann));
+        let seq = Expr::Comprehension {
+            kind: CollectionKind::Seq,
+            element: &square,
+            value: None,
+            pattern: Pattern::Identifier(n),
+            iterable: &range,
+            filter: None,
+        };
+        let map = Expr::Comprehension {
+            kind: CollectionKind::Map,
+            element: &key,
+            value: Some(&n_ref),
+            pattern: Pattern::Identifier(n),
+            iterable: &range,
+            filter: Some(&n_ref),
+        };
+        let stmts = [
+            Stmt::Let { var: squares, ty: None, value: &seq, mutable: false },
+            Stmt::Let { var: names, ty: None, value: &map, mutable: false },
+        ];
+        let env = run(&stmts, &interner);
+
+        assert_eq!(env.lookup(squares), &LogosType::Seq(Box::new(LogosType::Int)));
+        assert_eq!(
+            env.lookup(names),
+            &LogosType::Map(Box::new(LogosType::String), Box::new(LogosType::Int))
+        );
+    }
 }
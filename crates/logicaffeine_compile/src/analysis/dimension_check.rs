@@ -13,6 +13,11 @@
 //! `Quantity` function parameter, a collection element, a value from an opaque call) is treated as
 //! dimension-polymorphic and deferred to the existing runtime check. So this pass never rejects a
 //! correct program; it only promotes provable runtime failures to compile-time errors.
+//!
+//! A declared dimension is a contract wherever a value crosses into it: a `Let d: Quantity of Length`
+//! binding, a later `Set d to …`, an argument to a `Quantity of Length` parameter, and a `Return` from
+//! a function declared `-> Quantity of Length`. The dimension is only a static refinement — codegen
+//! erases it to a plain `LogosQuantity`.
 
 use std::collections::HashMap;
 
@@ -79,6 +84,11 @@ pub struct DimensionChecker<'a> {
     /// User function name → the dimensional nature of its declared return type, so a call to a
     /// function returning `Quantity of Area` is statically known to be an area.
     fn_returns: HashMap<Symbol, QDim>,
+    /// User function name → each parameter's name and declared dimensional nature, so an argument of
+    /// the wrong dimension is rejected at the call site.
+    fn_params: HashMap<Symbol, Vec<(Symbol, QDim)>>,
+    /// The function whose body is being checked, with its declared return dimension.
+    returning: Option<(Symbol, QDim)>,
     /// Variables whose currency is statically known in the current scope (money's analogue of `vars`).
     cur_vars: HashMap<Symbol, CurInfo>,
     interner: &'a Interner,
@@ -89,6 +99,8 @@ impl<'a> DimensionChecker<'a> {
         Self {
             vars: HashMap::new(),
             fn_returns: HashMap::new(),
+            fn_params: HashMap::new(),
+            returning: None,
             cur_vars: HashMap::new(),
             interner,
         }
@@ -102,9 +114,11 @@ impl<'a> DimensionChecker<'a> {
 
     fn collect_fn_returns(&mut self, stmts: &[Stmt<'_>]) {
         for stmt in stmts {
-            if let Stmt::FunctionDef { name, return_type, .. } = stmt {
+            if let Stmt::FunctionDef { name, params, return_type, .. } = stmt {
                 let qd = return_type.map(|t| self.dim_from_type(t)).unwrap_or(QDim::NotQuantity);
                 self.fn_returns.insert(*name, qd);
+                let params = params.iter().map(|(p, ty)| (*p, self.dim_from_type(ty))).collect();
+                self.fn_params.insert(*name, params);
             }
         }
     }
@@ -148,6 +162,9 @@ impl<'a> DimensionChecker<'a> {
                 let inferred = self.infer(value)?;
                 // A `Let d: Quantity of Length be …` declares the dimension authoritatively.
                 let declared = ty.map(|t| self.dim_from_type(t));
+                if let Some(declared) = declared {
+                    self.expect_dim(declared, inferred, || format!("`{}`", self.interner.resolve(*var)))?;
+                }
                 let d = match declared {
                     Some(QDim::Known(k)) => QDim::Known(k),
                     Some(QDim::Unknown) if !inferred.is_quantity() => QDim::Unknown,
@@ -158,8 +175,23 @@ impl<'a> DimensionChecker<'a> {
                 let c = self.currency_of(value);
                 self.cur_vars.insert(*var, c);
             }
-            Stmt::Set { value, .. } => {
-                self.infer(value)?;
+            // A variable keeps the dimension (and currency) it was bound with.
+            Stmt::Set { target, value } => {
+                let got = self.infer(value)?;
+                let name = || format!("`{}`", self.interner.resolve(*target));
+                if let Some(&held) = self.vars.get(target) {
+                    self.expect_dim(held, got, name)?;
+                }
+                if let (Some(CurInfo::Known(held)), CurInfo::Known(got)) =
+                    (self.cur_vars.get(target), self.currency_of(value))
+                {
+                    if *held != got {
+                        return Err(DimensionError {
+                            message: format!("{} is money in {held}, not {got}", name()),
+                            span: Span::default(),
+                        });
+                    }
+                }
             }
             Stmt::SetField { object, value, .. } => {
                 self.infer(object)?;
@@ -181,7 +213,12 @@ impl<'a> DimensionChecker<'a> {
                 self.check_block(body)?;
             }
             Stmt::Return { value: Some(e) } => {
-                self.infer(e)?;
+                let got = self.infer(e)?;
+                if let Some((function, declared)) = self.returning {
+                    self.expect_dim(declared, got, || {
+                        format!("the result of `{}`", self.interner.resolve(function))
+                    })?;
+                }
             }
             Stmt::Show { object, .. } => {
                 self.infer(object)?;
@@ -189,16 +226,18 @@ impl<'a> DimensionChecker<'a> {
             Stmt::RuntimeAssert { condition, .. } => {
                 self.infer(condition)?;
             }
-            Stmt::Call { args, .. } => {
-                for a in args {
-                    self.infer(a)?;
-                }
+            Stmt::Call { function, args } => {
+                self.check_call(*function, args)?;
             }
-            Stmt::FunctionDef { params, body, .. } => {
+            Stmt::FunctionDef { name, params, body, .. } => {
                 // A function body is a fresh scope: a `Quantity` parameter is dimension-polymorphic
                 // (unknown), every other parameter is a non-quantity for our purposes.
                 let saved = self.vars.clone();
                 let saved_cur = self.cur_vars.clone();
+                let saved_returning = self.returning.replace((
+                    *name,
+                    self.fn_returns.get(name).copied().unwrap_or(QDim::NotQuantity),
+                ));
                 for (name, ty) in params {
                     let qd = self.dim_from_type(ty);
                     if qd.is_quantity() {
@@ -209,6 +248,7 @@ impl<'a> DimensionChecker<'a> {
                 self.check_block(body)?;
                 self.vars = saved;
                 self.cur_vars = saved_cur;
+                self.returning = saved_returning;
             }
             _ => {}
         }
@@ -301,9 +341,7 @@ impl<'a> DimensionChecker<'a> {
             Expr::Identifier(s) => Ok(self.vars.get(s).copied().unwrap_or(QDim::NotQuantity)),
 
             Expr::Call { function, args } => {
-                for a in args {
-                    self.infer(a)?;
-                }
+                self.check_call(*function, args)?;
                 match self.interner.resolve(*function) {
                     "quantity" | "convert" if args.len() == 2 => Ok(self.unit_dim(args[1])),
                     // A user function with a declared `Quantity of <Dim>` return is statically known;
@@ -396,6 +434,42 @@ impl<'a> DimensionChecker<'a> {
         }
     }
 
+    /// Check a call's arguments, rejecting one of provably the wrong dimension for a user function's
+    /// `Quantity of <Dim>` parameter.
+    fn check_call(&self, function: Symbol, args: &[&Expr<'_>]) -> Result<(), DimensionError> {
+        let params = self.fn_params.get(&function);
+        for (i, arg) in args.iter().enumerate() {
+            let got = self.infer(arg)?;
+            if let Some(&(param, declared)) = params.and_then(|p| p.get(i)) {
+                self.expect_dim(declared, got, || {
+                    format!(
+                        "parameter `{}` of `{}`",
+                        self.interner.resolve(param),
+                        self.interner.resolve(function)
+                    )
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Error when a value of provably one dimension lands where another is declared; `subject`
+    /// names the place (`` `d` ``, ``parameter `q` of `stretch` ``) for the message.
+    fn expect_dim(
+        &self,
+        declared: QDim,
+        got: QDim,
+        subject: impl FnOnce() -> String,
+    ) -> Result<(), DimensionError> {
+        match (declared, got) {
+            (QDim::Known(a), QDim::Known(b)) if a != b => Err(DimensionError {
+                message: format!("{} is a quantity of {a}, not {b}", subject()),
+                span: Span::default(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
     /// For `+`/`−` where at least one side is dimension-unknown: carry a known dimension forward if
     /// there is one, otherwise stay quantity-but-unknown when either side is a quantity.
     fn propagate(&self, l: QDim, r: QDim) -> QDim {
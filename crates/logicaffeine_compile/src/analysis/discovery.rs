@@ -33,6 +33,16 @@ use super::{TypeRegistry, TypeDef, DiscoveryPass, scan_dependencies};
 /// Dependencies are loaded recursively, and their types are merged into
 /// the registry with namespace prefixes (e.g., `Geometry::Point`).
 ///
+/// # Visibility
+///
+/// A module that declares any type `public` (`A public Point has:`) exports
+/// only its public types; one that declares none exports them all.
+///
+/// # Errors
+///
+/// An import cycle fails with the chain of links that forms it, and an
+/// import that cannot be loaded fails with the loader's error.
+///
 /// # Arguments
 ///
 /// * `file_path` - Path to the source file
@@ -67,6 +77,24 @@ pub fn discover_with_imports(
     source: &str,
     loader: &mut Loader,
     interner: &mut Interner,
+) -> Result<TypeRegistry, String> {
+    // Check the whole import graph first: a cycle would recurse forever, and
+    // a missing module is better reported before any discovery work.
+    let graph = loader.module_graph(file_path, source);
+    if let Some(cycle) = graph.cycles().first() {
+        return Err(cycle.to_string());
+    }
+    if let Some(missing) = graph.unresolved().first() {
+        return Err(missing.error.clone());
+    }
+    discover_module(file_path, source, loader, interner)
+}
+
+fn discover_module(
+    file_path: &Path,
+    source: &str,
+    loader: &mut Loader,
+    interner: &mut Interner,
 ) -> Result<TypeRegistry, String> {
     let mut registry = TypeRegistry::with_primitives(interner);
 
@@ -80,7 +108,7 @@ pub fn discover_with_imports(
         let dep_path = module_source.path.clone();
 
         // Recursively discover types in the dependency
-        let dep_registry = discover_with_imports(
+        let dep_registry = discover_module(
             &dep_path,
             &dep_content,
             loader,
@@ -94,8 +122,8 @@ pub fn discover_with_imports(
     // 3. Scan local definitions using existing DiscoveryPass
     let mut lexer = Lexer::new(source, interner);
     let tokens = lexer.tokenize();
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, interner);
 
     let mut discovery = DiscoveryPass::new(&tokens, interner);
     let local_registry = discovery.run();
@@ -106,13 +134,16 @@ pub fn discover_with_imports(
         let name = interner.resolve(*sym);
         if !["Int", "Nat", "Text", "Bool", "Real", "Unit"].contains(&name) {
             registry.register(*sym, def.clone());
+            if local_registry.is_public(*sym) {
+                registry.register_public(*sym);
+            }
         }
     }
 
     Ok(registry)
 }
 
-/// Merges types from a dependency registry into the main registry with namespace prefix.
+/// Merges the types a dependency exports into the main registry with namespace prefix.
 fn merge_registry(
     main: &mut TypeRegistry,
     namespace: &str,
@@ -120,6 +151,10 @@ fn merge_registry(
     interner: &mut Interner,
 ) {
     for (sym, def) in dep.iter_types() {
+        if !dep.is_exported(*sym) {
+            continue;
+        }
+
         // Get original name
         let orig_name = interner.resolve(*sym);
 
@@ -326,6 +326,15 @@ impl<'a> EscapeChecker<'a> {
                 }
             }
 
+            // The collected elements come from the iterable, so it escapes with them.
+            Expr::Comprehension { element, value, iterable, .. } => {
+                self.check_no_escape(element, max_depth)?;
+                if let Some(value) = value {
+                    self.check_no_escape(value, max_depth)?;
+                }
+                self.check_no_escape(iterable, max_depth)?;
+            }
+
             // Literals are always safe
             Expr::Literal(_) => {}
         }
@@ -345,6 +345,16 @@ fn gen_expr(expr: &Expr<'_>, out: &mut HashSet<Symbol>) {
                 }
             }
         }
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            gen_expr(element, out);
+            if let Some(v) = value {
+                gen_expr(v, out);
+            }
+            gen_expr(iterable, out);
+            if let Some(f) = filter {
+                gen_expr(f, out);
+            }
+        }
         Expr::Literal(_) | Expr::OptionNone | Expr::Escape { .. } => {}
     }
 }
@@ -205,6 +205,7 @@ impl<'a> OwnershipChecker<'a> {
             Expr::Identifier(sym) => self.is_copy_sym(*sym),
             Expr::New { .. } => false,
             Expr::List(_) => false,
+            Expr::Comprehension { .. } => false,
             Expr::InterpolatedString(_) => false,
             Expr::Copy { .. } => true,
             Expr::BinaryOp { op: BinaryOpKind::Concat, .. } => false,
@@ -583,6 +584,18 @@ impl<'a> OwnershipChecker<'a> {
                 Ok(())
             }
 
+            Expr::Comprehension { element, value, iterable, filter, .. } => {
+                self.check_not_moved(iterable)?;
+                self.check_not_moved(element)?;
+                if let Some(value) = value {
+                    self.check_not_moved(value)?;
+                }
+                if let Some(filter) = filter {
+                    self.check_not_moved(filter)?;
+                }
+                Ok(())
+            }
+
             // Literals are always safe
             Expr::Literal(_) => Ok(()),
         }
@@ -1001,6 +1001,13 @@ pub(crate) fn stmt_references_symbol(stmt: &Stmt<'_>, sym: Symbol) -> bool {
             arms.iter().any(|arm| arm.body.iter().any(|s| stmt_references_symbol(s, sym)))
         }
         Stmt::Show { object, .. } => expr_references_symbol(object, sym),
+        Stmt::RunCommand { command, timeout, .. } => {
+            expr_references_symbol(command, sym) || timeout.map_or(false, |t| expr_references_symbol(t, sym))
+        }
+        Stmt::Sip { file, sources, .. } => expr_references_symbol(file, sym) || expr_references_symbol(sources, sym),
+        Stmt::Log { message, fields, .. } => {
+            expr_references_symbol(message, sym) || fields.iter().any(|(_, v)| expr_references_symbol(v, sym))
+        }
         _ => false,
     }
 }
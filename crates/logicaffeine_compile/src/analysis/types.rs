@@ -16,7 +16,7 @@
 
 use std::collections::HashMap;
 
-use crate::ast::stmt::{BinaryOpKind, Expr, Literal, Stmt, TypeExpr};
+use crate::ast::stmt::{BinaryOpKind, CollectionKind, Expr, Literal, Stmt, TypeExpr};
 use crate::intern::{Interner, Symbol};
 use crate::analysis::TypeRegistry;
 
@@ -67,6 +67,9 @@ pub enum LogosType {
     /// A 128-bit UUID (`uuid "…"`, `a random uuid`). An opaque identifier — no arithmetic; lowers to
     /// `LogosUuid`. Comparable/orderable by its bytes.
     Uuid,
+    /// A compiled regular expression (`the pattern "\d+"`). Not ordered, no arithmetic; lowers to
+    /// `LogosPattern` (an `Rc` handle, so clones share one compiled engine).
+    Pattern,
     UserDefined(Symbol),
     /// First-class function type: fn(P1, P2, ...) -> R
     Function(Vec<LogosType>, Box<LogosType>),
@@ -205,6 +208,7 @@ impl LogosType {
             LogosType::Quantity => "LogosQuantity".into(),
             LogosType::Money => "LogosMoney".into(),
             LogosType::Uuid => "LogosUuid".into(),
+            LogosType::Pattern => "LogosPattern".into(),
             LogosType::Duration => "std::time::Duration".into(),
             LogosType::Date => "LogosDate".into(),
             LogosType::Moment => "LogosMoment".into(),
@@ -305,6 +309,7 @@ impl LogosType {
             "Quantity" => LogosType::Quantity,
             "Money" => LogosType::Money,
             "Uuid" | "UUID" => LogosType::Uuid,
+            "Pattern" => LogosType::Pattern,
             "Real" | "Float" => LogosType::Float,
             "Bool" | "Boolean" => LogosType::Bool,
             "Text" | "String" => LogosType::String,
@@ -344,6 +349,7 @@ impl LogosType {
             "LogosQuantity" => LogosType::Quantity,
             "LogosMoney" => LogosType::Money,
             "LogosUuid" => LogosType::Uuid,
+            "LogosPattern" => LogosType::Pattern,
             "String" => LogosType::String,
             "()" => LogosType::Unit,
             "std::time::Duration" => LogosType::Duration,
@@ -538,9 +544,26 @@ impl TypeEnv {
                         LogosType::Seq(Box::new(LogosType::Int))
                     }
                     "uuid_from_bytes" => LogosType::Uuid,
+                    // Text processing (`logicaffeine_data::text`).
+                    "text_split" | "text_words" | "text_lines" => LogosType::Seq(Box::new(LogosType::String)),
+                    "text_join" | "text_trim" | "text_uppercase" | "text_lowercase" | "text_replace" => {
+                        LogosType::String
+                    }
+                    "text_find" => LogosType::Int,
+                    "text_starts_with" | "text_ends_with" => LogosType::Bool,
+                    // Sorting yields a Seq of the input's items.
+                    "sorted_by" | "largest_of" | "smallest_of" => match args.first().map(|a| self.infer_expr(a, interner)) {
+                        Some(seq @ LogosType::Seq(_)) => seq,
+                        _ => LogosType::Unknown,
+                    },
+                    // An updated copy has the record's type.
+                    "with_field" => args.first().map_or(LogosType::Unknown, |a| self.infer_expr(a, interner)),
+                    "pattern" => LogosType::Pattern,
+                    "pattern_matches" => LogosType::Bool,
                     "set_rate" | "set_rates" => LogosType::Unit,
                     "parse_timestamp" => LogosType::Moment,
                     "format_timestamp" => LogosType::String,
+                    "format_as" => LogosType::String,
                     "year_of" | "month_of" | "day_of" | "weekday_of" | "hour_of" | "minute_of"
                     | "second_of" | "week_of" | "quarter_of" => LogosType::Int,
                     "date_of" => LogosType::Date,
@@ -652,6 +675,18 @@ impl TypeEnv {
 
             Expr::WithCapacity { value, .. } => self.infer_expr(value, interner),
 
+            Expr::Comprehension { kind, element, value, .. } => {
+                let element = Box::new(self.infer_expr(element, interner));
+                match kind {
+                    CollectionKind::Seq => LogosType::Seq(element),
+                    CollectionKind::Set => LogosType::Set(element),
+                    CollectionKind::Map => LogosType::Map(
+                        element,
+                        Box::new(value.map_or(LogosType::Unknown, |v| self.infer_expr(v, interner))),
+                    ),
+                }
+            }
+
             _ => LogosType::Unknown,
         }
     }
@@ -787,9 +822,15 @@ impl TypeEnv {
                 self.infer_stmts(body, interner);
             }
 
-            Stmt::ReadFrom { var, .. } => {
-                // ReadFrom always gives String
-                self.register(*var, LogosType::String);
+            Stmt::ReadFrom { var, source } => {
+                // ReadFrom gives String, except settings and arguments, which are their struct
+                match source {
+                    crate::ast::stmt::ReadSource::Settings { type_name, .. }
+                    | crate::ast::stmt::ReadSource::Arguments { type_name } => {
+                        self.register(*var, LogosType::UserDefined(*type_name));
+                    }
+                    _ => self.register(*var, LogosType::String),
+                }
             }
 
             Stmt::CreatePipe { var, element_type, .. } => {
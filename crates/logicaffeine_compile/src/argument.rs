@@ -0,0 +1,274 @@
+//! Premise–conclusion argument analysis.
+//!
+//! [`analyze_argument`] takes an argument as English sentences and reports
+//! whether it is valid, which premises the proof actually uses, and — for an
+//! invalid argument — a countermodel and any classical fallacy its shape
+//! matches (see [`fallacies`](crate::fallacies)):
+//!
+//! ```text
+//! Every man is mortal. Socrates is mortal. ∴ Socrates is a man.
+//!   → Invalid, countermodel man(Socrates) = false, mortal(Socrates) = true,
+//!     fallacy: affirming the consequent
+//! ```
+//!
+//! The sentences compile as one discourse (a `## Theorem` block), so a pronoun
+//! in a later premise resolves against an earlier one. Validity comes from the
+//! kernel-certified prover, or from Z3 when the `verification` feature is on.
+//! Invalidity is only reported with a re-checked finite countermodel, never
+//! from a failed proof search.
+
+use logicaffeine_proof::counterexample::find_counterexample;
+use logicaffeine_proof::grounding::{domain_constants, ground};
+use logicaffeine_proof::verify::prove_certify_check;
+use logicaffeine_proof::{BackwardChainer, ProofExpr, ProofTerm};
+
+use crate::fallacies::{detect_fallacies, FallacyMatch};
+use crate::ui_bridge::{erase_tense, theorem_proof_exprs};
+
+/// Whether the conclusion follows from the premises.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgumentVerdict {
+    /// Every model of the premises satisfies the conclusion.
+    Valid,
+    /// A countermodel makes every premise true and the conclusion false.
+    Invalid,
+    /// Neither a proof nor a countermodel was found.
+    Unknown,
+}
+
+/// The analysis of one argument.
+#[derive(Debug, Clone)]
+pub struct ArgumentReport {
+    /// Compiled premises, in input order.
+    pub premises: Vec<ProofExpr>,
+    /// Compiled conclusion, or `None` on parse error.
+    pub conclusion: Option<ProofExpr>,
+    pub verdict: ArgumentVerdict,
+    /// True iff a `Valid` verdict was certified and kernel type-checked
+    /// rather than taken from Z3.
+    pub certified: bool,
+    /// Indices of the premises a valid argument needs: dropping any one of
+    /// them breaks the proof. Empty unless the verdict is `Valid`.
+    pub needed_premises: Vec<usize>,
+    /// For an invalid argument, the countermodel as a truth assignment.
+    pub countermodel: Option<String>,
+    /// Fallacy templates the argument's form matches, with explanations.
+    /// Empty unless the verdict is `Invalid`.
+    pub fallacies: Vec<FallacyMatch>,
+    /// Error message if the sentences did not compile.
+    pub error: Option<String>,
+}
+
+/// Analyze the argument `premises` ∴ `conclusion`.
+pub fn analyze_argument(premises: &[&str], conclusion: &str) -> ArgumentReport {
+    let mut report = ArgumentReport {
+        premises: Vec::new(),
+        conclusion: None,
+        verdict: ArgumentVerdict::Unknown,
+        certified: false,
+        needed_premises: Vec::new(),
+        countermodel: None,
+        fallacies: Vec::new(),
+        error: None,
+    };
+
+    let (compiled, goal) = match theorem_proof_exprs(&argument_document(premises, conclusion)) {
+        Ok(problem) => problem,
+        Err(e) => {
+            report.error = Some(format!("{:?}", e.kind));
+            return report;
+        }
+    };
+
+    // Decide over tenseless, event-free formulas: "If John runs, Mary smiles"
+    // becomes run(John) → smile(Mary), which both the prover and the model
+    // search handle.
+    let chainer = BackwardChainer::new();
+    let flat = |e: &ProofExpr| drop_vacuous(&chainer.abstract_all_events(&erase_tense(e)));
+    let flat_premises: Vec<ProofExpr> = compiled.iter().map(flat).collect();
+    let flat_goal = flat(&goal);
+    report.premises = compiled;
+    report.conclusion = Some(goal);
+
+    if let Some(certified) = entails(&flat_premises, &flat_goal) {
+        report.verdict = ArgumentVerdict::Valid;
+        report.certified = certified;
+        report.needed_premises = needed_premises(&flat_premises, &flat_goal);
+        return report;
+    }
+
+    if let Some(model) = countermodel(&flat_premises, &flat_goal) {
+        report.verdict = ArgumentVerdict::Invalid;
+        report.countermodel = Some(model);
+        report.fallacies = detect_fallacies(&flat_premises, &flat_goal);
+    }
+    report
+}
+
+/// Removes quantifiers whose variable no longer occurs — what an event
+/// quantifier leaves behind once its event is abstracted away
+/// (`∀e(run(John) → smile(Mary))`).
+fn drop_vacuous(e: &ProofExpr) -> ProofExpr {
+    let both = |l: &ProofExpr, r: &ProofExpr| (Box::new(drop_vacuous(l)), Box::new(drop_vacuous(r)));
+    match e {
+        ProofExpr::ForAll { variable, body } | ProofExpr::Exists { variable, body }
+            if !occurs(variable, body) =>
+        {
+            drop_vacuous(body)
+        }
+        ProofExpr::ForAll { variable, body } => ProofExpr::ForAll {
+            variable: variable.clone(),
+            body: Box::new(drop_vacuous(body)),
+        },
+        ProofExpr::Exists { variable, body } => ProofExpr::Exists {
+            variable: variable.clone(),
+            body: Box::new(drop_vacuous(body)),
+        },
+        ProofExpr::And(l, r) => {
+            let (l, r) = both(l, r);
+            ProofExpr::And(l, r)
+        }
+        ProofExpr::Or(l, r) => {
+            let (l, r) = both(l, r);
+            ProofExpr::Or(l, r)
+        }
+        ProofExpr::Implies(l, r) => {
+            let (l, r) = both(l, r);
+            ProofExpr::Implies(l, r)
+        }
+        ProofExpr::Iff(l, r) => {
+            let (l, r) = both(l, r);
+            ProofExpr::Iff(l, r)
+        }
+        ProofExpr::Not(x) => ProofExpr::Not(Box::new(drop_vacuous(x))),
+        other => other.clone(),
+    }
+}
+
+/// Whether `variable` occurs in `e`. Conservative: an unfamiliar node counts
+/// as an occurrence, so its quantifier is kept.
+fn occurs(variable: &str, e: &ProofExpr) -> bool {
+    fn in_term(variable: &str, t: &ProofTerm) -> bool {
+        match t {
+            ProofTerm::Variable(v) | ProofTerm::BoundVarRef(v) => v == variable,
+            ProofTerm::Constant(_) => false,
+            ProofTerm::Function(_, args) | ProofTerm::Group(args) => args.iter().any(|a| in_term(variable, a)),
+        }
+    }
+    match e {
+        ProofExpr::Predicate { args, .. } => args.iter().any(|a| in_term(variable, a)),
+        ProofExpr::Identity(l, r) => in_term(variable, l) || in_term(variable, r),
+        ProofExpr::Atom(_) => false,
+        ProofExpr::And(l, r) | ProofExpr::Or(l, r) | ProofExpr::Implies(l, r) | ProofExpr::Iff(l, r) => {
+            occurs(variable, l) || occurs(variable, r)
+        }
+        ProofExpr::Not(x) => occurs(variable, x),
+        ProofExpr::ForAll { variable: bound, body } | ProofExpr::Exists { variable: bound, body } => {
+            bound != variable && occurs(variable, body)
+        }
+        _ => true,
+    }
+}
+
+/// The argument as a theorem block, one `Given:` per premise.
+fn argument_document(premises: &[&str], conclusion: &str) -> String {
+    let sentence = |s: &str| {
+        let s = s.trim();
+        if s.ends_with(['.', '!', '?']) {
+            s.to_string()
+        } else {
+            format!("{s}.")
+        }
+    };
+    let mut doc = String::from("## Theorem: Argument\n");
+    for p in premises {
+        doc.push_str(&format!("Given: {}\n", sentence(p)));
+    }
+    doc.push_str(&format!("Prove: {}\nProof: Auto.\n", sentence(conclusion)));
+    doc
+}
+
+/// `Some(certified)` when `premises ⊨ goal`: `true` for a kernel-checked
+/// proof, `false` for a Z3 verdict.
+fn entails(premises: &[ProofExpr], goal: &ProofExpr) -> Option<bool> {
+    if prove_certify_check(premises, goal).verified {
+        return Some(true);
+    }
+    #[cfg(feature = "verification")]
+    if matches!(
+        logicaffeine_proof::oracle::oracle_entails(premises, goal),
+        logicaffeine_proof::oracle::SmtVerdict::Entailed
+    ) {
+        return Some(false);
+    }
+    None
+}
+
+/// Drop premises one at a time while the conclusion still follows; what is
+/// left is a minimal premise set.
+fn needed_premises(premises: &[ProofExpr], goal: &ProofExpr) -> Vec<usize> {
+    let mut kept: Vec<usize> = (0..premises.len()).collect();
+    for i in 0..premises.len() {
+        let without: Vec<ProofExpr> = kept
+            .iter()
+            .filter(|&&j| j != i)
+            .map(|&j| premises[j].clone())
+            .collect();
+        if entails(&without, goal).is_some() {
+            kept.retain(|&j| j != i);
+        }
+    }
+    kept
+}
+
+/// Fresh individuals added to the named ones when searching for a
+/// countermodel ("every cat is a dog" fails on an unnamed cat).
+const MAX_FRESH_INDIVIDUALS: usize = 2;
+
+/// A finite model of the premises that falsifies the goal. Each candidate
+/// domain grounds the problem exactly, so a model found there is a genuine
+/// countermodel. Identity is skipped: grounding leaves `a = b` a free atom.
+fn countermodel(premises: &[ProofExpr], goal: &ProofExpr) -> Option<String> {
+    let mut all = premises.to_vec();
+    all.push(goal.clone());
+    if all.iter().any(mentions_identity) {
+        return None;
+    }
+    let named = domain_constants(&all);
+    (0..=MAX_FRESH_INDIVIDUALS).find_map(|fresh| {
+        let mut domain = named.clone();
+        domain.extend((1..=fresh).map(|i| ProofTerm::Constant(format!("w{i}"))));
+        if domain.is_empty() {
+            return None;
+        }
+        let grounded: Vec<ProofExpr> = premises.iter().map(|p| ground(p, &domain)).collect();
+        find_counterexample(&grounded, &ground(goal, &domain)).map(|c| c.render())
+    })
+}
+
+fn mentions_identity(e: &ProofExpr) -> bool {
+    match e {
+        ProofExpr::Identity(..) => true,
+        ProofExpr::And(l, r) | ProofExpr::Or(l, r) | ProofExpr::Implies(l, r) | ProofExpr::Iff(l, r) => {
+            mentions_identity(l) || mentions_identity(r)
+        }
+        ProofExpr::Not(x) | ProofExpr::ForAll { body: x, .. } | ProofExpr::Exists { body: x, .. } => {
+            mentions_identity(x)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn premises_and_conclusion_become_a_theorem_block() {
+        let doc = argument_document(&["Socrates is a man", "Every man is mortal."], "Socrates is mortal");
+        assert_eq!(
+            doc,
+            "## Theorem: Argument\nGiven: Socrates is a man.\nGiven: Every man is mortal.\nProve: Socrates is mortal.\nProof: Auto.\n"
+        );
+    }
+}
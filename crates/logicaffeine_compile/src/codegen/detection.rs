@@ -28,6 +28,7 @@ pub(super) fn requires_async_stmt(stmt: &Stmt) -> bool {
         // Phase 51: Network operations and Sleep are async
         Stmt::Listen { .. } => true,
         Stmt::ConnectTo { .. } => true,
+        Stmt::JoinSwarm { .. } => true,
         Stmt::Sleep { .. } => true,
         // Phase 52: Sync is async (GossipSub subscription)
         Stmt::Sync { .. } => true,
@@ -90,6 +91,57 @@ pub(super) fn requires_vfs_stmt(stmt: &Stmt) -> bool {
     }
 }
 
+/// Detect if any statement runs a command, so the generated project links
+/// `logicaffeine-system` with its `process` feature.
+pub(crate) fn requires_process(stmts: &[Stmt]) -> bool {
+    stmts.iter().any(|s| stmt_tree_any(s, &|s| matches!(s, Stmt::RunCommand { .. })))
+}
+
+/// Detect a `Log` statement: the generated project needs the `log` feature.
+pub(crate) fn requires_log(stmts: &[Stmt]) -> bool {
+    stmts.iter().any(|s| stmt_tree_any(s, &|s| matches!(s, Stmt::Log { .. })))
+}
+
+/// Detect a read from a `## Settings` block: the generated project needs the
+/// `settings` feature.
+pub(crate) fn requires_settings(stmts: &[Stmt]) -> bool {
+    stmts.iter().any(|s| {
+        stmt_tree_any(s, &|s| matches!(s, Stmt::ReadFrom { source: ReadSource::Settings { .. }, .. }))
+    })
+}
+
+/// Detect a read of the `## Arguments` block: the generated project needs the
+/// `arguments` feature.
+pub(crate) fn requires_arguments(stmts: &[Stmt]) -> bool {
+    stmts.iter().any(|s| {
+        stmt_tree_any(s, &|s| matches!(s, Stmt::ReadFrom { source: ReadSource::Arguments { .. }, .. }))
+    })
+}
+
+/// Whether `stmt`, or any statement nested in its blocks, satisfies `pred`.
+fn stmt_tree_any(stmt: &Stmt, pred: &dyn Fn(&Stmt) -> bool) -> bool {
+    if pred(stmt) {
+        return true;
+    }
+    match stmt {
+        // Recursively check nested blocks
+        Stmt::If { then_block, else_block, .. } => {
+            then_block.iter().any(|s| stmt_tree_any(s, pred))
+                || else_block.map_or(false, |b| b.iter().any(|s| stmt_tree_any(s, pred)))
+        }
+        Stmt::While { body, .. } => body.iter().any(|s| stmt_tree_any(s, pred)),
+        Stmt::Repeat { body, .. } => body.iter().any(|s| stmt_tree_any(s, pred)),
+        Stmt::Zone { body, .. } => body.iter().any(|s| stmt_tree_any(s, pred)),
+        Stmt::Concurrent { tasks } => tasks.iter().any(|s| stmt_tree_any(s, pred)),
+        Stmt::Parallel { tasks } => tasks.iter().any(|s| stmt_tree_any(s, pred)),
+        Stmt::FunctionDef { body, .. } => body.iter().any(|s| stmt_tree_any(s, pred)),
+        Stmt::Inspect { arms, .. } => {
+            arms.iter().any(|arm| arm.body.iter().any(|s| stmt_tree_any(s, pred)))
+        }
+        _ => false,
+    }
+}
+
 /// Phase 49b: Extract root identifier from expression for mutability analysis.
 /// Works with both simple identifiers and field accesses.
 pub(super) fn get_root_identifier_for_mutability(expr: &Expr) -> Option<Symbol> {
@@ -331,6 +383,23 @@ fn check_single_char_usage(
                 // Show ch is fine — we'll emit `println!("{}", ch as char)`
                 // But don't check deeper — identifiers in Show are OK
             }
+            Stmt::RunCommand { command, timeout, .. } => {
+                check_expr_usage_strict(command, candidates, disqualified);
+                if let Some(timeout) = timeout {
+                    check_expr_usage_strict(timeout, candidates, disqualified);
+                }
+            }
+            Stmt::Sip { file, sources, .. } => {
+                check_expr_usage_strict(file, candidates, disqualified);
+                check_expr_usage_strict(sources, candidates, disqualified);
+            }
+            Stmt::Log { message, fields, .. } => {
+                // A logged u8 would print as a number, not a character.
+                check_expr_usage_strict(message, candidates, disqualified);
+                for (_, value) in fields {
+                    check_expr_usage_strict(value, candidates, disqualified);
+                }
+            }
             Stmt::Let { value, .. } => {
                 // `Let y be ch` would assign a u8 to y, which may not work.
                 // Disqualify if a candidate appears directly as the value.
@@ -561,6 +630,20 @@ pub(super) fn calls_async_function(stmt: &Stmt, async_fns: &HashSet<Symbol>) ->
         Stmt::RuntimeAssert { condition, .. } => calls_async_function_in_expr(condition, async_fns),
         // Check Show for async calls
         Stmt::Show { object, .. } => calls_async_function_in_expr(object, async_fns),
+        Stmt::RunCommand { command, timeout, .. } => {
+            calls_async_function_in_expr(command, async_fns)
+                || timeout.map_or(false, |t| calls_async_function_in_expr(t, async_fns))
+        }
+        // The progress function is called after every chunk.
+        Stmt::Sip { file, sources, progress } => {
+            progress.map_or(false, |f| async_fns.contains(&f))
+                || calls_async_function_in_expr(file, async_fns)
+                || calls_async_function_in_expr(sources, async_fns)
+        }
+        Stmt::Log { message, fields, .. } => {
+            calls_async_function_in_expr(message, async_fns)
+                || fields.iter().any(|(_, v)| calls_async_function_in_expr(v, async_fns))
+        }
         // Check Push for async calls
         Stmt::Push { collection, value } => {
             calls_async_function_in_expr(collection, async_fns)
@@ -615,6 +698,11 @@ fn calls_async_function_in_expr(expr: &Expr, async_fns: &HashSet<Symbol>) -> boo
             calls_async_function_in_expr(callee, async_fns)
                 || args.iter().any(|a| calls_async_function_in_expr(a, async_fns))
         }
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            calls_async_function_in_expr(element, async_fns) || calls_async_function_in_expr(iterable, async_fns)
+                || value.map_or(false, |v| calls_async_function_in_expr(v, async_fns))
+                || filter.map_or(false, |f| calls_async_function_in_expr(f, async_fns))
+        }
         Expr::InterpolatedString(parts) => {
             parts.iter().any(|p| {
                 if let crate::ast::stmt::StringPart::Expr { value, .. } = p {
@@ -676,9 +764,12 @@ pub(super) fn collect_pure_functions(stmts: &[Stmt]) -> HashSet<Symbol> {
 fn is_directly_impure_stmt(stmt: &Stmt) -> bool {
     match stmt {
         Stmt::Show { .. }
+        | Stmt::Log { .. }
         | Stmt::Give { .. }
         | Stmt::WriteFile { .. }
         | Stmt::ReadFrom { .. }
+        | Stmt::RunCommand { .. }
+        | Stmt::Sip { .. }
         | Stmt::Listen { .. }
         | Stmt::ConnectTo { .. }
         | Stmt::SendMessage { .. }
@@ -743,6 +834,17 @@ fn calls_impure_function(stmt: &Stmt, impure_fns: &HashSet<Symbol>) -> bool {
             arms.iter().any(|arm| arm.body.iter().any(|s| calls_impure_function(s, impure_fns)))
         }
         Stmt::Show { object, .. } => expr_calls_impure(object, impure_fns),
+        Stmt::RunCommand { command, timeout, .. } => {
+            expr_calls_impure(command, impure_fns) || timeout.map_or(false, |t| expr_calls_impure(t, impure_fns))
+        }
+        Stmt::Sip { file, sources, progress } => {
+            progress.map_or(false, |f| impure_fns.contains(&f))
+                || expr_calls_impure(file, impure_fns)
+                || expr_calls_impure(sources, impure_fns)
+        }
+        Stmt::Log { message, fields, .. } => {
+            expr_calls_impure(message, impure_fns) || fields.iter().any(|(_, v)| expr_calls_impure(v, impure_fns))
+        }
         Stmt::Push { value, collection } | Stmt::Add { value, collection } | Stmt::Remove { value, collection } => {
             expr_calls_impure(value, impure_fns) || expr_calls_impure(collection, impure_fns)
         }
@@ -768,6 +870,11 @@ fn expr_calls_impure(expr: &Expr, impure_fns: &HashSet<Symbol>) -> bool {
             expr_calls_impure(callee, impure_fns)
                 || args.iter().any(|a| expr_calls_impure(a, impure_fns))
         }
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            expr_calls_impure(element, impure_fns) || expr_calls_impure(iterable, impure_fns)
+                || value.map_or(false, |v| expr_calls_impure(v, impure_fns))
+                || filter.map_or(false, |f| expr_calls_impure(f, impure_fns))
+        }
         Expr::InterpolatedString(parts) => {
             parts.iter().any(|p| {
                 if let crate::ast::stmt::StringPart::Expr { value, .. } = p {
@@ -814,6 +921,13 @@ fn count_self_calls_in_stmt(func_name: Symbol, stmt: &Stmt) -> usize {
         }
         Stmt::Repeat { body, .. } => count_self_calls(func_name, body),
         Stmt::Show { object, .. } => count_self_calls_in_expr(func_name, object),
+        Stmt::Log { message, .. } => count_self_calls_in_expr(func_name, message),
+        Stmt::RunCommand { command, .. } => count_self_calls_in_expr(func_name, command),
+        Stmt::Sip { file, sources, progress } => {
+            usize::from(*progress == Some(func_name))
+                + count_self_calls_in_expr(func_name, file)
+                + count_self_calls_in_expr(func_name, sources)
+        }
         _ => 0,
     }
 }
@@ -835,6 +949,12 @@ fn count_self_calls_in_expr(func_name: Symbol, expr: &Expr) -> usize {
         Expr::List(items) | Expr::Tuple(items) => {
             items.iter().map(|i| count_self_calls_in_expr(func_name, i)).sum()
         }
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            count_self_calls_in_expr(func_name, element)
+                + count_self_calls_in_expr(func_name, iterable)
+                + value.map_or(0, |v| count_self_calls_in_expr(func_name, v))
+                + filter.map_or(0, |f| count_self_calls_in_expr(func_name, f))
+        }
         Expr::InterpolatedString(parts) => {
             parts.iter().map(|p| {
                 if let crate::ast::stmt::StringPart::Expr { value, .. } = p {
@@ -925,6 +1045,13 @@ fn stmt_contains_self_call(func_name: Symbol, stmt: &Stmt) -> bool {
             body.iter().any(|s| stmt_contains_self_call(func_name, s))
         }
         Stmt::Show { object, .. } => expr_contains_self_call(func_name, object),
+        Stmt::Log { message, .. } => expr_contains_self_call(func_name, message),
+        Stmt::RunCommand { command, .. } => expr_contains_self_call(func_name, command),
+        Stmt::Sip { file, sources, progress } => {
+            *progress == Some(func_name)
+                || expr_contains_self_call(func_name, file)
+                || expr_contains_self_call(func_name, sources)
+        }
         _ => false,
     }
 }
@@ -944,6 +1071,11 @@ pub(super) fn expr_contains_self_call(func_name: Symbol, expr: &Expr) -> bool {
         Expr::List(items) | Expr::Tuple(items) => {
             items.iter().any(|i| expr_contains_self_call(func_name, i))
         }
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            expr_contains_self_call(func_name, element) || expr_contains_self_call(func_name, iterable)
+                || value.map_or(false, |v| expr_contains_self_call(func_name, v))
+                || filter.map_or(false, |f| expr_contains_self_call(func_name, f))
+        }
         Expr::InterpolatedString(parts) => {
             parts.iter().any(|p| {
                 if let crate::ast::stmt::StringPart::Expr { value, .. } = p {
@@ -1173,6 +1305,16 @@ pub(super) fn collect_expr_identifiers(expr: &Expr, identifiers: &mut HashSet<Sy
                 collect_expr_identifiers(arg, identifiers);
             }
         }
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            collect_expr_identifiers(element, identifiers);
+            if let Some(v) = value {
+                collect_expr_identifiers(v, identifiers);
+            }
+            collect_expr_identifiers(iterable, identifiers);
+            if let Some(f) = filter {
+                collect_expr_identifiers(f, identifiers);
+            }
+        }
         Expr::InterpolatedString(parts) => {
             for part in parts {
                 if let crate::ast::stmt::StringPart::Expr { value, .. } = part {
@@ -1186,6 +1328,20 @@ pub(super) fn collect_expr_identifiers(expr: &Expr, identifiers: &mut HashSet<Sy
     }
 }
 
+/// Builtins only the interpreter and VM run, with the phrase that calls each.
+/// `sync_status` reports the engine's own replica bookkeeping — creation-order
+/// replica numbers and what `Sync` last published — which compiled programs
+/// don't keep.
+const INTERPRETER_ONLY_BUILTINS: &[(&str, &str)] = &[("sync_status", "the sync status of")];
+
+/// The phrase of the first interpreter-only builtin the program calls, if any.
+pub(crate) fn interpreter_only_builtin(stmts: &[Stmt], interner: &Interner) -> Option<&'static str> {
+    INTERPRETER_ONLY_BUILTINS.iter().find_map(|&(builtin, phrase)| {
+        let sym = interner.lookup(builtin)?;
+        stmts.iter().any(|s| symbol_appears_in_stmt(sym, s)).then_some(phrase)
+    })
+}
+
 /// Check if a symbol appears anywhere in a slice of statements (expressions or targets).
 /// Used by dead-counter elimination to decide if a post-loop counter binding is needed.
 pub(super) fn symbol_appears_in_stmts(sym: Symbol, stmts: &[&Stmt]) -> bool {
@@ -1197,6 +1353,17 @@ pub(super) fn symbol_appears_in_stmt(sym: Symbol, stmt: &Stmt) -> bool {
         Stmt::Let { value, .. } => symbol_appears_in_expr(sym, value),
         Stmt::Set { target, value, .. } => *target == sym || symbol_appears_in_expr(sym, value),
         Stmt::Show { object, .. } => symbol_appears_in_expr(sym, object),
+        Stmt::RunCommand { command, timeout, output, errors, exit_code } => {
+            [output, errors, exit_code].into_iter().flatten().any(|v| *v == sym)
+                || symbol_appears_in_expr(sym, command)
+                || timeout.map_or(false, |t| symbol_appears_in_expr(sym, t))
+        }
+        Stmt::Sip { file, sources, progress } => {
+            *progress == Some(sym) || symbol_appears_in_expr(sym, file) || symbol_appears_in_expr(sym, sources)
+        }
+        Stmt::Log { message, fields, .. } => {
+            symbol_appears_in_expr(sym, message) || fields.iter().any(|(_, v)| symbol_appears_in_expr(sym, v))
+        }
         Stmt::Return { value } => value.as_ref().map_or(false, |v| symbol_appears_in_expr(sym, v)),
         Stmt::Call { function, args } => *function == sym || args.iter().any(|a| symbol_appears_in_expr(sym, a)),
         Stmt::If { cond, then_block, else_block } => {
@@ -1280,6 +1447,11 @@ pub(super) fn symbol_appears_in_expr(sym: Symbol, expr: &Expr) -> bool {
             symbol_appears_in_expr(sym, callee)
                 || args.iter().any(|a| symbol_appears_in_expr(sym, a))
         }
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            symbol_appears_in_expr(sym, element) || symbol_appears_in_expr(sym, iterable)
+                || value.map_or(false, |v| symbol_appears_in_expr(sym, v))
+                || filter.map_or(false, |f| symbol_appears_in_expr(sym, f))
+        }
         Expr::InterpolatedString(parts) => {
             parts.iter().any(|p| {
                 if let crate::ast::stmt::StringPart::Expr { value, .. } = p {
@@ -1936,6 +2108,22 @@ fn derc_scan_stmt(
             derc_scan_expr(object, cands, borrow_params, dq);
             derc_scan_expr(recipient, cands, borrow_params, dq);
         }
+        Stmt::RunCommand { command, timeout, .. } => {
+            derc_scan_expr(command, cands, borrow_params, dq);
+            if let Some(timeout) = timeout {
+                derc_scan_expr(timeout, cands, borrow_params, dq);
+            }
+        }
+        Stmt::Sip { file, sources, .. } => {
+            derc_scan_expr(file, cands, borrow_params, dq);
+            derc_scan_expr(sources, cands, borrow_params, dq);
+        }
+        Stmt::Log { message, fields, .. } => {
+            derc_scan_expr(message, cands, borrow_params, dq);
+            for (_, value) in fields {
+                derc_scan_expr(value, cands, borrow_params, dq);
+            }
+        }
         // A nested function is a SEPARATE scope with its own de-Rc analysis. Its
         // parameter/local symbols are distinct bindings even when they reuse an
         // outer name (the interner gives `arr` one Symbol, but main's `arr` and a
@@ -2707,6 +2895,17 @@ fn stmt_has_const_member_index(s: &Stmt, members: &std::collections::HashSet<Sym
             expr_has_const_member_index(value, members) || expr_has_const_member_index(collection, members)
         }
         Stmt::Show { object, .. } => expr_has_const_member_index(object, members),
+        Stmt::RunCommand { command, timeout, .. } => {
+            expr_has_const_member_index(command, members)
+                || timeout.map_or(false, |t| expr_has_const_member_index(t, members))
+        }
+        Stmt::Sip { file, sources, .. } => {
+            expr_has_const_member_index(file, members) || expr_has_const_member_index(sources, members)
+        }
+        Stmt::Log { message, fields, .. } => {
+            expr_has_const_member_index(message, members)
+                || fields.iter().any(|(_, v)| expr_has_const_member_index(v, members))
+        }
         Stmt::SetField { object, value, .. } => {
             expr_has_const_member_index(object, members) || expr_has_const_member_index(value, members)
         }
@@ -2776,6 +2975,11 @@ fn expr_has_const_member_index(e: &Expr, members: &std::collections::HashSet<Sym
         Expr::List(items) | Expr::Tuple(items) => {
             items.iter().any(|i| expr_has_const_member_index(i, members))
         }
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            expr_has_const_member_index(element, members) || expr_has_const_member_index(iterable, members)
+                || value.map_or(false, |v| expr_has_const_member_index(v, members))
+                || filter.map_or(false, |f| expr_has_const_member_index(f, members))
+        }
         Expr::InterpolatedString(parts) => parts.iter().any(|p| match p {
             crate::ast::stmt::StringPart::Expr { value, .. } => expr_has_const_member_index(value, members),
             _ => false,
@@ -2903,6 +3107,17 @@ fn scalar_note_value(e: &Expr, cand: &mut HashMap<Symbol, ScalarCand>) {
                 scalar_note_value(a, cand);
             }
         }
+        // The iterable is consumed whole, so a bare candidate handle there disqualifies it.
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            scalar_note_value(iterable, cand);
+            scalar_note_value(element, cand);
+            if let Some(v) = value {
+                scalar_note_value(v, cand);
+            }
+            if let Some(f) = filter {
+                scalar_note_value(f, cand);
+            }
+        }
         Expr::InterpolatedString(parts) => {
             for p in parts {
                 if let crate::ast::stmt::StringPart::Expr { value, .. } = p {
@@ -3014,6 +3229,26 @@ fn scalar_walk_block(
                 scalar_note_value(object, cand);
                 scalar_note_value(recipient, cand);
             }
+            Stmt::RunCommand { command, timeout, output, errors, exit_code } => {
+                scalar_note_value(command, cand);
+                if let Some(timeout) = timeout {
+                    scalar_note_value(timeout, cand);
+                }
+                for var in [output, errors, exit_code].into_iter().flatten() {
+                    scalar_disq(cand, *var);
+                }
+            }
+            Stmt::Sip { file, sources, .. } => {
+                scalar_note_value(file, cand);
+                scalar_note_value(sources, cand);
+            }
+            Stmt::Log { message, fields, .. } => {
+                scalar_note_value(message, cand);
+                // A field logs the whole value under its name.
+                for (name, _) in fields {
+                    scalar_disq(cand, *name);
+                }
+            }
             Stmt::Give { object, recipient } => {
                 if let Expr::Identifier(s) = object {
                     scalar_disq(cand, *s);
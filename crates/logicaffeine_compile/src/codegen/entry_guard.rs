@@ -182,12 +182,16 @@ fn is_pure(stmts: &[Stmt]) -> bool {
 fn stmt_is_pure(s: &Stmt) -> bool {
     match s {
         Stmt::Show { .. }
+        | Stmt::Log { .. }
         | Stmt::Give { .. }
         | Stmt::WriteFile { .. }
         | Stmt::ReadFrom { .. }
+        | Stmt::RunCommand { .. }
+        | Stmt::Sip { .. }
         | Stmt::Sleep { .. }
         | Stmt::Listen { .. }
         | Stmt::ConnectTo { .. }
+        | Stmt::JoinSwarm { .. }
         | Stmt::SendMessage { .. }
         | Stmt::StreamMessage { .. }
         | Stmt::AwaitMessage { .. }
@@ -1148,6 +1148,12 @@ fn codegen_expr_ctx(expr: &Expr, ecx: &ExprCtx) -> String {
         Expr::Literal(lit) => codegen_literal(lit, interner),
 
         Expr::Identifier(sym) => {
+            // `today` / `now` read the clock, as in the interpreter (the NAME wins, even when shadowed).
+            match interner.resolve(*sym) {
+                "today" => return "LogosDate::today()".to_string(),
+                "now" => return "LogosMoment::now()".to_string(),
+                _ => {}
+            }
             let name = names.ident(*sym);
             // Dereference boxed bindings from enum destructuring
             let base = if boxed_bindings.contains(sym) {
@@ -2153,6 +2159,111 @@ fn codegen_expr_ctx(expr: &Expr, ecx: &ExprCtx) -> String {
                 // `uuid_v3`/`uuid_v5` are Logos stdlib functions now, emitted as normal calls.)
                 "text_bytes" if args_str.len() == 1 => format!("text_bytes(&({}))", args_str[0]),
                 "text_from_bytes" if args_str.len() == 1 => format!("text_from_bytes(&({}))", args_str[0]),
+                // Text processing — the same `logicaffeine_data::text` functions the interpreter calls.
+                "text_words" | "text_lines" | "text_trim" | "text_uppercase" | "text_lowercase"
+                    if args_str.len() == 1 =>
+                {
+                    format!("{}(&({}))", raw_name, args_str[0])
+                }
+                "text_split" | "text_join" | "text_find" | "text_starts_with" | "text_ends_with"
+                    if args_str.len() == 2 =>
+                {
+                    format!("{}(&({}), &({}))", raw_name, args_str[0], args_str[1])
+                }
+                "text_replace" if args_str.len() == 3 => {
+                    format!("text_replace(&({}), &({}), &({}))", args_str[0], args_str[1], args_str[2])
+                }
+                // Sorting — `logicaffeine_data::sort`, keyed by a field read (`Sort people by age`) or
+                // by the item itself, whose `PartialOrd` a `Comparable` struct implements.
+                "sorted_by" | "largest_of" | "smallest_of" if args_str.len() == 3 => {
+                    let key_arg = if raw_name == "sorted_by" { args[1] } else { args[2] };
+                    let key = match key_arg {
+                        Expr::Literal(Literal::Text(field)) if !interner.resolve(*field).is_empty() => {
+                            format!("|__x| __x.{}.clone()", interner.resolve(*field))
+                        }
+                        _ => "|__x| __x.clone()".to_string(),
+                    };
+                    if raw_name == "sorted_by" {
+                        format!("sorted_by(&({}), {}, {})", args_str[0], key, args_str[2])
+                    } else {
+                        format!("{}(&({}), ({}) as i64, {})", raw_name, args_str[0], args_str[1], key)
+                    }
+                }
+                // `a copy of user with age set to 31`: a copy, or a record read already cloned for the
+                // call, is owned; anything else is cloned first, so the original is never touched.
+                "with_field" if args_str.len() == 3 && matches!(args[1], Expr::Literal(Literal::Text(_))) => {
+                    let Expr::Literal(Literal::Text(field)) = args[1] else { unreachable!("guarded above") };
+                    let field = interner.resolve(*field);
+                    let record = if matches!(args[0], Expr::Copy { .. }) || args_str[0].ends_with(".clone()") {
+                        args_str[0].clone()
+                    } else {
+                        format!("({}).clone()", args_str[0])
+                    };
+                    format!("{{ let mut __r = {}; __r.{} = {}; __r }}", record, field, args_str[2])
+                }
+                // A pattern literal was validated by the parser, so it compiles once per thread and
+                // every evaluation shares it (an `Rc` clone) — a `matches` test inside a loop never
+                // recompiles. A pattern built from runtime text compiles where it is evaluated.
+                "pattern" if args_str.len() == 1 => {
+                    if matches!(args[0], Expr::Literal(Literal::Text(_))) {
+                        format!(
+                            "{{ thread_local!(static __PATTERN: LogosPattern = LogosPattern::compile(&({}))); __PATTERN.with(LogosPattern::clone) }}",
+                            args_str[0]
+                        )
+                    } else {
+                        format!("LogosPattern::compile(&({}))", args_str[0])
+                    }
+                }
+                "pattern_matches" if args_str.len() == 2 => {
+                    format!("pattern_matches(&({}), &({}))", args_str[0], args_str[1])
+                }
+                // `Capture … into m.` — the record's shape depends on the pattern's named groups, so
+                // each capture site declares its own `PatternMatch` struct (fields in name order, the
+                // order the interpreter shows them in).
+                "pattern_capture"
+                    if args_str.len() >= 2
+                        && args[2..].iter().all(|a| matches!(a, Expr::Literal(Literal::Text(_)))) =>
+                {
+                    let groups: Vec<&str> = args[2..]
+                        .iter()
+                        .filter_map(|a| match a {
+                            Expr::Literal(Literal::Text(sym)) => Some(interner.resolve(*sym)),
+                            _ => None,
+                        })
+                        .collect();
+                    let mut fields: Vec<&str> = groups.iter().copied().chain(["matched", "text"]).collect();
+                    fields.sort_unstable();
+                    let decl: Vec<String> = fields
+                        .iter()
+                        .map(|f| {
+                            let ty = if *f == "matched" { "bool" } else { "String" };
+                            format!("{}: {}", super::escape_rust_ident(f), ty)
+                        })
+                        .collect();
+                    let shown: Vec<String> = fields.iter().map(|f| format!("{}: {{}}", f)).collect();
+                    let shown_fmt = format!("PatternMatch {{{{ {} }}}}", shown.join(", "));
+                    let shown_args: String =
+                        fields.iter().map(|f| format!(", self.{}", super::escape_rust_ident(f))).collect();
+                    let inits: String = groups
+                        .iter()
+                        .map(|g| format!("{}: __groups.next().unwrap_or_default(), ", super::escape_rust_ident(g)))
+                        .collect();
+                    let group_names: Vec<String> = groups.iter().map(|g| format!("{:?}", g)).collect();
+                    format!(
+                        "{{ #[derive(Clone, Debug, Default, PartialEq)] struct PatternMatch {{ {} }} \
+                         impl Showable for PatternMatch {{ fn format_show(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{ write!(f, \"{}\"{}) }} }} \
+                         let (__matched, __text, __groups) = ({}).capture_fields(&({}), &[{}]); \
+                         let mut __groups = __groups.into_iter(); \
+                         PatternMatch {{ {}matched: __matched, text: __text }} }}",
+                        decl.join(", "),
+                        shown_fmt,
+                        shown_args,
+                        args_str[0],
+                        args_str[1],
+                        group_names.join(", "),
+                        inits,
+                    )
+                }
                 "readWireProgram" if args_str.is_empty() => {
                     "{ use std::io::Read as _; let mut __len = [0u8; 4]; if std::io::stdin().read_exact(&mut __len).is_err() { std::process::exit(0); } let __n = u32::from_le_bytes(__len) as usize; let mut __wb = vec![0u8; __n]; std::io::stdin().read_exact(&mut __wb).expect(\"readWireProgram: frame\"); <CProgram as logicaffeine_data::wire::WireDecode>::wire_decode(&__wb, &mut 0usize).expect(\"readWireProgram: decode\") }".to_string()
                 }
@@ -2176,6 +2287,10 @@ fn codegen_expr_ctx(expr: &Expr, ecx: &ExprCtx) -> String {
                 "format_timestamp" if args_str.len() == 1 => {
                     format!("({}).format_rfc3339()", args_str[0])
                 }
+                // Render a `LogosMoment`/`LogosDate` with a strftime-style pattern (`formatted as "…"`).
+                "format_as" if args_str.len() == 2 => {
+                    format!("({}).format(&({}).to_string())", args_str[0], args_str[1])
+                }
                 // UTC calendar component extractors on a `LogosMoment`.
                 "year_of" if args_str.len() == 1 => format!("({}).year()", args_str[0]),
                 "month_of" if args_str.len() == 1 => format!("({}).month()", args_str[0]),
@@ -3046,6 +3161,48 @@ fn codegen_expr_ctx(expr: &Expr, ecx: &ExprCtx) -> String {
             codegen_interpolated_string(parts, ecx)
         }
 
+        // `every k mapped to v for x in xs where c` → `xs.into_iter().filter(..).map(..).collect()`.
+        // The iterable is cloned first, as `Repeat` does, so it stays usable afterwards; the
+        // filter sees each element by value through its own copy of the binder.
+        Expr::Comprehension { kind, element, value, pattern, iterable, filter } => {
+            use crate::ast::stmt::{CollectionKind, Pattern};
+            let binder = match pattern {
+                Pattern::Identifier(sym) => names.ident(*sym),
+                Pattern::Tuple(syms) => {
+                    let idents: Vec<String> = syms.iter().map(|s| names.ident(*s)).collect();
+                    format!("({})", idents.join(", "))
+                }
+            };
+            let mut chain = format!("({}).clone().into_iter()", recurse!(iterable));
+            if let Some(filter) = filter {
+                let mut test = recurse!(filter);
+                if !matches!(
+                    infer_logos_type(filter, interner, variable_types),
+                    crate::analysis::types::LogosType::Bool
+                ) {
+                    test = format!("logos_truthy(&({}))", test);
+                }
+                chain.push_str(&format!(
+                    ".filter(|__item| {{ let {} = __item.clone(); {} }})",
+                    binder, test
+                ));
+            }
+            let produced = match value {
+                // The key is cloned so the value can still read the binder.
+                Some(value) => format!(
+                    "{{ let __key = ({}).clone(); (__key, {}) }}",
+                    recurse!(element), recurse!(value)
+                ),
+                None => recurse!(element),
+            };
+            chain.push_str(&format!(".map(|{}| {})", binder, produced));
+            match kind {
+                CollectionKind::Seq => format!("LogosSeq::from_vec({}.collect())", chain),
+                CollectionKind::Set => format!("{}.collect::<Set<_>>()", chain),
+                CollectionKind::Map => format!("LogosMap::from_map({}.collect())", chain),
+            }
+        }
+
         Expr::Not { operand } => {
             // Logical negation of truthiness (`~` is the bitwise complement).
             let operand_str = recurse!(operand);
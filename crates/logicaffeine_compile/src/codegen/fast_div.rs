@@ -119,6 +119,22 @@ impl<'a> Walk<'a> {
             Stmt::Let { value, .. } | Stmt::Set { value, .. } => self.expr(value, sc),
             Stmt::Return { value: Some(e) } => self.expr(e, sc),
             Stmt::Show { object, .. } | Stmt::Give { object, .. } => self.expr(object, sc),
+            Stmt::RunCommand { command, timeout, .. } => {
+                self.expr(command, sc);
+                if let Some(timeout) = timeout {
+                    self.expr(timeout, sc);
+                }
+            }
+            Stmt::Sip { file, sources, .. } => {
+                self.expr(file, sc);
+                self.expr(sources, sc);
+            }
+            Stmt::Log { message, fields, .. } => {
+                self.expr(message, sc);
+                for (_, value) in fields {
+                    self.expr(value, sc);
+                }
+            }
             Stmt::Push { value, collection, .. } => {
                 self.expr(value, sc);
                 self.expr(collection, sc);
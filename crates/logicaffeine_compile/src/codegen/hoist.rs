@@ -253,6 +253,11 @@ fn scan_value_expr(e: &Expr, roles: &mut AccessRoles) {
                 }
             }
         }
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            for x in [*element, *iterable].into_iter().chain(*value).chain(*filter) {
+                scan_value_expr(x, roles);
+            }
+        }
         Expr::Union { left, right } | Expr::Intersection { left, right } => {
             scan_value_expr(left, roles);
             scan_value_expr(right, roles);
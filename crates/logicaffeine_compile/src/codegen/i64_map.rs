@@ -581,6 +581,16 @@ impl<'c> Disq<'c> {
                     }
                 }
             }
+            Expr::Comprehension { element, value, iterable, filter, .. } => {
+                self.expr(iterable);
+                self.expr(element);
+                if let Some(v) = value {
+                    self.expr(v);
+                }
+                if let Some(f) = filter {
+                    self.expr(f);
+                }
+            }
         }
     }
 }
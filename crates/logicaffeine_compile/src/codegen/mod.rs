@@ -91,7 +91,7 @@ pub(crate) mod slice;
 
 pub use context::{RefinementContext, VariableCapabilities, empty_var_caps};
 pub use detection::{collect_async_functions, collect_pipe_sender_params, collect_pipe_vars};
-pub use program::{codegen_program, codegen_program_mapped, codegen_program_with_proven};
+pub use program::{codegen_program, codegen_program_mapped, codegen_program_mapped_with_proven, codegen_program_profiled, codegen_program_profiled_with_proven, codegen_program_with_proven};
 pub use slice::function_slice;
 pub use marshal::codegen_native_tier_export;
 pub use hoist::force_disable_for_test as force_disable_borrow_hoist_for_test;
@@ -151,7 +151,7 @@ pub(crate) use expr::{
     codegen_expr_with_async_and_strings, is_definitely_string_expr_with_vars,
     is_definitely_string_expr, is_definitely_numeric_expr, collect_string_concat_operands,
 };
-pub(crate) use stmt::{get_root_identifier, is_copy_type, has_copy_element_type, has_copy_value_type};
+pub(crate) use stmt::{get_root_identifier, is_copy_type, has_copy_element_type, has_copy_value_type, with_coverage_probes};
 pub(crate) use peephole::{
     try_emit_for_range_pattern, try_emit_vec_fill_pattern, try_emit_swap_pattern,
     try_emit_prefix_reverse,
@@ -2250,6 +2250,22 @@ fn classify_counter_accesses(
                 classify_expr_accesses(object, counter_sym, &mut order, &mut kinds);
                 classify_expr_accesses(recipient, counter_sym, &mut order, &mut kinds);
             }
+            Stmt::RunCommand { command, timeout, .. } => {
+                classify_expr_accesses(command, counter_sym, &mut order, &mut kinds);
+                if let Some(timeout) = timeout {
+                    classify_expr_accesses(timeout, counter_sym, &mut order, &mut kinds);
+                }
+            }
+            Stmt::Sip { file, sources, .. } => {
+                classify_expr_accesses(file, counter_sym, &mut order, &mut kinds);
+                classify_expr_accesses(sources, counter_sym, &mut order, &mut kinds);
+            }
+            Stmt::Log { message, fields, .. } => {
+                classify_expr_accesses(message, counter_sym, &mut order, &mut kinds);
+                for (_, value) in fields {
+                    classify_expr_accesses(value, counter_sym, &mut order, &mut kinds);
+                }
+            }
             Stmt::Push { value, .. } => {
                 classify_expr_accesses(value, counter_sym, &mut order, &mut kinds);
             }
@@ -2899,6 +2915,10 @@ pub(crate) fn try_block_peepholes<'a>(
     registry: &TypeRegistry,
     type_env: &crate::analysis::types::TypeEnv,
 ) -> Option<(String, usize)> {
+    // Coverage needs every statement emitted on its own, counter first.
+    if super::stmt::coverage_probe_count() > 0 {
+        return None;
+    }
     // Mark Peephole as fired iff some pattern in the dispatch chain below actually
     // emitted (the `Some` is the proof). Dedicated peephole opts (Simd, Cascade,
     // IndexString, CapScale) additionally mark themselves at their own emission.
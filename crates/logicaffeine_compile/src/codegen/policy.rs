@@ -69,6 +69,17 @@ pub(super) fn codegen_policy_impls(policies: &PolicyRegistry, interner: &Interne
     output
 }
 
+/// Generate the `__ALLOWED_COMMANDS` list `Run the command` checks programs
+/// against: the ones the `## Policy` block says the program can run.
+pub(super) fn codegen_command_policy(policies: &PolicyRegistry, interner: &Interner) -> String {
+    let programs: Vec<String> = policies
+        .allowed_commands()
+        .iter()
+        .map(|program| format!("{:?}", interner.resolve(*program)))
+        .collect();
+    format!("const __ALLOWED_COMMANDS: &[&str] = &[{}];\n\n", programs.join(", "))
+}
+
 /// Generate Rust code for a policy condition.
 pub(super) fn codegen_policy_condition(condition: &PolicyCondition, interner: &Interner) -> String {
     match condition {
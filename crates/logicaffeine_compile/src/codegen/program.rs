@@ -50,7 +50,7 @@ use crate::analysis::liveness::LivenessResult;
 use crate::analysis::readonly::{ReadonlyParams, MutableBorrowParams};
 
 use super::detection::{
-    requires_async, requires_vfs, collect_mutable_vars,
+    requires_async, requires_vfs, requires_process, collect_mutable_vars,
     collect_crdt_register_fields, collect_boxed_fields, collect_async_functions,
     collect_pure_functions, count_self_calls, is_hashable_type, is_copy_type_expr,
     should_memoize, body_contains_self_call, should_inline,
@@ -68,7 +68,7 @@ use super::ffi::{
     collect_c_export_value_type_structs,
 };
 use super::marshal::{is_text_type, is_char_type, codegen_c_export_with_marshaling};
-use super::policy::codegen_policy_impls;
+use super::policy::{codegen_command_policy, codegen_policy_impls};
 use super::stmt::codegen_stmt;
 use super::tce::{
     is_tail_recursive, body_has_top_level_tail_pair, codegen_tce_loopback,
@@ -78,7 +78,7 @@ use super::tce::{
 use crate::tail_call::detect_accumulator_pattern;
 use super::types::{
     codegen_type_expr, infer_return_type_from_body,
-    codegen_struct_def, codegen_enum_def,
+    codegen_struct_def, codegen_enum_def, codegen_ordering_impl, codegen_show_impl, StructTraits,
 };
 use super::{escape_rust_ident, is_rust_keyword};
 use super::{
@@ -422,7 +422,22 @@ pub fn codegen_program(stmts: &[Stmt], registry: &TypeRegistry, policies: &Polic
 /// proven modules reachable and avoids polluting the imperative namespace. When
 /// `proven` is `None`/blank the output is byte-identical to [`codegen_program`].
 pub fn codegen_program_with_proven(stmts: &[Stmt], registry: &TypeRegistry, policies: &PolicyRegistry, interner: &Interner, type_env: &crate::analysis::types::TypeEnv, cfg: &OptimizationConfig, module_name: &str, proven: Option<&str>) -> String {
-    codegen_program_inner(stmts, registry, policies, interner, type_env, cfg, module_name, proven, None).0
+    codegen_program_inner(stmts, registry, policies, interner, type_env, cfg, module_name, proven, None, false).0
+}
+
+/// Like [`codegen_program`], but instrumented for `largo run --profile`: each
+/// LOGOS function and `Main` open a `logicaffeine_system::profile` guard, and
+/// `Main` runs inside a profiling session that prints the report when the
+/// program ends. Async functions stay uninstrumented — they can resume on
+/// another thread, and the profiler's call stack is per-thread.
+pub fn codegen_program_profiled(stmts: &[Stmt], registry: &TypeRegistry, policies: &PolicyRegistry, interner: &Interner, type_env: &crate::analysis::types::TypeEnv, cfg: &OptimizationConfig) -> String {
+    codegen_program_profiled_with_proven(stmts, registry, policies, interner, type_env, cfg, None)
+}
+
+/// [`codegen_program_profiled`] with an extracted `proven` module bundled as in
+/// [`codegen_program_with_proven`]. The proven functions are not instrumented.
+pub fn codegen_program_profiled_with_proven(stmts: &[Stmt], registry: &TypeRegistry, policies: &PolicyRegistry, interner: &Interner, type_env: &crate::analysis::types::TypeEnv, cfg: &OptimizationConfig, proven: Option<&str>) -> String {
+    codegen_program_inner(stmts, registry, policies, interner, type_env, cfg, "proven", proven, None, true).0
 }
 
 /// Like [`codegen_program`], but also builds the rustc→LOGOS [`SourceMap`]:
@@ -439,16 +454,35 @@ pub fn codegen_program_mapped(
     cfg: &OptimizationConfig,
     stmt_spans: &[LogosSpan],
     logos_source: &str,
+) -> (String, SourceMap) {
+    codegen_program_mapped_with_proven(stmts, registry, policies, interner, type_env, cfg, "proven", None, stmt_spans, logos_source)
+}
+
+/// [`codegen_program_mapped`] with a proven module bundled in, as in
+/// [`codegen_program_with_proven`] — the Studio's Rust preview, which must show
+/// exactly what "Compile to Rust" produces.
+#[allow(clippy::too_many_arguments)]
+pub fn codegen_program_mapped_with_proven(
+    stmts: &[Stmt],
+    registry: &TypeRegistry,
+    policies: &PolicyRegistry,
+    interner: &Interner,
+    type_env: &crate::analysis::types::TypeEnv,
+    cfg: &OptimizationConfig,
+    module_name: &str,
+    proven: Option<&str>,
+    stmt_spans: &[LogosSpan],
+    logos_source: &str,
 ) -> (String, SourceMap) {
     let (code, map) = codegen_program_inner(
-        stmts, registry, policies, interner, type_env, cfg, "proven", None,
-        Some((stmt_spans, logos_source)),
+        stmts, registry, policies, interner, type_env, cfg, module_name, proven,
+        Some((stmt_spans, logos_source)), false,
     );
     (code, map.expect("mapping was requested"))
 }
 
 #[allow(clippy::too_many_arguments)]
-fn codegen_program_inner(stmts: &[Stmt], registry: &TypeRegistry, policies: &PolicyRegistry, interner: &Interner, type_env: &crate::analysis::types::TypeEnv, cfg: &OptimizationConfig, module_name: &str, proven: Option<&str>, mapping: Option<(&[LogosSpan], &str)>) -> (String, Option<SourceMap>) {
+fn codegen_program_inner(stmts: &[Stmt], registry: &TypeRegistry, policies: &PolicyRegistry, interner: &Interner, type_env: &crate::analysis::types::TypeEnv, cfg: &OptimizationConfig, module_name: &str, proven: Option<&str>, mapping: Option<(&[LogosSpan], &str)>, profile: bool) -> (String, Option<SourceMap>) {
     crate::optimize::set_active_config(*cfg);
     let mut output = String::new();
     // (rust line, LOGOS span) records; materialized into the SourceMap at the
@@ -587,8 +621,20 @@ fn codegen_program_inner(stmts: &[Stmt], registry: &TypeRegistry, policies: &Pol
         writeln!(output, "pub mod user_types {{").unwrap();
         writeln!(output, "    use super::*;\n").unwrap();
 
+        let comparable: HashSet<Symbol> =
+            structs.iter().map(|s| s.0).filter(|name| registry.ordering(*name).is_some()).collect();
+        let field_lists: Vec<(Symbol, &[FieldDef])> = structs.iter().map(|s| (s.0, s.1.as_slice())).collect();
+        let traits = StructTraits::new(&field_lists, &comparable, interner);
+
         for (name, fields, generics, is_portable, is_shared) in &structs {
-            output.push_str(&codegen_struct_def(*name, fields, generics, *is_portable, *is_shared, interner, 4, &c_abi_value_structs, &c_abi_ref_structs));
+            let extra_derives = traits.extra_derives(*name, comparable.contains(name));
+            output.push_str(&codegen_struct_def(*name, fields, generics, *is_portable, *is_shared, &extra_derives, interner, 4, &c_abi_value_structs, &c_abi_ref_structs));
+            if let Some(keys) = registry.ordering(*name) {
+                output.push_str(&codegen_ordering_impl(*name, keys, generics, interner, 4));
+            }
+            if traits.is_showable(*name) {
+                output.push_str(&codegen_show_impl(*name, fields, generics, interner, 4));
+            }
         }
 
         for (name, variants, generics, is_portable, is_shared) in &enums {
@@ -601,6 +647,9 @@ fn codegen_program_inner(stmts: &[Stmt], registry: &TypeRegistry, policies: &Pol
 
     // Phase 50: Generate policy impl blocks with predicate and capability methods
     output.push_str(&codegen_policy_impls(policies, interner));
+    if requires_process(stmts) {
+        output.push_str(&codegen_command_policy(policies, interner));
+    }
 
     // Mutual TCO: Detect pairs of mutually tail-calling functions
     let mutual_tce_pairs = detect_mutual_tce_pairs(stmts, interner);
@@ -814,14 +863,24 @@ fn codegen_program_inner(stmts: &[Stmt], registry: &TypeRegistry, policies: &Pol
                 if !mutual_tce_emitted.contains(name) {
                     // Find the pair this function belongs to
                     if let Some((a, b)) = mutual_tce_pairs.iter().find(|(a, b)| *a == *name || *b == *name) {
-                        output.push_str(&codegen_mutual_tce_pair(*a, *b, stmts, interner, &lww_fields, &mv_fields, &async_functions, &boxed_fields, registry, type_env));
+                        let code = codegen_mutual_tce_pair(*a, *b, stmts, interner, &lww_fields, &mv_fields, &async_functions, &boxed_fields, registry, type_env);
+                        if profile {
+                            output.push_str(&instrument_functions(&code, &[*a, *b], interner));
+                        } else {
+                            output.push_str(&code);
+                        }
                         mutual_tce_emitted.insert(*a);
                         mutual_tce_emitted.insert(*b);
                     }
                 }
                 // Skip individual emission — already emitted as part of merged pair
             } else {
-                output.push_str(&codegen_function_def(*name, generics, params, body, stmts, return_type.as_ref().copied(), *is_native, *native_path, *is_exported, *export_target, interner, &lww_fields, &mv_fields, &async_functions, &boxed_fields, registry, &pure_functions, type_env, &borrow_params_map, &mut_borrow_params_map, &value_mutable_params_map, &liveness, opt_flags, &fn_returns_map, &vec_return_fns, &array_return_fns, &fixed_array_param_map, oracle.as_ref(), &bigint_fns));
+                let code = codegen_function_def(*name, generics, params, body, stmts, return_type.as_ref().copied(), *is_native, *native_path, *is_exported, *export_target, interner, &lww_fields, &mv_fields, &async_functions, &boxed_fields, registry, &pure_functions, type_env, &borrow_params_map, &mut_borrow_params_map, &value_mutable_params_map, &liveness, opt_flags, &fn_returns_map, &vec_return_fns, &array_return_fns, &fixed_array_param_map, oracle.as_ref(), &bigint_fns);
+                if profile && !*is_native {
+                    output.push_str(&instrument_functions(&code, &[*name], interner));
+                } else {
+                    output.push_str(&code);
+                }
             }
             if let Some((spans, _)) = mapping {
                 record_emitted_lines(&output, fn_emit_start, spans.get(top_idx).copied(), &mut line_records);
@@ -849,6 +908,16 @@ fn codegen_program_inner(stmts: &[Stmt], registry: &TypeRegistry, policies: &Pol
     // OPT: Detect single-char text variables (emit u8 instead of String)
     let single_char_vars = collect_single_char_text_vars(stmts, interner);
 
+    // Coverage: one counter per probed statement, written out when `Main` ends.
+    if super::stmt::coverage_probe_count() > 0 {
+        writeln!(
+            output,
+            "static __LOGOS_COVERAGE: logicaffeine_system::coverage::Counters<{}> = logicaffeine_system::coverage::Counters::new();\n",
+            super::stmt::coverage_probe_count()
+        )
+        .unwrap();
+    }
+
     // Main function
     // Phase 51: Use async main when async operations are present
     if requires_async(stmts) {
@@ -863,6 +932,14 @@ fn codegen_program_inner(stmts: &[Stmt], registry: &TypeRegistry, policies: &Pol
         writeln!(output, "}}").unwrap();
         writeln!(output, "fn _logos_main() {{").unwrap();
     }
+    if profile {
+        // Declared first so it drops last, after `Main`'s own guard has recorded.
+        writeln!(output, "    let _logos_profile = logicaffeine_system::profile::Session::start();").unwrap();
+        writeln!(output, "    let _logos_prof = logicaffeine_system::profile::enter(\"Main\");").unwrap();
+    }
+    if super::stmt::coverage_probe_count() > 0 {
+        writeln!(output, "    let _logos_coverage = __LOGOS_COVERAGE.session();").unwrap();
+    }
     // Phase 53: Inject VFS when file operations or persistence is used
     if requires_vfs(stmts) {
         writeln!(output, "    let vfs: std::sync::Arc<dyn logicaffeine_system::fs::Vfs + Send + Sync> = std::sync::Arc::from(logicaffeine_system::fs::get_platform_vfs());").unwrap();
@@ -1072,6 +1149,38 @@ fn codegen_program_inner(stmts: &[Stmt], registry: &TypeRegistry, policies: &Pol
     (output, map)
 }
 
+/// Open a profiler guard as the first statement of each of `functions` in
+/// `code`. The guard goes after the line that closes the signature (the first
+/// line ending in `{` at or after `fn <name>`); async functions are skipped.
+fn instrument_functions(code: &str, functions: &[Symbol], interner: &Interner) -> String {
+    let names = RustNames::new(interner);
+    let mut pending: Vec<(String, String)> = functions
+        .iter()
+        .map(|f| (names.ident(*f), names.raw(*f).to_string()))
+        .collect();
+    let mut armed: Option<String> = None;
+    let mut out = String::with_capacity(code.len() + 128 * functions.len());
+    for line in code.split_inclusive('\n') {
+        if armed.is_none() && !line.contains("async fn ") {
+            if let Some(i) = pending.iter().position(|(ident, _)| {
+                line.contains(&format!("fn {ident}(")) || line.contains(&format!("fn {ident}<"))
+            }) {
+                armed = Some(pending.remove(i).1);
+            }
+        }
+        out.push_str(line);
+        if line.trim_end().ends_with('{') {
+            if let Some(raw) = armed.take() {
+                if !line.ends_with('\n') {
+                    out.push('\n');
+                }
+                writeln!(out, "    let _logos_prof = logicaffeine_system::profile::enter({raw:?});").unwrap();
+            }
+        }
+    }
+    out
+}
+
 /// Newlines emitted so far — brackets each statement's generated line range.
 fn line_count(s: &str) -> u32 {
     s.bytes().filter(|&b| b == b'\n').count() as u32
@@ -1,9 +1,9 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 
-use crate::analysis::registry::{FieldType, TypeDef, TypeRegistry};
+use crate::analysis::registry::{ArgumentStyle, FieldType, SettingDef, SettingKind, TypeDef, TypeRegistry};
 use crate::analysis::types::RustNames;
-use crate::ast::stmt::{BinaryOpKind, Expr, Literal, ReadSource, Stmt, TypeExpr};
+use crate::ast::stmt::{BinaryOpKind, Expr, Literal, ReadSource, Stmt, SwarmDiscovery, LogLevel, TypeExpr};
 use crate::intern::{Interner, Symbol};
 
 use super::context::{RefinementContext, VariableCapabilities, emit_refinement_check, analyze_variable_capabilities};
@@ -678,6 +678,32 @@ fn scratch_fill_target<'a>(
     None
 }
 
+thread_local! {
+    /// Coverage probes for the compile in progress: statement address → index
+    /// into the program's `__LOGOS_COVERAGE` counters. Empty (the default)
+    /// emits no counters. See [`crate::coverage`].
+    static COVERAGE_PROBES: std::cell::RefCell<HashMap<usize, usize>> =
+        std::cell::RefCell::new(HashMap::new());
+}
+
+/// Run `f` with a coverage counter bumped at the start of every statement in
+/// `probes`, restoring the previous probes afterwards.
+pub(crate) fn with_coverage_probes<T>(probes: HashMap<usize, usize>, f: impl FnOnce() -> T) -> T {
+    let prev = COVERAGE_PROBES.with(|p| p.replace(probes));
+    let out = f();
+    COVERAGE_PROBES.with(|p| *p.borrow_mut() = prev);
+    out
+}
+
+/// How many coverage counters the compile in progress declares (0 = off).
+pub(crate) fn coverage_probe_count() -> usize {
+    COVERAGE_PROBES.with(|p| p.borrow().len())
+}
+
+fn coverage_probe(stmt: &Stmt) -> Option<usize> {
+    COVERAGE_PROBES.with(|p| p.borrow().get(&(stmt as *const Stmt as usize)).copied())
+}
+
 pub fn codegen_stmt<'a>(
     stmt: &Stmt<'a>,
     interner: &Interner,
@@ -703,6 +729,10 @@ pub fn codegen_stmt<'a>(
     // Recursive calls (If/While/etc. bodies) see None → conservative clone.
     let live_vars_after: Option<HashSet<Symbol>> = ctx.take_live_vars_after();
 
+    if let Some(probe) = coverage_probe(stmt) {
+        writeln!(output, "{}__LOGOS_COVERAGE.hit({});", indent_str, probe).unwrap();
+    }
+
     // Drop a scratch buffer's `new Seq` declaration at any nesting: its fill loop is rewritten in place
     // to `let w: [T; N] = from_fn(…)` (the `Stmt::Repeat` handler), which becomes the binding, so this
     // `Seq::default()` declaration would be a dead duplicate.
@@ -874,12 +904,15 @@ pub fn codegen_stmt<'a>(
                 }
             } else if let Expr::New { type_name, type_args, .. } = value {
                 let type_str = interner.resolve(*type_name);
+                // An untyped `a new Seq/Map` (a comprehension's seed) keeps the type the checker
+                // inferred from its fill; failing that, the element type is left to rustc.
+                let inferred = ctx.get_variable_types().get(var).cloned();
                 match type_str {
                     "Seq" | "List" | "Vec" => {
                         let rust_type = if !type_args.is_empty() {
                             format!("LogosSeq<{}>", codegen_type_expr(&type_args[0], interner))
                         } else {
-                            "LogosSeq<()>".to_string()
+                            inferred.filter(|t| t.starts_with("LogosSeq<")).unwrap_or_else(|| "LogosSeq<_>".to_string())
                         };
                         ctx.register_variable_type(*var, rust_type);
                     }
@@ -892,7 +925,7 @@ pub fn codegen_stmt<'a>(
                                 ctx,
                             )
                         } else {
-                            "LogosMap<String, String>".to_string()
+                            inferred.filter(|t| t.starts_with("LogosMap<")).unwrap_or_else(|| "LogosMap<_, _>".to_string())
                         };
                         ctx.register_variable_type(*var, rust_type);
                     }
@@ -2353,6 +2386,36 @@ pub fn codegen_stmt<'a>(
                      indent_str, addr_str).unwrap();
         }
 
+        // Join the swarm: start the mesh with the named discovery methods
+        Stmt::JoinSwarm { using } => {
+            let discovery = if using.is_empty() {
+                "logicaffeine_system::discovery::Discovery::configured()".to_string()
+            } else {
+                let mut chain = "logicaffeine_system::discovery::Discovery::none()".to_string();
+                for method in using {
+                    let (setter, configured) = match method {
+                        SwarmDiscovery::Local => {
+                            chain.push_str(".with_local(true)");
+                            continue;
+                        }
+                        SwarmDiscovery::Bootstrap(_) => ("with_bootstrap_peers", "bootstrap_peers"),
+                        SwarmDiscovery::Relays(_) => ("with_relays", "relays"),
+                    };
+                    let addrs = match method.addresses() {
+                        Some(addrs) => format!(
+                            "({}).clone()",
+                            codegen_expr_with_async(addrs, interner, synced_vars, async_functions, ctx.get_variable_types())
+                        ),
+                        None => format!("logicaffeine_system::discovery::Discovery::configured().{}()", configured),
+                    };
+                    write!(chain, ".{}({})", setter, addrs).unwrap();
+                }
+                chain
+            };
+            writeln!(output, "{}logicaffeine_system::network::join_swarm(&{}).await.expect(\"Failed to join the swarm\");",
+                     indent_str, discovery).unwrap();
+        }
+
         // Phase 51: P2P Networking - Create PeerAgent remote handle
         Stmt::LetPeerAgent { var, address } => {
             let var_name = interner.resolve(*var);
@@ -2714,6 +2777,108 @@ pub fn codegen_stmt<'a>(
             }
         }
 
+        Stmt::Log { level, message, fields } => {
+            let level = match level {
+                LogLevel::Trace => "Trace",
+                LogLevel::Debug => "Debug",
+                LogLevel::Info => "Info",
+                LogLevel::Warn => "Warn",
+                LogLevel::Error => "Error",
+            };
+            let msg_str = codegen_expr_with_async_and_strings(message, interner, synced_vars, async_functions, ctx.get_string_vars(), ctx.get_variable_types(), ctx.get_fast_div());
+            let mut args = String::new();
+            for (name, value) in fields {
+                let value_str = codegen_expr_with_async(value, interner, synced_vars, async_functions, ctx.get_variable_types());
+                write!(args, ", {} = {}", names.ident(*name), value_str).unwrap();
+            }
+            writeln!(output, "{}logicaffeine_system::log_event!({}, {}{});", indent_str, level, msg_str, args).unwrap();
+        }
+
+        Stmt::RunCommand { command, timeout, output: out_var, errors, exit_code } => {
+            let cmd_str = codegen_expr_with_async_and_strings(command, interner, synced_vars, async_functions, ctx.get_string_vars(), ctx.get_variable_types(), ctx.get_fast_div());
+            let timeout_str = match timeout {
+                Some(limit) => {
+                    let limit_str = codegen_expr_with_async(limit, interner, synced_vars, async_functions, ctx.get_variable_types());
+                    if infer_rust_type_from_expr(limit, interner) == "std::time::Duration" {
+                        format!("Some({})", limit_str)
+                    } else {
+                        format!("Some(std::time::Duration::from_millis({} as u64))", limit_str)
+                    }
+                }
+                None => "None".to_string(),
+            };
+            // Each capture binds one field of the run; the rest pass through.
+            let captures: Vec<(Symbol, &str)> = [(*out_var, "stdout"), (*errors, "stderr"), (*exit_code, "exit_code")]
+                .into_iter()
+                .filter_map(|(var, field)| var.map(|v| (v, field)))
+                .collect();
+            let binding = |var: Symbol| {
+                if mutable_vars.contains(&var) { format!("mut {}", names.ident(var)) } else { names.ident(var) }
+            };
+            match captures.as_slice() {
+                [] => writeln!(output, "{}{{", indent_str).unwrap(),
+                [(var, _)] => writeln!(output, "{}let {} = {{", indent_str, binding(*var)).unwrap(),
+                vars => {
+                    let pattern: Vec<String> = vars.iter().map(|(var, _)| binding(*var)).collect();
+                    writeln!(output, "{}let ({}) = {{", indent_str, pattern.join(", ")).unwrap();
+                }
+            }
+            writeln!(output, "{}    let __command = ({}).to_string();", indent_str, cmd_str).unwrap();
+            writeln!(
+                output,
+                "{}    let __policy = logicaffeine_system::process::CommandPolicy::allow(__ALLOWED_COMMANDS.iter().copied());\n\
+                 {}    let __run = logicaffeine_system::process::run_with_policy(&__command, {}, &__policy).unwrap_or_else(|e| panic!(\"{{}}\", e));",
+                indent_str, indent_str, timeout_str
+            ).unwrap();
+            if out_var.is_none() {
+                writeln!(output, "{}    print!(\"{{}}\", __run.stdout);", indent_str).unwrap();
+            }
+            if errors.is_none() {
+                writeln!(output, "{}    eprint!(\"{{}}\", __run.stderr);", indent_str).unwrap();
+            }
+            if exit_code.is_none() {
+                writeln!(output, "{}    __run.require_success(&__command).unwrap_or_else(|e| panic!(\"{{}}\", e));", indent_str).unwrap();
+            }
+            match captures.as_slice() {
+                [] => writeln!(output, "{}}}", indent_str).unwrap(),
+                [(_, field)] => writeln!(output, "{}    __run.{}\n{}}};", indent_str, field, indent_str).unwrap(),
+                vars => {
+                    let fields: Vec<String> = vars.iter().map(|(_, field)| format!("__run.{}", field)).collect();
+                    writeln!(output, "{}    ({})\n{}}};", indent_str, fields.join(", "), indent_str).unwrap();
+                }
+            }
+            for (var, field) in &captures {
+                if *field == "exit_code" {
+                    ctx.register_variable_type(*var, "i64".to_string());
+                } else {
+                    ctx.register_string_var(*var);
+                    ctx.register_variable_type(*var, "String".to_string());
+                }
+            }
+        }
+
+        Stmt::Sip { file, sources, progress } => {
+            let file_str = codegen_expr_with_async_and_strings(file, interner, synced_vars, async_functions, ctx.get_string_vars(), ctx.get_variable_types(), ctx.get_fast_div());
+            let sources_str = codegen_expr_with_async_and_strings(sources, interner, synced_vars, async_functions, ctx.get_string_vars(), ctx.get_variable_types(), ctx.get_fast_div());
+            writeln!(
+                output,
+                "{}{{\n\
+                 {}    let __file = ({}).to_string();\n\
+                 {}    let __sources = logicaffeine_system::sip::SipSources::sip_paths(&({}));\n\
+                 {}    let __transfer = logicaffeine_system::sip::SipTransfer::open(&__file, &__sources)\n\
+                 {}        .unwrap_or_else(|e| panic!(\"Cannot sip {{}}: {{}}\", __file, e));\n\
+                 {}    for __step in __transfer {{\n\
+                 {}        let __step = __step.unwrap_or_else(|e| panic!(\"Cannot sip {{}}: {{}}\", __file, e));",
+                indent_str, indent_str, file_str, indent_str, sources_str, indent_str, indent_str, indent_str, indent_str
+            ).unwrap();
+            // The progress function gets the whole percent done after each chunk.
+            if let Some(function) = progress {
+                let await_suffix = if async_functions.contains(function) { ".await" } else { "" };
+                writeln!(output, "{}        {}(__step.percent() as i64){};", indent_str, names.ident(*function), await_suffix).unwrap();
+            }
+            writeln!(output, "{}    }}\n{}}}", indent_str, indent_str).unwrap();
+        }
+
         Stmt::SetField { object, field, value } => {
             let obj_str = codegen_expr_with_async(object, interner, synced_vars, async_functions, ctx.get_variable_types());
             let field_name = interner.resolve(*field);
@@ -3479,6 +3644,65 @@ pub fn codegen_stmt<'a>(
                         indent_str, var_name, path_str
                     ).unwrap();
                 }
+                ReadSource::Settings { type_name, path } => {
+                    // The parser already checked the schema holds only settings types.
+                    let schema = registry.settings_schema(*type_name, interner).unwrap_or_default();
+                    let path_str = match path {
+                        Some(path_expr) => format!(
+                            "Some(&*{}.to_string())",
+                            codegen_expr_with_async(path_expr, interner, synced_vars, async_functions, ctx.get_variable_types())
+                        ),
+                        None => "None".to_string(),
+                    };
+                    let fields: Vec<String> = schema.iter().map(|def| format!(
+                        "logicaffeine_system::settings::SettingField {{ name: \"{}\", kind: logicaffeine_system::settings::SettingKind::{}, optional: {} }}",
+                        interner.resolve(def.name), setting_kind_name(def.kind), def.optional
+                    )).collect();
+                    writeln!(output, "{}let {} = {{", indent_str, var_name).unwrap();
+                    writeln!(
+                        output,
+                        "{}    let __settings = logicaffeine_system::settings::load({}, &[{}]).unwrap_or_else(|e| panic!(\"{{}}\", e));",
+                        indent_str, path_str, fields.join(", ")
+                    ).unwrap();
+                    writeln!(
+                        output,
+                        "{}    {} {{ {} }}",
+                        indent_str, interner.resolve(*type_name), setting_inits(&schema, "__settings", interner)
+                    ).unwrap();
+                    writeln!(output, "{}}};", indent_str).unwrap();
+                }
+                ReadSource::Arguments { type_name } => {
+                    // The parser already checked every argument has a settings type.
+                    let schema = registry.arguments_schema(interner).unwrap_or_default();
+                    let specs: Vec<String> = schema.iter().map(|(arg, def)| {
+                        let style = match arg.style {
+                            ArgumentStyle::Positional => "Positional",
+                            ArgumentStyle::Flag => "Flag",
+                            ArgumentStyle::Option => "Option",
+                        };
+                        format!(
+                            "logicaffeine_system::arguments::ArgumentSpec {{ name: \"{}\", kind: logicaffeine_system::settings::SettingKind::{}, optional: {}, style: logicaffeine_system::arguments::ArgumentStyle::{}, help: {:?} }}",
+                            interner.resolve(def.name),
+                            setting_kind_name(def.kind),
+                            def.optional,
+                            style,
+                            arg.help.map(|h| interner.resolve(h)).unwrap_or("")
+                        )
+                    }).collect();
+                    let defs: Vec<SettingDef> = schema.iter().map(|(_, def)| def.clone()).collect();
+                    writeln!(output, "{}let {} = {{", indent_str, var_name).unwrap();
+                    writeln!(
+                        output,
+                        "{}    let __args = logicaffeine_system::arguments::parse(&[{}]);",
+                        indent_str, specs.join(", ")
+                    ).unwrap();
+                    writeln!(
+                        output,
+                        "{}    {} {{ {} }}",
+                        indent_str, interner.resolve(*type_name), setting_inits(&defs, "__args", interner)
+                    ).unwrap();
+                    writeln!(output, "{}}};", indent_str).unwrap();
+                }
             }
         }
 
@@ -3842,6 +4066,33 @@ fn truthy_cond_wrap(
     }
 }
 
+/// The `logicaffeine_system::settings::SettingKind` variant for a setting.
+fn setting_kind_name(kind: SettingKind) -> &'static str {
+    match kind {
+        SettingKind::Int => "Int",
+        SettingKind::Real => "Real",
+        SettingKind::Text => "Text",
+        SettingKind::Bool => "Bool",
+    }
+}
+
+/// Struct field initializers reading each setting out of the
+/// `SettingValues` bound to `values`: `port: __settings.int("port")`.
+fn setting_inits(schema: &[SettingDef], values: &str, interner: &Interner) -> String {
+    let inits: Vec<String> = schema.iter().map(|def| {
+        let name = interner.resolve(def.name);
+        let getter = match def.kind {
+            SettingKind::Int => "int",
+            SettingKind::Real => "real",
+            SettingKind::Text => "text",
+            SettingKind::Bool => "bool",
+        };
+        let prefix = if def.optional { "optional_" } else { "" };
+        format!("{}: {}.{}{}(\"{}\")", name, values, prefix, getter, name)
+    }).collect();
+    inits.join(", ")
+}
+
 /// Check if a Vec<T> type has a Copy element type.
 /// Delegates to `LogosType::element_type().is_copy()` — single source of truth.
 pub(crate) fn has_copy_element_type(vec_type: &str) -> bool {
@@ -317,6 +317,8 @@ pub(crate) fn map_type_to_rust(ty: &str) -> String {
         "Money" => "LogosMoney".to_string(),
         // A 128-bit UUID — a Copy newtype over `[u8; 16]`, alloc-free in compiled code.
         "Uuid" | "UUID" => "LogosUuid".to_string(),
+        // A compiled regular expression — an `Rc` handle, so clones share the engine.
+        "Pattern" => "LogosPattern".to_string(),
         other => other.to_string(),
     }
 }
@@ -325,7 +327,8 @@ pub(crate) fn map_type_to_rust(ty: &str) -> String {
 /// Phase 34: Now supports generic type parameters.
 /// Phase 47: Now supports is_portable for Serialize/Deserialize derives.
 /// Phase 49: Now supports is_shared for CRDT Merge impl.
-pub(super) fn codegen_struct_def(name: Symbol, fields: &[FieldDef], generics: &[Symbol], is_portable: bool, is_shared: bool, interner: &Interner, indent: usize, c_abi_value_structs: &HashSet<Symbol>, c_abi_ref_structs: &HashSet<Symbol>) -> String {
+/// `extra_derives` (`", Eq, Hash"`…) comes from [`StructTraits::extra_derives`].
+pub(super) fn codegen_struct_def(name: Symbol, fields: &[FieldDef], generics: &[Symbol], is_portable: bool, is_shared: bool, extra_derives: &str, interner: &Interner, indent: usize, c_abi_value_structs: &HashSet<Symbol>, c_abi_ref_structs: &HashSet<Symbol>) -> String {
     let ind = " ".repeat(indent);
     let mut output = String::new();
 
@@ -349,9 +352,9 @@ pub(super) fn codegen_struct_def(name: Symbol, fields: &[FieldDef], generics: &[
     // Phase 52: Shared types also need Serialize/Deserialize for Synced<T>
     // C ABI reference-type structs also need serde for from_json/to_json support
     if is_portable || is_shared || c_abi_ref_structs.contains(&name) {
-        writeln!(output, "{}#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]", ind).unwrap();
+        writeln!(output, "{}#[derive(Default, Debug, Clone, PartialEq{}, serde::Serialize, serde::Deserialize)]", ind, extra_derives).unwrap();
     } else {
-        writeln!(output, "{}#[derive(Default, Debug, Clone, PartialEq)]", ind).unwrap();
+        writeln!(output, "{}#[derive(Default, Debug, Clone, PartialEq{})]", ind, extra_derives).unwrap();
     }
     writeln!(output, "{}pub struct {}{} {{", ind, interner.resolve(name), generic_str).unwrap();
 
@@ -404,6 +407,204 @@ pub(super) fn codegen_merge_impl(name: Symbol, fields: &[FieldDef], generics: &[
     output
 }
 
+/// Generate `impl PartialOrd` for a `Comparable` struct: its key fields in turn, the first
+/// unequal one deciding. An unordered key (NaN) counts as equal, as the interpreter's `Sort` has it.
+pub(super) fn codegen_ordering_impl(name: Symbol, keys: &[Symbol], generics: &[Symbol], interner: &Interner, indent: usize) -> String {
+    let ind = " ".repeat(indent);
+    let name_str = interner.resolve(name);
+    let mut output = String::new();
+
+    let (impl_generics, type_generics) = if generics.is_empty() {
+        (String::new(), String::new())
+    } else {
+        let params: Vec<&str> = generics.iter().map(|g| interner.resolve(*g)).collect();
+        let bounded: Vec<String> = params.iter().map(|p| format!("{}: PartialOrd", p)).collect();
+        (format!("<{}>", bounded.join(", ")), format!("<{}>", params.join(", ")))
+    };
+
+    writeln!(output, "{}impl{} PartialOrd for {}{} {{", ind, impl_generics, name_str, type_generics).unwrap();
+    writeln!(output, "{}    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {{", ind).unwrap();
+    writeln!(output, "{}        Some(std::cmp::Ordering::Equal", ind).unwrap();
+    for key in keys {
+        let key = interner.resolve(*key);
+        writeln!(
+            output,
+            "{}            .then_with(|| self.{}.partial_cmp(&other.{}).unwrap_or(std::cmp::Ordering::Equal))",
+            ind, key, key
+        )
+        .unwrap();
+    }
+    writeln!(output, "{}        )", ind).unwrap();
+    writeln!(output, "{}    }}", ind).unwrap();
+    writeln!(output, "{}}}\n", ind).unwrap();
+
+    output
+}
+
+/// The structural traits each user struct gets beyond `Default, Debug, Clone, PartialEq`, decided
+/// by its field types: a `Real` field rules out `Eq`/`Hash`, a `Seq` or `Map` field rules out
+/// `Hash` (a live collection can't be a key — the interpreter refuses it too) and ordering. A
+/// struct holding another struct qualifies only if that one does, so all are settled together.
+pub(super) struct StructTraits {
+    hashable: HashSet<Symbol>,
+    orderable: HashSet<Symbol>,
+    showable: HashSet<Symbol>,
+}
+
+impl StructTraits {
+    /// `comparable` structs have their own `PartialOrd` (see [`codegen_ordering_impl`]), so
+    /// they order as fields whatever their own fields are.
+    pub(super) fn new(structs: &[(Symbol, &[FieldDef])], comparable: &HashSet<Symbol>, interner: &Interner) -> Self {
+        let none = HashSet::new();
+        StructTraits {
+            hashable: settle(structs, &none, |ty, ok| field_is_hashable(ty, ok, interner)),
+            orderable: settle(structs, comparable, |ty, ok| field_is_orderable(ty, ok, interner)),
+            showable: settle(structs, &none, |ty, ok| field_is_showable(ty, ok, interner)),
+        }
+    }
+
+    /// The derives to append for `name`: `Eq, Hash` when every field hashes, `PartialOrd`
+    /// (field by field, in declaration order) when every field orders and there is no
+    /// `Comparable` declaration to implement it instead.
+    pub(super) fn extra_derives(&self, name: Symbol, comparable: bool) -> String {
+        let mut derives = String::new();
+        if self.hashable.contains(&name) {
+            derives.push_str(", Eq, Hash");
+        }
+        if !comparable && self.orderable.contains(&name) {
+            derives.push_str(", PartialOrd");
+        }
+        derives
+    }
+
+    /// Whether every field of `name` can be shown, so the struct gets [`codegen_show_impl`].
+    pub(super) fn is_showable(&self, name: Symbol) -> bool {
+        self.showable.contains(&name)
+    }
+}
+
+/// The greatest set of structs whose fields all pass `field_ok` given that set (plus `pinned`,
+/// which always pass): start from every struct and drop failures until nothing changes.
+fn settle(
+    structs: &[(Symbol, &[FieldDef])],
+    pinned: &HashSet<Symbol>,
+    field_ok: impl Fn(&FieldType, &HashSet<Symbol>) -> bool,
+) -> HashSet<Symbol> {
+    let mut ok: HashSet<Symbol> = structs.iter().map(|(name, _)| *name).collect();
+    loop {
+        let known: HashSet<Symbol> = ok.union(pinned).copied().collect();
+        let next: HashSet<Symbol> = structs
+            .iter()
+            .filter(|(name, fields)| ok.contains(name) && fields.iter().all(|f| field_ok(&f.ty, &known)))
+            .map(|(name, _)| *name)
+            .collect();
+        if next.len() == ok.len() {
+            return ok;
+        }
+        ok = next;
+    }
+}
+
+/// The type's name when it is a leaf (`Int`, `Text`, a struct…) rather than generic or a parameter.
+fn leaf_type(ty: &FieldType) -> Option<Symbol> {
+    match ty {
+        FieldType::Primitive(sym) | FieldType::Named(sym) => Some(*sym),
+        _ => None,
+    }
+}
+
+fn field_is_hashable(ty: &FieldType, structs: &HashSet<Symbol>, interner: &Interner) -> bool {
+    match ty {
+        FieldType::Generic { base, params } => {
+            matches!(interner.resolve(*base), "Option" | "Maybe")
+                && params.iter().all(|p| field_is_hashable(p, structs, interner))
+        }
+        // A derive bounds the parameter itself (`T: Hash`).
+        FieldType::TypeParam(_) => true,
+        _ => leaf_type(ty).is_some_and(|sym| {
+            structs.contains(&sym)
+                || matches!(
+                    interner.resolve(sym),
+                    "Int" | "Nat" | "Text" | "Bool" | "Boolean" | "Char" | "Byte" | "Unit" | "Duration"
+                )
+        }),
+    }
+}
+
+fn field_is_orderable(ty: &FieldType, structs: &HashSet<Symbol>, interner: &Interner) -> bool {
+    match ty {
+        FieldType::Generic { base, params } => {
+            matches!(interner.resolve(*base), "Option" | "Maybe")
+                && params.iter().all(|p| field_is_orderable(p, structs, interner))
+        }
+        FieldType::TypeParam(_) => true,
+        _ => {
+            field_is_hashable(ty, structs, interner)
+                || leaf_type(ty).is_some_and(|sym| matches!(interner.resolve(sym), "Real" | "Float"))
+        }
+    }
+}
+
+fn field_is_showable(ty: &FieldType, structs: &HashSet<Symbol>, interner: &Interner) -> bool {
+    match ty {
+        FieldType::Generic { base, params } => {
+            matches!(
+                interner.resolve(*base),
+                "List" | "Seq" | "Set" | "Map" | "Option" | "Maybe" | "Quantity"
+                    | "LastWriteWins" | "LWWRegister" | "Divergent" | "MVRegister"
+            ) && params.iter().all(|p| field_is_showable(p, structs, interner))
+        }
+        // Bounded `T: Showable` on the impl.
+        FieldType::TypeParam(_) => true,
+        _ => leaf_type(ty).is_some_and(|sym| {
+            structs.contains(&sym)
+                || matches!(
+                    interner.resolve(sym),
+                    "Int" | "Nat" | "Text" | "Bool" | "Boolean" | "Real" | "Float" | "Char" | "Byte"
+                        | "Duration" | "Moment" | "Date" | "Time" | "Span" | "Quantity" | "Money"
+                        | "Pattern" | "ConvergentCount" | "Tally"
+                )
+        }),
+    }
+}
+
+/// Generate `impl Showable` for a struct: `Player { name: Ann, score: 25 }`, fields in name
+/// order — the interpreter's display of a record, so `Show` agrees on every tier.
+pub(super) fn codegen_show_impl(name: Symbol, fields: &[FieldDef], generics: &[Symbol], interner: &Interner, indent: usize) -> String {
+    let ind = " ".repeat(indent);
+    let name_str = interner.resolve(name);
+    let mut output = String::new();
+
+    let (impl_generics, type_generics) = if generics.is_empty() {
+        (String::new(), String::new())
+    } else {
+        let params: Vec<&str> = generics.iter().map(|g| interner.resolve(*g)).collect();
+        let bounded: Vec<String> = params.iter().map(|p| format!("{}: Showable", p)).collect();
+        (format!("<{}>", bounded.join(", ")), format!("<{}>", params.join(", ")))
+    };
+
+    let mut field_names: Vec<&str> = fields.iter().map(|f| interner.resolve(f.name)).collect();
+    field_names.sort();
+
+    writeln!(output, "{}impl{} Showable for {}{} {{", ind, impl_generics, name_str, type_generics).unwrap();
+    writeln!(output, "{}    fn format_show(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{", ind).unwrap();
+    if field_names.is_empty() {
+        writeln!(output, "{}        write!(f, \"{}\")", ind, name_str).unwrap();
+    } else {
+        writeln!(output, "{}        write!(f, \"{} {{{{ \")?;", ind, name_str).unwrap();
+        for (i, field) in field_names.iter().enumerate() {
+            let sep = if i == 0 { "" } else { ", " };
+            writeln!(output, "{}        write!(f, \"{}{}: \")?;", ind, sep, field).unwrap();
+            writeln!(output, "{}        self.{}.format_show(f)?;", ind, field).unwrap();
+        }
+        writeln!(output, "{}        write!(f, \" }}}}\")", ind).unwrap();
+    }
+    writeln!(output, "{}    }}", ind).unwrap();
+    writeln!(output, "{}}}\n", ind).unwrap();
+
+    output
+}
+
 /// Phase 49: Check if a field type is a CRDT type that implements Merge.
 pub(super) fn is_crdt_field_type(ty: &FieldType, interner: &Interner) -> bool {
     match ty {
@@ -543,6 +744,7 @@ pub(super) fn codegen_field_type(ty: &FieldType, interner: &Interner) -> String
                 "Span" => "LogosSpan".to_string(),
                 "Quantity" => "LogosQuantity".to_string(),
                 "Money" => "LogosMoney".to_string(),
+                "Pattern" => "LogosPattern".to_string(),
                 other => other.to_string(),
             }
         }
@@ -86,13 +86,14 @@ use crate::analysis::{DiscoveryPass, EscapeChecker, OwnershipChecker, PolicyRegi
 use crate::arena::Arena;
 use crate::arena_ctx::AstContext;
 use crate::ast::{Expr, MatchArm, Stmt, TypeExpr};
-use crate::ast::stmt::{BinaryOpKind, ClosureBody, Literal, Pattern, ReadSource, SelectBranch, StringPart};
+use crate::ast::stmt::{BinaryOpKind, ClosureBody, CollectionKind, Literal, Pattern, ReadSource, SelectBranch, StringPart};
 use crate::codegen::{codegen_program, generate_c_header, generate_python_bindings, generate_typescript_bindings};
 use crate::diagnostic::{parse_rustc_json, translate_diagnostics, LogosError};
 use crate::drs::WorldState;
 use crate::error::ParseError;
 use crate::intern::Interner;
 use crate::lexer::Lexer;
+use crate::metrics::{CompileMetrics, Phase, Stopwatch};
 use crate::parser::Parser;
 use crate::sourcemap::SourceMap;
 
@@ -117,6 +118,11 @@ pub struct CompileOutput {
     pub typescript_types: Option<String>,
     /// Generated TypeScript FFI bindings (.js content, populated when C exports exist).
     pub typescript_bindings: Option<String>,
+    /// How long each compile phase took; `largo` warns about slow ones.
+    pub metrics: CompileMetrics,
+    /// The `logicaffeine-system` features the generated code links
+    /// (see [`system_features`]).
+    pub system_features: Vec<String>,
 }
 
 /// Interpret LOGOS source and return output as a string.
@@ -579,6 +585,7 @@ pub fn compile_to_c(source: &str) -> Result<String, ParseError> {
         (result.types, result.policies)
     };
     let codegen_registry = type_registry.clone();
+    check_compiled_field_types(&codegen_registry, &interner)?;
 
     let mut world_state = WorldState::new();
     let expr_arena = Arena::new();
@@ -845,20 +852,30 @@ pub fn compile_program_full_with_proven(source: &str, proven: Option<&str>) -> R
     // Phase 10: auto-prepend the stdlib modules the program references (no-op when
     // it uses no stdlib vocabulary, so the benchmark corpus stays byte-identical).
     let prelude_src = crate::loader::apply_prelude(source);
+    // Coverage probes name lines of the caller's text, which is the tail of
+    // what is parsed (see `crate::coverage`).
+    let user_len = source.len();
     let source = prelude_src.as_ref();
+    let covering = crate::coverage::instrumenting();
+    let mut metrics = CompileMetrics::default();
 
     let mut interner = Interner::new();
+    let lexing = Stopwatch::start();
     let mut lexer = Lexer::new(source, &mut interner);
     let tokens = lexer.tokenize();
+    lexing.record(&mut metrics, Phase::Lex, tokens.len());
 
     // Pass 1: Discovery - scan for type definitions and policies
+    let discovering = Stopwatch::start();
     let (type_registry, policy_registry) = {
         let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
         let result = discovery.run_full();
         (result.types, result.policies)
     };
+    discovering.record(&mut metrics, Phase::Discovery, type_registry.iter_types().count());
     // Clone for codegen (parser takes ownership)
     let codegen_registry = type_registry.clone();
+    check_compiled_field_types(&codegen_registry, &interner)?;
     let codegen_policies = policy_registry.clone();
 
     let mut world_state = WorldState::new();
@@ -885,15 +902,22 @@ pub fn compile_program_full_with_proven(source: &str, proven: Option<&str>) -> R
     );
 
     // Pass 2: Parse with type context
+    let parsing = Stopwatch::start();
     let mut parser = Parser::new(tokens, &mut world_state, &mut interner, ast_ctx, type_registry);
     // Note: Don't call process_block_headers() - parse_program handles blocks itself
 
     let stmts = parser.parse_program()?;
+    parsing.record(&mut metrics, Phase::Parse, stmts.len());
+    let semantic = Stopwatch::start();
+    let mut spans = if covering { parser.stmt_span_index().clone() } else { HashMap::new() };
     // The ONE optimization config for this compile: env baseline merged with the
     // file-level `## No <X>` decorators (program-wide), normalized. Threaded to
     // BOTH the optimizer and codegen so they never disagree. Captured here (the
     // last use of `parser`) so its `&mut interner` borrow ends before reuse.
-    let opt_config = {
+    // Coverage counts the statements as written, so it compiles unoptimized.
+    let opt_config = if covering {
+        crate::optimization::OptimizationConfig::all_off()
+    } else {
         let mut c = crate::optimization::OptimizationConfig::from_env()
             .merged(&parser.program_opt_flags());
         c.normalize();
@@ -912,15 +936,33 @@ pub fn compile_program_full_with_proven(source: &str, proven: Option<&str>) -> R
         opt_config.is_on(crate::optimization::Opt::Comptime),
     );
     let stmts: Vec<Stmt> = match resolved {
-        Some(rw) => rw.to_vec(),
+        Some(rw) => {
+            let rw = rw.to_vec();
+            if covering {
+                crate::coverage::carry_spans(&stmts, &rw, &mut spans);
+            }
+            rw
+        }
         None => stmts,
     };
 
     // Pass 2.5: Optimization - constant folding and dead code elimination
-    let stmts = crate::optimize::optimize_program(stmts, &imperative_expr_arena, &stmt_arena, &mut interner, &opt_config);
+    let (stmts, probes) = if covering {
+        // A function's header is a declaration, not a line that runs.
+        for stmt in stmts.iter().filter(|s| matches!(s, Stmt::FunctionDef { .. })) {
+            spans.remove(&(stmt as *const Stmt as usize));
+        }
+        let prefix_len = source.len().saturating_sub(user_len);
+        let probes = crate::coverage::plan_probes(source, prefix_len, &spans);
+        (stmts, probes)
+    } else {
+        let stmts = crate::optimize::optimize_program(stmts, &imperative_expr_arena, &stmt_arena, &mut interner, &opt_config);
+        (stmts, HashMap::new())
+    };
 
     // Extract dependencies before escape analysis
     let mut dependencies = extract_dependencies(&stmts, &interner)?;
+    let system_features = system_features(&stmts);
 
     // FFI: Auto-inject wasm-bindgen dependency if any function is exported for WASM
     let needs_wasm_bindgen = stmts.iter().any(|stmt| {
@@ -953,12 +995,19 @@ pub fn compile_program_full_with_proven(source: &str, proven: Option<&str>) -> R
     // Note: Static verification is available when the `verification` feature is enabled,
     // but must be explicitly invoked via compile_to_rust_verified().
 
+    check_compiled_builtins(&stmts, &interner)?;
     let type_env = crate::analysis::check_program(&stmts, &interner, &codegen_registry)
         .map_err(|e| ParseError {
             kind: e.to_parse_error_kind(&interner),
             span: crate::token::Span::default(),
         })?;
-    let rust_code = crate::codegen::codegen_program_with_proven(&stmts, &codegen_registry, &codegen_policies, &interner, &type_env, &opt_config, "proven", proven);
+    // Division resolution, optimization, escape analysis, type checking.
+    semantic.record(&mut metrics, Phase::Semantics, 4);
+
+    let transpiling = Stopwatch::start();
+    let rust_code = crate::codegen::with_coverage_probes(probes, || {
+        crate::codegen::codegen_program_with_proven(&stmts, &codegen_registry, &codegen_policies, &interner, &type_env, &opt_config, "proven", proven)
+    });
 
     // Universal ABI: Generate C header + bindings if any C exports exist
     let has_c = stmts.iter().any(|stmt| {
@@ -999,8 +1048,9 @@ pub fn compile_program_full_with_proven(source: &str, proven: Option<&str>) -> R
     } else {
         (None, None)
     };
+    transpiling.record(&mut metrics, Phase::Transpile, rust_code.len());
 
-    Ok(CompileOutput { rust_code, dependencies, c_header, python_bindings, typescript_types, typescript_bindings })
+    Ok(CompileOutput { rust_code, dependencies, c_header, python_bindings, typescript_types, typescript_bindings, metrics, system_features })
 }
 
 /// Generate the Rust SOURCE for an AOT-native cdylib of ONE function (HOTSWAP §Axis-3
@@ -1027,6 +1077,7 @@ pub fn compile_function_to_native_rust(
         (result.types, result.policies)
     };
     let codegen_registry = type_registry.clone();
+    check_compiled_field_types(&codegen_registry, &interner)?;
     let codegen_policies = policy_registry.clone();
 
     let mut world_state = WorldState::new();
@@ -1091,6 +1142,7 @@ pub fn compile_function_to_native_rust(
     };
 
     let slice = crate::codegen::function_slice(&stmts, target_sym, &interner);
+    check_compiled_builtins(&slice, &interner)?;
     let type_env = crate::analysis::check_program(&slice, &interner, &codegen_registry).map_err(|e| {
         ParseError {
             kind: e.to_parse_error_kind(&interner),
@@ -1367,6 +1419,79 @@ pub fn build_native_bundle(
     Ok(manifest)
 }
 
+/// Refuse calls to builtins the Rust backend has no lowering for.
+fn check_compiled_builtins(stmts: &[Stmt], interner: &Interner) -> Result<(), ParseError> {
+    match crate::codegen::detection::interpreter_only_builtin(stmts, interner) {
+        Some(phrase) => Err(ParseError {
+            kind: crate::error::ParseErrorKind::Custom(format!(
+                "`{} …` reads the interpreter's replica bookkeeping, which compiled programs do not keep yet. \
+                 Run the program with `largo run --interpret`.",
+                phrase,
+            )),
+            span: crate::token::Span::default(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Refuse struct fields the Rust backend cannot lower faithfully.
+///
+/// The parts of a shared Document are live views that write through to the
+/// document, which only the interpreter and VM model; compiled to Rust they
+/// would quietly become copies, so the program is rejected instead.
+fn check_compiled_field_types(registry: &crate::analysis::TypeRegistry, interner: &Interner) -> Result<(), ParseError> {
+    fn interpreter_only(ty: &crate::analysis::FieldType, interner: &Interner) -> bool {
+        match ty {
+            crate::analysis::FieldType::Named(sym) => interner.resolve(*sym) == "SharedDocument",
+            crate::analysis::FieldType::Generic { params, .. } => params.iter().any(|p| interpreter_only(p, interner)),
+            _ => false,
+        }
+    }
+
+    for (type_name, def) in registry.iter_types() {
+        let crate::analysis::TypeDef::Struct { fields, .. } = def else {
+            continue;
+        };
+        if let Some(field) = fields.iter().find(|f| interpreter_only(&f.ty, interner)) {
+            return Err(ParseError {
+                kind: crate::error::ParseErrorKind::Custom(format!(
+                    "The field '{}' of {} is a shared Document, which compiled programs do not support yet. \
+                     Run the program with `largo run --interpret`, or use a SharedMap or SharedSequence field instead.",
+                    interner.resolve(field.name),
+                    interner.resolve(*type_name),
+                )),
+                span: crate::token::Span::default(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// The `logicaffeine-system` features a program's generated code links:
+/// `full`, plus `process`, `log`, `settings` and `arguments` each only when
+/// the program runs commands, logs, reads settings or reads its arguments.
+pub fn system_features(stmts: &[Stmt]) -> Vec<String> {
+    use crate::codegen::detection::{requires_arguments, requires_log, requires_process, requires_settings};
+
+    let mut features = vec!["full".to_string()];
+    for (feature, used) in [
+        ("process", requires_process(stmts)),
+        ("log", requires_log(stmts)),
+        ("settings", requires_settings(stmts)),
+        ("arguments", requires_arguments(stmts)),
+    ] {
+        if used {
+            features.push(feature.to_string());
+        }
+    }
+    features
+}
+
+/// A feature list as the body of a Cargo.toml `features = [...]` array.
+pub fn toml_feature_list(features: &[String]) -> String {
+    features.iter().map(|f| format!("\"{}\"", f)).collect::<Vec<_>>().join(", ")
+}
+
 /// Extract crate dependencies from `Stmt::Require` nodes.
 ///
 /// Deduplicates by crate name: same name + same version keeps one copy.
@@ -1460,6 +1585,7 @@ pub fn compile_to_rust_checked(source: &str) -> Result<String, ParseError> {
     };
     // Clone for codegen (parser takes ownership)
     let codegen_registry = type_registry.clone();
+    check_compiled_field_types(&codegen_registry, &interner)?;
     let codegen_policies = policy_registry.clone();
 
     let mut world_state = WorldState::new();
@@ -1511,6 +1637,7 @@ pub fn compile_to_rust_checked(source: &str) -> Result<String, ParseError> {
         }
     })?;
 
+    check_compiled_builtins(&stmts, &interner)?;
     let type_env = crate::analysis::check_program(&stmts, &interner, &codegen_registry)
         .map_err(|e| ParseError {
             kind: e.to_parse_error_kind(&interner),
@@ -1583,6 +1710,7 @@ pub fn compile_to_rust_verified(source: &str) -> Result<String, ParseError> {
     };
     // Clone for codegen (parser takes ownership)
     let codegen_registry = type_registry.clone();
+    check_compiled_field_types(&codegen_registry, &interner)?;
     let codegen_policies = policy_registry.clone();
 
     let mut world_state = WorldState::new();
@@ -1633,6 +1761,7 @@ pub fn compile_to_rust_verified(source: &str) -> Result<String, ParseError> {
         }
     })?;
 
+    check_compiled_builtins(&stmts, &interner)?;
     let type_env = crate::analysis::check_program(&stmts, &interner, &codegen_registry)
         .map_err(|e| ParseError {
             kind: e.to_parse_error_kind(&interner),
@@ -1643,6 +1772,71 @@ pub fn compile_to_rust_verified(source: &str) -> Result<String, ParseError> {
     Ok(rust_code)
 }
 
+/// Statically verify LOGOS source without generating code.
+///
+/// Runs the same passes as [`compile_to_rust_verified`] up to and including
+/// Z3 verification, proving obligations in parallel as `schedule` allows.
+/// With a `cache`, obligations it already proved are not re-sent to the
+/// solver and new proofs are recorded in it (saving the cache is the
+/// caller's job).
+///
+/// # Errors
+///
+/// Returns [`ParseError`] if parsing, escape analysis or verification fails.
+#[cfg(feature = "verification")]
+pub fn verify_source(
+    source: &str,
+    cache: Option<std::sync::Arc<logicaffeine_verify::cache::ObligationCache>>,
+    schedule: &logicaffeine_verify::schedule::ScheduleConfig,
+) -> Result<(), ParseError> {
+    use crate::verification::VerificationPass;
+
+    let mut interner = Interner::new();
+    let mut lexer = Lexer::new(source, &mut interner);
+    let tokens = lexer.tokenize();
+
+    let (type_registry, _policy_registry) = {
+        let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
+        let result = discovery.run_full();
+        (result.types, result.policies)
+    };
+
+    let mut world_state = WorldState::new();
+    let expr_arena = Arena::new();
+    let term_arena = Arena::new();
+    let np_arena = Arena::new();
+    let sym_arena = Arena::new();
+    let role_arena = Arena::new();
+    let pp_arena = Arena::new();
+    let stmt_arena: Arena<Stmt> = Arena::new();
+    let imperative_expr_arena: Arena<Expr> = Arena::new();
+    let type_expr_arena: Arena<TypeExpr> = Arena::new();
+
+    let ast_ctx = AstContext::with_types(
+        &expr_arena, &term_arena, &np_arena, &sym_arena,
+        &role_arena, &pp_arena, &stmt_arena, &imperative_expr_arena,
+        &type_expr_arena,
+    );
+
+    let mut parser = Parser::new(tokens, &mut world_state, &mut interner, ast_ctx, type_registry);
+    let stmts = parser.parse_program()?;
+
+    let mut escape_checker = EscapeChecker::new(&interner);
+    escape_checker.check_program(&stmts).map_err(|e| ParseError {
+        kind: crate::error::ParseErrorKind::Custom(e.to_string()),
+        span: e.span,
+    })?;
+
+    let mut verifier = VerificationPass::new(&interner).with_schedule(schedule.clone());
+    if let Some(cache) = cache {
+        verifier = verifier.with_cache(cache);
+    }
+    verifier.verify_program(&stmts).map_err(|e| ParseError {
+        kind: crate::error::ParseErrorKind::Custom(format!("Verification Failed:\n\n{}", e)),
+        span: crate::token::Span::default(),
+    })
+}
+
 /// Compile LOGOS source and write output to a directory as a Cargo project.
 ///
 /// Creates a complete Cargo project structure with:
@@ -1680,6 +1874,7 @@ pub fn compile_to_rust_verified(source: &str) -> Result<String, ParseError> {
 pub struct CheckArtifacts {
     pub rust_code: String,
     pub dependencies: Vec<CrateDependency>,
+    pub system_features: Vec<String>,
     pub source_map: crate::sourcemap::SourceMap,
     pub interner: Interner,
 }
@@ -1694,7 +1889,10 @@ pub struct CheckArtifacts {
 /// what only rustc can see, and a borrow error in the generated code comes
 /// back TRANSLATED rather than pre-empted.
 pub fn rustc_check_artifacts(source: &str) -> Result<CheckArtifacts, ParseError> {
-    let user_source = source;
+    // Target sections are blanked in place, so spans into the resolved text
+    // are spans into `source`.
+    let resolved = crate::loader::resolve_target_sections(source, &crate::loader::CompileTarget::host());
+    let user_source: &str = &resolved;
 
     // The stdlib prelude is PREPENDED when referenced; spans from the parse
     // are offsets into the expanded text and shift back by the prelude length.
@@ -1716,6 +1914,7 @@ pub fn rustc_check_artifacts(source: &str) -> Result<CheckArtifacts, ParseError>
         (result.types, result.policies)
     };
     let codegen_registry = type_registry.clone();
+    check_compiled_field_types(&codegen_registry, &interner)?;
     let codegen_policies = policy_registry.clone();
 
     let mut world_state = WorldState::new();
@@ -1772,11 +1971,13 @@ pub fn rustc_check_artifacts(source: &str) -> Result<CheckArtifacts, ParseError>
         None => stmts,
     };
 
+    check_compiled_builtins(&stmts, &interner)?;
     // Best-effort env: findings are the interactive pipeline's job here.
     let (type_env, _findings) =
         crate::analysis::check_program_collect(&stmts, &interner, &codegen_registry);
 
     let dependencies = extract_dependencies(&stmts, &interner)?;
+    let system_features = system_features(&stmts);
 
     let (rust_code, source_map) = crate::codegen::codegen_program_mapped(
         &stmts,
@@ -1792,6 +1993,7 @@ pub fn rustc_check_artifacts(source: &str) -> Result<CheckArtifacts, ParseError>
     Ok(CheckArtifacts {
         rust_code,
         dependencies,
+        system_features,
         source_map,
         interner,
     })
@@ -1809,7 +2011,7 @@ pub fn rustc_check_artifacts(source: &str) -> Result<CheckArtifacts, ParseError>
 /// a silent all-clear.
 pub fn rustc_check(source: &str, cache_dir: &Path) -> Result<Vec<LogosError>, CompileError> {
     let artifacts = rustc_check_artifacts(source).map_err(CompileError::Parse)?;
-    write_cargo_project(&artifacts.rust_code, &artifacts.dependencies, cache_dir)?;
+    write_cargo_project(&artifacts.rust_code, &artifacts.dependencies, &artifacts.system_features, cache_dir)?;
 
     let check_output = Command::new("cargo")
         .arg("check")
@@ -1833,7 +2035,7 @@ pub fn rustc_check(source: &str, cache_dir: &Path) -> Result<Vec<LogosError>, Co
 
 pub fn compile_to_dir(source: &str, output_dir: &Path) -> Result<(), CompileError> {
     let output = compile_program_full(source).map_err(CompileError::Parse)?;
-    write_cargo_project(&output.rust_code, &output.dependencies, output_dir)
+    write_cargo_project(&output.rust_code, &output.dependencies, &output.system_features, output_dir)
 }
 
 /// Write a generated program as a runnable cargo project: `src/main.rs`,
@@ -1843,6 +2045,7 @@ pub fn compile_to_dir(source: &str, output_dir: &Path) -> Result<(), CompileErro
 pub fn write_cargo_project(
     rust_code: &str,
     dependencies: &[CrateDependency],
+    system_features: &[String],
     output_dir: &Path,
 ) -> Result<(), CompileError> {
     // Create output directory structure
@@ -1855,15 +2058,16 @@ pub fn write_cargo_project(
     file.write_all(rust_code.as_bytes()).map_err(|e| CompileError::Io(e.to_string()))?;
 
     // Write Cargo.toml with runtime crate dependencies
-    let mut cargo_toml = String::from(r#"[package]
+    let features = toml_feature_list(system_features);
+    let mut cargo_toml = format!(r#"[package]
 name = "logos_output"
 version = "0.1.0"
 edition = "2021"
 
 [dependencies]
-logicaffeine-data = { path = "./crates/logicaffeine_data" }
-logicaffeine-system = { path = "./crates/logicaffeine_system", features = ["full"] }
-tokio = { version = "1", features = ["rt-multi-thread", "macros"] }
+logicaffeine-data = {{ path = "./crates/logicaffeine_data" }}
+logicaffeine-system = {{ path = "./crates/logicaffeine_system", features = [{features}] }}
+tokio = {{ version = "1", features = ["rt-multi-thread", "macros"] }}
 "#);
 
     // Append user-declared dependencies from ## Requires blocks — inside
@@ -1885,9 +2089,9 @@ tokio = { version = "1", features = ["rt-multi-thread", "macros"] }
         }
     }
 
-    cargo_toml.push_str(r#"
+    let _ = write!(cargo_toml, r#"
 [target.'cfg(target_os = "linux")'.dependencies]
-logicaffeine-system = { path = "./crates/logicaffeine_system", features = ["full", "io-uring"] }
+logicaffeine-system = {{ path = "./crates/logicaffeine_system", features = [{features}, "io-uring"] }}
 "#);
 
     cargo_toml.push_str("\n[profile.release]\nlto = true\nopt-level = 3\ncodegen-units = 1\npanic = \"abort\"\nstrip = true\n");
@@ -2261,49 +2465,140 @@ pub fn compile_file(path: &Path) -> Result<String, CompileError> {
 /// let result = compile_project(Path::new("/project/main.md"));
 /// ```
 pub fn compile_project(entry_file: &Path) -> Result<CompileOutput, CompileError> {
+    compile_project_inner(entry_file, false, &crate::loader::CompileTarget::host(), None)
+}
+
+/// [`compile_project`] for an explicit target: the entry file's and every
+/// imported module's `## When compiling …` sections are resolved for `target`
+/// rather than the machine running the compiler (see
+/// [`resolve_target_sections`](crate::loader::resolve_target_sections)).
+pub fn compile_project_for(entry_file: &Path, target: &crate::loader::CompileTarget) -> Result<CompileOutput, CompileError> {
+    compile_project_inner(entry_file, false, target, None)
+}
+
+/// [`compile_project_for`] with the project's kernel definitions extracted
+/// through `cache`: the `Definition`/`Inductive`/`## Theorem:` blocks of the
+/// entry file and every module it imports are extracted into `mod proven`
+/// (see [`compile_program_full_with_proven`]) only when that math changed since
+/// the last build, and linked from the cached artifact otherwise. This is what
+/// `largo build` uses, with the cache under `target/extracted`.
+pub fn compile_project_cached(
+    entry_file: &Path,
+    target: &crate::loader::CompileTarget,
+    cache: &crate::extraction::ExtractionCache,
+) -> Result<CompileOutput, CompileError> {
+    compile_project_inner(entry_file, false, target, Some(cache))
+}
+
+/// [`compile_project`] with profiling instrumentation for `largo run --profile`:
+/// every LOGOS function records its calls and time through
+/// `logicaffeine_system::profile`, and the program prints a flat profile and
+/// call tree to stderr when it exits (see [`crate::codegen::codegen_program_profiled`]).
+pub fn compile_project_profiled(entry_file: &Path) -> Result<CompileOutput, CompileError> {
+    compile_project_inner(entry_file, true, &crate::loader::CompileTarget::host(), None)
+}
+
+fn compile_project_inner(
+    entry_file: &Path,
+    profile: bool,
+    target: &crate::loader::CompileTarget,
+    cache: Option<&crate::extraction::ExtractionCache>,
+) -> Result<CompileOutput, CompileError> {
     use crate::loader::Loader;
     use crate::analysis::discover_with_imports;
 
     let root_path = entry_file.parent().unwrap_or(Path::new(".")).to_path_buf();
-    let mut loader = Loader::new(root_path);
+    let mut loader = Loader::new(root_path).with_target(target.clone());
     let mut interner = Interner::new();
 
     // Read the entry file
     let raw_source = fs::read_to_string(entry_file)
         .map_err(|e| CompileError::Io(format!("Failed to read entry file: {}", e)))?;
 
+    // Mixed-document aware, like `compile_program_full`: the entry's kernel math is
+    // set aside for extraction and only the imperative part is compiled. A pure
+    // imperative entry is kept exactly as written.
+    let (raw_source, entry_math) = match crate::ui_bridge::partition_mixed(&raw_source) {
+        (imperative, Some(math)) => (imperative, Some(math)),
+        (_, None) => (raw_source, None),
+    };
+
+    // `apply_prelude_for` leaves a malformed template as written; report it here instead.
+    crate::template::expand_templates(&crate::loader::resolve_target_sections(&raw_source, target))
+        .map_err(|e| CompileError::Io(format!("{}: {e}", entry_file.display())))?;
+
     // Auto-import the demand-driven stdlib prelude (uuid.lg, io/net/crdt/time/…) — the SAME seam the
     // string-compile and interpreter paths use (see `apply_prelude`), so `largo build` resolves stdlib
     // functions like `md5`/`sha1`/`uuidV3`/`uuidParse`/`flush`. Demand-driven + idempotent: a program
     // that references no stdlib name compiles byte-identically to before.
-    let source = crate::loader::apply_prelude(&raw_source).into_owned();
+    let source = crate::loader::apply_prelude_for(&raw_source, target).into_owned();
 
     // Discover types from entry file and all imports
+    let mut metrics = CompileMetrics::default();
+    let discovering = Stopwatch::start();
     let type_registry = discover_with_imports(entry_file, &source, &mut loader, &mut interner)
         .map_err(|e| CompileError::Io(e))?;
+    discovering.record(&mut metrics, Phase::Discovery, type_registry.iter_types().count());
+
+    // Verified functions: the math of every imported module (imports before their
+    // importers) and then the entry's, extracted into one `proven` module.
+    let mut math: Vec<String> = project_math(entry_file, &source, &mut loader);
+    math.extend(entry_math);
+    let math = math.join("\n\n");
+    let proven = if math.is_empty() {
+        None
+    } else {
+        match cache {
+            Some(cache) => cache.proven_module(&math),
+            None => crate::ui_bridge::mixed_proven_module(&math),
+        }
+    };
 
     // Now compile with the discovered types
-    compile_to_rust_with_registry_full(&source, type_registry, &mut interner)
+    compile_to_rust_with_registry_full(&source, type_registry, &mut interner, profile, proven.as_deref(), metrics)
         .map_err(CompileError::Parse)
 }
 
+/// The kernel math (`Definition`/`Inductive`/`## Theorem:` blocks) of each module
+/// `entry_file` imports, transitively, ordered so a module's imports come before it.
+/// The modules are already loaded by discovery, so this only reads the cache.
+fn project_math(entry_file: &Path, source: &str, loader: &mut crate::loader::Loader) -> Vec<String> {
+    let graph = loader.module_graph(entry_file, source);
+    graph
+        .modules()
+        .iter()
+        .rev()
+        .filter(|path| path.as_path() != entry_file)
+        .filter_map(|path| loader.module_at(path))
+        .filter_map(|module| crate::ui_bridge::partition_mixed(&module.content).1)
+        .collect()
+}
+
 /// Compile LOGOS source with a pre-populated type registry, returning full output.
 /// Returns both generated Rust code and extracted dependencies.
 fn compile_to_rust_with_registry_full(
     source: &str,
     type_registry: crate::analysis::TypeRegistry,
     interner: &mut Interner,
+    profile: bool,
+    proven: Option<&str>,
+    mut metrics: CompileMetrics,
 ) -> Result<CompileOutput, ParseError> {
+    let lexing = Stopwatch::start();
     let mut lexer = Lexer::new(source, interner);
     let tokens = lexer.tokenize();
+    lexing.record(&mut metrics, Phase::Lex, tokens.len());
 
     // Discovery pass for policies (types already discovered)
+    let discovering = Stopwatch::start();
     let policy_registry = {
         let mut discovery = DiscoveryPass::new(&tokens, interner);
         discovery.run_full().policies
     };
+    discovering.record(&mut metrics, Phase::Discovery, 0);
 
     let codegen_registry = type_registry.clone();
+    check_compiled_field_types(&codegen_registry, interner)?;
     let codegen_policies = policy_registry.clone();
 
     let mut world_state = WorldState::new();
@@ -2329,9 +2624,12 @@ fn compile_to_rust_with_registry_full(
         &type_expr_arena,
     );
 
+    let parsing = Stopwatch::start();
     let mut parser = Parser::new(tokens, &mut world_state, interner, ast_ctx, type_registry);
     let stmts = parser.parse_program()?;
+    parsing.record(&mut metrics, Phase::Parse, stmts.len());
 
+    let semantic = Stopwatch::start();
     // Run the AST optimizer in the production (largo) compile path so
     // compiled binaries get the same optimizations the test path
     // (compile_program_full) already validates: closed-form / modulus
@@ -2344,6 +2642,7 @@ fn compile_to_rust_with_registry_full(
 
     // Extract dependencies before escape analysis
     let mut dependencies = extract_dependencies(&stmts, interner)?;
+    let system_features = system_features(&stmts);
 
     // FFI: Auto-inject wasm-bindgen dependency if any function is exported for WASM
     let needs_wasm_bindgen = stmts.iter().any(|stmt| {
@@ -2369,12 +2668,22 @@ fn compile_to_rust_with_registry_full(
         }
     })?;
 
+    check_compiled_builtins(&stmts, interner)?;
     let type_env = crate::analysis::check_program(&stmts, interner, &codegen_registry)
         .map_err(|e| ParseError {
             kind: e.to_parse_error_kind(interner),
             span: crate::token::Span::default(),
         })?;
-    let rust_code = codegen_program(&stmts, &codegen_registry, &codegen_policies, interner, &type_env, &crate::optimization::OptimizationConfig::from_env());
+    // Optimization, escape analysis, type checking.
+    semantic.record(&mut metrics, Phase::Semantics, 3);
+
+    let transpiling = Stopwatch::start();
+    let cfg = crate::optimization::OptimizationConfig::from_env();
+    let rust_code = if profile {
+        crate::codegen::codegen_program_profiled_with_proven(&stmts, &codegen_registry, &codegen_policies, interner, &type_env, &cfg, proven)
+    } else {
+        crate::codegen::codegen_program_with_proven(&stmts, &codegen_registry, &codegen_policies, interner, &type_env, &cfg, "proven", proven)
+    };
 
     // Universal ABI: Generate C header + bindings if any C exports exist
     let has_c = stmts.iter().any(|stmt| {
@@ -2414,8 +2723,9 @@ fn compile_to_rust_with_registry_full(
     } else {
         (None, None)
     };
+    transpiling.record(&mut metrics, Phase::Transpile, rust_code.len());
 
-    Ok(CompileOutput { rust_code, dependencies, c_header, python_bindings, typescript_types, typescript_bindings })
+    Ok(CompileOutput { rust_code, dependencies, c_header, python_bindings, typescript_types, typescript_bindings, metrics, system_features })
 }
 
 /// Errors that can occur during the LOGOS compilation pipeline.
@@ -3315,6 +3625,21 @@ fn collect_free_vars_expr<'a>(expr: &'a Expr, interner: &Interner, bound: &HashS
                 }
             }
         }
+        Expr::Comprehension { element, value, pattern, iterable, filter, .. } => {
+            free.extend(collect_free_vars_expr(iterable, interner, bound));
+            let mut inner_bound = bound.clone();
+            match pattern {
+                Pattern::Identifier(sym) => {
+                    inner_bound.insert(interner.resolve(*sym).to_string());
+                }
+                Pattern::Tuple(syms) => {
+                    inner_bound.extend(syms.iter().map(|s| interner.resolve(*s).to_string()));
+                }
+            }
+            for e in std::iter::once(*element).chain(*value).chain(*filter) {
+                free.extend(collect_free_vars_expr(e, interner, &inner_bound));
+            }
+        }
         _ => {}
     }
     free
@@ -3336,6 +3661,22 @@ fn collect_free_vars_stmt<'a>(stmt: &'a Stmt, interner: &Interner, bound: &HashS
         Stmt::Show { object, .. } => {
             free.extend(collect_free_vars_expr(object, interner, bound));
         }
+        Stmt::Log { message, fields, .. } => {
+            free.extend(collect_free_vars_expr(message, interner, bound));
+            for (_, value) in fields {
+                free.extend(collect_free_vars_expr(value, interner, bound));
+            }
+        }
+        Stmt::RunCommand { command, timeout, .. } => {
+            free.extend(collect_free_vars_expr(command, interner, bound));
+            if let Some(timeout) = timeout {
+                free.extend(collect_free_vars_expr(timeout, interner, bound));
+            }
+        }
+        Stmt::Sip { file, sources, .. } => {
+            free.extend(collect_free_vars_expr(file, interner, bound));
+            free.extend(collect_free_vars_expr(sources, interner, bound));
+        }
         Stmt::Return { value } => {
             if let Some(v) = value {
                 free.extend(collect_free_vars_expr(v, interner, bound));
@@ -3847,6 +4188,71 @@ fn encode_stmt_src(stmt: &Stmt, counter: &mut usize, output: &mut String, intern
             let expr_var = encode_expr_src(object, counter, output, interner, variants);
             output.push_str(&format!("Let {} be a new CShow with expr {}.\n", var, expr_var));
         }
+        // `Log` is opaque output the PE must keep in place: it encodes as a statement call whose
+        // name (`Log <level>`) no user function can have, with the message then the field values.
+        Stmt::Log { level, message, fields } => {
+            let args_var = format!("callSArgs_{}", *counter);
+            *counter += 1;
+            output.push_str(&format!("Let {} be a new Seq of CExpr.\n", args_var));
+            for arg in std::iter::once(*message).chain(fields.iter().map(|(_, v)| *v)) {
+                let arg_var = encode_expr_src(arg, counter, output, interner, variants);
+                output.push_str(&format!("Push {} to {}.\n", arg_var, args_var));
+            }
+            output.push_str(&format!(
+                "Let {} be a new CCallS with name \"Log {}\" and args {}.\n",
+                var, level.as_str(), args_var
+            ));
+        }
+        // `Run the command` is opaque like `Log`: a `Run command` statement call with the command,
+        // the timeout when given, then the captured variable names as text (`output=out`, …).
+        Stmt::RunCommand { command, timeout, output: out_var, errors, exit_code } => {
+            let args_var = format!("callSArgs_{}", *counter);
+            *counter += 1;
+            output.push_str(&format!("Let {} be a new Seq of CExpr.\n", args_var));
+            for arg in std::iter::once(*command).chain(*timeout) {
+                let arg_var = encode_expr_src(arg, counter, output, interner, variants);
+                output.push_str(&format!("Push {} to {}.\n", arg_var, args_var));
+            }
+            for (label, captured) in [("output", out_var), ("errors", errors), ("exit code", exit_code)] {
+                if let Some(captured) = captured {
+                    let text_var = format!("e_{}", *counter);
+                    *counter += 1;
+                    output.push_str(&format!(
+                        "Let {} be a new CText with value \"{}={}\".\n",
+                        text_var, label, interner.resolve(*captured)
+                    ));
+                    output.push_str(&format!("Push {} to {}.\n", text_var, args_var));
+                }
+            }
+            output.push_str(&format!(
+                "Let {} be a new CCallS with name \"Run command\" and args {}.\n",
+                var, args_var
+            ));
+        }
+        // `Sip the file` likewise: a `Sip file` statement call with the file and the sources,
+        // then the progress function's name as text (`progress=report`).
+        Stmt::Sip { file, sources, progress } => {
+            let args_var = format!("callSArgs_{}", *counter);
+            *counter += 1;
+            output.push_str(&format!("Let {} be a new Seq of CExpr.\n", args_var));
+            for arg in [*file, *sources] {
+                let arg_var = encode_expr_src(arg, counter, output, interner, variants);
+                output.push_str(&format!("Push {} to {}.\n", arg_var, args_var));
+            }
+            if let Some(progress) = progress {
+                let text_var = format!("e_{}", *counter);
+                *counter += 1;
+                output.push_str(&format!(
+                    "Let {} be a new CText with value \"progress={}\".\n",
+                    text_var, interner.resolve(*progress)
+                ));
+                output.push_str(&format!("Push {} to {}.\n", text_var, args_var));
+            }
+            output.push_str(&format!(
+                "Let {} be a new CCallS with name \"Sip file\" and args {}.\n",
+                var, args_var
+            ));
+        }
         Stmt::Call { function, args } => {
             let fn_name = interner.resolve(*function);
             let args_var = format!("callSArgs_{}", *counter);
@@ -3974,6 +4380,42 @@ fn encode_stmt_src(stmt: &Stmt, counter: &mut usize, output: &mut String, intern
                         var, path_var, var_name
                     ));
                 }
+                // No core constructor for settings: bind an opaque call whose
+                // args are the optional path.
+                ReadSource::Settings { path, .. } => {
+                    let args_var = format!("callArgs_{}", *counter);
+                    *counter += 1;
+                    output.push_str(&format!("Let {} be a new Seq of CExpr.\n", args_var));
+                    if let Some(path_expr) = path {
+                        let path_var = encode_expr_src(path_expr, counter, output, interner, variants);
+                        output.push_str(&format!("Push {} to {}.\n", path_var, args_var));
+                    }
+                    let call_var = format!("e_{}", *counter);
+                    *counter += 1;
+                    output.push_str(&format!(
+                        "Let {} be a new CCall with name \"Read settings\" and args {}.\n",
+                        call_var, args_var
+                    ));
+                    output.push_str(&format!(
+                        "Let {} be a new CLet with name \"{}\" and expr {}.\n",
+                        var, var_name, call_var
+                    ));
+                }
+                ReadSource::Arguments { .. } => {
+                    let args_var = format!("callArgs_{}", *counter);
+                    *counter += 1;
+                    output.push_str(&format!("Let {} be a new Seq of CExpr.\n", args_var));
+                    let call_var = format!("e_{}", *counter);
+                    *counter += 1;
+                    output.push_str(&format!(
+                        "Let {} be a new CCall with name \"Read arguments\" and args {}.\n",
+                        call_var, args_var
+                    ));
+                    output.push_str(&format!(
+                        "Let {} be a new CLet with name \"{}\" and expr {}.\n",
+                        var, var_name, call_var
+                    ));
+                }
             }
         }
         Stmt::WriteFile { content, path } => {
@@ -4321,6 +4763,24 @@ fn encode_stmt_src(stmt: &Stmt, counter: &mut usize, output: &mut String, intern
                 var, addr_var
             ));
         }
+        // `Join the swarm` is a networking boundary like `Connect`: it encodes as `CConnectTo` with
+        // target "swarm" and the listed addresses gathered into one `CList` (so their reads are kept).
+        Stmt::JoinSwarm { using } => {
+            let items_var = format!("litems_{}", *counter);
+            *counter += 1;
+            output.push_str(&format!("Let {} be a new Seq of CExpr.\n", items_var));
+            for addrs in using.iter().filter_map(|d| d.addresses()) {
+                let addrs_var = encode_expr_src(addrs, counter, output, interner, variants);
+                output.push_str(&format!("Push {} to {}.\n", addrs_var, items_var));
+            }
+            let addr_var = format!("e_{}", *counter);
+            *counter += 1;
+            output.push_str(&format!("Let {} be a new CList with items {}.\n", addr_var, items_var));
+            output.push_str(&format!(
+                "Let {} be a new CConnectTo with addr {} and target \"swarm\".\n",
+                var, addr_var
+            ));
+        }
         Stmt::Zone { name, body, .. } => {
             let zone_name = interner.resolve(*name);
             let body_var = format!("e_{}", *counter);
@@ -5161,6 +5621,24 @@ fn decompile_expr(expr: &Expr, interner: &Interner) -> String {
             }
             format!("\"{}\"", result)
         }
+        Expr::Comprehension { kind, element, value, pattern, iterable, filter } => {
+            let binder = match pattern {
+                Pattern::Identifier(sym) => interner.resolve(*sym).to_string(),
+                Pattern::Tuple(syms) => {
+                    syms.iter().map(|s| interner.resolve(*s).to_string()).collect::<Vec<_>>().join(", ")
+                }
+            };
+            let head = if matches!(kind, CollectionKind::Set) { "the set of every" } else { "every" };
+            let mut out = format!("{} {}", head, decompile_expr(element, interner));
+            if let Some(v) = value {
+                out.push_str(&format!(" mapped to {}", decompile_expr(v, interner)));
+            }
+            out.push_str(&format!(" for {} in {}", binder, decompile_expr(iterable, interner)));
+            if let Some(f) = filter {
+                out.push_str(&format!(" where {}", decompile_expr(f, interner)));
+            }
+            out
+        }
         Expr::Slice { collection, start, end } => {
             let coll = decompile_expr(collection, interner);
             let s = decompile_expr(start, interner);
@@ -5632,6 +6110,22 @@ fn count_stmt_dispatch(stmt: &Stmt, interner: &Interner, count: &mut usize) {
             count_expr_dispatch(object, interner, count);
             count_expr_dispatch(recipient, interner, count);
         }
+        Stmt::Log { message, fields, .. } => {
+            count_expr_dispatch(message, interner, count);
+            for (_, value) in fields {
+                count_expr_dispatch(value, interner, count);
+            }
+        }
+        Stmt::RunCommand { command, timeout, .. } => {
+            count_expr_dispatch(command, interner, count);
+            if let Some(timeout) = timeout {
+                count_expr_dispatch(timeout, interner, count);
+            }
+        }
+        Stmt::Sip { file, sources, .. } => {
+            count_expr_dispatch(file, interner, count);
+            count_expr_dispatch(sources, interner, count);
+        }
         Stmt::SetField { object, value, .. } => {
             count_expr_dispatch(object, interner, count);
             count_expr_dispatch(value, interner, count);
@@ -5665,7 +6159,7 @@ fn count_stmt_dispatch(stmt: &Stmt, interner: &Interner, count: &mut usize) {
             count_block_dispatch(tasks, interner, count)
         }
         Stmt::ReadFrom { source, .. } => {
-            if let ReadSource::File(path) = source {
+            if let ReadSource::File(path) | ReadSource::Settings { path: Some(path), .. } = source {
                 count_expr_dispatch(path, interner, count);
             }
         }
@@ -5701,6 +6195,11 @@ fn count_stmt_dispatch(stmt: &Stmt, interner: &Interner, count: &mut usize) {
         Stmt::Listen { address, .. } | Stmt::ConnectTo { address, .. } | Stmt::LetPeerAgent { address, .. } => {
             count_expr_dispatch(address, interner, count)
         }
+        Stmt::JoinSwarm { using } => {
+            for addrs in using.iter().filter_map(|d| d.addresses()) {
+                count_expr_dispatch(addrs, interner, count);
+            }
+        }
         Stmt::Sleep { milliseconds } => count_expr_dispatch(milliseconds, interner, count),
         Stmt::Sync { topic, .. } => count_expr_dispatch(topic, interner, count),
         Stmt::Mount { path, .. } => count_expr_dispatch(path, interner, count),
@@ -5861,6 +6360,12 @@ fn count_expr_dispatch(expr: &Expr, interner: &Interner, count: &mut usize) {
                 }
             }
         }
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            count_expr_dispatch(iterable, interner, count);
+            for e in std::iter::once(*element).chain(*value).chain(*filter) {
+                count_expr_dispatch(e, interner, count);
+            }
+        }
     }
 }
 
@@ -6137,7 +6642,7 @@ edition = "2021"
 
 [dependencies]
 logicaffeine-data = {{ path = "{}/crates/logicaffeine_data" }}
-logicaffeine-system = {{ path = "{}/crates/logicaffeine_system", features = ["full"] }}
+logicaffeine-system = {{ path = "{}/crates/logicaffeine_system", features = [{}] }}
 tokio = {{ version = "1", features = ["rt-multi-thread", "macros"] }}
 serde = {{ version = "1", features = ["derive"] }}
 rayon = "1"
@@ -6145,6 +6650,7 @@ rayon = "1"
         pkg_name,
         workspace_root.display(),
         workspace_root.display(),
+        toml_feature_list(&compile_output.system_features),
     );
 
     std::fs::create_dir_all(project_dir.join("src"))
@@ -6815,6 +7321,32 @@ fn stmt_to_core<S: CoreSink>(stmt: &Stmt, interner: &Interner, vctors: &VCtors)
         Stmt::Let { var, value, .. } => S::inductive("CLet", vec![S::text(interner.resolve(*var)), expr_to_core::<S>(value, interner, vctors)?]),
         Stmt::Set { target, value } => S::inductive("CSet", vec![S::text(interner.resolve(*target)), expr_to_core::<S>(value, interner, vctors)?]),
         Stmt::Show { object, .. } => S::inductive("CShow", vec![expr_to_core::<S>(object, interner, vctors)?]),
+        Stmt::Log { level, message, fields } => {
+            let mut avals = vec![expr_to_core::<S>(message, interner, vctors)?];
+            for (_, value) in fields {
+                avals.push(expr_to_core::<S>(value, interner, vctors)?);
+            }
+            S::inductive("CCallS", vec![S::text(&format!("Log {}", level.as_str())), S::list(avals)])
+        }
+        Stmt::RunCommand { command, timeout, output, errors, exit_code } => {
+            let mut avals = vec![expr_to_core::<S>(command, interner, vctors)?];
+            if let Some(timeout) = timeout {
+                avals.push(expr_to_core::<S>(timeout, interner, vctors)?);
+            }
+            for (label, captured) in [("output", output), ("errors", errors), ("exit code", exit_code)] {
+                if let Some(captured) = captured {
+                    avals.push(S::inductive("CText", vec![S::text(&format!("{}={}", label, interner.resolve(*captured)))]));
+                }
+            }
+            S::inductive("CCallS", vec![S::text("Run command"), S::list(avals)])
+        }
+        Stmt::Sip { file, sources, progress } => {
+            let mut avals = vec![expr_to_core::<S>(file, interner, vctors)?, expr_to_core::<S>(sources, interner, vctors)?];
+            if let Some(progress) = progress {
+                avals.push(S::inductive("CText", vec![S::text(&format!("progress={}", interner.resolve(*progress)))]));
+            }
+            S::inductive("CCallS", vec![S::text("Sip file"), S::list(avals)])
+        }
         Stmt::SetIndex { collection, index, value } => S::inductive(
             "CSetIdx",
             vec![S::text(&extract_ident_name(collection, interner)), expr_to_core::<S>(index, interner, vctors)?, expr_to_core::<S>(value, interner, vctors)?],
@@ -7071,6 +7603,20 @@ fn stmt_to_core<S: CoreSink>(stmt: &Stmt, interner: &Interner, vctors: &VCtors)
                     "CReadFile",
                     vec![expr_to_core::<S>(path_expr, interner, vctors)?, S::text(var_name)],
                 ),
+                ReadSource::Settings { path, .. } => {
+                    let mut avals = Vec::new();
+                    if let Some(path_expr) = path {
+                        avals.push(expr_to_core::<S>(path_expr, interner, vctors)?);
+                    }
+                    S::inductive(
+                        "CLet",
+                        vec![S::text(var_name), S::inductive("CCall", vec![S::text("Read settings"), S::list(avals)])],
+                    )
+                }
+                ReadSource::Arguments { .. } => S::inductive(
+                    "CLet",
+                    vec![S::text(var_name), S::inductive("CCall", vec![S::text("Read arguments"), S::list(Vec::new())])],
+                ),
             }
         }
         Stmt::WriteFile { content, path } => S::inductive(
@@ -7086,6 +7632,13 @@ fn stmt_to_core<S: CoreSink>(stmt: &Stmt, interner: &Interner, vctors: &VCtors)
             "CListen",
             vec![expr_to_core::<S>(address, interner, vctors)?, S::text("default")],
         ),
+        Stmt::JoinSwarm { using } => {
+            let mut addrs = Vec::new();
+            for expr in using.iter().filter_map(|d| d.addresses()) {
+                addrs.push(expr_to_core::<S>(expr, interner, vctors)?);
+            }
+            S::inductive("CConnectTo", vec![S::inductive("CList", vec![S::list(addrs)]), S::text("swarm")])
+        }
         Stmt::SendMessage { message, destination, .. } => S::inductive(
             "CSendMessage",
             vec![expr_to_core::<S>(destination, interner, vctors)?, expr_to_core::<S>(message, interner, vctors)?],
@@ -81,6 +81,8 @@ pub fn materialize(value: &RuntimeValue) -> Result<RtPayload, MarshalError> {
         // A SIMD lane vector is a transient compute value (a register), not a wire type — like a
         // closure, it does not cross a task boundary. (A future increment can serialize its lanes.)
         RuntimeValue::Lanes(_) => return Err(MarshalError::NotSendable("Lanes8Word32")),
+        // A pattern owns a compiled engine; send its source text and rebuild it on the other side.
+        RuntimeValue::Pattern(_) => return Err(MarshalError::NotSendable("Pattern")),
         RuntimeValue::List(items) => {
             let vals = items.borrow().to_values();
             RtPayload::List(vals.iter().map(materialize).collect::<Result<_, _>>()?)
@@ -4326,6 +4328,9 @@ fn native_encode(v: &RuntimeValue, out: &mut Vec<u8>) -> Result<(), String> {
         RuntimeValue::Lanes(_) => {
             return Err("a SIMD lane vector is a transient compute value, not a wire type".to_string());
         }
+        RuntimeValue::Pattern(_) => {
+            return Err("a pattern is a compiled matcher, not a wire type; send its source text".to_string());
+        }
         // An out-of-i64 integer: ship sign + length + little-endian magnitude bytes —
         // exact (no base conversion), the typed alternative to a lossy JSON number.
         RuntimeValue::BigInt(b) => {
@@ -8255,7 +8260,9 @@ mod tests {
             // crypto kernels compute over it), but NOT a wire type — you serialize the underlying
             // `Seq`, never the register. So it is not wire-round-tripped (hence Opaque here), yet it
             // IS AOT-wired to a concrete Rust type (declared in `aot_wiring` below).
-            | RuntimeValue::Lanes(_) => ConfClass::Opaque,
+            | RuntimeValue::Lanes(_)
+            // A compiled pattern is a matcher, not data: it travels as its source text.
+            | RuntimeValue::Pattern(_) => ConfClass::Opaque,
         }
     }
 
@@ -8342,6 +8349,7 @@ mod tests {
             RuntimeValue::Quantity(_) => Rust("LogosQuantity"),
             RuntimeValue::Money(_) => Rust("LogosMoney"),
             RuntimeValue::Uuid(_) => Rust("LogosUuid"),
+            RuntimeValue::Pattern(_) => Rust("LogosPattern"),
             // A peer is a runtime networking handle — it round-trips on the wire but is not a
             // compiled-tier value type.
             RuntimeValue::Peer(_) => RuntimeOnly,
@@ -63,6 +63,9 @@ pub(crate) struct NetInbox {
     /// δ-CRDT `Sync`: the causal version we have already shipped to each topic, so the next sync
     /// publishes only the DELTA since then (not the whole set/sequence). Empty until first sync.
     pub sync_versions: HashMap<String, logicaffeine_data::crdt::VClock>,
+    /// The version each CRDT replica last published with `Sync`, on any topic — what
+    /// `the sync status of x` compares against to report unpublished changes.
+    pub synced: HashMap<logicaffeine_data::crdt::ReplicaId, logicaffeine_data::crdt::VClock>,
     /// OFFLINE loopback outbox — with no relay (the VM/wasm-AOT determinism oracles), `publish` queues
     /// the framed bytes HERE instead of dropping them, and `drain` feeds them back through the normal
     /// decode path (topic-filtered to our own inbox). This makes a single-node `Send … to <self>` then
@@ -115,6 +118,7 @@ impl NetInbox {
             handshaked: std::collections::HashSet::new(),
             my_registry: WireTypeRegistry::new(Vec::new()),
             sync_versions: HashMap::new(),
+            synced: HashMap::new(),
             offline_loopback: VecDeque::new(),
         }
     }
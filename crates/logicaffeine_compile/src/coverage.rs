@@ -0,0 +1,265 @@
+//! Statement coverage for the tree-walking interpreter.
+//!
+//! Run a program with
+//! [`interpret_with_coverage`](crate::ui_bridge::interpret_with_coverage) and
+//! every statement counts its executions; the counts are folded onto source
+//! lines through the parser's statement-span index, so a statement nested in
+//! an `Otherwise` arm or a function nobody calls still shows up — with a zero.
+//!
+//! # Accounting
+//!
+//! A line is *coverable* when a statement starts on it. Its count is the
+//! largest execution count among those statements (a line holding
+//! `Let x be 1. Let y be 2.` ran once, not twice). An `If` or `While` counts
+//! the times its condition was reached; the lines of its arms count
+//! themselves, which is what exposes the branch a test suite never took.
+//!
+//! Counting is opt-in and costs one branch per statement when off. Runs are
+//! UNoptimized, so the lines reported are the ones written.
+//!
+//! # Compiled programs
+//!
+//! [`compile_with_coverage`] compiles with the optimizer off and a counter
+//! bumped at the start of every statement (`logicaffeine_system::coverage`);
+//! the binary writes the counts to `LOGOS_COVERAGE_FILE` when `Main` ends, and
+//! [`CompiledCoverage::report`] folds them onto the same lines. Reports from
+//! both tiers [`merge`](CoverageReport::merge) into one.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ast::stmt::{Block, Stmt};
+use crate::compile::CompileOutput;
+use crate::error::ParseError;
+use crate::token::Span;
+
+/// Execution counts per source line, for the lines that hold statements.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoverageReport {
+    /// 1-based line → how many times its statements ran (0 = never).
+    pub lines: BTreeMap<usize, u64>,
+}
+
+impl CoverageReport {
+    /// Record that a statement starting on `line` ran `count` times.
+    pub fn record(&mut self, line: usize, count: u64) {
+        let entry = self.lines.entry(line).or_insert(0);
+        *entry = (*entry).max(count);
+    }
+
+    /// Fold in another run over the same source: counts add up.
+    pub fn merge(&mut self, other: &CoverageReport) {
+        for (&line, &count) in &other.lines {
+            *self.lines.entry(line).or_insert(0) += count;
+        }
+    }
+
+    /// Lines that ran at least once.
+    pub fn covered(&self) -> usize {
+        self.lines.values().filter(|&&count| count > 0).count()
+    }
+
+    /// Lines that hold statements.
+    pub fn coverable(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// The lines that hold statements and never ran, in order.
+    pub fn uncovered(&self) -> Vec<usize> {
+        self.lines.iter().filter(|(_, &count)| count == 0).map(|(&line, _)| line).collect()
+    }
+
+    /// `source` with a gcov-style gutter: the execution count of each
+    /// coverable line, `#####` for one that never ran, `-` for the rest.
+    pub fn annotate(&self, source: &str) -> String {
+        let mut out = String::new();
+        for (index, text) in source.lines().enumerate() {
+            let gutter = match self.lines.get(&(index + 1)) {
+                Some(0) => "#####".to_string(),
+                Some(count) => count.to_string(),
+                None => "-".to_string(),
+            };
+            out.push_str(&format!("{gutter:>9}: {:>4}: {text}\n", index + 1));
+        }
+        out
+    }
+
+    /// One lcov `SF:` record for `path` (append records to build a
+    /// multi-file `lcov.info`).
+    pub fn to_lcov(&self, path: &str) -> String {
+        let mut out = format!("SF:{path}\n");
+        for (line, count) in &self.lines {
+            out.push_str(&format!("DA:{line},{count}\n"));
+        }
+        out.push_str(&format!("LH:{}\nLF:{}\nend_of_record\n", self.covered(), self.coverable()));
+        out
+    }
+}
+
+/// Give the statements of `new` — a rewrite of `old` that changed
+/// expressions but kept every block's shape (division resolution) — the spans
+/// of their originals, so counts on the rewritten tree still land on lines.
+/// Blocks whose lengths differ are left unmapped.
+pub(crate) fn carry_spans(old: Block<'_>, new: Block<'_>, index: &mut HashMap<usize, Span>) {
+    if old.len() != new.len() {
+        return;
+    }
+    for (before, after) in old.iter().zip(new) {
+        if let Some(&span) = index.get(&(before as *const Stmt as usize)) {
+            index.insert(after as *const Stmt as usize, span);
+        }
+        for (b, a) in child_blocks(before).into_iter().zip(child_blocks(after)) {
+            carry_spans(b, a, index);
+        }
+    }
+}
+
+/// The statement blocks nested directly in `stmt`.
+fn child_blocks<'a>(stmt: &Stmt<'a>) -> Vec<Block<'a>> {
+    match stmt {
+        Stmt::If { then_block, else_block, .. } => {
+            std::iter::once(*then_block).chain(*else_block).collect()
+        }
+        Stmt::While { body, .. }
+        | Stmt::Repeat { body, .. }
+        | Stmt::Zone { body, .. }
+        | Stmt::Splice { body }
+        | Stmt::FunctionDef { body, .. } => vec![*body],
+        Stmt::Concurrent { tasks } | Stmt::Parallel { tasks } => vec![*tasks],
+        Stmt::Inspect { arms, .. } => arms.iter().map(|arm| arm.body).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// The 1-based line of the user's source a statement starts on. `ran` is the
+/// program that was parsed, whose first `prefix_len` bytes (an implicit
+/// `## Main`, auto-imported stdlib modules) are not the user's.
+fn user_line(ran: &str, prefix_len: usize, span: Span) -> Option<usize> {
+    if span.start < prefix_len || span.start > ran.len() {
+        return None;
+    }
+    let prefix_lines = ran[..prefix_len].matches('\n').count();
+    Some(ran[..span.start].matches('\n').count() + 1 - prefix_lines)
+}
+
+/// Fold per-statement execution counts onto the lines of the user's source.
+/// `spans` index the program that ran (see [`user_line`]).
+pub(crate) fn report_lines(
+    ran: &str,
+    prefix_len: usize,
+    spans: &HashMap<usize, Span>,
+    counts: &HashMap<usize, u64>,
+) -> CoverageReport {
+    let mut report = CoverageReport::default();
+    for (address, span) in spans {
+        if let Some(line) = user_line(ran, prefix_len, *span) {
+            report.record(line, counts.get(address).copied().unwrap_or(0));
+        }
+    }
+    report
+}
+
+thread_local! {
+    /// Set while [`compile_with_coverage`] runs: the user line of each probe
+    /// the compile declared, in probe order.
+    static INSTRUMENTING: RefCell<Option<Vec<usize>>> = const { RefCell::new(None) };
+}
+
+/// Whether the compile in progress is instrumenting for coverage.
+pub(crate) fn instrumenting() -> bool {
+    INSTRUMENTING.with(|i| i.borrow().is_some())
+}
+
+/// Give a probe to every statement of `spans` on a user line, in source
+/// order, and remember the lines for [`compile_with_coverage`]. Returns the
+/// probes by statement address, for the codegen.
+pub(crate) fn plan_probes(ran: &str, prefix_len: usize, spans: &HashMap<usize, Span>) -> HashMap<usize, usize> {
+    let mut placed: Vec<(usize, usize, usize)> = spans
+        .iter()
+        .filter_map(|(&address, &span)| Some((span.start, address, user_line(ran, prefix_len, span)?)))
+        .collect();
+    placed.sort_unstable();
+    let lines = placed.iter().map(|&(_, _, line)| line).collect();
+    INSTRUMENTING.with(|i| *i.borrow_mut() = Some(lines));
+    placed.iter().enumerate().map(|(probe, &(_, address, _))| (address, probe)).collect()
+}
+
+/// The probes of a coverage-instrumented binary: the user line of each.
+#[derive(Debug, Clone, Default)]
+pub struct CompiledCoverage {
+    lines: Vec<usize>,
+}
+
+impl CompiledCoverage {
+    /// Fold the counts the binary wrote to `LOGOS_COVERAGE_FILE` (one per
+    /// line, in probe order) onto source lines.
+    pub fn report(&self, counts: &str) -> CoverageReport {
+        let mut report = CoverageReport::default();
+        let mut counts = counts.lines().map(|c| c.trim().parse::<u64>().unwrap_or(0));
+        for &line in &self.lines {
+            report.record(line, counts.next().unwrap_or(0));
+        }
+        report
+    }
+}
+
+/// Compile `source` like [`compile_program_full`](crate::compile::compile_program_full),
+/// but UNoptimized and with a coverage counter at the start of every
+/// statement. Build it with `logicaffeine_system`, run it with
+/// `LOGOS_COVERAGE_FILE` set, and read the counts back through the returned
+/// [`CompiledCoverage`].
+pub fn compile_with_coverage(source: &str) -> Result<(CompileOutput, CompiledCoverage), ParseError> {
+    let prev = INSTRUMENTING.with(|i| i.replace(Some(Vec::new())));
+    let output = crate::compile::compile_program_full(source);
+    let lines = INSTRUMENTING.with(|i| i.replace(prev)).unwrap_or_default();
+    output.map(|output| (output, CompiledCoverage { lines }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(lines: &[(usize, u64)]) -> CoverageReport {
+        let mut r = CoverageReport::default();
+        for &(line, count) in lines {
+            r.record(line, count);
+        }
+        r
+    }
+
+    #[test]
+    fn lines_keep_the_largest_count_and_runs_add_up() {
+        let mut r = report(&[(2, 1), (2, 3), (4, 0)]);
+        assert_eq!(r.lines[&2], 3);
+        r.merge(&report(&[(2, 1), (4, 2)]));
+        assert_eq!(r.lines[&2], 4);
+        assert_eq!(r.lines[&4], 2);
+    }
+
+    #[test]
+    fn annotation_and_lcov_mark_untaken_lines() {
+        let r = report(&[(2, 1), (3, 0)]);
+        let annotated = r.annotate("## Main\nIf 1 > 2:\n    Show 1.\n");
+        assert_eq!(
+            annotated,
+            "        -:    1: ## Main\n        1:    2: If 1 > 2:\n    #####:    3:     Show 1.\n"
+        );
+        assert_eq!(r.uncovered(), vec![3]);
+        assert_eq!(r.to_lcov("src/main.lg"), "SF:src/main.lg\nDA:2,1\nDA:3,0\nLH:1\nLF:2\nend_of_record\n");
+    }
+
+    #[test]
+    fn compiled_probes_fold_onto_the_written_lines() {
+        let source = "## Main\nLet mutable i be 0.\nWhile i is less than 3:\n    Set i to i + 1.\nIf i is greater than 5:\n    Show i.\n";
+        let (output, probes) = compile_with_coverage(source).unwrap();
+        assert!(output.rust_code.contains("logicaffeine_system::coverage::Counters<5>"), "{}", output.rust_code);
+        assert!(output.rust_code.contains("let _logos_coverage = __LOGOS_COVERAGE.session();"), "{}", output.rust_code);
+        assert!(output.rust_code.contains("__LOGOS_COVERAGE.hit(4);"), "{}", output.rust_code);
+        assert!(!crate::compile::compile_program_full(source).unwrap().rust_code.contains("__LOGOS_COVERAGE"));
+
+        let report = probes.report("1\n1\n3\n1\n0\n");
+        assert_eq!(report.lines, BTreeMap::from([(2, 1), (3, 1), (4, 3), (5, 1), (6, 0)]));
+    }
+}
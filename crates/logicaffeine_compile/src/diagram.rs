@@ -0,0 +1,163 @@
+//! Graphviz and Mermaid diagrams.
+//!
+//! Text exporters for two structures worth drawing:
+//!
+//! - **Syntax trees** — an [`AstNode`] as parsed from an English sentence
+//!   ([`ast_to_dot`], [`ast_to_mermaid`])
+//! - **Module graphs** — which modules a project's entry file imports,
+//!   transitively, as [`Loader::module_graph`](crate::loader::Loader::module_graph)
+//!   resolves them ([`modules_to_dot`], [`modules_to_mermaid`])
+//!
+//! DOT renders with `dot -Tsvg`; Mermaid renders inline in Markdown on most
+//! documentation hosts, so either can be pasted straight into docs.
+//!
+//! # Example
+//!
+//! ```
+//! use logicaffeine_compile::diagram::{ast_to_dot, ast_to_mermaid};
+//! use logicaffeine_compile::AstNode;
+//!
+//! let tree = AstNode::with_children("∀x", "quantifier", vec![AstNode::leaf("Cat(1)", "predicate")]);
+//! assert!(ast_to_dot(&tree).contains("n0 -> n1;"));
+//! assert!(ast_to_mermaid(&tree).contains("n0 --> n1"));
+//! ```
+
+use std::fmt::Write;
+
+use crate::loader::ModuleGraph;
+use crate::ui_bridge::AstNode;
+
+/// A syntax tree as a Graphviz digraph, root first. Each node's tooltip is
+/// its node type.
+pub fn ast_to_dot(root: &AstNode) -> String {
+    let mut out = String::from("digraph ast {\n    node [shape=box, fontname=\"monospace\"];\n");
+    let mut next = 0;
+    write_dot_node(root, &mut next, &mut out);
+    out.push_str("}\n");
+    out
+}
+
+fn write_dot_node(node: &AstNode, next: &mut usize, out: &mut String) -> usize {
+    let id = *next;
+    *next += 1;
+    let _ = writeln!(out, "    n{id} [label=\"{}\", tooltip=\"{}\"];", dot_escape(&node.label), dot_escape(&node.node_type));
+    for child in &node.children {
+        let child_id = write_dot_node(child, next, out);
+        let _ = writeln!(out, "    n{id} -> n{child_id};");
+    }
+    id
+}
+
+/// A syntax tree as a top-down Mermaid flowchart. Nodes carry their node
+/// type as a class, for `classDef` styling.
+pub fn ast_to_mermaid(root: &AstNode) -> String {
+    let mut out = String::from("graph TD\n");
+    let mut next = 0;
+    write_mermaid_node(root, &mut next, &mut out);
+    out
+}
+
+fn write_mermaid_node(node: &AstNode, next: &mut usize, out: &mut String) -> usize {
+    let id = *next;
+    *next += 1;
+    let _ = writeln!(out, "    n{id}[\"{}\"]:::{}", mermaid_escape(&node.label), node.node_type);
+    for child in &node.children {
+        let child_id = write_mermaid_node(child, next, out);
+        let _ = writeln!(out, "    n{id} --> n{child_id}");
+    }
+    id
+}
+
+/// A module graph as a left-to-right Graphviz digraph, edges labeled by
+/// import alias. Modules are named relative to the entry's directory.
+pub fn modules_to_dot(graph: &ModuleGraph) -> String {
+    let mut out = String::from("digraph modules {\n    rankdir=LR;\n    node [shape=box];\n");
+    for id in 0..graph.modules().len() {
+        let _ = writeln!(out, "    n{id} [label=\"{}\"];", dot_escape(&graph.name(id)));
+    }
+    for import in graph.imports() {
+        let _ = writeln!(out, "    n{} -> n{} [label=\"{}\"];", import.from, import.to, dot_escape(&import.alias));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// A module graph as a left-to-right Mermaid flowchart, edges labeled by
+/// import alias.
+pub fn modules_to_mermaid(graph: &ModuleGraph) -> String {
+    let mut out = String::from("graph LR\n");
+    for id in 0..graph.modules().len() {
+        let _ = writeln!(out, "    n{id}[\"{}\"]", mermaid_escape(&graph.name(id)));
+    }
+    for import in graph.imports() {
+        let _ = writeln!(out, "    n{} -->|\"{}\"| n{}", import.from, mermaid_escape(&import.alias), import.to);
+    }
+    out
+}
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn mermaid_escape(text: &str) -> String {
+    text.replace('"', "#quot;").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loader::Loader;
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+
+    fn tree() -> AstNode {
+        AstNode::with_children(
+            "∀x",
+            "quantifier",
+            vec![AstNode::with_children(
+                "→",
+                "binary_op",
+                vec![AstNode::leaf("Cat(1)", "predicate"), AstNode::leaf("Sleep(1)", "predicate")],
+            )],
+        )
+    }
+
+    #[test]
+    fn ast_exports_number_nodes_in_preorder() {
+        let dot = ast_to_dot(&tree());
+        assert!(dot.starts_with("digraph ast {"), "{dot}");
+        assert!(dot.contains("n0 [label=\"∀x\", tooltip=\"quantifier\"];"), "{dot}");
+        for edge in ["n0 -> n1;", "n1 -> n2;", "n1 -> n3;"] {
+            assert!(dot.contains(edge), "missing {edge}: {dot}");
+        }
+
+        let mermaid = ast_to_mermaid(&tree());
+        assert!(mermaid.starts_with("graph TD\n"), "{mermaid}");
+        assert!(mermaid.contains("n3[\"Sleep(1)\"]:::predicate"), "{mermaid}");
+        assert!(mermaid.contains("n1 --> n3"), "{mermaid}");
+    }
+
+    #[test]
+    fn labels_are_escaped() {
+        let node = AstNode::leaf("say \"hi\"", "constant");
+        assert!(ast_to_dot(&node).contains(r#"label="say \"hi\"""#));
+        assert!(ast_to_mermaid(&node).contains("say #quot;hi#quot;"));
+    }
+
+    #[test]
+    fn module_graph_exports_name_modules_from_the_entry() {
+        let files = HashMap::from([
+            (PathBuf::from("/project/geo.md"), "# Geo\n\nUses [Units](units.md).\n".to_string()),
+            (PathBuf::from("/project/units.md"), "# Units\n\nNo imports.\n".to_string()),
+        ]);
+        let mut loader = Loader::with_files(PathBuf::from("/project"), files);
+        let main = "# Main\n\nUses [Geometry](geo.md) and [Units](units.md).\n";
+        let graph = loader.module_graph(Path::new("/project/main.md"), main);
+
+        let dot = modules_to_dot(&graph);
+        for line in ["n0 [label=\"main.md\"];", "n2 [label=\"units.md\"];", "n0 -> n1 [label=\"Geometry\"];", "n1 -> n2 [label=\"Units\"];"] {
+            assert!(dot.contains(line), "missing {line}: {dot}");
+        }
+        assert!(modules_to_mermaid(&graph).contains("n0 -->|\"Units\"| n2"));
+    }
+}
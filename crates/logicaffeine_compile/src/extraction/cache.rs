@@ -0,0 +1,65 @@
+//! On-disk cache of extracted `proven` modules for project builds.
+//!
+//! Replaying a proof file through the kernel and extracting it is the slow
+//! part of linking verified functions into a program, and the result only
+//! depends on the math source. Each artifact is stored as
+//! `<dir>/<fingerprint>.rs`, keyed by the math source and the compiler
+//! version, so an unchanged proof is linked from the cache and an edited one
+//! (or a new compiler) extracts afresh. A math source with nothing to extract
+//! is cached as an empty artifact.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// A directory of extracted modules, one file per distinct math source.
+#[derive(Debug, Clone)]
+pub struct ExtractionCache {
+    dir: PathBuf,
+}
+
+impl ExtractionCache {
+    /// A cache rooted at `dir`, created on first write.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        ExtractionCache { dir: dir.into() }
+    }
+
+    /// The cache directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Where the artifact for `math_src` lives, whether or not it exists yet.
+    pub fn artifact_path(&self, math_src: &str) -> PathBuf {
+        let mut hasher = DefaultHasher::new();
+        env!("CARGO_PKG_VERSION").hash(&mut hasher);
+        math_src.hash(&mut hasher);
+        self.dir.join(format!("{:016x}.rs", hasher.finish()))
+    }
+
+    /// The `proven` module body for `math_src` — from the cache when present,
+    /// otherwise extracted and stored. `None` when nothing is extractable.
+    ///
+    /// The cache is best effort: an unreadable artifact is re-extracted, and a
+    /// failed write only costs the next build another extraction.
+    pub fn proven_module(&self, math_src: &str) -> Option<String> {
+        let path = self.artifact_path(math_src);
+        if let Ok(cached) = fs::read_to_string(&path) {
+            return if cached.trim().is_empty() { None } else { Some(cached) };
+        }
+        let module = crate::ui_bridge::mixed_proven_module(math_src);
+        let _ = self.store(&path, module.as_deref().unwrap_or(""));
+        module
+    }
+
+    /// Write through a temporary file so a concurrent build never reads a
+    /// half-written artifact.
+    fn store(&self, path: &Path, contents: &str) -> std::io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let tmp = path.with_extension(format!("rs.{}.tmp", std::process::id()));
+        fs::write(&tmp, contents)?;
+        fs::rename(&tmp, path)
+    }
+}
+
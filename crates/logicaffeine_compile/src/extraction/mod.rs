@@ -17,12 +17,14 @@
 //! For example, `Nat` becomes `enum Nat { Zero, Succ(Box<Nat>) }`.
 //! This guarantees that the extracted code is isomorphic to the verified logic.
 
+mod cache;
 mod codegen;
 mod collector;
 mod error;
 pub mod fol_model;
 pub mod verilog;
 
+pub use cache::ExtractionCache;
 pub use codegen::{emit_property_check, emit_value, primitive_rust_type};
 pub use collector::{is_extractable, is_logical_type};
 pub use error::ExtractError;
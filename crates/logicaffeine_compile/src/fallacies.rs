@@ -0,0 +1,353 @@
+//! Formal fallacy templates.
+//!
+//! [`detect_fallacies`] matches compiled premises and a conclusion against the
+//! classic invalid argument forms and explains each match in terms of the
+//! argument's own formulas:
+//!
+//! ```text
+//! ∀x(man(x) → mortal(x)), mortal(Socrates) ∴ man(Socrates)
+//!   → affirming the consequent (P → Q, Q ∴ P), premises [0, 1]
+//! ```
+//!
+//! Matching is purely structural, so it does not decide validity: a
+//! biconditional premise elsewhere can rescue an argument whose shape matches.
+//! [`analyze_argument`](crate::analyze_argument) only consults it once a
+//! countermodel has shown the argument invalid; the Learn grader uses it to
+//! say *why* an argument a learner accepted is bad.
+
+use logicaffeine_language::proof_convert::instantiate_var_with_constant;
+use logicaffeine_proof::grounding::domain_constants;
+use logicaffeine_proof::{ProofExpr, ProofTerm};
+
+/// A named invalid argument form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fallacy {
+    /// `P → Q`, `Q` ∴ `P`.
+    AffirmingTheConsequent,
+    /// `P → Q`, `¬P` ∴ `¬Q`.
+    DenyingTheAntecedent,
+    /// All A are M, all C are M ∴ all A are C.
+    UndistributedMiddle,
+    /// All A are B ∴ all B are A.
+    IllicitConversion,
+    /// `∀x∃y R(x, y)` ∴ `∃y∀x R(x, y)`.
+    QuantifierShift,
+}
+
+impl Fallacy {
+    /// The textbook name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Fallacy::AffirmingTheConsequent => "affirming the consequent",
+            Fallacy::DenyingTheAntecedent => "denying the antecedent",
+            Fallacy::UndistributedMiddle => "undistributed middle",
+            Fallacy::IllicitConversion => "illicit conversion",
+            Fallacy::QuantifierShift => "quantifier shift",
+        }
+    }
+
+    /// The invalid form, schematically.
+    pub fn pattern(self) -> &'static str {
+        match self {
+            Fallacy::AffirmingTheConsequent => "P → Q, Q ∴ P",
+            Fallacy::DenyingTheAntecedent => "P → Q, ¬P ∴ ¬Q",
+            Fallacy::UndistributedMiddle => "∀x(A(x) → M(x)), ∀x(C(x) → M(x)) ∴ ∀x(A(x) → C(x))",
+            Fallacy::IllicitConversion => "∀x(A(x) → B(x)) ∴ ∀x(B(x) → A(x))",
+            Fallacy::QuantifierShift => "∀x∃y R(x, y) ∴ ∃y∀x R(x, y)",
+        }
+    }
+}
+
+/// One fallacy template matched against an argument.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FallacyMatch {
+    pub fallacy: Fallacy,
+    /// Indices of the premises the template matched, in input order.
+    pub premises: Vec<usize>,
+    /// Why the form fails, phrased over this argument's formulas.
+    pub explanation: String,
+}
+
+/// Every fallacy template that `premises` ∴ `conclusion` matches.
+pub fn detect_fallacies(premises: &[ProofExpr], conclusion: &ProofExpr) -> Vec<FallacyMatch> {
+    let conditionals: Vec<(usize, Conditional)> = premises
+        .iter()
+        .enumerate()
+        .filter_map(|(i, p)| Conditional::of(p).map(|c| (i, c)))
+        .collect();
+    let mut out = Vec::new();
+
+    for (i, c) in &conditionals {
+        let affirmed = premises.iter().position(|fact| c.matches(fact, conclusion, |(a, q)| (q, a)));
+        if let Some(j) = affirmed {
+            out.push(FallacyMatch {
+                fallacy: Fallacy::AffirmingTheConsequent,
+                premises: sorted(*i, j),
+                explanation: format!(
+                    "{} runs from antecedent to consequent, not back: {} can hold for some other reason, so it does not establish {}.",
+                    premises[*i], premises[j], conclusion
+                ),
+            });
+            break;
+        }
+    }
+
+    for (i, c) in &conditionals {
+        let negate = |e: ProofExpr| ProofExpr::Not(Box::new(e));
+        let denied = premises.iter().position(|fact| c.matches(fact, conclusion, |(a, q)| (negate(a), negate(q))));
+        if let Some(j) = denied {
+            out.push(FallacyMatch {
+                fallacy: Fallacy::DenyingTheAntecedent,
+                premises: sorted(*i, j),
+                explanation: format!(
+                    "{} says nothing about what happens once its antecedent fails: given {}, the consequent can still hold, so {} does not follow.",
+                    premises[*i], premises[j], conclusion
+                ),
+            });
+            break;
+        }
+    }
+
+    let general: Vec<(usize, ProofExpr, ProofExpr)> = conditionals
+        .iter()
+        .filter(|(_, c)| c.variable.is_some())
+        .map(|(i, c)| {
+            let (a, b) = c.about(ARBITRARY);
+            (*i, a, b)
+        })
+        .collect();
+    if let Some(conclusion) = Conditional::of(conclusion).filter(|c| c.variable.is_some()) {
+        let (subject, predicate) = conclusion.about(ARBITRARY);
+        let middle = general.iter().find_map(|(i, a, m)| {
+            general
+                .iter()
+                .find(|(j, c, m2)| j != i && m == m2 && a == &subject && c == &predicate)
+                .map(|(j, _, _)| (*i, *j, m))
+        });
+        if let Some((i, j, m)) = middle {
+            out.push(FallacyMatch {
+                fallacy: Fallacy::UndistributedMiddle,
+                premises: sorted(i, j),
+                explanation: format!(
+                    "{} and {} both fall under {}, but neither premise relates them: two things can share a property without one including the other.",
+                    head(&subject),
+                    head(&predicate),
+                    head(m)
+                ),
+            });
+        }
+        if let Some((i, _, _)) = general.iter().find(|(_, a, b)| a == &predicate && b == &subject) {
+            out.push(FallacyMatch {
+                fallacy: Fallacy::IllicitConversion,
+                premises: vec![*i],
+                explanation: format!(
+                    "{} puts every {} among the {}, not every {} among the {}.",
+                    premises[*i],
+                    head(&predicate),
+                    head(&subject),
+                    head(&subject),
+                    head(&predicate)
+                ),
+            });
+        }
+    }
+
+    if let Some(i) = premises.iter().position(|p| shifts_quantifiers(p, conclusion)) {
+        out.push(FallacyMatch {
+            fallacy: Fallacy::QuantifierShift,
+            premises: vec![i],
+            explanation: format!(
+                "{} lets each choice of the universal have its own witness; {} needs a single witness that works for all of them at once.",
+                premises[i], conclusion
+            ),
+        });
+    }
+    out
+}
+
+fn sorted(i: usize, j: usize) -> Vec<usize> {
+    if i <= j {
+        vec![i, j]
+    } else {
+        vec![j, i]
+    }
+}
+
+/// The predicate name of a simple property, or the formula itself.
+fn head(e: &ProofExpr) -> String {
+    match e {
+        ProofExpr::Predicate { name, .. } => name.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// A placeholder individual for comparing universal conditionals.
+const ARBITRARY: &str = "_";
+
+/// A conditional premise: `P → Q`, or `∀x(P(x) → Q(x))` with its variable.
+struct Conditional<'e> {
+    variable: Option<&'e str>,
+    antecedent: &'e ProofExpr,
+    consequent: &'e ProofExpr,
+}
+
+impl<'e> Conditional<'e> {
+    fn of(e: &'e ProofExpr) -> Option<Self> {
+        match e {
+            ProofExpr::Implies(a, c) => Some(Conditional { variable: None, antecedent: a, consequent: c }),
+            ProofExpr::ForAll { variable, body } => match body.as_ref() {
+                ProofExpr::Implies(a, c) => Some(Conditional {
+                    variable: Some(variable),
+                    antecedent: a,
+                    consequent: c,
+                }),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Antecedent and consequent about `individual` (unchanged when the
+    /// conditional is not universal).
+    fn about(&self, individual: &str) -> (ProofExpr, ProofExpr) {
+        match self.variable {
+            Some(v) => (
+                instantiate_var_with_constant(self.antecedent, v, individual),
+                instantiate_var_with_constant(self.consequent, v, individual),
+            ),
+            None => (self.antecedent.clone(), self.consequent.clone()),
+        }
+    }
+
+    /// Does `fact` instantiate one side of this conditional and `goal` the
+    /// other, about the same individual? `pick` chooses the sides.
+    fn matches(&self, fact: &ProofExpr, goal: &ProofExpr, pick: impl Fn((ProofExpr, ProofExpr)) -> (ProofExpr, ProofExpr)) -> bool {
+        let individuals: Vec<String> = match self.variable {
+            Some(_) => domain_constants(&[fact.clone(), goal.clone()])
+                .into_iter()
+                .filter_map(|t| match t {
+                    ProofTerm::Constant(c) => Some(c),
+                    _ => None,
+                })
+                .collect(),
+            None => vec![String::new()],
+        };
+        individuals.iter().any(|c| {
+            let (from, to) = pick(self.about(c));
+            &from == fact && &to == goal
+        })
+    }
+}
+
+/// Does `premise` bind `∀x … ∃y` where `conclusion` binds `∃y … ∀x` over the
+/// same literals? Restricted quantifiers count, so "every boy loves a girl" ∴
+/// "some girl is loved by every boy" matches.
+fn shifts_quantifiers(premise: &ProofExpr, conclusion: &ProofExpr) -> bool {
+    let (Some(outer), Some(inner)) = (quantifier_prefix(premise), quantifier_prefix(conclusion)) else {
+        return false;
+    };
+    let (every, some) = match outer.as_slice() {
+        [(true, x), (false, y)] => (x, y),
+        _ => return false,
+    };
+    let (some2, every2) = match inner.as_slice() {
+        [(false, y), (true, x)] => (y, x),
+        _ => return false,
+    };
+    // Literals, not the whole formula: substitution stops at the binders.
+    let pin = |e: &ProofExpr, x: &str, y: &str| {
+        let mut lits = Vec::new();
+        literals(e, &mut lits);
+        let mut pinned: Vec<String> = lits
+            .iter()
+            .map(|l| {
+                let l = instantiate_var_with_constant(l, x, "_every");
+                instantiate_var_with_constant(&l, y, "_some").to_string()
+            })
+            .collect();
+        pinned.sort();
+        pinned
+    };
+    let lits = pin(premise, every, some);
+    !lits.is_empty() && lits == pin(conclusion, every2, some2)
+}
+
+/// The quantifiers of `e` in nesting order (`true` for ∀), if they form a
+/// single chain.
+fn quantifier_prefix(e: &ProofExpr) -> Option<Vec<(bool, String)>> {
+    match e {
+        ProofExpr::ForAll { variable, body } | ProofExpr::Exists { variable, body } => {
+            let mut prefix = vec![(matches!(e, ProofExpr::ForAll { .. }), variable.clone())];
+            prefix.extend(quantifier_prefix(body)?);
+            Some(prefix)
+        }
+        ProofExpr::And(l, r) | ProofExpr::Or(l, r) | ProofExpr::Implies(l, r) | ProofExpr::Iff(l, r) => {
+            match (quantifier_prefix(l)?, quantifier_prefix(r)?) {
+                (l, r) if r.is_empty() => Some(l),
+                (l, r) if l.is_empty() => Some(r),
+                _ => None,
+            }
+        }
+        ProofExpr::Not(x) => quantifier_prefix(x),
+        ProofExpr::Predicate { .. } | ProofExpr::Atom(_) | ProofExpr::Identity(..) => Some(Vec::new()),
+        _ => None,
+    }
+}
+
+/// The literals of `e`, quantifiers stripped.
+fn literals<'e>(e: &'e ProofExpr, out: &mut Vec<&'e ProofExpr>) {
+    match e {
+        ProofExpr::ForAll { body, .. } | ProofExpr::Exists { body, .. } => literals(body, out),
+        ProofExpr::And(l, r) | ProofExpr::Or(l, r) | ProofExpr::Implies(l, r) | ProofExpr::Iff(l, r) => {
+            literals(l, out);
+            literals(r, out);
+        }
+        ProofExpr::Not(x) if !matches!(x.as_ref(), ProofExpr::Predicate { .. }) => literals(x, out),
+        other => out.push(other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pred(name: &str, args: &[&str]) -> ProofExpr {
+        ProofExpr::Predicate {
+            name: name.to_string(),
+            args: args
+                .iter()
+                .map(|a| match a.chars().next() {
+                    Some('x') | Some('y') => ProofTerm::Variable(a.to_string()),
+                    _ => ProofTerm::Constant(a.to_string()),
+                })
+                .collect(),
+            world: None,
+        }
+    }
+
+    fn forall(v: &str, body: ProofExpr) -> ProofExpr {
+        ProofExpr::ForAll { variable: v.to_string(), body: Box::new(body) }
+    }
+
+    fn exists(v: &str, body: ProofExpr) -> ProofExpr {
+        ProofExpr::Exists { variable: v.to_string(), body: Box::new(body) }
+    }
+
+    #[test]
+    fn affirming_the_consequent_cites_both_premises() {
+        let rule = ProofExpr::Implies(Box::new(pred("rain", &[])), Box::new(pred("wet", &[])));
+        let found = detect_fallacies(&[pred("wet", &[]), rule], &pred("rain", &[]));
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].fallacy, Fallacy::AffirmingTheConsequent);
+        assert_eq!(found[0].premises, vec![0, 1]);
+    }
+
+    #[test]
+    fn swapped_quantifiers_are_a_shift() {
+        let premise = forall("x", exists("y", pred("love", &["x", "y"])));
+        let conclusion = exists("y", forall("x", pred("love", &["x", "y"])));
+        let found = detect_fallacies(std::slice::from_ref(&premise), &conclusion);
+        assert_eq!(found.iter().map(|m| m.fallacy).collect::<Vec<_>>(), vec![Fallacy::QuantifierShift]);
+        assert!(detect_fallacies(std::slice::from_ref(&conclusion), &premise).is_empty(), "∃∀ ∴ ∀∃ is valid");
+    }
+}
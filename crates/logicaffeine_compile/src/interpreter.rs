@@ -39,7 +39,7 @@ use std::cell::RefCell;
 
 use async_recursion::async_recursion;
 
-use crate::ast::stmt::{BinaryOpKind, Block, ClosureBody, CompressionCodec, Expr, Literal, MatchArm, ReadSource, Stmt, TypeExpr};
+use crate::ast::stmt::{BinaryOpKind, Block, ClosureBody, CompressionCodec, Expr, Literal, LogLevel, MatchArm, ReadSource, Stmt, TypeExpr};
 use crate::intern::{Interner, Symbol};
 
 /// Map a surface `Send compressed with <codec>` choice to the wire codec.
@@ -51,6 +51,31 @@ fn wire_compression_of(codec: CompressionCodec) -> crate::concurrency::marshal::
         CompressionCodec::Zstd => WireCompression::Zstd,
     }
 }
+
+/// Map a `Log <level>` to the runtime's log level.
+fn system_log_level(level: LogLevel) -> logicaffeine_system::log::Level {
+    use logicaffeine_system::log::Level;
+    match level {
+        LogLevel::Trace => Level::Trace,
+        LogLevel::Debug => Level::Debug,
+        LogLevel::Info => Level::Info,
+        LogLevel::Warn => Level::Warn,
+        LogLevel::Error => Level::Error,
+    }
+}
+
+/// Map a settings or argument type to the runtime's.
+fn system_setting_kind(kind: crate::analysis::registry::SettingKind) -> logicaffeine_system::settings::SettingKind {
+    use crate::analysis::registry::SettingKind;
+    use logicaffeine_system::settings::SettingKind as Kind;
+    match kind {
+        SettingKind::Int => Kind::Int,
+        SettingKind::Real => Kind::Real,
+        SettingKind::Text => Kind::Text,
+        SettingKind::Bool => Kind::Bool,
+    }
+}
+
 use crate::analysis::{PolicyRegistry, PolicyCondition};
 
 // VFS imports for async file operations
@@ -1051,6 +1076,9 @@ pub enum RuntimeValue {
     /// canonical text form. `Rc`-boxed to keep `RuntimeValue` at 16 bytes (the value itself is a
     /// `Copy [u8;16]`; the compiled tier carries it unboxed).
     Uuid(Rc<logicaffeine_base::Uuid>),
+    /// A compiled regular expression (`the pattern "\d+"`). Equal and hashed by its source text;
+    /// the compiled engine is shared, so copying a pattern never recompiles it.
+    Pattern(Rc<logicaffeine_data::LogosPattern>),
 }
 
 /// The payload of a [`RuntimeValue::Quantity`]: the physical quantity (magnitude in SI base +
@@ -1179,6 +1207,7 @@ impl std::hash::Hash for RuntimeValue {
                     // Hash by value (currency + amount), consistent with `eq`.
                     RuntimeValue::Money(m) => m.hash(state),
                     RuntimeValue::Uuid(u) => u.hash(state),
+                    RuntimeValue::Pattern(p) => p.source().hash(state),
                 }
             }
         }
@@ -1255,6 +1284,7 @@ impl RuntimeValue {
             RuntimeValue::Quantity(_) => "Quantity",
             RuntimeValue::Money(_) => "Money",
             RuntimeValue::Uuid(_) => "Uuid",
+            RuntimeValue::Pattern(_) => "Pattern",
         }
     }
 
@@ -1311,10 +1341,9 @@ impl RuntimeValue {
             }
             // A value-copy of a CRDT is an INDEPENDENT replica — deep-copy the inner state
             // so a later mutation of one copy does not alias the other (a shallow `Rc`
-            // share would make a struct copy mutate the original's CRDT field).
-            RuntimeValue::Crdt(c) => {
-                RuntimeValue::Crdt(Rc::new(RefCell::new(c.borrow().clone())))
-            }
+            // share would make a struct copy mutate the original's CRDT field). A part of a
+            // shared Document copies out as its plain value.
+            RuntimeValue::Crdt(c) => crate::semantics::crdt::crdt_copy(&c.borrow()),
             other => other.clone(),
         }
     }
@@ -1364,6 +1393,7 @@ impl RuntimeValue {
             RuntimeValue::Quantity(qv) => qv.display(),
             RuntimeValue::Money(m) => m.to_string(),
             RuntimeValue::Uuid(u) => u.to_string(),
+            RuntimeValue::Pattern(p) => p.to_string(),
             RuntimeValue::Float(f) => logicaffeine_data::fmt::fmt_f64(*f),
             RuntimeValue::Bool(b) => if *b { "true" } else { "false" }.to_string(),
             RuntimeValue::Text(s) => s.as_str().to_string(),
@@ -1658,6 +1688,15 @@ impl Environment {
         }
     }
 
+    /// Every live binding: globals, Main's block scope, and each function frame.
+    fn values(&self) -> impl Iterator<Item = &RuntimeValue> {
+        self.globals
+            .values()
+            .chain(self.main_block.values())
+            .chain(self.locals.values())
+            .chain(self.frame_stack.iter().flat_map(|(locals, _)| locals.values()))
+    }
+
     fn lookup(&self, name: Symbol) -> Option<&RuntimeValue> {
         if self.in_function() {
             self.locals.get(&name).or_else(|| self.globals.get(&name))
@@ -1720,6 +1759,12 @@ pub struct Interpreter<'a> {
     /// [`crate::concurrency::net_inbox::NetInbox`] so the bytecode VM's task driver owns the SAME
     /// inbox and networking runs byte-identically on both tiers (no tier silently differs).
     netbox: crate::concurrency::net_inbox::NetInbox,
+    /// Per-statement collection census, when memory reporting is on (sync path
+    /// only). See [`crate::memory_report`].
+    memory: Option<crate::memory_report::MemoryTracker>,
+    /// Executions per statement, keyed by the statement's address, when
+    /// coverage is on. See [`crate::coverage`].
+    coverage: Option<HashMap<usize, u64>>,
 }
 
 /// The shared interpreter context: function definitions, type metadata, platform
@@ -1735,6 +1780,16 @@ struct SharedCtx<'a> {
     /// registry so `Send shared` elides enum type/constructor names (T_INDUCTIVE_TID),
     /// the enum analog of struct name elision.
     enum_defs: HashMap<Symbol, Vec<Symbol>>,
+    /// The `## Settings` struct's schema, for `Read config from settings`.
+    settings_schemas: HashMap<Symbol, Vec<crate::analysis::registry::SettingDef>>,
+    /// The `## Arguments` declarations, for `Read cli from arguments`.
+    arguments_schema: Vec<(crate::analysis::registry::ArgumentDef, crate::analysis::registry::SettingDef)>,
+    /// `Comparable` struct → the fields `Sort` orders it by.
+    orderings: crate::semantics::collections::Orderings,
+    /// Which programs `Run the command` may start: those the `## Policy`
+    /// block allows, none by default.
+    #[cfg(all(feature = "process", not(target_arch = "wasm32")))]
+    command_policy: logicaffeine_system::process::CommandPolicy,
     vfs: Option<Arc<dyn Vfs>>,
     kernel_ctx: Option<Arc<crate::kernel::Context>>,
     policy_registry: Option<PolicyRegistry>,
@@ -1763,6 +1818,9 @@ struct SharedCtx<'a> {
     /// Program arguments for the `args()` system native — full argv, index 0 is
     /// the program name (mirrors the compiled binary's `env::args()`).
     program_args: Vec<String>,
+    /// The run's execution limits, shared with every spawned task. `None` runs
+    /// unbounded.
+    budget: Option<Rc<crate::limits::Budget>>,
 }
 
 /// The per-task execution state the cooperative scheduler owns for each task.
@@ -1819,6 +1877,11 @@ impl<'a> Interpreter<'a> {
                 functions: HashMap::new(),
                 struct_defs: HashMap::new(),
                 enum_defs: HashMap::new(),
+                settings_schemas: HashMap::new(),
+                arguments_schema: Vec::new(),
+                orderings: HashMap::new(),
+                #[cfg(all(feature = "process", not(target_arch = "wasm32")))]
+                command_policy: logicaffeine_system::process::CommandPolicy::deny_all(),
                 vfs: None,
                 kernel_ctx: None,
                 policy_registry: None,
@@ -1842,11 +1905,14 @@ impl<'a> Interpreter<'a> {
                 sym_count_ones: interner.lookup("count_ones"),
                 sym_args: interner.lookup("args"),
                 program_args: Vec::new(),
+                budget: None,
             },
             task: TaskState::new(),
             output: Vec::new(),
             yield_state: None,
             netbox: crate::concurrency::net_inbox::NetInbox::new(),
+            memory: None,
+            coverage: None,
         }
     }
 
@@ -1858,6 +1924,55 @@ impl<'a> Interpreter<'a> {
         self
     }
 
+    /// Bound the run by `budget` (see [`crate::limits`]): each executed
+    /// statement is one step, and output past the line cap is dropped.
+    pub(crate) fn with_budget(mut self, budget: Option<Rc<crate::limits::Budget>>) -> Self {
+        self.ctx.budget = budget;
+        self
+    }
+
+    /// Charge one statement to the budget; a crossed limit becomes the run's error.
+    fn charge_step(&self) -> Result<(), String> {
+        match &self.ctx.budget {
+            Some(budget) => budget
+                .charge(1, || crate::limits::approx_live_bytes(self.task.env.values()))
+                .map_err(|limit| limit.to_string()),
+            None => Ok(()),
+        }
+    }
+
+    /// Record per-statement `Seq`/`Map`/`Text` usage during [`Self::run_sync`];
+    /// collect it with [`Self::take_memory_report`].
+    pub fn with_memory_report(mut self) -> Self {
+        self.memory = Some(crate::memory_report::MemoryTracker::default());
+        self
+    }
+
+    /// The memory report recorded so far, if [`Self::with_memory_report`] was set.
+    pub fn take_memory_report(&mut self) -> Option<crate::memory_report::MemoryReport> {
+        self.memory.take().map(crate::memory_report::MemoryTracker::finish)
+    }
+
+    /// Count how often each statement executes; collect the counts with
+    /// [`Self::take_coverage`].
+    pub fn with_coverage(mut self) -> Self {
+        self.coverage = Some(HashMap::new());
+        self
+    }
+
+    /// The execution count of every statement that ran at least once, keyed
+    /// by address (`stmt as *const Stmt as usize`), if coverage was on.
+    pub fn take_coverage(&mut self) -> Option<HashMap<usize, u64>> {
+        self.coverage.take()
+    }
+
+    #[inline(always)]
+    fn count_execution(&mut self, stmt: &Stmt<'a>) {
+        if let Some(coverage) = &mut self.coverage {
+            *coverage.entry(stmt as *const Stmt as usize).or_insert(0) += 1;
+        }
+    }
+
     /// Phase 55: Set the VFS for file operations.
     pub fn with_vfs(mut self, vfs: Arc<dyn Vfs>) -> Self {
         self.ctx.vfs = Some(vfs);
@@ -1873,8 +1988,25 @@ impl<'a> Interpreter<'a> {
         self
     }
 
+    /// Set which programs `Run the command` may start, replacing the list
+    /// from the `## Policy` block.
+    #[cfg(all(feature = "process", not(target_arch = "wasm32")))]
+    pub fn with_command_policy(mut self, policy: logicaffeine_system::process::CommandPolicy) -> Self {
+        self.ctx.command_policy = policy;
+        self
+    }
+
     /// Set the policy registry for security checks.
+    ///
+    /// The programs the registry allows become the command policy.
     pub fn with_policies(mut self, registry: PolicyRegistry) -> Self {
+        #[cfg(all(feature = "process", not(target_arch = "wasm32")))]
+        {
+            let interner = self.ctx.interner;
+            self.ctx.command_policy = logicaffeine_system::process::CommandPolicy::allow(
+                registry.allowed_commands().iter().map(|program| interner.resolve(*program).to_string()),
+            );
+        }
         self.ctx.policy_registry = Some(registry);
         self
     }
@@ -1894,6 +2026,20 @@ impl<'a> Interpreter<'a> {
                     (f.name, type_sym, f.is_public)
                 }).collect();
                 self.ctx.struct_defs.insert(*name_sym, field_defs);
+                if let Some(keys) = registry.ordering(*name_sym) {
+                    let keys = keys.iter().map(|k| self.ctx.interner.resolve(*k).to_string()).collect();
+                    self.ctx.orderings.insert(self.ctx.interner.resolve(*name_sym).to_string(), keys);
+                }
+                if self.ctx.interner.resolve(*name_sym) == crate::analysis::registry::SETTINGS_TYPE {
+                    if let Ok(schema) = registry.settings_schema(*name_sym, self.ctx.interner) {
+                        self.ctx.settings_schemas.insert(*name_sym, schema);
+                    }
+                }
+                if self.ctx.interner.resolve(*name_sym) == crate::analysis::registry::ARGUMENTS_TYPE {
+                    if let Ok(schema) = registry.arguments_schema(self.ctx.interner) {
+                        self.ctx.arguments_schema = schema.into_iter().map(|(arg, def)| (arg.clone(), def)).collect();
+                    }
+                }
             } else if let TypeDef::Enum { variants, .. } = type_def {
                 // Constructor names in declaration order — the order is the wire's ctor
                 // index, so both peers (deriving from the same program) agree.
@@ -1919,6 +2065,9 @@ impl<'a> Interpreter<'a> {
 
     /// Internal helper to emit output (calls callback if set, always adds to output vec)
     fn emit_output(&mut self, line: String) {
+        if self.ctx.budget.as_ref().is_some_and(|budget| !budget.admit_output_line()) {
+            return;
+        }
         if let Some(ref callback) = self.ctx.output_callback {
             (callback.borrow_mut())(line.clone());
         }
@@ -2001,6 +2150,8 @@ impl<'a> Interpreter<'a> {
     /// Phase 55: Now async for VFS operations.
     #[async_recursion(?Send)]
     async fn execute_stmt(&mut self, stmt: &Stmt<'a>) -> Result<ControlFlow, String> {
+        self.charge_step()?;
+        self.count_execution(stmt);
         match stmt {
             Stmt::Let { var, value, .. } => {
                 let val = self.evaluate_expr(value).await?;
@@ -2312,6 +2463,43 @@ impl<'a> Interpreter<'a> {
                 Ok(ControlFlow::Continue)
             }
 
+            Stmt::Log { level, message, fields } => {
+                let level = system_log_level(*level);
+                if logicaffeine_system::log::enabled(level) {
+                    let message = self.evaluate_expr(message).await?.to_display_string();
+                    let mut values = Vec::with_capacity(fields.len());
+                    for (name, value) in fields {
+                        values.push((self.ctx.interner.resolve(*name), self.evaluate_expr(value).await?.to_display_string()));
+                    }
+                    logicaffeine_system::log::emit(level, &message, &values);
+                }
+                Ok(ControlFlow::Continue)
+            }
+
+            // The program runs to completion on this thread, as in the sync path.
+            Stmt::RunCommand { command, timeout, output, errors, exit_code } => {
+                let command = self.evaluate_expr(command).await?.to_display_string();
+                let timeout = match timeout {
+                    Some(limit) => Some(self.evaluate_expr(limit).await?),
+                    None => None,
+                };
+                self.run_command(&command, timeout, *output, *errors, *exit_code)?;
+                Ok(ControlFlow::Continue)
+            }
+
+            // The transfer runs to completion on this thread; only the progress calls await.
+            Stmt::Sip { file, sources, progress } => {
+                let file = self.evaluate_expr(file).await?;
+                let sources = self.evaluate_expr(sources).await?;
+                for step in Self::open_sip(&file, &sources)? {
+                    let step = step.map_err(|e| e.to_string())?;
+                    if let Some(function) = progress {
+                        self.call_function_with_values(*function, vec![RuntimeValue::Int(step.percent() as i64)]).await?;
+                    }
+                }
+                Ok(ControlFlow::Continue)
+            }
+
             // Phase 55: VFS operations now supported
             Stmt::ReadFrom { var, source } => {
                 let content = match source {
@@ -2329,6 +2517,38 @@ impl<'a> Interpreter<'a> {
                             None => return Err("VFS not initialized. Use Interpreter::with_vfs()".to_string()),
                         }
                     }
+                    ReadSource::Settings { type_name, path } => {
+                        // The file comes through the VFS, like `Read … from file`.
+                        let file = match path {
+                            Some(path_expr) => {
+                                let path = self.evaluate_expr(path_expr).await?.to_display_string();
+                                let text = match &self.ctx.vfs {
+                                    Some(vfs) => vfs.read_to_string(&path).await
+                                        .map_err(|e| format!("cannot read settings from {}: {}", path, e))?,
+                                    // Without a VFS (`largo run --interpret`) settings come straight off disk.
+                                    #[cfg(not(target_arch = "wasm32"))]
+                                    None => std::fs::read_to_string(&path)
+                                        .map_err(|e| format!("cannot read settings from {}: {}", path, e))?,
+                                    #[cfg(target_arch = "wasm32")]
+                                    None => return Err("VFS not initialized. Use Interpreter::with_vfs()".to_string()),
+                                };
+                                Some((path, text))
+                            }
+                            None => None,
+                        };
+                        let settings = self.load_settings(*type_name, file.as_ref().map(|(p, t)| (p.as_str(), t.as_str())))?;
+                        self.define(*var, settings);
+                        return Ok(ControlFlow::Continue);
+                    }
+                    ReadSource::Arguments { type_name } => {
+                        return match self.read_arguments(*type_name)? {
+                            Some(cli) => {
+                                self.define(*var, cli);
+                                Ok(ControlFlow::Continue)
+                            }
+                            None => Ok(ControlFlow::Return(RuntimeValue::Nothing)),
+                        };
+                    }
                 };
                 self.define(*var, RuntimeValue::Text(Rc::new(content)));
                 Ok(ControlFlow::Continue)
@@ -2812,6 +3032,61 @@ impl<'a> Interpreter<'a> {
                 self.netbox.inbox = Some(Rc::new(topic));
                 Ok(ControlFlow::Continue)
             }
+            // `Join the swarm [using …]` — the interpreter rides the relay, so joining
+            // means dialing the first relay that answers: the listed relays, then the
+            // bootstrap peers (a native node serves its own relay). mDNS belongs to the
+            // compiled mesh; here `local discovery` finds no one. Offline, and once a
+            // relay is already connected, the addresses are only validated.
+            Stmt::JoinSwarm { using } => {
+                use logicaffeine_system::discovery::Discovery;
+                let configured = Discovery::configured();
+                let mut relays = Vec::new();
+                let mut bootstrap = Vec::new();
+                if using.is_empty() {
+                    relays.extend_from_slice(configured.relays());
+                    bootstrap.extend_from_slice(configured.bootstrap_peers());
+                }
+                for method in using {
+                    let (list, fallback) = match method {
+                        crate::ast::SwarmDiscovery::Local => continue,
+                        crate::ast::SwarmDiscovery::Bootstrap(_) => (&mut bootstrap, configured.bootstrap_peers()),
+                        crate::ast::SwarmDiscovery::Relays(_) => (&mut relays, configured.relays()),
+                    };
+                    match method.addresses() {
+                        Some(addrs) => match self.evaluate_expr(addrs).await? {
+                            RuntimeValue::List(items) => {
+                                list.extend(items.borrow().to_values().iter().map(|v| v.to_display_string()))
+                            }
+                            single => list.push(single.to_display_string()),
+                        },
+                        None => list.extend_from_slice(fallback),
+                    }
+                }
+                let urls = relays
+                    .iter()
+                    .chain(&bootstrap)
+                    .map(|raw| {
+                        logicaffeine_system::addr::multiaddr_to_ws_url(raw)
+                            .map_err(|e| format!("Join address '{raw}' is not a ws:// URL or supported multiaddr: {e}"))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if !crate::concurrency::net_inbox::net_is_offline() && self.netbox.net.is_none() {
+                    let mut failures = Vec::new();
+                    for url in &urls {
+                        match logicaffeine_system::net::Net::connect(url).await {
+                            Ok(net) => {
+                                self.netbox.net = Some(net);
+                                break;
+                            }
+                            Err(e) => failures.push(format!("{url}: {e}")),
+                        }
+                    }
+                    if self.netbox.net.is_none() && !failures.is_empty() {
+                        return Err(format!("Join the swarm failed, no relay answered: {}", failures.join("; ")));
+                    }
+                }
+                Ok(ControlFlow::Continue)
+            }
             // `Let r be a PeerAgent at "<addr>"` — a handle to a remote peer; its
             // value is the peer's canonical inbox topic. Pure (no I/O); `Send`
             // and `Await` do the networking.
@@ -2929,6 +3204,7 @@ impl<'a> Interpreter<'a> {
                     // Record the version we now hold per field, so the next sync ships only later changes.
                     for (name, rc) in &crdt_fields {
                         let v = rc.borrow().version();
+                        self.netbox.synced.insert(rc.borrow().replica_id(), v.clone());
                         self.netbox.sync_versions.insert(field_key(name), v);
                     }
                     return Ok(ControlFlow::Continue);
@@ -3452,6 +3728,10 @@ impl<'a> Interpreter<'a> {
                                     RuntimeValue::Crdt(Rc::new(RefCell::new(
                                         crate::semantics::crdt::CrdtValue::new_seq(
                                             crate::semantics::crdt::next_replica_id())))),
+                                "SharedDocument" =>
+                                    RuntimeValue::Crdt(Rc::new(RefCell::new(
+                                        crate::semantics::crdt::CrdtValue::new_document(
+                                            crate::semantics::crdt::next_replica_id())))),
                                 _ => RuntimeValue::Nothing,
                             };
                             fields.insert(field_name, default);
@@ -3506,6 +3786,33 @@ impl<'a> Interpreter<'a> {
                 crate::semantics::arith::not_value(val)
             }
 
+            // Element by element: each is bound, filtered, and only when it passes are its
+            // element (and value) evaluated and collected — no intermediate collection is
+            // built. The iterable is snapshotted first, as `Repeat` and the compiled
+            // iterator chain both do.
+            Expr::Comprehension { kind, element, value, pattern, iterable, filter } => {
+                let source = self.evaluate_expr(iterable).await?;
+                let items = crate::semantics::collections::iteration_snapshot(&source)?;
+                let collected = crate::semantics::collections::empty_collection(*kind);
+                self.push_scope();
+                for item in items {
+                    self.bind_pattern(pattern, item)?;
+                    if let Some(filter) = filter {
+                        if !self.evaluate_expr(filter).await?.is_truthy() {
+                            continue;
+                        }
+                    }
+                    let key = self.evaluate_expr(element).await?;
+                    let val = match value {
+                        Some(value) => Some(self.evaluate_expr(value).await?),
+                        None => None,
+                    };
+                    crate::semantics::collections::collect_into(&collected, key, val)?;
+                }
+                self.pop_scope();
+                Ok(collected)
+            }
+
             Expr::InterpolatedString(parts) => {
                 let mut result = String::new();
                 for part in parts {
@@ -3614,6 +3921,19 @@ impl<'a> Interpreter<'a> {
         crate::semantics::compare::values_equal(left, right)
     }
 
+    /// Run a builtin on evaluated arguments. `sync_status` reads engine state (the versions
+    /// this program has published with `Sync`), and sorting the `Comparable` declarations.
+    fn call_builtin(&self, id: crate::semantics::builtins::BuiltinId, vals: Vec<RuntimeValue>) -> Result<RuntimeValue, String> {
+        use crate::semantics::builtins::BuiltinId;
+        if id == BuiltinId::SyncStatus {
+            return crate::semantics::crdt::sync_status(&vals[0], &self.netbox.synced);
+        }
+        if matches!(id, BuiltinId::SortedBy | BuiltinId::LargestOf | BuiltinId::SmallestOf) {
+            return crate::semantics::builtins::call_sort_builtin(id, &vals, &self.ctx.orderings);
+        }
+        crate::semantics::builtins::call_builtin(id, vals)
+    }
+
     /// Call a function (built-in or user-defined).
     #[async_recursion(?Send)]
     async fn call_function(&mut self, function: Symbol, args: &[&'async_recursion Expr<'a>]) -> Result<RuntimeValue, String> {
@@ -3652,7 +3972,7 @@ impl<'a> Interpreter<'a> {
                 }
                 v
             };
-            return crate::semantics::builtins::call_builtin(id, vals);
+            return self.call_builtin(id, vals);
         }
 
         // User-defined function lookup — extract metadata without cloning params
@@ -3843,6 +4163,8 @@ impl<'a> Interpreter<'a> {
             output: Vec::new(),
             yield_state: Some(ys.clone()),
             netbox: crate::concurrency::net_inbox::NetInbox::new(),
+            memory: None,
+            coverage: None,
         };
         let fut = Box::pin(async move {
             child.call_function_with_values(function, args).await.map(|_| ())
@@ -4024,6 +4346,188 @@ impl<'a> Interpreter<'a> {
         self.task.env.define(name, value);
     }
 
+    /// Bind a comprehension's binder to one element of its iterable — a name, or a
+    /// tuple of names for a Map's `(key, value)` entries.
+    fn bind_pattern(&mut self, pattern: &crate::ast::stmt::Pattern, item: RuntimeValue) -> Result<(), String> {
+        use crate::ast::stmt::Pattern;
+        match pattern {
+            Pattern::Identifier(sym) => self.define(*sym, item),
+            Pattern::Tuple(syms) => {
+                let RuntimeValue::Tuple(ref tuple_vals) = item else {
+                    return Err(format!("Expected tuple for pattern, got {}", item.type_name()));
+                };
+                if syms.len() != tuple_vals.len() {
+                    return Err(format!(
+                        "Cannot bind a {}-tuple to {} names",
+                        tuple_vals.len(),
+                        syms.len()
+                    ));
+                }
+                for (sym, val) in syms.iter().zip(tuple_vals.iter()) {
+                    self.define(*sym, val.clone());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the settings struct `type_name` against an optional
+    /// `(path, TOML text)` and the environment, and build its struct value.
+    /// Optional settings that were left out are `Nothing`.
+    fn load_settings(&self, type_name: Symbol, file: Option<(&str, &str)>) -> Result<RuntimeValue, String> {
+        use logicaffeine_system::settings::{self, SettingField};
+        let schema = self.ctx.settings_schemas.get(&type_name)
+            .ok_or_else(|| format!("there is no `## {}` block declaring the settings to read", self.ctx.interner.resolve(type_name)))?;
+        let fields: Vec<SettingField> = schema.iter().map(|def| SettingField {
+            name: self.ctx.interner.resolve(def.name),
+            kind: system_setting_kind(def.kind),
+            optional: def.optional,
+        }).collect();
+        let values = settings::from_sources(file, &fields, |name| std::env::var(name).ok())
+            .map_err(|e| e.to_string())?;
+        Ok(self.settings_struct(type_name, fields.iter().map(|f| f.name), &values))
+    }
+
+    /// `Read cli from arguments`: parse the program's command line against the
+    /// `## Arguments` block. `None` means `--help` was shown and the program
+    /// should stop.
+    fn read_arguments(&mut self, type_name: Symbol) -> Result<Option<RuntimeValue>, String> {
+        use logicaffeine_system::arguments::{self, ArgumentSpec, ArgumentStyle, ArgumentsError};
+        let specs: Vec<ArgumentSpec> = self.ctx.arguments_schema.iter().map(|(arg, def)| ArgumentSpec {
+            name: self.ctx.interner.resolve(def.name),
+            kind: system_setting_kind(def.kind),
+            optional: def.optional,
+            style: match arg.style {
+                crate::analysis::registry::ArgumentStyle::Positional => ArgumentStyle::Positional,
+                crate::analysis::registry::ArgumentStyle::Flag => ArgumentStyle::Flag,
+                crate::analysis::registry::ArgumentStyle::Option => ArgumentStyle::Option,
+            },
+            help: arg.help.map(|h| self.ctx.interner.resolve(h)).unwrap_or(""),
+        }).collect();
+        let argv = if self.ctx.program_args.is_empty() {
+            vec!["program".to_string()]
+        } else {
+            self.ctx.program_args.clone()
+        };
+        match arguments::parse_from(&specs, argv) {
+            Ok(values) => Ok(Some(self.settings_struct(type_name, specs.iter().map(|s| s.name), &values))),
+            Err(ArgumentsError::Help(help)) => {
+                for line in help.trim_end().lines() {
+                    self.emit_output(line.to_string());
+                }
+                Ok(None)
+            }
+            Err(ArgumentsError::Invalid(message)) => Err(message.trim_end().to_string()),
+        }
+    }
+
+    /// `Run the command`: run `command` under the command policy and bind the
+    /// captures. What is not captured passes through — stdout as program
+    /// output, stderr to the host's stderr — and a non-zero exit is an error
+    /// unless its exit code is captured.
+    #[cfg(all(feature = "process", not(target_arch = "wasm32")))]
+    fn run_command(
+        &mut self,
+        command: &str,
+        timeout: Option<RuntimeValue>,
+        output: Option<Symbol>,
+        errors: Option<Symbol>,
+        exit_code: Option<Symbol>,
+    ) -> Result<(), String> {
+        let timeout = match timeout {
+            None => None,
+            Some(RuntimeValue::Duration(nanos)) => Some(std::time::Duration::from_nanos(nanos.max(0) as u64)),
+            Some(RuntimeValue::Int(ms)) => Some(std::time::Duration::from_millis(ms.max(0) as u64)),
+            Some(other) => return Err(format!("A command timeout must be a Duration or Int, got {}", other.type_name())),
+        };
+        let run = logicaffeine_system::process::run_with_policy(command, timeout, &self.ctx.command_policy)
+            .map_err(|e| e.to_string())?;
+        match output {
+            Some(var) => self.define(var, RuntimeValue::Text(Rc::new(run.stdout.clone()))),
+            None => {
+                for line in run.stdout.lines() {
+                    self.emit_output(line.to_string());
+                }
+            }
+        }
+        match errors {
+            Some(var) => self.define(var, RuntimeValue::Text(Rc::new(run.stderr.clone()))),
+            None => eprint!("{}", run.stderr),
+        }
+        match exit_code {
+            Some(var) => self.define(var, RuntimeValue::Int(run.exit_code)),
+            None => run.require_success(command).map_err(|e| e.to_string())?,
+        }
+        Ok(())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn run_command(
+        &mut self,
+        _command: &str,
+        _timeout: Option<RuntimeValue>,
+        _output: Option<Symbol>,
+        _errors: Option<Symbol>,
+        _exit_code: Option<Symbol>,
+    ) -> Result<(), String> {
+        Err("`Run the command` cannot start programs in the browser".to_string())
+    }
+
+    #[cfg(all(not(feature = "process"), not(target_arch = "wasm32")))]
+    fn run_command(
+        &mut self,
+        _command: &str,
+        _timeout: Option<RuntimeValue>,
+        _output: Option<Symbol>,
+        _errors: Option<Symbol>,
+        _exit_code: Option<Symbol>,
+    ) -> Result<(), String> {
+        Err("`Run the command` needs logicaffeine-compile's `process` feature".to_string())
+    }
+
+    /// `Sip the file`: a transfer assembling `file` from `sources`, a path or
+    /// a list of paths whose first entry every chunk is checked against.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn open_sip(file: &RuntimeValue, sources: &RuntimeValue) -> Result<logicaffeine_system::sip::SipTransfer, String> {
+        let sources: Vec<String> = match sources {
+            RuntimeValue::List(items) => items.borrow().to_values().iter().map(RuntimeValue::to_display_string).collect(),
+            source => vec![source.to_display_string()],
+        };
+        logicaffeine_system::sip::SipTransfer::open(file.to_display_string(), &sources)
+            .map_err(|e| format!("Cannot sip {}: {}", file.to_display_string(), e))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn open_sip(_file: &RuntimeValue, _sources: &RuntimeValue) -> Result<logicaffeine_system::sip::SipTransfer, String> {
+        Err("`Sip the file` cannot read files in the browser".to_string())
+    }
+
+    /// The struct a settings or arguments read binds: each named field from
+    /// `values`, `Nothing` for an optional one that was left out.
+    fn settings_struct<'n>(
+        &self,
+        type_name: Symbol,
+        names: impl Iterator<Item = &'n str>,
+        values: &logicaffeine_system::settings::SettingValues,
+    ) -> RuntimeValue {
+        use logicaffeine_system::settings::SettingValue;
+        let mut struct_fields = HashMap::new();
+        for name in names {
+            let value = match values.get(name) {
+                Some(SettingValue::Int(i)) => RuntimeValue::Int(*i),
+                Some(SettingValue::Real(r)) => RuntimeValue::Float(*r),
+                Some(SettingValue::Text(t)) => RuntimeValue::Text(Rc::new(t.clone())),
+                Some(SettingValue::Bool(b)) => RuntimeValue::Bool(*b),
+                None => RuntimeValue::Nothing,
+            };
+            struct_fields.insert(name.to_string(), value);
+        }
+        RuntimeValue::Struct(Box::new(StructValue {
+            type_name: self.ctx.interner.resolve(type_name).to_string(),
+            fields: struct_fields,
+        }))
+    }
+
     fn assign(&mut self, name: Symbol, value: RuntimeValue) -> Result<(), String> {
         if self.task.env.assign(name, value) {
             Ok(())
@@ -4134,7 +4638,10 @@ impl<'a> Interpreter<'a> {
     pub fn run_sync(&mut self, stmts: &[Stmt<'a>]) -> Result<(), String> {
         // Hermetic program start: no ambient exchange rates carried in (mirrors a fresh AOT process).
         logicaffeine_base::money::clear_ambient_rates();
-        for stmt in stmts {
+        for (index, stmt) in stmts.iter().enumerate() {
+            if let Some(memory) = &mut self.memory {
+                memory.begin_statement(index, crate::memory_report::statement_label(stmt, self.ctx.interner));
+            }
             match self.execute_stmt_sync(stmt)? {
                 ControlFlow::Return(_) => break,
                 ControlFlow::Break => break,
@@ -4144,7 +4651,18 @@ impl<'a> Interpreter<'a> {
         Ok(())
     }
 
+    #[inline(always)]
     fn execute_stmt_sync(&mut self, stmt: &Stmt<'a>) -> Result<ControlFlow, String> {
+        self.charge_step()?;
+        self.count_execution(stmt);
+        let flow = self.execute_stmt_sync_untracked(stmt);
+        if let Some(memory) = &mut self.memory {
+            memory.census(self.task.env.values());
+        }
+        flow
+    }
+
+    fn execute_stmt_sync_untracked(&mut self, stmt: &Stmt<'a>) -> Result<ControlFlow, String> {
         match stmt {
             Stmt::Let { var, value, .. } => {
                 let val = self.evaluate_expr_sync(value)?;
@@ -4452,6 +4970,41 @@ impl<'a> Interpreter<'a> {
                 Ok(ControlFlow::Continue)
             }
 
+            Stmt::Log { level, message, fields } => {
+                let level = system_log_level(*level);
+                if logicaffeine_system::log::enabled(level) {
+                    let message = self.evaluate_expr_sync(message)?.to_display_string();
+                    let mut values = Vec::with_capacity(fields.len());
+                    for (name, value) in fields {
+                        values.push((self.ctx.interner.resolve(*name), self.evaluate_expr_sync(value)?.to_display_string()));
+                    }
+                    logicaffeine_system::log::emit(level, &message, &values);
+                }
+                Ok(ControlFlow::Continue)
+            }
+
+            Stmt::RunCommand { command, timeout, output, errors, exit_code } => {
+                let command = self.evaluate_expr_sync(command)?.to_display_string();
+                let timeout = match timeout {
+                    Some(limit) => Some(self.evaluate_expr_sync(limit)?),
+                    None => None,
+                };
+                self.run_command(&command, timeout, *output, *errors, *exit_code)?;
+                Ok(ControlFlow::Continue)
+            }
+
+            Stmt::Sip { file, sources, progress } => {
+                let file = self.evaluate_expr_sync(file)?;
+                let sources = self.evaluate_expr_sync(sources)?;
+                for step in Self::open_sip(&file, &sources)? {
+                    let step = step.map_err(|e| e.to_string())?;
+                    if let Some(function) = progress {
+                        self.call_function_with_values_sync(*function, vec![RuntimeValue::Int(step.percent() as i64)])?;
+                    }
+                }
+                Ok(ControlFlow::Continue)
+            }
+
             // Async-only operations — unreachable in sync path (checked by needs_async)
             Stmt::ReadFrom { var, source } => {
                 match source {
@@ -4462,6 +5015,16 @@ impl<'a> Interpreter<'a> {
                     ReadSource::File(_) => {
                         Err("File read requires async execution path".to_string())
                     }
+                    ReadSource::Settings { .. } => {
+                        Err("Settings read requires async execution path".to_string())
+                    }
+                    ReadSource::Arguments { type_name } => match self.read_arguments(*type_name)? {
+                        Some(cli) => {
+                            self.define(*var, cli);
+                            Ok(ControlFlow::Continue)
+                        }
+                        None => Ok(ControlFlow::Return(RuntimeValue::Nothing)),
+                    },
                 }
             }
 
@@ -4639,8 +5202,8 @@ impl<'a> Interpreter<'a> {
                 Ok(ControlFlow::Continue)
             }
 
-            Stmt::Listen { .. } | Stmt::ConnectTo { .. } => {
-                Err("Networking (Connect/Listen) requires the async execution path".to_string())
+            Stmt::Listen { .. } | Stmt::ConnectTo { .. } | Stmt::JoinSwarm { .. } => {
+                Err("Networking (Connect/Listen/Join) requires the async execution path".to_string())
             }
             // A PeerAgent handle is pure (just its canonical topic), so it works
             // outside the async path; the `Send`/`Await` that use it do not.
@@ -4935,6 +5498,10 @@ impl<'a> Interpreter<'a> {
                                     RuntimeValue::Crdt(Rc::new(RefCell::new(
                                         crate::semantics::crdt::CrdtValue::new_seq(
                                             crate::semantics::crdt::next_replica_id())))),
+                                "SharedDocument" =>
+                                    RuntimeValue::Crdt(Rc::new(RefCell::new(
+                                        crate::semantics::crdt::CrdtValue::new_document(
+                                            crate::semantics::crdt::next_replica_id())))),
                                 _ => RuntimeValue::Nothing,
                             };
                             fields.insert(field_name, default);
@@ -4987,6 +5554,33 @@ impl<'a> Interpreter<'a> {
                 crate::semantics::arith::not_value(val)
             }
 
+            // Element by element: each is bound, filtered, and only when it passes are its
+            // element (and value) evaluated and collected — no intermediate collection is
+            // built. The iterable is snapshotted first, as `Repeat` and the compiled
+            // iterator chain both do.
+            Expr::Comprehension { kind, element, value, pattern, iterable, filter } => {
+                let source = self.evaluate_expr_sync(iterable)?;
+                let items = crate::semantics::collections::iteration_snapshot(&source)?;
+                let collected = crate::semantics::collections::empty_collection(*kind);
+                self.push_scope();
+                for item in items {
+                    self.bind_pattern(pattern, item)?;
+                    if let Some(filter) = filter {
+                        if !self.evaluate_expr_sync(filter)?.is_truthy() {
+                            continue;
+                        }
+                    }
+                    let key = self.evaluate_expr_sync(element)?;
+                    let val = match value {
+                        Some(value) => Some(self.evaluate_expr_sync(value)?),
+                        None => None,
+                    };
+                    crate::semantics::collections::collect_into(&collected, key, val)?;
+                }
+                self.pop_scope();
+                Ok(collected)
+            }
+
             Expr::InterpolatedString(parts) => {
                 let mut result = String::new();
                 for part in parts {
@@ -5124,7 +5718,7 @@ impl<'a> Interpreter<'a> {
                 }
                 v
             };
-            return crate::semantics::builtins::call_builtin(id, vals);
+            return self.call_builtin(id, vals);
         }
 
         // User-defined function lookup — extract metadata without cloning params
@@ -5515,6 +6109,20 @@ impl<'a> Interpreter<'a> {
                     }
                 }
             }
+            Expr::Comprehension { element, value, pattern, iterable, filter, .. } => {
+                Self::collect_symbols_from_expr(iterable, exclude, out, seen);
+                // The binder is local to the element, value and filter
+                let mut inner_exclude = exclude.clone();
+                match pattern {
+                    crate::ast::stmt::Pattern::Identifier(s) => {
+                        inner_exclude.insert(*s);
+                    }
+                    crate::ast::stmt::Pattern::Tuple(syms) => inner_exclude.extend(syms.iter().copied()),
+                }
+                for e in std::iter::once(*element).chain(*value).chain(*filter) {
+                    Self::collect_symbols_from_expr(e, &inner_exclude, out, seen);
+                }
+            }
         }
     }
 
@@ -5582,6 +6190,22 @@ impl<'a> Interpreter<'a> {
                 Stmt::Show { object, .. } | Stmt::Give { object, .. } => {
                     Self::collect_symbols_from_expr(object, &bound, out, seen);
                 }
+                Stmt::Log { message, fields, .. } => {
+                    Self::collect_symbols_from_expr(message, &bound, out, seen);
+                    for (_, value) in fields {
+                        Self::collect_symbols_from_expr(value, &bound, out, seen);
+                    }
+                }
+                Stmt::RunCommand { command, timeout, .. } => {
+                    Self::collect_symbols_from_expr(command, &bound, out, seen);
+                    if let Some(timeout) = timeout {
+                        Self::collect_symbols_from_expr(timeout, &bound, out, seen);
+                    }
+                }
+                Stmt::Sip { file, sources, .. } => {
+                    Self::collect_symbols_from_expr(file, &bound, out, seen);
+                    Self::collect_symbols_from_expr(sources, &bound, out, seen);
+                }
                 Stmt::Push { value, collection } | Stmt::Add { value, collection }
                 | Stmt::Remove { value, collection } => {
                     Self::collect_symbols_from_expr(value, &bound, out, seen);
@@ -5789,11 +6413,11 @@ pub fn needs_async(stmts: &[Stmt]) -> bool {
 fn stmt_needs_async(stmt: &Stmt) -> bool {
     match stmt {
         Stmt::ReadFrom { source, .. } => {
-            matches!(source, ReadSource::File(_))
+            matches!(source, ReadSource::File(_) | ReadSource::Settings { .. })
         }
         Stmt::WriteFile { .. } | Stmt::Sleep { .. } | Stmt::Mount { .. } => true,
         // Networking over the relay is async (dial + subscribe await).
-        Stmt::Sync { .. } | Stmt::Listen { .. } | Stmt::ConnectTo { .. } => true,
+        Stmt::Sync { .. } | Stmt::Listen { .. } | Stmt::ConnectTo { .. } | Stmt::JoinSwarm { .. } => true,
         // Peer messaging rides the relay (subscribe/publish/poll) — async only.
         Stmt::SendMessage { .. } | Stmt::AwaitMessage { .. } | Stmt::StreamMessage { .. } => true,
         Stmt::If { then_block, else_block, .. } => {
@@ -5825,12 +6449,19 @@ fn count_pi_args(term: &crate::kernel::Term) -> usize {
 /// Contains both the output produced by `show()` calls and any error
 /// that occurred during execution. Used by the UI bridge to display
 /// program output to users.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct InterpreterResult {
     /// Output lines from `show()` calls during execution.
     pub lines: Vec<String>,
     /// Error message if execution failed, or `None` on success.
     pub error: Option<String>,
+    /// Per-statement collection usage, for runs that asked for it
+    /// (see [`crate::ui_bridge::interpret_with_memory_report`]).
+    pub memory: Option<crate::memory_report::MemoryReport>,
+    /// Set when the run was stopped by an execution limit (see
+    /// [`crate::limits`]); `error` then carries its message and `lines` the
+    /// output produced before the stop.
+    pub limit_exceeded: Option<crate::limits::LimitExceeded>,
 }
 
 #[cfg(test)]
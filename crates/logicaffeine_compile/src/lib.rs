@@ -11,6 +11,7 @@ pub use logicaffeine_language::{
     arena_ctx::AstContext,
     registry::SymbolRegistry,
     formatter,
+    metrics,
     mwe,
     Lexer, Parser, ParseError,
 };
@@ -22,6 +23,9 @@ pub use logicaffeine_kernel as kernel;
 pub mod loader;
 pub use loader::{Loader, ModuleSource};
 
+// `## For each of …` declaration templates, expanded before discovery
+pub mod template;
+
 // Compile-time analysis
 pub mod analysis;
 
@@ -65,6 +69,19 @@ pub mod optimize;
 // Interpreter
 pub mod interpreter;
 
+// Opt-in per-line statement execution counts for the tree-walker and for
+// instrumented compiled binaries (`largo test --coverage`).
+pub mod coverage;
+
+// Opt-in per-statement Seq/Map/Text usage for the tree-walker (space complexity
+// made visible in the Guide).
+pub mod memory_report;
+pub use memory_report::{CollectionStats, MemoryReport, StatementMemory};
+
+// Step / time / output / memory bounds for running untrusted programs.
+pub mod limits;
+pub use limits::{ExecutionLimits, LimitExceeded};
+
 // Tail-call recognition shared by all three execution tiers (tree-walker, VM,
 // AOT) so "a self-tail-call runs in constant stack" is one definition, not three.
 pub(crate) mod tail_call;
@@ -87,10 +104,21 @@ pub mod debug;
 // UI Bridge - high-level compilation for web interface
 pub mod ui_bridge;
 
+// DOT and Mermaid exports of syntax trees and module graphs.
+pub mod diagram;
+
 // The replay-based interactive session behind `largo repl`.
 pub mod repl;
 pub use repl::{ReplOutcome, ReplSession};
 
+// Premise-conclusion argument analysis: validity, needed premises, fallacies.
+pub mod argument;
+pub use argument::{analyze_argument, ArgumentReport, ArgumentVerdict};
+
+// Formal fallacy templates, shared by the argument analyzer and the Learn grader.
+pub mod fallacies;
+pub use fallacies::{detect_fallacies, Fallacy, FallacyMatch};
+
 #[cfg(feature = "verification")]
 pub mod defeasible;
 
@@ -108,10 +136,12 @@ pub use ui_bridge::{
     interpret_for_ui, interpret_for_ui_with_args, interpret_for_ui_sync,
     interpret_for_ui_sync_with_args, interpret_for_ui_baseline,
     interpret_for_ui_baseline_with_args, interpret_for_ui_baseline_sync_with_args,
+    interpret_with_coverage, interpret_with_memory_report, set_execution_limits, execution_limits,
     interpret_streaming, interpret_streaming_with_vfs, interpret_streaming_with_vfs_observer,
+    interpret_streaming_project,
     ObserverCallback, run_vm_concurrent, run_vm_net_async,
     run_vm_concurrent_seeded, run_treewalker_concurrent_seeded,
-    CompileResult, ProofCompileResult,
+    CompileResult, ProofCompileResult, RustPreview, RustLineOrigin,
     TheoremCompileResult, AstNode, TokenInfo, TokenCategory,
     extract_math_rust, extract_math_rust_from_source, extract_logic_rust, parse_math_statements,
     extract_math_module, extract_math_module_from_source, extract_logic_module, partition_mixed,
@@ -121,7 +151,7 @@ pub use ui_bridge::{
 #[cfg(not(target_arch = "wasm32"))]
 pub use ui_bridge::run_vm_workstealing_seeded;
 #[cfg(feature = "codegen")]
-pub use ui_bridge::{generate_rust_code, generate_rust_code_with_proven};
+pub use ui_bridge::{generate_rust_code, generate_rust_code_with_proven, generate_rust_preview};
 #[cfg(feature = "verification")]
 pub use ui_bridge::{
     check_theorem_defeasible, check_theorem_defeasible_consistent,
@@ -0,0 +1,267 @@
+//! Execution limits for running untrusted programs.
+//!
+//! The web IDE runs whatever a visitor types, on the page's only thread: an
+//! infinite loop would freeze the tab and a runaway `Push` would exhaust its
+//! memory. [`ExecutionLimits`] bounds a run on four axes — executed steps,
+//! wall-clock time, output lines and (approximate) live memory — and a run that
+//! crosses one stops with a [`LimitExceeded`], keeping the output produced so
+//! far.
+//!
+//! Limits are installed per thread with
+//! [`set_execution_limits`](crate::ui_bridge::set_execution_limits) and honored
+//! by every `interpret_*` entry in [`crate::ui_bridge`], on both the bytecode VM
+//! (one step = one op) and the tree-walker (one step = one statement).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use logicaffeine_compile::{interpret_for_ui_sync, set_execution_limits, ExecutionLimits};
+//!
+//! set_execution_limits(ExecutionLimits::browser());
+//! let result = interpret_for_ui_sync("## Main\nWhile true:\n    Show 1.\n");
+//! assert!(result.limit_exceeded.is_some());
+//! ```
+
+use std::cell::Cell;
+use std::collections::HashSet;
+use std::fmt;
+use std::rc::Rc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::interpreter::{ListRepr, RuntimeValue};
+
+/// Upper bounds for one program run. `None` leaves an axis unbounded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExecutionLimits {
+    /// Steps: bytecode ops on the VM, statements on the tree-walker.
+    pub max_steps: Option<u64>,
+    pub wall_clock: Option<Duration>,
+    pub max_output_lines: Option<usize>,
+    /// Approximate bytes held by reachable collections and text.
+    pub max_memory_bytes: Option<usize>,
+}
+
+impl ExecutionLimits {
+    /// No limits: the behavior of every entry point by default.
+    pub const UNLIMITED: ExecutionLimits =
+        ExecutionLimits { max_steps: None, wall_clock: None, max_output_lines: None, max_memory_bytes: None };
+
+    /// Bounds for running visitors' code in a browser tab: generous enough for
+    /// every shipped example, tight enough that a runaway program stops in
+    /// seconds.
+    pub fn browser() -> Self {
+        ExecutionLimits {
+            max_steps: Some(500_000_000),
+            wall_clock: Some(Duration::from_secs(10)),
+            max_output_lines: Some(10_000),
+            max_memory_bytes: Some(256 * 1024 * 1024),
+        }
+    }
+
+    pub fn with_max_steps(mut self, steps: u64) -> Self {
+        self.max_steps = Some(steps);
+        self
+    }
+
+    pub fn with_wall_clock(mut self, budget: Duration) -> Self {
+        self.wall_clock = Some(budget);
+        self
+    }
+
+    pub fn with_max_output_lines(mut self, lines: usize) -> Self {
+        self.max_output_lines = Some(lines);
+        self
+    }
+
+    pub fn with_max_memory_bytes(mut self, bytes: usize) -> Self {
+        self.max_memory_bytes = Some(bytes);
+        self
+    }
+
+    pub fn is_unlimited(&self) -> bool {
+        *self == Self::UNLIMITED
+    }
+}
+
+/// The limit a run crossed; each variant carries the configured bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LimitExceeded {
+    Steps(u64),
+    WallClock(Duration),
+    OutputLines(usize),
+    Memory(usize),
+}
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LimitExceeded::Steps(n) => write!(f, "Execution stopped: exceeded the limit of {n} steps (is there an infinite loop?)"),
+            LimitExceeded::WallClock(d) => write!(f, "Execution stopped: exceeded the time limit of {:.1}s (is there an infinite loop?)", d.as_secs_f64()),
+            LimitExceeded::OutputLines(n) => write!(f, "Execution stopped: exceeded the limit of {n} output lines"),
+            LimitExceeded::Memory(n) => write!(f, "Execution stopped: exceeded the memory limit of {} MiB", n / (1024 * 1024)),
+        }
+    }
+}
+
+/// Milliseconds since an arbitrary origin; `std::time::Instant` panics on wasm32.
+fn now_ms() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::now()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        thread_local! {
+            static ORIGIN: std::time::Instant = std::time::Instant::now();
+        }
+        ORIGIN.with(|origin| origin.elapsed().as_secs_f64() * 1000.0)
+    }
+}
+
+/// How many steps pass between the clock and memory checks.
+const CHECK_INTERVAL: u64 = 4096;
+
+/// The running tally of one limited run, shared by every engine (and every
+/// task) executing it. The first limit crossed sticks.
+pub(crate) struct Budget {
+    limits: ExecutionLimits,
+    started_ms: f64,
+    steps: Cell<u64>,
+    output_lines: Cell<usize>,
+    exceeded: Cell<Option<LimitExceeded>>,
+}
+
+impl Budget {
+    /// A fresh budget, or `None` when `limits` bound nothing.
+    pub(crate) fn start(limits: ExecutionLimits) -> Option<Rc<Budget>> {
+        (!limits.is_unlimited()).then(|| {
+            Rc::new(Budget {
+                limits,
+                started_ms: now_ms(),
+                steps: Cell::new(0),
+                output_lines: Cell::new(0),
+                exceeded: Cell::new(None),
+            })
+        })
+    }
+
+    /// The limit crossed so far, if any.
+    pub(crate) fn exceeded(&self) -> Option<LimitExceeded> {
+        self.exceeded.get()
+    }
+
+    fn fail(&self, limit: LimitExceeded) -> Result<(), LimitExceeded> {
+        let first = self.exceeded.get().unwrap_or(limit);
+        self.exceeded.set(Some(first));
+        Err(first)
+    }
+
+    /// Account for `n` executed steps. Every `CHECK_INTERVAL` steps the clock is
+    /// read too, and `live_bytes` (only then evaluated) is checked against the
+    /// memory limit.
+    pub(crate) fn charge(&self, n: u64, live_bytes: impl FnOnce() -> usize) -> Result<(), LimitExceeded> {
+        if let Some(limit) = self.exceeded.get() {
+            return Err(limit);
+        }
+        let before = self.steps.get();
+        let after = before.saturating_add(n);
+        self.steps.set(after);
+        if let Some(max) = self.limits.max_steps {
+            if after > max {
+                return self.fail(LimitExceeded::Steps(max));
+            }
+        }
+        if before / CHECK_INTERVAL == after / CHECK_INTERVAL {
+            return Ok(());
+        }
+        if let Some(budget) = self.limits.wall_clock {
+            if now_ms() - self.started_ms > budget.as_secs_f64() * 1000.0 {
+                return self.fail(LimitExceeded::WallClock(budget));
+            }
+        }
+        if let Some(max) = self.limits.max_memory_bytes {
+            if live_bytes() > max {
+                return self.fail(LimitExceeded::Memory(max));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether one more output line fits; records the overflow when it does not.
+    pub(crate) fn admit_output_line(&self) -> bool {
+        let lines = self.output_lines.get() + 1;
+        match self.limits.max_output_lines {
+            Some(max) if lines > max => {
+                let _ = self.fail(LimitExceeded::OutputLines(max));
+                false
+            }
+            _ => {
+                self.output_lines.set(lines);
+                true
+            }
+        }
+    }
+}
+
+/// Approximate bytes held by the collections and text reachable from `roots`,
+/// counting each shared allocation once.
+pub(crate) fn approx_live_bytes<'v>(roots: impl Iterator<Item = &'v RuntimeValue>) -> usize {
+    const SLOT: usize = std::mem::size_of::<RuntimeValue>();
+    let mut seen = HashSet::new();
+    let mut pending: Vec<RuntimeValue> = roots.cloned().collect();
+    let mut total = 0usize;
+    while let Some(value) = pending.pop() {
+        let addr = match &value {
+            RuntimeValue::List(rc) => Rc::as_ptr(rc) as *const () as usize,
+            RuntimeValue::Map(rc) => Rc::as_ptr(rc) as *const () as usize,
+            RuntimeValue::Set(rc) => Rc::as_ptr(rc) as *const () as usize,
+            RuntimeValue::Tuple(rc) => Rc::as_ptr(rc) as *const () as usize,
+            RuntimeValue::Text(rc) => Rc::as_ptr(rc) as *const () as usize,
+            RuntimeValue::Struct(s) => &**s as *const _ as usize,
+            _ => continue,
+        };
+        if !seen.insert(addr) {
+            continue;
+        }
+        total += match &value {
+            RuntimeValue::List(list) => match &*list.borrow() {
+                ListRepr::Boxed(items) => {
+                    pending.extend(items.iter().cloned());
+                    items.len() * SLOT
+                }
+                ListRepr::Ints(v) => v.len() * 8,
+                ListRepr::IntsI32(v) => v.len() * 4,
+                ListRepr::Floats(v) => v.len() * 8,
+                ListRepr::Bools(v) => v.len(),
+                ListRepr::Strings { data, ends, .. } => data.len() + ends.len() * 4,
+                other => other.len() * SLOT,
+            },
+            RuntimeValue::Map(map) => {
+                let map = map.borrow();
+                for (k, v) in map.iter() {
+                    pending.push(k.clone());
+                    pending.push(v.clone());
+                }
+                map.len() * 2 * SLOT
+            }
+            RuntimeValue::Set(set) => {
+                let set = set.borrow();
+                pending.extend(set.iter().cloned());
+                set.len() * SLOT
+            }
+            RuntimeValue::Tuple(items) => {
+                pending.extend(items.iter().cloned());
+                items.len() * SLOT
+            }
+            RuntimeValue::Text(text) => text.len(),
+            RuntimeValue::Struct(s) => {
+                pending.extend(s.fields.values().cloned());
+                s.fields.len() * SLOT
+            }
+            _ => 0,
+        };
+    }
+    total
+}
@@ -16,6 +16,31 @@
 //! The loader prevents path traversal attacks by checking that resolved
 //! paths remain within the project root directory.
 //!
+//! # Virtual Files
+//!
+//! A loader built with [`Loader::with_files`] resolves `file:` URIs against an
+//! in-memory map before touching the filesystem. The browser Studio uses this
+//! to compile a project whose files live in its IndexedDB/OPFS store, where
+//! `std::fs` is unavailable.
+//!
+//! # Module Graph
+//!
+//! [`Loader::module_graph`] follows the `[Alias](uri)` imports in each
+//! module's abstract and returns a [`ModuleGraph`]: every module reached, the
+//! import edges with their source spans, and imports that failed to load.
+//! [`ModuleGraph::cycles`] reports each import cycle as the chain of links
+//! that forms it.
+//!
+//! # Target Sections
+//!
+//! `## When compiling for web:` (or `for native`, or `with feature <name>`)
+//! guards the blocks that follow it, up to the next guard, `## Otherwise:` or
+//! `## Always:`. The loader resolves them for its [`CompileTarget`] as each
+//! module loads, and [`apply_prelude_for`] does the same for an entry source,
+//! so one codebase can carry both a native and a wasm IO path. See
+//! [`resolve_target_sections`]. `## For each of …` templates are expanded
+//! at the same two points (see [`crate::template`]).
+//!
 //! # Caching
 //!
 //! Modules are cached by their normalized URI. The same module loaded from
@@ -35,10 +60,15 @@
 //! # }
 //! ```
 
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::analysis::scan_dependencies;
+use crate::template::{expand_templates, Expansion, ExpansionTrace};
+use crate::token::Span;
+
 /// A loaded module's source content and metadata.
 #[derive(Debug, Clone)]
 pub struct ModuleSource {
@@ -50,13 +80,17 @@ pub struct ModuleSource {
 
 /// Module loader that handles multiple URI schemes.
 ///
-/// Caches loaded modules to prevent duplicate loading and supports
-/// cycle detection through the cache.
+/// Caches loaded modules to prevent duplicate loading. Import cycles are
+/// found on the [`ModuleGraph`] built by [`Loader::module_graph`].
 pub struct Loader {
     /// Cache of loaded modules (URI -> ModuleSource)
     cache: HashMap<String, ModuleSource>,
     /// Root directory of the project (for relative path resolution)
     root_path: PathBuf,
+    /// In-memory sources keyed by normalized path, consulted before the filesystem
+    files: HashMap<PathBuf, String>,
+    /// The target whose `## When compiling …` sections survive loading
+    target: CompileTarget,
 }
 
 impl Loader {
@@ -65,9 +99,40 @@ impl Loader {
         Loader {
             cache: HashMap::new(),
             root_path,
+            files: HashMap::new(),
+            target: CompileTarget::host(),
+        }
+    }
+
+    /// Creates a Loader that resolves `file:` URIs from `files` first.
+    ///
+    /// Keys are paths as the entry file sees them (e.g. `/project/geo.md`);
+    /// `.` and `..` components are normalized away before lookup.
+    pub fn with_files(root_path: PathBuf, files: HashMap<PathBuf, String>) -> Self {
+        let files = files
+            .into_iter()
+            .map(|(path, content)| (normalize_path(&path), content))
+            .collect();
+        Loader {
+            cache: HashMap::new(),
+            root_path,
+            files,
+            target: CompileTarget::host(),
         }
     }
 
+    /// Resolves every module's target sections for `target` instead of the
+    /// host (see [`resolve_target_sections`]).
+    pub fn with_target(mut self, target: CompileTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// The target this loader resolves target sections for.
+    pub fn target(&self) -> &CompileTarget {
+        &self.target
+    }
+
     /// Resolves a URI to a module source.
     ///
     /// Supports:
@@ -84,7 +149,7 @@ impl Loader {
         }
 
         // Load based on scheme
-        let source = if uri.starts_with("file:") {
+        let mut source = if uri.starts_with("file:") {
             self.load_file(base_path, uri)?
         } else if uri.starts_with("logos:") {
             self.load_intrinsic(uri)?
@@ -100,6 +165,22 @@ impl Loader {
             self.load_file(base_path, &format!("file:{}", uri))?
         };
 
+        let resolved = match resolve_target_sections(&source.content, &self.target) {
+            Cow::Owned(resolved) => Some(resolved),
+            Cow::Borrowed(_) => None,
+        };
+        if let Some(resolved) = resolved {
+            source.content = resolved;
+        }
+        let expanded = match expand_templates(&source.content) {
+            Ok(Expansion { source: Cow::Owned(expanded), .. }) => Some(expanded),
+            Ok(_) => None,
+            Err(e) => return Err(format!("{}: {e}", source.path.display())),
+        };
+        if let Some(expanded) = expanded {
+            source.content = expanded;
+        }
+
         // Cache and return
         self.cache.insert(cache_key.clone(), source);
         Ok(&self.cache[&cache_key])
@@ -125,6 +206,21 @@ impl Loader {
         let base_dir = base_path.parent().unwrap_or(&self.root_path);
         let resolved_path = base_dir.join(path_str);
 
+        // In-memory files never touch the filesystem, so the root check is lexical
+        let normalized = normalize_path(&resolved_path);
+        if let Some(content) = self.files.get(&normalized) {
+            if !normalized.starts_with(normalize_path(&self.root_path)) {
+                return Err(format!(
+                    "Security: Cannot load '{}' - path escapes project root",
+                    uri
+                ));
+            }
+            return Ok(ModuleSource {
+                content: content.clone(),
+                path: normalized,
+            });
+        }
+
         // Security: Check that we're not escaping the root path
         let canonical_root = self.root_path.canonicalize()
             .unwrap_or_else(|_| self.root_path.clone());
@@ -175,6 +271,443 @@ impl Loader {
     pub fn loaded_modules(&self) -> Vec<&str> {
         self.cache.keys().map(|s| s.as_str()).collect()
     }
+
+    /// The loaded module whose resolved path is `path`, if any.
+    pub fn module_at(&self, path: &Path) -> Option<&ModuleSource> {
+        self.cache.values().find(|module| module.path == path)
+    }
+}
+
+/// Resolves `.` and `..` components without consulting the filesystem.
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+    use std::path::Component;
+
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !out.pop() {
+                    out.push("..");
+                }
+            }
+            other => out.push(other.as_os_str()),
+        }
+    }
+    out
+}
+
+// ─── Module graph ───────────────────────────────────────────────────────────
+//
+// Which modules import which, as written in each module's abstract. Discovery
+// walks it instead of recursing blindly, so an import cycle is reported with the
+// links that form it rather than overflowing the stack; the LSP assembles one
+// over the whole workspace.
+
+/// One `[Alias](uri)` link in a module's abstract, and where it resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportLink {
+    pub alias: String,
+    pub uri: String,
+    /// Byte span of the whole link in the importing module's source.
+    pub span: Span,
+    /// The imported module's path, or why it could not be loaded.
+    pub target: Result<PathBuf, String>,
+}
+
+/// A resolved import: module `from` imports module `to` as `alias`. Both are
+/// indices into [`ModuleGraph::modules`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleImport {
+    pub from: usize,
+    pub to: usize,
+    pub alias: String,
+    pub uri: String,
+    pub span: Span,
+}
+
+/// An import that could not be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedImport {
+    pub from: usize,
+    pub alias: String,
+    pub uri: String,
+    pub span: Span,
+    pub error: String,
+}
+
+/// Modules and the imports between them. Paths are normalized, so a module
+/// reached by two routes is one node.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleGraph {
+    modules: Vec<PathBuf>,
+    imports: Vec<ModuleImport>,
+    unresolved: Vec<UnresolvedImport>,
+    index: HashMap<PathBuf, usize>,
+}
+
+impl ModuleGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every module, in the order first reached.
+    pub fn modules(&self) -> &[PathBuf] {
+        &self.modules
+    }
+
+    pub fn imports(&self) -> &[ModuleImport] {
+        &self.imports
+    }
+
+    pub fn unresolved(&self) -> &[UnresolvedImport] {
+        &self.unresolved
+    }
+
+    /// The module at `path`, if it is in the graph.
+    pub fn module(&self, path: &Path) -> Option<usize> {
+        self.index.get(&normalize_path(path)).copied()
+    }
+
+    /// The module at `path`, added if it is not in the graph yet.
+    pub fn add_module(&mut self, path: &Path) -> usize {
+        let key = normalize_path(path);
+        if let Some(&id) = self.index.get(&key) {
+            return id;
+        }
+        let id = self.modules.len();
+        self.modules.push(key.clone());
+        self.index.insert(key, id);
+        id
+    }
+
+    /// Record `from`'s imports. Each resolved target is added as a module.
+    pub fn add_imports(&mut self, from: usize, links: Vec<ImportLink>) {
+        for link in links {
+            match link.target {
+                Ok(target) => {
+                    let to = self.add_module(&target);
+                    self.imports.push(ModuleImport { from, to, alias: link.alias, uri: link.uri, span: link.span });
+                }
+                Err(error) => self.unresolved.push(UnresolvedImport {
+                    from,
+                    alias: link.alias,
+                    uri: link.uri,
+                    span: link.span,
+                    error,
+                }),
+            }
+        }
+    }
+
+    /// What `module` imports, in source order.
+    pub fn imports_of(&self, module: usize) -> impl Iterator<Item = &ModuleImport> {
+        self.imports.iter().filter(move |import| import.from == module)
+    }
+
+    /// The imports that name `module`.
+    pub fn importers_of(&self, module: usize) -> impl Iterator<Item = &ModuleImport> {
+        self.imports.iter().filter(move |import| import.to == module)
+    }
+
+    /// `module`'s path relative to the first module's directory, for display.
+    pub fn name(&self, module: usize) -> String {
+        let path = &self.modules[module];
+        let base = self.modules.first().and_then(|entry| entry.parent());
+        match base.map(|base| path.strip_prefix(base)) {
+            Some(Ok(relative)) if !relative.as_os_str().is_empty() => relative.display().to_string(),
+            _ => path.display().to_string(),
+        }
+    }
+
+    /// Every import cycle, each found once: the chain of imports from a
+    /// module back to itself.
+    pub fn cycles(&self) -> Vec<ImportCycle> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Mark {
+            New,
+            Open,
+            Done,
+        }
+
+        fn visit(graph: &ModuleGraph, module: usize, marks: &mut [Mark], path: &mut Vec<usize>, cycles: &mut Vec<ImportCycle>) {
+            marks[module] = Mark::Open;
+            for (i, import) in graph.imports.iter().enumerate().filter(|(_, import)| import.from == module) {
+                match marks[import.to] {
+                    Mark::New => {
+                        path.push(i);
+                        visit(graph, import.to, marks, path, cycles);
+                        path.pop();
+                    }
+                    Mark::Open => {
+                        // The open chain from `import.to` down to here, closed by this import.
+                        let start = path.iter().position(|&e| graph.imports[e].from == import.to).unwrap_or(path.len());
+                        let imports: Vec<ModuleImport> = path[start..].iter().chain([&i]).map(|&e| graph.imports[e].clone()).collect();
+                        let modules = imports.iter().map(|import| graph.modules[import.from].clone()).collect();
+                        cycles.push(ImportCycle { modules, imports });
+                    }
+                    Mark::Done => {}
+                }
+            }
+            marks[module] = Mark::Done;
+        }
+
+        let mut marks = vec![Mark::New; self.modules.len()];
+        let mut cycles = Vec::new();
+        while let Some(module) = marks.iter().position(|mark| *mark == Mark::New) {
+            visit(self, module, &mut marks, &mut Vec::new(), &mut cycles);
+        }
+        cycles
+    }
+}
+
+/// A chain of imports that leads from a module back to itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportCycle {
+    /// The module each import sits in: each imports the next, and the last
+    /// imports the first.
+    pub modules: Vec<PathBuf>,
+    /// The import leading out of each module, with its span in that module.
+    pub imports: Vec<ModuleImport>,
+}
+
+impl std::fmt::Display for ImportCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path: Vec<String> = self.modules.iter().chain(self.modules.first()).map(|p| p.display().to_string()).collect();
+        write!(f, "import cycle: {}", path.join(" → "))?;
+        for (module, import) in self.modules.iter().zip(&self.imports) {
+            write!(
+                f,
+                "\n  {} (bytes {}..{}) imports [{}]({})",
+                module.display(),
+                import.span.start,
+                import.span.end,
+                import.alias,
+                import.uri
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Loader {
+    /// The imports declared in `source`'s abstract, each resolved relative
+    /// to `path`.
+    pub fn imports(&mut self, path: &Path, source: &str) -> Vec<ImportLink> {
+        scan_dependencies(source)
+            .into_iter()
+            .map(|dep| ImportLink {
+                target: self.resolve(path, &dep.uri).map(|module| module.path.clone()),
+                span: Span::new(dep.start, dep.end),
+                alias: dep.alias,
+                uri: dep.uri,
+            })
+            .collect()
+    }
+
+    /// Every module reachable from `entry` (whose contents are `source`),
+    /// following imports breadth-first. Never fails: imports that cannot be
+    /// loaded are kept in [`ModuleGraph::unresolved`], and a cycle is just an
+    /// edge back to a module already in the graph.
+    pub fn module_graph(&mut self, entry: &Path, source: &str) -> ModuleGraph {
+        let mut graph = ModuleGraph::new();
+        graph.add_module(entry);
+        let mut pending = VecDeque::from([(entry.to_path_buf(), source.to_string())]);
+        while let Some((path, content)) = pending.pop_front() {
+            let from = graph.add_module(&path);
+            let links = self.imports(&path, &content);
+            for link in &links {
+                let Ok(target) = &link.target else { continue };
+                if graph.module(target).is_some() {
+                    continue;
+                }
+                graph.add_module(target);
+                // Loaded moments ago by `imports`, so this is a cache hit.
+                if let Ok(module) = self.resolve(&path, &link.uri) {
+                    pending.push_back((module.path.clone(), module.content.clone()));
+                }
+            }
+            graph.add_imports(from, links);
+        }
+        graph
+    }
+}
+
+// ─── Target sections ────────────────────────────────────────────────────────
+//
+// One codebase for native and wasm: `## When compiling for web:` guards the blocks
+// below it until the next guard, `## Otherwise:` or `## Always:`. Sections are
+// resolved on the raw text before anything lexes it, and a dropped line is
+// overwritten with spaces rather than removed, so every byte offset — and with it
+// every span and diagnostic — in the rest of the file stays where the author
+// wrote it.
+
+/// The platform a program is compiled for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Platform {
+    /// A native executable, or the interpreter on a desktop.
+    Native,
+    /// WebAssembly: `largo build --emit wasm`, a `wasm32-*` target, the browser.
+    Web,
+}
+
+/// What a program is compiled for, as far as `## When compiling …` sections
+/// are concerned: a platform and a set of enabled features.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompileTarget {
+    pub platform: Platform,
+    /// Enabled feature names, lowercase.
+    pub features: Vec<String>,
+}
+
+impl CompileTarget {
+    pub fn native() -> Self {
+        CompileTarget { platform: Platform::Native, features: Vec::new() }
+    }
+
+    pub fn web() -> Self {
+        CompileTarget { platform: Platform::Web, features: Vec::new() }
+    }
+
+    /// The platform this code runs on: web in a wasm build (the browser
+    /// Studio interprets for the browser), native otherwise.
+    pub fn host() -> Self {
+        if cfg!(target_arch = "wasm32") {
+            Self::web()
+        } else {
+            Self::native()
+        }
+    }
+
+    /// The target a Rust build for `triple` produces: any `wasm32-*` triple,
+    /// or the `wasm` shorthand, is web.
+    pub fn for_triple(triple: Option<&str>) -> Self {
+        match triple {
+            Some(triple) if triple == "wasm" || triple.starts_with("wasm32") => Self::web(),
+            _ => Self::native(),
+        }
+    }
+
+    /// This target with `features` enabled as well.
+    pub fn with_features<I, S>(mut self, features: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for feature in features {
+            let feature = feature.as_ref().trim().to_lowercase();
+            if !feature.is_empty() && !self.features.contains(&feature) {
+                self.features.push(feature);
+            }
+        }
+        self
+    }
+
+    pub fn has_feature(&self, name: &str) -> bool {
+        self.features.iter().any(|feature| feature.eq_ignore_ascii_case(name))
+    }
+
+    fn is_platform(&self, name: &str) -> bool {
+        match self.platform {
+            Platform::Native => name == "native",
+            Platform::Web => name == "web" || name == "wasm",
+        }
+    }
+}
+
+impl Default for CompileTarget {
+    fn default() -> Self {
+        Self::host()
+    }
+}
+
+/// A header that opens or closes a target section.
+enum Guard {
+    /// `## When compiling …:`, and whether it holds for the target.
+    When(bool),
+    Otherwise,
+    Always,
+}
+
+fn guard(line: &str, target: &CompileTarget) -> Option<Guard> {
+    let header = line.strip_prefix("##")?.trim().trim_end_matches([':', '.']).trim_end().to_lowercase();
+    match header.as_str() {
+        "otherwise" => return Some(Guard::Otherwise),
+        "always" => return Some(Guard::Always),
+        _ => {}
+    }
+    let condition = header.strip_prefix("when compiling ")?.trim();
+    let name = |rest: &str| rest.trim().trim_matches(['"', '`']).to_string();
+    let holds = if let Some(platform) = condition.strip_prefix("for ") {
+        target.is_platform(&name(platform))
+    } else if let Some(feature) = condition.strip_prefix("without ") {
+        !target.has_feature(&name(feature.strip_prefix("feature ").unwrap_or(feature)))
+    } else if let Some(feature) = condition.strip_prefix("with ") {
+        target.has_feature(&name(feature.strip_prefix("feature ").unwrap_or(feature)))
+    } else {
+        return None;
+    };
+    Some(Guard::When(holds))
+}
+
+/// Keep the target sections of `source` that apply to `target` and blank out
+/// the rest.
+///
+/// A section opens at a `## When compiling …:` header and runs to the next
+/// guard header; `## Otherwise:` flips the section above it, and `## Always:`
+/// returns to code every target sees. The conditions are:
+///
+/// - `for web` (or `for wasm`) / `for native`
+/// - `with feature <name>` / `without feature <name>` (`feature` optional)
+///
+/// A platform this compiler does not know never matches. Guard headers and
+/// the lines of dropped sections become spaces of the same byte length, so
+/// spans in what remains are unchanged. Returns the source untouched when it
+/// has no sections.
+///
+/// ```
+/// use logicaffeine_compile::loader::{resolve_target_sections, CompileTarget};
+///
+/// let source = "## When compiling for web:\n## To save:\n    Show \"web\".\n## Otherwise:\n## To save:\n    Show \"disk\".\n";
+/// let native = resolve_target_sections(source, &CompileTarget::native());
+/// assert!(native.contains("disk") && !native.contains("web"));
+/// assert_eq!(native.len(), source.len());
+/// ```
+pub fn resolve_target_sections<'a>(source: &'a str, target: &CompileTarget) -> Cow<'a, str> {
+    if !source.lines().any(|line| matches!(guard(line, target), Some(Guard::When(_)))) {
+        return Cow::Borrowed(source);
+    }
+    let mut resolved = String::with_capacity(source.len());
+    // Whether the `## When` above held, while inside a section.
+    let mut section: Option<bool> = None;
+    let mut keep = true;
+    for line in source.split_inclusive('\n') {
+        let is_guard = match guard(line, target) {
+            Some(Guard::When(holds)) => {
+                section = Some(holds);
+                keep = holds;
+                true
+            }
+            Some(Guard::Otherwise) if section.is_some() => {
+                keep = section == Some(false);
+                true
+            }
+            Some(Guard::Always) if section.is_some() => {
+                section = None;
+                keep = true;
+                true
+            }
+            _ => false,
+        };
+        if keep && !is_guard {
+            resolved.push_str(line);
+        } else {
+            let text = line.trim_end_matches(['\r', '\n']);
+            resolved.extend(std::iter::repeat(' ').take(text.len()));
+            resolved.push_str(&line[text.len()..]);
+        }
+    }
+    Cow::Owned(resolved)
 }
 
 // ─── Standard-library prelude (Phase 10) ────────────────────────────────────
@@ -216,7 +749,7 @@ const PRELUDE_MODULES: &[&str] = &[
     STD_UUID,
 ];
 
-fn is_ident_byte(b: u8) -> bool {
+pub(crate) fn is_ident_byte(b: u8) -> bool {
     b.is_ascii_alphanumeric() || b == b'_'
 }
 
@@ -229,7 +762,7 @@ fn is_ident_byte(b: u8) -> bool {
 /// bare mention of one would wrongly pull a whole module into an unrelated program. A
 /// program names the distinctive *type* (`Severity`) — or defines its own — so the type
 /// name is the safe trigger. Field lines (`a sender, which is Int.`) are lowercase and skip.
-fn defined_names(code: &str) -> Vec<String> {
+pub(crate) fn defined_names(code: &str) -> Vec<String> {
     let mut names = Vec::new();
     for line in code.lines() {
         let t = line.trim();
@@ -381,8 +914,45 @@ fn references(source: &str, name: &str) -> bool {
 /// unchanged when the program references no stdlib vocabulary or opts out with
 /// `## NoPrelude` (in which case the opt-out marker is stripped so it never
 /// reaches the parser). This is the auto-import seam for both the interpreter and
-/// the compiler.
+/// the compiler. Target sections are resolved for the host first; see
+/// [`apply_prelude_for`].
 pub fn apply_prelude(source: &str) -> std::borrow::Cow<'_, str> {
+    apply_prelude_for(source, &CompileTarget::host())
+}
+
+/// [`apply_prelude`] for a program compiled for `target`: its target sections
+/// are resolved (see [`resolve_target_sections`]) and its templates expanded
+/// (see [`crate::template`]) before the prelude is chosen, so a module
+/// referenced only from a dropped section is not pulled in. A malformed
+/// template is left as written — [`compile_project`](crate::compile::compile_project)
+/// and `largo check` report it.
+pub fn apply_prelude_for<'a>(source: &'a str, target: &CompileTarget) -> std::borrow::Cow<'a, str> {
+    let resolved = resolve_target_sections(source, target);
+    let expanded = match expand_templates(&resolved) {
+        Ok(Expansion { source: Cow::Owned(expanded), .. }) => Some(expanded),
+        _ => None,
+    };
+    match expanded.map_or(resolved, Cow::Owned) {
+        Cow::Borrowed(source) => prepend_prelude(source),
+        Cow::Owned(resolved) => Cow::Owned(prepend_prelude(&resolved).into_owned()),
+    }
+}
+
+/// Where `span`, an offset into what [`apply_prelude_for`] made of `source`,
+/// was written in `source` — and the template copy it lies in, if any. Lets a
+/// diagnostic from the parser point at the line the author wrote.
+pub fn trace_span(source: &str, target: &CompileTarget, span: Span) -> (Span, Option<ExpansionTrace>) {
+    let resolved = resolve_target_sections(source, target);
+    let Ok(expansion) = expand_templates(&resolved) else {
+        return (span, None);
+    };
+    // The prelude is prepended, so everything after it shifts by its length.
+    let prelude = apply_prelude_for(source, target).len().saturating_sub(expansion.source.len());
+    let span = Span::new(span.start.saturating_sub(prelude), span.end.saturating_sub(prelude));
+    (expansion.original_span(span), expansion.trace(span.start).cloned())
+}
+
+fn prepend_prelude(source: &str) -> std::borrow::Cow<'_, str> {
     if let Some(stripped) = strip_no_prelude(source) {
         return std::borrow::Cow::Owned(stripped);
     }
@@ -489,6 +1059,32 @@ mod tests {
         assert!(result.unwrap_err().contains("Failed to read"));
     }
 
+    #[test]
+    fn test_virtual_files_resolve_before_filesystem() {
+        let mut files = HashMap::new();
+        files.insert(PathBuf::from("/project/lib/geo.md"), "A Point has:\n    an x, which is Int.\n".to_string());
+        let mut loader = Loader::with_files(PathBuf::from("/project"), files);
+
+        let source = loader
+            .resolve(Path::new("/project/main.md"), "file:./lib/geo.md")
+            .expect("virtual file should resolve");
+        assert!(source.content.contains("Point"));
+        assert_eq!(source.path, PathBuf::from("/project/lib/geo.md"));
+
+        let nested = loader.resolve(Path::new("/project/lib/geo.md"), "../lib/./geo.md");
+        assert!(nested.is_ok(), "relative paths normalize: {:?}", nested);
+    }
+
+    #[test]
+    fn test_virtual_files_stay_inside_root() {
+        let mut files = HashMap::new();
+        files.insert(PathBuf::from("/secret.md"), "hidden".to_string());
+        let mut loader = Loader::with_files(PathBuf::from("/project"), files);
+
+        let result = loader.resolve(Path::new("/project/main.md"), "file:../secret.md");
+        assert!(result.unwrap_err().contains("escapes project root"));
+    }
+
     // ─── Prelude auto-import internals ──────────────────────────────────────
 
     #[test]
@@ -555,4 +1151,107 @@ mod tests {
         assert!(defines("## Definition\nA Message has:\n    a kind, which is Int.", "Message"));
         assert!(!defines("## Main\n    Let x be 1.", "Message"));
     }
+
+    fn project(files: &[(&str, &str)]) -> Loader {
+        let files = files.iter().map(|(path, content)| (PathBuf::from(*path), content.to_string())).collect();
+        Loader::with_files(PathBuf::from("/project"), files)
+    }
+
+    #[test]
+    fn module_graph_records_each_module_once() {
+        let mut loader = project(&[
+            ("/project/geo.md", "# Geo\n\nUses [Units](./units.md).\n"),
+            ("/project/units.md", "# Units\n\nNo imports.\n"),
+        ]);
+        let main = "# Main\n\nUses [Geometry](geo.md), [Units](./units.md) and [Missing](gone.md).\n";
+        let graph = loader.module_graph(Path::new("/project/main.md"), main);
+
+        assert_eq!(graph.modules().len(), 3);
+        assert_eq!(graph.name(1), "geo.md");
+        let units = graph.module(Path::new("/project/units.md")).unwrap();
+        let importers: Vec<&str> = graph.importers_of(units).map(|i| i.alias.as_str()).collect();
+        assert_eq!(importers, ["Units", "Units"]);
+
+        let [missing] = graph.unresolved() else { panic!("{:?}", graph.unresolved()) };
+        assert_eq!(missing.alias, "Missing");
+        assert_eq!(&main[missing.span.start..missing.span.end], "[Missing](gone.md)");
+        assert!(graph.cycles().is_empty());
+    }
+
+    #[test]
+    fn module_graph_reports_cycles_with_their_links() {
+        let main = "# Main\n\nUses [A](a.md).\n";
+        let mut loader = project(&[
+            ("/project/main.md", main),
+            ("/project/a.md", "# A\n\nUses [B](b.md).\n"),
+            ("/project/b.md", "# B\n\nUses [Main](main.md).\n"),
+        ]);
+        let graph = loader.module_graph(Path::new("/project/main.md"), main);
+
+        let cycles = graph.cycles();
+        assert_eq!(cycles.len(), 1, "{cycles:?}");
+        let cycle = &cycles[0];
+        let aliases: Vec<&str> = cycle.imports.iter().map(|i| i.alias.as_str()).collect();
+        assert_eq!(aliases, ["A", "B", "Main"]);
+        assert_eq!(cycle.modules[0], PathBuf::from("/project/main.md"));
+        assert_eq!(&main[cycle.imports[0].span.start..cycle.imports[0].span.end], "[A](a.md)");
+
+        let message = cycle.to_string();
+        assert!(
+            message.starts_with("import cycle: /project/main.md → /project/a.md → /project/b.md → /project/main.md"),
+            "{message}"
+        );
+        assert!(message.contains("imports [Main](main.md)"), "{message}");
+    }
+
+    #[test]
+    fn a_module_importing_itself_is_a_cycle() {
+        let main = "# Main\n\nUses [Me](main.md).\n";
+        let mut loader = project(&[("/project/main.md", main)]);
+        let cycles = loader.module_graph(Path::new("/project/main.md"), main).cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].imports.len(), 1);
+    }
+
+    const TWO_TARGETS: &str = "## When compiling for web:\n## To save (t: Text):\n    Show \"local storage\".\n## Otherwise:\n## To save (t: Text):\n    Show \"disk\".\n## Always:\n## Main\n    Call save with \"x\".\n";
+
+    #[test]
+    fn target_sections_keep_the_matching_branch_in_place() {
+        let native = resolve_target_sections(TWO_TARGETS, &CompileTarget::native());
+        let web = resolve_target_sections(TWO_TARGETS, &CompileTarget::web());
+        assert!(native.contains("disk") && !native.contains("local storage"), "{native}");
+        assert!(web.contains("local storage") && !web.contains("disk"), "{web}");
+        for resolved in [&native, &web] {
+            assert_eq!(resolved.len(), TWO_TARGETS.len());
+            assert_eq!(resolved.lines().count(), TWO_TARGETS.lines().count());
+            assert!(!resolved.contains("## When") && !resolved.contains("## Otherwise"), "{resolved}");
+            assert!(resolved.contains("## Main\n    Call save"), "{resolved}");
+        }
+        assert_eq!(resolve_target_sections(TWO_TARGETS, &CompileTarget::for_triple(Some("wasm32-unknown-unknown"))), web);
+    }
+
+    #[test]
+    fn feature_sections_follow_the_enabled_features() {
+        let source = "## When compiling with feature Telemetry:\n## To report:\n    Show \"sent\".\n## When compiling without telemetry:\n## To report:\n    Show \"off\".\n";
+        let on = resolve_target_sections(source, &CompileTarget::native().with_features(["telemetry"]));
+        let off = resolve_target_sections(source, &CompileTarget::native());
+        assert!(on.contains("sent") && !on.contains("off"), "{on}");
+        assert!(off.contains("off") && !off.contains("sent"), "{off}");
+    }
+
+    #[test]
+    fn sources_without_sections_are_borrowed() {
+        // `## Otherwise` outside a section is an ordinary header.
+        let source = "## Otherwise\nJust prose.\n## Main\n    Show 1.\n";
+        assert!(matches!(resolve_target_sections(source, &CompileTarget::web()), Cow::Borrowed(_)));
+        assert!(matches!(resolve_target_sections("## When compiling for ios:", &CompileTarget::native()), Cow::Owned(s) if s.trim().is_empty()));
+    }
+
+    #[test]
+    fn loaded_modules_are_resolved_for_the_loader_target() {
+        let files = HashMap::from([(PathBuf::from("/project/store.md"), TWO_TARGETS.to_string())]);
+        let mut loader = Loader::with_files(PathBuf::from("/project"), files).with_target(CompileTarget::web());
+        let module = loader.resolve(Path::new("/project/main.md"), "file:./store.md").unwrap();
+        assert!(module.content.contains("local storage") && !module.content.contains("disk"));
+    }
 }
@@ -0,0 +1,192 @@
+//! Memory usage reporting for the tree-walking interpreter.
+//!
+//! Makes space complexity visible: run a program with
+//! [`interpret_with_memory_report`](crate::ui_bridge::interpret_with_memory_report)
+//! and each top-level statement of `Main` reports how many `Seq`, `Map` and
+//! `Text` values it created and how large they grew — including everything the
+//! statement called. A loop that pushes `n` items shows a `Seq` peak of `n`; a
+//! loop that rebuilds a `Text` shows `n` allocations.
+//!
+//! # Accounting
+//!
+//! After every executed statement the interpreter takes a census of the values
+//! reachable from live bindings (descending into boxed `Seq` elements and `Map`
+//! entries), attributed to the enclosing top-level statement:
+//!
+//! - An **allocation** is a collection that was not reachable at the previous
+//!   census — a fresh `Seq`/`Map`/`Text` stored somewhere the program can see.
+//!   Temporaries that never reach a binding are not counted.
+//! - A **peak** is the largest length observed: elements for `Seq` and `Map`,
+//!   bytes for `Text`.
+//!
+//! Tracking is opt-in and costs nothing when off; the census is linear in the
+//! live data, so it is meant for teaching-sized programs, not benchmarks.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ast::stmt::Stmt;
+use crate::intern::Interner;
+use crate::interpreter::{ListRepr, RuntimeValue};
+
+/// Allocation count and peak length for one kind of collection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectionStats {
+    pub allocations: usize,
+    pub peak_len: usize,
+}
+
+impl CollectionStats {
+    fn record(&mut self, fresh: bool, len: usize) {
+        self.allocations += usize::from(fresh);
+        self.peak_len = self.peak_len.max(len);
+    }
+
+    fn merge(&mut self, other: CollectionStats) {
+        self.allocations += other.allocations;
+        self.peak_len = self.peak_len.max(other.peak_len);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.allocations == 0 && self.peak_len == 0
+    }
+}
+
+/// Memory use of one top-level statement, including the functions it called.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatementMemory {
+    /// Position among the program's top-level statements.
+    pub index: usize,
+    /// A short description, e.g. `Let numbers` or `While`.
+    pub label: String,
+    pub seqs: CollectionStats,
+    pub maps: CollectionStats,
+    pub texts: CollectionStats,
+}
+
+impl StatementMemory {
+    fn is_empty(&self) -> bool {
+        self.seqs.is_empty() && self.maps.is_empty() && self.texts.is_empty()
+    }
+}
+
+/// Per-statement `Seq`/`Map`/`Text` usage of one interpreter run. Statements
+/// that never touched a collection are left out.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryReport {
+    pub statements: Vec<StatementMemory>,
+}
+
+impl MemoryReport {
+    /// Whole-program totals: allocations summed, peaks maxed.
+    pub fn totals(&self) -> (CollectionStats, CollectionStats, CollectionStats) {
+        let mut seqs = CollectionStats::default();
+        let mut maps = CollectionStats::default();
+        let mut texts = CollectionStats::default();
+        for stmt in &self.statements {
+            seqs.merge(stmt.seqs);
+            maps.merge(stmt.maps);
+            texts.merge(stmt.texts);
+        }
+        (seqs, maps, texts)
+    }
+
+    /// A plain-text table, one row per statement.
+    pub fn to_table(&self) -> String {
+        let mut out = format!("{:<24} {:>13} {:>13} {:>13}\n", "statement", "Seq new/peak", "Map new/peak", "Text new/peak");
+        let cell = |s: CollectionStats| format!("{}/{}", s.allocations, s.peak_len);
+        for stmt in &self.statements {
+            out.push_str(&format!(
+                "{:<24} {:>13} {:>13} {:>13}\n",
+                stmt.label,
+                cell(stmt.seqs),
+                cell(stmt.maps),
+                cell(stmt.texts)
+            ));
+        }
+        out
+    }
+}
+
+/// A short description of a top-level statement for the report.
+pub(crate) fn statement_label(stmt: &Stmt, interner: &Interner) -> String {
+    match stmt {
+        Stmt::Let { var, .. } => format!("Let {}", interner.resolve(*var)),
+        Stmt::Set { target, .. } => format!("Set {}", interner.resolve(*target)),
+        Stmt::Call { function, .. } => format!("Call {}", interner.resolve(*function)),
+        // The variant name: everything before the Debug payload
+        other => {
+            let debug = format!("{other:?}");
+            let end = debug.find([' ', '{', '(']).unwrap_or(debug.len());
+            debug[..end].to_string()
+        }
+    }
+}
+
+/// The census state behind a [`MemoryReport`].
+#[derive(Default)]
+pub(crate) struct MemoryTracker {
+    statements: Vec<StatementMemory>,
+    /// Collections reachable at the previous census, by address.
+    live: HashSet<usize>,
+}
+
+impl MemoryTracker {
+    /// Attribute everything observed from now on to a new top-level statement.
+    pub(crate) fn begin_statement(&mut self, index: usize, label: String) {
+        self.statements.push(StatementMemory {
+            index,
+            label,
+            seqs: CollectionStats::default(),
+            maps: CollectionStats::default(),
+            texts: CollectionStats::default(),
+        });
+    }
+
+    /// Record every collection reachable from `roots`.
+    pub(crate) fn census<'v>(&mut self, roots: impl Iterator<Item = &'v RuntimeValue>) {
+        let Some(stmt) = self.statements.last_mut() else { return };
+        let mut seen = HashSet::new();
+        let mut pending: Vec<RuntimeValue> = roots.cloned().collect();
+        while let Some(value) = pending.pop() {
+            match &value {
+                RuntimeValue::List(list) => {
+                    let addr = std::rc::Rc::as_ptr(list) as *const () as usize;
+                    if !seen.insert(addr) {
+                        continue;
+                    }
+                    let list = list.borrow();
+                    stmt.seqs.record(!self.live.contains(&addr), list.len());
+                    if let ListRepr::Boxed(items) = &*list {
+                        pending.extend(items.iter().cloned());
+                    }
+                }
+                RuntimeValue::Map(map) => {
+                    let addr = std::rc::Rc::as_ptr(map) as *const () as usize;
+                    if !seen.insert(addr) {
+                        continue;
+                    }
+                    let map = map.borrow();
+                    stmt.maps.record(!self.live.contains(&addr), map.len());
+                    for (k, v) in map.iter() {
+                        pending.push(k.clone());
+                        pending.push(v.clone());
+                    }
+                }
+                RuntimeValue::Text(text) => {
+                    let addr = std::rc::Rc::as_ptr(text) as *const () as usize;
+                    if seen.insert(addr) {
+                        stmt.texts.record(!self.live.contains(&addr), text.len());
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.live = seen;
+    }
+
+    pub(crate) fn finish(self) -> MemoryReport {
+        MemoryReport { statements: self.statements.into_iter().filter(|s| !s.is_empty()).collect() }
+    }
+}
@@ -2289,6 +2289,22 @@ fn collect_idents_stmt(stmt: &Stmt, f: &mut dyn FnMut(Symbol, usize)) {
         Stmt::If { cond, .. } | Stmt::While { cond, .. } => collect_idents_expr(cond, f),
         Stmt::Repeat { iterable, .. } => collect_idents_expr(iterable, f),
         Stmt::Show { object, .. } | Stmt::Give { object, .. } => collect_idents_expr(object, f),
+        Stmt::RunCommand { command, timeout, .. } => {
+            collect_idents_expr(command, f);
+            if let Some(timeout) = timeout {
+                collect_idents_expr(timeout, f);
+            }
+        }
+        Stmt::Sip { file, sources, .. } => {
+            collect_idents_expr(file, f);
+            collect_idents_expr(sources, f);
+        }
+        Stmt::Log { message, fields, .. } => {
+            collect_idents_expr(message, f);
+            for (_, value) in fields {
+                collect_idents_expr(value, f);
+            }
+        }
         Stmt::Push { value, collection }
         | Stmt::Add { value, collection }
         | Stmt::Remove { value, collection } => {
@@ -2307,7 +2323,8 @@ fn collect_idents_stmt(stmt: &Stmt, f: &mut dyn FnMut(Symbol, usize)) {
         Stmt::Inspect { target, .. } => collect_idents_expr(target, f),
         Stmt::RuntimeAssert { condition, .. } => collect_idents_expr(condition, f),
         Stmt::Sleep { milliseconds } => collect_idents_expr(milliseconds, f),
-        Stmt::ReadFrom { source: ReadSource::File(p), .. } => collect_idents_expr(p, f),
+        Stmt::ReadFrom { source: ReadSource::File(p), .. }
+        | Stmt::ReadFrom { source: ReadSource::Settings { path: Some(p), .. }, .. } => collect_idents_expr(p, f),
         _ => {}
     }
     // Nested blocks (mirror the block recursion the production walk does).
@@ -3977,6 +3994,22 @@ fn for_each_direct_expr(stmt: &Stmt, f: &mut impl FnMut(&Expr)) {
             f(value);
         }
         Stmt::Show { object, .. } => f(object),
+        Stmt::RunCommand { command, timeout, .. } => {
+            f(command);
+            if let Some(timeout) = timeout {
+                f(timeout);
+            }
+        }
+        Stmt::Sip { file, sources, .. } => {
+            f(file);
+            f(sources);
+        }
+        Stmt::Log { message, fields, .. } => {
+            f(message);
+            for (_, value) in fields {
+                f(value);
+            }
+        }
         // The pushed/added value is an expression that may read other arrays
         // (e.g. `Push item li of left to result` — the "build B from A" loop).
         Stmt::Push { value, .. } | Stmt::Add { value, .. } | Stmt::Remove { value, .. } => f(value),
@@ -4670,6 +4703,22 @@ fn record_stmt_exprs(stmt: &Stmt, st: &RichAbstractState, facts: &mut OracleFact
         Stmt::If { cond, .. } | Stmt::While { cond, .. } => record_expr(cond, st, facts),
         Stmt::Repeat { iterable, .. } => record_expr(iterable, st, facts),
         Stmt::Show { object, .. } | Stmt::Give { object, .. } => record_expr(object, st, facts),
+        Stmt::RunCommand { command, timeout, .. } => {
+            record_expr(command, st, facts);
+            if let Some(timeout) = timeout {
+                record_expr(timeout, st, facts);
+            }
+        }
+        Stmt::Sip { file, sources, .. } => {
+            record_expr(file, st, facts);
+            record_expr(sources, st, facts);
+        }
+        Stmt::Log { message, fields, .. } => {
+            record_expr(message, st, facts);
+            for (_, value) in fields {
+                record_expr(value, st, facts);
+            }
+        }
         Stmt::Push { value, collection }
         | Stmt::Add { value, collection }
         | Stmt::Remove { value, collection } => {
@@ -4688,7 +4737,8 @@ fn record_stmt_exprs(stmt: &Stmt, st: &RichAbstractState, facts: &mut OracleFact
         Stmt::Inspect { target, .. } => record_expr(target, st, facts),
         Stmt::RuntimeAssert { condition, .. } => record_expr(condition, st, facts),
         Stmt::Sleep { milliseconds } => record_expr(milliseconds, st, facts),
-        Stmt::ReadFrom { source: ReadSource::File(p), .. } => record_expr(p, st, facts),
+        Stmt::ReadFrom { source: ReadSource::File(p), .. }
+        | Stmt::ReadFrom { source: ReadSource::Settings { path: Some(p), .. }, .. } => record_expr(p, st, facts),
         _ => {}
     }
 }
@@ -4771,6 +4821,12 @@ fn rich_walk_stmt(stmt: &Stmt, st: &mut RichAbstractState, facts: &mut OracleFac
             st.invalidate_var(*var);
             st.aliases.unlink(*var);
         }
+        Stmt::RunCommand { output, errors, exit_code, .. } => {
+            for var in [output, errors, exit_code].into_iter().flatten() {
+                st.invalidate_var(*var);
+                st.aliases.unlink(*var);
+            }
+        }
         _ => {}
     }
 }
@@ -5772,6 +5828,11 @@ fn collect_mut_stmt(s: &Stmt, out: &mut Vec<Symbol>) {
             }
         }
         Stmt::ReadFrom { var, .. } => add_unique(*var, out),
+        Stmt::RunCommand { output, errors, exit_code, .. } => {
+            for var in [output, errors, exit_code].into_iter().flatten() {
+                add_unique(*var, out);
+            }
+        }
         Stmt::If { then_block, else_block, .. } => {
             for st in *then_block {
                 collect_mut_stmt(st, out);
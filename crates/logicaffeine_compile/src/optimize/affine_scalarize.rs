@@ -529,6 +529,11 @@ fn visit_idents(e: &Expr, f: &mut impl FnMut(Symbol)) {
                 visit_idents(value, f);
             }
         }),
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            for x in [*element, *iterable].into_iter().chain(*value).chain(*filter) {
+                visit_idents(x, f);
+            }
+        }
         _ => {}
     }
 }
@@ -545,6 +550,22 @@ fn for_each_stmt_expr(s: &Stmt, f: &mut impl FnMut(&Expr)) {
             f(object);
             f(recipient);
         }
+        Stmt::RunCommand { command, timeout, .. } => {
+            f(command);
+            if let Some(timeout) = timeout {
+                f(timeout);
+            }
+        }
+        Stmt::Sip { file, sources, .. } => {
+            f(file);
+            f(sources);
+        }
+        Stmt::Log { message, fields, .. } => {
+            f(message);
+            for (_, value) in fields {
+                f(value);
+            }
+        }
         Stmt::Push { collection, value } | Stmt::Add { collection, value } => {
             f(collection);
             f(value);
@@ -452,6 +452,9 @@ fn effectful_bound_vars(stmt: &Stmt) -> Vec<Symbol> {
         | Stmt::CreatePipe { var, .. }
         | Stmt::LetPeerAgent { var, .. } => vec![*var],
         Stmt::AwaitMessage { into, .. } => vec![*into],
+        Stmt::RunCommand { output, errors, exit_code, .. } => {
+            [output, errors, exit_code].into_iter().flatten().copied().collect()
+        }
         Stmt::LaunchTaskWithHandle { handle, .. } => vec![*handle],
         Stmt::Spawn { name, .. } => vec![*name],
         _ => Vec::new(),
@@ -50,7 +50,8 @@ fn is_pure_stmt(stmt: &Stmt) -> bool {
         | Stmt::Pop { .. } | Stmt::Add { .. } | Stmt::Remove { .. }
         | Stmt::SetIndex { .. } | Stmt::SetField { .. } | Stmt::Give { .. }
         | Stmt::WriteFile { .. } | Stmt::SendMessage { .. } | Stmt::StreamMessage { .. }
-        | Stmt::Sleep { .. } | Stmt::Spawn { .. } | Stmt::Break => false,
+        | Stmt::Sleep { .. } | Stmt::Spawn { .. } | Stmt::RunCommand { .. } | Stmt::Sip { .. }
+        | Stmt::Break => false,
         _ => false,
     }
 }
@@ -62,6 +62,14 @@ fn collect_expr_reads(expr: &Expr, reads: &mut HashSet<Symbol>) -> bool {
             }
             ok
         }
+        // The binder's reads are its own, but recording them only over-approximates.
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            let mut ok = collect_expr_reads(iterable, reads);
+            for e in std::iter::once(*element).chain(*value).chain(*filter) {
+                ok &= collect_expr_reads(e, reads);
+            }
+            ok
+        }
         // Opaque expressions: may read anything
         Expr::Call { args, .. } => {
             for arg in args { collect_expr_reads(arg, reads); }
@@ -90,6 +98,22 @@ fn collect_stmt_reads(stmt: &Stmt, reads: &mut HashSet<Symbol>) {
             collect_expr_reads(object, reads);
             collect_expr_reads(recipient, reads);
         }
+        Stmt::RunCommand { command, timeout, .. } => {
+            collect_expr_reads(command, reads);
+            if let Some(timeout) = timeout {
+                collect_expr_reads(timeout, reads);
+            }
+        }
+        Stmt::Sip { file, sources, .. } => {
+            collect_expr_reads(file, reads);
+            collect_expr_reads(sources, reads);
+        }
+        Stmt::Log { message, fields, .. } => {
+            collect_expr_reads(message, reads);
+            for (_, value) in fields {
+                collect_expr_reads(value, reads);
+            }
+        }
         Stmt::Push { collection, value } | Stmt::Add { collection, value }
         | Stmt::Remove { collection, value } => {
             collect_expr_reads(collection, reads);
@@ -138,6 +162,11 @@ fn collect_stmt_reads(stmt: &Stmt, reads: &mut HashSet<Symbol>) {
                 collect_expr_reads(bind.pad, reads);
             }
         }
+        Stmt::JoinSwarm { using } => {
+            for addrs in using.iter().filter_map(|d| d.addresses()) {
+                collect_expr_reads(addrs, reads);
+            }
+        }
         Stmt::Give { object, recipient } | Stmt::MergeCrdt { source: object, target: recipient }
         | Stmt::SendMessage { message: object, destination: recipient, .. }
         | Stmt::StreamMessage { values: object, destination: recipient }
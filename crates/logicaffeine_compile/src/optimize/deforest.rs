@@ -335,6 +335,11 @@ fn collect_expr_symbols(expr: &Expr, syms: &mut HashSet<Symbol>) {
             collect_expr_symbols(callee, syms);
             for arg in args { collect_expr_symbols(arg, syms); }
         }
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            for e in [*element, *iterable].into_iter().chain(*value).chain(*filter) {
+                collect_expr_symbols(e, syms);
+            }
+        }
         Expr::Closure { .. } | Expr::Escape { .. } => {}
     }
 }
@@ -450,6 +455,10 @@ fn expr_references_symbol(expr: &Expr, sym: Symbol) -> bool {
             args.iter().any(|a| expr_references_symbol(a, sym))
         }
         // Conservative for opaque expressions
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            [*element, *iterable].into_iter().chain(*value).chain(*filter)
+                .any(|e| expr_references_symbol(e, sym))
+        }
         Expr::Closure { .. } | Expr::Escape { .. } => true,
     }
 }
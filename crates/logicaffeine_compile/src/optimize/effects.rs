@@ -488,6 +488,14 @@ fn analyze_stmt_effects_core(
             effects.io = true;
             effects
         }
+        Stmt::Log { message, fields, .. } => {
+            let mut effects = analyze_expr_effects_core(message, known_fns);
+            for (_, value) in fields {
+                effects.join(&analyze_expr_effects_core(value, known_fns));
+            }
+            effects.io = true;
+            effects
+        }
         Stmt::Push { value, collection } => {
             let mut effects = analyze_expr_effects_core(value, known_fns);
             effects.join(&analyze_expr_effects_core(collection, known_fns));
@@ -629,6 +637,29 @@ fn analyze_stmt_effects_core(
             effects.io = true;
             effects
         }
+        Stmt::RunCommand { command, timeout, output, errors, exit_code } => {
+            let mut effects = analyze_expr_effects_core(command, known_fns);
+            if let Some(timeout) = timeout {
+                effects.join(&analyze_expr_effects_core(timeout, known_fns));
+            }
+            effects.writes.extend([output, errors, exit_code].into_iter().flatten().copied());
+            effects.io = true;
+            effects
+        }
+        Stmt::Sip { file, sources, progress } => {
+            let mut effects = analyze_expr_effects_core(file, known_fns);
+            effects.join(&analyze_expr_effects_core(sources, known_fns));
+            effects.io = true;
+            // The progress function runs after every chunk, like a `Call`.
+            if let Some(function) = progress {
+                match functions.and_then(|fns| fns.get(function)) {
+                    Some(fn_effects) => effects.join(fn_effects),
+                    None if known_fns.contains(function) => {}
+                    None => effects.unknown = true,
+                }
+            }
+            effects
+        }
         // ----- Go-like concurrency: opaque effectful boundaries -----
         Stmt::Select { branches } => {
             // A Select is a nondeterministic choice over its ready branches. Its outcome
@@ -701,6 +732,14 @@ fn analyze_stmt_effects_core(
             effects.concurrent = true;
             effects
         }
+        Stmt::JoinSwarm { using } => {
+            let mut effects = EffectSet::io();
+            for addrs in using.iter().filter_map(|d| d.addresses()) {
+                effects.join(&analyze_expr_effects_core(addrs, known_fns));
+            }
+            effects.concurrent = true;
+            effects
+        }
         Stmt::Listen { address, secure } | Stmt::ConnectTo { address, secure } => {
             let mut effects = analyze_expr_effects_core(address, known_fns);
             if let Some(bind) = secure {
@@ -856,6 +895,14 @@ fn analyze_expr_effects_core(expr: &Expr<'_>, known_fns: &HashSet<Symbol>) -> Ef
             }
             effects
         }
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            let mut effects = analyze_expr_effects_core(iterable, known_fns);
+            for e in std::iter::once(*element).chain(*value).chain(*filter) {
+                effects.join(&analyze_expr_effects_core(e, known_fns));
+            }
+            effects.allocates = true;
+            effects
+        }
         Expr::Closure { .. } => EffectSet::pure(),
         Expr::Escape { .. } => EffectSet::unknown(),
         Expr::InterpolatedString(parts) => {
@@ -879,7 +926,11 @@ fn classify_native_function(sym: Symbol, interner: &Interner) -> EffectSet {
         | "uuid" | "uuid_nil" | "uuid_max" | "uuid_v3" | "uuid_v5" | "uuid_version"
         | "uuid_dns" | "uuid_url" | "uuid_oid" | "uuid_x500"
         | "md5" | "sha1" | "text_bytes" | "uuid_bytes" | "uuid_from_bytes"
-        | "parse_timestamp" | "format_timestamp" | "year_of" | "month_of" | "day_of" | "weekday_of"
+        | "text_split" | "text_words" | "text_lines" | "text_join" | "text_trim" | "text_uppercase"
+        | "text_lowercase" | "text_replace" | "text_find" | "text_starts_with" | "text_ends_with"
+        | "sorted_by" | "largest_of" | "smallest_of" | "with_field"
+        | "pattern" | "pattern_matches" | "pattern_capture"
+        | "parse_timestamp" | "format_timestamp" | "format_as" | "year_of" | "month_of" | "day_of" | "weekday_of"
         | "hour_of" | "minute_of" | "second_of" | "week_of" | "quarter_of" | "date_of" | "time_of"
         | "seconds_between" | "months_between" | "years_between" | "add_seconds" | "in_zone"
         | "local_instant" | "abs" | "min" | "max" | "sqrt" | "floor"
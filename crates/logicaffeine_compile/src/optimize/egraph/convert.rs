@@ -767,6 +767,11 @@ fn collect_mutated(stmts: &[Stmt], out: &mut std::collections::HashSet<u32>) {
             Stmt::ReadFrom { var, .. } => {
                 out.insert(var.index() as u32);
             }
+            Stmt::RunCommand { output, errors, exit_code, .. } => {
+                for var in [output, errors, exit_code].into_iter().flatten() {
+                    out.insert(var.index() as u32);
+                }
+            }
             _ => {}
         }
     }
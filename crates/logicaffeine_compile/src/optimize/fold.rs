@@ -551,6 +551,29 @@ pub fn fold_expr<'a>(
         // Interpolated strings — fold sub-expressions in holes
         Expr::InterpolatedString(_) => expr,
 
+        Expr::Comprehension { kind, element, value, pattern, iterable, filter } => {
+            let fe = fold_expr(element, arena, stmt_arena, interner, bools);
+            let fv = value.map(|v| fold_expr(v, arena, stmt_arena, interner, bools));
+            let fi = fold_expr(iterable, arena, stmt_arena, interner, bools);
+            let ff = filter.map(|f| fold_expr(f, arena, stmt_arena, interner, bools));
+            let same = |a: Option<&Expr>, b: Option<&Expr>| match (a, b) {
+                (Some(a), Some(b)) => std::ptr::eq(a, b),
+                _ => true,
+            };
+            if std::ptr::eq(fe, *element) && std::ptr::eq(fi, *iterable) && same(fv, *value) && same(ff, *filter) {
+                expr
+            } else {
+                arena.alloc(Expr::Comprehension {
+                    kind: *kind,
+                    element: fe,
+                    value: fv,
+                    pattern: pattern.clone(),
+                    iterable: fi,
+                    filter: ff,
+                })
+            }
+        }
+
         // Leaves — no sub-expressions to fold
         Expr::Literal(_) | Expr::Identifier(_) | Expr::OptionNone | Expr::Escape { .. } => expr,
     }
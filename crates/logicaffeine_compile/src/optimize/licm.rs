@@ -29,6 +29,9 @@ pub(crate) fn collect_loop_writes(stmts: &[Stmt]) -> HashSet<Symbol> {
             Stmt::ReadFrom { var, .. } => {
                 writes.insert(*var);
             }
+            Stmt::RunCommand { output, errors, exit_code, .. } => {
+                writes.extend([output, errors, exit_code].into_iter().flatten().copied());
+            }
             Stmt::Push { collection, .. }
             | Stmt::Add { collection, .. }
             | Stmt::Remove { collection, .. } => {
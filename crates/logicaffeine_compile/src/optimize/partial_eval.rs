@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use crate::arena::Arena;
-use crate::ast::stmt::{Expr, Literal, Stmt, TypeExpr, Block, StringPart};
+use crate::ast::stmt::{Expr, Literal, Pattern, Stmt, TypeExpr, Block, StringPart};
 use crate::intern::{Interner, Symbol};
 use std::collections::HashSet;
 
@@ -116,6 +116,7 @@ fn body_has_io(stmts: &[Stmt]) -> bool {
 fn stmt_has_io(stmt: &Stmt) -> bool {
     match stmt {
         Stmt::Show { .. }
+        | Stmt::Log { .. }
         | Stmt::WriteFile { .. }
         | Stmt::SendMessage { .. }
         | Stmt::StreamMessage { .. }
@@ -125,6 +126,8 @@ fn stmt_has_io(stmt: &Stmt) -> bool {
         | Stmt::ReceivePipe { .. }
         | Stmt::TryReceivePipe { .. }
         | Stmt::ReadFrom { .. }
+        | Stmt::RunCommand { .. }
+        | Stmt::Sip { .. }
         | Stmt::Check { .. } => true,
         // Concurrency / spawn / networking — opaque boundaries, never specialize across.
         Stmt::LaunchTask { .. }
@@ -134,6 +137,7 @@ fn stmt_has_io(stmt: &Stmt) -> bool {
         | Stmt::Spawn { .. }
         | Stmt::Listen { .. }
         | Stmt::ConnectTo { .. }
+        | Stmt::JoinSwarm { .. }
         | Stmt::LetPeerAgent { .. }
         | Stmt::AwaitMessage { .. }
         | Stmt::Sync { .. }
@@ -465,6 +469,29 @@ fn substitute_expr<'a>(
                 .collect();
             expr_arena.alloc(Expr::InterpolatedString(new_parts))
         }
+        Expr::Comprehension { kind, element, value, pattern, iterable, filter } => {
+            // The binder shadows a substituted name of its own inside the comprehension.
+            let binders: Vec<Symbol> = match pattern {
+                Pattern::Identifier(sym) => vec![*sym],
+                Pattern::Tuple(syms) => syms.clone(),
+            };
+            let mut inner = substitutions.clone();
+            for b in &binders {
+                inner.remove(b);
+            }
+            let new_element = substitute_expr(element, &inner, expr_arena);
+            let new_value = value.map(|v| substitute_expr(v, &inner, expr_arena));
+            let new_iterable = substitute_expr(iterable, substitutions, expr_arena);
+            let new_filter = filter.map(|f| substitute_expr(f, &inner, expr_arena));
+            expr_arena.alloc(Expr::Comprehension {
+                kind: *kind,
+                element: new_element,
+                value: new_value,
+                pattern: pattern.clone(),
+                iterable: new_iterable,
+                filter: new_filter,
+            })
+        }
         _ => expr,
     }
 }
@@ -493,6 +520,23 @@ fn substitute_stmt<'a>(
             object: substitute_expr(object, substitutions, expr_arena),
             recipient: *recipient,
         },
+        Stmt::RunCommand { command, timeout, output, errors, exit_code } => Stmt::RunCommand {
+            command: substitute_expr(command, substitutions, expr_arena),
+            timeout: timeout.map(|t| substitute_expr(t, substitutions, expr_arena)),
+            output: *output,
+            errors: *errors,
+            exit_code: *exit_code,
+        },
+        Stmt::Sip { file, sources, progress } => Stmt::Sip {
+            file: substitute_expr(file, substitutions, expr_arena),
+            sources: substitute_expr(sources, substitutions, expr_arena),
+            progress: *progress,
+        },
+        Stmt::Log { level, message, fields } => Stmt::Log {
+            level: *level,
+            message: substitute_expr(message, substitutions, expr_arena),
+            fields: fields.iter().map(|(name, v)| (*name, substitute_expr(v, substitutions, expr_arena))).collect(),
+        },
         Stmt::Call { function, args } => Stmt::Call {
             function: *function,
             args: args.iter().map(|a| substitute_expr(a, substitutions, expr_arena)).collect(),
@@ -827,6 +871,25 @@ fn specialize_in_stmt<'a>(
             object: specialize_in_expr(object, func_defs, registry, expr_arena, stmt_arena, interner, effect_env),
             recipient,
         },
+        Stmt::RunCommand { command, timeout, output, errors, exit_code } => Stmt::RunCommand {
+            command: specialize_in_expr(command, func_defs, registry, expr_arena, stmt_arena, interner, effect_env),
+            timeout: timeout.map(|t| specialize_in_expr(t, func_defs, registry, expr_arena, stmt_arena, interner, effect_env)),
+            output,
+            errors,
+            exit_code,
+        },
+        Stmt::Sip { file, sources, progress } => Stmt::Sip {
+            file: specialize_in_expr(file, func_defs, registry, expr_arena, stmt_arena, interner, effect_env),
+            sources: specialize_in_expr(sources, func_defs, registry, expr_arena, stmt_arena, interner, effect_env),
+            progress,
+        },
+        Stmt::Log { level, message, fields } => Stmt::Log {
+            level,
+            message: specialize_in_expr(message, func_defs, registry, expr_arena, stmt_arena, interner, effect_env),
+            fields: fields.into_iter()
+                .map(|(name, v)| (name, specialize_in_expr(v, func_defs, registry, expr_arena, stmt_arena, interner, effect_env)))
+                .collect(),
+        },
         Stmt::Call { function, args } => {
             let new_args: Vec<&'a Expr<'a>> = args.iter()
                 .map(|a| specialize_in_expr(a, func_defs, registry, expr_arena, stmt_arena, interner, effect_env))
@@ -346,6 +346,8 @@ fn substitute_identifiers<'a>(
         }
         // Don't propagate into closures (captured variables may change)
         Expr::Closure { .. } => expr,
+        // Comprehensions bind their own names — left as written, like closures
+        Expr::Comprehension { .. } => expr,
         // Interpolated strings — don't substitute (preserves format string shape)
         Expr::InterpolatedString(_) => expr,
         // Leaves
@@ -384,6 +384,22 @@ fn scan_stmt(s: &Stmt, lens: &HashMap<Symbol, usize>, disq: &mut HashSet<Symbol>
             scan_expr(object, lens, disq);
             scan_expr(recipient, lens, disq);
         }
+        Stmt::RunCommand { command, timeout, .. } => {
+            scan_expr(command, lens, disq);
+            if let Some(timeout) = timeout {
+                scan_expr(timeout, lens, disq);
+            }
+        }
+        Stmt::Sip { file, sources, .. } => {
+            scan_expr(file, lens, disq);
+            scan_expr(sources, lens, disq);
+        }
+        Stmt::Log { message, fields, .. } => {
+            scan_expr(message, lens, disq);
+            for (_, value) in fields {
+                scan_expr(value, lens, disq);
+            }
+        }
         Stmt::Give { object, recipient } => {
             scan_expr(object, lens, disq);
             scan_expr(recipient, lens, disq);
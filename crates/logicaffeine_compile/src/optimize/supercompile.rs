@@ -686,6 +686,25 @@ fn drive_stmt<'a>(
             let driven = drive_expr(object, env, expr_arena, depth);
             Some(Stmt::Show { object: driven, recipient })
         }
+        Stmt::RunCommand { command, timeout, output, errors, exit_code } => {
+            let command = drive_expr(command, env, expr_arena, depth);
+            let timeout = timeout.map(|t| drive_expr(t, env, expr_arena, depth));
+            // The captured values are only known once the command has run.
+            for var in [output, errors, exit_code].into_iter().flatten() {
+                env.store.remove(&var);
+            }
+            Some(Stmt::RunCommand { command, timeout, output, errors, exit_code })
+        }
+        Stmt::Sip { file, sources, progress } => {
+            let file = drive_expr(file, env, expr_arena, depth);
+            let sources = drive_expr(sources, env, expr_arena, depth);
+            Some(Stmt::Sip { file, sources, progress })
+        }
+        Stmt::Log { level, message, fields } => {
+            let message = drive_expr(message, env, expr_arena, depth);
+            let fields = fields.into_iter().map(|(name, v)| (name, drive_expr(v, env, expr_arena, depth))).collect();
+            Some(Stmt::Log { level, message, fields })
+        }
         Stmt::Return { value } => {
             let driven = value.map(|v| drive_expr(v, env, expr_arena, depth));
             Some(Stmt::Return { value: driven })
@@ -771,6 +790,9 @@ fn collect_modified_vars_stmt(stmt: &Stmt, out: &mut Vec<Symbol>) {
     match stmt {
         Stmt::Set { target, .. } => out.push(*target),
         Stmt::Let { var, mutable: true, .. } => out.push(*var),
+        Stmt::RunCommand { output, errors, exit_code, .. } => {
+            out.extend([output, errors, exit_code].into_iter().flatten().copied());
+        }
         Stmt::If { then_block, else_block, .. } => {
             for s in *then_block {
                 collect_modified_vars_stmt(s, out);
@@ -529,6 +529,9 @@ fn expr_mentions(e: &Expr, sym: Symbol) -> bool {
         Expr::InterpolatedString(parts) => parts.iter().any(|p| {
             matches!(p, crate::ast::stmt::StringPart::Expr { value, .. } if expr_mentions(value, sym))
         }),
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            [*element, *iterable].into_iter().chain(*value).chain(*filter).any(|x| expr_mentions(x, sym))
+        }
         _ => false,
     }
 }
@@ -676,6 +679,11 @@ fn expr_indexed_roots(e: &Expr, out: &mut HashSet<Symbol>) {
                 }
             }
         }
+        Expr::Comprehension { element, value, iterable, filter, .. } => {
+            for x in [*element, *iterable].into_iter().chain(*value).chain(*filter) {
+                expr_indexed_roots(x, out);
+            }
+        }
         _ => {}
     }
 }
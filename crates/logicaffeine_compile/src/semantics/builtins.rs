@@ -6,11 +6,12 @@
 //! even when an argument expression would itself error.
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 use serde::{Deserialize, Serialize};
 
-use crate::interpreter::{ListRepr, RuntimeValue};
+use crate::interpreter::{ListRepr, RuntimeValue, StructValue};
 use logicaffeine_base::{Decimal, LanesVal, Word16, Word32, Word64, WordVal};
 
 /// Read a `Seq of Int` as raw bytes (each element masked to 0–255) — the byte-array convention the
@@ -32,6 +33,28 @@ fn byte_seq(v: &RuntimeValue) -> Result<Vec<u8>, String> {
     }
 }
 
+/// Read the Text argument of a `text_*` builtin (which names itself in the error).
+fn text_arg<'v>(v: &'v RuntimeValue, name: &str) -> Result<&'v str, String> {
+    match v {
+        RuntimeValue::Text(s) => Ok(s.as_str()),
+        _ => Err(format!("{}() requires text, got {}", name, v.type_name())),
+    }
+}
+
+/// Read the Pattern argument of a `pattern_*` builtin.
+fn pattern_arg<'v>(v: &'v RuntimeValue, name: &str) -> Result<&'v logicaffeine_data::LogosPattern, String> {
+    match v {
+        RuntimeValue::Pattern(p) => Ok(p),
+        _ => Err(format!("{}() requires a Pattern, got {}", name, v.type_name())),
+    }
+}
+
+/// Build a `Seq of Text` from split pieces.
+fn texts_to_seq(pieces: Vec<String>) -> RuntimeValue {
+    let items = pieces.into_iter().map(|p| RuntimeValue::Text(Rc::new(p))).collect();
+    RuntimeValue::List(Rc::new(RefCell::new(ListRepr::from_values(items))))
+}
+
 /// Build a `Seq of Int` from raw bytes (the packed-`i64` list repr).
 fn bytes_to_seq(bytes: &[u8]) -> RuntimeValue {
     RuntimeValue::List(Rc::new(RefCell::new(ListRepr::Ints(
@@ -99,6 +122,42 @@ pub enum BuiltinId {
     /// `text_from_bytes(seq)` — the exact inverse of `text_bytes`: rebuild a Text from its UTF-8
     /// `Seq of Int`. The wire medium for the Core-IR codec (`Seq of Int`) carries Text this way.
     TextFromBytes,
+    /// Text processing — the targets of the prose text sentences (`Split the line by commas into
+    /// fields.`, `the uppercase of name`, `line with "a" replaced by "b"`, `s starts with "#"`).
+    /// All delegate to `logicaffeine_data::text`, the implementation the AOT binary calls too.
+    /// `text_split(s, sep)` / `text_words(s)` / `text_lines(s)` yield a `Seq of Text`;
+    /// `text_join(seq, sep)` renders each item as `Show` would.
+    TextSplit,
+    TextWords,
+    TextLines,
+    TextJoin,
+    TextTrim,
+    TextUppercase,
+    TextLowercase,
+    /// `text_replace(s, from, to)` — every occurrence; an empty `from` matches nothing.
+    TextReplace,
+    /// `text_find(s, needle)` — the 1-based character position of the first match, `0` if absent.
+    TextFind,
+    TextStartsWith,
+    TextEndsWith,
+    /// Sorting — the targets of `Sort people by age descending.` and `the largest 3 items of
+    /// scores`. `sorted_by(seq, key, descending)` is a stable sorted copy; `largest_of(seq, n, key)`
+    /// / `smallest_of(seq, n, key)` are its first `n` items. An empty `key` sorts the items
+    /// themselves — a struct by its `Comparable` fields (see [`call_sort_builtin`]).
+    SortedBy,
+    LargestOf,
+    SmallestOf,
+    /// `with_field(record, "age", 31)` — a copy of a struct with one field replaced: the target of
+    /// `a copy of user with age set to 31` and of nested paths like `Set user's address's city to …`.
+    WithField,
+    /// `pattern(source)` — compile a `Pattern` (literals were already validated by the parser).
+    Pattern,
+    /// `pattern_matches(text, pattern)` — does the pattern match anywhere in the text?
+    PatternMatches,
+    /// `pattern_capture(pattern, text, name…)` — the first match as a `PatternMatch` record:
+    /// `matched`, the matched `text`, and one Text field per named group (`""` when absent).
+    /// The parser passes the group names it read off the pattern literal.
+    PatternCapture,
     /// `wireBytes(value)` — the value's plain wire form (peer codec `encode_value_raw`) as a
     /// `Seq of Int`. Lets the host serialize a program AST for a compile-once native PE, byte-
     /// identical to what the native binary's generated `wire_decode` reads.
@@ -122,6 +181,9 @@ pub enum BuiltinId {
     ParseTimestamp,
     /// `format_timestamp(moment)` — render a `Moment` as an RFC 3339 / ISO 8601 UTC string.
     FormatTimestamp,
+    /// `format_as(moment, "%d %B %Y")` — render a `Moment` or `Date` with a `strftime`-style
+    /// pattern. The natural surface is `<moment> formatted as "<pattern>"`.
+    FormatAs,
     /// Calendar component extractors on a `Moment` (UTC), each returning an `Int`:
     /// `year_of` / `month_of` (1–12) / `day_of` (1–31) / `weekday_of` (0 = Sunday … 6 = Saturday).
     YearOf,
@@ -256,6 +318,11 @@ pub enum BuiltinId {
     /// deep copy of `x` (the `n copies of x` / `[x] * n` fill; a repeated
     /// inner collection is n rows, never n aliases). `n ≤ 0` is empty.
     RepeatSeq,
+    /// `sync_status(x)` — the `the sync status of x` lowering: a shared value's version,
+    /// unpublished changes, peers seen and concurrent writes (see
+    /// [`crate::semantics::crdt::sync_status`]). Nothing has been `Sync`ed here; the
+    /// tree-walker, which runs `Sync`, passes its published versions instead.
+    SyncStatus,
 }
 
 /// Resolve a function name to a builtin, if it is one.
@@ -266,6 +333,7 @@ pub fn builtin_from_name(name: &str) -> Option<BuiltinId> {
         "mapOf" => BuiltinId::MapOf,
         "setOf" => BuiltinId::SetOf,
         "repeatSeq" => BuiltinId::RepeatSeq,
+        "sync_status" => BuiltinId::SyncStatus,
         "parseInt" => BuiltinId::ParseInt,
         "parseFloat" => BuiltinId::ParseFloat,
         "chr" => BuiltinId::Chr,
@@ -295,6 +363,24 @@ pub fn builtin_from_name(name: &str) -> Option<BuiltinId> {
         "uuid_x500" => BuiltinId::UuidX500,
         "text_bytes" => BuiltinId::TextBytes,
         "text_from_bytes" => BuiltinId::TextFromBytes,
+        "text_split" => BuiltinId::TextSplit,
+        "text_words" => BuiltinId::TextWords,
+        "text_lines" => BuiltinId::TextLines,
+        "text_join" => BuiltinId::TextJoin,
+        "text_trim" => BuiltinId::TextTrim,
+        "text_uppercase" => BuiltinId::TextUppercase,
+        "text_lowercase" => BuiltinId::TextLowercase,
+        "text_replace" => BuiltinId::TextReplace,
+        "text_find" => BuiltinId::TextFind,
+        "pattern" => BuiltinId::Pattern,
+        "pattern_matches" => BuiltinId::PatternMatches,
+        "pattern_capture" => BuiltinId::PatternCapture,
+        "text_starts_with" => BuiltinId::TextStartsWith,
+        "text_ends_with" => BuiltinId::TextEndsWith,
+        "sorted_by" => BuiltinId::SortedBy,
+        "largest_of" => BuiltinId::LargestOf,
+        "smallest_of" => BuiltinId::SmallestOf,
+        "with_field" => BuiltinId::WithField,
         "wireBytes" => BuiltinId::WireBytes,
         "readWireProgram" => BuiltinId::ReadWireProgram,
         "writeWireResidual" => BuiltinId::WriteWireResidual,
@@ -303,6 +389,7 @@ pub fn builtin_from_name(name: &str) -> Option<BuiltinId> {
         "convert" => BuiltinId::Convert,
         "parse_timestamp" => BuiltinId::ParseTimestamp,
         "format_timestamp" => BuiltinId::FormatTimestamp,
+        "format_as" => BuiltinId::FormatAs,
         "year_of" => BuiltinId::YearOf,
         "month_of" => BuiltinId::MonthOf,
         "day_of" => BuiltinId::DayOf,
@@ -396,6 +483,12 @@ pub fn check_arity(id: BuiltinId, n: usize) -> Result<(), String> {
             }
             return Ok(());
         }
+        BuiltinId::PatternCapture => {
+            if n < 2 {
+                return Err("pattern_capture takes a pattern, a text, then any group names".to_string());
+            }
+            return Ok(());
+        }
         BuiltinId::Min | BuiltinId::Max | BuiltinId::Pow => 2,
         BuiltinId::RepeatSeq => 2,
         BuiltinId::Complex => 2,
@@ -412,7 +505,14 @@ pub fn check_arity(id: BuiltinId, n: usize) -> Result<(), String> {
         | BuiltinId::UuidX500 => 0,
         BuiltinId::Uuid | BuiltinId::UuidVersion => 1,
         BuiltinId::TextBytes | BuiltinId::TextFromBytes | BuiltinId::WireBytes | BuiltinId::WriteWireResidual | BuiltinId::UuidBytes | BuiltinId::UuidFromBytes => 1,
-        BuiltinId::SecondsBetween | BuiltinId::AddSeconds | BuiltinId::InZone => 2,
+        BuiltinId::TextWords | BuiltinId::TextLines | BuiltinId::TextTrim | BuiltinId::TextUppercase | BuiltinId::TextLowercase => 1,
+        BuiltinId::TextSplit | BuiltinId::TextJoin | BuiltinId::TextFind | BuiltinId::TextStartsWith | BuiltinId::TextEndsWith => 2,
+        BuiltinId::TextReplace => 3,
+        BuiltinId::SortedBy | BuiltinId::LargestOf | BuiltinId::SmallestOf => 3,
+        BuiltinId::WithField => 3,
+        BuiltinId::Pattern => 1,
+        BuiltinId::PatternMatches => 2,
+        BuiltinId::SecondsBetween | BuiltinId::AddSeconds | BuiltinId::InZone | BuiltinId::FormatAs => 2,
         BuiltinId::MonthsBetween | BuiltinId::YearsBetween => 2,
         BuiltinId::LocalInstant => 2,
         BuiltinId::Rotl | BuiltinId::Rotr => 2,
@@ -446,6 +546,7 @@ pub fn check_arity(id: BuiltinId, n: usize) -> Result<(), String> {
             // MapOf/SetOf return early above (variadic with their own errors).
             BuiltinId::MapOf | BuiltinId::SetOf => unreachable!(),
             BuiltinId::RepeatSeq => "repeatSeq",
+            BuiltinId::SyncStatus => "sync_status",
             BuiltinId::ParseInt => "parseInt",
             BuiltinId::ParseFloat => "parseFloat",
             BuiltinId::Chr => "chr",
@@ -475,6 +576,24 @@ pub fn check_arity(id: BuiltinId, n: usize) -> Result<(), String> {
             BuiltinId::UuidX500 => "uuid_x500",
             BuiltinId::TextBytes => "text_bytes",
             BuiltinId::TextFromBytes => "text_from_bytes",
+            BuiltinId::TextSplit => "text_split",
+            BuiltinId::TextWords => "text_words",
+            BuiltinId::TextLines => "text_lines",
+            BuiltinId::TextJoin => "text_join",
+            BuiltinId::TextTrim => "text_trim",
+            BuiltinId::TextUppercase => "text_uppercase",
+            BuiltinId::TextLowercase => "text_lowercase",
+            BuiltinId::TextReplace => "text_replace",
+            BuiltinId::TextFind => "text_find",
+            BuiltinId::TextStartsWith => "text_starts_with",
+            BuiltinId::TextEndsWith => "text_ends_with",
+            BuiltinId::SortedBy => "sorted_by",
+            BuiltinId::LargestOf => "largest_of",
+            BuiltinId::SmallestOf => "smallest_of",
+            BuiltinId::WithField => "with_field",
+            BuiltinId::Pattern => "pattern",
+            BuiltinId::PatternMatches => "pattern_matches",
+            BuiltinId::PatternCapture => "pattern_capture",
             BuiltinId::WireBytes => "wireBytes",
             BuiltinId::ReadWireProgram => "readWireProgram",
             BuiltinId::WriteWireResidual => "writeWireResidual",
@@ -483,6 +602,7 @@ pub fn check_arity(id: BuiltinId, n: usize) -> Result<(), String> {
             BuiltinId::Convert => "convert",
             BuiltinId::ParseTimestamp => "parse_timestamp",
             BuiltinId::FormatTimestamp => "format_timestamp",
+            BuiltinId::FormatAs => "format_as",
             BuiltinId::YearOf => "year_of",
             BuiltinId::MonthOf => "month_of",
             BuiltinId::DayOf => "day_of",
@@ -563,6 +683,15 @@ pub fn check_arity(id: BuiltinId, n: usize) -> Result<(), String> {
     Ok(())
 }
 
+/// The instant a temporal builtin reads from a `Moment`, or a `Date` at its midnight (UTC).
+fn instant_nanos(v: &RuntimeValue) -> Option<i64> {
+    match v {
+        RuntimeValue::Moment(nanos) => Some(*nanos),
+        RuntimeValue::Date(days) => Some(*days as i64 * logicaffeine_base::temporal::NANOS_PER_DAY),
+        _ => None,
+    }
+}
+
 /// Apply a builtin to already-evaluated arguments. The caller has already
 /// validated arity with [`check_arity`].
 /// Validate a within-vector NTT stride argument: an `Int` that is one of the supported half-widths
@@ -576,6 +705,29 @@ fn ntt_stride(v: RuntimeValue, name: &str) -> Result<usize, String> {
     }
 }
 
+/// Run `sorted_by` / `largest_of` / `smallest_of` with the program's `Comparable` declarations.
+/// The tree-walker passes its own; without them (the bytecode VM, which leaves programs declaring
+/// a `Comparable` type to the tree-walker) a struct sorts only `by` a field.
+pub fn call_sort_builtin(
+    id: BuiltinId,
+    args: &[RuntimeValue],
+    orderings: &crate::semantics::collections::Orderings,
+) -> Result<RuntimeValue, String> {
+    use crate::semantics::collections::{sorted, take_sorted};
+    match id {
+        BuiltinId::SortedBy => {
+            let key = text_arg(&args[1], "sorted_by")?;
+            match &args[2] {
+                RuntimeValue::Bool(descending) => sorted(&args[0], key, *descending, orderings),
+                other => Err(format!("sorted_by() direction must be a Bool, got {}", other.type_name())),
+            }
+        }
+        BuiltinId::LargestOf => take_sorted(&args[0], &args[1], text_arg(&args[2], "largest_of")?, true, orderings),
+        BuiltinId::SmallestOf => take_sorted(&args[0], &args[1], text_arg(&args[2], "smallest_of")?, false, orderings),
+        _ => unreachable!("call_sort_builtin is only called with a sorting builtin"),
+    }
+}
+
 pub fn call_builtin(id: BuiltinId, args: Vec<RuntimeValue>) -> Result<RuntimeValue, String> {
     let mut args = args;
     match id {
@@ -611,6 +763,9 @@ pub fn call_builtin(id: BuiltinId, args: Vec<RuntimeValue>) -> Result<RuntimeVal
             let slots: Vec<RuntimeValue> = (0..n).map(|_| element.deep_clone()).collect();
             Ok(RuntimeValue::List(Rc::new(RefCell::new(ListRepr::from_values(slots)))))
         }
+        BuiltinId::SyncStatus => {
+            crate::semantics::crdt::sync_status(&args[0], &std::collections::HashMap::new())
+        }
         BuiltinId::Length => {
             let val = args.remove(0);
             match &val {
@@ -981,6 +1136,84 @@ pub fn call_builtin(id: BuiltinId, args: Vec<RuntimeValue>) -> Result<RuntimeVal
                 Err(e) => Err(format!("text_from_bytes(): invalid UTF-8: {}", e)),
             }
         }
+        BuiltinId::TextSplit => {
+            let s = text_arg(&args[0], "text_split")?;
+            let sep = text_arg(&args[1], "text_split")?;
+            Ok(texts_to_seq(logicaffeine_data::text::split_text(s, sep)))
+        }
+        BuiltinId::TextWords => Ok(texts_to_seq(logicaffeine_data::text::words_of(text_arg(&args[0], "text_words")?))),
+        BuiltinId::TextLines => Ok(texts_to_seq(logicaffeine_data::text::lines_of(text_arg(&args[0], "text_lines")?))),
+        BuiltinId::TextJoin => {
+            let sep = text_arg(&args[1], "text_join")?;
+            match &args[0] {
+                RuntimeValue::List(l) => {
+                    let items = l.borrow().to_values();
+                    let shown = items.iter().map(|v| v.to_display_string());
+                    Ok(RuntimeValue::Text(Rc::new(logicaffeine_data::text::join_text(shown, sep))))
+                }
+                other => Err(format!("text_join() requires a Seq, got {}", other.type_name())),
+            }
+        }
+        BuiltinId::TextTrim => Ok(RuntimeValue::Text(Rc::new(text_arg(&args[0], "text_trim")?.trim().to_string()))),
+        BuiltinId::TextUppercase => Ok(RuntimeValue::Text(Rc::new(text_arg(&args[0], "text_uppercase")?.to_uppercase()))),
+        BuiltinId::TextLowercase => Ok(RuntimeValue::Text(Rc::new(text_arg(&args[0], "text_lowercase")?.to_lowercase()))),
+        BuiltinId::TextReplace => {
+            let s = text_arg(&args[0], "text_replace")?;
+            let to = text_arg(&args[2], "text_replace")?;
+            let replaced = match &args[1] {
+                RuntimeValue::Pattern(p) => p.replace_all(s, to),
+                from => logicaffeine_data::text::replace_text(s, text_arg(from, "text_replace")?, to),
+            };
+            Ok(RuntimeValue::Text(Rc::new(replaced)))
+        }
+        BuiltinId::TextFind => {
+            let s = text_arg(&args[0], "text_find")?;
+            let needle = text_arg(&args[1], "text_find")?;
+            Ok(RuntimeValue::Int(logicaffeine_data::text::find_text(s, needle)))
+        }
+        BuiltinId::TextStartsWith => {
+            let s = text_arg(&args[0], "text_starts_with")?;
+            Ok(RuntimeValue::Bool(s.starts_with(text_arg(&args[1], "text_starts_with")?)))
+        }
+        BuiltinId::TextEndsWith => {
+            let s = text_arg(&args[0], "text_ends_with")?;
+            Ok(RuntimeValue::Bool(s.ends_with(text_arg(&args[1], "text_ends_with")?)))
+        }
+        BuiltinId::SortedBy | BuiltinId::LargestOf | BuiltinId::SmallestOf => {
+            call_sort_builtin(id, &args, &crate::semantics::collections::Orderings::new())
+        }
+        BuiltinId::WithField => {
+            let field = text_arg(&args[1], "with_field")?;
+            match &args[0] {
+                RuntimeValue::Struct(s) if s.fields.contains_key(field) => {
+                    let mut copy = s.clone();
+                    copy.fields.insert(field.to_string(), args[2].clone());
+                    Ok(RuntimeValue::Struct(copy))
+                }
+                RuntimeValue::Struct(s) => Err(format!("Cannot set {field}: a {} has no field {field}", s.type_name)),
+                other => Err(format!("Cannot set {field} on {}: it has no fields", other.type_name())),
+            }
+        }
+        BuiltinId::Pattern => {
+            let source = text_arg(&args[0], "pattern")?;
+            Ok(RuntimeValue::Pattern(Rc::new(logicaffeine_data::LogosPattern::new(source)?)))
+        }
+        BuiltinId::PatternMatches => {
+            let s = text_arg(&args[0], "pattern_matches")?;
+            Ok(RuntimeValue::Bool(pattern_arg(&args[1], "pattern_matches")?.is_match(s)))
+        }
+        BuiltinId::PatternCapture => {
+            let pattern = pattern_arg(&args[0], "pattern_capture")?;
+            let s = text_arg(&args[1], "pattern_capture")?;
+            let names = args[2..].iter().map(|n| text_arg(n, "pattern_capture")).collect::<Result<Vec<_>, _>>()?;
+            let (matched, whole, groups) = pattern.capture_fields(s, &names);
+            let text = |t: String| RuntimeValue::Text(Rc::new(t));
+            let mut fields: HashMap<String, RuntimeValue> =
+                names.iter().map(|n| n.to_string()).zip(groups.into_iter().map(text)).collect();
+            fields.insert("matched".to_string(), RuntimeValue::Bool(matched));
+            fields.insert("text".to_string(), text(whole));
+            Ok(RuntimeValue::Struct(Box::new(StructValue { type_name: "PatternMatch".to_string(), fields })))
+        }
         BuiltinId::WireBytes => {
             let arg = args.remove(0);
             match crate::concurrency::marshal::encode_value_raw(&arg) {
@@ -1102,6 +1335,19 @@ pub fn call_builtin(id: BuiltinId, args: Vec<RuntimeValue>) -> Result<RuntimeVal
                 _ => Err(format!("format_timestamp() requires a Moment, got {}", val.type_name())),
             }
         }
+        BuiltinId::FormatAs => {
+            let pattern = args.remove(1);
+            let val = args.remove(0);
+            match (instant_nanos(&val), &pattern) {
+                (Some(nanos), RuntimeValue::Text(p)) => logicaffeine_base::temporal::format_pattern(nanos, p)
+                    .map(|s| RuntimeValue::Text(Rc::new(s))),
+                _ => Err(format!(
+                    "format_as() requires a Moment or Date and a pattern (Text), got {} and {}",
+                    val.type_name(),
+                    pattern.type_name()
+                )),
+            }
+        }
         BuiltinId::YearOf
         | BuiltinId::MonthOf
         | BuiltinId::DayOf
@@ -1183,12 +1429,10 @@ pub fn call_builtin(id: BuiltinId, args: Vec<RuntimeValue>) -> Result<RuntimeVal
         BuiltinId::SecondsBetween => {
             let b = args.remove(1);
             let a = args.remove(0);
-            match (&a, &b) {
-                (RuntimeValue::Moment(a), RuntimeValue::Moment(b)) => {
-                    Ok(RuntimeValue::Int((b - a) / 1_000_000_000))
-                }
+            match (instant_nanos(&a), instant_nanos(&b)) {
+                (Some(a), Some(b)) => Ok(RuntimeValue::Int((b - a) / 1_000_000_000)),
                 _ => Err(format!(
-                    "seconds_between() requires two Moments, got {} and {}",
+                    "seconds_between() requires two Moments or Dates, got {} and {}",
                     a.type_name(),
                     b.type_name()
                 )),
@@ -1197,12 +1441,12 @@ pub fn call_builtin(id: BuiltinId, args: Vec<RuntimeValue>) -> Result<RuntimeVal
         BuiltinId::MonthsBetween | BuiltinId::YearsBetween => {
             let b = args.remove(1);
             let a = args.remove(0);
-            match (&a, &b) {
-                (RuntimeValue::Moment(a), RuntimeValue::Moment(b)) => {
+            match (instant_nanos(&a), instant_nanos(&b)) {
+                (Some(a), Some(b)) => {
                     let n = if matches!(id, BuiltinId::MonthsBetween) {
-                        logicaffeine_base::temporal::months_between(*a, *b)
+                        logicaffeine_base::temporal::months_between(a, b)
                     } else {
-                        logicaffeine_base::temporal::years_between(*a, *b)
+                        logicaffeine_base::temporal::years_between(a, b)
                     };
                     Ok(RuntimeValue::Int(n))
                 }
@@ -1213,7 +1457,7 @@ pub fn call_builtin(id: BuiltinId, args: Vec<RuntimeValue>) -> Result<RuntimeVal
                         "years_between"
                     };
                     Err(format!(
-                        "{name}() requires two Moments, got {} and {}",
+                        "{name}() requires two Moments or Dates, got {} and {}",
                         a.type_name(),
                         b.type_name()
                     ))
@@ -1981,9 +2225,22 @@ mod tests {
             assert!(call(id, &bogus).is_err(), "{id:?} should reject a non-temporal value");
         }
 
-        // seconds_between requires two Moments, in either position.
+        // seconds_between requires two Moments or Dates (a Date counts from midnight), in either position.
         assert!(call_builtin(BuiltinId::SecondsBetween, vec![m.clone(), bogus.clone()]).is_err());
         assert!(call_builtin(BuiltinId::SecondsBetween, vec![bogus.clone(), m.clone()]).is_err());
+        assert_eq!(
+            call_builtin(BuiltinId::SecondsBetween, vec![d.clone(), m.clone()]).unwrap(),
+            RuntimeValue::Int(7 * 3600 + 30 * 60 + 45)
+        );
+
+        // format_as renders either with a strftime-style pattern; a bad directive is an error.
+        let pattern = |p: &str| RuntimeValue::Text(Rc::new(p.to_string()));
+        assert_eq!(
+            call_builtin(BuiltinId::FormatAs, vec![m.clone(), pattern("%a %d %b %Y %H:%M")]).unwrap(),
+            pattern("Sun 10 Mar 2024 07:30")
+        );
+        assert_eq!(call_builtin(BuiltinId::FormatAs, vec![d.clone(), pattern("%F %T")]).unwrap(), pattern("2024-03-10 00:00:00"));
+        assert!(call_builtin(BuiltinId::FormatAs, vec![m.clone(), pattern("%Q")]).unwrap_err().contains("%Q"));
 
         // Pre-epoch (negative) Moments decompose by FLOOR division — no negative hour/second.
         let pre = RuntimeValue::Moment(temporal::parse_rfc3339("1969-12-31T23:59:58Z").unwrap());
@@ -2260,4 +2517,114 @@ mod tests {
             panic!("copy changed the type");
         }
     }
+
+    #[test]
+    fn with_field_copies_and_replaces_one_field() {
+        let mut fields = HashMap::new();
+        fields.insert("age".to_string(), RuntimeValue::Int(30));
+        let user = RuntimeValue::Struct(Box::new(StructValue { type_name: "User".to_string(), fields }));
+        let key = |s: &str| RuntimeValue::Text(Rc::new(s.to_string()));
+
+        let older = call_builtin(BuiltinId::WithField, vec![user.clone(), key("age"), RuntimeValue::Int(31)]).unwrap();
+        assert_eq!(older.to_display_string(), "User { age: 31 }");
+        assert_eq!(user.to_display_string(), "User { age: 30 }");
+
+        let e = call_builtin(BuiltinId::WithField, vec![user, key("name"), RuntimeValue::Int(1)]).unwrap_err();
+        assert_eq!(e, "Cannot set name: a User has no field name");
+        let e = call_builtin(BuiltinId::WithField, vec![RuntimeValue::Int(1), key("age"), RuntimeValue::Int(1)]).unwrap_err();
+        assert_eq!(e, "Cannot set age on Int: it has no fields");
+    }
+
+    #[test]
+    fn sorting_builtins_are_stable_and_keyed() {
+        let text = |s: &str| RuntimeValue::Text(Rc::new(s.to_string()));
+        let player = |name: &str, score: i64| {
+            let mut fields = HashMap::new();
+            fields.insert("name".to_string(), text(name));
+            fields.insert("score".to_string(), RuntimeValue::Int(score));
+            RuntimeValue::Struct(Box::new(StructValue { type_name: "Player".to_string(), fields }))
+        };
+        let names = |v: &RuntimeValue| match v {
+            RuntimeValue::List(l) => l
+                .borrow()
+                .to_values()
+                .iter()
+                .map(|p| match p {
+                    RuntimeValue::Struct(s) => s.fields["name"].to_display_string(),
+                    other => other.to_display_string(),
+                })
+                .collect::<Vec<_>>(),
+            other => panic!("expected a Seq, got {}", other.type_name()),
+        };
+        let people = RuntimeValue::List(Rc::new(RefCell::new(ListRepr::from_values(vec![
+            player("cy", 30),
+            player("ann", 25),
+            player("bo", 30),
+        ]))));
+        let by_score = call_builtin(BuiltinId::SortedBy, vec![people.clone(), text("score"), RuntimeValue::Bool(true)]).unwrap();
+        assert_eq!(names(&by_score), ["cy", "bo", "ann"]);
+        let top = call_builtin(BuiltinId::SmallestOf, vec![people.clone(), RuntimeValue::Int(1), text("score")]).unwrap();
+        assert_eq!(names(&top), ["ann"]);
+
+        let nums = RuntimeValue::List(Rc::new(RefCell::new(ListRepr::Ints(vec![3, 1, 2]))));
+        let largest = call_builtin(BuiltinId::LargestOf, vec![nums, RuntimeValue::Int(2), text("")]).unwrap();
+        assert_eq!(largest.to_display_string(), "[3, 2]");
+
+        // A struct sorts by its own order only when it is `Comparable`.
+        let e = call_builtin(BuiltinId::SortedBy, vec![people.clone(), text(""), RuntimeValue::Bool(false)]).unwrap_err();
+        assert_eq!(e, "Cannot sort Player values: sort them by a field, or declare `A Player is Comparable by <field>`");
+        let mut orderings = crate::semantics::collections::Orderings::new();
+        orderings.insert("Player".to_string(), vec!["score".to_string(), "name".to_string()]);
+        let natural = call_sort_builtin(BuiltinId::SortedBy, &[people, text(""), RuntimeValue::Bool(false)], &orderings).unwrap();
+        assert_eq!(names(&natural), ["ann", "bo", "cy"]);
+    }
+
+    #[test]
+    fn text_builtins_split_join_and_search() {
+        let text = |s: &str| RuntimeValue::Text(Rc::new(s.to_string()));
+        let fields = call_builtin(BuiltinId::TextSplit, vec![text("a,b,,c"), text(",")]).unwrap();
+        assert_eq!(fields.to_display_string(), "[a, b, , c]");
+        assert_eq!(call_builtin(BuiltinId::TextJoin, vec![fields, text("|")]).unwrap(), text("a|b||c"));
+        let nums = RuntimeValue::List(Rc::new(RefCell::new(ListRepr::Ints(vec![1, 2, 3]))));
+        assert_eq!(call_builtin(BuiltinId::TextJoin, vec![nums, text(" + ")]).unwrap(), text("1 + 2 + 3"));
+        let words = call_builtin(BuiltinId::TextWords, vec![text("  to be\tor  not ")]).unwrap();
+        assert_eq!(words.to_display_string(), "[to, be, or, not]");
+
+        assert_eq!(call_builtin(BuiltinId::TextTrim, vec![text("  hi \n")]).unwrap(), text("hi"));
+        assert_eq!(call_builtin(BuiltinId::TextUppercase, vec![text("Logos")]).unwrap(), text("LOGOS"));
+        assert_eq!(
+            call_builtin(BuiltinId::TextReplace, vec![text("a-b-c"), text("-"), text("::")]).unwrap(),
+            text("a::b::c")
+        );
+        assert_eq!(call_builtin(BuiltinId::TextFind, vec![text("café au lait"), text("au")]).unwrap(), RuntimeValue::Int(6));
+        assert_eq!(call_builtin(BuiltinId::TextFind, vec![text("abc"), text("z")]).unwrap(), RuntimeValue::Int(0));
+        assert_eq!(call_builtin(BuiltinId::TextStartsWith, vec![text("#x"), text("#")]).unwrap(), RuntimeValue::Bool(true));
+        assert_eq!(call_builtin(BuiltinId::TextEndsWith, vec![text("#x"), text("#")]).unwrap(), RuntimeValue::Bool(false));
+
+        let e = call_builtin(BuiltinId::TextTrim, vec![RuntimeValue::Int(3)]).unwrap_err();
+        assert_eq!(e, "text_trim() requires text, got Int");
+        assert_eq!(check_arity(BuiltinId::TextReplace, 2).unwrap_err(), "text_replace() takes exactly 3 arguments");
+    }
+
+    #[test]
+    fn pattern_builtins_match_replace_and_capture() {
+        let text = |s: &str| RuntimeValue::Text(Rc::new(s.to_string()));
+        let p = call_builtin(BuiltinId::Pattern, vec![text(r"(?<key>\w+)=(?<value>\d+)")]).unwrap();
+        assert_eq!(p.type_name(), "Pattern");
+        assert_eq!(call_builtin(BuiltinId::PatternMatches, vec![text("x=1"), p.clone()]).unwrap(), RuntimeValue::Bool(true));
+        assert_eq!(
+            call_builtin(BuiltinId::TextReplace, vec![text("a=1 b=2"), p.clone(), text("$value:$key")]).unwrap(),
+            text("1:a 2:b")
+        );
+        let record = call_builtin(BuiltinId::PatternCapture, vec![p.clone(), text("size=42"), text("key"), text("value")]).unwrap();
+        assert_eq!(record.to_display_string(), "PatternMatch { key: size, matched: true, text: size=42, value: 42 }");
+        let miss = call_builtin(BuiltinId::PatternCapture, vec![p, text("nothing"), text("key")]).unwrap();
+        assert_eq!(miss.to_display_string(), "PatternMatch { key: , matched: false, text:  }");
+
+        let e = call_builtin(BuiltinId::Pattern, vec![text("(a")]).unwrap_err();
+        assert_eq!(e, "invalid pattern \"(a\": unclosed group (at character 1)");
+        let e = call_builtin(BuiltinId::PatternMatches, vec![text("a"), text("a")]).unwrap_err();
+        assert_eq!(e, "pattern_matches() requires a Pattern, got Text");
+        assert!(check_arity(BuiltinId::PatternCapture, 1).is_err());
+    }
 }
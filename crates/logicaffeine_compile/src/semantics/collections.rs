@@ -3,6 +3,7 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use crate::ast::stmt::CollectionKind;
 use crate::interpreter::RuntimeValue;
 
 use super::compare::values_equal;
@@ -133,6 +134,8 @@ pub fn index_get(coll: &RuntimeValue, idx: &RuntimeValue) -> Result<RuntimeValue
                 None => Err(format!("Struct has no field '{}'", field)),
             }
         }
+        // A shared Document (or a map/list inside one) reads through to the document.
+        (RuntimeValue::Crdt(c), key) => crate::semantics::crdt::doc_item(c, key),
         _ => Err(format!(
             "Cannot index {} with {}",
             coll.type_name(),
@@ -156,6 +159,7 @@ pub fn index_set(coll: &RuntimeValue, idx: &RuntimeValue, value: RuntimeValue) -
             map.borrow_mut().insert(key.clone(), value);
             Ok(())
         }
+        (RuntimeValue::Crdt(c), key) => crate::semantics::crdt::doc_set_item(c, key, &value),
         (RuntimeValue::List(_), _) => Err("List index must be an integer".to_string()),
         _ => Err(format!("Cannot index into {}", coll.type_name())),
     }
@@ -325,10 +329,109 @@ pub fn iteration_snapshot(v: &RuntimeValue) -> Result<Vec<RuntimeValue>, String>
             .iter()
             .map(|(k, v)| RuntimeValue::Tuple(Rc::new(vec![k.clone(), v.clone()])))
             .collect()),
+        RuntimeValue::Crdt(c) => crate::semantics::crdt::doc_iteration(c)
+            .ok_or_else(|| format!("Cannot iterate over {}", v.type_name())),
         _ => Err(format!("Cannot iterate over {}", v.type_name())),
     }
 }
 
+/// Struct type name → the fields a `Comparable` type orders by, most significant first.
+pub type Orderings = std::collections::HashMap<String, Vec<String>>;
+
+/// `Sort xs [by field] [descending]` — a sorted copy of a Seq.
+///
+/// The sort is STABLE in both directions (equal keys keep their order), and an
+/// unordered pair (a NaN) counts as equal — `logicaffeine_data::sort`, which the
+/// compiled binary calls, follows the same two rules. An empty `key` sorts by the
+/// items themselves; a struct then orders by its `Comparable` fields.
+pub fn sorted(coll: &RuntimeValue, key: &str, descending: bool, orderings: &Orderings) -> Result<RuntimeValue, String> {
+    let items = match coll {
+        RuntimeValue::List(list) => list.borrow().to_values(),
+        other => return Err(format!("Cannot sort {}", other.type_name())),
+    };
+    let keys = if key.is_empty() {
+        items.clone()
+    } else {
+        items.iter().map(|item| sort_key(item, key)).collect::<Result<Vec<_>, _>>()?
+    };
+    // Compare every pair up front so an unorderable pair is an error, not a panic mid-sort.
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    let mut failure = None;
+    order.sort_by(|&a, &b| match sort_order(&keys[a], &keys[b], orderings) {
+        Ok(ord) if descending => ord.reverse(),
+        Ok(ord) => ord,
+        Err(e) => {
+            failure.get_or_insert(e);
+            std::cmp::Ordering::Equal
+        }
+    });
+    if let Some(e) = failure {
+        return Err(e);
+    }
+    let sorted = order.into_iter().map(|i| items[i].clone()).collect();
+    Ok(RuntimeValue::List(Rc::new(RefCell::new(crate::interpreter::ListRepr::from_values(sorted)))))
+}
+
+/// `the largest/smallest N items of xs [by field]` — the front `n` of the stable sort.
+pub fn take_sorted(
+    coll: &RuntimeValue,
+    n: &RuntimeValue,
+    key: &str,
+    largest: bool,
+    orderings: &Orderings,
+) -> Result<RuntimeValue, String> {
+    let n = match n {
+        RuntimeValue::Int(n) => (*n).max(0) as usize,
+        other => return Err(format!("The number of items must be an Int, got {}", other.type_name())),
+    };
+    let sorted = sorted(coll, key, largest, orderings)?;
+    if let RuntimeValue::List(list) = &sorted {
+        let mut items = list.borrow().to_values();
+        items.truncate(n);
+        *list.borrow_mut() = crate::interpreter::ListRepr::from_values(items);
+    }
+    Ok(sorted)
+}
+
+/// The field a `Sort … by <field>` orders a struct by.
+fn sort_key(item: &RuntimeValue, key: &str) -> Result<RuntimeValue, String> {
+    match item {
+        RuntimeValue::Struct(s) => s
+            .fields
+            .get(key)
+            .cloned()
+            .ok_or_else(|| format!("Cannot sort by {}: a {} has no field {}", key, s.type_name, key)),
+        other => Err(format!("Cannot sort {} by {}: it has no fields", other.type_name(), key)),
+    }
+}
+
+/// The order `Sort` uses: `compare`'s numeric/temporal order, plus Text, Char and
+/// Bool, plus `Comparable` structs field by field.
+fn sort_order(a: &RuntimeValue, b: &RuntimeValue, orderings: &Orderings) -> Result<std::cmp::Ordering, String> {
+    use std::cmp::Ordering;
+    match (a, b) {
+        (RuntimeValue::Text(x), RuntimeValue::Text(y)) => Ok(x.as_str().cmp(y.as_str())),
+        (RuntimeValue::Char(x), RuntimeValue::Char(y)) => Ok(x.cmp(y)),
+        (RuntimeValue::Bool(x), RuntimeValue::Bool(y)) => Ok(x.cmp(y)),
+        (RuntimeValue::Struct(x), RuntimeValue::Struct(y)) if x.type_name == y.type_name => {
+            let Some(fields) = orderings.get(&x.type_name) else {
+                return Err(format!(
+                    "Cannot sort {} values: sort them by a field, or declare `A {} is Comparable by <field>`",
+                    x.type_name, x.type_name
+                ));
+            };
+            for field in fields {
+                let ord = sort_order(&sort_key(a, field)?, &sort_key(b, field)?, orderings)?;
+                if ord != Ordering::Equal {
+                    return Ok(ord);
+                }
+            }
+            Ok(Ordering::Equal)
+        }
+        _ => Ok(super::compare::ordering(a, b)?.unwrap_or(Ordering::Equal)),
+    }
+}
+
 /// `Push value to obj's field` — pushes into a struct's List field through
 /// the shared allocation. Every error string is the spec.
 pub fn push_to_struct_field(
@@ -414,6 +517,8 @@ pub fn list_push(coll: &RuntimeValue, value: RuntimeValue) -> Result<(), String>
             items.borrow_mut().push(value);
             Ok(())
         }
+        // A list inside a shared Document
+        RuntimeValue::Crdt(c) if c.borrow().doc_value().is_some() => c.borrow_mut().append(&value),
         _ => Err("Can only push to a List".to_string()),
     }
 }
@@ -460,6 +565,34 @@ pub fn remove_from(coll: &RuntimeValue, value: &RuntimeValue) -> Result<(), Stri
     }
 }
 
+/// The empty collection a comprehension of `kind` fills.
+pub fn empty_collection(kind: CollectionKind) -> RuntimeValue {
+    match kind {
+        CollectionKind::Seq => RuntimeValue::List(Rc::new(RefCell::new(
+            crate::interpreter::ListRepr::from_values(Vec::new()),
+        ))),
+        CollectionKind::Set => RuntimeValue::Set(Rc::new(RefCell::new(Vec::new()))),
+        CollectionKind::Map => RuntimeValue::Map(Rc::new(RefCell::new(
+            crate::interpreter::MapStorage::default(),
+        ))),
+    }
+}
+
+/// Collect one comprehension result into `coll`: pushed onto a Seq, added to a
+/// Set unless already present, or stored under `element` in a Map (a repeated
+/// key keeps its first position and takes the latest value).
+pub fn collect_into(
+    coll: &RuntimeValue,
+    element: RuntimeValue,
+    value: Option<RuntimeValue>,
+) -> Result<(), String> {
+    match (coll, value) {
+        (RuntimeValue::Map(_), Some(value)) => index_set(coll, &element, value),
+        (RuntimeValue::Set(_), _) => set_add(coll, element),
+        _ => list_push(coll, element),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
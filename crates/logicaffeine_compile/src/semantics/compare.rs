@@ -1,5 +1,7 @@
 //! Equality and relational comparison.
 
+use std::cmp::Ordering;
+
 use logicaffeine_base::numeric;
 use logicaffeine_base::{BigInt, Rational};
 
@@ -83,6 +85,7 @@ fn values_equal_depth(left: &RuntimeValue, right: &RuntimeValue, depth: usize) -
         (RuntimeValue::Money(a), RuntimeValue::Money(b)) => a == b,
         (RuntimeValue::Quantity(a), RuntimeValue::Quantity(b)) => a.q == b.q,
         (RuntimeValue::Uuid(a), RuntimeValue::Uuid(b)) => a == b,
+        (RuntimeValue::Pattern(a), RuntimeValue::Pattern(b)) => a == b,
         (RuntimeValue::Inductive(a), RuntimeValue::Inductive(b)) => {
             a.inductive_type == b.inductive_type
                 && a.constructor == b.constructor
@@ -162,95 +165,80 @@ pub fn compare(
     left: &RuntimeValue,
     right: &RuntimeValue,
 ) -> Result<RuntimeValue, String> {
-    use std::cmp::Ordering;
-
-    // Map an `Ordering` (or `None` for an unordered/NaN pair) to the operator.
-    let rel = |ord: Option<Ordering>| -> bool {
-        match ord {
-            None => false,
-            Some(o) => match op {
-                BinaryOpKind::Lt => o == Ordering::Less,
-                BinaryOpKind::Gt => o == Ordering::Greater,
-                BinaryOpKind::LtEq => o != Ordering::Greater,
-                BinaryOpKind::GtEq => o != Ordering::Less,
-                _ => false,
-            },
-        }
+    // Map the `Ordering` (or `None` for an unordered/NaN pair) to the operator.
+    let holds = match ordering(left, right)? {
+        None => false,
+        Some(o) => match op {
+            BinaryOpKind::Lt => o == Ordering::Less,
+            BinaryOpKind::Gt => o == Ordering::Greater,
+            BinaryOpKind::LtEq => o != Ordering::Greater,
+            BinaryOpKind::GtEq => o != Ordering::Less,
+            _ => false,
+        },
     };
-    let int_rel = |a: i64, b: i64| rel(Some(a.cmp(&b)));
+    Ok(RuntimeValue::Bool(holds))
+}
 
+/// The order of two values under `compare`'s rules — `None` for an unordered
+/// (NaN) pair, an error when the types have no common order. Sorting shares it
+/// so `Sort` and `<` never disagree.
+pub fn ordering(left: &RuntimeValue, right: &RuntimeValue) -> Result<Option<Ordering>, String> {
     match (left, right) {
-        (RuntimeValue::Int(a), RuntimeValue::Int(b)) => Ok(RuntimeValue::Bool(int_rel(*a, *b))),
+        (RuntimeValue::Int(a), RuntimeValue::Int(b)) => Ok(Some(a.cmp(b))),
         // Exact integer ordering across the narrow/wide boundary: compare as BigInts.
-        (RuntimeValue::BigInt(a), RuntimeValue::BigInt(b)) => {
-            Ok(RuntimeValue::Bool(rel(Some((**a).cmp(b)))))
-        }
+        (RuntimeValue::BigInt(a), RuntimeValue::BigInt(b)) => Ok(Some((**a).cmp(b))),
         (RuntimeValue::BigInt(a), RuntimeValue::Int(b)) => {
-            Ok(RuntimeValue::Bool(rel(Some((**a).cmp(&BigInt::from_i64(*b))))))
-        }
-        (RuntimeValue::Int(a), RuntimeValue::BigInt(b)) => {
-            Ok(RuntimeValue::Bool(rel(Some(BigInt::from_i64(*a).cmp(b)))))
+            Ok(Some((**a).cmp(&BigInt::from_i64(*b))))
         }
+        (RuntimeValue::Int(a), RuntimeValue::BigInt(b)) => Ok(Some(BigInt::from_i64(*a).cmp(b))),
         // Cross-type numeric ordering is EXACT — mathematical values, never a
         // lossy as-f64 view (which rounds above 2^53). NaN stays unordered.
         (RuntimeValue::BigInt(a), RuntimeValue::Float(b)) => {
-            Ok(RuntimeValue::Bool(rel(numeric::cmp_bigint_f64_exact(a, *b))))
+            Ok(numeric::cmp_bigint_f64_exact(a, *b))
         }
         (RuntimeValue::Float(a), RuntimeValue::BigInt(b)) => {
-            Ok(RuntimeValue::Bool(rel(numeric::cmp_bigint_f64_exact(b, *a).map(std::cmp::Ordering::reverse))))
+            Ok(numeric::cmp_bigint_f64_exact(b, *a).map(Ordering::reverse))
         }
         // Exact rational ordering (cross-multiply, no rounding) including the
         // narrow/wide boundary; vs Float uses IEEE partial order on the f64 view.
-        (RuntimeValue::Rational(a), RuntimeValue::Rational(b)) => {
-            Ok(RuntimeValue::Bool(rel(Some((**a).cmp(b)))))
-        }
+        (RuntimeValue::Rational(a), RuntimeValue::Rational(b)) => Ok(Some((**a).cmp(b))),
         (RuntimeValue::Rational(a), RuntimeValue::Int(b)) => {
-            Ok(RuntimeValue::Bool(rel(Some((**a).cmp(&Rational::from_i64(*b))))))
+            Ok(Some((**a).cmp(&Rational::from_i64(*b))))
         }
         (RuntimeValue::Int(a), RuntimeValue::Rational(b)) => {
-            Ok(RuntimeValue::Bool(rel(Some(Rational::from_i64(*a).cmp(b)))))
+            Ok(Some(Rational::from_i64(*a).cmp(b)))
         }
         (RuntimeValue::Rational(a), RuntimeValue::BigInt(b)) => {
-            Ok(RuntimeValue::Bool(rel(Some((**a).cmp(&Rational::from_bigint((**b).clone()))))))
+            Ok(Some((**a).cmp(&Rational::from_bigint((**b).clone()))))
         }
         (RuntimeValue::BigInt(a), RuntimeValue::Rational(b)) => {
-            Ok(RuntimeValue::Bool(rel(Some(Rational::from_bigint((**a).clone()).cmp(b)))))
+            Ok(Some(Rational::from_bigint((**a).clone()).cmp(b)))
         }
         (RuntimeValue::Rational(a), RuntimeValue::Float(b)) => {
-            Ok(RuntimeValue::Bool(rel(numeric::cmp_rational_f64_exact(a, *b))))
+            Ok(numeric::cmp_rational_f64_exact(a, *b))
         }
         (RuntimeValue::Float(a), RuntimeValue::Rational(b)) => {
-            Ok(RuntimeValue::Bool(rel(numeric::cmp_rational_f64_exact(b, *a).map(std::cmp::Ordering::reverse))))
-        }
-        (RuntimeValue::Float(a), RuntimeValue::Float(b)) => {
-            Ok(RuntimeValue::Bool(rel(a.partial_cmp(b))))
-        }
-        (RuntimeValue::Int(a), RuntimeValue::Float(b)) => {
-            Ok(RuntimeValue::Bool(rel(numeric::cmp_i64_f64_exact(*a, *b))))
+            Ok(numeric::cmp_rational_f64_exact(b, *a).map(Ordering::reverse))
         }
+        (RuntimeValue::Float(a), RuntimeValue::Float(b)) => Ok(a.partial_cmp(b)),
+        (RuntimeValue::Int(a), RuntimeValue::Float(b)) => Ok(numeric::cmp_i64_f64_exact(*a, *b)),
         (RuntimeValue::Float(a), RuntimeValue::Int(b)) => {
-            Ok(RuntimeValue::Bool(rel(numeric::cmp_i64_f64_exact(*b, *a).map(std::cmp::Ordering::reverse))))
-        }
-        (RuntimeValue::Duration(a), RuntimeValue::Duration(b)) => {
-            Ok(RuntimeValue::Bool(int_rel(*a, *b)))
-        }
-        (RuntimeValue::Date(a), RuntimeValue::Date(b)) => {
-            Ok(RuntimeValue::Bool(int_rel(*a as i64, *b as i64)))
-        }
-        (RuntimeValue::Moment(a), RuntimeValue::Moment(b)) => {
-            Ok(RuntimeValue::Bool(int_rel(*a, *b)))
+            Ok(numeric::cmp_i64_f64_exact(*b, *a).map(Ordering::reverse))
         }
-        (RuntimeValue::Time(a), RuntimeValue::Time(b)) => Ok(RuntimeValue::Bool(int_rel(*a, *b))),
+        (RuntimeValue::Duration(a), RuntimeValue::Duration(b)) => Ok(Some(a.cmp(b))),
+        (RuntimeValue::Date(a), RuntimeValue::Date(b)) => Ok(Some(a.cmp(b))),
+        (RuntimeValue::Moment(a), RuntimeValue::Moment(b)) => Ok(Some(a.cmp(b))),
+        (RuntimeValue::Time(a), RuntimeValue::Time(b)) => Ok(Some(a.cmp(b))),
         // Moment vs Time: extract time-of-day from Moment. Use Euclidean
         // remainder so a pre-epoch (negative) Moment yields a 0..86399 ns
         // time-of-day, not a negative one.
         (RuntimeValue::Moment(m), RuntimeValue::Time(t)) => {
             let nanos_per_day = 86_400_000_000_000i64;
-            Ok(RuntimeValue::Bool(int_rel(m.rem_euclid(nanos_per_day), *t)))
+            Ok(Some(m.rem_euclid(nanos_per_day).cmp(t)))
         }
         (RuntimeValue::Time(t), RuntimeValue::Moment(m)) => {
             let nanos_per_day = 86_400_000_000_000i64;
-            Ok(RuntimeValue::Bool(int_rel(*t, m.rem_euclid(nanos_per_day))))
+            Ok(Some(t.cmp(&m.rem_euclid(nanos_per_day))))
         }
         // Two physical quantities order by their EXACT SI magnitude when their dimensions match;
         // ordering across dimensions (Length vs Mass) is a typed error, mirroring the AOT `PartialOrd`
@@ -263,7 +251,7 @@ pub fn compare(
                     b.q.dimension()
                 ));
             }
-            Ok(RuntimeValue::Bool(rel(Some(a.q.magnitude_si().cmp(b.q.magnitude_si())))))
+            Ok(Some(a.q.magnitude_si().cmp(b.q.magnitude_si())))
         }
         // Money orders by amount within the SAME currency; ordering across currencies is meaningless
         // without a rate context, so it is a typed error (the dimension-mismatch precedent).
@@ -274,12 +262,10 @@ pub fn compare(
                     a.currency.code, b.currency.code
                 ));
             }
-            Ok(RuntimeValue::Bool(rel(Some(
-                a.amount.to_rational().cmp(&b.amount.to_rational()),
-            ))))
+            Ok(Some(a.amount.to_rational().cmp(&b.amount.to_rational())))
         }
         // UUIDs order by their 128 bits — so v6/v7 (time-ordered) ids sort chronologically.
-        (RuntimeValue::Uuid(a), RuntimeValue::Uuid(b)) => Ok(RuntimeValue::Bool(rel(Some(a.cmp(b))))),
+        (RuntimeValue::Uuid(a), RuntimeValue::Uuid(b)) => Ok(Some(a.cmp(b))),
         // A Decimal orders by EXACT value against any exact number (Int/BigInt/Rational/
         // Decimal); against a Float it compares on the f64 view (IEEE partial order).
         (l, r) if matches!(l, RuntimeValue::Decimal(_)) || matches!(r, RuntimeValue::Decimal(_)) => {
@@ -293,7 +279,7 @@ pub fn compare(
                 }
             };
             if let (Some(a), Some(b)) = (rat_view(l), rat_view(r)) {
-                Ok(RuntimeValue::Bool(rel(Some(a.cmp(&b)))))
+                Ok(Some(a.cmp(&b)))
             } else {
                 let f64_view = |v: &RuntimeValue| -> Option<f64> {
                     match v {
@@ -302,7 +288,7 @@ pub fn compare(
                     }
                 };
                 match (f64_view(l), f64_view(r)) {
-                    (Some(a), Some(b)) => Ok(RuntimeValue::Bool(rel(a.partial_cmp(&b)))),
+                    (Some(a), Some(b)) => Ok(a.partial_cmp(&b)),
                     _ => Err(format!("Cannot compare {} and {}", l.type_name(), r.type_name())),
                 }
             }
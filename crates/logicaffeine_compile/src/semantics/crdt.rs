@@ -13,9 +13,23 @@
 //! [`CrdtScalar`] is the dynamically-typed element that covers every element type the
 //! surface syntax can name; it is `Hash + Eq + Ord + Clone`, so it satisfies the
 //! data-crate CRDT bounds.
-
-use crate::interpreter::RuntimeValue;
-use logicaffeine_data::crdt::{DeltaCrdt, Merge, MVRegister, ORSet, RemoveWins, ReplicaId, VClock, RGA};
+//!
+//! A `shared Document` is the exception: it nests maps and lists, so its content is
+//! a [`DocValue`]. `item "cards" of doc` on a nested map or list yields a
+//! [`CrdtValue::DocPart`] handle rather than a copy, so writes through it land in
+//! the document and one card's fields merge independently of another's.
+//!
+//! `the sync status of x` ([`sync_status`]) shows what hasn't converged: the version, whether
+//! the next `Sync` has anything to publish, the peers heard from, and a `Divergent` value's
+//! concurrent writes — so a program can show a conflict instead of resolving it blindly.
+
+use crate::interpreter::{ListRepr, MapStorage, RuntimeValue};
+use logicaffeine_data::crdt::{
+    DeltaCrdt, DocValue, Document, Key, Merge, MVRegister, ORSet, RemoveWins, ReplicaId, VClock, RGA,
+};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 /// A monotonic source of replica ids for CRDTs the interpreter constructs. Each `new`
@@ -92,6 +106,10 @@ pub enum CrdtValue {
     /// `Divergent T` — multi-value register (`MVRegister`). Keeps concurrent writes until
     /// one is resolved.
     Register(MVRegister<CrdtScalar>),
+    /// `shared Document` — JSON-like nested maps and lists (`Document`).
+    Document(Document),
+    /// A map or list inside a document: the document's cell and the path to it.
+    DocPart(Rc<RefCell<CrdtValue>>, Vec<Key>),
 }
 
 impl CrdtValue {
@@ -111,12 +129,52 @@ impl CrdtValue {
         CrdtValue::Register(MVRegister::new(replica))
     }
 
+    pub fn new_document(replica: ReplicaId) -> CrdtValue {
+        CrdtValue::Document(Document::new(replica))
+    }
+
+    /// Run `f` on the document and the path this value names within it.
+    fn with_doc<R>(&self, f: impl FnOnce(&Document, &[Key]) -> R) -> Option<R> {
+        match self {
+            CrdtValue::Document(d) => Some(f(d, &[])),
+            CrdtValue::DocPart(rc, path) => match &*rc.borrow() {
+                CrdtValue::Document(d) => Some(f(d, path)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn with_doc_mut<R>(&mut self, f: impl FnOnce(&mut Document, &[Key]) -> R) -> Option<R> {
+        match self {
+            CrdtValue::Document(d) => Some(f(d, &[])),
+            CrdtValue::DocPart(rc, path) => match &mut *rc.borrow_mut() {
+                CrdtValue::Document(d) => Some(f(d, path)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// The replica this value's local updates are tagged with.
+    pub fn replica_id(&self) -> ReplicaId {
+        match self {
+            CrdtValue::Set(s) => s.replica_id(),
+            CrdtValue::SetRemoveWins(s) => s.replica_id(),
+            CrdtValue::Seq(s) => s.replica_id(),
+            CrdtValue::Register(r) => r.replica_id(),
+            CrdtValue::Document(d) => d.replica_id(),
+            CrdtValue::DocPart(..) => self.with_doc(|d, _| d.replica_id()).unwrap_or_default(),
+        }
+    }
+
     /// The CRDT kind, used for type errors and `type_name`.
     pub fn kind(&self) -> &'static str {
         match self {
             CrdtValue::Set(_) | CrdtValue::SetRemoveWins(_) => "SharedSet",
             CrdtValue::Seq(_) => "SharedSequence",
             CrdtValue::Register(_) => "Divergent",
+            CrdtValue::Document(_) | CrdtValue::DocPart(..) => "Document",
         }
     }
 
@@ -132,6 +190,13 @@ impl CrdtValue {
 
     /// `Remove element from <set>` — observed-remove deletion.
     pub fn remove(&mut self, value: &RuntimeValue) -> Result<(), String> {
+        if let Some(removed) = self.with_doc_mut(|d, path| {
+            let key = doc_key(d, path, value)?;
+            d.remove(&[path, std::slice::from_ref(&key)].concat());
+            Ok(())
+        }) {
+            return removed;
+        }
         let e = CrdtScalar::from_runtime(value)?;
         match self {
             CrdtValue::Set(s) => Ok(s.remove(&e)),
@@ -142,6 +207,13 @@ impl CrdtValue {
 
     /// `<set> contains element` — observed-remove membership.
     pub fn contains(&self, value: &RuntimeValue) -> Result<bool, String> {
+        if let Some(found) = self.with_doc(|d, path| match (d.get(path), value) {
+            (Some(DocValue::Map(fields)), RuntimeValue::Text(key)) => Ok(fields.contains_key(key.as_str())),
+            (Some(DocValue::List(items)), _) => Ok(items.contains(&to_doc(value)?)),
+            _ => Ok(false),
+        }) {
+            return found;
+        }
         let e = CrdtScalar::from_runtime(value)?;
         match self {
             CrdtValue::Set(s) => Ok(s.contains(&e)),
@@ -157,6 +229,13 @@ impl CrdtValue {
                 s.append(CrdtScalar::from_runtime(value)?);
                 Ok(())
             }
+            CrdtValue::DocPart(..) => {
+                let value = to_doc(value)?;
+                match self.with_doc_mut(|d, path| d.push(path, value)) {
+                    Some(true) => Ok(()),
+                    _ => Err("can only append to a list in a Document".to_string()),
+                }
+            }
             other => Err(format!("cannot append to a {}", other.kind())),
         }
     }
@@ -180,6 +259,9 @@ impl CrdtValue {
             CrdtValue::SetRemoveWins(s) => s.len(),
             CrdtValue::Seq(s) => s.len(),
             CrdtValue::Register(r) => r.values().len(),
+            CrdtValue::Document(_) | CrdtValue::DocPart(..) => {
+                self.with_doc(|d, path| d.len(path)).flatten().unwrap_or(0)
+            }
         }
     }
 
@@ -207,6 +289,26 @@ impl CrdtValue {
                 vals.sort();
                 vals.into_iter().map(CrdtScalar::to_runtime).collect()
             }
+            // A document's items, or its keys in order
+            CrdtValue::Document(_) | CrdtValue::DocPart(..) => match self.doc_value() {
+                Some(DocValue::List(items)) => items.iter().map(from_doc).collect(),
+                Some(DocValue::Map(fields)) => {
+                    fields.into_keys().map(|k| RuntimeValue::Text(Rc::new(k))).collect()
+                }
+                _ => Vec::new(),
+            },
+        }
+    }
+
+    /// The plain content of a document or of the part this handle names.
+    pub fn doc_value(&self) -> Option<DocValue> {
+        self.with_doc(|d, path| d.get(path)).flatten()
+    }
+
+    fn doc_value_at(&self, path: &[Key]) -> Option<DocValue> {
+        match self {
+            CrdtValue::Document(d) => d.get(path),
+            _ => None,
         }
     }
 
@@ -246,6 +348,10 @@ impl CrdtValue {
                 a.merge(b);
                 Ok(())
             }
+            (CrdtValue::Document(a), CrdtValue::Document(b)) => {
+                a.merge(b);
+                Ok(())
+            }
             (a, b) => Err(format!("cannot merge a {} with a {}", a.kind(), b.kind())),
         }
     }
@@ -260,6 +366,8 @@ impl CrdtValue {
             CrdtValue::SetRemoveWins(s) => s.version(),
             CrdtValue::Seq(s) => s.version(),
             CrdtValue::Register(s) => s.version(),
+            CrdtValue::Document(d) => d.version(),
+            CrdtValue::DocPart(..) => self.with_doc(|d, _| d.version()).unwrap_or_default(),
         }
     }
 
@@ -280,6 +388,9 @@ impl CrdtValue {
             CrdtValue::SetRemoveWins(s) => tagged(1, &s.delta_since(since)?),
             CrdtValue::Seq(s) => tagged(2, &s.delta_since(since)?),
             CrdtValue::Register(s) => tagged(3, &s.delta_since(since)?),
+            CrdtValue::Document(d) => tagged(4, &d.delta_since(since)?),
+            // A part syncs with the document that holds it
+            CrdtValue::DocPart(..) => None,
         }
     }
 
@@ -327,13 +438,23 @@ impl CrdtValue {
                     Err(_) => false,
                 }
             }
+            (CrdtValue::Document(s), 4) => {
+                match bincode::deserialize::<<Document as DeltaCrdt>::Delta>(body) {
+                    Ok(d) => {
+                        s.apply_delta(&d);
+                        true
+                    }
+                    Err(_) => false,
+                }
+            }
             _ => false,
         }
     }
 
     /// `Show` rendering: a set renders in set notation `{…}` (matching a plain `Set`), a
     /// sequence as an ordered list `[…]`, a register as its resolved value (or, while still
-    /// divergent, the brace-joined set of concurrent values).
+    /// divergent, the brace-joined set of concurrent values), a document as the plain
+    /// maps and lists it holds.
     pub fn render(&self) -> String {
         let parts = || -> String {
             self.to_runtime_vec().iter().map(render_runtime).collect::<Vec<_>>().join(", ")
@@ -345,6 +466,9 @@ impl CrdtValue {
             },
             CrdtValue::Set(_) | CrdtValue::SetRemoveWins(_) => format!("{{{}}}", parts()),
             CrdtValue::Seq(_) => format!("[{}]", parts()),
+            CrdtValue::Document(_) | CrdtValue::DocPart(..) => self
+                .doc_value()
+                .map_or_else(|| "nothing".to_string(), |v| from_doc(&v).to_display_string()),
         }
     }
 }
@@ -359,7 +483,247 @@ pub fn crdt_values_equal(a: &CrdtValue, b: &CrdtValue) -> bool {
         | (CrdtValue::Register(_), CrdtValue::Register(_)) => {
             a.to_runtime_vec() == b.to_runtime_vec()
         }
+        (CrdtValue::Document(_) | CrdtValue::DocPart(..), CrdtValue::Document(_) | CrdtValue::DocPart(..)) => {
+            a.doc_value() == b.doc_value()
+        }
+        _ => false,
+    }
+}
+
+/// `the sync status of x` — a `SyncStatus` for a shared value, or for a `Shared` struct a map
+/// from each shared field's name to its status. `synced` is the version each replica last
+/// published with `Sync`; a replica missing from it has published nothing.
+///
+/// Replica ids come from a process-wide counter, so the status numbers the replicas it
+/// mentions 1, 2, … in the order they were created. Ids are allocated in program order, so
+/// every engine running the same program shows the same numbers.
+pub fn sync_status(value: &RuntimeValue, synced: &HashMap<ReplicaId, VClock>) -> Result<RuntimeValue, String> {
+    match value {
+        RuntimeValue::Crdt(c) => Ok(crdt_status(&c.borrow(), synced)),
+        RuntimeValue::Struct(s) => {
+            let mut fields: Vec<(&String, &Rc<RefCell<CrdtValue>>)> = s
+                .fields
+                .iter()
+                .filter_map(|(name, v)| match v {
+                    RuntimeValue::Crdt(c) => Some((name, c)),
+                    _ => None,
+                })
+                .collect();
+            if fields.is_empty() {
+                return Err(format!("a {} has no shared collections to report a sync status for", s.type_name));
+            }
+            fields.sort_by_key(|(name, _)| *name);
+            let mut map = MapStorage::default();
+            for (name, c) in fields {
+                map.insert(RuntimeValue::Text(Rc::new(name.clone())), crdt_status(&c.borrow(), synced));
+            }
+            Ok(RuntimeValue::Map(Rc::new(RefCell::new(map))))
+        }
+        other => Err(format!("only shared values have a sync status, not {}", other.type_name())),
+    }
+}
+
+fn crdt_status(crdt: &CrdtValue, synced: &HashMap<ReplicaId, VClock>) -> RuntimeValue {
+    let replica = crdt.replica_id();
+    let version = crdt.version();
+    let mut replicas: Vec<ReplicaId> = version.replicas().copied().chain([replica]).collect();
+    replicas.sort_unstable();
+    replicas.dedup();
+    let label = |r: ReplicaId| RuntimeValue::Int(replicas.binary_search(&r).map_or(0, |i| i as i64 + 1));
+    let clock = |clock: &VClock| {
+        let mut map = MapStorage::default();
+        for &r in &replicas {
+            let count = clock.get(r);
+            if count > 0 {
+                map.insert(label(r), RuntimeValue::Int(count as i64));
+            }
+        }
+        RuntimeValue::Map(Rc::new(RefCell::new(map)))
+    };
+
+    let peers: Vec<RuntimeValue> =
+        replicas.iter().filter(|&&r| r != replica && version.get(r) > 0).map(|&r| label(r)).collect();
+    // The same test `Sync` uses: it publishes whenever the version moved past what it last shipped.
+    let pending = !synced.get(&replica).cloned().unwrap_or_default().dominates(&version);
+    let conflicts: Vec<RuntimeValue> = match crdt {
+        CrdtValue::Register(r) if r.has_conflict() => r
+            .versions()
+            .into_iter()
+            .map(|(value, written)| RuntimeValue::Tuple(Rc::new(vec![value.to_runtime(), clock(written)])))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let list = |items: Vec<RuntimeValue>| RuntimeValue::List(Rc::new(RefCell::new(ListRepr::from_values(items))));
+    let fields = HashMap::from([
+        ("replica".to_string(), label(replica)),
+        ("version".to_string(), clock(&version)),
+        ("pending".to_string(), RuntimeValue::Bool(pending)),
+        ("peers".to_string(), list(peers)),
+        ("conflicts".to_string(), list(conflicts)),
+    ]);
+    RuntimeValue::Struct(Box::new(crate::interpreter::StructValue { type_name: "SyncStatus".to_string(), fields }))
+}
+
+/// The document cell and path that `value` names, if it is a document or a part of one.
+fn doc_place(value: &Rc<RefCell<CrdtValue>>) -> Option<(Rc<RefCell<CrdtValue>>, Vec<Key>)> {
+    match &*value.borrow() {
+        CrdtValue::Document(_) => Some((value.clone(), Vec::new())),
+        CrdtValue::DocPart(rc, path) => Some((rc.clone(), path.clone())),
+        _ => None,
+    }
+}
+
+/// The path step for a LOGOS key into the map or list at `path`: a Text key names
+/// a field, an Int a 1-based item.
+fn doc_key(doc: &Document, path: &[Key], key: &RuntimeValue) -> Result<Key, String> {
+    match key {
+        RuntimeValue::Text(field) => Ok(Key::Field((**field).clone())),
+        RuntimeValue::Int(i) => {
+            let len = doc.len(path).unwrap_or(0);
+            if *i >= 1 && (*i as usize) <= len {
+                Ok(Key::Index(*i as usize - 1))
+            } else {
+                Err(format!("Index {} is out of bounds for a document list of length {}", i, len))
+            }
+        }
+        other => Err(format!("a Document is indexed by Text or Int, not {}", other.type_name())),
+    }
+}
+
+/// `item key of doc` — a value for a scalar, a live [`CrdtValue::DocPart`] handle for
+/// a nested map or list.
+pub fn doc_item(value: &Rc<RefCell<CrdtValue>>, key: &RuntimeValue) -> Result<RuntimeValue, String> {
+    let Some((rc, mut path)) = doc_place(value) else {
+        return Err(format!("Cannot index {} with {}", value.borrow().kind(), key.type_name()));
+    };
+    let found = match &*rc.borrow() {
+        CrdtValue::Document(d) => {
+            path.push(doc_key(d, &path, key)?);
+            d.get(&path)
+        }
+        _ => None,
+    };
+    match found {
+        Some(DocValue::List(_) | DocValue::Map(_)) => {
+            Ok(RuntimeValue::Crdt(Rc::new(RefCell::new(CrdtValue::DocPart(rc, path)))))
+        }
+        Some(scalar) => Ok(from_doc(&scalar)),
+        None => Err(format!("Key '{}' not found in document", key.to_display_string())),
+    }
+}
+
+/// `Set item key of doc to value`.
+pub fn doc_set_item(value: &Rc<RefCell<CrdtValue>>, key: &RuntimeValue, new: &RuntimeValue) -> Result<(), String> {
+    let new = to_doc(new)?;
+    let Some((rc, path)) = doc_place(value) else {
+        return Err(format!("Cannot index into {}", value.borrow().kind()));
+    };
+    let written = match &mut *rc.borrow_mut() {
+        CrdtValue::Document(d) => {
+            let key = doc_key(d, &path, key)?;
+            d.set(&[path.as_slice(), std::slice::from_ref(&key)].concat(), new)
+        }
         _ => false,
+    };
+    if written {
+        Ok(())
+    } else {
+        Err("Cannot set an item of a Document value that is not a map or list".to_string())
+    }
+}
+
+/// The `Repeat` items of a document: list items, or a map's (key, value) pairs,
+/// with nested maps and lists as live handles.
+pub fn doc_iteration(value: &Rc<RefCell<CrdtValue>>) -> Option<Vec<RuntimeValue>> {
+    let (rc, path) = doc_place(value)?;
+    let part = |key: Key| {
+        let mut path = path.clone();
+        path.push(key);
+        match rc.borrow().doc_value_at(&path) {
+            Some(DocValue::List(_) | DocValue::Map(_)) => {
+                RuntimeValue::Crdt(Rc::new(RefCell::new(CrdtValue::DocPart(rc.clone(), path))))
+            }
+            Some(scalar) => from_doc(&scalar),
+            None => RuntimeValue::Nothing,
+        }
+    };
+    let content = rc.borrow().doc_value_at(&path);
+    Some(match content? {
+        DocValue::List(items) => (0..items.len()).map(|i| part(Key::Index(i))).collect(),
+        DocValue::Map(fields) => fields
+            .into_keys()
+            .map(|k| {
+                let item = part(Key::Field(k.clone()));
+                RuntimeValue::Tuple(Rc::new(vec![RuntimeValue::Text(Rc::new(k)), item]))
+            })
+            .collect(),
+        _ => Vec::new(),
+    })
+}
+
+/// A value-copy: a document part becomes its plain value, any other CRDT an
+/// independent replica.
+pub fn crdt_copy(value: &CrdtValue) -> RuntimeValue {
+    match value {
+        CrdtValue::DocPart(..) => value.doc_value().map_or(RuntimeValue::Nothing, |v| from_doc(&v)),
+        other => RuntimeValue::Crdt(Rc::new(RefCell::new(other.clone()))),
+    }
+}
+
+/// A runtime value as document content. Structs become maps of their fields.
+pub fn to_doc(value: &RuntimeValue) -> Result<DocValue, String> {
+    Ok(match value {
+        RuntimeValue::Nothing => DocValue::Null,
+        RuntimeValue::Bool(b) => DocValue::Bool(*b),
+        RuntimeValue::Int(n) => DocValue::Int(*n),
+        RuntimeValue::Text(s) => DocValue::Text((**s).clone()),
+        RuntimeValue::List(items) => {
+            DocValue::List(items.borrow().to_values().iter().map(to_doc).collect::<Result<_, _>>()?)
+        }
+        RuntimeValue::Map(map) => {
+            let mut fields = BTreeMap::new();
+            for (k, v) in map.borrow().iter() {
+                match k {
+                    RuntimeValue::Text(k) => fields.insert((**k).clone(), to_doc(v)?),
+                    other => return Err(format!("a Document map has Text keys, not {}", other.type_name())),
+                };
+            }
+            DocValue::Map(fields)
+        }
+        RuntimeValue::Struct(s) => DocValue::Map(
+            s.fields.iter().map(|(k, v)| Ok((k.clone(), to_doc(v)?))).collect::<Result<_, String>>()?,
+        ),
+        RuntimeValue::Crdt(c) => match c.try_borrow() {
+            Ok(c) => c.doc_value().ok_or_else(|| format!("a Document cannot hold a {}", c.kind()))?,
+            Err(_) => return Err("a Document cannot hold itself".to_string()),
+        },
+        other => {
+            return Err(format!(
+                "a Document holds Int, Text, Bool, lists, maps and structs, not {}",
+                other.type_name()
+            ))
+        }
+    })
+}
+
+/// Plain document content as a runtime value.
+pub fn from_doc(value: &DocValue) -> RuntimeValue {
+    match value {
+        DocValue::Null => RuntimeValue::Nothing,
+        DocValue::Bool(b) => RuntimeValue::Bool(*b),
+        DocValue::Int(n) => RuntimeValue::Int(*n),
+        DocValue::Text(s) => RuntimeValue::Text(Rc::new(s.clone())),
+        DocValue::List(items) => {
+            RuntimeValue::List(Rc::new(RefCell::new(ListRepr::from_values(items.iter().map(from_doc).collect()))))
+        }
+        DocValue::Map(fields) => {
+            let mut map = MapStorage::default();
+            for (k, v) in fields {
+                map.insert(RuntimeValue::Text(Rc::new(k.clone())), from_doc(v));
+            }
+            RuntimeValue::Map(Rc::new(RefCell::new(map)))
+        }
     }
 }
 
@@ -488,4 +852,25 @@ mod tests {
         // A non-scalar is refused at the seam, not silently coerced.
         assert!(CrdtScalar::from_runtime(&RuntimeValue::Float(1.5)).is_err());
     }
+
+    #[test]
+    fn sync_status_lists_concurrent_register_writes() {
+        let text = |s: &str| RuntimeValue::Text(Rc::new(s.to_string()));
+        let mut a = CrdtValue::new_register(10);
+        let mut b = CrdtValue::new_register(20);
+        a.resolve(&text("Draft")).unwrap();
+        b.resolve(&text("Final")).unwrap();
+        a.merge(&b).unwrap();
+
+        let status = sync_status(&RuntimeValue::Crdt(Rc::new(RefCell::new(a.clone()))), &HashMap::new()).unwrap();
+        assert_eq!(
+            status.to_display_string(),
+            "SyncStatus { conflicts: [(Draft, {1: 1}), (Final, {2: 1})], peers: [2], pending: true, replica: 1, version: {1: 1, 2: 1} }"
+        );
+
+        // Once this version has been published, nothing is pending
+        let synced = HashMap::from([(10, a.version())]);
+        let status = sync_status(&RuntimeValue::Crdt(Rc::new(RefCell::new(a))), &synced).unwrap();
+        assert!(status.to_display_string().contains("pending: false"));
+    }
 }
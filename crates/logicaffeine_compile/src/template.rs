@@ -0,0 +1,415 @@
+//! Declaration templates.
+//!
+//! A `## For each of …:` header stamps out the block below it once per item,
+//! with the body's placeholder — a capitalized name in braces — replaced by
+//! the item:
+//!
+//! ```text
+//! ## For each of Dog, Cat and Bird:
+//! ## A {Animal} has:
+//!     A name: Text.
+//!     A legs: Int.
+//! ```
+//!
+//! The body runs from the line after the header to the next `##` header. It
+//! may open with its own block header, as above, which is stamped out with
+//! it; a short body can also follow the colon on the header line itself.
+//! Templates are expanded on the text, before anything lexes it, so discovery
+//! and the parser only ever see the copies.
+//!
+//! # Hygiene
+//!
+//! - Only the template's placeholder is substituted. Lowercase `{name}`
+//!   string interpolation in the body is left alone, and a body may use one
+//!   placeholder only.
+//! - A function or type the body defines under a fixed name would be
+//!   defined once per copy. Each copy appends its item to such names, and to
+//!   every use of them within the copy: `## To describe` becomes
+//!   `describeDog`, `describeCat`, …
+//!
+//! # Traces
+//!
+//! An [`Expansion`] remembers where each copy came from:
+//! [`Expansion::trace`] finds the copy an offset of the expanded text falls
+//! in, and [`Expansion::original_span`] maps a span back onto the source, so
+//! a diagnostic in the third copy points at the template and can say it was
+//! the `Bird` copy ([`ExpansionTrace::describe`]).
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::loader::{defined_names, is_ident_byte};
+use crate::token::Span;
+
+/// A source with its templates expanded.
+#[derive(Debug, Clone)]
+pub struct Expansion<'a> {
+    /// The expanded text; borrowed when the source has no templates.
+    pub source: Cow<'a, str>,
+    /// One entry per copy, in the order they appear in [`Expansion::source`].
+    pub traces: Vec<ExpansionTrace>,
+    segments: Vec<Segment>,
+}
+
+/// Where one copy of a template came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpansionTrace {
+    /// The `## For each of …` header in the original source.
+    pub header: Span,
+    /// The template body in the original source.
+    pub body: Span,
+    /// The item this copy was made for.
+    pub item: String,
+    /// This copy in the expanded text.
+    pub expanded: Span,
+}
+
+impl ExpansionTrace {
+    /// A note for a diagnostic inside this copy: which template, on which
+    /// line of `source`, and which item.
+    pub fn describe(&self, source: &str) -> String {
+        let line = source[..self.header.start.min(source.len())].matches('\n').count() + 1;
+        let header = source.get(self.header.start..self.header.end).unwrap_or("## For each of").trim();
+        format!("in the `{}` copy of the template `{header}` (line {line})", self.item)
+    }
+}
+
+/// A run of the expanded text: copied verbatim from the source, or one copy
+/// of a template body.
+#[derive(Debug, Clone)]
+struct Segment {
+    expanded: Span,
+    original: Span,
+    verbatim: bool,
+}
+
+/// A template that cannot be expanded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateError {
+    pub message: String,
+    /// The template's header line.
+    pub span: Span,
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+impl Expansion<'_> {
+    /// The copy `offset` (into the expanded text) falls in, if any.
+    pub fn trace(&self, offset: usize) -> Option<&ExpansionTrace> {
+        self.traces.iter().find(|trace| trace.expanded.start <= offset && offset < trace.expanded.end)
+    }
+
+    /// `span` (into the expanded text) as a span into the original source.
+    /// Inside a copy it lands on the matching part of the template body —
+    /// approximately, since substituted items differ in length from the
+    /// placeholder.
+    pub fn original_span(&self, span: Span) -> Span {
+        Span::new(self.original_offset(span.start), self.original_offset(span.end).max(self.original_offset(span.start)))
+    }
+
+    fn original_offset(&self, offset: usize) -> usize {
+        let Some(segment) = self
+            .segments
+            .iter()
+            .find(|segment| offset < segment.expanded.end)
+            .or(self.segments.last())
+        else {
+            return offset;
+        };
+        let mapped = segment.original.start + offset.saturating_sub(segment.expanded.start);
+        if segment.verbatim {
+            mapped
+        } else {
+            mapped.min(segment.original.end)
+        }
+    }
+}
+
+/// The header's item list and any body after its colon, if `line` is a
+/// `## For each of …` header.
+fn template_header(line: &str) -> Option<(&str, &str)> {
+    const PREFIX: &str = "for each of ";
+    let rest = line.strip_prefix("## ")?;
+    if !rest.get(..PREFIX.len())?.eq_ignore_ascii_case(PREFIX) {
+        return None;
+    }
+    let rest = rest[PREFIX.len()..].trim_end_matches(['\r', '\n']);
+    Some(rest.split_once(':').unwrap_or((rest, "")))
+}
+
+fn parse_items(list: &str, header: &str, span: Span) -> Result<Vec<String>, TemplateError> {
+    let mut items = Vec::new();
+    for part in list.split(',').flat_map(|part| part.split(" and ")) {
+        let item = part.trim();
+        let item = item.strip_prefix("and ").unwrap_or(item).trim().trim_end_matches('.');
+        if item.is_empty() {
+            continue;
+        }
+        let is_name = item.starts_with(|c: char| c.is_alphabetic()) && item.bytes().all(is_ident_byte);
+        if !is_name {
+            return Err(TemplateError {
+                message: format!("template `{header}` lists `{item}`, but each item must be a single name"),
+                span,
+            });
+        }
+        items.push(item.to_string());
+    }
+    if items.is_empty() {
+        return Err(TemplateError { message: format!("template `{header}` lists no items"), span });
+    }
+    Ok(items)
+}
+
+/// The distinct capitalized `{Name}` placeholders in `body`, in order of
+/// first use.
+fn placeholders(body: &str) -> Vec<&str> {
+    let mut found = Vec::new();
+    let mut rest = body;
+    while let Some(open) = rest.find('{') {
+        rest = &rest[open + 1..];
+        let Some(close) = rest.find('}') else { break };
+        let name = &rest[..close];
+        if name.starts_with(|c: char| c.is_ascii_uppercase()) && name.bytes().all(is_ident_byte) && !found.contains(&name) {
+            found.push(name);
+        }
+    }
+    found
+}
+
+/// `text` with every whole-word `word` replaced by `with`.
+fn replace_word(text: &str, word: &str, with: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut from = 0;
+    while let Some(at) = text[from..].find(word) {
+        let start = from + at;
+        let end = start + word.len();
+        from = end;
+        if (start > 0 && is_ident_byte(bytes[start - 1])) || (end < bytes.len() && is_ident_byte(bytes[end])) {
+            continue;
+        }
+        out.push_str(&text[copied..start]);
+        out.push_str(with);
+        copied = end;
+    }
+    out.push_str(&text[copied..]);
+    out
+}
+
+/// Expand every `## For each of …` template in `source`.
+///
+/// # Errors
+///
+/// A template whose items are not single names, whose body is empty, or
+/// whose body uses no placeholder or more than one.
+///
+/// ```
+/// use logicaffeine_compile::template::expand_templates;
+///
+/// let source = "## For each of Dog and Cat:\n## A {Animal} has:\n    A name: Text.\n";
+/// let expansion = expand_templates(source).unwrap();
+/// assert!(expansion.source.contains("## A Dog has:") && expansion.source.contains("## A Cat has:"));
+/// assert_eq!(expansion.traces[1].item, "Cat");
+/// ```
+pub fn expand_templates(source: &str) -> Result<Expansion<'_>, TemplateError> {
+    if !source.lines().any(|line| template_header(line).is_some()) {
+        return Ok(Expansion { source: Cow::Borrowed(source), traces: Vec::new(), segments: Vec::new() });
+    }
+
+    let mut lines = Vec::new();
+    let mut offset = 0;
+    for line in source.split_inclusive('\n') {
+        lines.push((offset, line));
+        offset += line.len();
+    }
+
+    let mut out = String::with_capacity(source.len() * 2);
+    let mut traces = Vec::new();
+    let mut segments = Vec::new();
+    let mut verbatim_from = 0;
+    let mut i = 0;
+    while i < lines.len() {
+        let (start, line) = lines[i];
+        let Some((list, inline)) = template_header(line) else {
+            out.push_str(line);
+            i += 1;
+            continue;
+        };
+        if verbatim_from < start {
+            let expanded_start = out.len() - (start - verbatim_from);
+            segments.push(Segment {
+                expanded: Span::new(expanded_start, out.len()),
+                original: Span::new(verbatim_from, start),
+                verbatim: true,
+            });
+        }
+
+        let header_text = line.trim_end();
+        let header = Span::new(start, start + header_text.len());
+        let items = parse_items(list, header_text, header)?;
+
+        // The body: the rest of the header line, or the next block header,
+        // then every line up to the next `##`.
+        let mut body = String::new();
+        let body_start = if inline.trim().is_empty() {
+            lines.get(i + 1).map_or(source.len(), |(offset, _)| *offset)
+        } else {
+            body.push_str(inline.trim());
+            body.push('\n');
+            let colon = line.find(':').map_or(0, |colon| colon + 1);
+            start + colon + (inline.len() - inline.trim_start().len())
+        };
+        let mut j = i + 1;
+        if inline.trim().is_empty() {
+            if let Some((_, next)) = lines.get(j) {
+                if next.starts_with("##") && template_header(next).is_none() {
+                    body.push_str(next);
+                    j += 1;
+                }
+            }
+        }
+        while let Some((_, next)) = lines.get(j) {
+            if next.starts_with("##") {
+                break;
+            }
+            body.push_str(next);
+            j += 1;
+        }
+        let body_end = lines.get(j).map_or(source.len(), |(offset, _)| *offset);
+        if !body.ends_with('\n') {
+            body.push('\n');
+        }
+        if body.trim().is_empty() {
+            return Err(TemplateError { message: format!("template `{header_text}` has no body"), span: header });
+        }
+
+        let placeholder = match placeholders(&body)[..] {
+            [name] => name.to_string(),
+            [] => {
+                return Err(TemplateError {
+                    message: format!("template `{header_text}` never uses a placeholder such as `{{Item}}`"),
+                    span: header,
+                })
+            }
+            [first, second, ..] => {
+                return Err(TemplateError {
+                    message: format!(
+                        "template `{header_text}` uses both `{{{first}}}` and `{{{second}}}`; a template has one placeholder"
+                    ),
+                    span: header,
+                })
+            }
+        };
+
+        let fixed_names = defined_names(&body);
+        for item in items {
+            let mut copy = body.clone();
+            for name in &fixed_names {
+                copy = replace_word(&copy, name, &format!("{name}{item}"));
+            }
+            let copy = copy.replace(&format!("{{{placeholder}}}"), &item);
+            let expanded = Span::new(out.len(), out.len() + copy.len());
+            out.push_str(&copy);
+            segments.push(Segment { expanded, original: Span::new(body_start, body_end), verbatim: false });
+            traces.push(ExpansionTrace { header, body: Span::new(body_start, body_end), item, expanded });
+        }
+        verbatim_from = body_end;
+        i = j;
+    }
+    if verbatim_from < source.len() {
+        let expanded_start = out.len() - (source.len() - verbatim_from);
+        segments.push(Segment {
+            expanded: Span::new(expanded_start, out.len()),
+            original: Span::new(verbatim_from, source.len()),
+            verbatim: true,
+        });
+    }
+
+    Ok(Expansion { source: Cow::Owned(out), traces, segments })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANIMALS: &str = "## Note\nPets.\n## For each of Dog, Cat and Bird:\n## A {Animal} has:\n    A name: Text.\n\n## Main\nShow \"{count}\".\n";
+
+    #[test]
+    fn templates_stamp_out_one_copy_per_item() {
+        let expansion = expand_templates(ANIMALS).unwrap();
+        let source = &expansion.source;
+        for animal in ["Dog", "Cat", "Bird"] {
+            assert!(source.contains(&format!("## A {animal} has:\n    A name: Text.\n")), "{source}");
+        }
+        assert!(!source.contains("For each"), "{source}");
+        assert!(source.ends_with("## Main\nShow \"{count}\".\n"), "lowercase braces are untouched: {source}");
+
+        let items: Vec<&str> = expansion.traces.iter().map(|trace| trace.item.as_str()).collect();
+        assert_eq!(items, ["Dog", "Cat", "Bird"]);
+        let bird = &expansion.traces[2];
+        assert_eq!(&source[bird.expanded.start..bird.expanded.end], "## A Bird has:\n    A name: Text.\n\n");
+        assert_eq!(&ANIMALS[bird.header.start..bird.header.end], "## For each of Dog, Cat and Bird:");
+        assert_eq!(bird.describe(ANIMALS), "in the `Bird` copy of the template `## For each of Dog, Cat and Bird:` (line 3)");
+    }
+
+    #[test]
+    fn spans_map_back_through_the_expansion() {
+        let expansion = expand_templates(ANIMALS).unwrap();
+        let source = &expansion.source;
+
+        let show = source.find("Show").unwrap();
+        let original = expansion.original_span(Span::new(show, show + 4));
+        assert_eq!(&ANIMALS[original.start..original.end], "Show");
+        assert!(expansion.trace(show).is_none());
+
+        let cat = source.find("## A Cat").unwrap();
+        assert_eq!(expansion.trace(cat + 5).unwrap().item, "Cat");
+        let in_body = expansion.original_span(Span::new(cat, cat + 2));
+        assert_eq!(&ANIMALS[in_body.start..in_body.end], "##");
+        assert_eq!(in_body.start, ANIMALS.find("## A {Animal}").unwrap());
+    }
+
+    #[test]
+    fn fixed_names_are_renamed_per_copy() {
+        let source = "## For each of Dog, Cat:\n## To describe (a: {Animal}) -> Text:\n    Return describe(a).\n";
+        let expansion = expand_templates(source).unwrap();
+        assert!(expansion.source.contains("## To describeDog (a: Dog) -> Text:\n    Return describeDog(a).\n"), "{}", expansion.source);
+        assert!(expansion.source.contains("## To describeCat (a: Cat)"), "{}", expansion.source);
+    }
+
+    #[test]
+    fn an_inline_body_follows_the_colon() {
+        let expansion = expand_templates("## Definition\n## For each of Red, Green: A {Colour} is a colour.\n").unwrap();
+        assert_eq!(expansion.source, "## Definition\nA Red is a colour.\nA Green is a colour.\n");
+    }
+
+    #[test]
+    fn malformed_templates_are_errors() {
+        let no_placeholder = expand_templates("## For each of A, B:\nNothing here.\n").unwrap_err();
+        assert!(no_placeholder.message.contains("never uses a placeholder"), "{no_placeholder}");
+        assert_eq!(no_placeholder.span, Span::new(0, 20));
+
+        let two = expand_templates("## For each of A:\n{X} and {Y}.\n").unwrap_err();
+        assert!(two.message.contains("one placeholder"), "{two}");
+
+        let multiword = expand_templates("## For each of Big Dog:\n{X}.\n").unwrap_err();
+        assert!(multiword.message.contains("`Big Dog`"), "{multiword}");
+
+        assert!(expand_templates("## For each of A:\n\n## Main\n").unwrap_err().message.contains("no body"));
+    }
+
+    #[test]
+    fn sources_without_templates_are_borrowed() {
+        let source = "## Main\nShow 1.\n";
+        let expansion = expand_templates(source).unwrap();
+        assert!(matches!(expansion.source, Cow::Borrowed(_)));
+        assert_eq!(expansion.original_span(Span::new(8, 12)), Span::new(8, 12));
+    }
+}
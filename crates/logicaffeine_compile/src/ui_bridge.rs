@@ -29,6 +29,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::rc::Rc;
 
 use logicaffeine_base::{Arena, Interner};
 use logicaffeine_language::{
@@ -51,13 +52,14 @@ use logicaffeine_proof::{DerivationTree, ProofExpr, ProofTerm};
 
 // Re-export interpreter result from our interpreter module
 pub use crate::interpreter::InterpreterResult;
+use crate::limits::{Budget, ExecutionLimits, LimitExceeded};
 
 // ═══════════════════════════════════════════════════════════════════
 // Token Visualization
 // ═══════════════════════════════════════════════════════════════════
 
 /// Syntactic category of a token for UI highlighting.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum TokenCategory {
     /// Universal/existential quantifiers: every, some, no, most
     Quantifier,
@@ -85,6 +87,24 @@ pub enum TokenCategory {
     Other,
 }
 
+impl TokenCategory {
+    /// Every category, in declaration order.
+    pub const ALL: [TokenCategory; 12] = [
+        TokenCategory::Quantifier,
+        TokenCategory::Noun,
+        TokenCategory::Verb,
+        TokenCategory::Adjective,
+        TokenCategory::Connective,
+        TokenCategory::Determiner,
+        TokenCategory::Preposition,
+        TokenCategory::Pronoun,
+        TokenCategory::Modal,
+        TokenCategory::Punctuation,
+        TokenCategory::Proper,
+        TokenCategory::Other,
+    ];
+}
+
 /// Token information for UI display with position and category.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenInfo {
@@ -342,20 +362,20 @@ pub fn compile_for_ui(input: &str) -> CompileResult {
 
     // Generate Simple readings (modals stripped) - deduplicated
     let simple_readings: Vec<String> = {
-        let raw = compile_forest_with_options(input, CompileOptions { format: OutputFormat::SimpleFOL, pragmatic: false });
+        let raw = compile_forest_with_options(input, CompileOptions { format: OutputFormat::SimpleFOL, pragmatic: false, ..Default::default() });
         let mut seen = HashSet::new();
         raw.into_iter().filter(|r| seen.insert(r.clone())).collect()
     };
 
     // Generate Kripke readings with explicit world quantification
-    let kripke_readings = compile_forest_with_options(input, CompileOptions { format: OutputFormat::Kripke, pragmatic: false });
+    let kripke_readings = compile_forest_with_options(input, CompileOptions { format: OutputFormat::Kripke, pragmatic: false, ..Default::default() });
 
     let mut interner = Interner::new();
     let mut lexer = Lexer::new(input, &mut interner);
     let lex_tokens = lexer.tokenize();
 
-    let mwe_trie = mwe::build_mwe_trie();
-    let lex_tokens = mwe::apply_mwe_pipeline(lex_tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let lex_tokens = mwe::apply_mwe_pipeline(lex_tokens, mwe_trie, &mut interner);
 
     // Pass 1: Discovery
     let type_registry = {
@@ -451,8 +471,8 @@ pub fn compile_for_proof(input: &str) -> ProofCompileResult {
     let mut lexer = Lexer::new(input, &mut interner);
     let lex_tokens = lexer.tokenize();
 
-    let mwe_trie = mwe::build_mwe_trie();
-    let lex_tokens = mwe::apply_mwe_pipeline(lex_tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let lex_tokens = mwe::apply_mwe_pipeline(lex_tokens, mwe_trie, &mut interner);
 
     let type_registry = {
         let mut discovery = DiscoveryPass::new(&lex_tokens, &mut interner);
@@ -585,8 +605,8 @@ fn parse_theorem(input: &str) -> Result<ParsedTheorem, String> {
     let mut lexer = Lexer::new(input, &mut interner);
     let tokens = lexer.tokenize();
 
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
 
     let type_registry = {
         let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
@@ -688,8 +708,8 @@ pub fn compile_theory_for_ui(input: &str) -> TheoryCompileResult {
     let mut interner = Interner::new();
     let mut lexer = Lexer::new(input, &mut interner);
     let tokens = lexer.tokenize();
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
     let type_registry = {
         let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
         discovery.run()
@@ -990,14 +1010,96 @@ pub fn generate_rust_code(source: &str) -> Result<String, ParseError> {
 /// [`generate_rust_code`] with an already-extracted proven module bundled in.
 #[cfg(feature = "codegen")]
 pub fn generate_rust_code_with_proven(source: &str, proven: Option<&str>) -> Result<String, ParseError> {
+    generate_rust_code_inner(source, proven, false).map(|(rust_code, _)| rust_code)
+}
+
+/// One line of generated Rust and the LOGOS lines it was compiled from.
+///
+/// Lines are 1-based. A top-level statement owns every Rust line it emitted,
+/// so a function's whole body maps to its definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RustLineOrigin {
+    pub rust_line: u32,
+    pub logos_first: u32,
+    pub logos_last: u32,
+}
+
+/// Generated Rust alongside its line-level map back to the LOGOS source —
+/// what the Studio's side-by-side Rust preview renders.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RustPreview {
+    pub rust: String,
+    /// Sorted by `rust_line`; Rust lines without a LOGOS origin (the prelude,
+    /// `fn main` braces) are absent.
+    pub origins: Vec<RustLineOrigin>,
+}
+
+impl RustPreview {
+    /// The Rust lines compiled from LOGOS line `logos_line`.
+    pub fn rust_lines_for(&self, logos_line: u32) -> Vec<u32> {
+        self.origins
+            .iter()
+            .filter(|o| (o.logos_first..=o.logos_last).contains(&logos_line))
+            .map(|o| o.rust_line)
+            .collect()
+    }
+
+    /// The LOGOS line range Rust line `rust_line` was compiled from.
+    pub fn logos_lines_for(&self, rust_line: u32) -> Option<(u32, u32)> {
+        self.origins
+            .binary_search_by_key(&rust_line, |o| o.rust_line)
+            .ok()
+            .map(|i| (self.origins[i].logos_first, self.origins[i].logos_last))
+    }
+}
+
+/// [`generate_rust_code`] plus the map from each Rust line back to the LOGOS
+/// sentence that produced it. The Rust is byte-identical to
+/// [`generate_rust_code`]'s; line numbers refer to `source` as given, before
+/// the implicit `## Main` wrapping or math partitioning.
+#[cfg(feature = "codegen")]
+pub fn generate_rust_preview(source: &str) -> Result<RustPreview, ParseError> {
+    let (imperative_src, math_src) = partition_mixed(source);
+    let proven = math_src.as_deref().and_then(mixed_proven_module);
+    let (rust, map) = generate_rust_code_inner(&imperative_src, proven.as_deref(), true)?;
+
+    // `partition_mixed` blanks math lines in place, so line numbers survive;
+    // only the implicit `## Main` header shifts them.
+    let shift = u32::from(implicit_main(source).is_some());
+    let line_of = |offset: usize| {
+        let offset = offset.min(imperative_src.len());
+        imperative_src.as_bytes()[..offset].iter().filter(|&&b| b == b'\n').count() as u32 + 1
+    };
+    let origins = map
+        .map(|map| map.line_span_entries())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(rust_line, span)| {
+            // Block statements' spans run into the blank line after them.
+            let text = imperative_src.get(span.start..span.end.min(imperative_src.len()))?;
+            let end = span.start + text.trim_end().len().max(1);
+            let first = line_of(span.start).checked_sub(shift)?;
+            let last = line_of(end - 1).checked_sub(shift)?;
+            (first >= 1).then_some(RustLineOrigin { rust_line, logos_first: first, logos_last: last })
+        })
+        .collect();
+    Ok(RustPreview { rust, origins })
+}
+
+#[cfg(feature = "codegen")]
+fn generate_rust_code_inner(
+    source: &str,
+    proven: Option<&str>,
+    mapped: bool,
+) -> Result<(String, Option<crate::sourcemap::SourceMap>), ParseError> {
     use logicaffeine_language::ast::stmt::{Stmt, Expr, TypeExpr};
 
     let mut interner = Interner::new();
     let mut lexer = Lexer::new(source, &mut interner);
     let tokens = lexer.tokenize();
 
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
 
     let (type_registry, policy_registry) = {
         let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
@@ -1032,16 +1134,46 @@ pub fn generate_rust_code_with_proven(source: &str, proven: Option<&str>) -> Res
 
     let mut parser = Parser::new(tokens, &mut world_state, &mut interner, ast_ctx, type_registry);
     let stmts = parser.parse_program()?;
+    let stmt_spans = parser.stmt_spans().to_vec();
 
     let type_env = crate::analysis::types::TypeEnv::infer_program(&stmts, &interner, &codegen_registry);
-    let rust_code = crate::codegen::codegen_program_with_proven(&stmts, &codegen_registry, &codegen_policies, &interner, &type_env, &crate::optimization::OptimizationConfig::from_env(), "proven", proven);
-    Ok(rust_code)
+    let cfg = crate::optimization::OptimizationConfig::from_env();
+    if mapped {
+        let (rust_code, map) = crate::codegen::codegen_program_mapped_with_proven(&stmts, &codegen_registry, &codegen_policies, &interner, &type_env, &cfg, "proven", proven, &stmt_spans, source);
+        return Ok((rust_code, Some(map)));
+    }
+    let rust_code = crate::codegen::codegen_program_with_proven(&stmts, &codegen_registry, &codegen_policies, &interner, &type_env, &cfg, "proven", proven);
+    Ok((rust_code, None))
 }
 
 // ═══════════════════════════════════════════════════════════════════
 // Interpreter (async)
 // ═══════════════════════════════════════════════════════════════════
 
+thread_local! {
+    /// The limits every `interpret_*` run on this thread honors.
+    static EXECUTION_LIMITS: std::cell::Cell<ExecutionLimits> =
+        const { std::cell::Cell::new(ExecutionLimits::UNLIMITED) };
+}
+
+/// Bound every later `interpret_*` run on this thread (see [`crate::limits`]).
+/// A run that crosses a limit stops with its output so far, the limit's
+/// message as `error`, and `limit_exceeded` set. Runs are unlimited until this
+/// is called; pass [`ExecutionLimits::UNLIMITED`] to lift the bounds again.
+pub fn set_execution_limits(limits: ExecutionLimits) {
+    EXECUTION_LIMITS.with(|l| l.set(limits));
+}
+
+/// The limits set by [`set_execution_limits`] on this thread.
+pub fn execution_limits() -> ExecutionLimits {
+    EXECUTION_LIMITS.with(std::cell::Cell::get)
+}
+
+/// A fresh budget for one run under this thread's limits.
+fn start_budget() -> Option<Rc<Budget>> {
+    Budget::start(execution_limits())
+}
+
 /// Interpret LOGOS imperative code and return output lines.
 ///
 /// Same engine dispatch as [`interpret_for_ui_sync`]: the bytecode VM runs
@@ -1056,7 +1188,7 @@ pub fn generate_rust_code_with_proven(source: &str, proven: Option<&str>) -> Res
 fn send_escape_rejection(stmts: &[logicaffeine_language::ast::stmt::Stmt]) -> Option<InterpreterResult> {
     crate::concurrency::check_send_escape(stmts)
         .first()
-        .map(|d| InterpreterResult { lines: Vec::new(), error: Some(d.message.clone()) })
+        .map(|d| InterpreterResult { lines: Vec::new(), error: Some(d.message.clone()), memory: None, limit_exceeded: None })
 }
 
 /// Reject a dimension-incoherent program (`2 meters + 1 gram`) BEFORE it runs, so the interpreter
@@ -1069,7 +1201,7 @@ fn send_dimension_rejection(
     crate::analysis::dimension_check::DimensionChecker::new(interner)
         .check_program(stmts)
         .err()
-        .map(|e| InterpreterResult { lines: Vec::new(), error: Some(e.message) })
+        .map(|e| InterpreterResult { lines: Vec::new(), error: Some(e.message), memory: None, limit_exceeded: None })
 }
 
 pub async fn interpret_for_ui(input: &str) -> InterpreterResult {
@@ -1100,8 +1232,8 @@ pub async fn interpret_for_ui_with_args(
     let mut lexer = Lexer::new(input, &mut interner);
     let tokens = lexer.tokenize();
 
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
 
     let (type_registry, policy_registry) = {
         let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
@@ -1159,26 +1291,22 @@ pub async fn interpret_for_ui_with_args(
                 )
                 .await;
             }
+            let budget = start_budget();
             let mut interp = crate::interpreter::Interpreter::new(&interner)
                 .with_type_registry(&type_registry_for_interp)
                 .with_policies(policy_registry)
-                .with_program_args(program_args.to_vec());
-            match interp.run(&stmts).await {
-                Ok(()) => InterpreterResult {
-                    lines: interp.output,
-                    error: None,
-                },
-                Err(e) => InterpreterResult {
-                    lines: interp.output,
-                    error: Some(e),
-                },
-            }
+                .with_program_args(program_args.to_vec())
+                .with_budget(budget.clone());
+            let outcome = interp.run(&stmts).await;
+            treewalker_result(interp.output, outcome.err(), budget.as_deref())
         }
         Err(e) => {
             let advice = socratic_explanation(&e, &interner);
             InterpreterResult {
                 lines: vec![],
                 error: Some(advice),
+                memory: None,
+                limit_exceeded: None,
             }
         }
     }
@@ -1222,8 +1350,8 @@ pub fn with_parsed_program<R>(
     let mut lexer = Lexer::new(input, &mut interner);
     let tokens = lexer.tokenize();
 
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
 
     let (type_registry, policy_registry) = {
         let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
@@ -1362,8 +1490,8 @@ pub fn with_optimized_program_tiered<R>(
     let mut lexer = Lexer::new(input, &mut interner);
     let tokens = lexer.tokenize();
 
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
 
     let (type_registry, policy_registry) = {
         let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
@@ -1490,8 +1618,8 @@ pub fn with_v2_optimized_program<R>(
     let mut lexer = Lexer::new(input, &mut interner);
     let tokens = lexer.tokenize();
 
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
 
     let (type_registry, policy_registry) = {
         let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
@@ -1640,32 +1768,37 @@ pub fn interpret_for_ui_sync_with_args(input: &str, program_args: &[String]) ->
             ) {
                 Ok(program) => {
                     trace("vm+jit");
+                    let budget = start_budget();
                     let mut vm = crate::vm::Vm::new(&program)
                         .with_policy_ctx(&policies, interner)
                         .with_program_args(program_args.to_vec());
-                    if let Some(tier) = crate::vm::installed_native_tier() {
-                        vm = vm.with_native_tier(tier);
+                    // Native code can't be interrupted, so a limited run stays on bytecode
+                    if budget.is_none() {
+                        if let Some(tier) = crate::vm::installed_native_tier() {
+                            vm = vm.with_native_tier(tier);
+                        }
+                        // Compiled-native tier (HOTSWAP §Axis-3): install any AOT functions
+                        // the run was given, so they dispatch to rustc -O3 machine code.
+                        #[cfg(not(target_arch = "wasm32"))]
+                        install_pending_aot_natives(&mut vm, &program, interner);
                     }
-                    // Compiled-native tier (HOTSWAP §Axis-3): install any AOT functions
-                    // the run was given, so they dispatch to rustc -O3 machine code.
-                    #[cfg(not(target_arch = "wasm32"))]
-                    install_pending_aot_natives(&mut vm, &program, interner);
-                    let error = vm.run().err();
-                    let result = InterpreterResult { lines: vm.into_lines(), error };
+                    let (error, limit_exceeded) = run_vm_budgeted(&mut vm, budget.as_deref());
+                    let result = InterpreterResult { lines: vm.into_lines(), error, memory: None, limit_exceeded };
 
                     // Debug-build shadow oracle: the SAME program runs on the
                     // tree-walker and the full outcome must match — this turns
                     // the entire existing test corpus into a differential
                     // suite. (Skipped on wasm to keep dev builds light.)
                     #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
-                    {
-                        let shadow = run_treewalker(
+                    if result.limit_exceeded.is_none() {
+                        let shadow = run_treewalker_budgeted(
                             stmts,
                             type_registry,
                             policies.clone(),
                             interner,
                             false,
                             program_args,
+                            None,
                         );
                         assert_eq!(
                             (&result.lines, &result.error),
@@ -1683,7 +1816,7 @@ pub fn interpret_for_ui_sync_with_args(input: &str, program_args: &[String]) ->
                 }
             }
         }
-        Err(advice) => InterpreterResult { lines: vec![], error: Some(advice) },
+        Err(advice) => InterpreterResult { lines: vec![], error: Some(advice), memory: None, limit_exceeded: None },
     })
 }
 
@@ -1749,32 +1882,37 @@ pub fn interpret_for_ui_baseline_sync_with_args(
             match crate::vm::Compiler::compile_with_types(stmts, interner, Some(type_registry)) {
                 Ok(program) => {
                     trace("vm (baseline)");
+                    let budget = start_budget();
                     let mut vm = crate::vm::Vm::new(&program)
                         .with_policy_ctx(&policies, interner)
                         .with_program_args(program_args.to_vec());
-                    if let Some(tier) = crate::vm::installed_native_tier() {
-                        vm = vm.with_native_tier(tier);
+                    // Native code can't be interrupted, so a limited run stays on bytecode
+                    if budget.is_none() {
+                        if let Some(tier) = crate::vm::installed_native_tier() {
+                            vm = vm.with_native_tier(tier);
+                        }
+                        // Compiled-native tier (HOTSWAP §Axis-3): install any AOT functions
+                        // the run was given, so they dispatch to rustc -O3 machine code.
+                        #[cfg(not(target_arch = "wasm32"))]
+                        install_pending_aot_natives(&mut vm, &program, interner);
                     }
-                    // Compiled-native tier (HOTSWAP §Axis-3): install any AOT functions
-                    // the run was given, so they dispatch to rustc -O3 machine code.
-                    #[cfg(not(target_arch = "wasm32"))]
-                    install_pending_aot_natives(&mut vm, &program, interner);
-                    let error = vm.run().err();
-                    let result = InterpreterResult { lines: vm.into_lines(), error };
+                    let (error, limit_exceeded) = run_vm_budgeted(&mut vm, budget.as_deref());
+                    let result = InterpreterResult { lines: vm.into_lines(), error, memory: None, limit_exceeded };
 
                     // The same debug differential net as the optimized path: the
                     // baseline VM and the tree-walker must agree on the SAME
                     // (unoptimized) statements. (Skipped on wasm to keep dev
                     // builds light.)
                     #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
-                    {
-                        let shadow = run_treewalker(
+                    if result.limit_exceeded.is_none() {
+                        let shadow = run_treewalker_budgeted(
                             stmts,
                             type_registry,
                             policies.clone(),
                             interner,
                             false,
                             program_args,
+                            None,
                         );
                         assert_eq!(
                             (&result.lines, &result.error),
@@ -1792,10 +1930,143 @@ pub fn interpret_for_ui_baseline_sync_with_args(
                 }
             }
         }
-        Err(advice) => InterpreterResult { lines: vec![], error: Some(advice) },
+        Err(advice) => InterpreterResult { lines: vec![], error: Some(advice), memory: None, limit_exceeded: None },
     })
 }
 
+/// Interpret on the tree-walker with per-statement memory reporting: the
+/// result's `memory` records how many `Seq`/`Map`/`Text` values each top-level
+/// statement created and how large they grew (see [`crate::memory_report`]).
+/// Runs the parsed program UNoptimized, so the statements reported are the ones
+/// written. Async and concurrent programs run normally, without a report.
+pub fn interpret_with_memory_report(input: &str) -> InterpreterResult {
+    with_parsed_program(input, |parsed, interner| match parsed {
+        Ok((stmts, type_registry, policies)) => {
+            if let Some(rejection) = send_escape_rejection(stmts) {
+                return rejection;
+            }
+            if let Some(rejection) = send_dimension_rejection(stmts, interner) {
+                return rejection;
+            }
+            if crate::interpreter::needs_async(stmts) || crate::concurrency::uses_scheduler(stmts) {
+                return run_treewalker(stmts, type_registry, policies, interner, true, &[]);
+            }
+            let budget = start_budget();
+            let mut interp = crate::interpreter::Interpreter::new(interner)
+                .with_type_registry(type_registry)
+                .with_policies(policies)
+                .with_memory_report()
+                .with_budget(budget.clone());
+            let error = interp.run_sync(stmts).err();
+            let memory = interp.take_memory_report();
+            InterpreterResult { memory, ..treewalker_result(interp.output, error, budget.as_deref()) }
+        }
+        Err(advice) => InterpreterResult { lines: vec![], error: Some(advice), memory: None, limit_exceeded: None },
+    })
+}
+
+/// Interpret on the tree-walker counting how often every statement runs, and
+/// fold the counts onto the lines of `input` (see [`crate::coverage`]). Runs the
+/// parsed program UNoptimized — only the semantic division resolution is applied
+/// — so every count lands on a line as written. Async and concurrent programs
+/// run normally, with an empty report.
+pub fn interpret_with_coverage(input: &str) -> (InterpreterResult, crate::coverage::CoverageReport) {
+    use logicaffeine_language::ast::stmt::{Expr, Stmt, TypeExpr};
+
+    let implicit = implicit_main(input);
+    let with_main = implicit.as_deref().unwrap_or(input);
+    let prelude_src = crate::loader::apply_prelude(with_main);
+    let ran: &str = prelude_src.as_ref();
+    // The user's text is the tail of what runs; anything before it was added.
+    let prefix_len = if ran.ends_with(input) { ran.len() - input.len() } else { 0 };
+
+    let mut interner = Interner::new();
+    let mut lexer = Lexer::new(ran, &mut interner);
+    let tokens = lexer.tokenize();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe::shared_mwe_trie(), &mut interner);
+    let (type_registry, policy_registry) = {
+        let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
+        let result = discovery.run_full();
+        (result.types, result.policies)
+    };
+
+    let expr_arena = Arena::new();
+    let term_arena = Arena::new();
+    let np_arena = Arena::new();
+    let sym_arena = Arena::new();
+    let role_arena = Arena::new();
+    let pp_arena = Arena::new();
+    let stmt_arena: Arena<Stmt> = Arena::new();
+    let imperative_expr_arena: Arena<Expr> = Arena::new();
+    let type_expr_arena: Arena<TypeExpr> = Arena::new();
+    let ctx = AstContext::with_types(
+        &expr_arena,
+        &term_arena,
+        &np_arena,
+        &sym_arena,
+        &role_arena,
+        &pp_arena,
+        &stmt_arena,
+        &imperative_expr_arena,
+        &type_expr_arena,
+    );
+
+    let mut world_state = drs::WorldState::new();
+    let type_registry_for_interp = type_registry.clone();
+    let (parsed, mut spans) = {
+        let mut parser = Parser::new(tokens, &mut world_state, &mut interner, ctx, type_registry);
+        let parsed = parser.parse_program();
+        (parsed, parser.stmt_span_index().clone())
+    };
+    let stmts = match parsed {
+        Ok(stmts) => stmts,
+        Err(e) => {
+            let error = Some(socratic_explanation(&e, &interner));
+            let result = InterpreterResult { lines: vec![], error, memory: None, limit_exceeded: None };
+            return (result, crate::coverage::CoverageReport::default());
+        }
+    };
+    let run: &[Stmt] = match crate::resolve_division::resolve_divisions(
+        &stmts,
+        &stmt_arena,
+        &imperative_expr_arena,
+        &interner,
+        true,
+    ) {
+        Some(resolved) => {
+            crate::coverage::carry_spans(&stmts, resolved, &mut spans);
+            resolved
+        }
+        None => &stmts,
+    };
+    // A function's header is a declaration, not a line that runs.
+    for stmt in run.iter().filter(|s| matches!(s, Stmt::FunctionDef { .. })) {
+        spans.remove(&(stmt as *const Stmt as usize));
+    }
+
+    let empty = crate::coverage::CoverageReport::default;
+    if let Some(rejection) = send_escape_rejection(run) {
+        return (rejection, empty());
+    }
+    if let Some(rejection) = send_dimension_rejection(run, &interner) {
+        return (rejection, empty());
+    }
+    if crate::interpreter::needs_async(run) || crate::concurrency::uses_scheduler(run) {
+        let result = run_treewalker(run, &type_registry_for_interp, policy_registry, &interner, true, &[]);
+        return (result, empty());
+    }
+    let budget = start_budget();
+    let mut interp = crate::interpreter::Interpreter::new(&interner)
+        .with_type_registry(&type_registry_for_interp)
+        .with_policies(policy_registry)
+        .with_coverage()
+        .with_budget(budget.clone());
+    let error = interp.run_sync(run).err();
+    let counts = interp.take_coverage().unwrap_or_default();
+    let report = crate::coverage::report_lines(ran, prefix_len, &spans, &counts);
+    (treewalker_result(interp.output, error, budget.as_deref()), report)
+}
+
 /// Run a parsed program on the TREE-WALKER (the oracle engine). `force_async`
 /// selects the async executor; otherwise the sync path is used.
 /// Run a concurrent program on the deterministic scheduler: spawn the main block
@@ -1831,11 +2102,13 @@ fn run_program_concurrent<'a>(
         }));
     let err_sink: crate::concurrency::driver::ErrSink = Rc::new(RefCell::new(None));
 
+    let budget = start_budget();
     let mut main = crate::interpreter::Interpreter::new(interner)
         .with_type_registry(type_registry)
         .with_policies(policies)
         .with_program_args(program_args.to_vec())
-        .with_output_callback(callback);
+        .with_output_callback(callback)
+        .with_budget(budget.clone());
     if let Some(v) = vfs {
         main = main.with_vfs(v);
     }
@@ -1870,7 +2143,7 @@ fn run_program_concurrent<'a>(
         }
     }
     let lines = output_sink.borrow().clone();
-    InterpreterResult { lines, error }
+    treewalker_result(lines, error, budget.as_deref())
 }
 
 /// A snapshot sink for the Studio's Tasks/Channels strip — invoked between scheduler
@@ -1959,11 +2232,13 @@ async fn run_program_concurrent_streaming<'a>(
     }));
     let err_sink: crate::concurrency::driver::ErrSink = Rc::new(RefCell::new(None));
 
+    let budget = start_budget();
     let mut main = crate::interpreter::Interpreter::new(interner)
         .with_type_registry(type_registry)
         .with_policies(policies)
         .with_program_args(program_args.to_vec())
-        .with_output_callback(callback);
+        .with_output_callback(callback)
+        .with_budget(budget.clone());
     if let Some(v) = vfs {
         main = main.with_vfs(v);
     }
@@ -2005,7 +2280,7 @@ async fn run_program_concurrent_streaming<'a>(
         }
     }
     let lines = output_sink.borrow().clone();
-    InterpreterResult { lines, error }
+    treewalker_result(lines, error, budget.as_deref())
 }
 
 /// Parse `input` and run its concurrent program on the **tree-walker** scheduler
@@ -2016,7 +2291,7 @@ pub fn run_treewalker_concurrent_seeded(input: &str, seed: u64) -> InterpreterRe
         Ok((stmts, type_registry, policies)) => {
             run_program_concurrent(stmts, type_registry, policies, interner, &[], None, None, seed)
         }
-        Err(advice) => InterpreterResult { lines: vec![], error: Some(advice) },
+        Err(advice) => InterpreterResult { lines: vec![], error: Some(advice), memory: None, limit_exceeded: None },
     })
 }
 
@@ -2046,7 +2321,7 @@ pub fn run_vm_concurrent_seeded(input: &str, seed: u64) -> InterpreterResult {
                 Some(type_registry),
             ) {
                 Ok(p) => p,
-                Err(e) => return InterpreterResult { lines: vec![], error: Some(e) },
+                Err(e) => return InterpreterResult { lines: vec![], error: Some(e), memory: None, limit_exceeded: None },
             };
             let output: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
             let err_sink: crate::concurrency::driver::ErrSink = Rc::new(RefCell::new(None));
@@ -2072,9 +2347,9 @@ pub fn run_vm_concurrent_seeded(input: &str, seed: u64) -> InterpreterResult {
                 }
             }
             let lines = output.borrow().clone();
-            InterpreterResult { lines, error }
+            InterpreterResult { lines, error, memory: None, limit_exceeded: None }
         }
-        Err(advice) => InterpreterResult { lines: vec![], error: Some(advice) },
+        Err(advice) => InterpreterResult { lines: vec![], error: Some(advice), memory: None, limit_exceeded: None },
     })
 }
 
@@ -2257,8 +2532,8 @@ pub async fn run_vm_net_async(input: &str) -> InterpreterResult {
     let mut interner = Interner::new();
     let mut lexer = Lexer::new(input, &mut interner);
     let tokens = lexer.tokenize();
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
     let (type_registry, policy_registry) = {
         let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
         let result = discovery.run_full();
@@ -2289,12 +2564,12 @@ pub async fn run_vm_net_async(input: &str) -> InterpreterResult {
     let mut parser = Parser::new(tokens, &mut world_state, &mut interner, ctx, type_registry);
     let stmts = match parser.parse_program() {
         Ok(s) => s,
-        Err(e) => return InterpreterResult { lines: vec![], error: Some(format!("{e:?}")) },
+        Err(e) => return InterpreterResult { lines: vec![], error: Some(format!("{e:?}")), memory: None, limit_exceeded: None },
     };
     let program =
         match crate::vm::Compiler::compile_with_types(&stmts, &interner, Some(&type_registry_for_vm)) {
             Ok(p) => p,
-            Err(e) => return InterpreterResult { lines: vec![], error: Some(e) },
+            Err(e) => return InterpreterResult { lines: vec![], error: Some(e), memory: None, limit_exceeded: None },
         };
     let mut vm = crate::vm::Vm::new(&program).with_policy_ctx(&policy_registry, &interner);
     let mut netbox = crate::concurrency::net_inbox::NetInbox::new();
@@ -2344,7 +2619,7 @@ pub async fn run_vm_net_async(input: &str) -> InterpreterResult {
             }
         }
     }
-    InterpreterResult { lines, error }
+    InterpreterResult { lines, error, memory: None, limit_exceeded: None }
 }
 
 /// [`run_vm_concurrent_seeded`] under the **work-stealing M:N driver**: `workers`
@@ -2373,7 +2648,7 @@ pub fn run_vm_workstealing_seeded(input: &str, seed: u64, workers: usize) -> Int
                 Some(type_registry),
             ) {
                 Ok(p) => p,
-                Err(e) => return InterpreterResult { lines: vec![], error: Some(e) },
+                Err(e) => return InterpreterResult { lines: vec![], error: Some(e), memory: None, limit_exceeded: None },
             };
             // Build a worker-local task body from a `Send` descriptor. Workers run
             // the bytecode path: the native JIT tier is per-thread state (its code
@@ -2400,9 +2675,9 @@ pub fn run_vm_workstealing_seeded(input: &str, seed: u64, workers: usize) -> Int
                 }
                 _ => None,
             };
-            InterpreterResult { lines: result.output, error }
+            InterpreterResult { lines: result.output, error, memory: None, limit_exceeded: None }
         }
-        Err(advice) => InterpreterResult { lines: vec![], error: Some(advice) },
+        Err(advice) => InterpreterResult { lines: vec![], error: Some(advice), memory: None, limit_exceeded: None },
     })
 }
 
@@ -2434,6 +2709,22 @@ pub(crate) fn run_treewalker<'a>(
     interner: &'a Interner,
     force_async: bool,
     program_args: &[String],
+) -> InterpreterResult {
+    run_treewalker_budgeted(stmts, type_registry, policies, interner, force_async, program_args, start_budget())
+}
+
+/// [`run_treewalker`] under an explicit budget instead of this thread's
+/// execution limits — `None` for the debug shadow oracle, which must never be
+/// cut short where the VM was not.
+#[allow(clippy::too_many_arguments)]
+fn run_treewalker_budgeted<'a>(
+    stmts: &'a [logicaffeine_language::ast::stmt::Stmt<'a>],
+    type_registry: &logicaffeine_language::analysis::TypeRegistry,
+    policies: logicaffeine_language::analysis::PolicyRegistry,
+    interner: &'a Interner,
+    force_async: bool,
+    program_args: &[String],
+    budget: Option<Rc<Budget>>,
 ) -> InterpreterResult {
     if crate::concurrency::uses_scheduler(stmts) {
         return run_program_concurrent(
@@ -2443,15 +2734,29 @@ pub(crate) fn run_treewalker<'a>(
     let mut interp = crate::interpreter::Interpreter::new(interner)
         .with_type_registry(type_registry)
         .with_policies(policies)
-        .with_program_args(program_args.to_vec());
+        .with_program_args(program_args.to_vec())
+        .with_budget(budget.clone());
     let run_result = if force_async {
         futures::executor::block_on(interp.run(stmts))
     } else {
         interp.run_sync(stmts)
     };
-    match run_result {
-        Ok(()) => InterpreterResult { lines: interp.output, error: None },
-        Err(e) => InterpreterResult { lines: interp.output, error: Some(e) },
+    treewalker_result(interp.output, run_result.err(), budget.as_deref())
+}
+
+/// A finished tree-walker run's result. A crossed limit is reported even when
+/// it only dropped output at the very end, so the run still ended `Ok`.
+fn treewalker_result(lines: Vec<String>, error: Option<String>, budget: Option<&Budget>) -> InterpreterResult {
+    let limit_exceeded = budget.and_then(Budget::exceeded);
+    let error = error.or_else(|| limit_exceeded.map(|limit| limit.to_string()));
+    InterpreterResult { lines, error, memory: None, limit_exceeded }
+}
+
+/// Run `vm` to completion, under `budget` when one is set.
+fn run_vm_budgeted(vm: &mut crate::vm::Vm, budget: Option<&Budget>) -> (Option<String>, Option<LimitExceeded>) {
+    match budget {
+        Some(budget) => (vm.run_limited(budget).err(), budget.exceeded()),
+        None => (vm.run().err(), None),
     }
 }
 
@@ -2497,7 +2802,7 @@ pub async fn interpret_streaming_with_vfs<F>(
 where
     F: FnMut(String) + 'static,
 {
-    interpret_streaming_impl(input, on_output, vfs, None).await
+    interpret_streaming_impl(input, on_output, vfs, None, None).await
 }
 
 /// Like [`interpret_streaming_with_vfs`], but also emits a [`logicaffeine_runtime::SchedSnapshot`]
@@ -2512,7 +2817,35 @@ pub async fn interpret_streaming_with_vfs_observer<F>(
 where
     F: FnMut(String) + 'static,
 {
-    interpret_streaming_impl(input, on_output, vfs, Some(observer)).await
+    interpret_streaming_impl(input, on_output, vfs, Some(observer), None).await
+}
+
+/// Like [`interpret_streaming_with_vfs`], for the entry file of a multi-file
+/// project. Imports in the entry's abstract (`[Geometry](file:./geo.md)`) resolve
+/// against `files` through a [`Loader`](crate::loader::Loader), so the Studio can
+/// run a program whose modules live in its browser file system. `entry_path` is
+/// the entry's own key in `files`; its parent directory is the project root.
+pub async fn interpret_streaming_project<F>(
+    entry_path: &str,
+    files: std::collections::HashMap<std::path::PathBuf, String>,
+    on_output: std::rc::Rc<std::cell::RefCell<F>>,
+    vfs: Option<std::sync::Arc<dyn logicaffeine_system::fs::Vfs>>,
+) -> InterpreterResult
+where
+    F: FnMut(String) + 'static,
+{
+    let entry = std::path::Path::new(entry_path);
+    let Some(input) = files.get(entry).cloned() else {
+        return InterpreterResult {
+            lines: vec![],
+            error: Some(format!("Project entry '{}' not found", entry_path)),
+            memory: None,
+            limit_exceeded: None,
+        };
+    };
+    let root = entry.parent().unwrap_or(std::path::Path::new("/")).to_path_buf();
+    let loader = crate::loader::Loader::with_files(root, files);
+    interpret_streaming_impl(&input, on_output, vfs, None, Some((entry, loader))).await
 }
 
 async fn interpret_streaming_impl<F>(
@@ -2520,6 +2853,7 @@ async fn interpret_streaming_impl<F>(
     on_output: std::rc::Rc<std::cell::RefCell<F>>,
     vfs: Option<std::sync::Arc<dyn logicaffeine_system::fs::Vfs>>,
     observer: Option<ObserverCallback>,
+    project: Option<(&std::path::Path, crate::loader::Loader)>,
 ) -> InterpreterResult
 where
     F: FnMut(String) + 'static,
@@ -2531,15 +2865,34 @@ where
     let mut lexer = Lexer::new(input, &mut interner);
     let tokens = lexer.tokenize();
 
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
 
-    let (type_registry, policy_registry) = {
+    let (mut type_registry, policy_registry) = {
         let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
         let result = discovery.run_full();
         (result.types, result.policies)
     };
 
+    // Imported modules contribute their types under the import's alias
+    if let Some((entry, mut loader)) = project {
+        match crate::analysis::discover_with_imports(entry, input, &mut loader, &mut interner) {
+            Ok(imported) => {
+                for (sym, def) in imported.iter_types() {
+                    type_registry.register(*sym, def.clone());
+                }
+            }
+            Err(e) => {
+                return InterpreterResult {
+                    lines: vec![],
+                    error: Some(e),
+                    memory: None,
+                    limit_exceeded: None,
+                };
+            }
+        }
+    }
+
     let expr_arena = Arena::new();
     let term_arena = Arena::new();
     let np_arena = Arena::new();
@@ -2596,30 +2949,26 @@ where
                 .await;
             }
 
+            let budget = start_budget();
             let mut interp = crate::interpreter::Interpreter::new(&interner)
                 .with_type_registry(&type_registry_for_interp)
                 .with_policies(policy_registry)
-                .with_output_callback(callback);
+                .with_output_callback(callback)
+                .with_budget(budget.clone());
             if let Some(v) = vfs {
                 interp = interp.with_vfs(v);
             }
 
-            match interp.run(&stmts).await {
-                Ok(()) => InterpreterResult {
-                    lines: interp.output,
-                    error: None,
-                },
-                Err(e) => InterpreterResult {
-                    lines: interp.output,
-                    error: Some(e),
-                },
-            }
+            let outcome = interp.run(&stmts).await;
+            treewalker_result(interp.output, outcome.err(), budget.as_deref())
         }
         Err(e) => {
             let advice = socratic_explanation(&e, &interner);
             InterpreterResult {
                 lines: vec![],
                 error: Some(advice),
+                memory: None,
+                limit_exceeded: None,
             }
         }
     }
@@ -4124,7 +4473,7 @@ fn candidate_entailed_prepared(prepared: &[ProofExpr], goal: &ProofExpr) -> bool
 /// Strip `Temporal` wrappers throughout a [`ProofExpr`] — a logic grid is one
 /// static scenario, so tense carries no information and would otherwise block the
 /// oracle from forcing values across past-tense clues.
-fn erase_tense(e: &ProofExpr) -> ProofExpr {
+pub(crate) fn erase_tense(e: &ProofExpr) -> ProofExpr {
     match e {
         ProofExpr::Temporal { body, .. } => erase_tense(body),
         ProofExpr::And(l, r) => {
@@ -4234,8 +4583,8 @@ fn theorem_problem(
     let mut lexer = Lexer::new(input, &mut interner);
     let tokens = lexer.tokenize();
 
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
 
     let type_registry = {
         let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
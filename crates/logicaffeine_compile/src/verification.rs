@@ -11,6 +11,9 @@
 //! | LOGOS Type | Z3 Mapping |
 //! |------------|------------|
 //! | `Int`, `Bool` | Direct Z3 sorts |
+//! | `Real`, `Float` | Z3 reals (exact; no rounding) |
+//! | Bitwise ops on `Int` | 64-bit Z3 bitvectors |
+//! | `Seq of T`, list literals | Z3 arrays from `Int` plus a length |
 //! | `Object` | Uninterpreted sort for entities |
 //! | Predicates | Uninterpreted functions |
 //! | Modals | Uninterpreted functions |
@@ -32,12 +35,20 @@
 //!            │
 //!            ▼
 //! ┌────────────────────┐
-//! │ VerificationSession│ ─── assume/check
+//! │ VerificationSession│ ─── assume / capture obligations
+//! └──────────┬─────────┘
+//!            ▼
+//! ┌────────────────────┐
+//! │ verify_obligations │ ─── parallel Z3 solvers
 //! └──────────┬─────────┘
 //!            ▼
 //!     Z3 Result (Sat/Unsat)
 //! ```
 //!
+//! Each check is captured as an obligation while the program is walked and
+//! the whole batch is proved at the end, spread over the worker threads of
+//! the pass's [`ScheduleConfig`]. Failures are reported in program order.
+//!
 //! # Feature Flag
 //!
 //! This module requires the `verification` feature to be enabled.
@@ -48,7 +59,12 @@ use logicaffeine_language::ast::stmt::{BinaryOpKind, Expr, Literal, Stmt, TypeEx
 use logicaffeine_base::{Interner, Symbol};
 use logicaffeine_language::token::TokenType;
 
-use logicaffeine_verify::{VerificationSession, VerifyExpr, VerifyOp, VerifyType};
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use logicaffeine_verify::cache::ObligationCache;
+use logicaffeine_verify::schedule::{verify_obligations, Obligation, ObligationOutcome, ScheduleConfig};
+use logicaffeine_verify::{BitVecOp, VerificationSession, VerifyExpr, VerifyOp, VerifyType};
 
 /// The verification pass that maps LOGOS AST to Z3 constraints.
 pub struct VerificationPass<'a> {
@@ -57,6 +73,11 @@ pub struct VerificationPass<'a> {
     /// Counter for minting fresh, distinct uninterpreted propositions for
     /// constructs the verifier cannot yet interpret. See [`Self::unverifiable`].
     unverifiable_counter: std::cell::Cell<u32>,
+    /// Obligations captured so far, each with the context its failure is
+    /// reported under (`None` for plain assertions).
+    pending: RefCell<Vec<(Obligation, Option<String>)>>,
+    schedule: ScheduleConfig,
+    cache: Option<Arc<ObligationCache>>,
 }
 
 impl<'a> VerificationPass<'a> {
@@ -66,9 +87,31 @@ impl<'a> VerificationPass<'a> {
             session: VerificationSession::new(),
             interner,
             unverifiable_counter: std::cell::Cell::new(0),
+            pending: RefCell::new(Vec::new()),
+            schedule: ScheduleConfig::default(),
+            cache: None,
         }
     }
 
+    /// Skip obligations already proven in `cache` and record the ones this
+    /// pass proves.
+    pub fn with_cache(mut self, cache: Arc<ObligationCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Prove obligations with these worker threads, timeout and budget.
+    pub fn with_schedule(mut self, schedule: ScheduleConfig) -> Self {
+        self.schedule = schedule;
+        self
+    }
+
+    /// Queue `obligation`; its failure is reported as `context: <error>`.
+    fn defer(&self, obligation: Obligation, context: Option<String>) {
+        let label = context.clone().unwrap_or_else(|| "assertion".to_string());
+        self.pending.borrow_mut().push((obligation.labeled(label), context));
+    }
+
     /// A fresh, distinct uninterpreted proposition standing for a construct the
     /// verifier cannot interpret.
     ///
@@ -90,29 +133,68 @@ impl<'a> VerificationPass<'a> {
 
     /// Run verification on a list of statements.
     ///
-    /// This processes Let statements to build up assumptions,
-    /// then verifies Assert statements against those assumptions.
+    /// This processes Let statements to build up assumptions, captures an
+    /// obligation for every Assert against those assumptions, then proves
+    /// them all. Every failure is reported, in program order.
     pub fn verify_program(&mut self, stmts: &[Stmt]) -> Result<(), String> {
         for stmt in stmts {
             self.visit_stmt(stmt)?;
         }
-        Ok(())
+
+        let (obligations, contexts): (Vec<_>, Vec<_>) = self.pending.take().into_iter().unzip();
+        let reports = verify_obligations(&obligations, &self.schedule, self.cache.as_deref());
+        let failures: Vec<String> = reports
+            .into_iter()
+            .zip(contexts)
+            .filter_map(|(report, context)| {
+                let reason = match report.outcome {
+                    ObligationOutcome::Proven | ObligationOutcome::Cached => return None,
+                    ObligationOutcome::Failed(e) => e.to_string(),
+                    ObligationOutcome::TimedOut { timeout_ms } => {
+                        format!("The solver gave no answer within {} ms.\n", timeout_ms)
+                    }
+                    ObligationOutcome::OverBudget => {
+                        "Not checked: the verification time budget ran out.\n".to_string()
+                    }
+                };
+                Some(match context {
+                    Some(context) => format!("{}: {}", context, reason),
+                    None => reason,
+                })
+            })
+            .collect();
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures.join("\n"))
+        }
     }
 
     fn visit_stmt(&mut self, stmt: &Stmt) -> Result<(), String> {
         match stmt {
-            Stmt::Let { var, ty, value, .. } => {
+            Stmt::Let { var, ty, value, mutable } => {
                 let name = self.interner.resolve(*var);
 
                 // Check refinement constraints BEFORE declaring variable
-                if let Some(TypeExpr::Refinement { var: bound_var, predicate, .. }) = ty {
-                    self.check_refinement(name, *bound_var, predicate, value)?;
+                if let Some(TypeExpr::Refinement { base, var: bound_var, predicate }) = ty {
+                    self.check_refinement(name, base, *bound_var, predicate, value)?;
                 }
 
-                // Infer type from the value
-                let inferred_ty = self.infer_type(value);
+                // A declared numeric type wins; otherwise infer it from the value
+                let inferred_ty = self
+                    .declared_type(*ty)
+                    .unwrap_or_else(|| self.infer_type(value));
                 self.session.declare(name, inferred_ty);
 
+                // A list literal fixes the sequence's length and every item —
+                // unless it's mutable, since pushes and pops aren't tracked
+                if let Expr::List(items) = value {
+                    if !*mutable {
+                        self.assume_list_contents(name, items);
+                    }
+                    return Ok(());
+                }
+
                 // Map the value to IR and assume var = value
                 if let Some(val_ir) = self.map_imperative_expr(value) {
                     let constraint = VerifyExpr::eq(
@@ -145,7 +227,8 @@ impl<'a> VerificationPass<'a> {
                 if matches!(&ir, VerifyExpr::Bool(true)) {
                     return Ok(());
                 }
-                self.session.verify(&ir).map_err(|e| format!("{}", e))
+                self.defer(self.session.obligation(&ir), None);
+                Ok(())
             }
 
             Stmt::Trust { proposition, justification } => {
@@ -157,9 +240,11 @@ impl<'a> VerificationPass<'a> {
                     return Ok(());
                 }
                 let reason = self.interner.resolve(*justification);
-                self.session.verify(&ir).map_err(|e| {
-                    format!("Trust verification failed (justification: {}): {}", reason, e)
-                })
+                self.defer(
+                    self.session.obligation(&ir),
+                    Some(format!("Trust verification failed (justification: {})", reason)),
+                );
+                Ok(())
             }
 
             // Recurse into blocks (simplified - no path-sensitive analysis yet)
@@ -215,6 +300,7 @@ impl<'a> VerificationPass<'a> {
             | Stmt::Call { .. }
             | Stmt::Give { .. }
             | Stmt::Show { .. }
+            | Stmt::Log { .. }
             | Stmt::SetField { .. }
             | Stmt::StructDef { .. }
             | Stmt::Inspect { .. }
@@ -225,9 +311,12 @@ impl<'a> VerificationPass<'a> {
             | Stmt::Parallel { .. }
             | Stmt::ReadFrom { .. }
             | Stmt::WriteFile { .. }
+            | Stmt::RunCommand { .. }
+            | Stmt::Sip { .. }
             // Phase 51: P2P Networking
             | Stmt::Listen { .. }
             | Stmt::ConnectTo { .. }
+            | Stmt::JoinSwarm { .. }
             | Stmt::LetPeerAgent { .. } => Ok(()),
 
             // All other statements don't affect verification
@@ -239,6 +328,7 @@ impl<'a> VerificationPass<'a> {
     fn infer_type(&self, expr: &Expr) -> VerifyType {
         match expr {
             Expr::Literal(Literal::Number(_)) => VerifyType::Int,
+            Expr::Literal(Literal::Float(_)) => VerifyType::Real,
             Expr::Literal(Literal::Boolean(_)) => VerifyType::Bool,
             Expr::Literal(Literal::Text(_)) => VerifyType::Object,
             Expr::Literal(Literal::Nothing) => VerifyType::Object,
@@ -246,7 +336,19 @@ impl<'a> VerificationPass<'a> {
             Expr::Literal(Literal::Duration(_)) => VerifyType::Int,
             Expr::Literal(Literal::Date(_)) => VerifyType::Int,
             Expr::Literal(Literal::Moment(_)) => VerifyType::Int,
-            Expr::BinaryOp { op, .. } => {
+            Expr::Identifier(sym) => self
+                .session
+                .type_of(self.interner.resolve(*sym))
+                .cloned()
+                .unwrap_or(VerifyType::Int),
+            Expr::List(items) => VerifyType::SeqOf(Box::new(
+                items.first().map_or(VerifyType::Int, |item| self.infer_type(item)),
+            )),
+            Expr::Index { collection, .. } => match self.infer_type(collection) {
+                VerifyType::SeqOf(elem) => *elem,
+                _ => VerifyType::Int,
+            },
+            Expr::BinaryOp { op, left, right } => {
                 match op {
                     // Comparison operators produce Bool
                     BinaryOpKind::Eq
@@ -257,7 +359,7 @@ impl<'a> VerificationPass<'a> {
                     | BinaryOpKind::GtEq
                     | BinaryOpKind::And
                     | BinaryOpKind::Or => VerifyType::Bool,
-                    // Arithmetic operators produce Int
+                    // Arithmetic operators produce Real if either operand is Real, Int otherwise
                     BinaryOpKind::Add
                     | BinaryOpKind::Subtract
                     | BinaryOpKind::Multiply
@@ -265,7 +367,15 @@ impl<'a> VerificationPass<'a> {
                     | BinaryOpKind::ExactDivide
                     | BinaryOpKind::FloorDivide
                     | BinaryOpKind::Pow
-                    | BinaryOpKind::Modulo => VerifyType::Int,
+                    | BinaryOpKind::Modulo => {
+                        if self.infer_type(left) == VerifyType::Real
+                            || self.infer_type(right) == VerifyType::Real
+                        {
+                            VerifyType::Real
+                        } else {
+                            VerifyType::Int
+                        }
+                    }
                     // Concat produces a string (Object type)
                     BinaryOpKind::Concat => VerifyType::Object,
                     // `followed by` produces a sequence (Object type)
@@ -282,10 +392,87 @@ impl<'a> VerificationPass<'a> {
         }
     }
 
+    /// The verification type of a declared type annotation, for the numeric
+    /// types whose sort can't be read off the value (`Let x: Real be 2.`).
+    fn declared_type(&self, ty: Option<&TypeExpr>) -> Option<VerifyType> {
+        match ty? {
+            TypeExpr::Primitive(sym) | TypeExpr::Named(sym) => {
+                match self.interner.resolve(*sym) {
+                    "Real" | "Float" => Some(VerifyType::Real),
+                    "Int" | "Nat" => Some(VerifyType::Int),
+                    _ => None,
+                }
+            }
+            TypeExpr::Refinement { base, .. } => self.declared_type(Some(*base)),
+            TypeExpr::Generic { base, params } => match self.interner.resolve(*base) {
+                "Seq" | "List" => Some(VerifyType::SeqOf(Box::new(
+                    self.declared_type(params.first()).unwrap_or(VerifyType::Object),
+                ))),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Assume what a list literal says about the sequence `name`: its length,
+    /// and each item at its 1-based position.
+    fn assume_list_contents(&mut self, name: &str, items: &[&Expr]) {
+        let seq = VerifyExpr::var(name);
+        self.session.assume(&VerifyExpr::eq(
+            VerifyExpr::length(seq.clone()),
+            VerifyExpr::int(items.len() as i64),
+        ));
+        for (position, item) in (1..).zip(items) {
+            if let Some(value) = self.map_imperative_expr(item) {
+                let selected = VerifyExpr::select(seq.clone(), VerifyExpr::int(position));
+                self.session.assume(&VerifyExpr::eq(selected, value));
+            }
+        }
+    }
+
+    /// Whether `expr` is a sequence the solver knows as one.
+    fn is_sequence(&self, expr: &Expr) -> bool {
+        matches!(self.infer_type(expr), VerifyType::SeqOf(_))
+    }
+
+    /// Map a bitwise operator to 64-bit bitvector IR, mirroring the runtime's
+    /// `i64` semantics: shift counts wrap modulo 64 and `>>` is arithmetic.
+    ///
+    /// `None` for non-bitwise operators and for operands that aren't `Int`
+    /// (`&`, `|` and `^` are also set operations).
+    fn map_bitwise(
+        &self,
+        op: &BinaryOpKind,
+        left: &Expr,
+        right: &Expr,
+        l: &VerifyExpr,
+        r: &VerifyExpr,
+    ) -> Option<VerifyExpr> {
+        let bv_op = match op {
+            BinaryOpKind::BitXor => BitVecOp::Xor,
+            BinaryOpKind::BitAnd => BitVecOp::And,
+            BinaryOpKind::BitOr => BitVecOp::Or,
+            BinaryOpKind::Shl => BitVecOp::Shl,
+            BinaryOpKind::Shr => BitVecOp::AShr,
+            _ => return None,
+        };
+        if self.infer_type(left) != VerifyType::Int || self.infer_type(right) != VerifyType::Int {
+            return None;
+        }
+        let r = match bv_op {
+            BitVecOp::Shl | BitVecOp::AShr => {
+                VerifyExpr::bv_binary(BitVecOp::And, r.clone(), VerifyExpr::bv_const(64, 63))
+            }
+            _ => r.clone(),
+        };
+        Some(VerifyExpr::bv_binary(bv_op, l.clone(), r))
+    }
+
     /// Phase 43D: Check that a value satisfies a refinement type constraint.
     fn check_refinement(
         &self,
         var_name: &str,
+        base: &TypeExpr,
         bound_var: Symbol,
         predicate: &LogicExpr,
         value: &Expr,
@@ -308,16 +495,21 @@ impl<'a> VerificationPass<'a> {
         // 3. Get the bound variable name (e.g., "it" or "x")
         let bound_name = self.interner.resolve(bound_var);
 
-        // 4. Verify with the binding
-        self.session.verify_with_binding(
+        // 4. Verify with the binding, typed as the refined base type
+        let base_ty = self
+            .declared_type(Some(base))
+            .unwrap_or_else(|| self.infer_type(value));
+        let obligation = self.session.obligation_with_binding(
             bound_name,
-            VerifyType::Int, // Refinements are typically on Int
+            base_ty,
             &val_ir,
             &pred_ir,
-        ).map_err(|e| format!(
-            "Refinement type verification failed for '{}': {}",
-            var_name, e
-        ))
+        );
+        self.defer(
+            obligation,
+            Some(format!("Refinement type verification failed for '{}'", var_name)),
+        );
+        Ok(())
     }
 
     /// Phase 44: Verify that a loop terminates by checking its decreasing variant.
@@ -348,9 +540,11 @@ impl<'a> VerificationPass<'a> {
         // 6. Combined: decreasing AND bounded
         let termination_proof = VerifyExpr::and(decreasing_constraint, bounded_constraint);
 
-        self.session.verify(&termination_proof).map_err(|e| {
-            format!("Termination verification failed for '{}': {}", variant_name, e)
-        })
+        self.defer(
+            self.session.obligation(&termination_proof),
+            Some(format!("Termination verification failed for '{}'", variant_name)),
+        );
+        Ok(())
     }
 
     /// Simulate the loop body to determine the final value of the variant.
@@ -410,7 +604,7 @@ impl<'a> VerificationPass<'a> {
             Expr::Literal(Literal::Duration(nanos)) => Some(VerifyExpr::int(*nanos)),
             Expr::Literal(Literal::Date(days)) => Some(VerifyExpr::int(*days as i64)),
             Expr::Literal(Literal::Moment(nanos)) => Some(VerifyExpr::int(*nanos)),
-            Expr::Literal(Literal::Float(_)) => None,
+            Expr::Literal(Literal::Float(f)) => VerifyExpr::from_f64(*f),
             Expr::Literal(Literal::Char(_)) => None,
 
             Expr::Identifier(sym) => {
@@ -426,6 +620,9 @@ impl<'a> VerificationPass<'a> {
             Expr::BinaryOp { op, left, right } => {
                 let l = self.map_imperative_expr_with_bindings(left, bindings)?;
                 let r = self.map_imperative_expr_with_bindings(right, bindings)?;
+                if let Some(bits) = self.map_bitwise(op, left, right, &l, &r) {
+                    return Some(bits);
+                }
                 let verify_op = match op {
                     BinaryOpKind::Add => VerifyOp::Add,
                     BinaryOpKind::Subtract => VerifyOp::Sub,
@@ -442,7 +639,7 @@ impl<'a> VerificationPass<'a> {
                     BinaryOpKind::LtEq => VerifyOp::Lte,
                     BinaryOpKind::And => VerifyOp::And,
                     BinaryOpKind::Or => VerifyOp::Or,
-                    // Modulo, Pow, Concat, and bitwise ops are not directly supported in the
+                    // Modulo, Pow, Concat, and set operations are not directly supported in the
                     // verification IR.
                     BinaryOpKind::Modulo | BinaryOpKind::Pow
                     | BinaryOpKind::Concat | BinaryOpKind::SeqConcat
@@ -462,6 +659,15 @@ impl<'a> VerificationPass<'a> {
                 Some(VerifyExpr::apply(func_name, verify_args))
             }
 
+            // Sequence positions are 1-based in both LOGOS and the IR
+            Expr::Index { collection, index } if self.is_sequence(collection) => Some(VerifyExpr::select(
+                self.map_imperative_expr_with_bindings(collection, bindings)?,
+                self.map_imperative_expr_with_bindings(index, bindings)?,
+            )),
+            Expr::Length { collection } if self.is_sequence(collection) => Some(VerifyExpr::length(
+                self.map_imperative_expr_with_bindings(collection, bindings)?,
+            )),
+
             // Unsupported expressions
             _ => None,
         }
@@ -474,7 +680,7 @@ impl<'a> VerificationPass<'a> {
             Expr::Literal(Literal::Boolean(b)) => Some(VerifyExpr::bool(*b)),
             Expr::Literal(Literal::Text(_)) => None, // Text not supported in Z3
             Expr::Literal(Literal::Nothing) => None,
-            Expr::Literal(Literal::Float(_)) => None, // Float not directly supported
+            Expr::Literal(Literal::Float(f)) => VerifyExpr::from_f64(*f), // Exact decimal as a real
             Expr::Literal(Literal::Char(_)) => None, // Char not directly supported
             // Temporal types map to Int (nanoseconds/days are just integers for Z3)
             Expr::Literal(Literal::Duration(nanos)) => Some(VerifyExpr::int(*nanos)),
@@ -489,6 +695,9 @@ impl<'a> VerificationPass<'a> {
             Expr::BinaryOp { op, left, right } => {
                 let l = self.map_imperative_expr(left)?;
                 let r = self.map_imperative_expr(right)?;
+                if let Some(bits) = self.map_bitwise(op, left, right, &l, &r) {
+                    return Some(bits);
+                }
                 let verify_op = match op {
                     BinaryOpKind::Add => VerifyOp::Add,
                     BinaryOpKind::Subtract => VerifyOp::Sub,
@@ -505,7 +714,7 @@ impl<'a> VerificationPass<'a> {
                     BinaryOpKind::LtEq => VerifyOp::Lte,
                     BinaryOpKind::And => VerifyOp::And,
                     BinaryOpKind::Or => VerifyOp::Or,
-                    // Modulo, Pow, Concat, and bitwise ops are not directly supported in the
+                    // Modulo, Pow, Concat, and set operations are not directly supported in the
                     // verification IR.
                     BinaryOpKind::Modulo | BinaryOpKind::Pow
                     | BinaryOpKind::Concat | BinaryOpKind::SeqConcat
@@ -525,6 +734,14 @@ impl<'a> VerificationPass<'a> {
                 Some(VerifyExpr::apply(func_name, verify_args))
             }
 
+            Expr::Index { collection, index } if self.is_sequence(collection) => Some(VerifyExpr::select(
+                self.map_imperative_expr(collection)?,
+                self.map_imperative_expr(index)?,
+            )),
+            Expr::Length { collection } if self.is_sequence(collection) => {
+                Some(VerifyExpr::length(self.map_imperative_expr(collection)?))
+            }
+
             // Unsupported expressions
             _ => None,
         }
@@ -862,7 +1079,7 @@ impl<'a> VerificationPass<'a> {
             Term::Value { kind, .. } => {
                 match kind {
                     NumberKind::Integer(n) => VerifyExpr::int(*n),
-                    NumberKind::Real(r) => VerifyExpr::int(*r as i64), // Truncate for now
+                    NumberKind::Real(r) => VerifyExpr::from_f64(*r).unwrap_or_else(|| self.unverifiable()),
                     NumberKind::Symbolic(s) => {
                         let name = self.interner.resolve(*s);
                         VerifyExpr::var(name)
@@ -507,6 +507,11 @@ impl<'i> Compiler<'i> {
         oracle: Option<crate::optimize::OracleFacts>,
         debug_names: bool,
     ) -> Result<CompiledProgram, String> {
+        // A `Comparable` struct's sort order lives in the tree-walker's context; a `CallBuiltin`
+        // carries no type registry, so such a program sorts on the tree-walker.
+        if types.is_some_and(|t| t.iter_types().any(|(name, _)| t.ordering(*name).is_some())) {
+            return Err("`Comparable` types are only supported by the interpreter tier, not the bytecode VM".to_string());
+        }
         let mut c = Compiler {
             interner,
             debug_names,
@@ -1905,6 +1910,12 @@ impl<'i> Compiler<'i> {
                         self.emit(Op::FailWith { msg: idx });
                         Ok(())
                     }
+                    ReadSource::Settings { .. } => {
+                        Err("`Read … from settings` is only supported by the interpreter tier, not the bytecode VM".to_string())
+                    }
+                    ReadSource::Arguments { .. } => {
+                        Err("`Read … from arguments` is only supported by the interpreter tier, not the bytecode VM".to_string())
+                    }
                 }
             }
             Stmt::WriteFile { content, path } => {
@@ -2040,6 +2051,18 @@ impl<'i> Compiler<'i> {
                 self.emit(Op::NetConnect { url });
                 Ok(())
             }
+            Stmt::JoinSwarm { .. } => {
+                Err("`Join the swarm` is only supported by the interpreter tier, not the bytecode VM".to_string())
+            }
+            Stmt::Log { .. } => {
+                Err("`Log` is only supported by the interpreter tier, not the bytecode VM".to_string())
+            }
+            Stmt::RunCommand { .. } => {
+                Err("`Run the command` is only supported by the interpreter tier, not the bytecode VM".to_string())
+            }
+            Stmt::Sip { .. } => {
+                Err("`Sip the file` is only supported by the interpreter tier, not the bytecode VM".to_string())
+            }
             Stmt::LetPeerAgent { var, address } => {
                 let addr = self.compile_expr(address)?;
                 let dst = self.let_reg(*var)?;
@@ -2686,6 +2709,7 @@ impl<'i> Compiler<'i> {
                     "SharedSequence" | "RGA" | "SharedSequence_YATA" | "CollaborativeSequence" => {
                         self.emit(Op::NewCrdt { dst: v, kind: 1 })
                     }
+                    "SharedDocument" => self.emit(Op::NewCrdt { dst: v, kind: 4 }),
                     _ => {
                         let i = self.add_const(Constant::Nothing)?;
                         self.emit(Op::LoadConst { dst: v, idx: i });
@@ -3291,6 +3315,22 @@ fn visit_stmt_exprs(s: &Stmt, f: &mut dyn FnMut(&Expr)) {
         Stmt::If { cond, .. } | Stmt::While { cond, .. } => walk_expr(cond, f),
         Stmt::Repeat { iterable, .. } => walk_expr(iterable, f),
         Stmt::Show { object, .. } | Stmt::Give { object, .. } => walk_expr(object, f),
+        Stmt::Log { message, fields, .. } => {
+            walk_expr(message, f);
+            for (_, value) in fields {
+                walk_expr(value, f);
+            }
+        }
+        Stmt::RunCommand { command, timeout, .. } => {
+            walk_expr(command, f);
+            if let Some(timeout) = timeout {
+                walk_expr(timeout, f);
+            }
+        }
+        Stmt::Sip { file, sources, .. } => {
+            walk_expr(file, f);
+            walk_expr(sources, f);
+        }
         Stmt::Push { value, collection }
         | Stmt::Add { value, collection }
         | Stmt::Remove { value, collection } => {
@@ -3317,7 +3357,8 @@ fn visit_stmt_exprs(s: &Stmt, f: &mut dyn FnMut(&Expr)) {
             walk_expr(content, f);
             walk_expr(path, f);
         }
-        Stmt::ReadFrom { source: ReadSource::File(p), .. } => walk_expr(p, f),
+        Stmt::ReadFrom { source: ReadSource::File(p), .. }
+        | Stmt::ReadFrom { source: ReadSource::Settings { path: Some(p), .. }, .. } => walk_expr(p, f),
         _ => {}
     }
 }
@@ -320,7 +320,8 @@ pub enum Op {
     /// GCounter merge: fold every field of `R[source]` into `R[target]`.
     CrdtMerge { target: Reg, source: Reg },
     /// `R[dst]` = a fresh, empty rich CRDT — `kind` 0 = SharedSet (OR-Set),
-    /// 1 = SharedSequence (RGA), 2 = Divergent (MV-register). Used to default-fill a
+    /// 1 = SharedSequence (RGA), 2 = Divergent (MV-register), 3 = SharedSet
+    /// (RemoveWins), 4 = shared Document. Used to default-fill a
     /// `Shared` struct's CRDT fields, mirroring the tree-walker's `new`-struct init.
     NewCrdt { dst: Reg, kind: u8 },
     /// RGA append: push `R[value]` onto the replicated sequence in `R[seq]` (mutates the
@@ -1926,6 +1926,46 @@ impl<'p> Vm<'p> {
         self.run_until_block_impl::<true>(step_budget)
     }
 
+    /// Run the whole program under `budget` (see [`crate::limits`]): the
+    /// stepped loop advances in slices, and between slices the step, clock,
+    /// memory and output limits are checked. A crossed limit keeps the output
+    /// admitted so far and returns the limit's message as the error. Native
+    /// tiers are never entered here, so every op is counted.
+    pub(crate) fn run_limited(&mut self, budget: &crate::limits::Budget) -> Result<(), String> {
+        const SLICE: u64 = 4096;
+        logicaffeine_base::money::clear_ambient_rates();
+        let mut admitted = 0;
+        loop {
+            let step = self.run_steps(SLICE)?;
+            while admitted < self.lines.len() {
+                if !budget.admit_output_line() {
+                    self.lines.truncate(admitted);
+                    break;
+                }
+                admitted += 1;
+            }
+            match step {
+                VmStep::Done(_) => {}
+                VmStep::Blocked => return Err("vm: concurrency op requires the scheduler driver".to_string()),
+                VmStep::Paused => {
+                    let live = || {
+                        let globals = self.globals.iter().flatten();
+                        crate::limits::approx_live_bytes(
+                            self.registers.iter().chain(globals).filter_map(Value::as_runtime_ref),
+                        )
+                    };
+                    if budget.charge(SLICE, live).is_ok() {
+                        continue;
+                    }
+                }
+            }
+            return match budget.exceeded() {
+                Some(limit) => Err(limit.to_string()),
+                None => Ok(()),
+            };
+        }
+    }
+
     fn run_until_block_impl<const STEPPED: bool>(
         &mut self,
         step_budget: u64,
@@ -2780,6 +2820,7 @@ impl<'p> Vm<'p> {
                         0 => CrdtValue::new_set(next_replica_id()),
                         1 => CrdtValue::new_seq(next_replica_id()),
                         3 => CrdtValue::new_set_remove_wins(next_replica_id()),
+                        4 => CrdtValue::new_document(next_replica_id()),
                         _ => CrdtValue::new_register(next_replica_id()),
                     };
                     self.set(
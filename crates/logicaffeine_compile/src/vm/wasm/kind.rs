@@ -1575,11 +1575,13 @@ fn op_kind_effect(
         // underlying collection: an OR-Set (`kind` 0/3) → `CrdtSetText` (byte-dedup set, but MUTABLE-
         // SHARED so `Add`/`Remove` on the field mutate in place), an RGA/sequence (1) → `SeqText`, a
         // divergent register (else) → `Text`. Per-replica merge metadata isn't represented (merge defers).
-        Op::NewCrdt { dst, kind } => Some((dst as usize, Some(match kind {
-            0 | 3 => Kind::CrdtSetText,
-            1 => Kind::SeqText,
-            _ => Kind::Text,
-        }))),
+        Op::NewCrdt { dst, kind } => Some((dst as usize, match kind {
+            0 | 3 => Some(Kind::CrdtSetText),
+            1 => Some(Kind::SeqText),
+            // A shared Document has no flat kind (the lowering refuses it)
+            4 => None,
+            _ => Some(Kind::Text),
+        })),
         // `Add value to s` refines a set's element kind: a `Text` value makes it a `Set of Text`
         // (byte-equality dedup). An Int value keeps `Set`. `unify_strict` lets `Set` (the empty-set
         // default) absorb this `SetText` refinement, exactly as `SeqAny` refines to a concrete seq.
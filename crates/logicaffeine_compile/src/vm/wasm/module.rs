@@ -3177,6 +3177,8 @@ fn lower_op(pc: usize, plan: &Plan, ctx: &Ctx, blocks: &Blocks, k: usize, code:
             // soundness obligation), so an OR-Set field `Shared` struct stays deferred, not miscompiled.
             match kind {
                 0 | 1 | 3 => emit_empty_header(code, ctx, plan.num_regs, dst as u32),
+                // A shared Document is a nested tree with no flat-heap form yet.
+                4 => return Err(WasmLowerError::Unsupported("shared Document field")),
                 _ => {
                     lower_text_literal(code, ctx, plan.num_regs, b"");
                     local_set(code, dst as u32);
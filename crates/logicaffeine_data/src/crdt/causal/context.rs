@@ -87,6 +87,28 @@ impl DotContext {
         self.compact();
     }
 
+    /// The part of this context `author` can vouch for: all of its own
+    /// events, and of other replicas' only those `known` has already seen.
+    ///
+    /// A context claims which events were observed, and so which were
+    /// removed. Restricting it keeps a replica from removing writes that
+    /// haven't reached the receiver yet, including ones not yet made.
+    pub fn restricted_to(&self, author: ReplicaId, known: &Self) -> Self {
+        let mut clock = self.clock.meet(&known.clock);
+        clock.merge_vclock(&self.clock.restricted_to(author));
+        let cloud = self
+            .cloud
+            .iter()
+            .chain(&known.cloud)
+            .filter(|dot| self.has_seen(dot) && (dot.replica == author || known.has_seen(dot)))
+            .filter(|dot| dot.counter > clock.get(dot.replica))
+            .copied()
+            .collect();
+        let mut restricted = Self { clock, cloud };
+        restricted.compact();
+        restricted
+    }
+
     /// Get the underlying vector clock.
     pub fn clock(&self) -> &VClock {
         &self.clock
@@ -161,4 +183,27 @@ mod tests {
         assert!(a.has_seen(&Dot::new(1, 2)));
         assert!(a.has_seen(&Dot::new(2, 1)));
     }
+
+    #[test]
+    fn test_context_restricted_to_author() {
+        let mut claimed = DotContext::new();
+        for _ in 0..5 {
+            claimed.next(1);
+        }
+        for _ in 0..100 {
+            claimed.next(2);
+        }
+        claimed.add(Dot::new(3, 7));
+
+        let mut known = DotContext::new();
+        known.next(2);
+        known.next(2);
+        known.add(Dot::new(3, 7));
+
+        let restricted = claimed.restricted_to(1, &known);
+        assert!(restricted.has_seen(&Dot::new(1, 5)));
+        assert!(restricted.has_seen(&Dot::new(2, 2)));
+        assert!(!restricted.has_seen(&Dot::new(2, 3)));
+        assert!(restricted.has_seen(&Dot::new(3, 7)));
+    }
 }
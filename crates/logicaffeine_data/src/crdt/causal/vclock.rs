@@ -59,6 +59,25 @@ impl VClock {
         }
     }
 
+    /// The events both clocks have seen (pointwise min).
+    pub fn meet(&self, other: &Self) -> Self {
+        let entries = self
+            .entries
+            .iter()
+            .filter_map(|(&replica, &count)| {
+                let count = count.min(other.get(replica));
+                (count > 0).then_some((replica, count))
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// Just `replica`'s entry of this clock.
+    pub fn restricted_to(&self, replica: ReplicaId) -> Self {
+        let entries = self.entries.get(&replica).map(|&count| (replica, count)).into_iter().collect();
+        Self { entries }
+    }
+
     /// Get all replicas in this clock.
     pub fn replicas(&self) -> impl Iterator<Item = &ReplicaId> {
         self.entries.keys()
@@ -104,6 +123,24 @@ mod tests {
         assert_eq!(a.get(2), 1);
     }
 
+    #[test]
+    fn test_vclock_meet() {
+        let mut a = VClock::new();
+        let mut b = VClock::new();
+
+        a.increment(1);
+        a.increment(1);
+        a.increment(2);
+        b.increment(1);
+        b.increment(3);
+
+        let both = a.meet(&b);
+        assert_eq!(both.get(1), 1);
+        assert_eq!(both.get(2), 0);
+        assert_eq!(both.get(3), 0);
+        assert!(a.dominates(&both) && b.dominates(&both));
+    }
+
     #[test]
     fn test_vclock_dominates() {
         let mut a = VClock::new();
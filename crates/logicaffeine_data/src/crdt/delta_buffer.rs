@@ -63,6 +63,20 @@ impl<D: Clone> DeltaBuffer<D> {
         Some(result)
     }
 
+    /// Drop the oldest deltas that `seen` already covers.
+    ///
+    /// Once every known peer has seen a delta, nobody needs it again. The
+    /// newest covered delta stays, so a peer exactly at `seen` can still be
+    /// served; a late joiner behind the dropped deltas gets `None` from
+    /// `deltas_since`, as after an eviction.
+    pub fn prune(&mut self, seen: &VClock) {
+        while self.deltas.get(1).is_some_and(|(version, _)| seen.dominates(version)) {
+            if let Some((version, _)) = self.deltas.pop_front() {
+                self.oldest_version = version;
+            }
+        }
+    }
+
     /// Check if we can provide deltas since the given version.
     pub fn can_serve(&self, version: &VClock) -> bool {
         self.deltas_since(version).is_some()
@@ -178,4 +192,25 @@ mod tests {
         let deltas = buf.deltas_since(&v2).unwrap();
         assert_eq!(deltas, vec![3]);
     }
+
+    #[test]
+    fn test_buffer_prune() {
+        let mut buf: DeltaBuffer<i32> = DeltaBuffer::new(10);
+        let mut clock = VClock::new();
+
+        clock.increment(1);
+        let v1 = clock.clone();
+        buf.push(clock.clone(), 1);
+        clock.increment(1);
+        let v2 = clock.clone();
+        buf.push(clock.clone(), 2);
+        clock.increment(1);
+        buf.push(clock.clone(), 3);
+
+        buf.prune(&v2);
+        assert_eq!(buf.len(), 2);
+        assert_eq!(buf.deltas_since(&v2).unwrap(), vec![3]);
+        // Dropped deltas can no longer be served
+        assert!(buf.deltas_since(&v1).is_none());
+    }
 }
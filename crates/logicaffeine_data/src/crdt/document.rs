@@ -0,0 +1,692 @@
+//! Document CRDT: nested maps, lists and values.
+//!
+//! A JSON-like tree in the style of Automerge. Every write is tagged with a
+//! [`Dot`] from one document-wide [`DotContext`], so at any depth a replica can
+//! tell an entry it never saw from one that was removed:
+//! - Each map key and each list item holds a register of concurrent writes. A
+//!   write replaces the writes it saw; concurrent writes are all kept, and the
+//!   one with the highest Lamport stamp is read
+//! - Writing a map or list creates a nested object. Later writes edit that
+//!   object in place, and replicas holding the same object merge its contents
+//!   recursively
+//! - Lists order items the way [`RGA`](super::RGA) does: each item is inserted
+//!   after an origin, and newer inserts after the same origin come first
+//!
+//! A list of maps is a sequence of structs whose fields merge independently.
+//! Overwriting or removing an object drops concurrent edits made inside it.
+
+use super::causal::{Dot, DotContext, VClock};
+use super::delta::DeltaCrdt;
+use super::replica::{generate_replica_id, ReplicaId};
+use super::Merge;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// A plain snapshot of document content, and what writes put in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DocValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Text(String),
+    List(Vec<DocValue>),
+    Map(BTreeMap<String, DocValue>),
+}
+
+impl From<bool> for DocValue {
+    fn from(b: bool) -> Self {
+        DocValue::Bool(b)
+    }
+}
+
+impl From<i64> for DocValue {
+    fn from(n: i64) -> Self {
+        DocValue::Int(n)
+    }
+}
+
+impl From<&str> for DocValue {
+    fn from(s: &str) -> Self {
+        DocValue::Text(s.to_string())
+    }
+}
+
+impl From<String> for DocValue {
+    fn from(s: String) -> Self {
+        DocValue::Text(s)
+    }
+}
+
+/// One step of a path into a [`Document`]: a map key or a 0-based list index.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Key {
+    Field(String),
+    Index(usize),
+}
+
+impl From<&str> for Key {
+    fn from(s: &str) -> Self {
+        Key::Field(s.to_string())
+    }
+}
+
+impl From<String> for Key {
+    fn from(s: String) -> Self {
+        Key::Field(s)
+    }
+}
+
+impl From<usize> for Key {
+    fn from(i: usize) -> Self {
+        Key::Index(i)
+    }
+}
+
+/// Orders writes and list inserts: Lamport stamp, then replica.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct Stamp {
+    lamport: u64,
+    replica: ReplicaId,
+}
+
+/// What a write put in place. `Value` only ever holds a scalar.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Node {
+    Value(DocValue),
+    Map(Fields),
+    List(Items),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Write {
+    dot: Dot,
+    stamp: Stamp,
+    node: Node,
+}
+
+/// The concurrent writes to one key or item. Empty means removed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct Register {
+    writes: Vec<Write>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct Fields {
+    entries: BTreeMap<String, Register>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Item {
+    id: Dot,
+    stamp: Stamp,
+    /// The item this was inserted after (None for the head)
+    origin: Option<Dot>,
+    /// Empty once removed; the item stays as a tombstone for ordering
+    value: Register,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct Items {
+    items: Vec<Item>,
+}
+
+/// A map or list that a path leads to.
+enum Place<'a> {
+    Map(&'a Fields),
+    List(&'a Items),
+}
+
+enum PlaceMut<'a> {
+    Map(&'a mut Fields),
+    List(&'a mut Items),
+}
+
+impl Register {
+    fn single(write: Write) -> Self {
+        Self { writes: vec![write] }
+    }
+
+    fn winner(&self) -> Option<&Write> {
+        self.writes.iter().max_by_key(|w| w.stamp)
+    }
+
+    fn winner_mut(&mut self) -> Option<&mut Write> {
+        self.writes.iter_mut().max_by_key(|w| w.stamp)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.writes.is_empty()
+    }
+
+    /// Keep our writes the other side hasn't seen or still holds, take its
+    /// writes we haven't seen, and merge the objects we both hold.
+    fn merge(&mut self, other: &Register, mine: &DotContext, theirs: &DotContext) {
+        self.writes
+            .retain(|w| !theirs.has_seen(&w.dot) || other.writes.iter().any(|o| o.dot == w.dot));
+        for o in &other.writes {
+            match self.writes.iter_mut().find(|w| w.dot == o.dot) {
+                Some(w) => w.node.merge(&o.node, mine, theirs),
+                None if !mine.has_seen(&o.dot) => self.writes.push(o.clone()),
+                None => {}
+            }
+        }
+    }
+}
+
+impl Node {
+    fn merge(&mut self, other: &Node, mine: &DotContext, theirs: &DotContext) {
+        match (self, other) {
+            (Node::Map(a), Node::Map(b)) => a.merge(b, mine, theirs),
+            (Node::List(a), Node::List(b)) => a.merge(b, mine, theirs),
+            // The same write of a scalar holds the same value
+            _ => {}
+        }
+    }
+
+    fn to_value(&self) -> DocValue {
+        match self {
+            Node::Value(v) => v.clone(),
+            Node::Map(fields) => fields.to_value(),
+            Node::List(items) => items.to_value(),
+        }
+    }
+}
+
+impl Fields {
+    fn merge(&mut self, other: &Fields, mine: &DotContext, theirs: &DotContext) {
+        for (key, register) in &other.entries {
+            self.entries.entry(key.clone()).or_default().merge(register, mine, theirs);
+        }
+        let empty = Register::default();
+        for (key, register) in self.entries.iter_mut() {
+            if !other.entries.contains_key(key) {
+                register.merge(&empty, mine, theirs);
+            }
+        }
+        self.entries.retain(|_, register| !register.is_empty());
+    }
+
+    fn to_value(&self) -> DocValue {
+        DocValue::Map(
+            self.entries
+                .iter()
+                .filter_map(|(key, register)| register.winner().map(|w| (key.clone(), w.node.to_value())))
+                .collect(),
+        )
+    }
+}
+
+impl Items {
+    fn merge(&mut self, other: &Items, mine: &DotContext, theirs: &DotContext) {
+        let index: HashMap<Dot, usize> = self.items.iter().enumerate().map(|(i, item)| (item.id, i)).collect();
+        let mut held = vec![false; self.items.len()];
+        for o in &other.items {
+            match index.get(&o.id) {
+                Some(&i) => {
+                    held[i] = true;
+                    self.items[i].value.merge(&o.value, mine, theirs);
+                }
+                None if !mine.has_seen(&o.id) => self.items.push(o.clone()),
+                None => {}
+            }
+        }
+        let empty = Register::default();
+        for (item, held) in self.items.iter_mut().zip(held) {
+            if !held {
+                item.value.merge(&empty, mine, theirs);
+            }
+        }
+    }
+
+    /// Every item, tombstones included, in list order.
+    fn ordered(&self) -> Vec<&Item> {
+        let mut children: HashMap<Option<Dot>, Vec<&Item>> = HashMap::new();
+        for item in &self.items {
+            children.entry(item.origin).or_default().push(item);
+        }
+        // Newer inserts after the same origin come first
+        for siblings in children.values_mut() {
+            siblings.sort_by_key(|item| std::cmp::Reverse(item.stamp));
+        }
+
+        let mut result = Vec::with_capacity(self.items.len());
+        let mut stack: Vec<&Item> = children.get(&None).map_or_else(Vec::new, |heads| heads.iter().rev().copied().collect());
+        while let Some(item) = stack.pop() {
+            result.push(item);
+            if let Some(after) = children.get(&Some(item.id)) {
+                stack.extend(after.iter().rev());
+            }
+        }
+        result
+    }
+
+    fn visible(&self) -> impl Iterator<Item = &Item> {
+        self.ordered().into_iter().filter(|item| !item.value.is_empty())
+    }
+
+    fn visible_id(&self, index: usize) -> Option<Dot> {
+        self.visible().nth(index).map(|item| item.id)
+    }
+
+    fn visible_mut(&mut self, index: usize) -> Option<&mut Item> {
+        let id = self.visible_id(index)?;
+        self.items.iter_mut().find(|item| item.id == id)
+    }
+
+    fn len(&self) -> usize {
+        self.visible().count()
+    }
+
+    fn to_value(&self) -> DocValue {
+        DocValue::List(
+            self.visible()
+                .filter_map(|item| item.value.winner().map(|w| w.node.to_value()))
+                .collect(),
+        )
+    }
+}
+
+/// Delta for Document synchronization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentDelta {
+    root: Fields,
+    context: DotContext,
+    lamport: u64,
+}
+
+/// A replicated JSON-like document: a map at the root, holding values, maps
+/// and lists nested to any depth.
+///
+/// Paths are slices of [`Key`]s. Writes return `false`, leaving the document
+/// unchanged, when the path doesn't lead to a map or list to write into.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Document {
+    root: Fields,
+    /// Every dot this replica has seen, at any depth
+    context: DotContext,
+    /// Highest Lamport stamp seen
+    lamport: u64,
+    replica_id: ReplicaId,
+}
+
+impl Document {
+    /// Create an empty document with a specific replica ID.
+    pub fn new(replica_id: ReplicaId) -> Self {
+        Self {
+            root: Fields::default(),
+            context: DotContext::new(),
+            lamport: 0,
+            replica_id,
+        }
+    }
+
+    /// Create an empty document with a random replica ID.
+    pub fn new_random() -> Self {
+        Self::new(generate_replica_id())
+    }
+
+    /// Get the replica ID for this document.
+    pub fn replica_id(&self) -> ReplicaId {
+        self.replica_id
+    }
+
+    /// Set a map key or replace a list item. The last key names the slot; the
+    /// keys before it lead to the map or list holding it.
+    pub fn set(&mut self, path: &[Key], value: impl Into<DocValue>) -> bool {
+        let Some((last, parent)) = path.split_last() else {
+            return false;
+        };
+        let write = self.write(value.into());
+        match (resolve_mut(&mut self.root, parent), last) {
+            (Some(PlaceMut::Map(fields)), Key::Field(key)) => {
+                fields.entries.insert(key.clone(), Register::single(write));
+                true
+            }
+            (Some(PlaceMut::List(items)), Key::Index(index)) => match items.visible_mut(*index) {
+                Some(item) => {
+                    item.value = Register::single(write);
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Insert into the list at `path` so the value ends up at `index`.
+    pub fn insert(&mut self, path: &[Key], index: usize, value: impl Into<DocValue>) -> bool {
+        let write = self.write(value.into());
+        let Some(PlaceMut::List(items)) = resolve_mut(&mut self.root, path) else {
+            return false;
+        };
+        let origin = match index {
+            0 => None,
+            _ => match items.visible_id(index - 1) {
+                Some(id) => Some(id),
+                None => return false,
+            },
+        };
+        items.items.push(Item {
+            id: write.dot,
+            stamp: write.stamp,
+            origin,
+            value: Register::single(write),
+        });
+        true
+    }
+
+    /// Append to the end of the list at `path`.
+    pub fn push(&mut self, path: &[Key], value: impl Into<DocValue>) -> bool {
+        match resolve(&self.root, path) {
+            Some(Place::List(items)) => {
+                let len = items.len();
+                self.insert(path, len, value)
+            }
+            _ => false,
+        }
+    }
+
+    /// Remove a map key or a list item.
+    pub fn remove(&mut self, path: &[Key]) -> bool {
+        let Some((last, parent)) = path.split_last() else {
+            return false;
+        };
+        match (resolve_mut(&mut self.root, parent), last) {
+            (Some(PlaceMut::Map(fields)), Key::Field(key)) => fields.entries.remove(key).is_some(),
+            (Some(PlaceMut::List(items)), Key::Index(index)) => match items.visible_mut(*index) {
+                Some(item) => {
+                    item.value.writes.clear();
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// The value at `path`, taking the winning write wherever writes conflict.
+    pub fn get(&self, path: &[Key]) -> Option<DocValue> {
+        match path {
+            [] => Some(self.value()),
+            _ => register(&self.root, path)?.winner().map(|w| w.node.to_value()),
+        }
+    }
+
+    /// Every concurrent value at `path`, oldest stamp first. More than one
+    /// means writes conflicted; setting the path again resolves them.
+    pub fn conflicts(&self, path: &[Key]) -> Vec<DocValue> {
+        let Some(register) = register(&self.root, path) else {
+            return Vec::new();
+        };
+        let mut writes: Vec<&Write> = register.writes.iter().collect();
+        writes.sort_by_key(|w| w.stamp);
+        writes.into_iter().map(|w| w.node.to_value()).collect()
+    }
+
+    /// The number of keys or items in the map or list at `path`.
+    pub fn len(&self, path: &[Key]) -> Option<usize> {
+        match resolve(&self.root, path)? {
+            Place::Map(fields) => Some(fields.entries.len()),
+            Place::List(items) => Some(items.len()),
+        }
+    }
+
+    /// The whole document as a plain map.
+    pub fn value(&self) -> DocValue {
+        self.root.to_value()
+    }
+
+    /// Tag a new write, and every map entry and list item nested in it.
+    fn write(&mut self, value: DocValue) -> Write {
+        let dot = self.context.next(self.replica_id);
+        self.lamport += 1;
+        let stamp = Stamp { lamport: self.lamport, replica: self.replica_id };
+        let node = match value {
+            DocValue::Map(values) => Node::Map(Fields {
+                entries: values
+                    .into_iter()
+                    .map(|(key, value)| (key, Register::single(self.write(value))))
+                    .collect(),
+            }),
+            DocValue::List(values) => {
+                let mut items = Items::default();
+                let mut origin = None;
+                for value in values {
+                    let write = self.write(value);
+                    let id = write.dot;
+                    items.items.push(Item { id, stamp: write.stamp, origin, value: Register::single(write) });
+                    origin = Some(id);
+                }
+                Node::List(items)
+            }
+            scalar => Node::Value(scalar),
+        };
+        Write { dot, stamp, node }
+    }
+
+    fn join(&mut self, root: &Fields, context: &DotContext, lamport: u64) {
+        self.root.merge(root, &self.context, context);
+        self.context.merge(context);
+        self.lamport = self.lamport.max(lamport);
+    }
+}
+
+/// Follow `path` to the map or list it names.
+fn resolve<'a>(root: &'a Fields, path: &[Key]) -> Option<Place<'a>> {
+    let mut place = Place::Map(root);
+    for key in path {
+        let register = match (place, key) {
+            (Place::Map(fields), Key::Field(k)) => fields.entries.get(k)?,
+            (Place::List(items), Key::Index(i)) => &items.visible().nth(*i)?.value,
+            _ => return None,
+        };
+        place = match &register.winner()?.node {
+            Node::Map(fields) => Place::Map(fields),
+            Node::List(items) => Place::List(items),
+            Node::Value(_) => return None,
+        };
+    }
+    Some(place)
+}
+
+fn resolve_mut<'a>(root: &'a mut Fields, path: &[Key]) -> Option<PlaceMut<'a>> {
+    let mut place = PlaceMut::Map(root);
+    for key in path {
+        let register = match (place, key) {
+            (PlaceMut::Map(fields), Key::Field(k)) => fields.entries.get_mut(k)?,
+            (PlaceMut::List(items), Key::Index(i)) => &mut items.visible_mut(*i)?.value,
+            _ => return None,
+        };
+        place = match &mut register.winner_mut()?.node {
+            Node::Map(fields) => PlaceMut::Map(fields),
+            Node::List(items) => PlaceMut::List(items),
+            Node::Value(_) => return None,
+        };
+    }
+    Some(place)
+}
+
+/// The register for the key or item `path` ends on.
+fn register<'a>(root: &'a Fields, path: &[Key]) -> Option<&'a Register> {
+    let (last, parent) = path.split_last()?;
+    match (resolve(root, parent)?, last) {
+        (Place::Map(fields), Key::Field(key)) => fields.entries.get(key),
+        (Place::List(items), Key::Index(index)) => items.visible().nth(*index).map(|item| &item.value),
+        _ => None,
+    }
+}
+
+impl Merge for Document {
+    fn merge(&mut self, other: &Self) {
+        self.join(&other.root, &other.context, other.lamport);
+    }
+}
+
+impl DeltaCrdt for Document {
+    type Delta = DocumentDelta;
+
+    fn delta_since(&self, since: &VClock) -> Option<Self::Delta> {
+        let current = self.version();
+        if since.dominates(&current) {
+            return None;
+        }
+
+        Some(DocumentDelta {
+            root: self.root.clone(),
+            context: self.context.clone(),
+            lamport: self.lamport,
+        })
+    }
+
+    fn apply_delta(&mut self, delta: &Self::Delta) {
+        self.join(&delta.root, &delta.context, delta.lamport);
+    }
+
+    fn version(&self) -> VClock {
+        self.context.version()
+    }
+}
+
+impl Default for Document {
+    fn default() -> Self {
+        Self::new_random()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(title: &str, done: bool) -> DocValue {
+        DocValue::Map(BTreeMap::from([
+            ("title".to_string(), title.into()),
+            ("done".to_string(), done.into()),
+        ]))
+    }
+
+    fn board(replica: ReplicaId) -> Document {
+        let mut doc = Document::new(replica);
+        doc.set(&["cards".into()], DocValue::List(vec![card("a", false), card("b", false)]));
+        doc
+    }
+
+    fn titles(doc: &Document) -> Vec<DocValue> {
+        (0..doc.len(&["cards".into()]).unwrap())
+            .map(|i| doc.get(&["cards".into(), i.into(), "title".into()]).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_document_nested_set_and_get() {
+        let mut doc = Document::new(1);
+        assert!(doc.set(&["profile".into()], DocValue::Map(BTreeMap::new())));
+        assert!(doc.set(&["profile".into(), "name".into()], "Ada"));
+        assert_eq!(doc.get(&["profile".into(), "name".into()]), Some("Ada".into()));
+        assert!(!doc.set(&["missing".into(), "name".into()], "x"), "no map to write into");
+        assert!(!doc.set(&["profile".into(), "name".into(), "first".into()], "x"), "a value has no keys");
+    }
+
+    #[test]
+    fn test_document_list_insert_push_remove() {
+        let mut doc = board(1);
+        assert!(doc.push(&["cards".into()], card("c", false)));
+        assert!(doc.insert(&["cards".into()], 0, card("z", false)));
+        assert!(doc.remove(&["cards".into(), 1.into()]));
+        assert_eq!(titles(&doc), vec!["z".into(), "b".into(), "c".into()]);
+        assert!(!doc.insert(&["cards".into()], 9, card("y", false)));
+    }
+
+    #[test]
+    fn test_document_struct_fields_merge_independently() {
+        let mut a = board(1);
+        let mut b = Document::new(2);
+        b.merge(&a);
+
+        a.set(&["cards".into(), 0.into(), "done".into()], true);
+        b.set(&["cards".into(), 0.into(), "title".into()], "renamed");
+        a.merge(&b);
+        b.merge(&a);
+
+        assert_eq!(a.value(), b.value());
+        assert_eq!(a.get(&["cards".into(), 0.into()]), Some(card("renamed", true)));
+    }
+
+    #[test]
+    fn test_document_concurrent_inserts_converge() {
+        let mut a = board(1);
+        let mut b = Document::new(2);
+        b.merge(&a);
+
+        a.insert(&["cards".into()], 1, card("from-a", false));
+        b.insert(&["cards".into()], 1, card("from-b", false));
+        b.push(&["cards".into()], card("last", false));
+        let mut ab = a.clone();
+        ab.merge(&b);
+        let mut ba = b.clone();
+        ba.merge(&a);
+
+        assert_eq!(ab.value(), ba.value());
+        let order = titles(&ab);
+        assert_eq!(order.len(), 5);
+        assert_eq!((order[0].clone(), order[4].clone()), ("a".into(), "last".into()));
+    }
+
+    #[test]
+    fn test_document_conflicting_writes_are_kept() {
+        let mut a = Document::new(1);
+        let mut b = Document::new(2);
+        a.set(&["title".into()], "from-a");
+        b.set(&["title".into()], "from-b");
+        a.merge(&b);
+        b.merge(&a);
+
+        assert_eq!(a.conflicts(&["title".into()]).len(), 2);
+        assert_eq!(a.get(&["title".into()]), b.get(&["title".into()]));
+
+        a.set(&["title".into()], "resolved");
+        b.merge(&a);
+        assert_eq!(b.conflicts(&["title".into()]), vec![DocValue::from("resolved")]);
+    }
+
+    #[test]
+    fn test_document_remove_loses_to_concurrent_set() {
+        let mut a = board(1);
+        let mut b = Document::new(2);
+        b.merge(&a);
+
+        a.remove(&["cards".into(), 0.into()]);
+        b.set(&["cards".into(), 0.into()], card("kept", true));
+        a.merge(&b);
+
+        assert_eq!(titles(&a), vec!["kept".into(), "b".into()]);
+    }
+
+    #[test]
+    fn test_document_removed_keys_stay_removed() {
+        let mut a = Document::new(1);
+        a.set(&["draft".into()], "text");
+        let mut b = Document::new(2);
+        b.merge(&a);
+
+        a.remove(&["draft".into()]);
+        a.merge(&b);
+        b.merge(&a);
+        assert_eq!(a.get(&["draft".into()]), None);
+        assert_eq!(b.get(&["draft".into()]), None);
+    }
+
+    #[test]
+    fn test_document_merge_idempotent_and_delta() {
+        let mut a = board(1);
+        let before = a.value();
+        let copy = a.clone();
+        a.merge(&copy);
+        assert_eq!(a.value(), before);
+
+        let mut b = Document::new(2);
+        let since = b.version();
+        b.apply_delta(&a.delta_since(&since).unwrap());
+        assert_eq!(b.value(), before);
+        assert!(a.delta_since(&a.version()).is_none());
+    }
+}
@@ -7,7 +7,7 @@
 //! Uses `u64` replica IDs for efficient vector clock operations.
 
 use super::replica::{generate_replica_id, ReplicaId};
-use super::Merge;
+use super::{AuthoredMerge, Merge};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -76,6 +76,17 @@ impl Merge for GCounter {
     }
 }
 
+impl AuthoredMerge for GCounter {
+    /// Take only `author`'s own count: nobody can increment on another
+    /// replica's behalf.
+    fn merge_authored(&mut self, other: &Self, author: ReplicaId) {
+        if let Some(&count) = other.counts.get(&author) {
+            let entry = self.counts.entry(author).or_insert(0);
+            *entry = (*entry).max(count);
+        }
+    }
+}
+
 // NOTE: Showable impl is in logicaffeine_system (io module)
 
 /// Compare a GCounter directly to a `u64` value.
@@ -167,4 +178,18 @@ mod tests {
         c1.merge(&c2);
         assert_eq!(c1.value(), 5);
     }
+
+    #[test]
+    fn test_gcounter_merge_authored_ignores_claims_for_others() {
+        let mut honest = GCounter::with_replica_id(1);
+        honest.increment(2);
+
+        // Replica 2 inflates replica 1's count along with its own
+        let mut forged = GCounter::with_replica_id(2);
+        forged.increment(3);
+        forged.counts.insert(1, 1_000);
+
+        honest.merge_authored(&forged, 2);
+        assert_eq!(honest.value(), 5);
+    }
 }
@@ -1,5 +1,7 @@
 //! The Merge trait for CRDTs
 
+use super::replica::ReplicaId;
+
 /// A type that can be merged with another instance of itself.
 ///
 /// The merge operation must satisfy CRDT properties:
@@ -15,3 +17,22 @@ pub trait Merge {
     /// After merging, `self` contains the combined state of both instances.
     fn merge(&mut self, other: &Self);
 }
+
+/// A CRDT that can merge state from an authenticated author, keeping only
+/// what that author could have written.
+///
+/// State carries every replica's contribution, so a peer relaying it could
+/// claim writes on another replica's behalf, or claim to have seen (and
+/// removed) writes that other replicas haven't made yet. Once a sync layer
+/// has authenticated the sender, it merges through
+/// [`merge_authored`](AuthoredMerge::merge_authored) instead of
+/// [`merge`](Merge::merge).
+///
+/// Only types that record which replica wrote what can implement it. Signed
+/// sync requires this trait, so a type that can't tell authors apart can't
+/// be synced as if it were protected.
+pub trait AuthoredMerge: Merge {
+    /// Merge state that `author` sent, keeping only what `author` could have
+    /// written.
+    fn merge_authored(&mut self, other: &Self, author: ReplicaId);
+}
@@ -26,6 +26,7 @@ mod mvregister;
 // Complex CRDTs
 mod orset;
 mod ormap;
+mod document;
 pub mod sequence;
 
 // NOTE: sync.rs (Synced<T>) is NOT in this crate - it's in logicaffeine_system
@@ -33,7 +34,7 @@ pub mod sequence;
 
 pub use gcounter::GCounter;
 pub use lww::LWWRegister;
-pub use merge::Merge;
+pub use merge::{AuthoredMerge, Merge};
 
 // Replica utilities
 pub use replica::{generate_replica_id, ReplicaId};
@@ -52,4 +53,5 @@ pub use mvregister::MVRegister;
 // Complex CRDTs
 pub use orset::{AddWins, ORSet, RemoveWins, SetBias};
 pub use ormap::ORMap;
+pub use document::{DocValue, Document, DocumentDelta, Key};
 pub use sequence::{RGA, YATA};
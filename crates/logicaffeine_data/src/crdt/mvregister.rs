@@ -6,7 +6,7 @@
 use super::causal::VClock;
 use super::delta::DeltaCrdt;
 use super::replica::{generate_replica_id, ReplicaId};
-use super::Merge;
+use super::{AuthoredMerge, Merge};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
@@ -70,6 +70,14 @@ impl<T: Clone + PartialEq> MVRegister<T> {
         self.values.iter().map(|(v, _)| v).collect()
     }
 
+    /// Each current value with the vector clock of the write that produced it.
+    ///
+    /// Concurrent values have clocks neither of which dominates the other;
+    /// each clock shows which replicas' writes that value had already seen.
+    pub fn versions(&self) -> Vec<(&T, &VClock)> {
+        self.values.iter().map(|(v, clock)| (v, clock)).collect()
+    }
+
     /// Resolve a conflict by setting a new value.
     ///
     /// This is the same as `set`, but semantically indicates conflict resolution.
@@ -116,6 +124,33 @@ impl<T: Clone + PartialEq> Merge for MVRegister<T> {
     }
 }
 
+impl<T: Clone + PartialEq> AuthoredMerge for MVRegister<T> {
+    /// Take only values whose clocks `author` can vouch for: beyond its own
+    /// entry, a clock may not claim more of another replica's writes than this
+    /// register has seen. Otherwise an author could pass off a write as
+    /// another replica's, or overwrite writes that haven't reached us yet.
+    ///
+    /// A write over values we haven't seen yet is taken once they arrive and
+    /// the author next publishes.
+    fn merge_authored(&mut self, other: &Self, author: ReplicaId) {
+        let mut seen = VClock::new();
+        for (_, clock) in &self.values {
+            seen.merge_vclock(clock);
+        }
+        for (_, clock) in &other.values {
+            seen.merge_vclock(&clock.restricted_to(author));
+        }
+
+        let values = other
+            .values
+            .iter()
+            .filter(|(_, clock)| seen.dominates(clock))
+            .cloned()
+            .collect();
+        self.merge(&MVRegister { values, replica_id: other.replica_id });
+    }
+}
+
 impl<T: Clone + PartialEq + Serialize + DeserializeOwned + Send + 'static> DeltaCrdt
     for MVRegister<T>
 {
@@ -206,6 +241,27 @@ mod tests {
         assert_eq!(a.values().len(), 2);
     }
 
+    #[test]
+    fn test_mvregister_versions_show_each_writer() {
+        let mut a: MVRegister<String> = MVRegister::new(1);
+        let mut b: MVRegister<String> = MVRegister::new(2);
+
+        a.set("first".to_string());
+        b.merge(&a);
+        b.set("from-b".to_string());
+        a.set("from-a".to_string());
+        a.merge(&b);
+
+        let versions = a.versions();
+        assert_eq!(versions.len(), 2);
+        let (_, clock_a) = versions.iter().find(|(v, _)| *v == "from-a").unwrap();
+        let (_, clock_b) = versions.iter().find(|(v, _)| *v == "from-b").unwrap();
+        assert!(clock_a.concurrent(clock_b));
+        // Both writers had seen the first write
+        assert_eq!(clock_b.get(1), 1);
+        assert_eq!(clock_a.get(1), 2);
+    }
+
     #[test]
     fn test_mvregister_resolve() {
         let mut a: MVRegister<String> = MVRegister::new(1);
@@ -219,4 +275,41 @@ mod tests {
         assert_eq!(a.values().len(), 1);
         assert_eq!(a.values()[0], &"resolved".to_string());
     }
+
+    #[test]
+    fn test_mvregister_merge_authored_rejects_forged_clocks() {
+        let mut alice: MVRegister<String> = MVRegister::new(1);
+        alice.set("first".to_string());
+        let mut local: MVRegister<String> = MVRegister::new(3);
+        local.merge(&alice);
+
+        // Replica 2 claims a write that already saw alice's next fifty
+        let mut forged: MVRegister<String> = MVRegister::new(2);
+        let mut clock = VClock::new();
+        for _ in 0..50 {
+            clock.increment(1);
+        }
+        clock.increment(2);
+        forged.values = vec![("forged".to_string(), clock)];
+        local.merge_authored(&forged, 2);
+        assert_eq!(local.values(), vec![&"first".to_string()]);
+
+        alice.set("second".to_string());
+        local.merge_authored(&alice, 1);
+        assert_eq!(local.values(), vec![&"second".to_string()]);
+    }
+
+    #[test]
+    fn test_mvregister_merge_authored_takes_observed_overwrites() {
+        let mut alice: MVRegister<String> = MVRegister::new(1);
+        alice.set("first".to_string());
+        let mut bob: MVRegister<String> = MVRegister::new(2);
+        bob.merge(&alice);
+        let mut local: MVRegister<String> = MVRegister::new(3);
+        local.merge(&alice);
+
+        bob.set("overwritten".to_string());
+        local.merge_authored(&bob, 2);
+        assert_eq!(local.values(), vec![&"overwritten".to_string()]);
+    }
 }
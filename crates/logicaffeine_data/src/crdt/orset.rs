@@ -6,7 +6,7 @@
 use super::causal::{Dot, DotContext, VClock};
 use super::delta::DeltaCrdt;
 use super::replica::{generate_replica_id, ReplicaId};
-use super::Merge;
+use super::{AuthoredMerge, Merge};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -267,6 +267,36 @@ impl<T: Hash + Eq + Clone, B: SetBias> Merge for ORSet<T, B> {
     }
 }
 
+impl<T: Hash + Eq + Clone, B: SetBias> AuthoredMerge for ORSet<T, B> {
+    /// Take `author`'s own adds, and only the removals of adds this replica
+    /// has seen: an author can neither add under another replica's dots nor
+    /// remove writes that haven't reached us, including ones not yet made.
+    ///
+    /// A removal of a write we haven't seen yet is taken once the write
+    /// arrives and the author next publishes.
+    fn merge_authored(&mut self, other: &Self, author: ReplicaId) {
+        let entries = other
+            .entries
+            .iter()
+            .map(|(value, dots)| {
+                let vouched = dots
+                    .iter()
+                    .filter(|dot| dot.replica == author || self.context.has_seen(dot))
+                    .copied()
+                    .collect();
+                (value.clone(), vouched)
+            })
+            .collect();
+        let vouched = ORSet {
+            entries,
+            context: other.context.restricted_to(author, &self.context),
+            replica_id: other.replica_id,
+            _bias: PhantomData,
+        };
+        self.merge(&vouched);
+    }
+}
+
 impl<T: Hash + Eq + Clone + Serialize + DeserializeOwned + Send + 'static, B: SetBias> DeltaCrdt
     for ORSet<T, B>
 {
@@ -371,4 +401,54 @@ mod tests {
 
         assert_eq!(a1.len(), b1.len());
     }
+
+    #[test]
+    fn test_orset_merge_authored_ignores_adds_for_others() {
+        let mut local: ORSet<String> = ORSet::new(1);
+
+        // Replica 2 slips in an add under replica 3's name
+        let mut forged: ORSet<String> = ORSet::new(2);
+        forged.add("mine".to_string());
+        forged.entries.entry("theirs".to_string()).or_default().insert(Dot::new(3, 1));
+        forged.context.add(Dot::new(3, 1));
+
+        local.merge_authored(&forged, 2);
+        assert!(local.contains(&"mine".to_string()));
+        assert!(!local.contains(&"theirs".to_string()));
+    }
+
+    #[test]
+    fn test_orset_merge_authored_rejects_forged_removals() {
+        let mut alice: ORSet<String> = ORSet::new(1);
+        alice.add("a".to_string());
+        let mut local: ORSet<String> = ORSet::new(3);
+        local.merge(&alice);
+
+        // Replica 2 claims to have seen (and removed) alice's next hundred adds
+        let mut forged: ORSet<String> = ORSet::new(2);
+        forged.merge(&alice);
+        for _ in 0..100 {
+            forged.context.next(1);
+        }
+        local.merge_authored(&forged, 2);
+        assert!(local.contains(&"a".to_string()));
+
+        alice.add("b".to_string());
+        local.merge_authored(&alice, 1);
+        assert!(local.contains(&"b".to_string()));
+    }
+
+    #[test]
+    fn test_orset_merge_authored_takes_observed_removals() {
+        let mut alice: ORSet<String> = ORSet::new(1);
+        alice.add("a".to_string());
+        let mut bob: ORSet<String> = ORSet::new(2);
+        bob.merge(&alice);
+        let mut local: ORSet<String> = ORSet::new(3);
+        local.merge(&alice);
+
+        bob.remove(&"a".to_string());
+        local.merge_authored(&bob, 2);
+        assert!(!local.contains(&"a".to_string()));
+    }
 }
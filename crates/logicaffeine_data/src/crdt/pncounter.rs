@@ -6,7 +6,7 @@
 use super::causal::VClock;
 use super::delta::DeltaCrdt;
 use super::replica::{generate_replica_id, ReplicaId};
-use super::Merge;
+use super::{AuthoredMerge, Merge};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -98,6 +98,21 @@ impl Merge for PNCounter {
     }
 }
 
+impl AuthoredMerge for PNCounter {
+    /// Take only `author`'s own increments and decrements.
+    fn merge_authored(&mut self, other: &Self, author: ReplicaId) {
+        if let Some(&count) = other.increments.get(&author) {
+            let entry = self.increments.entry(author).or_insert(0);
+            *entry = (*entry).max(count);
+        }
+        if let Some(&count) = other.decrements.get(&author) {
+            let entry = self.decrements.entry(author).or_insert(0);
+            *entry = (*entry).max(count);
+        }
+        self.version.merge_vclock(&other.version.restricted_to(author));
+    }
+}
+
 impl DeltaCrdt for PNCounter {
     type Delta = PNCounterDelta;
 
@@ -207,4 +222,20 @@ mod tests {
 
         assert_eq!(a1.value(), b1.value());
     }
+
+    #[test]
+    fn test_pncounter_merge_authored_takes_only_the_authors_part() {
+        let mut local = PNCounter::with_replica_id(1);
+        local.increment(4);
+
+        let mut remote = PNCounter::with_replica_id(2);
+        remote.increment(1);
+        remote.decrement(3);
+        // A forged decrement on replica 1's behalf
+        remote.decrements.insert(1, 4);
+
+        local.merge_authored(&remote, 2);
+        assert_eq!(local.value(), 2);
+        assert_eq!(local.version().get(2), remote.version().get(2));
+    }
 }
@@ -7,4 +7,4 @@ mod rga;
 mod yata;
 
 pub use rga::RGA;
-pub use yata::YATA;
+pub use yata::{YATADelta, YataId, YataItem, YATA};
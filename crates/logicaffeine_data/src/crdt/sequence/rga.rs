@@ -89,6 +89,11 @@ impl<T: Clone + PartialEq> RGA<T> {
         Self::new(generate_replica_id())
     }
 
+    /// Get the replica ID for this sequence.
+    pub fn replica_id(&self) -> ReplicaId {
+        self.replica_id
+    }
+
     /// Append a value to the end of the sequence.
     pub fn append(&mut self, value: T) {
         self.timestamp += 1;
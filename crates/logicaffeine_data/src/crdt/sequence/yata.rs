@@ -10,6 +10,7 @@ use crate::crdt::Merge;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 
 /// Delta for YATA synchronization.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -137,6 +138,53 @@ impl<T: Clone + PartialEq> YATA<T> {
         }
     }
 
+    /// Insert `values` as one run starting at the given index.
+    ///
+    /// Each value is anchored to the one before it, so concurrent edits never
+    /// land inside the run. Returns the delta carrying the run to other replicas.
+    pub fn insert_run(&mut self, index: usize, values: impl IntoIterator<Item = T>) -> YATADelta<T> {
+        let visible: Vec<YataId> = self.visible_items().map(|i| i.id).collect();
+        let index = index.min(visible.len());
+        let mut origin_left = index.checked_sub(1).map(|i| visible[i]);
+        let origin_right = visible.get(index).copied();
+
+        let start = self.items.len();
+        for value in values {
+            self.clock += 1;
+            let id = YataId::new(self.clock, self.replica_id);
+            self.items.push(YataItem {
+                id,
+                value,
+                deleted: false,
+                origin_left,
+                origin_right,
+            });
+            origin_left = Some(id);
+        }
+        YATADelta {
+            items: self.items[start..].to_vec(),
+            clock: self.clock,
+        }
+    }
+
+    /// Remove `len` elements starting at the given index.
+    ///
+    /// Returns the delta carrying the removal to other replicas.
+    pub fn remove_range(&mut self, index: usize, len: usize) -> YATADelta<T> {
+        let doomed: HashSet<YataId> = self.visible_items().skip(index).take(len).map(|i| i.id).collect();
+        let mut items = Vec::with_capacity(doomed.len());
+        for item in &mut self.items {
+            if doomed.contains(&item.id) {
+                item.deleted = true;
+                items.push(item.clone());
+            }
+        }
+        YATADelta {
+            items,
+            clock: self.clock,
+        }
+    }
+
     /// Remove the element at the given index.
     pub fn remove(&mut self, index: usize) {
         if let Some(id) = self.visible_id_at(index) {
@@ -171,6 +219,30 @@ impl<T: Clone + PartialEq> YATA<T> {
         self.visible_items().map(|i| &i.value)
     }
 
+    /// The ID of the element at the given index.
+    ///
+    /// Unlike the index, the ID keeps naming the same element while other
+    /// replicas insert and remove around it.
+    pub fn id_at(&self, index: usize) -> Option<YataId> {
+        self.visible_id_at(index)
+    }
+
+    /// The index just after the element `id`, or `None` if it is unknown.
+    ///
+    /// A removed element still marks the place where it was.
+    pub fn index_after(&self, id: YataId) -> Option<usize> {
+        let mut visible = 0;
+        for item in self.sorted_items() {
+            if !item.deleted {
+                visible += 1;
+            }
+            if item.id == id {
+                return Some(visible);
+            }
+        }
+        None
+    }
+
     /// Get sorted visible items.
     fn visible_items(&self) -> impl Iterator<Item = &YataItem<T>> {
         self.sorted_items().into_iter().filter(|i| !i.deleted)
@@ -178,32 +250,25 @@ impl<T: Clone + PartialEq> YATA<T> {
 
     /// Sort items according to YATA rules.
     fn sorted_items(&self) -> Vec<&YataItem<T>> {
-        let mut result: Vec<&YataItem<T>> = Vec::new();
+        let mut result: Vec<&YataItem<T>> = Vec::with_capacity(self.items.len());
 
-        // Start with items that have no left origin
-        let mut to_process: Vec<&YataItem<T>> = self
-            .items
-            .iter()
-            .filter(|i| i.origin_left.is_none())
-            .collect();
+        // Group items by their left origin; `None` holds the items that start the sequence
+        let mut followers: HashMap<Option<YataId>, Vec<&YataItem<T>>> = HashMap::new();
+        for item in &self.items {
+            followers.entry(item.origin_left).or_default().push(item);
+        }
 
         // Sort ascending so pop() gives higher IDs first (later inserts appear first)
-        to_process.sort_by(|a, b| a.id.cmp(&b.id));
+        for group in followers.values_mut() {
+            group.sort_by(|a, b| a.id.cmp(&b.id));
+        }
 
+        let mut to_process = followers.remove(&None).unwrap_or_default();
         while let Some(item) = to_process.pop() {
             result.push(item);
-
-            // Find items whose origin_left is this item
-            let mut followers: Vec<&YataItem<T>> = self
-                .items
-                .iter()
-                .filter(|i| i.origin_left == Some(item.id))
-                .collect();
-
-            // Sort ascending so pop() gives higher IDs first
-            followers.sort_by(|a, b| a.id.cmp(&b.id));
-
-            to_process.extend(followers);
+            if let Some(group) = followers.remove(&Some(item.id)) {
+                to_process.extend(group);
+            }
         }
 
         result
@@ -314,4 +379,37 @@ mod tests {
 
         assert_eq!(a.to_vec(), b.to_vec());
     }
+
+    #[test]
+    fn test_yata_run_deltas_converge() {
+        let mut a: YATA<char> = YATA::new(1);
+        let mut b: YATA<char> = YATA::new(2);
+        b.apply_delta(&a.insert_run(0, "held".chars()));
+
+        // Concurrent runs at the same place stay contiguous
+        let from_a = a.insert_run(2, "AA".chars());
+        let from_b = b.insert_run(2, "BB".chars());
+        a.apply_delta(&from_b);
+        b.apply_delta(&from_a);
+        let text: String = a.iter().collect();
+        assert_eq!(text, b.iter().collect::<String>());
+        assert!(text == "heAABBld" || text == "heBBAAld", "{text}");
+
+        b.apply_delta(&a.remove_range(0, 2));
+        assert_eq!(a.to_vec(), b.to_vec());
+        assert_eq!(a.len(), 6);
+    }
+
+    #[test]
+    fn test_yata_ids_follow_their_element() {
+        let mut seq: YATA<char> = YATA::new(1);
+        seq.insert_run(0, "abc".chars());
+        let b = seq.id_at(1).unwrap();
+        assert_eq!(seq.index_after(b), Some(2));
+
+        seq.insert_run(0, "xy".chars());
+        assert_eq!(seq.index_after(b), Some(4));
+        seq.remove_range(3, 1);
+        assert_eq!(seq.index_after(b), Some(3), "a removed element keeps its place");
+    }
 }
@@ -5,13 +5,17 @@ pub mod crdt;
 pub mod fmt;
 pub mod indexing;
 pub mod ops;
+pub mod pattern;
+pub mod sort;
+pub mod text;
 pub mod types;
 pub mod wire;
 
 // Re-export commonly used types
 pub use crdt::{
-    generate_replica_id, AddWins, DeltaBuffer, DeltaCrdt, Dot, DotContext, GCounter, LWWRegister,
-    MVRegister, Merge, ORMap, ORSet, PNCounter, RemoveWins, ReplicaId, SetBias, VClock, RGA, YATA,
+    generate_replica_id, AddWins, AuthoredMerge, DeltaBuffer, DeltaCrdt, Dot, DotContext, GCounter,
+    LWWRegister, MVRegister, Merge, ORMap, ORSet, PNCounter, RemoveWins, ReplicaId, SetBias, VClock,
+    RGA, YATA,
 };
 pub use types::{
     Bool, Byte, Char, FillClone, FxIndexMap, FxIndexSet, Int, LogosContains, LogosDenseI64Map, LogosDenseI64MapNoPresence,
@@ -22,4 +26,10 @@ pub use types::{
 };
 pub use rustc_hash::{FxHashMap, FxHashSet};
 pub use indexing::{LogosGetChar, LogosIndex, LogosIndexMut};
+pub use pattern::{pattern_matches, LogosPattern};
+pub use sort::{largest_of, smallest_of, sorted_by};
+pub use text::{
+    text_ends_with, text_find, text_join, text_lines, text_lowercase, text_replace, text_split,
+    text_starts_with, text_trim, text_uppercase, text_words, TextNeedle,
+};
 pub use ops::{logos_add_exact, logos_add_i64, logos_approx_eq, logos_cmp_i64_f64, logos_div_exact, logos_div_i128, logos_div_i64, logos_floordiv_exact, logos_i64_eq_f64, logos_i64_key_of_f64, logos_mul_exact, logos_mul_i64, logos_narrow_i128, logos_pow_exact, logos_rem_exact, logos_rem_i128, logos_rem_i64, logos_sub_exact, logos_sub_i64, logos_truthy, Truthy};
@@ -0,0 +1,181 @@
+//! `LogosPattern` — the runtime value of the imperative `Pattern` type.
+//!
+//! Every pattern is parsed by [`logicaffeine_base::pattern`], the one authority on the dialect.
+//! Natively the parsed pattern's canonical syntax is compiled by the `regex` crate; on wasm the
+//! base crate's backtracker runs instead, so the web bundle carries no regex engine. Iteration
+//! and `$name` template expansion are shared, so `replace` and `capture` agree on every tier.
+//!
+//! The value is an `Rc` handle: copying a pattern into a loop never recompiles it.
+
+use std::fmt;
+use std::rc::Rc;
+
+use logicaffeine_base::pattern::{self, GroupSpans, Pattern};
+
+struct Compiled {
+    parsed: Pattern,
+    #[cfg(not(target_arch = "wasm32"))]
+    regex: regex::Regex,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Compiled {
+    fn build(parsed: Pattern) -> Result<Self, String> {
+        let regex = regex::Regex::new(parsed.to_regex_syntax()).map_err(|e| e.to_string())?;
+        Ok(Compiled { parsed, regex })
+    }
+
+    fn is_match(&self, text: &str) -> bool {
+        self.regex.is_match(text)
+    }
+
+    fn captures_at(&self, text: &str, from: usize) -> Option<GroupSpans> {
+        self.regex
+            .captures_at(text, from)
+            .map(|caps| (0..caps.len()).map(|i| caps.get(i).map(|m| (m.start(), m.end()))).collect())
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Compiled {
+    fn build(parsed: Pattern) -> Result<Self, String> {
+        Ok(Compiled { parsed })
+    }
+
+    fn is_match(&self, text: &str) -> bool {
+        self.parsed.is_match(text)
+    }
+
+    fn captures_at(&self, text: &str, from: usize) -> Option<GroupSpans> {
+        self.parsed.captures_at(text, from)
+    }
+}
+
+/// A compiled pattern (`the pattern "(?<key>\w+)=(?<value>\d+)"`).
+#[derive(Clone)]
+pub struct LogosPattern(Rc<Compiled>);
+
+impl LogosPattern {
+    /// Compile `source`, or explain why it is not a pattern.
+    pub fn new(source: &str) -> Result<Self, String> {
+        Pattern::parse(source)
+            .map_err(|e| e.to_string())
+            .and_then(Compiled::build)
+            .map(|compiled| LogosPattern(Rc::new(compiled)))
+            .map_err(|e| format!("invalid pattern \"{}\": {}", source, e))
+    }
+
+    /// `pattern(source)` (AOT). Literals are validated at compile time, so this only fails on a
+    /// pattern built from runtime text.
+    pub fn compile(source: &str) -> Self {
+        Self::new(source).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// The pattern as it was written.
+    pub fn source(&self) -> &str {
+        self.0.parsed.source()
+    }
+
+    /// The named groups, in the order they open.
+    pub fn named_groups(&self) -> impl Iterator<Item = &str> {
+        self.0.parsed.named_groups()
+    }
+
+    /// Does the pattern match anywhere in `text`?
+    pub fn is_match(&self, text: &str) -> bool {
+        self.0.is_match(text)
+    }
+
+    /// The first match at or after byte `from`, with every group's byte span.
+    pub fn captures_at(&self, text: &str, from: usize) -> Option<GroupSpans> {
+        self.0.captures_at(text, from)
+    }
+
+    /// Replace every match in `text` with `template` (`$1`, `${name}`, `$$`).
+    pub fn replace_all(&self, text: &str, template: &str) -> String {
+        pattern::replace_all_with(text, template, self.0.parsed.group_names(), |at| self.captures_at(text, at))
+    }
+
+    /// The first match as record fields: whether it matched, the matched text, and the text of
+    /// each group in `names` — `""` for a group that did not take part (or when nothing matched).
+    pub fn capture_fields(&self, text: &str, names: &[&str]) -> (bool, String, Vec<String>) {
+        let Some(groups) = self.captures_at(text, 0) else {
+            return (false, String::new(), vec![String::new(); names.len()]);
+        };
+        let slice = |span: Option<(usize, usize)>| span.map_or(String::new(), |(s, e)| text[s..e].to_string());
+        let group_names = self.0.parsed.group_names();
+        let fields = names
+            .iter()
+            .map(|name| {
+                group_names
+                    .iter()
+                    .position(|n| n.as_deref() == Some(*name))
+                    .map(|i| slice(groups[i]))
+                    .unwrap_or_default()
+            })
+            .collect();
+        (true, slice(groups[0]), fields)
+    }
+}
+
+impl PartialEq for LogosPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.source() == other.source()
+    }
+}
+
+impl fmt::Display for LogosPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.source())
+    }
+}
+
+impl fmt::Debug for LogosPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LogosPattern({:?})", self.source())
+    }
+}
+
+/// `pattern_matches(text, pattern)` (AOT).
+pub fn pattern_matches(text: &str, pattern: &LogosPattern) -> bool {
+    pattern.is_match(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn native_engine_agrees_with_the_portable_one() {
+        let cases = [
+            (r"(?<k>\w+)=(?<v>\d+)", "a=1; bb=22; c=x"),
+            (r"a*", "baaac"),
+            (r"(a|ab)(c|bcd)(d*)", "abcd"),
+            (r"[^\s,]+", " one, two  three "),
+            (r"(x)?y", "y xy"),
+            (r"h.llo$", "say héllo"),
+        ];
+        for (source, text) in cases {
+            let native = LogosPattern::new(source).unwrap();
+            let portable = Pattern::parse(source).unwrap();
+            assert_eq!(native.captures_at(text, 0), portable.captures_at(text, 0), "{}", source);
+            assert_eq!(native.replace_all(text, "<$0>"), portable.replace_all(text, "<$0>"), "{}", source);
+        }
+    }
+
+    #[test]
+    fn capture_fields_fill_missing_groups_with_empty_text() {
+        let p = LogosPattern::new(r"(?<year>\d{4})(-(?<month>\d\d))?").unwrap();
+        assert_eq!(
+            p.capture_fields("in 2024!", &["year", "month"]),
+            (true, "2024".to_string(), vec!["2024".to_string(), String::new()])
+        );
+        assert_eq!(p.capture_fields("none", &["year"]), (false, String::new(), vec![String::new()]));
+    }
+
+    #[test]
+    fn invalid_patterns_report_the_source() {
+        let err = LogosPattern::new("(oops").unwrap_err();
+        assert_eq!(err, "invalid pattern \"(oops\": unclosed group (at character 1)");
+    }
+}
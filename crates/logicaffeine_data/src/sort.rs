@@ -0,0 +1,75 @@
+//! Sorting behind the `Sort` sentence and `the largest/smallest N items of`.
+//!
+//! `Sort people by age descending.` desugars to `sorted_by(people, "age", true)`;
+//! the AOT binary calls the functions here with a key closure (`|p| p.age.clone()`,
+//! or `|p| p.clone()` for the natural order). Sorts are STABLE in both directions —
+//! equal keys keep their original relative order, so sorting by one field after
+//! another composes — and an unordered pair (a NaN key) counts as equal, exactly
+//! like the interpreter's `semantics::collections::sorted`.
+
+use std::cmp::Ordering;
+
+use crate::types::LogosSeq;
+
+/// A sorted copy of `seq`, ordered by `key` (ascending unless `descending`).
+pub fn sorted_by<T: Clone, K: PartialOrd>(
+    seq: &LogosSeq<T>,
+    key: impl Fn(&T) -> K,
+    descending: bool,
+) -> LogosSeq<T> {
+    let mut items = seq.to_vec();
+    items.sort_by(|a, b| {
+        let ord = key(a).partial_cmp(&key(b)).unwrap_or(Ordering::Equal);
+        if descending { ord.reverse() } else { ord }
+    });
+    LogosSeq::from_vec(items)
+}
+
+/// The `n` items with the largest keys, largest first; ties keep their order in `seq`.
+pub fn largest_of<T: Clone, K: PartialOrd>(seq: &LogosSeq<T>, n: i64, key: impl Fn(&T) -> K) -> LogosSeq<T> {
+    take_front(sorted_by(seq, key, true), n)
+}
+
+/// The `n` items with the smallest keys, smallest first; ties keep their order in `seq`.
+pub fn smallest_of<T: Clone, K: PartialOrd>(seq: &LogosSeq<T>, n: i64, key: impl Fn(&T) -> K) -> LogosSeq<T> {
+    take_front(sorted_by(seq, key, false), n)
+}
+
+/// The first `n` items (all of them when there are fewer; none for `n <= 0`).
+fn take_front<T>(seq: LogosSeq<T>, n: i64) -> LogosSeq<T> {
+    seq.borrow_mut().truncate(n.max(0) as usize);
+    seq
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_is_stable_in_both_directions() {
+        let people = LogosSeq::from_vec(vec![("ann", 30), ("bob", 25), ("cy", 30), ("di", 25)]);
+        let up = sorted_by(&people, |p| p.1, false);
+        assert_eq!(up.to_vec(), [("bob", 25), ("di", 25), ("ann", 30), ("cy", 30)]);
+        let down = sorted_by(&people, |p| p.1, true);
+        assert_eq!(down.to_vec(), [("ann", 30), ("cy", 30), ("bob", 25), ("di", 25)]);
+        // The input is left untouched.
+        assert_eq!(people.to_vec()[0], ("ann", 30));
+    }
+
+    #[test]
+    fn largest_and_smallest_take_the_front_of_the_order() {
+        let scores = LogosSeq::from_vec(vec![4.5, 9.0, 1.0, 9.0, 7.25]);
+        assert_eq!(largest_of(&scores, 3, |s| *s).to_vec(), [9.0, 9.0, 7.25]);
+        assert_eq!(smallest_of(&scores, 2, |s| *s).to_vec(), [1.0, 4.5]);
+        assert_eq!(largest_of(&scores, 10, |s| *s).len(), 5);
+        assert!(smallest_of(&scores, -1, |s| *s).is_empty());
+    }
+
+    #[test]
+    fn nan_keys_compare_equal() {
+        let xs = LogosSeq::from_vec(vec![2.0, f64::NAN, 1.0]);
+        let sorted = sorted_by(&xs, |x| *x, false).to_vec();
+        assert_eq!(sorted.len(), 3);
+        assert!(sorted.iter().any(|x| x.is_nan()));
+    }
+}
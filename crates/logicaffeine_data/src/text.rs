@@ -0,0 +1,198 @@
+//! The ONE text-processing path.
+//!
+//! The prose text sentences — `Split the line by commas into fields.`,
+//! `the uppercase of name`, `line with "a" replaced by "b"`, … — desugar to the
+//! `text_*` builtins. The tree-walker and VM (`semantics::builtins`) and the
+//! AOT-compiled binary (via `use logicaffeine_data::*`) both call the functions
+//! here, so a program splits, trims and searches identically on every engine.
+//!
+//! Positions are 1-based and counted in characters, like text indexing
+//! (`item 1 of s`); `0` means "not found".
+
+use std::fmt::Display;
+
+use crate::pattern::LogosPattern;
+use crate::types::LogosSeq;
+
+/// Split on every occurrence of `sep`. An empty separator splits into characters
+/// (never the leading/trailing empty pieces `str::split("")` would produce).
+pub fn split_text(s: &str, sep: &str) -> Vec<String> {
+    if sep.is_empty() {
+        return s.chars().map(String::from).collect();
+    }
+    s.split(sep).map(String::from).collect()
+}
+
+/// Split on runs of whitespace, dropping empty pieces (`the words of s`).
+pub fn words_of(s: &str) -> Vec<String> {
+    s.split_whitespace().map(String::from).collect()
+}
+
+/// Split into lines, accepting `\n` or `\r\n`; a trailing newline does not add an empty line.
+pub fn lines_of(s: &str) -> Vec<String> {
+    s.lines().map(String::from).collect()
+}
+
+/// Join displayed items with `sep` between them.
+pub fn join_text<T: Display>(items: impl IntoIterator<Item = T>, sep: &str) -> String {
+    let mut out = String::new();
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            out.push_str(sep);
+        }
+        out.push_str(&item.to_string());
+    }
+    out
+}
+
+/// Replace every occurrence of `from` with `to`. An empty `from` matches nothing.
+pub fn replace_text(s: &str, from: &str, to: &str) -> String {
+    if from.is_empty() {
+        return s.to_string();
+    }
+    s.replace(from, to)
+}
+
+/// The 1-based character position of the first `needle` in `s`, or `0` when absent.
+/// An empty needle is found at position 1.
+pub fn find_text(s: &str, needle: &str) -> i64 {
+    match s.find(needle) {
+        Some(byte) => s[..byte].chars().count() as i64 + 1,
+        None => 0,
+    }
+}
+
+/// `text_split(s, sep)` (AOT).
+pub fn text_split(s: &str, sep: &str) -> LogosSeq<String> {
+    LogosSeq::from_vec(split_text(s, sep))
+}
+
+/// `text_words(s)` (AOT).
+pub fn text_words(s: &str) -> LogosSeq<String> {
+    LogosSeq::from_vec(words_of(s))
+}
+
+/// `text_lines(s)` (AOT).
+pub fn text_lines(s: &str) -> LogosSeq<String> {
+    LogosSeq::from_vec(lines_of(s))
+}
+
+/// `text_join(items, sep)` (AOT) — any displayable element type, as the interpreter shows it.
+pub fn text_join<T: Display + Clone>(items: &LogosSeq<T>, sep: &str) -> String {
+    join_text(items.iter(), sep)
+}
+
+/// `text_trim(s)` (AOT).
+pub fn text_trim(s: &str) -> String {
+    s.trim().to_string()
+}
+
+/// `text_uppercase(s)` (AOT).
+pub fn text_uppercase(s: &str) -> String {
+    s.to_uppercase()
+}
+
+/// `text_lowercase(s)` (AOT).
+pub fn text_lowercase(s: &str) -> String {
+    s.to_lowercase()
+}
+
+/// What `Replace … with … in …` can search for: literal text, or a `Pattern` — whose
+/// replacement is a template (`$1`, `${name}`) rather than literal text.
+pub trait TextNeedle {
+    fn replace_in(&self, s: &str, to: &str) -> String;
+}
+
+impl TextNeedle for str {
+    fn replace_in(&self, s: &str, to: &str) -> String {
+        replace_text(s, self, to)
+    }
+}
+
+impl TextNeedle for String {
+    fn replace_in(&self, s: &str, to: &str) -> String {
+        replace_text(s, self, to)
+    }
+}
+
+impl TextNeedle for LogosPattern {
+    fn replace_in(&self, s: &str, to: &str) -> String {
+        self.replace_all(s, to)
+    }
+}
+
+impl<T: TextNeedle + ?Sized> TextNeedle for &T {
+    fn replace_in(&self, s: &str, to: &str) -> String {
+        (**self).replace_in(s, to)
+    }
+}
+
+/// `text_replace(s, from, to)` (AOT).
+pub fn text_replace<N: TextNeedle + ?Sized>(s: &str, from: &N, to: &str) -> String {
+    from.replace_in(s, to)
+}
+
+/// `text_find(s, needle)` (AOT).
+pub fn text_find(s: &str, needle: &str) -> i64 {
+    find_text(s, needle)
+}
+
+/// `text_starts_with(s, prefix)` (AOT).
+pub fn text_starts_with(s: &str, prefix: &str) -> bool {
+    s.starts_with(prefix)
+}
+
+/// `text_ends_with(s, suffix)` (AOT).
+pub fn text_ends_with(s: &str, suffix: &str) -> bool {
+    s.ends_with(suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_keeps_empty_fields_between_separators() {
+        assert_eq!(split_text("a,,b,", ","), ["a", "", "b", ""]);
+        assert_eq!(split_text("héllo", ""), ["h", "é", "l", "l", "o"]);
+        assert_eq!(split_text("", ","), [""]);
+    }
+
+    #[test]
+    fn words_and_lines_drop_separator_noise() {
+        assert_eq!(words_of("  the  quick\tfox \n"), ["the", "quick", "fox"]);
+        assert_eq!(lines_of("one\r\ntwo\nthree\n"), ["one", "two", "three"]);
+    }
+
+    #[test]
+    fn join_displays_each_item() {
+        assert_eq!(join_text(["a", "b", "c"], ", "), "a, b, c");
+        assert_eq!(join_text([1, 2, 3], "-"), "1-2-3");
+        assert_eq!(join_text(Vec::<String>::new(), ","), "");
+    }
+
+    #[test]
+    fn replace_with_empty_pattern_is_identity() {
+        assert_eq!(replace_text("a-b-c", "-", "+"), "a+b+c");
+        assert_eq!(replace_text("abc", "", "x"), "abc");
+    }
+
+    #[test]
+    fn find_counts_characters_from_one() {
+        assert_eq!(find_text("héllo world", "world"), 7);
+        assert_eq!(find_text("hello", "z"), 0);
+        assert_eq!(find_text("hello", ""), 1);
+    }
+
+    #[test]
+    fn aot_wrappers_build_seqs() {
+        let fields = text_split("x;y", ";");
+        assert_eq!(fields.len(), 2);
+        assert_eq!(text_join(&fields, "+"), "x+y");
+        assert_eq!(text_uppercase("straße"), "STRASSE");
+        assert!(text_starts_with("#comment", "#") && text_ends_with("file.lg", ".lg"));
+        let digits = LogosPattern::new(r"\d+").unwrap();
+        assert_eq!(text_replace("a1b22", &digits, "#"), "a#b#");
+        assert_eq!(text_replace("a1b22", "1", "$0"), "a$0b22");
+    }
+}
@@ -3,10 +3,16 @@
 //! A context maps variable names to their types.
 //! Used during type checking to track what variables are in scope.
 
+use crate::simp::{SimpRule, SimpSet};
 use crate::term::{Term, Universe};
+use crate::universe::UniverseConstraint;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
+/// The source of [`Context::generation`] numbers. Zero is the empty environment.
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
 /// Typing context: maps variable names to their types.
 ///
 /// The context is immutable-by-default: `extend` creates a new context
@@ -60,10 +66,23 @@ pub struct Context {
     /// When auto fails with decision procedures, it tries to apply these hints.
     hints: Arc<Vec<String>>,
 
+    /// Simp sets: set name -> rules `simp` rewrites with. Rules come from
+    /// proved equations marked as simplification rules.
+    simp_sets: Arc<HashMap<String, SimpSet>>,
+
     /// Universe-polymorphic definitions (R3): name -> (universe params, type, body). A
     /// `Term::Const { name, levels }` reference instantiates these params with `levels`.
     universe_polys: Arc<HashMap<String, (Vec<String>, Term, Term)>>,
 
+    /// The universe constraints a universe-polymorphic definition was checked
+    /// under (`u < v`), re-checked at every instantiation. Absent means none.
+    universe_constraints: Arc<HashMap<String, Vec<UniverseConstraint>>>,
+
+    /// Universe constraints ASSUMED while a polymorphic definition is checked
+    /// generically: cumulativity may use them. Not part of the global
+    /// environment, so they do not change the `generation`.
+    assumed_universes: Arc<Vec<UniverseConstraint>>,
+
     /// Typeclass instance database (R4): each `(type, value)` is an instance the
     /// elaborator may resolve for an instance-implicit argument — e.g.
     /// `(Inhabited Nat, mk Nat Zero)`. Searched by unifying `type` against the required
@@ -97,6 +116,12 @@ pub struct Context {
     /// recursor derivation reads this to give each member a motive and to route a
     /// recursive occurrence of a SIBLING to the sibling's fixpoint.
     mutual_blocks: Arc<HashMap<String, Vec<String>>>,
+
+    /// Identifies the global environment above: every change to it draws a fresh
+    /// number, and clones share theirs until one of them changes. Local
+    /// `bindings` do not count — reduction never reads them — so `extend` keeps
+    /// the generation, and the normalization cache stays warm under binders.
+    generation: u64,
 }
 
 /// One member of a MUTUAL inductive block: its name, arity sort, uniform parameter
@@ -138,18 +163,35 @@ impl Context {
             declarations: Arc::new(HashMap::new()),
             definitions: Arc::new(HashMap::new()),
             hints: Arc::new(Vec::new()),
+            simp_sets: Arc::new(HashMap::new()),
             universe_polys: Arc::new(HashMap::new()),
+            universe_constraints: Arc::new(HashMap::new()),
+            assumed_universes: Arc::new(Vec::new()),
             instances: Arc::new(Vec::new()),
             coercions: Arc::new(Vec::new()),
             binder_kinds: Arc::new(HashMap::new()),
             implicit_args: Arc::new(HashMap::new()),
             structures: Arc::new(HashMap::new()),
             mutual_blocks: Arc::new(HashMap::new()),
+            generation: 0,
         }
     }
 
+    /// The generation of the global environment: equal generations mean equal
+    /// inductives, constructors, declarations and definitions, so anything
+    /// computed from them may be reused. Changes with every global addition.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Record that the global environment is about to change.
+    fn touch(&mut self) {
+        self.generation = NEXT_GENERATION.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Record structure metadata (used by [`Context::add_structure`]).
     pub fn register_struct_info(&mut self, name: &str, info: StructInfo) {
+        self.touch();
         Arc::make_mut(&mut self.structures).insert(name.to_string(), info);
     }
 
@@ -170,6 +212,7 @@ impl Context {
     /// Record that the global `name` has `count` leading implicit parameters, so the
     /// surface elaborator inserts that many inferred arguments at each application.
     pub fn set_implicit_args(&mut self, name: &str, count: usize) {
+        self.touch();
         Arc::make_mut(&mut self.implicit_args).insert(name.to_string(), count);
     }
 
@@ -182,12 +225,14 @@ impl Context {
     /// `Inhabited Nat`). The elaborator resolves an instance-implicit argument by
     /// searching these for a `ty` that unifies with the required class type.
     pub fn add_instance(&mut self, ty: Term, value: Term) {
+        self.touch();
         Arc::make_mut(&mut self.instances).push((ty, value));
     }
 
     /// Register a coercion `coe : from → to` — the elaborator may insert it when an
     /// argument of type `from` appears where `to` is expected.
     pub fn add_coercion(&mut self, from: Term, to: Term, coe: Term) {
+        self.touch();
         Arc::make_mut(&mut self.coercions).push((from, to, coe));
     }
 
@@ -199,6 +244,7 @@ impl Context {
     /// Record a global's per-parameter kinds (implicit/explicit/instance, in order), so the
     /// elaborator can insert implicit and instance arguments at their real positions.
     pub fn set_binder_kinds(&mut self, name: &str, kinds: Vec<crate::elaborate::ParamKind>) {
+        self.touch();
         Arc::make_mut(&mut self.binder_kinds).insert(name.to_string(), kinds);
     }
 
@@ -216,6 +262,7 @@ impl Context {
     /// `Term::Const { name, levels }` reference later instantiates `params` with `levels`
     /// (the `.{ℓ…}` syntax), so one definition is reused at every level.
     pub fn add_universe_poly(&mut self, name: &str, params: Vec<String>, ty: Term, body: Term) {
+        self.touch();
         Arc::make_mut(&mut self.universe_polys).insert(name.to_string(), (params, ty, body));
     }
 
@@ -224,6 +271,88 @@ impl Context {
         self.universe_polys.get(name)
     }
 
+    /// Register a universe-polymorphic definition after checking it: the
+    /// `constraints` must mention only `params` and be satisfiable, `ty` and
+    /// `body` may mention no other universe variables, and `body` must have
+    /// type `ty` for every level assignment the constraints allow. Each later
+    /// `name.{ℓ…}` must satisfy the constraints at its levels.
+    pub fn add_universe_poly_checked(
+        &mut self,
+        name: &str,
+        params: Vec<String>,
+        constraints: Vec<UniverseConstraint>,
+        ty: Term,
+        body: Term,
+    ) -> crate::error::KernelResult<()> {
+        use crate::error::KernelError;
+
+        let mut mentioned = Vec::new();
+        for constraint in &constraints {
+            constraint.variables(&mut mentioned);
+        }
+        crate::term::universe_variables(&ty, &mut mentioned);
+        crate::term::universe_variables(&body, &mut mentioned);
+        if let Some(stray) = mentioned.iter().find(|v| !params.contains(v)) {
+            return Err(KernelError::UniverseInconsistency(format!(
+                "'{}' mentions the universe variable {} but declares only {{{}}}",
+                name,
+                stray,
+                params.join(", ")
+            )));
+        }
+        crate::universe::consistent(&constraints)
+            .map_err(|why| KernelError::UniverseInconsistency(format!("'{}': {}", name, why)))?;
+
+        let mut generic = self.clone();
+        generic.assumed_universes = Arc::new(constraints.clone());
+        match crate::type_checker::infer_type(&generic, &ty)? {
+            Term::Sort(_) => {}
+            other => return Err(KernelError::NotAType(format!("{} (it has type {})", ty, other))),
+        }
+        let inferred = crate::type_checker::infer_type(&generic, &body)?;
+        if !crate::type_checker::is_subtype(&generic, &inferred, &ty) {
+            return Err(KernelError::TypeMismatch { expected: ty.to_string(), found: inferred.to_string() });
+        }
+
+        self.add_universe_poly(name, params, ty, body);
+        if !constraints.is_empty() {
+            self.touch();
+            Arc::make_mut(&mut self.universe_constraints).insert(name.to_string(), constraints);
+        }
+        Ok(())
+    }
+
+    /// The universe constraints of polymorphic definition `name` (empty if none).
+    pub fn universe_constraints(&self, name: &str) -> &[UniverseConstraint] {
+        self.universe_constraints.get(name).map_or(&[], |c| c.as_slice())
+    }
+
+    /// Check that instantiating polymorphic definition `name` at `levels`
+    /// satisfies its constraints — outright, or under the constraints assumed
+    /// while checking an enclosing polymorphic definition.
+    pub fn check_universe_instance(&self, name: &str, params: &[String], levels: &[Universe]) -> Result<(), String> {
+        let subst: HashMap<String, Universe> = params.iter().cloned().zip(levels.iter().cloned()).collect();
+        for constraint in self.universe_constraints(name) {
+            let instance = constraint.substitute(&subst);
+            if !instance.holds_assuming(&self.assumed_universes) {
+                let shown: Vec<String> = levels.iter().map(|l| l.to_string()).collect();
+                return Err(format!("'{}.{{{}}}' violates {} (here {})", name, shown.join(", "), constraint, instance));
+            }
+        }
+        Ok(())
+    }
+
+    /// Cumulativity `a ≤ b` of two levels: by the level algebra, or from the
+    /// universe constraints currently assumed.
+    pub fn universe_leq(&self, a: &Universe, b: &Universe) -> bool {
+        a.is_subtype_of(b) || crate::universe::entail(&self.assumed_universes, a, b)
+    }
+
+    /// Level equality, under the assumed universe constraints.
+    pub fn universe_equiv(&self, a: &Universe, b: &Universe) -> bool {
+        a.equiv(b) || (self.universe_leq(a, b) && self.universe_leq(b, a))
+    }
+
     /// Add a local binding to this context (mutates in place).
     pub fn add(&mut self, name: &str, ty: Term) {
         self.bindings.insert(name.to_string(), Arc::new(ty));
@@ -251,6 +380,7 @@ impl Context {
     /// [`set_inductive_params`](Self::set_inductive_params) records a smaller parameter
     /// count — see [`add_indexed_inductive`](Self::add_indexed_inductive).
     pub fn add_inductive(&mut self, name: &str, sort: Term) {
+        self.touch();
         Arc::make_mut(&mut self.inductives).insert(name.to_string(), sort);
     }
 
@@ -378,6 +508,7 @@ impl Context {
 
     /// Record how many leading arguments of `name`'s arity are uniform parameters.
     pub fn set_inductive_params(&mut self, name: &str, num_params: usize) {
+        self.touch();
         Arc::make_mut(&mut self.inductive_params).insert(name.to_string(), num_params);
     }
 
@@ -417,6 +548,7 @@ impl Context {
     ///
     /// Constructors are tracked in registration order for match expressions.
     pub fn add_constructor(&mut self, name: &str, inductive: &str, ty: Term) {
+        self.touch();
         Arc::make_mut(&mut self.constructors)
             .insert(name.to_string(), (inductive.to_string(), ty));
 
@@ -432,6 +564,7 @@ impl Context {
     /// Used for proof certification where hypotheses are assumed.
     /// Example: h1 : P -> Q
     pub fn add_declaration(&mut self, name: &str, ty: Term) {
+        self.touch();
         Arc::make_mut(&mut self.declarations).insert(name.to_string(), ty);
     }
 
@@ -440,6 +573,7 @@ impl Context {
     /// Definitions are transparent and unfold during normalization (delta reduction).
     /// This distinguishes them from declarations (axioms) which have no body.
     pub fn add_definition(&mut self, name: String, ty: Term, body: Term) {
+        self.touch();
         Arc::make_mut(&mut self.definitions).insert(name, (ty, body));
     }
 
@@ -605,6 +739,7 @@ impl Context {
         // 4. Record block membership (only for a genuine block of ≥ 2 members).
         if block.len() > 1 {
             let members: Vec<String> = block.iter().map(|m| m.name.clone()).collect();
+            self.touch();
             let reg = Arc::make_mut(&mut self.mutual_blocks);
             for member in block {
                 reg.insert(member.name.clone(), members.clone());
@@ -657,6 +792,7 @@ impl Context {
     /// procedures fail. This allows auto to "learn" from proven theorems.
     pub fn add_hint(&mut self, name: &str) {
         if !self.hints.contains(&name.to_string()) {
+            self.touch();
             Arc::make_mut(&mut self.hints).push(name.to_string());
         }
     }
@@ -672,6 +808,22 @@ impl Context {
     pub fn is_hint(&self, name: &str) -> bool {
         self.hints.contains(&name.to_string())
     }
+
+    /// Add a simp rule to the named set, creating the set if needed.
+    ///
+    /// The set is left unchanged when [`SimpSet::add`] refuses the rule.
+    pub fn add_simp_rule(&mut self, set: &str, rule: SimpRule) -> crate::error::KernelResult<()> {
+        let mut updated = self.simp_sets.get(set).cloned().unwrap_or_default();
+        updated.add(rule)?;
+        self.touch();
+        Arc::make_mut(&mut self.simp_sets).insert(set.to_string(), updated);
+        Ok(())
+    }
+
+    /// Get a simp set by name.
+    pub fn simp_set(&self, set: &str) -> Option<&SimpSet> {
+        self.simp_sets.get(set)
+    }
 }
 
 /// Count the leading `Π`s of a term — an inductive's arity, or a constructor's parameter
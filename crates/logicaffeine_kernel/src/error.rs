@@ -45,6 +45,14 @@ pub enum KernelError {
         reason: String,
     },
 
+    /// Universe levels that cannot be assigned consistently: an unsatisfiable
+    /// constraint set, or an instantiation `name.{ℓ…}` that violates one.
+    UniverseInconsistency(String),
+
+    /// An equation refused as a simp rule: not an equation, an obvious
+    /// rewrite loop, or an overlap that does not rejoin.
+    SimpRuleRejected(String),
+
     /// Cannot infer the type of a hole without context.
     ///
     /// Holes (implicit arguments) need to be checked against an expected type,
@@ -101,6 +109,12 @@ impl fmt::Display for KernelError {
                     constructor, inductive, reason
                 )
             }
+            KernelError::UniverseInconsistency(msg) => {
+                write!(f, "Universe inconsistency: {}", msg)
+            }
+            KernelError::SimpRuleRejected(msg) => {
+                write!(f, "Simp rule rejected: {}", msg)
+            }
             KernelError::CannotInferHole => {
                 write!(f, "Cannot infer type of implicit argument (_) without context")
             }
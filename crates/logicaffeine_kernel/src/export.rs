@@ -0,0 +1,490 @@
+//! Export kernel terms to Lean 4 and Coq — so a statement checked here can be
+//! carried into a mature proof assistant and worked on there.
+//!
+//! [`export_term`] renders one [`Term`] in the target's surface syntax.
+//! [`export_theorem`] writes a whole file: every global the statement mentions
+//! is declared as an axiom of the type the [`Context`] gives it (inductives
+//! become opaque types, constructors and definitions opaque constants), then
+//! the theorem itself, closed by the exported proof term when there is one
+//! and left open (`sorry` / `Admitted`) when there is not.
+//!
+//! Everything lives in a `Logos` namespace (a module, in Coq), so kernel names
+//! such as `Nat` or `Eq` never collide with the target's own library.
+//!
+//! The export is syntactic: `match`, `fix` and mutual fixpoints have no
+//! faithful rendering without the target's own inductive declarations, and
+//! are reported as [`ExportError::Unsupported`] rather than approximated.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::term::{Literal, Term, Universe};
+use crate::Context;
+
+/// A proof assistant to export to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Prover {
+    Lean4,
+    Coq,
+}
+
+impl Prover {
+    /// Every supported prover, for listing the choices.
+    pub const ALL: [Prover; 2] = [Prover::Lean4, Prover::Coq];
+
+    /// The prover's name as `largo export --prover` takes it.
+    pub fn name(self) -> &'static str {
+        match self {
+            Prover::Lean4 => "lean4",
+            Prover::Coq => "coq",
+        }
+    }
+
+    /// The prover called `name` (`lean4`/`lean`, `coq`/`rocq`; any case).
+    pub fn from_name(name: &str) -> Option<Prover> {
+        match name.to_ascii_lowercase().as_str() {
+            "lean4" | "lean" => Some(Prover::Lean4),
+            "coq" | "rocq" => Some(Prover::Coq),
+            _ => None,
+        }
+    }
+
+    /// The extension of the prover's source files.
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            Prover::Lean4 => "lean",
+            Prover::Coq => "v",
+        }
+    }
+
+    /// A one-line comment in the prover's syntax.
+    pub fn comment(self, text: &str) -> String {
+        match self {
+            Prover::Lean4 => format!("-- {}", text),
+            Prover::Coq => format!("(* {} *)", text),
+        }
+    }
+
+    /// The opening and closing lines of the `Logos` namespace.
+    pub fn namespace(self) -> (&'static str, &'static str) {
+        match self {
+            Prover::Lean4 => ("namespace Logos", "end Logos"),
+            Prover::Coq => ("Module Logos.", "End Logos."),
+        }
+    }
+
+    /// A declaration of `name : ty` with no definition.
+    pub fn axiom(self, name: &str, ty: &str) -> String {
+        match self {
+            Prover::Lean4 => format!("axiom {} : {}", name, ty),
+            Prover::Coq => format!("Parameter {} : {}.", name, ty),
+        }
+    }
+
+    /// A theorem `name binders : statement`, closed by `proof` when given and
+    /// left open otherwise.
+    pub fn theorem(self, name: &str, binders: &str, statement: &str, proof: Option<&str>) -> String {
+        let head = if binders.is_empty() { name.to_string() } else { format!("{} {}", name, binders) };
+        match (self, proof) {
+            (Prover::Lean4, Some(proof)) => format!("theorem {} : {} :=\n  {}", head, statement, proof),
+            (Prover::Lean4, None) => format!("theorem {} : {} := by\n  sorry", head, statement),
+            (Prover::Coq, Some(proof)) => format!("Theorem {} : {}.\nProof.\n  exact ({}).\nQed.", head, statement, proof),
+            (Prover::Coq, None) => format!("Theorem {} : {}.\nProof.\nAdmitted.", head, statement),
+        }
+    }
+
+    /// `name` as an identifier of the prover: kept when it already is one,
+    /// otherwise quoted (Lean's `«…»`) or mangled (Coq).
+    pub fn identifier(self, name: &str) -> String {
+        let plain = |c: char| c.is_alphanumeric() || c == '_' || c == '\'';
+        let starts_well = name.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_');
+        match self {
+            Prover::Lean4 => {
+                let valid = starts_well
+                    && name.split('.').all(|part| !part.is_empty() && part.chars().all(plain))
+                    && !LEAN_KEYWORDS.contains(&name);
+                if valid {
+                    name.to_string()
+                } else {
+                    format!("«{}»", name)
+                }
+            }
+            Prover::Coq => {
+                let mut id: String = name.chars().map(|c| if plain(c) { c } else { '_' }).collect();
+                if !starts_well {
+                    id.insert(0, '_');
+                }
+                if COQ_KEYWORDS.contains(&id.as_str()) {
+                    id.push('_');
+                }
+                id
+            }
+        }
+    }
+}
+
+impl fmt::Display for Prover {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+const LEAN_KEYWORDS: &[&str] = &[
+    "at", "axiom", "by", "class", "def", "do", "else", "end", "example", "for", "forall", "from", "fun",
+    "have", "if", "import", "in", "inductive", "instance", "let", "match", "mutual", "namespace", "open",
+    "Prop", "section", "show", "Sort", "structure", "then", "theorem", "Type", "universe", "variable",
+    "where", "with",
+];
+
+const COQ_KEYWORDS: &[&str] = &[
+    "as", "at", "Axiom", "cofix", "Definition", "else", "end", "exists", "fix", "for", "forall", "fun",
+    "if", "in", "Lemma", "let", "match", "Parameter", "Proof", "Prop", "Qed", "return", "SProp", "Set",
+    "then", "Theorem", "Type", "using", "where", "with",
+];
+
+/// Why a term could not be exported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportError {
+    /// The term uses a construct the target syntax has no faithful rendering for.
+    Unsupported(String),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportError::Unsupported(what) => write!(f, "cannot export {}", what),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+/// `term` in `prover`'s surface syntax.
+pub fn export_term(term: &Term, prover: Prover) -> Result<String, ExportError> {
+    let mut out = String::new();
+    Writer { prover }.term(term, Prec::Top, &mut out)?;
+    Ok(out)
+}
+
+/// A `prover` source file stating `name : ty`: axioms for the globals it
+/// mentions (transitively, through their types), then the theorem, proved by
+/// `proof` if that exports and admitted otherwise.
+///
+/// A global `ctx` does not know is left undeclared, with a comment saying so.
+pub fn export_theorem(
+    ctx: &Context,
+    name: &str,
+    ty: &Term,
+    proof: Option<&Term>,
+    prover: Prover,
+) -> Result<String, ExportError> {
+    let statement = export_term(ty, prover)?;
+    let exported_proof = proof.and_then(|proof| Some((proof, export_term(proof, prover).ok()?)));
+
+    let mut roots = Vec::new();
+    globals(ty, &mut roots);
+    if let Some((proof, _)) = exported_proof {
+        globals(proof, &mut roots);
+    }
+    let mut declared = HashSet::new();
+    let mut preamble = Vec::new();
+    for global in roots {
+        declare(ctx, &global, prover, &mut declared, &mut preamble)?;
+    }
+
+    let proof = exported_proof.map(|(_, text)| text);
+    let (open, close) = prover.namespace();
+
+    let mut file = vec![prover.comment("Exported from LOGOS."), String::new(), open.to_string(), String::new()];
+    if !preamble.is_empty() {
+        file.extend(preamble);
+        file.push(String::new());
+    }
+    file.push(prover.theorem(&prover.identifier(name), "", &statement, proof.as_deref()));
+    file.push(String::new());
+    file.push(close.to_string());
+    Ok(file.join("\n") + "\n")
+}
+
+/// Declare `global` (after everything its type mentions) as an axiom.
+fn declare(
+    ctx: &Context,
+    global: &str,
+    prover: Prover,
+    declared: &mut HashSet<String>,
+    out: &mut Vec<String>,
+) -> Result<(), ExportError> {
+    if !declared.insert(global.to_string()) {
+        return Ok(());
+    }
+    let Some(ty) = ctx.get_global(global) else {
+        out.push(prover.comment(&format!("`{}` is not declared in the exporting context", global)));
+        return Ok(());
+    };
+    let mut dependencies = Vec::new();
+    globals(ty, &mut dependencies);
+    for dependency in dependencies {
+        declare(ctx, &dependency, prover, declared, out)?;
+    }
+
+    let mut name = prover.identifier(global);
+    if prover == Prover::Lean4 {
+        let mut levels = Vec::new();
+        universe_variables(ty, &mut levels);
+        if !levels.is_empty() {
+            name = format!("{}.{{{}}}", name, levels.join(", "));
+        }
+    }
+    out.push(prover.axiom(&name, &export_term(ty, prover)?));
+    Ok(())
+}
+
+/// The globals `term` mentions, in order of first occurrence.
+fn globals(term: &Term, out: &mut Vec<String>) {
+    let mut push = |name: &str| {
+        if !out.iter().any(|seen| seen == name) {
+            out.push(name.to_string());
+        }
+    };
+    match term {
+        Term::Global(name) | Term::Const { name, .. } => push(name),
+        Term::Pi { param_type, body_type, .. } => {
+            globals(param_type, out);
+            globals(body_type, out);
+        }
+        Term::Lambda { param_type, body, .. } => {
+            globals(param_type, out);
+            globals(body, out);
+        }
+        Term::App(func, arg) => {
+            globals(func, out);
+            globals(arg, out);
+        }
+        Term::Match { discriminant, motive, cases } => {
+            globals(discriminant, out);
+            globals(motive, out);
+            for case in cases {
+                globals(case, out);
+            }
+        }
+        Term::Fix { body, .. } => globals(body, out),
+        Term::MutualFix { defs, .. } => {
+            for (_, body) in defs {
+                globals(body, out);
+            }
+        }
+        Term::Let { ty, value, body, .. } => {
+            globals(ty, out);
+            globals(value, out);
+            globals(body, out);
+        }
+        Term::Sort(_) | Term::Var(_) | Term::Lit(_) | Term::Hole => {}
+    }
+}
+
+/// The universe variables `term`'s sorts mention, in order of first occurrence.
+fn universe_variables(term: &Term, out: &mut Vec<String>) {
+    fn level(u: &Universe, out: &mut Vec<String>) {
+        match u {
+            Universe::Var(v) if !out.contains(v) => out.push(v.clone()),
+            Universe::Succ(l) => level(l, out),
+            Universe::Max(a, b) | Universe::IMax(a, b) => {
+                level(a, out);
+                level(b, out);
+            }
+            _ => {}
+        }
+    }
+    match term {
+        Term::Sort(u) => level(u, out),
+        Term::Const { levels, .. } => levels.iter().for_each(|u| level(u, out)),
+        Term::Pi { param_type, body_type: body, .. } | Term::Lambda { param_type, body, .. } => {
+            universe_variables(param_type, out);
+            universe_variables(body, out);
+        }
+        Term::App(func, arg) => {
+            universe_variables(func, out);
+            universe_variables(arg, out);
+        }
+        Term::Let { ty, value, body, .. } => {
+            universe_variables(ty, out);
+            universe_variables(value, out);
+            universe_variables(body, out);
+        }
+        _ => {}
+    }
+}
+
+/// Whether the variable `name` occurs free in `term`.
+fn mentions(term: &Term, name: &str) -> bool {
+    match term {
+        Term::Var(v) => v == name,
+        Term::Pi { param, param_type, body_type: body } | Term::Lambda { param, param_type, body } => {
+            mentions(param_type, name) || (param != name && mentions(body, name))
+        }
+        Term::App(func, arg) => mentions(func, name) || mentions(arg, name),
+        Term::Match { discriminant, motive, cases } => {
+            mentions(discriminant, name) || mentions(motive, name) || cases.iter().any(|case| mentions(case, name))
+        }
+        Term::Fix { name: bound, body } => bound != name && mentions(body, name),
+        Term::MutualFix { defs, .. } => {
+            !defs.iter().any(|(bound, _)| bound == name) && defs.iter().any(|(_, body)| mentions(body, name))
+        }
+        Term::Let { name: bound, ty, value, body } => {
+            mentions(ty, name) || mentions(value, name) || (bound != name && mentions(body, name))
+        }
+        Term::Sort(_) | Term::Global(_) | Term::Const { .. } | Term::Lit(_) | Term::Hole => false,
+    }
+}
+
+/// How tightly the surrounding syntax binds a sub-term.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Prec {
+    /// Anywhere: binders and arrows need no parentheses.
+    Top,
+    /// The left of an arrow: binders need parentheses.
+    Arrow,
+    /// An argument: anything but an atom needs parentheses.
+    Arg,
+}
+
+struct Writer {
+    prover: Prover,
+}
+
+impl Writer {
+    fn arrow(&self) -> &'static str {
+        match self.prover {
+            Prover::Lean4 => "→",
+            Prover::Coq => "->",
+        }
+    }
+
+    fn term(&self, term: &Term, prec: Prec, out: &mut String) -> Result<(), ExportError> {
+        // Literals render atomically or come with their own parentheses.
+        let compound = !matches!(
+            term,
+            Term::Sort(_) | Term::Var(_) | Term::Global(_) | Term::Const { .. } | Term::Lit(_) | Term::Hole
+        );
+        let arrow_like = matches!(term, Term::Pi { .. } | Term::Lambda { .. } | Term::Let { .. });
+        let wrap = compound && (prec == Prec::Arg || (prec == Prec::Arrow && arrow_like));
+        if wrap {
+            out.push('(');
+        }
+        self.bare(term, out)?;
+        if wrap {
+            out.push(')');
+        }
+        Ok(())
+    }
+
+    fn bare(&self, term: &Term, out: &mut String) -> Result<(), ExportError> {
+        let id = |name: &str| self.prover.identifier(name);
+        match term {
+            Term::Sort(u) => out.push_str(&self.sort(u)),
+            Term::Var(name) | Term::Global(name) => out.push_str(&id(name)),
+            Term::Const { name, levels } => {
+                out.push_str(&id(name));
+                if self.prover == Prover::Lean4 && !levels.is_empty() {
+                    let levels: Vec<String> = levels.iter().map(level).collect();
+                    out.push_str(&format!(".{{{}}}", levels.join(", ")));
+                }
+            }
+            Term::Pi { param, param_type, body_type } => {
+                if param == "_" || !mentions(body_type, param) {
+                    self.term(param_type, Prec::Arrow, out)?;
+                    out.push_str(&format!(" {} ", self.arrow()));
+                } else {
+                    let binder = match self.prover {
+                        Prover::Lean4 => "∀",
+                        Prover::Coq => "forall",
+                    };
+                    out.push_str(&format!("{} ({} : ", binder, id(param)));
+                    self.term(param_type, Prec::Top, out)?;
+                    out.push_str("), ");
+                }
+                self.term(body_type, Prec::Top, out)?;
+            }
+            Term::Lambda { param, param_type, body } => {
+                out.push_str(&format!("fun ({} : ", id(param)));
+                self.term(param_type, Prec::Top, out)?;
+                out.push_str(") => ");
+                self.term(body, Prec::Top, out)?;
+            }
+            Term::App(func, arg) => {
+                // Left-nested applications print as one spine: `f a b`.
+                match func.as_ref() {
+                    Term::App(..) => self.bare(func, out)?,
+                    _ => self.term(func, Prec::Arg, out)?,
+                }
+                out.push(' ');
+                self.term(arg, Prec::Arg, out)?;
+            }
+            Term::Let { name, ty, value, body } => {
+                out.push_str(&format!("let {} : ", id(name)));
+                self.term(ty, Prec::Top, out)?;
+                out.push_str(" := ");
+                self.term(value, Prec::Top, out)?;
+                out.push_str(match self.prover {
+                    Prover::Lean4 => "; ",
+                    Prover::Coq => " in ",
+                });
+                self.term(body, Prec::Top, out)?;
+            }
+            Term::Lit(lit) => out.push_str(&self.literal(lit)?),
+            Term::Hole => out.push('_'),
+            Term::Match { .. } => return Err(ExportError::Unsupported("a `match` expression".to_string())),
+            Term::Fix { name, .. } => return Err(ExportError::Unsupported(format!("the fixpoint `{}`", name))),
+            Term::MutualFix { .. } => return Err(ExportError::Unsupported("a mutual fixpoint".to_string())),
+        }
+        Ok(())
+    }
+
+    fn sort(&self, u: &Universe) -> String {
+        match (self.prover, u) {
+            (_, Universe::Prop) => "Prop".to_string(),
+            (Prover::Lean4, Universe::SProp) => "Prop".to_string(),
+            (Prover::Coq, Universe::SProp) => "SProp".to_string(),
+            (Prover::Lean4, Universe::Type(0)) | (Prover::Coq, _) => "Type".to_string(),
+            (Prover::Lean4, Universe::Type(n)) => format!("Type {}", n),
+            (Prover::Lean4, u) => format!("Sort {}", level(u)),
+        }
+    }
+
+    fn literal(&self, lit: &Literal) -> Result<String, ExportError> {
+        let int = |n: &dyn fmt::Display| match self.prover {
+            Prover::Lean4 => format!("({} : Int)", n),
+            Prover::Coq => format!("{}%Z", n),
+        };
+        Ok(match lit {
+            Literal::Int(n) if *n >= 0 && self.prover == Prover::Lean4 => n.to_string(),
+            Literal::Int(n) | Literal::Duration(n) | Literal::Moment(n) => int(n),
+            Literal::Date(days) => int(days),
+            Literal::BigInt(n) => int(n),
+            Literal::Nat(n) => match self.prover {
+                Prover::Lean4 => format!("({} : _root_.Nat)", n),
+                Prover::Coq => n.to_string(),
+            },
+            Literal::Text(s) => match self.prover {
+                Prover::Lean4 => format!("{:?}", s),
+                Prover::Coq => format!("{:?}%string", s),
+            },
+            Literal::Float(x) => match self.prover {
+                Prover::Lean4 => format!("({:?} : Float)", x),
+                Prover::Coq => return Err(ExportError::Unsupported(format!("the float literal {}", x))),
+            },
+        })
+    }
+}
+
+/// A Lean universe level: `Prop` is 0 and `Type n` is `n + 1`.
+fn level(u: &Universe) -> String {
+    match u {
+        Universe::SProp | Universe::Prop => "0".to_string(),
+        Universe::Type(n) => (n + 1).to_string(),
+        Universe::Var(v) => v.clone(),
+        Universe::Succ(l) => format!("({} + 1)", level(l)),
+        Universe::Max(a, b) => format!("(max {} {})", level(a), level(b)),
+        Universe::IMax(a, b) => format!("(imax {} {})", level(a), level(b)),
+    }
+}
@@ -0,0 +1,145 @@
+//! Hash-consing of terms: one identity per distinct term.
+//!
+//! A [`HashCons`] table assigns every structurally distinct term a [`TermId`],
+//! interning bottom-up so that each node is keyed on its children's ids rather
+//! than on their full structure. Interning a term is therefore linear in its
+//! size, identical subterms — however often they recur, in one term or across
+//! many — share one id, and comparing two interned terms is comparing two
+//! integers.
+//!
+//! Identity is exact structural equality, stricter than [`Term`]'s `==` in one
+//! place: float literals are compared by bit pattern, so `0.0` and `-0.0` get
+//! different ids. A memo keyed on a [`TermId`] can never hand back the result
+//! for a different term.
+//!
+//! The table is the identity key of the [`normalize`](crate::normalize) cache,
+//! which pairs it with the [`Context::generation`](crate::Context::generation)
+//! of the global environment the term was normalized in.
+
+use std::collections::HashMap;
+
+use crate::term::{Literal, Term, Universe};
+
+/// The identity of an interned term, valid for the [`HashCons`] that issued it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TermId(u32);
+
+/// One node of an interned term: its constructor, names and literals, with
+/// every subterm replaced by that subterm's id.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Node {
+    Sort(Universe),
+    Var(String),
+    Global(String),
+    Const(String, Vec<Universe>),
+    Pi(String, TermId, TermId),
+    Lambda(String, TermId, TermId),
+    App(TermId, TermId),
+    Match(TermId, TermId, Vec<TermId>),
+    Fix(String, TermId),
+    MutualFix(Vec<(String, TermId)>, usize),
+    Let(String, TermId, TermId, TermId),
+    Lit(LitKey),
+    Hole,
+}
+
+/// A literal keyed exactly: floats by their bits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum LitKey {
+    Int(i64),
+    Float(u64),
+    Text(String),
+    Duration(i64),
+    Date(i32),
+    Moment(i64),
+    BigInt(logicaffeine_base::BigInt),
+    Nat(logicaffeine_base::BigInt),
+}
+
+impl From<&Literal> for LitKey {
+    fn from(lit: &Literal) -> Self {
+        match lit {
+            Literal::Int(n) => LitKey::Int(*n),
+            Literal::Float(x) => LitKey::Float(x.to_bits()),
+            Literal::Text(s) => LitKey::Text(s.clone()),
+            Literal::Duration(n) => LitKey::Duration(*n),
+            Literal::Date(d) => LitKey::Date(*d),
+            Literal::Moment(n) => LitKey::Moment(*n),
+            Literal::BigInt(n) => LitKey::BigInt(n.clone()),
+            Literal::Nat(n) => LitKey::Nat(n.clone()),
+        }
+    }
+}
+
+/// A hash-consing table.
+///
+/// ```
+/// use logicaffeine_kernel::hashcons::HashCons;
+/// use logicaffeine_kernel::Term;
+///
+/// let x = || Term::App(Box::new(Term::Global("f".into())), Box::new(Term::Global("a".into())));
+/// let mut table = HashCons::new();
+/// let (a, b) = (table.intern(&x()), table.intern(&x()));
+/// assert_eq!(a, b);
+/// assert_eq!(table.len(), 3); // f, a, and f a
+/// ```
+#[derive(Debug, Default)]
+pub struct HashCons {
+    ids: HashMap<Node, TermId>,
+}
+
+impl HashCons {
+    /// An empty table.
+    pub fn new() -> Self {
+        HashCons::default()
+    }
+
+    /// The id of `term`, interning it and each of its subterms not seen before.
+    pub fn intern(&mut self, term: &Term) -> TermId {
+        let node = match term {
+            Term::Sort(u) => Node::Sort(u.clone()),
+            Term::Var(name) => Node::Var(name.clone()),
+            Term::Global(name) => Node::Global(name.clone()),
+            Term::Const { name, levels } => Node::Const(name.clone(), levels.clone()),
+            Term::Pi { param, param_type, body_type } => {
+                Node::Pi(param.clone(), self.intern(param_type), self.intern(body_type))
+            }
+            Term::Lambda { param, param_type, body } => {
+                Node::Lambda(param.clone(), self.intern(param_type), self.intern(body))
+            }
+            Term::App(func, arg) => Node::App(self.intern(func), self.intern(arg)),
+            Term::Match { discriminant, motive, cases } => Node::Match(
+                self.intern(discriminant),
+                self.intern(motive),
+                cases.iter().map(|case| self.intern(case)).collect(),
+            ),
+            Term::Fix { name, body } => Node::Fix(name.clone(), self.intern(body)),
+            Term::MutualFix { defs, index } => Node::MutualFix(
+                defs.iter().map(|(name, body)| (name.clone(), self.intern(body))).collect(),
+                *index,
+            ),
+            Term::Let { name, ty, value, body } => {
+                Node::Let(name.clone(), self.intern(ty), self.intern(value), self.intern(body))
+            }
+            Term::Lit(lit) => Node::Lit(LitKey::from(lit)),
+            Term::Hole => Node::Hole,
+        };
+        let next = TermId(self.ids.len() as u32);
+        *self.ids.entry(node).or_insert(next)
+    }
+
+    /// How many distinct terms have been interned.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Whether nothing has been interned yet.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Forget every interned term. Ids issued before are no longer meaningful.
+    pub fn clear(&mut self) {
+        self.ids.clear();
+    }
+}
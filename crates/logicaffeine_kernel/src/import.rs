@@ -0,0 +1,815 @@
+//! Import proofs finished in Lean 4 or Coq, and re-check them here.
+//!
+//! The inverse of [`crate::export`]: a file in the subset of each prover's
+//! syntax that the exporter writes — `axiom`/`Parameter` declarations and
+//! `theorem`/`Theorem` statements — is read back into kernel [`Term`]s. A
+//! theorem whose proof was completed as a *term* (`:= h1 Socrates h2`, or
+//! `exact (…).` before `Qed.`) comes back with that proof, and
+//! [`check_proof`] re-validates it with [`infer_type`]: the term must
+//! type-check and its type must be the statement, up to definitional
+//! equality. Tactic proofs, `sorry` and `Admitted` come back without one —
+//! there is no term to check.
+//!
+//! # What is trusted
+//!
+//! Nothing in an imported file. As with [`crate::certificate`], the checker
+//! supplies its own context: [`declare`] refuses any declaration whose type
+//! is a proposition (an axiom of a proposition is an assumption, not a
+//! proof), and a caller discharging its own theorems should check each
+//! imported proof against the statement *it* exported, not the one the file
+//! claims.
+//!
+//! The notation maps onto the prelude: `∧`/`/\` is `And`, `∨`/`\/` is `Or`,
+//! `¬`/`~` is `Not`, `∃`/`exists` is `Ex`, `=` is `Eq` at the type of its
+//! left side, and `↔`/`<->` is the conjunction of both implications.
+//! Constructors are the prelude's, applied to every argument (`conj P Q p q`,
+//! `witness A P x h`) — no implicit arguments are inferred, so a binder
+//! without a type is accepted only where the expected type supplies it.
+
+use std::fmt;
+
+use crate::export::Prover;
+use crate::term::{int_lit, Literal, Term, Universe};
+use crate::type_checker::substitute;
+use crate::{infer_type, is_subtype, normalize, Context, KernelError};
+
+/// A theorem read from an imported file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedTheorem {
+    pub name: String,
+    /// The statement, with the theorem's binders as `Π`s.
+    pub statement: Term,
+    /// The proof term, with the theorem's binders as `λ`s; `None` when the
+    /// theorem was left open or proved by tactics.
+    pub proof: Option<Term>,
+}
+
+/// The declarations and theorems of an imported file, in file order.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ImportedFile {
+    pub declarations: Vec<(String, Term)>,
+    pub theorems: Vec<ImportedTheorem>,
+}
+
+impl ImportedFile {
+    /// The theorem called `name`, if the file states one.
+    pub fn theorem(&self, name: &str) -> Option<&ImportedTheorem> {
+        self.theorems.iter().find(|theorem| theorem.name == name)
+    }
+}
+
+/// Why an imported file or proof was rejected.
+#[derive(Debug)]
+pub enum ImportError {
+    /// The file is not in the supported subset of the prover's syntax.
+    Syntax { line: usize, message: String },
+    /// A `λ` binder or `_` whose type nothing determines.
+    Untyped(String),
+    /// A declaration whose type is a proposition.
+    Assumption(String),
+    /// A declaration of a name the context already gives another type.
+    Redeclared(String),
+    /// The proof is ill-typed.
+    Kernel(KernelError),
+    /// The proof type-checks, but proves something else.
+    Mismatch { proves: Term, statement: Term },
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::Syntax { line, message } => write!(f, "line {}: {}", line, message),
+            ImportError::Untyped(name) => write!(f, "cannot infer the type of `{}`; annotate it", name),
+            ImportError::Assumption(name) => {
+                write!(f, "`{}` is declared with a proposition as its type, which would assume it unproved", name)
+            }
+            ImportError::Redeclared(name) => write!(f, "`{}` is already declared with a different type", name),
+            ImportError::Kernel(e) => write!(f, "the proof does not type-check: {}", e),
+            ImportError::Mismatch { proves, statement } => {
+                write!(f, "the proof establishes {} rather than {}", proves, statement)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+impl From<KernelError> for ImportError {
+    fn from(e: KernelError) -> Self {
+        ImportError::Kernel(e)
+    }
+}
+
+/// Read a `prover` source file.
+pub fn parse_file(source: &str, prover: Prover) -> Result<ImportedFile, ImportError> {
+    Parser::new(source, prover)?.file()
+}
+
+/// Read a single term in `prover`'s syntax.
+pub fn parse_term(source: &str, prover: Prover) -> Result<Term, ImportError> {
+    let mut parser = Parser::new(source, prover)?;
+    let term = parser.term(0)?;
+    match parser.peek() {
+        None => Ok(term),
+        Some(_) => Err(parser.unexpected("the end of the term")),
+    }
+}
+
+/// Add `declarations` to `ctx` as opaque constants.
+///
+/// A name `ctx` already has is skipped when the types agree (the prelude's
+/// `Entity` is the exported `Entity : Type`) and refused otherwise.
+pub fn declare(ctx: &mut Context, declarations: &[(String, Term)]) -> Result<(), ImportError> {
+    for (name, ty) in declarations {
+        let ty = fill(ctx, ty, None)?;
+        if let Term::Sort(Universe::Prop | Universe::SProp) = infer_type(ctx, &ty)? {
+            return Err(ImportError::Assumption(name.clone()));
+        }
+        match ctx.get_global(name) {
+            Some(existing) if is_subtype(ctx, existing, &ty) && is_subtype(ctx, &ty, existing) => {}
+            Some(_) => return Err(ImportError::Redeclared(name.clone())),
+            None => ctx.add_declaration(name, ty),
+        }
+    }
+    Ok(())
+}
+
+/// Check that `proof` proves `statement` in `ctx`.
+pub fn check_proof(ctx: &Context, statement: &Term, proof: &Term) -> Result<(), ImportError> {
+    let statement = fill(ctx, statement, None)?;
+    let proof = fill(ctx, proof, Some(&statement))?;
+    let proves = infer_type(ctx, &proof)?;
+    if is_subtype(ctx, &proves, &statement) {
+        Ok(())
+    } else {
+        Err(ImportError::Mismatch { proves, statement })
+    }
+}
+
+/// Resolve what the notation left open: the types of unannotated `λ`
+/// binders, from the expected type, and of equations, from their left side.
+fn fill(ctx: &Context, term: &Term, expected: Option<&Term>) -> Result<Term, ImportError> {
+    Ok(match term {
+        Term::Lambda { param, param_type, body } => {
+            let expected_pi = expected.map(|ty| normalize(ctx, ty)).and_then(|ty| match ty {
+                Term::Pi { param, param_type, body_type } => Some((param, *param_type, *body_type)),
+                _ => None,
+            });
+            let param_type = match (param_type.as_ref(), &expected_pi) {
+                (Term::Hole, Some((_, domain, _))) => domain.clone(),
+                (Term::Hole, None) => return Err(ImportError::Untyped(param.clone())),
+                (ty, _) => fill(ctx, ty, None)?,
+            };
+            let body_expected = expected_pi
+                .map(|(pi_param, _, codomain)| substitute(&codomain, &pi_param, &Term::Var(param.clone())));
+            let inner = ctx.extend(param, param_type.clone());
+            Term::Lambda {
+                param: param.clone(),
+                param_type: Box::new(param_type),
+                body: Box::new(fill(&inner, body, body_expected.as_ref())?),
+            }
+        }
+        Term::Pi { param, param_type, body_type } => {
+            let param_type = fill(ctx, param_type, None)?;
+            let inner = ctx.extend(param, param_type.clone());
+            Term::Pi {
+                param: param.clone(),
+                param_type: Box::new(param_type),
+                body_type: Box::new(fill(&inner, body_type, None)?),
+            }
+        }
+        Term::Let { name, ty, value, body } => {
+            let value = fill(ctx, value, None)?;
+            let ty = match ty.as_ref() {
+                Term::Hole => infer_type(ctx, &value)?,
+                ty => fill(ctx, ty, None)?,
+            };
+            let inner = ctx.extend(name, ty.clone());
+            Term::Let {
+                name: name.clone(),
+                ty: Box::new(ty),
+                value: Box::new(value),
+                body: Box::new(fill(&inner, body, expected)?),
+            }
+        }
+        Term::App(function, right) => {
+            if let Term::App(eq, left) = function.as_ref() {
+                if let Term::App(head, ty) = eq.as_ref() {
+                    if matches!((head.as_ref(), ty.as_ref()), (Term::Global(g), Term::Hole) if g == "Eq") {
+                        let left = fill(ctx, left, None)?;
+                        let ty = infer_type(ctx, &left)?;
+                        return Ok(app(app(app(global("Eq"), ty), left), fill(ctx, right, None)?));
+                    }
+                }
+            }
+            app(fill(ctx, function, None)?, fill(ctx, right, None)?)
+        }
+        Term::Hole => return Err(ImportError::Untyped("_".to_string())),
+        other => other.clone(),
+    })
+}
+
+fn app(function: Term, argument: Term) -> Term {
+    Term::App(Box::new(function), Box::new(argument))
+}
+
+fn global(name: &str) -> Term {
+    Term::Global(name.to_string())
+}
+
+fn arrow(domain: Term, codomain: Term) -> Term {
+    Term::Pi { param: "_".to_string(), param_type: Box::new(domain), body_type: Box::new(codomain) }
+}
+
+// =============================================================================
+// Lexer
+// =============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Ident(String),
+    Num(String),
+    Sym(&'static str),
+}
+
+impl fmt::Display for Tok {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tok::Ident(s) | Tok::Num(s) => f.write_str(s),
+            Tok::Sym(s) => f.write_str(s),
+        }
+    }
+}
+
+struct Token {
+    tok: Tok,
+    line: usize,
+    /// The first token on its line.
+    starts_line: bool,
+}
+
+/// Longest first, so `<->` is not read as `<` and `->`.
+const SYMBOLS: &[&str] = &[
+    "<->", ":=", "=>", "->", "/\\", "\\/", "%Z", "(", ")", "{", "}", ",", ":", ";", "=", "~", ".", "→", "↔",
+    "∧", "∨", "¬", "∀", "∃", "↦", "λ", "Π",
+];
+
+fn lex(source: &str, prover: Prover) -> Result<Vec<Token>, ImportError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens: Vec<Token> = Vec::new();
+    let (mut i, mut line, mut line_of_last) = (0, 1, 0);
+    let ident_char = |c: char| c.is_alphanumeric() || c == '_' || c == '\'';
+    let starts = |at: usize, s: &str| s.chars().enumerate().all(|(k, c)| chars.get(at + k) == Some(&c));
+    while i < chars.len() {
+        let c = chars[i];
+        let mut push = |tok: Tok, line: usize| {
+            tokens.push(Token { tok, line, starts_line: line_of_last != line });
+            line_of_last = line;
+        };
+        if c == '\n' {
+            line += 1;
+            i += 1;
+        } else if c.is_whitespace() {
+            i += 1;
+        } else if prover == Prover::Lean4 && starts(i, "--") {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if (prover == Prover::Lean4 && starts(i, "/-")) || (prover == Prover::Coq && starts(i, "(*")) {
+            let close = if prover == Prover::Lean4 { "-/" } else { "*)" };
+            i += 2;
+            while i < chars.len() && !starts(i, close) {
+                line += usize::from(chars[i] == '\n');
+                i += 1;
+            }
+            i += 2;
+        } else if c == '«' {
+            let start = i + 1;
+            while i < chars.len() && chars[i] != '»' {
+                i += 1;
+            }
+            push(Tok::Ident(chars[start..i.min(chars.len())].iter().collect()), line);
+            i += 1;
+        } else if c.is_ascii_digit() || (c == '-' && chars.get(i + 1).is_some_and(|d| d.is_ascii_digit())) {
+            let start = i;
+            i += 1;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            push(Tok::Num(chars[start..i].iter().collect()), line);
+        } else if ident_char(c) && (!matches!(c, 'λ' | 'Π') || chars.get(i + 1).is_some_and(|&d| ident_char(d))) {
+            let start = i;
+            // A `.` continues a qualified name only when a name follows it.
+            while i < chars.len()
+                && (ident_char(chars[i]) || (chars[i] == '.' && chars.get(i + 1).is_some_and(|&d| d.is_alphabetic() || d == '_')))
+            {
+                i += 1;
+            }
+            push(Tok::Ident(chars[start..i].iter().collect()), line);
+        } else if let Some(symbol) = SYMBOLS.iter().find(|s| starts(i, s)) {
+            push(Tok::Sym(symbol), line);
+            i += symbol.chars().count();
+        } else {
+            return Err(ImportError::Syntax { line, message: format!("unexpected character `{}`", c) });
+        }
+    }
+    Ok(tokens)
+}
+
+// =============================================================================
+// Parser
+// =============================================================================
+
+/// Words that end a term: the start of the next command, or a keyword no
+/// supported term contains.
+const RESERVED: &[&str] = &[
+    "axiom", "by", "def", "else", "end", "example", "exists", "forall", "fun", "import", "in", "lemma",
+    "let", "match", "namespace", "open", "section", "sorry", "then", "theorem", "with", "Admitted", "Axiom",
+    "Defined", "End", "Lemma", "Module", "Parameter", "Proof", "Qed", "Theorem",
+];
+
+/// Lean commands a tactic block runs until.
+const LEAN_COMMANDS: &[&str] =
+    &["axiom", "def", "end", "example", "import", "lemma", "namespace", "open", "section", "theorem"];
+
+#[derive(Clone, Copy)]
+enum Op {
+    Iff,
+    Arrow,
+    Or,
+    And,
+    Eq,
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    prover: Prover,
+    /// Binders in scope, innermost last.
+    bound: Vec<String>,
+}
+
+impl Parser {
+    fn new(source: &str, prover: Prover) -> Result<Parser, ImportError> {
+        Ok(Parser { tokens: lex(source, prover)?, pos: 0, prover, bound: Vec::new() })
+    }
+
+    fn peek(&self) -> Option<&Tok> {
+        self.tokens.get(self.pos).map(|token| &token.tok)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Tok> {
+        self.tokens.get(self.pos + offset).map(|token| &token.tok)
+    }
+
+    fn line(&self) -> usize {
+        self.tokens.get(self.pos).or(self.tokens.last()).map_or(1, |token| token.line)
+    }
+
+    fn unexpected(&self, wanted: &str) -> ImportError {
+        let found = match self.peek() {
+            Some(tok) => format!("`{}`", tok),
+            None => "the end of the file".to_string(),
+        };
+        ImportError::Syntax { line: self.line(), message: format!("expected {}, found {}", wanted, found) }
+    }
+
+    fn is_sym(&self, symbol: &str) -> bool {
+        matches!(self.peek(), Some(Tok::Sym(s)) if *s == symbol)
+    }
+
+    fn is_word(&self, word: &str) -> bool {
+        matches!(self.peek(), Some(Tok::Ident(s)) if s == word)
+    }
+
+    fn eat_sym(&mut self, symbol: &str) -> bool {
+        let found = self.is_sym(symbol);
+        self.pos += usize::from(found);
+        found
+    }
+
+    fn eat_word(&mut self, word: &str) -> bool {
+        let found = self.is_word(word);
+        self.pos += usize::from(found);
+        found
+    }
+
+    fn expect_sym(&mut self, symbol: &str) -> Result<(), ImportError> {
+        if self.eat_sym(symbol) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("`{}`", symbol)))
+        }
+    }
+
+    fn name(&mut self) -> Result<String, ImportError> {
+        match self.peek() {
+            Some(Tok::Ident(name)) if !RESERVED.contains(&name.as_str()) => {
+                let name = name.clone();
+                self.pos += 1;
+                Ok(name)
+            }
+            _ => Err(self.unexpected("a name")),
+        }
+    }
+
+    /// Skip the rest of the current line (Lean) or sentence (Coq).
+    fn skip_command(&mut self) {
+        match self.prover {
+            Prover::Lean4 => {
+                let line = self.line();
+                while self.tokens.get(self.pos).is_some_and(|token| token.line == line) {
+                    self.pos += 1;
+                }
+            }
+            Prover::Coq => {
+                while self.peek().is_some() && !self.eat_sym(".") {
+                    self.pos += 1;
+                }
+            }
+        }
+    }
+
+    fn file(&mut self) -> Result<ImportedFile, ImportError> {
+        let mut file = ImportedFile::default();
+        while let Some(tok) = self.peek() {
+            let Tok::Ident(word) = tok.clone() else {
+                return Err(self.unexpected("a declaration"));
+            };
+            match (self.prover, word.as_str()) {
+                (Prover::Lean4, "namespace" | "end" | "open" | "import" | "section" | "universe" | "set_option")
+                | (Prover::Coq, "Module" | "End" | "Require" | "Import" | "Export" | "From" | "Set" | "Unset" | "Open") => {
+                    self.skip_command()
+                }
+                (Prover::Lean4, "axiom") | (Prover::Coq, "Parameter" | "Axiom" | "Variable" | "Hypothesis") => {
+                    self.pos += 1;
+                    let (name, ty) = self.declaration()?;
+                    file.declarations.push((name, ty));
+                }
+                (Prover::Lean4, "theorem" | "lemma")
+                | (Prover::Coq, "Theorem" | "Lemma" | "Example" | "Corollary" | "Proposition" | "Fact" | "Remark") => {
+                    self.pos += 1;
+                    file.theorems.push(self.theorem()?);
+                }
+                _ => return Err(self.unexpected("a declaration")),
+            }
+        }
+        Ok(file)
+    }
+
+    /// `name binders : type`, the binders folded into the type.
+    fn declaration(&mut self) -> Result<(String, Term), ImportError> {
+        let name = self.name()?;
+        if self.prover == Prover::Lean4 && self.eat_sym(".") {
+            // Universe parameters, `.{u, v}`: the levels are read as variables.
+            self.expect_sym("{")?;
+            while self.peek().is_some() && !self.eat_sym("}") {
+                self.pos += 1;
+            }
+        }
+        let depth = self.bound.len();
+        let binders = self.binders(false)?;
+        self.expect_sym(":")?;
+        let ty = self.term(0);
+        self.bound.truncate(depth);
+        let ty = pis(&binders, ty?);
+        if self.prover == Prover::Coq {
+            self.expect_sym(".")?;
+        }
+        Ok((name, ty))
+    }
+
+    fn theorem(&mut self) -> Result<ImportedTheorem, ImportError> {
+        let name = self.name()?;
+        let depth = self.bound.len();
+        let result = self.theorem_body(&name);
+        self.bound.truncate(depth);
+        result
+    }
+
+    fn theorem_body(&mut self, name: &str) -> Result<ImportedTheorem, ImportError> {
+        let binders = self.binders(false)?;
+        self.expect_sym(":")?;
+        let statement = pis(&binders, self.term(0)?);
+        let prover = self.prover;
+        let proof = match prover {
+            Prover::Lean4 => {
+                self.expect_sym(":=")?;
+                if self.eat_word("by") {
+                    while self.tokens.get(self.pos).is_some_and(|token| {
+                        !(token.starts_line && matches!(&token.tok, Tok::Ident(w) if LEAN_COMMANDS.contains(&w.as_str())))
+                    }) {
+                        self.pos += 1;
+                    }
+                    None
+                } else if self.eat_word("sorry") {
+                    None
+                } else {
+                    Some(self.term(0)?)
+                }
+            }
+            Prover::Coq if self.eat_sym(":=") => {
+                let proof = self.term(0)?;
+                self.expect_sym(".")?;
+                Some(proof)
+            }
+            Prover::Coq => {
+                self.expect_sym(".")?;
+                if self.eat_word("Proof") {
+                    self.expect_sym(".")?;
+                }
+                let mut proof = None;
+                if self.eat_word("exact") {
+                    proof = Some(self.term(0)?);
+                    self.expect_sym(".")?;
+                }
+                // Anything else is tactics, which leave no term to check.
+                loop {
+                    match self.peek() {
+                        Some(Tok::Ident(w)) if w == "Qed" || w == "Defined" => {
+                            self.pos += 1;
+                            self.expect_sym(".")?;
+                            break;
+                        }
+                        Some(Tok::Ident(w)) if w == "Admitted" || w == "Abort" => {
+                            self.pos += 1;
+                            self.expect_sym(".")?;
+                            proof = None;
+                            break;
+                        }
+                        Some(_) => {
+                            proof = None;
+                            self.skip_command();
+                        }
+                        None => return Err(self.unexpected(&format!("`Qed.` to close `{}`", name))),
+                    }
+                }
+                proof
+            }
+        };
+        Ok(ImportedTheorem { name: name.to_string(), statement, proof: proof.map(|p| lambdas(&binders, p)) })
+    }
+
+    /// Binder groups, bringing each name into scope: `(x y : A) (h : P)`,
+    /// or a single unparenthesized `x y : A`; bare names (given
+    /// `untyped`) have no type yet.
+    fn binders(&mut self, untyped: bool) -> Result<Vec<(String, Term)>, ImportError> {
+        let mut binders = Vec::new();
+        loop {
+            // `(` starts a binder group only when names and a `:` follow.
+            let group = self.is_sym("(") && {
+                let names = (1..).take_while(|&k| matches!(self.peek_at(k), Some(Tok::Ident(_)))).count();
+                names > 0 && matches!(self.peek_at(names + 1), Some(Tok::Sym(":")))
+            };
+            if group {
+                self.pos += 1;
+                let names = self.names()?;
+                self.expect_sym(":")?;
+                let ty = self.term(0)?;
+                self.expect_sym(")")?;
+                for name in names {
+                    self.bound.push(name.clone());
+                    binders.push((name, ty.clone()));
+                }
+            } else if matches!(self.peek(), Some(Tok::Ident(w)) if !RESERVED.contains(&w.as_str())) && binders.is_empty() {
+                let names = self.names()?;
+                let ty = if self.eat_sym(":") {
+                    self.term(0)?
+                } else if untyped {
+                    Term::Hole
+                } else {
+                    return Err(self.unexpected("`:`"));
+                };
+                for name in names {
+                    self.bound.push(name.clone());
+                    binders.push((name, ty.clone()));
+                }
+                return Ok(binders);
+            } else {
+                return Ok(binders);
+            }
+        }
+    }
+
+    fn names(&mut self) -> Result<Vec<String>, ImportError> {
+        let mut names = vec![self.name()?];
+        while matches!(self.peek(), Some(Tok::Ident(w)) if !RESERVED.contains(&w.as_str())) {
+            names.push(self.name()?);
+        }
+        Ok(names)
+    }
+
+    fn infix(&self) -> Option<(Op, u8, u8)> {
+        let Some(Tok::Sym(symbol)) = self.peek() else {
+            return None;
+        };
+        let op = match *symbol {
+            "↔" | "<->" => Op::Iff,
+            "→" | "->" => Op::Arrow,
+            "∨" | "\\/" => Op::Or,
+            "∧" | "/\\" => Op::And,
+            "=" => Op::Eq,
+            _ => return None,
+        };
+        // (left, right) binding powers; Coq's levels reversed into the same scale.
+        let (left, right) = match (self.prover, op) {
+            (Prover::Lean4, Op::Iff) => (20, 21),
+            (Prover::Lean4, Op::Arrow) => (25, 25),
+            (Prover::Lean4, Op::Or) => (30, 30),
+            (Prover::Lean4, Op::And) => (35, 35),
+            (Prover::Lean4, Op::Eq) => (50, 51),
+            (Prover::Coq, Op::Arrow) => (1, 1),
+            (Prover::Coq, Op::Iff) => (5, 6),
+            (Prover::Coq, Op::Or) => (15, 15),
+            (Prover::Coq, Op::And) => (20, 20),
+            (Prover::Coq, Op::Eq) => (30, 31),
+        };
+        Some((op, left, right))
+    }
+
+    /// A term whose infix operators all bind at least as tightly as `min`.
+    fn term(&mut self, min: u8) -> Result<Term, ImportError> {
+        let mut left = self.prefix()?;
+        while let Some((op, left_power, right_power)) = self.infix() {
+            if left_power < min {
+                break;
+            }
+            self.pos += 1;
+            let right = self.term(right_power)?;
+            left = match op {
+                Op::Iff => app(app(global("And"), arrow(left.clone(), right.clone())), arrow(right, left)),
+                Op::Arrow => arrow(left, right),
+                Op::Or => app(app(global("Or"), left), right),
+                Op::And => app(app(global("And"), left), right),
+                Op::Eq => app(app(app(global("Eq"), Term::Hole), left), right),
+            };
+        }
+        Ok(left)
+    }
+
+    fn prefix(&mut self) -> Result<Term, ImportError> {
+        if self.eat_sym("∀") || self.eat_sym("Π") || self.eat_word("forall") {
+            return self.binding(",", false, pis);
+        }
+        if self.eat_sym("∃") || self.eat_word("exists") {
+            return self.binding(",", false, |binders, body| {
+                binders.iter().rev().fold(body, |body, (name, ty)| {
+                    let predicate =
+                        Term::Lambda { param: name.clone(), param_type: Box::new(ty.clone()), body: Box::new(body) };
+                    app(app(global("Ex"), ty.clone()), predicate)
+                })
+            });
+        }
+        if self.eat_sym("λ") || self.eat_word("fun") {
+            return self.binding("=>", true, lambdas);
+        }
+        if self.eat_word("let") {
+            let name = self.name()?;
+            let ty = if self.eat_sym(":") { self.term(0)? } else { Term::Hole };
+            self.expect_sym(":=")?;
+            let value = self.term(0)?;
+            if !(self.eat_sym(";") || self.eat_word("in")) {
+                return Err(self.unexpected(if self.prover == Prover::Coq { "`in`" } else { "`;`" }));
+            }
+            self.bound.push(name.clone());
+            let body = self.term(0);
+            self.bound.pop();
+            return Ok(Term::Let { name, ty: Box::new(ty), value: Box::new(value), body: Box::new(body?) });
+        }
+        if self.eat_sym("¬") || self.eat_sym("~") {
+            let power = if self.prover == Prover::Lean4 { 40 } else { 25 };
+            return Ok(app(global("Not"), self.term(power)?));
+        }
+        self.application()
+    }
+
+    /// Binders, then `separator`, then a body running as far right as it can.
+    fn binding(
+        &mut self,
+        separator: &str,
+        untyped: bool,
+        build: impl FnOnce(&[(String, Term)], Term) -> Term,
+    ) -> Result<Term, ImportError> {
+        let depth = self.bound.len();
+        let result = (|| {
+            let binders = self.binders(untyped)?;
+            if binders.is_empty() {
+                return Err(self.unexpected("a binder"));
+            }
+            if !(self.eat_sym(separator) || (separator == "=>" && self.eat_sym("↦"))) {
+                return Err(self.unexpected(&format!("`{}`", separator)));
+            }
+            let body = self.term(0)?;
+            Ok(build(&binders, body))
+        })();
+        self.bound.truncate(depth);
+        result
+    }
+
+    fn application(&mut self) -> Result<Term, ImportError> {
+        let mut term = self.atom()?;
+        while self.starts_atom() {
+            term = app(term, self.atom()?);
+        }
+        Ok(term)
+    }
+
+    fn starts_atom(&self) -> bool {
+        match self.peek() {
+            Some(Tok::Ident(w)) => !RESERVED.contains(&w.as_str()),
+            Some(Tok::Num(_)) => true,
+            Some(Tok::Sym(s)) => *s == "(",
+            None => false,
+        }
+    }
+
+    fn atom(&mut self) -> Result<Term, ImportError> {
+        let line = self.line();
+        match self.peek().cloned() {
+            Some(Tok::Sym("(")) => {
+                self.pos += 1;
+                let term = self.term(0)?;
+                let term = if self.eat_sym(":") {
+                    // An ascription fixes a numeral's type; the kernel checks
+                    // any other term's type itself.
+                    let ty = self.term(0)?;
+                    match (&term, &ty) {
+                        (Term::Lit(Literal::Int(n)), Term::Global(g)) if g == "Nat" || g == "_root_.Nat" => {
+                            if *n < 0 {
+                                return Err(ImportError::Syntax { line, message: format!("a negative `Nat`, `{}`", n) });
+                            }
+                            Term::Lit(Literal::Nat(logicaffeine_base::BigInt::from_i64(*n)))
+                        }
+                        _ => term,
+                    }
+                } else {
+                    term
+                };
+                self.expect_sym(")")?;
+                Ok(term)
+            }
+            Some(Tok::Num(digits)) => {
+                self.pos += 1;
+                let value = logicaffeine_base::BigInt::parse_decimal(&digits)
+                    .ok_or_else(|| ImportError::Syntax { line, message: format!("the numeral `{}`", digits) })?;
+                // Lean's bare numerals are exported `Int`s; Coq's are `nat`s
+                // unless scoped `%Z`.
+                if self.prover == Prover::Lean4 || self.eat_sym("%Z") {
+                    Ok(Term::Lit(int_lit(value)))
+                } else if digits.starts_with('-') {
+                    Err(ImportError::Syntax { line, message: format!("a negative `nat`, `{}`", digits) })
+                } else {
+                    Ok(Term::Lit(Literal::Nat(value)))
+                }
+            }
+            Some(Tok::Ident(word)) if !RESERVED.contains(&word.as_str()) => {
+                self.pos += 1;
+                Ok(match word.as_str() {
+                    "_" => Term::Hole,
+                    "Prop" => Term::Sort(Universe::Prop),
+                    "SProp" => Term::Sort(Universe::SProp),
+                    "Type" | "Set" => match (self.prover, self.peek().cloned()) {
+                        (Prover::Lean4, Some(Tok::Num(n))) => {
+                            self.pos += 1;
+                            let level = n
+                                .parse()
+                                .map_err(|_| ImportError::Syntax { line, message: format!("the universe `{}`", n) })?;
+                            Term::Sort(Universe::Type(level))
+                        }
+                        _ => Term::Sort(Universe::Type(0)),
+                    },
+                    "Sort" => match self.peek().cloned() {
+                        Some(Tok::Num(n)) if n == "0" => {
+                            self.pos += 1;
+                            Term::Sort(Universe::Prop)
+                        }
+                        Some(Tok::Ident(level)) => {
+                            self.pos += 1;
+                            Term::Sort(Universe::Var(level))
+                        }
+                        _ => return Err(self.unexpected("a universe level")),
+                    },
+                    _ if self.bound.contains(&word) => Term::Var(word),
+                    _ => Term::Global(word),
+                })
+            }
+            _ => Err(self.unexpected("a term")),
+        }
+    }
+}
+
+fn pis(binders: &[(String, Term)], body: Term) -> Term {
+    binders.iter().rev().fold(body, |body, (name, ty)| Term::Pi {
+        param: name.clone(),
+        param_type: Box::new(ty.clone()),
+        body_type: Box::new(body),
+    })
+}
+
+fn lambdas(binders: &[(String, Term)], body: Term) -> Term {
+    binders.iter().rev().fold(body, |body, (name, ty)| Term::Lambda {
+        param: name.clone(),
+        param_type: Box::new(ty.clone()),
+        body: Box::new(body),
+    })
+}
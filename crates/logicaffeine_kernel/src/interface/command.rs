@@ -9,11 +9,14 @@ pub enum Command {
     ///
     /// If `ty` is None, the type is inferred from the body.
     /// If `is_hint` is true, register as a hint for auto tactic.
+    /// If `simp` is set, register the proved equation as a rule of that
+    /// simp set, with that priority.
     Definition {
         name: String,
         ty: Option<Term>,
         body: Term,
         is_hint: bool,
+        simp: Option<(String, i64)>,
         /// How many leading parameters of `ty` are implicit (`{x:T}` binders).
         implicit_count: usize,
     },
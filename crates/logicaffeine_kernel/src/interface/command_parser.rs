@@ -97,6 +97,7 @@ fn parse_definition(input: &str) -> Result<Command, ParseError> {
             ty: Some(ty),
             body,
             is_hint: false,
+            simp: None,
             implicit_count,
         })
     } else {
@@ -114,6 +115,7 @@ fn parse_definition(input: &str) -> Result<Command, ParseError> {
             ty: None,
             body,
             is_hint: false,
+            simp: None,
             implicit_count: 0,
         })
     }
@@ -459,6 +459,7 @@ impl<'a> LiterateParser<'a> {
             ty,
             body: func_body,
             is_hint: false,
+            simp: None,
             implicit_count: 0,
         })
     }
@@ -508,6 +509,7 @@ impl<'a> LiterateParser<'a> {
             ty: None,
             body,
             is_hint: false,
+            simp: None,
             implicit_count: 0,
         })
     }
@@ -569,7 +571,7 @@ impl<'a> LiterateParser<'a> {
                     found: self.peek_word().unwrap_or("EOF".to_string()),
                 });
             }
-            self.skip_whitespace();
+            self.skip_whitespace_and_newlines();
 
             // Parse proof tactic and apply it to the statement
             let proof = self.parse_proof_tactic(&statement)?;
@@ -607,15 +609,55 @@ impl<'a> LiterateParser<'a> {
             false
         };
 
+        // Check for optional "Mark this equation as a simplification rule." sentence
+        self.skip_whitespace_and_newlines();
+        let simp = if self.peek_keyword("Mark") {
+            Some(self.parse_simp_mark()?)
+        } else {
+            None
+        };
+
         Ok(Command::Definition {
             name,
             ty,
             body,
             is_hint,
+            simp,
             implicit_count: 0,
         })
     }
 
+    /// Parse: Mark this equation as a simplification rule[ in Set][ with priority N].
+    ///
+    /// Returns the simp set (the default set when none is named) and the priority.
+    fn parse_simp_mark(&mut self) -> Result<(String, i64), ParseError> {
+        for word in ["Mark", "this", "equation", "as", "a", "simplification", "rule"] {
+            self.skip_whitespace();
+            self.consume_keyword(word)?;
+        }
+        self.skip_whitespace();
+        let set = if self.try_consume_keyword("in") {
+            self.parse_ident()?
+        } else {
+            crate::simp::DEFAULT_SET.to_string()
+        };
+        self.skip_whitespace();
+        let priority = if self.try_consume_keyword("with") {
+            self.skip_whitespace();
+            self.consume_keyword("priority")?;
+            self.skip_whitespace();
+            match self.parse_number()? {
+                Term::Lit(Literal::Int(n)) => n,
+                _ => unreachable!("parse_number returns an integer literal"),
+            }
+        } else {
+            0
+        };
+        self.skip_whitespace();
+        let _ = self.try_consume(".");
+        Ok((set, priority))
+    }
+
     /// Parse a proof tactic like `ring.` and return the proof term.
     ///
     /// Supported tactics:
@@ -665,15 +707,18 @@ impl<'a> LiterateParser<'a> {
                 Box::new(goal_syntax),
             ))
         } else if self.try_consume_keyword("simp") {
-            // Simp tactic: simplification and arithmetic
+            // Simp tactic: simplification and arithmetic, optionally `using` a simp set
+            self.skip_whitespace();
+            let set = if self.try_consume_keyword("using") {
+                Some(self.parse_ident()?)
+            } else {
+                None
+            };
             self.skip_whitespace();
             let _ = self.try_consume(".");
 
             let goal_syntax = self.term_to_syntax(statement, &[]);
-            Ok(Term::App(
-                Box::new(Term::Global("try_simp".to_string())),
-                Box::new(goal_syntax),
-            ))
+            Ok(simp_tactic(set, goal_syntax))
         } else if self.try_consume_keyword("omega") {
             // Omega tactic: true integer arithmetic (Omega Test)
             self.skip_whitespace();
@@ -684,6 +729,16 @@ impl<'a> LiterateParser<'a> {
                 Box::new(Term::Global("try_omega".to_string())),
                 Box::new(goal_syntax),
             ))
+        } else if self.try_consume_keyword("nia") {
+            // Nia tactic: nonlinear integer arithmetic (products and squares)
+            self.skip_whitespace();
+            let _ = self.try_consume(".");
+
+            let goal_syntax = self.term_to_syntax(statement, &[]);
+            Ok(Term::App(
+                Box::new(Term::Global("try_nia".to_string())),
+                Box::new(goal_syntax),
+            ))
         } else if self.try_consume_keyword("auto") {
             // Auto tactic: tries all decision procedures in sequence
             self.skip_whitespace();
@@ -695,17 +750,28 @@ impl<'a> LiterateParser<'a> {
                 Box::new(goal_syntax),
             ))
         } else if self.try_consume_keyword("induction") {
-            // Induction tactic: structural induction on a variable
+            // Induction tactic: structural induction on a variable, `induction t : Tree.`
+            // (Nat when no type is given), one bullet per constructor
             self.skip_whitespace();
             let var_name = self.parse_ident()?;
             self.skip_whitespace();
+            let ind_type = if self.try_consume(":") {
+                self.skip_whitespace();
+                self.parse_type()?
+            } else {
+                Term::Global("Nat".to_string())
+            };
+            self.skip_whitespace();
             let _ = self.try_consume(".");
 
+            let ind_syntax = self.term_to_syntax(&ind_type, &[]);
+            let motive = self.induction_motive(&var_name, &ind_syntax, statement)?;
+
             // Parse bullet cases
-            let cases = self.parse_bullet_cases(statement)?;
+            let cases = self.parse_bullet_cases(&ind_syntax, &motive)?;
 
             // Build induction derivation
-            self.build_induction_derivation(&var_name, statement, cases)
+            Ok(build_induction_derivation(ind_syntax, motive, cases))
         } else {
             Err(ParseError::Expected {
                 expected: "proof tactic (ring, refl, lia, cc, simp, omega, auto, induction)".to_string(),
@@ -732,36 +798,87 @@ impl<'a> LiterateParser<'a> {
         }
     }
 
-    /// Parse bullet-pointed cases for induction
-    fn parse_bullet_cases(&mut self, statement: &Term) -> Result<Vec<Term>, ParseError> {
+    /// Parse bullet-pointed cases for induction, one per constructor in order.
+    ///
+    /// Case `i` proves `induction_step_goal ind motive i` — the motive at the
+    /// constructor, under its arguments and induction hypotheses. The bullet's
+    /// tactics see that goal with its arguments introduced, and `DIntros`
+    /// closes the binders again.
+    fn parse_bullet_cases(&mut self, ind_type: &Term, motive: &Term) -> Result<Vec<Term>, ParseError> {
         let mut cases = Vec::new();
 
         while self.peek_bullet() {
             self.consume_bullet();
             self.skip_whitespace();
 
+            let case_goal = Term::App(
+                Box::new(Term::App(
+                    Box::new(Term::App(
+                        Box::new(Term::Global("induction_step_goal".to_string())),
+                        Box::new(ind_type.clone()),
+                    )),
+                    Box::new(motive.clone()),
+                )),
+                Box::new(nat_literal(cases.len())),
+            );
+            let introduced = Term::App(
+                Box::new(Term::Global("syn_intros".to_string())),
+                Box::new(case_goal.clone()),
+            );
+
             // Parse tactics for this case
-            let case_proof = self.parse_tactic_sequence(statement)?;
-            cases.push(case_proof);
+            let case_proof = self.parse_tactic_sequence(&introduced)?;
+            cases.push(Term::App(
+                Box::new(Term::App(
+                    Box::new(Term::Global("DIntros".to_string())),
+                    Box::new(case_goal),
+                )),
+                Box::new(case_proof),
+            ));
         }
 
         Ok(cases)
     }
 
-    /// Parse a sequence of tactics (simp. auto.) on a single line
-    fn parse_tactic_sequence(&mut self, statement: &Term) -> Result<Term, ParseError> {
+    /// The induction motive `SLam ind_type body`, where body is the statement
+    /// with `var_name` as the bound variable.
+    fn induction_motive(&self, var_name: &str, ind_type: &Term, statement: &Term) -> Result<Term, ParseError> {
+        let open = bind_global(statement, var_name);
+        let body = self.term_to_syntax(&open, &[var_name.to_string()]);
+        if body == self.term_to_syntax(statement, &[]) {
+            return Err(ParseError::Expected {
+                expected: "a variable of the statement to induct on".to_string(),
+                found: var_name.to_string(),
+            });
+        }
+        Ok(Term::App(
+            Box::new(Term::App(
+                Box::new(Term::Global("SLam".to_string())),
+                Box::new(ind_type.clone()),
+            )),
+            Box::new(body),
+        ))
+    }
+
+    /// Parse a sequence of tactics (simp. auto.) on a single line, each applied
+    /// to the goal Syntax `goal_syntax`
+    fn parse_tactic_sequence(&mut self, goal_syntax: &Term) -> Result<Term, ParseError> {
         let mut tactics = Vec::new();
 
         loop {
             self.skip_whitespace();
 
-            // Stop at newline followed by bullet, end of input, or Attribute keyword
-            if self.peek_bullet() || self.at_end() || self.peek_keyword("Attribute") {
+            // Stop at newline followed by bullet, end of input, Attribute or Mark
+            if self.peek_bullet()
+                || self.at_end()
+                || self.peek_keyword("Attribute")
+                || self.peek_keyword("Mark")
+            {
                 break;
             }
 
             // Try to parse a single tactic
-            match self.parse_single_tactic(statement) {
+            match self.parse_single_tactic(goal_syntax) {
                 Ok(tactic) => tactics.push(tactic),
                 Err(_) => break,
             }
@@ -787,8 +904,8 @@ impl<'a> LiterateParser<'a> {
     }
 
     /// Parse a single tactic (ring, auto, etc.) without consuming trailing period
-    fn parse_single_tactic(&mut self, statement: &Term) -> Result<Term, ParseError> {
-        let goal_syntax = self.term_to_syntax(statement, &[]);
+    fn parse_single_tactic(&mut self, goal_syntax: &Term) -> Result<Term, ParseError> {
+        let goal_syntax = goal_syntax.clone();
 
         if self.try_consume_keyword("ring") {
             self.skip_whitespace();
@@ -819,17 +936,27 @@ impl<'a> LiterateParser<'a> {
                 Box::new(goal_syntax),
             ))
         } else if self.try_consume_keyword("simp") {
+            self.skip_whitespace();
+            let set = if self.try_consume_keyword("using") {
+                Some(self.parse_ident()?)
+            } else {
+                None
+            };
+            self.skip_whitespace();
+            let _ = self.try_consume(".");
+            Ok(simp_tactic(set, goal_syntax))
+        } else if self.try_consume_keyword("omega") {
             self.skip_whitespace();
             let _ = self.try_consume(".");
             Ok(Term::App(
-                Box::new(Term::Global("try_simp".to_string())),
+                Box::new(Term::Global("try_omega".to_string())),
                 Box::new(goal_syntax),
             ))
-        } else if self.try_consume_keyword("omega") {
+        } else if self.try_consume_keyword("nia") {
             self.skip_whitespace();
             let _ = self.try_consume(".");
             Ok(Term::App(
-                Box::new(Term::Global("try_omega".to_string())),
+                Box::new(Term::Global("try_nia".to_string())),
                 Box::new(goal_syntax),
             ))
         } else if self.try_consume_keyword("auto") {
@@ -861,47 +988,6 @@ impl<'a> LiterateParser<'a> {
         }
     }
 
-    /// Build an induction derivation from variable name and case proofs
-    fn build_induction_derivation(
-        &self,
-        _var_name: &str,
-        statement: &Term,
-        cases: Vec<Term>,
-    ) -> Result<Term, ParseError> {
-        // Build the inductive type (for now assume Nat if not specified)
-        let ind_type = Term::App(
-            Box::new(Term::Global("SName".to_string())),
-            Box::new(Term::Lit(Literal::Text("Nat".to_string()))),
-        );
-
-        // Build the motive from the statement
-        let motive = self.term_to_syntax(statement, &[]);
-
-        // Build the cases as DCase chain: DCase c1 (DCase c2 ... DCaseEnd)
-        let mut case_chain = Term::Global("DCaseEnd".to_string());
-        for case_proof in cases.into_iter().rev() {
-            case_chain = Term::App(
-                Box::new(Term::App(
-                    Box::new(Term::Global("DCase".to_string())),
-                    Box::new(case_proof),
-                )),
-                Box::new(case_chain),
-            );
-        }
-
-        // Build: try_induction ind_type motive cases
-        Ok(Term::App(
-            Box::new(Term::App(
-                Box::new(Term::App(
-                    Box::new(Term::Global("try_induction".to_string())),
-                    Box::new(ind_type),
-                )),
-                Box::new(motive),
-            )),
-            Box::new(case_chain),
-        ))
-    }
-
     /// Parse function parameters: (x: T) and (y: U) or (x: T) (y: U)
     fn parse_function_params(&mut self) -> Result<Vec<(String, Term)>, ParseError> {
         let mut params = Vec::new();
@@ -2125,6 +2211,77 @@ impl<'a> LiterateParser<'a> {
     }
 }
 
+/// Build `try_induction ind_type motive (DCase c1 (DCase c2 ... DCaseEnd))`.
+fn build_induction_derivation(ind_type: Term, motive: Term, cases: Vec<Term>) -> Term {
+    let mut case_chain = Term::Global("DCaseEnd".to_string());
+    for case_proof in cases.into_iter().rev() {
+        case_chain = Term::App(
+            Box::new(Term::App(
+                Box::new(Term::Global("DCase".to_string())),
+                Box::new(case_proof),
+            )),
+            Box::new(case_chain),
+        );
+    }
+
+    Term::App(
+        Box::new(Term::App(
+            Box::new(Term::App(
+                Box::new(Term::Global("try_induction".to_string())),
+                Box::new(ind_type),
+            )),
+            Box::new(motive),
+        )),
+        Box::new(case_chain),
+    )
+}
+
+/// The Nat literal `Succ (... (Succ Zero))` for `n`.
+fn nat_literal(n: usize) -> Term {
+    (0..n).fold(Term::Global("Zero".to_string()), |acc, _| {
+        Term::App(Box::new(Term::Global("Succ".to_string())), Box::new(acc))
+    })
+}
+
+/// Turn the free name `name` (parsed as a global) into the variable `name`.
+fn bind_global(term: &Term, name: &str) -> Term {
+    match term {
+        Term::Global(g) if g == name => Term::Var(name.to_string()),
+        Term::App(f, a) => Term::App(Box::new(bind_global(f, name)), Box::new(bind_global(a, name))),
+        Term::Lambda { param, param_type, body } if param != name => Term::Lambda {
+            param: param.clone(),
+            param_type: Box::new(bind_global(param_type, name)),
+            body: Box::new(bind_global(body, name)),
+        },
+        Term::Pi { param, param_type, body_type } if param != name => Term::Pi {
+            param: param.clone(),
+            param_type: Box::new(bind_global(param_type, name)),
+            body_type: Box::new(bind_global(body_type, name)),
+        },
+        _ => term.clone(),
+    }
+}
+
+/// `try_simp goal`, or `try_simp_using (SName set) goal` for `simp using set`.
+fn simp_tactic(set: Option<String>, goal_syntax: Term) -> Term {
+    match set {
+        None => Term::App(
+            Box::new(Term::Global("try_simp".to_string())),
+            Box::new(goal_syntax),
+        ),
+        Some(set) => Term::App(
+            Box::new(Term::App(
+                Box::new(Term::Global("try_simp_using".to_string())),
+                Box::new(Term::App(
+                    Box::new(Term::Global("SName".to_string())),
+                    Box::new(Term::Lit(Literal::Text(set))),
+                )),
+            )),
+            Box::new(goal_syntax),
+        ),
+    }
+}
+
 // ============================================================
 // UNIT TESTS
 // ============================================================
@@ -6,9 +6,10 @@ use super::command::Command;
 use super::command_parser::parse_command;
 use super::error::InterfaceError;
 use crate::prelude::StandardLibrary;
+use crate::simp::SimpRule;
 use crate::{
-    auto_bind_implicits, bind_self_recursion, derive_recursor, fill_match_motives, infer_type,
-    normalize, surface_elaborate, surface_elaborate_against, Context, Term,
+    auto_bind_implicits, bind_self_recursion, derive_induction, derive_recursor, fill_match_motives,
+    infer_type, normalize, surface_elaborate, surface_elaborate_against, Context, KernelError, Literal, Term,
 };
 
 /// The Vernacular REPL.
@@ -33,7 +34,7 @@ impl Repl {
         let cmd = parse_command(input)?;
 
         match cmd {
-            Command::Definition { name, ty, body, is_hint, implicit_count } => {
+            Command::Definition { name, ty, body, is_hint, simp, implicit_count } => {
                 // Auto-bind free type variables (`id : A -> A`) as leading implicits, then
                 // elaborate the body against its declared type — implicit arguments are
                 // filled from the body's own arguments AND from the expected type.
@@ -54,6 +55,13 @@ impl Repl {
                 // Use provided type or inferred type
                 let ty = ty.unwrap_or(inferred_ty);
 
+                // A proved equation marked as a simplification rule joins its simp
+                // set before the theorem is added, so a refused rule adds neither.
+                if let Some((set, priority)) = simp {
+                    let rule = self.simp_rule(&name, &body, priority)?;
+                    self.ctx.add_simp_rule(&set, rule)?;
+                }
+
                 // Add definition to context
                 self.ctx.add_definition(name.clone(), ty, body);
 
@@ -115,12 +123,16 @@ impl Repl {
                 }
 
                 // Auto-derive the recursor (the dependent eliminator) — declaring an
-                // inductive gives you induction/recursion for free, as `{Name}_rec`. If
-                // derivation is not yet supported for this shape, the inductive is still
-                // usable; we simply skip the eliminator.
+                // inductive gives you induction/recursion for free, as `{Name}_rec`, and
+                // its Prop-motive form as `{Name}_ind`. If derivation is not yet
+                // supported for this shape, the inductive is still usable; we simply
+                // skip the eliminators.
                 if let Ok((rec_ty, rec_term)) = derive_recursor(&self.ctx, &name) {
                     self.ctx.add_definition(format!("{}_rec", name), rec_ty, rec_term);
                 }
+                if let Ok((ind_ty, ind_term)) = derive_induction(&self.ctx, &name) {
+                    self.ctx.add_definition(format!("{}_ind", name), ind_ty, ind_term);
+                }
 
                 Ok(String::new()) // Silent success
             }
@@ -288,6 +300,13 @@ impl Repl {
                                         rec_term,
                                     );
                                 }
+                                if let Ok((ind_ty, ind_term)) = derive_induction(&self.ctx, name) {
+                                    self.ctx.add_definition(
+                                        format!("{}_ind", name),
+                                        ind_ty,
+                                        ind_term,
+                                    );
+                                }
                             }
                         }
                     }
@@ -306,6 +325,26 @@ impl Repl {
         results
     }
 
+    /// The simp rule of a theorem marked as a simplification rule: the
+    /// equation its derivation concludes. Names the context knows are
+    /// constants; every other argument-position name is a pattern variable.
+    fn simp_rule(&self, name: &str, proof: &Term, priority: i64) -> Result<SimpRule, InterfaceError> {
+        let concluded = normalize(
+            &self.ctx,
+            &Term::App(Box::new(Term::Global("concludes".to_string())), Box::new(proof.clone())),
+        );
+        let error = Term::App(
+            Box::new(Term::Global("SName".to_string())),
+            Box::new(Term::Lit(Literal::Text("Error".to_string()))),
+        );
+        if concluded == error {
+            return Err(KernelError::SimpRuleRejected(format!("'{}' is not proved", name)).into());
+        }
+        Ok(SimpRule::from_equation(name, &concluded, priority, |n| {
+            self.ctx.get_global(n).is_some()
+        })?)
+    }
+
     /// Get a reference to the underlying context.
     pub fn context(&self) -> &Context {
         &self.ctx
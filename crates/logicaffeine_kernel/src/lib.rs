@@ -13,6 +13,7 @@ mod reduction;
 pub mod reify;
 pub mod ring;
 pub mod lia;
+pub mod nia;
 pub mod field_algebra;
 pub mod word_ring;
 pub mod bitvector;
@@ -20,14 +21,19 @@ pub mod cc;
 pub mod eval;
 pub mod simp;
 pub mod elaborate;
+pub mod export;
+pub mod hashcons;
+pub mod import;
 pub mod omega;
 pub mod recheck;
 pub mod recursor;
 pub mod termination;
+pub mod universe;
 mod term;
 mod type_checker;
 
 pub use context::{Context, MutualInductive, StructInfo};
+pub use universe::UniverseConstraint;
 pub use inductive_compile::{NestedDecl, NestedInfo};
 pub use error::{KernelError, KernelResult};
 pub use eval::{
@@ -41,7 +47,7 @@ pub use elaborate::{
     ParamKind, ANON_CTOR_MARKER, DOT_MARKER,
 };
 pub use recheck::{double_check, recheck, DoubleCheck, ReCheckError};
-pub use recursor::derive_recursor;
+pub use recursor::{derive_induction, derive_recursor};
 pub use reify::VarInterner;
 pub use logicaffeine_base::BigInt;
 pub use term::{instantiate_universes, int_lit, lit_bigint, Literal, Term, Universe};
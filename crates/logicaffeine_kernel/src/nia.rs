@@ -0,0 +1,477 @@
+//! Nonlinear Integer Arithmetic: Intervals and Case Splits
+//!
+//! This module implements the `nia` decision procedure for simple nonlinear
+//! goals over the integers — products and squares, which [`crate::omega`] and
+//! [`crate::lia`] reject outright. It closes goals like `x * x >= 0` and
+//! monotonicity facts like `x <= y -> 0 <= z -> x * z <= y * z` without an
+//! external solver.
+//!
+//! # Algorithm
+//!
+//! The goal is proved by refuting its negation together with the hypotheses,
+//! each normalized to a fact `e >= 0` with omega's integer tightening
+//! (`a < b` becomes `b - a - 1 >= 0`). A set of facts is refuted when one of
+//! three steps finds a contradiction:
+//!
+//! 1. **Intervals**: every variable is bounded by the facts linear in it, and
+//!    some fact evaluates to an interval entirely below zero. Evaluation
+//!    follows the term's own shape, where a product of a term with itself is
+//!    a square — `x * x` is `[0, ∞)` even for an unbounded `x`.
+//! 2. **Products**: the facts and their pairwise products (`a >= 0` and
+//!    `b >= 0` give `a * b >= 0`), with every nonlinear monomial read as a
+//!    fresh variable bounded by its interval, are unsatisfiable by
+//!    [`omega_unsat`](crate::omega::omega_unsat). This step proves the
+//!    monotonicity facts.
+//! 3. **Case splits**: a variable of a nonlinear monomial whose sign is still
+//!    unknown is split into `x <= -1`, `x = 0` and `x >= 1`, and every case must
+//!    be refuted in turn, up to [`SPLIT_DEPTH`] nested splits.
+//!
+//! # Soundness
+//!
+//! Each step only weakens the facts: intervals over-approximate, products of
+//! nonnegative facts are nonnegative, and monomials become independent
+//! variables. A refutation is therefore a proof. The procedure is incomplete
+//! and fails on goals that need other products or deeper splits.
+
+use std::collections::BTreeMap;
+
+use logicaffeine_base::numeric::{BigInt, Rational};
+
+use crate::omega::{self, IntConstraint, IntExpr};
+use crate::reify::{extract_binary_app, extract_slit, extract_sname, extract_svar, VarInterner};
+use crate::ring::{Monomial, Polynomial};
+use crate::term::Term;
+
+/// How many nested sign splits one refutation may use.
+pub const SPLIT_DEPTH: usize = 2;
+
+/// Facts are multiplied pairwise only up to this many: the product step's
+/// elimination grows quickly with the number of constraints.
+const MAX_PRODUCT_FACTS: usize = 6;
+
+/// A closed integer interval; a missing bound is infinite.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interval {
+    /// The lower bound, `None` for -∞.
+    pub lo: Option<BigInt>,
+    /// The upper bound, `None` for +∞.
+    pub hi: Option<BigInt>,
+}
+
+/// An interval endpoint, ordered `-∞ < n < +∞`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Bound {
+    NegInf,
+    Finite(BigInt),
+    PosInf,
+}
+
+impl Bound {
+    fn mul(&self, other: &Bound) -> Bound {
+        match (self, other) {
+            (Bound::Finite(a), Bound::Finite(b)) => Bound::Finite(a.mul(b)),
+            (Bound::Finite(n), inf) | (inf, Bound::Finite(n)) => {
+                if n.is_zero() {
+                    Bound::Finite(BigInt::zero())
+                } else if n.is_positive() == (*inf == Bound::PosInf) {
+                    Bound::PosInf
+                } else {
+                    Bound::NegInf
+                }
+            }
+            (a, b) if a == b => Bound::PosInf,
+            _ => Bound::NegInf,
+        }
+    }
+
+    fn finite(self) -> Option<BigInt> {
+        match self {
+            Bound::Finite(n) => Some(n),
+            _ => None,
+        }
+    }
+}
+
+impl Interval {
+    /// The whole line, (-∞, +∞).
+    pub fn unbounded() -> Self {
+        Interval { lo: None, hi: None }
+    }
+
+    /// The single point `[n, n]`.
+    pub fn point(n: BigInt) -> Self {
+        Interval { lo: Some(n.clone()), hi: Some(n) }
+    }
+
+    /// `[n, +∞)`.
+    pub fn at_least(n: BigInt) -> Self {
+        Interval { lo: Some(n), hi: None }
+    }
+
+    /// `(-∞, n]`.
+    pub fn at_most(n: BigInt) -> Self {
+        Interval { lo: None, hi: Some(n) }
+    }
+
+    /// Whether `n` lies in the interval.
+    pub fn contains(&self, n: &BigInt) -> bool {
+        self.lo.as_ref().map_or(true, |lo| lo <= n) && self.hi.as_ref().map_or(true, |hi| n <= hi)
+    }
+
+    /// The sums `a + b` for `a` in `self` and `b` in `other`.
+    pub fn add(&self, other: &Interval) -> Interval {
+        let sum = |a: &Option<BigInt>, b: &Option<BigInt>| Some(a.as_ref()?.add(b.as_ref()?));
+        Interval { lo: sum(&self.lo, &other.lo), hi: sum(&self.hi, &other.hi) }
+    }
+
+    /// The negations `-a` for `a` in `self`.
+    pub fn neg(&self) -> Interval {
+        Interval { lo: self.hi.as_ref().map(BigInt::negated), hi: self.lo.as_ref().map(BigInt::negated) }
+    }
+
+    /// The differences `a - b` for `a` in `self` and `b` in `other`.
+    pub fn sub(&self, other: &Interval) -> Interval {
+        self.add(&other.neg())
+    }
+
+    /// The products `a * b` for `a` in `self` and `b` in `other`.
+    pub fn mul(&self, other: &Interval) -> Interval {
+        let (a_lo, a_hi) = self.bounds();
+        let (b_lo, b_hi) = other.bounds();
+        let corners = [a_lo.mul(&b_lo), a_lo.mul(&b_hi), a_hi.mul(&b_lo), a_hi.mul(&b_hi)];
+        let lo = corners.iter().min().cloned().unwrap_or(Bound::NegInf);
+        let hi = corners.iter().max().cloned().unwrap_or(Bound::PosInf);
+        Interval { lo: lo.finite(), hi: hi.finite() }
+    }
+
+    /// The squares `a * a` for `a` in `self` — never negative, unlike
+    /// `self.mul(self)`, which treats the two factors as independent.
+    pub fn square(&self) -> Interval {
+        let product = self.mul(self);
+        if self.contains(&BigInt::zero()) {
+            Interval { lo: Some(BigInt::zero()), hi: product.hi }
+        } else {
+            product
+        }
+    }
+
+    /// The powers `a^exp` for `a` in `self`.
+    pub fn pow(&self, exp: u64) -> Interval {
+        if exp == 0 {
+            Interval::point(BigInt::from_i64(1))
+        } else if exp % 2 == 0 {
+            self.pow(exp / 2).square()
+        } else {
+            self.pow(exp - 1).mul(self)
+        }
+    }
+
+    /// The intersection, `None` when it is empty.
+    pub fn meet(&self, other: &Interval) -> Option<Interval> {
+        let lo = match (&self.lo, &other.lo) {
+            (Some(a), Some(b)) => Some(a.max(b).clone()),
+            (a, b) => a.clone().or_else(|| b.clone()),
+        };
+        let hi = match (&self.hi, &other.hi) {
+            (Some(a), Some(b)) => Some(a.min(b).clone()),
+            (a, b) => a.clone().or_else(|| b.clone()),
+        };
+        match (&lo, &hi) {
+            (Some(lo), Some(hi)) if lo > hi => None,
+            _ => Some(Interval { lo, hi }),
+        }
+    }
+
+    fn bounds(&self) -> (Bound, Bound) {
+        (
+            self.lo.clone().map_or(Bound::NegInf, Bound::Finite),
+            self.hi.clone().map_or(Bound::PosInf, Bound::Finite),
+        )
+    }
+
+    /// Whether every value has the same sign, zero counting as its own.
+    fn sign_known(&self) -> bool {
+        let one = BigInt::from_i64(1);
+        self.lo.as_ref().is_some_and(|lo| *lo >= one)
+            || self.hi.as_ref().is_some_and(|hi| *hi <= one.negated())
+            || (self.lo.as_ref().is_some_and(BigInt::is_zero) && self.hi.as_ref().is_some_and(BigInt::is_zero))
+    }
+}
+
+/// A reflected arithmetic term, kept in its own shape for interval evaluation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expr {
+    Lit(BigInt),
+    Var(i64),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Reify `SLit`, `SVar`, `SName` and `add`/`sub`/`mul` applications.
+    fn reify(term: &Term, vars: &mut VarInterner) -> Option<Expr> {
+        if let Some(n) = extract_slit(term) {
+            return Some(Expr::Lit(BigInt::from_i64(n)));
+        }
+        if let Some(i) = extract_svar(term) {
+            return Some(Expr::Var(i));
+        }
+        if let Some(name) = extract_sname(term) {
+            return Some(Expr::Var(vars.intern(&name)));
+        }
+        let (op, a, b) = extract_binary_app(term)?;
+        let (a, b) = (Box::new(Expr::reify(&a, vars)?), Box::new(Expr::reify(&b, vars)?));
+        match op.as_str() {
+            "add" => Some(Expr::Add(a, b)),
+            "sub" => Some(Expr::Sub(a, b)),
+            "mul" => Some(Expr::Mul(a, b)),
+            _ => None,
+        }
+    }
+
+    fn sub(self, other: Expr) -> Expr {
+        Expr::Sub(Box::new(self), Box::new(other))
+    }
+
+    fn interval(&self, bounds: &BTreeMap<i64, Interval>) -> Interval {
+        match self {
+            Expr::Lit(n) => Interval::point(n.clone()),
+            Expr::Var(v) => bounds.get(v).cloned().unwrap_or_else(Interval::unbounded),
+            Expr::Add(a, b) => a.interval(bounds).add(&b.interval(bounds)),
+            Expr::Sub(a, b) => a.interval(bounds).sub(&b.interval(bounds)),
+            Expr::Mul(a, b) if a == b => a.interval(bounds).square(),
+            Expr::Mul(a, b) => a.interval(bounds).mul(&b.interval(bounds)),
+        }
+    }
+
+    fn polynomial(&self) -> Polynomial {
+        match self {
+            Expr::Lit(n) => Polynomial::constant(n.clone()),
+            Expr::Var(v) => Polynomial::var(*v),
+            Expr::Add(a, b) => a.polynomial().add(&b.polynomial()),
+            Expr::Sub(a, b) => a.polynomial().sub(&b.polynomial()),
+            Expr::Mul(a, b) => a.polynomial().mul(&b.polynomial()),
+        }
+    }
+}
+
+/// A fact `expr >= 0`, with `expr` also expanded into a polynomial.
+#[derive(Debug, Clone)]
+struct Fact {
+    expr: Expr,
+    poly: Polynomial,
+}
+
+impl Fact {
+    fn new(expr: Expr) -> Fact {
+        let poly = expr.polynomial();
+        Fact { expr, poly }
+    }
+
+    /// `lhs rel rhs`, integer-tightened.
+    fn comparison(rel: &str, lhs: Expr, rhs: Expr) -> Option<Fact> {
+        let one = || Expr::Lit(BigInt::from_i64(1));
+        let expr = match rel {
+            "lt" => rhs.sub(lhs).sub(one()),
+            "le" => rhs.sub(lhs),
+            "gt" => lhs.sub(rhs).sub(one()),
+            "ge" => lhs.sub(rhs),
+            _ => return None,
+        };
+        Some(Fact::new(expr))
+    }
+
+    /// The interval of the fact's expression, as written and as expanded.
+    /// `None` when the two do not meet, which no value can satisfy.
+    fn interval(&self, bounds: &BTreeMap<i64, Interval>) -> Option<Interval> {
+        let mut expanded = Interval::point(BigInt::zero());
+        for (mono, coeff) in self.poly.terms() {
+            expanded = expanded.add(&monomial_interval(mono, bounds).mul(&Interval::point(coeff.clone())));
+        }
+        self.expr.interval(bounds).meet(&expanded)
+    }
+}
+
+/// Reify a comparison (`Lt`/`Le`/`Gt`/`Ge`) into a fact, or into the fact of
+/// its negation.
+fn reify_comparison(term: &Term, vars: &mut VarInterner, negate: bool) -> Option<Fact> {
+    let (rel, lhs, rhs) = omega::extract_comparison(term)?;
+    let (lhs, rhs) = (Expr::reify(&lhs, vars)?, Expr::reify(&rhs, vars)?);
+    let rel = rel.to_ascii_lowercase();
+    let rel = match (negate, rel.as_str()) {
+        (false, rel) => rel,
+        (true, "lt") => "ge",
+        (true, "le") => "gt",
+        (true, "gt") => "le",
+        (true, "ge") => "lt",
+        _ => return None,
+    };
+    Fact::comparison(rel, lhs, rhs)
+}
+
+/// Check whether a comparison goal follows from comparison hypotheses over
+/// the integers.
+///
+/// Both sides of every comparison may use `add`, `sub`, `mul`, integer
+/// literals, `SVar` and `SName` variables. Hypotheses outside that fragment
+/// are ignored, which only weakens them.
+///
+/// # Returns
+///
+/// `true` if the negated goal and the hypotheses were refuted, `false` if
+/// the goal is not a supported comparison or no refutation was found.
+pub fn check_goal(hyps: &[Term], goal: &Term) -> bool {
+    let mut vars = VarInterner::new();
+    let Some(negated) = reify_comparison(goal, &mut vars, true) else {
+        return false;
+    };
+    let mut facts: Vec<Fact> = hyps.iter().filter_map(|hyp| reify_comparison(hyp, &mut vars, false)).collect();
+    facts.push(negated);
+    refute(&facts, SPLIT_DEPTH)
+}
+
+/// Whether no integer assignment satisfies every fact.
+fn refute(facts: &[Fact], depth: usize) -> bool {
+    let Some(bounds) = variable_bounds(facts) else {
+        return true;
+    };
+    for fact in facts {
+        match fact.interval(&bounds) {
+            None => return true,
+            Some(range) if range.hi.as_ref().is_some_and(BigInt::is_negative) => return true,
+            Some(_) => {}
+        }
+    }
+    if products_refute(facts, &bounds) {
+        return true;
+    }
+    if depth == 0 {
+        return false;
+    }
+    let Some(var) = split_variable(facts, &bounds) else {
+        return false;
+    };
+    let x = || Expr::Var(var);
+    let lit = |n: i64| Expr::Lit(BigInt::from_i64(n));
+    let cases = [
+        vec![Fact::new(lit(-1).sub(x()))],
+        vec![Fact::new(x()), Fact::new(lit(0).sub(x()))],
+        vec![Fact::new(x().sub(lit(1)))],
+    ];
+    cases.into_iter().all(|case| {
+        let mut branch = facts.to_vec();
+        branch.extend(case);
+        refute(&branch, depth - 1)
+    })
+}
+
+/// The bounds on each variable from the facts linear in it alone, or `None`
+/// if some variable has no value left (or a constant fact is negative).
+fn variable_bounds(facts: &[Fact]) -> Option<BTreeMap<i64, Interval>> {
+    let one = BigInt::from_i64(1);
+    let mut bounds: BTreeMap<i64, Interval> = BTreeMap::new();
+    'facts: for fact in facts {
+        // c*x + d >= 0
+        let mut linear: Option<(i64, BigInt)> = None;
+        let mut constant = BigInt::zero();
+        for (mono, coeff) in fact.poly.terms() {
+            let powers: Vec<(i64, &BigInt)> = mono.powers().collect();
+            match powers.as_slice() {
+                [] => constant = coeff.clone(),
+                [(var, exp)] if **exp == one && linear.is_none() => linear = Some((*var, coeff.clone())),
+                _ => continue 'facts,
+            }
+        }
+        let Some((var, coeff)) = linear else {
+            if constant.is_negative() {
+                return None;
+            }
+            continue;
+        };
+        let limit = Rational::new(constant.negated(), coeff.clone())?;
+        let bound =
+            if coeff.is_positive() { Interval::at_least(limit.ceil()) } else { Interval::at_most(limit.floor()) };
+        let current = bounds.get(&var).cloned().unwrap_or_else(Interval::unbounded);
+        bounds.insert(var, current.meet(&bound)?);
+    }
+    Some(bounds)
+}
+
+/// The interval of a monomial under the variable bounds.
+fn monomial_interval(mono: &Monomial, bounds: &BTreeMap<i64, Interval>) -> Interval {
+    let mut range = Interval::point(BigInt::from_i64(1));
+    for (var, exp) in mono.powers() {
+        let base = bounds.get(&var).cloned().unwrap_or_else(Interval::unbounded);
+        let power = match exp.to_i64() {
+            Some(exp) if exp >= 0 => base.pow(exp as u64),
+            _ => return Interval::unbounded(),
+        };
+        range = range.mul(&power);
+    }
+    range
+}
+
+/// The product step: the facts and their pairwise products, linear over
+/// monomials, with each nonlinear monomial bounded by its interval.
+fn products_refute(facts: &[Fact], bounds: &BTreeMap<i64, Interval>) -> bool {
+    let mut polys: Vec<Polynomial> = facts.iter().map(|fact| fact.poly.clone()).collect();
+    if facts.len() <= MAX_PRODUCT_FACTS {
+        for (i, a) in facts.iter().enumerate() {
+            for b in &facts[i..] {
+                polys.push(a.poly.mul(&b.poly));
+            }
+        }
+    }
+
+    let mut atoms: BTreeMap<Monomial, i64> = BTreeMap::new();
+    let mut constraints: Vec<IntConstraint> = polys.iter().map(|poly| nonnegative(poly, &mut atoms)).collect();
+    let nonlinear: Vec<(Monomial, i64)> = atoms
+        .iter()
+        .filter(|(mono, _)| mono.powers().map(|(_, exp)| exp.to_i64().unwrap_or(i64::MAX)).sum::<i64>() > 1)
+        .map(|(mono, &atom)| (mono.clone(), atom))
+        .collect();
+    for (mono, atom) in nonlinear {
+        let range = monomial_interval(&mono, bounds);
+        // lo <= m, i.e. lo - m <= 0; m <= hi, i.e. m - hi <= 0.
+        if let Some(lo) = range.lo {
+            let expr = IntExpr::constant(lo).sub(&IntExpr::var(atom));
+            constraints.push(IntConstraint { expr, strict: false });
+        }
+        if let Some(hi) = range.hi {
+            let expr = IntExpr::var(atom).sub(&IntExpr::constant(hi));
+            constraints.push(IntConstraint { expr, strict: false });
+        }
+    }
+    omega::omega_unsat(&constraints)
+}
+
+/// `poly >= 0` as the omega constraint `-poly <= 0`, each non-constant
+/// monomial an atom of its own.
+fn nonnegative(poly: &Polynomial, atoms: &mut BTreeMap<Monomial, i64>) -> IntConstraint {
+    let mut expr = IntExpr::constant(BigInt::zero());
+    for (mono, coeff) in poly.terms() {
+        if *mono == Monomial::one() {
+            expr = expr.sub(&IntExpr::constant(coeff.clone()));
+        } else {
+            let next = atoms.len() as i64;
+            let atom = *atoms.entry(mono.clone()).or_insert(next);
+            expr = expr.sub(&IntExpr::var(atom).scale(coeff.clone()));
+        }
+    }
+    IntConstraint { expr, strict: false }
+}
+
+/// A variable of a nonlinear monomial whose sign the bounds leave open.
+fn split_variable(facts: &[Fact], bounds: &BTreeMap<i64, Interval>) -> Option<i64> {
+    facts
+        .iter()
+        .flat_map(|fact| fact.poly.terms())
+        .filter(|(mono, _)| {
+            let mut powers = mono.powers();
+            let first = powers.next();
+            first.is_some_and(|(_, exp)| *exp > BigInt::from_i64(1)) || powers.next().is_some()
+        })
+        .flat_map(|(mono, _)| mono.powers().map(|(var, _)| var).collect::<Vec<_>>())
+        .find(|var| !bounds.get(var).is_some_and(Interval::sign_known))
+}
@@ -6,6 +6,7 @@
 //! - True, False: propositional constants
 //! - Eq: propositional equality
 //! - And, Or: logical connectives
+//! - id, const, comp: universe-polymorphic combinators, one definition for every level
 
 use crate::context::Context;
 use crate::term::{Literal, Term, Universe};
@@ -36,6 +37,7 @@ impl StandardLibrary {
         Self::register_native_decide(ctx);
         Self::register_quot(ctx);
         Self::register_acc(ctx);
+        Self::register_universe_poly(ctx);
         Self::register_primitives(ctx);
         Self::register_int_ring_axioms(ctx);
         Self::register_int_order_axioms(ctx);
@@ -1337,6 +1339,83 @@ impl StandardLibrary {
         );
     }
 
+    /// The function combinators, universe-polymorphic so that one definition serves
+    /// every level — `id.{Type 0} Nat` and `id.{Type 1} (Type 0)` alike:
+    ///
+    /// - `id.{u} : Π(A:Sort u). A → A`
+    /// - `const.{u v} : Π(A:Sort u)(B:Sort v). A → B → A`
+    /// - `comp.{u v w} : Π(A:Sort u)(B:Sort v)(C:Sort w). (B → C) → (A → B) → A → C`
+    ///
+    /// Each is checked once with its levels abstract.
+    fn register_universe_poly(ctx: &mut Context) {
+        fn pi(p: &str, t: Term, b: Term) -> Term {
+            Term::Pi { param: p.to_string(), param_type: Box::new(t), body_type: Box::new(b) }
+        }
+        fn arrow(a: Term, b: Term) -> Term {
+            pi("_", a, b)
+        }
+        fn lam(p: &str, t: Term, b: Term) -> Term {
+            Term::Lambda { param: p.to_string(), param_type: Box::new(t), body: Box::new(b) }
+        }
+        fn v(n: &str) -> Term {
+            Term::Var(n.to_string())
+        }
+        fn sort(u: &str) -> Term {
+            Term::Sort(Universe::Var(u.to_string()))
+        }
+        let params = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+        let id_ty = pi("A", sort("u"), arrow(v("A"), v("A")));
+        let id_body = lam("A", sort("u"), lam("a", v("A"), v("a")));
+
+        let const_ty = pi("A", sort("u"), pi("B", sort("v"), arrow(v("A"), arrow(v("B"), v("A")))));
+        let const_body = lam("A", sort("u"), lam("B", sort("v"), lam("a", v("A"), lam("b", v("B"), v("a")))));
+
+        let comp_ty = pi(
+            "A",
+            sort("u"),
+            pi(
+                "B",
+                sort("v"),
+                pi(
+                    "C",
+                    sort("w"),
+                    arrow(arrow(v("B"), v("C")), arrow(arrow(v("A"), v("B")), arrow(v("A"), v("C")))),
+                ),
+            ),
+        );
+        let comp_body = lam(
+            "A",
+            sort("u"),
+            lam(
+                "B",
+                sort("v"),
+                lam(
+                    "C",
+                    sort("w"),
+                    lam(
+                        "g",
+                        arrow(v("B"), v("C")),
+                        lam(
+                            "f",
+                            arrow(v("A"), v("B")),
+                            lam("a", v("A"), Term::App(Box::new(v("g")), Box::new(Term::App(Box::new(v("f")), Box::new(v("a")))))),
+                        ),
+                    ),
+                ),
+            ),
+        );
+
+        for (name, levels, ty, body) in [
+            ("id", params(&["u"]), id_ty, id_body),
+            ("const", params(&["u", "v"]), const_ty, const_body),
+            ("comp", params(&["u", "v", "w"]), comp_ty, comp_body),
+        ] {
+            ctx.add_universe_poly_checked(name, levels, Vec::new(), ty, body)
+                .unwrap_or_else(|e| panic!("prelude combinator '{}' must type-check: {}", name, e));
+        }
+    }
+
     /// Eq : Π(A : Type 0). A → A → Prop
     /// refl : Π(A : Type 0). Π(x : A). Eq A x x
     /// Well-founded recursion. `Acc (A)(R) : A → Prop` is the accessibility
@@ -2009,7 +2088,9 @@ impl StandardLibrary {
         Self::register_try_lia(ctx);
         Self::register_try_cc(ctx);
         Self::register_try_simp(ctx);
+        Self::register_try_simp_using(ctx);
         Self::register_try_omega(ctx);
+        Self::register_try_nia(ctx);
         Self::register_try_auto(ctx);
         Self::register_try_induction(ctx);
         Self::register_induction_helpers(ctx);
@@ -3093,6 +3174,32 @@ impl StandardLibrary {
         ctx.add_declaration("try_simp", try_simp_type);
     }
 
+    /// DSimpUsingSolve : Syntax -> Syntax -> Derivation
+    /// try_simp_using : Syntax -> Syntax -> Derivation
+    ///
+    /// Simplifier with a named simp set: `try_simp_using (SName "set") goal`
+    /// rewrites with the rules of that set instead of the default one.
+    /// Computational behavior defined in reduction.rs.
+    fn register_try_simp_using(ctx: &mut Context) {
+        let syntax = Term::Global("Syntax".to_string());
+        let derivation = Term::Global("Derivation".to_string());
+        let syntax_to_derivation = Term::Pi {
+            param: "_".to_string(),
+            param_type: Box::new(syntax.clone()),
+            body_type: Box::new(Term::Pi {
+                param: "_".to_string(),
+                param_type: Box::new(syntax),
+                body_type: Box::new(derivation),
+            }),
+        };
+
+        // DSimpUsingSolve : Syntax -> Syntax -> Derivation
+        ctx.add_constructor("DSimpUsingSolve", "Derivation", syntax_to_derivation.clone());
+
+        // try_simp_using : Syntax -> Syntax -> Derivation
+        ctx.add_declaration("try_simp_using", syntax_to_derivation);
+    }
+
     /// DOmegaSolve : Syntax -> Derivation
     /// try_omega : Syntax -> Derivation
     ///
@@ -3128,11 +3235,46 @@ impl StandardLibrary {
         ctx.add_declaration("try_omega", try_omega_type);
     }
 
+    /// DNiaSolve : Syntax -> Derivation
+    /// try_nia : Syntax -> Derivation
+    ///
+    /// Nia tactic: proves simple nonlinear integer inequalities — products and
+    /// squares — by interval reasoning, products of hypotheses and sign case splits.
+    /// - x * x >= 0
+    /// - x <= y -> 0 <= z -> x * z <= y * z
+    /// Computational behavior defined in reduction.rs.
+    fn register_try_nia(ctx: &mut Context) {
+        let syntax = Term::Global("Syntax".to_string());
+        let derivation = Term::Global("Derivation".to_string());
+
+        // DNiaSolve : Syntax -> Derivation
+        // Proof constructor for nia-solved inequalities
+        ctx.add_constructor(
+            "DNiaSolve",
+            "Derivation",
+            Term::Pi {
+                param: "_".to_string(),
+                param_type: Box::new(syntax.clone()),
+                body_type: Box::new(derivation.clone()),
+            },
+        );
+
+        // try_nia : Syntax -> Derivation
+        // Nia tactic: given a goal, try to prove it by nonlinear integer arithmetic
+        let try_nia_type = Term::Pi {
+            param: "_".to_string(),
+            param_type: Box::new(syntax),
+            body_type: Box::new(derivation),
+        };
+
+        ctx.add_declaration("try_nia", try_nia_type);
+    }
+
     /// DAutoSolve : Syntax -> Derivation
     /// try_auto : Syntax -> Derivation
     ///
     /// Auto tactic: tries all decision procedures in sequence.
-    /// Order: simp → ring → cc → omega → lia
+    /// Order: simp → ring → cc → omega → lia → nia
     /// Returns the first successful derivation, or error if all fail.
     /// Computational behavior defined in reduction.rs.
     fn register_try_auto(ctx: &mut Context) {
@@ -3199,6 +3341,7 @@ impl StandardLibrary {
     /// - induction_base_goal: Computes the base case goal
     /// - induction_step_goal: Computes the step case goal for a constructor
     /// - induction_num_cases: Returns number of constructors for an inductive
+    /// - syn_intros / DIntros: Introduce a case goal's variables and prove its body
     fn register_induction_helpers(ctx: &mut Context) {
         let syntax = Term::Global("Syntax".to_string());
         let nat = Term::Global("Nat".to_string());
@@ -3243,10 +3386,38 @@ impl StandardLibrary {
             "induction_num_cases",
             Term::Pi {
                 param: "_".to_string(),
-                param_type: Box::new(syntax),
+                param_type: Box::new(syntax.clone()),
                 body_type: Box::new(Term::Global("Nat".to_string())),
             },
         );
+
+        // syn_intros : Syntax -> Syntax
+        // Strips a case goal's leading foralls, leaving its variables free
+        ctx.add_declaration(
+            "syn_intros",
+            Term::Pi {
+                param: "_".to_string(),
+                param_type: Box::new(syntax.clone()),
+                body_type: Box::new(syntax.clone()),
+            },
+        );
+
+        // DIntros : Syntax -> Derivation -> Derivation
+        // DIntros goal proof proves goal from a proof of (syn_intros goal)
+        let derivation = Term::Global("Derivation".to_string());
+        ctx.add_constructor(
+            "DIntros",
+            "Derivation",
+            Term::Pi {
+                param: "_".to_string(),
+                param_type: Box::new(syntax),
+                body_type: Box::new(Term::Pi {
+                    param: "_".to_string(),
+                    param_type: Box::new(derivation.clone()),
+                    body_type: Box::new(derivation),
+                }),
+            },
+        );
     }
 
     // -------------------------------------------------------------------------
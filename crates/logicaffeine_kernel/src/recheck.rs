@@ -657,7 +657,7 @@ fn def_eq(genv: &Context, lctx: &[Db], a: &Db, b: &Db) -> bool {
     }
 
     let congruent = match (&a, &b) {
-        (Db::Sort(x), Db::Sort(y)) => x.equiv(y),
+        (Db::Sort(x), Db::Sort(y)) => genv.universe_equiv(x, y),
         (Db::Var(i), Db::Var(j)) => i == j,
         (Db::Global(m), Db::Global(n)) => m == n,
         (Db::Lit(x), Db::Lit(y)) => x == y,
@@ -770,7 +770,7 @@ fn is_sub(genv: &Context, lctx: &[Db], sub: &Db, sup: &Db) -> bool {
     let s = whnf(genv, sub);
     let t = whnf(genv, sup);
     match (&s, &t) {
-        (Db::Sort(x), Db::Sort(y)) => x.is_subtype_of(y),
+        (Db::Sort(x), Db::Sort(y)) => genv.universe_leq(x, y),
         (Db::Pi(d1, b1), Db::Pi(d2, b2)) => {
             def_eq(genv, lctx, d1, d2) && {
                 let mut ext = lctx.to_vec();
@@ -819,6 +819,7 @@ fn infer(genv: &Context, lctx: &[Db], t: &Db) -> RResult<Db> {
                     levels.len()
                 )));
             }
+            genv.check_universe_instance(name, params, levels).map_err(ReCheckError::ill)?;
             let subst: std::collections::HashMap<String, Universe> =
                 params.iter().cloned().zip(levels.iter().cloned()).collect();
             let instantiated = crate::term::instantiate_universes(&ty.clone(), &subst);
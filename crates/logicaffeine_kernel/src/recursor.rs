@@ -24,6 +24,9 @@
 //! so `derive_recursor("Eq")` synthesizes FULL Paulin-Mohring J:
 //! `Π(A). Π(x:A). Π(P : Π(y:A). Eq A x y → Sort). P x (refl A x) → Π(y). Π(h:Eq A x y). P y h`
 //! — the identity eliminator as a kernel-checked term, not an axiom.
+//!
+//! [`derive_induction`] narrows the recursor's motives to `Prop`, giving the induction
+//! principle `I_ind` that the literate `induction` tactic's case split mirrors.
 
 use crate::context::Context;
 use crate::error::{KernelError, KernelResult};
@@ -174,6 +177,55 @@ pub fn derive_recursor(ctx: &Context, ind: &str) -> KernelResult<(Term, Term)> {
     Ok((ty, term))
 }
 
+/// Build `I_ind`, the induction principle for `ind`: `I_rec` with every motive narrowed
+/// to `Prop`,
+///
+/// ```text
+/// I_ind : Π(A…). Π(P : I A… → Prop). minor₀ → … → minorₖ → Π(x : I A…). P x
+/// I_ind := λA…. λP. I_rec A… P
+/// ```
+///
+/// The recursor already accepts a `Prop` motive by cumulativity; `I_ind` states it, so a
+/// proof about a user type reads as one, and its motive is inferred at `Prop` rather than
+/// `Type 0`. Mutual members narrow all of their block's motives. Returns `(type, term)`
+/// like [`derive_recursor`].
+pub fn derive_induction(ctx: &Context, ind: &str) -> KernelResult<(Term, Term)> {
+    let (rec_ty, rec_term) = derive_recursor(ctx, ind)?;
+    let num_params = ctx.inductive_num_params(ind);
+    let (binders, _) = peel_pis(&rec_ty);
+
+    // The parameters, then the motives: the binders after them whose type ends in a sort
+    // (minor premises end in an application of a motive).
+    let mut abstracted: Vec<(String, Term)> = Vec::new();
+    for (i, (name, ty)) in binders.iter().enumerate() {
+        if i < num_params {
+            abstracted.push((name.clone(), ty.clone()));
+        } else if matches!(peel_pis(ty).1, Term::Sort(_)) {
+            abstracted.push((name.clone(), into_prop(ty)));
+        } else {
+            break;
+        }
+    }
+
+    let mut term = rec_term;
+    for (name, _) in &abstracted {
+        term = app(term, var(name));
+    }
+    for (name, ty) in abstracted.iter().rev() {
+        term = lam(name, ty.clone(), term);
+    }
+    let ty = infer_type(ctx, &term)?;
+    Ok((ty, term))
+}
+
+/// Retarget a motive type `Π…. Sort s` to `Π…. Prop`.
+fn into_prop(ty: &Term) -> Term {
+    match ty {
+        Term::Pi { param, param_type, body_type } => pi(param, (**param_type).clone(), into_prop(body_type)),
+        _ => Term::Sort(Universe::Prop),
+    }
+}
+
 /// Derive the recursor for `ind`, a member of the MUTUAL block `block`. Every member
 /// shares one `MutualFix`; this returns `(type, term)` for `ind`'s recursor, whose body
 /// selects `ind`'s component. The eliminator takes ONE motive per member and ALL
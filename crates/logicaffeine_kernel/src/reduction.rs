@@ -39,8 +39,22 @@
 //!
 //! Normalization uses a fuel counter (default 10000) to prevent infinite loops.
 //! If fuel is exhausted, the current term is returned as-is.
+//!
+//! # Normalization Cache
+//!
+//! Normal forms are memoized per thread, keyed on the term's [`TermId`] in a
+//! hash-consing table and the [`Context::generation`] of the global
+//! environment. Reduction reads only globals, never local bindings, so the
+//! key determines the result: the reflection builtins and the ring/lia/omega
+//! procedures, which normalize the same subterms over and over, pay for each
+//! distinct one once.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
 
 use crate::context::Context;
+use crate::hashcons::{HashCons, TermId};
+use crate::nia;
 use crate::omega;
 use crate::term::{int_lit, lit_bigint, Literal, Term, Universe};
 use crate::type_checker::substitute;
@@ -50,6 +64,63 @@ use crate::type_checker::substitute;
 /// Repeatedly applies reduction rules until no more reductions are possible.
 /// This is a full normalization that reduces under binders.
 pub fn normalize(ctx: &Context, term: &Term) -> Term {
+    // Atoms other than globals are their own normal form; not worth a lookup.
+    if matches!(term, Term::Sort(_) | Term::Var(_) | Term::Lit(_) | Term::Hole) {
+        return term.clone();
+    }
+    let generation = ctx.generation();
+    let (id, epoch) = match NORMAL_FORMS.with(|cache| cache.borrow_mut().lookup(generation, term)) {
+        Ok(normal) => return normal,
+        Err(key) => key,
+    };
+    let normal = normalize_uncached(ctx, term);
+    NORMAL_FORMS.with(|cache| cache.borrow_mut().store(epoch, generation, id, &normal));
+    normal
+}
+
+thread_local! {
+    static NORMAL_FORMS: RefCell<NormalForms> = RefCell::new(NormalForms::default());
+}
+
+/// Past this many cached normal forms, or interned terms, the cache starts over.
+const NORMAL_FORMS_CAPACITY: usize = 1 << 16;
+const INTERNED_CAPACITY: usize = 1 << 20;
+
+/// The per-thread normalization cache.
+#[derive(Default)]
+struct NormalForms {
+    terms: HashCons,
+    normal: HashMap<(u64, TermId), Term>,
+    /// Bumped whenever the cache starts over: a [`TermId`] handed out before is
+    /// meaningless after, so a normalization that outlived a reset (it recurses
+    /// into `normalize`) must not store its result.
+    epoch: u64,
+}
+
+impl NormalForms {
+    /// The cached normal form of `term`, or the key to store it under.
+    fn lookup(&mut self, generation: u64, term: &Term) -> Result<Term, (TermId, u64)> {
+        if self.normal.len() >= NORMAL_FORMS_CAPACITY || self.terms.len() >= INTERNED_CAPACITY {
+            self.terms.clear();
+            self.normal.clear();
+            self.epoch += 1;
+        }
+        let id = self.terms.intern(term);
+        match self.normal.get(&(generation, id)) {
+            Some(normal) => Ok(normal.clone()),
+            None => Err((id, self.epoch)),
+        }
+    }
+
+    fn store(&mut self, epoch: u64, generation: u64, id: TermId, normal: &Term) {
+        if epoch == self.epoch {
+            self.normal.insert((generation, id), normal.clone());
+        }
+    }
+}
+
+/// [`normalize`] without the cache.
+fn normalize_uncached(ctx: &Context, term: &Term) -> Term {
     let mut current = term.clone();
     let mut fuel = 10000; // Safety limit to prevent infinite loops
 
@@ -553,6 +624,11 @@ fn try_reflection_reduce(ctx: &Context, func: &Term, arg: &Term) -> Option<Term>
                 let norm_arg = normalize(ctx, arg);
                 return try_syn_diag_reduce(ctx, &norm_arg);
             }
+            "syn_intros" => {
+                // Normalize the argument first
+                let norm_arg = normalize(ctx, arg);
+                return Some(strip_foralls(&norm_arg));
+            }
             "concludes" => {
                 // Normalize the argument first
                 let norm_arg = normalize(ctx, arg);
@@ -601,6 +677,11 @@ fn try_reflection_reduce(ctx: &Context, func: &Term, arg: &Term) -> Option<Term>
                 let norm_arg = normalize(ctx, arg);
                 return try_try_omega_reduce(ctx, &norm_arg);
             }
+            "try_nia" => {
+                // Nia tactic: prove nonlinear integer inequalities
+                let norm_arg = normalize(ctx, arg);
+                return try_try_nia_reduce(ctx, &norm_arg);
+            }
             "try_auto" => {
                 // Auto tactic: tries all tactics in sequence
                 let norm_arg = normalize(ctx, arg);
@@ -721,6 +802,17 @@ fn try_reflection_reduce(ctx: &Context, func: &Term, arg: &Term) -> Option<Term>
         }
     }
 
+    // try_simp_using set goal (2 arguments)
+    // Structure: ((try_simp_using set) goal), set = SName "name"
+    if let Term::App(partial1, set) = func {
+        if let Term::Global(op_name) = partial1.as_ref() {
+            if op_name == "try_simp_using" {
+                let norm_set = normalize(ctx, set);
+                return try_try_simp_using_reduce(ctx, &norm_set, arg);
+            }
+        }
+    }
+
     // syn_eval fuel term (2 arguments)
     // Structure: ((syn_eval fuel) term)
     if let Term::App(partial1, fuel_term) = func {
@@ -1616,6 +1708,10 @@ fn try_concludes_reduce(ctx: &Context, deriv: &Term) -> Option<Term> {
                 // DOmegaSolve goal: verify goal by integer arithmetic
                 return try_domega_solve_conclude(ctx, p);
             }
+            if ctor_name == "DNiaSolve" {
+                // DNiaSolve goal: verify goal by nonlinear integer arithmetic
+                return try_dnia_solve_conclude(ctx, p);
+            }
             if ctor_name == "DAutoSolve" {
                 // DAutoSolve goal: verify goal by trying all tactics
                 return try_dauto_solve_conclude(ctx, p);
@@ -1695,6 +1791,14 @@ fn try_concludes_reduce(ctx: &Context, deriv: &Term) -> Option<Term> {
                     // where context = SLam T body, eq_proof proves Eq T a b
                     return try_dcong_conclude(ctx, t, a);
                 }
+                if ctor_name == "DIntros" {
+                    // DIntros goal proof: goal's foralls introduced, proof shows the body
+                    return try_dintros_conclude(ctx, t, a);
+                }
+                if ctor_name == "DSimpUsingSolve" {
+                    // DSimpUsingSolve set goal: verify goal with the named simp set
+                    return try_dsimp_using_solve_conclude(ctx, t, a);
+                }
             }
         }
     }
@@ -1893,6 +1997,44 @@ fn extract_slam_body(term: &Term) -> Option<Term> {
     None
 }
 
+/// Strip the leading `Forall T (SLam T body)` binders of a goal, leaving the
+/// body with the bound variables free (`SVar 0` the innermost).
+///
+/// Induction hypotheses arrive as `Implies IH goal`; the leading chain is
+/// respelled `implies` so the hypotheses reach the tactics that read them.
+fn strip_foralls(term: &Term) -> Term {
+    let mut current = term.clone();
+    while let Some(body) = extract_forall_body(&current) {
+        current = body;
+    }
+    respell_implications(&current)
+}
+
+/// Rewrite a leading `Implies` chain to `implies`.
+fn respell_implications(term: &Term) -> Term {
+    match extract_implication(term) {
+        Some((hyp, concl)) => make_sapp(
+            make_sapp(make_sname("implies"), hyp),
+            respell_implications(&concl),
+        ),
+        None => term.clone(),
+    }
+}
+
+/// Verify DIntros proof.
+///
+/// DIntros goal proof → goal, if proof concludes goal with its leading
+/// foralls stripped: a proof of the open body holds for every value of the
+/// variables left free.
+fn try_dintros_conclude(ctx: &Context, goal: &Term, proof: &Term) -> Option<Term> {
+    let norm_goal = normalize(ctx, goal);
+    let body_conc = try_concludes_reduce(ctx, &normalize(ctx, proof))?;
+    if syntax_equal(&body_conc, &strip_foralls(&norm_goal)) {
+        return Some(norm_goal);
+    }
+    Some(make_sname_error())
+}
+
 /// Structural equality check for Syntax terms
 fn syntax_equal(a: &Term, b: &Term) -> bool {
     a == b
@@ -2502,11 +2644,12 @@ fn try_try_simp_reduce(ctx: &Context, goal: &Term) -> Option<Term> {
     // Normalize the goal first
     let norm_goal = normalize(ctx, goal);
 
-    // Use simp::check_goal which handles:
+    // Use simp::check_goal_with which handles:
     // - Reflexive equalities: (Eq a a)
     // - Constant folding: (Eq (add 2 3) 5)
     // - Hypothesis substitution: (implies (Eq x 0) (Eq (add x 1) 1))
-    if simp::check_goal(&norm_goal) {
+    // - Rewriting with the default simp set
+    if simp::check_goal_with(&norm_goal, &default_simp_set(ctx)) {
         // Success! Return DSimpSolve goal
         return Some(Term::App(
             Box::new(Term::Global("DSimpSolve".to_string())),
@@ -2525,13 +2668,54 @@ fn try_dsimp_solve_conclude(ctx: &Context, goal: &Term) -> Option<Term> {
     let norm_goal = normalize(ctx, goal);
 
     // Re-verify using simplification
-    if simp::check_goal(&norm_goal) {
+    if simp::check_goal_with(&norm_goal, &default_simp_set(ctx)) {
         return Some(norm_goal);
     }
 
     Some(make_sname_error())
 }
 
+/// The default simp set, empty until a rule is added to it.
+fn default_simp_set(ctx: &Context) -> simp::SimpSet {
+    ctx.simp_set(simp::DEFAULT_SET).cloned().unwrap_or_default()
+}
+
+/// try_simp_using set goal:
+/// - Looks up the simp set named by `SName set`
+/// - Simplifies with its rules only, not the default set
+/// - Returns DSimpUsingSolve set goal on success
+/// - Returns Error derivation for an unknown set or an unproved goal
+fn try_try_simp_using_reduce(ctx: &Context, set: &Term, goal: &Term) -> Option<Term> {
+    let norm_goal = normalize(ctx, goal);
+    let proved = match extract_sname_from_syntax(set).and_then(|name| ctx.simp_set(&name)) {
+        Some(rules) => simp::check_goal_with(&norm_goal, rules),
+        None => false,
+    };
+    if !proved {
+        return Some(make_error_derivation());
+    }
+    Some(Term::App(
+        Box::new(Term::App(
+            Box::new(Term::Global("DSimpUsingSolve".to_string())),
+            Box::new(set.clone()),
+        )),
+        Box::new(norm_goal),
+    ))
+}
+
+/// Verify DSimpUsingSolve proof.
+///
+/// DSimpUsingSolve set goal → goal (if verified)
+fn try_dsimp_using_solve_conclude(ctx: &Context, set: &Term, goal: &Term) -> Option<Term> {
+    let norm_goal = normalize(ctx, goal);
+    if let Some(rules) = extract_sname_from_syntax(set).and_then(|name| ctx.simp_set(&name)) {
+        if simp::check_goal_with(&norm_goal, rules) {
+            return Some(norm_goal);
+        }
+    }
+    Some(make_sname_error())
+}
+
 // =============================================================================
 // OMEGA TACTIC (TRUE INTEGER ARITHMETIC)
 // =============================================================================
@@ -2706,6 +2890,47 @@ fn try_domega_solve_conclude(ctx: &Context, goal: &Term) -> Option<Term> {
     Some(make_sname_error())
 }
 
+// =============================================================================
+// NIA TACTIC (NONLINEAR INTEGER ARITHMETIC)
+// =============================================================================
+
+/// try_nia goal:
+///
+/// Nonlinear integer arithmetic by intervals, products of hypotheses and
+/// sign case splits (see [`nia`]):
+/// - x * x >= 0 (a square is never negative)
+/// - x <= y -> 0 <= z -> x * z <= y * z (monotonicity)
+///
+/// Returns DNiaSolve goal on success
+fn try_try_nia_reduce(ctx: &Context, goal: &Term) -> Option<Term> {
+    let norm_goal = normalize(ctx, goal);
+    let (hyps, conclusion) = extract_implications(&norm_goal).unwrap_or_else(|| (Vec::new(), norm_goal.clone()));
+
+    if nia::check_goal(&hyps, &conclusion) {
+        return Some(Term::App(
+            Box::new(Term::Global("DNiaSolve".to_string())),
+            Box::new(norm_goal),
+        ));
+    }
+
+    Some(make_error_derivation())
+}
+
+/// Verify DNiaSolve proof.
+///
+/// DNiaSolve goal → goal (if verified)
+fn try_dnia_solve_conclude(ctx: &Context, goal: &Term) -> Option<Term> {
+    let norm_goal = normalize(ctx, goal);
+    let (hyps, conclusion) = extract_implications(&norm_goal).unwrap_or_else(|| (Vec::new(), norm_goal.clone()));
+
+    // Re-verify by the nia refutation
+    if nia::check_goal(&hyps, &conclusion) {
+        return Some(norm_goal);
+    }
+
+    Some(make_sname_error())
+}
+
 // =============================================================================
 // AUTO TACTIC (THE INFINITY GAUNTLET)
 // =============================================================================
@@ -2746,7 +2971,7 @@ fn is_error_derivation(term: &Term) -> bool {
 
 /// Auto tactic: tries each decision procedure in sequence.
 ///
-/// Order: True/False → simp → ring → cc → omega → lia
+/// Order: True/False → simp → ring → cc → omega → lia → nia
 /// Returns the first successful derivation, or error if all fail.
 // =============================================================================
 // HARDWARE TACTICS (bitblast, tabulate, hw_auto)
@@ -3174,6 +3399,13 @@ fn try_try_auto_reduce(ctx: &Context, goal: &Term) -> Option<Term> {
         }
     }
 
+    // Try nia (nonlinear arithmetic - only products and squares get here)
+    if let Some(result) = try_try_nia_reduce(ctx, &norm_goal) {
+        if !is_error_derivation(&result) {
+            return Some(result);
+        }
+    }
+
     // Try registered hints
     for hint_name in ctx.get_hints() {
         if let Some(hint_type) = ctx.get_global(hint_name) {
@@ -3230,6 +3462,11 @@ fn try_dauto_solve_conclude(ctx: &Context, goal: &Term) -> Option<Term> {
             return Some(norm_goal.clone());
         }
     }
+    if let Some(result) = try_try_nia_reduce(ctx, &norm_goal) {
+        if !is_error_derivation(&result) {
+            return Some(norm_goal.clone());
+        }
+    }
 
     // Try registered hints
     for hint_name in ctx.get_hints() {
@@ -66,6 +66,11 @@ impl Monomial {
         Monomial { powers }
     }
 
+    /// The variables of the monomial with their exponents, in index order.
+    pub fn powers(&self) -> impl Iterator<Item = (i64, &BigInt)> {
+        self.powers.iter().map(|(var, exp)| (*var, exp))
+    }
+
     /// Multiply two monomials by adding their exponents.
     ///
     /// For monomials m1 = x^a * y^b and m2 = x^c * z^d,
@@ -165,6 +170,11 @@ impl Polynomial {
         result
     }
 
+    /// The monomials with their nonzero coefficients, in canonical order.
+    pub fn terms(&self) -> impl Iterator<Item = (&Monomial, &BigInt)> {
+        self.terms.iter()
+    }
+
     /// Check equality in canonical form.
     /// Since BTreeMap maintains sorted order and we remove zeros,
     /// structural equality is semantic equality.
@@ -22,15 +22,32 @@
 //! Supports `add`, `sub`, `mul`, `div`, `mod` on integer literals.
 //! Non-literal arithmetic is left unevaluated.
 //!
+//! # Simp Sets
+//!
+//! Proved equations can be collected into named [`SimpSet`]s and used as
+//! left-to-right rewrites after arithmetic at every node. Rules are tried
+//! in priority order, highest first. [`SimpSet::add`] refuses a rule that
+//! obviously loops — its right-hand side contains an instance of its own
+//! left-hand side, or it and another rule rewrite into each other — and a
+//! rule whose root overlap with an equal-priority rule does not rejoin.
+//!
 //! # Fuel Limit
 //!
 //! Simplification uses a fuel counter to prevent infinite loops from
 //! cyclic rewrites. The default fuel is 1000 simplification steps.
 
 use std::collections::HashMap;
+use std::fmt;
 
+use crate::error::{KernelError, KernelResult};
 use crate::term::{Literal, Term};
 
+/// The simp set `simp` rewrites with when no set is named.
+pub const DEFAULT_SET: &str = "default";
+
+/// Fuel for one side of a goal, and for joining a critical pair.
+const FUEL: usize = 1000;
+
 // =============================================================================
 // SIMPLIFIED SYNTAX REPRESENTATION
 // =============================================================================
@@ -58,6 +75,29 @@ pub enum STerm {
     App(Box<STerm>, Box<STerm>),
 }
 
+impl fmt::Display for STerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            STerm::Lit(n) => write!(f, "{}", n),
+            STerm::Var(i) => write!(f, "#{}", i),
+            STerm::Name(s) => write!(f, "{}", s),
+            STerm::App(..) => {
+                let mut args = Vec::new();
+                let mut head = self;
+                while let STerm::App(g, a) = head {
+                    args.push(a.as_ref());
+                    head = g.as_ref();
+                }
+                write!(f, "({}", head)?;
+                for a in args.iter().rev() {
+                    write!(f, " {}", a)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
 /// Substitution mapping variable indices to replacement terms.
 ///
 /// Extracted from hypothesis equalities like `x = t`, where `x` is a
@@ -128,37 +168,47 @@ fn sterm_to_term(st: &STerm) -> Term {
 /// Simplify an STerm using the given substitution (from hypotheses)
 /// and arithmetic evaluation.
 fn simplify_sterm(term: &STerm, subst: &Substitution, fuel: usize) -> STerm {
+    simplify_with_rules(term, subst, &[], fuel)
+}
+
+/// Simplify bottom-up: substitution, arithmetic, then the first rule (in
+/// priority order) whose left-hand side matches the node.
+fn simplify_with_rules(term: &STerm, subst: &Substitution, rules: &[SimpRule], fuel: usize) -> STerm {
     if fuel == 0 {
         return term.clone();
     }
 
-    match term {
+    let node = match term {
         // Variables: apply substitution if bound
         STerm::Var(i) => {
             if let Some(replacement) = subst.get(i) {
                 // Re-simplify the replacement (may enable more rewrites)
-                simplify_sterm(replacement, subst, fuel - 1)
-            } else {
-                term.clone()
+                return simplify_with_rules(replacement, subst, rules, fuel - 1);
             }
+            term.clone()
         }
 
-        // Literals and names are already simplified
+        // Literals and names have no children to simplify
         STerm::Lit(_) => term.clone(),
         STerm::Name(_) => term.clone(),
 
         // Applications: simplify children first, then try arithmetic
         STerm::App(f, a) => {
-            let sf = simplify_sterm(f, subst, fuel - 1);
-            let sa = simplify_sterm(a, subst, fuel - 1);
+            let sf = simplify_with_rules(f, subst, rules, fuel - 1);
+            let sa = simplify_with_rules(a, subst, rules, fuel - 1);
 
             // Try arithmetic simplification on the simplified application
             if let Some(result) = try_arithmetic(&sf, &sa) {
-                return simplify_sterm(&result, subst, fuel - 1);
+                return simplify_with_rules(&result, subst, rules, fuel - 1);
             }
 
             STerm::App(Box::new(sf), Box::new(sa))
         }
+    };
+
+    match rules.iter().find_map(|rule| rule.rewrite(&node)) {
+        Some(next) => simplify_with_rules(&next, subst, rules, fuel - 1),
+        None => node,
     }
 }
 
@@ -186,6 +236,265 @@ fn try_arithmetic(func: &STerm, arg: &STerm) -> Option<STerm> {
     None
 }
 
+// =============================================================================
+// SIMP RULES AND SETS
+// =============================================================================
+
+/// A proved equation `lhs = rhs`, used as a left-to-right rewrite.
+///
+/// The pattern variables are the names in argument position of `lhs` that
+/// are not constants: in `add x 0 = x`, `x` is a variable and `add` is not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimpRule {
+    /// The theorem the rule came from.
+    pub name: String,
+    pub lhs: STerm,
+    pub rhs: STerm,
+    /// Pattern variables of `lhs`.
+    pub vars: Vec<String>,
+    /// Rules with a higher priority are tried first.
+    pub priority: i64,
+}
+
+impl SimpRule {
+    /// Orient the equation `statement` (Syntax) left to right.
+    ///
+    /// `is_constant` tells which argument-position names are constants
+    /// rather than pattern variables. Refuses a statement that is not an
+    /// equation, a left-hand side that is a bare variable, and a right-hand
+    /// side with variables the left-hand side does not bind.
+    pub fn from_equation(
+        name: &str,
+        statement: &Term,
+        priority: i64,
+        is_constant: impl Fn(&str) -> bool,
+    ) -> KernelResult<SimpRule> {
+        let reject = |why: String| KernelError::SimpRuleRejected(format!("'{}': {}", name, why));
+        let (lhs, rhs) = extract_equality(statement)
+            .ok_or_else(|| reject("not an equation".to_string()))?;
+        let (lhs, rhs) = match (term_to_sterm(&lhs), term_to_sterm(&rhs)) {
+            (Some(l), Some(r)) => (l, r),
+            _ => return Err(reject("not a first-order equation".to_string())),
+        };
+
+        let mut vars = Vec::new();
+        collect_vars(&lhs, true, &is_constant, &mut vars);
+        if let STerm::Name(n) = &lhs {
+            if vars.contains(n) {
+                return Err(reject(format!("the left-hand side is the bare variable {}", n)));
+            }
+        }
+        let mut rhs_vars = Vec::new();
+        collect_vars(&rhs, true, &is_constant, &mut rhs_vars);
+        if let Some(stray) = rhs_vars.iter().find(|v| !vars.contains(v)) {
+            return Err(reject(format!("{} occurs on the right but not on the left", stray)));
+        }
+
+        Ok(SimpRule { name: name.to_string(), lhs, rhs, vars, priority })
+    }
+
+    /// Rewrite `term` at its root, if the left-hand side matches it.
+    fn rewrite(&self, term: &STerm) -> Option<STerm> {
+        let mut binding = HashMap::new();
+        if match_pattern(&self.lhs, term, &self.vars, &mut binding) {
+            Some(substitute(&self.rhs, &binding))
+        } else {
+            None
+        }
+    }
+
+    /// Whether some subterm of `term` is an instance of the left-hand side.
+    fn fires_in(&self, term: &STerm) -> bool {
+        if self.rewrite(term).is_some() {
+            return true;
+        }
+        match term {
+            STerm::App(f, a) => self.fires_in(f) || self.fires_in(a),
+            _ => false,
+        }
+    }
+
+    /// The same rule with every pattern variable primed, so that its
+    /// variables are apart from those of any unprimed rule.
+    fn renamed_apart(&self) -> SimpRule {
+        let binding: HashMap<String, STerm> =
+            self.vars.iter().map(|v| (v.clone(), STerm::Name(format!("{}'", v)))).collect();
+        SimpRule {
+            name: self.name.clone(),
+            lhs: substitute(&self.lhs, &binding),
+            rhs: substitute(&self.rhs, &binding),
+            vars: self.vars.iter().map(|v| format!("{}'", v)).collect(),
+            priority: self.priority,
+        }
+    }
+}
+
+/// A named collection of simp rules, kept in the order they are tried:
+/// highest priority first, then in the order they were added.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimpSet {
+    rules: Vec<SimpRule>,
+}
+
+impl SimpSet {
+    /// An empty set: simplification by arithmetic and hypotheses only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The rules, in the order they are tried.
+    pub fn rules(&self) -> &[SimpRule] {
+        &self.rules
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Add a rule after the sanity checks: it must not rewrite into its own
+    /// left-hand side, must not loop with a rule already in the set, and
+    /// each root overlap with an equal-priority rule must rejoin.
+    pub fn add(&mut self, rule: SimpRule) -> KernelResult<()> {
+        let reject = |why: String| KernelError::SimpRuleRejected(format!("'{}': {}", rule.name, why));
+        if self.rules.iter().any(|r| r.name == rule.name) {
+            return Err(reject("already in the set".to_string()));
+        }
+
+        // Termination: arithmetic alone must not turn the right-hand side
+        // back into an instance of the left.
+        let rhs = simplify_sterm(&rule.rhs, &HashMap::new(), FUEL);
+        if rule.fires_in(&rhs) {
+            return Err(reject(format!(
+                "it loops: {} contains an instance of {}",
+                rule.rhs, rule.lhs
+            )));
+        }
+        for other in &self.rules {
+            if other.fires_in(&rule.rhs) && rule.fires_in(&other.rhs) {
+                return Err(reject(format!("it loops with '{}'", other.name)));
+            }
+        }
+
+        let at = self.rules.iter().take_while(|r| r.priority >= rule.priority).count();
+        let mut rules = self.rules.clone();
+        rules.insert(at, rule.clone());
+
+        // Confluence: where both left-hand sides match the same term and
+        // neither outranks the other, both results must simplify alike.
+        for other in self.rules.iter().filter(|r| r.priority == rule.priority) {
+            let other = other.renamed_apart();
+            let mut vars = rule.vars.clone();
+            vars.extend(other.vars.iter().cloned());
+            let mut unifier = HashMap::new();
+            if !unify(&rule.lhs, &other.lhs, &vars, &mut unifier) {
+                continue;
+            }
+            let peak = resolve(&rule.lhs, &unifier);
+            let left = simplify_with_rules(&resolve(&rule.rhs, &unifier), &HashMap::new(), &rules, FUEL);
+            let right = simplify_with_rules(&resolve(&other.rhs, &unifier), &HashMap::new(), &rules, FUEL);
+            if left != right {
+                return Err(reject(format!(
+                    "it overlaps '{}' on {}, which simplifies to {} or to {}; give one of them a higher priority",
+                    other.name, peak, left, right
+                )));
+            }
+        }
+
+        self.rules = rules;
+        Ok(())
+    }
+
+    /// Simplify a Syntax term with this set. `None` if `term` is not
+    /// first-order Syntax.
+    pub fn simplify(&self, term: &Term) -> Option<Term> {
+        let st = term_to_sterm(term)?;
+        Some(sterm_to_term(&simplify_with_rules(&st, &HashMap::new(), &self.rules, FUEL)))
+    }
+}
+
+/// Collect the non-constant names in argument position, in first-occurrence
+/// order. A name in head position is a function symbol.
+fn collect_vars(term: &STerm, argument: bool, is_constant: &impl Fn(&str) -> bool, out: &mut Vec<String>) {
+    match term {
+        STerm::Name(n) if argument && !is_constant(n) && !out.contains(n) => out.push(n.clone()),
+        STerm::App(f, a) => {
+            collect_vars(f, false, is_constant, out);
+            collect_vars(a, true, is_constant, out);
+        }
+        _ => {}
+    }
+}
+
+/// One-way matching of `pattern` against `term`, extending `binding`.
+fn match_pattern(pattern: &STerm, term: &STerm, vars: &[String], binding: &mut HashMap<String, STerm>) -> bool {
+    match (pattern, term) {
+        (STerm::Name(v), _) if vars.contains(v) => match binding.get(v) {
+            Some(bound) => bound == term,
+            None => {
+                binding.insert(v.clone(), term.clone());
+                true
+            }
+        },
+        (STerm::App(pf, pa), STerm::App(tf, ta)) => {
+            match_pattern(pf, tf, vars, binding) && match_pattern(pa, ta, vars, binding)
+        }
+        _ => pattern == term,
+    }
+}
+
+/// Replace the bound names of `term`.
+fn substitute(term: &STerm, binding: &HashMap<String, STerm>) -> STerm {
+    match term {
+        STerm::Name(n) => binding.get(n).cloned().unwrap_or_else(|| term.clone()),
+        STerm::App(f, a) => STerm::App(Box::new(substitute(f, binding)), Box::new(substitute(a, binding))),
+        _ => term.clone(),
+    }
+}
+
+/// Apply a triangular unifier all the way down.
+fn resolve(term: &STerm, unifier: &HashMap<String, STerm>) -> STerm {
+    match term {
+        STerm::Name(n) => match unifier.get(n) {
+            Some(t) => resolve(t, unifier),
+            None => term.clone(),
+        },
+        STerm::App(f, a) => STerm::App(Box::new(resolve(f, unifier)), Box::new(resolve(a, unifier))),
+        _ => term.clone(),
+    }
+}
+
+fn occurs(v: &str, term: &STerm) -> bool {
+    match term {
+        STerm::Name(n) => n == v,
+        STerm::App(f, a) => occurs(v, f) || occurs(v, a),
+        _ => false,
+    }
+}
+
+/// Syntactic unification with occurs check; `vars` may be bound in either term.
+fn unify(x: &STerm, y: &STerm, vars: &[String], unifier: &mut HashMap<String, STerm>) -> bool {
+    let x = resolve(x, unifier);
+    let y = resolve(y, unifier);
+    match (&x, &y) {
+        _ if x == y => true,
+        (STerm::Name(v), t) | (t, STerm::Name(v)) if vars.contains(v) => {
+            if occurs(v, t) {
+                return false;
+            }
+            unifier.insert(v.clone(), t.clone());
+            true
+        }
+        (STerm::App(f1, a1), STerm::App(f2, a2)) => {
+            unify(f1, f2, vars, unifier) && unify(a1, a2, vars, unifier)
+        }
+        _ => false,
+    }
+}
+
 // =============================================================================
 // GOAL DECOMPOSITION
 // =============================================================================
@@ -233,6 +542,11 @@ fn decompose_goal(goal: &Term) -> (Substitution, Term) {
 ///
 /// `true` if the simplified LHS equals the simplified RHS, `false` otherwise.
 pub fn check_goal(goal: &Term) -> bool {
+    check_goal_with(goal, &SimpSet::new())
+}
+
+/// [`check_goal`], also rewriting with the rules of `set`.
+pub fn check_goal_with(goal: &Term, set: &SimpSet) -> bool {
     let (subst, conclusion) = decompose_goal(goal);
 
     // Conclusion must be an equality
@@ -253,9 +567,8 @@ pub fn check_goal(goal: &Term) -> bool {
     };
 
     // Simplify both sides
-    const FUEL: usize = 1000;
-    let simp_lhs = simplify_sterm(&st_lhs, &subst, FUEL);
-    let simp_rhs = simplify_sterm(&st_rhs, &subst, FUEL);
+    let simp_lhs = simplify_with_rules(&st_lhs, &subst, &set.rules, FUEL);
+    let simp_rhs = simplify_with_rules(&st_rhs, &subst, &set.rules, FUEL);
 
     // Check if they're equal
     simp_lhs == simp_rhs
@@ -601,4 +914,70 @@ mod tests {
         let goal = make_sapp(make_sapp(make_sname("Eq"), make_slit(2)), make_slit(3));
         assert!(!check_goal(&goal), "simp should NOT prove 2 = 3");
     }
+
+    /// Build (Eq lhs rhs)
+    fn make_eq(lhs: Term, rhs: Term) -> Term {
+        make_sapp(make_sapp(make_sname("Eq"), lhs), rhs)
+    }
+
+    /// Build (op a b)
+    fn make_binary(op: &str, a: Term, b: Term) -> Term {
+        make_sapp(make_sapp(make_sname(op), a), b)
+    }
+
+    fn make_rule(name: &str, lhs: Term, rhs: Term, priority: i64) -> SimpRule {
+        SimpRule::from_equation(name, &make_eq(lhs, rhs), priority, |_| false).unwrap()
+    }
+
+    #[test]
+    fn test_rule_variables_are_argument_names() {
+        // f x (g y) = x: x and y are variables, f and g are function symbols
+        let lhs = make_binary("f", make_sname("x"), make_sapp(make_sname("g"), make_sname("y")));
+        let rule = make_rule("r", lhs, make_sname("x"), 0);
+        assert_eq!(rule.vars, vec!["x".to_string(), "y".to_string()]);
+    }
+
+    #[test]
+    fn test_rule_rewrites_inside_goal() {
+        // With f x 0 = x, simp proves (f (f a 0) 0) = a
+        let mut set = SimpSet::new();
+        set.add(make_rule("f_zero", make_binary("f", make_sname("x"), make_slit(0)), make_sname("x"), 0))
+            .unwrap();
+        let nested = make_binary("f", make_binary("f", make_sname("a"), make_slit(0)), make_slit(0));
+        assert!(!check_goal(&make_eq(nested.clone(), make_sname("a"))));
+        assert!(check_goal_with(&make_eq(nested.clone(), make_sname("a")), &set));
+        assert_eq!(set.simplify(&nested), Some(make_sname("a")));
+    }
+
+    #[test]
+    fn test_higher_priority_rule_is_tried_first() {
+        // g x = 1 at priority 0, g 0 = 2 at priority 5: g 0 becomes 2
+        let mut set = SimpSet::new();
+        set.add(make_rule("g_any", make_sapp(make_sname("g"), make_sname("x")), make_slit(1), 0))
+            .unwrap();
+        set.add(make_rule("g_zero", make_sapp(make_sname("g"), make_slit(0)), make_slit(2), 5))
+            .unwrap();
+        let names: Vec<&str> = set.rules().iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["g_zero", "g_any"]);
+        assert_eq!(set.simplify(&make_sapp(make_sname("g"), make_slit(0))), Some(make_slit(2)));
+    }
+
+    #[test]
+    fn test_joinable_overlap_is_accepted() {
+        // mul x 0 = 0 and mul 0 y = 0 overlap on mul 0 0, and both give 0
+        let mut set = SimpSet::new();
+        set.add(make_rule("mul_x0", make_binary("mul", make_sname("x"), make_slit(0)), make_slit(0), 0))
+            .unwrap();
+        set.add(make_rule("mul_0y", make_binary("mul", make_slit(0), make_sname("y")), make_slit(0), 0))
+            .unwrap();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_arithmetic_loop_is_refused() {
+        // 0 = sub 1 1 folds straight back to 0
+        let rule = make_rule("zero", make_slit(0), make_binary("sub", make_slit(1), make_slit(1)), 0);
+        let err = SimpSet::new().add(rule).unwrap_err();
+        assert!(err.to_string().contains("loops"), "{err}");
+    }
 }
@@ -164,7 +164,7 @@ fn days_to_ymd(days: i64) -> (i64, u8, u8) {
 /// The algebra (`succ`/`max`/`equiv`/`is_subtype_of`) is decided over a canonical
 /// normal form, NOT by the derived structural equality — `max(u,u) ≡ u`,
 /// `max(succ u, u) ≡ succ u`, etc.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Universe {
     /// SProp — the DEFINITIONALLY-proof-irrelevant sort (S). The bottom of the hierarchy
@@ -644,6 +644,51 @@ impl fmt::Display for Term {
     }
 }
 
+/// Collect the universe variables `term` mentions, in order of first
+/// occurrence, into `out`.
+pub(crate) fn universe_variables(term: &Term, out: &mut Vec<String>) {
+    fn level(u: &Universe, out: &mut Vec<String>) {
+        match u {
+            Universe::SProp | Universe::Prop | Universe::Type(_) => {}
+            Universe::Var(v) => {
+                if !out.contains(v) {
+                    out.push(v.clone());
+                }
+            }
+            Universe::Succ(l) => level(l, out),
+            Universe::Max(a, b) | Universe::IMax(a, b) => {
+                level(a, out);
+                level(b, out);
+            }
+        }
+    }
+    match term {
+        Term::Sort(u) => level(u, out),
+        Term::Const { levels, .. } => levels.iter().for_each(|l| level(l, out)),
+        Term::Var(_) | Term::Global(_) | Term::Lit(_) | Term::Hole => {}
+        Term::Pi { param_type, body_type: body, .. } | Term::Lambda { param_type, body, .. } => {
+            universe_variables(param_type, out);
+            universe_variables(body, out);
+        }
+        Term::App(f, a) => {
+            universe_variables(f, out);
+            universe_variables(a, out);
+        }
+        Term::Match { discriminant, motive, cases } => {
+            universe_variables(discriminant, out);
+            universe_variables(motive, out);
+            cases.iter().for_each(|c| universe_variables(c, out));
+        }
+        Term::Fix { body, .. } => universe_variables(body, out),
+        Term::MutualFix { defs, .. } => defs.iter().for_each(|(_, b)| universe_variables(b, out)),
+        Term::Let { ty, value, body, .. } => {
+            universe_variables(ty, out);
+            universe_variables(value, out);
+            universe_variables(body, out);
+        }
+    }
+}
+
 /// Instantiate universe variables throughout a term: substitute every `Sort`'s level by
 /// `subst`. This specializes a universe-POLYMORPHIC term (`λA:Sort u. …`) to a concrete
 /// level (`u := Type 0`), yielding an ordinary term the kernel checks as-is — so one
@@ -79,6 +79,8 @@ pub fn infer_type(ctx: &Context, term: &Term) -> KernelResult<Term> {
                     levels.len()
                 )));
             }
+            ctx.check_universe_instance(name, params, levels)
+                .map_err(KernelError::UniverseInconsistency)?;
             let subst: std::collections::HashMap<String, Universe> =
                 params.iter().cloned().zip(levels.iter().cloned()).collect();
             Ok(crate::term::instantiate_universes(&ty.clone(), &subst))
@@ -567,7 +569,7 @@ pub fn check_constructor_universes(
     while let Term::Pi { param, param_type, body_type } = cur {
         if i >= num_params {
             let s = infer_sort(&ext, param_type)?;
-            if !s.is_subtype_of(&target) {
+            if !ext.universe_leq(&s, &target) {
                 return Err(KernelError::CertificationError(format!(
                     "universe inconsistency: constructor '{ctor}' stores an argument in sort \
                      {s}, which exceeds the sort {target} of its inductive '{ind}'"
@@ -1330,7 +1332,7 @@ pub fn is_subtype(ctx: &Context, a: &Term, b: &Term) -> bool {
 fn is_subtype_normalized(ctx: &Context, a: &Term, b: &Term) -> bool {
     match (a, b) {
         // Universe subtyping
-        (Term::Sort(u1), Term::Sort(u2)) => u1.is_subtype_of(u2),
+        (Term::Sort(u1), Term::Sort(u2)) => ctx.universe_leq(u1, u2),
 
         // Pi subtyping (contravariant in param, covariant in body)
         (
@@ -1493,7 +1495,7 @@ fn def_eq_normalized(ctx: &Context, a: &Term, b: &Term) -> bool {
     }
 
     let congruent = match (a, b) {
-        (Term::Sort(u1), Term::Sort(u2)) => u1.equiv(u2),
+        (Term::Sort(u1), Term::Sort(u2)) => ctx.universe_equiv(u1, u2),
         (
             Term::Pi { param: p1, param_type: t1, body_type: b1 },
             Term::Pi { param: p2, param_type: t2, body_type: b2 },
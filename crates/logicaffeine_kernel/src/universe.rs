@@ -0,0 +1,275 @@
+//! Universe constraints for universe-polymorphic definitions.
+//!
+//! A polymorphic definition may need more than "for every level": a type
+//! former `Π(A:Sort u). Sort v` whose body stores `Sort u` inside `Sort v`
+//! only checks when `u < v`. Such a definition declares its
+//! [`UniverseConstraint`]s alongside its level parameters
+//! ([`Context::add_universe_poly_checked`]). The kernel then
+//!
+//! - refuses an unsatisfiable constraint set up front ([`consistent`]),
+//! - checks the body generically ASSUMING the constraints — cumulativity
+//!   `Sort a ≤ Sort b` holds when the level algebra proves it outright or the
+//!   assumptions [`entail`] it, and
+//! - checks every instantiation `name.{ℓ…}` against the constraints, so the
+//!   assumption is discharged at each use.
+//!
+//! Levels use the ℕ-encoding of the level algebra (`Prop = 0`, `Type n = n+1`,
+//! variables range over ℕ). A constraint between *atoms* — a variable plus an
+//! offset (`u`, `u+1`), or a constant — is a difference constraint, so a set of
+//! them is decided by longest paths in a small graph: it is unsatisfiable
+//! exactly when the graph has a positive cycle, and `a ≤ b` follows from it
+//! when a path from `a` to `b` is at least as heavy as their offsets demand.
+//! A `max` on the left of a constraint splits into one constraint per argument;
+//! on the right, or an `imax` anywhere, it is not a difference constraint and
+//! is rejected when declared (entailment ignores it).
+//!
+//! [`Context::add_universe_poly_checked`]: crate::Context::add_universe_poly_checked
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::term::Universe;
+
+/// `left ≤ right`, or `left < right` when `strict`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UniverseConstraint {
+    pub left: Universe,
+    pub right: Universe,
+    pub strict: bool,
+}
+
+impl UniverseConstraint {
+    /// `left ≤ right`.
+    pub fn le(left: Universe, right: Universe) -> Self {
+        UniverseConstraint { left, right, strict: false }
+    }
+
+    /// `left < right`.
+    pub fn lt(left: Universe, right: Universe) -> Self {
+        UniverseConstraint { left, right, strict: true }
+    }
+
+    /// The constraint with universe variables replaced by `subst`.
+    pub fn substitute(&self, subst: &HashMap<String, Universe>) -> Self {
+        UniverseConstraint {
+            left: self.left.substitute(subst),
+            right: self.right.substitute(subst),
+            strict: self.strict,
+        }
+    }
+
+    /// Whether the constraint holds for every value of its variables.
+    pub fn holds(&self) -> bool {
+        self.holds_assuming(&[])
+    }
+
+    /// Whether the constraint follows from `assumptions`.
+    pub fn holds_assuming(&self, assumptions: &[UniverseConstraint]) -> bool {
+        // `a < b` is `a + 1 ≤ b`. `Succ` is built raw: `Universe::succ` is the
+        // typing successor (`Prop`'s type is `Type 1`), not `+ 1`.
+        let left = if self.strict { Universe::Succ(Box::new(self.left.clone())) } else { self.left.clone() };
+        left.is_subtype_of(&self.right) || entail(assumptions, &left, &self.right)
+    }
+
+    /// The universe variables the constraint mentions.
+    pub(crate) fn variables(&self, out: &mut Vec<String>) {
+        level_variables(&self.left, out);
+        level_variables(&self.right, out);
+    }
+}
+
+impl fmt::Display for UniverseConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.left, if self.strict { "<" } else { "≤" }, self.right)
+    }
+}
+
+/// Check that some assignment of levels satisfies every constraint. The error
+/// names the constraints on a contradictory cycle, or the one that is not a
+/// difference constraint.
+pub fn consistent(constraints: &[UniverseConstraint]) -> Result<(), String> {
+    let graph = Graph::build(constraints).map_err(|c| format!("{c} is not supported (a `max` on its right, or an `imax`)"))?;
+    match graph.positive_cycle() {
+        None => Ok(()),
+        Some(cycle) => Err(format!(
+            "the constraints cannot all hold: {}",
+            cycle.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(", ")
+        )),
+    }
+}
+
+/// Whether `assumptions` entail `a ≤ b`. Sound but not complete: a `max` on
+/// the right is provable only through one of its arguments, and an `imax` or an
+/// unsupported assumption contributes nothing.
+pub fn entail(assumptions: &[UniverseConstraint], a: &Universe, b: &Universe) -> bool {
+    if assumptions.is_empty() {
+        return false;
+    }
+    let supported: Vec<UniverseConstraint> =
+        assumptions.iter().filter(|c| Graph::build(std::slice::from_ref(c)).is_ok()).cloned().collect();
+    let graph = match Graph::build(&supported) {
+        Ok(graph) => graph,
+        Err(_) => return false,
+    };
+    if graph.positive_cycle().is_some() {
+        // Anything follows from contradictory assumptions, but a definition
+        // checked under them was refused before it got here.
+        return false;
+    }
+    let (Some(left), Some(right)) = (atoms(a), atoms(b)) else {
+        return false;
+    };
+    let distances = graph.longest_paths();
+    left.iter().all(|(x, k)| {
+        right.iter().any(|(y, m)| match (graph.nodes.get(x), graph.nodes.get(y)) {
+            (Some(&i), Some(&j)) => distances[i][j].is_some_and(|d| d >= *k as i64 - *m as i64),
+            // A variable the assumptions never mention is known only to be at
+            // least zero, and equal to itself.
+            (Some(0), None) => m >= k,
+            _ => x == y && m >= k,
+        })
+    })
+}
+
+/// A level as a `max` of atoms `(variable, offset)`; `None` is the constant
+/// zero, so `Type 0` is `(None, 1)`. `None` for a level with an `imax`.
+fn atoms(u: &Universe) -> Option<Vec<(Option<String>, u64)>> {
+    fn go(u: &Universe, offset: u64, out: &mut Vec<(Option<String>, u64)>) -> bool {
+        match u {
+            Universe::SProp | Universe::Prop => out.push((None, offset)),
+            Universe::Type(n) => out.push((None, offset + *n as u64 + 1)),
+            Universe::Var(v) => out.push((Some(v.clone()), offset)),
+            Universe::Succ(l) => return go(l, offset + 1, out),
+            Universe::Max(a, b) => return go(a, offset, out) && go(b, offset, out),
+            Universe::IMax(..) => return false,
+        }
+        true
+    }
+    let mut out = Vec::new();
+    go(u, 0, &mut out).then_some(out)
+}
+
+fn level_variables(u: &Universe, out: &mut Vec<String>) {
+    match u {
+        Universe::SProp | Universe::Prop | Universe::Type(_) => {}
+        Universe::Var(v) => {
+            if !out.contains(v) {
+                out.push(v.clone());
+            }
+        }
+        Universe::Succ(l) => level_variables(l, out),
+        Universe::Max(a, b) | Universe::IMax(a, b) => {
+            level_variables(a, out);
+            level_variables(b, out);
+        }
+    }
+}
+
+/// The difference-constraint graph: an edge `x → y` of weight `w` says
+/// `y ≥ x + w`. Node 0 is the constant zero, below every variable.
+struct Graph {
+    nodes: HashMap<Option<String>, usize>,
+    /// `(from, to, weight, the constraint it came from)`.
+    edges: Vec<(usize, usize, i64, Option<UniverseConstraint>)>,
+}
+
+impl Graph {
+    /// The graph of `constraints`, or the first one that is not a difference
+    /// constraint.
+    fn build(constraints: &[UniverseConstraint]) -> Result<Graph, UniverseConstraint> {
+        let mut graph = Graph { nodes: HashMap::from([(None, 0)]), edges: Vec::new() };
+        for constraint in constraints {
+            let left = atoms(&constraint.left).ok_or_else(|| constraint.clone())?;
+            let right = atoms(&constraint.right).ok_or_else(|| constraint.clone())?;
+            let [(y, m)] = right.as_slice() else {
+                return Err(constraint.clone());
+            };
+            let to = graph.node(y);
+            for (x, k) in &left {
+                let from = graph.node(x);
+                let weight = *k as i64 - *m as i64 + i64::from(constraint.strict);
+                graph.edges.push((from, to, weight, Some(constraint.clone())));
+            }
+        }
+        Ok(graph)
+    }
+
+    fn node(&mut self, name: &Option<String>) -> usize {
+        let next = self.nodes.len();
+        let index = *self.nodes.entry(name.clone()).or_insert(next);
+        if index == next && name.is_some() {
+            // Every level is at least zero.
+            self.edges.push((0, index, 0, None));
+        }
+        index
+    }
+
+    /// All-pairs longest path weights (Floyd–Warshall); `None` where no path.
+    fn longest_paths(&self) -> Vec<Vec<Option<i64>>> {
+        let n = self.nodes.len();
+        let mut d = vec![vec![None; n]; n];
+        for (i, row) in d.iter_mut().enumerate() {
+            row[i] = Some(0);
+        }
+        for &(from, to, weight, _) in &self.edges {
+            d[from][to] = Some(d[from][to].map_or(weight, |w: i64| w.max(weight)));
+        }
+        for k in 0..n {
+            let from_k = d[k].clone();
+            for row in d.iter_mut() {
+                let Some(ik) = row[k] else { continue };
+                for (cell, kj) in row.iter_mut().zip(&from_k) {
+                    if let Some(kj) = kj {
+                        let through = ik + kj;
+                        if cell.map_or(true, |w| through > w) {
+                            *cell = Some(through);
+                        }
+                    }
+                }
+            }
+        }
+        d
+    }
+
+    /// The constraints along some positive-weight cycle, if there is one.
+    fn positive_cycle(&self) -> Option<Vec<UniverseConstraint>> {
+        // Bellman–Ford from a virtual source: a relaxation still possible after
+        // |V| rounds lies on or behind a positive cycle.
+        let n = self.nodes.len();
+        let mut dist = vec![0i64; n];
+        let mut via: Vec<Option<usize>> = vec![None; n];
+        let mut last = None;
+        for _ in 0..n {
+            last = None;
+            for (index, &(from, to, weight, _)) in self.edges.iter().enumerate() {
+                if dist[from] + weight > dist[to] {
+                    dist[to] = dist[from] + weight;
+                    via[to] = Some(index);
+                    last = Some(to);
+                }
+            }
+            last?;
+        }
+        // Walk back n steps to land on the cycle itself, then collect it.
+        let mut node = last?;
+        for _ in 0..n {
+            node = self.edges[via[node]?].0;
+        }
+        let start = node;
+        let mut cycle = Vec::new();
+        loop {
+            let (from, _, _, constraint) = &self.edges[via[node]?];
+            if let Some(constraint) = constraint {
+                if !cycle.contains(constraint) {
+                    cycle.push(constraint.clone());
+                }
+            }
+            node = *from;
+            if node == start {
+                break;
+            }
+        }
+        cycle.reverse();
+        Some(cycle)
+    }
+}
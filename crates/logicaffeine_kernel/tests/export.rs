@@ -0,0 +1,146 @@
+//! Lean 4 / Coq export — kernel terms rendered in each prover's surface syntax,
+//! and whole theorem files whose globals are axiomatized from the context.
+
+use logicaffeine_kernel::export::{export_term, export_theorem, ExportError, Prover};
+use logicaffeine_kernel::{Context, Literal, Term, Universe};
+
+fn g(n: &str) -> Term {
+    Term::Global(n.to_string())
+}
+fn v(n: &str) -> Term {
+    Term::Var(n.to_string())
+}
+fn app(f: Term, x: Term) -> Term {
+    Term::App(Box::new(f), Box::new(x))
+}
+fn arrow(a: Term, b: Term) -> Term {
+    Term::Pi { param: "_".to_string(), param_type: Box::new(a), body_type: Box::new(b) }
+}
+fn pi(x: &str, a: Term, b: Term) -> Term {
+    Term::Pi { param: x.to_string(), param_type: Box::new(a), body_type: Box::new(b) }
+}
+fn lam(x: &str, a: Term, b: Term) -> Term {
+    Term::Lambda { param: x.to_string(), param_type: Box::new(a), body: Box::new(b) }
+}
+fn prop() -> Term {
+    Term::Sort(Universe::Prop)
+}
+
+/// `∀ (x : Entity), Man x → Mortal x`.
+fn syllogism() -> Term {
+    pi("x", g("Entity"), arrow(app(g("Man"), v("x")), app(g("Mortal"), v("x"))))
+}
+
+fn syllogism_ctx() -> Context {
+    let mut ctx = Context::new();
+    ctx.add_inductive("Entity", Term::Sort(Universe::Type(0)));
+    ctx.add_declaration("Man", arrow(g("Entity"), prop()));
+    ctx.add_declaration("Mortal", arrow(g("Entity"), prop()));
+    ctx
+}
+
+#[test]
+fn dependent_and_plain_arrows() {
+    assert_eq!(export_term(&syllogism(), Prover::Lean4).unwrap(), "∀ (x : Entity), Man x → Mortal x");
+    assert_eq!(export_term(&syllogism(), Prover::Coq).unwrap(), "forall (x : Entity), Man x -> Mortal x");
+
+    // A Π whose body never mentions its binder is an arrow, named or not.
+    let named = pi("p", prop(), prop());
+    assert_eq!(export_term(&named, Prover::Lean4).unwrap(), "Prop → Prop");
+}
+
+#[test]
+fn arguments_and_arrow_domains_are_parenthesized() {
+    let higher_order = arrow(arrow(prop(), prop()), prop());
+    assert_eq!(export_term(&higher_order, Prover::Coq).unwrap(), "(Prop -> Prop) -> Prop");
+
+    let nested = app(app(g("f"), app(g("g"), v("x"))), v("y"));
+    assert_eq!(export_term(&nested, Prover::Lean4).unwrap(), "f (g x) y");
+
+    let id = lam("x", g("Nat"), v("x"));
+    assert_eq!(export_term(&app(id, g("Zero")), Prover::Lean4).unwrap(), "(fun (x : Nat) => x) Zero");
+}
+
+#[test]
+fn sorts_and_literals() {
+    assert_eq!(export_term(&Term::Sort(Universe::Type(0)), Prover::Lean4).unwrap(), "Type");
+    assert_eq!(export_term(&Term::Sort(Universe::Type(2)), Prover::Lean4).unwrap(), "Type 2");
+    assert_eq!(export_term(&Term::Sort(Universe::Type(2)), Prover::Coq).unwrap(), "Type");
+    assert_eq!(export_term(&Term::Sort(Universe::Var("u".into())), Prover::Lean4).unwrap(), "Sort u");
+
+    assert_eq!(export_term(&Term::Lit(Literal::Int(-3)), Prover::Lean4).unwrap(), "(-3 : Int)");
+    assert_eq!(export_term(&Term::Lit(Literal::Int(-3)), Prover::Coq).unwrap(), "-3%Z");
+    assert_eq!(export_term(&Term::Lit(Literal::Text("hi".into())), Prover::Coq).unwrap(), "\"hi\"%string");
+}
+
+#[test]
+fn identifiers_are_made_valid() {
+    assert_eq!(Prover::Lean4.identifier("Nat.add"), "Nat.add");
+    assert_eq!(Prover::Lean4.identifier("fun"), "«fun»");
+    assert_eq!(Prover::Lean4.identifier("is-even"), "«is-even»");
+    assert_eq!(Prover::Coq.identifier("Nat.add"), "Nat_add");
+    assert_eq!(Prover::Coq.identifier("fun"), "fun_");
+    assert_eq!(Prover::Coq.identifier("2x"), "_2x");
+}
+
+#[test]
+fn unsupported_constructs_are_reported() {
+    let fix = Term::Fix { name: "loop".to_string(), body: Box::new(v("loop")) };
+    assert_eq!(
+        export_term(&fix, Prover::Lean4),
+        Err(ExportError::Unsupported("the fixpoint `loop`".to_string()))
+    );
+    assert!(export_term(&Term::Lit(Literal::Float(1.5)), Prover::Coq).is_err());
+}
+
+#[test]
+fn theorem_files_axiomatize_what_they_use() {
+    let lean = export_theorem(&syllogism_ctx(), "mortality", &syllogism(), None, Prover::Lean4).unwrap();
+    assert_eq!(
+        lean,
+        "-- Exported from LOGOS.\n\nnamespace Logos\n\n\
+         axiom Entity : Type\n\
+         axiom Man : Entity → Prop\n\
+         axiom Mortal : Entity → Prop\n\n\
+         theorem mortality : ∀ (x : Entity), Man x → Mortal x := by\n  sorry\n\n\
+         end Logos\n"
+    );
+
+    let coq = export_theorem(&syllogism_ctx(), "mortality", &syllogism(), None, Prover::Coq).unwrap();
+    assert!(coq.contains("Parameter Man : Entity -> Prop."), "{coq}");
+    assert!(coq.contains("Theorem mortality : forall (x : Entity), Man x -> Mortal x.\nProof.\nAdmitted."), "{coq}");
+    assert!(coq.starts_with("(* Exported from LOGOS. *)") && coq.ends_with("End Logos.\n"), "{coq}");
+}
+
+#[test]
+fn exportable_proofs_close_the_theorem() {
+    let mut ctx = syllogism_ctx();
+    ctx.add_declaration("socrates", g("Entity"));
+    ctx.add_declaration("man_socrates", app(g("Man"), g("socrates")));
+
+    let proof = lam("h", syllogism(), app(app(v("h"), g("socrates")), g("man_socrates")));
+    let statement = arrow(syllogism(), app(g("Mortal"), g("socrates")));
+
+    let lean = export_theorem(&ctx, "socrates_mortal", &statement, Some(&proof), Prover::Lean4).unwrap();
+    assert!(lean.contains("axiom man_socrates : Man socrates"), "{lean}");
+    assert!(lean.contains(":=\n  fun (h : ∀ (x : Entity), Man x → Mortal x) => h socrates man_socrates"), "{lean}");
+
+    let coq = export_theorem(&ctx, "socrates_mortal", &statement, Some(&proof), Prover::Coq).unwrap();
+    assert!(coq.contains("Proof.\n  exact (fun (h : forall (x : Entity), Man x -> Mortal x) => h socrates man_socrates).\nQed."), "{coq}");
+}
+
+#[test]
+fn unknown_globals_are_flagged() {
+    let lean = export_theorem(&Context::new(), "t", &app(g("Mystery"), g("a")), None, Prover::Lean4).unwrap();
+    assert!(lean.contains("-- `Mystery` is not declared in the exporting context"), "{lean}");
+}
+
+#[test]
+fn prover_names() {
+    for prover in Prover::ALL {
+        assert_eq!(Prover::from_name(prover.name()), Some(prover));
+    }
+    assert_eq!(Prover::from_name("Lean"), Some(Prover::Lean4));
+    assert_eq!(Prover::from_name("isabelle"), None);
+    assert_eq!(Prover::Coq.file_extension(), "v");
+}
@@ -0,0 +1,70 @@
+//! Hash-consed terms and the normalization cache: identical subterms share one
+//! id, and a cached normal form is only reused in the global environment it was
+//! computed in.
+
+use logicaffeine_kernel::hashcons::HashCons;
+use logicaffeine_kernel::prelude::StandardLibrary;
+use logicaffeine_kernel::{normalize, Context, Literal, Term, Universe};
+
+fn g(n: &str) -> Term {
+    Term::Global(n.to_string())
+}
+fn app(f: Term, x: Term) -> Term {
+    Term::App(Box::new(f), Box::new(x))
+}
+
+#[test]
+fn identical_subterms_share_an_id() {
+    let fa = app(g("f"), g("a"));
+    let mut table = HashCons::new();
+    let twice = table.intern(&app(fa.clone(), fa.clone()));
+    assert_eq!(table.len(), 4, "f, a, f a, (f a) (f a)");
+    assert_eq!(table.intern(&fa), table.intern(&app(g("f"), g("a"))));
+    assert_eq!(table.intern(&app(fa.clone(), fa.clone())), twice);
+    assert_ne!(table.intern(&app(fa, g("a"))), twice);
+    assert_eq!(table.len(), 5);
+}
+
+#[test]
+fn float_literals_are_told_apart_by_their_bits() {
+    let mut table = HashCons::new();
+    let zero = table.intern(&Term::Lit(Literal::Float(0.0)));
+    let negative = table.intern(&Term::Lit(Literal::Float(-0.0)));
+    assert_ne!(zero, negative, "equal under ==, but not the same term");
+}
+
+#[test]
+fn the_generation_tracks_globals_only() {
+    let mut ctx = Context::new();
+    assert_eq!(ctx.generation(), 0);
+    StandardLibrary::register(&mut ctx);
+    let registered = ctx.generation();
+    assert_ne!(registered, 0);
+
+    let under_binder = ctx.extend("x", g("Nat"));
+    assert_eq!(under_binder.generation(), registered);
+
+    let mut extended = ctx.clone();
+    extended.add_declaration("p", Term::Sort(Universe::Prop));
+    assert_ne!(extended.generation(), registered);
+    assert_eq!(ctx.generation(), registered, "the original is untouched");
+}
+
+#[test]
+fn cached_normal_forms_follow_the_environment() {
+    let mut base = Context::new();
+    StandardLibrary::register(&mut base);
+    let nat = g("Nat");
+
+    let mut left = base.clone();
+    left.add_definition("c".to_string(), nat.clone(), g("Zero"));
+    let mut right = base.clone();
+    right.add_definition("c".to_string(), nat, app(g("Succ"), g("Zero")));
+
+    let term = app(g("Succ"), g("c"));
+    for _ in 0..2 {
+        assert_eq!(normalize(&left, &term), app(g("Succ"), g("Zero")));
+        assert_eq!(normalize(&right, &term), app(g("Succ"), app(g("Succ"), g("Zero"))));
+    }
+    assert_eq!(normalize(&base, &term), term, "`c` is not defined here");
+}
@@ -0,0 +1,187 @@
+//! Lean 4 / Coq import — files in the exporter's syntax read back into kernel
+//! terms, and proofs finished in those assistants re-checked by the kernel.
+
+use logicaffeine_kernel::export::{export_term, export_theorem, Prover};
+use logicaffeine_kernel::import::{check_proof, declare, parse_file, parse_term, ImportError};
+use logicaffeine_kernel::prelude::StandardLibrary;
+use logicaffeine_kernel::{Context, Term, Universe};
+
+fn g(n: &str) -> Term {
+    Term::Global(n.to_string())
+}
+fn v(n: &str) -> Term {
+    Term::Var(n.to_string())
+}
+fn app(f: Term, x: Term) -> Term {
+    Term::App(Box::new(f), Box::new(x))
+}
+fn arrow(a: Term, b: Term) -> Term {
+    Term::Pi { param: "_".to_string(), param_type: Box::new(a), body_type: Box::new(b) }
+}
+fn pi(x: &str, a: Term, b: Term) -> Term {
+    Term::Pi { param: x.to_string(), param_type: Box::new(a), body_type: Box::new(b) }
+}
+
+/// `∀ (x : Entity), man x → mortal x`.
+fn syllogism() -> Term {
+    pi("x", g("Entity"), arrow(app(g("man"), v("x")), app(g("mortal"), v("x"))))
+}
+
+/// The prelude plus the file's declarations.
+fn context_for(file: &logicaffeine_kernel::import::ImportedFile) -> Context {
+    let mut ctx = Context::new();
+    StandardLibrary::register(&mut ctx);
+    declare(&mut ctx, &file.declarations).expect("declarations should be accepted");
+    ctx
+}
+
+const SOCRATES_LEAN: &str = "\
+-- Exported from LOGOS.
+
+namespace Logos
+
+axiom Entity : Type
+axiom man : Entity → Prop
+axiom mortal : Entity → Prop
+axiom Socrates : Entity
+
+theorem Socrates_Mortality (h1 : ∀ x : Entity, man x → mortal x) (h2 : man Socrates) : mortal Socrates :=
+  h1 Socrates h2
+
+end Logos
+";
+
+const SOCRATES_COQ: &str = "\
+(* Exported from LOGOS. *)
+
+Module Logos.
+
+Parameter Entity : Type.
+Parameter man : Entity -> Prop.
+Parameter mortal : Entity -> Prop.
+Parameter Socrates : Entity.
+
+Theorem Socrates_Mortality (h1 : forall x : Entity, man x -> mortal x) (h2 : man Socrates) : mortal Socrates.
+Proof.
+  exact (h1 Socrates h2).
+Qed.
+
+End Logos.
+";
+
+#[test]
+fn exported_terms_read_back() {
+    for prover in Prover::ALL {
+        let text = export_term(&syllogism(), prover).unwrap();
+        assert_eq!(parse_term(&text, prover).unwrap(), syllogism(), "{prover}: {text}");
+    }
+    let higher = arrow(arrow(Term::Sort(Universe::Prop), Term::Sort(Universe::Prop)), Term::Sort(Universe::Type(2)));
+    let text = export_term(&higher, Prover::Lean4).unwrap();
+    assert_eq!(parse_term(&text, Prover::Lean4).unwrap(), higher, "{text}");
+}
+
+#[test]
+fn exported_theorem_files_read_back() {
+    let mut ctx = Context::new();
+    ctx.add_inductive("Entity", Term::Sort(Universe::Type(0)));
+    ctx.add_declaration("man", arrow(g("Entity"), Term::Sort(Universe::Prop)));
+    ctx.add_declaration("mortal", arrow(g("Entity"), Term::Sort(Universe::Prop)));
+    for prover in Prover::ALL {
+        let text = export_theorem(&ctx, "mortality", &syllogism(), None, prover).unwrap();
+        let file = parse_file(&text, prover).unwrap();
+        assert_eq!(file.declarations.len(), 3, "{text}");
+        let theorem = file.theorem("mortality").unwrap();
+        assert_eq!(theorem.statement, syllogism());
+        assert_eq!(theorem.proof, None, "left open: {text}");
+    }
+}
+
+#[test]
+fn finished_proofs_check_in_both_syntaxes() {
+    for (source, prover) in [(SOCRATES_LEAN, Prover::Lean4), (SOCRATES_COQ, Prover::Coq)] {
+        let file = parse_file(source, prover).unwrap();
+        let theorem = file.theorem("Socrates_Mortality").unwrap();
+        let proof = theorem.proof.as_ref().expect("a term proof");
+        check_proof(&context_for(&file), &theorem.statement, proof).unwrap();
+    }
+}
+
+#[test]
+fn a_proof_of_something_else_is_refused() {
+    let wrong = SOCRATES_LEAN.replace("h1 Socrates h2", "h2");
+    let file = parse_file(&wrong, Prover::Lean4).unwrap();
+    let theorem = file.theorem("Socrates_Mortality").unwrap();
+    let error = check_proof(&context_for(&file), &theorem.statement, theorem.proof.as_ref().unwrap()).unwrap_err();
+    assert!(matches!(error, ImportError::Mismatch { .. }), "{error}");
+}
+
+#[test]
+fn unannotated_binders_take_the_expected_type() {
+    let source = "\
+axiom man : Entity → Prop
+axiom mortal : Entity → Prop
+theorem t : (∀ x : Entity, man x → mortal x) → ∀ y : Entity, man y → mortal y :=
+  fun h y hy => h y hy
+";
+    let file = parse_file(source, Prover::Lean4).unwrap();
+    let theorem = &file.theorems[0];
+    check_proof(&context_for(&file), &theorem.statement, theorem.proof.as_ref().unwrap()).unwrap();
+
+    let unguided = parse_term("fun h => h", Prover::Lean4).unwrap();
+    let error = check_proof(&context_for(&file), &g("True"), &unguided).unwrap_err();
+    assert!(matches!(&error, ImportError::Untyped(name) if name == "h"), "{error}");
+}
+
+#[test]
+fn connectives_map_onto_the_prelude() {
+    let lean = parse_term("¬P ∧ Q ∨ R → S ↔ T", Prover::Lean4).unwrap();
+    let coq = parse_term("(~ P /\\ Q \\/ R -> S) <-> T", Prover::Coq).unwrap();
+    assert_eq!(lean, coq);
+
+    let source = "\
+Parameter a : Entity.
+Theorem same : exists x : Entity, x = a.
+Proof.
+  exact (witness Entity (fun x : Entity => x = a) a (refl Entity a)).
+Qed.
+";
+    let file = parse_file(source, Prover::Coq).unwrap();
+    let theorem = &file.theorems[0];
+    check_proof(&context_for(&file), &theorem.statement, theorem.proof.as_ref().unwrap()).unwrap();
+}
+
+#[test]
+fn tactic_proofs_leave_nothing_to_check() {
+    let lean = "theorem t (p : Prop) (hp : p) : p := by\n  exact hp\n\ntheorem u : Prop := sorry\n";
+    let file = parse_file(lean, Prover::Lean4).unwrap();
+    assert_eq!(file.theorems.len(), 2);
+    assert!(file.theorems.iter().all(|theorem| theorem.proof.is_none()));
+
+    let coq = "Theorem t (p : Prop) (hp : p) : p.\nProof.\n  intros.\n  assumption.\nQed.\n";
+    assert_eq!(parse_file(coq, Prover::Coq).unwrap().theorems[0].proof, None);
+}
+
+#[test]
+fn assumed_propositions_are_refused() {
+    let file = parse_file("axiom cheat : False\n", Prover::Lean4).unwrap();
+    let mut ctx = Context::new();
+    StandardLibrary::register(&mut ctx);
+    let error = declare(&mut ctx, &file.declarations).unwrap_err();
+    assert!(matches!(&error, ImportError::Assumption(name) if name == "cheat"), "{error}");
+
+    let file = parse_file("Parameter Nat : Prop.\n", Prover::Coq).unwrap();
+    let error = declare(&mut ctx, &file.declarations).unwrap_err();
+    assert!(matches!(&error, ImportError::Redeclared(name) if name == "Nat"), "{error}");
+}
+
+#[test]
+fn syntax_errors_name_the_line() {
+    let error = parse_file("axiom p : Prop\ntheorem t : p :=\n  )\n", Prover::Lean4).unwrap_err();
+    match error {
+        ImportError::Syntax { line, message } => {
+            assert_eq!(line, 3);
+            assert!(message.contains("expected a term"), "{message}");
+        }
+        other => panic!("expected a syntax error, got {other}"),
+    }
+}
@@ -9,8 +9,8 @@
 
 use logicaffeine_kernel::prelude::StandardLibrary;
 use logicaffeine_kernel::{
-    derive_recursor, double_check, infer_type, is_subtype, normalize, recheck, Context,
-    DoubleCheck, MutualInductive, Term, Universe,
+    derive_induction, derive_recursor, double_check, infer_type, is_subtype, normalize, recheck,
+    Context, DoubleCheck, MutualInductive, Term, Universe,
 };
 
 /// Alpha-aware type equality (binder names may differ, e.g. `Π(n:Nat).Bool` ≡ `Nat→Bool`).
@@ -243,6 +243,25 @@ fn tree_forest_mutual_recursor_derives_and_typechecks() {
     }
 }
 
+#[test]
+fn tree_forest_induction_narrows_every_motive() {
+    // Tree_ind : Π(P0 : Tree → Prop). Π(P1 : Forest → Prop). … — both motives of
+    // the block become Prop, and the result is still two-kernel-verified.
+    let mut ctx = std_ctx();
+    ctx.add_mutual_inductives(&tree_forest_block()).expect("Tree/Forest registers");
+
+    let (tree_ty, tree_ind) = derive_induction(&ctx, "Tree").expect("Tree_ind derives");
+    assert_eq!(double_check(&ctx, &tree_ind), DoubleCheck::Agreed);
+    let Term::Pi { param_type: p0, body_type, .. } = &tree_ty else {
+        panic!("Tree_ind type must be a Π, got {tree_ty}");
+    };
+    let Term::Pi { param_type: p1, .. } = body_type.as_ref() else {
+        panic!("Tree_ind takes two motives, got {tree_ty}");
+    };
+    assert!(same_type(&ctx, p0, &arrow(g("Tree"), Term::Sort(Universe::Prop))), "{tree_ty}");
+    assert!(same_type(&ctx, p1, &arrow(g("Forest"), Term::Sort(Universe::Prop))), "{tree_ty}");
+}
+
 #[test]
 fn tree_forest_mutual_recursor_computes() {
     // THE HEADLINE COMPUTATION: define `forestLength : Forest → Nat` (the number of
@@ -8,7 +8,8 @@
 
 use logicaffeine_kernel::prelude::StandardLibrary;
 use logicaffeine_kernel::{
-    derive_recursor, double_check, infer_type, normalize, Context, DoubleCheck, Term, Universe,
+    derive_induction, derive_recursor, double_check, infer_type, normalize, Context, DoubleCheck,
+    Term, Universe,
 };
 
 fn g(n: &str) -> Term {
@@ -201,3 +202,81 @@ fn nat_recursor_computes_a_real_function_predecessor() {
     let result = normalize(&ctx, &applied);
     assert_eq!(result, two, "pred(3) must reduce to 2, got {result}");
 }
+
+/// `{ind}_ind` must check in both kernels, like the recursor it narrows.
+fn assert_induction_two_kernel_verified(ctx: &Context, ind: &str) -> Term {
+    let (ty, term) = derive_induction(ctx, ind).expect("induction principle derives");
+    assert_eq!(
+        double_check(ctx, &term),
+        DoubleCheck::Agreed,
+        "{ind}_ind must be independently re-checked, got {:?}",
+        double_check(ctx, &term)
+    );
+    ty
+}
+
+#[test]
+fn induction_principles_have_prop_motives() {
+    // Nat_ind : Π(P : Nat → Prop). P Zero → (Π a. P a → P (Succ a)) → Π(x). P x
+    let ctx = std_ctx();
+    let ty = assert_induction_two_kernel_verified(&ctx, "Nat");
+    match &ty {
+        Term::Pi { param_type, .. } => {
+            assert_eq!(**param_type, pi("_", g("Nat"), Term::Sort(Universe::Prop)), "{ty}")
+        }
+        _ => panic!("expected a Π, got {ty}"),
+    }
+
+    // TList_ind keeps its type parameter ahead of the narrowed motive.
+    let ty = assert_induction_two_kernel_verified(&ctx, "TList");
+    match &ty {
+        Term::Pi { body_type, .. } => match body_type.as_ref() {
+            Term::Pi { param_type, .. } => assert_eq!(
+                **param_type,
+                pi("_", app(g("TList"), var("A0")), Term::Sort(Universe::Prop)),
+                "{ty}"
+            ),
+            _ => panic!("TList_ind opens with Π(A0). Π(P). …, got {ty}"),
+        },
+        _ => panic!("expected a Π, got {ty}"),
+    }
+}
+
+#[test]
+fn tree_induction_proves_a_statement_for_every_tree() {
+    // Tree_ind (λt. Eq Tree t t) (refl Tree Leaf) (λl r ihl ihr. refl Tree (Node l r))
+    //   : Π(t : Tree). Eq Tree t t
+    let mut ctx = std_ctx();
+    ctx.add_inductive("Tree", Term::Sort(Universe::Type(0)));
+    ctx.add_constructor("Leaf", "Tree", g("Tree"));
+    ctx.add_constructor("Node", "Tree", pi("l", g("Tree"), pi("r", g("Tree"), g("Tree"))));
+    assert_induction_two_kernel_verified(&ctx, "Tree");
+    let (_ty, tree_ind) = derive_induction(&ctx, "Tree").unwrap();
+
+    let tree = g("Tree");
+    let refl_at = |t: Term| app(app(g("refl"), tree.clone()), t);
+    let eq_self = |t: &str| app(app(app(g("Eq"), tree.clone()), var(t)), var(t));
+    let motive = lam("t", tree.clone(), eq_self("t"));
+    let step = lam(
+        "l",
+        tree.clone(),
+        lam(
+            "r",
+            tree.clone(),
+            lam("ihl", eq_self("l"), lam("ihr", eq_self("r"), refl_at(app(app(g("Node"), var("l")), var("r"))))),
+        ),
+    );
+    let proof = app(app(app(tree_ind, motive), refl_at(g("Leaf"))), step);
+    let ty = infer_type(&ctx, &proof).expect("the induction proof checks");
+    let expected = pi("x", tree.clone(), eq_self("x"));
+    assert!(logicaffeine_kernel::defeq_for_test(&ctx, &ty, &expected), "{ty}");
+}
+
+#[test]
+fn induction_principles_refuse_a_type_motive() {
+    // Nat_rec computes into Nat; Nat_ind only proves propositions.
+    let ctx = std_ctx();
+    let (_ty, nat_ind) = derive_induction(&ctx, "Nat").unwrap();
+    let const_nat_motive = lam("_", g("Nat"), g("Nat"));
+    assert!(infer_type(&ctx, &app(nat_ind, const_nat_motive)).is_err(), "Nat is not a Prop");
+}
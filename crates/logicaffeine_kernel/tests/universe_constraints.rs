@@ -0,0 +1,167 @@
+//! Universe constraints on polymorphic definitions: a definition that only
+//! checks when `u < v` declares so, is checked generically under that
+//! assumption, and every instantiation must discharge it. An unsatisfiable
+//! constraint set is refused with a universe-inconsistency error.
+
+use logicaffeine_kernel::prelude::StandardLibrary;
+use logicaffeine_kernel::{
+    double_check, infer_type, recheck, Context, DoubleCheck, KernelError, ReCheckError, Term,
+    Universe, UniverseConstraint,
+};
+
+fn pi(p: &str, ty: Term, body: Term) -> Term {
+    Term::Pi { param: p.to_string(), param_type: Box::new(ty), body_type: Box::new(body) }
+}
+fn app(f: Term, x: Term) -> Term {
+    Term::App(Box::new(f), Box::new(x))
+}
+fn g(n: &str) -> Term {
+    Term::Global(n.to_string())
+}
+fn u(n: &str) -> Universe {
+    Universe::Var(n.to_string())
+}
+fn const_at(name: &str, levels: Vec<Universe>) -> Term {
+    Term::Const { name: name.to_string(), levels }
+}
+fn params(names: &[&str]) -> Vec<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+fn std_ctx() -> Context {
+    let mut ctx = Context::new();
+    StandardLibrary::register(&mut ctx);
+    ctx
+}
+
+/// `lift.{u v} : Sort v := Sort u` — a universe stored one level up, which
+/// only checks when `u < v`.
+fn add_lift(ctx: &mut Context, constraints: Vec<UniverseConstraint>) -> Result<(), KernelError> {
+    ctx.add_universe_poly_checked(
+        "lift",
+        params(&["u", "v"]),
+        constraints,
+        Term::Sort(u("v")),
+        Term::Sort(u("u")),
+    )
+}
+
+#[test]
+fn a_definition_needing_a_constraint_checks_only_with_it() {
+    let mut ctx = std_ctx();
+    let err = add_lift(&mut ctx, Vec::new()).expect_err("Sort u : Sort v is not true for every u, v");
+    assert!(matches!(err, KernelError::TypeMismatch { .. }), "{err:?}");
+    assert!(ctx.get_universe_poly("lift").is_none());
+
+    add_lift(&mut ctx, vec![UniverseConstraint::lt(u("u"), u("v"))]).expect("u < v makes it check");
+    assert_eq!(ctx.universe_constraints("lift"), &[UniverseConstraint::lt(u("u"), u("v"))]);
+}
+
+#[test]
+fn instantiations_must_satisfy_the_constraints() {
+    let mut ctx = std_ctx();
+    add_lift(&mut ctx, vec![UniverseConstraint::lt(u("u"), u("v"))]).unwrap();
+
+    let good = const_at("lift", vec![Universe::Type(0), Universe::Type(1)]);
+    assert_eq!(infer_type(&ctx, &good).unwrap(), Term::Sort(Universe::Type(1)));
+    assert_eq!(double_check(&ctx, &good), DoubleCheck::Agreed);
+
+    let bad = const_at("lift", vec![Universe::Type(1), Universe::Type(1)]);
+    match infer_type(&ctx, &bad) {
+        Err(KernelError::UniverseInconsistency(why)) => {
+            assert!(why.contains("'lift.{Type1, Type1}' violates"), "{why}")
+        }
+        other => panic!("Type 1 < Type 1 is false, got {other:?}"),
+    }
+    assert!(
+        matches!(recheck(&ctx, &bad), Err(ReCheckError::Ill(_))),
+        "the re-checker refuses it too: {:?}",
+        recheck(&ctx, &bad)
+    );
+}
+
+#[test]
+fn an_unsatisfiable_constraint_set_is_refused() {
+    let mut ctx = std_ctx();
+    let cyclic = vec![UniverseConstraint::lt(u("u"), u("v")), UniverseConstraint::le(u("v"), u("u"))];
+    match add_lift(&mut ctx, cyclic) {
+        Err(KernelError::UniverseInconsistency(why)) => {
+            assert!(why.contains("cannot all hold"), "{why}");
+            assert!(why.contains("u < v") && why.contains("v ≤ u"), "the cycle is named: {why}");
+        }
+        other => panic!("u < v ≤ u has no solution, got {other:?}"),
+    }
+
+    let below_prop = vec![UniverseConstraint::lt(u("u"), Universe::Prop)];
+    let err = add_lift(&mut ctx, below_prop).expect_err("no level is below Prop");
+    assert!(matches!(err, KernelError::UniverseInconsistency(_)), "{err:?}");
+}
+
+#[test]
+fn undeclared_universe_variables_are_refused() {
+    let mut ctx = std_ctx();
+    let err = ctx
+        .add_universe_poly_checked("stray", params(&["u"]), Vec::new(), Term::Sort(u("w")), Term::Sort(u("u")))
+        .expect_err("w is not a parameter");
+    match err {
+        KernelError::UniverseInconsistency(why) => assert!(why.contains("universe variable w"), "{why}"),
+        other => panic!("{other:?}"),
+    }
+}
+
+#[test]
+fn constraints_are_discharged_inside_another_polymorphic_definition() {
+    let mut ctx = std_ctx();
+    add_lift(&mut ctx, vec![UniverseConstraint::lt(u("u"), u("v"))]).unwrap();
+
+    // `lift2.{a b c} := lift.{a b}` inherits the obligation `a < b`: it checks
+    // only when it assumes it.
+    let use_lift = |ctx: &mut Context, constraints| {
+        ctx.add_universe_poly_checked(
+            "lift2",
+            params(&["a", "b", "c"]),
+            constraints,
+            Term::Sort(Universe::Max(Box::new(u("b")), Box::new(u("c")))),
+            const_at("lift", vec![u("a"), u("b")]),
+        )
+    };
+    let err = use_lift(&mut ctx, Vec::new()).expect_err("a < b is not known");
+    assert!(matches!(err, KernelError::UniverseInconsistency(_)), "{err:?}");
+    use_lift(&mut ctx, vec![UniverseConstraint::lt(u("a"), u("b"))]).expect("assumed, so discharged");
+}
+
+#[test]
+fn the_prelude_combinators_serve_every_level() {
+    let ctx = std_ctx();
+    // id.{Type 0} Nat Zero : Nat
+    let at_nat = app(app(const_at("id", vec![Universe::Type(0)]), g("Nat")), g("Zero"));
+    assert_eq!(infer_type(&ctx, &at_nat).unwrap(), g("Nat"));
+    assert_eq!(double_check(&ctx, &at_nat), DoubleCheck::Agreed);
+
+    // id.{Type 1} (Type 0) Nat : Type 0 — the same definition, one level up.
+    let at_type = app(app(const_at("id", vec![Universe::Type(1)]), Term::Sort(Universe::Type(0))), g("Nat"));
+    assert_eq!(infer_type(&ctx, &at_type).unwrap(), Term::Sort(Universe::Type(0)));
+    let too_low = app(app(const_at("id", vec![Universe::Type(0)]), Term::Sort(Universe::Type(0))), g("Nat"));
+    assert!(infer_type(&ctx, &too_low).is_err(), "Type 0 is not in Type 0");
+
+    // comp.{…} Nat Nat Nat Succ Succ : Nat → Nat
+    let t0 = Universe::Type(0);
+    let mut twice = const_at("comp", vec![t0.clone(), t0.clone(), t0]);
+    for arg in [g("Nat"), g("Nat"), g("Nat"), g("Succ"), g("Succ")] {
+        twice = app(twice, arg);
+    }
+    let ty = infer_type(&ctx, &twice).unwrap();
+    assert!(logicaffeine_kernel::defeq_for_test(&ctx, &ty, &pi("_", g("Nat"), g("Nat"))), "{ty}");
+    assert_eq!(
+        logicaffeine_kernel::normalize(&ctx, &app(twice, g("Zero"))),
+        app(g("Succ"), app(g("Succ"), g("Zero")))
+    );
+
+    // const.{Prop, Type 0} at a proposition and a type.
+    let k = app(
+        app(const_at("const", vec![Universe::Prop, Universe::Type(0)]), g("True")),
+        g("Nat"),
+    );
+    let k_ty = infer_type(&ctx, &k).unwrap();
+    let expected = pi("_", g("True"), pi("_", g("Nat"), g("True")));
+    assert!(logicaffeine_kernel::defeq_for_test(&ctx, &k_ty, &expected), "{k_ty}");
+}
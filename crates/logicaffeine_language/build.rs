@@ -59,6 +59,10 @@ struct RefactoredLexiconData {
     /// lexer only applies the expansion.
     #[serde(default)]
     negative_contractions: HashMap<String, String>,
+    /// Universal pronouns and the quantified phrases they abbreviate
+    /// ("everyone" → "every person"), so they scope like any "every" NP.
+    #[serde(default)]
+    universal_pronouns: HashMap<String, String>,
     noun_patterns: Vec<String>,
     disambiguation_not_verbs: Vec<String>,
     morphology: Morphology,
@@ -199,6 +203,10 @@ struct MweEntry {
 struct PhrasalVerbEntry {
     lemma: String,
     class: String,
+    /// The lemma when no object follows, for verbs whose intransitive sense
+    /// differs ("the plane took off" → Depart vs "took off his coat" → Remove).
+    #[serde(default)]
+    intransitive: Option<String>,
 }
 
 #[derive(Deserialize, Default)]
@@ -394,6 +402,24 @@ fn main() {
         .map(|v| v.lemma.to_lowercase())
         .collect();
     generate_is_check(&mut file, "is_relevance_verb", &relevance_verbs);
+    // Question-embedding verbs (wonder, ask, …): "if" after one opens a polar
+    // embedded question, not a conditional.
+    let question_embedding_verbs: Vec<String> = data
+        .verbs
+        .iter()
+        .filter(|v| v.features.iter().any(|f| f == "QuestionEmbedding"))
+        .map(|v| v.lemma.to_lowercase())
+        .collect();
+    generate_is_check(&mut file, "is_question_embedding_verb", &question_embedding_verbs);
+    // Neg-raising verbs (think, believe, …): matrix negation can be read in
+    // the complement.
+    let neg_raising_verbs: Vec<String> = data
+        .verbs
+        .iter()
+        .filter(|v| v.features.iter().any(|f| f == "NegRaising"))
+        .map(|v| v.lemma.to_lowercase())
+        .collect();
+    generate_is_check(&mut file, "is_neg_raising_verb", &neg_raising_verbs);
     generate_is_check(&mut file, "is_particle", &data.particles);
     generate_is_check(&mut file, "is_month", &data.months);
     generate_is_check(&mut file, "is_adverb", &data.adverbs);
@@ -525,6 +551,7 @@ fn main() {
 
     // Generate negative n't-contraction expansion lookup
     generate_lookup_negative_contraction(&mut file, &data.negative_contractions);
+    generate_lookup_universal_pronoun(&mut file, &data.universal_pronouns);
 
     // Generate phrasal verb lookup for particle movement
     generate_lookup_phrasal_verb(&mut file, &data.phrasal_verbs);
@@ -558,6 +585,65 @@ fn main() {
 
     // Generate relational/pertainymic adjective lookups (mirrors noun derivation)
     generate_lookup_relational_adjective(&mut file, &data.adjectives);
+
+    // Surface-form word lists per class: the candidate pools the spelling
+    // corrector (`suggest`) searches. Inflected forms are included so "brks"
+    // can reach "barks", not just the lemma "bark".
+    let mut noun_words = Vec::new();
+    for noun in data.nouns.iter().filter(|n| !n.features.iter().any(|f| f == "Proper")) {
+        let lower = noun.lemma.to_lowercase();
+        match noun.forms.as_ref().and_then(|f| f.plural.as_ref()) {
+            Some(plural) => noun_words.push(plural.to_lowercase()),
+            None => noun_words.push(regular_suffix_s(&lower)),
+        }
+        noun_words.push(lower);
+    }
+    generate_word_list(&mut file, "NOUN_WORDS", &noun_words);
+
+    let mut verb_words = Vec::new();
+    for verb in &data.verbs {
+        let lower = verb.lemma.to_lowercase();
+        let forms = verb.forms.as_ref();
+        match forms.and_then(|f| f.present3s.as_ref()) {
+            Some(present3s) => verb_words.push(present3s.to_lowercase()),
+            None => verb_words.push(regular_suffix_s(&lower)),
+        }
+        if let Some(forms) = forms {
+            for form in [&forms.past, &forms.participle, &forms.gerund].into_iter().flatten() {
+                verb_words.push(form.to_lowercase());
+            }
+        }
+        verb_words.push(lower);
+    }
+    generate_word_list(&mut file, "VERB_WORDS", &verb_words);
+
+    let adjective_words: Vec<String> =
+        data.adjectives.iter().map(|a| a.lemma.to_lowercase()).collect();
+    generate_word_list(&mut file, "ADJECTIVE_WORDS", &adjective_words);
+
+    let adverb_words: Vec<String> = data
+        .adverbs
+        .iter()
+        .chain(&data.scopal_adverbs)
+        .chain(&data.temporal_adverbs)
+        .chain(&data.quantificational_adverbs)
+        .chain(&data.degree_adverbs)
+        .cloned()
+        .collect();
+    generate_word_list(&mut file, "ADVERB_WORDS", &adverb_words);
+
+    let function_words: Vec<String> = data
+        .keywords
+        .keys()
+        .chain(data.articles.keys())
+        .chain(data.auxiliaries.keys())
+        .chain(data.pronouns.iter().map(|p| &p.word))
+        .chain(&data.prepositions)
+        .chain(&data.particles)
+        .chain(data.number_words.keys())
+        .cloned()
+        .collect();
+    generate_word_list(&mut file, "FUNCTION_WORDS", &function_words);
 }
 
 // ═══════════════════════════════════════════════════════════════════
@@ -834,6 +920,25 @@ fn derive_irregular_plurals(nouns: &[NounDefinition]) -> HashMap<String, String>
     plurals
 }
 
+/// The regular "-s" inflection (plural noun / third-person verb): "dog" →
+/// "dogs", "church" → "churches", "fly" → "flies".
+fn regular_suffix_s(word: &str) -> String {
+    let sibilant = ["s", "x", "z", "ch", "sh"].iter().any(|s| word.ends_with(s));
+    let consonant_y = word.ends_with('y')
+        && word
+            .chars()
+            .rev()
+            .nth(1)
+            .is_some_and(|c| !"aeiou".contains(c));
+    if sibilant {
+        format!("{word}es")
+    } else if consonant_y {
+        format!("{}ies", &word[..word.len() - 1])
+    } else {
+        format!("{word}s")
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════
 // Code Generation Functions
 // ═══════════════════════════════════════════════════════════════════
@@ -1146,6 +1251,21 @@ fn generate_is_check(file: &mut fs::File, fn_name: &str, words: &[String]) {
     writeln!(file, "}}\n").unwrap();
 }
 
+fn generate_word_list(file: &mut fs::File, const_name: &str, words: &[String]) {
+    use std::collections::BTreeSet;
+
+    let unique_words: BTreeSet<String> = words
+        .iter()
+        .map(|w| w.to_lowercase())
+        .filter(|w| !w.is_empty() && !w.contains(' '))
+        .collect();
+    writeln!(file, "pub const {}: &[&str] = &[", const_name).unwrap();
+    for word in unique_words {
+        writeln!(file, "    \"{}\",", word).unwrap();
+    }
+    writeln!(file, "];\n").unwrap();
+}
+
 fn generate_lookup_verb_class(
     file: &mut fs::File,
     state_verbs: &[String],
@@ -1473,6 +1593,28 @@ fn generate_lookup_negative_contraction(
     writeln!(file, "}}\n").unwrap();
 }
 
+fn generate_lookup_universal_pronoun(file: &mut fs::File, pronouns: &HashMap<String, String>) {
+    writeln!(
+        file,
+        "/// The quantified phrase a universal pronoun abbreviates (\"everyone\" → \"every person\")."
+    )
+    .unwrap();
+    writeln!(
+        file,
+        "pub fn lookup_universal_pronoun(word: &str) -> Option<&'static str> {{"
+    )
+    .unwrap();
+    writeln!(file, "    match word {{").unwrap();
+    let mut entries: Vec<_> = pronouns.iter().collect();
+    entries.sort();
+    for (word, expansion) in entries {
+        writeln!(file, "        \"{}\" => Some(\"{}\"),", word, expansion).unwrap();
+    }
+    writeln!(file, "        _ => None,").unwrap();
+    writeln!(file, "    }}").unwrap();
+    writeln!(file, "}}\n").unwrap();
+}
+
 fn generate_lookup_unit_dimension(file: &mut fs::File, units: &HashMap<String, String>) {
     writeln!(
         file,
@@ -1527,6 +1669,22 @@ fn generate_lookup_phrasal_verb(file: &mut fs::File, phrasal_verbs: &HashMap<Str
     writeln!(file, "        _ => None,").unwrap();
     writeln!(file, "    }}").unwrap();
     writeln!(file, "}}\n").unwrap();
+
+    writeln!(
+        file,
+        "pub fn lookup_intransitive_phrasal_verb(verb: &str, particle: &str) -> Option<&'static str> {{"
+    )
+    .unwrap();
+    writeln!(file, "    let key = format!(\"{{}}_{{}}\", verb.to_lowercase(), particle.to_lowercase());").unwrap();
+    writeln!(file, "    match key.as_str() {{").unwrap();
+    for (key, entry) in phrasal_verbs {
+        if let Some(intransitive) = &entry.intransitive {
+            writeln!(file, "        \"{}\" => Some(\"{}\"),", key, intransitive).unwrap();
+        }
+    }
+    writeln!(file, "        _ => None,").unwrap();
+    writeln!(file, "    }}").unwrap();
+    writeln!(file, "}}\n").unwrap();
 }
 
 fn generate_lookup_sort(file: &mut fs::File, nouns: &[NounDefinition]) {
@@ -1592,11 +1750,12 @@ fn generate_mwe_trie_init(file: &mut fs::File, mwes: &[MweEntry]) {
         };
         writeln!(
             file,
-            "    trie.insert(&[{}], MweTarget {{ lemma: \"{}\", pos: \"{}\", class: {} }});",
+            "    trie.insert(&[{}], MweTarget {{ lemma: \"{}\", pos: \"{}\", class: {}, idiom: {} }});",
             pattern.join(", "),
             mwe.lemma,
             mwe.pos,
-            class_expr
+            class_expr,
+            mwe.features.iter().any(|f| f == "Idiom")
         )
         .unwrap();
     }
@@ -11,6 +11,9 @@
 //! | `## Definition` | "A User is a structure." | `TypeDef::Struct` |
 //! | `## Definition` | "A Shape is an enum." | `TypeDef::Enum` |
 //! | `## Policy` | "A user can publish if they are admin." | `CapabilityDef` |
+//! | `## Policy` | "A program can run \"git\" and \"make\"." | [`PolicyRegistry::allow_command`] |
+//! | `## Settings` | "port: Int." | `TypeDef::Struct` named `Settings` |
+//! | `## Arguments` | "A flag verbose, \"Print every step\"." | `ArgumentDef` + `TypeDef::Struct` named `Arguments` |
 //!
 //! # Key Function
 //!
@@ -18,7 +21,9 @@
 
 use crate::token::{Token, TokenType, BlockType};
 use logicaffeine_base::{Interner, Symbol};
-use super::registry::{TypeRegistry, TypeDef, FieldDef, FieldType, VariantDef};
+use super::registry::{
+    TypeRegistry, TypeDef, FieldDef, FieldType, VariantDef, ArgumentDef, ArgumentStyle, ARGUMENTS_TYPE, SETTINGS_TYPE,
+};
 use super::policy::{PolicyRegistry, PredicateDef, CapabilityDef, PolicyCondition};
 use super::dependencies::scan_dependencies;
 
@@ -72,6 +77,16 @@ impl<'a> DiscoveryPass<'a> {
                 // Phase 50: Security policy definitions
                 self.advance(); // consume ## Policy
                 self.scan_policy_block(&mut policy_registry);
+            } else if self.check_block_header(BlockType::Settings) {
+                // The settings schema is an ordinary struct named `Settings`,
+                // one field per line: `port: Int.` or `a host, which is Text.`
+                self.advance(); // consume ## Settings
+                let fields = self.parse_struct_fields();
+                let name = self.interner.intern(SETTINGS_TYPE);
+                type_registry.register(name, TypeDef::Struct { fields, generics: vec![], is_portable: false, is_shared: false });
+            } else if self.check_block_header(BlockType::Arguments) {
+                self.advance(); // consume ## Arguments
+                self.scan_arguments_block(&mut type_registry);
             } else if self.check_block_header(BlockType::Requires) {
                 // Requires blocks contain dependency metadata, not type definitions.
                 // Skip to next block header.
@@ -117,6 +132,92 @@ impl<'a> DiscoveryPass<'a> {
         }
     }
 
+    /// Scan an `## Arguments` block, one declaration per line:
+    /// `[a|an] [flag|option] name[: Type][, "help text"].`
+    ///
+    /// A bare `name: Type` is positional; a flag needs no type (it is a Bool).
+    /// Registers the declarations, and an `Arguments` struct with one field each.
+    fn scan_arguments_block(&mut self, registry: &mut TypeRegistry) {
+        let mut arguments = Vec::new();
+        while self.pos < self.tokens.len() {
+            if matches!(self.peek(), Some(Token { kind: TokenType::BlockHeader { .. }, .. })) {
+                break;
+            }
+            if self.check_newline() || self.check_indent() || self.check_dedent() || self.check_period() {
+                self.advance();
+                continue;
+            }
+            if self.check_article() {
+                self.advance();
+            }
+
+            // `flag`/`option` are the style, unless they are the name itself (`option: Text.`)
+            let names_follow = !matches!(
+                self.tokens.get(self.pos + 1).map(|t| &t.kind),
+                Some(TokenType::Colon | TokenType::Comma | TokenType::Period)
+            );
+            let style = if names_follow && self.check_word("flag") {
+                self.advance();
+                ArgumentStyle::Flag
+            } else if names_follow && self.check_word("option") {
+                self.advance();
+                ArgumentStyle::Option
+            } else {
+                ArgumentStyle::Positional
+            };
+
+            let Some(name) = self.consume_argument_name() else {
+                self.skip_to_period();
+                continue;
+            };
+            let ty = if self.check_colon() {
+                self.advance(); // consume ":"
+                self.consume_field_type()
+            } else {
+                // A flag is a Bool; a positional or option without a type is
+                // reported when the arguments are read.
+                let implied = if style == ArgumentStyle::Flag { "Bool" } else { "Unknown" };
+                FieldType::Primitive(self.interner.intern(implied))
+            };
+            let help = match self.tokens.get(self.pos + 1).map(|t| &t.kind) {
+                Some(TokenType::StringLiteral(text)) if self.check_comma() => {
+                    let text = *text;
+                    self.advance(); // consume ","
+                    self.advance(); // consume the help text
+                    Some(text)
+                }
+                _ => None,
+            };
+            arguments.push(ArgumentDef { name, ty, style, help });
+        }
+
+        let fields = arguments.iter()
+            .map(|arg| FieldDef { name: arg.name, ty: arg.ty.clone(), is_public: true })
+            .collect();
+        let name = self.interner.intern(ARGUMENTS_TYPE);
+        registry.register(name, TypeDef::Struct { fields, generics: vec![], is_portable: false, is_shared: false });
+        registry.register_arguments(arguments);
+    }
+
+    /// An argument's name, spelled as written (`maxCount`), whatever part of
+    /// speech the lexer took it for.
+    fn consume_argument_name(&mut self) -> Option<Symbol> {
+        let token = self.peek()?;
+        if matches!(token.kind, TokenType::StringLiteral(_) | TokenType::CharLiteral(_)) {
+            return None;
+        }
+        let lexeme = token.lexeme;
+        let text = self.interner.resolve(lexeme);
+        let is_name = text.chars().next().is_some_and(|c| c.is_alphabetic())
+            && text.chars().all(|c| c.is_alphanumeric() || c == '_');
+        if is_name {
+            self.advance();
+            Some(lexeme)
+        } else {
+            None
+        }
+    }
+
     /// Phase 50: Scan policy block for predicate and capability definitions
     /// Patterns:
     /// - "A User is admin if the user's role equals \"admin\"."
@@ -195,6 +296,24 @@ impl<'a> DiscoveryPass<'a> {
             // "A User can publish the Document if..."
             self.advance(); // consume "can"
 
+            // "A program can run "git" and "make"." grants the program host
+            // access rather than a type a capability
+            if self.interner.resolve(subject_type).eq_ignore_ascii_case("program") && self.check_word("run") {
+                self.advance(); // consume "run"
+                while let Some(Token { kind: TokenType::StringLiteral(program), .. }) = self.peek() {
+                    registry.allow_command(*program);
+                    self.advance();
+                    if self.check_comma() {
+                        self.advance();
+                    }
+                    if self.check_word("and") || self.check_word("or") {
+                        self.advance();
+                    }
+                }
+                self.skip_to_period();
+                return;
+            }
+
             // Get action name (e.g., "publish", "edit"). For a verb action take its
             // LEMMA — that is exactly what the `Check ... can <verb>` site resolves
             // (parser), so the registered and looked-up symbols agree. Using
@@ -471,6 +590,10 @@ impl<'a> DiscoveryPass<'a> {
         // Phase 47/49: Check for pre-type modifiers: "A portable Config has:" or "A shared Config has:"
         let mut is_portable = false;
         let mut is_shared = false;
+        // "A public Point has:" — visible to importing modules.
+        let mut is_public = false;
+        // `Comparable [by f1 then f2]` — `Some(keys)`; empty keys order by every field.
+        let mut ordering: Option<Vec<Symbol>> = None;
         loop {
             if self.check_portable() {
                 is_portable = true;
@@ -478,12 +601,18 @@ impl<'a> DiscoveryPass<'a> {
             } else if self.check_shared() {
                 is_shared = true;
                 self.advance();
+            } else if self.check_word("public") {
+                is_public = true;
+                self.advance();
             } else {
                 break;
             }
         }
 
         if let Some(name_sym) = self.consume_noun_or_proper() {
+            if is_public {
+                registry.register_public(name_sym);
+            }
             // Phase 34: Check for "of [T]" which indicates user-defined generic
             let type_params = if self.check_preposition("of") {
                 self.advance(); // consume "of"
@@ -509,13 +638,19 @@ impl<'a> DiscoveryPass<'a> {
                         if self.check_word("and") {
                             self.advance(); // consume "and"
                         }
+                    } else if self.check_word("Comparable") {
+                        self.advance(); // consume "Comparable"
+                        ordering = Some(self.parse_ordering_keys());
+                        if self.check_word("and") {
+                            self.advance(); // consume "and"
+                        }
                     } else {
                         break;
                     }
                 }
 
                 // If no modifiers were found, restore position
-                if !is_portable && !is_shared {
+                if !is_portable && !is_shared && ordering.is_none() {
                     self.pos = copula_pos;
                 }
             }
@@ -533,6 +668,10 @@ impl<'a> DiscoveryPass<'a> {
                     if self.check_indent() {
                         self.advance(); // consume INDENT
                         let fields = self.parse_struct_fields_with_params(&type_params);
+                        if let Some(keys) = ordering {
+                            let keys = if keys.is_empty() { fields.iter().map(|f| f.name).collect() } else { keys };
+                            registry.register_ordering(name_sym, keys);
+                        }
                         registry.register(name_sym, TypeDef::Struct { fields, generics: type_params, is_portable, is_shared });
                         return;
                     }
@@ -885,6 +1024,13 @@ impl<'a> DiscoveryPass<'a> {
             self.advance();
         }
 
+        // "a shared Document": the nested JSON-like CRDT
+        if self.check_shared_document() {
+            self.advance(); // consume "shared"
+            self.advance(); // consume "Document"
+            return FieldType::Named(self.interner.intern("SharedDocument"));
+        }
+
         if let Some(name) = self.consume_noun_or_proper() {
             let name_str = self.interner.resolve(name);
 
@@ -1243,6 +1389,25 @@ impl<'a> DiscoveryPass<'a> {
         }
     }
 
+    /// The keys after `Comparable`: `by age`, `by last then first` — none without `by`.
+    fn parse_ordering_keys(&mut self) -> Vec<Symbol> {
+        let mut keys = Vec::new();
+        if !self.check_word("by") {
+            return keys;
+        }
+        self.advance(); // consume "by"
+        while let Some(token) = self.peek() {
+            keys.push(token.lexeme);
+            self.advance(); // consume the field name
+            if self.check_word("then") || self.check_comma() {
+                self.advance(); // consume "then" / ","
+            } else {
+                break;
+            }
+        }
+        keys
+    }
+
     /// Phase 47: Check for Portable token
     fn check_portable(&self) -> bool {
         matches!(self.peek(), Some(Token { kind: TokenType::Portable, .. }))
@@ -1253,6 +1418,15 @@ impl<'a> DiscoveryPass<'a> {
         matches!(self.peek(), Some(Token { kind: TokenType::Shared, .. }))
     }
 
+    /// Check for "shared Document" (a user type named Document stays a plain name)
+    fn check_shared_document(&self) -> bool {
+        self.check_shared()
+            && self
+                .tokens
+                .get(self.pos + 1)
+                .map_or(false, |t| self.interner.resolve(t.lexeme) == "Document")
+    }
+
     // Phase 34: Bracket checks for type parameters
     fn check_lbracket(&self) -> bool {
         matches!(self.peek(), Some(Token { kind: TokenType::LBracket, .. }))
@@ -1315,6 +1489,13 @@ impl<'a> DiscoveryPass<'a> {
             self.advance();
         }
 
+        // "a shared Document": the nested JSON-like CRDT
+        if self.check_shared_document() {
+            self.advance(); // consume "shared"
+            self.advance(); // consume "Document"
+            return FieldType::Named(self.interner.intern("SharedDocument"));
+        }
+
         if let Some(name) = self.consume_noun_or_proper() {
             // Check if this is a type parameter reference
             if type_params.contains(&name) {
@@ -1431,8 +1612,8 @@ mod tests {
     fn make_tokens(source: &str, interner: &mut Interner) -> Vec<Token> {
         let mut lexer = Lexer::new(source, interner);
         let tokens = lexer.tokenize();
-        let mwe_trie = mwe::build_mwe_trie();
-        mwe::apply_mwe_pipeline(tokens, &mwe_trie, interner)
+        let mwe_trie = mwe::shared_mwe_trie();
+        mwe::apply_mwe_pipeline(tokens, mwe_trie, interner)
     }
 
     #[test]
@@ -1474,6 +1655,89 @@ A Point has:
         }
     }
 
+    #[test]
+    fn discovery_records_public_types() {
+        let source = r#"## Definition
+A public Point has:
+    an x, which is Int.
+A Cache has:
+    a size, which is Int.
+"#;
+        let mut interner = Interner::new();
+        let tokens = make_tokens(source, &mut interner);
+
+        let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
+        let registry = discovery.run();
+
+        let point = interner.intern("Point");
+        let cache = interner.intern("Cache");
+        assert!(registry.is_type(point) && registry.is_type(cache));
+        assert!(registry.is_exported(point), "public Point is exported");
+        assert!(!registry.is_exported(cache), "Cache stays private once Point is public");
+    }
+
+    #[test]
+    fn discovery_collects_allowed_commands() {
+        let mut interner = Interner::new();
+        let source = "## Policy\nA program can run \"git\", \"make\" and \"cargo\".\nA program can run \"git\".\n\n## Main\nShow 1.";
+        let tokens = make_tokens(source, &mut interner);
+
+        let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
+        let policies = discovery.run_full().policies;
+
+        let allowed: Vec<&str> = policies.allowed_commands().iter().map(|c| interner.resolve(*c)).collect();
+        assert_eq!(allowed, ["git", "make", "cargo"]);
+    }
+
+    #[test]
+    fn discovery_parses_arguments_block() {
+        let source = r#"## Arguments
+input: Text, "The file to read".
+A flag verbose, "Print every step".
+An option maxCount: Option of Int.
+"#;
+        let mut interner = Interner::new();
+        let tokens = make_tokens(source, &mut interner);
+
+        let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
+        let registry = discovery.run();
+
+        let schema = registry.arguments_schema(&interner).expect("the declarations should be valid");
+        let names: Vec<&str> = schema.iter().map(|(arg, _)| interner.resolve(arg.name)).collect();
+        assert_eq!(names, ["input", "verbose", "maxCount"]);
+        let styles: Vec<ArgumentStyle> = schema.iter().map(|(arg, _)| arg.style).collect();
+        assert_eq!(styles, [ArgumentStyle::Positional, ArgumentStyle::Flag, ArgumentStyle::Option]);
+        assert_eq!(schema[0].0.help.map(|h| interner.resolve(h)), Some("The file to read"));
+        assert!(schema[2].1.optional && schema[2].0.help.is_none());
+        assert!(registry.is_type(interner.intern(ARGUMENTS_TYPE)));
+    }
+
+    #[test]
+    fn discovery_parses_comparable_keys() {
+        let source = r#"## Definition
+A Player is Comparable by score then name and has:
+    a name, which is Text.
+    a score, which is Int.
+A Card is Portable and Comparable and has:
+    a rank, which is Int.
+    a suit, which is Text.
+"#;
+        let mut interner = Interner::new();
+        let tokens = make_tokens(source, &mut interner);
+
+        let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
+        let registry = discovery.run();
+
+        let mut keys = |name: &str| -> Vec<String> {
+            let keys = registry.ordering(interner.intern(name)).expect("should be Comparable");
+            keys.iter().map(|k| interner.resolve(*k).to_string()).collect()
+        };
+        assert_eq!(keys("Player"), ["score", "name"]);
+        // Without `by`, every field in declaration order.
+        assert_eq!(keys("Card"), ["rank", "suit"]);
+        assert!(matches!(registry.get(interner.intern("Card")), Some(TypeDef::Struct { is_portable: true, .. })));
+    }
+
     #[test]
     fn discovery_works_with_markdown_header() {
         // Phase 36: LOGOS files have `# Header` before `## Definition`
@@ -21,7 +21,10 @@ pub mod discovery;
 pub mod dependencies;
 pub mod policy;
 
-pub use registry::{FieldDef, FieldType, TypeDef, TypeRegistry, VariantDef};
+pub use registry::{
+    ArgumentDef, ArgumentStyle, FieldDef, FieldType, SettingDef, SettingKind, TypeDef, TypeRegistry, VariantDef,
+    ARGUMENTS_TYPE, SETTINGS_TYPE,
+};
 pub use discovery::{DiscoveryPass, DiscoveryResult};
 pub use dependencies::{scan_dependencies, Dependency};
 pub use policy::{PolicyRegistry, PredicateDef, CapabilityDef, PolicyCondition};
@@ -3,6 +3,10 @@
 //! Stores predicate and capability definitions parsed from `## Policy` blocks.
 //! These are used to generate security methods on structs and enforce them
 //! with the `Check` statement.
+//!
+//! The block also grants the program itself access to its host:
+//! `A program can run "git" and "make".` lists the programs `Run the command`
+//! may start. Without such a line it may start none.
 
 use std::collections::HashMap;
 use logicaffeine_base::Symbol;
@@ -79,6 +83,8 @@ pub struct PolicyRegistry {
     predicates: HashMap<Symbol, Vec<PredicateDef>>,
     /// Capabilities indexed by subject type
     capabilities: HashMap<Symbol, Vec<CapabilityDef>>,
+    /// Programs `Run the command` may start
+    commands: Vec<Symbol>,
 }
 
 impl PolicyRegistry {
@@ -102,6 +108,18 @@ impl PolicyRegistry {
             .push(def);
     }
 
+    /// Allow `Run the command` to start `program`
+    pub fn allow_command(&mut self, program: Symbol) {
+        if !self.commands.contains(&program) {
+            self.commands.push(program);
+        }
+    }
+
+    /// The programs `Run the command` may start, in the order allowed
+    pub fn allowed_commands(&self) -> &[Symbol] {
+        &self.commands
+    }
+
     /// Get predicates for a type
     pub fn get_predicates(&self, subject_type: Symbol) -> Option<&[PredicateDef]> {
         self.predicates.get(&subject_type).map(|v| v.as_slice())
@@ -134,7 +152,7 @@ impl PolicyRegistry {
 
     /// Check if registry has any policies
     pub fn is_empty(&self) -> bool {
-        self.predicates.is_empty() && self.capabilities.is_empty()
+        self.predicates.is_empty() && self.capabilities.is_empty() && self.commands.is_empty()
     }
 }
 
@@ -10,7 +10,7 @@
 //!
 //! The registry is populated by [`super::DiscoveryPass`] before main parsing begins.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use logicaffeine_base::{Interner, Symbol};
 
 /// Type reference for struct fields (avoids circular deps with ast::TypeExpr)
@@ -71,9 +71,86 @@ pub enum TypeDef {
     Alias { target: Symbol },
 }
 
+/// The struct a `## Settings` block declares.
+pub const SETTINGS_TYPE: &str = "Settings";
+
+/// The struct an `## Arguments` block declares.
+pub const ARGUMENTS_TYPE: &str = "Arguments";
+
+/// A value a settings file, environment variable or command-line argument
+/// can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingKind {
+    Int,
+    Real,
+    Text,
+    Bool,
+}
+
+impl SettingKind {
+    fn from_type_name(name: &str) -> Option<SettingKind> {
+        match name {
+            "Int" => Some(SettingKind::Int),
+            "Real" | "Float" => Some(SettingKind::Real),
+            "Text" | "String" => Some(SettingKind::Text),
+            "Bool" | "Boolean" => Some(SettingKind::Bool),
+            _ => None,
+        }
+    }
+}
+
+/// The settings shape of a field type: a scalar kind, and whether it is an
+/// `Option of` one (so it may be left out).
+fn setting_shape(ty: &FieldType, interner: &Interner) -> Option<(SettingKind, bool)> {
+    let scalar = |ty: &FieldType| match ty {
+        FieldType::Primitive(s) | FieldType::Named(s) => SettingKind::from_type_name(interner.resolve(*s)),
+        _ => None,
+    };
+    match ty {
+        FieldType::Generic { base, params } if params.len() == 1
+            && matches!(interner.resolve(*base), "Option" | "Maybe") => Some((scalar(&params[0])?, true)),
+        ty => Some((scalar(ty)?, false)),
+    }
+}
+
+/// One field of a settings schema. `optional` fields are `Option of` a kind
+/// and may be left out; the rest are required.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingDef {
+    pub name: Symbol,
+    pub kind: SettingKind,
+    pub optional: bool,
+}
+
+/// How a declared command-line argument is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgumentStyle {
+    /// `input: Text.` — a bare value, in declaration order.
+    Positional,
+    /// `A flag verbose.` — `--verbose`, true when given.
+    Flag,
+    /// `An option count: Int.` — `--count <COUNT>`.
+    Option,
+}
+
+/// One declaration of an `## Arguments` block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgumentDef {
+    pub name: Symbol,
+    pub ty: FieldType,
+    pub style: ArgumentStyle,
+    /// The help text after the declaration, shown by `--help`.
+    pub help: Option<Symbol>,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct TypeRegistry {
     types: HashMap<Symbol, TypeDef>,
+    arguments: Vec<ArgumentDef>,
+    /// `Comparable` structs → the fields they order by, most significant first.
+    orderings: HashMap<Symbol, Vec<Symbol>>,
+    /// Types declared `public` (`A public Point has:`).
+    public: HashSet<Symbol>,
 }
 
 impl TypeRegistry {
@@ -115,6 +192,79 @@ impl TypeRegistry {
         self.types.get(&name)
     }
 
+    /// The schema of the settings struct `name`, in declaration order.
+    ///
+    /// Fails with a message naming the first field a settings file can't
+    /// hold: only `Int`, `Real`, `Text`, `Bool` and `Option of` one of those.
+    pub fn settings_schema(&self, name: Symbol, interner: &Interner) -> Result<Vec<SettingDef>, String> {
+        let Some(TypeDef::Struct { fields, .. }) = self.types.get(&name) else {
+            return Err(format!("there is no `## {}` block declaring the settings to read", SETTINGS_TYPE));
+        };
+        fields.iter().map(|field| match setting_shape(&field.ty, interner) {
+            Some((kind, optional)) => Ok(SettingDef { name: field.name, kind, optional }),
+            None => Err(format!(
+                "setting `{}` must be an Int, Real, Text, Bool, or an Option of one of those",
+                interner.resolve(field.name)
+            )),
+        }).collect()
+    }
+
+    /// Record the declarations of the `## Arguments` block, in order.
+    pub fn register_arguments(&mut self, arguments: Vec<ArgumentDef>) {
+        self.arguments = arguments;
+    }
+
+    /// The `## Arguments` declarations paired with their value shapes, in
+    /// declaration order. A flag is always a required `Bool`.
+    ///
+    /// Fails with a message naming the first declaration whose type is not
+    /// `Int`, `Real`, `Text`, `Bool` or `Option of` one of those.
+    pub fn arguments_schema(&self, interner: &Interner) -> Result<Vec<(&ArgumentDef, SettingDef)>, String> {
+        if self.arguments.is_empty() {
+            return Err(format!("there is no `## {}` block declaring the arguments to read", ARGUMENTS_TYPE));
+        }
+        self.arguments.iter().map(|arg| {
+            let shape = setting_shape(&arg.ty, interner);
+            match shape {
+                Some((kind, optional)) if arg.style != ArgumentStyle::Flag || (kind, optional) == (SettingKind::Bool, false) => {
+                    Ok((arg, SettingDef { name: arg.name, kind, optional }))
+                }
+                Some(_) => Err(format!("flag `{}` can only be a Bool", interner.resolve(arg.name))),
+                None => Err(format!(
+                    "argument `{}` must be an Int, Real, Text, Bool, or an Option of one of those",
+                    interner.resolve(arg.name)
+                )),
+            }
+        }).collect()
+    }
+
+    /// Record that struct `name` is `Comparable`, ordered by `fields` in turn.
+    pub fn register_ordering(&mut self, name: Symbol, fields: Vec<Symbol>) {
+        self.orderings.insert(name, fields);
+    }
+
+    /// The fields a `Comparable` struct orders by, or `None` if it is not one.
+    pub fn ordering(&self, name: Symbol) -> Option<&[Symbol]> {
+        self.orderings.get(&name).map(Vec::as_slice)
+    }
+
+    /// Record that type `name` was declared `public`.
+    pub fn register_public(&mut self, name: Symbol) {
+        self.public.insert(name);
+    }
+
+    /// Whether type `name` was declared `public`.
+    pub fn is_public(&self, name: Symbol) -> bool {
+        self.public.contains(&name)
+    }
+
+    /// Whether type `name` is visible to modules that import this one. A
+    /// module that declares no type `public` exports them all; once one is
+    /// `public`, the rest stay private to the module.
+    pub fn is_exported(&self, name: Symbol) -> bool {
+        self.public.is_empty() || self.public.contains(&name)
+    }
+
     /// Iterate over all registered types (for codegen)
     pub fn iter_types(&self) -> impl Iterator<Item = (&Symbol, &TypeDef)> {
         self.types.iter()
@@ -195,4 +345,24 @@ mod tests {
         assert!(registry.is_type(foo));
         assert!(!registry.is_generic(foo));
     }
+
+    #[test]
+    fn settings_schema_accepts_scalars_and_their_options() {
+        let mut interner = Interner::new();
+        let mut registry = TypeRegistry::new();
+        let settings = interner.intern(SETTINGS_TYPE);
+        let field = |interner: &mut Interner, name: &str, ty: FieldType| FieldDef { name: interner.intern(name), ty, is_public: true };
+        let int = FieldType::Primitive(interner.intern("Int"));
+        let maybe_text = FieldType::Generic { base: interner.intern("Option"), params: vec![FieldType::Primitive(interner.intern("Text"))] };
+        let fields = vec![field(&mut interner, "port", int.clone()), field(&mut interner, "host", maybe_text)];
+        registry.register(settings, TypeDef::Struct { fields, generics: vec![], is_portable: false, is_shared: false });
+
+        let schema = registry.settings_schema(settings, &interner).unwrap();
+        assert_eq!(schema.iter().map(|d| (d.kind, d.optional)).collect::<Vec<_>>(), [(SettingKind::Int, false), (SettingKind::Text, true)]);
+
+        let list = FieldType::Generic { base: interner.intern("Seq"), params: vec![int] };
+        let fields = vec![field(&mut interner, "ports", list)];
+        registry.register(settings, TypeDef::Struct { fields, generics: vec![], is_portable: false, is_shared: false });
+        assert!(registry.settings_schema(settings, &interner).unwrap_err().contains("`ports`"));
+    }
 }
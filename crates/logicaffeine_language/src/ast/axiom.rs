@@ -11,7 +11,7 @@
 /// `## Axiom flip: for all a b, Cong(a, b, b, a).` registers `flip` as a shared premise
 /// available to every later theorem in the program — the seam for an axiomatic base like
 /// Tarski geometry.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct AxiomBlock {
     /// The axiom's name (`flip`), for citation and diagnostics.
     pub name: String,
@@ -22,7 +22,7 @@ pub struct AxiomBlock {
 /// A `## Theory` block: a named development that groups the axioms and theorems that
 /// follow it (`## Theory Tarski`). Its body is the formal development text — a sequence of
 /// `Axiom …` and `Theorem …` declarations parsed downstream.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct TheoryBlock {
     /// The theory's name (`Tarski`).
     pub name: String,
@@ -45,7 +45,7 @@ pub enum LogicalType {
 ///
 /// Used with [`NumberKind`] to enable dimensional analysis and prevent
 /// nonsensical comparisons (e.g., adding meters to seconds).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum Dimension {
     /// Spatial extent (meters, feet, inches).
     Length,
@@ -117,7 +117,7 @@ pub enum Term<'a> {
 /// `Greater` is the strict comparative (`>`, "taller than"); `GreaterEqual` is the
 /// equative (`≥`, "as tall as" — at least as tall); `Equal` is the exact equative
 /// ("exactly as tall as").
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum ComparisonRelation {
     Greater,
     GreaterEqual,
@@ -128,7 +128,7 @@ pub enum ComparisonRelation {
 ///
 /// Extends standard FOL with generalized quantifiers that cannot be
 /// expressed with ∀ and ∃ alone (e.g., "most", "few", "at least 3").
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum QuantifierKind {
     /// Universal: ∀x ("every", "all", "each").
     Universal,
@@ -178,7 +178,7 @@ pub enum UnaryOpKind {
 ///
 /// Prior-style tense operators (Past, Future) for linguistic temporality.
 /// Pnueli-style LTL operators (Always, Eventually, Next) for hardware verification.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum TemporalOperator {
     /// Past tense: P(φ) — "it was the case that φ".
     Past,
@@ -199,7 +199,7 @@ pub enum TemporalOperator {
 ///
 /// These require two operands and express relationships between
 /// properties over time in hardware state machines.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum BinaryTemporalOp {
     /// φ U ψ — φ holds until ψ becomes true.
     Until,
@@ -218,7 +218,7 @@ pub enum BinaryTemporalOp {
 /// Following Parsons' neo-Davidsonian analysis, events are reified and
 /// participants are related to events via thematic role predicates:
 /// `∃e(Run(e) ∧ Agent(e, john) ∧ Location(e, park))`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum ThematicRole {
     /// Animate initiator of action (e.g., "John" in "John ran").
     Agent,
@@ -252,7 +252,7 @@ pub enum ThematicRole {
 ///
 /// Aspect describes the internal temporal structure of events,
 /// distinct from tense which locates events in time.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum AspectOperator {
     /// Ongoing action: "is running" → PROG(Run(e)).
     Progressive,
@@ -265,7 +265,7 @@ pub enum AspectOperator {
 }
 
 /// Grammatical voice operators.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum VoiceOperator {
     /// Passive voice: "was eaten" promotes patient to subject position.
     Passive,
@@ -378,7 +378,7 @@ impl<'a> NounPhrase<'a> {
 ///
 /// Determines the accessibility relation in Kripke semantics:
 /// what kinds of possible worlds are relevant.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum ModalDomain {
     /// Alethic modality: logical/metaphysical possibility and necessity.
     /// "It is possible that P" = P holds in some accessible world.
@@ -396,7 +396,7 @@ pub enum ModalDomain {
 /// The distinction between root and epistemic modals affects
 /// quantifier scope: root modals scope under quantifiers (de re),
 /// while epistemic modals scope over quantifiers (de dicto).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum ModalFlavor {
     /// Root modals express ability, obligation, or circumstantial possibility.
     /// Verbs: can, must, should, shall, could, would.
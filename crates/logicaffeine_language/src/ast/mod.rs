@@ -39,5 +39,5 @@ pub mod theorem;
 pub use axiom::{AxiomBlock, TheoryBlock};
 pub use definition::DefinitionBlock;
 pub use logic::*;
-pub use stmt::{Stmt, Expr, Literal, Block, BinaryOpKind, CompressionCodec, SendLayout, TypeExpr, MatchArm, SecurePad, SecureRole};
+pub use stmt::{Stmt, Expr, Literal, Block, BinaryOpKind, CompressionCodec, SendLayout, TypeExpr, MatchArm, SecurePad, SecureRole, SwarmDiscovery, LogLevel};
 pub use theorem::{TheoremBlock, ProofStrategy};
@@ -79,6 +79,17 @@ pub enum ReadSource<'a> {
     Console,
     /// Read from file at given path
     File(&'a Expr<'a>),
+    /// Load the `## Settings` struct `type_name` from an optional TOML file,
+    /// with environment variables overriding it: `Read config from settings "app.toml".`
+    Settings {
+        type_name: Symbol,
+        path: Option<&'a Expr<'a>>,
+    },
+    /// Parse the command line against the `## Arguments` declarations into
+    /// the struct `type_name`: `Read cli from arguments.`
+    Arguments {
+        type_name: Symbol,
+    },
 }
 
 /// Pattern for loop variable binding.
@@ -91,8 +102,19 @@ pub enum Pattern {
     Tuple(Vec<Symbol>),
 }
 
+/// The collection a comprehension builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum CollectionKind {
+    /// `every n in xs`
+    Seq,
+    /// `the set of every n in xs`, or a declared `Set of …`
+    Set,
+    /// `every k mapped to v for x in xs`
+    Map,
+}
+
 /// Binary operation kinds for imperative expressions.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum BinaryOpKind {
     Add,
     Subtract,
@@ -159,7 +181,7 @@ pub struct MatchArm<'a> {
 
 /// The wire compression codec a `Send compressed [with <codec>]` selects. The
 /// transpiler maps this to the runtime's wire codec; bare `compressed` = `Deflate`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum CompressionCodec {
     /// DEFLATE — the balanced default (bare `Send compressed`).
     Deflate,
@@ -172,7 +194,7 @@ pub enum CompressionCodec {
 /// The wire LAYOUT a `Send` modifier picks — the size↔speed dial the sender chooses for
 /// their link. The transpiler maps this to the runtime's numeric codec. The sender knows
 /// their use case; this lets them express it in one word.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum SendLayout {
     /// `compact` / `small` — smallest wire (LEB128 varint). For a bandwidth-bound link
     /// (mobile, WAN, metered). This is also the default when no layout word is given.
@@ -211,7 +233,7 @@ pub enum ZoneSource {
 /// and function bodies. The Assert variant bridges to the Logic Kernel.
 /// Which end of the shared pad this peer draws from, for the PNP one-time-pad tier.
 /// `as initiator` sends on the first directional half; `as responder` sends on the second.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum SecureRole {
     /// Draws the send pad from the first directional half (`i2r`).
     Initiator,
@@ -229,6 +251,62 @@ pub struct SecurePad<'a> {
     pub role: SecureRole,
 }
 
+/// One way of finding peers named in `Join the swarm using …`.
+#[derive(Debug, Clone, Copy)]
+pub enum SwarmDiscovery<'a> {
+    /// `local discovery` — mDNS on the local network.
+    Local,
+    /// `bootstrap peers [at <addrs>]` — dial these multiaddrs, or the project's list when absent.
+    Bootstrap(Option<&'a Expr<'a>>),
+    /// `relays [at <urls>]` — reach the swarm through these relays, or the project's when absent.
+    Relays(Option<&'a Expr<'a>>),
+}
+
+impl<'a> SwarmDiscovery<'a> {
+    /// The address list written after `at`, if any.
+    pub fn addresses(&self) -> Option<&'a Expr<'a>> {
+        match self {
+            SwarmDiscovery::Local => None,
+            SwarmDiscovery::Bootstrap(addrs) | SwarmDiscovery::Relays(addrs) => *addrs,
+        }
+    }
+}
+
+/// How severe a `Log` event is, from most to least verbose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// The level named by a word after `Log` (`warning` is `Warn`).
+    pub fn from_word(word: &str) -> Option<LogLevel> {
+        match word.to_ascii_lowercase().as_str() {
+            "trace" => Some(LogLevel::Trace),
+            "debug" => Some(LogLevel::Debug),
+            "info" => Some(LogLevel::Info),
+            "warn" | "warning" => Some(LogLevel::Warn),
+            "error" => Some(LogLevel::Error),
+            _ => None,
+        }
+    }
+
+    /// The canonical lowercase name.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LogLevel::Trace => "trace",
+            LogLevel::Debug => "debug",
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Stmt<'a> {
     /// Variable binding: `Let x be 5.` or `Let x: Int be 5.`
@@ -317,6 +395,38 @@ pub enum Stmt<'a> {
         recipient: &'a Expr<'a>,
     },
 
+    /// Structured log event: `Log info "user joined" with id and name.`
+    /// Semantics: Emit `message` at `level` with each field named after its
+    /// variable; filtered by the run's log level, never written to stdout
+    Log {
+        level: LogLevel,
+        message: &'a Expr<'a>,
+        fields: Vec<(Symbol, &'a Expr<'a>)>,
+    },
+
+    /// Subprocess: `Run the command "git status" and capture its output into status.`
+    /// Semantics: Run `command` as a program and its arguments (no shell), killed
+    /// after `timeout`. Uncaptured output is passed through; a non-zero exit is
+    /// an error unless `exit_code` captures it
+    RunCommand {
+        command: &'a Expr<'a>,
+        timeout: Option<&'a Expr<'a>>,
+        output: Option<Symbol>,
+        errors: Option<Symbol>,
+        exit_code: Option<Symbol>,
+    },
+
+    /// Chunked file transfer: `Sip the file "copy.bin" from mirrors, reporting progress to report.`
+    /// Semantics: Assemble `file` from `sources` (a path or a list of paths to
+    /// copies of it), checking every chunk against the first source. A chunk
+    /// another copy gets wrong is fetched elsewhere, and a partial `file`
+    /// resumes. `progress` is called with the whole percent done after each chunk
+    Sip {
+        file: &'a Expr<'a>,
+        sources: &'a Expr<'a>,
+        progress: Option<Symbol>,
+    },
+
     /// Field mutation: `Set p's x to 10.`
     SetField {
         object: &'a Expr<'a>,
@@ -435,8 +545,9 @@ pub enum Stmt<'a> {
         tasks: Block<'a>,
     },
 
-    /// Read from console or file.
-    /// `Read input from the console.` or `Read data from file "path.txt".`
+    /// Read from console, file, settings or the command line.
+    /// `Read input from the console.`, `Read data from file "path.txt".`,
+    /// `Read config from settings "app.toml".` or `Read cli from arguments.`
     ReadFrom {
         var: Symbol,
         source: ReadSource<'a>,
@@ -598,6 +709,14 @@ pub enum Stmt<'a> {
         secure: Option<SecurePad<'a>>,
     },
 
+    /// Join the peer-to-peer swarm.
+    /// `Join the swarm using local discovery and bootstrap peers.`
+    /// Semantics: Find peers the named ways; a bare `Join the swarm.` uses
+    /// everything the project configures (mDNS, bootstrap peers, relays)
+    JoinSwarm {
+        using: Vec<SwarmDiscovery<'a>>,
+    },
+
     /// Create PeerAgent remote handle.
     /// `Let remote be a PeerAgent at "/ip4/127.0.0.1/tcp/8000".`
     /// Semantics: Create handle for remote agent communication
@@ -955,6 +1074,20 @@ pub enum Expr<'a> {
 
     /// Interpolated string: `"Hello, {name}! Value: {x:.2}"`
     InterpolatedString(Vec<StringPart<'a>>),
+
+    /// Comprehension: `every n in xs where n is even`, `every n * n for n in xs`,
+    /// `every w mapped to length of w for w in words`.
+    /// A fresh collection of the `iterable`'s elements that pass `filter`, each bound to
+    /// `pattern` in `element`, `value` and `filter`. `value` is present exactly when `kind`
+    /// is `Map`, and `element` is then the key.
+    Comprehension {
+        kind: CollectionKind,
+        element: &'a Expr<'a>,
+        value: Option<&'a Expr<'a>>,
+        pattern: Pattern,
+        iterable: &'a Expr<'a>,
+        filter: Option<&'a Expr<'a>>,
+    },
 }
 
 /// A segment of an interpolated string.
@@ -39,7 +39,7 @@ pub struct TheoremBlock<'a> {
 }
 
 /// Proof strategies for theorem verification.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum ProofStrategy {
     /// Automatic proof search using backward chaining.
     /// The prover will try all available inference rules.
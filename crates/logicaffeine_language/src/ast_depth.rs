@@ -198,6 +198,22 @@ fn push_stmt_children<'w, 'a>(stmt: &'w Stmt<'a>, d: usize, work: &mut Vec<(Node
             e!(object);
             e!(recipient);
         }
+        Stmt::RunCommand { command, timeout, output: _, errors: _, exit_code: _ } => {
+            e!(command);
+            if let Some(timeout) = timeout {
+                e!(timeout);
+            }
+        }
+        Stmt::Sip { file, sources, progress: _ } => {
+            e!(file);
+            e!(sources);
+        }
+        Stmt::Log { message, fields, level: _ } => {
+            e!(message);
+            for (_, value) in fields {
+                e!(value);
+            }
+        }
         Stmt::SetField { object, value, field: _ } => {
             e!(object);
             e!(value);
@@ -243,6 +259,8 @@ fn push_stmt_children<'w, 'a>(stmt: &'w Stmt<'a>, d: usize, work: &mut Vec<(Node
         Stmt::ReadFrom { source, var: _ } => match source {
             ReadSource::Console => {}
             ReadSource::File(path) => e!(path),
+            ReadSource::Settings { path: Some(path), .. } => e!(path),
+            ReadSource::Settings { path: None, .. } | ReadSource::Arguments { .. } => {}
         },
         Stmt::WriteFile { content, path } => {
             e!(content);
@@ -291,6 +309,11 @@ fn push_stmt_children<'w, 'a>(stmt: &'w Stmt<'a>, d: usize, work: &mut Vec<(Node
                 e!(pad.pad);
             }
         }
+        Stmt::JoinSwarm { using } => {
+            for addrs in using.iter().filter_map(|d| d.addresses()) {
+                e!(addrs);
+            }
+        }
         Stmt::LetPeerAgent { address, var: _ } => e!(address),
         Stmt::Sleep { milliseconds } => e!(milliseconds),
         Stmt::Sync { topic, var: _ } => e!(topic),
@@ -429,6 +452,12 @@ fn push_expr_children<'w, 'a>(expr: &'w Expr<'a>, d: usize, work: &mut Vec<(Node
                 }
             }
         }
+        Expr::Comprehension { element, value, iterable, filter, kind: _, pattern: _ } => {
+            e!(element);
+            if let Some(v) = value { e!(v); }
+            e!(iterable);
+            if let Some(f) = filter { e!(f); }
+        }
     }
 }
 
@@ -8,13 +8,31 @@
 //! | Function | Use Case |
 //! |----------|----------|
 //! | [`compile`] | Single sentence, Unicode output |
+//! | [`compile_cancellable`] | The same, abandoned when a [`CancellationToken`] fires |
 //! | [`compile_simple`] | Single sentence, ASCII output |
 //! | [`compile_kripke`] | Modal logic with world quantification |
 //! | [`compile_with_discourse`] | Multi-sentence with anaphora resolution |
-//! | [`compile_forest`] | Ambiguous sentences, all readings |
+//! | [`compile_forest`] | Ambiguous sentences, all readings, most likely first |
+//! | [`compile_forest_ranked`] | The same readings with their ranking features and scores |
 //! | [`compile_all_scopes`] | All quantifier scope permutations |
-//! | [`compile_discourse`] | Multi-sentence with temporal ordering |
+//! | [`compile_all_scopes_cancellable`] | The same, abandoned when a [`CancellationToken`] fires |
+//! | [`compile_discourse`] | Multi-sentence with a tense-aware timeline |
 //! | [`compile_theorem`] | Theorem proving with backward chaining |
+//! | [`export_theorems`] | Theorem blocks as a Lean 4 or Coq source file |
+//! | [`check_exported_proofs`] | Kernel-check proofs finished in that file |
+//! | [`compile_with_trace`] | Single sentence plus its derivation trace |
+//! | [`compile_with_metrics`] | Single sentence plus per-phase timings and counts |
+//! | [`compile_with_gazetteer`] | Single sentence with user-listed proper names |
+//! | [`compile_with_modes`] | Single sentence with the parser modes set by hand |
+//!
+//! ## Custom Passes
+//!
+//! Third parties extend the pipeline without forking it by implementing
+//! [`Pass`] and listing it in a [`PassRegistry`] that
+//! [`CompileOptions::passes`] points at. Registered passes run in order
+//! after the built-in semantics (axioms, Kripke lowering, pragmatics) and
+//! before transpilation; a pass can rewrite the reading, reject it, or render
+//! it in an output format of its own.
 //!
 //! ## Example
 //!
@@ -32,10 +50,14 @@
 
 use crate::{
     analysis, Arena, CompileOptions, drs, Interner, lambda, lexicon, Lexer, mwe,
-    OutputFormat, Parser, pragmatics, semantics, SymbolRegistry, ParseError, token,
-    arena_ctx::AstContext,
+    OutputFormat, Parser, pragmatics, semantics, SymbolRegistry, ParseError, tense, token,
+    arena_ctx::AstContext, trace::DerivationTrace, gazetteer::{self, Gazetteer},
+    metrics::{CompileMetrics, Phase, Stopwatch},
+    ranking::{self, RankingWeights, ReadingFeatures},
     parser::{NegativeScopeMode, ModalPreference, QuantifierParsing},
 };
+use crate::ast::logic::LogicExpr;
+use logicaffeine_base::{CancellationToken, Cancelled};
 
 /// Maximum number of readings in a parse forest.
 /// Prevents exponential blowup from ambiguous sentences.
@@ -52,18 +74,19 @@ pub fn compile_pragmatic(input: &str) -> Result<String, ParseError> {
     compile_with_options(input, CompileOptions {
         format: OutputFormat::Unicode,
         pragmatic: true,
+        ..Default::default()
     })
 }
 
 /// Compile with simple FOL format.
 pub fn compile_simple(input: &str) -> Result<String, ParseError> {
-    compile_with_options(input, CompileOptions { format: OutputFormat::SimpleFOL, pragmatic: false })
+    compile_with_options(input, CompileOptions { format: OutputFormat::SimpleFOL, pragmatic: false, ..Default::default() })
 }
 
 /// Compile with Kripke semantics lowering.
 /// Modal operators are transformed into explicit possible world quantification.
 pub fn compile_kripke(input: &str) -> Result<String, ParseError> {
-    compile_with_options(input, CompileOptions { format: OutputFormat::Kripke, pragmatic: false })
+    compile_with_options(input, CompileOptions { format: OutputFormat::Kripke, pragmatic: false, ..Default::default() })
 }
 
 /// Compile to Kripke-lowered FOL and pass the AST to a callback.
@@ -84,8 +107,8 @@ where
     let mut lexer = Lexer::new(input, &mut interner);
     let tokens = lexer.tokenize();
 
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
 
     let type_registry = {
         let mut discovery = analysis::DiscoveryPass::new(&tokens, &mut interner);
@@ -119,26 +142,144 @@ where
 
 /// Compile natural language input to first-order logic with specified options.
 pub fn compile_with_options(input: &str, options: CompileOptions) -> Result<String, ParseError> {
+    compile_recording(input, options, &gazetteer::EMPTY, None, &CancellationToken::new(), &mut CompileMetrics::default())
+}
+
+/// [`compile_with_options`] with a per-phase breakdown of where the time went
+/// (see [`crate::metrics`]). The metrics are returned on failure too, covering
+/// the phases that ran.
+pub fn compile_with_metrics(input: &str, options: CompileOptions) -> (Result<String, ParseError>, CompileMetrics) {
+    let mut metrics = CompileMetrics::default();
+    let result = compile_recording(input, options, &gazetteer::EMPTY, None, &CancellationToken::new(), &mut metrics);
+    (result, metrics)
+}
+
+/// [`compile_with_options`] that stops when `cancel` fires, with a
+/// [`ParseErrorKind::Cancelled`](crate::error::ParseErrorKind::Cancelled)
+/// error. Every construal of an ambiguous input polls the token while it
+/// parses.
+pub fn compile_cancellable(
+    input: &str,
+    options: CompileOptions,
+    cancel: &CancellationToken,
+) -> Result<String, ParseError> {
+    compile_recording(input, options, &gazetteer::EMPTY, None, cancel, &mut CompileMetrics::default())
+}
+
+/// Compile with a list of known proper names: multi-word names in the
+/// gazetteer lex as single names, and their sorts feed the sort checks.
+pub fn compile_with_gazetteer(input: &str, gazetteer: &Gazetteer) -> Result<String, ParseError> {
+    compile_recording(
+        input,
+        CompileOptions::default(),
+        gazetteer,
+        None,
+        &CancellationToken::new(),
+        &mut CompileMetrics::default(),
+    )
+}
+
+/// Compile with a derivation trace: the FOL (or the error) together with the
+/// tree of grammar rules that fired, for step-by-step "why did it parse this
+/// way?" explanations. The trace is returned on failure too — the dead ends
+/// are what explain the error.
+pub fn compile_with_trace(input: &str) -> (Result<String, ParseError>, DerivationTrace) {
+    let mut trace = DerivationTrace::default();
+    let result = compile_recording(
+        input,
+        CompileOptions::default(),
+        &gazetteer::EMPTY,
+        Some(&mut trace),
+        &CancellationToken::new(),
+        &mut CompileMetrics::default(),
+    );
+    (result, trace)
+}
+
+fn compile_recording(
+    input: &str,
+    options: CompileOptions,
+    gazetteer: &Gazetteer,
+    trace: Option<&mut DerivationTrace>,
+    cancel: &CancellationToken,
+    metrics: &mut CompileMetrics,
+) -> Result<String, ParseError> {
     if input.trim().is_empty() {
         return Err(ParseError {
             kind: crate::error::ParseErrorKind::Custom("Empty input".to_string()),
             span: crate::token::Span { start: 0, end: 0 },
         });
     }
+    let cancelled = || ParseError {
+        kind: crate::error::ParseErrorKind::Cancelled,
+        span: crate::token::Span { start: 0, end: 0 },
+    };
     let mut interner = Interner::new();
-    let mut lexer = Lexer::new(input, &mut interner);
-    let tokens = lexer.tokenize();
-
-    // Apply MWE collapsing
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let (tokens, type_registry) = lex_for_parse(input, gazetteer, &mut interner, metrics);
+    if cancel.is_cancelled() {
+        return Err(cancelled());
+    }
 
-    // Pass 1: Discovery - scan for type definitions
-    let type_registry = {
-        let mut discovery = analysis::DiscoveryPass::new(&tokens, &mut interner);
-        discovery.run()
+    // An ambiguous input commits to its top-ranked reading. Ranking only
+    // chooses between readings; an input whose default construal fails keeps
+    // that failure as its diagnosis, so the alternatives are compiled only
+    // once the default has compiled.
+    let candidates = construals(&tokens, &interner);
+    let tracing = trace.is_some();
+    let compile = |construal: &Construal, interner: &mut Interner, metrics: &mut CompileMetrics| {
+        compile_construal(construal, &type_registry, interner, options, gazetteer, tracing, cancel, metrics)
     };
+    let (default, default_trace) = compile(&candidates[0], &mut interner, metrics);
+    let default = match default {
+        Ok(default) => default,
+        Err(e) => {
+            if let Some(trace) = trace {
+                *trace = default_trace;
+            }
+            return Err(if cancel.is_cancelled() { cancelled() } else { e });
+        }
+    };
+    let mut readings = vec![(default, default_trace)];
+    for construal in &candidates[1..] {
+        if cancel.is_cancelled() {
+            return Err(cancelled());
+        }
+        if let (Ok(reading), reading_trace) = compile(construal, &mut interner, metrics) {
+            if !readings.iter().any(|((output, _), _)| *output == reading.0) {
+                readings.push((reading, reading_trace));
+            }
+        }
+    }
+    // A parse cut short by the token reads as ungrammatical; don't rank the rest.
+    if cancel.is_cancelled() {
+        return Err(cancelled());
+    }
 
+    let features: Vec<ReadingFeatures> = readings.iter().map(|((_, features), _)| *features).collect();
+    let best = ranking::rank(&features, RankingWeights::active()).first().copied().unwrap_or(0);
+    let ((output, _), best_trace) = readings.swap_remove(best);
+    if let Some(trace) = trace {
+        *trace = best_trace;
+    }
+    Ok(output)
+}
+
+/// Take one construal through the whole `compile` pipeline — parse,
+/// semantics, pragmatics, transpilation and temporal constraints — to its
+/// output and ranking features. The derivation trace is recorded when
+/// `tracing`, and returned on failure too.
+#[allow(clippy::too_many_arguments)]
+fn compile_construal(
+    construal: &Construal,
+    type_registry: &analysis::TypeRegistry,
+    interner: &mut Interner,
+    options: CompileOptions,
+    gazetteer: &Gazetteer,
+    tracing: bool,
+    cancel: &CancellationToken,
+    metrics: &mut CompileMetrics,
+) -> (Result<(String, ReadingFeatures), ParseError>, DerivationTrace) {
+    let parsing = Stopwatch::start();
     let expr_arena = Arena::new();
     let term_arena = Arena::new();
     let np_arena = Arena::new();
@@ -157,39 +298,62 @@ pub fn compile_with_options(input: &str, options: CompileOptions) -> Result<Stri
 
     // Pass 2: Parse with type context
     let mut world_state = drs::WorldState::new();
-    let mut parser = Parser::new(tokens, &mut world_state, &mut interner, ctx, type_registry);
-    let ast = if options.pragmatic {
-        parser.parse_pragmatic()?
+    let mut parser = Parser::new(construal.tokens.clone(), &mut world_state, interner, ctx, type_registry.clone());
+    parser.set_negative_scope_mode(options.negative_scope);
+    parser.set_cancellation(cancel);
+    construal.mode.configure(&mut parser);
+    parser.register_gazetteer(gazetteer);
+    if tracing {
+        parser.enable_trace();
+    }
+    let parsed = if options.pragmatic {
+        parser.parse_pragmatic()
     } else {
-        parser.parse()?
+        parser.parse()
     };
-    let ast = semantics::apply_axioms(ast, ctx.exprs, ctx.terms, &mut interner);
+    let trace = parser.take_trace();
+    let parsed = parsed.and_then(|ast| construal.mode.finish(&mut parser, ast));
+    parsing.record(metrics, Phase::Parse, usize::from(parsed.is_ok()));
+    let ast = match parsed {
+        Ok(ast) => ast,
+        Err(e) => return (Err(e), trace),
+    };
+
+    let semantic = Stopwatch::start();
+    let ast = semantics::apply_axioms(ast, ctx.exprs, ctx.terms, interner);
 
     // Apply Kripke lowering for Kripke format (before pragmatics to preserve modal structure)
     let ast = if options.format == OutputFormat::Kripke {
-        semantics::apply_kripke_lowering(ast, ctx.exprs, ctx.terms, &mut interner)
+        semantics::apply_kripke_lowering(ast, ctx.exprs, ctx.terms, interner)
     } else {
         ast
     };
+    let features = construal.features().with_form(ast, interner);
 
-    let ast = pragmatics::apply_pragmatics(ast, ctx.exprs, &interner);
+    let ast = pragmatics::apply_pragmatics(ast, ctx.exprs, interner);
+    let passes = if options.format == OutputFormat::Kripke { 3 } else { 2 };
+    semantic.record(metrics, Phase::Semantics, passes);
+
+    let transpiling = Stopwatch::start();
     let mut registry = SymbolRegistry::new();
     // Use transpile_discourse to format multiple sentences as numbered formulas
-    let main_output = ast.transpile_discourse(&mut registry, &interner, options.format);
+    let main_output = match finish_reading(ast, ctx, interner, options, |ast, interner| {
+        ast.transpile_discourse(&mut registry, interner, options.format)
+    }) {
+        Ok(output) => output,
+        Err(e) => return (Err(e), trace),
+    };
 
     // Append Reichenbach temporal constraints
     let constraints = world_state.time_constraints();
-    if constraints.is_empty() {
-        Ok(main_output)
+    let output = if constraints.is_empty() {
+        main_output
     } else {
-        let constraint_strs: Vec<String> = constraints.iter().map(|c| {
-            match c.relation {
-                drs::TimeRelation::Precedes => format!("Precedes({}, {})", c.left, c.right),
-                drs::TimeRelation::Equals => format!("{}={}", c.left, c.right),
-            }
-        }).collect();
-        Ok(format!("{} ∧ {}", main_output, constraint_strs.join(" ∧ ")))
-    }
+        let constraint_strs: Vec<String> = constraints.iter().map(tense::render).collect();
+        format!("{} ∧ {}", main_output, constraint_strs.join(" ∧ "))
+    };
+    transpiling.record(metrics, Phase::Transpile, output.len());
+    (Ok((output, features)), trace)
 }
 
 /// Compile with shared WorldState for cross-sentence discourse.
@@ -228,8 +392,8 @@ pub fn compile_with_world_state_interner_options(
     let tokens = lexer.tokenize();
 
     // Apply MWE collapsing
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, interner);
 
     // Pass 1: Discovery
     let type_registry = {
@@ -254,6 +418,7 @@ pub fn compile_with_world_state_interner_options(
     );
 
     let mut parser = Parser::new(tokens, world_state, interner, ctx, type_registry);
+    parser.set_negative_scope_mode(options.negative_scope);
     // Swap DRS from WorldState into Parser at start
     parser.swap_drs_with_world_state();
     let ast = parser.parse()?;
@@ -272,22 +437,142 @@ pub fn compile_with_world_state_interner_options(
 
     let ast = pragmatics::apply_pragmatics(ast, ctx.exprs, interner);
     let mut registry = SymbolRegistry::new();
-    let main_output = ast.transpile_discourse(&mut registry, interner, options.format);
+    let main_output = finish_reading(ast, ctx, interner, options, |ast, interner| {
+        ast.transpile_discourse(&mut registry, interner, options.format)
+    })?;
 
     let constraints = world_state.time_constraints();
     if constraints.is_empty() {
         Ok(main_output)
     } else {
-        let constraint_strs: Vec<String> = constraints.iter().map(|c| {
-            match c.relation {
-                drs::TimeRelation::Precedes => format!("Precedes({}, {})", c.left, c.right),
-                drs::TimeRelation::Equals => format!("{}={}", c.left, c.right),
-            }
-        }).collect();
+        let constraint_strs: Vec<String> = constraints.iter().map(tense::render).collect();
         Ok(format!("{} ∧ {}", main_output, constraint_strs.join(" ∧ ")))
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════
+// Custom Passes
+// ═══════════════════════════════════════════════════════════════════
+
+/// A third-party stage between the built-in semantics and transpilation —
+/// a domain axiom injector, a house-style normaliser, a custom output format.
+///
+/// Both hooks default to doing nothing, so a pass implements only the ones
+/// it needs.
+pub trait Pass: Send + Sync {
+    /// The pass's name, for diagnostics.
+    fn name(&self) -> &str;
+
+    /// Rewrite a reading, allocating new nodes in `cx.ast`. An error rejects
+    /// the reading: [`compile_with_options`] fails with it, and the forest
+    /// drops that reading.
+    fn rewrite<'a>(
+        &self,
+        expr: &'a LogicExpr<'a>,
+        cx: &mut PassContext<'a, '_>,
+    ) -> Result<&'a LogicExpr<'a>, ParseError> {
+        let _ = cx;
+        Ok(expr)
+    }
+
+    /// Render the final reading in place of the built-in transpiler. The
+    /// first registered pass that returns `Some` wins.
+    fn render(&self, expr: &LogicExpr<'_>, interner: &Interner, format: OutputFormat) -> Option<String> {
+        let _ = (expr, interner, format);
+        None
+    }
+}
+
+/// What a [`Pass`] gets to work with besides the reading itself.
+pub struct PassContext<'a, 'i> {
+    /// The arenas the reading lives in; rewrites allocate here.
+    pub ast: AstContext<'a>,
+    pub interner: &'i mut Interner,
+    /// The output format the compile was asked for.
+    pub format: OutputFormat,
+}
+
+/// The passes a compile runs, in registration order.
+///
+/// [`CompileOptions`] stays `Copy` by pointing at a registry with a
+/// `'static` lifetime — build yours once into a `static` `OnceLock`, or
+/// `Box::leak` it.
+#[derive(Default)]
+pub struct PassRegistry {
+    passes: Vec<Box<dyn Pass>>,
+}
+
+impl PassRegistry {
+    pub const fn new() -> Self {
+        PassRegistry { passes: Vec::new() }
+    }
+
+    /// Append `pass` to the pipeline.
+    pub fn register(&mut self, pass: impl Pass + 'static) -> &mut Self {
+        self.passes.push(Box::new(pass));
+        self
+    }
+
+    /// [`register`](Self::register), builder-style.
+    pub fn with(mut self, pass: impl Pass + 'static) -> Self {
+        self.register(pass);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.passes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.passes.is_empty()
+    }
+
+    /// The registered passes' names, in the order they run.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.passes.iter().map(|pass| pass.name())
+    }
+
+    /// Run every pass's rewrite over `expr`, in order.
+    pub fn rewrite<'a>(
+        &self,
+        expr: &'a LogicExpr<'a>,
+        cx: &mut PassContext<'a, '_>,
+    ) -> Result<&'a LogicExpr<'a>, ParseError> {
+        self.passes.iter().try_fold(expr, |expr, pass| pass.rewrite(expr, cx))
+    }
+
+    /// The first custom rendering of `expr`, if any pass provides one.
+    pub fn render(&self, expr: &LogicExpr<'_>, interner: &Interner, format: OutputFormat) -> Option<String> {
+        self.passes.iter().find_map(|pass| pass.render(expr, interner, format))
+    }
+}
+
+impl std::fmt::Debug for PassRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+/// The registry [`CompileOptions::default`] points at.
+pub(crate) static NO_PASSES: PassRegistry = PassRegistry::new();
+
+/// Run `options`' passes over a reading and render it: with the first pass
+/// that renders, or else with `transpile`.
+fn finish_reading<'a>(
+    expr: &'a LogicExpr<'a>,
+    ast: AstContext<'a>,
+    interner: &mut Interner,
+    options: CompileOptions,
+    transpile: impl FnOnce(&'a LogicExpr<'a>, &Interner) -> String,
+) -> Result<String, ParseError> {
+    let passes = options.passes;
+    if passes.is_empty() {
+        return Ok(transpile(expr, interner));
+    }
+    let expr = passes.rewrite(expr, &mut PassContext { ast, interner, format: options.format })?;
+    Ok(passes.render(expr, interner, options.format).unwrap_or_else(|| transpile(expr, interner)))
+}
+
 /// Returns all possible scope readings for a sentence.
 /// For sentences with multiple quantifiers, this returns all permutations.
 /// Example: "Every woman loves a man" returns both:
@@ -299,13 +584,32 @@ pub fn compile_all_scopes(input: &str) -> Result<Vec<String>, ParseError> {
 
 /// Returns all scope readings with specified output format.
 pub fn compile_all_scopes_with_options(input: &str, options: CompileOptions) -> Result<Vec<String>, ParseError> {
+    compile_all_scopes_cancellable(input, options, &CancellationToken::new())
+}
+
+/// [`compile_all_scopes_with_options`] that stops when `cancel` fires, with a
+/// [`ParseErrorKind::Cancelled`] error. Lexing, parsing and scope enumeration
+/// all poll the token, so a sentence with factorially many scopings can be
+/// abandoned part-way.
+pub fn compile_all_scopes_cancellable(
+    input: &str,
+    options: CompileOptions,
+    cancel: &CancellationToken,
+) -> Result<Vec<String>, ParseError> {
+    let cancelled = || ParseError {
+        kind: crate::error::ParseErrorKind::Cancelled,
+        span: crate::token::Span { start: 0, end: 0 },
+    };
     let mut interner = Interner::new();
-    let mut lexer = Lexer::new(input, &mut interner);
+    let mut lexer = Lexer::new(input, &mut interner).with_cancellation(cancel);
     let tokens = lexer.tokenize();
+    if cancel.is_cancelled() {
+        return Err(cancelled());
+    }
 
     // Apply MWE collapsing
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
 
     // Pass 1: Discovery - scan for type definitions
     let type_registry = {
@@ -332,11 +636,24 @@ pub fn compile_all_scopes_with_options(input: &str, options: CompileOptions) ->
     // Pass 2: Parse with type context
     let mut world_state = drs::WorldState::new();
     let mut parser = Parser::new(tokens, &mut world_state, &mut interner, ctx, type_registry);
-    let ast = parser.parse()?;
+    parser.set_cancellation(cancel);
+    let ast = parser.parse().map_err(|e| if cancel.is_cancelled() { cancelled() } else { e })?;
+
+    scope_readings(ast, &mut interner, options.format, cancel).map_err(|Cancelled| cancelled())
+}
 
+/// Every scoping of `ast` — quantifier orders, de re/de dicto, and a
+/// cumulative reading where there is one — rendered in `format`.
+fn scope_readings<'a>(
+    ast: &'a LogicExpr<'a>,
+    interner: &mut Interner,
+    format: OutputFormat,
+    cancel: &CancellationToken,
+) -> Result<Vec<String>, Cancelled> {
     let scope_arena = Arena::new();
     let scope_term_arena = Arena::new();
-    let scopings = lambda::enumerate_scopings(ast, &mut interner, &scope_arena, &scope_term_arena);
+    let scopings = lambda::enumerate_scopings(ast, interner, &scope_arena, &scope_term_arena)
+        .with_cancellation(cancel);
 
     let intensional_arena = Arena::new();
     let intensional_term_arena = Arena::new();
@@ -346,25 +663,28 @@ pub fn compile_all_scopes_with_options(input: &str, options: CompileOptions) ->
     for scoped_expr in scopings {
         let intensional_readings = lambda::enumerate_intensional_readings(
             scoped_expr,
-            &mut interner,
+            interner,
             &intensional_arena,
             &intensional_term_arena,
             &intensional_role_arena,
         );
         for reading in intensional_readings {
-            let reading = semantics::apply_axioms(reading, &intensional_arena, &intensional_term_arena, &mut interner);
+            let reading = semantics::apply_axioms(reading, &intensional_arena, &intensional_term_arena, interner);
             let mut registry = SymbolRegistry::new();
-            results.push(reading.transpile(&mut registry, &interner, options.format));
+            results.push(reading.transpile(&mut registry, interner, format));
         }
     }
+    if cancel.is_cancelled() {
+        return Err(Cancelled);
+    }
 
     // Cumulative reading (Scha) — irreducible to either nesting — for two-cardinal
     // transitive sentences ("Three boys lifted five boxes.").
-    if let Some(cumulative) = lambda::cumulative_reading(ast, &mut interner, &scope_arena) {
+    if let Some(cumulative) = lambda::cumulative_reading(ast, interner, &scope_arena) {
         let cumulative =
-            semantics::apply_axioms(cumulative, &scope_arena, &scope_term_arena, &mut interner);
+            semantics::apply_axioms(cumulative, &scope_arena, &scope_term_arena, interner);
         let mut registry = SymbolRegistry::new();
-        let rendered = cumulative.transpile(&mut registry, &interner, options.format);
+        let rendered = cumulative.transpile(&mut registry, interner, format);
         if !results.contains(&rendered) {
             results.push(rendered);
         }
@@ -373,32 +693,346 @@ pub fn compile_all_scopes_with_options(input: &str, options: CompileOptions) ->
     Ok(results)
 }
 
+// ═══════════════════════════════════════════════════════════════════
+// Explicit Parser Modes
+// ═══════════════════════════════════════════════════════════════════
+
+/// The parser's interpretive switches, set by hand instead of chosen by
+/// ranking. `compile` picks a construal for an ambiguous input itself;
+/// [`compile_with_modes`] parses in exactly these modes, so the effect of
+/// each switch on the analysis can be seen in isolation. The default is the
+/// parser's own default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParserModes {
+    /// Read Ambiguous noun/verb tokens as nouns ("The old man the boats.").
+    pub noun_priority: bool,
+    /// Attach a prepositional phrase to the nearest noun, not the verb.
+    pub pp_attach_to_noun: bool,
+    /// Read a plural subject of a mixed verb as a group.
+    pub collective: bool,
+    /// Read an adjective over an agentive noun as modifying the event
+    /// ("beautiful dancer" → dances beautifully).
+    pub event_reading: bool,
+    /// Where negation scopes against quantifiers, modals and neg-raising verbs.
+    pub negative_scope: NegativeScopeMode,
+    /// How may/can/could are read.
+    pub modal_preference: ModalPreference,
+    /// Enumerate every quantifier scoping too, not just the surface order.
+    pub all_scopings: bool,
+}
+
+/// A reading produced under explicit [`ParserModes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModesReading {
+    /// The FOL the parser produces in those modes.
+    pub reading: String,
+    /// Every quantifier scoping of that parse, when
+    /// [`ParserModes::all_scopings`] is on; empty otherwise.
+    pub scopings: Vec<String>,
+}
+
+/// Compile `input` with the parser in exactly `modes`, rendering in `format`.
+pub fn compile_with_modes(input: &str, modes: ParserModes, format: OutputFormat) -> Result<ModesReading, ParseError> {
+    if input.trim().is_empty() {
+        return Err(ParseError {
+            kind: crate::error::ParseErrorKind::Custom("Empty input".to_string()),
+            span: crate::token::Span { start: 0, end: 0 },
+        });
+    }
+    let options = CompileOptions { format, ..CompileOptions::default() };
+    let mut interner = Interner::new();
+    let (tokens, type_registry) = lex_for_parse(input, &gazetteer::EMPTY, &mut interner, &mut CompileMetrics::default());
+
+    let expr_arena = Arena::new();
+    let term_arena = Arena::new();
+    let np_arena = Arena::new();
+    let sym_arena = Arena::new();
+    let role_arena = Arena::new();
+    let pp_arena = Arena::new();
+
+    let ctx = AstContext::new(
+        &expr_arena,
+        &term_arena,
+        &np_arena,
+        &sym_arena,
+        &role_arena,
+        &pp_arena,
+    );
+
+    let mut world_state = drs::WorldState::new();
+    let mut parser = Parser::new(tokens, &mut world_state, &mut interner, ctx, type_registry);
+    parser.set_noun_priority_mode(modes.noun_priority);
+    parser.set_pp_attachment_mode(modes.pp_attach_to_noun);
+    parser.set_collective_mode(modes.collective);
+    parser.set_event_reading_mode(modes.event_reading);
+    parser.set_negative_scope_mode(modes.negative_scope);
+    parser.set_modal_preference(modes.modal_preference);
+    let mut ast = parser.parse()?;
+    if modes.collective {
+        ast = parser.transform_cardinal_to_group(ast)?;
+    }
+
+    let scopings = if modes.all_scopings {
+        scope_readings(ast, &mut interner, format, &CancellationToken::new()).unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+
+    let ast = semantics::apply_axioms(ast, ctx.exprs, ctx.terms, &mut interner);
+    let ast = if format == OutputFormat::Kripke {
+        semantics::apply_kripke_lowering(ast, ctx.exprs, ctx.terms, &mut interner)
+    } else {
+        ast
+    };
+    let ast = pragmatics::apply_pragmatics(ast, ctx.exprs, &interner);
+    let mut registry = SymbolRegistry::new();
+    let main_output = finish_reading(ast, ctx, &mut interner, options, |ast, interner| {
+        ast.transpile_discourse(&mut registry, interner, format)
+    })?;
+
+    let constraints = world_state.time_constraints();
+    let reading = if constraints.is_empty() {
+        main_output
+    } else {
+        let constraint_strs: Vec<String> = constraints.iter().map(tense::render).collect();
+        format!("{} ∧ {}", main_output, constraint_strs.join(" ∧ "))
+    };
+    Ok(ModesReading { reading, scopings })
+}
+
 // ═══════════════════════════════════════════════════════════════════
 // Parse Forest Compilation (Ambiguity Resolution)
 // ═══════════════════════════════════════════════════════════════════
 
 /// Compile natural language input, producing all valid parse readings.
 /// Handles lexical ambiguity (Noun/Verb) and structural ambiguity (PP attachment).
+/// Readings come most likely first (see [`crate::ranking`]).
 pub fn compile_forest(input: &str) -> Vec<String> {
     compile_forest_with_options(input, CompileOptions::default())
 }
 
 /// Compile natural language input with options, producing all valid parse readings.
 pub fn compile_forest_with_options(input: &str, options: CompileOptions) -> Vec<String> {
+    compile_forest_ranked_with_options(input, options)
+        .into_iter()
+        .map(|reading| reading.fol)
+        .collect()
+}
+
+/// One forest reading with the evidence it was ranked on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedReading {
+    pub fol: String,
+    pub features: ReadingFeatures,
+    /// Score under the active [`RankingWeights`]; higher is likelier.
+    pub score: f64,
+    /// For input containing an idiom, which sense this reading takes.
+    pub idiom: Option<IdiomReading>,
+}
+
+/// The two senses of an idiom ("kick the bucket"): the lexicalised meaning
+/// (Die) or the word-for-word one (a kicking event with a bucket as theme).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdiomReading {
+    Idiomatic,
+    Literal,
+}
+
+/// [`compile_forest`] with each reading's features and score, most likely first.
+pub fn compile_forest_ranked(input: &str) -> Vec<RankedReading> {
+    compile_forest_ranked_with_options(input, CompileOptions::default())
+}
+
+/// [`compile_forest_with_options`] with each reading's features and score,
+/// most likely first.
+pub fn compile_forest_ranked_with_options(input: &str, options: CompileOptions) -> Vec<RankedReading> {
+    compile_forest_ranked_cancellable(input, options, &CancellationToken::new())
+        .expect("a fresh token is never cancelled")
+}
+
+/// [`compile_forest_ranked_with_options`] that gives up with [`Cancelled`]
+/// when `cancel` fires — between construals, or inside one's parse.
+pub fn compile_forest_ranked_cancellable(
+    input: &str,
+    options: CompileOptions,
+    cancel: &CancellationToken,
+) -> Result<Vec<RankedReading>, Cancelled> {
     let mut interner = Interner::new();
-    let mut lexer = Lexer::new(input, &mut interner);
+    let (tokens, type_registry) = lex_for_parse(input, &gazetteer::EMPTY, &mut interner, &mut CompileMetrics::default());
+
+    let mut candidates = construals(&tokens, &interner);
+    if let Some(literal) = literal_idiom_tokens(input, &mut interner) {
+        for construal in &mut candidates {
+            construal.idiom = Some(IdiomReading::Idiomatic);
+        }
+        candidates.push(Construal { idiom: Some(IdiomReading::Literal), ..Construal::literal(literal) });
+    }
+
+    let mut results: Vec<(String, ReadingFeatures, Option<IdiomReading>)> = Vec::new();
+    for construal in candidates {
+        cancel.check()?;
+        if let Some((reading, features)) = read_construal(&construal, &type_registry, &mut interner, options, Some(cancel)) {
+            if !results.iter().any(|(r, _, _)| *r == reading) {
+                results.push((reading, features, construal.idiom));
+            }
+        }
+    }
+    // A parse cut short by the token reads as ungrammatical; don't rank the rest.
+    cancel.check()?;
+
+    // Rank everything, then keep the likeliest MAX_FOREST_READINGS: cutting
+    // first would drop readings by construal order, not by likelihood.
+    let weights = RankingWeights::active();
+    let features: Vec<ReadingFeatures> = results.iter().map(|(_, f, _)| *f).collect();
+    Ok(ranking::rank(&features, weights)
+        .into_iter()
+        .take(MAX_FOREST_READINGS)
+        .map(|i| RankedReading {
+            fol: results[i].0.clone(),
+            features: features[i],
+            score: weights.score(&features[i]),
+            idiom: results[i].2,
+        })
+        .collect())
+}
+
+/// The input lexed with its idioms left word-for-word, or `None` when it
+/// contains no idiom.
+fn literal_idiom_tokens(input: &str, interner: &mut Interner) -> Option<Vec<token::Token>> {
+    let tokens = Lexer::new(input, interner).with_gazetteer(&gazetteer::EMPTY).tokenize();
+    let mwe_trie = mwe::shared_mwe_trie();
+    if !mwe::contains_idiom(&tokens, mwe_trie, interner) {
+        return None;
+    }
+    Some(mwe::apply_literal_mwe_pipeline(tokens, mwe_trie, interner))
+}
+
+/// Lex, collapse multi-word expressions, and run discovery — the shared
+/// front half of every single-input compile.
+fn lex_for_parse(
+    input: &str,
+    gazetteer: &Gazetteer,
+    interner: &mut Interner,
+    metrics: &mut CompileMetrics,
+) -> (Vec<token::Token>, analysis::TypeRegistry) {
+    let lexing = Stopwatch::start();
+    let mut lexer = Lexer::new(input, interner).with_gazetteer(gazetteer);
     let tokens = lexer.tokenize();
+    lexing.record(metrics, Phase::Lex, tokens.len());
 
     // Apply MWE collapsing
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let collapsing = Stopwatch::start();
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, interner);
+    collapsing.record(metrics, Phase::Mwe, tokens.len());
 
     // Pass 1: Discovery - scan for type definitions
+    let discovering = Stopwatch::start();
     let type_registry = {
-        let mut discovery = analysis::DiscoveryPass::new(&tokens, &mut interner);
+        let mut discovery = analysis::DiscoveryPass::new(&tokens, interner);
         discovery.run()
     };
+    discovering.record(metrics, Phase::Discovery, type_registry.iter_types().count());
+    (tokens, type_registry)
+}
 
+/// The interpretive mode a construal parses in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ConstrualMode {
+    /// The parser's defaults (verb priority for Ambiguous tokens).
+    Default,
+    /// PP attaches to the nearest noun (structural ambiguity).
+    LowAttachment,
+    /// Plural subject of a mixed verb read as a group.
+    Collective,
+    /// Mixed verb with a definite plural read per member.
+    Distributive,
+    /// Event-modifying adjective over an agentive noun ("beautiful dancer").
+    EventAdjective,
+    /// Negation over the quantifier or modal it follows ("Everyone didn't
+    /// leave" → ¬∀), and wide-scope negation for lexically negative verbs
+    /// ("lacks").
+    WideNegation,
+    /// Negation lowered into a neg-raising verb's complement ("I don't think
+    /// he came" → Think(i, ⟨¬Came(he)⟩)).
+    NarrowNegation,
+    /// may/could as epistemic possibility.
+    Epistemic,
+    /// can as deontic permission.
+    Deontic,
+    /// Wide negation and deontic can together.
+    WideNegationDeontic,
+}
+
+impl ConstrualMode {
+    pub(crate) fn configure(self, parser: &mut Parser<'_, '_, '_>) {
+        match self {
+            ConstrualMode::Default => parser.set_noun_priority_mode(false),
+            ConstrualMode::LowAttachment => parser.set_pp_attachment_mode(true),
+            ConstrualMode::Collective => parser.set_collective_mode(true),
+            ConstrualMode::Distributive => parser.set_distributive_marker(true),
+            ConstrualMode::EventAdjective => parser.set_event_reading_mode(true),
+            ConstrualMode::WideNegation => parser.set_negative_scope_mode(NegativeScopeMode::Wide),
+            ConstrualMode::NarrowNegation => parser.set_negative_scope_mode(NegativeScopeMode::Narrow),
+            ConstrualMode::Epistemic => parser.set_modal_preference(ModalPreference::Epistemic),
+            ConstrualMode::Deontic => parser.set_modal_preference(ModalPreference::Deontic),
+            ConstrualMode::WideNegationDeontic => {
+                parser.set_negative_scope_mode(NegativeScopeMode::Wide);
+                parser.set_modal_preference(ModalPreference::Deontic);
+            }
+        }
+    }
+
+    /// Post-parse rewriting the mode calls for: a collective reading
+    /// regroups its cardinal subject.
+    pub(crate) fn finish<'a>(
+        self,
+        parser: &mut Parser<'a, '_, '_>,
+        ast: &'a crate::ast::LogicExpr<'a>,
+    ) -> Result<&'a crate::ast::LogicExpr<'a>, ParseError> {
+        match self {
+            ConstrualMode::Collective => parser.transform_cardinal_to_group(ast),
+            _ => Ok(ast),
+        }
+    }
+}
+
+/// One way of construing the input: a token-level disambiguation parsed in
+/// one interpretive mode. Each yields at most one forest reading.
+pub(crate) struct Construal {
+    pub(crate) tokens: Vec<token::Token>,
+    pub(crate) mode: ConstrualMode,
+    /// Share of Ambiguous tokens resolved to their primary category (1.0 when
+    /// the parser resolves them itself).
+    lexical_frequency: f64,
+    /// Set on every construal of input containing an idiom.
+    pub(crate) idiom: Option<IdiomReading>,
+}
+
+impl Construal {
+    /// The input as the parser reads it by default.
+    pub(crate) fn literal(tokens: Vec<token::Token>) -> Self {
+        Construal { tokens, mode: ConstrualMode::Default, lexical_frequency: 1.0, idiom: None }
+    }
+
+    /// The features known before parsing; the rest come from the formula.
+    pub(crate) fn features(&self) -> ReadingFeatures {
+        ReadingFeatures {
+            attachment_locality: if self.mode == ConstrualMode::LowAttachment { 1.0 } else { 0.0 },
+            lexical_frequency: self.lexical_frequency,
+            marked_construal: match (self.mode, self.idiom) {
+                (_, Some(IdiomReading::Literal)) => 1.0,
+                (ConstrualMode::Default | ConstrualMode::LowAttachment, _) => 0.0,
+                _ => 1.0,
+            },
+            ..ReadingFeatures::default()
+        }
+    }
+}
+
+/// Every construal the input's ambiguity triggers call for, the parser's
+/// default first.
+pub(crate) fn construals(tokens: &[token::Token], interner: &Interner) -> Vec<Construal> {
     let has_lexical_ambiguity = tokens.iter().any(|t| {
         matches!(t.kind, token::TokenType::Ambiguous { .. })
     });
@@ -433,6 +1067,12 @@ pub fn compile_forest_with_options(input: &str, options: CompileOptions) -> Vec<
     let has_plural_subject = tokens.iter().any(|t| {
         matches!(t.kind, token::TokenType::Cardinal(_))
             || matches!(&t.kind, token::TokenType::Article(def) if matches!(def, lexicon::Definiteness::Definite))
+    }) || tokens.windows(3).any(|w| {
+        // A coordinated subject: "John and Mary lifted the piano".
+        matches!(
+            (&w[0].kind, &w[1].kind, &w[2].kind),
+            (token::TokenType::ProperName(_), token::TokenType::And, token::TokenType::ProperName(_))
+        )
     });
 
     let has_plurality_ambiguity = (has_mixed_verb || has_collective_verb) && has_plural_subject;
@@ -441,7 +1081,7 @@ pub fn compile_forest_with_options(input: &str, options: CompileOptions) -> Vec<
     let has_event_adjective_ambiguity = {
         let mut has_event_adj = false;
         let mut has_agentive_noun = false;
-        for token in &tokens {
+        for token in tokens {
             if let token::TokenType::Adjective(sym) = &token.kind {
                 if lexicon::is_event_modifier_adjective(interner.resolve(*sym)) {
                     has_event_adj = true;
@@ -467,46 +1107,42 @@ pub fn compile_forest_with_options(input: &str, options: CompileOptions) -> Vec<
         }
     });
 
+    // Detect clausal "not" scoping against a universal subject, a modal, or
+    // a neg-raising verb ("Everyone didn't leave", "I don't think he came")
+    let has_clausal_negation = tokens.iter().any(|t| matches!(t.kind, token::TokenType::Not));
+    let has_negation_scope_ambiguity = has_clausal_negation
+        && tokens.iter().any(|t| {
+            matches!(
+                t.kind,
+                token::TokenType::All
+                    | token::TokenType::Must
+                    | token::TokenType::May
+                    | token::TokenType::Might
+                    | token::TokenType::Should
+                    | token::TokenType::Can
+                    | token::TokenType::Could
+                    | token::TokenType::Would
+                    | token::TokenType::Shall
+            )
+        });
+    let has_neg_raising = has_clausal_negation
+        && tokens.iter().any(|t| {
+            if let token::TokenType::Verb { lemma, .. } = &t.kind {
+                lexicon::is_neg_raising_verb(&interner.resolve(*lemma).to_lowercase())
+            } else {
+                false
+            }
+        });
+
     // Detect modal polysemy (may, can, could)
     let has_may = tokens.iter().any(|t| matches!(t.kind, token::TokenType::May));
     let has_can = tokens.iter().any(|t| matches!(t.kind, token::TokenType::Can));
     let has_could = tokens.iter().any(|t| matches!(t.kind, token::TokenType::Could));
 
-    let mut results: Vec<String> = Vec::new();
+    let whole = |mode| Construal { mode, ..Construal::literal(tokens.to_vec()) };
 
     // Reading 1: Default mode (verb priority for Ambiguous tokens)
-    {
-        let expr_arena = Arena::new();
-        let term_arena = Arena::new();
-        let np_arena = Arena::new();
-        let sym_arena = Arena::new();
-        let role_arena = Arena::new();
-        let pp_arena = Arena::new();
-
-        let ast_ctx = AstContext::new(
-            &expr_arena,
-            &term_arena,
-            &np_arena,
-            &sym_arena,
-            &role_arena,
-            &pp_arena,
-        );
-
-        let mut world_state = drs::WorldState::new();
-        let mut parser = Parser::new(tokens.clone(), &mut world_state, &mut interner, ast_ctx, type_registry.clone());
-        parser.set_noun_priority_mode(false);
-
-        if let Ok(ast) = parser.parse() {
-            let ast = semantics::apply_axioms(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner);
-            let ast = if options.format == OutputFormat::Kripke {
-                semantics::apply_kripke_lowering(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner)
-            } else {
-                ast
-            };
-            let mut registry = SymbolRegistry::new();
-            results.push(ast.transpile_discourse(&mut registry, &interner, options.format));
-        }
-    }
+    let mut out = vec![whole(ConstrualMode::Default)];
 
     // Reading set 2: PER-TOKEN resolution of lexical ambiguity. Each
     // Ambiguous token contributes its primary and alternative readings;
@@ -534,357 +1170,153 @@ pub fn compile_forest_with_options(input: &str, options: CompileOptions) -> Vec<
 
         if total <= MAX_FOREST_READINGS {
             for combo in 0..total {
-                let mut variant = tokens.clone();
+                let mut variant = tokens.to_vec();
                 let mut rem = combo;
+                let mut primaries = 0;
                 for (slot, &i) in amb_positions.iter().enumerate() {
                     let pick = rem % option_counts[slot];
                     rem /= option_counts[slot];
                     if let token::TokenType::Ambiguous { primary, alternatives } = &tokens[i].kind {
                         variant[i].kind = if pick == 0 {
+                            primaries += 1;
                             (**primary).clone()
                         } else {
                             alternatives[pick - 1].clone()
                         };
                     }
                 }
-
-                let expr_arena = Arena::new();
-                let term_arena = Arena::new();
-                let np_arena = Arena::new();
-                let sym_arena = Arena::new();
-                let role_arena = Arena::new();
-                let pp_arena = Arena::new();
-
-                let ast_ctx = AstContext::new(
-                    &expr_arena,
-                    &term_arena,
-                    &np_arena,
-                    &sym_arena,
-                    &role_arena,
-                    &pp_arena,
-                );
-
-                let mut world_state = drs::WorldState::new();
-                let mut parser = Parser::new(
-                    variant,
-                    &mut world_state,
-                    &mut interner,
-                    ast_ctx,
-                    type_registry.clone(),
-                );
-
-                if let Ok(ast) = parser.parse() {
-                    let ast = semantics::apply_axioms(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner);
-                    let ast = if options.format == OutputFormat::Kripke {
-                        semantics::apply_kripke_lowering(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner)
-                    } else {
-                        ast
-                    };
-                    let mut registry = SymbolRegistry::new();
-                    let reading = ast.transpile_discourse(&mut registry, &interner, options.format);
-                    if !results.contains(&reading) {
-                        results.push(reading);
-                    }
-                }
+                out.push(Construal {
+                    tokens: variant,
+                    mode: ConstrualMode::Default,
+                    lexical_frequency: primaries as f64 / amb_positions.len() as f64,
+                    idiom: None,
+                });
             }
         }
     }
 
     // Reading 3: PP attachment mode (for structural ambiguity)
     if has_pp_ambiguity {
-        let expr_arena = Arena::new();
-        let term_arena = Arena::new();
-        let np_arena = Arena::new();
-        let sym_arena = Arena::new();
-        let role_arena = Arena::new();
-        let pp_arena = Arena::new();
-
-        let ast_ctx = AstContext::new(
-            &expr_arena,
-            &term_arena,
-            &np_arena,
-            &sym_arena,
-            &role_arena,
-            &pp_arena,
-        );
-
-        let mut world_state = drs::WorldState::new();
-        let mut parser = Parser::new(tokens.clone(), &mut world_state, &mut interner, ast_ctx, type_registry.clone());
-        parser.set_pp_attachment_mode(true);
-
-        if let Ok(ast) = parser.parse() {
-            let ast = semantics::apply_axioms(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner);
-            let ast = if options.format == OutputFormat::Kripke {
-                semantics::apply_kripke_lowering(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner)
-            } else {
-                ast
-            };
-            let mut registry = SymbolRegistry::new();
-            let reading = ast.transpile_discourse(&mut registry, &interner, options.format);
-            if !results.contains(&reading) {
-                results.push(reading);
-            }
-        }
+        out.push(whole(ConstrualMode::LowAttachment));
     }
 
     // Reading 4: Collective mode (for plurality ambiguity with mixed verbs)
     if has_plurality_ambiguity {
-        let expr_arena = Arena::new();
-        let term_arena = Arena::new();
-        let np_arena = Arena::new();
-        let sym_arena = Arena::new();
-        let role_arena = Arena::new();
-        let pp_arena = Arena::new();
-
-        let ast_ctx = AstContext::new(
-            &expr_arena,
-            &term_arena,
-            &np_arena,
-            &sym_arena,
-            &role_arena,
-            &pp_arena,
-        );
-
-        let mut world_state = drs::WorldState::new();
-        let mut parser = Parser::new(tokens.clone(), &mut world_state, &mut interner, ast_ctx, type_registry.clone());
-        parser.set_collective_mode(true);
-
-        if let Ok(ast) = parser.parse() {
-            if let Ok(transformed) = parser.transform_cardinal_to_group(ast) {
-                let transformed = semantics::apply_axioms(transformed, ast_ctx.exprs, ast_ctx.terms, &mut interner);
-                let mut registry = SymbolRegistry::new();
-                let reading = transformed.transpile(&mut registry, &interner, options.format);
-                if !results.contains(&reading) {
-                    results.push(reading);
-                }
-            }
-        }
+        out.push(whole(ConstrualMode::Collective));
     }
 
     // Reading 4b: Distributive mode — a mixed verb with a definite plural
     // defaults to the collective reading, so the per-member reading is the
     // OTHER half of the ambiguity ("the boys lifted the piano" — each alone).
     if has_plurality_ambiguity {
-        let expr_arena = Arena::new();
-        let term_arena = Arena::new();
-        let np_arena = Arena::new();
-        let sym_arena = Arena::new();
-        let role_arena = Arena::new();
-        let pp_arena = Arena::new();
-
-        let ast_ctx = AstContext::new(
-            &expr_arena,
-            &term_arena,
-            &np_arena,
-            &sym_arena,
-            &role_arena,
-            &pp_arena,
-        );
-
-        let mut world_state = drs::WorldState::new();
-        let mut parser = Parser::new(tokens.clone(), &mut world_state, &mut interner, ast_ctx, type_registry.clone());
-        parser.set_distributive_marker(true);
-
-        if let Ok(ast) = parser.parse() {
-            let ast = semantics::apply_axioms(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner);
-            let mut registry = SymbolRegistry::new();
-            let reading = ast.transpile(&mut registry, &interner, options.format);
-            if !results.contains(&reading) {
-                results.push(reading);
-            }
-        }
+        out.push(whole(ConstrualMode::Distributive));
     }
 
     // Reading 5: Event adjective mode (for event-modifying adjectives with agentive nouns)
     if has_event_adjective_ambiguity {
-        let expr_arena = Arena::new();
-        let term_arena = Arena::new();
-        let np_arena = Arena::new();
-        let sym_arena = Arena::new();
-        let role_arena = Arena::new();
-        let pp_arena = Arena::new();
-
-        let ast_ctx = AstContext::new(
-            &expr_arena,
-            &term_arena,
-            &np_arena,
-            &sym_arena,
-            &role_arena,
-            &pp_arena,
-        );
-
-        let mut world_state = drs::WorldState::new();
-        let mut parser = Parser::new(tokens.clone(), &mut world_state, &mut interner, ast_ctx, type_registry.clone());
-        parser.set_event_reading_mode(true);
-
-        if let Ok(ast) = parser.parse() {
-            let ast = semantics::apply_axioms(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner);
-            let ast = if options.format == OutputFormat::Kripke {
-                semantics::apply_kripke_lowering(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner)
-            } else {
-                ast
-            };
-            let mut registry = SymbolRegistry::new();
-            let reading = ast.transpile_discourse(&mut registry, &interner, options.format);
-            if !results.contains(&reading) {
-                results.push(reading);
-            }
-        }
+        out.push(whole(ConstrualMode::EventAdjective));
     }
 
     // Reading 6: Wide scope negation mode (for lexically negative verbs like "lacks")
     if has_negative_verb {
-        let expr_arena = Arena::new();
-        let term_arena = Arena::new();
-        let np_arena = Arena::new();
-        let sym_arena = Arena::new();
-        let role_arena = Arena::new();
-        let pp_arena = Arena::new();
-
-        let ast_ctx = AstContext::new(
-            &expr_arena,
-            &term_arena,
-            &np_arena,
-            &sym_arena,
-            &role_arena,
-            &pp_arena,
-        );
+        out.push(whole(ConstrualMode::WideNegation));
+    }
 
-        let mut world_state = drs::WorldState::new();
-        let mut parser = Parser::new(tokens.clone(), &mut world_state, &mut interner, ast_ctx, type_registry.clone());
-        parser.set_negative_scope_mode(NegativeScopeMode::Wide);
+    // Reading 6b: Negation over a universal subject or modal
+    if has_negation_scope_ambiguity && !has_negative_verb {
+        out.push(whole(ConstrualMode::WideNegation));
+    }
 
-        if let Ok(ast) = parser.parse() {
-            let ast = semantics::apply_axioms(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner);
-            let ast = if options.format == OutputFormat::Kripke {
-                semantics::apply_kripke_lowering(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner)
-            } else {
-                ast
-            };
-            let mut registry = SymbolRegistry::new();
-            let reading = ast.transpile_discourse(&mut registry, &interner, options.format);
-            if !results.contains(&reading) {
-                results.push(reading);
-            }
-        }
+    // Reading 6c: Negation lowered into a neg-raising verb's complement
+    if has_neg_raising {
+        out.push(whole(ConstrualMode::NarrowNegation));
     }
 
     // Reading 7: Epistemic modal preference (May=Possibility, Could=Possibility)
     if has_may || has_could {
-        let expr_arena = Arena::new();
-        let term_arena = Arena::new();
-        let np_arena = Arena::new();
-        let sym_arena = Arena::new();
-        let role_arena = Arena::new();
-        let pp_arena = Arena::new();
-
-        let ast_ctx = AstContext::new(
-            &expr_arena,
-            &term_arena,
-            &np_arena,
-            &sym_arena,
-            &role_arena,
-            &pp_arena,
-        );
-
-        let mut world_state = drs::WorldState::new();
-        let mut parser = Parser::new(tokens.clone(), &mut world_state, &mut interner, ast_ctx, type_registry.clone());
-        parser.set_modal_preference(ModalPreference::Epistemic);
-
-        if let Ok(ast) = parser.parse() {
-            let ast = semantics::apply_axioms(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner);
-            let ast = if options.format == OutputFormat::Kripke {
-                semantics::apply_kripke_lowering(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner)
-            } else {
-                ast
-            };
-            let mut registry = SymbolRegistry::new();
-            let reading = ast.transpile_discourse(&mut registry, &interner, options.format);
-            if !results.contains(&reading) {
-                results.push(reading);
-            }
-        }
+        out.push(whole(ConstrualMode::Epistemic));
     }
 
     // Reading 8: Deontic modal preference (Can=Permission)
     if has_can {
-        let expr_arena = Arena::new();
-        let term_arena = Arena::new();
-        let np_arena = Arena::new();
-        let sym_arena = Arena::new();
-        let role_arena = Arena::new();
-        let pp_arena = Arena::new();
-
-        let ast_ctx = AstContext::new(
-            &expr_arena,
-            &term_arena,
-            &np_arena,
-            &sym_arena,
-            &role_arena,
-            &pp_arena,
-        );
-
-        let mut world_state = drs::WorldState::new();
-        let mut parser = Parser::new(tokens.clone(), &mut world_state, &mut interner, ast_ctx, type_registry.clone());
-        parser.set_modal_preference(ModalPreference::Deontic);
-
-        if let Ok(ast) = parser.parse() {
-            let ast = semantics::apply_axioms(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner);
-            let ast = if options.format == OutputFormat::Kripke {
-                semantics::apply_kripke_lowering(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner)
-            } else {
-                ast
-            };
-            let mut registry = SymbolRegistry::new();
-            let reading = ast.transpile_discourse(&mut registry, &interner, options.format);
-            if !results.contains(&reading) {
-                results.push(reading);
-            }
-        }
+        out.push(whole(ConstrualMode::Deontic));
     }
 
     // Reading 9: Wide scope negation + Deontic modal preference
     if has_negative_verb && has_can {
-        let expr_arena = Arena::new();
-        let term_arena = Arena::new();
-        let np_arena = Arena::new();
-        let sym_arena = Arena::new();
-        let role_arena = Arena::new();
-        let pp_arena = Arena::new();
+        out.push(whole(ConstrualMode::WideNegationDeontic));
+    }
 
-        let ast_ctx = AstContext::new(
-            &expr_arena,
-            &term_arena,
-            &np_arena,
-            &sym_arena,
-            &role_arena,
-            &pp_arena,
-        );
+    out
+}
 
-        let mut world_state = drs::WorldState::new();
-        let mut parser = Parser::new(tokens.clone(), &mut world_state, &mut interner, ast_ctx, type_registry);
-        parser.set_negative_scope_mode(NegativeScopeMode::Wide);
-        parser.set_modal_preference(ModalPreference::Deontic);
+/// Parse one construal into its forest reading and ranking features, or
+/// `None` when it is ungrammatical (or `cancel` fired mid-parse).
+fn read_construal(
+    construal: &Construal,
+    type_registry: &analysis::TypeRegistry,
+    interner: &mut Interner,
+    options: CompileOptions,
+    cancel: Option<&CancellationToken>,
+) -> Option<(String, ReadingFeatures)> {
+    let expr_arena = Arena::new();
+    let term_arena = Arena::new();
+    let np_arena = Arena::new();
+    let sym_arena = Arena::new();
+    let role_arena = Arena::new();
+    let pp_arena = Arena::new();
+
+    let ast_ctx = AstContext::new(
+        &expr_arena,
+        &term_arena,
+        &np_arena,
+        &sym_arena,
+        &role_arena,
+        &pp_arena,
+    );
 
-        if let Ok(ast) = parser.parse() {
-            let ast = semantics::apply_axioms(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner);
+    let mut world_state = drs::WorldState::new();
+    let mut parser = Parser::new(construal.tokens.clone(), &mut world_state, interner, ast_ctx, type_registry.clone());
+    parser.set_negative_scope_mode(options.negative_scope);
+    if let Some(cancel) = cancel {
+        parser.set_cancellation(cancel);
+    }
+    construal.mode.configure(&mut parser);
+
+    let ast = parser.parse().ok()?;
+    let mut registry = SymbolRegistry::new();
+    match construal.mode {
+        ConstrualMode::Collective => {
+            let transformed = construal.mode.finish(&mut parser, ast).ok()?;
+            let transformed = semantics::apply_axioms(transformed, ast_ctx.exprs, ast_ctx.terms, interner);
+            let features = construal.features().with_form(transformed, interner);
+            let reading = finish_reading(transformed, ast_ctx, interner, options, |ast, interner| {
+                ast.transpile_discourse(&mut registry, interner, options.format)
+            });
+            Some((reading.ok()?, features))
+        }
+        ConstrualMode::Distributive => {
+            let ast = semantics::apply_axioms(ast, ast_ctx.exprs, ast_ctx.terms, interner);
+            let features = construal.features().with_form(ast, interner);
+            let reading = finish_reading(ast, ast_ctx, interner, options, |ast, interner| {
+                ast.transpile_discourse(&mut registry, interner, options.format)
+            });
+            Some((reading.ok()?, features))
+        }
+        _ => {
+            let ast = semantics::apply_axioms(ast, ast_ctx.exprs, ast_ctx.terms, interner);
             let ast = if options.format == OutputFormat::Kripke {
-                semantics::apply_kripke_lowering(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner)
+                semantics::apply_kripke_lowering(ast, ast_ctx.exprs, ast_ctx.terms, interner)
             } else {
                 ast
             };
-            let mut registry = SymbolRegistry::new();
-            let reading = ast.transpile_discourse(&mut registry, &interner, options.format);
-            if !results.contains(&reading) {
-                results.push(reading);
-            }
+            let features = construal.features().with_form(ast, interner);
+            let reading = finish_reading(ast, ast_ctx, interner, options, |ast, interner| {
+                ast.transpile_discourse(&mut registry, interner, options.format)
+            });
+            Some((reading.ok()?, features))
         }
     }
-
-    // Enforce MAX_FOREST_READINGS limit
-    results.truncate(MAX_FOREST_READINGS);
-
-    results
 }
 
 // ═══════════════════════════════════════════════════════════════════
@@ -902,7 +1334,8 @@ pub fn compile_discourse_with_options(sentences: &[&str], options: CompileOption
     let mut world_state = drs::WorldState::new();
     let mut results = Vec::new();
     let mut registry = SymbolRegistry::new();
-    let mwe_trie = mwe::build_mwe_trie();
+    let mwe_trie = mwe::shared_mwe_trie();
+    let mut timeline = Vec::new();
 
     for sentence in sentences {
         let event_var_name = world_state.next_event_var();
@@ -912,7 +1345,7 @@ pub fn compile_discourse_with_options(sentences: &[&str], options: CompileOption
         let tokens = lexer.tokenize();
 
         // Apply MWE collapsing
-        let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+        let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
 
         // Pass 1: Discovery - scan for type definitions
         let type_registry = {
@@ -947,21 +1380,23 @@ pub fn compile_discourse_with_options(sentences: &[&str], options: CompileOption
 
         // Mark sentence boundary - collect telescope candidates for cross-sentence anaphora
         world_state.end_sentence();
+        let tense = world_state
+            .take_sentence_tense()
+            .unwrap_or(tense::TenseAspect::simple(lexicon::Time::Past));
+        timeline.push((event_var_name, tense));
 
         let ast = semantics::apply_axioms(ast, ast_ctx.exprs, ast_ctx.terms, &mut interner);
         results.push(ast.transpile_discourse(&mut registry, &interner, options.format));
     }
 
-    let event_history = world_state.event_history();
-    let mut precedes = Vec::new();
-    for i in 0..event_history.len().saturating_sub(1) {
-        precedes.push(format!("Precedes({}, {})", event_history[i], event_history[i + 1]));
-    }
+    // A past perfect reaches back before the story's reference event and a
+    // progressive surrounds it; only simple tenses move the story forward.
+    let order: Vec<String> = tense::narrative_order(&timeline).iter().map(tense::render).collect();
 
-    if precedes.is_empty() {
+    if order.is_empty() {
         Ok(results.join(" ∧ "))
     } else {
-        Ok(format!("{} ∧ {}", results.join(" ∧ "), precedes.join(" ∧ ")))
+        Ok(format!("{} ∧ {}", results.join(" ∧ "), order.join(" ∧ ")))
     }
 }
 
@@ -982,8 +1417,8 @@ pub fn compile_ambiguous_with_options(input: &str, options: CompileOptions) -> R
     let tokens = lexer.tokenize();
 
     // Apply MWE collapsing
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
 
     // Pass 1: Discovery - scan for type definitions
     let type_registry = {
@@ -1065,16 +1500,20 @@ use crate::ast::{self, Stmt};
 use crate::token::Span;
 use crate::error::ParseErrorKind;
 use crate::proof_convert::logic_expr_to_proof_expr;
+use logicaffeine_proof::export;
 
-/// Compile and prove a theorem block.
-pub fn compile_theorem(input: &str) -> Result<String, ParseError> {
+/// Parse `input` as a program and hand its statements and interner to `f`.
+fn with_theorem_program<R>(
+    input: &str,
+    f: impl FnOnce(&[Stmt], &Interner) -> Result<R, ParseError>,
+) -> Result<R, ParseError> {
     let mut interner = Interner::new();
     let mut lexer = Lexer::new(input, &mut interner);
     let tokens = lexer.tokenize();
 
     // Apply MWE collapsing
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
 
     // Pass 1: Discovery
     let type_registry = {
@@ -1088,21 +1527,36 @@ pub fn compile_theorem(input: &str) -> Result<String, ParseError> {
     let sym_arena = Arena::new();
     let role_arena = Arena::new();
     let pp_arena = Arena::new();
+    let stmt_arena = Arena::new();
+    let imperative_expr_arena = Arena::new();
+    let type_expr_arena = Arena::new();
 
-    let ctx = AstContext::new(
+    // Imperative arenas too, so a theorem file that also has code still parses
+    let ctx = AstContext::with_types(
         &expr_arena,
         &term_arena,
         &np_arena,
         &sym_arena,
         &role_arena,
         &pp_arena,
+        &stmt_arena,
+        &imperative_expr_arena,
+        &type_expr_arena,
     );
 
     // Parse as program to get statements including Theorem blocks
     let mut world_state = drs::WorldState::new();
     let mut parser = Parser::new(tokens, &mut world_state, &mut interner, ctx, type_registry);
     let statements = parser.parse_program()?;
+    f(&statements, &interner)
+}
+
+/// Compile and prove a theorem block.
+pub fn compile_theorem(input: &str) -> Result<String, ParseError> {
+    with_theorem_program(input, prove_first_theorem)
+}
 
+fn prove_first_theorem(statements: &[Stmt], interner: &Interner) -> Result<String, ParseError> {
     // Find the first Theorem statement
     let theorem = statements
         .iter()
@@ -1122,9 +1576,9 @@ pub fn compile_theorem(input: &str) -> Result<String, ParseError> {
     let premises: Vec<_> = theorem
         .premises
         .iter()
-        .map(|premise| logic_expr_to_proof_expr(premise, &interner))
+        .map(|premise| logic_expr_to_proof_expr(premise, interner))
         .collect();
-    let goal = logic_expr_to_proof_expr(theorem.goal, &interner);
+    let goal = logic_expr_to_proof_expr(theorem.goal, interner);
 
     // Route through the one canonical pipeline (prove → certify → kernel check),
     // so every theorem entry point shares a single engine. This door reports a
@@ -1177,6 +1631,60 @@ pub fn compile_theorem(input: &str) -> Result<String, ParseError> {
     }
 }
 
+/// Export every theorem block in `input` to `prover`'s syntax, as one source
+/// file that declares the predicates and individuals they use as axioms and
+/// leaves each theorem for the user to prove there.
+pub fn export_theorems(input: &str, prover: export::Prover) -> Result<String, ParseError> {
+    with_theorem_program(input, |statements, interner| {
+        let theorems: Vec<_> = statements
+            .iter()
+            .filter_map(|stmt| if let Stmt::Theorem(t) = stmt { Some(t) } else { None })
+            .map(|theorem| export::Statement {
+                name: theorem.name.clone(),
+                premises: theorem
+                    .premises
+                    .iter()
+                    .zip(&theorem.premise_names)
+                    .enumerate()
+                    .map(|(i, (premise, name))| {
+                        let name = name.clone().unwrap_or_else(|| format!("h{}", i + 1));
+                        (name, logic_expr_to_proof_expr(premise, interner))
+                    })
+                    .collect(),
+                goal: logic_expr_to_proof_expr(theorem.goal, interner),
+            })
+            .collect();
+        if theorems.is_empty() {
+            return Err(ParseError {
+                kind: ParseErrorKind::Custom("No theorem block found in input".to_string()),
+                span: Span::default(),
+            });
+        }
+        export::export_statements(&theorems, prover).map_err(|e| ParseError {
+            kind: ParseErrorKind::Custom(e.to_string()),
+            span: Span::default(),
+        })
+    })
+}
+
+/// Re-check `finished`, a copy of [`export_theorems`]' output with theorems
+/// proved in `prover`, against the theorem blocks of `input`.
+///
+/// The statements come from exporting `input` afresh, so a finished file that
+/// edits a theorem's statement does not discharge it. One
+/// [`export::Discharge`] is returned per theorem in `finished`.
+pub fn check_exported_proofs(
+    input: &str,
+    finished: &str,
+    prover: export::Prover,
+) -> Result<Vec<export::Discharge>, ParseError> {
+    let exported = export_theorems(input, prover)?;
+    export::check_finished(&exported, finished, prover).map_err(|e| ParseError {
+        kind: ParseErrorKind::Custom(e.to_string()),
+        span: Span::default(),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1192,7 +1700,7 @@ mod tests {
 
     #[test]
     fn test_compile_with_unicode_format() {
-        let options = CompileOptions { format: OutputFormat::Unicode, pragmatic: false };
+        let options = CompileOptions { format: OutputFormat::Unicode, pragmatic: false, ..Default::default() };
         let result = compile_with_options("Every dog barks.", options);
         assert!(result.is_ok());
         let output = result.unwrap();
@@ -0,0 +1,288 @@
+//! Parser rule coverage — which constructions does a corpus exercise?
+//!
+//! A [`ParserCoverage`] parses sentence after sentence with the derivation
+//! trace on ([`compile_with_trace`](crate::compile_with_trace)) and counts
+//! what fired: every grammar rule in [`RULES`] by outcome, and every named
+//! branch in [`BRANCHES`] — the alternatives inside a rule, such as which
+//! sentence-level construction matched or which determiner opened a
+//! quantified phrase. A rule that is never entered is untested; one that is
+//! entered but never applied only ever fails or is backtracked past, which
+//! for a broad corpus points at a dead rule.
+//!
+//! `largo dev parser-coverage` runs the generated sentence corpus through
+//! it; test suites can feed it their own sentences.
+//!
+//! ```rust
+//! use logicaffeine_language::coverage::ParserCoverage;
+//!
+//! let mut coverage = ParserCoverage::new();
+//! assert!(coverage.record("Every man is mortal.").is_ok());
+//! assert!(coverage.rule("quantified").applied > 0);
+//! assert_eq!(coverage.branch("quantified/all"), 1);
+//! assert_eq!(coverage.branch("sentence/cleft"), 0);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::error::ParseError;
+use crate::trace::{DerivationTrace, RuleOutcome};
+
+/// Every rule the parser records in a derivation trace.
+pub const RULES: &[&str] = &[
+    "discourse",
+    "sentence",
+    "conditional",
+    "either-or",
+    "disjunction",
+    "conjunction",
+    "modal",
+    "aspect chain",
+    "focus",
+    "wh-question",
+    "yes/no question",
+    "quantified",
+    "restriction",
+    "noun phrase",
+    "relative clause",
+    "predicate",
+];
+
+/// Every named branch, as `area/alternative`. The area is the rule the branch
+/// belongs to, or `clause` for the subject–predicate core that every rule
+/// bottoms out in.
+pub const BRANCHES: &[&str] = &[
+    "sentence/ellipsis",
+    "sentence/optative",
+    "sentence/correlative",
+    "sentence/partition",
+    "sentence/fronted temporal",
+    "sentence/whoever",
+    "sentence/exclamative",
+    "sentence/cleft",
+    "sentence/existential there",
+    "sentence/imperative",
+    "sentence/concessive",
+    "sentence/while",
+    "sentence/when",
+    "sentence/inverted conditional",
+    "sentence/negation",
+    "sentence/both",
+    "sentence/temporal operator",
+    "sentence/after",
+    "sentence/before",
+    "clause/measure",
+    "clause/npi quantifier",
+    "clause/temporal npi",
+    "clause/parenthesized",
+    "clause/pronoun subject",
+    "clause/plural subject",
+    "clause/scopal adverb",
+    "clause/appositive",
+    "clause/relative subject",
+    "clause/identity",
+    "clause/copula",
+    "clause/do as main verb",
+    "clause/auxiliary",
+    "clause/presupposition",
+    "clause/generic",
+    "clause/do-support",
+    "clause/never",
+    "clause/finite verb",
+    "quantified/all",
+    "quantified/no",
+    "quantified/some",
+    "quantified/any",
+    "quantified/most",
+    "quantified/few",
+    "quantified/many",
+    "quantified/cardinal",
+    "quantified/at least",
+    "quantified/at most",
+    "quantified/partitive",
+    "quantified/counted list",
+    "quantified/delegated predicate",
+    "predicate/never",
+    "predicate/perfect",
+    "predicate/do-support",
+    "predicate/auxiliary",
+    "predicate/copula",
+    "predicate/do as main verb",
+    "predicate/finite verb",
+];
+
+/// How often one rule fired, by outcome.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RuleHits {
+    pub applied: usize,
+    pub backtracked: usize,
+    pub failed: usize,
+}
+
+impl RuleHits {
+    /// Every time the rule was entered.
+    pub fn total(&self) -> usize {
+        self.applied + self.backtracked + self.failed
+    }
+}
+
+/// Rule and branch counts accumulated over a corpus.
+#[derive(Debug, Clone, Default)]
+pub struct ParserCoverage {
+    /// Sentences recorded.
+    pub sentences: usize,
+    /// Recorded sentences the parser rejected.
+    pub rejected: usize,
+    rules: HashMap<&'static str, RuleHits>,
+    branches: HashMap<&'static str, usize>,
+}
+
+impl ParserCoverage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses `sentence` and counts the rules and branches its derivation
+    /// used, returning the parse.
+    pub fn record(&mut self, sentence: &str) -> Result<String, ParseError> {
+        let (result, trace) = crate::compile_with_trace(sentence);
+        self.add(&trace, result.is_ok());
+        result
+    }
+
+    /// Counts a trace recorded elsewhere, from a parse that succeeded or not.
+    pub fn add(&mut self, trace: &DerivationTrace, parsed: bool) {
+        self.sentences += 1;
+        if !parsed {
+            self.rejected += 1;
+        }
+        for application in trace.rules() {
+            let hits = self.rules.entry(application.rule).or_default();
+            match application.outcome {
+                RuleOutcome::Applied => hits.applied += 1,
+                RuleOutcome::Backtracked => hits.backtracked += 1,
+                RuleOutcome::Failed => hits.failed += 1,
+            }
+        }
+        for &branch in &trace.branches {
+            *self.branches.entry(branch).or_default() += 1;
+        }
+    }
+
+    pub fn rule(&self, rule: &str) -> RuleHits {
+        self.rules.get(rule).copied().unwrap_or_default()
+    }
+
+    /// How often `branch` was taken.
+    pub fn branch(&self, branch: &str) -> usize {
+        self.branches.get(branch).copied().unwrap_or(0)
+    }
+
+    /// Rules that never applied: never entered, or entered only to fail or
+    /// be backtracked past.
+    pub fn unapplied_rules(&self) -> Vec<&'static str> {
+        RULES.iter().copied().filter(|rule| self.rule(rule).applied == 0).collect()
+    }
+
+    /// Branches never taken.
+    pub fn untaken_branches(&self) -> Vec<&'static str> {
+        BRANCHES.iter().copied().filter(|branch| self.branch(branch) == 0).collect()
+    }
+}
+
+impl fmt::Display for ParserCoverage {
+    /// A report: a summary line, then every rule with its counts and every
+    /// branch with its count, unexercised ones marked `✗`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = RULES.iter().chain(BRANCHES).map(|name| name.chars().count()).max().unwrap_or(0);
+        writeln!(
+            f,
+            "{} sentences ({} rejected): {} of {} rules applied, {} of {} branches taken",
+            self.sentences,
+            self.rejected,
+            RULES.len() - self.unapplied_rules().len(),
+            RULES.len(),
+            BRANCHES.len() - self.untaken_branches().len(),
+            BRANCHES.len()
+        )?;
+        writeln!(f, "\nrules")?;
+        for rule in RULES {
+            let hits = self.rule(rule);
+            let mark = if hits.applied > 0 { '✓' } else { '✗' };
+            write!(f, "  {mark} {rule:width$}")?;
+            if hits.total() == 0 {
+                writeln!(f, "  never entered")?;
+            } else {
+                writeln!(f, "  {} applied, {} backtracked, {} failed", hits.applied, hits.backtracked, hits.failed)?;
+            }
+        }
+        writeln!(f, "\nbranches")?;
+        for branch in BRANCHES {
+            let taken = self.branch(branch);
+            let mark = if taken > 0 { '✓' } else { '✗' };
+            writeln!(f, "  {mark} {branch:width$}  {taken}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARSER_SOURCES: [&str; 9] = [
+        include_str!("parser/mod.rs"),
+        include_str!("parser/clause.rs"),
+        include_str!("parser/modal.rs"),
+        include_str!("parser/noun.rs"),
+        include_str!("parser/pragmatics.rs"),
+        include_str!("parser/quantifier.rs"),
+        include_str!("parser/question.rs"),
+        include_str!("parser/verb.rs"),
+        include_str!("parser/common.rs"),
+    ];
+
+    /// The string literals of the parser that start with `prefix`.
+    fn literals_after(prefix: &str) -> Vec<String> {
+        let mut found = Vec::new();
+        for source in PARSER_SOURCES {
+            for (start, _) in source.match_indices(prefix) {
+                let rest = &source[start + prefix.len()..];
+                if let Some(end) = rest.find('"') {
+                    found.push(rest[..end].to_string());
+                }
+            }
+        }
+        found
+    }
+
+    #[test]
+    fn every_traced_rule_is_listed() {
+        let traced = literals_after("traced(\"");
+        for rule in &traced {
+            assert!(RULES.contains(&rule.as_str()), "rule `{rule}` is missing from RULES");
+        }
+        for rule in RULES {
+            assert!(traced.iter().any(|t| t == rule), "RULES lists `{rule}`, which the parser never traces");
+        }
+    }
+
+    #[test]
+    fn every_branch_probe_is_listed() {
+        let areas: Vec<&str> = RULES.iter().copied().chain(["clause"]).collect();
+        let probes: Vec<String> = literals_after("\"")
+            .into_iter()
+            .filter(|literal| {
+                literal.split_once('/').is_some_and(|(area, alternative)| {
+                    areas.contains(&area) && !alternative.is_empty() && !alternative.contains(['/', '"'])
+                })
+            })
+            .collect();
+        for probe in &probes {
+            assert!(BRANCHES.contains(&probe.as_str()), "branch `{probe}` is missing from BRANCHES");
+        }
+        for branch in BRANCHES {
+            assert!(probes.iter().any(|p| p == branch), "BRANCHES lists `{branch}`, which the parser never records");
+        }
+    }
+}
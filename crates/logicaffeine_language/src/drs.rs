@@ -43,7 +43,9 @@
 //! - Both receive universal quantification due to conditional DRS signature
 
 use logicaffeine_base::Symbol;
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use crate::tense::TenseAspect;
 
 // Re-export lexicon types for DRS usage
 pub use logicaffeine_lexicon::types::{Gender, Number, Case};
@@ -52,10 +54,22 @@ pub use logicaffeine_lexicon::types::{Gender, Number, Case};
 // CORE DISCOURSE TYPES (moved from context.rs)
 // ============================================
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TimeRelation {
     Precedes,
     Equals,
+    /// The left interval contains the right one (a progressive's event
+    /// around its reference time).
+    Includes,
+}
+
+/// Saved temporal state; see [`WorldState::time_checkpoint`].
+#[derive(Debug, Clone)]
+pub struct TimeCheckpoint {
+    constraints: usize,
+    reference_time_counter: usize,
+    current_reference_time: Option<String>,
+    sentence_tense: Option<TenseAspect>,
 }
 
 #[derive(Debug, Clone)]
@@ -65,7 +79,7 @@ pub struct TimeConstraint {
     pub right: String,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum OwnershipState {
     #[default]
     Owned,
@@ -115,7 +129,7 @@ impl std::error::Error for ScopeError {}
 // ============================================
 
 /// Path segment for navigating to insertion point during AST restructuring
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ScopePath {
     /// Enter body of ∀ or ∃ quantifier
     QuantifierBody,
@@ -165,24 +179,26 @@ pub struct WorldState {
     /// The global DRS (box hierarchy for scope tracking)
     pub drs: Drs,
     /// Event variable counter (e1, e2, e3...)
-    event_counter: usize,
+    pub(crate) event_counter: usize,
     /// Event history for temporal ordering
-    event_history: Vec<String>,
+    pub(crate) event_history: Vec<String>,
     /// Reference time counter (r1, r2, r3...)
-    reference_time_counter: usize,
+    pub(crate) reference_time_counter: usize,
     /// Current reference time
-    current_reference_time: Option<String>,
+    pub(crate) current_reference_time: Option<String>,
     /// Temporal constraints between events
-    time_constraints: Vec<TimeConstraint>,
+    pub(crate) time_constraints: Vec<TimeConstraint>,
+    /// Tense and aspect of the sentence's compound-tense clause, if any
+    pub(crate) sentence_tense: Option<TenseAspect>,
     /// Telescope candidates from previous sentence
-    telescope_candidates: Vec<TelescopeCandidate>,
+    pub(crate) telescope_candidates: Vec<TelescopeCandidate>,
     /// Whether we're in discourse mode (processing multi-sentence discourse)
     /// When true, unresolved pronouns should error instead of deictic fallback
-    discourse_mode: bool,
+    pub(crate) discourse_mode: bool,
     /// Current modal context (if any) for tracking modal scope
-    current_modal_context: Option<ModalContext>,
+    pub(crate) current_modal_context: Option<ModalContext>,
     /// Modal context from previous sentence for subordination
-    prior_modal_context: Option<ModalContext>,
+    pub(crate) prior_modal_context: Option<ModalContext>,
 }
 
 impl WorldState {
@@ -194,6 +210,7 @@ impl WorldState {
             reference_time_counter: 0,
             current_reference_time: None,
             time_constraints: Vec::new(),
+            sentence_tense: None,
             telescope_candidates: Vec::new(),
             discourse_mode: false,
             current_modal_context: None,
@@ -237,11 +254,53 @@ impl WorldState {
         &self.time_constraints
     }
 
+    /// Records a clause's tense and aspect: allocates its reference time
+    /// (unless present) and adds its Reichenbach constraints over the
+    /// current event. Simple tenses only mark the sentence's tense.
+    pub fn record_tense(&mut self, tense: TenseAspect) {
+        self.sentence_tense = Some(tense);
+        if !tense.is_compound() {
+            return;
+        }
+        let reference = if tense.has_reference_time() {
+            self.next_reference_time()
+        } else {
+            crate::tense::SPEECH_TIME.to_string()
+        };
+        let event = format!("e{}", self.event_history.len().max(1));
+        self.time_constraints.extend(tense.constraints(&event, &reference));
+    }
+
+    /// The tense recorded for the current sentence, clearing it.
+    pub fn take_sentence_tense(&mut self) -> Option<TenseAspect> {
+        self.sentence_tense.take()
+    }
+
+    /// A restore point for the temporal state, taken before speculative
+    /// parsing so an abandoned parse leaves no constraints behind.
+    pub fn time_checkpoint(&self) -> TimeCheckpoint {
+        TimeCheckpoint {
+            constraints: self.time_constraints.len(),
+            reference_time_counter: self.reference_time_counter,
+            current_reference_time: self.current_reference_time.clone(),
+            sentence_tense: self.sentence_tense,
+        }
+    }
+
+    /// Rolls the temporal state back to `checkpoint`.
+    pub fn restore_time(&mut self, checkpoint: TimeCheckpoint) {
+        self.time_constraints.truncate(checkpoint.constraints);
+        self.reference_time_counter = checkpoint.reference_time_counter;
+        self.current_reference_time = checkpoint.current_reference_time;
+        self.sentence_tense = checkpoint.sentence_tense;
+    }
+
     /// Clear time constraints (for sentence boundary reset if needed)
     pub fn clear_time_constraints(&mut self) {
         self.time_constraints.clear();
         self.reference_time_counter = 0;
         self.current_reference_time = None;
+        self.sentence_tense = None;
     }
 
     /// Mark a sentence boundary - collect telescope candidates
@@ -391,7 +450,7 @@ impl Default for WorldState {
 // REFERENT SOURCE
 // ============================================
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReferentSource {
     /// Indefinite in main clause - gets existential force
     MainClause,
@@ -418,7 +477,7 @@ impl ReferentSource {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BoxType {
     /// Top-level discourse box
     Main,
@@ -555,9 +614,9 @@ impl DrsBox {
 
 #[derive(Debug, Clone)]
 pub struct Drs {
-    boxes: Vec<DrsBox>,
-    main_box: usize,
-    current_box: usize,
+    pub(crate) boxes: Vec<DrsBox>,
+    pub(crate) main_box: usize,
+    pub(crate) current_box: usize,
     /// Maps a declared ITEM to the CATEGORY noun it was declared under. A
     /// category declaration ("2001, 2002, 2003, and 2004 are four different
     /// years.") records `2001 → Year`, `2002 → Year`, … so a later definite
@@ -565,7 +624,7 @@ pub struct Drs {
     /// to the SAME relation the prepositional-phrase form produces ("the
     /// holiday was in 2003" → In(x, 2003)). Persisting in the shared discourse
     /// DRS is what makes the two surface forms converge across sentences.
-    item_categories: std::collections::HashMap<Symbol, Symbol>,
+    pub(crate) item_categories: std::collections::HashMap<Symbol, Symbol>,
 }
 
 impl Drs {
@@ -730,9 +789,10 @@ impl Drs {
         false
     }
 
-    /// Resolve a pronoun by finding accessible referents matching gender and number
-    pub fn resolve_pronoun(&mut self, from_box: usize, gender: Gender, number: Number) -> Result<Symbol, ScopeError> {
-        // Phase 1: Search accessible referents
+    /// Accessible referents a pronoun of this gender and number could pick
+    /// up, as `(box, referent index)` in discourse order — the last is the
+    /// one [`Drs::resolve_pronoun`] chooses.
+    fn accessible_antecedents(&self, from_box: usize, gender: Gender, number: Number) -> Vec<(usize, usize)> {
         // A referent is accessible if:
         //   - It's in an accessible box, OR
         //   - It has MainClause/ProperName source (globally accessible, e.g. definite descriptions)
@@ -742,7 +802,7 @@ impl Drs {
         for (box_idx, drs_box) in self.boxes.iter().enumerate() {
             let box_accessible = self.is_accessible(box_idx, from_box);
 
-            for referent in &drs_box.universe {
+            for (referent_idx, referent) in drs_box.universe.iter().enumerate() {
                 // Skip referents that are from negative quantifiers (No X) or disjuncts
                 // Both are inaccessible outward per DRS accessibility
                 if matches!(referent.source, ReferentSource::NegationScope | ReferentSource::Disjunct) {
@@ -769,22 +829,46 @@ impl Drs {
                 let number_match = referent.number == number;
 
                 if gender_match && number_match {
-                    candidates.push((box_idx, referent.variable));
+                    candidates.push((box_idx, referent_idx));
                 }
             }
         }
 
-        // If found in accessible scope, return success
-        if let Some((box_idx, var)) = candidates.last() {
-            let box_idx = *box_idx;
-            let var = *var;
-            for referent in &mut self.boxes[box_idx].universe {
-                if referent.variable == var {
-                    referent.used_by_pronoun = true;
-                    return Ok(var);
-                }
+        candidates
+    }
+
+    /// Every accessible referent a pronoun could resolve to, most preferred
+    /// first: the head is what [`Drs::resolve_pronoun`] picks. More than one
+    /// means the antecedent is ambiguous.
+    pub fn antecedent_candidates(&self, from_box: usize, gender: Gender, number: Number) -> Vec<&Referent> {
+        self.accessible_antecedents(from_box, gender, number)
+            .into_iter()
+            .rev()
+            .map(|(box_idx, referent_idx)| &self.boxes[box_idx].universe[referent_idx])
+            .collect()
+    }
+
+    /// Records that a pronoun picked up `variable`.
+    pub fn mark_used_by_pronoun(&mut self, variable: Symbol) {
+        for referent in self.boxes.iter_mut().flat_map(|b| b.universe.iter_mut()) {
+            if referent.variable == variable {
+                referent.used_by_pronoun = true;
+                return;
             }
         }
+    }
+
+    /// Resolve a pronoun by finding accessible referents matching gender and number
+    pub fn resolve_pronoun(&mut self, from_box: usize, gender: Gender, number: Number) -> Result<Symbol, ScopeError> {
+        // Phase 1: Search accessible referents
+        let candidates = self.accessible_antecedents(from_box, gender, number);
+
+        // If found in accessible scope, return success
+        if let Some(&(box_idx, referent_idx)) = candidates.last() {
+            let referent = &mut self.boxes[box_idx].universe[referent_idx];
+            referent.used_by_pronoun = true;
+            return Ok(referent.variable);
+        }
 
         // Phase 2: Check inaccessible boxes OR referents with NegationScope/Disjunct source
         // Use the same strict gender matching for consistency
@@ -73,11 +73,13 @@ impl ParseError {
             error_label, kind_str, line_num_str, pipe, line_content, pipe, underline_colored
         );
 
-        if let Some(word) = self.extract_word(source) {
-            if let Some(suggestion) = find_similar(&word, KNOWN_WORDS, 2) {
-                let hint = Style::cyan("help");
-                result.push_str(&format!("\n     {} {}: did you mean '{}'?", pipe, hint, Style::green(suggestion)));
-            }
+        let ranked = match &self.kind {
+            ParseErrorKind::UnknownWord { suggestions, .. } => suggestions.first().map(String::as_str),
+            _ => self.extract_word(source).and_then(|word| find_similar(word, KNOWN_WORDS, 2)),
+        };
+        if let Some(suggestion) = ranked {
+            let hint = Style::cyan("help");
+            result.push_str(&format!("\n     {} {}: did you mean '{}'?", pipe, hint, Style::green(suggestion)));
         }
 
         result
@@ -223,6 +225,14 @@ pub enum ParseErrorKind {
         /// The enforced limit ([`crate::ast_depth::max_ast_depth`]).
         max_depth: usize,
     },
+    /// A word outside the lexicon broke the parse, with ranked spelling
+    /// corrections from [`crate::suggest::suggest`] (best first; may be empty).
+    UnknownWord {
+        word: String,
+        suggestions: Vec<String>,
+    },
+    /// The caller cancelled the parse (see [`crate::Parser::set_cancellation`]).
+    Cancelled,
     /// Custom error message (used for escape analysis, zone errors, etc.).
     Custom(String),
 }
@@ -592,6 +602,23 @@ pub fn socratic_explanation(error: &ParseError, _interner: &Interner) -> String
                 suggested = (depth + depth / 4).next_power_of_two()
             )
         }
+        ParseErrorKind::UnknownWord { word, suggestions } => {
+            let options = match suggestions.as_slice() {
+                [] => String::new(),
+                [only] => format!(" Did you mean '{only}'?"),
+                [first, rest @ ..] => format!(
+                    " Did you mean '{first}'? (Also close: {}.)",
+                    rest.iter().map(|s| format!("'{s}'")).collect::<Vec<_>>().join(", ")
+                ),
+            };
+            format!(
+                "I don't know the word '{word}', and the sentence stopped making sense \
+                around it. Every word I read is looked up in my lexicon — an unknown one \
+                gets a guessed role, and here the guess broke the structure.{options} If \
+                '{word}' is spelled the way you meant, is there a more common word for it?"
+            )
+        }
+        ParseErrorKind::Cancelled => "The parse was cancelled before it finished.".to_string(),
         ParseErrorKind::Custom(msg) => msg.clone(),
     }
 }
@@ -635,6 +662,35 @@ mod tests {
         assert!(display.contains("logic"), "Should suggest 'logic': {}", display);
     }
 
+    #[test]
+    fn display_with_source_uses_the_ranked_suggestion_for_unknown_words() {
+        let error = ParseError {
+            kind: ParseErrorKind::UnknownWord {
+                word: "lovse".to_string(),
+                suggestions: vec!["loves".to_string(), "lives".to_string()],
+            },
+            span: Span::new(5, 10),
+        };
+        let display = error.display_with_source("John lovse Mary.");
+        assert!(display.contains("did you mean"), "Should suggest fix: {}", display);
+        assert!(display.contains("loves"), "Should lead with the best correction: {}", display);
+    }
+
+    #[test]
+    fn unknown_word_socratic_lists_the_alternatives() {
+        let interner = logicaffeine_base::Interner::new();
+        let error = ParseError {
+            kind: ParseErrorKind::UnknownWord {
+                word: "lovse".to_string(),
+                suggestions: vec!["loves".to_string(), "lives".to_string(), "loose".to_string()],
+            },
+            span: Span::new(5, 10),
+        };
+        let explanation = socratic_explanation(&error, &interner);
+        assert!(explanation.contains("Did you mean 'loves'?"), "{}", explanation);
+        assert!(explanation.contains("'lives'") && explanation.contains("'loose'"), "{}", explanation);
+    }
+
     #[test]
     fn display_with_source_has_color_codes() {
         let error = ParseError {
@@ -9,13 +9,20 @@
 //!
 //! Formatters handle quantifiers, connectives, modal operators, temporal operators,
 //! and special constructs like plurals and possessives.
+//!
+//! The trait is object-safe, so other crates can add notations of their own
+//! (Isabelle/HOL, Lean 4, Prover9, …): [`register_formatter`] files one under
+//! a name and returns the [`OutputFormat::Custom`] that selects it, and
+//! [`OutputFormat::from_name`] finds it again from a string such as a CLI flag.
 
 use std::fmt::Write;
+use std::sync::{Arc, RwLock};
 
 use crate::ast::{AspectOperator, BinaryTemporalOp, ModalDomain, QuantifierKind, TemporalOperator, Term, VoiceOperator};
 use logicaffeine_base::Interner;
 use crate::registry::SymbolRegistry;
 use crate::token::TokenType;
+use crate::OutputFormat;
 
 /// Trait for formatting logical expressions in various notations.
 pub trait LogicFormatter {
@@ -202,9 +209,9 @@ pub trait LogicFormatter {
 
     /// Hook for customizing how comparatives are rendered.
     /// Default implementation uses standard logic notation: tallER(subj, obj) or tallER(subj, obj, diff)
-    fn write_comparative<W: Write>(
+    fn write_comparative(
         &self,
-        w: &mut W,
+        w: &mut dyn Write,
         adjective: &str,
         subject: &str,
         object: &str,
@@ -219,9 +226,9 @@ pub trait LogicFormatter {
 
     /// Hook for customizing how predicates are rendered.
     /// Default implementation uses standard logic notation: Name(Arg1, Arg2)
-    fn write_predicate<W: Write>(
+    fn write_predicate(
         &self,
-        w: &mut W,
+        mut w: &mut dyn Write,
         name: &str,
         args: &[Term],
         registry: &mut SymbolRegistry,
@@ -233,9 +240,9 @@ pub trait LogicFormatter {
                 write!(w, ", ")?;
             }
             if self.use_full_names() {
-                arg.write_to_full(w, registry, interner)?;
+                arg.write_to_full(&mut w, registry, interner)?;
             } else {
-                arg.write_to(w, registry, interner)?;
+                arg.write_to(&mut w, registry, interner)?;
             }
         }
         write!(w, ")")
@@ -527,9 +534,9 @@ impl LogicFormatter for RustFormatter {
     }
 
     // Override comparative for Rust: map adjectives to comparison operators
-    fn write_comparative<W: Write>(
+    fn write_comparative(
         &self,
-        w: &mut W,
+        w: &mut dyn Write,
         adjective: &str,
         subject: &str,
         object: &str,
@@ -567,9 +574,9 @@ impl LogicFormatter for RustFormatter {
     }
 
     // Core predicate mapping: semantic interpretation of predicates to Rust operators
-    fn write_predicate<W: Write>(
+    fn write_predicate(
         &self,
-        w: &mut W,
+        w: &mut dyn Write,
         name: &str,
         args: &[Term],
         _registry: &mut SymbolRegistry,
@@ -617,6 +624,92 @@ impl LogicFormatter for RustFormatter {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════
+// Custom Formatters
+// ═══════════════════════════════════════════════════════════════════
+
+/// A formatter shared between compiles, as the registry hands it out.
+pub type SharedFormatter = Arc<dyn LogicFormatter + Send + Sync>;
+
+static CUSTOM_FORMATTERS: RwLock<Vec<(&'static str, SharedFormatter)>> = RwLock::new(Vec::new());
+
+/// Make `formatter` available as [`OutputFormat::Custom`]`(name)`, returning
+/// that format. Registering a name again replaces the earlier formatter.
+///
+/// # Panics
+///
+/// If `name` is one of the built-in format names (see [`OutputFormat::from_name`]).
+pub fn register_formatter(name: &'static str, formatter: impl LogicFormatter + Send + Sync + 'static) -> OutputFormat {
+    assert!(
+        OutputFormat::builtin(name).is_none(),
+        "`{name}` is a built-in output format and cannot be re-registered"
+    );
+    let mut formatters = CUSTOM_FORMATTERS.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let formatter: SharedFormatter = Arc::new(formatter);
+    match formatters.iter_mut().find(|(registered, _)| *registered == name) {
+        Some(slot) => slot.1 = formatter,
+        None => formatters.push((name, formatter)),
+    }
+    OutputFormat::Custom(name)
+}
+
+/// The formatter registered under `name`, if any.
+pub fn custom_formatter(name: &str) -> Option<SharedFormatter> {
+    let formatters = CUSTOM_FORMATTERS.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+    formatters.iter().find(|(registered, _)| *registered == name).map(|(_, formatter)| Arc::clone(formatter))
+}
+
+/// The names of the registered custom formatters, in registration order.
+pub fn custom_formatter_names() -> Vec<&'static str> {
+    let formatters = CUSTOM_FORMATTERS.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+    formatters.iter().map(|(name, _)| *name).collect()
+}
+
+impl OutputFormat {
+    /// The built-in formats by name, with their accepted aliases.
+    const BUILTIN: &'static [(&'static str, OutputFormat)] = &[
+        ("unicode", OutputFormat::Unicode),
+        ("latex", OutputFormat::LaTeX),
+        ("ascii", OutputFormat::SimpleFOL),
+        ("simple", OutputFormat::SimpleFOL),
+        ("kripke", OutputFormat::Kripke),
+    ];
+
+    fn builtin(name: &str) -> Option<OutputFormat> {
+        Self::BUILTIN
+            .iter()
+            .find(|(builtin, _)| builtin.eq_ignore_ascii_case(name))
+            .map(|(_, format)| *format)
+    }
+
+    /// The format called `name`: a built-in (`unicode`, `latex`, `ascii` or
+    /// `simple`, `kripke`; any case) or a registered custom formatter.
+    pub fn from_name(name: &str) -> Option<OutputFormat> {
+        Self::builtin(name).or_else(|| {
+            let formatters = CUSTOM_FORMATTERS.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+            formatters.iter().find(|(registered, _)| *registered == name).map(|(name, _)| OutputFormat::Custom(name))
+        })
+    }
+
+    /// The format's canonical name, as [`from_name`](Self::from_name) accepts it.
+    pub fn name(self) -> &'static str {
+        match self {
+            OutputFormat::Unicode => "unicode",
+            OutputFormat::LaTeX => "latex",
+            OutputFormat::SimpleFOL => "ascii",
+            OutputFormat::Kripke => "kripke",
+            OutputFormat::Custom(name) => name,
+        }
+    }
+
+    /// Every name [`from_name`](Self::from_name) currently accepts, built-ins first.
+    pub fn names() -> Vec<&'static str> {
+        let mut names: Vec<&'static str> = Self::BUILTIN.iter().map(|(name, _)| *name).collect();
+        names.extend(custom_formatter_names());
+        names
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -720,4 +813,100 @@ mod tests {
         assert_eq!(f.universal(), "/* ∀ */");
         assert_eq!(f.existential(), "/* ∃ */");
     }
+
+    // Custom formatter registry tests
+
+    /// Prover9 syntax, as an external crate might add it.
+    struct Prover9Formatter;
+
+    impl LogicFormatter for Prover9Formatter {
+        fn quantifier(&self, kind: &QuantifierKind, var: &str, body: &str) -> String {
+            match kind {
+                QuantifierKind::Universal => format!("(all {} {})", var, body),
+                _ => format!("(exists {} {})", var, body),
+            }
+        }
+        fn universal(&self) -> String { "all ".to_string() }
+        fn existential(&self) -> String { "exists ".to_string() }
+        fn cardinal(&self, _: u32) -> String { "exists ".to_string() }
+        fn at_least(&self, _: u32) -> String { "exists ".to_string() }
+        fn at_most(&self, _: u32) -> String { "exists ".to_string() }
+
+        fn and(&self) -> &'static str { "&" }
+        fn or(&self) -> &'static str { "|" }
+        fn implies(&self) -> &'static str { "->" }
+        fn iff(&self) -> &'static str { "<->" }
+        fn not(&self) -> &'static str { "-" }
+
+        fn necessity(&self) -> &'static str { "Nec" }
+        fn possibility(&self) -> &'static str { "Pos" }
+        fn past(&self) -> &'static str { "Past" }
+        fn future(&self) -> &'static str { "Future" }
+        fn progressive(&self) -> &'static str { "Prog" }
+        fn perfect(&self) -> &'static str { "Perf" }
+        fn habitual(&self) -> &'static str { "Hab" }
+        fn iterative(&self) -> &'static str { "Iter" }
+        fn passive(&self) -> &'static str { "Pass" }
+
+        fn lambda(&self, var: &str, body: &str) -> String { format!("(\\{} {})", var, body) }
+        fn counterfactual(&self, a: &str, c: &str) -> String { format!("({} -> {})", a, c) }
+        fn superlative(&self, comp: &str, _: &str, subject: &str) -> String { format!("Most{}({})", comp, subject) }
+
+        fn categorical_all(&self) -> &'static str { "all" }
+        fn categorical_no(&self) -> &'static str { "no" }
+        fn categorical_some(&self) -> &'static str { "some" }
+        fn categorical_not(&self) -> &'static str { "not" }
+
+        fn use_full_names(&self) -> bool { true }
+    }
+
+    #[test]
+    fn formatters_are_object_safe() {
+        let formatters: [&dyn LogicFormatter; 3] = [&UnicodeFormatter, &LatexFormatter, &Prover9Formatter];
+        let rendered: Vec<String> = formatters.iter().map(|f| f.binary_op(&TokenType::And, "P", "Q")).collect();
+        assert_eq!(rendered, ["(P ∧ Q)", "(P \\cdot Q)", "(P & Q)"]);
+    }
+
+    #[test]
+    fn registered_formatters_are_found_by_name() {
+        let format = register_formatter("prover9-lookup", Prover9Formatter);
+        assert_eq!(format, OutputFormat::Custom("prover9-lookup"));
+        assert_eq!(OutputFormat::from_name("prover9-lookup"), Some(format));
+        assert_eq!(format.name(), "prover9-lookup");
+        assert!(OutputFormat::names().contains(&"prover9-lookup"));
+        assert!(custom_formatter("prover9-lookup").is_some());
+        assert_eq!(OutputFormat::from_name("isabelle-unregistered"), None);
+    }
+
+    #[test]
+    fn builtin_names_resolve_in_any_case() {
+        for format in [OutputFormat::Unicode, OutputFormat::LaTeX, OutputFormat::SimpleFOL, OutputFormat::Kripke] {
+            assert_eq!(OutputFormat::from_name(format.name()), Some(format));
+        }
+        assert_eq!(OutputFormat::from_name("LaTeX"), Some(OutputFormat::LaTeX));
+        assert_eq!(OutputFormat::from_name("simple"), Some(OutputFormat::SimpleFOL));
+    }
+
+    #[test]
+    #[should_panic(expected = "built-in output format")]
+    fn builtin_names_cannot_be_registered() {
+        register_formatter("Unicode", Prover9Formatter);
+    }
+
+    #[test]
+    fn custom_formats_drive_compilation() {
+        let format = register_formatter("prover9-compile", Prover9Formatter);
+        let options = crate::CompileOptions { format, ..Default::default() };
+        let fol = crate::compile::compile_with_options("Every cat sleeps.", options).unwrap();
+        assert!(fol.starts_with("(all x"), "{fol}");
+        assert!(fol.contains("->"), "{fol}");
+        assert!(!fol.contains('∀'), "{fol}");
+    }
+
+    #[test]
+    fn unregistered_custom_formats_fall_back_to_unicode() {
+        let options = crate::CompileOptions { format: OutputFormat::Custom("never-registered"), ..Default::default() };
+        let fol = crate::compile::compile_with_options("Every cat sleeps.", options).unwrap();
+        assert_eq!(fol, crate::compile::compile("Every cat sleeps.").unwrap());
+    }
 }
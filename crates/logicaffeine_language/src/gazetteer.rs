@@ -0,0 +1,251 @@
+//! Gazetteer — multi-word proper names as single tokens.
+//!
+//! The lexer sees "Rio de Janeiro" as a name, a stray noun "de", and another
+//! name, and the parse falls apart. The parser does compound adjacent
+//! capitalized names ("Marie Curie" → `Marie_Curie`), but only when nothing
+//! sits between them, and it knows nothing about what a name denotes.
+//!
+//! The gazetteer pass runs at the end of [`Lexer::tokenize`](crate::Lexer::tokenize)
+//! and collapses two kinds of multi-word name into one
+//! [`TokenType::ProperName`]:
+//!
+//! - **Listed names**, from a user-supplied [`Gazetteer`]. They may carry a
+//!   [`Sort`], which the parser's sort checks (metaphor detection) use just
+//!   like a lexicon sort.
+//! - **Particle names**: capitalized names joined by lowercase name particles,
+//!   such as "Rio de Janeiro", "Leonardo da Vinci" or "Ludwig van Beethoven".
+//!   These are joined heuristically with no list needed.
+//!
+//! A joined name's symbol is its words separated by `_`. That is the same
+//! symbol the parser's own compounding produces, so "Marie Curie" means
+//! `Marie_Curie` whether it is listed or not.
+//!
+//! Imperative blocks (`## Main`, `## To …`) are left untouched.
+//!
+//! ```rust
+//! use logicaffeine_language::gazetteer::Gazetteer;
+//! use logicaffeine_language::lexicon::Sort;
+//!
+//! let gazetteer = Gazetteer::parse("# people\nMarie Curie: Human\nThe Hague: Place\n").unwrap();
+//! assert_eq!(gazetteer.sort_of("Marie_Curie"), Some(Sort::Human));
+//! ```
+
+use logicaffeine_base::Interner;
+
+use crate::lexicon::Sort;
+use crate::token::{BlockType, Span, Token, TokenType};
+
+/// Lowercase words that can sit between the capitalized parts of a name.
+const NAME_PARTICLES: &[&str] = &[
+    "al", "bin", "da", "das", "de", "del", "della", "der", "di", "do", "dos", "du", "ibn", "la",
+    "le", "van", "von", "y",
+];
+
+/// One listed name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GazetteerEntry {
+    /// The name as listed ("Marie Curie").
+    pub name: String,
+    pub sort: Option<Sort>,
+    /// The name's words, lowercased, for matching.
+    words: Vec<String>,
+}
+
+impl GazetteerEntry {
+    /// The symbol the name lexes to: its words joined by `_`.
+    pub fn symbol(&self) -> String {
+        joined(self.name.split_whitespace())
+    }
+}
+
+/// A list of known proper names.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Gazetteer {
+    entries: Vec<GazetteerEntry>,
+}
+
+impl Gazetteer {
+    /// An empty gazetteer: only particle names are joined.
+    pub const fn new() -> Self {
+        Gazetteer { entries: Vec::new() }
+    }
+
+    /// Adds a name. Names of one word are accepted but never need joining,
+    /// so only their sort matters.
+    pub fn insert(&mut self, name: &str, sort: Option<Sort>) {
+        let words: Vec<String> = name.split_whitespace().map(str::to_lowercase).collect();
+        if words.is_empty() {
+            return;
+        }
+        self.entries.retain(|e| e.words != words);
+        self.entries.push(GazetteerEntry { name: words_of(name), sort, words });
+    }
+
+    /// [`Gazetteer::insert`] in builder form.
+    pub fn with(mut self, name: &str, sort: Option<Sort>) -> Self {
+        self.insert(name, sort);
+        self
+    }
+
+    /// Parses a name list: one name per line, optionally followed by
+    /// `: Sort` (`Human`, `Place`, … as in [`Sort`]). Blank lines and
+    /// `#` comments are skipped.
+    pub fn parse(list: &str) -> Result<Self, String> {
+        let mut gazetteer = Gazetteer::new();
+        for (n, line) in list.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (name, sort) = match line.rsplit_once(':') {
+                Some((name, sort)) => {
+                    let sort = parse_sort(sort.trim())
+                        .ok_or_else(|| format!("line {}: unknown sort '{}'", n + 1, sort.trim()))?;
+                    (name, Some(sort))
+                }
+                None => (line, None),
+            };
+            gazetteer.insert(name, sort);
+        }
+        Ok(gazetteer)
+    }
+
+    pub fn entries(&self) -> &[GazetteerEntry] {
+        &self.entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The sort of a listed name, looked up by its symbol (`Marie_Curie`).
+    pub fn sort_of(&self, symbol: &str) -> Option<Sort> {
+        self.entries.iter().find(|e| e.symbol() == symbol).and_then(|e| e.sort)
+    }
+
+    /// The longest listed name of two or more words starting at `words[0]`,
+    /// with the number of words it spans.
+    fn longest_match(&self, words: &[String]) -> Option<(usize, &GazetteerEntry)> {
+        self.entries
+            .iter()
+            .filter(|e| e.words.len() > 1 && words.len() >= e.words.len())
+            .filter(|e| words[..e.words.len()].iter().zip(&e.words).all(|(w, e)| w.to_lowercase() == *e))
+            .max_by_key(|e| e.words.len())
+            .map(|e| (e.words.len(), e))
+    }
+}
+
+/// The gazetteer used when none is supplied.
+pub(crate) static EMPTY: Gazetteer = Gazetteer::new();
+
+pub(crate) fn parse_sort(name: &str) -> Option<Sort> {
+    const SORTS: [Sort; 14] = [
+        Sort::Entity, Sort::Physical, Sort::Animate, Sort::Human, Sort::Plant, Sort::Place,
+        Sort::Time, Sort::Abstract, Sort::Information, Sort::Event, Sort::Celestial,
+        Sort::Value, Sort::Signal, Sort::Group,
+    ];
+    SORTS.into_iter().find(|s| format!("{:?}", s).eq_ignore_ascii_case(name))
+}
+
+fn words_of(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn joined<'w>(words: impl Iterator<Item = &'w str>) -> String {
+    words.collect::<Vec<_>>().join("_")
+}
+
+fn is_capitalized(word: &str) -> bool {
+    word.chars().next().is_some_and(char::is_uppercase)
+}
+
+/// Collapses multi-word proper names in a token stream. See the module docs.
+pub(crate) fn join_proper_names(tokens: Vec<Token>, gazetteer: &Gazetteer, interner: &mut Interner) -> Vec<Token> {
+    let words: Vec<String> = tokens.iter().map(|t| interner.resolve(t.lexeme).to_string()).collect();
+    let mut result = Vec::with_capacity(tokens.len());
+    let mut imperative = false;
+    let mut i = 0;
+    while i < tokens.len() {
+        if let TokenType::BlockHeader { block_type } = &tokens[i].kind {
+            imperative = matches!(block_type, BlockType::Main | BlockType::Function);
+        }
+        let span = if imperative || !is_capitalized(&words[i]) {
+            None
+        } else {
+            gazetteer
+                .longest_match(&words[i..])
+                .map(|(len, entry)| (len, entry.symbol()))
+                .or_else(|| particle_name(&tokens[i..], &words[i..]))
+        };
+        match span {
+            Some((len, name)) => {
+                let sym = interner.intern(&name);
+                let span = Span::new(tokens[i].span.start, tokens[i + len - 1].span.end);
+                result.push(Token::new(TokenType::ProperName(sym), sym, span));
+                i += len;
+            }
+            None => {
+                result.push(tokens[i].clone());
+                i += 1;
+            }
+        }
+    }
+    result
+}
+
+/// A proper name extended by one or more `particle… Name` groups, as the
+/// number of tokens it spans and its joined symbol.
+fn particle_name(tokens: &[Token], words: &[String]) -> Option<(usize, String)> {
+    let is_name = |i: usize| matches!(tokens.get(i).map(|t| &t.kind), Some(TokenType::ProperName(_)));
+    let is_particle = |i: usize| words.get(i).is_some_and(|w| NAME_PARTICLES.contains(&w.as_str()));
+    if !is_name(0) {
+        return None;
+    }
+    let mut end = 1;
+    loop {
+        let mut next = end;
+        while is_particle(next) {
+            next += 1;
+        }
+        if next == end || !is_name(next) {
+            break;
+        }
+        end = next + 1;
+    }
+    (end > 1).then(|| (end, joined(words[..end].iter().map(String::as_str))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_names_sorts_and_comments() {
+        let gazetteer = Gazetteer::parse("Marie Curie : human\n\n# comment\nNew   York\n").unwrap();
+        let names: Vec<_> = gazetteer.entries().iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["Marie Curie", "New York"]);
+        assert_eq!(gazetteer.sort_of("Marie_Curie"), Some(Sort::Human));
+        assert_eq!(gazetteer.sort_of("New_York"), None);
+    }
+
+    #[test]
+    fn parse_rejects_unknown_sorts() {
+        let err = Gazetteer::parse("Rex: Dog").unwrap_err();
+        assert!(err.contains("line 1") && err.contains("Dog"), "{err}");
+    }
+
+    #[test]
+    fn reinserting_a_name_replaces_it() {
+        let gazetteer = Gazetteer::new().with("Ada Lovelace", None).with("ada lovelace", Some(Sort::Human));
+        assert_eq!(gazetteer.entries().len(), 1);
+        assert_eq!(gazetteer.sort_of("ada_lovelace"), Some(Sort::Human));
+    }
+
+    #[test]
+    fn the_longest_listed_name_wins() {
+        let gazetteer = Gazetteer::new().with("New York", None).with("New York City", None);
+        let words: Vec<String> = ["New", "York", "City", "is"].iter().map(|w| w.to_string()).collect();
+        let (len, entry) = gazetteer.longest_match(&words).unwrap();
+        assert_eq!((len, entry.symbol().as_str()), (3, "New_York_City"));
+    }
+}
@@ -37,7 +37,7 @@
 
 use logicaffeine_base::Arena;
 use crate::ast::{LogicExpr, QuantifierKind, Term};
-use logicaffeine_base::{Interner, Symbol};
+use logicaffeine_base::{CancellationToken, Interner, Symbol};
 use crate::lexicon;
 use crate::token::TokenType;
 
@@ -331,9 +331,22 @@ pub struct ScopeIterator<'a> {
     total: u64,
     single_result: Option<&'a LogicExpr<'a>>,
     returned_single: bool,
+    cancel: Option<CancellationToken>,
 }
 
 impl<'a> ScopeIterator<'a> {
+    /// Stop yielding readings once `cancel` fires. A sentence with many
+    /// quantifiers in one island has factorially many scopings; the token lets
+    /// a superseded request walk away between two of them.
+    pub fn with_cancellation(mut self, cancel: &CancellationToken) -> Self {
+        self.cancel = Some(cancel.clone());
+        self
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancel.as_ref().is_some_and(|c| c.is_cancelled())
+    }
+
     fn nth_island_aware_permutation(&self, n: u64) -> Vec<ScopalElement<'a>> {
         let mut result = Vec::new();
         let mut remainder = n;
@@ -370,6 +383,9 @@ impl<'a> Iterator for ScopeIterator<'a> {
     type Item = &'a LogicExpr<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.is_cancelled() {
+            return None;
+        }
         if let Some(single) = self.single_result {
             if self.returned_single {
                 return None;
@@ -387,6 +403,9 @@ impl<'a> Iterator for ScopeIterator<'a> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.is_cancelled() {
+            return (0, Some(0));
+        }
         if self.single_result.is_some() {
             let remaining = if self.returned_single { 0 } else { 1 };
             return (remaining, Some(remaining));
@@ -450,6 +469,7 @@ pub fn enumerate_scopings<'a>(
             total: 0,
             single_result: Some(expr),
             returned_single: false,
+            cancel: None,
         };
     }
 
@@ -464,6 +484,7 @@ pub fn enumerate_scopings<'a>(
         total,
         single_result: None,
         returned_single: false,
+        cancel: None,
     }
 }
 
@@ -34,8 +34,10 @@
 //! Output: [Quantifier("every"), Noun("cat"), Verb("sleeps"), Period]
 //! ```
 
-use logicaffeine_base::Interner;
+use logicaffeine_base::{CancellationToken, Interner};
+use crate::gazetteer::{self, Gazetteer};
 use crate::lexicon::{self, Aspect, Definiteness, Lexicon, Time};
+use crate::numerals;
 use crate::token::{BlockType, CalendarUnit, FocusKind, MeasureKind, Span, Token, TokenType};
 
 // ============================================================================
@@ -342,6 +344,10 @@ pub struct Lexer<'a> {
     source: String,
     /// Escape block body byte ranges: (skip_start, skip_end) for filtering LineLexer events
     escape_body_ranges: Vec<(usize, usize)>,
+    /// Known multi-word proper names, joined into single tokens.
+    gazetteer: &'a Gazetteer,
+    /// Polled once per word; a cancelled token ends the stream early.
+    cancel: Option<CancellationToken>,
 }
 
 struct WordItem {
@@ -394,9 +400,26 @@ impl<'a> Lexer<'a> {
             mode: LexerMode::Declarative,
             source: input.to_string(),
             escape_body_ranges,
+            gazetteer: &gazetteer::EMPTY,
+            cancel: None,
         }
     }
 
+    /// Joins the names in `gazetteer` into single proper-name tokens (see
+    /// [`crate::gazetteer`]). Names joined by lowercase particles ("Rio de
+    /// Janeiro") are joined even without one.
+    pub fn with_gazetteer(mut self, gazetteer: &'a Gazetteer) -> Self {
+        self.gazetteer = gazetteer;
+        self
+    }
+
+    /// Stops tokenizing once `cancel` is cancelled, returning a bare EOF — the
+    /// caller checks the token before trusting the stream.
+    pub fn with_cancellation(mut self, cancel: &CancellationToken) -> Self {
+        self.cancel = Some(cancel.clone());
+        self
+    }
+
     /// Pre-scan source text for escape block bodies.
     /// Returns (skip_start_byte, skip_end_byte, content_start_byte, raw_code) tuples.
     /// `skip_start` is the line start (for byte skipping in split_into_words).
@@ -1368,7 +1391,37 @@ impl<'a> Lexer<'a> {
             });
         }
 
-        items
+        Self::expand_universal_pronouns(items)
+    }
+
+    /// Replaces a universal pronoun with the quantified phrase it abbreviates
+    /// ("everyone" → "every person"), so it scopes like any "every" NP. Both
+    /// words keep the pronoun's span; punctuation stays on the last.
+    fn expand_universal_pronouns(items: Vec<WordItem>) -> Vec<WordItem> {
+        let mut out = Vec::with_capacity(items.len());
+        for item in items {
+            let Some(expansion) = crate::lexicon::lookup_universal_pronoun(&item.word.to_lowercase()) else {
+                out.push(item);
+                continue;
+            };
+            let (quantifier, noun) = expansion.split_once(' ').unwrap_or((expansion, ""));
+            let capitalized = item.word.starts_with(char::is_uppercase);
+            out.push(WordItem {
+                word: if capitalized {
+                    let mut q = quantifier.to_string();
+                    q[..1].make_ascii_uppercase();
+                    q
+                } else {
+                    quantifier.to_string()
+                },
+                trailing_punct: None,
+                start: item.start,
+                end: item.end,
+                punct_pos: None,
+            });
+            out.push(WordItem { word: noun.to_string(), ..item });
+        }
+        out
     }
 
     fn peek_word(&self, offset: usize) -> Option<&str> {
@@ -1450,6 +1503,10 @@ impl<'a> Lexer<'a> {
         let mut tokens = Vec::new();
 
         while self.pos < self.words.len() {
+            if self.cancel.as_ref().is_some_and(CancellationToken::is_cancelled) {
+                let eof_lexeme = self.interner.intern("");
+                return vec![Token::new(TokenType::EOF, eof_lexeme, Span::new(self.input_len, self.input_len))];
+            }
             let item = &self.words[self.pos];
             let word = item.word.clone();
             let trailing_punct = item.trailing_punct;
@@ -1694,6 +1751,9 @@ impl<'a> Lexer<'a> {
             self.pos += 1;
         }
 
+        let tokens = numerals::normalize(tokens, self.interner);
+        let mut tokens = gazetteer::join_proper_names(tokens, self.gazetteer, self.interner);
+
         let eof_lexeme = self.interner.intern("");
         let eof_span = Span::new(self.input_len, self.input_len);
         tokens.push(Token::new(TokenType::EOF, eof_lexeme, eof_span));
@@ -1931,7 +1991,8 @@ impl<'a> Lexer<'a> {
     }
 
     fn word_to_number(word: &str) -> Option<u32> {
-        lexicon::word_to_number(&word.to_lowercase())
+        let lower = word.to_lowercase();
+        lexicon::word_to_number(&lower).or_else(|| numerals::parse_cardinal(&lower))
     }
 
     /// Check if a hyphen at the current position is part of an ISO-8601 date.
@@ -1980,7 +2041,7 @@ impl<'a> Lexer<'a> {
         false
     }
 
-    /// Check if a colon is part of a time literal (e.g., 9:30am, 11:45pm).
+    /// Check if a colon is part of a time literal (e.g., 9:30am, 11:45pm, 14:30).
     ///
     /// Detects patterns like:
     /// - "9:" followed by "30am" or "30pm"
@@ -1995,6 +2056,20 @@ impl<'a> Lexer<'a> {
             return false;
         }
 
+        // 24-hour clock: a free-standing two-digit hour and two digits of
+        // minutes ("at 14:30.", "09:05"). Bracketed ranges ("[10:15]") are not
+        // times.
+        let stands_alone = |c: Option<&char>| c.map_or(true, |c| c.is_whitespace() || ".,;!?".contains(*c));
+        if word_chars.len() == 2
+            && current_word.parse::<u32>().is_ok_and(|h| h <= 23)
+            && chars.get(char_idx + 1).is_some_and(|c| ('0'..='5').contains(c))
+            && chars.get(char_idx + 2).is_some_and(|c| c.is_ascii_digit())
+            && stands_alone(chars.get(char_idx + 3))
+            && stands_alone(char_idx.checked_sub(3).and_then(|p| chars.get(p)))
+        {
+            return true;
+        }
+
         // Followed by exactly 2 digits (the minutes), then "am"/"pm" — which may
         // come immediately ("9:30am") or after a space ("8:15 pm").
         if char_idx + 3 < chars.len()
@@ -2209,34 +2284,9 @@ impl<'a> Lexer<'a> {
         let month: u32 = word[5..7].parse().ok()?;
         let day: u32 = word[8..10].parse().ok()?;
 
-        // Reject calendar-impossible dates (e.g. 2026-02-30, 2026-04-31): the
-        // Howard Hinnant day count below would otherwise silently map them onto
-        // a real but DIFFERENT day.
-        if month < 1 || month > 12 || day < 1 {
-            return None;
-        }
-        let is_leap = (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0);
-        let max_day = match month {
-            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-            4 | 6 | 9 | 11 => 30,
-            2 => if is_leap { 29 } else { 28 },
-            _ => return None,
-        };
-        if day > max_day {
-            return None;
-        }
-
-        // Convert to days since Unix epoch using Howard Hinnant's algorithm
-        // https://howardhinnant.github.io/date_algorithms.html
-        let y = if month <= 2 { year - 1 } else { year };
-        let era = if y >= 0 { y / 400 } else { (y - 399) / 400 };
-        let yoe = (y - era * 400) as u32;
-        let m = month;
-        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day - 1;
-        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
-        let days = era * 146097 + doe as i32 - 719468;
-
-        Some(days)
+        // Calendar-impossible dates (e.g. 2026-02-30, 2026-04-31) are rejected
+        // rather than silently mapped onto a real but DIFFERENT day.
+        numerals::days_from_civil(year, month, day)
     }
 
     /// Parse a time-of-day literal.
@@ -2244,6 +2294,7 @@ impl<'a> Lexer<'a> {
     /// Supported formats:
     /// - 12-hour with am/pm: "4pm", "9am", "12pm"
     /// - 12-hour with minutes: "9:30am", "11:45pm"
+    /// - 24-hour: "14:30", "09:05"
     /// - Special words: "noon" (12:00), "midnight" (00:00)
     ///
     /// Returns Some(nanos_from_midnight) if valid, None otherwise.
@@ -2263,7 +2314,7 @@ impl<'a> Lexer<'a> {
         let is_am = lower.ends_with("am");
 
         if !is_pm && !is_am {
-            return None;
+            return Self::parse_24_hour_time(&lower);
         }
 
         // Strip the am/pm suffix
@@ -2299,6 +2350,20 @@ impl<'a> Lexer<'a> {
         Some(nanos)
     }
 
+    /// A 24-hour clock time, "HH:MM" with a two-digit hour: "14:30", "09:05".
+    fn parse_24_hour_time(word: &str) -> Option<i64> {
+        let (hour, minute) = word.split_once(':')?;
+        if hour.len() != 2 || minute.len() != 2 {
+            return None;
+        }
+        let hour: i64 = hour.parse().ok()?;
+        let minute: i64 = minute.parse().ok()?;
+        if hour > 23 || minute > 59 {
+            return None;
+        }
+        Some((hour * 3600 + minute * 60) * 1_000_000_000)
+    }
+
     /// True for the currency symbols that prefix a money literal (`$ € £ ¥`).
     fn is_currency_symbol(c: char) -> bool {
         matches!(c, '$' | '€' | '£' | '¥')
@@ -2354,6 +2419,8 @@ impl<'a> Lexer<'a> {
                 "a" | "an" => BlockType::TypeDef,  // Inline type definitions: ## A Point has:
                 "policy" => BlockType::Policy,  // Security policy definitions
                 "requires" => BlockType::Requires,  // External crate dependencies
+                "settings" => BlockType::Settings,  // Typed configuration schema
+                "arguments" => BlockType::Arguments,  // Command-line argument declarations
                 "hardware" => BlockType::Hardware,  // Signal declarations
                 "property" => BlockType::Property,  // Temporal assertions
                 "no" => BlockType::No,  // Optimization annotation: ## No Memo, ## No TCO, etc.
@@ -2367,8 +2434,8 @@ impl<'a> Lexer<'a> {
                     // (`## Notes`, `## Design`) keep working.
                     const CONSEQUENTIAL: &[&str] = &[
                         "main", "theorem", "definition", "define", "axiom",
-                        "theory", "proof", "policy", "requires", "hardware",
-                        "property", "tier",
+                        "theory", "proof", "policy", "requires", "settings",
+                        "arguments", "hardware", "property", "tier",
                     ];
                     if let Some(similar) =
                         crate::suggest::find_similar(other, CONSEQUENTIAL, 2)
@@ -3082,7 +3149,10 @@ impl<'a> Lexer<'a> {
                         self.is_noun_like(&next_lower) || self.is_adjective_like(&next_lower)
                     })
                     .unwrap_or(false);
-            if !attributive && !lexicon::is_preposition(&lower) {
+            if !attributive
+                && !lexicon::is_preposition(&lower)
+                && !lexicon::is_disambiguation_not_verb(&lower)
+            {
                 if let Some(entry) = self.lexicon.lookup_verb(&lower) {
                     let sym = self.interner.intern(&entry.lemma);
                     return TokenType::Verb {
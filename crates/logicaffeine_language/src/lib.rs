@@ -2,7 +2,7 @@
 #![doc = include_str!("../README.md")]
 
 // Re-export base types for internal use and consumers
-pub use logicaffeine_base::{Arena, Interner, Symbol, SymbolEq, Span as BaseSpan};
+pub use logicaffeine_base::{Arena, Interner, Symbol, SymbolEq, Span as BaseSpan, CancellationToken, Cancelled};
 
 // Provide an `intern` module alias for internal code that uses `crate::intern::*`
 pub mod intern {
@@ -39,16 +39,24 @@ pub mod compile;
 pub mod analysis;
 pub mod arena_ctx;
 pub mod ast_depth;
+pub mod coverage;
 pub mod formatter;
+pub mod gazetteer;
+pub mod metrics;
 pub mod mwe;
+pub mod numerals;
 pub mod ontology;
 pub mod pragmatics;
+pub mod ranking;
 pub mod registry;
 pub mod scope;
+pub mod serial;
 pub mod session;
 pub mod source_format;
 pub mod suggest;
 pub mod symbol_dict;
+pub mod tense;
+pub mod trace;
 pub mod view;
 pub mod visitor;
 pub mod debug;
@@ -64,30 +72,39 @@ pub use proof_convert::{logic_expr_to_proof_expr, term_to_proof_term};
 // Re-export key types at crate root
 pub use token::{BlockType, FocusKind, MeasureKind, PresupKind, Span, Token, TokenType};
 pub use lexer::{Lexer, LineLexer, LineToken};
-pub use parser::{Parser, ParserMode, NegativeScopeMode, QuantifierParsing};
+pub use parser::{AntecedentAmbiguity, Parser, ParserMode, NegativeScopeMode, ModalPreference, QuantifierParsing};
 pub use error::{ParseError, ParseErrorKind, socratic_explanation};
 pub use drs::{Drs, BoxType, WorldState, Gender, Number, Case};
 pub use analysis::TypeRegistry;
 pub use registry::SymbolRegistry;
 pub use arena_ctx::AstContext;
-pub use session::Session;
+pub use session::{DisambiguationPolicy, Session, SessionSnapshot, SnapshotError};
+pub use gazetteer::Gazetteer;
 
 // Compile API re-exports
 pub use compile::{
-    compile, compile_pragmatic, compile_simple, compile_kripke, compile_kripke_with, compile_with_options,
+    compile, compile_cancellable, compile_pragmatic, compile_simple, compile_kripke, compile_kripke_with, compile_with_options,
     compile_with_world_state, compile_with_world_state_options,
     compile_with_discourse, compile_with_world_state_interner_options,
-    compile_all_scopes, compile_all_scopes_with_options,
+    compile_all_scopes, compile_all_scopes_with_options, compile_all_scopes_cancellable,
     compile_forest, compile_forest_with_options, MAX_FOREST_READINGS,
+    compile_forest_ranked, compile_forest_ranked_with_options, compile_forest_ranked_cancellable,
+    RankedReading, IdiomReading,
     compile_discourse, compile_discourse_with_options,
     compile_ambiguous, compile_ambiguous_with_options,
-    compile_theorem,
+    compile_theorem, export_theorems, check_exported_proofs, compile_with_trace, compile_with_metrics, compile_with_gazetteer,
+    compile_with_modes, ParserModes, ModesReading,
+    Pass, PassContext, PassRegistry,
 };
 
 // Runtime lexicon re-export (when dynamic-lexicon feature is enabled)
 #[cfg(feature = "dynamic-lexicon")]
 pub use logicaffeine_lexicon::runtime as runtime_lexicon;
 
+// Sentence generation over the runtime lexicon
+#[cfg(feature = "dynamic-lexicon")]
+pub mod test_utils;
+
 // Output format configuration
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum OutputFormat {
@@ -97,6 +114,9 @@ pub enum OutputFormat {
     SimpleFOL,
     /// Kripke semantics output: modals lowered to explicit world quantification.
     Kripke,
+    /// A formatter registered under this name with
+    /// [`formatter::register_formatter`]. An unregistered name renders as Unicode.
+    Custom(&'static str),
 }
 
 // Transpile context
@@ -118,6 +138,13 @@ pub struct CompileOptions {
     /// scalar "some" is strengthened to `∃… +> ¬∀…`. Off by default so the literal
     /// truth-conditional output is unchanged.
     pub pragmatic: bool,
+    /// Scope of "not" against quantifiers, modals and neg-raising verbs.
+    /// `compile_forest` still enumerates the other scopings as alternative
+    /// readings.
+    pub negative_scope: NegativeScopeMode,
+    /// Custom passes run between the built-in semantics and transpilation
+    /// (see [`compile::Pass`]). None by default.
+    pub passes: &'static PassRegistry,
 }
 
 impl Default for CompileOptions {
@@ -125,6 +152,8 @@ impl Default for CompileOptions {
         CompileOptions {
             format: OutputFormat::Unicode,
             pragmatic: false,
+            negative_scope: NegativeScopeMode::Surface,
+            passes: &compile::NO_PASSES,
         }
     }
 }
@@ -0,0 +1,228 @@
+//! Compile-phase timing — where did the time go?
+//!
+//! A [`CompileMetrics`] records, for each phase of the pipeline, how long it
+//! took and how much it produced: tokens out of the lexer and the multi-word
+//! expression pass, types found by discovery, statements (or formulas) out of
+//! the parser, semantic passes run, and bytes of transpiled output. The LSP
+//! and `largo` report any phase slower than [`CompileMetrics::SLOW_PHASE`]
+//! so a pathological input shows up without reaching for an external
+//! profiler.
+//!
+//! `std::time::Instant` is unavailable on `wasm32-unknown-unknown`; there the
+//! durations read zero and only the counts are meaningful.
+//!
+//! ```rust
+//! use logicaffeine_language::{compile_with_metrics, CompileOptions};
+//! use logicaffeine_language::metrics::Phase;
+//!
+//! let (fol, metrics) = compile_with_metrics("Every man is mortal.", CompileOptions::default());
+//! assert!(fol.is_ok());
+//! assert!(metrics.phase(Phase::Lex).count > 0);
+//! ```
+
+use std::fmt;
+use std::time::Duration;
+
+/// One stage of the compile pipeline, in execution order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Lex,
+    Mwe,
+    Discovery,
+    Parse,
+    Semantics,
+    Transpile,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 6] =
+        [Phase::Lex, Phase::Mwe, Phase::Discovery, Phase::Parse, Phase::Semantics, Phase::Transpile];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Lex => "lex",
+            Phase::Mwe => "mwe",
+            Phase::Discovery => "discovery",
+            Phase::Parse => "parse",
+            Phase::Semantics => "semantics",
+            Phase::Transpile => "transpile",
+        }
+    }
+
+    /// What this phase's [`PhaseMetrics::count`] counts.
+    pub fn unit(self) -> &'static str {
+        match self {
+            Phase::Lex | Phase::Mwe => "tokens",
+            Phase::Discovery => "types",
+            Phase::Parse => "statements",
+            Phase::Semantics => "passes",
+            Phase::Transpile => "bytes",
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Time spent in one phase and how much it produced (see [`Phase::unit`]).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PhaseMetrics {
+    pub duration: Duration,
+    pub count: usize,
+}
+
+/// Per-phase durations and counts for one compile. Phases a pipeline does
+/// not run stay at zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompileMetrics {
+    phases: [PhaseMetrics; 6],
+}
+
+impl CompileMetrics {
+    /// The threshold the LSP and `largo` warn above: one phase eating more
+    /// than this is noticeable on every keystroke.
+    pub const SLOW_PHASE: Duration = Duration::from_millis(100);
+
+    pub fn phase(&self, phase: Phase) -> &PhaseMetrics {
+        &self.phases[phase as usize]
+    }
+
+    /// Add `duration` and `count` to `phase`; a phase run more than once
+    /// (per-block recovery, per-construal parses) accumulates.
+    pub fn record(&mut self, phase: Phase, duration: Duration, count: usize) {
+        let slot = &mut self.phases[phase as usize];
+        slot.duration += duration;
+        slot.count += count;
+    }
+
+    /// Every phase in pipeline order.
+    pub fn iter(&self) -> impl Iterator<Item = (Phase, &PhaseMetrics)> + '_ {
+        Phase::ALL.iter().map(move |&phase| (phase, self.phase(phase)))
+    }
+
+    pub fn total(&self) -> Duration {
+        self.phases.iter().map(|p| p.duration).sum()
+    }
+
+    /// Phases that took longer than `threshold`, slowest first.
+    pub fn slow_phases(&self, threshold: Duration) -> Vec<(Phase, PhaseMetrics)> {
+        let mut slow: Vec<(Phase, PhaseMetrics)> = self
+            .iter()
+            .filter(|(_, m)| m.duration > threshold)
+            .map(|(phase, m)| (phase, *m))
+            .collect();
+        slow.sort_by_key(|(_, m)| std::cmp::Reverse(m.duration));
+        slow
+    }
+
+    /// One warning line per phase slower than `threshold`, slowest first.
+    pub fn slow_phase_warnings(&self, threshold: Duration) -> Vec<String> {
+        self.slow_phases(threshold)
+            .into_iter()
+            .map(|(phase, m)| {
+                format!(
+                    "slow {} phase: {:.1}ms ({} {}, threshold {}ms)",
+                    phase,
+                    m.duration.as_secs_f64() * 1000.0,
+                    m.count,
+                    phase.unit(),
+                    threshold.as_millis(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// A phase table: one `phase  duration  count unit` row per phase, then the total.
+impl fmt::Display for CompileMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (phase, m) in self.iter() {
+            writeln!(
+                f,
+                "{:<10} {:>9.2}ms  {} {}",
+                phase.name(),
+                m.duration.as_secs_f64() * 1000.0,
+                m.count,
+                phase.unit(),
+            )?;
+        }
+        write!(f, "{:<10} {:>9.2}ms", "total", self.total().as_secs_f64() * 1000.0)
+    }
+}
+
+/// Measures one phase. Reads zero on wasm32, where there is no monotonic clock.
+#[derive(Debug, Clone, Copy)]
+pub struct Stopwatch {
+    #[cfg(not(target_arch = "wasm32"))]
+    started: std::time::Instant,
+}
+
+impl Stopwatch {
+    pub fn start() -> Self {
+        Stopwatch {
+            #[cfg(not(target_arch = "wasm32"))]
+            started: std::time::Instant::now(),
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.started.elapsed()
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            Duration::ZERO
+        }
+    }
+
+    /// Record the time since [`start`](Self::start) against `phase`.
+    pub fn record(self, metrics: &mut CompileMetrics, phase: Phase, count: usize) {
+        metrics.record(phase, self.elapsed(), count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn record_accumulates_per_phase() {
+        let mut metrics = CompileMetrics::default();
+        metrics.record(Phase::Parse, ms(3), 2);
+        metrics.record(Phase::Parse, ms(4), 1);
+        assert_eq!(metrics.phase(Phase::Parse), &PhaseMetrics { duration: ms(7), count: 3 });
+        assert_eq!(metrics.phase(Phase::Lex), &PhaseMetrics::default());
+        assert_eq!(metrics.total(), ms(7));
+    }
+
+    #[test]
+    fn slow_phases_are_reported_slowest_first() {
+        let mut metrics = CompileMetrics::default();
+        metrics.record(Phase::Lex, ms(150), 10);
+        metrics.record(Phase::Parse, ms(400), 3);
+        metrics.record(Phase::Transpile, ms(20), 900);
+        let slow: Vec<Phase> = metrics.slow_phases(ms(100)).into_iter().map(|(p, _)| p).collect();
+        assert_eq!(slow, vec![Phase::Parse, Phase::Lex]);
+        assert_eq!(
+            metrics.slow_phase_warnings(ms(100))[0],
+            "slow parse phase: 400.0ms (3 statements, threshold 100ms)"
+        );
+    }
+
+    #[test]
+    fn display_lists_every_phase_and_the_total() {
+        let rendered = CompileMetrics::default().to_string();
+        for phase in Phase::ALL {
+            assert!(rendered.contains(phase.name()), "{rendered}");
+        }
+        assert!(rendered.lines().last().unwrap().starts_with("total"));
+    }
+}
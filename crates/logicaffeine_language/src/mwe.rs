@@ -14,13 +14,20 @@
 //! # Supported MWE Types
 //!
 //! - **Compound nouns**: "fire engine", "ice cream"
-//! - **Phrasal verbs**: "look up", "give in"
+//! - **Phrasal verbs**: "give up", "pick up" — the `phrasal_verbs` table
+//!   collapses an adjacent verb and particle into one event predicate
+//! - **Light verbs**: "make a decision" → Decide, "take a walk" → Walk
 //! - **Fixed phrases**: "in order to", "as well as"
+//! - **Idioms**: "kick the bucket" → Die. An idiom is also grammatical
+//!   word-for-word, so [`apply_literal_mwe_pipeline`] leaves it uncollapsed
+//!   for the literal forest reading
 //!
 //! # Key Functions
 //!
 //! - [`build_mwe_trie`]: Construct the MWE lookup trie
+//! - [`shared_mwe_trie`]: The same trie, built once per process
 //! - [`apply_mwe_pipeline`]: Transform token stream by collapsing MWEs
+//! - [`contains_idiom`]: Whether the stream has a literal/idiomatic ambiguity
 
 use std::collections::HashMap;
 use crate::token::{Token, TokenType};
@@ -32,6 +39,8 @@ pub struct MweTarget {
     pub lemma: &'static str,
     pub pos: &'static str,
     pub class: Option<VerbClass>,
+    /// Non-compositional: the words also have a literal reading.
+    pub idiom: bool,
 }
 
 #[derive(Default, Debug)]
@@ -53,21 +62,58 @@ impl MweTrie {
     }
 }
 
+/// The built-in trie, built on first use and shared for the life of the
+/// process — long-lived hosts (the LSP, `largo daemon`) pay for it once.
+pub fn shared_mwe_trie() -> &'static MweTrie {
+    static TRIE: std::sync::OnceLock<MweTrie> = std::sync::OnceLock::new();
+    TRIE.get_or_init(build_mwe_trie)
+}
+
 /// Apply MWE collapsing to a token stream.
 /// Matches on lemmas (not raw strings) to handle morphological variants.
 pub fn apply_mwe_pipeline(
     tokens: Vec<Token>,
     trie: &MweTrie,
     interner: &mut Interner,
+) -> Vec<Token> {
+    collapse(tokens, trie, interner, true)
+}
+
+/// [`apply_mwe_pipeline`] with idioms read word-for-word: "kicked the
+/// bucket" stays a kicking event with a bucket as its theme.
+pub fn apply_literal_mwe_pipeline(
+    tokens: Vec<Token>,
+    trie: &MweTrie,
+    interner: &mut Interner,
+) -> Vec<Token> {
+    collapse(tokens, trie, interner, false)
+}
+
+/// Whether any idiom in the trie occurs in the token stream.
+pub fn contains_idiom(tokens: &[Token], trie: &MweTrie, interner: &Interner) -> bool {
+    (0..tokens.len()).any(|i| {
+        find_longest_match(&tokens[i..], trie, interner, true)
+            .is_some_and(|(_, target)| target.idiom)
+    })
+}
+
+fn collapse(
+    tokens: Vec<Token>,
+    trie: &MweTrie,
+    interner: &mut Interner,
+    idioms: bool,
 ) -> Vec<Token> {
     let mut result = Vec::new();
     let mut i = 0;
 
     while i < tokens.len() {
-        if let Some((match_len, target)) = find_longest_match(&tokens[i..], trie, interner) {
+        if let Some((match_len, target)) = find_longest_match(&tokens[i..], trie, interner, idioms) {
             let merged = create_merged_token(&tokens[i], target, interner);
             result.push(merged);
             i += match_len;
+        } else if let Some(merged) = merge_phrasal_verb(&tokens[i..], interner) {
+            result.push(merged);
+            i += 2;
         } else {
             result.push(tokens[i].clone());
             i += 1;
@@ -76,6 +122,37 @@ pub fn apply_mwe_pipeline(
     result
 }
 
+/// Collapse an adjacent verb + particle from the phrasal-verb table into one
+/// verb ("picked up the book" → Collect). Clause-finally the intransitive
+/// sense applies where the table lists one ("the plane took off" → Depart).
+/// A separated particle ("gave it up") is left to the verb-phrase parser.
+fn merge_phrasal_verb(tokens: &[Token], interner: &mut Interner) -> Option<Token> {
+    let TokenType::Verb { lemma, time, aspect, .. } = tokens.first()?.kind else {
+        return None;
+    };
+    let particle = match tokens.get(1)?.kind {
+        TokenType::Particle(sym) | TokenType::Preposition(sym) => interner.resolve(sym).to_lowercase(),
+        _ => return None,
+    };
+    let verb = interner.resolve(lemma).to_lowercase();
+    let (transitive, class) = crate::lexicon::lookup_phrasal_verb(&verb, &particle)?;
+    let clause_final = matches!(
+        tokens.get(2).map(|t| &t.kind),
+        None | Some(TokenType::Period | TokenType::EOF | TokenType::Comma | TokenType::And)
+    );
+    let phrasal = if clause_final {
+        crate::lexicon::lookup_intransitive_phrasal_verb(&verb, &particle).unwrap_or(transitive)
+    } else {
+        transitive
+    };
+    let lemma_sym = interner.intern(phrasal);
+    Some(Token {
+        kind: TokenType::Verb { lemma: lemma_sym, time, aspect, class },
+        lexeme: lemma_sym,
+        span: tokens[0].span,
+    })
+}
+
 /// Extract lemma from a token for MWE matching.
 /// Uses lowercase for case-insensitive matching.
 fn get_lemma(token: &Token, interner: &Interner) -> String {
@@ -91,11 +168,13 @@ fn get_lemma(token: &Token, interner: &Interner) -> String {
     }
 }
 
-/// Find the longest MWE match starting at the beginning of the token slice.
+/// Find the longest MWE match starting at the beginning of the token slice,
+/// skipping idioms unless `idioms` is set.
 fn find_longest_match<'a>(
     tokens: &[Token],
     trie: &'a MweTrie,
     interner: &Interner,
+    idioms: bool,
 ) -> Option<(usize, &'a MweTarget)> {
     let mut node = trie;
     let mut best: Option<(usize, &MweTarget)> = None;
@@ -104,7 +183,7 @@ fn find_longest_match<'a>(
         let lemma = get_lemma(token, interner);
         if let Some(child) = node.children.get(&lemma) {
             node = child;
-            if let Some(target) = &node.target {
+            if let Some(target) = node.target.as_ref().filter(|t| idioms || !t.idiom) {
                 best = Some((i + 1, target));
             }
         } else {
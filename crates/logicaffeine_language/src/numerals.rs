@@ -0,0 +1,273 @@
+//! Numerals — written numbers, ordinals, and natural-language dates and times.
+//!
+//! The lexer already reads digit literals, ISO dates (`2026-05-20`) and
+//! 12-hour clock times (`9:30am`). This module covers the spelled-out forms
+//! that prose uses for the same values:
+//!
+//! | Input | Token |
+//! |-------|-------|
+//! | "eleven", "twenty-three" | `Cardinal(23)` |
+//! | "third", "3rd", "twenty-first" | `Adjective("third")`, like the lexicon's "first" |
+//! | "March 5th, 2024", "5 March 2024" | `DateLiteral` (days since 1970-01-01) |
+//! | "5 o'clock", "five o'clock" | `TimeLiteral` |
+//!
+//! Written cardinals are single words, read by the lexer's number lookup
+//! alongside the lexicon's "one" to "ten". Dates, clock times and ordinals
+//! span or reinterpret several tokens, so they are rewritten by
+//! [`normalize`] at the end of tokenization. A date needs its year: a bare
+//! "March 5th" stays a month and a day, which the noun-phrase parser joins
+//! into the date name `March_5`. Ordinals are only rewritten in declarative
+//! text, and the word "second" only after an article, since on its own it is
+//! usually the unit of time.
+
+use logicaffeine_base::Interner;
+
+use crate::token::{BlockType, Span, Token, TokenType};
+
+const UNITS: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] =
+    ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+
+const UNIT_ORDINALS: [&str; 20] = [
+    "zeroth", "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth",
+    "ninth", "tenth", "eleventh", "twelfth", "thirteenth", "fourteenth", "fifteenth",
+    "sixteenth", "seventeenth", "eighteenth", "nineteenth",
+];
+
+const TEN_ORDINALS: [&str; 10] = [
+    "", "", "twentieth", "thirtieth", "fortieth", "fiftieth", "sixtieth", "seventieth",
+    "eightieth", "ninetieth",
+];
+
+/// A written cardinal from one to ninety-nine ("seven", "eleven", "forty",
+/// "twenty-three"). Lowercase input.
+pub fn parse_cardinal(word: &str) -> Option<u32> {
+    spelled(word, &UNITS, &TENS).filter(|&n| n > 0)
+}
+
+/// A written or numeric ordinal from 1 to 99 ("third", "twenty-first",
+/// "3rd", "21st"). Numeric ordinals must carry the right suffix: "3rd",
+/// never "3th". Lowercase input.
+pub fn parse_ordinal(word: &str) -> Option<u32> {
+    let digits = word.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    if !digits.chars().all(|c| c.is_ascii_digit()) || digits.is_empty() {
+        return spelled(word, &UNIT_ORDINALS, &TEN_ORDINALS).filter(|&n| n > 0);
+    }
+    let n: u32 = digits.parse().ok()?;
+    ((1..100).contains(&n) && word[digits.len()..] == *ordinal_suffix(n)).then_some(n)
+}
+
+/// The spelled-out ordinal for 1 to 99 ("third", "twenty-first").
+pub fn ordinal_word(n: u32) -> Option<String> {
+    match n {
+        1..=19 => Some(UNIT_ORDINALS[n as usize].to_string()),
+        20..=99 if n % 10 == 0 => Some(TEN_ORDINALS[n as usize / 10].to_string()),
+        20..=99 => Some(format!("{}-{}", TENS[n as usize / 10], UNIT_ORDINALS[n as usize % 10])),
+        _ => None,
+    }
+}
+
+fn ordinal_suffix(n: u32) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// A number word from `units` or `tens`, or a cardinal ten, a hyphen and a
+/// unit word ("twenty-three", "twenty-third").
+fn spelled(word: &str, units: &[&str; 20], tens: &[&str; 10]) -> Option<u32> {
+    let position = |table: &[&str], w: &str| table.iter().position(|t| !t.is_empty() && *t == w);
+    if let Some(n) = position(units, word) {
+        return Some(n as u32);
+    }
+    if let Some(n) = position(tens, word) {
+        return Some(n as u32 * 10);
+    }
+    let (ten, unit) = word.split_once('-')?;
+    let ten = position(&TENS, ten)?;
+    let unit = position(&units[1..10], unit)? + 1;
+    Some((ten * 10 + unit) as u32)
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date, or `None` when the
+/// date does not exist (`2026-02-30`).
+pub fn days_from_civil(year: i32, month: u32, day: u32) -> Option<i32> {
+    if !(1..=12).contains(&month) || day < 1 {
+        return None;
+    }
+    let is_leap = (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0);
+    let max_day = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        _ => if is_leap { 29 } else { 28 },
+    };
+    if day > max_day {
+        return None;
+    }
+
+    // Howard Hinnant's days_from_civil:
+    // https://howardhinnant.github.io/date_algorithms.html
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y / 400 } else { (y - 399) / 400 };
+    let yoe = (y - era * 400) as u32;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    Some(era * 146097 + doe as i32 - 719468)
+}
+
+fn month_number(word: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "january", "february", "march", "april", "may", "june", "july", "august", "september",
+        "october", "november", "december",
+    ];
+    let capitalized = word.chars().next().is_some_and(char::is_uppercase);
+    let lower = word.to_lowercase();
+    capitalized.then(|| MONTHS.iter().position(|m| *m == lower)).flatten().map(|m| m as u32 + 1)
+}
+
+/// Rewrites dates, o'clock times and ordinals. See the module docs.
+pub(crate) fn normalize(tokens: Vec<Token>, interner: &mut Interner) -> Vec<Token> {
+    let words: Vec<String> = tokens.iter().map(|t| interner.resolve(t.lexeme).to_string()).collect();
+    let mut result: Vec<Token> = Vec::with_capacity(tokens.len());
+    let mut imperative = false;
+    let mut i = 0;
+    while i < tokens.len() {
+        if let TokenType::BlockHeader { block_type } = &tokens[i].kind {
+            imperative = matches!(block_type, BlockType::Main | BlockType::Function);
+        }
+        if let Some((len, kind, text)) = date(&tokens[i..], &words[i..]).or_else(|| oclock(&tokens[i..], &words[i..])) {
+            let lexeme = interner.intern(&text);
+            let span = Span::new(tokens[i].span.start, tokens[i + len - 1].span.end);
+            result.push(Token::new(kind, lexeme, span));
+            i += len;
+            continue;
+        }
+        let after_month = i > 0 && month_number(&words[i - 1]).is_some();
+        let after_article = result.last().is_some_and(|t| matches!(t.kind, TokenType::Article(_)));
+        let ordinal = (!imperative && !after_month)
+            .then(|| ordinal(&tokens[i], &words[i], after_article))
+            .flatten();
+        match ordinal {
+            Some(word) => {
+                let sym = interner.intern(&word);
+                result.push(Token::new(TokenType::Adjective(sym), sym, tokens[i].span));
+            }
+            None => result.push(tokens[i].clone()),
+        }
+        i += 1;
+    }
+    result
+}
+
+/// A day of the month: a plain or ordinal numeral, or a written ordinal.
+fn day_of_month(token: &Token, word: &str) -> Option<u32> {
+    let day = match token.kind {
+        TokenType::Cardinal(n) => n,
+        TokenType::Number(_) if word.chars().all(|c| c.is_ascii_digit()) => word.parse().ok()?,
+        _ => parse_ordinal(&word.to_lowercase())?,
+    };
+    (1..=31).contains(&day).then_some(day)
+}
+
+fn year(token: &Token, word: &str) -> Option<i32> {
+    let is_year = matches!(token.kind, TokenType::Number(_))
+        && word.len() == 4
+        && word.chars().all(|c| c.is_ascii_digit());
+    is_year.then(|| word.parse().ok()).flatten()
+}
+
+/// A multi-token rewrite: tokens spanned, the new token, and its lexeme.
+type Rewrite = (usize, TokenType, String);
+
+/// "March 5th, 2024", "March 5 2024" or "5 March 2024". The lexeme is the
+/// ISO form, as for a written `2024-03-05`.
+fn date(tokens: &[Token], words: &[String]) -> Option<Rewrite> {
+    if tokens.len() < 3 {
+        return None;
+    }
+    let (month, day) = match month_number(&words[0]) {
+        Some(month) => (month, day_of_month(&tokens[1], &words[1])?),
+        None => (month_number(&words[1])?, day_of_month(&tokens[0], &words[0])?),
+    };
+    let comma = usize::from(tokens[2].kind == TokenType::Comma);
+    let y = year(tokens.get(2 + comma)?, words.get(2 + comma)?)?;
+    let days = days_from_civil(y, month, day)?;
+    Some((3 + comma, TokenType::DateLiteral { days }, format!("{y:04}-{month:02}-{day:02}")))
+}
+
+/// "5 o'clock" or "five o'clock" (the apostrophe splits it into "o" and
+/// "clock"). The hour is taken as written, so "5 o'clock" is 5:00.
+fn oclock(tokens: &[Token], words: &[String]) -> Option<Rewrite> {
+    let hour = match tokens.first()?.kind {
+        TokenType::Cardinal(n) => n,
+        TokenType::Number(_) => words[0].parse().ok()?,
+        _ => return None,
+    };
+    let is_oclock = words.get(1).is_some_and(|w| w.eq_ignore_ascii_case("o"))
+        && words.get(2).is_some_and(|w| w.eq_ignore_ascii_case("clock"));
+    if !is_oclock || !(1..=12).contains(&hour) {
+        return None;
+    }
+    let nanos_from_midnight = hour as i64 * 3600 * 1_000_000_000;
+    Some((3, TokenType::TimeLiteral { nanos_from_midnight }, format!("{hour}:00")))
+}
+
+/// The canonical ordinal word for an ordinal token ("3rd" → "third").
+fn ordinal(token: &Token, word: &str, after_article: bool) -> Option<String> {
+    if !matches!(
+        token.kind,
+        TokenType::Number(_) | TokenType::Noun(_) | TokenType::ProperName(_) | TokenType::Adjective(_)
+    ) {
+        return None;
+    }
+    let lower = word.to_lowercase();
+    let n = parse_ordinal(&lower)?;
+    if lower == "second" && !after_article {
+        return None;
+    }
+    ordinal_word(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn written_cardinals() {
+        let read: Vec<_> = ["eleven", "forty", "twenty-three", "ninety-nine", "zero", "twenty-ten"]
+            .iter()
+            .map(|w| parse_cardinal(w))
+            .collect();
+        assert_eq!(read, [Some(11), Some(40), Some(23), Some(99), None, None]);
+    }
+
+    #[test]
+    fn ordinals_round_trip() {
+        for n in 1..100 {
+            let word = ordinal_word(n).unwrap();
+            assert_eq!(parse_ordinal(&word), Some(n), "{word}");
+            let numeric = format!("{n}{}", ordinal_suffix(n));
+            assert_eq!(parse_ordinal(&numeric), Some(n), "{numeric}");
+        }
+        assert_eq!(parse_ordinal("3th"), None);
+        assert_eq!(parse_ordinal("11st"), None);
+    }
+
+    #[test]
+    fn civil_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), Some(0));
+        assert_eq!(days_from_civil(2026, 5, 20), Some(20593));
+        assert_eq!(days_from_civil(2024, 2, 29), Some(19782));
+        assert_eq!(days_from_civil(2023, 2, 29), None);
+        assert_eq!(days_from_civil(2026, 13, 1), None);
+    }
+}
@@ -71,20 +71,39 @@ struct OfEntity<'a> {
 pub trait ClauseParsing<'a, 'ctx, 'int> {
     /// Parses a complete sentence, handling imperatives, ellipsis, and questions.
     fn parse_sentence(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
+    /// [`Self::parse_sentence`] without the derivation-trace wrapper.
+    fn parse_sentence_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
     /// Parses "if P then Q" conditionals with DRS scope handling.
     fn parse_conditional(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
+    /// [`Self::parse_conditional`] without the derivation-trace wrapper.
+    fn parse_conditional_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
     /// Parses "either P or Q" exclusive disjunctions.
     fn parse_either_or(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
+    /// [`Self::parse_either_or`] without the derivation-trace wrapper.
+    fn parse_either_or_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
     /// Parses "P or Q" disjunctions.
     fn parse_disjunction(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
+    /// [`Self::parse_disjunction`] without the derivation-trace wrapper.
+    fn parse_disjunction_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
     /// Parses "P and Q" conjunctions with scope coordination.
     fn parse_conjunction(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
+    /// [`Self::parse_conjunction`] without the derivation-trace wrapper.
+    fn parse_conjunction_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
     /// Extracts the subject of a copular predication, for non-parallel coordination.
     fn extract_copular_subject(&self, expr: &'a LogicExpr<'a>) -> Option<Symbol>;
     /// Parses a bare copular-predicate remnant ("wealthy" / "a philanthropist").
     fn try_parse_copular_predicate(&mut self, subject: Symbol) -> ParseResult<Option<&'a LogicExpr<'a>>>;
+    /// The subject a coordinated verb phrase shares ("John sang and DANCED"): the
+    /// agent, or failing that the first role, of the last event in `expr`.
+    fn coordinated_vp_subject(&self, expr: &'a LogicExpr<'a>) -> Option<Term<'a>>;
+    /// Parses a verb phrase after "and" / "," predicated of `expr`'s subject and
+    /// conjoins it inside that subject's scope. `None` (consuming nothing) if no
+    /// verb phrase follows.
+    fn try_parse_coordinated_vp(&mut self, expr: &'a LogicExpr<'a>) -> ParseResult<Option<&'a LogicExpr<'a>>>;
     /// Parses "who/that/which" relative clauses attaching to noun phrases.
     fn parse_relative_clause(&mut self, gap_var: Symbol) -> ParseResult<&'a LogicExpr<'a>>;
+    /// [`Self::parse_relative_clause`] without the derivation-trace wrapper.
+    fn parse_relative_clause_rule(&mut self, gap_var: Symbol) -> ParseResult<&'a LogicExpr<'a>>;
     /// Parses a clause with a gap filled by borrowed verb (for VP coordination).
     fn parse_gapped_clause(&mut self, borrowed_verb: Symbol) -> ParseResult<&'a LogicExpr<'a>>;
     /// Parses "if P were/had" counterfactual antecedent (subjunctive).
@@ -112,9 +131,27 @@ pub trait ClauseParsing<'a, 'ctx, 'int> {
     /// sits at `vp - 1`, the relativized head is the determiner-headed noun before
     /// it), so it is NOT the clause's main verb.
     fn is_reduced_relative_verb(&self, vp: usize) -> bool;
-    /// Attempts to parse an it-cleft "It was X who/that VP." → focus on X plus
-    /// exhaustivity (only X did it). Returns `None` (restoring position) otherwise.
+    /// Attempts to parse an it-cleft "It was X who/that VP." (or an object cleft
+    /// "It was X that Y VP") → focus on X plus exhaustivity (only X did it), or
+    /// a pseudo-cleft. Returns `None` (restoring position) otherwise.
     fn try_parse_cleft(&mut self) -> ParseResult<Option<&'a LogicExpr<'a>>>;
+    /// Attempts to parse a pseudo-cleft "What Mary wants is coffee." → focus on
+    /// coffee plus exhaustivity (nothing else is wanted). `None` otherwise.
+    fn try_parse_pseudo_cleft(&mut self) -> ParseResult<Option<&'a LogicExpr<'a>>>;
+    /// Builds `Cleft(focus, core) ∧ ∀z(core_z → z = focus)` from a cleft's core
+    /// predication and the same predication over the gap variable `z`.
+    fn build_cleft(
+        &mut self,
+        focus_sym: Symbol,
+        core: &'a LogicExpr<'a>,
+        core_z: &'a LogicExpr<'a>,
+        z: Symbol,
+    ) -> &'a LogicExpr<'a>;
+    /// Attempts to parse an existential "there" sentence: "There is a cat on
+    /// the mat." → ∃x(Cat(x) ∧ On(x, Mat)), "There are three solutions." →
+    /// ∃=3x Solutions(x), "There is no cat." → ¬∃x Cat(x). The pivot's
+    /// determiner picks the quantifier. `None` (restoring position) otherwise.
+    fn try_parse_existential_there(&mut self) -> ParseResult<Option<&'a LogicExpr<'a>>>;
     /// Attempts to parse an exclamative "How tall she is!" / "What a fool he is!"
     /// (how/what, no subject-aux inversion, "!"-terminated). Returns `None` otherwise.
     fn try_parse_exclamative(&mut self) -> ParseResult<Option<&'a LogicExpr<'a>>>;
@@ -124,6 +161,11 @@ pub trait ClauseParsing<'a, 'ctx, 'int> {
     /// Attempts to parse correlative coordination "Neither X nor Y VP" / "Either X
     /// or Y VP" — a shared predicate scoped over two subjects. `None` otherwise.
     fn try_parse_correlative(&mut self) -> ParseResult<Option<&'a LogicExpr<'a>>>;
+    /// Distributes a verb phrase over coordinated quantified subjects by
+    /// repeating it after each one: "Every boy and every girl smiled" is read
+    /// as "Every boy smiled and every girl smiled". Collective verbs are left
+    /// alone. Rewrites the token stream in place.
+    fn distribute_coordinated_quantifiers(&mut self);
     /// Attempts to parse "Of NP₁ and NP₂, one VP₁ and the other VP₂" →
     /// (VP₁(NP₁) ∧ VP₂(NP₂)) ∨ (VP₁(NP₂) ∧ VP₂(NP₁)). Returns `None` otherwise.
     fn try_parse_of_pair_xor(&mut self) -> ParseResult<Option<&'a LogicExpr<'a>>>;
@@ -138,8 +180,10 @@ pub trait ClauseParsing<'a, 'ctx, 'int> {
     fn try_parse_ellipsis(&mut self) -> Option<ParseResult<&'a LogicExpr<'a>>>;
     /// Checks for ellipsis auxiliary (did, does, can, etc.).
     fn check_ellipsis_auxiliary(&self) -> bool;
-    /// Checks for ellipsis terminator (too, also, as well).
+    /// Checks for ellipsis terminator (too, also, either, as well).
     fn check_ellipsis_terminator(&self) -> bool;
+    /// The overt subject of an elided clause: a name, a pronoun, or a definite.
+    fn parse_ellipsis_subject(&mut self) -> Option<ParseResult<Symbol>>;
 }
 
 impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
@@ -346,6 +390,9 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
 
     fn try_parse_cleft(&mut self) -> ParseResult<Option<&'a LogicExpr<'a>>> {
         let start = self.current;
+        if self.check(&TokenType::What) || self.check(&TokenType::Who) {
+            return self.try_parse_pseudo_cleft();
+        }
         // "It was/is X who/that VP." — the expletive "it" + copula + focus + relative.
         if !self.interner.resolve(self.peek().lexeme).eq_ignore_ascii_case("it") {
             return Ok(None);
@@ -373,13 +420,62 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         self.advance(); // who/that
 
         let focus_sym = focus_np.noun;
+
+        // An object cleft ("It was the vase that JOHN broke") has its own
+        // subject; the focus fills the object gap of the relative clause.
+        if starts_clause_subject(&self.peek().kind) {
+            let z = self.next_var_name();
+            let core_z = self.parse_relative_clause(z)?;
+            let core = self.substitute_variable_with_constant(core_z, z, focus_sym)?;
+            return Ok(Some(self.build_cleft(focus_sym, core, core_z, z)));
+        }
+
         // The cleft clause "broke the vase" with the focus as subject — the core
         // predication.
         let core = self.parse_predicate_with_subject(focus_sym)?;
-
-        // Exhaustivity: ∀z( core[focus→z] → z = focus ) — no one but the focus did it.
         let z = self.next_var_name();
         let core_z = self.substitute_constant_with_var_sym(core, focus_sym, z)?;
+        Ok(Some(self.build_cleft(focus_sym, core, core_z, z)))
+    }
+
+    fn try_parse_pseudo_cleft(&mut self) -> ParseResult<Option<&'a LogicExpr<'a>>> {
+        // "What Mary wants is coffee." / "Who broke the vase was John." — a
+        // free relative, a copula, then the focus. Questions end in "?".
+        let is_question = self.tokens[self.current..]
+            .iter()
+            .find(|t| matches!(t.kind, TokenType::Period | TokenType::EOF))
+            .is_some_and(|t| self.interner.resolve(t.lexeme) == "?");
+        if is_question {
+            return Ok(None);
+        }
+        let start = self.current;
+        self.advance(); // what / who
+        let z = self.next_var_name();
+        let parsed = self.try_parse(|p| {
+            let core_z = p.parse_relative_clause(z)?;
+            if !matches!(p.peek().kind, TokenType::Is | TokenType::Was) {
+                return Err(ParseError { kind: ParseErrorKind::ExpectedCopula, span: p.current_span() });
+            }
+            p.advance(); // is/was
+            let focus_np = p.parse_noun_phrase(false)?;
+            Ok((core_z, focus_np.noun))
+        });
+        let Some((core_z, focus_sym)) = parsed else {
+            self.current = start;
+            return Ok(None);
+        };
+        let core = self.substitute_variable_with_constant(core_z, z, focus_sym)?;
+        Ok(Some(self.build_cleft(focus_sym, core, core_z, z)))
+    }
+
+    fn build_cleft(
+        &mut self,
+        focus_sym: Symbol,
+        core: &'a LogicExpr<'a>,
+        core_z: &'a LogicExpr<'a>,
+        z: Symbol,
+    ) -> &'a LogicExpr<'a> {
+        // Exhaustivity: ∀z( core[focus→z] → z = focus ) — no one but the focus did it.
         let identity = self.ctx.exprs.alloc(LogicExpr::Identity {
             left: self.ctx.terms.alloc(Term::Variable(z)),
             right: self.ctx.terms.alloc(Term::Constant(focus_sym)),
@@ -403,11 +499,112 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
             focused: focused_term,
             scope: core,
         });
-        Ok(Some(self.ctx.exprs.alloc(LogicExpr::BinaryOp {
+        self.ctx.exprs.alloc(LogicExpr::BinaryOp {
             left: focus_expr,
             op: TokenType::And,
             right: exhaustivity,
-        })))
+        })
+    }
+
+    fn try_parse_existential_there(&mut self) -> ParseResult<Option<&'a LogicExpr<'a>>> {
+        if !self.interner.resolve(self.peek().lexeme).eq_ignore_ascii_case("there") {
+            return Ok(None);
+        }
+        // Expletive "there" + copula (or "exist"): "There is/was …", "There exists …".
+        let time = match self.tokens.get(self.current + 1).map(|t| &t.kind) {
+            Some(TokenType::Is) | Some(TokenType::Are) => Time::Present,
+            Some(TokenType::Was) | Some(TokenType::Were) => Time::Past,
+            Some(TokenType::Verb { lemma, time, .. })
+                if self.interner.resolve(*lemma).eq_ignore_ascii_case("exist") =>
+            {
+                *time
+            }
+            _ => return Ok(None),
+        };
+        let start = self.current;
+        self.advance(); // there
+        self.advance(); // is / are / was / were / exist(s)
+        let mut negated = self.match_token(&[TokenType::Not]);
+
+        // The pivot's determiner; a bare (plural) pivot is existential.
+        let bare = self.check_content_word() && !self.check_article();
+        let kind = match self.peek().kind {
+            TokenType::Article(Definiteness::Indefinite) | TokenType::Some => QuantifierKind::Existential,
+            TokenType::No => {
+                negated = !negated;
+                QuantifierKind::Existential
+            }
+            TokenType::Cardinal(n) => QuantifierKind::Cardinal(n),
+            TokenType::AtLeast(n) => QuantifierKind::AtLeast(n),
+            TokenType::AtMost(n) => QuantifierKind::AtMost(n),
+            TokenType::Many => QuantifierKind::Many,
+            TokenType::Few => QuantifierKind::Few,
+            _ if bare => QuantifierKind::Existential,
+            _ => {
+                self.current = start;
+                return Ok(None);
+            }
+        };
+        if !bare {
+            self.advance(); // the determiner
+        }
+
+        // The pivot with its modifiers — PPs ("on the mat") and relative clauses
+        // ("a man who ran") — is the whole restriction; nothing else is asserted.
+        let variable = self.next_var_name();
+        let pivot = self.current;
+        let restriction = self.try_parse(|p| {
+            let restriction = p.parse_restriction(variable)?;
+            if !p.is_at_end() && !p.check(&TokenType::Period) {
+                return Err(ParseError {
+                    kind: ParseErrorKind::UnexpectedToken {
+                        expected: TokenType::Period,
+                        found: p.peek().kind.clone(),
+                    },
+                    span: p.current_span(),
+                });
+            }
+            Ok(restriction)
+        });
+        let Some(restriction) = restriction else {
+            self.current = start;
+            return Ok(None);
+        };
+
+        // The pivot is a discourse referent: "There is a cat. It sleeps."
+        let head = self.tokens[pivot..self.current].iter().find_map(|t| match t.kind {
+            TokenType::Noun(noun) => Some(noun),
+            _ => None,
+        });
+        if let (Some(noun), false) = (head, negated) {
+            let gender = Self::infer_noun_gender(self.interner.resolve(noun));
+            let number = if Self::is_plural_noun(self.interner.resolve(noun)) {
+                Number::Plural
+            } else {
+                Number::Singular
+            };
+            self.drs.introduce_referent(variable, noun, gender, number);
+        }
+
+        let mut result: &'a LogicExpr<'a> = self.ctx.exprs.alloc(LogicExpr::Quantifier {
+            kind,
+            variable,
+            body: restriction,
+            island_id: self.current_island,
+        });
+        if negated {
+            result = self.ctx.exprs.alloc(LogicExpr::UnaryOp {
+                op: TokenType::Not,
+                operand: result,
+            });
+        }
+        if time == Time::Past {
+            result = self.ctx.exprs.alloc(LogicExpr::Temporal {
+                operator: TemporalOperator::Past,
+                body: result,
+            });
+        }
+        Ok(Some(result))
     }
 
     fn try_parse_exclamative(&mut self) -> ParseResult<Option<&'a LogicExpr<'a>>> {
@@ -871,6 +1068,10 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
     }
 
     fn parse_sentence(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
+        self.traced("sentence", Self::parse_sentence_rule)
+    }
+
+    fn parse_sentence_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
         // In imperative mode, handle Let statements by converting to LogicExpr
         // This supports declarative parser being called after process_block_headers()
         // Let x is/= value -> returns the value expression (the test just checks parsing succeeds)
@@ -887,12 +1088,14 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
 
         // Check for ellipsis pattern: "Mary does too." / "Mary can too."
         if let Some(result) = self.try_parse_ellipsis() {
+            self.branch("sentence/ellipsis");
             return result;
         }
 
         // Optatives: "May you prosper!", "Long live the king!", "If only …!".
         if self.mode != ParserMode::Imperative {
             if let Some(opt) = self.try_parse_optative()? {
+                self.branch("sentence/optative");
                 return Ok(opt);
             }
         }
@@ -900,13 +1103,20 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         // Correlative coordination: "Neither X nor Y VP" / "Either X or Y VP".
         if self.mode != ParserMode::Imperative {
             if let Some(corr) = self.try_parse_correlative()? {
+                self.branch("sentence/correlative");
                 return Ok(corr);
             }
         }
 
+        // Coordinated quantifiers: "Every boy and every girl smiled."
+        if self.mode != ParserMode::Imperative {
+            self.distribute_coordinated_quantifiers();
+        }
+
         // "Of NP₁ and NP₂, one VP₁ and the other VP₂" binary XOR partition.
         if self.mode != ParserMode::Imperative {
             if let Some(xor) = self.try_parse_of_pair_xor()? {
+                self.branch("sentence/partition");
                 return Ok(xor);
             }
         }
@@ -915,6 +1125,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         // (habitual) — the fronted time NP frames the whole clause.
         if self.mode != ParserMode::Imperative {
             if let Some(framed) = self.try_parse_fronted_temporal_adjunct()? {
+                self.branch("sentence/fronted temporal");
                 return Ok(framed);
             }
         }
@@ -924,6 +1135,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         if self.mode != ParserMode::Imperative {
             let lead_text = self.interner.resolve(self.peek().lexeme).to_lowercase();
             if lead_text == "whoever" {
+                self.branch("sentence/whoever");
                 self.advance(); // consume "whoever"
                 let var = self.next_var_name();
                 let restrictor = self.parse_predicate_with_subject(var)?;
@@ -946,6 +1158,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         // wh-question path, since they share how/what but are "!"-terminated.
         if self.mode != ParserMode::Imperative {
             if let Some(excl) = self.try_parse_exclamative()? {
+                self.branch("sentence/exclamative");
                 return Ok(excl);
             }
         }
@@ -953,15 +1166,25 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         // it-clefts: "It was John who broke the vase." → focus + exhaustivity.
         if self.mode != ParserMode::Imperative {
             if let Some(cleft) = self.try_parse_cleft()? {
+                self.branch("sentence/cleft");
                 return Ok(cleft);
             }
         }
 
+        // Existential "there": "There is a cat on the mat." → ∃x(Cat(x) ∧ On(x, Mat)).
+        if self.mode != ParserMode::Imperative {
+            if let Some(existential) = self.try_parse_existential_there()? {
+                self.branch("sentence/existential there");
+                return Ok(existential);
+            }
+        }
+
         // English imperatives: bare-verb-initial commands ("Close the door."),
         // negatives ("Don't touch that."), and hortatives ("Let's leave."). Only in
         // declarative (English) mode — code mode has its own verb-initial handling.
         if self.mode != ParserMode::Imperative {
             if let Some(imp) = self.try_parse_imperative()? {
+                self.branch("sentence/imperative");
                 return Ok(imp);
             }
         }
@@ -969,6 +1192,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         // "Although/Though X, Y" concessive subordinator: Y holds despite X (a
         // defeated expectation). → Concessive{ main: Y, concession: X }.
         if self.check(&TokenType::Although) {
+            self.branch("sentence/concessive");
             self.advance(); // consume "Although"/"Though"
             let concession = self.parse_sentence()?;
             if self.check(&TokenType::Comma) {
@@ -982,6 +1206,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         // Duration semantics: Y holds for the entire interval where X is true.
         // Lowered as implication checked globally: G(X → Y)
         if self.check(&TokenType::While) {
+            self.branch("sentence/while");
             self.advance(); // consume "While"
             let condition = self.parse_sentence()?;
             if self.check(&TokenType::Comma) {
@@ -1008,6 +1233,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
                 }
             }
             if found_comma {
+                self.branch("sentence/when");
                 self.advance(); // consume "When"
                 let condition = self.parse_sentence()?;
                 if self.check(&TokenType::Comma) {
@@ -1027,6 +1253,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         if self.check_content_word() {
             let word = self.interner.resolve(self.peek().lexeme).to_string();
             if word == "Whenever" || word == "whenever" {
+                self.branch("sentence/when");
                 self.advance(); // consume "Whenever"
                 let condition = self.parse_sentence()?;
                 if self.check(&TokenType::Comma) {
@@ -1061,6 +1288,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         // Inverted conditional (§4.1): "Had I known, …" / "Were I rich, …" /
         // "Should it rain, …" — subject-aux inversion stands in for "if".
         if let Some(expr) = self.try_parse_inverted_conditional()? {
+            self.branch("sentence/inverted conditional");
             return Ok(expr);
         }
 
@@ -1080,6 +1308,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         }
 
         if self.match_token(&[TokenType::Not]) {
+            self.branch("sentence/negation");
             self.negative_depth += 1;
             let inner = self.parse_sentence()?;
             self.negative_depth -= 1;
@@ -1105,6 +1334,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
                 false
             };
             if next_is_clausal {
+                self.branch("sentence/both");
                 self.advance(); // consume "both"
                 let first = self.parse_atom()?;
                 if self.check(&TokenType::And) {
@@ -1147,6 +1377,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
                 _ => None,
             };
             if let Some(op) = temporal_op {
+                self.branch("sentence/temporal operator");
                 self.advance(); // consume the token
                 // Optionally consume comma: "Always, P"
                 if self.check(&TokenType::Comma) {
@@ -1161,6 +1392,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         }
         // "Never P" → G(¬P): Always { Not { P } }
         if self.check(&TokenType::Never) {
+            self.branch("sentence/temporal operator");
             self.advance(); // consume "Never"
             // Optionally consume comma
             if self.check(&TokenType::Comma) {
@@ -1182,6 +1414,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         // Handles both "After reset is deasserted, ..." (full clause)
         // and "After request, ..." (bare signal/event noun)
         if self.check_preposition_is("after") || self.check_preposition_is("After") {
+            self.branch("sentence/after");
             self.advance(); // consume "after"
 
             // Check for bare noun/signal + comma pattern: "After request, ..."
@@ -1217,6 +1450,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
             }));
         }
         if self.check_preposition_is("before") || self.check_preposition_is("Before") {
+            self.branch("sentence/before");
             self.advance(); // consume "before"
             let first_clause = self.parse_sentence()?;
             if self.check(&TokenType::Comma) {
@@ -1259,6 +1493,10 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
     }
 
     fn parse_conditional(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
+        self.traced("conditional", Self::parse_conditional_rule)
+    }
+
+    fn parse_conditional_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
         let is_counterfactual = self.is_counterfactual_context();
 
         // Biscuit / relevance conditional (§4.2): "If you WANT tea, the kettle is
@@ -1302,7 +1540,11 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
 
         // Enter DRS consequent box - can access antecedent referents
         self.drs.enter_box(BoxType::ConditionalConsequent);
-        let mut consequent = self.parse_counterfactual_consequent()?;
+        // A command consequent: "If Mary asks who came, tell her."
+        let mut consequent = match self.try_parse_imperative()? {
+            Some(command) => command,
+            None => self.parse_counterfactual_consequent()?,
+        };
 
         // Conjunction of consequent clauses: "…, he would have passed and he
         // would have celebrated." A non-clausal "and" (NP coordination left
@@ -1401,6 +1643,10 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
     /// Handles coordination: "Either Alice or Bob is guilty" should become
     /// guilty(Alice) ∨ guilty(Bob), not Alice ∨ guilty(Bob)
     fn parse_either_or(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
+        self.traced("either-or", Self::parse_either_or_rule)
+    }
+
+    fn parse_either_or_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
         // Save position for potential backtracking
         let start_pos = self.current;
 
@@ -2107,7 +2353,20 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
     /// Phase 46: Generalized gapping with template-guided reconstruction.
     /// Handles NPs, PPs, temporal adverbs, and preserves roles from EventTemplate.
     fn parse_gapped_clause(&mut self, borrowed_verb: Symbol) -> ParseResult<&'a LogicExpr<'a>> {
-        let subject = self.parse_noun_phrase(true)?;
+        // A name right before the remnant is the whole subject: "and Mary
+        // beans" is Mary plus the object beans, not the compound Mary_beans.
+        let subject = match self.peek().kind {
+            TokenType::ProperName(name)
+                if matches!(
+                    self.tokens.get(self.current + 1).map(|t| &t.kind),
+                    Some(TokenType::Noun(_) | TokenType::Adjective(_))
+                ) =>
+            {
+                self.advance();
+                NounPhrase::simple(name)
+            }
+            _ => self.parse_noun_phrase(true)?,
+        };
 
         if self.check(&TokenType::Comma) {
             self.advance();
@@ -2204,6 +2463,10 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
     /// Parse disjunction (Or/Iff) - lowest precedence logical connectives.
     /// Calls parse_conjunction for operands to ensure And binds tighter.
     fn parse_disjunction(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
+        self.traced("disjunction", Self::parse_disjunction_rule)
+    }
+
+    fn parse_disjunction_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
         let mut expr = self.parse_conjunction()?;
 
         while self.check(&TokenType::Comma)
@@ -2399,7 +2662,90 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         })))
     }
 
+    fn coordinated_vp_subject(&self, expr: &'a LogicExpr<'a>) -> Option<Term<'a>> {
+        let subject = match expr {
+            LogicExpr::NeoEvent(data) => data
+                .roles
+                .iter()
+                .find(|(role, _)| *role == ThematicRole::Agent)
+                .or(data.roles.first())
+                .map(|(_, term)| *term),
+            LogicExpr::Predicate { args, .. } => args.first().copied(),
+            LogicExpr::Quantifier { body, .. } => return self.coordinated_vp_subject(body),
+            LogicExpr::BinaryOp { left, op: TokenType::And, right } => {
+                return self.coordinated_vp_subject(right).or_else(|| self.coordinated_vp_subject(left));
+            }
+            LogicExpr::BinaryOp { right, op: TokenType::Implies, .. } => return self.coordinated_vp_subject(right),
+            LogicExpr::Temporal { body, .. } | LogicExpr::Aspectual { body, .. } => {
+                return self.coordinated_vp_subject(body);
+            }
+            LogicExpr::Modal { operand, .. } => return self.coordinated_vp_subject(operand),
+            _ => None,
+        };
+        subject.filter(|t| matches!(t, Term::Constant(_) | Term::Variable(_)))
+    }
+
+    fn try_parse_coordinated_vp(&mut self, expr: &'a LogicExpr<'a>) -> ParseResult<Option<&'a LogicExpr<'a>>> {
+        if !matches!(self.peek().kind, TokenType::Verb { .. }) {
+            return Ok(None);
+        }
+        let vp = match self.coordinated_vp_subject(expr) {
+            Some(Term::Constant(s)) => self.try_parse(|p| p.parse_predicate_with_subject(s)),
+            Some(Term::Variable(v)) => self.try_parse(|p| p.parse_predicate_with_subject_as_var(v)),
+            _ => None,
+        };
+        let Some(vp) = vp else {
+            return Ok(None);
+        };
+
+        // A constant subject scopes over nothing, so the VP joins the whole
+        // clause. A variable subject is bound by a quantifier ("Every boy sang
+        // and danced"), and the VP must land in that quantifier's nuclear scope.
+        fn conjoin<'a>(
+            p: &Parser<'a, '_, '_>,
+            expr: &'a LogicExpr<'a>,
+            var: Option<Symbol>,
+            vp: &'a LogicExpr<'a>,
+        ) -> Option<&'a LogicExpr<'a>> {
+            match expr {
+                LogicExpr::Quantifier { kind, variable, body, island_id } if var.is_some() => {
+                    let var = if Some(*variable) == var { None } else { var };
+                    let body = conjoin(p, body, var, vp)?;
+                    Some(p.ctx.exprs.alloc(LogicExpr::Quantifier { kind: *kind, variable: *variable, body, island_id: *island_id }))
+                }
+                LogicExpr::BinaryOp { left, op: TokenType::Implies, right } if var.is_none() => {
+                    let right = conjoin(p, right, var, vp)?;
+                    Some(p.ctx.exprs.alloc(LogicExpr::BinaryOp { left, op: TokenType::Implies, right }))
+                }
+                LogicExpr::BinaryOp { left, op: TokenType::And, right } if var.is_some() => {
+                    match conjoin(p, right, var, vp) {
+                        Some(right) => Some(p.ctx.exprs.alloc(LogicExpr::BinaryOp { left, op: TokenType::And, right })),
+                        None => {
+                            let left = conjoin(p, left, var, vp)?;
+                            Some(p.ctx.exprs.alloc(LogicExpr::BinaryOp { left, op: TokenType::And, right }))
+                        }
+                    }
+                }
+                _ if var.is_some() => None,
+                _ => Some(p.ctx.exprs.alloc(LogicExpr::BinaryOp { left: expr, op: TokenType::And, right: vp })),
+            }
+        }
+
+        let var = match self.coordinated_vp_subject(expr) {
+            Some(Term::Variable(v)) => Some(v),
+            _ => None,
+        };
+        let joined = conjoin(self, expr, var, vp).unwrap_or_else(|| {
+            self.ctx.exprs.alloc(LogicExpr::BinaryOp { left: expr, op: TokenType::And, right: vp })
+        });
+        Ok(Some(joined))
+    }
+
     fn parse_conjunction(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
+        self.traced("conjunction", Self::parse_conjunction_rule)
+    }
+
+    fn parse_conjunction_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
         let mut expr = self.parse_atom()?;
 
         // Handle causal "because" at conjunction level
@@ -2416,6 +2762,11 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         while self.check(&TokenType::Comma) || self.check(&TokenType::And) {
             if self.check(&TokenType::Comma) {
                 self.advance();
+                // A comma-separated verb list: "sang, danced and laughed".
+                if let Some(joined) = self.try_parse_coordinated_vp(expr)? {
+                    expr = joined;
+                    continue;
+                }
             }
             if !self.match_token(&[TokenType::And]) {
                 break;
@@ -2423,6 +2774,20 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
             let operator = self.previous().kind.clone();
             self.current_island += 1;
 
+            // VP coordination: "John loves Mary and hates Bill" — a verb right
+            // after "and" shares the subject of the clause before it.
+            if let Some(joined) = self.try_parse_coordinated_vp(expr)? {
+                expr = joined;
+                continue;
+            }
+
+            // VP ellipsis: "John runs and Mary does too" rebuilds the elided
+            // verb phrase from the clause before it.
+            if let Some(right) = self.try_parse_ellipsis() {
+                expr = self.ctx.exprs.alloc(LogicExpr::BinaryOp { left: expr, op: operator, right: right? });
+                continue;
+            }
+
             // Non-parallel copular coordination (§2.2): "X is wealthy and a
             // philanthropist" — the remnant after "and" is a bare predicate (an
             // adjective or a predicate nominal) attributed to the SAME copular
@@ -2488,6 +2853,10 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
     }
 
     fn parse_relative_clause(&mut self, gap_var: Symbol) -> ParseResult<&'a LogicExpr<'a>> {
+        self.traced("relative clause", |p| p.parse_relative_clause_rule(gap_var))
+    }
+
+    fn parse_relative_clause_rule(&mut self, gap_var: Symbol) -> ParseResult<&'a LogicExpr<'a>> {
         // A clause-initial adverb ("who FIRST started in 1983", "who ORIGINALLY came
         // out in 1866") modifies the relative clause's event. It hid the verb from the
         // dispatch below, stranding the clue (TrailingTokens) or dropping the clause to
@@ -2793,7 +3162,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
             }
 
             if self.check_verb() {
-                let verb = self.consume_verb();
+                let verb = self.consume_verb()?;
 
                 // Check for reflexive object: "shave themselves"
                 let roles = if self.check(&TokenType::Reflexive) {
@@ -2846,7 +3215,7 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
             };
 
             if self.check_verb() {
-                let verb = self.consume_verb();
+                let verb = self.consume_verb()?;
 
                 // A STRANDED preposition ("the animal Eva works WITH", "the case Bob
                 // paid FOR") makes the GAP the object of that preposition, not the
@@ -3007,21 +3376,27 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
     fn check_ellipsis_auxiliary(&self) -> bool {
         matches!(
             self.peek().kind,
-            TokenType::Does | TokenType::Do |
+            TokenType::Does | TokenType::Do | TokenType::Auxiliary(_) |
             TokenType::Can | TokenType::Could | TokenType::Would |
             TokenType::May | TokenType::Must | TokenType::Should
         )
     }
 
     fn check_ellipsis_terminator(&self) -> bool {
-        if self.is_at_end() || self.check(&TokenType::Period) {
+        if self.is_at_end()
+            || self.check(&TokenType::Period)
+            || self.check(&TokenType::Comma)
+            || self.check(&TokenType::And)
+            || self.check(&TokenType::Either)
+        {
             return true;
         }
-        if self.check_content_word() {
-            let word = self.interner.resolve(self.peek().lexeme).to_lowercase();
-            return word == "too" || word == "also";
-        }
-        false
+        let word = self.interner.resolve(self.peek().lexeme).to_lowercase();
+        let as_well = word == "as"
+            && self.tokens.get(self.current + 1).is_some_and(|t| {
+                self.interner.resolve(t.lexeme).eq_ignore_ascii_case("well")
+            });
+        word == "too" || word == "also" || as_well
     }
 
     fn try_parse_ellipsis(&mut self) -> Option<ParseResult<&'a LogicExpr<'a>>> {
@@ -3032,41 +3407,49 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
 
         let saved_pos = self.current;
 
-        // Pattern: Subject + Auxiliary + (not)? + Terminator
-        // Subject must be proper name or pronoun
-        let subject_sym = if matches!(self.peek().kind, TokenType::ProperName(_)) {
-            if let TokenType::ProperName(sym) = self.advance().kind {
-                sym
-            } else {
-                self.current = saved_pos;
-                return None;
-            }
-        } else if self.check_pronoun() {
-            let token = self.advance().clone();
-            if let TokenType::Pronoun { gender, number, .. } = token.kind {
-                match self.resolve_pronoun(gender, number) {
-                    Ok(resolved) => match resolved {
-                        super::ResolvedPronoun::Variable(s) | super::ResolvedPronoun::Constant(s) => s,
-                    },
-                    Err(e) => return Some(Err(e)),
+        // Pattern: Subject + Auxiliary + (not)? + Terminator, or the inverted
+        // "so/neither/nor + Auxiliary + Subject" ("and so did Mary").
+        let lead = self.interner.resolve(self.peek().lexeme).to_lowercase();
+        let inverted = matches!(lead.as_str(), "so" | "neither" | "nor")
+            && self.tokens.get(self.current + 1).is_some_and(|t| {
+                matches!(
+                    t.kind,
+                    TokenType::Does | TokenType::Do | TokenType::Auxiliary(_) |
+                    TokenType::Can | TokenType::Could | TokenType::Would |
+                    TokenType::May | TokenType::Must | TokenType::Should
+                )
+            });
+        let mut is_negated = inverted && lead != "so";
+        let aux_token;
+        let subject_sym;
+        if inverted {
+            self.advance(); // so / neither / nor
+            aux_token = self.advance().kind.clone();
+            subject_sym = match self.parse_ellipsis_subject() {
+                Some(Ok(sym)) => sym,
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    self.current = saved_pos;
+                    return None;
                 }
-            } else {
+            };
+        } else {
+            subject_sym = match self.parse_ellipsis_subject() {
+                Some(Ok(sym)) => sym,
+                Some(Err(e)) => return Some(Err(e)),
+                None => return None,
+            };
+
+            // Must be followed by ellipsis auxiliary
+            if !self.check_ellipsis_auxiliary() {
                 self.current = saved_pos;
                 return None;
             }
-        } else {
-            return None;
-        };
+            aux_token = self.advance().kind.clone();
 
-        // Must be followed by ellipsis auxiliary
-        if !self.check_ellipsis_auxiliary() {
-            self.current = saved_pos;
-            return None;
+            // Check for negation
+            is_negated = self.match_token(&[TokenType::Not]);
         }
-        let aux_token = self.advance().kind.clone();
-
-        // Check for negation
-        let is_negated = self.match_token(&[TokenType::Not]);
 
         // Must end with terminator
         if !self.check_ellipsis_terminator() {
@@ -3074,12 +3457,13 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
             return None;
         }
 
-        // Consume "too"/"also" if present
-        if self.check_content_word() {
-            let word = self.interner.resolve(self.peek().lexeme).to_lowercase();
-            if word == "too" || word == "also" {
-                self.advance();
-            }
+        // Consume "too"/"also"/"either"/"as well" if present
+        let word = self.interner.resolve(self.peek().lexeme).to_lowercase();
+        if word == "as" {
+            self.advance();
+            self.advance();
+        } else if word == "too" || word == "also" || self.check(&TokenType::Either) {
+            self.advance();
         }
 
         // Reconstruct from template
@@ -3093,15 +3477,39 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         ];
         roles.extend(template.non_agent_roles.iter().cloned());
 
+        // The auxiliary carries the elided clause's own tense: "John runs and
+        // Mary did too" is past for Mary alone.
+        let past = self.interner.intern("Past");
+        let future = self.interner.intern("Future");
+        let mut modifiers: Vec<Symbol> = template.modifiers.clone();
+        let tense = match aux_token {
+            TokenType::Auxiliary(Time::Past) => Some(past),
+            TokenType::Auxiliary(Time::Future) => Some(future),
+            TokenType::Does | TokenType::Do => None,
+            _ => modifiers.iter().copied().find(|m| *m == past || *m == future),
+        };
+        modifiers.retain(|m| *m != past && *m != future);
+        modifiers.extend(tense);
+
         let neo_event = self.ctx.exprs.alloc(LogicExpr::NeoEvent(Box::new(NeoEventData {
             event_var,
             verb: template.verb,
             roles: self.ctx.roles.alloc_slice(roles),
-            modifiers: self.ctx.syms.alloc_slice(template.modifiers.clone()),
+            modifiers: self.ctx.syms.alloc_slice(modifiers),
             suppress_existential,
             world: None,
         })));
 
+        // Present "does" keeps the habitual reading a simple-present event
+        // verb gets: "John runs and Mary does too".
+        let present_event = matches!(aux_token, TokenType::Does | TokenType::Do)
+            && !crate::lexicon::lookup_verb_class(&self.interner.resolve(template.verb).to_lowercase()).is_stative();
+        let neo_event = if present_event {
+            self.ctx.exprs.alloc(LogicExpr::Aspectual { operator: AspectOperator::Habitual, body: neo_event })
+        } else {
+            neo_event
+        };
+
         // Apply modal if auxiliary is modal
         let with_modal = match aux_token {
             TokenType::Can | TokenType::Could => {
@@ -3128,6 +3536,91 @@ impl<'a, 'ctx, 'int> ClauseParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         Some(Ok(result))
     }
 
+    fn parse_ellipsis_subject(&mut self) -> Option<ParseResult<Symbol>> {
+        let saved_pos = self.current;
+        if matches!(self.peek().kind, TokenType::ProperName(_)) {
+            if let TokenType::ProperName(sym) = self.advance().kind {
+                return Some(Ok(sym));
+            }
+        } else if self.check_pronoun() {
+            let token = self.advance().clone();
+            if let TokenType::Pronoun { gender, number, .. } = token.kind {
+                return Some(match self.resolve_pronoun(gender, number) {
+                    Ok(super::ResolvedPronoun::Variable(s) | super::ResolvedPronoun::Constant(s)) => Ok(s),
+                    Err(e) => Err(e),
+                });
+            }
+        } else if matches!(self.peek().kind, TokenType::Article(Definiteness::Definite)) {
+            // "the woman did too" — a definite names its referent.
+            if let Some(np) = self.try_parse(|p| p.parse_noun_phrase(true)) {
+                return Some(Ok(np.noun));
+            }
+        }
+        self.current = saved_pos;
+        None
+    }
+
+    fn distribute_coordinated_quantifiers(&mut self) {
+        let is_quantifier = |k: &TokenType| {
+            matches!(
+                k,
+                TokenType::All | TokenType::No | TokenType::Some | TokenType::Most | TokenType::Few | TokenType::Many
+            )
+        };
+
+        // Each conjunct is a quantifier and its nominal, or a proper name.
+        let mut conjuncts: Vec<(usize, usize)> = Vec::new();
+        let mut i = self.current;
+        loop {
+            let start = i;
+            match self.tokens.get(i).map(|t| &t.kind) {
+                Some(TokenType::ProperName(_)) => i += 1,
+                Some(k) if is_quantifier(k) => {
+                    i += 1;
+                    while matches!(self.tokens.get(i).map(|t| &t.kind), Some(TokenType::Adjective(_))) {
+                        i += 1;
+                    }
+                    if !matches!(self.tokens.get(i).map(|t| &t.kind), Some(TokenType::Noun(_))) {
+                        return;
+                    }
+                    i += 1;
+                }
+                _ => return,
+            }
+            conjuncts.push((start, i));
+            if !matches!(self.tokens.get(i).map(|t| &t.kind), Some(TokenType::And)) {
+                break;
+            }
+            i += 1;
+        }
+        let quantified = conjuncts.iter().any(|&(start, _)| is_quantifier(&self.tokens[start].kind));
+        let TokenType::Verb { lemma, .. } = self.tokens.get(i).map_or(TokenType::EOF, |t| t.kind.clone()) else {
+            return;
+        };
+        if conjuncts.len() < 2 || !quantified || Lexer::is_collective_verb(self.interner.resolve(lemma)) {
+            return;
+        }
+
+        let vp_end = (i..self.tokens.len())
+            .find(|&j| {
+                matches!(
+                    self.tokens[j].kind,
+                    TokenType::Period | TokenType::Exclamation | TokenType::Comma | TokenType::EOF
+                )
+            })
+            .unwrap_or(self.tokens.len());
+        let vp: Vec<_> = self.tokens[i..vp_end].to_vec();
+        let mut rewritten = Vec::with_capacity(self.tokens.len() + vp.len() * conjuncts.len());
+        for (n, &(start, end)) in conjuncts.iter().enumerate() {
+            rewritten.extend_from_slice(&self.tokens[start..end]);
+            if n + 1 < conjuncts.len() {
+                rewritten.extend(vp.iter().cloned());
+                rewritten.push(self.tokens[end].clone()); // and
+            }
+        }
+        self.tokens.splice(self.current..i, rewritten);
+    }
+
     fn try_parse_of_pair_xor(&mut self) -> ParseResult<Option<&'a LogicExpr<'a>>> {
         let start = self.current;
 
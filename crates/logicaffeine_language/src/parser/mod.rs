@@ -74,15 +74,18 @@ pub use verb::{LogicVerbParsing, ImperativeVerbParsing};
 use crate::analysis::TypeRegistry;
 use crate::arena_ctx::AstContext;
 use crate::ast::{AspectOperator, CompressionCodec, SendLayout, LogicExpr, NeoEventData, NumberKind, QuantifierKind, TemporalOperator, Term, ThematicRole, Stmt, Expr, Literal, TypeExpr, BinaryOpKind, MatchArm};
+use crate::suggest::{self, WordClass};
+use crate::trace::{DerivationTrace, TraceRecorder};
 use crate::optimization::{by_keyword, pin_from_str, OptimizationConfig, PinSet};
-use crate::ast::stmt::{ReadSource, Pattern};
+use crate::ast::stmt::{CollectionKind, ReadSource, Pattern};
 use std::collections::HashSet;
 use crate::drs::{Case, Gender, Number, ReferentSource};
 use crate::drs::{Drs, BoxType, WorldState};
 use crate::error::{ParseError, ParseErrorKind};
-use logicaffeine_base::{Interner, Symbol, SymbolEq};
+use logicaffeine_base::{CancellationToken, Interner, Symbol, SymbolEq};
 use crate::lexer::Lexer;
 use crate::lexicon::{self, Aspect, Definiteness, Time, VerbClass};
+use crate::tense::TenseAspect;
 use crate::token::{BlockType, FocusKind, Span, Token, TokenType};
 
 pub(super) type ParseResult<T> = Result<T, ParseError>;
@@ -107,18 +110,29 @@ pub(super) enum CopulaTemporal {
     Eventually,
 }
 
-/// Controls scope of negation for lexically negative verbs (lacks, miss).
-/// "user who lacks a key" can mean:
-///   - Wide:   ¬∃y(Key(y) ∧ Have(x,y)) - "has NO keys" (natural reading)
-///   - Narrow: ∃y(Key(y) ∧ ¬Have(x,y)) - "missing SOME key" (literal reading)
+/// Controls the scope of negation against the operators around it.
+///
+/// | Construction | Narrow | Wide |
+/// |--------------|--------|------|
+/// | "user who lacks a key" | ∃y(Key(y) ∧ ¬Have(x,y)) | ¬∃y(Key(y) ∧ Have(x,y)) |
+/// | "Everyone didn't leave" | ∀x(Person(x) → ¬Leave(x)) | ¬∀x(Person(x) → Leave(x)) |
+/// | "John must not leave" | □¬Leave(John) | ¬□Leave(John) |
+/// | "I don't think he came" | Think(i, ⟨¬Came(he)⟩) | ¬Think(i, ⟨Came(he)⟩) |
+///
+/// `Surface` reads each construction as written: narrow for everything but
+/// a negated neg-raising verb ("think", "believe"), which keeps the
+/// negation on the matrix clause.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum NegativeScopeMode {
-    /// Narrow scope negation (literal reading): ∃y(Key(y) ∧ ¬Have(x,y))
-    /// "User is missing some key" - need all keys (default/traditional reading)
+    /// Negation scopes where the surface string puts it.
     #[default]
+    Surface,
+    /// Negation below quantifiers and modals, and lowered into the
+    /// complement of a neg-raising verb: ∃y(Key(y) ∧ ¬Have(x,y)) - "missing
+    /// SOME key".
     Narrow,
-    /// Wide scope negation (natural reading): ¬∃y(Key(y) ∧ Have(x,y))
-    /// "User has no keys" - need at least one key
+    /// Negation over quantifiers and modals: ¬∃y(Key(y) ∧ Have(x,y)) - "has
+    /// NO keys".
     Wide,
 }
 
@@ -152,6 +166,19 @@ pub enum ResolvedPronoun {
     Constant(Symbol),
 }
 
+/// A pronoun that had more than one accessible antecedent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AntecedentAmbiguity {
+    /// Index of the pronoun in the token stream.
+    pub token: usize,
+    pub span: Span,
+    /// The pronoun as written.
+    pub pronoun: String,
+    /// Candidate antecedents by noun or name, most preferred first: the
+    /// head is what the parser picks on its own.
+    pub candidates: Vec<String>,
+}
+
 #[derive(Clone)]
 struct ParserCheckpoint {
     pos: usize,
@@ -160,6 +187,7 @@ struct ParserCheckpoint {
     island: u32,
     time: Option<Time>,
     negative_depth: u32,
+    temporal: crate::drs::TimeCheckpoint,
 }
 
 /// RAII guard for speculative parsing with automatic rollback.
@@ -281,6 +309,9 @@ pub struct Parser<'a, 'ctx, 'int> {
     /// to user variables of the same name (ambiguity-preserving: a bound
     /// name is never shadowed by a literal reading).
     pub(super) user_bound: std::collections::HashSet<Symbol>,
+    /// Named groups of every variable bound to a pattern literal (`Let kv be the pattern "…"`),
+    /// so `Capture kv in line into m.` knows the record fields `m` will have.
+    pub(super) pattern_groups: std::collections::HashMap<Symbol, Vec<Symbol>>,
     /// Pending tense from temporal adverbs.
     pub(super) pending_time: Option<Time>,
     /// Donkey bindings: (noun, var, is_donkey_used, wide_scope_negation).
@@ -365,12 +396,32 @@ pub struct Parser<'a, 'ctx, 'int> {
     /// ownership cause-links, and the rustc sourcemap real spans without a
     /// span field on every `Stmt` variant. Read via [`Parser::stmt_spans`].
     pub(super) stmt_spans: Vec<Span>,
+    /// The source span of EVERY statement — top-level and inside indented
+    /// blocks — keyed by the statement's address in the arena. Block slices
+    /// never move once allocated, so a walker over the parsed tree (coverage
+    /// counters) can find any statement's line. Read via [`Parser::stmt_span_index`].
+    pub(super) stmt_span_index: std::collections::HashMap<usize, Span>,
     /// Live recursion depth of the imperative descent (parenthesized
     /// expressions, nested blocks). Checked against
     /// [`crate::ast_depth::max_ast_depth`] so a parenthesis tower or block
     /// pyramid errors gracefully instead of overflowing the parser's own
     /// stack — the parse-time half of the AST depth gate.
     pub(super) recursion_depth: usize,
+    /// Derivation trace recorder, present only while tracing
+    /// ([`Parser::enable_trace`]).
+    pub(super) trace: Option<TraceRecorder>,
+    /// Pronouns whose antecedent was ambiguous, in the order they were
+    /// resolved. Read via [`Parser::antecedent_ambiguities`].
+    pub(super) antecedent_ambiguities: Vec<AntecedentAmbiguity>,
+    /// Antecedents fixed by the caller: pronoun token index → the candidate
+    /// (by noun or name) to resolve to.
+    pub(super) antecedent_choices: std::collections::HashMap<usize, String>,
+    /// Sorts of gazetteer names ([`Parser::register_gazetteer`]), consulted
+    /// before the lexicon by the sort checks.
+    pub(super) name_sorts: std::collections::HashMap<Symbol, lexicon::Sort>,
+    /// Polled at every grammar-rule entry and imperative recursion step
+    /// ([`Parser::set_cancellation`]).
+    pub(super) cancel: Option<CancellationToken>,
 }
 
 impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
@@ -401,6 +452,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             program_tier_pins: PinSet::none(),
             var_counter: 0,
             user_bound: std::collections::HashSet::new(),
+            pattern_groups: std::collections::HashMap::new(),
             pending_time: None,
             donkey_bindings: Vec::new(),
             interner,
@@ -428,7 +480,13 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             in_negative_quantifier: false,
             pending_partitive: None,
             stmt_spans: Vec::new(),
+            stmt_span_index: std::collections::HashMap::new(),
             recursion_depth: 0,
+            trace: None,
+            antecedent_ambiguities: Vec::new(),
+            antecedent_choices: std::collections::HashMap::new(),
+            name_sorts: std::collections::HashMap::new(),
+            cancel: None,
         }
     }
 
@@ -438,10 +496,23 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         &self.stmt_spans
     }
 
+    /// The span of every statement of the last `parse_program` call, nested
+    /// ones included, keyed by the statement's address (`stmt as *const Stmt
+    /// as usize`). Valid for as long as the returned tree is alive and unmoved.
+    pub fn stmt_span_index(&self) -> &std::collections::HashMap<usize, Span> {
+        &self.stmt_span_index
+    }
+
     /// Record the span for the statement just pushed: from the first token of
     /// `start_tok` through the last consumed token (terminating period or
     /// dedent included). `parse_program` asserts 1:1 alignment on return.
     fn record_stmt_span(&mut self, start_tok: usize) {
+        let span = self.span_since(start_tok);
+        self.stmt_spans.push(span);
+    }
+
+    /// From the first token of `start_tok` through the last consumed token.
+    fn span_since(&self, start_tok: usize) -> Span {
         let start = self
             .tokens
             .get(start_tok)
@@ -452,7 +523,15 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             .get(self.current.saturating_sub(1))
             .map(|t| t.span.end)
             .unwrap_or(start);
-        self.stmt_spans.push(Span::new(start, end));
+        Span::new(start, end)
+    }
+
+    /// Index the spans of a nested block's statements, as parsed, by their
+    /// arena addresses (see [`Parser::stmt_span_index`]).
+    fn index_block_spans(&mut self, block: &[Stmt<'a>], spans: Vec<Span>) {
+        for (stmt, span) in block.iter().zip(spans) {
+            self.stmt_span_index.insert(stmt as *const Stmt as usize, span);
+        }
     }
 
     pub fn set_discourse_event_var(&mut self, var: Symbol) {
@@ -878,8 +957,8 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                     BlockType::Main | BlockType::Function => ParserMode::Imperative,
                     BlockType::Theorem | BlockType::Definition | BlockType::Define | BlockType::Proof |
                     BlockType::Example | BlockType::Logic | BlockType::Note | BlockType::TypeDef |
-                    BlockType::Policy | BlockType::Requires | BlockType::Axiom | BlockType::Theory |
-                    BlockType::Hardware | BlockType::Property
+                    BlockType::Policy | BlockType::Requires | BlockType::Settings | BlockType::Arguments |
+                    BlockType::Axiom | BlockType::Theory | BlockType::Hardware | BlockType::Property
                     | BlockType::SuspectedTypo { .. } => ParserMode::Declarative,
                     BlockType::No | BlockType::Tier => self.mode, // Annotation — keep current mode
                 };
@@ -1057,41 +1136,135 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         }))
     }
 
+    /// Parses the body of an embedded wh-question after its wh-word — "(who)
+    /// came", "(what) John ate" — as a question over a fresh variable filling
+    /// the gap.
     fn parse_embedded_wh_clause(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
-        // Parse embedded question body: "who runs", "what John ate"
-        let var_name = self.interner.intern("x");
-        let var_term = Term::Variable(var_name);
+        let wh_variable = self.next_var_name();
+        let body = self.parse_relative_clause(wh_variable)?;
+        Ok(self.ctx.exprs.alloc(LogicExpr::Question { wh_variable, body }))
+    }
 
-        if self.check_verb() {
-            // "who runs" pattern
-            let verb = self.consume_verb();
-            let body = self.ctx.exprs.alloc(LogicExpr::Predicate {
-                name: verb,
-                args: self.ctx.terms.alloc_slice([var_term]),
-                world: None,
-            });
-            return Ok(body);
-        }
+    /// Whether a progressive "be V-ing" starts here ("will BE RUNNING").
+    pub(super) fn at_progressive_be(&self) -> bool {
+        self.check_content_word()
+            && self.interner.resolve(self.peek().lexeme).eq_ignore_ascii_case("be")
+            && matches!(
+                self.tokens.get(self.current + 1).map(|t| &t.kind),
+                Some(TokenType::Verb { aspect: Aspect::Progressive, .. })
+            )
+    }
 
-        if self.check_content_word() || self.check_article() {
-            // "what John ate" pattern
-            let subject = self.parse_noun_phrase(true)?;
-            if self.check_verb() {
-                let verb = self.consume_verb();
-                let body = self.ctx.exprs.alloc(LogicExpr::Predicate {
-                    name: verb,
-                    args: self.ctx.terms.alloc_slice([
-                        Term::Constant(subject.noun),
-                        var_term,
-                    ]),
-                    world: None,
+    /// Negates a do-supported clause. Narrow scope lowers the negation of a
+    /// neg-raising verb into its propositional complement: "I don't think he
+    /// came" → Think(i, ⟨¬Came(he)⟩).
+    pub(super) fn negate_clause(&self, verb: Symbol, vp: &'a LogicExpr<'a>) -> &'a LogicExpr<'a> {
+        if self.negative_scope_mode == NegativeScopeMode::Narrow
+            && crate::lexicon::is_neg_raising_verb(&self.interner.resolve(verb).to_lowercase())
+        {
+            if let LogicExpr::Predicate { name, args: [holder, Term::Proposition(complement)], world } = vp {
+                let lowered = self.ctx.exprs.alloc(LogicExpr::UnaryOp {
+                    op: TokenType::Not,
+                    operand: complement,
+                });
+                return self.ctx.exprs.alloc(LogicExpr::Predicate {
+                    name: *name,
+                    args: self.ctx.terms.alloc_slice([*holder, Term::Proposition(lowered)]),
+                    world: *world,
                 });
-                return Ok(body);
             }
         }
+        self.ctx.exprs.alloc(LogicExpr::UnaryOp {
+            op: TokenType::Not,
+            operand: vp,
+        })
+    }
 
-        // Fallback: just the wh-variable
-        Ok(self.ctx.exprs.alloc(LogicExpr::Atom(var_name)))
+    /// Consumes a particle separated from its verb by the object ("gave the
+    /// book UP", "switched the lamp ON") and returns the phrasal verb's lemma.
+    /// A preposition only counts as a particle when it ends the clause, so
+    /// "put the book on the shelf" keeps its PP.
+    pub(super) fn take_separated_particle(&mut self, verb: Symbol) -> Option<Symbol> {
+        let particle = match self.peek().kind {
+            TokenType::Particle(p) => p,
+            TokenType::Preposition(p) if matches!(
+                self.tokens.get(self.current + 1).map(|t| &t.kind),
+                None | Some(TokenType::Period | TokenType::EOF | TokenType::Comma)
+            ) => p,
+            _ => return None,
+        };
+        let phrasal = self.phrasal_lemma(verb, particle)?;
+        self.advance(); // consume the particle
+        Some(phrasal)
+    }
+
+    /// The lemma of the phrasal verb `verb` + `particle`, if it is listed.
+    pub(super) fn phrasal_lemma(&mut self, verb: Symbol, particle: Symbol) -> Option<Symbol> {
+        let verb_str = self.interner.resolve(verb).to_lowercase();
+        let particle_str = self.interner.resolve(particle).to_lowercase();
+        let (phrasal_lemma, _class) = lexicon::lookup_phrasal_verb(&verb_str, &particle_str)?;
+        Some(self.interner.intern(phrasal_lemma))
+    }
+
+    /// Whether an interrogative complement of `verb` starts here: a wh-word,
+    /// "whether", or "if" after a question-embedding verb ("wonders if …").
+    pub(super) fn opens_question_complement(&self, verb: Symbol) -> bool {
+        self.check_wh_word() || self.opens_polar_question(verb)
+    }
+
+    fn opens_polar_question(&self, verb: Symbol) -> bool {
+        let token = self.peek();
+        self.interner.resolve(token.lexeme).eq_ignore_ascii_case("whether")
+            || (matches!(token.kind, TokenType::If)
+                && lexicon::is_question_embedding_verb(&self.interner.resolve(verb).to_lowercase()))
+    }
+
+    /// Parses a polar embedded question — "whether Mary left", "(wonders) if
+    /// Mary left" — as `?φ`. `None` when no such complement follows.
+    pub(super) fn try_parse_polar_question_complement(
+        &mut self,
+        verb: Symbol,
+    ) -> ParseResult<Option<&'a LogicExpr<'a>>> {
+        if !self.opens_polar_question(verb) {
+            return Ok(None);
+        }
+        self.advance(); // whether / if
+        let body = self.parse_atom()?;
+        Ok(Some(self.ctx.exprs.alloc(LogicExpr::YesNoQuestion { body })))
+    }
+
+    /// `Verb(e) ∧ Agent(e, subject) ∧ Theme(e, question)`: a question-embedding
+    /// verb takes its interrogative complement as an intensional argument, like
+    /// an attitude verb takes a proposition.
+    pub(super) fn question_embedding_event(
+        &mut self,
+        verb: Symbol,
+        subject: Term<'a>,
+        question: &'a LogicExpr<'a>,
+        time: Time,
+    ) -> &'a LogicExpr<'a> {
+        let event_var = self.get_event_var();
+        let suppress_existential = self.drs.in_conditional_antecedent();
+        if suppress_existential {
+            let event_class = self.interner.intern("Event");
+            self.drs.introduce_referent(event_var, event_class, Gender::Neuter, Number::Singular);
+        }
+        let modifiers: Vec<Symbol> = match time {
+            Time::Past => vec![self.interner.intern("Past")],
+            Time::Future => vec![self.interner.intern("Future")],
+            _ => vec![],
+        };
+        self.ctx.exprs.alloc(LogicExpr::NeoEvent(Box::new(NeoEventData {
+            event_var,
+            verb,
+            roles: self.ctx.roles.alloc_slice(vec![
+                (ThematicRole::Agent, subject),
+                (ThematicRole::Theme, Term::Proposition(question)),
+            ]),
+            modifiers: self.ctx.syms.alloc_slice(modifiers),
+            suppress_existential,
+            world: None,
+        })))
     }
 
     pub fn set_pp_attachment_mode(&mut self, attach_to_noun: bool) {
@@ -1132,6 +1305,23 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         self.modal_preference = pref;
     }
 
+    /// Abort the parse with [`ParseErrorKind::Cancelled`] once `cancel` is
+    /// cancelled. Backtracking may still fold the abort into another error, so
+    /// a caller that cancels checks its token rather than the error kind.
+    pub fn set_cancellation(&mut self, cancel: &CancellationToken) {
+        self.cancel = Some(cancel.clone());
+    }
+
+    fn check_cancelled(&self) -> ParseResult<()> {
+        match &self.cancel {
+            Some(cancel) if cancel.is_cancelled() => Err(ParseError {
+                kind: ParseErrorKind::Cancelled,
+                span: self.current_span(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
     fn checkpoint(&self) -> ParserCheckpoint {
         ParserCheckpoint {
             pos: self.current,
@@ -1140,6 +1330,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             island: self.current_island,
             time: self.pending_time,
             negative_depth: self.negative_depth,
+            temporal: self.world_state.time_checkpoint(),
         }
     }
 
@@ -1150,6 +1341,10 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         self.current_island = cp.island;
         self.pending_time = cp.time;
         self.negative_depth = cp.negative_depth;
+        self.world_state.restore_time(cp.temporal);
+        if let Some(trace) = &mut self.trace {
+            trace.rewind(cp.pos);
+        }
     }
 
     fn is_negative_context(&self) -> bool {
@@ -1178,6 +1373,44 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         }
     }
 
+    /// Records an ambiguous pronoun (several accessible antecedents) and,
+    /// when the caller fixed its antecedent, returns that referent.
+    fn choose_antecedent(&mut self, from_box: usize, gender: Gender, number: Number) -> Option<Symbol> {
+        let mut candidates: Vec<(Symbol, String)> = Vec::new();
+        for referent in self.drs.antecedent_candidates(from_box, gender, number) {
+            let name = self.interner.resolve(referent.noun_class).to_string();
+            if !candidates.iter().any(|(_, seen)| *seen == name) {
+                candidates.push((referent.variable, name));
+            }
+        }
+        if candidates.len() < 2 {
+            return None;
+        }
+
+        // The pronoun is normally the token just consumed.
+        let is_pronoun = |i: usize| matches!(self.tokens.get(i).map(|t| &t.kind), Some(TokenType::Pronoun { .. }));
+        let token = match self.current.checked_sub(1) {
+            Some(previous) if is_pronoun(previous) => previous,
+            _ => self.current.min(self.tokens.len() - 1),
+        };
+        let ambiguity = AntecedentAmbiguity {
+            token,
+            span: self.tokens[token].span,
+            pronoun: self.interner.resolve(self.tokens[token].lexeme).to_string(),
+            candidates: candidates.iter().map(|(_, name)| name.clone()).collect(),
+        };
+        // Backtracking can resolve the same pronoun twice; keep the latest.
+        match self.antecedent_ambiguities.iter_mut().find(|a| a.token == token) {
+            Some(existing) => *existing = ambiguity,
+            None => self.antecedent_ambiguities.push(ambiguity),
+        }
+
+        let wanted = self.antecedent_choices.get(&token)?;
+        let (variable, _) = candidates.iter().find(|(_, name)| name == wanted)?;
+        self.drs.mark_used_by_pronoun(*variable);
+        Some(*variable)
+    }
+
     fn resolve_pronoun(&mut self, gender: Gender, number: Number) -> ParseResult<ResolvedPronoun> {
         // MODAL BARRIER: In discourse mode, try telescope FIRST if prior sentence was modal.
         // This ensures the modal barrier check runs before we search the swapped DRS.
@@ -1221,6 +1454,13 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         // Try DRS resolution (scope-aware) for same-sentence referents
         let current_box = self.drs.current_box_index();
+        if let Some(sym) = self.choose_antecedent(current_box, gender, number) {
+            return Ok(if self.drs.is_rigid_referent(sym) {
+                ResolvedPronoun::Constant(sym)
+            } else {
+                ResolvedPronoun::Variable(sym)
+            });
+        }
         match self.drs.resolve_pronoun(current_box, gender, number) {
             Ok(sym) => {
                 // A rigid referent (proper name / deictic constant) denotes
@@ -1627,7 +1867,102 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
     /// The parser maintains discourse state across sentences, enabling
     /// anaphora resolution ("he", "she", "they" refer to prior entities)
     /// and temporal coherence (tense interpretation relative to reference time).
+    ///
+    /// A failure caused by a misspelled word is reported as
+    /// [`ParseErrorKind::UnknownWord`] with ranked corrections.
     pub fn parse(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
+        self.traced("discourse", Self::parse_discourse)
+            .map_err(|error| self.diagnose_unknown_word(error))
+    }
+
+    /// Starts recording a derivation trace: every grammar rule entered from
+    /// here on is logged with the tokens it consumed. Collect it with
+    /// [`Parser::take_trace`].
+    pub fn enable_trace(&mut self) {
+        self.trace = Some(TraceRecorder::default());
+    }
+
+    /// Stops tracing and returns what was recorded (empty if tracing was
+    /// never enabled).
+    pub fn take_trace(&mut self) -> DerivationTrace {
+        self.trace.take().map(TraceRecorder::finish).unwrap_or_default()
+    }
+
+    /// Fixes the antecedent of the pronoun at token index `pronoun` to the
+    /// candidate named `antecedent` (a noun or proper name, as listed in
+    /// [`AntecedentAmbiguity::candidates`]). Ignored if that candidate is
+    /// not accessible when the pronoun is reached.
+    pub fn set_antecedent_choice(&mut self, pronoun: usize, antecedent: &str) {
+        self.antecedent_choices.insert(pronoun, antecedent.to_string());
+    }
+
+    /// Makes the sorts of `gazetteer`'s names visible to the sort checks
+    /// (metaphor detection), as if they were lexicon entries.
+    pub fn register_gazetteer(&mut self, gazetteer: &crate::gazetteer::Gazetteer) {
+        for entry in gazetteer.entries() {
+            if let Some(sort) = entry.sort {
+                let name = self.interner.intern(&entry.symbol());
+                self.name_sorts.insert(name, sort);
+            }
+        }
+    }
+
+    /// The sort of a noun or name: a registered gazetteer sort, else the
+    /// lexicon's.
+    pub(super) fn sort_of(&self, noun: Symbol) -> Option<lexicon::Sort> {
+        self.name_sorts
+            .get(&noun)
+            .copied()
+            .or_else(|| lexicon::lookup_sort(self.interner.resolve(noun)))
+    }
+
+    /// Pronouns the last parse found more than one antecedent for.
+    pub fn antecedent_ambiguities(&self) -> &[AntecedentAmbiguity] {
+        &self.antecedent_ambiguities
+    }
+
+    /// Runs one grammar rule, recording it in the derivation trace when
+    /// tracing is on.
+    pub(super) fn traced<T>(
+        &mut self,
+        rule: &'static str,
+        body: impl FnOnce(&mut Self) -> ParseResult<T>,
+    ) -> ParseResult<T> {
+        self.check_cancelled()?;
+        let Some(trace) = &mut self.trace else {
+            return body(self);
+        };
+        let first = self.current;
+        trace.enter(rule, first);
+        let result = body(self);
+        // Token splicing can shrink the stream under a rule; clamp to it.
+        let end = self.current.max(first).min(self.tokens.len());
+        let consumed = &self.tokens[first.min(end)..end];
+        let span = match (consumed.first(), consumed.last()) {
+            (Some(a), Some(b)) => Span::new(a.span.start, b.span.end),
+            _ => Span::default(),
+        };
+        let text = consumed
+            .iter()
+            .map(|t| self.interner.resolve(t.lexeme))
+            .collect::<Vec<_>>()
+            .join(" ");
+        if let Some(trace) = &mut self.trace {
+            trace.exit(end, span, text, result.is_ok());
+        }
+        result
+    }
+
+    /// Records that the parser took `branch`, a named alternative inside a
+    /// rule (`"sentence/cleft"`), when tracing is on. Every branch name is
+    /// listed in [`crate::coverage::BRANCHES`].
+    pub(super) fn branch(&mut self, branch: &'static str) {
+        if let Some(trace) = &mut self.trace {
+            trace.branch(branch);
+        }
+    }
+
+    fn parse_discourse(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
         let mut result = self.parse_prefix()?;
 
         // Postposed "while": "Y while X." mirrors the fronted "While X, Y."
@@ -1816,6 +2151,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
     pub fn parse_program(&mut self) -> ParseResult<Vec<Stmt<'a>>> {
         let mut statements = Vec::new();
         self.stmt_spans.clear();
+        self.stmt_span_index.clear();
         let mut in_definition_block = false;
         let mut pending_opt_flags = OptimizationConfig::all_on();
 
@@ -1942,6 +2278,14 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                         self.advance();
                         continue;
                     }
+                    BlockType::Settings | BlockType::Arguments => {
+                        // The settings and argument schemas are registered as structs by DiscoveryPass
+                        // Skip content until next block header
+                        in_definition_block = true;
+                        self.mode = ParserMode::Declarative;
+                        self.advance();
+                        continue;
+                    }
                     BlockType::Hardware | BlockType::Property => {
                         // Hardware signal declarations and temporal property assertions
                         // Skip content until next block header
@@ -2069,6 +2413,10 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             self.stmt_spans.first().copied().unwrap_or_else(|| self.current_span()),
         )?;
 
+        for (stmt, span) in statements.iter().zip(&self.stmt_spans) {
+            self.stmt_span_index.insert(stmt as *const Stmt as usize, *span);
+        }
+
         Ok(statements)
     }
 
@@ -2076,6 +2424,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
     /// the depth limit (the parse-time half of the AST depth gate). Pair
     /// every call with [`Parser::leave_recursion`].
     pub(super) fn enter_recursion(&mut self) -> ParseResult<()> {
+        self.check_cancelled()?;
         self.recursion_depth += 1;
         let limit = crate::ast_depth::max_parse_recursion();
         if self.recursion_depth > limit {
@@ -2174,14 +2523,40 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         if self.check(&TokenType::NetConnect) {
             return self.parse_connect_statement();
         }
+        if self.check_word("Join") && self.peek_word_at(1, "the") && self.peek_word_at(2, "swarm") {
+            return self.parse_join_swarm_statement();
+        }
         if self.check(&TokenType::Sleep) {
             return self.parse_sleep_statement();
         }
         // `Run <f> on <arg> under <Name> into <var>` — invoke a shipped computation through a
         // named acceptance contract; desugars to `Let <var> be run_accepted(<f>, <arg>, lo, hi)`.
+        if self.check_word("Run") && self.peek_word_at(1, "the") && self.peek_word_at(2, "command") {
+            return self.parse_run_command_statement();
+        }
         if self.check_word("Run") {
             return self.parse_run_under_contract();
         }
+        // `Sip the file <path> from <sources>[, reporting progress to <function>].`
+        if self.check_word("Sip") && self.peek_word_at(1, "the") && self.peek_word_at(2, "file") {
+            return self.parse_sip_statement();
+        }
+        // Text sentences: `Split <text> by <separator> into <var>.` and
+        // `Replace <old> with <new> in <var>.` desugar to the `text_*` builtins.
+        if self.check_word("Split") {
+            return self.parse_split_statement();
+        }
+        if self.check_word("Replace") {
+            return self.parse_replace_statement();
+        }
+        // `Capture <pattern> in <text> into <var>.` — the first match as a record.
+        if self.check_word("Capture") {
+            return self.parse_capture_statement();
+        }
+        // `Sort <var> [by <field>] [ascending|descending].` — a stable sort (`sorted_by`).
+        if self.check_word("Sort") {
+            return self.parse_sort_statement();
+        }
         // Phase 52: GossipSub sync statement
         if self.check(&TokenType::Sync) {
             return self.parse_sync_statement();
@@ -2209,6 +2584,9 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         if self.check(&TokenType::Show) {
             return self.parse_show_statement();
         }
+        if self.check_word("Log") && self.peek_log_level().is_some() {
+            return self.parse_log_statement();
+        }
         // Phase 33: Pattern matching on sum types
         if self.check(&TokenType::Inspect) {
             return self.parse_inspect_statement();
@@ -2355,6 +2733,12 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             }
         }
 
+        // A misspelled statement keyword (`Shwo x.`) is a spelling error,
+        // not a missing statement.
+        if let Some(unknown) = self.unknown_word_at(self.current, &[WordClass::Keyword], None) {
+            return Err(unknown);
+        }
+
         Err(ParseError {
             kind: ParseErrorKind::ExpectedStatement,
             span: self.current_span(),
@@ -2384,11 +2768,14 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // Parse the then body — an indented block, OR (inline guard) a single
         // statement on the same line: `If c: Return 1.`
         let mut then_stmts = Vec::new();
+        let mut then_spans = Vec::new();
         if self.check(&TokenType::Indent) {
             self.advance(); // consume Indent
             while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+                let start_tok = self.current;
                 let stmt = self.parse_statement()?;
                 then_stmts.push(stmt);
+                then_spans.push(self.span_since(start_tok));
                 if self.check(&TokenType::Period) {
                     self.advance();
                 }
@@ -2397,8 +2784,10 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 self.advance();
             }
         } else {
+            let start_tok = self.current;
             let stmt = self.parse_statement()?;
             then_stmts.push(stmt);
+            then_spans.push(self.span_since(start_tok));
             if self.check(&TokenType::Period) {
                 self.advance();
             }
@@ -2407,6 +2796,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // Allocate then_block in arena
         let then_block = self.ctx.stmts.expect("imperative arenas not initialized")
             .alloc_slice(then_stmts.into_iter());
+        self.index_block_spans(then_block, then_spans);
 
         // Check for else clause: Otherwise/Else/Otherwise If/Else If/elif
         let else_block = if self.check(&TokenType::Otherwise) || self.check(&TokenType::Else) {
@@ -2415,9 +2805,11 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             // Check for "Otherwise If" / "Else If" chain
             if self.check(&TokenType::If) {
                 // Parse as else-if: create single-statement else block containing nested If
+                let start_tok = self.current;
                 let nested_if = self.parse_if_statement()?;
                 let nested_slice = self.ctx.stmts.expect("imperative arenas not initialized")
                     .alloc_slice(std::iter::once(nested_if));
+                self.index_block_spans(nested_slice, vec![self.span_since(start_tok)]);
                 Some(nested_slice)
             } else {
                 // Regular else block - expect colon and indent
@@ -2431,11 +2823,14 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
                 // Indented block OR inline: `Otherwise: Show "x".`
                 let mut else_stmts = Vec::new();
+                let mut else_spans = Vec::new();
                 if self.check(&TokenType::Indent) {
                     self.advance(); // consume Indent
                     while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+                        let start_tok = self.current;
                         let stmt = self.parse_statement()?;
                         else_stmts.push(stmt);
+                        else_spans.push(self.span_since(start_tok));
                         if self.check(&TokenType::Period) {
                             self.advance();
                         }
@@ -2444,23 +2839,29 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                         self.advance();
                     }
                 } else {
+                    let start_tok = self.current;
                     let stmt = self.parse_statement()?;
                     else_stmts.push(stmt);
+                    else_spans.push(self.span_since(start_tok));
                     if self.check(&TokenType::Period) {
                         self.advance();
                     }
                 }
 
-                Some(self.ctx.stmts.expect("imperative arenas not initialized")
-                    .alloc_slice(else_stmts.into_iter()))
+                let else_block = self.ctx.stmts.expect("imperative arenas not initialized")
+                    .alloc_slice(else_stmts.into_iter());
+                self.index_block_spans(else_block, else_spans);
+                Some(else_block)
             }
         } else if self.check(&TokenType::Elif) {
             // Python-style elif: equivalent to "Else If"
             self.advance(); // consume "elif"
             // Parse the condition and body directly (elif acts like "Else If" without the separate If token)
+            let start_tok = self.current;
             let nested_if = self.parse_elif_as_if()?;
             let nested_slice = self.ctx.stmts.expect("imperative arenas not initialized")
                 .alloc_slice(std::iter::once(nested_if));
+            self.index_block_spans(nested_slice, vec![self.span_since(start_tok)]);
             Some(nested_slice)
         } else {
             None
@@ -2491,11 +2892,14 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // Parse the then body — an indented block, OR (inline guard) a single
         // statement on the same line: `If c: Return 1.`
         let mut then_stmts = Vec::new();
+        let mut then_spans = Vec::new();
         if self.check(&TokenType::Indent) {
             self.advance(); // consume Indent
             while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+                let start_tok = self.current;
                 let stmt = self.parse_statement()?;
                 then_stmts.push(stmt);
+                then_spans.push(self.span_since(start_tok));
                 if self.check(&TokenType::Period) {
                     self.advance();
                 }
@@ -2504,8 +2908,10 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 self.advance();
             }
         } else {
+            let start_tok = self.current;
             let stmt = self.parse_statement()?;
             then_stmts.push(stmt);
+            then_spans.push(self.span_since(start_tok));
             if self.check(&TokenType::Period) {
                 self.advance();
             }
@@ -2514,6 +2920,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // Allocate then_block in arena
         let then_block = self.ctx.stmts.expect("imperative arenas not initialized")
             .alloc_slice(then_stmts.into_iter());
+        self.index_block_spans(then_block, then_spans);
 
         // Check for else clause: Otherwise/Else/Otherwise If/Else If/elif
         let else_block = if self.check(&TokenType::Otherwise) || self.check(&TokenType::Else) {
@@ -2521,9 +2928,11 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
             // Check for "Otherwise If" / "Else If" chain
             if self.check(&TokenType::If) {
+                let start_tok = self.current;
                 let nested_if = self.parse_if_statement()?;
                 let nested_slice = self.ctx.stmts.expect("imperative arenas not initialized")
                     .alloc_slice(std::iter::once(nested_if));
+                self.index_block_spans(nested_slice, vec![self.span_since(start_tok)]);
                 Some(nested_slice)
             } else {
                 // Regular else block
@@ -2537,11 +2946,14 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
                 // Indented block OR inline: `Otherwise: Show "x".`
                 let mut else_stmts = Vec::new();
+                let mut else_spans = Vec::new();
                 if self.check(&TokenType::Indent) {
                     self.advance(); // consume Indent
                     while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+                        let start_tok = self.current;
                         let stmt = self.parse_statement()?;
                         else_stmts.push(stmt);
+                        else_spans.push(self.span_since(start_tok));
                         if self.check(&TokenType::Period) {
                             self.advance();
                         }
@@ -2550,21 +2962,27 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                         self.advance();
                     }
                 } else {
+                    let start_tok = self.current;
                     let stmt = self.parse_statement()?;
                     else_stmts.push(stmt);
+                    else_spans.push(self.span_since(start_tok));
                     if self.check(&TokenType::Period) {
                         self.advance();
                     }
                 }
 
-                Some(self.ctx.stmts.expect("imperative arenas not initialized")
-                    .alloc_slice(else_stmts.into_iter()))
+                let else_block = self.ctx.stmts.expect("imperative arenas not initialized")
+                    .alloc_slice(else_stmts.into_iter());
+                self.index_block_spans(else_block, else_spans);
+                Some(else_block)
             }
         } else if self.check(&TokenType::Elif) {
             self.advance(); // consume "elif"
+            let start_tok = self.current;
             let nested_if = self.parse_elif_as_if()?;
             let nested_slice = self.ctx.stmts.expect("imperative arenas not initialized")
                 .alloc_slice(std::iter::once(nested_if));
+            self.index_block_spans(nested_slice, vec![self.span_since(start_tok)]);
             Some(nested_slice)
         } else {
             None
@@ -2627,9 +3045,12 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         self.advance(); // consume Indent
 
         let mut body_stmts = Vec::new();
+        let mut body_spans = Vec::new();
         while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+            let start_tok = self.current;
             let stmt = self.parse_statement()?;
             body_stmts.push(stmt);
+            body_spans.push(self.span_since(start_tok));
             if self.check(&TokenType::Period) {
                 self.advance();
             }
@@ -2641,6 +3062,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         let body = self.ctx.stmts.expect("imperative arenas not initialized")
             .alloc_slice(body_stmts.into_iter());
+        self.index_block_spans(body, body_spans);
 
         Ok(Stmt::While { cond, body, decreasing })
     }
@@ -2699,13 +3121,17 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             }
             self.advance(); // consume Indent
             let mut body_stmts = Vec::new();
+            let mut body_spans = Vec::new();
             while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+                let start_tok = self.current;
                 let stmt = self.parse_statement()?;
                 body_stmts.push(stmt);
+                body_spans.push(self.span_since(start_tok));
                 if self.check(&TokenType::Period) { self.advance(); }
             }
             if self.check(&TokenType::Dedent) { self.advance(); }
             let body = self.ctx.stmts.expect("imperative arenas not initialized").alloc_slice(body_stmts.into_iter());
+            self.index_block_spans(body, body_spans);
             let cond = self.ctx.alloc_imperative_expr(Expr::Literal(crate::ast::Literal::Boolean(true)));
             return Ok(Stmt::While { cond, body, decreasing: None });
         }
@@ -2764,9 +3190,12 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         // Parse body statements
         let mut body_stmts = Vec::new();
+        let mut body_spans = Vec::new();
         while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+            let start_tok = self.current;
             let stmt = self.parse_statement()?;
             body_stmts.push(stmt);
+            body_spans.push(self.span_since(start_tok));
             if self.check(&TokenType::Period) {
                 self.advance();
             }
@@ -2778,6 +3207,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         let body = self.ctx.stmts.expect("imperative arenas not initialized")
             .alloc_slice(body_stmts.into_iter());
+        self.index_block_spans(body, body_spans);
 
         Ok(Stmt::Repeat { pattern, iterable, body })
     }
@@ -2836,9 +3266,12 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         // Parse body statements
         let mut body_stmts = Vec::new();
+        let mut body_spans = Vec::new();
         while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+            let start_tok = self.current;
             let stmt = self.parse_statement()?;
             body_stmts.push(stmt);
+            body_spans.push(self.span_since(start_tok));
             if self.check(&TokenType::Period) {
                 self.advance();
             }
@@ -2850,6 +3283,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         let body = self.ctx.stmts.expect("imperative arenas not initialized")
             .alloc_slice(body_stmts.into_iter());
+        self.index_block_spans(body, body_spans);
 
         Ok(Stmt::Repeat { pattern, iterable, body })
     }
@@ -2928,7 +3362,73 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
     fn parse_condition(&mut self) -> ParseResult<&'a Expr<'a>> {
         // Grand Challenge: Parse compound conditions with "and" and "or"
         // "or" has lower precedence than "and"
-        let expr = self.parse_or_condition()?;
+        let mut expr = self.parse_or_condition()?;
+        // Text postfixes, chainable: `<text> trimmed`, `<seq> joined with|by <sep>`, and
+        // `<text> with <old> replaced by <new>` → `text_trim` / `text_join` / `text_replace`.
+        // `<old>` may be `the pattern "…"`, whose replacement is a template (`$1`, `${name}`).
+        loop {
+            if self.check_word("trimmed") {
+                self.advance(); // "trimmed"
+                let func = self.interner.intern("text_trim");
+                expr = self.ctx.alloc_imperative_expr(Expr::Call { function: func, args: vec![expr] });
+            } else if self.check_word("joined") && (self.peek_word_at(1, "with") || self.peek_word_at(1, "by")) {
+                self.advance(); // "joined"
+                self.advance(); // "with" / "by"
+                let sep = self.parse_or_condition()?;
+                let func = self.interner.intern("text_join");
+                expr = self.ctx.alloc_imperative_expr(Expr::Call { function: func, args: vec![expr, sep] });
+            } else if self.check_word("with")
+                && ((matches!(self.tokens.get(self.current + 1).map(|t| &t.kind), Some(TokenType::StringLiteral(_)))
+                    && self.peek_word_at(2, "replaced")
+                    && self.peek_word_at(3, "by"))
+                    || (self.peek_word_at(1, "the")
+                        && self.peek_word_at(2, "pattern")
+                        && matches!(self.tokens.get(self.current + 3).map(|t| &t.kind), Some(TokenType::StringLiteral(_)))
+                        && self.peek_word_at(4, "replaced")
+                        && self.peek_word_at(5, "by")))
+            {
+                self.advance(); // "with"
+                let from = self.parse_primary_expr()?;
+                self.advance(); // "replaced"
+                self.advance(); // "by"
+                let to = self.parse_or_condition()?;
+                let func = self.interner.intern("text_replace");
+                expr = self.ctx.alloc_imperative_expr(Expr::Call { function: func, args: vec![expr, from, to] });
+            } else {
+                break;
+            }
+        }
+        // Formatting: `<moment> formatted as "%d %B %Y" [in "<zone>"]` → `format_as(moment, pattern)`.
+        // A trailing zone formats the local wall clock there, by shifting the operand to the zone's
+        // local-as-UTC instant (as `the hour of m in "<zone>"` does).
+        if self.check_word("formatted") && self.peek_word_at(1, "as") {
+            if let Some(TokenType::StringLiteral(pattern_sym)) = self.tokens.get(self.current + 2).map(|t| &t.kind) {
+                let pattern_sym = *pattern_sym;
+                self.advance(); // "formatted"
+                self.advance(); // "as"
+                self.advance(); // the pattern string
+                let mut operand = expr;
+                if self.check(&TokenType::In) || self.check_preposition_is("in") {
+                    if let Some(TokenType::StringLiteral(zone_sym)) =
+                        self.tokens.get(self.current + 1).map(|t| &t.kind)
+                    {
+                        let zone_sym = *zone_sym;
+                        self.advance(); // "in"
+                        self.advance(); // the zone string
+                        let zone = self.ctx.alloc_imperative_expr(Expr::Literal(crate::ast::Literal::Text(zone_sym)));
+                        let local = self.interner.intern("local_instant");
+                        operand = self
+                            .ctx
+                            .alloc_imperative_expr(Expr::Call { function: local, args: vec![operand, zone] });
+                    }
+                }
+                let pattern = self.ctx.alloc_imperative_expr(Expr::Literal(crate::ast::Literal::Text(pattern_sym)));
+                let func = self.interner.intern("format_as");
+                return Ok(self
+                    .ctx
+                    .alloc_imperative_expr(Expr::Call { function: func, args: vec![operand, pattern] }));
+            }
+        }
         // Postfix unit conversion: `<expr> in <unit>` → `convert(expr, "unit")`, e.g.
         // `2 inches + 5 centimeters in feet`. The lowest-precedence postfix, so `in` applies to the
         // whole expression. Gated on the token after `in` being a UNIT word, so `for each x in list`
@@ -3098,6 +3598,37 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             return Ok(self.ctx.alloc_imperative_expr(Expr::Not { operand: contains }));
         }
 
+        // Text affix tests: `line starts with "#"` / `name ends with ".lg"` → `text_starts_with` /
+        // `text_ends_with`, a Bool like any comparison.
+        if (self.check_word("starts") || self.check_word("ends")) && self.peek_word_at(1, "with") {
+            let func = if self.check_word("starts") { "text_starts_with" } else { "text_ends_with" };
+            self.advance(); // "starts" / "ends"
+            self.advance(); // "with"
+            let affix = self.parse_xor_expr()?;
+            let func = self.interner.intern(func);
+            return Ok(self.ctx.alloc_imperative_expr(Expr::Call { function: func, args: vec![left, affix] }));
+        }
+
+        // Pattern test: `line matches the pattern "\d+"` (or a bare literal, `line matches "\d+"`,
+        // validated here like any pattern literal) → `pattern_matches`, a Bool.
+        if self.check_op_word("matches") {
+            self.advance(); // "matches"
+            let literal = match self.peek().kind {
+                TokenType::StringLiteral(source) => Some(source),
+                _ => None,
+            };
+            let pattern = match literal {
+                Some(source) => {
+                    let span = self.current_span();
+                    self.advance(); // the pattern literal
+                    self.pattern_literal(source, span)?
+                }
+                None => self.parse_xor_expr()?,
+            };
+            let func = self.interner.intern("pattern_matches");
+            return Ok(self.ctx.alloc_imperative_expr(Expr::Call { function: func, args: vec![left, pattern] }));
+        }
+
         // Check for comparison operators
         let op = if self.check(&TokenType::Equals) {
             self.advance();
@@ -3461,8 +3992,17 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             return Ok(Stmt::LaunchTaskWithHandle { handle: var, function, args });
         }
 
-        // Parse expression value (simple: just a number for now)
-        let value = self.parse_imperative_expr()?;
+        // `every n in numbers where n is even` / `the set of every …`: a comprehension.
+        let set_head = self.check_word("the")
+            && self.peek_word_at(1, "set")
+            && self.peek_word_at(2, "of")
+            && self.peek_word_at(3, "every");
+        let value = if set_head || self.check_op_word("every") {
+            self.parse_comprehension(ty, set_head)?
+        } else {
+            // Parse expression value (simple: just a number for now)
+            self.parse_imperative_expr()?
+        };
 
         // Phase 43B: Type check - verify declared type matches value type
         if let Some(declared_ty) = &ty {
@@ -3505,6 +4045,14 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         self.world_state.drs.introduce_referent(var, var, crate::drs::Gender::Unknown, crate::drs::Number::Singular);
 
         self.user_bound.insert(var);
+        match self.pattern_group_names(value) {
+            Some(groups) => {
+                self.pattern_groups.insert(var, groups);
+            }
+            None => {
+                self.pattern_groups.remove(&var);
+            }
+        }
         Ok(Stmt::Let { var, ty, value, mutable })
     }
 
@@ -3778,7 +4326,15 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // Also handle index targets: Set item N of X to Y
         match target_expr {
             Expr::FieldAccess { object, field } => {
-                Ok(Stmt::SetField { object: *object, field: *field, value })
+                // Nested path: `Set user's address's city to v` rebuilds each record on the way
+                // up — `Set user's address to (user's address with city set to v)` — so only the
+                // outermost variable is assigned, on every tier.
+                let (mut object, mut field, mut value) = (*object, *field, value);
+                while let Expr::FieldAccess { object: outer, field: outer_field } = object {
+                    value = self.with_field(object, field, value);
+                    (object, field) = (*outer, *outer_field);
+                }
+                Ok(Stmt::SetField { object, field, value })
             }
             Expr::Identifier(target) => {
                 Ok(Stmt::Set { target: *target, value })
@@ -4137,6 +4693,376 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         Ok(Stmt::Let { var, ty: None, value: call, mutable: false })
     }
 
+    /// Parse Split statement - break text into a `Seq of Text`
+    /// Syntax: Split <expr> by (commas | spaces | tabs | semicolons | whitespace | lines | <expr>) into <var>.
+    /// `by whitespace` drops empty pieces (`text_words`); `by lines` accepts `\r\n` (`text_lines`).
+    /// Splitting into an already-bound variable re-assigns it, as `Let` does.
+    fn parse_split_statement(&mut self) -> ParseResult<Stmt<'a>> {
+        self.advance(); // "Split"
+        let source = self.parse_additive_expr()?;
+        self.expect_keyword("by")?;
+        let named = |w: &str| match w.to_ascii_lowercase().as_str() {
+            "comma" | "commas" => Some(","),
+            "space" | "spaces" => Some(" "),
+            "tab" | "tabs" => Some("\t"),
+            "semicolon" | "semicolons" => Some(";"),
+            "colon" | "colons" => Some(":"),
+            _ => None,
+        };
+        let lexeme = self.interner.resolve(self.peek().lexeme).to_string();
+        let is_literal = matches!(self.peek().kind, TokenType::StringLiteral(_) | TokenType::CharLiteral(_));
+        let value = if !is_literal && (lexeme.eq_ignore_ascii_case("whitespace") || lexeme.eq_ignore_ascii_case("lines")) {
+            self.advance(); // "whitespace" / "lines"
+            let func = if lexeme.eq_ignore_ascii_case("lines") { "text_lines" } else { "text_words" };
+            let func = self.interner.intern(func);
+            self.ctx.alloc_imperative_expr(Expr::Call { function: func, args: vec![source] })
+        } else {
+            let sep = match named(&lexeme).filter(|_| !is_literal) {
+                Some(sep) => {
+                    self.advance(); // the separator word
+                    let sep = self.interner.intern(sep);
+                    self.ctx.alloc_imperative_expr(Expr::Literal(crate::ast::Literal::Text(sep)))
+                }
+                None => self.parse_additive_expr()?,
+            };
+            let func = self.interner.intern("text_split");
+            self.ctx.alloc_imperative_expr(Expr::Call { function: func, args: vec![source, sep] })
+        };
+        self.expect_keyword("into")?;
+        let var = self.expect_identifier()?;
+        if self.user_bound.contains(&var) {
+            return Ok(Stmt::Set { target: var, value });
+        }
+        self.world_state.drs.introduce_referent(var, var, crate::drs::Gender::Unknown, crate::drs::Number::Singular);
+        self.user_bound.insert(var);
+        Ok(Stmt::Let { var, ty: None, value, mutable: false })
+    }
+
+    /// Parse Replace statement - find/replace in a Text variable
+    /// Syntax: Replace <old> with <new> in <var>.
+    /// Every occurrence is replaced (`text_replace`); the variable is updated in place.
+    fn parse_replace_statement(&mut self) -> ParseResult<Stmt<'a>> {
+        self.advance(); // "Replace"
+        let from = self.parse_additive_expr()?;
+        self.expect_keyword("with")?;
+        let to = self.parse_additive_expr()?;
+        if !(self.check(&TokenType::In) || self.check_preposition_is("in")) {
+            return Err(ParseError {
+                kind: ParseErrorKind::ExpectedKeyword { keyword: "in".to_string() },
+                span: self.current_span(),
+            });
+        }
+        self.advance(); // "in"
+        let target = self.expect_identifier()?;
+        let current = self.ctx.alloc_imperative_expr(Expr::Identifier(target));
+        let func = self.interner.intern("text_replace");
+        let value = self.ctx.alloc_imperative_expr(Expr::Call { function: func, args: vec![current, from, to] });
+        Ok(Stmt::Set { target, value })
+    }
+
+    /// Parse Sort statement - reorder a sequence
+    /// Syntax: Sort <var> [by <field>] [ascending | descending].
+    /// The sort is stable (`sorted_by`): sorting by one field and then another orders by the
+    /// second, ties broken by the first. Without `by`, items sort by their own order — a
+    /// struct's needs a `Comparable` declaration.
+    fn parse_sort_statement(&mut self) -> ParseResult<Stmt<'a>> {
+        self.advance(); // "Sort"
+        let target = self.expect_identifier()?;
+        let key = self.parse_sort_key()?;
+        let descending = if self.check_word("descending") {
+            self.advance(); // "descending"
+            true
+        } else {
+            if self.check_word("ascending") {
+                self.advance(); // "ascending"
+            }
+            false
+        };
+        let current = self.ctx.alloc_imperative_expr(Expr::Identifier(target));
+        let direction = self.ctx.alloc_imperative_expr(Expr::Literal(crate::ast::Literal::Boolean(descending)));
+        let func = self.interner.intern("sorted_by");
+        let value = self.ctx.alloc_imperative_expr(Expr::Call { function: func, args: vec![current, key, direction] });
+        Ok(Stmt::Set { target, value })
+    }
+
+    /// `… with age set to 31 and name set to "Bo"` after a record: each update wraps the
+    /// record in `with_field(record, "age", 31)`, so the copy is rebuilt without touching
+    /// the original. Without `with <field> set to` the record is returned as is.
+    fn parse_field_updates(&mut self, mut record: &'a Expr<'a>) -> ParseResult<&'a Expr<'a>> {
+        use crate::ast::Expr;
+        if !(self.check_word("with") && self.peek_word_at(2, "set") && self.peek_word_at(3, "to")) {
+            return Ok(record);
+        }
+        self.advance(); // "with"
+        loop {
+            let field = self.expect_identifier()?;
+            self.expect_keyword("set")?;
+            self.expect_keyword("to")?;
+            // `parse_comparison` stops before `and`, which separates the updates.
+            let value = self.parse_comparison()?;
+            record = self.with_field(record, field, value);
+            if self.check(&TokenType::And) && self.peek_word_at(2, "set") {
+                self.advance(); // "and"
+                continue;
+            }
+            return Ok(record);
+        }
+    }
+
+    /// `with_field(record, "<field>", value)` — a copy of `record` with one field replaced.
+    fn with_field(&mut self, record: &'a Expr<'a>, field: Symbol, value: &'a Expr<'a>) -> &'a Expr<'a> {
+        use crate::ast::Expr;
+        let key = self.ctx.alloc_imperative_expr(Expr::Literal(crate::ast::Literal::Text(field)));
+        let function = self.interner.intern("with_field");
+        self.ctx.alloc_imperative_expr(Expr::Call { function, args: vec![record, key, value] })
+    }
+
+    /// The `by <field>` of a sort as a Text literal — `""` (the items' own order) when absent.
+    fn parse_sort_key(&mut self) -> ParseResult<&'a Expr<'a>> {
+        let field = if self.check_word("by") {
+            self.advance(); // "by"
+            if matches!(self.peek().kind, TokenType::Period | TokenType::EOF | TokenType::Newline) {
+                return Err(ParseError {
+                    kind: ParseErrorKind::Custom("expected the field to sort by after `by`".to_string()),
+                    span: self.current_span(),
+                });
+            }
+            let field = self.peek().lexeme;
+            self.advance(); // the field name
+            field
+        } else {
+            self.interner.intern("")
+        };
+        Ok(self.ctx.alloc_imperative_expr(Expr::Literal(crate::ast::Literal::Text(field))))
+    }
+
+    /// Parse Capture statement - the first match of a pattern as a record
+    /// Syntax: Capture <pattern> in <text> into <var>.
+    /// `<var>` gets `matched` (Bool), `text` (the whole match) and one Text field per named
+    /// group, `""` when absent. The groups must be known here — from a pattern literal or a
+    /// variable bound to one — because they are the record's fields.
+    fn parse_capture_statement(&mut self) -> ParseResult<Stmt<'a>> {
+        self.advance(); // "Capture"
+        let pattern = self.parse_primary_expr()?;
+        if !(self.check(&TokenType::In) || self.check_preposition_is("in")) {
+            return Err(ParseError {
+                kind: ParseErrorKind::ExpectedKeyword { keyword: "in".to_string() },
+                span: self.current_span(),
+            });
+        }
+        self.advance(); // "in"
+        let text = self.parse_additive_expr()?;
+        self.expect_keyword("into")?;
+        let var = self.expect_identifier()?;
+        let groups = self.pattern_group_names(pattern).unwrap_or_default();
+        let mut args = vec![pattern, text];
+        for group in groups {
+            if matches!(self.interner.resolve(group), "matched" | "text") {
+                return Err(ParseError {
+                    kind: ParseErrorKind::Custom(format!(
+                        "a pattern group cannot be named `{}`: the capture record already has that field",
+                        self.interner.resolve(group)
+                    )),
+                    span: self.current_span(),
+                });
+            }
+            args.push(self.ctx.alloc_imperative_expr(Expr::Literal(crate::ast::Literal::Text(group))));
+        }
+        let func = self.interner.intern("pattern_capture");
+        let value = self.ctx.alloc_imperative_expr(Expr::Call { function: func, args });
+        self.world_state.drs.introduce_referent(var, var, crate::drs::Gender::Unknown, crate::drs::Number::Singular);
+        self.user_bound.insert(var);
+        Ok(Stmt::Let { var, ty: None, value, mutable: false })
+    }
+
+    /// `pattern(<source>)` for a pattern literal, validated now: a malformed pattern is a compile
+    /// error at the literal, never a runtime failure.
+    fn pattern_literal(&mut self, source: Symbol, span: Span) -> ParseResult<&'a Expr<'a>> {
+        if let Err(e) = logicaffeine_base::pattern::Pattern::parse(self.interner.resolve(source)) {
+            return Err(ParseError {
+                kind: ParseErrorKind::Custom(format!("invalid pattern \"{}\": {}", self.interner.resolve(source), e)),
+                span,
+            });
+        }
+        let literal = self.ctx.alloc_imperative_expr(Expr::Literal(crate::ast::Literal::Text(source)));
+        let func = self.interner.intern("pattern");
+        Ok(self.ctx.alloc_imperative_expr(Expr::Call { function: func, args: vec![literal] }))
+    }
+
+    /// The named groups of a pattern known at compile time — a pattern literal, or a variable
+    /// bound to one — or `None` when the expression is not such a pattern.
+    fn pattern_group_names(&mut self, expr: &Expr<'a>) -> Option<Vec<Symbol>> {
+        match expr {
+            Expr::Call { function, args } if self.interner.resolve(*function) == "pattern" => {
+                let Some(Expr::Literal(crate::ast::Literal::Text(source))) = args.first().copied() else {
+                    return None;
+                };
+                let parsed = logicaffeine_base::pattern::Pattern::parse(self.interner.resolve(*source)).ok()?;
+                let names: Vec<String> = parsed.named_groups().map(str::to_string).collect();
+                Some(names.iter().map(|n| self.interner.intern(n)).collect())
+            }
+            Expr::Identifier(sym) => self.pattern_groups.get(sym).cloned(),
+            _ => None,
+        }
+    }
+
+    /// Parse a comprehension: `every n in numbers where n is even`.
+    ///
+    /// `every <e> for <x> in <c>` collects `e`; `every <k> mapped to <v> for <x> in <c>` builds
+    /// a Map; `the set of every …` (or a declared `Set of …`) builds a Set. The optional
+    /// `where <cond>` filters the elements before they are collected.
+    fn parse_comprehension(
+        &mut self,
+        ty: Option<&'a TypeExpr<'a>>,
+        set_head: bool,
+    ) -> ParseResult<&'a Expr<'a>> {
+        if set_head {
+            self.advance(); // "the"
+            self.advance(); // "set"
+            self.advance(); // "of"
+        }
+        self.advance(); // "every"
+
+        // `every n in …` binds and collects `n`; any other head is an expression over a
+        // binder introduced by the `for` that follows it.
+        let (pattern, element, mapped) = if self.peek_word_at(1, "in") {
+            let x = self.expect_identifier()?;
+            (Pattern::Identifier(x), self.ctx.alloc_imperative_expr(Expr::Identifier(x)), None)
+        } else {
+            let element = self.parse_imperative_expr()?;
+            let mapped = if self.check_word("mapped") {
+                self.advance(); // "mapped"
+                if !self.check_to_preposition() {
+                    return Err(ParseError {
+                        kind: ParseErrorKind::ExpectedKeyword { keyword: "to".to_string() },
+                        span: self.current_span(),
+                    });
+                }
+                self.advance(); // "to"
+                Some(self.parse_imperative_expr()?)
+            } else {
+                None
+            };
+            if !self.check(&TokenType::For) {
+                return Err(ParseError {
+                    kind: ParseErrorKind::ExpectedKeyword { keyword: "for".to_string() },
+                    span: self.current_span(),
+                });
+            }
+            self.advance(); // "for"
+            (self.parse_loop_pattern()?, element, mapped)
+        };
+
+        if !(self.check(&TokenType::In) || self.check_preposition_is("in")) {
+            return Err(ParseError {
+                kind: ParseErrorKind::ExpectedKeyword { keyword: "in".to_string() },
+                span: self.current_span(),
+            });
+        }
+        self.advance(); // "in"
+        let iterable = self.parse_imperative_expr()?;
+        let filter = if self.check_word("where") {
+            self.advance(); // "where"
+            Some(self.parse_condition()?)
+        } else {
+            None
+        };
+
+        let into_set = set_head
+            || matches!(ty, Some(TypeExpr::Generic { base, .. })
+                if matches!(self.interner.resolve(*base), "Set" | "HashSet"));
+        let kind = match mapped {
+            Some(_) => CollectionKind::Map,
+            None if into_set => CollectionKind::Set,
+            None => CollectionKind::Seq,
+        };
+        Ok(self.ctx.alloc_imperative_expr(Expr::Comprehension {
+            kind,
+            element,
+            value: mapped,
+            pattern,
+            iterable,
+            filter,
+        }))
+    }
+
+    /// Parse Run statement - execute a subprocess
+    /// Syntax: Run the command <expr> [with a timeout of <duration>]
+    ///         [and capture its output into <var> [(, | and) its errors into <var>]
+    ///          [(, | and) its exit code into <var>]].
+    fn parse_run_command_statement(&mut self) -> ParseResult<Stmt<'a>> {
+        self.advance(); // "Run"
+        self.advance(); // "the"
+        self.advance(); // "command"
+        let command = self.parse_additive_expr()?;
+
+        let mut timeout = None;
+        if self.check_word("with") {
+            self.advance(); // "with"
+            if self.check_word("a") {
+                self.advance();
+            }
+            self.expect_keyword("timeout")?;
+            self.expect_keyword("of")?;
+            timeout = Some(self.parse_additive_expr()?);
+        }
+
+        let (mut output, mut errors, mut exit_code) = (None, None, None);
+        if self.check(&TokenType::And) && self.peek_word_at(1, "capture") {
+            self.advance(); // "and"
+            self.advance(); // "capture"
+            loop {
+                self.expect_keyword("its")?;
+                let slot = if self.check_word("output") {
+                    &mut output
+                } else if self.check_word("errors") {
+                    &mut errors
+                } else if self.check_word("exit") && self.peek_word_at(1, "code") {
+                    self.advance(); // "exit"
+                    &mut exit_code
+                } else {
+                    return Err(ParseError {
+                        kind: ParseErrorKind::Custom(
+                            "a command can capture `its output`, `its errors` or `its exit code`".to_string(),
+                        ),
+                        span: self.current_span(),
+                    });
+                };
+                self.advance(); // "output" / "errors" / "code"
+                self.expect_keyword("into")?;
+                *slot = Some(self.expect_identifier()?);
+                if !(self.check(&TokenType::Comma) || self.check(&TokenType::And)) {
+                    break;
+                }
+                self.advance(); // "," or "and"
+            }
+        }
+
+        Ok(Stmt::RunCommand { command, timeout, output, errors, exit_code })
+    }
+
+    /// Parse Sip statement - a verified, resumable chunked file transfer
+    /// Syntax: Sip the file <expr> from <expr> [, reporting progress to <function>].
+    fn parse_sip_statement(&mut self) -> ParseResult<Stmt<'a>> {
+        self.advance(); // "Sip"
+        self.advance(); // "the"
+        self.advance(); // "file"
+        let file = self.parse_additive_expr()?;
+        self.expect_keyword("from")?;
+        let sources = self.parse_additive_expr()?;
+
+        let mut progress = None;
+        if self.check(&TokenType::Comma) && self.peek_word_at(1, "reporting") {
+            self.advance(); // ","
+            self.advance(); // "reporting"
+            self.expect_keyword("progress")?;
+            self.expect_keyword("to")?;
+            progress = Some(self.expect_identifier()?);
+        }
+
+        Ok(Stmt::Sip { file, sources, progress })
+    }
+
     /// Phase 51: Parse Listen statement - bind to network address
     /// Syntax: Listen on [address].
     fn parse_listen_statement(&mut self) -> ParseResult<Stmt<'a>> {
@@ -4158,25 +5084,76 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         Ok(Stmt::Listen { address, secure })
     }
 
-    /// Phase 51: Parse Connect statement - dial remote peer
-    /// Syntax: Connect to [address].
-    fn parse_connect_statement(&mut self) -> ParseResult<Stmt<'a>> {
-        self.advance(); // consume "Connect"
-
-        // Expect "to" (can be TokenType::To or preposition)
-        if !self.check(&TokenType::To) && !self.check_preposition_is("to") {
-            return Err(ParseError {
-                kind: ParseErrorKind::ExpectedKeyword { keyword: "to".to_string() },
-                span: self.current_span(),
-            });
+    /// Phase 51: Parse Connect statement - dial remote peer
+    /// Syntax: Connect to [address].
+    fn parse_connect_statement(&mut self) -> ParseResult<Stmt<'a>> {
+        self.advance(); // consume "Connect"
+
+        // Expect "to" (can be TokenType::To or preposition)
+        if !self.check(&TokenType::To) && !self.check_preposition_is("to") {
+            return Err(ParseError {
+                kind: ParseErrorKind::ExpectedKeyword { keyword: "to".to_string() },
+                span: self.current_span(),
+            });
+        }
+        self.advance(); // consume "to"
+
+        // Parse address expression
+        let address = self.parse_imperative_expr()?;
+
+        let secure = self.parse_secure_clause()?;
+        Ok(Stmt::ConnectTo { address, secure })
+    }
+
+    /// Parse Join statement - find peers
+    /// Syntax: Join the swarm [using <method> (and <method>)*].
+    /// Methods: `local discovery`, `bootstrap peers [at <addrs>]`, `relays [at <urls>]`.
+    fn parse_join_swarm_statement(&mut self) -> ParseResult<Stmt<'a>> {
+        self.advance(); // "Join"
+        self.advance(); // "the"
+        self.advance(); // "swarm"
+
+        let mut using = Vec::new();
+        if !self.check_word("using") {
+            return Ok(Stmt::JoinSwarm { using });
+        }
+        self.advance(); // "using"
+        loop {
+            let method = if self.check_word("local") {
+                self.advance();
+                self.expect_keyword("discovery")?;
+                crate::ast::SwarmDiscovery::Local
+            } else if self.check_word("bootstrap") {
+                self.advance();
+                self.expect_keyword("peers")?;
+                crate::ast::SwarmDiscovery::Bootstrap(self.parse_swarm_addresses()?)
+            } else if self.check_word("relays") || self.check_word("relay") {
+                self.advance();
+                crate::ast::SwarmDiscovery::Relays(self.parse_swarm_addresses()?)
+            } else {
+                return Err(ParseError {
+                    kind: ParseErrorKind::ExpectedKeyword {
+                        keyword: "local discovery, bootstrap peers or relays".to_string(),
+                    },
+                    span: self.current_span(),
+                });
+            };
+            using.push(method);
+            if !self.check(&TokenType::And) {
+                return Ok(Stmt::JoinSwarm { using });
+            }
+            self.advance(); // "and"
+        }
+    }
+
+    /// The optional `at <addrs>` after a discovery method. Parsed below `and`, so
+    /// `relays at r and local discovery` lists two methods.
+    fn parse_swarm_addresses(&mut self) -> ParseResult<Option<&'a Expr<'a>>> {
+        if !self.check_word("at") {
+            return Ok(None);
         }
-        self.advance(); // consume "to"
-
-        // Parse address expression
-        let address = self.parse_imperative_expr()?;
-
-        let secure = self.parse_secure_clause()?;
-        Ok(Stmt::ConnectTo { address, secure })
+        self.advance(); // "at"
+        Ok(Some(self.parse_additive_expr()?))
     }
 
     /// Parse the optional PNP one-time-pad clause `with pad "<path>" as initiator|responder` that may
@@ -4967,12 +5944,15 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         self.advance();
 
         let mut stmts = Vec::new();
+        let mut spans = Vec::new();
         while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+            let start_tok = self.current;
             let stmt = self.parse_statement()?;
             stmts.push(stmt);
             if self.check(&TokenType::Period) {
                 self.advance();
             }
+            spans.push(self.span_since(start_tok));
         }
 
         // Consume dedent
@@ -4982,6 +5962,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         let block = self.ctx.stmts.expect("imperative arenas not initialized")
             .alloc_slice(stmts.into_iter());
+        self.index_block_spans(block, spans);
 
         Ok(block)
     }
@@ -5052,6 +6033,50 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         Ok(Stmt::Show { object, recipient })
     }
 
+    /// The level word after `Log` (`Log info …`), if the next token is one.
+    fn peek_log_level(&self) -> Option<crate::ast::LogLevel> {
+        let token = self.tokens.get(self.current + 1)?;
+        if matches!(token.kind, TokenType::StringLiteral(_) | TokenType::CharLiteral(_)) {
+            return None;
+        }
+        crate::ast::LogLevel::from_word(self.interner.resolve(token.lexeme))
+    }
+
+    /// Parse Log statement - a structured log event
+    /// Syntax: Log <level> <message> [with <field> ((, | and) <field>)*].
+    /// Levels: `trace`, `debug`, `info`, `warn` (or `warning`), `error`. Each
+    /// field is a variable, logged under its own name.
+    fn parse_log_statement(&mut self) -> ParseResult<Stmt<'a>> {
+        let level = self.peek_log_level().expect("checked by the caller");
+        self.advance(); // "Log"
+        self.advance(); // level
+
+        let message = self.parse_imperative_expr()?;
+
+        let mut fields = Vec::new();
+        if self.check_word("with") {
+            self.advance(); // "with"
+            loop {
+                let value = self.parse_additive_expr()?;
+                let Expr::Identifier(name) = value else {
+                    return Err(ParseError {
+                        kind: ParseErrorKind::Custom(
+                            "a logged field must be a variable, e.g. `Log info \"user joined\" with id.`".to_string(),
+                        ),
+                        span: self.current_span(),
+                    });
+                };
+                fields.push((*name, value));
+                if !(self.check(&TokenType::Comma) || self.check(&TokenType::And)) {
+                    break;
+                }
+                self.advance(); // "," or "and"
+            }
+        }
+
+        Ok(Stmt::Log { level, message, fields })
+    }
+
     /// Phase 43D: Parse Push statement for collection operations
     /// Syntax: Push x to items.
     fn parse_push_statement(&mut self) -> ParseResult<Stmt<'a>> {
@@ -5211,9 +6236,38 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             self.advance(); // consume "file"
             let path = self.parse_imperative_expr()?;
             ReadSource::File(path)
+        } else if self.check_word("settings") {
+            let settings_tok = self.current_span();
+            self.advance(); // consume "settings"
+            // No path: the settings come from the environment alone.
+            let path = if self.check(&TokenType::Period) || self.check(&TokenType::Newline) || self.check(&TokenType::EOF) {
+                None
+            } else {
+                Some(self.parse_imperative_expr()?)
+            };
+            let type_name = self.interner.intern(crate::analysis::SETTINGS_TYPE);
+            let schema = match self.type_registry.as_ref() {
+                Some(registry) => registry.settings_schema(type_name, self.interner).map(|_| ()),
+                None => Ok(()),
+            };
+            if let Err(message) = schema {
+                return Err(ParseError { kind: ParseErrorKind::Custom(message), span: settings_tok });
+            }
+            ReadSource::Settings { type_name, path }
+        } else if self.check_word("arguments") {
+            let arguments_tok = self.current_span();
+            self.advance(); // consume "arguments"
+            let schema = match self.type_registry.as_ref() {
+                Some(registry) => registry.arguments_schema(self.interner).map(|_| ()),
+                None => Ok(()),
+            };
+            if let Err(message) = schema {
+                return Err(ParseError { kind: ParseErrorKind::Custom(message), span: arguments_tok });
+            }
+            ReadSource::Arguments { type_name: self.interner.intern(crate::analysis::ARGUMENTS_TYPE) }
         } else {
             return Err(ParseError {
-                kind: ParseErrorKind::ExpectedKeyword { keyword: "console or file".to_string() },
+                kind: ParseErrorKind::ExpectedKeyword { keyword: "console, file, settings or arguments".to_string() },
                 span: self.current_span(),
             });
         };
@@ -5395,9 +6449,12 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         // Parse body statements
         let mut body_stmts = Vec::new();
+        let mut body_spans = Vec::new();
         while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+            let start_tok = self.current;
             let stmt = self.parse_statement()?;
             body_stmts.push(stmt);
+            body_spans.push(self.span_since(start_tok));
             if self.check(&TokenType::Period) {
                 self.advance();
             }
@@ -5410,6 +6467,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         let body = self.ctx.stmts.expect("imperative arenas not initialized")
             .alloc_slice(body_stmts.into_iter());
+        self.index_block_spans(body, body_spans);
 
         Ok(Stmt::Zone { name, capacity, source_file, body })
     }
@@ -5501,9 +6559,12 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         // Parse body statements
         let mut task_stmts = Vec::new();
+        let mut task_spans = Vec::new();
         while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+            let start_tok = self.current;
             let stmt = self.parse_statement()?;
             task_stmts.push(stmt);
+            task_spans.push(self.span_since(start_tok));
             if self.check(&TokenType::Period) {
                 self.advance();
             }
@@ -5516,6 +6577,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         let tasks = self.ctx.stmts.expect("imperative arenas not initialized")
             .alloc_slice(task_stmts.into_iter());
+        self.index_block_spans(tasks, task_spans);
 
         Ok(Stmt::Concurrent { tasks })
     }
@@ -5551,9 +6613,12 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         // Parse body statements
         let mut task_stmts = Vec::new();
+        let mut task_spans = Vec::new();
         while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+            let start_tok = self.current;
             let stmt = self.parse_statement()?;
             task_stmts.push(stmt);
+            task_spans.push(self.span_since(start_tok));
             if self.check(&TokenType::Period) {
                 self.advance();
             }
@@ -5566,6 +6631,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         let tasks = self.ctx.stmts.expect("imperative arenas not initialized")
             .alloc_slice(task_stmts.into_iter());
+        self.index_block_spans(tasks, task_spans);
 
         Ok(Stmt::Parallel { tasks })
     }
@@ -5618,12 +6684,15 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 self.advance(); // consume ":"
 
                 // Handle both inline (Otherwise: stmt.) and block body
-                let body_stmts = if self.check(&TokenType::Indent) {
+                let (body_stmts, body_spans) = if self.check(&TokenType::Indent) {
                     self.advance(); // consume Indent
                     let mut stmts = Vec::new();
+                    let mut spans = Vec::new();
                     while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+                        let start_tok = self.current;
                         let stmt = self.parse_statement()?;
                         stmts.push(stmt);
+                        spans.push(self.span_since(start_tok));
                         if self.check(&TokenType::Period) {
                             self.advance();
                         }
@@ -5631,18 +6700,20 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                     if self.check(&TokenType::Dedent) {
                         self.advance();
                     }
-                    stmts
+                    (stmts, spans)
                 } else {
                     // Inline body: "Otherwise: Show x."
+                    let start_tok = self.current;
                     let stmt = self.parse_statement()?;
                     if self.check(&TokenType::Period) {
                         self.advance();
                     }
-                    vec![stmt]
+                    (vec![stmt], vec![self.span_since(start_tok)])
                 };
 
                 let body = self.ctx.stmts.expect("imperative arenas not initialized")
                     .alloc_slice(body_stmts.into_iter());
+                self.index_block_spans(body, body_spans);
 
                 arms.push(MatchArm { enum_name: None, variant: None, bindings: vec![], body });
                 has_otherwise = true;
@@ -5734,9 +6805,12 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         // Parse body statements
         let mut body_stmts = Vec::new();
+        let mut body_spans = Vec::new();
         while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+            let start_tok = self.current;
             let stmt = self.parse_statement()?;
             body_stmts.push(stmt);
+            body_spans.push(self.span_since(start_tok));
             if self.check(&TokenType::Period) {
                 self.advance();
             }
@@ -5749,6 +6823,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         let body = self.ctx.stmts.expect("imperative arenas not initialized")
             .alloc_slice(body_stmts.into_iter());
+        self.index_block_spans(body, body_spans);
 
         Ok(MatchArm { enum_name, variant: Some(variant), bindings, body })
     }
@@ -5791,12 +6866,15 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         self.advance(); // consume ":"
 
         // Handle both inline body (When Variant: stmt.) and block body
-        let body_stmts = if self.check(&TokenType::Indent) {
+        let (body_stmts, body_spans) = if self.check(&TokenType::Indent) {
             self.advance(); // consume Indent
             let mut stmts = Vec::new();
+            let mut spans = Vec::new();
             while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+                let start_tok = self.current;
                 let stmt = self.parse_statement()?;
                 stmts.push(stmt);
+                spans.push(self.span_since(start_tok));
                 if self.check(&TokenType::Period) {
                     self.advance();
                 }
@@ -5804,18 +6882,20 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             if self.check(&TokenType::Dedent) {
                 self.advance();
             }
-            stmts
+            (stmts, spans)
         } else {
             // Inline body: "When Red: Show x."
+            let start_tok = self.current;
             let stmt = self.parse_statement()?;
             if self.check(&TokenType::Period) {
                 self.advance();
             }
-            vec![stmt]
+            (vec![stmt], vec![self.span_since(start_tok)])
         };
 
         let body = self.ctx.stmts.expect("imperative arenas not initialized")
             .alloc_slice(body_stmts.into_iter());
+        self.index_block_spans(body, body_spans);
 
         Ok(MatchArm { enum_name, variant: Some(variant), bindings, body })
     }
@@ -6670,6 +7750,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         // Parse body statements
         let mut body_stmts = Vec::new();
+        let mut body_spans = Vec::new();
         // Contract clauses: `Requires <cond>.` (precondition, checked at entry) and
         // `Ensures <cond>.` (postcondition, checked before every return). Collected
         // here and desugared into enforced (hard) asserts below — NOT emitted as
@@ -6698,8 +7779,10 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 if self.check(&TokenType::Period) { self.advance(); }
                 continue;
             }
+            let start_tok = self.current;
             let stmt = self.parse_statement()?;
             body_stmts.push(stmt);
+            body_spans.push(self.span_since(start_tok));
             if self.check(&TokenType::Period) {
                 self.advance();
             }
@@ -6716,6 +7799,8 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // Desugar postconditions: a hard assert before EVERY return path (recursing
         // into nested control flow) and at fallthrough — so no exit escapes the check.
         if !ensures_checks.is_empty() {
+            // The rewritten statements are copies: they keep no span.
+            body_spans.clear();
             body_stmts = self.insert_ensures_before_returns(&body_stmts, &ensures_checks);
             if !matches!(body_stmts.last(), Some(Stmt::Return { .. })) {
                 for &c in &ensures_checks {
@@ -6736,6 +7821,8 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // Allocate body in arena
         let body = self.ctx.stmts.expect("imperative arenas not initialized")
             .alloc_slice(body_stmts.into_iter());
+        // The precondition asserts lead the body and are no statement of the source.
+        self.index_block_spans(&body[requires_checks.len()..], body_spans);
 
         Ok(Stmt::FunctionDef {
             name,
@@ -6802,6 +7889,23 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             }
         }
 
+        // `the sync status of x` → the invisible `sync_status(x)` builtin: a shared value's version,
+        // unpublished changes, peers and concurrent writes. The four-word prefix can't start any
+        // other expression.
+        if self.check_word("the")
+            && self.peek_word_at(1, "sync")
+            && self.peek_word_at(2, "status")
+            && self.peek_word_at(3, "of")
+        {
+            self.advance(); // "the"
+            self.advance(); // "sync"
+            self.advance(); // "status"
+            self.advance(); // "of"
+            let target = self.parse_primary_expr()?;
+            let func = self.interner.intern("sync_status");
+            return Ok(self.ctx.alloc_imperative_expr(Expr::Call { function: func, args: vec![target] }));
+        }
+
         // Natural timestamp literal: `timestamp "2024-03-10T07:30:00Z"` → a Moment. Reads as a
         // value, not a function call; lowers to the (invisible) parse_timestamp. Only fires when an
         // identifier `timestamp` is immediately followed by a string, so a variable named
@@ -6897,6 +8001,13 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             // Phase 33: Extended for variant constructors "a new Circle with radius 10"
             // Phase 34: Extended for generic instantiation "a new Box of Int"
             TokenType::Article(_) => {
+                // `a copy of user with age set to 31` — the article before `copy` is decoration.
+                if matches!(self.tokens.get(self.current + 1).map(|t| &t.kind), Some(TokenType::Copy))
+                    && self.peek_word_at(2, "of")
+                {
+                    self.advance(); // "a"
+                    return self.parse_primary_expr();
+                }
                 // Natural date-component accessor: `the year|month|day|weekday of <moment>` reads as
                 // a sentence and desugars to the extractor (which threads through every tier). The
                 // component words lex as CalendarUnit/Noun; we match by lexeme and require `of` next.
@@ -6951,6 +8062,69 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                                 .alloc_imperative_expr(Expr::Call { function: func, args: vec![operand] }));
                         }
                     }
+                    // Selections: `the largest 3 items of scores [by points]` / `the smallest …` — the
+                    // first N of a stable sort, so tied items keep their order in the sequence.
+                    let pick = match self.interner.resolve(cl).to_ascii_lowercase().as_str() {
+                        "largest" => Some("largest_of"),
+                        "smallest" => Some("smallest_of"),
+                        _ => None,
+                    };
+                    if let Some(pick) = pick.filter(|_| self.peek_word_at(3, "items") || self.peek_word_at(3, "item")) {
+                        self.advance(); // "the"
+                        self.advance(); // "largest" / "smallest"
+                        let count = self.parse_primary_expr()?;
+                        self.advance(); // "items"
+                        self.expect_keyword("of")?;
+                        let seq = self.parse_primary_expr()?;
+                        let key = self.parse_sort_key()?;
+                        let func = self.interner.intern(pick);
+                        return Ok(self
+                            .ctx
+                            .alloc_imperative_expr(Expr::Call { function: func, args: vec![seq, count, key] }));
+                    }
+                    // Pattern literal: `the pattern "(?<key>\w+)=(?<value>\d+)"` — validated now.
+                    if self.interner.resolve(cl).eq_ignore_ascii_case("pattern") {
+                        if let Some(TokenType::StringLiteral(source)) = self.tokens.get(self.current + 2).map(|t| &t.kind) {
+                            let source = *source;
+                            self.advance(); // "the"
+                            self.advance(); // "pattern"
+                            let span = self.current_span();
+                            self.advance(); // the pattern literal
+                            return self.pattern_literal(source, span);
+                        }
+                    }
+                    // Text views: `the uppercase of <text>` / `the lowercase of <text>`, and
+                    // `the position of <needle> in <text>` (1-based, 0 when absent). `position` is a
+                    // plausible struct field, so without the trailing `in` it backtracks to field access.
+                    let view = match self.interner.resolve(cl).to_ascii_lowercase().as_str() {
+                        "uppercase" => Some("text_uppercase"),
+                        "lowercase" => Some("text_lowercase"),
+                        "position" => Some("text_find"),
+                        _ => None,
+                    };
+                    if let Some(view) = view {
+                        if self.interner.resolve(ol).eq_ignore_ascii_case("of") {
+                            let saved = self.current;
+                            self.advance(); // "the"
+                            self.advance(); // "uppercase" / "lowercase" / "position"
+                            self.advance(); // "of"
+                            let operand = self.parse_primary_expr()?;
+                            let func = self.interner.intern(view);
+                            if view != "text_find" {
+                                return Ok(self
+                                    .ctx
+                                    .alloc_imperative_expr(Expr::Call { function: func, args: vec![operand] }));
+                            }
+                            if self.check(&TokenType::In) || self.check_preposition_is("in") {
+                                self.advance(); // "in"
+                                let haystack = self.parse_primary_expr()?;
+                                return Ok(self
+                                    .ctx
+                                    .alloc_imperative_expr(Expr::Call { function: func, args: vec![haystack, operand] }));
+                            }
+                            self.current = saved;
+                        }
+                    }
                     // Natural elapsed-time: `the <unit> between <a> and <b>`. FIXED-width units
                     // (second…week) divide the second-difference; CALENDAR units (month/year) count
                     // complete periods (a month is 28–31 days, so it cannot be a fixed division).
@@ -7708,7 +8882,8 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 self.advance(); // consume "of"
 
                 let expr = self.parse_imperative_expr()?;
-                Ok(self.ctx.alloc_imperative_expr(Expr::Copy { expr }))
+                let copy = self.ctx.alloc_imperative_expr(Expr::Copy { expr });
+                self.parse_field_updates(copy)
             }
 
             // Phase 48: Manifest expression: "manifest of Zone"
@@ -8152,9 +9327,12 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             self.advance(); // consume Indent
 
             let mut block_stmts = Vec::new();
+            let mut block_spans = Vec::new();
             while !self.check(&TokenType::Dedent) && !self.is_at_end() {
+                let start_tok = self.current;
                 let stmt = self.parse_statement()?;
                 block_stmts.push(stmt);
+                block_spans.push(self.span_since(start_tok));
                 if self.check(&TokenType::Period) {
                     self.advance();
                 }
@@ -8165,6 +9343,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
             let block = self.ctx.stmts.expect("imperative arenas not initialized")
                 .alloc_slice(block_stmts.into_iter());
+            self.index_block_spans(block, block_spans);
             ClosureBody::Block(block)
         } else {
             // Single expression body — use parse_condition to support comparisons and boolean ops
@@ -8714,6 +9893,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
                         result?
                     };
+                    self.check_hole_names(sub_expr)?;
 
                     let format_sym = format_spec.map(|s| self.interner.intern(&s));
                     parts.push(StringPart::Expr {
@@ -8745,6 +9925,43 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         Ok(parts)
     }
 
+    /// A name read in an interpolation hole must be written somewhere outside the string — a
+    /// `{nmae}` typo is reported here, at the string, instead of as an undefined variable at run
+    /// time or a Rust error in the generated code. Deliberately conservative: any mention of the
+    /// name elsewhere in the program counts, so scoping stays with the later passes.
+    fn check_hole_names(&self, expr: &Expr<'a>) -> ParseResult<()> {
+        if self.mode != ParserMode::Imperative {
+            return Ok(());
+        }
+        let mut names = Vec::new();
+        collect_hole_names(expr, &mut names);
+        for sym in names {
+            let name = self.interner.resolve(sym);
+            // `today` and `now` read the clock; they are never declared.
+            if name.eq_ignore_ascii_case("today") || name.eq_ignore_ascii_case("now") {
+                continue;
+            }
+            let written = self.tokens.iter().any(|t| {
+                let word = match t.kind {
+                    TokenType::StringLiteral(_) | TokenType::InterpolatedString(_) | TokenType::CharLiteral(_) => return false,
+                    TokenType::Noun(s) | TokenType::ProperName(s) | TokenType::Adjective(s) => Some(s),
+                    TokenType::Verb { lemma, .. } => Some(lemma),
+                    _ => None,
+                };
+                std::iter::once(t.lexeme)
+                    .chain(word)
+                    .any(|w| self.interner.resolve(w).eq_ignore_ascii_case(name))
+            });
+            if !written {
+                return Err(ParseError {
+                    kind: ParseErrorKind::UndefinedVariable { name: name.to_string() },
+                    span: self.current_span(),
+                });
+            }
+        }
+        Ok(())
+    }
+
     /// Parse a primary expression or a full binary expression for interpolation holes.
     fn parse_primary_or_binary_expr(&mut self) -> ParseResult<&'a Expr<'a>> {
         self.parse_imperative_expr()
@@ -9676,6 +10893,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         // Handle mass noun measure: "Much water flows", "Little time remains"
         if self.check_measure() {
+            self.branch("clause/measure");
             return self.parse_measure();
         }
 
@@ -9795,14 +11013,17 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         }
 
         if self.check_npi_quantifier() {
+            self.branch("clause/npi quantifier");
             return self.parse_npi_quantified();
         }
 
         if self.check_temporal_npi() {
+            self.branch("clause/temporal npi");
             return self.parse_temporal_npi();
         }
 
         if self.match_token(&[TokenType::LParen]) {
+            self.branch("clause/parenthesized");
             let expr = self.parse_sentence()?;
             self.consume(TokenType::RParen)?;
             return Ok(expr);
@@ -9810,6 +11031,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         // Handle pronoun as subject
         if self.check_pronoun() {
+            self.branch("clause/pronoun subject");
             let token = self.advance().clone();
             let (gender, number) = match &token.kind {
                 TokenType::Pronoun { gender, number, .. } => (*gender, *number),
@@ -10018,7 +11240,10 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // Handle plural subjects: "John and Mary verb"
         if self.check(&TokenType::And) {
             match self.try_parse_plural_subject(&subject) {
-                Ok(Some(result)) => return Ok(result),
+                Ok(Some(result)) => {
+                    self.branch("clause/plural subject");
+                    return Ok(result);
+                }
                 Ok(None) => {} // Not a plural subject, continue
                 Err(e) => return Err(e), // Semantic error (e.g., respectively mismatch)
             }
@@ -10026,33 +11251,20 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         // Handle scopal adverbs: "John almost died"
         if self.check_scopal_adverb() {
+            self.branch("clause/scopal adverb");
             return self.parse_scopal_adverb(&subject);
         }
 
-        // Non-restrictive (appositive) RC (§3.1): "John, who loves Mary, left." — a
-        // comma-delimited RC on a referential head is a SIDE-ASSERTION about the
-        // head referent, conjoined with the main clause, NOT a restriction.
-        if self.check(&TokenType::Comma)
-            && matches!(
-                self.tokens.get(self.current + 1).map(|t| &t.kind),
-                Some(TokenType::Who) | Some(TokenType::That)
-            )
-        {
-            self.advance(); // comma
-            self.advance(); // who / which / that
-            // The RC predication, with the head referent filling the gap.
-            let rc = self.parse_relative_clause(subject.noun)?;
-            if self.check(&TokenType::Comma) {
-                self.advance(); // closing comma
-            }
+        // Appositives (§3.1): a non-restrictive RC "John, who loves Mary, left." or
+        // a nominal "Socrates, a philosopher, is mortal." on the subject is a
+        // SIDE-ASSERTION about the head referent, conjoined with the main clause,
+        // NOT a restriction.
+        if let Some(side) = self.try_parse_appositive(&subject)? {
+            self.branch("clause/appositive");
             // The main clause predicated of the same head.
             let main = self.parse_predicate_with_subject(subject.noun)?;
-            let conj = self.ctx.exprs.alloc(LogicExpr::BinaryOp {
-                left: main,
-                op: TokenType::And,
-                right: rc,
-            });
-            return self.wrap_with_definiteness_full(&subject, conj);
+            let combined = self.attach_appositive(main, side);
+            return self.wrap_with_definiteness_full(&subject, combined);
         }
 
         // Comma-coordinated list subject: "A, B and C are all different animals."
@@ -10120,7 +11332,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                         };
 
                         if p.check_verb() {
-                            let verb = p.consume_verb();
+                            let verb = p.consume_verb()?;
                             let predicate = p.ctx.exprs.alloc(LogicExpr::Predicate {
                                 name: verb,
                                 args: p.ctx.terms.alloc_slice([
@@ -10154,7 +11366,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 let topic_attempt = self.try_parse(|p| {
                     let real_subject = p.parse_noun_phrase(true)?;
                     if p.check_verb() {
-                        let verb = p.consume_verb();
+                        let verb = p.consume_verb()?;
                         let predicate = p.ctx.exprs.alloc(LogicExpr::Predicate {
                             name: verb,
                             args: p.ctx.terms.alloc_slice([
@@ -10184,6 +11396,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // Handle relative clause after subject: "The cat that the dog chased ran."
         let mut relative_clause: Option<(Symbol, &'a LogicExpr<'a>)> = None;
         if self.check(&TokenType::That) || self.check(&TokenType::Who) {
+            self.branch("clause/relative subject");
             self.advance();
             let var_name = self.next_var_name();
             let rel_pred = self.parse_relative_clause(var_name)?;
@@ -10357,6 +11570,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         // Identity check: "Clark is equal to Superman"
         if self.check(&TokenType::Identity) {
+            self.branch("clause/identity");
             self.advance();
             let right = self.consume_content_word()?;
             return Ok(self.ctx.exprs.alloc(LogicExpr::Identity {
@@ -10641,6 +11855,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         if self.check(&TokenType::Is) || self.check(&TokenType::Are)
             || self.check(&TokenType::Was) || self.check(&TokenType::Were)
         {
+            self.branch("clause/copula");
             let copula_time = if self.check(&TokenType::Was) || self.check(&TokenType::Were) {
                 Time::Past
             } else {
@@ -10729,6 +11944,27 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 self.advance(); // degree adverb
             }
 
+            // A written number standing alone is the same value as its digits:
+            // "The temperature is ninety." reads like "The temperature is 90."
+            if let TokenType::Cardinal(n) = self.peek().kind {
+                let ends_clause = matches!(
+                    self.tokens.get(self.current + 1).map(|t| &t.kind),
+                    Some(TokenType::Period) | Some(TokenType::EOF)
+                );
+                if ends_clause && self.mode == ParserMode::Declarative {
+                    self.advance();
+                    let result = self.ctx.exprs.alloc(LogicExpr::Identity {
+                        left: self.ctx.terms.alloc(Term::Constant(subject.noun)),
+                        right: self.ctx.terms.alloc(Term::Value {
+                            kind: crate::ast::logic::NumberKind::Integer(n as i64),
+                            unit: None,
+                            dimension: None,
+                        }),
+                    });
+                    return self.wrap_with_definiteness_full(&subject, result);
+                }
+            }
+
             // Check for Number token (measure phrase) before comparative or adjective
             // "John is 2 inches taller than Mary" or "The rope is 5 meters long"
             if self.check_number() {
@@ -10911,7 +12147,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                     }
                 }
 
-                let subject_sort = lexicon::lookup_sort(self.interner.resolve(subject.noun));
+                let subject_sort = self.sort_of(subject.noun);
                 let predicate_sort = lexicon::lookup_sort(self.interner.resolve(predicate_noun));
 
                 if let (Some(s_sort), Some(p_sort)) = (subject_sort, predicate_sort) {
@@ -11322,6 +12558,13 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                     })
                 };
 
+                if verb_aspect == Aspect::Progressive {
+                    self.world_state.record_tense(TenseAspect {
+                        time: copula_time,
+                        perfect: false,
+                        progressive: true,
+                    });
+                }
                 let with_aspect = if verb_aspect == Aspect::Progressive {
                     // Semelfactive + Progressive → Iterative
                     let operator = if verb_class == VerbClass::Semelfactive {
@@ -11922,7 +13165,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             }
 
             // Check for sort violation (metaphor detection)
-            let subject_sort = lexicon::lookup_sort(self.interner.resolve(subject.noun));
+            let subject_sort = self.sort_of(subject.noun);
             let predicate_str = self.interner.resolve(predicate_name);
 
             // Check ontology's predicate sort requirements (for adjectives like "happy")
@@ -12057,6 +13300,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // simple-clause path here did not, so proper-name-subject "did X"
         // stranded. (Object NPs only; "did not"/"did run" stay auxiliary.)
         if self.check_auxiliary_as_main_verb() {
+            self.branch("clause/do as main verb");
             let subject_term = self.noun_phrase_to_term(&subject);
             let result = self.parse_do_as_main_verb(subject_term)?;
             return self.wrap_with_definiteness_full(&subject, result);
@@ -12066,6 +13310,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // BUT: "did it" should be parsed as verb "do" with object "it"
         // We lookahead to check if this is truly an auxiliary usage
         if self.check_auxiliary() && self.is_true_auxiliary_usage() {
+            self.branch("clause/auxiliary");
             let aux_time = if let TokenType::Auxiliary(time) = self.advance().kind {
                 time
             } else {
@@ -12073,6 +13318,11 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             };
             self.pending_time = Some(aux_time);
 
+            // "John will be running" — a progressive under the auxiliary.
+            if self.at_progressive_be() {
+                return self.parse_aspect_chain(subject.noun);
+            }
+
             // Handle negation: "John did not see dogs"
             if self.match_token(&[TokenType::Not]) {
                 self.negative_depth += 1;
@@ -12329,10 +13579,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                     )?;
 
                     self.negative_depth -= 1;
-                    return Ok(self.ctx.exprs.alloc(LogicExpr::UnaryOp {
-                        op: TokenType::Not,
-                        operand: vp,
-                    }));
+                    return Ok(self.negate_clause(verb, vp));
                 }
 
                 self.negative_depth -= 1;
@@ -12346,6 +13593,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // Only trigger presupposition if followed by a gerund (e.g., "stopped smoking")
         // "John stopped." alone should parse as intransitive verb, not presupposition
         if self.check_presup_trigger() && !self.is_followed_by_np_object() && self.is_followed_by_gerund() {
+            self.branch("clause/presupposition");
             let presup_kind = self.consume_presup_trigger();
             return self.parse_presupposition(&subject, presup_kind, false);
         }
@@ -12377,6 +13625,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             && (self.check_verb() || generic_do_not);
 
         if is_bare_plural && generic_do_not {
+            self.branch("clause/generic");
             // "Penguins do not fly." → Gen x(Penguin(x) → ¬∃e(Fly(e) ∧ Agent(e, x))).
             // The nucleus is a real event so the generic matches what an
             // instance sentence ("Opus does not fly.") parses to.
@@ -12419,6 +13668,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         }
 
         if is_bare_plural {
+            self.branch("clause/generic");
             let var_name = self.next_var_name();
             let (verb, verb_time, verb_aspect, _) = self.consume_verb_with_metadata();
 
@@ -12490,6 +13740,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         // Handle do-support: "John does not exist" or "John does run"
         if self.check(&TokenType::Does) || self.check(&TokenType::Do) {
+            self.branch("clause/do-support");
             self.advance(); // consume does/do
             let is_negated = self.match_token(&[TokenType::Not]);
 
@@ -12817,10 +14068,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 // clause here, keeping negated do-support goals structurally clean
                 // for the proof engine (matches the pre-existing intransitive form).
                 let result = if is_negated {
-                    self.ctx.exprs.alloc(LogicExpr::UnaryOp {
-                        op: TokenType::Not,
-                        operand: vp,
-                    })
+                    self.negate_clause(verb, vp)
                 } else {
                     vp
                 };
@@ -13025,8 +14273,9 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         // Handle "never" temporal negation: "John never runs"
         if self.check(&TokenType::Never) {
+            self.branch("clause/never");
             self.advance();
-            let verb = self.consume_verb();
+            let verb = self.consume_verb()?;
             let subject_term = self.noun_phrase_to_term(&subject);
 
             // Optional object: quantified ("two requests") raises past the
@@ -13135,10 +14384,11 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         }
 
         if self.check_verb() {
+            self.branch("clause/finite verb");
             let (mut verb, verb_time, verb_aspect, verb_class) = self.consume_verb_with_metadata();
 
             // Check for verb sort violation (metaphor detection)
-            let subject_sort = lexicon::lookup_sort(self.interner.resolve(subject.noun));
+            let subject_sort = self.sort_of(subject.noun);
             let verb_str = self.interner.resolve(verb);
             if let Some(s_sort) = subject_sort {
                 if !crate::ontology::check_sort_compatibility(verb_str, s_sort) {
@@ -13356,9 +14606,9 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             // "to" — its object comes first ("persuaded Mary to be examined").
             if self.is_control_verb(verb)
                 && (self.check_to()
-                    || lexicon::is_object_control_verb(
+                    || (lexicon::is_object_control_verb(
                         &self.interner.resolve(verb).to_lowercase(),
-                    ))
+                    ) && !self.opens_question_complement(verb)))
             {
                 return self.parse_control_structure(&subject, verb, verb_time);
             }
@@ -13424,6 +14674,12 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
             let unknown = self.interner.intern("?");
 
+            // Polar embedded question: "John wonders whether Mary left."
+            if let Some(question) = self.try_parse_polar_question_complement(verb)? {
+                let event = self.question_embedding_event(verb, subject_term, question, verb_time);
+                return self.wrap_with_definiteness_full(&subject, event);
+            }
+
             // Check for embedded wh-clause: "I know who/what"
             if self.check_wh_word() {
                 let wh_token = self.advance().kind.clone();
@@ -13505,30 +14761,8 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 }
 
                 // Non-sluicing embedded question: "I know who runs"
-                let embedded = self.parse_embedded_wh_clause()?;
-                let question = self.ctx.exprs.alloc(LogicExpr::Question {
-                    wh_variable: self.interner.intern("x"),
-                    body: embedded,
-                });
-
-                // Build: Know(subject, question)
-                let know_event_var = self.get_event_var();
-                let suppress_existential = self.drs.in_conditional_antecedent();
-                if suppress_existential {
-                    let event_class = self.interner.intern("Event");
-                    self.drs.introduce_referent(know_event_var, event_class, Gender::Neuter, Number::Singular);
-                }
-                let know_event = self.ctx.exprs.alloc(LogicExpr::NeoEvent(Box::new(NeoEventData {
-                    event_var: know_event_var,
-                    verb,
-                    roles: self.ctx.roles.alloc_slice(vec![
-                        (ThematicRole::Agent, subject_term),
-                        (ThematicRole::Theme, Term::Proposition(question)),
-                    ]),
-                    modifiers: self.ctx.syms.alloc_slice(vec![]),
-                    suppress_existential,
-                    world: None,
-                })));
+                let question = self.parse_embedded_wh_clause()?;
+                let know_event = self.question_embedding_event(verb, subject_term, question, verb_time);
 
                 return self.wrap_with_definiteness_full(&subject, know_event);
             }
@@ -13886,13 +15120,8 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 }
 
                 // Check for distanced phrasal verb particle: "gave the book up"
-                if let TokenType::Particle(particle_sym) = self.peek().kind {
-                    let verb_str = self.interner.resolve(verb).to_lowercase();
-                    let particle_str = self.interner.resolve(particle_sym).to_lowercase();
-                    if let Some((phrasal_lemma, _class)) = crate::lexicon::lookup_phrasal_verb(&verb_str, &particle_str) {
-                        self.advance(); // consume the particle
-                        verb = self.interner.intern(phrasal_lemma);
-                    }
+                if let Some(phrasal) = self.take_separated_particle(verb) {
+                    verb = phrasal;
                 }
 
                 if let Some(obj_q) = obj_quantifier {
@@ -14422,7 +15651,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 while self.check(&TokenType::And) {
                     let saved = self.current;
                     self.advance(); // consume "and"
-                    if self.check_content_word() || self.check_article() {
+                    if (self.check_content_word() || self.check_article()) && !self.coordinate_opens_clause(self.current, verb) {
                         let next_obj = match self.parse_noun_phrase(false) {
                             Ok(np) => np,
                             Err(_) => {
@@ -14471,13 +15700,8 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 }
 
                 // Check for distanced phrasal verb particle: "gave the book up"
-                if let TokenType::Particle(particle_sym) = self.peek().kind {
-                    let verb_str = self.interner.resolve(verb).to_lowercase();
-                    let particle_str = self.interner.resolve(particle_sym).to_lowercase();
-                    if let Some((phrasal_lemma, _class)) = crate::lexicon::lookup_phrasal_verb(&verb_str, &particle_str) {
-                        self.advance(); // consume the particle
-                        verb = self.interner.intern(phrasal_lemma);
-                    }
+                if let Some(phrasal) = self.take_separated_particle(verb) {
+                    verb = phrasal;
                 }
 
                 // Check for "has cardinality aleph_0" pattern: noun followed by number
@@ -14581,6 +15805,11 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                     // A clause-final object-less PARTICLE preposition is an
                     // intransitive directional ("walked in", "sat down") — a
                     // lexically listed class; "of"/"to" cannot end a clause.
+                    // "switched the lamp ON": the particle completes a phrasal verb.
+                    if let Some(phrasal) = self.phrasal_lemma(verb, prep_name) {
+                        verb = phrasal;
+                        continue;
+                    }
                     let event_sym = self.get_event_var();
                     pp_predicates.push(self.ctx.exprs.alloc(LogicExpr::Predicate {
                         name: prep_name,
@@ -14935,6 +16164,144 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         }
     }
 
+    /// Parses an appositive after `head`: a comma-delimited non-restrictive
+    /// relative clause ("John, who runs, …", also with "which") or noun phrase
+    /// ("Socrates, a philosopher, …"), returning its content predicated of the
+    /// head. A nominal appositive needs its closing comma and must not continue
+    /// a list ("Socrates, a philosopher, and Plato …"). `None` (restoring
+    /// position) when the comma opens something else.
+    pub(super) fn try_parse_appositive(
+        &mut self,
+        head: &crate::ast::NounPhrase<'a>,
+    ) -> ParseResult<Option<&'a LogicExpr<'a>>> {
+        if !self.check(&TokenType::Comma) {
+            return Ok(None);
+        }
+        let Some(next) = self.tokens.get(self.current + 1) else {
+            return Ok(None);
+        };
+        let relativizer = matches!(next.kind, TokenType::Who | TokenType::That)
+            || self.interner.resolve(next.lexeme).eq_ignore_ascii_case("which");
+        if relativizer {
+            self.advance(); // comma
+            self.advance(); // who / which / that
+            // The RC predication, with the head referent filling the gap.
+            let rc = self.parse_relative_clause(head.noun)?;
+            let rc = self.substitute_variable_with_constant(rc, head.noun, head.noun)?;
+            if self.check(&TokenType::Comma) {
+                self.advance(); // closing comma
+            }
+            return Ok(Some(rc));
+        }
+        if !matches!(next.kind, TokenType::Article(_)) {
+            return Ok(None);
+        }
+
+        let apposition = self.try_parse(|p| {
+            p.advance(); // comma
+            let np = p.parse_noun_phrase(false)?;
+            let closes = p.check(&TokenType::Comma)
+                && !matches!(
+                    p.tokens.get(p.current + 1).map(|t| &t.kind),
+                    Some(TokenType::And) | Some(TokenType::Or) | Some(TokenType::EOF) | None
+                );
+            if !closes {
+                return Err(ParseError {
+                    kind: ParseErrorKind::ExpectedVerb { found: p.peek().kind.clone() },
+                    span: p.current_span(),
+                });
+            }
+            p.advance(); // closing comma
+            Ok(np)
+        });
+        let Some(np) = apposition else {
+            return Ok(None);
+        };
+
+        // "a Greek philosopher" → Philosopher(head) ∧ Greek(head).
+        let head_term = Term::Constant(head.noun);
+        let mut side = self.ctx.exprs.alloc(LogicExpr::Predicate {
+            name: np.noun,
+            args: self.ctx.terms.alloc_slice([head_term]),
+            world: None,
+        });
+        for &adj in np.adjectives {
+            let adj_pred = self.ctx.exprs.alloc(LogicExpr::Predicate {
+                name: adj,
+                args: self.ctx.terms.alloc_slice([head_term]),
+                world: None,
+            });
+            side = self.ctx.exprs.alloc(LogicExpr::BinaryOp {
+                left: side,
+                op: TokenType::And,
+                right: adj_pred,
+            });
+        }
+        Ok(Some(side))
+    }
+
+    /// Attaches appositive content to its main clause: a plain conjunct, or
+    /// in pragmatic mode projective content (`main [Presup: side]`) that
+    /// survives negation and questions of the main clause.
+    pub(super) fn attach_appositive(
+        &mut self,
+        main: &'a LogicExpr<'a>,
+        side: &'a LogicExpr<'a>,
+    ) -> &'a LogicExpr<'a> {
+        if self.pragmatic {
+            return self.ctx.exprs.alloc(LogicExpr::Presupposition {
+                assertion: main,
+                presupposition: side,
+            });
+        }
+        self.ctx.exprs.alloc(LogicExpr::BinaryOp {
+            left: main,
+            op: TokenType::And,
+            right: side,
+        })
+    }
+
+    /// Whether the coordinate at `at`, just after the "and" of an object
+    /// list, opens a new clause rather than another object of `verb`. That is a
+    /// verb ("and hates Bill"), a subject with an auxiliary or verb ("and
+    /// Bill does too", "and Mary ate beans"), an inverted "so did Bill", or a
+    /// gapped clause ("and Mary beans"). A ditransitive verb's recipients
+    /// may be followed by its theme ("gave Tom and Jerry a book"), so that
+    /// last case is left to the object list there.
+    pub(super) fn coordinate_opens_clause(&self, at: usize, verb: Symbol) -> bool {
+        let kind = |offset: usize| self.tokens.get(at + offset).map(|t| &t.kind);
+        let is_clausal = |k: Option<&TokenType>| {
+            matches!(
+                k,
+                Some(
+                    TokenType::Verb { .. }
+                        | TokenType::Does
+                        | TokenType::Do
+                        | TokenType::Auxiliary(_)
+                        | TokenType::Can
+                        | TokenType::Could
+                        | TokenType::Would
+                        | TokenType::May
+                        | TokenType::Must
+                        | TokenType::Should
+                        | TokenType::Is
+                        | TokenType::Was
+                )
+            )
+        };
+        let word = self.tokens.get(at).map_or(String::new(), |t| self.interner.resolve(t.lexeme).to_lowercase());
+        match kind(0) {
+            Some(TokenType::Verb { .. }) => true,
+            _ if matches!(word.as_str(), "so" | "neither" | "nor") => is_clausal(kind(1)),
+            Some(TokenType::ProperName(_)) => {
+                is_clausal(kind(1))
+                    || (matches!(kind(1), Some(TokenType::Article(_) | TokenType::Noun(_) | TokenType::Adjective(_)))
+                        && !Lexer::is_ditransitive_verb(self.interner.resolve(verb)))
+            }
+            _ => false,
+        }
+    }
+
     fn check_content_word(&self) -> bool {
         match &self.peek().kind {
             TokenType::Noun(_)
@@ -15419,15 +16786,24 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         false
     }
 
-    fn consume_verb(&mut self) -> Symbol {
-        let t = self.advance().clone();
-        match t.kind {
-            TokenType::Verb { lemma, .. } => lemma,
-            TokenType::Ambiguous { primary, .. } => match *primary {
-                TokenType::Verb { lemma, .. } => lemma,
-                _ => panic!("Expected verb in Ambiguous primary, got {:?}", primary),
+    fn consume_verb(&mut self) -> ParseResult<Symbol> {
+        let lemma = match &self.peek().kind {
+            TokenType::Verb { lemma, .. } => Some(*lemma),
+            TokenType::Ambiguous { primary, .. } => match **primary {
+                TokenType::Verb { lemma, .. } => Some(lemma),
+                _ => None,
             },
-            _ => panic!("Expected verb, got {:?}", t.kind),
+            _ => None,
+        };
+        match lemma {
+            Some(lemma) => {
+                self.advance();
+                Ok(lemma)
+            }
+            None => Err(ParseError {
+                kind: ParseErrorKind::ExpectedVerb { found: self.peek().kind.clone() },
+                span: self.current_span(),
+            }),
         }
     }
 
@@ -15509,7 +16885,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
         self.negative_depth += 1;
 
-        let verb = self.consume_verb();
+        let verb = self.consume_verb()?;
 
         if self.check_npi_object() {
             let obj_npi_token = self.advance().kind.clone();
@@ -15605,7 +16981,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             self.negative_depth += 1;
         }
 
-        let verb = self.consume_verb();
+        let verb = self.consume_verb()?;
         let verb_pred = self.ctx.exprs.alloc(LogicExpr::Predicate {
             name: verb,
             args: self.ctx.terms.alloc_slice([Term::Constant(subject.noun)]),
@@ -15677,6 +17053,74 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         self.peek().span
     }
 
+    /// Re-reads a failed declarative sentence for a misspelled word.
+    ///
+    /// The lexer gives an out-of-lexicon word a guessed role; when that guess
+    /// is what broke the parse, the honest diagnosis is the spelling, not the
+    /// structure. Scanning back from the failure to the start of its sentence,
+    /// the nearest unknown word with lexicon neighbours replaces the
+    /// structural error. Mid-sentence capitalized words are names, not typos.
+    fn diagnose_unknown_word(&self, error: ParseError) -> ParseError {
+        let preferred = match error.kind {
+            ParseErrorKind::ExpectedVerb { .. } => Some(WordClass::Verb),
+            ParseErrorKind::ExpectedCopula
+            | ParseErrorKind::UnknownQuantifier { .. }
+            | ParseErrorKind::UnknownModal { .. } => Some(WordClass::Function),
+            ParseErrorKind::UnexpectedToken { .. }
+            | ParseErrorKind::ExpectedContentWord { .. }
+            | ParseErrorKind::TrailingTokens { .. } => None,
+            _ => return error,
+        };
+        let Some(failed_at) = self.tokens.iter().rposition(|t| t.span.start <= error.span.start) else {
+            return error;
+        };
+        let sentence_start = self.tokens[..failed_at]
+            .iter()
+            .rposition(|t| matches!(t.kind, TokenType::Period | TokenType::Exclamation))
+            .map_or(0, |i| i + 1);
+        (sentence_start..=failed_at)
+            .rev()
+            .filter(|&i| {
+                i == sentence_start
+                    || !self
+                        .interner
+                        .resolve(self.tokens[i].lexeme)
+                        .starts_with(|c: char| c.is_uppercase())
+            })
+            .find_map(|i| self.unknown_word_at(i, &WordClass::LEXICAL, preferred))
+            .unwrap_or(error)
+    }
+
+    /// An [`ParseErrorKind::UnknownWord`] for the token at `index`, when it
+    /// is an alphabetic word the lexicon does not know and `classes` offer
+    /// corrections for it.
+    fn unknown_word_at(
+        &self,
+        index: usize,
+        classes: &[WordClass],
+        preferred: Option<WordClass>,
+    ) -> Option<ParseError> {
+        let token = self.tokens.get(index)?;
+        let word = self.interner.resolve(token.lexeme);
+        if word.chars().count() < 3
+            || !word.chars().all(|c| c.is_ascii_alphabetic())
+            || suggest::is_known_word(word)
+        {
+            return None;
+        }
+        let ranked = suggest::suggest_from(word, classes, preferred, 3);
+        if ranked.is_empty() {
+            return None;
+        }
+        Some(ParseError {
+            kind: ParseErrorKind::UnknownWord {
+                word: word.to_string(),
+                suggestions: ranked.iter().map(|s| s.word.to_string()).collect(),
+            },
+            span: token.span,
+        })
+    }
+
     fn consume(&mut self, kind: TokenType) -> ParseResult<&Token> {
         if self.check(&kind) {
             Ok(self.advance())
@@ -16353,3 +17797,26 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
 }
 
+/// Every variable name an interpolation hole reads — `{p's name}`, `{count + 1}`, `{item(xs, 2)}`.
+fn collect_hole_names(expr: &Expr<'_>, out: &mut Vec<Symbol>) {
+    match expr {
+        Expr::Identifier(sym) => out.push(*sym),
+        Expr::BinaryOp { left, right, .. } => {
+            collect_hole_names(left, out);
+            collect_hole_names(right, out);
+        }
+        Expr::Not { operand } => collect_hole_names(operand, out),
+        Expr::FieldAccess { object, .. } => collect_hole_names(object, out),
+        Expr::Index { collection, index } => {
+            collect_hole_names(collection, out);
+            collect_hole_names(index, out);
+        }
+        Expr::Length { collection } => collect_hole_names(collection, out),
+        Expr::Call { args, .. } => {
+            for arg in args {
+                collect_hole_names(arg, out);
+            }
+        }
+        _ => {}
+    }
+}
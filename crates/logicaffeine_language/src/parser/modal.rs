@@ -22,9 +22,9 @@ use super::clause::ClauseParsing;
 use super::noun::NounParsing;
 use super::pragmatics::PragmaticsParsing;
 use super::quantifier::QuantifierParsing;
-use super::{ParseResult, Parser};
-use crate::ast::{AspectOperator, LogicExpr, ModalDomain, ModalFlavor, ModalVector, NeoEventData, QuantifierKind, ThematicRole, VoiceOperator, Term};
-use crate::drs::TimeRelation;
+use super::{NegativeScopeMode, ParseResult, Parser};
+use crate::ast::{AspectOperator, LogicExpr, ModalDomain, ModalFlavor, ModalVector, NeoEventData, QuantifierKind, TemporalOperator, ThematicRole, VoiceOperator, Term};
+use crate::tense::TenseAspect;
 use crate::error::{ParseError, ParseErrorKind};
 use logicaffeine_base::Symbol;
 use crate::lexicon::{Time, Aspect};
@@ -37,6 +37,8 @@ use crate::token::TokenType;
 pub trait ModalParsing<'a, 'ctx, 'int> {
     /// Parses a modal verb and its scope content.
     fn parse_modal(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
+    /// [`Self::parse_modal`] without the derivation-trace wrapper.
+    fn parse_modal_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
     /// Parses perfect/progressive aspect chain with a symbol subject.
     fn parse_aspect_chain(&mut self, subject_symbol: Symbol) -> ParseResult<&'a LogicExpr<'a>>;
     /// Parses perfect/progressive aspect chain with a term subject.
@@ -47,6 +49,10 @@ pub trait ModalParsing<'a, 'ctx, 'int> {
 
 impl<'a, 'ctx, 'int> ModalParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
     fn parse_modal(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
+        self.traced("modal", Self::parse_modal_rule)
+    }
+
+    fn parse_modal_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
         use crate::drs::BoxType;
 
         let vector = self.token_to_vector(&self.previous().kind.clone());
@@ -70,7 +76,7 @@ impl<'a, 'ctx, 'int> ModalParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
     }
 
     fn parse_aspect_chain(&mut self, subject_symbol: Symbol) -> ParseResult<&'a LogicExpr<'a>> {
-        self.parse_aspect_chain_with_term(Term::Constant(subject_symbol))
+        self.traced("aspect chain", |p| p.parse_aspect_chain_with_term(Term::Constant(subject_symbol)))
     }
 
     fn parse_aspect_chain_with_term(&mut self, subject_term: Term<'a>) -> ParseResult<&'a LogicExpr<'a>> {
@@ -80,6 +86,8 @@ impl<'a, 'ctx, 'int> ModalParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         let mut has_perfect = false;
         let mut has_passive = false;
         let mut has_progressive = false;
+        // Tense carried by the perfect auxiliary itself ("had").
+        let mut perfect_time = None;
 
         if self.check(&TokenType::Would) || self.check(&TokenType::Could)
             || self.check(&TokenType::Must) || self.check(&TokenType::Can)
@@ -146,15 +154,22 @@ impl<'a, 'ctx, 'int> ModalParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
             if word == "have" || word == "has" || word == "had" {
                 self.advance();
                 has_perfect = true;
+                if word == "had" {
+                    perfect_time = Some(Time::Past);
+                }
             }
         }
 
         if self.check(&TokenType::Had) {
             self.advance();
             has_perfect = true;
-            // "had" = past perfect: R < S (past reference time)
-            let r_var = self.world_state.next_reference_time();
-            self.world_state.add_time_constraint(r_var, TimeRelation::Precedes, "S".to_string());
+            perfect_time = Some(Time::Past);
+        }
+
+        // Progressive "be V-ing" after a modal or "will": "will be running".
+        if !has_perfect && self.at_progressive_be() {
+            self.advance();
+            has_progressive = true;
         }
 
         if self.check_content_word() {
@@ -243,7 +258,7 @@ impl<'a, 'ctx, 'int> ModalParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         }
 
         let verb = if self.check_verb() {
-            self.consume_verb()
+            self.consume_verb()?
         } else if self.check_content_word() {
             self.consume_content_word()?
         } else {
@@ -364,7 +379,9 @@ impl<'a, 'ctx, 'int> ModalParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
         let mut modifiers: Vec<Symbol> = Vec::new();
         // Manner adverbs under the modal ("would spread quickly").
         modifiers.extend(self.collect_adverbs());
-        if let Some(pending) = self.pending_time {
+        // Under a perfect or progressive the tense orders the reference time,
+        // not the event ("will have left": the leaving may already be past).
+        if let Some(pending) = self.pending_time.filter(|_| !has_perfect && !has_progressive) {
             match pending {
                 Time::Past => modifiers.push(self.interner.intern("Past")),
                 Time::Future => modifiers.push(self.interner.intern("Future")),
@@ -479,33 +496,30 @@ impl<'a, 'ctx, 'int> ModalParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
 
         if has_perfect {
             result = self.ctx.aspectual(AspectOperator::Perfect, result);
+        }
 
-            // Check pending_time to set up reference time for tense
-            if let Some(pending) = self.pending_time.take() {
-                match pending {
-                    Time::Future => {
-                        // Future perfect: S < R
-                        let r_var = self.world_state.next_reference_time();
-                        self.world_state.add_time_constraint("S".to_string(), TimeRelation::Precedes, r_var);
-                    }
-                    Time::Past => {
-                        // Past perfect fallback (if not already set by "had")
-                        if self.world_state.current_reference_time() == "S" {
-                            let r_var = self.world_state.next_reference_time();
-                            self.world_state.add_time_constraint(r_var, TimeRelation::Precedes, "S".to_string());
-                        }
-                    }
-                    _ => {}
-                }
+        if has_perfect || has_progressive {
+            let pending = self.pending_time.take();
+            let time = perfect_time.or(pending).unwrap_or(Time::Present);
+            self.world_state.record_tense(TenseAspect {
+                time,
+                perfect: has_perfect,
+                progressive: has_progressive,
+            });
+            // The tense locates the reference time: "will have left" is
+            // F(Perf(…)), a future point by which the leaving is done.
+            match time {
+                Time::Past => result = self.ctx.temporal(TemporalOperator::Past, result),
+                Time::Future => result = self.ctx.temporal(TemporalOperator::Future, result),
+                _ => {}
             }
-
-            // Perfect: E < R (event before reference)
-            let e_var = format!("e{}", self.world_state.event_history().len().max(1));
-            let r_var = self.world_state.current_reference_time();
-            self.world_state.add_time_constraint(e_var, TimeRelation::Precedes, r_var);
         }
 
-        if has_negation {
+        // Wide negation scopes over the modal: "John must not leave" as
+        // ¬□Leave(John) rather than the surface □¬Leave(John).
+        let negation_over_modal =
+            has_negation && has_modal && self.negative_scope_mode == NegativeScopeMode::Wide;
+        if has_negation && !negation_over_modal {
             result = self.ctx.exprs.alloc(LogicExpr::UnaryOp {
                 op: TokenType::Not,
                 operand: result,
@@ -529,6 +543,13 @@ impl<'a, 'ctx, 'int> ModalParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
             }
         }
 
+        if negation_over_modal {
+            result = self.ctx.exprs.alloc(LogicExpr::UnaryOp {
+                op: TokenType::Not,
+                operand: result,
+            });
+        }
+
         if let Some((kind, var, restriction)) = object_quant {
             let connective = if matches!(kind, QuantifierKind::Universal) {
                 TokenType::Implies
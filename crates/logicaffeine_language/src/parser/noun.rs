@@ -61,6 +61,8 @@ fn category_preposition(category_lemma_lower: &str) -> Option<&'static str> {
 pub trait NounParsing<'a, 'ctx, 'int> {
     /// Parses a full noun phrase with optional greedy PP attachment.
     fn parse_noun_phrase(&mut self, greedy: bool) -> ParseResult<NounPhrase<'a>>;
+    /// [`Self::parse_noun_phrase`] without the derivation-trace wrapper.
+    fn parse_noun_phrase_rule(&mut self, greedy: bool) -> ParseResult<NounPhrase<'a>>;
     /// Parses a noun phrase suitable for relative clause antecedent.
     fn parse_noun_phrase_for_relative(&mut self) -> ParseResult<NounPhrase<'a>>;
     /// Converts a parsed noun phrase to a first-order term.
@@ -104,6 +106,10 @@ pub trait NounParsing<'a, 'ctx, 'int> {
 
 impl<'a, 'ctx, 'int> NounParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
     fn parse_noun_phrase(&mut self, greedy: bool) -> ParseResult<NounPhrase<'a>> {
+        self.traced("noun phrase", |p| p.parse_noun_phrase_rule(greedy))
+    }
+
+    fn parse_noun_phrase_rule(&mut self, greedy: bool) -> ParseResult<NounPhrase<'a>> {
         let mut definiteness = None;
         let mut adjectives = Vec::new();
         let mut non_intersective_prefix: Option<crate::intern::Symbol> = None;
@@ -41,6 +41,8 @@ pub trait PragmaticsParsing<'a, 'ctx, 'int> {
     /// Returns a [`LogicExpr::Focus`] with the focus kind, focused element,
     /// and the scope predicate.
     fn parse_focus(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
+    /// [`Self::parse_focus`] without the derivation-trace wrapper.
+    fn parse_focus_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
 
     /// Parses a measure construction: "much water is cold", "little food arrived".
     ///
@@ -150,6 +152,10 @@ pub trait PragmaticsParsing<'a, 'ctx, 'int> {
 
 impl<'a, 'ctx, 'int> PragmaticsParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
     fn parse_focus(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
+        self.traced("focus", Self::parse_focus_rule)
+    }
+
+    fn parse_focus_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
         let kind = if let TokenType::Focus(k) = self.advance().kind {
             k
         } else {
@@ -325,7 +331,7 @@ impl<'a, 'ctx, 'int> PragmaticsParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
 
         let unknown = self.interner.intern("?");
         let complement_verb = if self.check_verb() {
-            Some(self.consume_verb())
+            Some(self.consume_verb()?)
         } else {
             None
         };
@@ -444,7 +450,7 @@ impl<'a, 'ctx, 'int> PragmaticsParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
         subject: &NounPhrase<'a>,
     ) -> ParseResult<&'a LogicExpr<'a>> {
         if self.check_verb() {
-            let verb = self.consume_verb();
+            let verb = self.consume_verb()?;
 
             // Check for focused object: "eats only rice"
             if self.check_focus() {
@@ -672,13 +678,9 @@ impl<'a, 'ctx, 'int> PragmaticsParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
         self.advance();
 
         // Check if the comparison target is a number (e.g., "greater than 0")
-        let object_term = if self.check_number() {
+        let object_term = if let TokenType::Number(num_sym) = self.peek().kind {
             // Parse number as the comparison target
-            let num_sym = if let TokenType::Number(sym) = self.advance().kind {
-                sym
-            } else {
-                unreachable!()
-            };
+            self.advance();
             let num_str = self.interner.resolve(num_sym);
             let num_val = num_str.parse::<i64>().unwrap_or(0);
             self.ctx.terms.alloc(Term::Value {
@@ -686,6 +688,9 @@ impl<'a, 'ctx, 'int> PragmaticsParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
                 unit: None,
                 dimension: None,
             })
+        } else if self.check_number() {
+            // A clock time or date ("earlier than 9:30am", "later than March 5th, 2024").
+            self.parse_measure_phrase()?
         } else {
             // Parse noun phrase as the comparison target — GREEDY so the standard's
             // PPs / reduced relatives attach ("shorter than the figure WITH THE
@@ -930,9 +935,10 @@ impl<'a, 'ctx, 'int> PragmaticsParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
         // A clock time ("9:30am", "8:15 pm") is a measure-like value too — it names
         // a point on the day's timeline that the prover can order, so it is a valid
         // measure-phrase / PP object ("is at 9:30am", "the meeting at 8:15 pm").
+        // A calendar date ("on March 5th, 2024") likewise names a point in time.
         matches!(
             self.peek().kind,
-            TokenType::Number(_) | TokenType::TimeLiteral { .. }
+            TokenType::Number(_) | TokenType::TimeLiteral { .. } | TokenType::DateLiteral { .. }
         )
     }
 
@@ -951,6 +957,16 @@ impl<'a, 'ctx, 'int> PragmaticsParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
                 dimension: Some(crate::ast::logic::Dimension::Time),
             }));
         }
+        // A date is a day on the calendar timeline, as days since 1970-01-01, so
+        // "before March 5th, 2024" orders against other dates the same way.
+        if let TokenType::DateLiteral { days } = self.peek().kind {
+            self.advance();
+            return Ok(self.ctx.terms.alloc(Term::Value {
+                kind: crate::ast::logic::NumberKind::Integer(days as i64),
+                unit: None,
+                dimension: Some(crate::ast::logic::Dimension::Time),
+            }));
+        }
         let num_sym = if let TokenType::Number(sym) = self.advance().kind {
             sym
         } else {
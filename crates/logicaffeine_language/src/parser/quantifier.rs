@@ -45,12 +45,16 @@ use crate::token::{PresupKind, TokenType};
 pub trait QuantifierParsing<'a, 'ctx, 'int> {
     /// Parses a quantified expression from a quantifier determiner.
     fn parse_quantified(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
+    /// [`Self::parse_quantified`] without the derivation-trace wrapper.
+    fn parse_quantified_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
 
     /// The quantifier-parsing body; `parse_quantified` wraps its result with any pending
     /// partitive-superset presupposition (§5.3).
     fn parse_quantified_core(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
     /// Parses the restrictor clause for a quantifier.
     fn parse_restriction(&mut self, var_name: Symbol) -> ParseResult<&'a LogicExpr<'a>>;
+    /// [`Self::parse_restriction`] without the derivation-trace wrapper.
+    fn parse_restriction_rule(&mut self, var_name: Symbol) -> ParseResult<&'a LogicExpr<'a>>;
     /// Builds the restriction conjunct for one pre-nominal adjective, dispatching
     /// on its lexical class (relational/subsective/intersective). Shared by every
     /// NP-restriction path so all paths model adjective classes identically.
@@ -204,6 +208,27 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
     }
 
     fn parse_quantified(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
+        self.traced("quantified", Self::parse_quantified_rule)
+    }
+
+    fn parse_quantified_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
+        let determiner = match self.previous().kind {
+            TokenType::All => Some("quantified/all"),
+            TokenType::No => Some("quantified/no"),
+            TokenType::Some => Some("quantified/some"),
+            TokenType::Any => Some("quantified/any"),
+            TokenType::Most => Some("quantified/most"),
+            TokenType::Few => Some("quantified/few"),
+            TokenType::Many => Some("quantified/many"),
+            TokenType::Cardinal(_) => Some("quantified/cardinal"),
+            TokenType::AtLeast(_) => Some("quantified/at least"),
+            TokenType::AtMost(_) => Some("quantified/at most"),
+            _ => None,
+        };
+        if let Some(determiner) = determiner {
+            self.branch(determiner);
+        }
+
         // The specialized quantified-VP grammar below covers many frames but
         // not all of them. A parse that stops mid-clause silently drops the
         // remainder's meaning, so when it under-consumes (or fails), re-parse
@@ -219,7 +244,10 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
             let core_partitive = self.pending_partitive.take();
             self.restore(cp);
             match self.parse_quantified_delegating() {
-                Ok(r) if self.at_clause_boundary() => r,
+                Ok(r) if self.at_clause_boundary() => {
+                    self.branch("quantified/delegated predicate");
+                    r
+                }
                 _ => match core {
                     Ok(r) => {
                         self.restore(core_end);
@@ -278,6 +306,7 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
             && self.current + 1 < self.tokens.len()
             && matches!(self.tokens[self.current + 1].kind, TokenType::Article(_))
         {
+            self.branch("quantified/partitive");
             self.advance(); // consume "of"
             self.advance(); // consume "the"
             if let TokenType::Cardinal(n) = self.peek().kind {
@@ -289,6 +318,7 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
         if matches!(quantifier_token, TokenType::AtMost(_) | TokenType::AtLeast(_) | TokenType::Cardinal(_))
             && self.check_preposition_is("of")
         {
+            self.branch("quantified/counted list");
             self.advance(); // consume "of"
 
             // Parse comma-separated list of identifiers: "grant0, grant1, and grant2"
@@ -641,7 +671,7 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
             }
 
             if self.check_verb() {
-                let verb = self.consume_verb();
+                let verb = self.consume_verb()?;
 
                 // Passive auxiliary "will/would BE used": the copula `be`/`been` is
                 // followed by a past-participle verb that is the real predicate — its
@@ -651,7 +681,7 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
                 let obj_term = if matches!(verb_lower.as_str(), "be" | "been")
                     && self.check_verb()
                 {
-                    Some(Term::Constant(self.consume_verb()))
+                    Some(Term::Constant(self.consume_verb()?))
                 } else {
                     None
                 };
@@ -681,8 +711,15 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
 
                 let verb_pred = self.build_verb_neo_event(verb, var_name, obj_term, modifiers);
 
-                let maybe_negated = if is_negated {
+                // Wide negation takes the universal in its scope: "Everyone
+                // didn't leave" → ¬∀x(Person(x) → Leave(x)).
+                let lifted_negation = is_negated
+                    && self.negative_scope_mode == NegativeScopeMode::Wide
+                    && matches!(quantifier_token, TokenType::All);
+                if is_negated {
                     self.negative_depth -= 1;
+                }
+                let maybe_negated = if is_negated && !lifted_negation {
                     self.ctx.exprs.alloc(LogicExpr::UnaryOp {
                         op: TokenType::Not,
                         operand: verb_pred,
@@ -727,12 +764,20 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
                 };
 
                 self.in_negative_quantifier = was_in_negative_quantifier;
-                return Ok(self.ctx.exprs.alloc(LogicExpr::Quantifier {
+                let quantified = self.ctx.exprs.alloc(LogicExpr::Quantifier {
                     kind,
                     variable: var_name,
                     body,
                     island_id: self.current_island,
-                }));
+                });
+                return Ok(if lifted_negation {
+                    self.ctx.exprs.alloc(LogicExpr::UnaryOp {
+                        op: TokenType::Not,
+                        operand: quantified,
+                    })
+                } else {
+                    quantified
+                });
             }
         }
 
@@ -749,7 +794,7 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
             };
 
             let complement = if self.check_verb() {
-                let verb = self.consume_verb();
+                let verb = self.consume_verb()?;
                 let modifiers = self.collect_adverbs();
                 self.build_verb_neo_event(verb, var_name, None, modifiers)
             } else {
@@ -801,7 +846,7 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
         }
 
         if self.check_verb() {
-            let verb = self.consume_verb();
+            let verb = self.consume_verb()?;
             let mut args = vec![Term::Variable(var_name)];
 
             if self.check_pronoun() {
@@ -1208,13 +1253,16 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
             self.advance(); // consume "does"/"do"
             let negative = self.match_token(&[TokenType::Not]);
             // The verb after "does not" becomes the predicate
-            let verb_sym = self.consume_verb();
+            let verb_sym = self.consume_verb()?;
             let predicate_expr = self.ctx.exprs.alloc(LogicExpr::Predicate {
                 name: verb_sym,
                 args: self.ctx.terms.alloc_slice([Term::Variable(var_name)]),
                 world: None,
             });
-            let final_predicate = if negative {
+            let lifted_negation = negative
+                && self.negative_scope_mode == NegativeScopeMode::Wide
+                && matches!(quantifier_token, TokenType::All);
+            let final_predicate = if negative && !lifted_negation {
                 self.ctx.exprs.alloc(LogicExpr::UnaryOp {
                     op: TokenType::Not,
                     operand: predicate_expr,
@@ -1246,7 +1294,14 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
                 island_id: self.current_island,
             });
             self.in_negative_quantifier = was_in_negative_quantifier;
-            return Ok(result);
+            return Ok(if lifted_negation {
+                self.ctx.exprs.alloc(LogicExpr::UnaryOp {
+                    op: TokenType::Not,
+                    operand: result,
+                })
+            } else {
+                result
+            });
         }
 
         self.consume_copula()?;
@@ -1337,6 +1392,18 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
             });
         }
 
+        // Wide negation lifts a clausal "not" over a universal subject:
+        // "Everyone didn't leave" → ¬∀x(Person(x) → Leave(x)).
+        let mut lifted_negation = false;
+        if self.negative_scope_mode == NegativeScopeMode::Wide
+            && matches!(quantifier_token, TokenType::All)
+        {
+            if let LogicExpr::UnaryOp { op: TokenType::Not, operand } = final_predicate {
+                final_predicate = *operand;
+                lifted_negation = true;
+            }
+        }
+
         let body = match quantifier_token {
             TokenType::All => self.ctx.exprs.alloc(LogicExpr::BinaryOp {
                 left: subject_pred,
@@ -1439,6 +1506,13 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
         }
         self.donkey_bindings.clear();
 
+        if lifted_negation {
+            result = self.ctx.exprs.alloc(LogicExpr::UnaryOp {
+                op: TokenType::Not,
+                operand: result,
+            });
+        }
+
         self.in_negative_quantifier = was_in_negative_quantifier;
         Ok(result)
     }
@@ -1524,6 +1598,10 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
     }
 
     fn parse_restriction(&mut self, var_name: Symbol) -> ParseResult<&'a LogicExpr<'a>> {
+        self.traced("restriction", |p| p.parse_restriction_rule(var_name))
+    }
+
+    fn parse_restriction_rule(&mut self, var_name: Symbol) -> ParseResult<&'a LogicExpr<'a>> {
         // Collect leading adjectives, then consume the head noun. The adjective
         // predicate forms (subsective `Adj(x, ^Noun)`, relational expansions)
         // need the head noun, so the noun is resolved before they are emitted.
@@ -1644,7 +1722,7 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
 
     fn parse_verb_phrase_for_restriction(&mut self, var_name: Symbol) -> ParseResult<&'a LogicExpr<'a>> {
         let var_term = Term::Variable(var_name);
-        let verb = self.consume_verb();
+        let verb = self.consume_verb()?;
         let verb_str_owned = self.interner.resolve(verb).to_string();
 
         // Check EARLY if verb is lexically negative (e.g., "lacks" -> "Have" with negation)
@@ -1659,7 +1737,7 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
         if Lexer::is_raising_verb(&verb_str_owned) && self.check_to() {
             self.advance();
             if self.check_verb() {
-                let inf_verb = self.consume_verb();
+                let inf_verb = self.consume_verb()?;
                 let inf_verb_str = self.interner.resolve(inf_verb).to_lowercase();
 
                 if inf_verb_str == "be" && self.check_content_word() {
@@ -1984,7 +2062,7 @@ impl<'a, 'ctx, 'int> QuantifierParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int
         // Wide scope mode: negation handled via donkey binding flag in wrap_donkey_in_restriction
         // - Narrow: ∃y(Key(y) ∧ ¬Have(x,y)) - "missing ANY key"
         // - Wide:   ¬∃y(Key(y) ∧ Have(x,y)) - "has NO keys"
-        let verb_pred = if is_negative && self.negative_scope_mode == NegativeScopeMode::Narrow {
+        let verb_pred = if is_negative && self.negative_scope_mode != NegativeScopeMode::Wide {
             self.ctx.exprs.alloc(LogicExpr::UnaryOp {
                 op: TokenType::Not,
                 operand: base_pred,
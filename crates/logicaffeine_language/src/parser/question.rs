@@ -30,14 +30,22 @@ use crate::token::TokenType;
 pub trait QuestionParsing<'a, 'ctx, 'int> {
     /// Parses a wh-question: "Who runs?", "What does John love?".
     fn parse_wh_question(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
+    /// [`Self::parse_wh_question`] without the derivation-trace wrapper.
+    fn parse_wh_question_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
     /// Parses a yes/no question: "Does John run?", "Is Mary tall?".
     fn parse_yes_no_question(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
+    /// [`Self::parse_yes_no_question`] without the derivation-trace wrapper.
+    fn parse_yes_no_question_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>>;
     /// Converts an auxiliary token to its modal vector for questions.
     fn aux_token_to_modal_vector(&self, token: &TokenType) -> ModalVector;
 }
 
 impl<'a, 'ctx, 'int> QuestionParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
     fn parse_wh_question(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
+        self.traced("wh-question", Self::parse_wh_question_rule)
+    }
+
+    fn parse_wh_question_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
         let pied_piping_prep = if self.check_preposition() {
             let prep = self.advance().kind.clone();
             Some(prep)
@@ -56,7 +64,7 @@ impl<'a, 'ctx, 'int> QuestionParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int>
             }
 
             let subject = self.parse_noun_phrase(true)?;
-            let verb = self.consume_verb();
+            let verb = self.consume_verb()?;
 
             let mut args = vec![Term::Constant(subject.noun)];
             if self.check_content_word() || self.check_article() {
@@ -124,7 +132,7 @@ impl<'a, 'ctx, 'int> QuestionParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int>
         }
 
         if self.check_verb() {
-            let verb = self.consume_verb();
+            let verb = self.consume_verb()?;
             let mut args = vec![var_term];
 
             if self.check_content_word() {
@@ -146,7 +154,7 @@ impl<'a, 'ctx, 'int> QuestionParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int>
         if self.check(&TokenType::Does) || self.check(&TokenType::Do) {
             self.advance();
             let subject = self.parse_noun_phrase(true)?;
-            let verb = self.consume_verb();
+            let verb = self.consume_verb()?;
 
             let body = self.ctx.exprs.alloc(LogicExpr::Predicate {
                 name: verb,
@@ -183,6 +191,10 @@ impl<'a, 'ctx, 'int> QuestionParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int>
     }
 
     fn parse_yes_no_question(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
+        self.traced("yes/no question", Self::parse_yes_no_question_rule)
+    }
+
+    fn parse_yes_no_question_rule(&mut self) -> ParseResult<&'a LogicExpr<'a>> {
         let aux_token = self.advance().kind.clone();
 
         let is_modal = matches!(aux_token, TokenType::Can | TokenType::Could | TokenType::Would | TokenType::May | TokenType::Must | TokenType::Should);
@@ -36,6 +36,7 @@ use crate::error::{ParseError, ParseErrorKind};
 use logicaffeine_base::Symbol;
 use crate::lexer::Lexer;
 use crate::lexicon::{Aspect, Definiteness, Time};
+use crate::tense::TenseAspect;
 use crate::token::{FocusKind, Span, Token, TokenType};
 
 use crate::ast::Stmt;
@@ -1145,7 +1146,9 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             std_at(k)
                 || matches!(
                     k,
-                    Some(TokenType::Number(_)) | Some(TokenType::TimeLiteral { .. })
+                    Some(TokenType::Number(_))
+                        | Some(TokenType::TimeLiteral { .. })
+                        | Some(TokenType::DateLiteral { .. })
                 )
         };
         let bare_dir = match self.tokens.get(dj).map(|t| &t.kind) {
@@ -1177,7 +1180,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // the year/clock-time is a value, so relate the subject to it directly.
         if matches!(
             self.peek().kind,
-            TokenType::Number(_) | TokenType::TimeLiteral { .. }
+            TokenType::Number(_) | TokenType::TimeLiteral { .. } | TokenType::DateLiteral { .. }
         ) {
             let year = self.parse_measure_phrase()?;
             return Ok(Some(self.ctx.exprs.alloc(LogicExpr::Predicate {
@@ -1280,7 +1283,9 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         let num_at = |k: Option<&TokenType>| {
             matches!(
                 k,
-                Some(TokenType::Number(_)) | Some(TokenType::TimeLiteral { .. })
+                Some(TokenType::Number(_))
+                    | Some(TokenType::TimeLiteral { .. })
+                    | Some(TokenType::DateLiteral { .. })
             )
         };
         let bare_dir = match self.tokens.get(dj).map(|t| &t.kind) {
@@ -1329,7 +1334,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             // 2010"): relate the EVENT to the year/clock-time value directly.
             if matches!(
                 self.peek().kind,
-                TokenType::Number(_) | TokenType::TimeLiteral { .. }
+                TokenType::Number(_) | TokenType::TimeLiteral { .. } | TokenType::DateLiteral { .. }
             ) {
                 let year = self.parse_measure_phrase()?;
                 let rel = self.ctx.exprs.alloc(LogicExpr::Predicate {
@@ -1845,8 +1850,9 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         }
 
         if self.check(&TokenType::Never) {
+            self.branch("predicate/never");
             self.advance();
-            let verb = self.consume_verb();
+            let verb = self.consume_verb()?;
             let verb_pred = self.ctx.exprs.alloc(LogicExpr::Predicate {
                 name: verb,
                 args: self.ctx.terms.alloc_slice([subject_term]),
@@ -1878,6 +1884,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                     false
                 };
                 if is_perfect_aspect {
+                    self.branch("predicate/perfect");
                     return self.parse_aspect_chain(subject_symbol);
                 }
                 // Otherwise, treat "has" as a main verb (possession) and continue below
@@ -1885,11 +1892,13 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         }
 
         if self.check(&TokenType::Had) {
+            self.branch("predicate/perfect");
             return self.parse_aspect_chain(subject_symbol);
         }
 
         // Handle do-support: "I do/don't know who"
         if self.check(&TokenType::Does) || self.check(&TokenType::Do) {
+            self.branch("predicate/do-support");
             self.advance();
             let is_negated = self.match_token(&[TokenType::Not]);
 
@@ -1991,10 +2000,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 )?;
                 if is_negated {
                     self.negative_depth -= 1;
-                    return Ok(self.ctx.exprs.alloc(LogicExpr::UnaryOp {
-                        op: TokenType::Not,
-                        operand: vp,
-                    }));
+                    return Ok(self.negate_clause(verb, vp));
                 }
                 return Ok(vp);
             }
@@ -2004,6 +2010,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // BUT: "did it" should be parsed as verb "do" with object "it"
         // We lookahead to check if this is truly an auxiliary usage
         if self.check_auxiliary() && self.is_true_auxiliary_usage() {
+            self.branch("predicate/auxiliary");
             let aux_time = if let TokenType::Auxiliary(time) = self.advance().kind {
                 time
             } else {
@@ -2011,6 +2018,11 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             };
             self.pending_time = Some(aux_time);
 
+            // "John will be running" — a progressive under the auxiliary.
+            if self.at_progressive_be() {
+                return self.parse_aspect_chain_with_term(subject_term);
+            }
+
             if self.match_token(&[TokenType::Not]) {
                 self.negative_depth += 1;
 
@@ -2210,10 +2222,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                     )?;
 
                     self.negative_depth -= 1;
-                    return Ok(self.ctx.exprs.alloc(LogicExpr::UnaryOp {
-                        op: TokenType::Not,
-                        operand: vp,
-                    }));
+                    return Ok(self.negate_clause(verb, vp));
                 }
 
                 self.negative_depth -= 1;
@@ -2225,6 +2234,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             || self.check(&TokenType::Was)
             || self.check(&TokenType::Were)
         {
+            self.branch("predicate/copula");
             let copula_time = if self.check(&TokenType::Was) || self.check(&TokenType::Were) {
                 Time::Past
             } else {
@@ -2341,6 +2351,13 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                     });
                 }
 
+                if verb_aspect == Aspect::Progressive {
+                    self.world_state.record_tense(TenseAspect {
+                        time: copula_time,
+                        perfect: false,
+                        progressive: true,
+                    });
+                }
                 let with_aspect = if verb_aspect == Aspect::Progressive {
                     // Semelfactive + Progressive → Iterative
                     let operator = if verb_class == crate::lexicon::VerbClass::Semelfactive {
@@ -2824,9 +2841,11 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
         // Handle "did it" - when Auxiliary(Past) is used as a transitive verb (past of "do")
         // This happens when we bypassed auxiliary handling because of lookahead
         if self.check_auxiliary_as_main_verb() {
+            self.branch("predicate/do as main verb");
             return self.parse_do_as_main_verb(subject_term);
         }
 
+        self.branch("predicate/finite verb");
         self.parse_finite_verb_vp(subject_symbol, subject_term, as_variable)
     }
 
@@ -2977,7 +2996,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                                 | TokenType::Might | TokenType::Shall | TokenType::Cannot
                         )
                 });
-                if saw_noun_head && verb_follows {
+                if (saw_noun_head && verb_follows) || self.coordinate_opens_clause(k, verb) {
                     break;
                 }
             }
@@ -3368,6 +3387,11 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 }
             }
 
+            // Polar embedded question: "John wonders whether Mary left."
+            if let Some(question) = self.try_parse_polar_question_complement(verb)? {
+                return Ok(self.question_embedding_event(verb, subject_term, question, verb_time));
+            }
+
             // Check for embedded wh-clause: "I know who/what"
             if self.check_wh_word() {
                 let wh_token = self.advance().kind.clone();
@@ -3432,24 +3456,8 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 }
 
                 // Non-sluicing: "I know who runs"
-                let embedded = self.parse_embedded_wh_clause()?;
-                let question = self.ctx.exprs.alloc(LogicExpr::Question {
-                    wh_variable: self.interner.intern("x"),
-                    body: embedded,
-                });
-
-                let suppress_existential = self.drs.in_conditional_antecedent();
-                let know_event = self.ctx.exprs.alloc(LogicExpr::NeoEvent(Box::new(NeoEventData {
-                    event_var: self.get_event_var(),
-                    verb,
-                    roles: self.ctx.roles.alloc_slice(vec![
-                        (ThematicRole::Agent, subject_term),
-                        (ThematicRole::Theme, Term::Proposition(question)),
-                    ]),
-                    modifiers: self.ctx.syms.alloc_slice(vec![]),
-                    suppress_existential,
-                    world: None,
-                })));
+                let question = self.parse_embedded_wh_clause()?;
+                let know_event = self.question_embedding_event(verb, subject_term, question, verb_time);
 
                 return Ok(know_event);
             }
@@ -4211,7 +4219,7 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                 while self.check(&TokenType::And) {
                     let saved = self.current;
                     self.advance(); // consume "and"
-                    if self.check_content_word() || self.check_article() {
+                    if (self.check_content_word() || self.check_article()) && !self.coordinate_opens_clause(self.current, verb) {
                         let next_obj = match self.parse_noun_phrase(false) {
                             Ok(np) => np,
                             Err(_) => {
@@ -4286,34 +4294,29 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
             let mut pp_predicates: Vec<&'a LogicExpr<'a>> = Vec::new();
 
             // Check for distanced phrasal verb particle: "gave the book up"
-            if let TokenType::Particle(particle_sym) = self.peek().kind {
-                let verb_str = self.interner.resolve(verb).to_lowercase();
-                let particle_str = self.interner.resolve(particle_sym).to_lowercase();
-                if let Some((phrasal_lemma, _class)) = crate::lexicon::lookup_phrasal_verb(&verb_str, &particle_str) {
-                    self.advance(); // consume the particle
-                    verb = self.interner.intern(phrasal_lemma);
-                } else {
-                    // A particle with no phrasal-verb table entry ("came OUT",
-                    // "went UP") — keep it as a particle predicate over the event so
-                    // a trailing PP still attaches ("came out IN 1995"). The
-                    // clause-final case is handled in the PP loop; this covers the
-                    // particle-then-PP case it misses.
-                    self.advance(); // consume the particle
-                    let event_sym = self.get_event_var();
-                    let cap = {
-                        let p = self.interner.resolve(particle_sym);
-                        let mut chs = p.chars();
-                        match chs.next() {
-                            Some(f) => f.to_uppercase().collect::<String>() + chs.as_str(),
-                            None => String::new(),
-                        }
-                    };
-                    pp_predicates.push(self.ctx.exprs.alloc(LogicExpr::Predicate {
-                        name: self.interner.intern(&cap),
-                        args: self.ctx.terms.alloc_slice([Term::Variable(event_sym)]),
-                        world: None,
-                    }));
-                }
+            if let Some(phrasal) = self.take_separated_particle(verb) {
+                verb = phrasal;
+            } else if let TokenType::Particle(particle_sym) = self.peek().kind {
+                // A particle with no phrasal-verb table entry ("came OUT",
+                // "went UP") — keep it as a particle predicate over the event so
+                // a trailing PP still attaches ("came out IN 1995"). The
+                // clause-final case is handled in the PP loop; this covers the
+                // particle-then-PP case it misses.
+                self.advance(); // consume the particle
+                let event_sym = self.get_event_var();
+                let cap = {
+                    let p = self.interner.resolve(particle_sym);
+                    let mut chs = p.chars();
+                    match chs.next() {
+                        Some(f) => f.to_uppercase().collect::<String>() + chs.as_str(),
+                        None => String::new(),
+                    }
+                };
+                pp_predicates.push(self.ctx.exprs.alloc(LogicExpr::Predicate {
+                    name: self.interner.intern(&cap),
+                    args: self.ctx.terms.alloc_slice([Term::Variable(event_sym)]),
+                    world: None,
+                }));
             }
             while self.check_preposition() || self.check_to() {
                 // "within N cycles" is a temporal bound, not a PP — leave for try_wrap_bounded_delay
@@ -4391,6 +4394,11 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
                     // A clause-final object-less PARTICLE preposition is an
                     // intransitive directional ("walked in", "sat down") — a
                     // lexically listed class; "of"/"to" cannot end a clause.
+                    // "switched the lamp ON": the particle completes a phrasal verb.
+                    if let Some(phrasal) = self.phrasal_lemma(verb, prep_name) {
+                        verb = phrasal;
+                        continue;
+                    }
                     let event_sym = self.get_event_var();
                     pp_predicates.push(self.ctx.exprs.alloc(LogicExpr::Predicate {
                         name: prep_name,
@@ -4626,12 +4634,12 @@ impl<'a, 'ctx, 'int> Parser<'a, 'ctx, 'int> {
 
 impl<'a, 'ctx, 'int> LogicVerbParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int> {
     fn parse_predicate_with_subject(&mut self, subject_symbol: Symbol) -> ParseResult<&'a LogicExpr<'a>> {
-        let result = self.parse_predicate_impl(subject_symbol, false)?;
+        let result = self.traced("predicate", |p| p.parse_predicate_impl(subject_symbol, false))?;
         Ok(self.try_wrap_bounded_delay(result))
     }
 
     fn parse_predicate_with_subject_as_var(&mut self, subject_symbol: Symbol) -> ParseResult<&'a LogicExpr<'a>> {
-        let result = self.parse_predicate_impl(subject_symbol, true)?;
+        let result = self.traced("predicate", |p| p.parse_predicate_impl(subject_symbol, true))?;
         Ok(self.try_wrap_bounded_delay(result))
     }
 
@@ -4797,6 +4805,15 @@ impl<'a, 'ctx, 'int> LogicVerbParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int>
             return Ok(Some(with_time));
         }
 
+        // Floated quantifier: "John and Mary each lifted the piano".
+        let floated = self.check(&TokenType::All)
+            || self.check(&TokenType::Both)
+            || self.interner.resolve(self.peek().lexeme).eq_ignore_ascii_case("each");
+        if floated && matches!(self.tokens.get(self.current + 1).map(|t| &t.kind), Some(TokenType::Verb { .. })) {
+            self.advance();
+            self.distributive_marker = true;
+        }
+
         if !self.check_verb() {
             self.current = saved_pos;
             return Ok(None);
@@ -4804,6 +4821,7 @@ impl<'a, 'ctx, 'int> LogicVerbParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int>
 
         // Coordinated subjects registered in DRS via introduce_referent
 
+        let verb_pos = self.current;
         let (verb, verb_time, _verb_aspect, _) = self.consume_verb_with_metadata();
 
         // Check for reciprocal: "John and Mary kicked each other"
@@ -4865,7 +4883,7 @@ impl<'a, 'ctx, 'int> LogicVerbParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int>
             // Parse additional objects: "Tom and Jerry and Bob"
             while self.check(&TokenType::And) {
                 self.advance();
-                if self.check_content_word() || self.check_article() {
+                if (self.check_content_word() || self.check_article()) && !self.coordinate_opens_clause(self.current, verb) {
                     let next_obj = match self.parse_noun_phrase(false) {
                         Ok(np) => np,
                         Err(_) => break,
@@ -4983,14 +5001,71 @@ impl<'a, 'ctx, 'int> LogicVerbParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int>
             return Ok(Some(with_time));
         }
 
+        // A distributive verb ("John and Mary slept") holds of each member
+        // alone, as does any non-collective verb after a floated "each" or
+        // under the distributive construal; the collective construal keeps
+        // the group. Each member gets its own
+        // parse of the shared verb phrase.
+        let lemma = self.interner.resolve(verb);
+        let distributes = !self.collective_mode
+            && !Lexer::is_collective_verb(lemma)
+            && (Lexer::is_distributive_verb(lemma) || self.distributive_marker);
+        if distributes {
+            let mut result: Option<&'a LogicExpr<'a>> = None;
+            for subj in &subjects {
+                self.current = verb_pos;
+                let mut pred = self.parse_predicate_with_subject(*subj)?;
+                // "… ate an apple and slept": a coordinated verb phrase is
+                // part of what each member did.
+                loop {
+                    let saved = self.current;
+                    if !self.match_token(&[TokenType::And, TokenType::Comma]) {
+                        break;
+                    }
+                    match self.try_parse_coordinated_vp(pred)? {
+                        Some(joined) => pred = joined,
+                        None => {
+                            self.current = saved;
+                            break;
+                        }
+                    }
+                }
+                result = Some(match result {
+                    Some(left) => self.ctx.exprs.alloc(LogicExpr::BinaryOp { left, op: TokenType::And, right: pred }),
+                    None => pred,
+                });
+            }
+            return Ok(result);
+        }
+
         // No "respectively" - use group semantics
-        if objects.is_empty() {
+        let mut result = if objects.is_empty() {
             // Intransitive: group subject
-            Ok(Some(self.build_group_predicate(&subjects, verb, verb_time)))
+            self.build_group_predicate(&subjects, verb, verb_time)
         } else {
             // Transitive without "respectively": group subject, group object
-            Ok(Some(self.build_group_transitive(&subjects, &objects, verb, verb_time)))
+            self.build_group_transitive(&subjects, &objects, verb, verb_time)
+        };
+
+        // Coordinated verb phrases keep the group: "John and Mary met and talked".
+        loop {
+            let saved = self.current;
+            if !self.match_token(&[TokenType::And, TokenType::Comma])
+                || !matches!(self.peek().kind, TokenType::Verb { .. })
+            {
+                self.current = saved;
+                break;
+            }
+            let (verb, verb_time, _, _) = self.consume_verb_with_metadata();
+            let next = if self.check_content_word() || self.check_article() {
+                let object = self.parse_noun_phrase(false)?;
+                self.build_group_transitive(&subjects, &[object.noun], verb, verb_time)
+            } else {
+                self.build_group_predicate(&subjects, verb, verb_time)
+            };
+            result = self.ctx.exprs.alloc(LogicExpr::BinaryOp { left: result, op: TokenType::And, right: next });
         }
+        Ok(Some(result))
     }
 
     /// Build a group predicate for intransitive verbs
@@ -5088,7 +5163,7 @@ impl<'a, 'ctx, 'int> LogicVerbParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int>
                 }));
             }
 
-            let inf_verb = self.consume_verb();
+            let inf_verb = self.consume_verb()?;
 
             let embedded = if self.is_control_verb(inf_verb) {
                 let raised_np = NounPhrase {
@@ -5172,11 +5247,11 @@ impl<'a, 'ctx, 'int> LogicVerbParsing<'a, 'ctx, 'int> for Parser<'a, 'ctx, 'int>
             }));
         }
 
-        let inf_verb = self.consume_verb();
+        let inf_verb = self.consume_verb()?;
         let inf_verb_str = self.interner.resolve(inf_verb).to_lowercase();
 
         let infinitive = if inf_verb_str == "be" && self.check_verb() {
-            let passive_verb = self.consume_verb();
+            let passive_verb = self.consume_verb()?;
             // An agent by-phrase fills the first argument slot, matching the
             // finite passive ("was seen by the people" → See(People, s)).
             let mut passive_args = vec![Term::Constant(pro_controller_sym)];
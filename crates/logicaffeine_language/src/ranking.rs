@@ -0,0 +1,262 @@
+//! Reading ranking — which reading of an ambiguous sentence is most likely.
+//!
+//! [`compile_forest`](crate::compile_forest) produces every grammatical
+//! reading; this module orders them. Each reading is described by a small
+//! vector of [`ReadingFeatures`], each in `0.0..=1.0`, and scored as a
+//! weighted sum under [`RankingWeights`]:
+//!
+//! | Feature | 1.0 means |
+//! |---------|-----------|
+//! | `attachment_locality` | a PP attached to the nearest noun, not the verb |
+//! | `surface_scope` | quantifiers scope in the order they were spoken |
+//! | `lexical_frequency` | every ambiguous word took its primary category |
+//! | `sort_compatibility` | every predicate's sort requirement is met |
+//! | `marked_construal` | a non-default interpretive mode (collective, wide negation, a modal flavour, …) |
+//!
+//! Weights are data, not code. The defaults ship in
+//! `assets/ranking_weights.json` and reproduce the parser's hand-tuned
+//! preferences (minimal attachment, surface scope, literal construals);
+//! point `LOGOS_RANKING_WEIGHTS` at another JSON file to retune them
+//! without a rebuild. Ties keep the forest's generation order, so the
+//! parser's own default reading wins any tie.
+
+use logicaffeine_base::Interner;
+use serde::Deserialize;
+
+use crate::ast::{LogicExpr, Term};
+use crate::lexicon::Sort;
+use crate::visitor::{walk_expr, Visitor};
+
+/// The bundled default weights.
+pub const DEFAULT_WEIGHTS_JSON: &str = include_str!("../assets/ranking_weights.json");
+
+/// Feature vector for one reading. Every feature lies in `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ReadingFeatures {
+    pub attachment_locality: f64,
+    pub surface_scope: f64,
+    pub lexical_frequency: f64,
+    pub sort_compatibility: f64,
+    pub marked_construal: f64,
+}
+
+impl ReadingFeatures {
+    /// Fills in the features read off the reading's logical form:
+    /// [`surface_scope`] and [`sort_compatibility`].
+    pub fn with_form(mut self, expr: &LogicExpr<'_>, interner: &Interner) -> Self {
+        self.surface_scope = surface_scope(expr, interner);
+        self.sort_compatibility = sort_compatibility(expr, interner);
+        self
+    }
+}
+
+/// Linear weights over [`ReadingFeatures`]. Negative weights express
+/// dispreferences.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RankingWeights {
+    pub attachment_locality: f64,
+    pub surface_scope: f64,
+    pub lexical_frequency: f64,
+    pub sort_compatibility: f64,
+    pub marked_construal: f64,
+}
+
+impl Default for RankingWeights {
+    fn default() -> Self {
+        Self::from_json(DEFAULT_WEIGHTS_JSON).expect("bundled ranking weights are valid")
+    }
+}
+
+impl RankingWeights {
+    /// Parses weights from JSON; every field is required.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// The EFFECTIVE weights: the bundled defaults unless
+    /// `LOGOS_RANKING_WEIGHTS` names a readable, valid JSON file. Read once
+    /// per process.
+    pub fn active() -> &'static RankingWeights {
+        static WEIGHTS: std::sync::OnceLock<RankingWeights> = std::sync::OnceLock::new();
+        WEIGHTS.get_or_init(|| {
+            let file = std::env::var("LOGOS_RANKING_WEIGHTS")
+                .ok()
+                .and_then(|path| std::fs::read_to_string(path).ok());
+            weights_from(file.as_deref())
+        })
+    }
+
+    pub fn score(&self, f: &ReadingFeatures) -> f64 {
+        self.attachment_locality * f.attachment_locality
+            + self.surface_scope * f.surface_scope
+            + self.lexical_frequency * f.lexical_frequency
+            + self.sort_compatibility * f.sort_compatibility
+            + self.marked_construal * f.marked_construal
+    }
+}
+
+/// Pure resolution of an override file's contents (unit-testable without
+/// touching the process environment): valid JSON wins, anything else falls
+/// back to the bundled defaults.
+pub fn weights_from(json: Option<&str>) -> RankingWeights {
+    json.and_then(|j| RankingWeights::from_json(j).ok()).unwrap_or_default()
+}
+
+/// Indices of `features` from most to least likely. Stable: equal scores
+/// keep their original order.
+pub fn rank(features: &[ReadingFeatures], weights: &RankingWeights) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..features.len()).collect();
+    order.sort_by(|&a, &b| {
+        weights.score(&features[b]).total_cmp(&weights.score(&features[a]))
+    });
+    order
+}
+
+/// Position of a parser-generated variable in allocation (= surface) order:
+/// `x, y, z, w, v, u, x1, x2, …`.
+fn surface_position(name: &str) -> Option<usize> {
+    const VARS: [&str; 6] = ["x", "y", "z", "w", "v", "u"];
+    VARS.iter().position(|v| *v == name).or_else(|| {
+        name.strip_prefix('x')?.parse::<usize>().ok().map(|n| VARS.len() + n - 1)
+    })
+}
+
+/// Share of nested quantifier pairs whose scope follows surface order (an
+/// outer quantifier introduced before its inner one). 1.0 with fewer than
+/// two nested quantifiers.
+pub fn surface_scope(expr: &LogicExpr<'_>, interner: &Interner) -> f64 {
+    struct Scopes<'i> {
+        interner: &'i Interner,
+        open: Vec<usize>,
+        pairs: usize,
+        in_order: usize,
+    }
+    impl<'a> Visitor<'a> for Scopes<'_> {
+        fn visit_expr(&mut self, expr: &'a LogicExpr<'a>) {
+            let position = match expr {
+                LogicExpr::Quantifier { variable, .. } => {
+                    surface_position(self.interner.resolve(*variable))
+                }
+                _ => None,
+            };
+            if let Some(p) = position {
+                self.pairs += self.open.len();
+                self.in_order += self.open.iter().filter(|&&outer| outer < p).count();
+                self.open.push(p);
+            }
+            walk_expr(self, expr);
+            if position.is_some() {
+                self.open.pop();
+            }
+        }
+    }
+    let mut scopes = Scopes { interner, open: Vec::new(), pairs: 0, in_order: 0 };
+    scopes.visit_expr(expr);
+    if scopes.pairs == 0 {
+        1.0
+    } else {
+        scopes.in_order as f64 / scopes.pairs as f64
+    }
+}
+
+/// Share of sort-checkable predications that pass their sort requirement.
+///
+/// A predication is checkable when its predicate requires a sort
+/// ([`crate::ontology::required_sort`]) and its argument has one — a
+/// sorted proper noun, or a variable restricted by a sorted noun. Metaphor
+/// nodes are predications the parser already found incompatible. 1.0 when
+/// nothing is checkable.
+pub fn sort_compatibility(expr: &LogicExpr<'_>, interner: &Interner) -> f64 {
+    struct Predications<'a, 'i> {
+        interner: &'i Interner,
+        unary: Vec<(&'i str, &'a Term<'a>)>,
+        metaphors: usize,
+    }
+    impl<'a> Visitor<'a> for Predications<'a, '_> {
+        fn visit_expr(&mut self, expr: &'a LogicExpr<'a>) {
+            match expr {
+                LogicExpr::Predicate { name, args: [arg], .. } => {
+                    self.unary.push((self.interner.resolve(*name), arg));
+                }
+                LogicExpr::Metaphor { .. } => self.metaphors += 1,
+                _ => {}
+            }
+            walk_expr(self, expr);
+        }
+    }
+    let mut found = Predications { interner, unary: Vec::new(), metaphors: 0 };
+    found.visit_expr(expr);
+
+    let sort_of = |term: &Term<'_>| -> Option<Sort> {
+        match term {
+            Term::Constant(c) => crate::lexicon::lookup_sort(interner.resolve(*c)),
+            Term::Variable(v) => found.unary.iter().find_map(|(noun, arg)| match arg {
+                Term::Variable(w) if w == v => crate::lexicon::lookup_sort(noun),
+                _ => None,
+            }),
+            _ => None,
+        }
+    };
+
+    let mut checked = found.metaphors;
+    let mut passed = 0;
+    for (predicate, arg) in &found.unary {
+        if let (Some(required), Some(sort)) = (crate::ontology::required_sort(predicate), sort_of(arg)) {
+            checked += 1;
+            if sort.is_compatible_with(required) {
+                passed += 1;
+            }
+        }
+    }
+    if checked == 0 {
+        1.0
+    } else {
+        passed as f64 / checked as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn features(attachment_locality: f64, marked_construal: f64) -> ReadingFeatures {
+        ReadingFeatures { attachment_locality, marked_construal, ..ReadingFeatures::default() }
+    }
+
+    #[test]
+    fn bundled_weights_parse() {
+        let weights = RankingWeights::default();
+        assert!(weights.marked_construal < 0.0);
+        assert!(weights.surface_scope > 0.0);
+    }
+
+    #[test]
+    fn invalid_or_missing_overrides_fall_back_to_defaults() {
+        assert_eq!(weights_from(None), RankingWeights::default());
+        assert_eq!(weights_from(Some("not json")), RankingWeights::default());
+        assert_eq!(weights_from(Some(r#"{"surface_scope": 1.0}"#)), RankingWeights::default());
+        let tuned = weights_from(Some(
+            r#"{"attachment_locality": 3.0, "surface_scope": 0.0, "lexical_frequency": 0.0,
+                "sort_compatibility": 0.0, "marked_construal": 0.0}"#,
+        ));
+        assert_eq!(tuned.attachment_locality, 3.0);
+    }
+
+    #[test]
+    fn rank_orders_by_score_and_keeps_ties_in_place() {
+        let weights = RankingWeights::default();
+        let readings = [features(1.0, 0.0), features(0.0, 0.0), features(0.0, 1.0), features(0.0, 0.0)];
+        assert_eq!(rank(&readings, &weights), [1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn surface_positions_follow_variable_allocation_order() {
+        let order: Vec<_> = ["x", "y", "z", "w", "v", "u", "x1", "x2"]
+            .iter()
+            .map(|v| surface_position(v))
+            .collect();
+        assert_eq!(order, (0..8).map(Some).collect::<Vec<_>>());
+        assert_eq!(surface_position("e"), None);
+    }
+}
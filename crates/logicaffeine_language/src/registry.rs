@@ -10,9 +10,10 @@ use std::collections::HashMap;
 use logicaffeine_base::{Interner, Symbol};
 
 /// Registry for mapping words to FOL variable names.
+#[derive(Clone)]
 pub struct SymbolRegistry {
-    mapping: HashMap<String, String>,
-    counters: HashMap<char, usize>,
+    pub(crate) mapping: HashMap<String, String>,
+    pub(crate) counters: HashMap<char, usize>,
 }
 
 impl SymbolRegistry {
@@ -0,0 +1,2009 @@
+//! Versioned, lossless serialization of parsed ASTs.
+//!
+//! ASTs normally live in the parser's arenas for the length of one call, with
+//! every name interned. This module writes a [`LogicExpr`] (with its
+//! [`Term`]s) or an imperative program (a slice of [`Stmt`]s) out as a
+//! self-contained document — names spelled out, arenas flattened into owned
+//! trees — and reads it back into fresh arenas. A read-back tree is
+//! indistinguishable from the parsed one, so documents work as parse caches,
+//! as stored parse results, and as the exchange format between processes.
+//!
+//! Two encodings share one document model:
+//!
+//! - **JSON** ([`logic_to_json`], [`program_to_json`]) — a
+//!   `{"format": "logos-ast", "version": N, "ast": …}` envelope, readable
+//!   and diffable
+//! - **Binary** ([`logic_to_bytes`], [`program_to_bytes`]) — a 4-byte magic
+//!   and little-endian `u32` version, then the tree as bincode
+//!
+//! # Versioning
+//!
+//! Every document carries [`FORMAT_VERSION`]. Readers accept any version up
+//! to their own and refuse newer ones with [`SerialError::TooNew`] rather
+//! than misreading them. JSON readers also skip fields they don't know, so a
+//! document from a writer that added optional information still loads.
+//! Changing what an existing field or variant means requires a version bump.
+//!
+//! # Example
+//!
+//! ```
+//! use logicaffeine_language::ast::Term;
+//! use logicaffeine_language::{Arena, AstContext, Interner};
+//! use logicaffeine_language::serial::{logic_from_json, logic_to_json};
+//!
+//! let mut interner = Interner::new();
+//! let (exprs, terms, nps, syms, roles, pps) =
+//!     (Arena::new(), Arena::new(), Arena::new(), Arena::new(), Arena::new(), Arena::new());
+//! let ctx = AstContext::new(&exprs, &terms, &nps, &syms, &roles, &pps);
+//!
+//! let socrates = Term::Constant(interner.intern("Socrates"));
+//! let mortal = ctx.predicate(interner.intern("Mortal"), ctx.alloc_terms([socrates]));
+//! let json = logic_to_json(mortal, &interner).unwrap();
+//!
+//! let mut other = Interner::new();
+//! let back = logic_from_json(&json, ctx, &mut other).unwrap();
+//! assert_eq!(logic_to_json(back, &other).unwrap(), json);
+//! ```
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use logicaffeine_base::{Interner, Symbol};
+
+use crate::arena_ctx::AstContext;
+use crate::ast::logic::{CategoricalData, NeoEventData, NumberKind, RelationData};
+use crate::ast::stmt::{ClosureBody, CollectionKind, Pattern, ReadSource, SelectBranch, StringPart, ZoneSource};
+use crate::ast::{
+    AspectOperator, AxiomBlock, BinaryOpKind, BinaryTemporalOp, Block, ComparisonRelation,
+    CompressionCodec, DefinitionBlock, Dimension, Expr, Literal, LogLevel, LogicExpr, MatchArm,
+    ModalDomain, ModalFlavor, ModalVector, NounPhrase, ProofStrategy, QuantifierKind, SecurePad,
+    SecureRole, SendLayout, Stmt, SwarmDiscovery, TemporalOperator, Term, TheoremBlock,
+    ThematicRole, TheoryBlock, TypeExpr, VoiceOperator,
+};
+use crate::lexicon::Definiteness;
+use crate::optimization::OptimizationConfig;
+use crate::token::{FocusKind, Span, TokenType};
+
+/// The `format` tag every JSON document carries.
+pub const FORMAT: &str = "logos-ast";
+
+/// The document version this build writes, and the newest it reads.
+pub const FORMAT_VERSION: u32 = 1;
+
+const MAGIC: &[u8; 4] = b"LGAS";
+
+/// Why a tree could not be written or read.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SerialError {
+    /// The tree holds something the format has no encoding for.
+    Unsupported(String),
+    /// The input is not a LOGOS AST document at all.
+    NotAnAst,
+    /// The document was written by a newer format version.
+    TooNew { found: u32, supported: u32 },
+    /// The document claims to be an AST but does not decode as one.
+    Malformed(String),
+    /// A program was read into an [`AstContext`] without imperative arenas.
+    MissingArenas,
+}
+
+impl fmt::Display for SerialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SerialError::Unsupported(what) => write!(f, "cannot serialize {what}"),
+            SerialError::NotAnAst => write!(f, "not a {FORMAT} document"),
+            SerialError::TooNew { found, supported } => write!(
+                f,
+                "{FORMAT} version {found} is newer than this reader (version {supported})"
+            ),
+            SerialError::Malformed(message) => write!(f, "malformed {FORMAT} document: {message}"),
+            SerialError::MissingArenas => {
+                write!(f, "decoding a program needs an AstContext with imperative and type arenas")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SerialError {}
+
+// ═══════════════════════════════════════════════════════════════════
+// Document Model
+// ═══════════════════════════════════════════════════════════════════
+
+/// A serialized tree with its format tag and version.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Document {
+    pub format: String,
+    pub version: u32,
+    pub ast: Tree,
+}
+
+/// What a document holds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Tree {
+    /// A single logical formula.
+    Logic(Box<LogicNode>),
+    /// An imperative program, top-level statements in order.
+    Program(Vec<StmtNode>),
+}
+
+impl Document {
+    fn new(ast: Tree) -> Self {
+        Document { format: FORMAT.to_string(), version: FORMAT_VERSION, ast }
+    }
+}
+
+/// [`Term`] with names spelled out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TermNode {
+    Constant(String),
+    Variable(String),
+    Function(String, Vec<TermNode>),
+    Group(Vec<TermNode>),
+    Possessed { possessor: Box<TermNode>, possessed: String },
+    Sigma(String),
+    Intension(String),
+    Kind(String),
+    Proposition(Box<LogicNode>),
+    Value { kind: NumberNode, unit: Option<String>, dimension: Option<Dimension> },
+}
+
+/// [`NumberKind`] with names spelled out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NumberNode {
+    Real(f64),
+    Integer(i64),
+    Symbolic(String),
+}
+
+/// The article of a noun phrase, mirroring the lexicon's [`Definiteness`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DefinitenessNode {
+    Definite,
+    Indefinite,
+    Proximal,
+    Distal,
+}
+
+/// [`NounPhrase`] with names spelled out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NounPhraseNode {
+    pub definiteness: Option<DefinitenessNode>,
+    pub adjectives: Vec<String>,
+    pub noun: String,
+    pub possessor: Option<Box<NounPhraseNode>>,
+    pub pps: Vec<LogicNode>,
+    pub superlative: Option<String>,
+}
+
+/// [`ModalVector`] with names spelled out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModalNode {
+    pub domain: ModalDomain,
+    pub force: f32,
+    pub flavor: ModalFlavor,
+    pub modal_base: Option<String>,
+    pub ordering_source: Option<String>,
+}
+
+/// The connective tokens a `BinaryOp` or `UnaryOp` can carry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Connective {
+    And,
+    Or,
+    If,
+    Then,
+    Not,
+    Iff,
+    Because,
+    Although,
+    Until,
+    Release,
+    WeakUntil,
+    Implies,
+}
+
+/// The quantifier tokens a `Categorical` proposition can carry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CategoricalQuantifier {
+    All,
+    No,
+    Some,
+    Any,
+    Both,
+    Most,
+    Few,
+    Many,
+    Cardinal(u32),
+    AtLeast(u32),
+    AtMost(u32),
+}
+
+/// [`LogicExpr`] with names spelled out; one variant per expression form.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LogicNode {
+    Predicate { name: String, args: Vec<TermNode>, world: Option<String> },
+    Identity { left: TermNode, right: TermNode },
+    Metaphor { tenor: TermNode, vehicle: TermNode },
+    Quantifier { kind: QuantifierKind, variable: String, body: Box<LogicNode>, island_id: u32 },
+    Categorical {
+        quantifier: CategoricalQuantifier,
+        subject: NounPhraseNode,
+        copula_negative: bool,
+        predicate: NounPhraseNode,
+    },
+    Relation { subject: NounPhraseNode, verb: String, object: NounPhraseNode },
+    Modal { vector: ModalNode, operand: Box<LogicNode> },
+    Temporal { operator: TemporalOperator, body: Box<LogicNode> },
+    TemporalBinary { operator: BinaryTemporalOp, left: Box<LogicNode>, right: Box<LogicNode> },
+    Aspectual { operator: AspectOperator, body: Box<LogicNode> },
+    Voice { operator: VoiceOperator, body: Box<LogicNode> },
+    BinaryOp { left: Box<LogicNode>, op: Connective, right: Box<LogicNode> },
+    UnaryOp { op: Connective, operand: Box<LogicNode> },
+    Question { wh_variable: String, body: Box<LogicNode> },
+    YesNoQuestion { body: Box<LogicNode> },
+    Atom(String),
+    Lambda { variable: String, body: Box<LogicNode> },
+    App { function: Box<LogicNode>, argument: Box<LogicNode> },
+    Intensional { operator: String, content: Box<LogicNode> },
+    Event { predicate: Box<LogicNode>, adverbs: Vec<String> },
+    NeoEvent {
+        event_var: String,
+        verb: String,
+        roles: Vec<(ThematicRole, TermNode)>,
+        modifiers: Vec<String>,
+        suppress_existential: bool,
+        world: Option<String>,
+    },
+    Imperative { action: Box<LogicNode> },
+    Exclamative { degree_var: String, body: Box<LogicNode> },
+    Optative { wish: Box<LogicNode> },
+    Implicature { assertion: Box<LogicNode>, implicature: Box<LogicNode> },
+    SpeechAct { performer: String, act_type: String, content: Box<LogicNode> },
+    Counterfactual { antecedent: Box<LogicNode>, consequent: Box<LogicNode> },
+    Causal { effect: Box<LogicNode>, cause: Box<LogicNode> },
+    Concessive { main: Box<LogicNode>, concession: Box<LogicNode> },
+    Comparative {
+        adjective: String,
+        subject: TermNode,
+        object: TermNode,
+        difference: Option<TermNode>,
+        relation: ComparisonRelation,
+    },
+    Superlative { adjective: String, subject: TermNode, domain: String },
+    Scopal { operator: String, body: Box<LogicNode> },
+    Control { verb: String, subject: TermNode, object: Option<TermNode>, infinitive: Box<LogicNode> },
+    Presupposition { assertion: Box<LogicNode>, presupposition: Box<LogicNode> },
+    Focus { kind: FocusKind, focused: TermNode, scope: Box<LogicNode> },
+    TemporalAnchor { anchor: String, body: Box<LogicNode> },
+    Distributive { predicate: Box<LogicNode> },
+    GroupQuantifier {
+        group_var: String,
+        count: u32,
+        member_var: String,
+        restriction: Box<LogicNode>,
+        body: Box<LogicNode>,
+    },
+}
+
+/// [`TypeExpr`] with names spelled out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TypeNode {
+    Primitive(String),
+    Named(String),
+    Generic { base: String, params: Vec<TypeNode> },
+    Function { inputs: Vec<TypeNode>, output: Box<TypeNode> },
+    Refinement { base: Box<TypeNode>, var: String, predicate: Box<LogicNode> },
+    Persistent { inner: Box<TypeNode> },
+    Mutable { inner: Box<TypeNode> },
+}
+
+/// [`Literal`] with text spelled out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LiteralNode {
+    Number(i64),
+    Float(f64),
+    Text(String),
+    Boolean(bool),
+    Nothing,
+    Char(char),
+    Duration(i64),
+    Date(i32),
+    Moment(i64),
+    Span { months: i32, days: i32 },
+    Time(i64),
+}
+
+/// The source of a `Read … from …` statement.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ReadSourceNode {
+    Console,
+    File(Box<ExprNode>),
+    Settings { type_name: String, path: Option<Box<ExprNode>> },
+    Arguments { type_name: String },
+}
+
+/// The loop variable(s) of a `Repeat`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PatternNode {
+    Identifier(String),
+    Tuple(Vec<String>),
+}
+
+/// One arm of an `Inspect`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchArmNode {
+    pub enum_name: Option<String>,
+    pub variant: Option<String>,
+    pub bindings: Vec<(String, String)>,
+    pub body: Vec<StmtNode>,
+}
+
+/// Where a `Zone` is mapped from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ZoneSourceNode {
+    Literal(String),
+    Variable(String),
+}
+
+/// The pre-shared pad of a secure `Listen` or `Connect`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SecurePadNode {
+    pub pad: ExprNode,
+    pub role: SecureRole,
+}
+
+/// One discovery mechanism of a `Join swarm`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SwarmNode {
+    Local,
+    Bootstrap(Option<ExprNode>),
+    Relays(Option<ExprNode>),
+}
+
+/// One branch of a `Select`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SelectBranchNode {
+    Receive { var: String, pipe: ExprNode, body: Vec<StmtNode> },
+    Timeout { milliseconds: ExprNode, body: Vec<StmtNode> },
+}
+
+/// One piece of an interpolated string.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StringPartNode {
+    Literal(String),
+    Expr { value: ExprNode, format_spec: Option<String>, debug: bool },
+}
+
+/// The body of a closure.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ClosureBodyNode {
+    Expression(Box<ExprNode>),
+    Block(Vec<StmtNode>),
+}
+
+/// A `## Theorem` block.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TheoremNode {
+    pub name: String,
+    pub premises: Vec<LogicNode>,
+    pub premise_names: Vec<Option<String>>,
+    pub goal: LogicNode,
+    pub strategy: ProofStrategy,
+}
+
+/// A `## Define` block.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DefinitionNode {
+    pub name: String,
+    pub params: Vec<String>,
+    pub definiendum: LogicNode,
+    pub definiens: LogicNode,
+}
+
+/// [`Expr`] with names spelled out; one variant per expression form.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ExprNode {
+    Literal(LiteralNode),
+    Identifier(String),
+    BinaryOp { op: BinaryOpKind, left: Box<ExprNode>, right: Box<ExprNode> },
+    Not { operand: Box<ExprNode> },
+    Call { function: String, args: Vec<ExprNode> },
+    Index { collection: Box<ExprNode>, index: Box<ExprNode> },
+    Slice { collection: Box<ExprNode>, start: Box<ExprNode>, end: Box<ExprNode> },
+    Copy { expr: Box<ExprNode> },
+    Give { value: Box<ExprNode> },
+    Length { collection: Box<ExprNode> },
+    Contains { collection: Box<ExprNode>, value: Box<ExprNode> },
+    Union { left: Box<ExprNode>, right: Box<ExprNode> },
+    Intersection { left: Box<ExprNode>, right: Box<ExprNode> },
+    ManifestOf { zone: Box<ExprNode> },
+    ChunkAt { index: Box<ExprNode>, zone: Box<ExprNode> },
+    List(Vec<ExprNode>),
+    Tuple(Vec<ExprNode>),
+    Range { start: Box<ExprNode>, end: Box<ExprNode> },
+    FieldAccess { object: Box<ExprNode>, field: String },
+    New { type_name: String, type_args: Vec<TypeNode>, init_fields: Vec<(String, ExprNode)> },
+    NewVariant { enum_name: String, variant: String, fields: Vec<(String, ExprNode)> },
+    Escape { language: String, code: String },
+    OptionSome { value: Box<ExprNode> },
+    OptionNone,
+    WithCapacity { value: Box<ExprNode>, capacity: Box<ExprNode> },
+    Closure { params: Vec<(String, TypeNode)>, body: ClosureBodyNode, return_type: Option<TypeNode> },
+    CallExpr { callee: Box<ExprNode>, args: Vec<ExprNode> },
+    InterpolatedString(Vec<StringPartNode>),
+    Comprehension {
+        kind: CollectionKind,
+        element: Box<ExprNode>,
+        value: Option<Box<ExprNode>>,
+        pattern: PatternNode,
+        iterable: Box<ExprNode>,
+        filter: Option<Box<ExprNode>>,
+    },
+}
+
+/// [`Stmt`] with names spelled out; one variant per statement form.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StmtNode {
+    Let { var: String, ty: Option<TypeNode>, value: ExprNode, mutable: bool },
+    Set { target: String, value: ExprNode },
+    Call { function: String, args: Vec<ExprNode> },
+    If { cond: ExprNode, then_block: Vec<StmtNode>, else_block: Option<Vec<StmtNode>> },
+    While { cond: ExprNode, body: Vec<StmtNode>, decreasing: Option<ExprNode> },
+    Repeat { pattern: PatternNode, iterable: ExprNode, body: Vec<StmtNode> },
+    Return { value: Option<ExprNode> },
+    Break,
+    Assert { proposition: LogicNode },
+    Trust { proposition: LogicNode, justification: String },
+    RuntimeAssert { condition: ExprNode, hard: bool },
+    Give { object: ExprNode, recipient: ExprNode },
+    Show { object: ExprNode, recipient: ExprNode },
+    Log { level: LogLevel, message: ExprNode, fields: Vec<(String, ExprNode)> },
+    RunCommand {
+        command: ExprNode,
+        timeout: Option<ExprNode>,
+        output: Option<String>,
+        errors: Option<String>,
+        exit_code: Option<String>,
+    },
+    SetField { object: ExprNode, field: String, value: ExprNode },
+    StructDef { name: String, fields: Vec<(String, String, bool)>, is_portable: bool },
+    FunctionDef {
+        name: String,
+        generics: Vec<String>,
+        params: Vec<(String, TypeNode)>,
+        body: Vec<StmtNode>,
+        return_type: Option<TypeNode>,
+        is_native: bool,
+        native_path: Option<String>,
+        is_exported: bool,
+        export_target: Option<String>,
+        opt_flags: OptimizationConfig,
+    },
+    Inspect { target: ExprNode, arms: Vec<MatchArmNode>, has_otherwise: bool },
+    Push { value: ExprNode, collection: ExprNode },
+    Pop { collection: ExprNode, into: Option<String> },
+    Add { value: ExprNode, collection: ExprNode },
+    Remove { value: ExprNode, collection: ExprNode },
+    SetIndex { collection: ExprNode, index: ExprNode, value: ExprNode },
+    Splice { body: Vec<StmtNode> },
+    Zone { name: String, capacity: Option<usize>, source_file: Option<ZoneSourceNode>, body: Vec<StmtNode> },
+    Concurrent { tasks: Vec<StmtNode> },
+    Parallel { tasks: Vec<StmtNode> },
+    ReadFrom { var: String, source: ReadSourceNode },
+    WriteFile { content: ExprNode, path: ExprNode },
+    Spawn { agent_type: String, name: String },
+    SendMessage {
+        message: ExprNode,
+        destination: ExprNode,
+        compression: Option<CompressionCodec>,
+        cached: bool,
+        unchecked: bool,
+        layout: Option<SendLayout>,
+        shared: bool,
+        computed: bool,
+        indexed: bool,
+        deduped: bool,
+    },
+    AwaitMessage { source: ExprNode, into: String, view: bool, stream: bool },
+    StreamMessage { values: ExprNode, destination: ExprNode },
+    MergeCrdt { source: ExprNode, target: ExprNode },
+    IncreaseCrdt { object: ExprNode, field: String, amount: ExprNode },
+    DecreaseCrdt { object: ExprNode, field: String, amount: ExprNode },
+    AppendToSequence { sequence: ExprNode, value: ExprNode },
+    ResolveConflict { object: ExprNode, field: String, value: ExprNode },
+    Check {
+        subject: String,
+        predicate: String,
+        is_capability: bool,
+        object: Option<String>,
+        source_text: String,
+        span: Span,
+    },
+    Listen { address: ExprNode, secure: Option<SecurePadNode> },
+    ConnectTo { address: ExprNode, secure: Option<SecurePadNode> },
+    JoinSwarm { using: Vec<SwarmNode> },
+    LetPeerAgent { var: String, address: ExprNode },
+    Sleep { milliseconds: ExprNode },
+    Sync { var: String, topic: ExprNode },
+    Mount { var: String, path: ExprNode },
+    LaunchTask { function: String, args: Vec<ExprNode> },
+    LaunchTaskWithHandle { handle: String, function: String, args: Vec<ExprNode> },
+    CreatePipe { var: String, element_type: String, capacity: Option<u32> },
+    SendPipe { value: ExprNode, pipe: ExprNode },
+    ReceivePipe { var: String, pipe: ExprNode },
+    TrySendPipe { value: ExprNode, pipe: ExprNode, result: Option<String> },
+    TryReceivePipe { var: String, pipe: ExprNode },
+    StopTask { handle: ExprNode },
+    Select { branches: Vec<SelectBranchNode> },
+    Theorem(TheoremNode),
+    Definition(DefinitionNode),
+    Axiom(AxiomBlock),
+    Theory(TheoryBlock),
+    Escape { language: String, code: String, span: Span },
+    Require { crate_name: String, version: String, features: Vec<String>, span: Span },
+    Sip { file: ExprNode, sources: ExprNode, progress: Option<String> },
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// Public Entry Points
+// ═══════════════════════════════════════════════════════════════════
+
+/// Flatten a formula into the document model.
+pub fn encode_logic(expr: &LogicExpr, interner: &Interner) -> Result<Document, SerialError> {
+    Ok(Document::new(Tree::Logic(Encoder { interner }.boxed(expr)?)))
+}
+
+/// Flatten a program into the document model.
+pub fn encode_program(stmts: &[Stmt], interner: &Interner) -> Result<Document, SerialError> {
+    Ok(Document::new(Tree::Program(Encoder { interner }.block(stmts)?)))
+}
+
+/// A formula as a JSON document.
+pub fn logic_to_json(expr: &LogicExpr, interner: &Interner) -> Result<String, SerialError> {
+    document_to_json(&encode_logic(expr, interner)?)
+}
+
+/// A program as a JSON document.
+pub fn program_to_json(stmts: &[Stmt], interner: &Interner) -> Result<String, SerialError> {
+    document_to_json(&encode_program(stmts, interner)?)
+}
+
+/// A formula as a binary document.
+pub fn logic_to_bytes(expr: &LogicExpr, interner: &Interner) -> Result<Vec<u8>, SerialError> {
+    document_to_bytes(&encode_logic(expr, interner)?)
+}
+
+/// A program as a binary document.
+pub fn program_to_bytes(stmts: &[Stmt], interner: &Interner) -> Result<Vec<u8>, SerialError> {
+    document_to_bytes(&encode_program(stmts, interner)?)
+}
+
+/// Read a JSON document into the document model, checking its tag and version.
+pub fn document_from_json(json: &str) -> Result<Document, SerialError> {
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|e| SerialError::Malformed(e.to_string()))?;
+    if value.get("format").and_then(|f| f.as_str()) != Some(FORMAT) {
+        return Err(SerialError::NotAnAst);
+    }
+    let version = value
+        .get("version")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| SerialError::Malformed("missing version".to_string()))?;
+    check_version(u32::try_from(version).unwrap_or(u32::MAX))?;
+    serde_json::from_value(value).map_err(|e| SerialError::Malformed(e.to_string()))
+}
+
+/// Read a binary document into the document model, checking its magic and version.
+pub fn document_from_bytes(bytes: &[u8]) -> Result<Document, SerialError> {
+    if bytes.len() < 8 || &bytes[..4] != MAGIC {
+        return Err(SerialError::NotAnAst);
+    }
+    let version = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+    check_version(version)?;
+    let ast = bincode::deserialize(&bytes[8..]).map_err(|e| SerialError::Malformed(e.to_string()))?;
+    Ok(Document { format: FORMAT.to_string(), version, ast })
+}
+
+/// Read a JSON formula into `ctx`'s arenas, interning its names.
+pub fn logic_from_json<'a>(json: &str, ctx: AstContext<'a>, interner: &mut Interner) -> Result<&'a LogicExpr<'a>, SerialError> {
+    decode_logic(&document_from_json(json)?, ctx, interner)
+}
+
+/// Read a JSON program into `ctx`'s arenas, interning its names.
+pub fn program_from_json<'a>(json: &str, ctx: AstContext<'a>, interner: &mut Interner) -> Result<Block<'a>, SerialError> {
+    decode_program(&document_from_json(json)?, ctx, interner)
+}
+
+/// Read a binary formula into `ctx`'s arenas, interning its names.
+pub fn logic_from_bytes<'a>(bytes: &[u8], ctx: AstContext<'a>, interner: &mut Interner) -> Result<&'a LogicExpr<'a>, SerialError> {
+    decode_logic(&document_from_bytes(bytes)?, ctx, interner)
+}
+
+/// Read a binary program into `ctx`'s arenas, interning its names.
+pub fn program_from_bytes<'a>(bytes: &[u8], ctx: AstContext<'a>, interner: &mut Interner) -> Result<Block<'a>, SerialError> {
+    decode_program(&document_from_bytes(bytes)?, ctx, interner)
+}
+
+/// Rebuild the formula a document holds.
+pub fn decode_logic<'a>(document: &Document, ctx: AstContext<'a>, interner: &mut Interner) -> Result<&'a LogicExpr<'a>, SerialError> {
+    match &document.ast {
+        Tree::Logic(node) => Ok(Decoder { ctx, interner }.logic(node)),
+        Tree::Program(_) => Err(SerialError::Malformed("expected a formula, found a program".to_string())),
+    }
+}
+
+/// Rebuild the program a document holds. `ctx` needs its statement,
+/// imperative expression and type arenas.
+pub fn decode_program<'a>(document: &Document, ctx: AstContext<'a>, interner: &mut Interner) -> Result<Block<'a>, SerialError> {
+    let Tree::Program(nodes) = &document.ast else {
+        return Err(SerialError::Malformed("expected a program, found a formula".to_string()));
+    };
+    if ctx.stmts.is_none() || ctx.imperative_exprs.is_none() || ctx.type_exprs.is_none() {
+        return Err(SerialError::MissingArenas);
+    }
+    Ok(Decoder { ctx, interner }.block(nodes))
+}
+
+fn check_version(found: u32) -> Result<(), SerialError> {
+    if found > FORMAT_VERSION {
+        return Err(SerialError::TooNew { found, supported: FORMAT_VERSION });
+    }
+    Ok(())
+}
+
+fn document_to_json(document: &Document) -> Result<String, SerialError> {
+    serde_json::to_string(document).map_err(|e| SerialError::Unsupported(e.to_string()))
+}
+
+fn document_to_bytes(document: &Document) -> Result<Vec<u8>, SerialError> {
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend_from_slice(&document.version.to_le_bytes());
+    bincode::serialize_into(&mut bytes, &document.ast).map_err(|e| SerialError::Unsupported(e.to_string()))?;
+    Ok(bytes)
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// Encoding
+// ═══════════════════════════════════════════════════════════════════
+
+struct Encoder<'i> {
+    interner: &'i Interner,
+}
+
+impl Encoder<'_> {
+    fn name(&self, sym: Symbol) -> String {
+        self.interner.resolve(sym).to_string()
+    }
+
+    fn names(&self, syms: &[Symbol]) -> Vec<String> {
+        syms.iter().map(|&sym| self.name(sym)).collect()
+    }
+
+    fn opt_name(&self, sym: Option<Symbol>) -> Option<String> {
+        sym.map(|sym| self.name(sym))
+    }
+
+    fn term(&self, term: &Term) -> Result<TermNode, SerialError> {
+        Ok(match term {
+            Term::Constant(s) => TermNode::Constant(self.name(*s)),
+            Term::Variable(s) => TermNode::Variable(self.name(*s)),
+            Term::Function(name, args) => TermNode::Function(self.name(*name), self.terms(args)?),
+            Term::Group(members) => TermNode::Group(self.terms(members)?),
+            Term::Possessed { possessor, possessed } => TermNode::Possessed {
+                possessor: Box::new(self.term(possessor)?),
+                possessed: self.name(*possessed),
+            },
+            Term::Sigma(s) => TermNode::Sigma(self.name(*s)),
+            Term::Intension(s) => TermNode::Intension(self.name(*s)),
+            Term::Kind(s) => TermNode::Kind(self.name(*s)),
+            Term::Proposition(expr) => TermNode::Proposition(self.boxed(expr)?),
+            Term::Value { kind, unit, dimension } => TermNode::Value {
+                kind: match kind {
+                    NumberKind::Real(r) => NumberNode::Real(*r),
+                    NumberKind::Integer(i) => NumberNode::Integer(*i),
+                    NumberKind::Symbolic(s) => NumberNode::Symbolic(self.name(*s)),
+                },
+                unit: self.opt_name(*unit),
+                dimension: *dimension,
+            },
+        })
+    }
+
+    fn terms(&self, terms: &[Term]) -> Result<Vec<TermNode>, SerialError> {
+        terms.iter().map(|term| self.term(term)).collect()
+    }
+
+    fn noun_phrase(&self, np: &NounPhrase) -> Result<NounPhraseNode, SerialError> {
+        Ok(NounPhraseNode {
+            definiteness: np.definiteness.map(|d| match d {
+                Definiteness::Definite => DefinitenessNode::Definite,
+                Definiteness::Indefinite => DefinitenessNode::Indefinite,
+                Definiteness::Proximal => DefinitenessNode::Proximal,
+                Definiteness::Distal => DefinitenessNode::Distal,
+            }),
+            adjectives: self.names(np.adjectives),
+            noun: self.name(np.noun),
+            possessor: np.possessor.map(|p| self.noun_phrase(p).map(Box::new)).transpose()?,
+            pps: np.pps.iter().map(|pp| self.logic(pp)).collect::<Result<_, _>>()?,
+            superlative: self.opt_name(np.superlative),
+        })
+    }
+
+    fn connective(&self, op: &TokenType) -> Result<Connective, SerialError> {
+        Ok(match op {
+            TokenType::And => Connective::And,
+            TokenType::Or => Connective::Or,
+            TokenType::If => Connective::If,
+            TokenType::Then => Connective::Then,
+            TokenType::Not => Connective::Not,
+            TokenType::Iff => Connective::Iff,
+            TokenType::Because => Connective::Because,
+            TokenType::Although => Connective::Although,
+            TokenType::Until => Connective::Until,
+            TokenType::Release => Connective::Release,
+            TokenType::WeakUntil => Connective::WeakUntil,
+            TokenType::Implies => Connective::Implies,
+            other => return Err(SerialError::Unsupported(format!("{other:?} as a connective"))),
+        })
+    }
+
+    fn categorical_quantifier(&self, token: &TokenType) -> Result<CategoricalQuantifier, SerialError> {
+        Ok(match token {
+            TokenType::All => CategoricalQuantifier::All,
+            TokenType::No => CategoricalQuantifier::No,
+            TokenType::Some => CategoricalQuantifier::Some,
+            TokenType::Any => CategoricalQuantifier::Any,
+            TokenType::Both => CategoricalQuantifier::Both,
+            TokenType::Most => CategoricalQuantifier::Most,
+            TokenType::Few => CategoricalQuantifier::Few,
+            TokenType::Many => CategoricalQuantifier::Many,
+            TokenType::Cardinal(n) => CategoricalQuantifier::Cardinal(*n),
+            TokenType::AtLeast(n) => CategoricalQuantifier::AtLeast(*n),
+            TokenType::AtMost(n) => CategoricalQuantifier::AtMost(*n),
+            other => return Err(SerialError::Unsupported(format!("{other:?} as a categorical quantifier"))),
+        })
+    }
+
+    fn boxed(&self, expr: &LogicExpr) -> Result<Box<LogicNode>, SerialError> {
+        self.logic(expr).map(Box::new)
+    }
+
+    fn logic(&self, expr: &LogicExpr) -> Result<LogicNode, SerialError> {
+        Ok(match expr {
+            LogicExpr::Predicate { name, args, world } => LogicNode::Predicate {
+                name: self.name(*name),
+                args: self.terms(args)?,
+                world: self.opt_name(*world),
+            },
+            LogicExpr::Identity { left, right } => LogicNode::Identity { left: self.term(left)?, right: self.term(right)? },
+            LogicExpr::Metaphor { tenor, vehicle } => LogicNode::Metaphor { tenor: self.term(tenor)?, vehicle: self.term(vehicle)? },
+            LogicExpr::Quantifier { kind, variable, body, island_id } => LogicNode::Quantifier {
+                kind: *kind,
+                variable: self.name(*variable),
+                body: self.boxed(body)?,
+                island_id: *island_id,
+            },
+            LogicExpr::Categorical(data) => LogicNode::Categorical {
+                quantifier: self.categorical_quantifier(&data.quantifier)?,
+                subject: self.noun_phrase(&data.subject)?,
+                copula_negative: data.copula_negative,
+                predicate: self.noun_phrase(&data.predicate)?,
+            },
+            LogicExpr::Relation(data) => LogicNode::Relation {
+                subject: self.noun_phrase(&data.subject)?,
+                verb: self.name(data.verb),
+                object: self.noun_phrase(&data.object)?,
+            },
+            LogicExpr::Modal { vector, operand } => LogicNode::Modal {
+                vector: ModalNode {
+                    domain: vector.domain,
+                    force: vector.force,
+                    flavor: vector.flavor,
+                    modal_base: self.opt_name(vector.modal_base),
+                    ordering_source: self.opt_name(vector.ordering_source),
+                },
+                operand: self.boxed(operand)?,
+            },
+            LogicExpr::Temporal { operator, body } => LogicNode::Temporal { operator: *operator, body: self.boxed(body)? },
+            LogicExpr::TemporalBinary { operator, left, right } => LogicNode::TemporalBinary {
+                operator: *operator,
+                left: self.boxed(left)?,
+                right: self.boxed(right)?,
+            },
+            LogicExpr::Aspectual { operator, body } => LogicNode::Aspectual { operator: *operator, body: self.boxed(body)? },
+            LogicExpr::Voice { operator, body } => LogicNode::Voice { operator: *operator, body: self.boxed(body)? },
+            LogicExpr::BinaryOp { left, op, right } => LogicNode::BinaryOp {
+                left: self.boxed(left)?,
+                op: self.connective(op)?,
+                right: self.boxed(right)?,
+            },
+            LogicExpr::UnaryOp { op, operand } => LogicNode::UnaryOp { op: self.connective(op)?, operand: self.boxed(operand)? },
+            LogicExpr::Question { wh_variable, body } => LogicNode::Question {
+                wh_variable: self.name(*wh_variable),
+                body: self.boxed(body)?,
+            },
+            LogicExpr::YesNoQuestion { body } => LogicNode::YesNoQuestion { body: self.boxed(body)? },
+            LogicExpr::Atom(s) => LogicNode::Atom(self.name(*s)),
+            LogicExpr::Lambda { variable, body } => LogicNode::Lambda { variable: self.name(*variable), body: self.boxed(body)? },
+            LogicExpr::App { function, argument } => LogicNode::App {
+                function: self.boxed(function)?,
+                argument: self.boxed(argument)?,
+            },
+            LogicExpr::Intensional { operator, content } => LogicNode::Intensional {
+                operator: self.name(*operator),
+                content: self.boxed(content)?,
+            },
+            LogicExpr::Event { predicate, adverbs } => LogicNode::Event {
+                predicate: self.boxed(predicate)?,
+                adverbs: self.names(adverbs),
+            },
+            LogicExpr::NeoEvent(data) => LogicNode::NeoEvent {
+                event_var: self.name(data.event_var),
+                verb: self.name(data.verb),
+                roles: data.roles.iter().map(|(role, term)| Ok((*role, self.term(term)?))).collect::<Result<_, SerialError>>()?,
+                modifiers: self.names(data.modifiers),
+                suppress_existential: data.suppress_existential,
+                world: self.opt_name(data.world),
+            },
+            LogicExpr::Imperative { action } => LogicNode::Imperative { action: self.boxed(action)? },
+            LogicExpr::Exclamative { degree_var, body } => LogicNode::Exclamative {
+                degree_var: self.name(*degree_var),
+                body: self.boxed(body)?,
+            },
+            LogicExpr::Optative { wish } => LogicNode::Optative { wish: self.boxed(wish)? },
+            LogicExpr::Implicature { assertion, implicature } => LogicNode::Implicature {
+                assertion: self.boxed(assertion)?,
+                implicature: self.boxed(implicature)?,
+            },
+            LogicExpr::SpeechAct { performer, act_type, content } => LogicNode::SpeechAct {
+                performer: self.name(*performer),
+                act_type: self.name(*act_type),
+                content: self.boxed(content)?,
+            },
+            LogicExpr::Counterfactual { antecedent, consequent } => LogicNode::Counterfactual {
+                antecedent: self.boxed(antecedent)?,
+                consequent: self.boxed(consequent)?,
+            },
+            LogicExpr::Causal { effect, cause } => LogicNode::Causal { effect: self.boxed(effect)?, cause: self.boxed(cause)? },
+            LogicExpr::Concessive { main, concession } => LogicNode::Concessive {
+                main: self.boxed(main)?,
+                concession: self.boxed(concession)?,
+            },
+            LogicExpr::Comparative { adjective, subject, object, difference, relation } => LogicNode::Comparative {
+                adjective: self.name(*adjective),
+                subject: self.term(subject)?,
+                object: self.term(object)?,
+                difference: difference.map(|d| self.term(d)).transpose()?,
+                relation: *relation,
+            },
+            LogicExpr::Superlative { adjective, subject, domain } => LogicNode::Superlative {
+                adjective: self.name(*adjective),
+                subject: self.term(subject)?,
+                domain: self.name(*domain),
+            },
+            LogicExpr::Scopal { operator, body } => LogicNode::Scopal { operator: self.name(*operator), body: self.boxed(body)? },
+            LogicExpr::Control { verb, subject, object, infinitive } => LogicNode::Control {
+                verb: self.name(*verb),
+                subject: self.term(subject)?,
+                object: object.map(|o| self.term(o)).transpose()?,
+                infinitive: self.boxed(infinitive)?,
+            },
+            LogicExpr::Presupposition { assertion, presupposition } => LogicNode::Presupposition {
+                assertion: self.boxed(assertion)?,
+                presupposition: self.boxed(presupposition)?,
+            },
+            LogicExpr::Focus { kind, focused, scope } => LogicNode::Focus {
+                kind: *kind,
+                focused: self.term(focused)?,
+                scope: self.boxed(scope)?,
+            },
+            LogicExpr::TemporalAnchor { anchor, body } => LogicNode::TemporalAnchor {
+                anchor: self.name(*anchor),
+                body: self.boxed(body)?,
+            },
+            LogicExpr::Distributive { predicate } => LogicNode::Distributive { predicate: self.boxed(predicate)? },
+            LogicExpr::GroupQuantifier { group_var, count, member_var, restriction, body } => LogicNode::GroupQuantifier {
+                group_var: self.name(*group_var),
+                count: *count,
+                member_var: self.name(*member_var),
+                restriction: self.boxed(restriction)?,
+                body: self.boxed(body)?,
+            },
+        })
+    }
+
+    fn ty(&self, ty: &TypeExpr) -> Result<TypeNode, SerialError> {
+        Ok(match ty {
+            TypeExpr::Primitive(s) => TypeNode::Primitive(self.name(*s)),
+            TypeExpr::Named(s) => TypeNode::Named(self.name(*s)),
+            TypeExpr::Generic { base, params } => TypeNode::Generic { base: self.name(*base), params: self.types(params)? },
+            TypeExpr::Function { inputs, output } => TypeNode::Function {
+                inputs: self.types(inputs)?,
+                output: Box::new(self.ty(output)?),
+            },
+            TypeExpr::Refinement { base, var, predicate } => TypeNode::Refinement {
+                base: Box::new(self.ty(base)?),
+                var: self.name(*var),
+                predicate: self.boxed(predicate)?,
+            },
+            TypeExpr::Persistent { inner } => TypeNode::Persistent { inner: Box::new(self.ty(inner)?) },
+            TypeExpr::Mutable { inner } => TypeNode::Mutable { inner: Box::new(self.ty(inner)?) },
+        })
+    }
+
+    fn types(&self, types: &[TypeExpr]) -> Result<Vec<TypeNode>, SerialError> {
+        types.iter().map(|ty| self.ty(ty)).collect()
+    }
+
+    fn typed_params(&self, params: &[(Symbol, &TypeExpr)]) -> Result<Vec<(String, TypeNode)>, SerialError> {
+        params.iter().map(|(name, ty)| Ok((self.name(*name), self.ty(ty)?))).collect()
+    }
+
+    fn literal(&self, literal: &Literal) -> LiteralNode {
+        match literal {
+            Literal::Number(n) => LiteralNode::Number(*n),
+            Literal::Float(f) => LiteralNode::Float(*f),
+            Literal::Text(s) => LiteralNode::Text(self.name(*s)),
+            Literal::Boolean(b) => LiteralNode::Boolean(*b),
+            Literal::Nothing => LiteralNode::Nothing,
+            Literal::Char(c) => LiteralNode::Char(*c),
+            Literal::Duration(d) => LiteralNode::Duration(*d),
+            Literal::Date(d) => LiteralNode::Date(*d),
+            Literal::Moment(m) => LiteralNode::Moment(*m),
+            Literal::Span { months, days } => LiteralNode::Span { months: *months, days: *days },
+            Literal::Time(t) => LiteralNode::Time(*t),
+        }
+    }
+
+    fn bexpr(&self, expr: &Expr) -> Result<Box<ExprNode>, SerialError> {
+        self.expr(expr).map(Box::new)
+    }
+
+    fn exprs(&self, exprs: &[&Expr]) -> Result<Vec<ExprNode>, SerialError> {
+        exprs.iter().map(|expr| self.expr(expr)).collect()
+    }
+
+    fn opt_expr(&self, expr: Option<&Expr>) -> Result<Option<ExprNode>, SerialError> {
+        expr.map(|expr| self.expr(expr)).transpose()
+    }
+
+    fn named_exprs(&self, fields: &[(Symbol, &Expr)]) -> Result<Vec<(String, ExprNode)>, SerialError> {
+        fields.iter().map(|(name, expr)| Ok((self.name(*name), self.expr(expr)?))).collect()
+    }
+
+    fn expr(&self, expr: &Expr) -> Result<ExprNode, SerialError> {
+        Ok(match expr {
+            Expr::Literal(literal) => ExprNode::Literal(self.literal(literal)),
+            Expr::Identifier(s) => ExprNode::Identifier(self.name(*s)),
+            Expr::BinaryOp { op, left, right } => ExprNode::BinaryOp { op: *op, left: self.bexpr(left)?, right: self.bexpr(right)? },
+            Expr::Not { operand } => ExprNode::Not { operand: self.bexpr(operand)? },
+            Expr::Call { function, args } => ExprNode::Call { function: self.name(*function), args: self.exprs(args)? },
+            Expr::Index { collection, index } => ExprNode::Index { collection: self.bexpr(collection)?, index: self.bexpr(index)? },
+            Expr::Slice { collection, start, end } => ExprNode::Slice {
+                collection: self.bexpr(collection)?,
+                start: self.bexpr(start)?,
+                end: self.bexpr(end)?,
+            },
+            Expr::Copy { expr } => ExprNode::Copy { expr: self.bexpr(expr)? },
+            Expr::Give { value } => ExprNode::Give { value: self.bexpr(value)? },
+            Expr::Length { collection } => ExprNode::Length { collection: self.bexpr(collection)? },
+            Expr::Contains { collection, value } => ExprNode::Contains { collection: self.bexpr(collection)?, value: self.bexpr(value)? },
+            Expr::Union { left, right } => ExprNode::Union { left: self.bexpr(left)?, right: self.bexpr(right)? },
+            Expr::Intersection { left, right } => ExprNode::Intersection { left: self.bexpr(left)?, right: self.bexpr(right)? },
+            Expr::ManifestOf { zone } => ExprNode::ManifestOf { zone: self.bexpr(zone)? },
+            Expr::ChunkAt { index, zone } => ExprNode::ChunkAt { index: self.bexpr(index)?, zone: self.bexpr(zone)? },
+            Expr::List(items) => ExprNode::List(self.exprs(items)?),
+            Expr::Tuple(items) => ExprNode::Tuple(self.exprs(items)?),
+            Expr::Range { start, end } => ExprNode::Range { start: self.bexpr(start)?, end: self.bexpr(end)? },
+            Expr::FieldAccess { object, field } => ExprNode::FieldAccess { object: self.bexpr(object)?, field: self.name(*field) },
+            Expr::New { type_name, type_args, init_fields } => ExprNode::New {
+                type_name: self.name(*type_name),
+                type_args: self.types(type_args)?,
+                init_fields: self.named_exprs(init_fields)?,
+            },
+            Expr::NewVariant { enum_name, variant, fields } => ExprNode::NewVariant {
+                enum_name: self.name(*enum_name),
+                variant: self.name(*variant),
+                fields: self.named_exprs(fields)?,
+            },
+            Expr::Escape { language, code } => ExprNode::Escape { language: self.name(*language), code: self.name(*code) },
+            Expr::OptionSome { value } => ExprNode::OptionSome { value: self.bexpr(value)? },
+            Expr::OptionNone => ExprNode::OptionNone,
+            Expr::WithCapacity { value, capacity } => ExprNode::WithCapacity { value: self.bexpr(value)?, capacity: self.bexpr(capacity)? },
+            Expr::Closure { params, body, return_type } => ExprNode::Closure {
+                params: self.typed_params(params)?,
+                body: match body {
+                    ClosureBody::Expression(expr) => ClosureBodyNode::Expression(self.bexpr(expr)?),
+                    ClosureBody::Block(block) => ClosureBodyNode::Block(self.block(block)?),
+                },
+                return_type: return_type.map(|ty| self.ty(ty)).transpose()?,
+            },
+            Expr::CallExpr { callee, args } => ExprNode::CallExpr { callee: self.bexpr(callee)?, args: self.exprs(args)? },
+            Expr::InterpolatedString(parts) => ExprNode::InterpolatedString(
+                parts
+                    .iter()
+                    .map(|part| {
+                        Ok(match part {
+                            StringPart::Literal(s) => StringPartNode::Literal(self.name(*s)),
+                            StringPart::Expr { value, format_spec, debug } => StringPartNode::Expr {
+                                value: self.expr(value)?,
+                                format_spec: self.opt_name(*format_spec),
+                                debug: *debug,
+                            },
+                        })
+                    })
+                    .collect::<Result<_, SerialError>>()?,
+            ),
+            Expr::Comprehension { kind, element, value, pattern, iterable, filter } => ExprNode::Comprehension {
+                kind: *kind,
+                element: self.bexpr(element)?,
+                value: value.map(|value| self.bexpr(value)).transpose()?,
+                pattern: match pattern {
+                    Pattern::Identifier(s) => PatternNode::Identifier(self.name(*s)),
+                    Pattern::Tuple(names) => PatternNode::Tuple(self.names(names)),
+                },
+                iterable: self.bexpr(iterable)?,
+                filter: filter.map(|filter| self.bexpr(filter)).transpose()?,
+            },
+        })
+    }
+
+    fn block(&self, stmts: &[Stmt]) -> Result<Vec<StmtNode>, SerialError> {
+        stmts.iter().map(|stmt| self.stmt(stmt)).collect()
+    }
+
+    fn secure(&self, pad: &Option<SecurePad>) -> Result<Option<SecurePadNode>, SerialError> {
+        pad.map(|pad| Ok(SecurePadNode { pad: self.expr(pad.pad)?, role: pad.role })).transpose()
+    }
+
+    fn stmt(&self, stmt: &Stmt) -> Result<StmtNode, SerialError> {
+        Ok(match stmt {
+            Stmt::Let { var, ty, value, mutable } => StmtNode::Let {
+                var: self.name(*var),
+                ty: ty.map(|ty| self.ty(ty)).transpose()?,
+                value: self.expr(value)?,
+                mutable: *mutable,
+            },
+            Stmt::Set { target, value } => StmtNode::Set { target: self.name(*target), value: self.expr(value)? },
+            Stmt::Call { function, args } => StmtNode::Call { function: self.name(*function), args: self.exprs(args)? },
+            Stmt::If { cond, then_block, else_block } => StmtNode::If {
+                cond: self.expr(cond)?,
+                then_block: self.block(then_block)?,
+                else_block: else_block.map(|block| self.block(block)).transpose()?,
+            },
+            Stmt::While { cond, body, decreasing } => StmtNode::While {
+                cond: self.expr(cond)?,
+                body: self.block(body)?,
+                decreasing: self.opt_expr(*decreasing)?,
+            },
+            Stmt::Repeat { pattern, iterable, body } => StmtNode::Repeat {
+                pattern: match pattern {
+                    Pattern::Identifier(s) => PatternNode::Identifier(self.name(*s)),
+                    Pattern::Tuple(names) => PatternNode::Tuple(self.names(names)),
+                },
+                iterable: self.expr(iterable)?,
+                body: self.block(body)?,
+            },
+            Stmt::Return { value } => StmtNode::Return { value: self.opt_expr(*value)? },
+            Stmt::Break => StmtNode::Break,
+            Stmt::Assert { proposition } => StmtNode::Assert { proposition: self.logic(proposition)? },
+            Stmt::Trust { proposition, justification } => StmtNode::Trust {
+                proposition: self.logic(proposition)?,
+                justification: self.name(*justification),
+            },
+            Stmt::RuntimeAssert { condition, hard } => StmtNode::RuntimeAssert { condition: self.expr(condition)?, hard: *hard },
+            Stmt::Give { object, recipient } => StmtNode::Give { object: self.expr(object)?, recipient: self.expr(recipient)? },
+            Stmt::Show { object, recipient } => StmtNode::Show { object: self.expr(object)?, recipient: self.expr(recipient)? },
+            Stmt::Log { level, message, fields } => StmtNode::Log {
+                level: *level,
+                message: self.expr(message)?,
+                fields: self.named_exprs(fields)?,
+            },
+            Stmt::RunCommand { command, timeout, output, errors, exit_code } => StmtNode::RunCommand {
+                command: self.expr(command)?,
+                timeout: self.opt_expr(*timeout)?,
+                output: self.opt_name(*output),
+                errors: self.opt_name(*errors),
+                exit_code: self.opt_name(*exit_code),
+            },
+            Stmt::Sip { file, sources, progress } => StmtNode::Sip {
+                file: self.expr(file)?,
+                sources: self.expr(sources)?,
+                progress: self.opt_name(*progress),
+            },
+            Stmt::SetField { object, field, value } => StmtNode::SetField {
+                object: self.expr(object)?,
+                field: self.name(*field),
+                value: self.expr(value)?,
+            },
+            Stmt::StructDef { name, fields, is_portable } => StmtNode::StructDef {
+                name: self.name(*name),
+                fields: fields.iter().map(|(field, ty, public)| (self.name(*field), self.name(*ty), *public)).collect(),
+                is_portable: *is_portable,
+            },
+            Stmt::FunctionDef {
+                name,
+                generics,
+                params,
+                body,
+                return_type,
+                is_native,
+                native_path,
+                is_exported,
+                export_target,
+                opt_flags,
+            } => StmtNode::FunctionDef {
+                name: self.name(*name),
+                generics: self.names(generics),
+                params: self.typed_params(params)?,
+                body: self.block(body)?,
+                return_type: return_type.map(|ty| self.ty(ty)).transpose()?,
+                is_native: *is_native,
+                native_path: self.opt_name(*native_path),
+                is_exported: *is_exported,
+                export_target: self.opt_name(*export_target),
+                opt_flags: *opt_flags,
+            },
+            Stmt::Inspect { target, arms, has_otherwise } => StmtNode::Inspect {
+                target: self.expr(target)?,
+                arms: arms
+                    .iter()
+                    .map(|arm| {
+                        Ok(MatchArmNode {
+                            enum_name: self.opt_name(arm.enum_name),
+                            variant: self.opt_name(arm.variant),
+                            bindings: arm.bindings.iter().map(|(field, binding)| (self.name(*field), self.name(*binding))).collect(),
+                            body: self.block(arm.body)?,
+                        })
+                    })
+                    .collect::<Result<_, SerialError>>()?,
+                has_otherwise: *has_otherwise,
+            },
+            Stmt::Push { value, collection } => StmtNode::Push { value: self.expr(value)?, collection: self.expr(collection)? },
+            Stmt::Pop { collection, into } => StmtNode::Pop { collection: self.expr(collection)?, into: self.opt_name(*into) },
+            Stmt::Add { value, collection } => StmtNode::Add { value: self.expr(value)?, collection: self.expr(collection)? },
+            Stmt::Remove { value, collection } => StmtNode::Remove { value: self.expr(value)?, collection: self.expr(collection)? },
+            Stmt::SetIndex { collection, index, value } => StmtNode::SetIndex {
+                collection: self.expr(collection)?,
+                index: self.expr(index)?,
+                value: self.expr(value)?,
+            },
+            Stmt::Splice { body } => StmtNode::Splice { body: self.block(body)? },
+            Stmt::Zone { name, capacity, source_file, body } => StmtNode::Zone {
+                name: self.name(*name),
+                capacity: *capacity,
+                source_file: source_file.map(|source| match source {
+                    ZoneSource::Literal(s) => ZoneSourceNode::Literal(self.name(s)),
+                    ZoneSource::Variable(s) => ZoneSourceNode::Variable(self.name(s)),
+                }),
+                body: self.block(body)?,
+            },
+            Stmt::Concurrent { tasks } => StmtNode::Concurrent { tasks: self.block(tasks)? },
+            Stmt::Parallel { tasks } => StmtNode::Parallel { tasks: self.block(tasks)? },
+            Stmt::ReadFrom { var, source } => StmtNode::ReadFrom {
+                var: self.name(*var),
+                source: match source {
+                    ReadSource::Console => ReadSourceNode::Console,
+                    ReadSource::File(path) => ReadSourceNode::File(self.bexpr(path)?),
+                    ReadSource::Settings { type_name, path } => ReadSourceNode::Settings {
+                        type_name: self.name(*type_name),
+                        path: path.map(|path| self.bexpr(path)).transpose()?,
+                    },
+                    ReadSource::Arguments { type_name } => ReadSourceNode::Arguments { type_name: self.name(*type_name) },
+                },
+            },
+            Stmt::WriteFile { content, path } => StmtNode::WriteFile { content: self.expr(content)?, path: self.expr(path)? },
+            Stmt::Spawn { agent_type, name } => StmtNode::Spawn { agent_type: self.name(*agent_type), name: self.name(*name) },
+            Stmt::SendMessage {
+                message,
+                destination,
+                compression,
+                cached,
+                unchecked,
+                layout,
+                shared,
+                computed,
+                indexed,
+                deduped,
+            } => StmtNode::SendMessage {
+                message: self.expr(message)?,
+                destination: self.expr(destination)?,
+                compression: *compression,
+                cached: *cached,
+                unchecked: *unchecked,
+                layout: *layout,
+                shared: *shared,
+                computed: *computed,
+                indexed: *indexed,
+                deduped: *deduped,
+            },
+            Stmt::AwaitMessage { source, into, view, stream } => StmtNode::AwaitMessage {
+                source: self.expr(source)?,
+                into: self.name(*into),
+                view: *view,
+                stream: *stream,
+            },
+            Stmt::StreamMessage { values, destination } => StmtNode::StreamMessage {
+                values: self.expr(values)?,
+                destination: self.expr(destination)?,
+            },
+            Stmt::MergeCrdt { source, target } => StmtNode::MergeCrdt { source: self.expr(source)?, target: self.expr(target)? },
+            Stmt::IncreaseCrdt { object, field, amount } => StmtNode::IncreaseCrdt {
+                object: self.expr(object)?,
+                field: self.name(*field),
+                amount: self.expr(amount)?,
+            },
+            Stmt::DecreaseCrdt { object, field, amount } => StmtNode::DecreaseCrdt {
+                object: self.expr(object)?,
+                field: self.name(*field),
+                amount: self.expr(amount)?,
+            },
+            Stmt::AppendToSequence { sequence, value } => StmtNode::AppendToSequence {
+                sequence: self.expr(sequence)?,
+                value: self.expr(value)?,
+            },
+            Stmt::ResolveConflict { object, field, value } => StmtNode::ResolveConflict {
+                object: self.expr(object)?,
+                field: self.name(*field),
+                value: self.expr(value)?,
+            },
+            Stmt::Check { subject, predicate, is_capability, object, source_text, span } => StmtNode::Check {
+                subject: self.name(*subject),
+                predicate: self.name(*predicate),
+                is_capability: *is_capability,
+                object: self.opt_name(*object),
+                source_text: source_text.clone(),
+                span: *span,
+            },
+            Stmt::Listen { address, secure } => StmtNode::Listen { address: self.expr(address)?, secure: self.secure(secure)? },
+            Stmt::ConnectTo { address, secure } => StmtNode::ConnectTo { address: self.expr(address)?, secure: self.secure(secure)? },
+            Stmt::JoinSwarm { using } => StmtNode::JoinSwarm {
+                using: using
+                    .iter()
+                    .map(|discovery| {
+                        Ok(match discovery {
+                            SwarmDiscovery::Local => SwarmNode::Local,
+                            SwarmDiscovery::Bootstrap(addrs) => SwarmNode::Bootstrap(self.opt_expr(*addrs)?),
+                            SwarmDiscovery::Relays(addrs) => SwarmNode::Relays(self.opt_expr(*addrs)?),
+                        })
+                    })
+                    .collect::<Result<_, SerialError>>()?,
+            },
+            Stmt::LetPeerAgent { var, address } => StmtNode::LetPeerAgent { var: self.name(*var), address: self.expr(address)? },
+            Stmt::Sleep { milliseconds } => StmtNode::Sleep { milliseconds: self.expr(milliseconds)? },
+            Stmt::Sync { var, topic } => StmtNode::Sync { var: self.name(*var), topic: self.expr(topic)? },
+            Stmt::Mount { var, path } => StmtNode::Mount { var: self.name(*var), path: self.expr(path)? },
+            Stmt::LaunchTask { function, args } => StmtNode::LaunchTask { function: self.name(*function), args: self.exprs(args)? },
+            Stmt::LaunchTaskWithHandle { handle, function, args } => StmtNode::LaunchTaskWithHandle {
+                handle: self.name(*handle),
+                function: self.name(*function),
+                args: self.exprs(args)?,
+            },
+            Stmt::CreatePipe { var, element_type, capacity } => StmtNode::CreatePipe {
+                var: self.name(*var),
+                element_type: self.name(*element_type),
+                capacity: *capacity,
+            },
+            Stmt::SendPipe { value, pipe } => StmtNode::SendPipe { value: self.expr(value)?, pipe: self.expr(pipe)? },
+            Stmt::ReceivePipe { var, pipe } => StmtNode::ReceivePipe { var: self.name(*var), pipe: self.expr(pipe)? },
+            Stmt::TrySendPipe { value, pipe, result } => StmtNode::TrySendPipe {
+                value: self.expr(value)?,
+                pipe: self.expr(pipe)?,
+                result: self.opt_name(*result),
+            },
+            Stmt::TryReceivePipe { var, pipe } => StmtNode::TryReceivePipe { var: self.name(*var), pipe: self.expr(pipe)? },
+            Stmt::StopTask { handle } => StmtNode::StopTask { handle: self.expr(handle)? },
+            Stmt::Select { branches } => StmtNode::Select {
+                branches: branches
+                    .iter()
+                    .map(|branch| {
+                        Ok(match branch {
+                            SelectBranch::Receive { var, pipe, body } => SelectBranchNode::Receive {
+                                var: self.name(*var),
+                                pipe: self.expr(pipe)?,
+                                body: self.block(body)?,
+                            },
+                            SelectBranch::Timeout { milliseconds, body } => SelectBranchNode::Timeout {
+                                milliseconds: self.expr(milliseconds)?,
+                                body: self.block(body)?,
+                            },
+                        })
+                    })
+                    .collect::<Result<_, SerialError>>()?,
+            },
+            Stmt::Theorem(theorem) => StmtNode::Theorem(TheoremNode {
+                name: theorem.name.clone(),
+                premises: theorem.premises.iter().map(|premise| self.logic(premise)).collect::<Result<_, _>>()?,
+                premise_names: theorem.premise_names.clone(),
+                goal: self.logic(theorem.goal)?,
+                strategy: theorem.strategy.clone(),
+            }),
+            Stmt::Definition(definition) => StmtNode::Definition(DefinitionNode {
+                name: definition.name.clone(),
+                params: self.names(&definition.params),
+                definiendum: self.logic(definition.definiendum)?,
+                definiens: self.logic(definition.definiens)?,
+            }),
+            Stmt::Axiom(axiom) => StmtNode::Axiom(axiom.clone()),
+            Stmt::Theory(theory) => StmtNode::Theory(theory.clone()),
+            Stmt::Escape { language, code, span } => StmtNode::Escape {
+                language: self.name(*language),
+                code: self.name(*code),
+                span: *span,
+            },
+            Stmt::Require { crate_name, version, features, span } => StmtNode::Require {
+                crate_name: self.name(*crate_name),
+                version: self.name(*version),
+                features: self.names(features),
+                span: *span,
+            },
+        })
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// Decoding
+// ═══════════════════════════════════════════════════════════════════
+
+struct Decoder<'a, 'i> {
+    ctx: AstContext<'a>,
+    interner: &'i mut Interner,
+}
+
+impl<'a> Decoder<'a, '_> {
+    fn sym(&mut self, name: &str) -> Symbol {
+        self.interner.intern(name)
+    }
+
+    fn opt_sym(&mut self, name: &Option<String>) -> Option<Symbol> {
+        name.as_deref().map(|name| self.sym(name))
+    }
+
+    fn syms(&mut self, names: &[String]) -> Vec<Symbol> {
+        names.iter().map(|name| self.sym(name)).collect()
+    }
+
+    fn term(&mut self, node: &TermNode) -> Term<'a> {
+        match node {
+            TermNode::Constant(s) => Term::Constant(self.sym(s)),
+            TermNode::Variable(s) => Term::Variable(self.sym(s)),
+            TermNode::Function(name, args) => {
+                let name = self.sym(name);
+                Term::Function(name, self.terms(args))
+            }
+            TermNode::Group(members) => Term::Group(self.terms(members)),
+            TermNode::Possessed { possessor, possessed } => {
+                let possessor = self.term(possessor);
+                Term::Possessed { possessor: self.ctx.alloc_term(possessor), possessed: self.sym(possessed) }
+            }
+            TermNode::Sigma(s) => Term::Sigma(self.sym(s)),
+            TermNode::Intension(s) => Term::Intension(self.sym(s)),
+            TermNode::Kind(s) => Term::Kind(self.sym(s)),
+            TermNode::Proposition(expr) => Term::Proposition(self.logic(expr)),
+            TermNode::Value { kind, unit, dimension } => Term::Value {
+                kind: match kind {
+                    NumberNode::Real(r) => NumberKind::Real(*r),
+                    NumberNode::Integer(i) => NumberKind::Integer(*i),
+                    NumberNode::Symbolic(s) => NumberKind::Symbolic(self.sym(s)),
+                },
+                unit: self.opt_sym(unit),
+                dimension: *dimension,
+            },
+        }
+    }
+
+    fn terms(&mut self, nodes: &[TermNode]) -> &'a [Term<'a>] {
+        let terms: Vec<Term<'a>> = nodes.iter().map(|node| self.term(node)).collect();
+        self.ctx.alloc_terms(terms)
+    }
+
+    fn term_ref(&mut self, node: &TermNode) -> &'a Term<'a> {
+        let term = self.term(node);
+        self.ctx.alloc_term(term)
+    }
+
+    fn noun_phrase(&mut self, node: &NounPhraseNode) -> NounPhrase<'a> {
+        let adjectives = self.syms(&node.adjectives);
+        let possessor = node.possessor.as_ref().map(|p| {
+            let np = self.noun_phrase(p);
+            self.ctx.alloc_np(np)
+        });
+        let pps: Vec<&'a LogicExpr<'a>> = node.pps.iter().map(|pp| self.logic(pp)).collect();
+        NounPhrase {
+            definiteness: node.definiteness.map(|d| match d {
+                DefinitenessNode::Definite => Definiteness::Definite,
+                DefinitenessNode::Indefinite => Definiteness::Indefinite,
+                DefinitenessNode::Proximal => Definiteness::Proximal,
+                DefinitenessNode::Distal => Definiteness::Distal,
+            }),
+            adjectives: self.ctx.alloc_syms(adjectives),
+            noun: self.sym(&node.noun),
+            possessor,
+            pps: self.ctx.alloc_pps(pps),
+            superlative: self.opt_sym(&node.superlative),
+        }
+    }
+
+    fn logic(&mut self, node: &LogicNode) -> &'a LogicExpr<'a> {
+        let expr = match node {
+            LogicNode::Predicate { name, args, world } => LogicExpr::Predicate {
+                name: self.sym(name),
+                args: self.terms(args),
+                world: self.opt_sym(world),
+            },
+            LogicNode::Identity { left, right } => LogicExpr::Identity { left: self.term_ref(left), right: self.term_ref(right) },
+            LogicNode::Metaphor { tenor, vehicle } => LogicExpr::Metaphor { tenor: self.term_ref(tenor), vehicle: self.term_ref(vehicle) },
+            LogicNode::Quantifier { kind, variable, body, island_id } => LogicExpr::Quantifier {
+                kind: *kind,
+                variable: self.sym(variable),
+                body: self.logic(body),
+                island_id: *island_id,
+            },
+            LogicNode::Categorical { quantifier, subject, copula_negative, predicate } => {
+                LogicExpr::Categorical(Box::new(CategoricalData {
+                    quantifier: match *quantifier {
+                        CategoricalQuantifier::All => TokenType::All,
+                        CategoricalQuantifier::No => TokenType::No,
+                        CategoricalQuantifier::Some => TokenType::Some,
+                        CategoricalQuantifier::Any => TokenType::Any,
+                        CategoricalQuantifier::Both => TokenType::Both,
+                        CategoricalQuantifier::Most => TokenType::Most,
+                        CategoricalQuantifier::Few => TokenType::Few,
+                        CategoricalQuantifier::Many => TokenType::Many,
+                        CategoricalQuantifier::Cardinal(n) => TokenType::Cardinal(n),
+                        CategoricalQuantifier::AtLeast(n) => TokenType::AtLeast(n),
+                        CategoricalQuantifier::AtMost(n) => TokenType::AtMost(n),
+                    },
+                    subject: self.noun_phrase(subject),
+                    copula_negative: *copula_negative,
+                    predicate: self.noun_phrase(predicate),
+                }))
+            }
+            LogicNode::Relation { subject, verb, object } => LogicExpr::Relation(Box::new(RelationData {
+                subject: self.noun_phrase(subject),
+                verb: self.sym(verb),
+                object: self.noun_phrase(object),
+            })),
+            LogicNode::Modal { vector, operand } => LogicExpr::Modal {
+                vector: ModalVector {
+                    domain: vector.domain,
+                    force: vector.force,
+                    flavor: vector.flavor,
+                    modal_base: self.opt_sym(&vector.modal_base),
+                    ordering_source: self.opt_sym(&vector.ordering_source),
+                },
+                operand: self.logic(operand),
+            },
+            LogicNode::Temporal { operator, body } => LogicExpr::Temporal { operator: *operator, body: self.logic(body) },
+            LogicNode::TemporalBinary { operator, left, right } => LogicExpr::TemporalBinary {
+                operator: *operator,
+                left: self.logic(left),
+                right: self.logic(right),
+            },
+            LogicNode::Aspectual { operator, body } => LogicExpr::Aspectual { operator: *operator, body: self.logic(body) },
+            LogicNode::Voice { operator, body } => LogicExpr::Voice { operator: *operator, body: self.logic(body) },
+            LogicNode::BinaryOp { left, op, right } => LogicExpr::BinaryOp {
+                left: self.logic(left),
+                op: connective_token(*op),
+                right: self.logic(right),
+            },
+            LogicNode::UnaryOp { op, operand } => LogicExpr::UnaryOp { op: connective_token(*op), operand: self.logic(operand) },
+            LogicNode::Question { wh_variable, body } => LogicExpr::Question { wh_variable: self.sym(wh_variable), body: self.logic(body) },
+            LogicNode::YesNoQuestion { body } => LogicExpr::YesNoQuestion { body: self.logic(body) },
+            LogicNode::Atom(s) => LogicExpr::Atom(self.sym(s)),
+            LogicNode::Lambda { variable, body } => LogicExpr::Lambda { variable: self.sym(variable), body: self.logic(body) },
+            LogicNode::App { function, argument } => LogicExpr::App { function: self.logic(function), argument: self.logic(argument) },
+            LogicNode::Intensional { operator, content } => LogicExpr::Intensional {
+                operator: self.sym(operator),
+                content: self.logic(content),
+            },
+            LogicNode::Event { predicate, adverbs } => {
+                let adverbs = self.syms(adverbs);
+                LogicExpr::Event { predicate: self.logic(predicate), adverbs: self.ctx.alloc_syms(adverbs) }
+            }
+            LogicNode::NeoEvent { event_var, verb, roles, modifiers, suppress_existential, world } => {
+                let roles: Vec<(ThematicRole, Term<'a>)> = roles.iter().map(|(role, term)| (*role, self.term(term))).collect();
+                let modifiers = self.syms(modifiers);
+                LogicExpr::NeoEvent(Box::new(NeoEventData {
+                    event_var: self.sym(event_var),
+                    verb: self.sym(verb),
+                    roles: self.ctx.alloc_roles(roles),
+                    modifiers: self.ctx.alloc_syms(modifiers),
+                    suppress_existential: *suppress_existential,
+                    world: self.opt_sym(world),
+                }))
+            }
+            LogicNode::Imperative { action } => LogicExpr::Imperative { action: self.logic(action) },
+            LogicNode::Exclamative { degree_var, body } => LogicExpr::Exclamative { degree_var: self.sym(degree_var), body: self.logic(body) },
+            LogicNode::Optative { wish } => LogicExpr::Optative { wish: self.logic(wish) },
+            LogicNode::Implicature { assertion, implicature } => LogicExpr::Implicature {
+                assertion: self.logic(assertion),
+                implicature: self.logic(implicature),
+            },
+            LogicNode::SpeechAct { performer, act_type, content } => LogicExpr::SpeechAct {
+                performer: self.sym(performer),
+                act_type: self.sym(act_type),
+                content: self.logic(content),
+            },
+            LogicNode::Counterfactual { antecedent, consequent } => LogicExpr::Counterfactual {
+                antecedent: self.logic(antecedent),
+                consequent: self.logic(consequent),
+            },
+            LogicNode::Causal { effect, cause } => LogicExpr::Causal { effect: self.logic(effect), cause: self.logic(cause) },
+            LogicNode::Concessive { main, concession } => LogicExpr::Concessive { main: self.logic(main), concession: self.logic(concession) },
+            LogicNode::Comparative { adjective, subject, object, difference, relation } => LogicExpr::Comparative {
+                adjective: self.sym(adjective),
+                subject: self.term_ref(subject),
+                object: self.term_ref(object),
+                difference: difference.as_ref().map(|d| self.term_ref(d)),
+                relation: *relation,
+            },
+            LogicNode::Superlative { adjective, subject, domain } => LogicExpr::Superlative {
+                adjective: self.sym(adjective),
+                subject: self.term_ref(subject),
+                domain: self.sym(domain),
+            },
+            LogicNode::Scopal { operator, body } => LogicExpr::Scopal { operator: self.sym(operator), body: self.logic(body) },
+            LogicNode::Control { verb, subject, object, infinitive } => LogicExpr::Control {
+                verb: self.sym(verb),
+                subject: self.term_ref(subject),
+                object: object.as_ref().map(|o| self.term_ref(o)),
+                infinitive: self.logic(infinitive),
+            },
+            LogicNode::Presupposition { assertion, presupposition } => LogicExpr::Presupposition {
+                assertion: self.logic(assertion),
+                presupposition: self.logic(presupposition),
+            },
+            LogicNode::Focus { kind, focused, scope } => LogicExpr::Focus {
+                kind: *kind,
+                focused: self.term_ref(focused),
+                scope: self.logic(scope),
+            },
+            LogicNode::TemporalAnchor { anchor, body } => LogicExpr::TemporalAnchor { anchor: self.sym(anchor), body: self.logic(body) },
+            LogicNode::Distributive { predicate } => LogicExpr::Distributive { predicate: self.logic(predicate) },
+            LogicNode::GroupQuantifier { group_var, count, member_var, restriction, body } => LogicExpr::GroupQuantifier {
+                group_var: self.sym(group_var),
+                count: *count,
+                member_var: self.sym(member_var),
+                restriction: self.logic(restriction),
+                body: self.logic(body),
+            },
+        };
+        self.ctx.alloc_expr(expr)
+    }
+
+    fn ty(&mut self, node: &TypeNode) -> TypeExpr<'a> {
+        match node {
+            TypeNode::Primitive(s) => TypeExpr::Primitive(self.sym(s)),
+            TypeNode::Named(s) => TypeExpr::Named(self.sym(s)),
+            TypeNode::Generic { base, params } => TypeExpr::Generic { base: self.sym(base), params: self.types(params) },
+            TypeNode::Function { inputs, output } => TypeExpr::Function { inputs: self.types(inputs), output: self.ty_ref(output) },
+            TypeNode::Refinement { base, var, predicate } => TypeExpr::Refinement {
+                base: self.ty_ref(base),
+                var: self.sym(var),
+                predicate: self.logic(predicate),
+            },
+            TypeNode::Persistent { inner } => TypeExpr::Persistent { inner: self.ty_ref(inner) },
+            TypeNode::Mutable { inner } => TypeExpr::Mutable { inner: self.ty_ref(inner) },
+        }
+    }
+
+    fn ty_ref(&mut self, node: &TypeNode) -> &'a TypeExpr<'a> {
+        let ty = self.ty(node);
+        self.ctx.alloc_type_expr(ty)
+    }
+
+    fn types(&mut self, nodes: &[TypeNode]) -> &'a [TypeExpr<'a>] {
+        let types: Vec<TypeExpr<'a>> = nodes.iter().map(|node| self.ty(node)).collect();
+        self.ctx.alloc_type_exprs(types)
+    }
+
+    fn typed_params(&mut self, params: &[(String, TypeNode)]) -> Vec<(Symbol, &'a TypeExpr<'a>)> {
+        params.iter().map(|(name, ty)| (self.sym(name), self.ty_ref(ty))).collect()
+    }
+
+    fn expr(&mut self, node: &ExprNode) -> &'a Expr<'a> {
+        let expr = match node {
+            ExprNode::Literal(literal) => Expr::Literal(match literal {
+                LiteralNode::Number(n) => Literal::Number(*n),
+                LiteralNode::Float(f) => Literal::Float(*f),
+                LiteralNode::Text(s) => Literal::Text(self.sym(s)),
+                LiteralNode::Boolean(b) => Literal::Boolean(*b),
+                LiteralNode::Nothing => Literal::Nothing,
+                LiteralNode::Char(c) => Literal::Char(*c),
+                LiteralNode::Duration(d) => Literal::Duration(*d),
+                LiteralNode::Date(d) => Literal::Date(*d),
+                LiteralNode::Moment(m) => Literal::Moment(*m),
+                LiteralNode::Span { months, days } => Literal::Span { months: *months, days: *days },
+                LiteralNode::Time(t) => Literal::Time(*t),
+            }),
+            ExprNode::Identifier(s) => Expr::Identifier(self.sym(s)),
+            ExprNode::BinaryOp { op, left, right } => Expr::BinaryOp { op: *op, left: self.expr(left), right: self.expr(right) },
+            ExprNode::Not { operand } => Expr::Not { operand: self.expr(operand) },
+            ExprNode::Call { function, args } => Expr::Call { function: self.sym(function), args: self.exprs(args) },
+            ExprNode::Index { collection, index } => Expr::Index { collection: self.expr(collection), index: self.expr(index) },
+            ExprNode::Slice { collection, start, end } => Expr::Slice {
+                collection: self.expr(collection),
+                start: self.expr(start),
+                end: self.expr(end),
+            },
+            ExprNode::Copy { expr } => Expr::Copy { expr: self.expr(expr) },
+            ExprNode::Give { value } => Expr::Give { value: self.expr(value) },
+            ExprNode::Length { collection } => Expr::Length { collection: self.expr(collection) },
+            ExprNode::Contains { collection, value } => Expr::Contains { collection: self.expr(collection), value: self.expr(value) },
+            ExprNode::Union { left, right } => Expr::Union { left: self.expr(left), right: self.expr(right) },
+            ExprNode::Intersection { left, right } => Expr::Intersection { left: self.expr(left), right: self.expr(right) },
+            ExprNode::ManifestOf { zone } => Expr::ManifestOf { zone: self.expr(zone) },
+            ExprNode::ChunkAt { index, zone } => Expr::ChunkAt { index: self.expr(index), zone: self.expr(zone) },
+            ExprNode::List(items) => Expr::List(self.exprs(items)),
+            ExprNode::Tuple(items) => Expr::Tuple(self.exprs(items)),
+            ExprNode::Range { start, end } => Expr::Range { start: self.expr(start), end: self.expr(end) },
+            ExprNode::FieldAccess { object, field } => Expr::FieldAccess { object: self.expr(object), field: self.sym(field) },
+            ExprNode::New { type_name, type_args, init_fields } => Expr::New {
+                type_name: self.sym(type_name),
+                type_args: type_args.iter().map(|ty| self.ty(ty)).collect(),
+                init_fields: self.named_exprs(init_fields),
+            },
+            ExprNode::NewVariant { enum_name, variant, fields } => Expr::NewVariant {
+                enum_name: self.sym(enum_name),
+                variant: self.sym(variant),
+                fields: self.named_exprs(fields),
+            },
+            ExprNode::Escape { language, code } => Expr::Escape { language: self.sym(language), code: self.sym(code) },
+            ExprNode::OptionSome { value } => Expr::OptionSome { value: self.expr(value) },
+            ExprNode::OptionNone => Expr::OptionNone,
+            ExprNode::WithCapacity { value, capacity } => Expr::WithCapacity { value: self.expr(value), capacity: self.expr(capacity) },
+            ExprNode::Closure { params, body, return_type } => Expr::Closure {
+                params: self.typed_params(params),
+                body: match body {
+                    ClosureBodyNode::Expression(expr) => ClosureBody::Expression(self.expr(expr)),
+                    ClosureBodyNode::Block(block) => ClosureBody::Block(self.block(block)),
+                },
+                return_type: return_type.as_ref().map(|ty| self.ty_ref(ty)),
+            },
+            ExprNode::CallExpr { callee, args } => Expr::CallExpr { callee: self.expr(callee), args: self.exprs(args) },
+            ExprNode::InterpolatedString(parts) => Expr::InterpolatedString(
+                parts
+                    .iter()
+                    .map(|part| match part {
+                        StringPartNode::Literal(s) => StringPart::Literal(self.sym(s)),
+                        StringPartNode::Expr { value, format_spec, debug } => StringPart::Expr {
+                            value: self.expr(value),
+                            format_spec: self.opt_sym(format_spec),
+                            debug: *debug,
+                        },
+                    })
+                    .collect(),
+            ),
+            ExprNode::Comprehension { kind, element, value, pattern, iterable, filter } => Expr::Comprehension {
+                kind: *kind,
+                element: self.expr(element),
+                value: value.as_ref().map(|value| self.expr(value)),
+                pattern: match pattern {
+                    PatternNode::Identifier(s) => Pattern::Identifier(self.sym(s)),
+                    PatternNode::Tuple(names) => Pattern::Tuple(self.syms(names)),
+                },
+                iterable: self.expr(iterable),
+                filter: filter.as_ref().map(|filter| self.expr(filter)),
+            },
+        };
+        self.ctx.alloc_imperative_expr(expr)
+    }
+
+    fn exprs(&mut self, nodes: &[ExprNode]) -> Vec<&'a Expr<'a>> {
+        nodes.iter().map(|node| self.expr(node)).collect()
+    }
+
+    fn opt_expr(&mut self, node: &Option<ExprNode>) -> Option<&'a Expr<'a>> {
+        node.as_ref().map(|node| self.expr(node))
+    }
+
+    fn named_exprs(&mut self, fields: &[(String, ExprNode)]) -> Vec<(Symbol, &'a Expr<'a>)> {
+        fields.iter().map(|(name, value)| (self.sym(name), self.expr(value))).collect()
+    }
+
+    fn secure(&mut self, node: &Option<SecurePadNode>) -> Option<SecurePad<'a>> {
+        node.as_ref().map(|node| SecurePad { pad: self.expr(&node.pad), role: node.role })
+    }
+
+    fn block(&mut self, nodes: &[StmtNode]) -> Block<'a> {
+        let stmts: Vec<Stmt<'a>> = nodes.iter().map(|node| self.stmt(node)).collect();
+        self.ctx.stmts.expect("checked by decode_program").alloc_slice(stmts)
+    }
+
+    fn stmt(&mut self, node: &StmtNode) -> Stmt<'a> {
+        match node {
+            StmtNode::Let { var, ty, value, mutable } => Stmt::Let {
+                var: self.sym(var),
+                ty: ty.as_ref().map(|ty| self.ty_ref(ty)),
+                value: self.expr(value),
+                mutable: *mutable,
+            },
+            StmtNode::Set { target, value } => Stmt::Set { target: self.sym(target), value: self.expr(value) },
+            StmtNode::Call { function, args } => Stmt::Call { function: self.sym(function), args: self.exprs(args) },
+            StmtNode::If { cond, then_block, else_block } => Stmt::If {
+                cond: self.expr(cond),
+                then_block: self.block(then_block),
+                else_block: else_block.as_ref().map(|block| self.block(block)),
+            },
+            StmtNode::While { cond, body, decreasing } => Stmt::While {
+                cond: self.expr(cond),
+                body: self.block(body),
+                decreasing: self.opt_expr(decreasing),
+            },
+            StmtNode::Repeat { pattern, iterable, body } => Stmt::Repeat {
+                pattern: match pattern {
+                    PatternNode::Identifier(s) => Pattern::Identifier(self.sym(s)),
+                    PatternNode::Tuple(names) => Pattern::Tuple(self.syms(names)),
+                },
+                iterable: self.expr(iterable),
+                body: self.block(body),
+            },
+            StmtNode::Return { value } => Stmt::Return { value: self.opt_expr(value) },
+            StmtNode::Break => Stmt::Break,
+            StmtNode::Assert { proposition } => Stmt::Assert { proposition: self.logic(proposition) },
+            StmtNode::Trust { proposition, justification } => Stmt::Trust {
+                proposition: self.logic(proposition),
+                justification: self.sym(justification),
+            },
+            StmtNode::RuntimeAssert { condition, hard } => Stmt::RuntimeAssert { condition: self.expr(condition), hard: *hard },
+            StmtNode::Give { object, recipient } => Stmt::Give { object: self.expr(object), recipient: self.expr(recipient) },
+            StmtNode::Show { object, recipient } => Stmt::Show { object: self.expr(object), recipient: self.expr(recipient) },
+            StmtNode::Log { level, message, fields } => Stmt::Log {
+                level: *level,
+                message: self.expr(message),
+                fields: self.named_exprs(fields),
+            },
+            StmtNode::RunCommand { command, timeout, output, errors, exit_code } => Stmt::RunCommand {
+                command: self.expr(command),
+                timeout: self.opt_expr(timeout),
+                output: self.opt_sym(output),
+                errors: self.opt_sym(errors),
+                exit_code: self.opt_sym(exit_code),
+            },
+            StmtNode::Sip { file, sources, progress } => Stmt::Sip {
+                file: self.expr(file),
+                sources: self.expr(sources),
+                progress: self.opt_sym(progress),
+            },
+            StmtNode::SetField { object, field, value } => Stmt::SetField {
+                object: self.expr(object),
+                field: self.sym(field),
+                value: self.expr(value),
+            },
+            StmtNode::StructDef { name, fields, is_portable } => Stmt::StructDef {
+                name: self.sym(name),
+                fields: fields.iter().map(|(field, ty, public)| (self.sym(field), self.sym(ty), *public)).collect(),
+                is_portable: *is_portable,
+            },
+            StmtNode::FunctionDef {
+                name,
+                generics,
+                params,
+                body,
+                return_type,
+                is_native,
+                native_path,
+                is_exported,
+                export_target,
+                opt_flags,
+            } => Stmt::FunctionDef {
+                name: self.sym(name),
+                generics: self.syms(generics),
+                params: self.typed_params(params),
+                body: self.block(body),
+                return_type: return_type.as_ref().map(|ty| self.ty_ref(ty)),
+                is_native: *is_native,
+                native_path: self.opt_sym(native_path),
+                is_exported: *is_exported,
+                export_target: self.opt_sym(export_target),
+                opt_flags: *opt_flags,
+            },
+            StmtNode::Inspect { target, arms, has_otherwise } => Stmt::Inspect {
+                target: self.expr(target),
+                arms: arms
+                    .iter()
+                    .map(|arm| MatchArm {
+                        enum_name: self.opt_sym(&arm.enum_name),
+                        variant: self.opt_sym(&arm.variant),
+                        bindings: arm.bindings.iter().map(|(field, binding)| (self.sym(field), self.sym(binding))).collect(),
+                        body: self.block(&arm.body),
+                    })
+                    .collect(),
+                has_otherwise: *has_otherwise,
+            },
+            StmtNode::Push { value, collection } => Stmt::Push { value: self.expr(value), collection: self.expr(collection) },
+            StmtNode::Pop { collection, into } => Stmt::Pop { collection: self.expr(collection), into: self.opt_sym(into) },
+            StmtNode::Add { value, collection } => Stmt::Add { value: self.expr(value), collection: self.expr(collection) },
+            StmtNode::Remove { value, collection } => Stmt::Remove { value: self.expr(value), collection: self.expr(collection) },
+            StmtNode::SetIndex { collection, index, value } => Stmt::SetIndex {
+                collection: self.expr(collection),
+                index: self.expr(index),
+                value: self.expr(value),
+            },
+            StmtNode::Splice { body } => Stmt::Splice { body: self.block(body) },
+            StmtNode::Zone { name, capacity, source_file, body } => Stmt::Zone {
+                name: self.sym(name),
+                capacity: *capacity,
+                source_file: source_file.as_ref().map(|source| match source {
+                    ZoneSourceNode::Literal(s) => ZoneSource::Literal(self.sym(s)),
+                    ZoneSourceNode::Variable(s) => ZoneSource::Variable(self.sym(s)),
+                }),
+                body: self.block(body),
+            },
+            StmtNode::Concurrent { tasks } => Stmt::Concurrent { tasks: self.block(tasks) },
+            StmtNode::Parallel { tasks } => Stmt::Parallel { tasks: self.block(tasks) },
+            StmtNode::ReadFrom { var, source } => Stmt::ReadFrom {
+                var: self.sym(var),
+                source: match source {
+                    ReadSourceNode::Console => ReadSource::Console,
+                    ReadSourceNode::File(path) => ReadSource::File(self.expr(path)),
+                    ReadSourceNode::Settings { type_name, path } => ReadSource::Settings {
+                        type_name: self.sym(type_name),
+                        path: path.as_ref().map(|path| self.expr(path)),
+                    },
+                    ReadSourceNode::Arguments { type_name } => ReadSource::Arguments { type_name: self.sym(type_name) },
+                },
+            },
+            StmtNode::WriteFile { content, path } => Stmt::WriteFile { content: self.expr(content), path: self.expr(path) },
+            StmtNode::Spawn { agent_type, name } => Stmt::Spawn { agent_type: self.sym(agent_type), name: self.sym(name) },
+            StmtNode::SendMessage {
+                message,
+                destination,
+                compression,
+                cached,
+                unchecked,
+                layout,
+                shared,
+                computed,
+                indexed,
+                deduped,
+            } => Stmt::SendMessage {
+                message: self.expr(message),
+                destination: self.expr(destination),
+                compression: *compression,
+                cached: *cached,
+                unchecked: *unchecked,
+                layout: *layout,
+                shared: *shared,
+                computed: *computed,
+                indexed: *indexed,
+                deduped: *deduped,
+            },
+            StmtNode::AwaitMessage { source, into, view, stream } => Stmt::AwaitMessage {
+                source: self.expr(source),
+                into: self.sym(into),
+                view: *view,
+                stream: *stream,
+            },
+            StmtNode::StreamMessage { values, destination } => Stmt::StreamMessage {
+                values: self.expr(values),
+                destination: self.expr(destination),
+            },
+            StmtNode::MergeCrdt { source, target } => Stmt::MergeCrdt { source: self.expr(source), target: self.expr(target) },
+            StmtNode::IncreaseCrdt { object, field, amount } => Stmt::IncreaseCrdt {
+                object: self.expr(object),
+                field: self.sym(field),
+                amount: self.expr(amount),
+            },
+            StmtNode::DecreaseCrdt { object, field, amount } => Stmt::DecreaseCrdt {
+                object: self.expr(object),
+                field: self.sym(field),
+                amount: self.expr(amount),
+            },
+            StmtNode::AppendToSequence { sequence, value } => Stmt::AppendToSequence {
+                sequence: self.expr(sequence),
+                value: self.expr(value),
+            },
+            StmtNode::ResolveConflict { object, field, value } => Stmt::ResolveConflict {
+                object: self.expr(object),
+                field: self.sym(field),
+                value: self.expr(value),
+            },
+            StmtNode::Check { subject, predicate, is_capability, object, source_text, span } => Stmt::Check {
+                subject: self.sym(subject),
+                predicate: self.sym(predicate),
+                is_capability: *is_capability,
+                object: self.opt_sym(object),
+                source_text: source_text.clone(),
+                span: *span,
+            },
+            StmtNode::Listen { address, secure } => Stmt::Listen { address: self.expr(address), secure: self.secure(secure) },
+            StmtNode::ConnectTo { address, secure } => Stmt::ConnectTo { address: self.expr(address), secure: self.secure(secure) },
+            StmtNode::JoinSwarm { using } => Stmt::JoinSwarm {
+                using: using
+                    .iter()
+                    .map(|discovery| match discovery {
+                        SwarmNode::Local => SwarmDiscovery::Local,
+                        SwarmNode::Bootstrap(addrs) => SwarmDiscovery::Bootstrap(self.opt_expr(addrs)),
+                        SwarmNode::Relays(addrs) => SwarmDiscovery::Relays(self.opt_expr(addrs)),
+                    })
+                    .collect(),
+            },
+            StmtNode::LetPeerAgent { var, address } => Stmt::LetPeerAgent { var: self.sym(var), address: self.expr(address) },
+            StmtNode::Sleep { milliseconds } => Stmt::Sleep { milliseconds: self.expr(milliseconds) },
+            StmtNode::Sync { var, topic } => Stmt::Sync { var: self.sym(var), topic: self.expr(topic) },
+            StmtNode::Mount { var, path } => Stmt::Mount { var: self.sym(var), path: self.expr(path) },
+            StmtNode::LaunchTask { function, args } => Stmt::LaunchTask { function: self.sym(function), args: self.exprs(args) },
+            StmtNode::LaunchTaskWithHandle { handle, function, args } => Stmt::LaunchTaskWithHandle {
+                handle: self.sym(handle),
+                function: self.sym(function),
+                args: self.exprs(args),
+            },
+            StmtNode::CreatePipe { var, element_type, capacity } => Stmt::CreatePipe {
+                var: self.sym(var),
+                element_type: self.sym(element_type),
+                capacity: *capacity,
+            },
+            StmtNode::SendPipe { value, pipe } => Stmt::SendPipe { value: self.expr(value), pipe: self.expr(pipe) },
+            StmtNode::ReceivePipe { var, pipe } => Stmt::ReceivePipe { var: self.sym(var), pipe: self.expr(pipe) },
+            StmtNode::TrySendPipe { value, pipe, result } => Stmt::TrySendPipe {
+                value: self.expr(value),
+                pipe: self.expr(pipe),
+                result: self.opt_sym(result),
+            },
+            StmtNode::TryReceivePipe { var, pipe } => Stmt::TryReceivePipe { var: self.sym(var), pipe: self.expr(pipe) },
+            StmtNode::StopTask { handle } => Stmt::StopTask { handle: self.expr(handle) },
+            StmtNode::Select { branches } => Stmt::Select {
+                branches: branches
+                    .iter()
+                    .map(|branch| match branch {
+                        SelectBranchNode::Receive { var, pipe, body } => SelectBranch::Receive {
+                            var: self.sym(var),
+                            pipe: self.expr(pipe),
+                            body: self.block(body),
+                        },
+                        SelectBranchNode::Timeout { milliseconds, body } => SelectBranch::Timeout {
+                            milliseconds: self.expr(milliseconds),
+                            body: self.block(body),
+                        },
+                    })
+                    .collect(),
+            },
+            StmtNode::Theorem(theorem) => Stmt::Theorem(TheoremBlock {
+                name: theorem.name.clone(),
+                premises: theorem.premises.iter().map(|premise| self.logic(premise)).collect(),
+                premise_names: theorem.premise_names.clone(),
+                goal: self.logic(&theorem.goal),
+                strategy: theorem.strategy.clone(),
+            }),
+            StmtNode::Definition(definition) => Stmt::Definition(DefinitionBlock {
+                name: definition.name.clone(),
+                params: self.syms(&definition.params),
+                definiendum: self.logic(&definition.definiendum),
+                definiens: self.logic(&definition.definiens),
+            }),
+            StmtNode::Axiom(axiom) => Stmt::Axiom(axiom.clone()),
+            StmtNode::Theory(theory) => Stmt::Theory(theory.clone()),
+            StmtNode::Escape { language, code, span } => Stmt::Escape {
+                language: self.sym(language),
+                code: self.sym(code),
+                span: *span,
+            },
+            StmtNode::Require { crate_name, version, features, span } => Stmt::Require {
+                crate_name: self.sym(crate_name),
+                version: self.sym(version),
+                features: self.syms(features),
+                span: *span,
+            },
+        }
+    }
+}
+
+fn connective_token(op: Connective) -> TokenType {
+    match op {
+        Connective::And => TokenType::And,
+        Connective::Or => TokenType::Or,
+        Connective::If => TokenType::If,
+        Connective::Then => TokenType::Then,
+        Connective::Not => TokenType::Not,
+        Connective::Iff => TokenType::Iff,
+        Connective::Because => TokenType::Because,
+        Connective::Although => TokenType::Although,
+        Connective::Until => TokenType::Until,
+        Connective::Release => TokenType::Release,
+        Connective::WeakUntil => TokenType::WeakUntil,
+        Connective::Implies => TokenType::Implies,
+    }
+}
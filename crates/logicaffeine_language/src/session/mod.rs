@@ -0,0 +1,506 @@
+//! Session Manager for Incremental Evaluation
+//!
+//! Provides a REPL-style interface for parsing sentences one at a time
+//! while maintaining persistent discourse state across turns.
+//!
+//! # Example
+//!
+//! ```
+//! use logicaffeine_language::Session;
+//!
+//! let mut session = Session::new();
+//! let out1 = session.eval("The boys lifted the piano.").unwrap();
+//! let out2 = session.eval("They smiled.").unwrap();  // "They" resolves to "the boys"
+//! ```
+//!
+//! # Interactive disambiguation
+//!
+//! By default a session silently takes the likeliest reading of an ambiguous
+//! sentence and the parser's preferred antecedent of an ambiguous pronoun.
+//! Install a [`DisambiguationPolicy`] to be asked instead — a web UI or CLI
+//! prompt puts the alternatives to the user. Every answer is remembered, so
+//! a sentence that recurs resolves the same way without asking again.
+//!
+//! ```
+//! use logicaffeine_language::session::{DisambiguationPolicy, Session};
+//! use logicaffeine_language::parser::AntecedentAmbiguity;
+//!
+//! struct Latest;
+//! impl DisambiguationPolicy for Latest {
+//!     fn choose_reading(&mut self, _: &str, readings: &[String]) -> usize {
+//!         readings.len() - 1
+//!     }
+//!     fn choose_antecedent(&mut self, _: &str, ambiguity: &AntecedentAmbiguity) -> usize {
+//!         ambiguity.candidates.len() - 1
+//!     }
+//! }
+//!
+//! let mut session = Session::new();
+//! session.set_disambiguation_policy(Latest);
+//! session.eval("A farmer met a lawyer.").unwrap();
+//! session.eval("He smiled.").unwrap(); // "He" is the lawyer, not the farmer
+//! assert_eq!(session.disambiguation("He smiled.").unwrap().antecedents[0].1, "lawyer");
+//! ```
+//!
+//! # Persistence
+//!
+//! [`Session::to_json`] and [`Session::to_bytes`] save the whole discourse
+//! as a versioned [`SessionSnapshot`]; [`Session::from_json`] and
+//! [`Session::from_bytes`] resume it, pronouns still resolving against the
+//! referents introduced before the save (see [`snapshot`]).
+
+use std::collections::HashMap;
+
+use crate::analysis::{self, TypeRegistry};
+use logicaffeine_base::{Arena, Symbol};
+use crate::arena_ctx::AstContext;
+use crate::compile::{self, Construal};
+use crate::drs::WorldState;
+use crate::error::ParseError;
+use crate::gazetteer::Gazetteer;
+use logicaffeine_base::Interner;
+use crate::lexer::Lexer;
+use crate::mwe;
+use crate::parser::{AntecedentAmbiguity, Parser};
+use crate::ranking::{self, RankingWeights, ReadingFeatures};
+use crate::registry::SymbolRegistry;
+use crate::semantics;
+use crate::OutputFormat;
+
+pub mod snapshot;
+pub use snapshot::{SessionSnapshot, SnapshotError};
+
+/// Consulted by a [`Session`] when a sentence can be understood more than
+/// one way. Both methods return an index into the alternatives they are
+/// shown, which come most likely first — answering `0` reproduces what the
+/// session would have done on its own. Out-of-range answers count as `0`.
+pub trait DisambiguationPolicy {
+    /// Picks one of the sentence's distinct readings.
+    fn choose_reading(&mut self, sentence: &str, readings: &[String]) -> usize;
+
+    /// Picks the antecedent of a pronoun that could refer to more than one
+    /// entity.
+    fn choose_antecedent(&mut self, sentence: &str, ambiguity: &AntecedentAmbiguity) -> usize;
+}
+
+/// The choices made for one sentence, replayed whenever it recurs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Disambiguation {
+    /// Index into the sentence's ranked readings, when it had several.
+    pub reading: Option<usize>,
+    /// Pronoun token index → the chosen antecedent's noun or name.
+    pub antecedents: Vec<(usize, String)>,
+}
+
+impl Disambiguation {
+    fn antecedent_for(&self, ambiguity: &AntecedentAmbiguity) -> Option<&str> {
+        self.antecedents
+            .iter()
+            .find(|(token, name)| *token == ambiguity.token && ambiguity.candidates.contains(name))
+            .map(|(_, name)| name.as_str())
+    }
+}
+
+/// What one parse of a turn produced.
+struct Turn {
+    output: String,
+    features: ReadingFeatures,
+    ambiguities: Vec<AntecedentAmbiguity>,
+}
+
+/// A persistent session for incremental sentence evaluation.
+///
+/// Maintains discourse state across multiple `eval()` calls, enabling
+/// cross-sentence anaphora resolution and temporal ordering.
+pub struct Session {
+    /// Persistent discourse state (DRS tree, referents, modal contexts)
+    world_state: WorldState,
+
+    /// Symbol interner for string interning across all sentences
+    interner: Interner,
+
+    /// Symbol registry for transpilation
+    registry: SymbolRegistry,
+
+    /// MWE trie for multi-word expression detection
+    mwe_trie: mwe::MweTrie,
+
+    /// Accumulated transpiled outputs from each sentence
+    history: Vec<String>,
+
+    /// Output format for transpilation
+    format: OutputFormat,
+
+    /// Asked to resolve ambiguity; `None` takes the likeliest reading.
+    policy: Option<Box<dyn DisambiguationPolicy>>,
+
+    /// Choices made so far, keyed by whitespace-normalized sentence.
+    choices: HashMap<String, Disambiguation>,
+
+    /// Known multi-word proper names.
+    gazetteer: Gazetteer,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Session {
+    /// Create a new session with default settings.
+    pub fn new() -> Self {
+        Session {
+            world_state: WorldState::new(),
+            interner: Interner::new(),
+            registry: SymbolRegistry::new(),
+            mwe_trie: mwe::build_mwe_trie(),
+            history: Vec::new(),
+            format: OutputFormat::Unicode,
+            policy: None,
+            choices: HashMap::new(),
+            gazetteer: Gazetteer::new(),
+        }
+    }
+
+    /// Switch the output format mid-session.
+    ///
+    /// Affects only how subsequent sentences are rendered; discourse state
+    /// (referents, event counters) is untouched, so a session switched to
+    /// LaTeX renders exactly as one that used LaTeX from the start.
+    pub fn set_format(&mut self, format: OutputFormat) {
+        self.format = format;
+    }
+
+    /// Create a new session with a specific output format.
+    pub fn with_format(format: OutputFormat) -> Self {
+        Session {
+            world_state: WorldState::new(),
+            interner: Interner::new(),
+            registry: SymbolRegistry::new(),
+            mwe_trie: mwe::build_mwe_trie(),
+            history: Vec::new(),
+            format,
+            policy: None,
+            choices: HashMap::new(),
+            gazetteer: Gazetteer::new(),
+        }
+    }
+
+    /// Lex the names in `gazetteer` as single proper names, with their
+    /// sorts, from the next sentence on.
+    pub fn set_gazetteer(&mut self, gazetteer: Gazetteer) {
+        self.gazetteer = gazetteer;
+    }
+
+    /// Ask `policy` whenever a sentence has several readings or a pronoun
+    /// several possible antecedents.
+    pub fn set_disambiguation_policy(&mut self, policy: impl DisambiguationPolicy + 'static) {
+        self.policy = Some(Box::new(policy));
+    }
+
+    /// Go back to silently taking the likeliest interpretation. Choices
+    /// already made are still replayed.
+    pub fn clear_disambiguation_policy(&mut self) {
+        self.policy = None;
+    }
+
+    /// The choices remembered for `sentence`, if it was ever disambiguated.
+    pub fn disambiguation(&self, sentence: &str) -> Option<&Disambiguation> {
+        self.choices.get(&sentence_key(sentence))
+    }
+
+    /// Forget every remembered choice; the policy is asked afresh.
+    pub fn forget_choices(&mut self) {
+        self.choices.clear();
+    }
+
+    /// Evaluate a single sentence, updating the session state.
+    ///
+    /// Returns the transpiled logic for just this sentence.
+    /// Pronouns in this sentence can resolve to entities from previous sentences.
+    pub fn eval(&mut self, input: &str) -> Result<String, ParseError> {
+        // Generate event variable for this turn
+        let event_var_name = self.world_state.next_event_var();
+        let event_var_symbol = self.interner.intern(&event_var_name);
+
+        // Tokenize
+        let mut lexer = Lexer::new(input, &mut self.interner).with_gazetteer(&self.gazetteer);
+        let tokens = lexer.tokenize();
+
+        // Apply MWE collapsing
+        let tokens = mwe::apply_mwe_pipeline(tokens, &self.mwe_trie, &mut self.interner);
+
+        // Pass 1: Discovery - scan for type definitions
+        let type_registry = {
+            let mut discovery = analysis::DiscoveryPass::new(&tokens, &mut self.interner);
+            discovery.run()
+        };
+
+        let key = sentence_key(input);
+        let output = match self.policy.take() {
+            Some(mut policy) => {
+                let result = self.eval_disambiguated(policy.as_mut(), input, &key, tokens, &type_registry, event_var_symbol);
+                self.policy = Some(policy);
+                result?
+            }
+            None => {
+                let remembered = self.choices.get(&key).cloned().unwrap_or_default();
+                let construal = match remembered.reading {
+                    Some(reading) => self.replay_reading(tokens, reading, &type_registry, event_var_symbol),
+                    None => Construal::literal(tokens),
+                };
+                self.parse_turn(&construal, &type_registry, event_var_symbol, &remembered.antecedents)?.output
+            }
+        };
+
+        // Store in history
+        self.history.push(output.clone());
+
+        Ok(output)
+    }
+
+    /// [`Session::eval`] with a policy installed: settle the reading, then
+    /// every ambiguous pronoun, asking only about what is not yet remembered.
+    fn eval_disambiguated(
+        &mut self,
+        policy: &mut dyn DisambiguationPolicy,
+        input: &str,
+        key: &str,
+        tokens: Vec<crate::token::Token>,
+        type_registry: &TypeRegistry,
+        event_var: Symbol,
+    ) -> Result<String, ParseError> {
+        let mut remembered = self.choices.get(key).cloned().unwrap_or_default();
+        let (world_state, registry) = (self.world_state.clone(), self.registry.clone());
+
+        let (mut candidates, readings) = self.distinct_readings(tokens, type_registry, event_var);
+        let construal = if readings.len() > 1 {
+            let texts: Vec<String> = readings.iter().map(|(_, text)| text.clone()).collect();
+            let choice = match remembered.reading.filter(|&r| r < texts.len()) {
+                Some(r) => r,
+                None => Some(policy.choose_reading(input, &texts)).filter(|&r| r < texts.len()).unwrap_or(0),
+            };
+            remembered.reading = Some(choice);
+            candidates.swap_remove(readings[choice].0)
+        } else {
+            candidates.swap_remove(0)
+        };
+
+        // Choosing one antecedent can change the candidates for the next
+        // pronoun, so re-parse until every ambiguous pronoun has an answer.
+        let mut rounds = 0;
+        loop {
+            let turn = self.parse_turn(&construal, type_registry, event_var, &remembered.antecedents)?;
+            let undecided: Vec<AntecedentAmbiguity> = turn
+                .ambiguities
+                .into_iter()
+                .filter(|a| remembered.antecedent_for(a).is_none())
+                .collect();
+            rounds += 1;
+            if undecided.is_empty() || rounds > construal.tokens.len() {
+                self.choices.insert(key.to_string(), remembered);
+                return Ok(turn.output);
+            }
+            self.world_state = world_state.clone();
+            self.registry = registry.clone();
+            for ambiguity in undecided {
+                let pick = Some(policy.choose_antecedent(input, &ambiguity))
+                    .filter(|&p| p < ambiguity.candidates.len())
+                    .unwrap_or(0);
+                remembered.antecedents.retain(|(token, _)| *token != ambiguity.token);
+                remembered.antecedents.push((ambiguity.token, ambiguity.candidates[pick].clone()));
+            }
+        }
+    }
+
+    /// The construal behind remembered reading `reading`, when no policy
+    /// is installed to be asked.
+    fn replay_reading(
+        &mut self,
+        tokens: Vec<crate::token::Token>,
+        reading: usize,
+        type_registry: &TypeRegistry,
+        event_var: Symbol,
+    ) -> Construal {
+        let (mut candidates, readings) = self.distinct_readings(tokens, type_registry, event_var);
+        match readings.get(reading) {
+            Some(&(index, _)) if readings.len() > 1 => candidates.swap_remove(index),
+            _ => candidates.swap_remove(0),
+        }
+    }
+
+    /// Every construal of the turn, plus its distinct readings most likely
+    /// first as `(construal index, logic)`. Each is a trial parse against
+    /// a copy of the session state, which is left untouched. Empty when the
+    /// sentence is unambiguous or its default construal does not parse —
+    /// the latter keeps the default's error as the diagnosis.
+    fn distinct_readings(
+        &mut self,
+        tokens: Vec<crate::token::Token>,
+        type_registry: &TypeRegistry,
+        event_var: Symbol,
+    ) -> (Vec<Construal>, Vec<(usize, String)>) {
+        let candidates = compile::construals(&tokens, &self.interner);
+        if candidates.len() == 1 {
+            return (candidates, Vec::new());
+        }
+        let (world_state, registry) = (self.world_state.clone(), self.registry.clone());
+        let mut parsed: Vec<(usize, String, ReadingFeatures)> = Vec::new();
+        for (i, construal) in candidates.iter().enumerate() {
+            let trial = self.parse_turn(construal, type_registry, event_var, &[]);
+            self.world_state = world_state.clone();
+            self.registry = registry.clone();
+            if let Ok(turn) = trial {
+                if !parsed.iter().any(|(_, text, _)| *text == turn.output) {
+                    parsed.push((i, turn.output, turn.features));
+                }
+            }
+        }
+        if parsed.first().map(|(i, _, _)| *i) != Some(0) {
+            return (candidates, Vec::new());
+        }
+        let features: Vec<ReadingFeatures> = parsed.iter().map(|(_, _, f)| *f).collect();
+        let readings = ranking::rank(&features, RankingWeights::active())
+            .into_iter()
+            .map(|i| (parsed[i].0, parsed[i].1.clone()))
+            .collect();
+        (candidates, readings)
+    }
+
+    /// Parse and transpile one construal of the turn against the session
+    /// state, with the given pronoun antecedents fixed.
+    fn parse_turn(
+        &mut self,
+        construal: &Construal,
+        type_registry: &TypeRegistry,
+        event_var: Symbol,
+        antecedents: &[(usize, String)],
+    ) -> Result<Turn, ParseError> {
+        // Create arenas for this parse (fresh each sentence)
+        let expr_arena = Arena::new();
+        let term_arena = Arena::new();
+        let np_arena = Arena::new();
+        let sym_arena = Arena::new();
+        let role_arena = Arena::new();
+        let pp_arena = Arena::new();
+
+        let ast_ctx = AstContext::new(
+            &expr_arena,
+            &term_arena,
+            &np_arena,
+            &sym_arena,
+            &role_arena,
+            &pp_arena,
+        );
+
+        // Pass 2: Parse with WorldState (DRS persists across sentences)
+        let mut parser = Parser::new(
+            construal.tokens.clone(),
+            &mut self.world_state,
+            &mut self.interner,
+            ast_ctx,
+            type_registry.clone(),
+        );
+        parser.set_discourse_event_var(event_var);
+        construal.mode.configure(&mut parser);
+        parser.register_gazetteer(&self.gazetteer);
+        for (token, antecedent) in antecedents {
+            parser.set_antecedent_choice(*token, antecedent);
+        }
+
+        // Swap DRS from WorldState into Parser at start
+        parser.swap_drs_with_world_state();
+        let ast = parser.parse()?;
+        let ast = construal.mode.finish(&mut parser, ast)?;
+        // Swap DRS back to WorldState at end
+        parser.swap_drs_with_world_state();
+        let ambiguities = parser.antecedent_ambiguities().to_vec();
+
+        // Mark sentence boundary - collect telescope candidates for cross-sentence anaphora
+        self.world_state.end_sentence();
+
+        // Apply semantic axioms
+        let ast = semantics::apply_axioms(ast, ast_ctx.exprs, ast_ctx.terms, &mut self.interner);
+
+        // Transpile
+        let output = ast.transpile(&mut self.registry, &self.interner, self.format);
+        let features = construal.features().with_form(ast, &self.interner);
+
+        Ok(Turn { output, features, ambiguities })
+    }
+
+    /// Get the full accumulated logic from all sentences.
+    ///
+    /// Includes temporal ordering constraints (Precedes relations).
+    pub fn history(&self) -> String {
+        if self.history.is_empty() {
+            return String::new();
+        }
+
+        let event_history = self.world_state.event_history();
+        let mut precedes = Vec::new();
+        for i in 0..event_history.len().saturating_sub(1) {
+            precedes.push(format!("Precedes({}, {})", event_history[i], event_history[i + 1]));
+        }
+
+        if precedes.is_empty() {
+            self.history.join(" ∧ ")
+        } else {
+            format!("{} ∧ {}", self.history.join(" ∧ "), precedes.join(" ∧ "))
+        }
+    }
+
+    /// Get the number of sentences processed.
+    pub fn turn_count(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Get direct access to the world state (for advanced use).
+    pub fn world_state(&self) -> &WorldState {
+        &self.world_state
+    }
+
+    /// Get mutable access to the world state (for advanced use).
+    pub fn world_state_mut(&mut self) -> &mut WorldState {
+        &mut self.world_state
+    }
+
+    /// Reset the session to initial state.
+    pub fn reset(&mut self) {
+        self.world_state = WorldState::new();
+        self.history.clear();
+        // Keep interner and registry - symbols are still valid
+    }
+}
+
+/// Memory key for a sentence: its words, whitespace-normalized.
+fn sentence_key(sentence: &str) -> String {
+    sentence.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_basic() {
+        let mut session = Session::new();
+        let out = session.eval("John walked.").unwrap();
+        assert!(out.contains("Walk"), "Should have Walk predicate");
+    }
+
+    #[test]
+    fn test_session_multiple_sentences() {
+        let mut session = Session::new();
+        session.eval("John walked.").unwrap();
+        session.eval("Mary ran.").unwrap();
+
+        assert_eq!(session.turn_count(), 2);
+
+        let history = session.history();
+        assert!(history.contains("Walk"));
+        assert!(history.contains("Run"));
+        assert!(history.contains("Precedes"));
+    }
+}
@@ -0,0 +1,593 @@
+//! Saving and restoring a [`Session`].
+//!
+//! A [`SessionSnapshot`] captures everything a discourse has accumulated —
+//! the [`WorldState`] (DRS boxes and their referents, event and reference-time
+//! counters, temporal constraints, telescoping candidates, modal context),
+//! the interner, the symbol registry's variable names, the turn history, the
+//! output format, remembered disambiguation choices and the gazetteer — so a
+//! discourse can be picked up again later with its anaphora intact. The
+//! disambiguation policy is code, not state: reinstall it after restoring.
+//!
+//! Two callers use it today: the CLI REPL's `:save` and `:load`, and web
+//! notebooks, which checkpoint the session after each logic cell so a rerun
+//! resumes at the first edited cell. The language server keeps no discourse,
+//! since it analyzes each LOGOS document on its own, so it has nothing to save;
+//! English-aware LSP features would be the next caller.
+//!
+//! Symbols are stored as indices into the interner's strings, which are saved
+//! in interning order, so a restored interner hands out the same symbols.
+//!
+//! Two encodings, as in [`crate::serial`]:
+//!
+//! - **JSON** — a `{"format": "logos-session", "version": N, …}` document
+//! - **Binary** — a 4-byte magic and little-endian `u32` version, then the
+//!   snapshot as bincode
+//!
+//! Readers accept any version up to [`SNAPSHOT_VERSION`] and refuse newer ones
+//! with [`SnapshotError::TooNew`].
+//!
+//! ```
+//! use logicaffeine_language::Session;
+//!
+//! let mut session = Session::new();
+//! session.eval("The boys lifted the piano.").unwrap();
+//! let saved = session.to_json();
+//!
+//! let mut resumed = Session::from_json(&saved).unwrap();
+//! assert_eq!(resumed.eval("They smiled.").unwrap(), session.eval("They smiled.").unwrap());
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use logicaffeine_base::{Interner, Symbol};
+
+use super::{Disambiguation, Session};
+use crate::drs::{
+    BoxType, Drs, DrsBox, Gender, ModalContext, Number, OwnershipState, Referent, ReferentSource,
+    ScopePath, TelescopeCandidate, TimeConstraint, TimeRelation, WorldState,
+};
+use crate::gazetteer::{self, Gazetteer};
+use crate::lexicon::Time;
+use crate::mwe;
+use crate::registry::SymbolRegistry;
+use crate::tense::TenseAspect;
+use crate::OutputFormat;
+
+/// The `format` tag every JSON snapshot carries.
+pub const SNAPSHOT_FORMAT: &str = "logos-session";
+
+/// The snapshot version this build writes, and the newest it reads.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+const MAGIC: &[u8; 4] = b"LGSS";
+
+/// Why a snapshot could not be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The input is not a session snapshot at all.
+    NotASnapshot,
+    /// The snapshot was written by a newer format version.
+    TooNew { found: u32, supported: u32 },
+    /// The input claims to be a snapshot but does not decode as one, or
+    /// refers to symbols or DRS boxes it does not contain.
+    Malformed(String),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::NotASnapshot => write!(f, "not a {SNAPSHOT_FORMAT} snapshot"),
+            SnapshotError::TooNew { found, supported } => write!(
+                f,
+                "{SNAPSHOT_FORMAT} version {found} is newer than this reader (version {supported})"
+            ),
+            SnapshotError::Malformed(message) => write!(f, "malformed {SNAPSHOT_FORMAT} snapshot: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+/// A saved [`Session`]; see the [module docs](self).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    format: String,
+    version: u32,
+    /// Interned strings in interning order, after the empty string.
+    strings: Vec<String>,
+    world: WorldNode,
+    /// The registry's word → variable-name mapping and per-letter counters.
+    variables: Vec<(String, String)>,
+    counters: Vec<(char, usize)>,
+    history: Vec<String>,
+    /// The output format's name ([`OutputFormat::name`]).
+    output: String,
+    choices: Vec<ChoiceNode>,
+    gazetteer: Vec<(String, Option<String>)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ChoiceNode {
+    sentence: String,
+    reading: Option<usize>,
+    antecedents: Vec<(usize, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct WorldNode {
+    boxes: Vec<BoxNode>,
+    main_box: usize,
+    current_box: usize,
+    item_categories: Vec<(u32, u32)>,
+    event_counter: usize,
+    event_history: Vec<String>,
+    reference_time_counter: usize,
+    current_reference_time: Option<String>,
+    time_constraints: Vec<(String, TimeRelation, String)>,
+    sentence_tense: Option<TenseNode>,
+    telescope_candidates: Vec<TelescopeNode>,
+    discourse_mode: bool,
+    current_modal_context: Option<ModalNode>,
+    prior_modal_context: Option<ModalNode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct BoxNode {
+    universe: Vec<ReferentNode>,
+    box_type: Option<BoxType>,
+    parent: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ReferentNode {
+    variable: u32,
+    noun_class: u32,
+    gender: GenderNode,
+    plural: bool,
+    source: ReferentSource,
+    used_by_pronoun: bool,
+    ownership: OwnershipState,
+    modifiers: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct TelescopeNode {
+    variable: u32,
+    noun_class: u32,
+    gender: GenderNode,
+    origin_box: usize,
+    scope_path: Vec<ScopePath>,
+    in_modal_scope: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct ModalNode {
+    active: bool,
+    is_epistemic: bool,
+    force: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct TenseNode {
+    time: TimeNode,
+    perfect: bool,
+    progressive: bool,
+}
+
+/// The lexicon's [`Gender`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum GenderNode {
+    Male,
+    Female,
+    Neuter,
+    Unknown,
+}
+
+/// The lexicon's [`Time`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum TimeNode {
+    Past,
+    Present,
+    Future,
+    None,
+}
+
+impl SessionSnapshot {
+    /// The snapshot as a JSON document.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("session snapshots are plain data")
+    }
+
+    /// The snapshot as a binary document.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&self.version.to_le_bytes());
+        bincode::serialize_into(&mut bytes, self).expect("session snapshots are plain data");
+        bytes
+    }
+
+    /// Read a JSON snapshot, checking its tag and version.
+    pub fn from_json(json: &str) -> Result<Self, SnapshotError> {
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| SnapshotError::Malformed(e.to_string()))?;
+        if value.get("format").and_then(|f| f.as_str()) != Some(SNAPSHOT_FORMAT) {
+            return Err(SnapshotError::NotASnapshot);
+        }
+        let version = value
+            .get("version")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| SnapshotError::Malformed("missing version".to_string()))?;
+        check_version(u32::try_from(version).unwrap_or(u32::MAX))?;
+        serde_json::from_value(value).map_err(|e| SnapshotError::Malformed(e.to_string()))
+    }
+
+    /// Read a binary snapshot, checking its magic and version.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        if bytes.len() < 8 || &bytes[..4] != MAGIC {
+            return Err(SnapshotError::NotASnapshot);
+        }
+        check_version(u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]))?;
+        bincode::deserialize(&bytes[8..]).map_err(|e| SnapshotError::Malformed(e.to_string()))
+    }
+
+    /// The number of sentences the saved session had evaluated.
+    pub fn turn_count(&self) -> usize {
+        self.history.len()
+    }
+}
+
+fn check_version(found: u32) -> Result<(), SnapshotError> {
+    if found > SNAPSHOT_VERSION {
+        return Err(SnapshotError::TooNew { found, supported: SNAPSHOT_VERSION });
+    }
+    Ok(())
+}
+
+impl Session {
+    /// Everything the session has accumulated, ready to save.
+    pub fn snapshot(&self) -> SessionSnapshot {
+        let strings = (1..self.interner.len())
+            .map(|i| self.interner.resolve(Symbol::from_index(i)).to_string())
+            .collect();
+
+        let mut variables: Vec<(String, String)> =
+            self.registry.mapping.iter().map(|(word, var)| (word.clone(), var.clone())).collect();
+        variables.sort();
+        let mut counters: Vec<(char, usize)> = self.registry.counters.iter().map(|(&c, &n)| (c, n)).collect();
+        counters.sort();
+
+        let mut choices: Vec<ChoiceNode> = self
+            .choices
+            .iter()
+            .map(|(sentence, choice)| ChoiceNode {
+                sentence: sentence.clone(),
+                reading: choice.reading,
+                antecedents: choice.antecedents.clone(),
+            })
+            .collect();
+        choices.sort_by(|a, b| a.sentence.cmp(&b.sentence));
+
+        SessionSnapshot {
+            format: SNAPSHOT_FORMAT.to_string(),
+            version: SNAPSHOT_VERSION,
+            strings,
+            world: world_node(&self.world_state),
+            variables,
+            counters,
+            history: self.history.clone(),
+            output: self.format.name().to_string(),
+            choices,
+            gazetteer: self
+                .gazetteer
+                .entries()
+                .iter()
+                .map(|entry| (entry.name.clone(), entry.sort.map(|sort| format!("{sort:?}"))))
+                .collect(),
+        }
+    }
+
+    /// A session that continues where the snapshot's left off. No
+    /// disambiguation policy is installed, and a custom output format that
+    /// is not registered in this process falls back to Unicode.
+    pub fn restore(snapshot: &SessionSnapshot) -> Result<Session, SnapshotError> {
+        let mut interner = Interner::new();
+        for (i, string) in snapshot.strings.iter().enumerate() {
+            if interner.intern(string).index() != i + 1 {
+                return Err(SnapshotError::Malformed(format!("string {string:?} is interned twice")));
+            }
+        }
+        let symbols = SymbolTable { count: interner.len() };
+
+        let mut gazetteer = Gazetteer::new();
+        for (name, sort) in &snapshot.gazetteer {
+            let sort = match sort {
+                Some(sort) => Some(
+                    gazetteer::parse_sort(sort)
+                        .ok_or_else(|| SnapshotError::Malformed(format!("unknown sort {sort:?}")))?,
+                ),
+                None => None,
+            };
+            gazetteer.insert(name, sort);
+        }
+
+        Ok(Session {
+            world_state: symbols.world(&snapshot.world)?,
+            interner,
+            registry: SymbolRegistry {
+                mapping: snapshot.variables.iter().cloned().collect(),
+                counters: snapshot.counters.iter().copied().collect(),
+            },
+            mwe_trie: mwe::build_mwe_trie(),
+            history: snapshot.history.clone(),
+            format: OutputFormat::from_name(&snapshot.output).unwrap_or_default(),
+            policy: None,
+            choices: snapshot
+                .choices
+                .iter()
+                .map(|choice| {
+                    let disambiguation =
+                        Disambiguation { reading: choice.reading, antecedents: choice.antecedents.clone() };
+                    (choice.sentence.clone(), disambiguation)
+                })
+                .collect::<HashMap<_, _>>(),
+            gazetteer,
+        })
+    }
+
+    /// The session as a JSON snapshot.
+    pub fn to_json(&self) -> String {
+        self.snapshot().to_json()
+    }
+
+    /// The session as a binary snapshot.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.snapshot().to_bytes()
+    }
+
+    /// Restore a session from a JSON snapshot.
+    pub fn from_json(json: &str) -> Result<Session, SnapshotError> {
+        Session::restore(&SessionSnapshot::from_json(json)?)
+    }
+
+    /// Restore a session from a binary snapshot.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Session, SnapshotError> {
+        Session::restore(&SessionSnapshot::from_bytes(bytes)?)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// Encoding
+// ═══════════════════════════════════════════════════════════════════
+
+fn world_node(world: &WorldState) -> WorldNode {
+    let drs = &world.drs;
+    let mut item_categories: Vec<(u32, u32)> =
+        drs.item_categories.iter().map(|(&item, &category)| (index(item), index(category))).collect();
+    item_categories.sort();
+    WorldNode {
+        boxes: drs.boxes.iter().map(box_node).collect(),
+        main_box: drs.main_box,
+        current_box: drs.current_box,
+        item_categories,
+        event_counter: world.event_counter,
+        event_history: world.event_history.clone(),
+        reference_time_counter: world.reference_time_counter,
+        current_reference_time: world.current_reference_time.clone(),
+        time_constraints: world
+            .time_constraints
+            .iter()
+            .map(|c| (c.left.clone(), c.relation, c.right.clone()))
+            .collect(),
+        sentence_tense: world.sentence_tense.map(|tense| TenseNode {
+            time: match tense.time {
+                Time::Past => TimeNode::Past,
+                Time::Present => TimeNode::Present,
+                Time::Future => TimeNode::Future,
+                Time::None => TimeNode::None,
+            },
+            perfect: tense.perfect,
+            progressive: tense.progressive,
+        }),
+        telescope_candidates: world
+            .telescope_candidates
+            .iter()
+            .map(|candidate| TelescopeNode {
+                variable: index(candidate.variable),
+                noun_class: index(candidate.noun_class),
+                gender: gender_node(candidate.gender),
+                origin_box: candidate.origin_box,
+                scope_path: candidate.scope_path.clone(),
+                in_modal_scope: candidate.in_modal_scope,
+            })
+            .collect(),
+        discourse_mode: world.discourse_mode,
+        current_modal_context: world.current_modal_context.as_ref().map(modal_node),
+        prior_modal_context: world.prior_modal_context.as_ref().map(modal_node),
+    }
+}
+
+fn box_node(drs_box: &DrsBox) -> BoxNode {
+    BoxNode {
+        universe: drs_box
+            .universe
+            .iter()
+            .map(|referent| ReferentNode {
+                variable: index(referent.variable),
+                noun_class: index(referent.noun_class),
+                gender: gender_node(referent.gender),
+                plural: referent.number == Number::Plural,
+                source: referent.source,
+                used_by_pronoun: referent.used_by_pronoun,
+                ownership: referent.ownership,
+                modifiers: referent.modifiers.iter().copied().map(index).collect(),
+            })
+            .collect(),
+        box_type: drs_box.box_type,
+        parent: drs_box.parent,
+    }
+}
+
+fn modal_node(context: &ModalContext) -> ModalNode {
+    ModalNode { active: context.active, is_epistemic: context.is_epistemic, force: context.force }
+}
+
+fn gender_node(gender: Gender) -> GenderNode {
+    match gender {
+        Gender::Male => GenderNode::Male,
+        Gender::Female => GenderNode::Female,
+        Gender::Neuter => GenderNode::Neuter,
+        Gender::Unknown => GenderNode::Unknown,
+    }
+}
+
+fn index(symbol: Symbol) -> u32 {
+    symbol.index() as u32
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// Decoding
+// ═══════════════════════════════════════════════════════════════════
+
+/// Turns saved indices back into symbols of the restored interner,
+/// rejecting any it does not hold.
+struct SymbolTable {
+    count: usize,
+}
+
+impl SymbolTable {
+    fn symbol(&self, index: u32) -> Result<Symbol, SnapshotError> {
+        if (index as usize) < self.count {
+            Ok(Symbol::from_index(index as usize))
+        } else {
+            Err(SnapshotError::Malformed(format!("symbol {index} is not in the string table")))
+        }
+    }
+
+    fn world(&self, node: &WorldNode) -> Result<WorldState, SnapshotError> {
+        let box_count = node.boxes.len();
+        let in_range = |i: usize, what: &str| {
+            if i < box_count {
+                Ok(i)
+            } else {
+                Err(SnapshotError::Malformed(format!("{what} {i} of {box_count} DRS boxes")))
+            }
+        };
+
+        let mut boxes = Vec::with_capacity(box_count);
+        for saved in &node.boxes {
+            let mut universe = Vec::with_capacity(saved.universe.len());
+            for referent in &saved.universe {
+                universe.push(Referent {
+                    variable: self.symbol(referent.variable)?,
+                    noun_class: self.symbol(referent.noun_class)?,
+                    gender: gender(referent.gender),
+                    number: if referent.plural { Number::Plural } else { Number::Singular },
+                    source: referent.source,
+                    used_by_pronoun: referent.used_by_pronoun,
+                    ownership: referent.ownership,
+                    modifiers: referent.modifiers.iter().map(|&m| self.symbol(m)).collect::<Result<_, _>>()?,
+                });
+            }
+            let parent = saved.parent.map(|p| in_range(p, "parent box")).transpose()?;
+            boxes.push(DrsBox { universe, box_type: saved.box_type, parent });
+        }
+        if boxes.is_empty() {
+            return Err(SnapshotError::Malformed("the DRS has no boxes".to_string()));
+        }
+
+        let mut item_categories = HashMap::new();
+        for &(item, category) in &node.item_categories {
+            item_categories.insert(self.symbol(item)?, self.symbol(category)?);
+        }
+
+        let mut telescope_candidates = Vec::with_capacity(node.telescope_candidates.len());
+        for candidate in &node.telescope_candidates {
+            telescope_candidates.push(TelescopeCandidate {
+                variable: self.symbol(candidate.variable)?,
+                noun_class: self.symbol(candidate.noun_class)?,
+                gender: gender(candidate.gender),
+                origin_box: in_range(candidate.origin_box, "telescope origin box")?,
+                scope_path: candidate.scope_path.clone(),
+                in_modal_scope: candidate.in_modal_scope,
+            });
+        }
+
+        Ok(WorldState {
+            drs: Drs {
+                boxes,
+                main_box: in_range(node.main_box, "main box")?,
+                current_box: in_range(node.current_box, "current box")?,
+                item_categories,
+            },
+            event_counter: node.event_counter,
+            event_history: node.event_history.clone(),
+            reference_time_counter: node.reference_time_counter,
+            current_reference_time: node.current_reference_time.clone(),
+            time_constraints: node
+                .time_constraints
+                .iter()
+                .map(|(left, relation, right)| TimeConstraint {
+                    left: left.clone(),
+                    relation: *relation,
+                    right: right.clone(),
+                })
+                .collect(),
+            sentence_tense: node.sentence_tense.map(|tense| TenseAspect {
+                time: match tense.time {
+                    TimeNode::Past => Time::Past,
+                    TimeNode::Present => Time::Present,
+                    TimeNode::Future => Time::Future,
+                    TimeNode::None => Time::None,
+                },
+                perfect: tense.perfect,
+                progressive: tense.progressive,
+            }),
+            telescope_candidates,
+            discourse_mode: node.discourse_mode,
+            current_modal_context: node.current_modal_context.map(modal_context),
+            prior_modal_context: node.prior_modal_context.map(modal_context),
+        })
+    }
+}
+
+fn modal_context(node: ModalNode) -> ModalContext {
+    ModalContext { active: node.active, is_epistemic: node.is_epistemic, force: node.force }
+}
+
+fn gender(node: GenderNode) -> Gender {
+    match node {
+        GenderNode::Male => Gender::Male,
+        GenderNode::Female => Gender::Female,
+        GenderNode::Neuter => Gender::Neuter,
+        GenderNode::Unknown => Gender::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symbols_survive_by_index() {
+        let mut session = Session::new();
+        session.eval("A farmer owns a donkey.").unwrap();
+        let restored = Session::restore(&session.snapshot()).unwrap();
+        for i in 0..session.interner.len() {
+            let symbol = Symbol::from_index(i);
+            assert_eq!(restored.interner.resolve(symbol), session.interner.resolve(symbol));
+        }
+        assert_eq!(restored.world_state.drs.boxes.len(), session.world_state.drs.boxes.len());
+    }
+
+    #[test]
+    fn dangling_symbols_are_rejected() {
+        let mut session = Session::new();
+        session.eval("A farmer walked.").unwrap();
+        let mut snapshot = session.snapshot();
+        snapshot.strings.clear();
+        assert!(matches!(Session::restore(&snapshot), Err(SnapshotError::Malformed(_))));
+    }
+}
@@ -1,11 +1,17 @@
 //! Spelling suggestions for unknown words.
 //!
 //! This module provides fuzzy matching to suggest corrections for misspelled
-//! words in the input. It uses Levenshtein distance to find similar words
-//! from a known vocabulary list.
+//! words in the input. Plain [`levenshtein`] distance backs the small fixed
+//! vocabularies (block headers, [`KNOWN_WORDS`]); the full corrector,
+//! [`suggest`], searches the lexicon's surface forms keyed by [`WordClass`]
+//! with a keyboard-aware cost ([`typo_cost`]) and returns ranked candidates.
 //!
-//! When the parser encounters an unknown word, this module can suggest
-//! "Did you mean X?" alternatives based on edit distance.
+//! When the parser fails on a sentence containing an out-of-lexicon word with
+//! close neighbours, the failure is reported as
+//! [`ParseErrorKind::UnknownWord`](crate::error::ParseErrorKind::UnknownWord)
+//! carrying those ranked suggestions — the LSP turns them into quick-fixes.
+
+use crate::lexicon;
 
 /// Compute the Levenshtein edit distance between two strings.
 pub fn levenshtein(a: &str, b: &str) -> usize {
@@ -73,6 +79,210 @@ pub const KNOWN_WORDS: &[&str] = &[
     "he", "she", "it", "they", "him", "her", "them",
 ];
 
+/// Words that open an imperative statement. The lexer hardcodes these (they
+/// are not lexicon entries), so the corrector keeps its own list.
+pub const STATEMENT_KEYWORDS: &[&str] = &[
+    "assert", "break", "call", "check", "for", "give", "if", "inspect", "let",
+    "pop", "push", "repeat", "return", "set", "show", "while",
+];
+
+/// The lexical class a correction candidate is drawn from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WordClass {
+    Noun,
+    Verb,
+    Adjective,
+    Adverb,
+    /// Closed-class words: quantifiers, articles, pronouns, prepositions.
+    Function,
+    /// Imperative statement keywords ([`STATEMENT_KEYWORDS`]).
+    Keyword,
+}
+
+impl WordClass {
+    /// Every class, in the order candidates are searched.
+    pub const ALL: [WordClass; 6] = [
+        WordClass::Noun,
+        WordClass::Verb,
+        WordClass::Adjective,
+        WordClass::Adverb,
+        WordClass::Function,
+        WordClass::Keyword,
+    ];
+
+    /// The open and closed lexical classes — everything but statement
+    /// keywords, which only make sense at the head of an imperative statement.
+    pub const LEXICAL: [WordClass; 5] = [
+        WordClass::Noun,
+        WordClass::Verb,
+        WordClass::Adjective,
+        WordClass::Adverb,
+        WordClass::Function,
+    ];
+
+    /// The candidate pool for this class (lowercase, sorted).
+    pub fn candidates(self) -> &'static [&'static str] {
+        match self {
+            WordClass::Noun => lexicon::NOUN_WORDS,
+            WordClass::Verb => lexicon::VERB_WORDS,
+            WordClass::Adjective => lexicon::ADJECTIVE_WORDS,
+            WordClass::Adverb => lexicon::ADVERB_WORDS,
+            WordClass::Function => lexicon::FUNCTION_WORDS,
+            WordClass::Keyword => STATEMENT_KEYWORDS,
+        }
+    }
+}
+
+/// One ranked correction for an unknown word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub word: &'static str,
+    pub class: WordClass,
+    /// Edit cost in half-edits (see [`typo_cost`]).
+    pub cost: u32,
+}
+
+/// Row and staggered column of a letter on a QWERTY keyboard. The column is
+/// doubled and offset by the row so a key's diagonal neighbours sit one
+/// half-step away.
+fn key_position(c: char) -> Option<(i32, i32)> {
+    const ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+    ROWS.iter().enumerate().find_map(|(row, keys)| {
+        keys.find(c).map(|col| (row as i32, 2 * col as i32 + row as i32))
+    })
+}
+
+/// Whether two letters are neighbouring keys — the classic fat-finger slip.
+pub fn keys_adjacent(a: char, b: char) -> bool {
+    match (key_position(a), key_position(b)) {
+        (Some((ra, ca)), Some((rb, cb))) => match (ra - rb).abs() {
+            0 => (ca - cb).abs() == 2,
+            1 => (ca - cb).abs() <= 1,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Keyboard-aware edit cost between two words, in half-edits.
+///
+/// Insertions, deletions and unrelated substitutions cost 2; substituting a
+/// neighbouring key and swapping two adjacent letters ("teh" → "the") cost 1,
+/// since those are the slips fingers actually make. Comparison is
+/// case-insensitive.
+pub fn typo_cost(a: &str, b: &str) -> u32 {
+    let a: Vec<char> = a.to_lowercase().chars().collect();
+    let b: Vec<char> = b.to_lowercase().chars().collect();
+    let (m, n) = (a.len(), b.len());
+    let mut d = vec![vec![0u32; n + 1]; m + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = 2 * i as u32;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = 2 * j as u32;
+    }
+    for i in 1..=m {
+        for j in 1..=n {
+            let substitution = if a[i - 1] == b[j - 1] {
+                0
+            } else if keys_adjacent(a[i - 1], b[j - 1]) {
+                1
+            } else {
+                2
+            };
+            let mut best = (d[i - 1][j] + 2)
+                .min(d[i][j - 1] + 2)
+                .min(d[i - 1][j - 1] + substitution);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(d[i - 2][j - 2] + 1);
+            }
+            d[i][j] = best;
+        }
+    }
+    d[m][n]
+}
+
+/// The largest cost (in half-edits) a correction may have for a word of this
+/// length: one slip for short words, two for long ones. Short words have
+/// too many neighbours for a second edit to mean anything.
+fn max_cost(len: usize) -> u32 {
+    match len {
+        0..=4 => 2,
+        5..=7 => 3,
+        _ => 4,
+    }
+}
+
+/// Ranked corrections for `word` from the lexicon.
+///
+/// Candidates are ordered by [`typo_cost`]; ties go to the `preferred` class
+/// (the class the parser expected at this position), then to the candidate
+/// closest in length, then alphabetically — so the ranking is deterministic.
+/// A word that already is a candidate yields nothing.
+pub fn suggest(word: &str, preferred: Option<WordClass>, limit: usize) -> Vec<Suggestion> {
+    suggest_from(word, &WordClass::ALL, preferred, limit)
+}
+
+/// [`suggest`], restricted to the candidate pools of `classes`.
+pub fn suggest_from(
+    word: &str,
+    classes: &[WordClass],
+    preferred: Option<WordClass>,
+    limit: usize,
+) -> Vec<Suggestion> {
+    let lower = word.to_lowercase();
+    let len = lower.chars().count();
+    if len < 2 {
+        return Vec::new();
+    }
+    let budget = max_cost(len);
+
+    let mut found: Vec<Suggestion> = Vec::new();
+    for &class in classes {
+        for &candidate in class.candidates() {
+            if candidate == lower {
+                return Vec::new();
+            }
+            if candidate.chars().count().abs_diff(len) > budget as usize / 2 {
+                continue;
+            }
+            let cost = typo_cost(&lower, candidate);
+            if cost <= budget {
+                found.push(Suggestion { word: candidate, class, cost });
+            }
+        }
+    }
+
+    found.sort_by_key(|s| {
+        (
+            s.cost,
+            Some(s.class) != preferred,
+            s.word.chars().count().abs_diff(len),
+            s.word,
+        )
+    });
+    let mut seen = std::collections::HashSet::new();
+    found.retain(|s| seen.insert(s.word));
+    found.truncate(limit);
+    found
+}
+
+/// Whether the lexicon knows `word`, directly or through a regular
+/// inflection ("dogs", "barked", "running", "quickly").
+pub fn is_known_word(word: &str) -> bool {
+    let lower = word.to_lowercase();
+    let listed = |w: &str| WordClass::ALL.iter().any(|c| c.candidates().binary_search(&w).is_ok());
+    if listed(&lower) || lexicon::analyze_word(&lower).is_some() {
+        return true;
+    }
+    const SUFFIXES: &[&str] = &["s", "es", "ed", "d", "ing", "ly", "er", "est"];
+    SUFFIXES.iter().any(|suffix| {
+        lower.strip_suffix(suffix).is_some_and(|stem| {
+            !stem.is_empty() && (listed(stem) || listed(&format!("{stem}e")))
+        })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,4 +335,83 @@ mod tests {
         let result = find_similar("LOGIC", KNOWN_WORDS, 2);
         assert_eq!(result, Some("logic"));
     }
+
+    #[test]
+    fn keys_adjacent_follows_the_qwerty_stagger() {
+        assert!(keys_adjacent('s', 'a'));
+        assert!(keys_adjacent('s', 'w'));
+        assert!(keys_adjacent('s', 'e'));
+        assert!(keys_adjacent('s', 'z'));
+        assert!(!keys_adjacent('s', 'q'));
+        assert!(!keys_adjacent('s', 'p'));
+        assert!(!keys_adjacent('s', 's'));
+    }
+
+    #[test]
+    fn typo_cost_discounts_neighbour_keys_and_swaps() {
+        assert_eq!(typo_cost("dog", "dog"), 0);
+        assert_eq!(typo_cost("dof", "dog"), 1, "f and g are neighbours");
+        assert_eq!(typo_cost("dop", "dog"), 2, "p and g are not");
+        assert_eq!(typo_cost("teh", "the"), 1, "adjacent transposition");
+        assert_eq!(typo_cost("brks", "barks"), 2, "one insertion");
+        assert_eq!(typo_cost("DOG", "dog"), 0);
+    }
+
+    #[test]
+    fn suggest_ranks_the_closest_lexicon_word_first() {
+        let ranked = suggest("mortla", None, 3);
+        assert_eq!(ranked.first().map(|s| s.word), Some("mortal"), "{ranked:?}");
+    }
+
+    #[test]
+    fn suggest_prefers_the_expected_class_on_ties() {
+        // "apply" and "apple" are both one insertion away from "appl".
+        let as_verb = suggest("appl", Some(WordClass::Verb), 5);
+        let as_noun = suggest("appl", Some(WordClass::Noun), 5);
+        assert_eq!(as_verb[0].cost, as_noun[0].cost, "{as_verb:?} {as_noun:?}");
+        assert_eq!(as_verb[0].class, WordClass::Verb, "{as_verb:?}");
+        assert_eq!(as_verb[0].word, "apply");
+        assert_eq!(as_noun[0].class, WordClass::Noun, "{as_noun:?}");
+        assert_eq!(as_noun[0].word, "apple");
+    }
+
+    #[test]
+    fn suggest_reaches_statement_keywords() {
+        let ranked = suggest("Shwo", Some(WordClass::Keyword), 3);
+        assert_eq!(ranked.first().map(|s| s.word), Some("show"), "{ranked:?}");
+    }
+
+    #[test]
+    fn suggest_is_empty_for_known_and_hopeless_words() {
+        assert!(suggest("dog", None, 3).is_empty());
+        assert!(suggest("qqqqqqqq", None, 3).is_empty());
+        assert!(suggest("x", None, 3).is_empty());
+    }
+
+    #[test]
+    fn suggest_is_deduplicated_and_limited() {
+        let ranked = suggest("rn", None, 50);
+        let mut words: Vec<_> = ranked.iter().map(|s| s.word).collect();
+        words.dedup();
+        assert_eq!(words.len(), ranked.len());
+        assert!(suggest("rn", None, 2).len() <= 2);
+    }
+
+    #[test]
+    fn candidate_pools_are_sorted_for_binary_search() {
+        for class in WordClass::ALL {
+            let pool = class.candidates();
+            assert!(pool.windows(2).all(|w| w[0] < w[1]), "{class:?} pool is not sorted");
+        }
+    }
+
+    #[test]
+    fn is_known_word_sees_through_regular_inflection() {
+        assert!(is_known_word("dog"));
+        assert!(is_known_word("dogs"));
+        assert!(is_known_word("barked"));
+        assert!(is_known_word("Every"));
+        assert!(!is_known_word("mortla"));
+        assert!(!is_known_word("brks"));
+    }
 }
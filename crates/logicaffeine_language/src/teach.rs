@@ -352,6 +352,22 @@ static BLOCK_REQUIRES: ConstructDoc = lesson(
     None,
 );
 
+static BLOCK_SETTINGS: ConstructDoc = lesson(
+    "Settings",
+    "Declares the typed settings a program reads from a TOML file or the environment.",
+    "## Settings\nport: Int.\nhost: Text.",
+    "Which settings may be left out? Declare those as `Option of` a type; the rest are required.",
+    None,
+);
+
+static BLOCK_ARGUMENTS: ConstructDoc = lesson(
+    "Arguments",
+    "Declares a program's command-line flags, options and positional parameters.",
+    "## Arguments\ninput: Text, \"The file to read\".\nA flag verbose, \"Print every step\".",
+    "Tip: the help text after each declaration becomes the program's `--help` output.",
+    None,
+);
+
 static BLOCK_HARDWARE: ConstructDoc = lesson(
     "Hardware",
     "Declares hardware signals for verification.",
@@ -517,7 +533,7 @@ pub static ALL_DOCS: &[&ConstructDoc] = &[
     // block headers
     &BLOCK_MAIN, &BLOCK_FUNCTION, &BLOCK_THEOREM, &BLOCK_PROOF, &BLOCK_DEFINITION, &BLOCK_DEFINE,
     &BLOCK_AXIOM, &BLOCK_THEORY, &BLOCK_TYPEDEF, &BLOCK_POLICY, &BLOCK_LOGIC, &BLOCK_EXAMPLE,
-    &BLOCK_NOTE, &BLOCK_REQUIRES, &BLOCK_HARDWARE, &BLOCK_PROPERTY, &BLOCK_NO, &BLOCK_TIER,
+    &BLOCK_NOTE, &BLOCK_REQUIRES, &BLOCK_SETTINGS, &BLOCK_ARGUMENTS, &BLOCK_HARDWARE, &BLOCK_PROPERTY, &BLOCK_NO, &BLOCK_TIER,
     &BLOCK_SUSPECTED_TYPO,
     // types
     &TY_INT, &TY_NAT, &TY_TEXT, &TY_BOOL, &TY_FLOAT, &TY_UNIT, &TY_CHAR, &TY_BYTE, &TY_LIST,
@@ -789,6 +805,8 @@ pub fn doc_for_block(block: &BlockType) -> &'static ConstructDoc {
         BlockType::TypeDef => &BLOCK_TYPEDEF,
         BlockType::Policy => &BLOCK_POLICY,
         BlockType::Requires => &BLOCK_REQUIRES,
+        BlockType::Settings => &BLOCK_SETTINGS,
+        BlockType::Arguments => &BLOCK_ARGUMENTS,
         BlockType::Hardware => &BLOCK_HARDWARE,
         BlockType::Property => &BLOCK_PROPERTY,
         BlockType::No => &BLOCK_NO,
@@ -0,0 +1,158 @@
+//! Reichenbach tense and aspect.
+//!
+//! A finite clause relates three times: the speech time `S`, a reference
+//! time `R` the clause is about, and the event time `E`. Tense orders `R`
+//! against `S`; aspect orders `E` against `R`:
+//!
+//! | Form | Tense | Aspect | Constraints |
+//! |------|-------|--------|-------------|
+//! | "had left" | past | perfect | `R < S`, `E < R` |
+//! | "has left" | present | perfect | `E < S` |
+//! | "will have left" | future | perfect | `S < R`, `E < R` |
+//! | "was running" | past | progressive | `R < S`, `R ⊆ E` |
+//! | "will be running" | future | progressive | `S < R`, `R ⊆ E` |
+//!
+//! Present tense identifies `R` with `S`, so no reference time is allocated.
+//! Simple tenses identify `E` with `R`; their ordering is already carried by
+//! the event's `Past`/`Future` modifier and adds no constraint.
+//!
+//! Across a discourse the same rules drive [`narrative_order`]: a simple
+//! past moves the story's reference event forward, a past perfect reaches
+//! back before it, and a progressive surrounds it.
+
+use crate::drs::{TimeConstraint, TimeRelation};
+use crate::lexicon::Time;
+
+/// The speech time.
+pub const SPEECH_TIME: &str = "S";
+
+/// The tense and aspect of one clause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TenseAspect {
+    pub time: Time,
+    pub perfect: bool,
+    pub progressive: bool,
+}
+
+impl TenseAspect {
+    /// A simple tense: the event is its own reference time.
+    pub fn simple(time: Time) -> Self {
+        TenseAspect { time, perfect: false, progressive: false }
+    }
+
+    /// Whether the clause needs a reference time distinct from `S`.
+    pub fn has_reference_time(&self) -> bool {
+        matches!(self.time, Time::Past | Time::Future)
+    }
+
+    /// Whether aspect separates the event from its reference time. Only
+    /// then are the Reichenbach constraints spelled out.
+    pub fn is_compound(&self) -> bool {
+        self.perfect || self.progressive
+    }
+
+    /// The clause's constraints over `event` and `reference` (which is
+    /// [`SPEECH_TIME`] for a present tense).
+    pub fn constraints(&self, event: &str, reference: &str) -> Vec<TimeConstraint> {
+        let mut out = Vec::new();
+        match self.time {
+            Time::Past => out.push(constraint(reference, TimeRelation::Precedes, SPEECH_TIME)),
+            Time::Future => out.push(constraint(SPEECH_TIME, TimeRelation::Precedes, reference)),
+            _ => {}
+        }
+        // A perfect progressive ("had been running") is still anterior: the
+        // running leads up to R.
+        if self.perfect {
+            out.push(constraint(event, TimeRelation::Precedes, reference));
+        } else if self.progressive {
+            out.push(constraint(event, TimeRelation::Includes, reference));
+        }
+        out
+    }
+
+    /// How this clause's event sits against the narrative's current
+    /// reference event.
+    fn narrative_relation(&self, event: &str, reference: &str) -> TimeConstraint {
+        if self.perfect {
+            constraint(event, TimeRelation::Precedes, reference)
+        } else if self.progressive {
+            constraint(event, TimeRelation::Includes, reference)
+        } else {
+            constraint(reference, TimeRelation::Precedes, event)
+        }
+    }
+}
+
+/// The timeline of a discourse, one `(event, tense)` per sentence in order.
+///
+/// Only simple tenses advance the reference event ("John entered. He sat."
+/// → `e1 < e2`); a perfect precedes it and a progressive includes it
+/// without moving it ("John left. Mary had arrived. She sat." → `e2 < e1`,
+/// `e1 < e3`).
+pub fn narrative_order(events: &[(String, TenseAspect)]) -> Vec<TimeConstraint> {
+    let mut out = Vec::new();
+    let mut reference: Option<&str> = None;
+    for (event, tense) in events {
+        match reference {
+            None => reference = Some(event),
+            Some(r) => {
+                out.push(tense.narrative_relation(event, r));
+                if !tense.is_compound() {
+                    reference = Some(event);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Renders a constraint as it appears in compiled output.
+pub fn render(c: &TimeConstraint) -> String {
+    match c.relation {
+        TimeRelation::Precedes => format!("Precedes({}, {})", c.left, c.right),
+        TimeRelation::Equals => format!("{}={}", c.left, c.right),
+        TimeRelation::Includes => format!("Includes({}, {})", c.left, c.right),
+    }
+}
+
+fn constraint(left: &str, relation: TimeRelation, right: &str) -> TimeConstraint {
+    TimeConstraint { left: left.to_string(), relation, right: right.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rendered(cs: &[TimeConstraint]) -> Vec<String> {
+        cs.iter().map(render).collect()
+    }
+
+    #[test]
+    fn past_perfect_puts_the_event_before_a_past_reference() {
+        let had = TenseAspect { time: Time::Past, perfect: true, progressive: false };
+        assert_eq!(rendered(&had.constraints("e1", "r1")), ["Precedes(r1, S)", "Precedes(e1, r1)"]);
+    }
+
+    #[test]
+    fn future_progressive_surrounds_a_future_reference() {
+        let will_be = TenseAspect { time: Time::Future, perfect: false, progressive: true };
+        assert_eq!(rendered(&will_be.constraints("e1", "r1")), ["Precedes(S, r1)", "Includes(e1, r1)"]);
+    }
+
+    #[test]
+    fn only_simple_tenses_advance_the_narrative() {
+        let simple = TenseAspect::simple(Time::Past);
+        let perfect = TenseAspect { perfect: true, ..simple };
+        let progressive = TenseAspect { progressive: true, ..simple };
+        let story = [
+            ("e1".to_string(), simple),
+            ("e2".to_string(), perfect),
+            ("e3".to_string(), progressive),
+            ("e4".to_string(), simple),
+        ];
+        assert_eq!(
+            rendered(&narrative_order(&story)),
+            ["Precedes(e2, e1)", "Includes(e3, e1)", "Precedes(e1, e4)"]
+        );
+    }
+}
@@ -0,0 +1,6 @@
+//! Generators for test corpora and generated learning content.
+//!
+//! Available with the `dynamic-lexicon` feature, since the generators draw
+//! their words from the runtime [`LexiconIndex`](crate::runtime_lexicon::LexiconIndex).
+
+pub mod sentence_gen;
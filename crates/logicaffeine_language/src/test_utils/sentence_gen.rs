@@ -0,0 +1,312 @@
+//! Grammatical sentences from templated patterns over the lexicon, each paired
+//! with the FOL the parser should produce for it.
+//!
+//! A pattern is a [`Quantifier`] for the subject × a Vendler [`VerbClass`] for
+//! the verb × a [`Construction`] for the rest of the clause: `Every` ×
+//! `State` × `ObjectSome` yields sentences like "Every dog owns some cat.".
+//! The expected FOL is built compositionally, in the simplified notation of
+//! [`compile_simple`](crate::compile_simple) — no event variables — including
+//! the hypernyms the axiom pass adds to a noun (`Dog(x) ∧ Animal(x) ∧ …`).
+//! It is written independently of the parser, so a sentence whose parse
+//! stops matching is a regression.
+//!
+//! Only words with one unambiguous reading are drawn: a common noun that is
+//! not also a verb or adjective, a verb with no canonical rewrite
+//! ("holds" → `Have`) or entailment, an intersective adjective. Word choice is
+//! deterministic — the `index` of a sentence picks its words — so a corpus is
+//! the same on every run, and the web learning content can draw fresh drills
+//! by index.
+
+use crate::intern::Interner;
+use crate::lexer::Lexer;
+use crate::lexicon::{is_adjective, is_base_verb, is_common_noun, lookup_canonical, lookup_keyword, VerbClass};
+use crate::runtime_lexicon::{present_3s, AdjectiveEntry, LexiconIndex, NounEntry, VerbEntry};
+use crate::semantics::{is_privative_adjective, lookup_noun_entailments, lookup_noun_hypernyms, lookup_verb_entailment};
+use crate::token::TokenType;
+
+/// How the subject is quantified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Quantifier {
+    /// "Every dog …" — `∀x((Dog(x) → …))`.
+    Every,
+    /// "Some dog …" — `∃x((Dog(x) ∧ …))`.
+    Some,
+    /// "No dog …" — `∀x((Dog(x) → ¬…))`.
+    No,
+    /// "John …" — the predicate applied to the name.
+    Name,
+}
+
+impl Quantifier {
+    /// Every quantifier, in declaration order.
+    pub const ALL: [Quantifier; 4] = [Quantifier::Every, Quantifier::Some, Quantifier::No, Quantifier::Name];
+}
+
+/// The shape of the clause after the subject.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Construction {
+    /// "… runs."
+    Intransitive,
+    /// "… does not run."
+    Negated,
+    /// "… sees Mary."
+    ObjectName,
+    /// "… sees every cat."
+    ObjectEvery,
+    /// "… sees some cat."
+    ObjectSome,
+    /// "Every happy dog runs." — an adjective on the subject noun.
+    Modified,
+}
+
+impl Construction {
+    /// Every construction, in declaration order.
+    pub const ALL: [Construction; 6] = [
+        Construction::Intransitive,
+        Construction::Negated,
+        Construction::ObjectName,
+        Construction::ObjectEvery,
+        Construction::ObjectSome,
+        Construction::Modified,
+    ];
+
+    /// Whether the construction takes a transitive verb.
+    pub fn is_transitive(self) -> bool {
+        matches!(self, Construction::ObjectName | Construction::ObjectEvery | Construction::ObjectSome)
+    }
+}
+
+/// Every Vendler class, in declaration order.
+pub const VERB_CLASSES: [VerbClass; 5] = [
+    VerbClass::State,
+    VerbClass::Activity,
+    VerbClass::Accomplishment,
+    VerbClass::Achievement,
+    VerbClass::Semelfactive,
+];
+
+/// One generated sentence, its expected FOL, and the pattern it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedSentence {
+    /// The English sentence, capitalized and ending in a period.
+    pub sentence: String,
+    /// The expected [`compile_simple`](crate::compile_simple) output.
+    pub expected_fol: String,
+    /// The subject's quantifier.
+    pub quantifier: Quantifier,
+    /// The Vendler class of the verb.
+    pub class: VerbClass,
+    /// The clause shape.
+    pub construction: Construction,
+}
+
+/// Fills patterns with words drawn from a lexicon.
+pub struct SentenceGenerator<'a> {
+    names: Vec<&'a NounEntry>,
+    nouns: Vec<&'a NounEntry>,
+    adjectives: Vec<&'a AdjectiveEntry>,
+    /// Intransitive and transitive verbs per class, indexed like [`VERB_CLASSES`].
+    intransitive: Vec<Vec<&'a VerbEntry>>,
+    transitive: Vec<Vec<&'a VerbEntry>>,
+}
+
+/// A single word, no spaces or hyphens, that is not a keyword ("many").
+fn plain_word(lemma: &str) -> bool {
+    !lemma.is_empty()
+        && lemma.chars().all(|c| c.is_ascii_alphabetic())
+        && lookup_keyword(&lemma.to_lowercase()).is_none()
+}
+
+impl<'a> SentenceGenerator<'a> {
+    /// Index the unambiguous words of `lexicon`.
+    pub fn new(lexicon: &'a LexiconIndex) -> Self {
+        let names = lexicon.proper_nouns().into_iter().filter(|n| plain_word(&n.lemma)).collect();
+        let nouns = lexicon
+            .common_nouns()
+            .into_iter()
+            .filter(|n| {
+                let word = n.lemma.to_lowercase();
+                plain_word(&n.lemma)
+                    && !n.features.iter().any(|f| f == "Mass" || f == "Collective")
+                    && is_common_noun(&word)
+                    && !is_base_verb(&word)
+                    && !is_adjective(&word)
+                    && lookup_canonical(&word).is_none()
+                    && lookup_noun_entailments(&word).is_empty()
+                    && lexes_as_noun(&word)
+            })
+            .collect();
+        let adjectives = lexicon
+            .intersective_adjectives()
+            .into_iter()
+            .filter(|a| {
+                let word = a.lemma.to_lowercase();
+                plain_word(&a.lemma)
+                    && !is_common_noun(&word)
+                    && !is_base_verb(&word)
+                    && !is_privative_adjective(&word)
+                    && lookup_canonical(&word).is_none()
+                    && lexes_as_adjective(&word)
+            })
+            .collect();
+
+        let verb_ok = |v: &&VerbEntry| {
+            let word = v.lemma.to_lowercase();
+            plain_word(&v.lemma)
+                // "exist" is read as the existential quantifier, not a predicate.
+                && !matches!(word.as_str(), "be" | "have" | "do" | "exist")
+                && !is_common_noun(&word)
+                && !is_adjective(&word)
+                && lookup_canonical(&word).is_none()
+                && lookup_verb_entailment(&word).is_none()
+                && lexes_as_verb(&present_3s(v))
+                && lexes_as_verb(&format!("does not {word}"))
+        };
+        let by_class = |features: &[&str]| -> Vec<Vec<&'a VerbEntry>> {
+            VERB_CLASSES
+                .iter()
+                .map(|class| {
+                    lexicon
+                        .verbs_with_class(&format!("{class:?}"))
+                        .into_iter()
+                        .filter(verb_ok)
+                        .filter(|v| v.features.len() == features.len() && features.iter().all(|f| v.features.iter().any(|g| g == f)))
+                        .collect()
+                })
+                .collect()
+        };
+        SentenceGenerator {
+            names,
+            nouns,
+            adjectives,
+            intransitive: by_class(&[]),
+            transitive: by_class(&["Transitive"]),
+        }
+    }
+
+    /// The `index`th sentence of a pattern, or `None` when the pattern has
+    /// no sentences: `No` × `Negated` (a double negative) and `Name` ×
+    /// `Modified` (a name takes no adjective) are not generated, nor is a
+    /// pattern whose class has no eligible verb.
+    pub fn sentence(
+        &self,
+        quantifier: Quantifier,
+        class: VerbClass,
+        construction: Construction,
+        index: usize,
+    ) -> Option<GeneratedSentence> {
+        use Construction::*;
+        if matches!((quantifier, construction), (Quantifier::No, Negated) | (Quantifier::Name, Modified)) {
+            return None;
+        }
+        let slot = VERB_CLASSES.iter().position(|c| *c == class)?;
+        let verbs = if construction.is_transitive() { &self.transitive[slot] } else { &self.intransitive[slot] };
+        let verb = pick(verbs, index, 0)?;
+        let subject_noun = pick(&self.nouns, index, 1)?;
+        let object_noun = pick(&self.nouns, index, 2)?;
+        let name = pick(&self.names, index, 0)?;
+        let object_name = pick(&self.names, index + 1, 0)?;
+        let adjective = pick(&self.adjectives, index, 3)?;
+
+        // The subject: its words, and the variable (or name) the body predicates of.
+        let (subject_words, subject) = match quantifier {
+            Quantifier::Every => ("Every", "x"),
+            Quantifier::Some => ("Some", "x"),
+            Quantifier::No => ("No", "x"),
+            Quantifier::Name => ("", name.lemma.as_str()),
+        };
+        let object_var = if quantifier == Quantifier::Name { "x" } else { "y" };
+        let lemma = &verb.lemma;
+        let verb_3s = present_3s(verb);
+
+        let (predicate, body) = match construction {
+            Intransitive | Modified => (verb_3s.clone(), format!("{lemma}({subject})")),
+            Negated => (format!("does not {}", lemma.to_lowercase()), format!("¬{lemma}({subject})")),
+            ObjectName => (
+                format!("{verb_3s} {}", object_name.lemma),
+                format!("{lemma}({subject}, {})", object_name.lemma),
+            ),
+            ObjectEvery => (
+                format!("{verb_3s} every {}", object_noun.lemma.to_lowercase()),
+                format!("∀{object_var}(({} → {lemma}({subject}, {object_var})))", restrictor(object_noun, object_var)),
+            ),
+            ObjectSome => (
+                format!("{verb_3s} some {}", object_noun.lemma.to_lowercase()),
+                format!("∃{object_var}(({} ∧ {lemma}({subject}, {object_var})))", restrictor(object_noun, object_var)),
+            ),
+        };
+
+        let (sentence, expected_fol) = if quantifier == Quantifier::Name {
+            (format!("{} {predicate}.", name.lemma), body)
+        } else {
+            let mut noun_words = subject_noun.lemma.to_lowercase();
+            let mut restrict = restrictor(subject_noun, "x");
+            if construction == Modified {
+                noun_words = format!("{} {noun_words}", adjective.lemma.to_lowercase());
+                restrict = format!("({}(x) ∧ {restrict})", adjective.lemma);
+            }
+            let fol = match quantifier {
+                Quantifier::Every => format!("∀x(({restrict} → {body}))"),
+                Quantifier::Some => format!("∃x(({restrict} ∧ {body}))"),
+                _ => format!("∀x(({restrict} → ¬{body}))"),
+            };
+            (format!("{subject_words} {noun_words} {predicate}."), fol)
+        };
+        Some(GeneratedSentence { sentence, expected_fol, quantifier, class, construction })
+    }
+
+    /// `per_pattern` sentences of every pattern, quantifier-major.
+    pub fn corpus(&self, per_pattern: usize) -> Vec<GeneratedSentence> {
+        let mut corpus = Vec::new();
+        for quantifier in Quantifier::ALL {
+            for class in VERB_CLASSES {
+                for construction in Construction::ALL {
+                    corpus.extend((0..per_pattern).filter_map(|i| self.sentence(quantifier, class, construction, i)));
+                }
+            }
+        }
+        corpus
+    }
+}
+
+/// Whether `words` ends in a plain verb token after a name subject — not a
+/// keyword, performative or copula the parser treats specially.
+fn lexes_as_verb(words: &str) -> bool {
+    let mut interner = Interner::new();
+    let tokens = Lexer::new(&format!("John {words}."), &mut interner).tokenize();
+    let last = tokens.iter().rev().find(|t| !matches!(t.kind, TokenType::Period | TokenType::EOF));
+    matches!(last.map(|t| &t.kind), Some(TokenType::Verb { .. }))
+}
+
+/// Whether `adjective` lexes as an intersective adjective before a noun,
+/// rather than a participle that compounds with it ("worried sentence").
+fn lexes_as_adjective(adjective: &str) -> bool {
+    let mut interner = Interner::new();
+    let tokens = Lexer::new(&format!("Every {adjective} dog runs."), &mut interner).tokenize();
+    matches!(tokens.get(1).map(|t| &t.kind), Some(TokenType::Adjective(_)))
+}
+
+/// Whether `noun` lexes as a plain noun after a modifier — not a temporal
+/// noun or a word the parser reads as the start of a clause.
+fn lexes_as_noun(noun: &str) -> bool {
+    let mut interner = Interner::new();
+    let tokens = Lexer::new(&format!("Every red {noun} runs."), &mut interner).tokenize();
+    matches!(tokens.get(2).map(|t| &t.kind), Some(TokenType::Noun(_)))
+}
+
+/// The `index`th word of a pool, offset per slot so the words of one
+/// sentence come from different parts of the pool.
+fn pick<T: Copy>(pool: &[T], index: usize, slot: usize) -> Option<T> {
+    if pool.is_empty() {
+        return None;
+    }
+    Some(pool[(index * 7 + slot * pool.len() / 4) % pool.len()])
+}
+
+/// The noun's predicate on `var`, conjoined with its hypernyms the way the
+/// axiom pass expands it: `((Dog(x) ∧ Animal(x)) ∧ Mammal(x))`.
+fn restrictor(noun: &NounEntry, var: &str) -> String {
+    lookup_noun_hypernyms(&noun.lemma.to_lowercase())
+        .iter()
+        .fold(format!("{}({var})", noun.lemma), |acc, h| format!("({acc} ∧ {h}({var}))"))
+}
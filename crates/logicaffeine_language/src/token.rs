@@ -28,7 +28,7 @@
 use logicaffeine_base::Symbol;
 use logicaffeine_lexicon::{Aspect, Case, Definiteness, Gender, Number, Time, VerbClass};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 pub struct Span {
     pub start: usize,
     pub end: usize,
@@ -50,7 +50,7 @@ pub enum PresupKind {
     Know,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum FocusKind {
     Only,
     Even,
@@ -123,6 +123,12 @@ pub enum BlockType {
     Policy,
     /// `## Requires` - External crate dependency declarations.
     Requires,
+    /// `## Settings` - The typed schema of the program's configuration, loaded
+    /// at run time with `Read config from settings "app.toml".`
+    Settings,
+    /// `## Arguments` - The program's command-line flags, options and positional
+    /// parameters, parsed at run time with `Read cli from arguments.`
+    Arguments,
     /// `## Hardware` - Signal declarations for hardware verification.
     Hardware,
     /// `## Property` - Temporal assertions for hardware verification.
@@ -0,0 +1,244 @@
+//! Derivation traces — "why did it parse this way?"
+//!
+//! With tracing switched on ([`Parser::enable_trace`](crate::Parser::enable_trace)),
+//! every grammar rule the recursive descent enters is recorded as a
+//! [`RuleApplication`]: the rule's name, the tokens it consumed, whether it
+//! succeeded, and the rules it called in turn. The result is a tree that
+//! mirrors the derivation, ready for the Studio to replay step by step.
+//!
+//! Failed attempts stay in the tree. A rule that fails outright is
+//! [`RuleOutcome::Failed`]; one that succeeded but whose tokens were later
+//! handed back by a backtracking caller is [`RuleOutcome::Backtracked`]. Those
+//! dead ends are the interesting part when teaching *why* the parser settled
+//! on the reading it did. Rules that succeed without consuming anything
+//! (a construction that merely checked and declined) are dropped as noise.
+//!
+//! Alongside the rules, the trace lists the named branches the parser took
+//! inside them — which sentence-level construction matched, which determiner
+//! opened a quantified phrase — the finer grain [`crate::coverage`] counts.
+//!
+//! Tracing is off by default and costs one `Option` check per rule when off.
+//!
+//! ```rust
+//! use logicaffeine_language::compile_with_trace;
+//!
+//! let (fol, trace) = compile_with_trace("Every man is mortal.");
+//! assert!(fol.is_ok());
+//! assert!(trace.rules().any(|rule| rule.rule == "quantified"));
+//! ```
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::token::Span;
+
+/// How a rule application ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleOutcome {
+    /// The rule succeeded and its reading is part of the final parse.
+    Applied,
+    /// The rule succeeded, but a caller rewound past it to try another reading.
+    Backtracked,
+    /// The rule returned an error.
+    Failed,
+}
+
+/// One grammar rule firing over a stretch of tokens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleApplication {
+    /// The rule's name ("sentence", "noun phrase", "quantified", …).
+    pub rule: &'static str,
+    /// Indices into the token stream the rule consumed.
+    pub tokens: Range<usize>,
+    /// Source span of the consumed tokens (empty when none were consumed).
+    pub span: Span,
+    /// The consumed words, space-separated.
+    pub text: String,
+    pub outcome: RuleOutcome,
+    /// Rules this one invoked, in call order.
+    pub children: Vec<RuleApplication>,
+}
+
+impl RuleApplication {
+    /// This application and all of its descendants, depth-first.
+    pub fn walk(&self) -> impl Iterator<Item = &RuleApplication> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let next = stack.pop()?;
+            stack.extend(next.children.iter().rev());
+            Some(next)
+        })
+    }
+}
+
+/// The tree of rule applications behind one parse.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DerivationTrace {
+    /// Top-level rule applications (normally one "discourse").
+    pub roots: Vec<RuleApplication>,
+    /// Branches taken inside rules (`"sentence/cleft"`), in the order taken,
+    /// those of backtracked attempts included.
+    pub branches: Vec<&'static str>,
+}
+
+impl DerivationTrace {
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    /// Every rule application, depth-first in derivation order.
+    pub fn rules(&self) -> impl Iterator<Item = &RuleApplication> {
+        self.roots.iter().flat_map(RuleApplication::walk)
+    }
+}
+
+impl fmt::Display for DerivationTrace {
+    /// An indented outline, one rule per line: `✓` applied, `↺` backtracked,
+    /// `✗` failed.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_node(f: &mut fmt::Formatter<'_>, node: &RuleApplication, depth: usize) -> fmt::Result {
+            let mark = match node.outcome {
+                RuleOutcome::Applied => '✓',
+                RuleOutcome::Backtracked => '↺',
+                RuleOutcome::Failed => '✗',
+            };
+            write!(f, "{:indent$}{} {}", "", mark, node.rule, indent = depth * 2)?;
+            if !node.text.is_empty() {
+                write!(f, ": {}", node.text)?;
+            }
+            writeln!(f)?;
+            node.children.iter().try_for_each(|child| write_node(f, child, depth + 1))
+        }
+        self.roots.iter().try_for_each(|root| write_node(f, root, 0))
+    }
+}
+
+/// Builds the trace while the parser runs: a stack of open rules whose
+/// children accumulate until the rule returns.
+#[derive(Debug, Default)]
+pub(crate) struct TraceRecorder {
+    open: Vec<(&'static str, usize, Vec<RuleApplication>)>,
+    roots: Vec<RuleApplication>,
+    branches: Vec<&'static str>,
+}
+
+impl TraceRecorder {
+    pub(crate) fn enter(&mut self, rule: &'static str, first_token: usize) {
+        self.open.push((rule, first_token, Vec::new()));
+    }
+
+    /// Closes the innermost open rule. `text` and `span` describe the tokens
+    /// `first_token..end_token` it consumed.
+    pub(crate) fn exit(&mut self, end_token: usize, span: Span, text: String, succeeded: bool) {
+        let Some((rule, first_token, children)) = self.open.pop() else { return };
+        let tokens = first_token..end_token.max(first_token);
+        if succeeded && tokens.is_empty() && children.is_empty() {
+            return;
+        }
+        let outcome = if succeeded { RuleOutcome::Applied } else { RuleOutcome::Failed };
+        let node = RuleApplication { rule, tokens, span, text, outcome, children };
+        match self.open.last_mut() {
+            Some((_, _, siblings)) => siblings.push(node),
+            None => self.roots.push(node),
+        }
+    }
+
+    pub(crate) fn branch(&mut self, branch: &'static str) {
+        self.branches.push(branch);
+    }
+
+    /// The parser rewound to `position`: finished siblings that consumed
+    /// tokens at or past it were abandoned.
+    pub(crate) fn rewind(&mut self, position: usize) {
+        let siblings = match self.open.last_mut() {
+            Some((_, _, children)) => children,
+            None => &mut self.roots,
+        };
+        for node in siblings.iter_mut().rev() {
+            if node.tokens.end <= position {
+                break;
+            }
+            if node.outcome == RuleOutcome::Applied {
+                node.outcome = RuleOutcome::Backtracked;
+            }
+        }
+    }
+
+    /// The finished trace. Rules still open (an aborted parse) are closed as
+    /// failures so nothing recorded is lost.
+    pub(crate) fn finish(mut self) -> DerivationTrace {
+        while let Some((_, first_token, _)) = self.open.last() {
+            let first_token = *first_token;
+            self.exit(first_token, Span::default(), String::new(), false);
+        }
+        DerivationTrace { roots: self.roots, branches: self.branches }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn closed(recorder: &mut TraceRecorder, end: usize, succeeded: bool) {
+        recorder.exit(end, Span::default(), String::new(), succeeded);
+    }
+
+    #[test]
+    fn nested_rules_build_a_tree_in_call_order() {
+        let mut recorder = TraceRecorder::default();
+        recorder.enter("sentence", 0);
+        recorder.enter("noun phrase", 0);
+        closed(&mut recorder, 1, true);
+        recorder.enter("predicate", 1);
+        closed(&mut recorder, 3, true);
+        closed(&mut recorder, 3, true);
+        let trace = recorder.finish();
+
+        let names: Vec<_> = trace.rules().map(|r| r.rule).collect();
+        assert_eq!(names, ["sentence", "noun phrase", "predicate"]);
+        assert_eq!(trace.roots[0].tokens, 0..3);
+    }
+
+    #[test]
+    fn empty_successes_are_dropped_but_failures_kept() {
+        let mut recorder = TraceRecorder::default();
+        recorder.enter("sentence", 0);
+        recorder.enter("focus", 0);
+        closed(&mut recorder, 0, true);
+        recorder.enter("modal", 0);
+        closed(&mut recorder, 0, false);
+        closed(&mut recorder, 2, true);
+        let trace = recorder.finish();
+
+        let children: Vec<_> = trace.roots[0].children.iter().map(|r| (r.rule, r.outcome)).collect();
+        assert_eq!(children, [("modal", RuleOutcome::Failed)]);
+    }
+
+    #[test]
+    fn rewinding_marks_abandoned_siblings_backtracked() {
+        let mut recorder = TraceRecorder::default();
+        recorder.enter("sentence", 0);
+        recorder.enter("noun phrase", 0);
+        closed(&mut recorder, 2, true);
+        recorder.enter("noun phrase", 2);
+        closed(&mut recorder, 4, true);
+        recorder.rewind(2);
+        closed(&mut recorder, 4, true);
+        let trace = recorder.finish();
+
+        let outcomes: Vec<_> = trace.roots[0].children.iter().map(|r| r.outcome).collect();
+        assert_eq!(outcomes, [RuleOutcome::Applied, RuleOutcome::Backtracked]);
+    }
+
+    #[test]
+    fn finishing_mid_parse_closes_open_rules_as_failed() {
+        let mut recorder = TraceRecorder::default();
+        recorder.enter("discourse", 0);
+        recorder.enter("sentence", 0);
+        let trace = recorder.finish();
+
+        assert_eq!(trace.roots.len(), 1);
+        assert_eq!(trace.roots[0].outcome, RuleOutcome::Failed);
+        assert_eq!(trace.roots[0].children[0].outcome, RuleOutcome::Failed);
+    }
+}
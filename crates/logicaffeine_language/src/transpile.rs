@@ -28,7 +28,7 @@ use std::fmt::Write;
 
 use crate::ast::{LogicExpr, NounPhrase, Term, QuantifierKind};
 use crate::ast::logic::NumberKind;
-use crate::formatter::{KripkeFormatter, LatexFormatter, LogicFormatter, SimpleFOLFormatter, UnicodeFormatter};
+use crate::formatter::{custom_formatter, KripkeFormatter, LatexFormatter, LogicFormatter, SimpleFOLFormatter, UnicodeFormatter};
 use logicaffeine_base::{Interner, Symbol};
 use crate::registry::SymbolRegistry;
 use crate::token::TokenType;
@@ -342,7 +342,7 @@ impl<'a> LogicExpr<'a> {
         result
     }
 
-    pub fn write_logic<W: Write, F: LogicFormatter>(
+    pub fn write_logic<W: Write, F: LogicFormatter + ?Sized>(
         &self,
         w: &mut W,
         registry: &mut SymbolRegistry,
@@ -889,7 +889,7 @@ impl<'a> LogicExpr<'a> {
     }
 
     /// Transpiles to a logic formula string using a custom formatter.
-    pub fn transpile_with<F: LogicFormatter>(
+    pub fn transpile_with<F: LogicFormatter + ?Sized>(
         &self,
         registry: &mut SymbolRegistry,
         interner: &Interner,
@@ -908,6 +908,7 @@ impl<'a> LogicExpr<'a> {
     /// - [`OutputFormat::LaTeX`]: `\forall x(Cat(x) \to Sleeps(x))`
     /// - [`OutputFormat::SimpleFOL`]: Ax(Cat(x) -> Sleeps(x))
     /// - [`OutputFormat::Kripke`]: □(P) @w0
+    /// - [`OutputFormat::Custom`]: whatever the registered formatter writes
     pub fn transpile(
         &self,
         registry: &mut SymbolRegistry,
@@ -919,11 +920,15 @@ impl<'a> LogicExpr<'a> {
             OutputFormat::LaTeX => self.transpile_with(registry, interner, &LatexFormatter),
             OutputFormat::SimpleFOL => self.transpile_with(registry, interner, &SimpleFOLFormatter),
             OutputFormat::Kripke => self.transpile_with(registry, interner, &KripkeFormatter),
+            OutputFormat::Custom(name) => match custom_formatter(name) {
+                Some(formatter) => self.transpile_with(registry, interner, &*formatter),
+                None => self.transpile_with(registry, interner, &UnicodeFormatter),
+            },
         }
     }
 
     /// Transpiles using a [`TranspileContext`] and custom formatter.
-    pub fn transpile_ctx<F: LogicFormatter>(
+    pub fn transpile_ctx<F: LogicFormatter + ?Sized>(
         &self,
         ctx: &mut TranspileContext<'_>,
         fmt: &F,
@@ -1,8 +1,13 @@
-//! AST visitor pattern for traversing logical expressions.
+//! AST visitor pattern for traversing and rewriting logical expressions.
 //!
-//! This module provides a visitor trait for walking the AST without mutation.
-//! It follows the standard visitor pattern with `walk_*` functions that handle
-//! recursive traversal and `visit_*` methods that can be overridden.
+//! This module provides a visitor trait for walking the AST without mutation,
+//! and a [`Folder`] trait for rewriting it. Both follow the standard visitor
+//! pattern: `walk_*` functions handle the recursive traversal, and the
+//! `visit_*` / `fold_*` methods can be overridden.
+//!
+//! Folding never mutates the input tree — it rebuilds the nodes it walks in
+//! the arenas of a caller-provided [`AstContext`]. [`substitute`] is built on
+//! it: capture-avoiding substitution of a term for a free variable.
 //!
 //! # Usage
 //!
@@ -25,7 +30,43 @@
 //!     }
 //! }
 //! ```
+//!
+//! To rewrite, implement [`Folder`] and override the `fold_*` methods whose
+//! nodes change; everything else is copied through:
+//!
+//! ```no_run
+//! # use logicaffeine_base::Symbol;
+//! # use logicaffeine_language::ast::{LogicExpr, Term};
+//! # use logicaffeine_language::AstContext;
+//! # use logicaffeine_language::visitor::{Folder, walk_fold_expr};
+//! struct RenamePredicate<'a> {
+//!     ctx: AstContext<'a>,
+//!     from: Symbol,
+//!     to: Symbol,
+//! }
+//!
+//! impl<'a> Folder<'a> for RenamePredicate<'a> {
+//!     fn ctx(&self) -> AstContext<'a> {
+//!         self.ctx
+//!     }
+//!
+//!     fn fold_expr(&mut self, expr: &'a LogicExpr<'a>) -> &'a LogicExpr<'a> {
+//!         match expr {
+//!             LogicExpr::Predicate { name, args, world } if *name == self.from => {
+//!                 let args: Vec<Term<'a>> = args.iter().map(|arg| self.fold_term(arg)).collect();
+//!                 let args = self.ctx.alloc_terms(args);
+//!                 self.ctx.alloc_expr(LogicExpr::Predicate { name: self.to, args, world: *world })
+//!             }
+//!             _ => walk_fold_expr(self, expr),
+//!         }
+//!     }
+//! }
+//! ```
 
+use logicaffeine_base::{Interner, Symbol};
+
+use crate::arena_ctx::AstContext;
+use crate::ast::logic::{CategoricalData, NeoEventData, RelationData};
 use crate::ast::{LogicExpr, NounPhrase, Term};
 
 /// Trait for visiting AST nodes.
@@ -170,9 +211,12 @@ pub fn walk_expr<'a, V: Visitor<'a>>(v: &mut V, expr: &'a LogicExpr<'a>) {
             v.visit_expr(concession);
         }
 
-        LogicExpr::Comparative { subject, object, .. } => {
+        LogicExpr::Comparative { subject, object, difference, .. } => {
             v.visit_term(subject);
             v.visit_term(object);
+            if let Some(difference) = difference {
+                v.visit_term(difference);
+            }
         }
 
         LogicExpr::Superlative { subject, .. } => {
@@ -251,10 +295,368 @@ pub fn walk_np<'a, V: Visitor<'a>>(v: &mut V, np: &'a NounPhrase<'a>) {
     }
 }
 
+/// Trait for rewriting AST nodes into a caller-provided arena.
+///
+/// Each `fold_*` method returns the rewritten node; the defaults rebuild the
+/// node from its folded children via the matching `walk_fold_*` function.
+pub trait Folder<'a>: Sized {
+    /// The arenas rewritten nodes are allocated in.
+    fn ctx(&self) -> AstContext<'a>;
+
+    fn fold_expr(&mut self, expr: &'a LogicExpr<'a>) -> &'a LogicExpr<'a> {
+        walk_fold_expr(self, expr)
+    }
+
+    fn fold_term(&mut self, term: &'a Term<'a>) -> Term<'a> {
+        walk_fold_term(self, term)
+    }
+
+    fn fold_np(&mut self, np: &'a NounPhrase<'a>) -> NounPhrase<'a> {
+        walk_fold_np(self, np)
+    }
+}
+
+fn fold_terms<'a, F: Folder<'a>>(f: &mut F, terms: &'a [Term<'a>]) -> &'a [Term<'a>] {
+    let folded: Vec<Term<'a>> = terms.iter().map(|term| f.fold_term(term)).collect();
+    f.ctx().alloc_terms(folded)
+}
+
+fn fold_term_ref<'a, F: Folder<'a>>(f: &mut F, term: &'a Term<'a>) -> &'a Term<'a> {
+    let folded = f.fold_term(term);
+    f.ctx().alloc_term(folded)
+}
+
+pub fn walk_fold_expr<'a, F: Folder<'a>>(f: &mut F, expr: &'a LogicExpr<'a>) -> &'a LogicExpr<'a> {
+    let folded = match expr {
+        LogicExpr::Predicate { name, args, world } => LogicExpr::Predicate {
+            name: *name,
+            args: fold_terms(f, args),
+            world: *world,
+        },
+        LogicExpr::Identity { left, right } => LogicExpr::Identity {
+            left: fold_term_ref(f, left),
+            right: fold_term_ref(f, right),
+        },
+        LogicExpr::Metaphor { tenor, vehicle } => LogicExpr::Metaphor {
+            tenor: fold_term_ref(f, tenor),
+            vehicle: fold_term_ref(f, vehicle),
+        },
+        LogicExpr::Quantifier { kind, variable, body, island_id } => LogicExpr::Quantifier {
+            kind: *kind,
+            variable: *variable,
+            body: f.fold_expr(body),
+            island_id: *island_id,
+        },
+        LogicExpr::Categorical(data) => LogicExpr::Categorical(Box::new(CategoricalData {
+            quantifier: data.quantifier.clone(),
+            subject: f.fold_np(&data.subject),
+            copula_negative: data.copula_negative,
+            predicate: f.fold_np(&data.predicate),
+        })),
+        LogicExpr::Relation(data) => LogicExpr::Relation(Box::new(RelationData {
+            subject: f.fold_np(&data.subject),
+            verb: data.verb,
+            object: f.fold_np(&data.object),
+        })),
+        LogicExpr::Modal { vector, operand } => LogicExpr::Modal { vector: *vector, operand: f.fold_expr(operand) },
+        LogicExpr::Temporal { operator, body } => LogicExpr::Temporal { operator: *operator, body: f.fold_expr(body) },
+        LogicExpr::TemporalBinary { operator, left, right } => LogicExpr::TemporalBinary {
+            operator: *operator,
+            left: f.fold_expr(left),
+            right: f.fold_expr(right),
+        },
+        LogicExpr::Aspectual { operator, body } => LogicExpr::Aspectual { operator: *operator, body: f.fold_expr(body) },
+        LogicExpr::Voice { operator, body } => LogicExpr::Voice { operator: *operator, body: f.fold_expr(body) },
+        LogicExpr::BinaryOp { left, op, right } => LogicExpr::BinaryOp {
+            left: f.fold_expr(left),
+            op: op.clone(),
+            right: f.fold_expr(right),
+        },
+        LogicExpr::UnaryOp { op, operand } => LogicExpr::UnaryOp { op: op.clone(), operand: f.fold_expr(operand) },
+        LogicExpr::Question { wh_variable, body } => LogicExpr::Question { wh_variable: *wh_variable, body: f.fold_expr(body) },
+        LogicExpr::YesNoQuestion { body } => LogicExpr::YesNoQuestion { body: f.fold_expr(body) },
+        LogicExpr::Atom(symbol) => LogicExpr::Atom(*symbol),
+        LogicExpr::Lambda { variable, body } => LogicExpr::Lambda { variable: *variable, body: f.fold_expr(body) },
+        LogicExpr::App { function, argument } => LogicExpr::App {
+            function: f.fold_expr(function),
+            argument: f.fold_expr(argument),
+        },
+        LogicExpr::Intensional { operator, content } => LogicExpr::Intensional { operator: *operator, content: f.fold_expr(content) },
+        LogicExpr::Event { predicate, adverbs } => LogicExpr::Event { predicate: f.fold_expr(predicate), adverbs },
+        LogicExpr::NeoEvent(data) => {
+            let roles: Vec<_> = data.roles.iter().map(|(role, term)| (*role, f.fold_term(term))).collect();
+            LogicExpr::NeoEvent(Box::new(NeoEventData {
+                event_var: data.event_var,
+                verb: data.verb,
+                roles: f.ctx().alloc_roles(roles),
+                modifiers: data.modifiers,
+                suppress_existential: data.suppress_existential,
+                world: data.world,
+            }))
+        }
+        LogicExpr::Imperative { action } => LogicExpr::Imperative { action: f.fold_expr(action) },
+        LogicExpr::Exclamative { degree_var, body } => LogicExpr::Exclamative { degree_var: *degree_var, body: f.fold_expr(body) },
+        LogicExpr::Optative { wish } => LogicExpr::Optative { wish: f.fold_expr(wish) },
+        LogicExpr::Implicature { assertion, implicature } => LogicExpr::Implicature {
+            assertion: f.fold_expr(assertion),
+            implicature: f.fold_expr(implicature),
+        },
+        LogicExpr::SpeechAct { performer, act_type, content } => LogicExpr::SpeechAct {
+            performer: *performer,
+            act_type: *act_type,
+            content: f.fold_expr(content),
+        },
+        LogicExpr::Counterfactual { antecedent, consequent } => LogicExpr::Counterfactual {
+            antecedent: f.fold_expr(antecedent),
+            consequent: f.fold_expr(consequent),
+        },
+        LogicExpr::Causal { effect, cause } => LogicExpr::Causal { effect: f.fold_expr(effect), cause: f.fold_expr(cause) },
+        LogicExpr::Concessive { main, concession } => LogicExpr::Concessive {
+            main: f.fold_expr(main),
+            concession: f.fold_expr(concession),
+        },
+        LogicExpr::Comparative { adjective, subject, object, difference, relation } => LogicExpr::Comparative {
+            adjective: *adjective,
+            subject: fold_term_ref(f, subject),
+            object: fold_term_ref(f, object),
+            difference: difference.map(|difference| fold_term_ref(f, difference)),
+            relation: *relation,
+        },
+        LogicExpr::Superlative { adjective, subject, domain } => LogicExpr::Superlative {
+            adjective: *adjective,
+            subject: fold_term_ref(f, subject),
+            domain: *domain,
+        },
+        LogicExpr::Scopal { operator, body } => LogicExpr::Scopal { operator: *operator, body: f.fold_expr(body) },
+        LogicExpr::Control { verb, subject, object, infinitive } => LogicExpr::Control {
+            verb: *verb,
+            subject: fold_term_ref(f, subject),
+            object: object.map(|object| fold_term_ref(f, object)),
+            infinitive: f.fold_expr(infinitive),
+        },
+        LogicExpr::Presupposition { assertion, presupposition } => LogicExpr::Presupposition {
+            assertion: f.fold_expr(assertion),
+            presupposition: f.fold_expr(presupposition),
+        },
+        LogicExpr::Focus { kind, focused, scope } => LogicExpr::Focus {
+            kind: *kind,
+            focused: fold_term_ref(f, focused),
+            scope: f.fold_expr(scope),
+        },
+        LogicExpr::TemporalAnchor { anchor, body } => LogicExpr::TemporalAnchor { anchor: *anchor, body: f.fold_expr(body) },
+        LogicExpr::Distributive { predicate } => LogicExpr::Distributive { predicate: f.fold_expr(predicate) },
+        LogicExpr::GroupQuantifier { group_var, count, member_var, restriction, body } => LogicExpr::GroupQuantifier {
+            group_var: *group_var,
+            count: *count,
+            member_var: *member_var,
+            restriction: f.fold_expr(restriction),
+            body: f.fold_expr(body),
+        },
+    };
+    f.ctx().alloc_expr(folded)
+}
+
+pub fn walk_fold_term<'a, F: Folder<'a>>(f: &mut F, term: &'a Term<'a>) -> Term<'a> {
+    match term {
+        Term::Constant(_) | Term::Variable(_) | Term::Sigma(_) | Term::Intension(_) | Term::Kind(_) | Term::Value { .. } => *term,
+        Term::Function(name, args) => Term::Function(*name, fold_terms(f, args)),
+        Term::Group(members) => Term::Group(fold_terms(f, members)),
+        Term::Possessed { possessor, possessed } => Term::Possessed {
+            possessor: fold_term_ref(f, possessor),
+            possessed: *possessed,
+        },
+        Term::Proposition(expr) => Term::Proposition(f.fold_expr(expr)),
+    }
+}
+
+pub fn walk_fold_np<'a, F: Folder<'a>>(f: &mut F, np: &'a NounPhrase<'a>) -> NounPhrase<'a> {
+    let possessor = np.possessor.map(|possessor| {
+        let folded = f.fold_np(possessor);
+        f.ctx().alloc_np(folded)
+    });
+    let pps: Vec<&'a LogicExpr<'a>> = np.pps.iter().map(|pp| f.fold_expr(pp)).collect();
+    NounPhrase { possessor, pps: f.ctx().alloc_pps(pps), ..*np }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// Variables and Substitution
+// ═══════════════════════════════════════════════════════════════════
+
+/// The variables an expression binds at its root: quantifiers, lambdas,
+/// wh-questions, exclamatives and group quantifiers.
+fn binders(expr: &LogicExpr) -> Vec<Symbol> {
+    match expr {
+        LogicExpr::Quantifier { variable, .. } | LogicExpr::Lambda { variable, .. } => vec![*variable],
+        LogicExpr::Question { wh_variable, .. } => vec![*wh_variable],
+        LogicExpr::Exclamative { degree_var, .. } => vec![*degree_var],
+        LogicExpr::GroupQuantifier { group_var, member_var, .. } => vec![*group_var, *member_var],
+        _ => Vec::new(),
+    }
+}
+
+struct VariableCollector {
+    bound: Vec<Symbol>,
+    found: Vec<Symbol>,
+    free_only: bool,
+}
+
+impl<'a> Visitor<'a> for VariableCollector {
+    fn visit_expr(&mut self, expr: &'a LogicExpr<'a>) {
+        let binders = binders(expr);
+        if !self.free_only {
+            for binder in &binders {
+                if !self.found.contains(binder) {
+                    self.found.push(*binder);
+                }
+            }
+        }
+        let depth = self.bound.len();
+        self.bound.extend(binders);
+        walk_expr(self, expr);
+        self.bound.truncate(depth);
+    }
+
+    fn visit_term(&mut self, term: &'a Term<'a>) {
+        if let Term::Variable(var) = term {
+            let counts = !self.free_only || !self.bound.contains(var);
+            if counts && !self.found.contains(var) {
+                self.found.push(*var);
+            }
+        }
+        walk_term(self, term);
+    }
+}
+
+/// The variables that occur free in `expr`, in order of first occurrence.
+pub fn free_variables<'a>(expr: &'a LogicExpr<'a>) -> Vec<Symbol> {
+    let mut collector = VariableCollector { bound: Vec::new(), found: Vec::new(), free_only: true };
+    collector.visit_expr(expr);
+    collector.found
+}
+
+/// The variables that occur free in `term`, in order of first occurrence.
+pub fn term_free_variables<'a>(term: &'a Term<'a>) -> Vec<Symbol> {
+    let mut collector = VariableCollector { bound: Vec::new(), found: Vec::new(), free_only: true };
+    collector.visit_term(term);
+    collector.found
+}
+
+/// Replace the free occurrences of `var` in `expr` with `replacement`.
+///
+/// Substitution is capture-avoiding: a binder under which `var` occurs free
+/// and which would capture a free variable of `replacement` is renamed to a
+/// fresh variable (`x` becomes `x1`, `x2`, …) first. Binders of `var` itself
+/// shadow it, and subtrees without a free `var` are returned as they are.
+pub fn substitute<'a>(
+    expr: &'a LogicExpr<'a>,
+    var: Symbol,
+    replacement: &'a Term<'a>,
+    ctx: AstContext<'a>,
+    interner: &mut Interner,
+) -> &'a LogicExpr<'a> {
+    let replacement_free = term_free_variables(replacement);
+    Substitution { ctx, interner, var, replacement, replacement_free }.fold_expr(expr)
+}
+
+struct Substitution<'a, 'i> {
+    ctx: AstContext<'a>,
+    interner: &'i mut Interner,
+    var: Symbol,
+    replacement: &'a Term<'a>,
+    replacement_free: Vec<Symbol>,
+}
+
+impl<'a> Substitution<'a, '_> {
+    /// A variable named after `binder` that clashes with nothing in `scope`
+    /// or in the replacement.
+    fn fresh(&mut self, binder: Symbol, scope: &'a LogicExpr<'a>) -> Symbol {
+        let mut taken = self.replacement_free.clone();
+        let mut collector = VariableCollector { bound: Vec::new(), found: Vec::new(), free_only: false };
+        collector.visit_expr(scope);
+        taken.extend(collector.found);
+
+        let base = self.interner.resolve(binder).to_string();
+        (1..)
+            .map(|n| self.interner.intern(&format!("{base}{n}")))
+            .find(|candidate| !taken.contains(candidate))
+            .expect("an unbounded range always yields a fresh name")
+    }
+
+    fn rename(&mut self, expr: &'a LogicExpr<'a>, from: Symbol, to: Symbol) -> &'a LogicExpr<'a> {
+        let to = self.ctx.alloc_term(Term::Variable(to));
+        substitute(expr, from, to, self.ctx, self.interner)
+    }
+
+    /// Rename every binder of `expr` that would capture the replacement,
+    /// returning the binders' new names and the renamed sub-expressions.
+    fn rebind<const N: usize>(
+        &mut self,
+        expr: &'a LogicExpr<'a>,
+        mut binders: [Symbol; N],
+        mut scopes: [&'a LogicExpr<'a>; N],
+    ) -> ([Symbol; N], [&'a LogicExpr<'a>; N]) {
+        for binder in binders.iter_mut() {
+            if self.replacement_free.contains(binder) {
+                let fresh = self.fresh(*binder, expr);
+                for scope in scopes.iter_mut() {
+                    *scope = self.rename(scope, *binder, fresh);
+                }
+                *binder = fresh;
+            }
+        }
+        (binders, scopes.map(|scope| self.fold_expr(scope)))
+    }
+}
+
+impl<'a> Folder<'a> for Substitution<'a, '_> {
+    fn ctx(&self) -> AstContext<'a> {
+        self.ctx
+    }
+
+    fn fold_expr(&mut self, expr: &'a LogicExpr<'a>) -> &'a LogicExpr<'a> {
+        if binders(expr).contains(&self.var) || !free_variables(expr).contains(&self.var) {
+            return expr;
+        }
+        let rebound = match expr {
+            LogicExpr::Quantifier { kind, variable, body, island_id } => {
+                let ([variable], [body]) = self.rebind(expr, [*variable], [*body]);
+                LogicExpr::Quantifier { kind: *kind, variable, body, island_id: *island_id }
+            }
+            LogicExpr::Lambda { variable, body } => {
+                let ([variable], [body]) = self.rebind(expr, [*variable], [*body]);
+                LogicExpr::Lambda { variable, body }
+            }
+            LogicExpr::Question { wh_variable, body } => {
+                let ([wh_variable], [body]) = self.rebind(expr, [*wh_variable], [*body]);
+                LogicExpr::Question { wh_variable, body }
+            }
+            LogicExpr::Exclamative { degree_var, body } => {
+                let ([degree_var], [body]) = self.rebind(expr, [*degree_var], [*body]);
+                LogicExpr::Exclamative { degree_var, body }
+            }
+            LogicExpr::GroupQuantifier { group_var, count, member_var, restriction, body } => {
+                let ([group_var, member_var], [restriction, body]) =
+                    self.rebind(expr, [*group_var, *member_var], [*restriction, *body]);
+                LogicExpr::GroupQuantifier { group_var, count: *count, member_var, restriction, body }
+            }
+            _ => return walk_fold_expr(self, expr),
+        };
+        self.ctx.alloc_expr(rebound)
+    }
+
+    fn fold_term(&mut self, term: &'a Term<'a>) -> Term<'a> {
+        match term {
+            Term::Variable(var) if *var == self.var => *self.replacement,
+            _ => walk_fold_term(self, term),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use logicaffeine_base::Symbol;
+    use crate::ast::QuantifierKind;
+    use crate::token::TokenType;
 
     struct VariableCollector {
         variables: Vec<Symbol>,
@@ -283,7 +685,6 @@ mod tests {
     #[test]
     fn variable_collector_finds_variables() {
         use logicaffeine_base::Arena;
-        use logicaffeine_base::Interner;
 
         let mut interner = Interner::new();
         let x = interner.intern("x");
@@ -307,8 +708,6 @@ mod tests {
     #[test]
     fn expr_counter_counts_nested() {
         use logicaffeine_base::Arena;
-        use logicaffeine_base::Interner;
-        use crate::token::TokenType;
 
         let mut interner = Interner::new();
         let p = interner.intern("P");
@@ -329,4 +728,145 @@ mod tests {
 
         assert_eq!(counter.count, 3);
     }
+
+    /// Run `f` with a fresh set of logic arenas.
+    fn with_ctx<R>(f: impl for<'a> FnOnce(AstContext<'a>) -> R) -> R {
+        use logicaffeine_base::Arena;
+        let (exprs, terms, nps, syms, roles, pps) =
+            (Arena::new(), Arena::new(), Arena::new(), Arena::new(), Arena::new(), Arena::new());
+        f(AstContext::new(&exprs, &terms, &nps, &syms, &roles, &pps))
+    }
+
+    fn render(expr: &LogicExpr, interner: &Interner) -> String {
+        use crate::formatter::UnicodeFormatter;
+        use crate::registry::SymbolRegistry;
+        expr.transpile_with(&mut SymbolRegistry::new(), interner, &UnicodeFormatter)
+    }
+
+    struct Identity<'a> {
+        ctx: AstContext<'a>,
+    }
+
+    impl<'a> Folder<'a> for Identity<'a> {
+        fn ctx(&self) -> AstContext<'a> {
+            self.ctx
+        }
+    }
+
+    struct RenameConstant<'a> {
+        ctx: AstContext<'a>,
+        from: Symbol,
+        to: Symbol,
+    }
+
+    impl<'a> Folder<'a> for RenameConstant<'a> {
+        fn ctx(&self) -> AstContext<'a> {
+            self.ctx
+        }
+
+        fn fold_term(&mut self, term: &'a Term<'a>) -> Term<'a> {
+            match term {
+                Term::Constant(name) if *name == self.from => Term::Constant(self.to),
+                _ => walk_fold_term(self, term),
+            }
+        }
+    }
+
+    #[test]
+    fn identity_fold_preserves_parsed_sentences() {
+        use crate::compile::compile_kripke_with;
+
+        for sentence in ["Every man is mortal.", "Mary gave John a book yesterday.", "John is taller than Mary."] {
+            let (before, after) = compile_kripke_with(sentence, |ast, interner| {
+                with_ctx(|ctx| {
+                    let folded = Identity { ctx }.fold_expr(ast);
+                    (render(ast, interner), render(folded, interner))
+                })
+            })
+            .unwrap();
+            assert_eq!(before, after, "{sentence}");
+        }
+    }
+
+    #[test]
+    fn folder_overrides_rewrite_only_their_nodes() {
+        let mut interner = Interner::new();
+        let (loves, john, mary, bill) =
+            (interner.intern("Loves"), interner.intern("John"), interner.intern("Mary"), interner.intern("Bill"));
+
+        with_ctx(|ctx| {
+            let args = ctx.alloc_terms([Term::Constant(john), Term::Constant(mary)]);
+            let expr = ctx.unary(TokenType::Not, ctx.predicate(loves, args));
+            let folded = RenameConstant { ctx, from: mary, to: bill }.fold_expr(expr);
+
+            assert_eq!(render(folded, &interner), "¬Loves(John, Bill)");
+            assert_eq!(render(expr, &interner), "¬Loves(John, Mary)");
+        });
+    }
+
+    #[test]
+    fn free_variables_skip_bound_ones() {
+        let mut interner = Interner::new();
+        let (p, x, y) = (interner.intern("P"), interner.intern("x"), interner.intern("y"));
+
+        with_ctx(|ctx| {
+            let body = ctx.predicate(p, ctx.alloc_terms([Term::Variable(x), Term::Variable(y)]));
+            let expr = ctx.quantifier(QuantifierKind::Universal, x, body, 0);
+
+            assert_eq!(free_variables(body), vec![x, y]);
+            assert_eq!(free_variables(expr), vec![y]);
+        });
+    }
+
+    #[test]
+    fn substitute_replaces_free_occurrences() {
+        let mut interner = Interner::new();
+        let (p, q, x, john) = (interner.intern("P"), interner.intern("Q"), interner.intern("x"), interner.intern("John"));
+
+        with_ctx(|ctx| {
+            let left = ctx.predicate(p, ctx.alloc_terms([Term::Variable(x)]));
+            let right = ctx.predicate(q, ctx.alloc_terms([Term::Variable(x)]));
+            let expr = ctx.binary(left, TokenType::And, right);
+
+            let john = ctx.alloc_term(Term::Constant(john));
+            let result = substitute(expr, x, john, ctx, &mut interner);
+            assert_eq!(render(result, &interner), "(P(John) ∧ Q(John))");
+        });
+    }
+
+    #[test]
+    fn substitute_stops_at_shadowing_binders() {
+        let mut interner = Interner::new();
+        let (p, x, john) = (interner.intern("P"), interner.intern("x"), interner.intern("John"));
+
+        with_ctx(|ctx| {
+            let body = ctx.predicate(p, ctx.alloc_terms([Term::Variable(x)]));
+            let expr = ctx.quantifier(QuantifierKind::Existential, x, body, 0);
+
+            let john = ctx.alloc_term(Term::Constant(john));
+            let result = substitute(expr, x, john, ctx, &mut interner);
+            assert!(std::ptr::eq(result, expr));
+        });
+    }
+
+    #[test]
+    fn substitute_renames_capturing_binders() {
+        let mut interner = Interner::new();
+        let (r, x, y) = (interner.intern("R"), interner.intern("x"), interner.intern("y"));
+
+        with_ctx(|ctx| {
+            // ∀y R(x, y)  with  x := y  must not become  ∀y R(y, y).
+            let body = ctx.predicate(r, ctx.alloc_terms([Term::Variable(x), Term::Variable(y)]));
+            let expr = ctx.quantifier(QuantifierKind::Universal, y, body, 0);
+
+            let replacement = ctx.alloc_term(Term::Variable(y));
+            let result = substitute(expr, x, replacement, ctx, &mut interner);
+
+            let LogicExpr::Quantifier { variable, body, .. } = result else { panic!("expected a quantifier") };
+            let y1 = interner.intern("y1");
+            assert_eq!(*variable, y1);
+            assert_eq!(free_variables(body), vec![y, y1]);
+            assert_eq!(free_variables(result), vec![y]);
+        });
+    }
 }
@@ -0,0 +1,210 @@
+//! Versioned AST serialization: parsed trees written as JSON or binary and
+//! read back into fresh arenas, with a fresh interner, render exactly as the
+//! originals did — and documents from newer or foreign writers are handled
+//! predictably.
+
+use logicaffeine_base::Arena;
+use logicaffeine_language::analysis::DiscoveryPass;
+use logicaffeine_language::arena_ctx::AstContext;
+use logicaffeine_language::ast::stmt::{Expr, Stmt, TypeExpr};
+use logicaffeine_language::compile::compile_kripke_with;
+use logicaffeine_language::drs::WorldState;
+use logicaffeine_language::formatter::UnicodeFormatter;
+use logicaffeine_language::registry::SymbolRegistry;
+use logicaffeine_language::serial::{
+    self, document_from_json, encode_logic, encode_program, logic_from_bytes, logic_from_json,
+    logic_to_bytes, logic_to_json, program_from_bytes, program_from_json, program_to_json,
+    SerialError, Tree, FORMAT_VERSION,
+};
+use logicaffeine_language::{Interner, Lexer, Parser};
+
+/// JSON and bytes for a sentence, plus its formula as parsed.
+fn serialize_sentence(sentence: &str) -> (String, Vec<u8>, String) {
+    compile_kripke_with(sentence, |ast, interner| {
+        let formula = ast.transpile_with(&mut SymbolRegistry::new(), interner, &UnicodeFormatter);
+        (logic_to_json(ast, interner).unwrap(), logic_to_bytes(ast, interner).unwrap(), formula)
+    })
+    .expect("sentence should parse")
+}
+
+/// Run `read` against fresh logic arenas and a fresh interner.
+fn read_back<R>(read: impl FnOnce(AstContext<'_>, &mut Interner) -> R) -> R {
+    let (exprs, terms, nps, syms, roles, pps) =
+        (Arena::new(), Arena::new(), Arena::new(), Arena::new(), Arena::new(), Arena::new());
+    let ctx = AstContext::new(&exprs, &terms, &nps, &syms, &roles, &pps);
+    let mut interner = Interner::new();
+    read(ctx, &mut interner)
+}
+
+/// Parse a program and hand its statements and interner to `f`.
+fn with_program<R>(source: &str, f: impl FnOnce(&[Stmt], &Interner) -> R) -> R {
+    let mut interner = Interner::new();
+    let mut lexer = Lexer::new(source, &mut interner);
+    let tokens = lexer.tokenize();
+    let type_registry = DiscoveryPass::new(&tokens, &mut interner).run_full().types;
+
+    let (exprs, terms, nps, syms, roles, pps) =
+        (Arena::new(), Arena::new(), Arena::new(), Arena::new(), Arena::new(), Arena::new());
+    let (stmts, imperative_exprs, types): (Arena<Stmt>, Arena<Expr>, Arena<TypeExpr>) =
+        (Arena::new(), Arena::new(), Arena::new());
+    let ctx = AstContext::with_types(&exprs, &terms, &nps, &syms, &roles, &pps, &stmts, &imperative_exprs, &types);
+
+    let mut world_state = WorldState::new();
+    let mut parser = Parser::new(tokens, &mut world_state, &mut interner, ctx, type_registry);
+    let program = parser.parse_program().expect("program should parse");
+    f(&program, &interner)
+}
+
+fn with_program_arenas<R>(f: impl FnOnce(AstContext<'_>, &mut Interner) -> R) -> R {
+    let (exprs, terms, nps, syms, roles, pps) =
+        (Arena::new(), Arena::new(), Arena::new(), Arena::new(), Arena::new(), Arena::new());
+    let (stmts, imperative_exprs, types): (Arena<Stmt>, Arena<Expr>, Arena<TypeExpr>) =
+        (Arena::new(), Arena::new(), Arena::new());
+    let ctx = AstContext::with_types(&exprs, &terms, &nps, &syms, &roles, &pps, &stmts, &imperative_exprs, &types);
+    let mut interner = Interner::new();
+    f(ctx, &mut interner)
+}
+
+const SENTENCES: &[&str] = &[
+    "Every man is mortal.",
+    "John loves Mary.",
+    "Some cat does not sleep.",
+    "If it rains, the ground is wet.",
+    "John must leave.",
+    "Mary gave John a book yesterday.",
+    "Who loves Mary?",
+    "John is taller than Mary.",
+    "Only John runs.",
+];
+
+const PROGRAM: &str = r#"## A Point has:
+    An x: Int.
+    A y: Int.
+
+## To distance (p: Point) -> Int:
+    Return p's x * p's x + p's y * p's y.
+
+## Main
+Let numbers be [1, 2, 3].
+Let mutable total be 0.
+Repeat for n in numbers:
+    If n > 1:
+        Set total to total + n.
+    Otherwise:
+        Show "small: {n}".
+Let p be a new Point with x 3 and y 4.
+Show distance(p).
+While total > 0:
+    Set total to total - 1.
+"#;
+
+#[test]
+fn sentences_round_trip_through_json() {
+    for sentence in SENTENCES {
+        let (json, _, formula) = serialize_sentence(sentence);
+        let back = read_back(|ctx, interner| {
+            let expr = logic_from_json(&json, ctx, interner).unwrap();
+            assert_eq!(logic_to_json(expr, interner).unwrap(), json, "{sentence}");
+            expr.transpile_with(&mut SymbolRegistry::new(), interner, &UnicodeFormatter)
+        });
+        assert_eq!(back, formula, "{sentence}");
+    }
+}
+
+#[test]
+fn sentences_round_trip_through_bytes() {
+    for sentence in SENTENCES {
+        let (_, bytes, formula) = serialize_sentence(sentence);
+        let back = read_back(|ctx, interner| {
+            let expr = logic_from_bytes(&bytes, ctx, interner).unwrap();
+            assert_eq!(logic_to_bytes(expr, interner).unwrap(), bytes, "{sentence}");
+            expr.transpile_with(&mut SymbolRegistry::new(), interner, &UnicodeFormatter)
+        });
+        assert_eq!(back, formula, "{sentence}");
+    }
+}
+
+#[test]
+fn programs_round_trip_in_both_encodings() {
+    let (document, json, bytes) = with_program(PROGRAM, |stmts, interner| {
+        (
+            encode_program(stmts, interner).unwrap(),
+            program_to_json(stmts, interner).unwrap(),
+            serial::program_to_bytes(stmts, interner).unwrap(),
+        )
+    });
+    assert!(matches!(&document.ast, Tree::Program(stmts) if stmts.len() >= 3));
+
+    with_program_arenas(|ctx, interner| {
+        let stmts = program_from_json(&json, ctx, interner).unwrap();
+        assert_eq!(encode_program(stmts, interner).unwrap(), document);
+    });
+    with_program_arenas(|ctx, interner| {
+        let stmts = program_from_bytes(&bytes, ctx, interner).unwrap();
+        assert_eq!(encode_program(stmts, interner).unwrap(), document);
+    });
+}
+
+#[test]
+fn programs_need_imperative_arenas() {
+    let json = with_program("## Main\nShow 1.\n", |stmts, interner| program_to_json(stmts, interner).unwrap());
+    read_back(|ctx, interner| {
+        assert_eq!(program_from_json(&json, ctx, interner).unwrap_err(), SerialError::MissingArenas);
+    });
+}
+
+#[test]
+fn unknown_json_fields_are_ignored() {
+    let (json, _, formula) = serialize_sentence("Every man is mortal.");
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["written_by"] = "a newer tool".into();
+    let root = value["ast"]["Logic"].as_object_mut().unwrap().values_mut().next().unwrap();
+    root["note"] = serde_json::json!({ "confidence": 0.9 });
+    let extended = value.to_string();
+
+    let back = read_back(|ctx, interner| {
+        let expr = logic_from_json(&extended, ctx, interner).unwrap();
+        expr.transpile_with(&mut SymbolRegistry::new(), interner, &UnicodeFormatter)
+    });
+    assert_eq!(back, formula);
+}
+
+#[test]
+fn older_versions_are_read() {
+    let (json, _, _) = serialize_sentence("John loves Mary.");
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["version"] = 0.into();
+    let document = document_from_json(&value.to_string()).unwrap();
+    assert_eq!(document.version, 0);
+}
+
+#[test]
+fn newer_versions_are_refused() {
+    let (json, mut bytes, _) = serialize_sentence("John loves Mary.");
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["version"] = (FORMAT_VERSION + 1).into();
+    value["ast"] = serde_json::json!({ "SomethingNew": {} });
+    let too_new = SerialError::TooNew { found: FORMAT_VERSION + 1, supported: FORMAT_VERSION };
+    assert_eq!(document_from_json(&value.to_string()).unwrap_err(), too_new);
+
+    bytes[4..8].copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+    assert_eq!(serial::document_from_bytes(&bytes).unwrap_err(), too_new);
+}
+
+#[test]
+fn foreign_documents_are_not_asts() {
+    assert_eq!(document_from_json(r#"{"format": "something-else", "version": 1}"#).unwrap_err(), SerialError::NotAnAst);
+    assert_eq!(serial::document_from_bytes(b"PK\x03\x04 not an ast").unwrap_err(), SerialError::NotAnAst);
+    assert!(matches!(document_from_json("not json"), Err(SerialError::Malformed(_))));
+}
+
+#[test]
+fn documents_carry_format_and_version() {
+    let document = compile_kripke_with("John runs.", |ast, interner| encode_logic(ast, interner).unwrap()).unwrap();
+    assert_eq!(document.format, serial::FORMAT);
+    assert_eq!(document.version, FORMAT_VERSION);
+
+    let (json, bytes, _) = serialize_sentence("John runs.");
+    assert!(json.starts_with(r#"{"format":"logos-ast","version":1,"ast":{"Logic":"#), "{json}");
+    assert_eq!(&bytes[..4], b"LGAS");
+}
@@ -0,0 +1,92 @@
+//! Cooperative cancellation: a fired `CancellationToken` stops lexing,
+//! parsing and scope enumeration early, and the cancellable entry points say
+//! so instead of returning partial or misleading results.
+
+use logicaffeine_base::Arena;
+use logicaffeine_language::arena_ctx::AstContext;
+use logicaffeine_language::drs::WorldState;
+use logicaffeine_language::error::ParseErrorKind;
+use logicaffeine_language::{
+    compile, compile_all_scopes, compile_all_scopes_cancellable, compile_cancellable,
+    compile_forest_ranked_cancellable, lambda, CancellationToken, Cancelled, CompileOptions,
+    Interner, Lexer, Parser,
+};
+
+const AMBIGUOUS: &str = "Every woman loves a man.";
+
+#[test]
+fn an_uncancelled_token_changes_nothing() {
+    let token = CancellationToken::new();
+    assert_eq!(
+        compile_all_scopes_cancellable(AMBIGUOUS, CompileOptions::default(), &token).unwrap(),
+        compile_all_scopes(AMBIGUOUS).unwrap(),
+    );
+    assert!(!compile_forest_ranked_cancellable(AMBIGUOUS, CompileOptions::default(), &token)
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        compile_cancellable(AMBIGUOUS, CompileOptions::default(), &token).unwrap(),
+        compile(AMBIGUOUS).unwrap(),
+    );
+}
+
+#[test]
+fn a_cancelled_token_reports_cancelled() {
+    let token = CancellationToken::new();
+    token.cancel();
+    let err = compile_all_scopes_cancellable(AMBIGUOUS, CompileOptions::default(), &token)
+        .unwrap_err();
+    assert!(matches!(err.kind, ParseErrorKind::Cancelled), "{:?}", err.kind);
+    assert_eq!(
+        compile_forest_ranked_cancellable(AMBIGUOUS, CompileOptions::default(), &token),
+        Err(Cancelled),
+    );
+    let err = compile_cancellable("I saw her duck.", CompileOptions::default(), &token).unwrap_err();
+    assert!(matches!(err.kind, ParseErrorKind::Cancelled), "{:?}", err.kind);
+}
+
+#[test]
+fn the_lexer_stops_at_the_first_word_after_cancellation() {
+    let token = CancellationToken::new();
+    token.cancel();
+    let mut interner = Interner::new();
+    let tokens = Lexer::new(AMBIGUOUS, &mut interner).with_cancellation(&token).tokenize();
+    assert_eq!(tokens.len(), 1, "only the terminating EOF: {tokens:?}");
+}
+
+#[test]
+fn the_parser_refuses_to_start_once_cancelled() {
+    let token = CancellationToken::new();
+    token.cancel();
+    let mut interner = Interner::new();
+    let tokens = Lexer::new(AMBIGUOUS, &mut interner).tokenize();
+    let (exprs, terms, nps, syms, roles, pps) =
+        (Arena::new(), Arena::new(), Arena::new(), Arena::new(), Arena::new(), Arena::new());
+    let ctx = AstContext::new(&exprs, &terms, &nps, &syms, &roles, &pps);
+    let mut world_state = WorldState::new();
+    let mut parser = Parser::new(tokens, &mut world_state, &mut interner, ctx, Default::default());
+    parser.set_cancellation(&token);
+    assert!(matches!(parser.parse().unwrap_err().kind, ParseErrorKind::Cancelled));
+}
+
+#[test]
+fn scope_enumeration_stops_between_readings() {
+    let mut interner = Interner::new();
+    let tokens = Lexer::new("Every boy gave a girl three books.", &mut interner).tokenize();
+    let (exprs, terms, nps, syms, roles, pps) =
+        (Arena::new(), Arena::new(), Arena::new(), Arena::new(), Arena::new(), Arena::new());
+    let ctx = AstContext::new(&exprs, &terms, &nps, &syms, &roles, &pps);
+    let mut world_state = WorldState::new();
+    let mut parser = Parser::new(tokens, &mut world_state, &mut interner, ctx, Default::default());
+    let ast = parser.parse().unwrap();
+
+    let (scope_exprs, scope_terms) = (Arena::new(), Arena::new());
+    let token = CancellationToken::new();
+    let mut scopings = lambda::enumerate_scopings(ast, &mut interner, &scope_exprs, &scope_terms)
+        .with_cancellation(&token);
+    assert!(scopings.len() > 1, "the sentence is scope-ambiguous");
+    assert!(scopings.next().is_some());
+    token.cancel();
+    assert_eq!(scopings.len(), 0);
+    assert!(scopings.next().is_none());
+}
@@ -0,0 +1,32 @@
+//! `compile_with_metrics`: the same FOL as `compile_with_options`, plus a
+//! per-phase breakdown whose counts describe what each phase produced.
+
+use logicaffeine_language::metrics::Phase;
+use logicaffeine_language::{compile_with_metrics, compile_with_options, CompileOptions};
+
+#[test]
+fn metrics_leave_the_output_unchanged() {
+    let input = "Every man is mortal.";
+    let (fol, _) = compile_with_metrics(input, CompileOptions::default());
+    assert_eq!(fol.unwrap(), compile_with_options(input, CompileOptions::default()).unwrap());
+}
+
+#[test]
+fn every_phase_of_a_successful_compile_is_counted() {
+    let (fol, metrics) = compile_with_metrics("John loves Mary.", CompileOptions::default());
+    let fol = fol.unwrap();
+    assert!(metrics.phase(Phase::Lex).count >= 4, "{metrics}");
+    assert!(metrics.phase(Phase::Mwe).count > 0);
+    assert_eq!(metrics.phase(Phase::Parse).count, 1);
+    assert!(metrics.phase(Phase::Semantics).count > 0);
+    assert_eq!(metrics.phase(Phase::Transpile).count, fol.len());
+}
+
+#[test]
+fn a_failed_parse_still_reports_the_phases_that_ran() {
+    let (fol, metrics) = compile_with_metrics("Every every every.", CompileOptions::default());
+    assert!(fol.is_err());
+    assert!(metrics.phase(Phase::Lex).count > 0);
+    assert_eq!(metrics.phase(Phase::Parse).count, 0);
+    assert_eq!(metrics.phase(Phase::Transpile).count, 0);
+}
@@ -0,0 +1,144 @@
+//! Custom passes registered through `CompileOptions::passes`: they rewrite,
+//! reject or render readings between the built-in semantics and the
+//! transpiler, for single compiles and forest readings alike.
+
+use std::sync::OnceLock;
+
+use logicaffeine_language::ast::{LogicExpr, Term};
+use logicaffeine_language::visitor::{walk_expr, Visitor};
+use logicaffeine_language::{
+    compile_forest_with_options, compile_with_options, CompileOptions, Interner, OutputFormat, ParseError,
+    ParseErrorKind, Pass, PassContext, PassRegistry, Span, TokenType,
+};
+
+/// Conjoins a domain axiom, `Mortal(Socrates)`, to every reading.
+struct InjectAxiom;
+
+impl Pass for InjectAxiom {
+    fn name(&self) -> &str {
+        "inject-axiom"
+    }
+
+    fn rewrite<'a>(&self, expr: &'a LogicExpr<'a>, cx: &mut PassContext<'a, '_>) -> Result<&'a LogicExpr<'a>, ParseError> {
+        let socrates = cx.ast.alloc_terms([Term::Constant(cx.interner.intern("Socrates"))]);
+        let axiom = cx.ast.predicate(cx.interner.intern("Mortal"), socrates);
+        Ok(cx.ast.binary(expr, TokenType::And, axiom))
+    }
+}
+
+/// Refuses readings that mention `Unicorn`.
+struct NoUnicorns;
+
+impl Pass for NoUnicorns {
+    fn name(&self) -> &str {
+        "no-unicorns"
+    }
+
+    fn rewrite<'a>(&self, expr: &'a LogicExpr<'a>, cx: &mut PassContext<'a, '_>) -> Result<&'a LogicExpr<'a>, ParseError> {
+        let mut finder = PredicateFinder { name: "unicorn", interner: cx.interner, found: false };
+        finder.visit_expr(expr);
+        if finder.found {
+            return Err(ParseError {
+                kind: ParseErrorKind::Custom("unicorns are out of scope".to_string()),
+                span: Span { start: 0, end: 0 },
+            });
+        }
+        Ok(expr)
+    }
+}
+
+/// Whether a reading has a predicate called `name`, in any capitalisation.
+struct PredicateFinder<'i> {
+    name: &'i str,
+    interner: &'i Interner,
+    found: bool,
+}
+
+impl<'a> Visitor<'a> for PredicateFinder<'_> {
+    fn visit_expr(&mut self, expr: &'a LogicExpr<'a>) {
+        match expr {
+            LogicExpr::Predicate { name, .. } if self.interner.resolve(*name).eq_ignore_ascii_case(self.name) => {
+                self.found = true
+            }
+            _ => walk_expr(self, expr),
+        }
+    }
+}
+
+/// An output format of its own: the reading's top-level connective.
+struct Outline;
+
+impl Pass for Outline {
+    fn name(&self) -> &str {
+        "outline"
+    }
+
+    fn render(&self, expr: &LogicExpr<'_>, _: &Interner, format: OutputFormat) -> Option<String> {
+        (format == OutputFormat::SimpleFOL).then(|| match expr {
+            LogicExpr::BinaryOp { op, .. } => format!("binary {op:?}"),
+            LogicExpr::Quantifier { kind, .. } => format!("quantifier {kind:?}"),
+            _ => "other".to_string(),
+        })
+    }
+}
+
+fn with_passes(registry: &'static OnceLock<PassRegistry>, build: fn() -> PassRegistry) -> CompileOptions {
+    CompileOptions { passes: registry.get_or_init(build), ..Default::default() }
+}
+
+fn injecting() -> CompileOptions {
+    static PASSES: OnceLock<PassRegistry> = OnceLock::new();
+    with_passes(&PASSES, || PassRegistry::new().with(InjectAxiom))
+}
+
+fn guarded() -> CompileOptions {
+    static PASSES: OnceLock<PassRegistry> = OnceLock::new();
+    with_passes(&PASSES, || PassRegistry::new().with(NoUnicorns).with(InjectAxiom))
+}
+
+fn outlined() -> CompileOptions {
+    static PASSES: OnceLock<PassRegistry> = OnceLock::new();
+    with_passes(&PASSES, || PassRegistry::new().with(InjectAxiom).with(Outline))
+}
+
+#[test]
+fn no_passes_are_registered_by_default() {
+    assert!(CompileOptions::default().passes.is_empty());
+}
+
+#[test]
+fn rewriting_passes_change_the_output() {
+    let plain = compile_with_options("John runs.", CompileOptions::default()).unwrap();
+    let injected = compile_with_options("John runs.", injecting()).unwrap();
+    assert!(!plain.contains("Mortal"), "{plain}");
+    assert!(injected.contains("Mortal(Socrates)"), "{injected}");
+}
+
+#[test]
+fn passes_run_in_registration_order_and_can_reject() {
+    let options = guarded();
+    assert_eq!(options.passes.names().collect::<Vec<_>>(), ["no-unicorns", "inject-axiom"]);
+
+    let error = compile_with_options("A unicorn runs.", options).unwrap_err();
+    assert!(matches!(&error.kind, ParseErrorKind::Custom(message) if message.contains("unicorns")));
+    assert!(compile_with_options("A horse runs.", options).unwrap().contains("Mortal(Socrates)"));
+}
+
+#[test]
+fn forest_readings_go_through_the_passes() {
+    let readings = compile_forest_with_options("Every woman loves a man.", injecting());
+    assert!(!readings.is_empty());
+    assert!(readings.iter().all(|reading| reading.contains("Mortal(Socrates)")), "{readings:?}");
+
+    assert!(compile_forest_with_options("A unicorn runs.", guarded()).is_empty());
+}
+
+#[test]
+fn rendering_passes_replace_the_transpiler() {
+    let options = CompileOptions { format: OutputFormat::SimpleFOL, ..outlined() };
+    assert_eq!(compile_with_options("John runs.", options).unwrap(), "binary And");
+
+    // A renderer that declines leaves the built-in output in place.
+    let unicode = compile_with_options("John runs.", outlined()).unwrap();
+    assert!(unicode.contains("Mortal(Socrates)"), "{unicode}");
+}
@@ -0,0 +1,50 @@
+//! `compile_with_trace` — the derivation behind a parse, as a tree of rule
+//! applications. Tracing must observe, never steer: the traced output is the
+//! untraced output, and the tree accounts for the tokens it claims.
+
+use logicaffeine_language::trace::RuleOutcome;
+use logicaffeine_language::{compile, compile_with_trace};
+
+#[test]
+fn tracing_does_not_change_the_output() {
+    for sentence in ["John loves Mary.", "Every man is mortal.", "If it rains, John sleeps."] {
+        let (traced, _) = compile_with_trace(sentence);
+        assert_eq!(traced.ok(), compile(sentence).ok(), "{sentence}");
+    }
+}
+
+#[test]
+fn the_tree_follows_the_descent_from_discourse_to_noun_phrase() {
+    let (result, trace) = compile_with_trace("Every man is mortal.");
+    assert!(result.is_ok());
+
+    let root = &trace.roots[0];
+    assert_eq!(root.rule, "discourse");
+    assert_eq!(root.outcome, RuleOutcome::Applied);
+    assert!(root.text.starts_with("Every man is mortal"), "{}", root.text);
+
+    let quantified = trace.rules().find(|r| r.rule == "quantified").expect("quantified rule");
+    assert!(quantified.walk().any(|r| r.rule == "restriction" && r.text == "man"), "{trace}");
+}
+
+#[test]
+fn child_tokens_nest_inside_their_parent() {
+    let (_, trace) = compile_with_trace("The old man who lives here sleeps.");
+    for node in trace.rules() {
+        for child in &node.children {
+            assert!(
+                child.tokens.start >= node.tokens.start && child.tokens.end <= node.tokens.end,
+                "{} {:?} escapes {} {:?}",
+                child.rule, child.tokens, node.rule, node.tokens
+            );
+        }
+    }
+}
+
+#[test]
+fn a_failed_parse_still_explains_itself() {
+    let (result, trace) = compile_with_trace("John lovse Mary.");
+    assert!(result.is_err());
+    assert_eq!(trace.roots[0].outcome, RuleOutcome::Failed);
+    assert!(trace.to_string().contains("✗ discourse"), "{trace}");
+}
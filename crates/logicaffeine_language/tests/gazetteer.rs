@@ -0,0 +1,47 @@
+//! Multi-word proper names lex as one name: particle names always, listed
+//! names when a gazetteer is supplied, and listed sorts drive the same sort
+//! checks as lexicon sorts.
+
+use logicaffeine_base::Interner;
+use logicaffeine_language::lexicon::Sort;
+use logicaffeine_language::token::TokenType;
+use logicaffeine_language::{compile, compile_with_gazetteer, Gazetteer, Lexer, Session};
+
+fn places() -> Gazetteer {
+    Gazetteer::parse("The Hague: Place\nBig Ben: Physical\nSilicon Valley: Place\n").unwrap()
+}
+
+#[test]
+fn particle_names_join_without_a_gazetteer() {
+    assert_eq!(compile("Rio de Janeiro is big.").unwrap(), "Big(Rio_de_Janeiro)");
+    let fol = compile("Leonardo da Vinci painted.").unwrap();
+    assert!(fol.contains("Leonardo_da_Vinci"), "{fol}");
+}
+
+#[test]
+fn listed_names_absorb_their_article() {
+    assert_eq!(compile("The Hague is a city.").unwrap(), "City(Hague)");
+    assert_eq!(compile_with_gazetteer("The Hague is a city.", &places()).unwrap(), "City(The_Hague)");
+}
+
+#[test]
+fn listed_sorts_feed_the_sort_checks() {
+    assert_eq!(compile("Big Ben is happy.").unwrap(), "Happy(Big_Ben)");
+    let fol = compile_with_gazetteer("Big Ben is happy.", &places()).unwrap();
+    assert!(fol.starts_with("Metaphor("), "{fol}");
+}
+
+#[test]
+fn session_uses_its_gazetteer() {
+    let mut session = Session::new();
+    session.set_gazetteer(Gazetteer::new().with("The Hague", Some(Sort::Place)));
+    assert_eq!(session.eval("The Hague is a city.").unwrap(), "City(The_Hague)");
+}
+
+#[test]
+fn imperative_blocks_are_left_alone() {
+    let mut interner = Interner::new();
+    let tokens = Lexer::new("## Main\nShow Rio de Janeiro.\n", &mut interner).tokenize();
+    let names = tokens.iter().filter(|t| matches!(t.kind, TokenType::ProperName(_))).count();
+    assert_eq!(names, 2);
+}
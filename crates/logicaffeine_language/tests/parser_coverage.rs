@@ -0,0 +1,52 @@
+//! Parser coverage — the branch probes fire for the constructions they name,
+//! and the report accounts for every rule and branch.
+
+use logicaffeine_language::compile_with_trace;
+use logicaffeine_language::coverage::{ParserCoverage, BRANCHES, RULES};
+
+#[test]
+fn each_construction_takes_its_branch() {
+    for (sentence, branch) in [
+        ("It was John who broke the vase.", "sentence/cleft"),
+        ("There is a cat.", "sentence/existential there"),
+        ("If it rains, John sleeps.", "predicate/finite verb"),
+        ("No dog barks.", "quantified/no"),
+        ("Most students passed.", "quantified/most"),
+        ("He runs.", "clause/pronoun subject"),
+        ("John and Mary run.", "clause/plural subject"),
+        ("John is happy.", "clause/copula"),
+        ("If John is happy, Mary runs.", "predicate/copula"),
+        ("Two of the three dogs bark.", "quantified/partitive"),
+        ("Birds fly.", "clause/generic"),
+        ("John does not run.", "clause/do-support"),
+    ] {
+        let (result, trace) = compile_with_trace(sentence);
+        assert!(result.is_ok(), "{sentence}: {result:?}");
+        assert!(trace.branches.contains(&branch), "{sentence} took {:?}, not {branch}", trace.branches);
+    }
+}
+
+#[test]
+fn coverage_accumulates_over_sentences() {
+    let mut coverage = ParserCoverage::new();
+    for sentence in ["Every man is mortal.", "Every dog barks.", "John lovse Mary."] {
+        let _ = coverage.record(sentence);
+    }
+    assert_eq!((coverage.sentences, coverage.rejected), (3, 1));
+    assert_eq!(coverage.branch("quantified/all"), 2);
+    assert!(coverage.rule("discourse").applied >= 2 && coverage.rule("discourse").failed >= 1);
+    assert!(coverage.untaken_branches().contains(&"sentence/cleft"));
+    assert!(!coverage.unapplied_rules().contains(&"quantified"));
+}
+
+#[test]
+fn the_report_lists_every_rule_and_branch() {
+    let mut coverage = ParserCoverage::new();
+    let _ = coverage.record("Every man is mortal.");
+    let report = coverage.to_string();
+    assert!(report.starts_with("1 sentences (0 rejected)"), "{report}");
+    for name in RULES.iter().chain(BRANCHES) {
+        assert!(report.contains(name), "{name} missing from\n{report}");
+    }
+    assert!(report.contains("✗ sentence/cleft"), "{report}");
+}
@@ -0,0 +1,46 @@
+//! Explicit parser modes — each switch changes the analysis the way its
+//! construal does, and the defaults are the parser's own.
+
+use logicaffeine_language::{
+    compile, compile_with_modes, ModalPreference, NegativeScopeMode, OutputFormat, ParserModes,
+};
+
+fn read(sentence: &str, modes: ParserModes) -> String {
+    compile_with_modes(sentence, modes, OutputFormat::Unicode).unwrap().reading
+}
+
+#[test]
+fn default_modes_read_like_compile() {
+    for sentence in ["John loves Mary.", "Every woman loves a man.", "John may leave."] {
+        assert_eq!(read(sentence, ParserModes::default()), compile(sentence).unwrap());
+    }
+}
+
+#[test]
+fn each_switch_changes_its_reading() {
+    for (sentence, modes, expected) in [
+        ("John saw the man with the telescope.", ParserModes { pp_attach_to_noun: true, ..Default::default() }, "With(Man, Telescope)"),
+        ("Three boys lifted a piano.", ParserModes { collective: true, ..Default::default() }, "Group(g)"),
+        ("Mary is a beautiful dancer.", ParserModes { event_reading: true, ..Default::default() }, "Beautiful(e)"),
+        ("Everyone didn't leave.", ParserModes { negative_scope: NegativeScopeMode::Wide, ..Default::default() }, "¬∀x"),
+        ("I don't think he came.", ParserModes { negative_scope: NegativeScopeMode::Narrow, ..Default::default() }, "Think(Speaker, [¬"),
+        ("John may leave.", ParserModes { modal_preference: ModalPreference::Epistemic, ..Default::default() }, "◇"),
+        ("John can swim.", ParserModes { modal_preference: ModalPreference::Deontic, ..Default::default() }, "P_"),
+    ] {
+        let default = read(sentence, ParserModes::default());
+        let reading = read(sentence, modes);
+        assert_ne!(reading, default, "{modes:?} left {sentence} unchanged");
+        assert!(reading.contains(expected), "{sentence} under {modes:?}: {reading}");
+    }
+}
+
+#[test]
+fn scopings_are_listed_only_on_request() {
+    let surface = compile_with_modes("Every woman loves a man.", ParserModes::default(), OutputFormat::Unicode).unwrap();
+    assert!(surface.scopings.is_empty());
+    let all = ParserModes { all_scopings: true, ..Default::default() };
+    let scoped = compile_with_modes("Every woman loves a man.", all, OutputFormat::Unicode).unwrap();
+    assert_eq!(scoped.reading, surface.reading);
+    assert_eq!(scoped.scopings.len(), 2);
+    assert!(scoped.scopings[1].starts_with("∃y"), "{:?}", scoped.scopings);
+}
@@ -0,0 +1,59 @@
+//! Forest readings come back in likelihood order, scored by the ranking
+//! model, and `compile` commits to the top-ranked reading.
+
+use logicaffeine_language::ranking::RankingWeights;
+use logicaffeine_language::{compile, compile_forest, compile_forest_ranked};
+
+#[test]
+fn forest_readings_are_ordered_by_score() {
+    let ranked = compile_forest_ranked("I saw the man with the telescope.");
+    assert!(ranked.len() >= 2, "{ranked:?}");
+    for pair in ranked.windows(2) {
+        assert!(pair[0].score >= pair[1].score, "{ranked:?}");
+    }
+}
+
+#[test]
+fn minimal_attachment_outranks_low_attachment() {
+    let ranked = compile_forest_ranked("I saw the man with the telescope.");
+    assert_eq!(ranked[0].features.attachment_locality, 0.0);
+    assert!(ranked.iter().any(|r| r.features.attachment_locality == 1.0));
+}
+
+#[test]
+fn marked_construals_rank_below_the_default() {
+    let ranked = compile_forest_ranked("Three boys lifted the piano.");
+    assert_eq!(ranked[0].features.marked_construal, 0.0, "{ranked:?}");
+    assert!(ranked.iter().skip(1).any(|r| r.features.marked_construal == 1.0));
+}
+
+#[test]
+fn forest_strings_match_the_ranked_readings() {
+    let input = "The boys lifted the piano.";
+    let fols: Vec<String> = compile_forest_ranked(input).into_iter().map(|r| r.fol).collect();
+    assert_eq!(compile_forest(input), fols);
+}
+
+#[test]
+fn scores_come_from_the_active_weights() {
+    let weights = RankingWeights::active();
+    for reading in compile_forest_ranked("John may leave.") {
+        assert_eq!(reading.score, weights.score(&reading.features));
+    }
+}
+
+#[test]
+fn compile_agrees_with_the_top_ranked_reading_for_unambiguous_input() {
+    let forest = compile_forest("Every man is mortal.");
+    assert_eq!(forest.len(), 1);
+    assert_eq!(compile("Every man is mortal.").unwrap(), forest[0]);
+}
+
+#[test]
+fn plural_readings_keep_the_discourse_numbering() {
+    let input = "The boys lifted the piano. They smiled.";
+    let forest = compile_forest(input);
+    assert_eq!(forest.len(), 2, "{forest:?}");
+    assert!(forest.iter().all(|reading| reading.starts_with("1) ")), "{forest:?}");
+    assert_eq!(compile(input).unwrap(), forest[0]);
+}
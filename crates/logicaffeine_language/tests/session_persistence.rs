@@ -0,0 +1,88 @@
+//! Session snapshots — a restored session continues the discourse exactly
+//! as the original would have, in either encoding.
+
+use logicaffeine_language::parser::AntecedentAmbiguity;
+use logicaffeine_language::session::snapshot::{SNAPSHOT_FORMAT, SNAPSHOT_VERSION};
+use logicaffeine_language::session::DisambiguationPolicy;
+use logicaffeine_language::{Gazetteer, OutputFormat, Session, SessionSnapshot, SnapshotError};
+
+/// A discourse with referents, events and a conditional box to carry over.
+fn discourse() -> Session {
+    let mut session = Session::new();
+    for sentence in ["The boys lifted the piano.", "If a farmer owns a donkey, he feeds it.", "John walked."] {
+        session.eval(sentence).unwrap();
+    }
+    session
+}
+
+#[test]
+fn a_restored_session_resolves_earlier_referents() {
+    for restored in [Session::from_json(&discourse().to_json()), Session::from_bytes(&discourse().to_bytes())] {
+        let mut restored = restored.unwrap();
+        let mut original = discourse();
+        assert_eq!(restored.turn_count(), 3);
+        for sentence in ["They smiled.", "He ran."] {
+            assert_eq!(restored.eval(sentence).unwrap(), original.eval(sentence).unwrap(), "{sentence}");
+        }
+        assert_eq!(restored.history(), original.history());
+    }
+}
+
+#[test]
+fn snapshots_round_trip_unchanged() {
+    let json = discourse().to_json();
+    assert!(json.starts_with(&format!("{{\"format\":\"{SNAPSHOT_FORMAT}\",\"version\":{SNAPSHOT_VERSION}")), "{json}");
+    assert_eq!(Session::from_json(&json).unwrap().to_json(), json);
+    let bytes = discourse().to_bytes();
+    assert_eq!(Session::from_bytes(&bytes).unwrap().to_bytes(), bytes);
+    assert_eq!(SessionSnapshot::from_bytes(&bytes).unwrap(), SessionSnapshot::from_json(&json).unwrap());
+}
+
+struct Latest;
+
+impl DisambiguationPolicy for Latest {
+    fn choose_reading(&mut self, _: &str, readings: &[String]) -> usize {
+        readings.len() - 1
+    }
+    fn choose_antecedent(&mut self, _: &str, ambiguity: &AntecedentAmbiguity) -> usize {
+        ambiguity.candidates.len() - 1
+    }
+}
+
+fn latex_session(gazetteer: &str) -> Session {
+    let mut session = Session::with_format(OutputFormat::LaTeX);
+    session.set_gazetteer(Gazetteer::parse(gazetteer).unwrap());
+    session.set_disambiguation_policy(Latest);
+    session.eval("A farmer met a lawyer.").unwrap();
+    session.eval("He smiled.").unwrap();
+    session
+}
+
+#[test]
+fn format_choices_and_gazetteer_are_kept() {
+    let mut original = latex_session("The Hague: Place\n");
+    let mut restored = Session::from_json(&original.to_json()).unwrap();
+    assert_eq!(restored.disambiguation("He smiled.").unwrap().antecedents[0].1, "lawyer");
+
+    let sentence = "The Hague is a city.";
+    let fol = restored.eval(sentence).unwrap();
+    assert_eq!(fol, original.eval(sentence).unwrap());
+    assert_ne!(fol, latex_session("").eval(sentence).unwrap(), "the gazetteer must survive");
+    assert!(restored.eval("A farmer waved.").unwrap().contains('\\'), "LaTeX output expected");
+}
+
+#[test]
+fn foreign_and_newer_documents_are_refused() {
+    assert_eq!(Session::from_json("{\"format\":\"logos-ast\",\"version\":1}").err(), Some(SnapshotError::NotASnapshot));
+    assert_eq!(Session::from_bytes(b"LGAS\x01\x00\x00\x00").err(), Some(SnapshotError::NotASnapshot));
+    assert!(matches!(Session::from_json("John walked."), Err(SnapshotError::Malformed(_))));
+
+    let newer = discourse().to_json().replacen(&format!("\"version\":{SNAPSHOT_VERSION}"), "\"version\":99", 1);
+    assert_eq!(
+        Session::from_json(&newer).err(),
+        Some(SnapshotError::TooNew { found: 99, supported: SNAPSHOT_VERSION })
+    );
+    let mut bytes = discourse().to_bytes();
+    bytes[4..8].copy_from_slice(&99u32.to_le_bytes());
+    assert!(matches!(Session::from_bytes(&bytes), Err(SnapshotError::TooNew { found: 99, .. })));
+}
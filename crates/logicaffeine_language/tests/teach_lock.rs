@@ -73,6 +73,8 @@ fn all_block_types(interner: &mut Interner) -> Vec<BlockType> {
         BlockType::TypeDef,
         BlockType::Policy,
         BlockType::Requires,
+        BlockType::Settings,
+        BlockType::Arguments,
         BlockType::Hardware,
         BlockType::Property,
         BlockType::No,
@@ -101,6 +103,8 @@ fn block_type_guard(block: &BlockType) {
         | BlockType::TypeDef
         | BlockType::Policy
         | BlockType::Requires
+        | BlockType::Settings
+        | BlockType::Arguments
         | BlockType::Hardware
         | BlockType::Property
         | BlockType::No
@@ -0,0 +1,71 @@
+//! Theorem blocks exported to Lean 4 and Coq: the English premises and goal
+//! are compiled, their predicates and individuals axiomatized, and each
+//! theorem stated with its premises as hypotheses. Proofs finished there are
+//! re-checked by the kernel against a fresh export.
+
+use logicaffeine_language::{check_exported_proofs, export_theorems};
+use logicaffeine_proof::export::{Prover, Verdict};
+
+const SOCRATES: &str = r#"
+## Theorem: Socrates_Mortality
+Given: All men are mortal.
+Given: Socrates is a man.
+Prove: Socrates is mortal.
+Proof: Auto.
+"#;
+
+#[test]
+fn syllogism_exports_to_lean() {
+    let lean = export_theorems(SOCRATES, Prover::Lean4).unwrap();
+    assert!(lean.starts_with("-- Exported from LOGOS.\n\nnamespace Logos\n\naxiom Entity : Type\n"), "{lean}");
+    assert!(lean.contains("axiom man : Entity → Prop"), "{lean}");
+    assert!(lean.contains("axiom mortal : Entity → Prop"), "{lean}");
+    assert!(lean.contains("axiom Socrates : Entity"), "{lean}");
+    assert!(lean.contains("theorem Socrates_Mortality (h1 : ∀ "), "{lean}");
+    assert!(lean.contains("(h2 : man Socrates) : mortal Socrates := by\n  sorry"), "{lean}");
+    assert!(lean.ends_with("end Logos\n"), "{lean}");
+}
+
+#[test]
+fn syllogism_exports_to_coq() {
+    let coq = export_theorems(SOCRATES, Prover::Coq).unwrap();
+    assert!(coq.contains("Parameter man : Entity -> Prop."), "{coq}");
+    assert!(coq.contains("(h2 : man Socrates) : mortal Socrates.\nProof.\nAdmitted."), "{coq}");
+    assert!(coq.ends_with("End Logos.\n"), "{coq}");
+}
+
+#[test]
+fn every_theorem_block_is_exported() {
+    let input = format!(
+        "{}\n## Theorem: Plato_Wisdom\nGiven: Every philosopher is wise.\nGiven: Plato is a philosopher.\nProve: Plato is wise.\nProof: Auto.\n",
+        SOCRATES
+    );
+    let lean = export_theorems(&input, Prover::Lean4).unwrap();
+    assert!(lean.contains("theorem Socrates_Mortality "), "{lean}");
+    assert!(lean.contains("theorem Plato_Wisdom "), "{lean}");
+    assert!(lean.contains("axiom philosopher : Entity → Prop"), "{lean}");
+}
+
+#[test]
+fn input_without_theorems_is_an_error() {
+    assert!(export_theorems("## Main\nShow 1.\n", Prover::Lean4).is_err());
+}
+
+#[test]
+fn finished_lean_proof_discharges_the_theorem() {
+    let lean = export_theorems(SOCRATES, Prover::Lean4).unwrap();
+    let finished = lean.replace("by\n  sorry", "h1 Socrates h2");
+    let discharged = check_exported_proofs(SOCRATES, &finished, Prover::Lean4).unwrap();
+    assert_eq!(discharged.len(), 1);
+    assert_eq!(discharged[0].name, "Socrates_Mortality");
+    assert_eq!(discharged[0].verdict, Verdict::Discharged);
+}
+
+#[test]
+fn edited_statement_does_not_discharge_the_theorem() {
+    let lean = export_theorems(SOCRATES, Prover::Lean4).unwrap();
+    let edited = lean.replace(": mortal Socrates := by\n  sorry", ": man Socrates := h2");
+    assert_ne!(edited, lean);
+    let discharged = check_exported_proofs(SOCRATES, &edited, Prover::Lean4).unwrap();
+    assert!(matches!(discharged[0].verdict, Verdict::Rejected(_)), "{:?}", discharged[0].verdict);
+}
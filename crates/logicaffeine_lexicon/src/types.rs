@@ -367,6 +367,20 @@ pub enum Feature {
     /// Examples: "want", "need", "wonder", "care", "desire", "wish", "like"
     Relevance,
 
+    /// Question-embedding verb taking an interrogative complement: a polar
+    /// "whether/if" clause or a wh-clause ("John wonders WHETHER Mary left",
+    /// "Mary asked WHO came") → Wonder(john, ?Left(mary)).
+    ///
+    /// Examples: "wonder", "ask", "know", "check", "decide", "remember"
+    QuestionEmbedding,
+
+    /// Neg-raising verb: matrix negation can be read inside the complement
+    /// ("I don't THINK he came" ≈ "I think he DIDN'T come") →
+    /// Think(i, ⟨¬Came(he)⟩).
+    ///
+    /// Examples: "think", "believe", "suppose", "expect", "imagine"
+    NegRaising,
+
     // -------------------------------------------------------------------------
     // Noun Features
     // -------------------------------------------------------------------------
@@ -550,6 +564,8 @@ impl Feature {
             "Resultative" => Some(Feature::Resultative),
             "Perception" => Some(Feature::Perception),
             "Relevance" => Some(Feature::Relevance),
+            "QuestionEmbedding" => Some(Feature::QuestionEmbedding),
+            "NegRaising" => Some(Feature::NegRaising),
             "Count" => Some(Feature::Count),
             "Mass" => Some(Feature::Mass),
             "Proper" => Some(Feature::Proper),
@@ -3,7 +3,7 @@ use tower_lsp::lsp_types::{
 };
 use std::collections::HashMap;
 
-use logicaffeine_language::suggest::{find_similar, KNOWN_WORDS};
+use logicaffeine_language::suggest::{find_similar, suggest, KNOWN_WORDS};
 
 use crate::document::DocumentState;
 
@@ -266,6 +266,41 @@ pub fn code_actions(
             }
         }
 
+        // UnknownWord → one rewrite per ranked correction, best first. The
+        // parser ships its ranking in `data`; re-rank locally if a client
+        // dropped it. The rewrite keeps the typo's leading capital.
+        if matches!(diag_code_str, Some("unknown-word")) && !word.is_empty() {
+            let ranked: Vec<String> = diagnostic
+                .data
+                .as_ref()
+                .and_then(|data| serde_json::from_value(data.clone()).ok())
+                .unwrap_or_else(|| {
+                    suggest(word, None, 3).into_iter().map(|s| s.word.to_string()).collect()
+                });
+            for (rank, suggestion) in ranked.iter().enumerate() {
+                let replacement = match_capital(word, suggestion);
+                let mut changes = HashMap::new();
+                changes.insert(
+                    uri.clone(),
+                    vec![TextEdit {
+                        range: diagnostic.range,
+                        new_text: replacement.clone(),
+                    }],
+                );
+                actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                    title: format!("Did you mean '{}'?", replacement),
+                    kind: Some(CodeActionKind::QUICKFIX),
+                    diagnostics: Some(vec![diagnostic.clone()]),
+                    edit: Some(WorkspaceEdit {
+                        changes: Some(changes),
+                        ..Default::default()
+                    }),
+                    is_preferred: Some(rank == 0),
+                    ..Default::default()
+                }));
+            }
+        }
+
         // UndefinedVariable → suggest closest match from definitions
         if matches!(diag_code_str, Some("undefined-variable")) && !word.is_empty() {
             let def_names: Vec<&str> = doc.symbol_index.definitions.iter()
@@ -297,6 +332,17 @@ pub fn code_actions(
     actions
 }
 
+/// `suggestion` capitalized like `original` ("Shwo" → "Show").
+fn match_capital(original: &str, suggestion: &str) -> String {
+    let mut chars = suggestion.chars();
+    match (original.chars().next(), chars.next()) {
+        (Some(first), Some(head)) if first.is_uppercase() => {
+            head.to_uppercase().chain(chars).collect()
+        }
+        _ => suggestion.to_string(),
+    }
+}
+
 fn ranges_overlap(a: &Range, b: &Range) -> bool {
     !(a.end.line < b.start.line
         || (a.end.line == b.start.line && a.end.character < b.start.character)
@@ -516,6 +562,37 @@ mod tests {
         assert!(!ea_actions.is_empty(), "Should have 'Copy before assignment' action");
     }
 
+    #[test]
+    fn unknown_word_offers_each_ranked_correction_best_first() {
+        let doc = make_doc("## Main\n    Let x be 5.\n    Shwo x.\n");
+        let actions = code_actions(&doc, Range::default(), &test_uri());
+        let fixes: Vec<_> = actions.iter()
+            .filter_map(|a| match a {
+                CodeActionOrCommand::CodeAction(ca) if ca.title.starts_with("Did you mean") => Some(ca),
+                _ => None,
+            })
+            .collect();
+        assert!(!fixes.is_empty(), "Should offer corrections for 'Shwo'. Got: {:?}", actions);
+        assert_eq!(fixes[0].title, "Did you mean 'Show'?");
+        assert_eq!(fixes[0].is_preferred, Some(true));
+        assert!(fixes[1..].iter().all(|f| f.is_preferred == Some(false)));
+        let edit = &fixes[0].edit.as_ref().unwrap().changes.as_ref().unwrap()[&test_uri()][0];
+        assert_eq!(edit.new_text, "Show");
+    }
+
+    #[test]
+    fn unknown_word_reranks_when_the_client_drops_data() {
+        let source = "## Main\n    Shwo x.\n";
+        let r = make_range(1, 4, 8); // "Shwo"
+        let doc = make_doc_with_diagnostic(source, "unknown-word", r);
+        let actions = code_actions(&doc, Range::default(), &test_uri());
+        assert!(
+            actions.iter().any(|a| matches!(a, CodeActionOrCommand::CodeAction(ca) if ca.title == "Did you mean 'Show'?")),
+            "Should fall back to local ranking. Got: {:?}",
+            actions
+        );
+    }
+
     #[test]
     fn no_code_action_for_valid_code() {
         let doc = make_doc("## Main\n    Let x be 5.\n    Show x.\n");
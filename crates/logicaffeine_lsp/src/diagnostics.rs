@@ -56,6 +56,11 @@ fn error_to_diagnostic(
         source: Some("logicaffeine".to_string()),
         message,
         related_information,
+        data: match &error.kind {
+            // The ranked corrections ride along for the quick-fix.
+            ParseErrorKind::UnknownWord { suggestions, .. } => Some(serde_json::json!(suggestions)),
+            _ => None,
+        },
         ..Default::default()
     }
 }
@@ -562,6 +567,19 @@ pub fn decision_for(kind: &ParseErrorKind) -> ErrorDecision {
 
         // Escape/zone analysis reports through AnalysisError with its own
         // codes; the Custom passthrough carries only prose here.
+        ParseErrorKind::UnknownWord { .. } => (
+            S::ERROR,
+            Some("unknown-word"),
+            Quickfix::Provided("Did you mean '<suggestion>'?"),
+            DocsLink::None("a misspelling is not a language feature; there is no docs section to link"),
+        ),
+        // A cancelled analysis is dropped before publish; this is a fallback.
+        ParseErrorKind::Cancelled => (
+            S::INFORMATION,
+            None,
+            Quickfix::None("nothing is wrong with the source; a newer edit superseded the parse"),
+            DocsLink::None("cancellation is a server event, not a language construct"),
+        ),
         ParseErrorKind::Custom(_) => (
             S::ERROR,
             None,
@@ -2,10 +2,11 @@ use std::collections::HashMap;
 
 use tower_lsp::lsp_types::{Diagnostic, Range, Url};
 
-use logicaffeine_base::Interner;
+use logicaffeine_base::{CancellationToken, Cancelled, Interner};
 use logicaffeine_compile::analysis::VarState;
 use logicaffeine_language::{
     analysis::{TypeRegistry, PolicyRegistry},
+    metrics::CompileMetrics,
     token::Token,
 };
 
@@ -27,6 +28,8 @@ pub struct DocumentState {
     pub type_registry: TypeRegistry,
     pub policy_registry: PolicyRegistry,
     pub ownership_states: HashMap<String, VarState>,
+    /// Phase timings of the analysis that produced this snapshot.
+    pub metrics: CompileMetrics,
 }
 
 impl DocumentState {
@@ -39,12 +42,24 @@ impl DocumentState {
 
     /// Create a new document state with a document URI for richer diagnostics.
     pub fn with_uri(source: String, version: i32, uri: Option<&Url>) -> Self {
+        Self::with_uri_cancellable(source, version, uri, &CancellationToken::new())
+            .expect("a fresh token is never cancelled")
+    }
+
+    /// [`with_uri`](Self::with_uri) that abandons analysis once `cancel`
+    /// fires, for edits that supersede the text being analyzed.
+    pub fn with_uri_cancellable(
+        source: String,
+        version: i32,
+        uri: Option<&Url>,
+        cancel: &CancellationToken,
+    ) -> Result<Self, Cancelled> {
         let line_index = LineIndex::new(&source);
 
-        let analysis = pipeline::analyze(&source);
+        let analysis = pipeline::analyze_cancellable(&source, cancel)?;
         let diagnostics = build_diagnostics(&analysis, &line_index, uri);
 
-        DocumentState {
+        Ok(DocumentState {
             source,
             version,
             line_index,
@@ -55,7 +70,8 @@ impl DocumentState {
             type_registry: analysis.type_registry,
             policy_registry: analysis.policy_registry,
             ownership_states: analysis.ownership_states,
-        }
+            metrics: analysis.metrics,
+        })
     }
 
 }
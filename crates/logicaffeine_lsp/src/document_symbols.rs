@@ -15,7 +15,9 @@ pub fn document_symbols(doc: &DocumentState) -> Vec<DocumentSymbol> {
             logicaffeine_language::token::BlockType::Function => SymbolKind::FUNCTION,
             logicaffeine_language::token::BlockType::Theorem => SymbolKind::CLASS,
             logicaffeine_language::token::BlockType::Definition
-            | logicaffeine_language::token::BlockType::TypeDef => SymbolKind::STRUCT,
+            | logicaffeine_language::token::BlockType::TypeDef
+            | logicaffeine_language::token::BlockType::Settings
+            | logicaffeine_language::token::BlockType::Arguments => SymbolKind::STRUCT,
             logicaffeine_language::token::BlockType::Policy => SymbolKind::INTERFACE,
             logicaffeine_language::token::BlockType::Proof => SymbolKind::METHOD,
             _ => SymbolKind::NAMESPACE,
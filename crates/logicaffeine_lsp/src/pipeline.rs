@@ -1,9 +1,10 @@
 use std::collections::HashMap;
 
-use logicaffeine_base::{Arena, Interner};
+use logicaffeine_base::{Arena, CancellationToken, Cancelled, Interner};
 use logicaffeine_compile::analysis::{
     EscapeChecker, OwnershipChecker, VarState,
 };
+use logicaffeine_compile::loader::{resolve_target_sections, CompileTarget};
 use logicaffeine_language::{
     analysis::{DiscoveryPass, TypeRegistry, PolicyRegistry},
     arena_ctx::AstContext,
@@ -11,6 +12,7 @@ use logicaffeine_language::{
     drs::WorldState,
     error::ParseError,
     lexer::Lexer,
+    metrics::{CompileMetrics, Phase, Stopwatch},
     mwe,
     parser::Parser,
     token::{Token, BlockType, Span, TokenType},
@@ -46,6 +48,8 @@ pub struct AnalysisResult {
     pub ownership_errors: Vec<AnalysisError>,
     pub ownership_states: HashMap<String, VarState>,
     pub symbol_index: SymbolIndex,
+    /// Per-phase timings of this pass; the server logs slow phases.
+    pub metrics: CompileMetrics,
 }
 
 /// Run the full analysis pipeline: lex → MWE → discover → parse → index.
@@ -53,18 +57,42 @@ pub struct AnalysisResult {
 /// Uses block-level error recovery: if the full parse fails, splits source
 /// at `## BlockHeader` boundaries and parses each block independently.
 pub fn analyze(source: &str) -> AnalysisResult {
+    analyze_cancellable(source, &CancellationToken::new())
+        .expect("a fresh token is never cancelled")
+}
+
+/// [`analyze`] that gives up with [`Cancelled`] once `cancel` fires — the
+/// document changed again, so this version's diagnostics would be stale.
+/// The lexer and every parse attempt poll the token; a parse it cut short is
+/// never mistaken for a syntax error.
+pub fn analyze_cancellable(
+    source: &str,
+    cancel: &CancellationToken,
+) -> Result<AnalysisResult, Cancelled> {
+    // Sections for other targets are blanked in place, so spans still index `source`.
+    let resolved = resolve_target_sections(source, &CompileTarget::host());
+    let source: &str = &resolved;
+
+    let mut metrics = CompileMetrics::default();
     let mut interner = Interner::new();
-    let mut lexer = Lexer::new(source, &mut interner);
+    let lexing = Stopwatch::start();
+    let mut lexer = Lexer::new(source, &mut interner).with_cancellation(cancel);
     let tokens = lexer.tokenize();
+    lexing.record(&mut metrics, Phase::Lex, tokens.len());
+    cancel.check()?;
 
-    let mwe_trie = mwe::build_mwe_trie();
-    let tokens = mwe::apply_mwe_pipeline(tokens, &mwe_trie, &mut interner);
+    let collapsing = Stopwatch::start();
+    let mwe_trie = mwe::shared_mwe_trie();
+    let tokens = mwe::apply_mwe_pipeline(tokens, mwe_trie, &mut interner);
+    collapsing.record(&mut metrics, Phase::Mwe, tokens.len());
 
+    let discovering = Stopwatch::start();
     let (type_registry, policy_registry) = {
         let mut discovery = DiscoveryPass::new(&tokens, &mut interner);
         let result = discovery.run_full();
         (result.types, result.policies)
     };
+    discovering.record(&mut metrics, Phase::Discovery, type_registry.iter_types().count());
 
     let parse_tokens = tokens.clone();
 
@@ -73,19 +101,26 @@ pub fn analyze(source: &str) -> AnalysisResult {
         parse_tokens.clone(),
         &type_registry,
         &mut interner,
+        cancel,
+        &mut metrics,
     ) {
         Ok(result) => {
             let idx = SymbolIndex::build(&result.owned_stmts, &tokens, &type_registry, result.interner, source);
             (result.type_errors, idx, result.escape_errors, result.ownership_errors, result.ownership_states)
         }
         Err(first_error) => {
+            cancel.check()?;
             // Fall through to block-level recovery
+            let recovering = Stopwatch::start();
             let mut recovery = parse_with_recovery(
                 source,
                 &tokens,
                 &type_registry,
                 &mut interner,
+                cancel,
             );
+            recovering.record(&mut metrics, Phase::Parse, recovery.stmts.len());
+            cancel.check()?;
 
             // Extract function definitions from block header tokens (the standard
             // parser cannot handle `## To funcName with param: Type` blocks)
@@ -124,7 +159,7 @@ pub fn analyze(source: &str) -> AnalysisResult {
         }
     };
 
-    AnalysisResult {
+    Ok(AnalysisResult {
         tokens,
         interner,
         type_registry,
@@ -134,7 +169,8 @@ pub fn analyze(source: &str) -> AnalysisResult {
         ownership_errors,
         ownership_states,
         symbol_index,
-    }
+        metrics,
+    })
 }
 
 /// Result of a successful full parse, including analysis checker results.
@@ -153,6 +189,8 @@ fn try_full_parse<'a>(
     tokens: Vec<Token>,
     type_registry: &TypeRegistry,
     interner: &'a mut Interner,
+    cancel: &CancellationToken,
+    metrics: &mut CompileMetrics,
 ) -> Result<FullParseResult<'a>, ParseError> {
     let expr_arena = Arena::new();
     let term_arena = Arena::new();
@@ -178,8 +216,13 @@ fn try_full_parse<'a>(
 
     let mut world_state = WorldState::new();
     let mut parser = Parser::new(tokens, &mut world_state, interner, ctx, type_registry.clone());
-    let stmts = parser.parse_program()?;
+    parser.set_cancellation(cancel);
+    let parsing = Stopwatch::start();
+    let stmts = parser.parse_program();
+    parsing.record(metrics, Phase::Parse, stmts.as_ref().map_or(0, |s| s.len()));
+    let stmts = stmts?;
     let stmt_spans = parser.stmt_spans().to_vec();
+    let checking = Stopwatch::start();
 
     // Typecheck while the arena AST is alive; every failing top-level
     // statement reports, anchored on its span from the parser's side-table.
@@ -288,6 +331,8 @@ fn try_full_parse<'a>(
 
         (errors, states)
     };
+    // Type checking, escape analysis, ownership analysis.
+    checking.record(metrics, Phase::Semantics, 3);
 
     // Convert arena-allocated stmts to owned summaries for the symbol index
     let owned = stmts.iter().map(|s| summarize_stmt(s, interner)).collect();
@@ -458,6 +503,7 @@ fn parse_with_recovery(
     tokens: &[Token],
     type_registry: &TypeRegistry,
     interner: &mut Interner,
+    cancel: &CancellationToken,
 ) -> RecoveryResult {
     let mut result = RecoveryResult {
         stmts: vec![],
@@ -486,7 +532,7 @@ fn parse_with_recovery(
         // Non-function single block: attempt to parse for partial recovery
         let block_tokens: Vec<Token> = tokens.to_vec();
         let (block, errors) =
-            parse_block_excising_bad_sentences(block_tokens, type_registry, interner);
+            parse_block_excising_bad_sentences(block_tokens, type_registry, interner, cancel);
         result.parse_errors.extend(errors);
         if let Some(block) = block {
             result.stmts = block.owned_stmts;
@@ -504,7 +550,7 @@ fn parse_with_recovery(
         }
         let block_tokens: Vec<Token> = tokens[start..end_excl].to_vec();
         let (block, errors) =
-            parse_block_excising_bad_sentences(block_tokens, type_registry, interner);
+            parse_block_excising_bad_sentences(block_tokens, type_registry, interner, cancel);
         result.parse_errors.extend(errors);
         if let Some(block) = block {
             result.stmts.extend(block.owned_stmts);
@@ -858,6 +904,7 @@ fn parse_block_excising_bad_sentences(
     mut block_tokens: Vec<Token>,
     type_registry: &TypeRegistry,
     interner: &mut Interner,
+    cancel: &CancellationToken,
 ) -> (Option<BlockParseResult>, Vec<ParseError>) {
     const MAX_EXCISIONS: usize = 32;
     let mut errors = Vec::new();
@@ -875,7 +922,10 @@ fn parse_block_excising_bad_sentences(
     }
 
     for _ in 0..=MAX_EXCISIONS {
-        match try_parse_block(block_tokens.clone(), type_registry, interner) {
+        if cancel.is_cancelled() {
+            return (None, errors);
+        }
+        match try_parse_block(block_tokens.clone(), type_registry, interner, cancel) {
             Ok(block) => return (Some(block), errors),
             Err(e) => {
                 let sentence = sentence_token_range(&block_tokens, e.span.start);
@@ -941,6 +991,7 @@ fn try_parse_block(
     tokens: Vec<Token>,
     type_registry: &TypeRegistry,
     interner: &mut Interner,
+    cancel: &CancellationToken,
 ) -> Result<BlockParseResult, ParseError> {
     let expr_arena = Arena::new();
     let term_arena = Arena::new();
@@ -966,6 +1017,7 @@ fn try_parse_block(
 
     let mut world_state = WorldState::new();
     let mut parser = Parser::new(tokens, &mut world_state, interner, ctx, type_registry.clone());
+    parser.set_cancellation(cancel);
     let stmts = parser.parse_program()?;
 
     let escape_errors = {
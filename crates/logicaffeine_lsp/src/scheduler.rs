@@ -3,6 +3,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use dashmap::DashMap;
+use logicaffeine_base::CancellationToken;
 use tower_lsp::lsp_types::{TextDocumentContentChangeEvent, Url};
 
 use crate::document::apply_content_change;
@@ -18,13 +19,17 @@ pub const DEBOUNCE: Duration = Duration::from_millis(150);
 /// The generation guard is the cancellation model: every edit bumps the
 /// generation, and an analysis pass only installs+publishes its result if the
 /// generation it captured is still current when it finishes. Stale results
-/// are dropped on the floor — no locks held, nothing blocks.
+/// are dropped on the floor — no locks held, nothing blocks. Each generation
+/// also carries a [`CancellationToken`] that the next edit (or closing the
+/// document) cancels, so a pass already running on stale text stops early
+/// instead of finishing work nobody will publish.
 pub struct Scheduler {
     entries: DashMap<Url, DocEntry>,
 }
 
 struct DocEntry {
     generation: Arc<AtomicU64>,
+    cancel: CancellationToken,
     text: String,
     version: i32,
 }
@@ -40,15 +45,18 @@ impl Scheduler {
     pub fn open(&self, uri: Url, text: String, version: i32) -> u64 {
         let entry = DocEntry {
             generation: Arc::new(AtomicU64::new(0)),
+            cancel: CancellationToken::new(),
             text,
             version,
         };
-        self.entries.insert(uri, entry);
+        if let Some(previous) = self.entries.insert(uri, entry) {
+            previous.cancel.cancel();
+        }
         0
     }
 
-    /// Apply LSP content changes in order and bump the generation.
-    /// Returns the new generation, or `None` for an unopened document.
+    /// Apply LSP content changes in order, bump the generation, and cancel
+    /// the previous generation's token. Returns the new generation, or `None` for an unopened document.
     pub fn apply_changes(
         &self,
         uri: &Url,
@@ -60,6 +68,8 @@ impl Scheduler {
             apply_content_change(&mut entry.text, change.range, &change.text);
         }
         entry.version = version;
+        entry.cancel.cancel();
+        entry.cancel = CancellationToken::new();
         Some(entry.generation.fetch_add(1, Ordering::SeqCst) + 1)
     }
 
@@ -72,6 +82,16 @@ impl Scheduler {
         Some((entry.text.clone(), entry.version))
     }
 
+    /// The cancellation token of `generation`, if it is still current. The
+    /// token fires as soon as the generation goes stale.
+    pub fn token(&self, uri: &Url, generation: u64) -> Option<CancellationToken> {
+        let entry = self.entries.get(uri)?;
+        if entry.generation.load(Ordering::SeqCst) != generation {
+            return None;
+        }
+        Some(entry.cancel.clone())
+    }
+
     /// The live text and version of an open document, regardless of
     /// generation — what a save should check.
     pub fn current_text(&self, uri: &Url) -> Option<(String, i32)> {
@@ -92,7 +112,9 @@ impl Scheduler {
     }
 
     pub fn close(&self, uri: &Url) {
-        self.entries.remove(uri);
+        if let Some((_, entry)) = self.entries.remove(uri) {
+            entry.cancel.cancel();
+        }
     }
 }
 
@@ -170,4 +192,24 @@ mod tests {
         scheduler.close(&uri());
         assert!(!scheduler.is_current(&uri(), 0));
     }
+
+    #[test]
+    fn an_edit_cancels_the_previous_generation_token() {
+        let scheduler = Scheduler::new();
+        scheduler.open(uri(), "a".into(), 1);
+        let stale = scheduler.token(&uri(), 0).unwrap();
+        let generation = scheduler.apply_changes(&uri(), vec![full_change("b")], 2).unwrap();
+        assert!(stale.is_cancelled(), "the superseded pass is told to stop");
+        assert!(scheduler.token(&uri(), 0).is_none());
+        assert!(!scheduler.token(&uri(), generation).unwrap().is_cancelled());
+    }
+
+    #[test]
+    fn close_cancels_the_live_token() {
+        let scheduler = Scheduler::new();
+        scheduler.open(uri(), "a".into(), 1);
+        let live = scheduler.token(&uri(), 0).unwrap();
+        scheduler.close(&uri());
+        assert!(live.is_cancelled());
+    }
 }
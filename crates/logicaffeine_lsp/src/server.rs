@@ -4,6 +4,8 @@ use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer};
 
+use logicaffeine_language::metrics::CompileMetrics;
+
 use crate::document::DocumentState;
 use crate::flycheck::{CargoFlycheck, Flycheck, FlycheckRunner};
 use crate::scheduler::{Scheduler, DEBOUNCE};
@@ -60,18 +62,31 @@ impl LogicAffeineServer {
         state: Arc<ServerState>,
         scheduler: Arc<Scheduler>,
         flycheck: Arc<Flycheck>,
+        workspace_index: Arc<WorkspaceIndex>,
         uri: Url,
         text: String,
         version: i32,
         generation: u64,
     ) {
+        let Some(cancel) = scheduler.token(&uri, generation) else {
+            return;
+        };
         let analysis_uri = uri.clone();
         let document = match tokio::task::spawn_blocking(move || {
-            DocumentState::with_uri(text, version, Some(&analysis_uri))
+            DocumentState::with_uri_cancellable(text, version, Some(&analysis_uri), &cancel).map(
+                |mut document| {
+                    // Import cycles and missing modules need the other files' imports.
+                    let imports = workspace_index.import_diagnostics(&analysis_uri, &document.source);
+                    document.diagnostics.extend(imports);
+                    document
+                },
+            )
         })
         .await
         {
-            Ok(document) => document,
+            Ok(Ok(document)) => document,
+            // A newer edit superseded this text mid-analysis.
+            Ok(Err(_cancelled)) => return,
             Err(join_error) => {
                 // Defense in depth: the parser is total by construction, but
                 // an analysis panic must degrade to "stale snapshot", never
@@ -81,6 +96,9 @@ impl LogicAffeineServer {
             }
         };
 
+        for warning in document.metrics.slow_phase_warnings(CompileMetrics::SLOW_PHASE) {
+            log::warn!("{uri}: {warning}");
+        }
         if !scheduler.is_current(&uri, generation) {
             return;
         }
@@ -337,6 +355,7 @@ impl LanguageServer for LogicAffeineServer {
             Arc::clone(&self.state),
             Arc::clone(&self.scheduler),
             Arc::clone(&self.flycheck),
+            Arc::clone(&self.workspace_index),
             uri,
             text,
             version,
@@ -365,13 +384,14 @@ impl LanguageServer for LogicAffeineServer {
         let state = Arc::clone(&self.state);
         let scheduler = Arc::clone(&self.scheduler);
         let flycheck = Arc::clone(&self.flycheck);
+        let workspace_index = Arc::clone(&self.workspace_index);
         tokio::spawn(async move {
             tokio::time::sleep(DEBOUNCE).await;
             let Some((text, version)) = scheduler.current_if(&uri, generation) else {
                 return;
             };
             Self::analyze_and_publish(
-                client, state, scheduler, flycheck, uri, text, version, generation,
+                client, state, scheduler, flycheck, workspace_index, uri, text, version, generation,
             )
             .await;
         });
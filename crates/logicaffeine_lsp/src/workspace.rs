@@ -4,12 +4,15 @@
 //!
 //! Open-document state stays authoritative in [`crate::state::ServerState`];
 //! this index answers only what per-document resolution cannot — workspace
-//! symbol queries and cross-file definition lookups.
+//! symbol queries, cross-file definition lookups, and the module graph the
+//! files' imports form (for import-cycle and missing-module diagnostics).
 
 use std::path::Path;
 
 use dashmap::DashMap;
-use tower_lsp::lsp_types::{Location, Range, SymbolKind, Url};
+use logicaffeine_compile::loader::{ImportLink, Loader, ModuleGraph};
+use logicaffeine_language::token::Span;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, Location, Range, SymbolKind, Url};
 
 use crate::index::DefinitionKind;
 use crate::line_index::LineIndex;
@@ -40,6 +43,8 @@ pub struct FileReference {
 pub struct WorkspaceIndex {
     files: DashMap<Url, Vec<WorkspaceSymbol>>,
     refs: DashMap<Url, Vec<FileReference>>,
+    /// Each file's `[Alias](uri)` imports, resolved.
+    imports: DashMap<Url, Vec<ImportLink>>,
 }
 
 impl WorkspaceIndex {
@@ -144,12 +149,87 @@ impl WorkspaceIndex {
             .collect();
 
         self.files.insert(uri.clone(), symbols);
-        self.refs.insert(uri, references);
+        self.refs.insert(uri.clone(), references);
+        self.imports.insert(uri, file_imports(path, &source));
     }
 
     pub fn remove(&self, uri: &Url) {
         self.files.remove(uri);
         self.refs.remove(uri);
+        self.imports.remove(uri);
+    }
+
+    /// The module graph of every indexed file.
+    pub fn module_graph(&self) -> ModuleGraph {
+        self.module_graph_with(None)
+    }
+
+    /// [`Self::module_graph`], with one file's imports taken from `live`
+    /// instead of the index — an open buffer answers for itself.
+    fn module_graph_with(&self, live: Option<(&Url, Vec<ImportLink>)>) -> ModuleGraph {
+        let mut graph = ModuleGraph::new();
+        let live_uri = live.as_ref().map(|(uri, _)| (*uri).clone());
+        if let Some((uri, links)) = live {
+            if let Ok(path) = uri.to_file_path() {
+                let module = graph.add_module(&path);
+                graph.add_imports(module, links);
+            }
+        }
+        for entry in self.imports.iter() {
+            if Some(entry.key()) == live_uri.as_ref() {
+                continue;
+            }
+            let Ok(path) = entry.key().to_file_path() else { continue };
+            let module = graph.add_module(&path);
+            graph.add_imports(module, entry.value().clone());
+        }
+        graph
+    }
+
+    /// Diagnostics on the imports in `source` (the live text of `uri`): one
+    /// per import that cannot be loaded, and one per import cycle this file
+    /// is on, at the link that continues the cycle.
+    pub fn import_diagnostics(&self, uri: &Url, source: &str) -> Vec<Diagnostic> {
+        let Ok(path) = uri.to_file_path() else { return Vec::new() };
+        let links = file_imports(&path, source);
+        let line_index = LineIndex::new(source);
+        let range = |span: Span| Range {
+            start: line_index.position(span.start),
+            end: line_index.position(span.end),
+        };
+        let error = |span: Span, message: String| Diagnostic {
+            range: range(span),
+            severity: Some(DiagnosticSeverity::ERROR),
+            source: Some("logicaffeine".to_string()),
+            message,
+            ..Default::default()
+        };
+
+        let mut diagnostics: Vec<Diagnostic> = links
+            .iter()
+            .filter_map(|link| {
+                let reason = link.target.as_ref().err()?;
+                Some(error(link.span, format!("cannot load [{}]({}): {reason}", link.alias, link.uri)))
+            })
+            .collect();
+
+        let graph = self.module_graph_with(Some((uri, links)));
+        let this = graph.module(&path);
+        for cycle in graph.cycles() {
+            let Some(step) = cycle.modules.iter().position(|module| graph.module(module) == this) else {
+                continue;
+            };
+            let names: Vec<String> = cycle
+                .modules
+                .iter()
+                .cycle()
+                .skip(step)
+                .take(cycle.modules.len() + 1)
+                .map(|module| module.file_name().unwrap_or(module.as_os_str()).to_string_lossy().into_owned())
+                .collect();
+            diagnostics.push(error(cycle.imports[step].span, format!("import cycle: {}", names.join(" → "))));
+        }
+        diagnostics
     }
 
     /// Does this file define `name` as workspace-visible API shape?
@@ -224,3 +304,44 @@ impl WorkspaceIndex {
         fallback
     }
 }
+
+/// `path`'s imports, resolved the way the build resolves them but without
+/// confining them to a project root — an editor reads what the user points at.
+fn file_imports(path: &Path, source: &str) -> Vec<ImportLink> {
+    let root = path.ancestors().last().unwrap_or(path).to_path_buf();
+    Loader::new(root).imports(path, source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_cycles_are_reported_on_the_link_that_closes_them() {
+        let dir = std::env::temp_dir().join(format!("logos-lsp-cycle-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("b.md"), "# B\n\nUses [A](a.md).\n").unwrap();
+        let a_source = "# A\n\nUses [B](b.md) and [Gone](gone.md).\n";
+        std::fs::write(dir.join("a.md"), a_source).unwrap();
+
+        let index = WorkspaceIndex::new();
+        index.index_file(&dir.join("a.md"));
+        index.index_file(&dir.join("b.md"));
+        let uri = Url::from_file_path(dir.join("a.md")).unwrap();
+        let diagnostics = index.import_diagnostics(&uri, a_source);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let messages: Vec<&str> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert!(messages.iter().any(|m| m.starts_with("cannot load [Gone](gone.md)")), "{messages:?}");
+        let cycle = diagnostics
+            .iter()
+            .find(|d| d.message == "import cycle: a.md → b.md → a.md")
+            .unwrap_or_else(|| panic!("{messages:?}"));
+        assert_eq!(cycle.range.start.line, 2);
+        assert_eq!(cycle.range.start.character, 5);
+
+        let graph = index.module_graph();
+        assert_eq!(graph.modules().len(), 2);
+        assert_eq!(graph.imports().len(), 2);
+    }
+}
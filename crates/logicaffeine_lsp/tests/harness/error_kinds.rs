@@ -14,7 +14,7 @@ use logicaffeine_language::token::TokenType;
 /// One entry per `ParseErrorKind` variant. `parse_error_kind_guard` breaks the
 /// build when the enum grows; update the guard, this list, and
 /// `ALL_PARSE_ERROR_KIND_COUNT` together.
-pub const ALL_PARSE_ERROR_KIND_COUNT: usize = 39;
+pub const ALL_PARSE_ERROR_KIND_COUNT: usize = 41;
 
 pub fn all_parse_error_kinds() -> Vec<ParseErrorKind> {
     vec![
@@ -86,6 +86,11 @@ pub fn all_parse_error_kinds() -> Vec<ParseErrorKind> {
         },
         ParseErrorKind::TrailingTokens { found: TokenType::Comma },
         ParseErrorKind::AstTooDeep { depth: 3000, max_depth: 2048 },
+        ParseErrorKind::UnknownWord {
+            word: "mortla".to_string(),
+            suggestions: vec!["mortal".to_string()],
+        },
+        ParseErrorKind::Cancelled,
         ParseErrorKind::Custom("zone escape".to_string()),
     ]
 }
@@ -134,6 +139,8 @@ pub fn parse_error_kind_guard(kind: &ParseErrorKind) {
         | ParseErrorKind::UnresolvedPronoun { .. }
         | ParseErrorKind::TrailingTokens { .. }
         | ParseErrorKind::AstTooDeep { .. }
+        | ParseErrorKind::UnknownWord { .. }
+        | ParseErrorKind::Cancelled
         | ParseErrorKind::Custom(_) => {}
     }
 }
@@ -181,6 +188,8 @@ pub fn kind_label(kind: &ParseErrorKind) -> &'static str {
         ParseErrorKind::UnresolvedPronoun { .. } => "UnresolvedPronoun",
         ParseErrorKind::TrailingTokens { .. } => "TrailingTokens",
         ParseErrorKind::AstTooDeep { .. } => "AstTooDeep",
+        ParseErrorKind::UnknownWord { .. } => "UnknownWord",
+        ParseErrorKind::Cancelled => "Cancelled",
         ParseErrorKind::Custom(_) => "Custom",
     }
 }
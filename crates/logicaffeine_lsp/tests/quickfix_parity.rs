@@ -17,6 +17,7 @@ const BATTERY: &[(&str, &str)] = &[
     ("IsValueEquality", "## Main\nLet x be 5.\nx is 5.\n"),
     ("ZeroIndex", "## Main\nLet xs be [1, 2, 3].\nShow item 0 of xs.\n"),
     ("UseAfterMove", "## Main\nLet x be 5.\nLet a be 0.\nGive x to a.\nShow x.\n"),
+    ("UnknownWord", "## Main\nLet x be 5.\nShwo x.\n"),
 ];
 
 /// Promised quickfixes with NO producing program today — each a recorded,
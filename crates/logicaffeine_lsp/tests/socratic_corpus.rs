@@ -45,6 +45,7 @@ fn every_socratic_explanation_matches_the_committed_golden() {
 /// Both directions: an exempt kind that now teaches must leave this table.
 const CONTRACT_EXEMPT: &[(&str, &str)] = &[
     ("Custom", "carries caller-authored prose verbatim; the caller owns the contract"),
+    ("Cancelled", "never reaches a reader; cancelled results are dropped before publishing"),
 ];
 
 #[test]
@@ -4795,10 +4795,10 @@ impl BackwardChainer {
     /// Recursively abstract all events in an expression.
     ///
     /// This transforms the entire expression tree, replacing event semantics
-    /// with simple predicates wherever possible. `pub(crate)` so the
-    /// verify/certify boundary can register hypotheses and the goal in the
-    /// SAME abstracted language the search runs in.
-    pub(crate) fn abstract_all_events(&self, expr: &ProofExpr) -> ProofExpr {
+    /// with simple predicates wherever possible. Public so the verify/certify
+    /// boundary (and callers building their own models, such as argument
+    /// analysis) can work in the SAME abstracted language the search runs in.
+    pub fn abstract_all_events(&self, expr: &ProofExpr) -> ProofExpr {
         // First try direct abstraction
         if let Some(abstracted) = self.abstract_event_to_predicate(expr) {
             return abstracted;
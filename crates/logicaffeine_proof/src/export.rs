@@ -0,0 +1,300 @@
+//! Export first-order theorems to Lean 4 and Coq.
+//!
+//! A [`Statement`] — named premises and a goal, as a `## Theorem` block states
+//! them — becomes a theorem over one opaque sort, `Entity`. Everything the
+//! statements mention is uninterpreted, so it is declared as an axiom: each
+//! predicate `P` used at arity `n` becomes `P : Entity → … → Prop`, each
+//! function `Entity → … → Entity`, each individual constant an `Entity`, and
+//! each propositional atom a `Prop`. The premises become hypotheses and the
+//! theorem is left open (`sorry` / `Admitted`) for the user to prove there.
+//!
+//! Neo-Davidsonian events quantify over the same sort:
+//! `∃e(Run(e) ∧ Agent(e, John))` exports as `∃ e : Entity, Run e ∧ Agent e John`.
+//!
+//! Modal, temporal and higher-order constructs have no faithful first-order
+//! rendering and are reported as [`ExportError::Unsupported`].
+//!
+//! [`check_finished`] closes the loop: given the exported file and the user's
+//! copy with some theorems proved as terms, it re-checks each proof in the
+//! kernel against the statement LOGOS exported.
+
+use logicaffeine_kernel::import::{check_proof, declare, parse_file};
+use logicaffeine_kernel::prelude::StandardLibrary;
+use logicaffeine_kernel::Context;
+
+use crate::{ProofExpr, ProofTerm};
+
+pub use logicaffeine_kernel::export::{ExportError, Prover};
+pub use logicaffeine_kernel::import::ImportError;
+
+/// The sort every individual, event and function value lives in.
+const ENTITY: &str = "Entity";
+
+/// A theorem to export: named premises entailing a goal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    pub name: String,
+    pub premises: Vec<(String, ProofExpr)>,
+    pub goal: ProofExpr,
+}
+
+/// A `prover` source file stating each of `statements`, after axioms for
+/// every symbol they use.
+///
+/// A symbol used at two different arities, or both as a predicate and as a
+/// term, cannot be given one type and is reported as unsupported.
+pub fn export_statements(statements: &[Statement], prover: Prover) -> Result<String, ExportError> {
+    let mut signature = Signature::default();
+    let mut theorems = Vec::new();
+    for statement in statements {
+        let mut binders = Vec::new();
+        for (name, premise) in &statement.premises {
+            let ty = Writer { prover, signature: &mut signature }.expr(premise, &mut Vec::new())?;
+            binders.push(format!("({} : {})", prover.identifier(name), ty));
+        }
+        let goal = Writer { prover, signature: &mut signature }.expr(&statement.goal, &mut Vec::new())?;
+        theorems.push(prover.theorem(&prover.identifier(&statement.name), &binders.join(" "), &goal, None));
+    }
+
+    let (open, close) = prover.namespace();
+    let arrow = match prover {
+        Prover::Lean4 => " → ",
+        Prover::Coq => " -> ",
+    };
+    let mut file = vec![prover.comment("Exported from LOGOS."), String::new(), open.to_string(), String::new()];
+    file.push(prover.axiom(ENTITY, "Type"));
+    for symbol in &signature.symbols {
+        let mut ty = vec![ENTITY; symbol.arity];
+        ty.push(if symbol.proposition { "Prop" } else { ENTITY });
+        file.push(prover.axiom(&prover.identifier(&symbol.name), &ty.join(arrow)));
+    }
+    for theorem in theorems {
+        file.push(String::new());
+        file.push(theorem);
+    }
+    file.push(String::new());
+    file.push(close.to_string());
+    Ok(file.join("\n") + "\n")
+}
+
+/// How one theorem of a finished file fared.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Discharge {
+    pub name: String,
+    pub verdict: Verdict,
+}
+
+/// The kernel's judgement of one finished theorem.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    /// The proof establishes the exported statement.
+    Discharged,
+    /// Left open, or proved by tactics: there is no term to check.
+    Open,
+    /// Why the proof was not accepted.
+    Rejected(String),
+}
+
+/// Re-check the theorems `finished` proves against the statements in
+/// `exported`, a file [`export_statements`] wrote.
+///
+/// Only `exported` is trusted: its axioms form the context and its statements
+/// are what each proof must establish. `finished` may add declarations of
+/// types and constants, but not of propositions. Every theorem in `finished`
+/// gets a [`Discharge`], in file order; one absent from `exported` is
+/// rejected. An error means one of the files could not be read.
+pub fn check_finished(exported: &str, finished: &str, prover: Prover) -> Result<Vec<Discharge>, ImportError> {
+    let trusted = parse_file(exported, prover)?;
+    let claimed = parse_file(finished, prover)?;
+    let mut ctx = Context::new();
+    StandardLibrary::register(&mut ctx);
+    declare(&mut ctx, &trusted.declarations)?;
+    declare(&mut ctx, &claimed.declarations)?;
+
+    Ok(claimed
+        .theorems
+        .iter()
+        .map(|theorem| {
+            let verdict = match (trusted.theorem(&theorem.name), &theorem.proof) {
+                (None, _) => Verdict::Rejected("no exported theorem has this name".to_string()),
+                (Some(_), None) => Verdict::Open,
+                (Some(statement), Some(proof)) => match check_proof(&ctx, &statement.statement, proof) {
+                    Ok(()) => Verdict::Discharged,
+                    Err(e) => Verdict::Rejected(e.to_string()),
+                },
+            };
+            Discharge { name: theorem.name.clone(), verdict }
+        })
+        .collect())
+}
+
+/// An uninterpreted symbol and the type it is used at.
+struct Symbol {
+    name: String,
+    arity: usize,
+    /// A predicate or atom (ending in `Prop`) rather than a function or constant.
+    proposition: bool,
+}
+
+/// The symbols seen so far, in order of first use.
+#[derive(Default)]
+struct Signature {
+    symbols: Vec<Symbol>,
+}
+
+impl Signature {
+    fn record(&mut self, name: &str, arity: usize, proposition: bool) -> Result<(), ExportError> {
+        match self.symbols.iter().find(|symbol| symbol.name == name) {
+            Some(symbol) if symbol.arity == arity && symbol.proposition == proposition => Ok(()),
+            Some(_) => Err(ExportError::Unsupported(format!("`{}`, which is used at two different types", name))),
+            None => {
+                self.symbols.push(Symbol { name: name.to_string(), arity, proposition });
+                Ok(())
+            }
+        }
+    }
+}
+
+struct Writer<'s> {
+    prover: Prover,
+    signature: &'s mut Signature,
+}
+
+impl Writer<'_> {
+    fn expr(&mut self, expr: &ProofExpr, bound: &mut Vec<String>) -> Result<String, ExportError> {
+        let (and, or, implies, iff, not) = match self.prover {
+            Prover::Lean4 => ("∧", "∨", "→", "↔", "¬"),
+            Prover::Coq => ("/\\", "\\/", "->", "<->", "~ "),
+        };
+        Ok(match expr {
+            ProofExpr::Predicate { name, world: Some(_), .. } => {
+                return Err(ExportError::Unsupported(format!("the world-indexed predicate `{}`", name)));
+            }
+            ProofExpr::Predicate { name, args, world: None } => {
+                self.signature.record(name, args.len(), true)?;
+                self.application(name, args, bound)?
+            }
+            ProofExpr::Atom(name) => {
+                self.signature.record(name, 0, true)?;
+                self.prover.identifier(name)
+            }
+            ProofExpr::Identity(left, right) => {
+                format!("{} = {}", self.term(left, bound)?, self.term(right, bound)?)
+            }
+            ProofExpr::And(left, right) => self.binary(left, and, right, bound)?,
+            ProofExpr::Or(left, right) => self.binary(left, or, right, bound)?,
+            ProofExpr::Implies(left, right) => self.binary(left, implies, right, bound)?,
+            ProofExpr::Iff(left, right) => self.binary(left, iff, right, bound)?,
+            ProofExpr::Not(inner) => format!("{}{}", not, self.operand(inner, bound)?),
+            ProofExpr::ForAll { variable, body } => self.quantifier("∀", "forall", variable, body, bound)?,
+            ProofExpr::Exists { variable, body } => self.quantifier("∃", "exists", variable, body, bound)?,
+            ProofExpr::NeoEvent { event_var, verb, roles } => {
+                bound.push(event_var.clone());
+                let event = ProofTerm::Variable(event_var.clone());
+                let mut conjuncts = Vec::with_capacity(roles.len() + 1);
+                let verb_applied = self.predicate(verb, std::slice::from_ref(&event), bound);
+                conjuncts.push(verb_applied);
+                for (role, filler) in roles {
+                    conjuncts.push(self.predicate(role, &[event.clone(), filler.clone()], bound));
+                }
+                let body = conjuncts.into_iter().collect::<Result<Vec<_>, _>>();
+                bound.pop();
+                let body = body?.join(&format!(" {} ", and));
+                match self.prover {
+                    Prover::Lean4 => format!("∃ {} : {}, {}", self.prover.identifier(event_var), ENTITY, body),
+                    Prover::Coq => format!("exists {} : {}, {}", self.prover.identifier(event_var), ENTITY, body),
+                }
+            }
+            ProofExpr::Modal { .. } => return Err(ExportError::Unsupported("a modal operator".to_string())),
+            ProofExpr::Counterfactual { .. } => {
+                return Err(ExportError::Unsupported("a counterfactual conditional".to_string()));
+            }
+            ProofExpr::Temporal { operator, .. } | ProofExpr::TemporalBinary { operator, .. } => {
+                return Err(ExportError::Unsupported(format!("the temporal operator `{}`", operator)));
+            }
+            ProofExpr::Lambda { .. } | ProofExpr::App(..) => {
+                return Err(ExportError::Unsupported("a higher-order expression".to_string()));
+            }
+            ProofExpr::Ctor { name, .. } => return Err(ExportError::Unsupported(format!("the constructor `{}`", name))),
+            ProofExpr::Match { .. } => return Err(ExportError::Unsupported("a `match` expression".to_string())),
+            ProofExpr::Fixpoint { name, .. } => return Err(ExportError::Unsupported(format!("the fixpoint `{}`", name))),
+            ProofExpr::TypedVar { name, .. } => {
+                return Err(ExportError::Unsupported(format!("the typed variable `{}`", name)));
+            }
+            ProofExpr::Hole(name) => return Err(ExportError::Unsupported(format!("the hole `?{}`", name))),
+            ProofExpr::Term(term) => return Err(ExportError::Unsupported(format!("the bare term `{}`", term))),
+            ProofExpr::Unsupported(what) => return Err(ExportError::Unsupported(what.clone())),
+        })
+    }
+
+    /// A predicate applied to terms, recording its arity.
+    fn predicate(&mut self, name: &str, args: &[ProofTerm], bound: &mut Vec<String>) -> Result<String, ExportError> {
+        self.signature.record(name, args.len(), true)?;
+        self.application(name, args, bound)
+    }
+
+    fn binary(&mut self, left: &ProofExpr, op: &str, right: &ProofExpr, bound: &mut Vec<String>) -> Result<String, ExportError> {
+        Ok(format!("{} {} {}", self.operand(left, bound)?, op, self.operand(right, bound)?))
+    }
+
+    /// `expr` as the operand of a connective: the two targets disagree on
+    /// connective precedence, so anything compound is parenthesized.
+    fn operand(&mut self, expr: &ProofExpr, bound: &mut Vec<String>) -> Result<String, ExportError> {
+        let text = self.expr(expr, bound)?;
+        Ok(match expr {
+            ProofExpr::Predicate { .. } | ProofExpr::Atom(_) | ProofExpr::Identity(..) | ProofExpr::Not(_) => text,
+            _ => format!("({})", text),
+        })
+    }
+
+    fn quantifier(
+        &mut self,
+        lean: &str,
+        coq: &str,
+        variable: &str,
+        body: &ProofExpr,
+        bound: &mut Vec<String>,
+    ) -> Result<String, ExportError> {
+        bound.push(variable.to_string());
+        let body = self.expr(body, bound);
+        bound.pop();
+        let binder = match self.prover {
+            Prover::Lean4 => lean,
+            Prover::Coq => coq,
+        };
+        Ok(format!("{} {} : {}, {}", binder, self.prover.identifier(variable), ENTITY, body?))
+    }
+
+    fn application(&mut self, head: &str, args: &[ProofTerm], bound: &mut Vec<String>) -> Result<String, ExportError> {
+        let mut out = self.prover.identifier(head);
+        for arg in args {
+            let text = self.term(arg, bound)?;
+            if matches!(arg, ProofTerm::Function(_, args) if !args.is_empty()) {
+                out.push_str(&format!(" ({})", text));
+            } else {
+                out.push(' ');
+                out.push_str(&text);
+            }
+        }
+        Ok(out)
+    }
+
+    /// A term of sort `Entity`. Variables bound by an enclosing quantifier
+    /// stay variables; free ones are declared as constants.
+    fn term(&mut self, term: &ProofTerm, bound: &mut Vec<String>) -> Result<String, ExportError> {
+        match term {
+            ProofTerm::Variable(name) | ProofTerm::BoundVarRef(name) if bound.contains(name) => {
+                Ok(self.prover.identifier(name))
+            }
+            ProofTerm::Constant(name) | ProofTerm::Variable(name) | ProofTerm::BoundVarRef(name) => {
+                self.signature.record(name, 0, false)?;
+                Ok(self.prover.identifier(name))
+            }
+            ProofTerm::Function(name, args) => {
+                self.signature.record(name, args.len(), false)?;
+                self.application(name, args, bound)
+            }
+            ProofTerm::Group(_) => Err(ExportError::Unsupported(format!("the tuple `{}`", term))),
+        }
+    }
+}
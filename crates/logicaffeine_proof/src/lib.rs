@@ -88,6 +88,7 @@ pub mod development;
 pub mod discrimination;
 pub mod lemma_index;
 pub mod engine;
+pub mod export;
 pub mod simp;
 pub mod formula;
 pub mod tactic;
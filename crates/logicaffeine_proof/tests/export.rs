@@ -0,0 +1,154 @@
+//! First-order theorems exported to Lean 4 and Coq: every predicate, function
+//! and constant becomes an axiom over the opaque sort `Entity`, premises become
+//! hypotheses, and the theorem is left open for the target prover.
+
+use logicaffeine_proof::export::{check_finished, export_statements, ExportError, ImportError, Prover, Statement, Verdict};
+use logicaffeine_proof::{ProofExpr, ProofTerm};
+
+fn k(n: &str) -> ProofTerm {
+    ProofTerm::Constant(n.to_string())
+}
+fn var(n: &str) -> ProofTerm {
+    ProofTerm::Variable(n.to_string())
+}
+fn pred(n: &str, args: Vec<ProofTerm>) -> ProofExpr {
+    ProofExpr::Predicate { name: n.to_string(), args, world: None }
+}
+fn implies(a: ProofExpr, b: ProofExpr) -> ProofExpr {
+    ProofExpr::Implies(Box::new(a), Box::new(b))
+}
+fn forall(x: &str, body: ProofExpr) -> ProofExpr {
+    ProofExpr::ForAll { variable: x.to_string(), body: Box::new(body) }
+}
+
+fn socrates() -> Statement {
+    Statement {
+        name: "Socrates_Mortal".to_string(),
+        premises: vec![
+            ("h1".to_string(), forall("x", implies(pred("Man", vec![var("x")]), pred("Mortal", vec![var("x")])))),
+            ("h2".to_string(), pred("Man", vec![k("Socrates")])),
+        ],
+        goal: pred("Mortal", vec![k("Socrates")]),
+    }
+}
+
+#[test]
+fn syllogism_in_lean() {
+    assert_eq!(
+        export_statements(&[socrates()], Prover::Lean4).unwrap(),
+        "-- Exported from LOGOS.\n\nnamespace Logos\n\n\
+         axiom Entity : Type\n\
+         axiom Man : Entity → Prop\n\
+         axiom Mortal : Entity → Prop\n\
+         axiom Socrates : Entity\n\n\
+         theorem Socrates_Mortal (h1 : ∀ x : Entity, Man x → Mortal x) (h2 : Man Socrates) : Mortal Socrates := by\n  sorry\n\n\
+         end Logos\n"
+    );
+}
+
+#[test]
+fn syllogism_in_coq() {
+    let coq = export_statements(&[socrates()], Prover::Coq).unwrap();
+    assert!(coq.contains("Parameter Entity : Type.\nParameter Man : Entity -> Prop."), "{coq}");
+    assert!(coq.contains("Parameter Socrates : Entity."), "{coq}");
+    assert!(
+        coq.contains(
+            "Theorem Socrates_Mortal (h1 : forall x : Entity, Man x -> Mortal x) (h2 : Man Socrates) : Mortal Socrates.\nProof.\nAdmitted."
+        ),
+        "{coq}"
+    );
+    assert!(coq.ends_with("End Logos.\n"), "{coq}");
+}
+
+#[test]
+fn compound_operands_are_parenthesized() {
+    let p = ProofExpr::Atom("P".to_string());
+    let q = ProofExpr::Atom("Q".to_string());
+    let goal = ProofExpr::Not(Box::new(ProofExpr::And(
+        Box::new(ProofExpr::Or(Box::new(p.clone()), Box::new(q.clone()))),
+        Box::new(ProofExpr::Not(Box::new(p))),
+    )));
+    let statement = Statement { name: "t".to_string(), premises: vec![], goal };
+
+    let lean = export_statements(&[statement.clone()], Prover::Lean4).unwrap();
+    assert!(lean.contains("axiom P : Prop\naxiom Q : Prop"), "{lean}");
+    assert!(lean.contains("theorem t : ¬((P ∨ Q) ∧ ¬P) := by"), "{lean}");
+
+    let coq = export_statements(&[statement], Prover::Coq).unwrap();
+    assert!(coq.contains("Theorem t : ~ ((P \\/ Q) /\\ ~ P)."), "{coq}");
+}
+
+#[test]
+fn events_functions_and_identity() {
+    let run = ProofExpr::NeoEvent {
+        event_var: "e".to_string(),
+        verb: "Run".to_string(),
+        roles: vec![("Agent".to_string(), ProofTerm::Function("father".to_string(), vec![k("John")]))],
+    };
+    let same = ProofExpr::Identity(k("Tully"), k("Cicero"));
+    let statement = Statement { name: "t".to_string(), premises: vec![("same".to_string(), same)], goal: run };
+
+    let lean = export_statements(&[statement], Prover::Lean4).unwrap();
+    assert!(lean.contains("axiom Tully : Entity\naxiom Cicero : Entity\naxiom Run : Entity → Prop"), "{lean}");
+    assert!(lean.contains("axiom Agent : Entity → Entity → Prop\naxiom father : Entity → Entity"), "{lean}");
+    assert!(lean.contains("(same : Tully = Cicero) : ∃ e : Entity, Run e ∧ Agent e (father John)"), "{lean}");
+}
+
+#[test]
+fn statements_share_one_signature() {
+    let second = Statement { name: "again".to_string(), premises: vec![], goal: pred("Man", vec![k("Plato")]) };
+    let lean = export_statements(&[socrates(), second], Prover::Lean4).unwrap();
+    assert_eq!(lean.matches("axiom Man ").count(), 1, "{lean}");
+    assert!(lean.contains("axiom Plato : Entity"), "{lean}");
+    assert!(lean.contains("theorem again : Man Plato := by"), "{lean}");
+}
+
+#[test]
+fn inconsistent_arities_and_modal_goals_are_refused() {
+    let goal = ProofExpr::And(Box::new(pred("P", vec![k("A")])), Box::new(pred("P", vec![k("A"), k("B")])));
+    let statement = Statement { name: "t".to_string(), premises: vec![], goal };
+    assert_eq!(
+        export_statements(&[statement], Prover::Coq),
+        Err(ExportError::Unsupported("`P`, which is used at two different types".to_string()))
+    );
+
+    let modal = ProofExpr::Modal {
+        domain: "Alethic".to_string(),
+        force: 1.0,
+        flavor: "Root".to_string(),
+        body: Box::new(pred("P", vec![])),
+    };
+    let statement = Statement { name: "t".to_string(), premises: vec![], goal: modal };
+    assert!(export_statements(&[statement], Prover::Lean4).is_err());
+}
+
+#[test]
+fn finished_proofs_are_rechecked_against_the_export() {
+    let exported = export_statements(&[socrates()], Prover::Lean4).unwrap();
+    let finished = exported.replace("by\n  sorry", "h1 Socrates h2");
+    let discharged = check_finished(&exported, &finished, Prover::Lean4).unwrap();
+    assert_eq!(discharged.len(), 1);
+    assert_eq!(discharged[0].name, "Socrates_Mortal");
+    assert_eq!(discharged[0].verdict, Verdict::Discharged);
+
+    let coq = export_statements(&[socrates()], Prover::Coq).unwrap();
+    let finished = coq.replace("Admitted.", "  exact (h1 Socrates h2).\nQed.");
+    assert_eq!(check_finished(&coq, &finished, Prover::Coq).unwrap()[0].verdict, Verdict::Discharged);
+}
+
+#[test]
+fn weakened_or_unfinished_proofs_are_not_accepted() {
+    let exported = export_statements(&[socrates()], Prover::Lean4).unwrap();
+
+    // The claimed statement drops a premise; the proof is checked against the export's.
+    let weakened = exported.replace("(h1 : ∀ x : Entity, Man x → Mortal x) ", "").replace("by\n  sorry", "h2");
+    assert!(weakened.contains("theorem Socrates_Mortal (h2 : Man Socrates) : Mortal Socrates := h2"), "{weakened}");
+    let verdict = &check_finished(&exported, &weakened, Prover::Lean4).unwrap()[0].verdict;
+    assert!(matches!(verdict, Verdict::Rejected(why) if why.contains("establishes")), "{verdict:?}");
+
+    let open = check_finished(&exported, &exported, Prover::Lean4).unwrap();
+    assert_eq!(open[0].verdict, Verdict::Open);
+
+    let cheat = exported.replace("axiom Socrates : Entity", "axiom Socrates : Entity\naxiom cheat : Mortal Socrates");
+    assert!(matches!(check_finished(&exported, &cheat, Prover::Lean4), Err(ImportError::Assumption(_))));
+}
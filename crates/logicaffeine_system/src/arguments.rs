@@ -0,0 +1,212 @@
+//! Command-Line Argument Parsing
+//!
+//! The runtime behind an `## Arguments` block and `Read cli from arguments.`:
+//! the declarations become a [`clap`] command, so a program gets `--help`,
+//! usage errors and typo suggestions without indexing `args()` by hand.
+//!
+//! - A positional (`input: Text.`) is a bare value, in declaration order
+//! - A flag (`A flag verbose.`) is `--verbose`, true when given
+//! - An option (`An option maxCount: Int.`) is `--max-count <MAX_COUNT>`
+//!
+//! A positional or option declared `Option of` a type may be left out; every
+//! other one is required. Parsed values come back as [`SettingValues`], the
+//! same typed map settings load into.
+//!
+//! ```
+//! use logicaffeine_system::arguments::{self, ArgumentSpec, ArgumentStyle};
+//! use logicaffeine_system::settings::SettingKind;
+//!
+//! let specs = [
+//!     ArgumentSpec { name: "input", kind: SettingKind::Text, optional: false, style: ArgumentStyle::Positional, help: "The file to read" },
+//!     ArgumentSpec { name: "verbose", kind: SettingKind::Bool, optional: false, style: ArgumentStyle::Flag, help: "" },
+//! ];
+//! let values = arguments::parse_from(&specs, ["app", "notes.txt", "--verbose"]).unwrap();
+//! assert_eq!(values.text("input"), "notes.txt");
+//! assert!(values.bool("verbose"));
+//! ```
+
+use std::fmt;
+
+use clap::error::ErrorKind;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use crate::settings::{SettingKind, SettingValue, SettingValues};
+
+/// How a declared argument is written on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgumentStyle {
+    Positional,
+    Flag,
+    Option,
+}
+
+/// One declaration of an `## Arguments` block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArgumentSpec<'a> {
+    pub name: &'a str,
+    pub kind: SettingKind,
+    /// Whether it may be left out (`Option of` a type). Flags never are.
+    pub optional: bool,
+    pub style: ArgumentStyle,
+    /// Shown by `--help`; empty for none.
+    pub help: &'a str,
+}
+
+/// Why parsing stopped before the program could run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgumentsError {
+    /// `--help` was asked for: the rendered help, to print before stopping.
+    Help(String),
+    /// The command line does not match the declarations: the error and usage.
+    Invalid(String),
+}
+
+impl fmt::Display for ArgumentsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgumentsError::Help(text) | ArgumentsError::Invalid(text) => f.write_str(text.trim_end()),
+        }
+    }
+}
+
+impl std::error::Error for ArgumentsError {}
+
+/// The long flag for a declared name: `maxCount` and `max_count` are both
+/// `--max-count`.
+pub fn long_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    let mut prev_lower = false;
+    for c in name.chars() {
+        if c.is_ascii_uppercase() && prev_lower {
+            out.push('-');
+        }
+        prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        out.push(if c == '_' { '-' } else { c.to_ascii_lowercase() });
+    }
+    out
+}
+
+/// The `clap` command for a program's declarations.
+pub fn command(program: &str, specs: &[ArgumentSpec]) -> Command {
+    let mut command = Command::new(program.to_string());
+    for spec in specs {
+        let mut arg = Arg::new(spec.name.to_string());
+        if !spec.help.is_empty() {
+            arg = arg.help(spec.help.to_string());
+        }
+        arg = match spec.style {
+            ArgumentStyle::Flag => arg.long(long_name(spec.name)).action(ArgAction::SetTrue),
+            ArgumentStyle::Option => value_arg(arg.long(long_name(spec.name)), spec),
+            ArgumentStyle::Positional => value_arg(arg, spec),
+        };
+        command = command.arg(arg);
+    }
+    command
+}
+
+fn value_arg(arg: Arg, spec: &ArgumentSpec) -> Arg {
+    let arg = arg
+        .value_name(long_name(spec.name).replace('-', "_").to_ascii_uppercase())
+        .required(!spec.optional)
+        .action(ArgAction::Set);
+    match spec.kind {
+        SettingKind::Int => arg.value_parser(clap::value_parser!(i64)),
+        SettingKind::Real => arg.value_parser(clap::value_parser!(f64)),
+        SettingKind::Text => arg.value_parser(clap::value_parser!(String)),
+        SettingKind::Bool => arg.value_parser(clap::value_parser!(bool)),
+    }
+}
+
+/// Parse `argv` (program name first) against the declarations.
+pub fn parse_from<I, T>(specs: &[ArgumentSpec], argv: I) -> Result<SettingValues, ArgumentsError>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<String>,
+{
+    let argv: Vec<String> = argv.into_iter().map(Into::into).collect();
+    let program = argv.first().map(|p| program_name(p)).unwrap_or("program");
+    match command(program, specs).try_get_matches_from(&argv) {
+        Ok(matches) => Ok(values(specs, &matches)),
+        Err(e) if e.kind() == ErrorKind::DisplayHelp => Err(ArgumentsError::Help(e.render().to_string())),
+        Err(e) => Err(ArgumentsError::Invalid(e.render().to_string())),
+    }
+}
+
+/// Parse the process's own command line, the way a compiled program does:
+/// `--help` prints and exits 0, a usage error prints and exits 2.
+pub fn parse(specs: &[ArgumentSpec]) -> SettingValues {
+    let argv: Vec<String> = std::env::args().collect();
+    let program = argv.first().map(|p| program_name(p)).unwrap_or("program");
+    match command(program, specs).try_get_matches_from(&argv) {
+        Ok(matches) => values(specs, &matches),
+        Err(e) => e.exit(),
+    }
+}
+
+fn program_name(path: &str) -> &str {
+    std::path::Path::new(path).file_stem().and_then(|s| s.to_str()).unwrap_or(path)
+}
+
+fn values(specs: &[ArgumentSpec], matches: &ArgMatches) -> SettingValues {
+    let mut values = SettingValues::default();
+    for spec in specs {
+        let value = match (spec.style, spec.kind) {
+            (ArgumentStyle::Flag, _) => Some(SettingValue::Bool(matches.get_flag(spec.name))),
+            (_, SettingKind::Int) => matches.get_one::<i64>(spec.name).map(|v| SettingValue::Int(*v)),
+            (_, SettingKind::Real) => matches.get_one::<f64>(spec.name).map(|v| SettingValue::Real(*v)),
+            (_, SettingKind::Text) => matches.get_one::<String>(spec.name).map(|v| SettingValue::Text(v.clone())),
+            (_, SettingKind::Bool) => matches.get_one::<bool>(spec.name).map(|v| SettingValue::Bool(*v)),
+        };
+        if let Some(value) = value {
+            values.insert(spec.name, value);
+        }
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPECS: &[ArgumentSpec] = &[
+        ArgumentSpec { name: "input", kind: SettingKind::Text, optional: false, style: ArgumentStyle::Positional, help: "The file to read" },
+        ArgumentSpec { name: "verbose", kind: SettingKind::Bool, optional: false, style: ArgumentStyle::Flag, help: "Print every step" },
+        ArgumentSpec { name: "maxCount", kind: SettingKind::Int, optional: true, style: ArgumentStyle::Option, help: "" },
+    ];
+
+    #[test]
+    fn test_long_names_are_kebab_case() {
+        assert_eq!(long_name("verbose"), "verbose");
+        assert_eq!(long_name("maxCount"), "max-count");
+        assert_eq!(long_name("max_count"), "max-count");
+    }
+
+    #[test]
+    fn test_parses_positionals_flags_and_options() {
+        let values = parse_from(SPECS, ["app", "--max-count", "3", "notes.txt"]).unwrap();
+        assert_eq!(values.text("input"), "notes.txt");
+        assert!(!values.bool("verbose"));
+        assert_eq!(values.optional_int("maxCount"), Some(3));
+
+        let values = parse_from(SPECS, ["app", "notes.txt"]).unwrap();
+        assert_eq!(values.optional_int("maxCount"), None);
+    }
+
+    #[test]
+    fn test_help_lists_every_declaration() {
+        let Err(ArgumentsError::Help(help)) = parse_from(SPECS, ["/bin/app", "--help"]) else {
+            panic!("--help should render the help");
+        };
+        assert!(help.contains("Usage: app [OPTIONS] <INPUT>"), "{help}");
+        assert!(help.contains("The file to read") && help.contains("--verbose") && help.contains("--max-count <MAX_COUNT>"), "{help}");
+    }
+
+    #[test]
+    fn test_usage_errors_name_the_argument() {
+        let Err(ArgumentsError::Invalid(message)) = parse_from(SPECS, ["app", "notes.txt", "--max-count", "many"]) else {
+            panic!("a non-number should be rejected");
+        };
+        assert!(message.contains("--max-count <MAX_COUNT>") && message.contains("many"), "{message}");
+        assert!(matches!(parse_from(SPECS, ["app"]), Err(ArgumentsError::Invalid(_))));
+    }
+}
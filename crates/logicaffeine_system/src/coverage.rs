@@ -0,0 +1,96 @@
+//! Statement Coverage Counters
+//!
+//! Runtime half of coverage for compiled programs. A program compiled with
+//! coverage on declares one [`Counters`] with a probe per LOGOS statement,
+//! calls [`Counters::hit`] as each statement starts, and holds a [`Session`]
+//! for the extent of `Main`. When the session ends the counts are written to
+//! the file named by `LOGOS_COVERAGE_FILE` — one count per line, in probe
+//! order — for the compiler to fold back onto source lines. Without the
+//! variable nothing is written.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use logicaffeine_system::coverage::Counters;
+//!
+//! static COVERAGE: Counters<2> = Counters::new();
+//!
+//! let _session = COVERAGE.session();
+//! COVERAGE.hit(0);
+//! ```
+
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The environment variable naming the file the counts are written to.
+pub const COVERAGE_FILE_VAR: &str = "LOGOS_COVERAGE_FILE";
+
+/// One execution counter per probe, shared by every thread of the program.
+pub struct Counters<const N: usize> {
+    hits: [AtomicU64; N],
+}
+
+impl<const N: usize> Counters<N> {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const ZERO: AtomicU64 = AtomicU64::new(0);
+
+    pub const fn new() -> Self {
+        Counters { hits: [Self::ZERO; N] }
+    }
+
+    /// Count one execution of the statement behind `probe`.
+    #[inline(always)]
+    pub fn hit(&self, probe: usize) {
+        self.hits[probe].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// The counts so far, in probe order.
+    pub fn counts(&self) -> Vec<u64> {
+        self.hits.iter().map(|hit| hit.load(Ordering::Relaxed)).collect()
+    }
+
+    /// Write the counts to `LOGOS_COVERAGE_FILE` when this session is dropped.
+    pub fn session(&'static self) -> Session {
+        Session { hits: &self.hits }
+    }
+}
+
+impl<const N: usize> Default for Counters<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A covered run: writes the counts when dropped, including when `Main`
+/// unwinds from a panic.
+#[must_use = "the counts are written when the session is dropped"]
+pub struct Session {
+    hits: &'static [AtomicU64],
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        let Ok(path) = std::env::var(COVERAGE_FILE_VAR) else { return };
+        let mut out = String::with_capacity(self.hits.len() * 4);
+        for hit in self.hits {
+            let _ = writeln!(out, "{}", hit.load(Ordering::Relaxed));
+        }
+        if let Err(e) = std::fs::write(&path, out) {
+            eprintln!("cannot write coverage to {path}: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counters_count_per_probe() {
+        static COVERAGE: Counters<3> = Counters::new();
+        COVERAGE.hit(0);
+        COVERAGE.hit(2);
+        COVERAGE.hit(2);
+        assert_eq!(COVERAGE.counts(), vec![1, 0, 2]);
+    }
+}
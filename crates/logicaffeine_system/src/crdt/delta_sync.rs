@@ -0,0 +1,432 @@
+//! Delta-State Gossip Protocol
+//!
+//! [`DeltaSync`] is the network-agnostic half of delta synchronization: it
+//! decides what a replica of a [`DeltaCrdt`] sends and how it folds in what
+//! it receives. The transport (GossipSub, see
+//! [`Distributed::mount_delta`](crate::distributed::Distributed::mount_delta))
+//! only moves the resulting [`SyncMessage`]s.
+//!
+//! # Protocol
+//!
+//! - **Batching**: local changes queue up as deltas and leave together, one
+//!   [`SyncMessage::Deltas`] per round, instead of one full state per mutation
+//! - **Anti-entropy**: every few rounds a replica gossips a
+//!   [`SyncMessage::Digest`] of its version. Any peer that has seen more
+//!   answers with the buffered deltas the digest is missing, or with its full
+//!   state if its [`DeltaBuffer`] no longer reaches back that far
+//! - **Causal-context compaction**: each replica tracks the versions its peers
+//!   have announced. Deltas that every known peer has seen are dropped from
+//!   the buffer, which then only holds what someone might still ask for
+//!
+//! A change the CRDT's version doesn't capture (a tombstone, for some types)
+//! yields no delta; the next round ships full state for it instead.
+//!
+//! # Example
+//!
+//! ```
+//! use logicaffeine_data::crdt::PNCounter;
+//! use logicaffeine_system::crdt::DeltaSync;
+//!
+//! let mut alice = PNCounter::with_replica_id(1);
+//! let mut bob = PNCounter::with_replica_id(2);
+//! let mut alice_sync = DeltaSync::new(1);
+//! let mut bob_sync = DeltaSync::new(2);
+//!
+//! alice_sync.mutate(&mut alice, |c| c.increment(3));
+//! alice_sync.mutate(&mut alice, |c| c.increment(4));
+//! for message in alice_sync.round(&alice, false) {
+//!     bob_sync.receive(&mut bob, message);
+//! }
+//! assert_eq!(bob.value(), 7);
+//! ```
+
+use logicaffeine_data::crdt::{DeltaBuffer, DeltaCrdt, ReplicaId, VClock};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// How often a replica flushes its batch of local deltas.
+pub const ROUND_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Every how many rounds a replica gossips its digest.
+pub const ANTI_ENTROPY_ROUNDS: u32 = 10;
+
+/// How many recent deltas a replica keeps to catch peers up.
+pub const DELTA_BUFFER_CAPACITY: usize = 256;
+
+/// What replicas gossip to each other.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SyncMessage<T, D> {
+    /// A batch of deltas, and the sender's version once they are applied.
+    Deltas { from: ReplicaId, version: VClock, deltas: Vec<D> },
+    /// The sender's version, so peers that have seen more can catch it up.
+    Digest { from: ReplicaId, version: VClock },
+    /// Full state, for a peer the buffered deltas can't catch up.
+    State { from: ReplicaId, version: VClock, state: T },
+}
+
+impl<T, D> SyncMessage<T, D> {
+    /// The replica that sent this message.
+    pub fn sender(&self) -> ReplicaId {
+        match self {
+            SyncMessage::Deltas { from, .. }
+            | SyncMessage::Digest { from, .. }
+            | SyncMessage::State { from, .. } => *from,
+        }
+    }
+
+    /// The sender's version when it sent this message.
+    pub fn version(&self) -> &VClock {
+        match self {
+            SyncMessage::Deltas { version, .. }
+            | SyncMessage::Digest { version, .. }
+            | SyncMessage::State { version, .. } => version,
+        }
+    }
+}
+
+/// A snapshot of one replica's sync state, for showing users what hasn't
+/// converged yet rather than merging silently.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncStatus {
+    /// This replica.
+    pub replica: ReplicaId,
+    /// The version of the local state.
+    pub version: VClock,
+    /// Local deltas waiting for the next round.
+    pub pending: usize,
+    /// Whether the next round ships full state instead.
+    pub stale: bool,
+    /// Deltas buffered for catching peers up.
+    pub buffered: usize,
+    /// The latest version each peer has announced.
+    pub peers: HashMap<ReplicaId, VClock>,
+}
+
+impl SyncStatus {
+    /// Whether some peer has announced updates the local state hasn't seen.
+    pub fn behind(&self) -> bool {
+        self.peers.values().any(|theirs| !self.version.dominates(theirs))
+    }
+}
+
+/// One replica's side of the delta gossip protocol.
+///
+/// The CRDT itself stays with the caller; every method that needs it takes it
+/// as an argument, so the state can live behind whatever lock the caller
+/// already uses.
+#[derive(Debug)]
+pub struct DeltaSync<D> {
+    replica: ReplicaId,
+    /// Local deltas waiting for the next round.
+    pending: Vec<D>,
+    /// A local change no delta captured; the next round ships full state.
+    stale: bool,
+    /// Recent deltas, local and received, for catching peers up.
+    buffer: DeltaBuffer<D>,
+    /// The latest version each peer has announced.
+    peers: HashMap<ReplicaId, VClock>,
+}
+
+impl<D: Clone> DeltaSync<D> {
+    /// A replica with the default buffer capacity.
+    pub fn new(replica: ReplicaId) -> Self {
+        Self::with_capacity(replica, DELTA_BUFFER_CAPACITY)
+    }
+
+    /// A replica keeping up to `capacity` recent deltas.
+    pub fn with_capacity(replica: ReplicaId, capacity: usize) -> Self {
+        Self {
+            replica,
+            pending: Vec::new(),
+            stale: false,
+            buffer: DeltaBuffer::new(capacity),
+            peers: HashMap::new(),
+        }
+    }
+
+    pub fn replica(&self) -> ReplicaId {
+        self.replica
+    }
+
+    /// Apply a local mutation and queue its delta for the next round.
+    pub fn mutate<T, F, R>(&mut self, state: &mut T, f: F) -> R
+    where
+        T: DeltaCrdt<Delta = D>,
+        F: FnOnce(&mut T) -> R,
+    {
+        let before = state.version();
+        let result = f(state);
+        self.record(state, &before);
+        result
+    }
+
+    /// Queue the delta of a local change made since version `before`.
+    pub fn record<T: DeltaCrdt<Delta = D>>(&mut self, state: &T, before: &VClock) {
+        let version = state.version();
+        if version == *before {
+            self.stale = true;
+            return;
+        }
+        match state.delta_since(before) {
+            Some(delta) => {
+                self.pending.push(delta.clone());
+                self.buffer.push(version, delta);
+            }
+            None => self.stale = true,
+        }
+    }
+
+    /// Run one round: compact the buffer, flush the pending batch, and, if
+    /// `anti_entropy`, gossip this replica's digest.
+    pub fn round<T>(&mut self, state: &T, anti_entropy: bool) -> Vec<SyncMessage<T, D>>
+    where
+        T: DeltaCrdt<Delta = D> + Clone,
+    {
+        self.compact(state);
+        let mut messages = Vec::new();
+        let version = state.version();
+        if self.stale {
+            self.stale = false;
+            self.pending.clear();
+            messages.push(SyncMessage::State { from: self.replica, version: version.clone(), state: state.clone() });
+        } else if !self.pending.is_empty() {
+            let deltas = std::mem::take(&mut self.pending);
+            messages.push(SyncMessage::Deltas { from: self.replica, version: version.clone(), deltas });
+        }
+        if anti_entropy {
+            messages.push(SyncMessage::Digest { from: self.replica, version });
+        }
+        messages
+    }
+
+    /// Fold in a peer's message, returning the reply to gossip, if any.
+    pub fn receive<T>(&mut self, state: &mut T, message: SyncMessage<T, D>) -> Option<SyncMessage<T, D>>
+    where
+        T: DeltaCrdt<Delta = D> + Clone,
+    {
+        let from = message.sender();
+        if from == self.replica {
+            return None;
+        }
+        self.peers.entry(from).or_default().merge_vclock(message.version());
+        match message {
+            SyncMessage::Deltas { version, deltas, .. } => {
+                for delta in deltas {
+                    state.apply_delta(&delta);
+                    // Kept so this replica can pass them on to peers that missed them
+                    self.buffer.push(version.clone(), delta);
+                }
+                None
+            }
+            SyncMessage::State { state: incoming, .. } => {
+                state.merge(&incoming);
+                None
+            }
+            SyncMessage::Digest { version: theirs, .. } => {
+                let ours = state.version();
+                if theirs.dominates(&ours) {
+                    return None;
+                }
+                match self.buffer.deltas_since(&theirs) {
+                    Some(deltas) if !deltas.is_empty() => {
+                        Some(SyncMessage::Deltas { from: self.replica, version: ours, deltas })
+                    }
+                    _ => Some(SyncMessage::State { from: self.replica, version: ours, state: state.clone() }),
+                }
+            }
+        }
+    }
+
+    /// Drop buffered deltas that this replica and every known peer have seen.
+    fn compact<T: DeltaCrdt<Delta = D>>(&mut self, state: &T) {
+        if self.peers.is_empty() {
+            return;
+        }
+        let seen = self.peers.values().fold(state.version(), |seen, version| seen.meet(version));
+        self.buffer.prune(&seen);
+    }
+
+    /// How many deltas are buffered for catching peers up.
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// What this replica has yet to send, and what it knows of its peers.
+    pub fn status<T: DeltaCrdt<Delta = D>>(&self, state: &T) -> SyncStatus {
+        SyncStatus {
+            replica: self.replica,
+            version: state.version(),
+            pending: self.pending.len(),
+            stale: self.stale,
+            buffered: self.buffer.len(),
+            peers: self.peers.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use logicaffeine_data::crdt::{PNCounter, RGA};
+
+    type Delta = <RGA<char> as DeltaCrdt>::Delta;
+    type Message = SyncMessage<RGA<char>, Delta>;
+    type Replica = (RGA<char>, DeltaSync<Delta>);
+
+    /// Deliver every message to every replica but its sender, and keep
+    /// delivering replies until the network is quiet.
+    fn gossip(replicas: &mut [Replica], mut queue: Vec<Message>) {
+        while let Some(message) = queue.pop() {
+            for (state, sync) in replicas.iter_mut() {
+                queue.extend(sync.receive(state, message.clone()));
+            }
+        }
+    }
+
+    fn text(rga: &RGA<char>) -> String {
+        rga.to_vec().into_iter().collect()
+    }
+
+    fn replicas(n: u64) -> Vec<Replica> {
+        (1..=n).map(|id| (RGA::new(id), DeltaSync::new(id))).collect()
+    }
+
+    #[test]
+    fn test_rounds_batch_local_deltas() {
+        let mut peers = replicas(2);
+        let (state, sync) = &mut peers[0];
+        for c in "abc".chars() {
+            sync.mutate(state, |rga| rga.append(c));
+        }
+        let messages = sync.round(state, false);
+        assert_eq!(messages.len(), 1);
+        let SyncMessage::Deltas { deltas, .. } = &messages[0] else {
+            panic!("expected a batch of deltas, got {:?}", messages[0]);
+        };
+        assert_eq!(deltas.len(), 3);
+        assert!(sync.round(state, false).is_empty(), "the batch leaves once");
+
+        gossip(&mut peers, messages);
+        assert_eq!(text(&peers[1].0), "abc");
+    }
+
+    #[test]
+    fn test_anti_entropy_catches_up_a_late_joiner() {
+        let mut peers = replicas(3);
+        for (i, c) in "xyz".chars().enumerate() {
+            let (state, sync) = &mut peers[i % 2];
+            sync.mutate(state, |rga| rga.append(c));
+        }
+        // Replica 3 misses every batch
+        let mut batches = Vec::new();
+        for (state, sync) in &mut peers[..2] {
+            batches.extend(sync.round(state, false));
+        }
+        gossip(&mut peers[..2], batches);
+        assert_eq!(text(&peers[0].0), text(&peers[1].0));
+        assert_eq!(text(&peers[2].0), "");
+
+        let (state, sync) = &mut peers[2];
+        let digest = sync.round(state, true);
+        gossip(&mut peers, digest);
+        assert_eq!(text(&peers[2].0), text(&peers[0].0));
+        assert_eq!(text(&peers[2].0).len(), 3);
+    }
+
+    #[test]
+    fn test_buffer_compacts_once_every_peer_has_seen_it() {
+        let mut peers = replicas(2);
+        for c in "hello".chars() {
+            let (state, sync) = &mut peers[0];
+            sync.mutate(state, |rga| rga.append(c));
+            let batch = sync.round(state, false);
+            gossip(&mut peers, batch);
+        }
+        assert_eq!(peers[0].1.buffered(), 5);
+
+        // Replica 2's digest shows it has everything
+        let (state, sync) = &mut peers[1];
+        let digest = sync.round(state, true);
+        gossip(&mut peers, digest);
+        let (state, sync) = &mut peers[0];
+        sync.round(state, false);
+        assert_eq!(sync.buffered(), 1);
+    }
+
+    #[test]
+    fn test_evicted_deltas_fall_back_to_full_state() {
+        let mut alice = PNCounter::with_replica_id(1);
+        let mut bob = PNCounter::with_replica_id(2);
+        let mut alice_sync = DeltaSync::with_capacity(1, 2);
+        let mut bob_sync = DeltaSync::with_capacity(2, 2);
+        for _ in 0..5 {
+            alice_sync.mutate(&mut alice, |c| c.increment(1));
+            alice_sync.round(&alice, false);
+        }
+
+        let digest = bob_sync.round(&bob, true).pop().unwrap();
+        let reply = alice_sync.receive(&mut alice, digest).unwrap();
+        assert!(matches!(reply, SyncMessage::State { .. }), "got {:?}", reply);
+        bob_sync.receive(&mut bob, reply);
+        assert_eq!(bob.value(), 5);
+    }
+
+    #[test]
+    fn test_unversioned_changes_ship_full_state() {
+        let mut peers = replicas(2);
+        let (state, sync) = &mut peers[0];
+        sync.mutate(state, |rga| {
+            rga.append('a');
+            rga.append('b');
+        });
+        let batch = sync.round(state, false);
+        gossip(&mut peers, batch);
+
+        // A tombstone doesn't advance the version, so no delta carries it
+        let (state, sync) = &mut peers[0];
+        sync.mutate(state, |rga| rga.remove(0));
+        let messages = sync.round(state, false);
+        assert!(matches!(messages[..], [SyncMessage::State { .. }]), "got {:?}", messages);
+        gossip(&mut peers, messages);
+        assert_eq!(text(&peers[1].0), "b");
+    }
+
+    #[test]
+    fn test_status_reports_pending_deltas_and_peers() {
+        let mut peers = replicas(2);
+        let (state, sync) = &mut peers[0];
+        sync.mutate(state, |rga| rga.append('a'));
+        sync.mutate(state, |rga| rga.append('b'));
+        let status = sync.status(state);
+        assert_eq!(status.pending, 2);
+        assert!(status.peers.is_empty());
+
+        let batch = sync.round(state, false);
+        gossip(&mut peers, batch);
+        let (state, sync) = &mut peers[1];
+        let status = sync.status(state);
+        assert_eq!(status.pending, 0);
+        assert_eq!(status.peers.keys().collect::<Vec<_>>(), vec![&1]);
+        assert!(!status.behind());
+
+        // A digest from a peer that has seen more leaves this replica behind
+        let (state, sync) = &mut peers[0];
+        sync.mutate(state, |rga| rga.append('c'));
+        let digest = sync.round(state, true).pop().unwrap();
+        let (state, sync) = &mut peers[1];
+        sync.receive(state, digest);
+        assert!(sync.status(state).behind());
+    }
+
+    #[test]
+    fn test_own_messages_are_ignored() {
+        let mut alice = PNCounter::with_replica_id(1);
+        let mut sync = DeltaSync::new(1);
+        sync.mutate(&mut alice, |c| c.increment(2));
+        for message in sync.round(&alice, true) {
+            assert!(sync.receive(&mut alice, message).is_none());
+        }
+        assert_eq!(alice.value(), 2);
+    }
+}
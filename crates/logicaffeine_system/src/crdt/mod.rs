@@ -1,10 +1,16 @@
 //! CRDT Synchronization Wrappers
 //!
 //! This module provides the `Synced<T>` wrapper which adds automatic
-//! network synchronization to CRDT types from logicaffeine_data.
+//! network synchronization to CRDT types from logicaffeine_data, optionally
+//! with signed operations ([`Signer`], [`Keyring`]), and the [`DeltaSync`]
+//! gossip protocol that ships deltas instead of full state.
 //!
 //! Requires the `networking` feature to be enabled.
 
+mod delta_sync;
+mod signing;
 mod sync;
 
+pub use delta_sync::{DeltaSync, SyncMessage, SyncStatus, ANTI_ENTROPY_ROUNDS, DELTA_BUFFER_CAPACITY, ROUND_INTERVAL};
+pub use signing::{Keyring, Rejected, Signed, Signer};
 pub use sync::Synced;
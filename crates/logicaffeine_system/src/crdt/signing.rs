@@ -0,0 +1,295 @@
+//! Signed CRDT Operations
+//!
+//! Anyone who can reach a GossipSub topic can publish to it, and a plain
+//! [`Synced<T>`](super::Synced) merges whatever arrives. Signing closes that:
+//! - Each replica holds a [`Signer`]: its replica id and an ed25519 key
+//! - Every published state travels as [`Signed`], signed over the topic, the
+//!   author's replica id, and the encoded state
+//! - Receivers keep a [`Keyring`] of the replicas they trust. Messages from
+//!   unknown replicas, or whose signature doesn't check out, are dropped
+//! - What passes is merged with [`AuthoredMerge::merge_authored`], so an
+//!   authenticated replica still can't write on another replica's behalf — a
+//!   counter only takes the author's own increments, and a set only the
+//!   author's adds and its removals of adds the receiver has seen
+//!
+//! Only types implementing [`AuthoredMerge`] can be synced signed: counters,
+//! [`ORSet`](logicaffeine_data::crdt::ORSet) and
+//! [`MVRegister`](logicaffeine_data::crdt::MVRegister). Types that can't tell
+//! one author's writes from another's don't compile against it.
+//!
+//! Replaying an old signed message is harmless: merges are idempotent, and an
+//! old state is already covered by newer ones.
+
+use crate::network::wire;
+use ed25519_dalek::{Signature, Signer as _, SigningKey, Verifier as _, VerifyingKey};
+use logicaffeine_data::crdt::{AuthoredMerge, ReplicaId};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Domain separation, so these signatures can't be passed off as any other.
+const DOMAIN: &[u8] = b"logicaffeine/crdt-op/v1";
+
+/// A replica's identity for signing its operations.
+pub struct Signer {
+    replica: ReplicaId,
+    key: SigningKey,
+}
+
+impl Signer {
+    /// A fresh random key for `replica`.
+    pub fn generate(replica: ReplicaId) -> Self {
+        Self::from_seed(replica, rand::random())
+    }
+
+    /// The key derived from a 32-byte secret seed, for replicas that keep
+    /// their identity across restarts.
+    pub fn from_seed(replica: ReplicaId, seed: [u8; 32]) -> Self {
+        Self { replica, key: SigningKey::from_bytes(&seed) }
+    }
+
+    pub fn replica(&self) -> ReplicaId {
+        self.replica
+    }
+
+    /// The public key peers add to their [`Keyring`].
+    pub fn public_key(&self) -> [u8; 32] {
+        self.key.verifying_key().to_bytes()
+    }
+
+    /// Sign `state` for publishing on `topic`.
+    pub fn sign<T: Serialize>(&self, topic: &str, state: &T) -> Result<Signed, Rejected> {
+        let payload = wire::encode(state).map_err(|e| Rejected::Malformed(e.to_string()))?;
+        let signature = self.key.sign(&signed_bytes(topic, self.replica, &payload));
+        Ok(Signed { author: self.replica, payload, signature: signature.to_bytes().to_vec() })
+    }
+}
+
+impl fmt::Debug for Signer {
+    // Never print the secret key
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Signer").field("replica", &self.replica).finish_non_exhaustive()
+    }
+}
+
+/// An encoded state, its author, and the author's signature.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Signed {
+    pub author: ReplicaId,
+    pub payload: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+/// Why a signed message was dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejected {
+    /// No trusted key for the claimed author.
+    UnknownReplica(ReplicaId),
+    /// The signature doesn't match the author's key.
+    BadSignature(ReplicaId),
+    /// The message or its payload doesn't decode.
+    Malformed(String),
+}
+
+impl fmt::Display for Rejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejected::UnknownReplica(replica) => write!(f, "no trusted key for replica {}", replica),
+            Rejected::BadSignature(replica) => write!(f, "bad signature claiming replica {}", replica),
+            Rejected::Malformed(e) => write!(f, "malformed signed message: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Rejected {}
+
+/// The replicas a receiver trusts, by public key.
+#[derive(Debug, Clone, Default)]
+pub struct Keyring {
+    keys: HashMap<ReplicaId, VerifyingKey>,
+}
+
+impl Keyring {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Trust `replica`'s operations signed with `public_key`.
+    ///
+    /// Fails if the bytes aren't a valid ed25519 public key.
+    pub fn trust(&mut self, replica: ReplicaId, public_key: [u8; 32]) -> Result<(), Rejected> {
+        let key = VerifyingKey::from_bytes(&public_key)
+            .map_err(|e| Rejected::Malformed(e.to_string()))?;
+        self.keys.insert(replica, key);
+        Ok(())
+    }
+
+    /// Stop trusting `replica`.
+    pub fn revoke(&mut self, replica: ReplicaId) {
+        self.keys.remove(&replica);
+    }
+
+    pub fn trusts(&self, replica: ReplicaId) -> bool {
+        self.keys.contains_key(&replica)
+    }
+
+    /// Check a message received on `topic` and decode its state.
+    pub fn verify<T: DeserializeOwned>(&self, topic: &str, signed: &Signed) -> Result<T, Rejected> {
+        let key = self.keys.get(&signed.author).ok_or(Rejected::UnknownReplica(signed.author))?;
+        let signature = Signature::from_slice(&signed.signature)
+            .map_err(|_| Rejected::BadSignature(signed.author))?;
+        key.verify(&signed_bytes(topic, signed.author, &signed.payload), &signature)
+            .map_err(|_| Rejected::BadSignature(signed.author))?;
+        wire::decode(&signed.payload).map_err(|e| Rejected::Malformed(e.to_string()))
+    }
+
+    /// Verify raw bytes from the network and merge the state into `target`,
+    /// taking only what the author could have written.
+    pub fn merge_into<T>(&self, topic: &str, bytes: &[u8], target: &mut T) -> Result<ReplicaId, Rejected>
+    where
+        T: AuthoredMerge + DeserializeOwned,
+    {
+        let signed: Signed = wire::decode(bytes).map_err(|e| Rejected::Malformed(e.to_string()))?;
+        let incoming: T = self.verify(topic, &signed)?;
+        target.merge_authored(&incoming, signed.author);
+        Ok(signed.author)
+    }
+}
+
+/// What a signature covers: the domain, the topic, the author, the payload.
+///
+/// Lengths prefix the variable parts so no two messages share these bytes.
+fn signed_bytes(topic: &str, author: ReplicaId, payload: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(DOMAIN.len() + topic.len() + payload.len() + 24);
+    bytes.extend_from_slice(DOMAIN);
+    bytes.extend_from_slice(&(topic.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(topic.as_bytes());
+    bytes.extend_from_slice(&author.to_le_bytes());
+    bytes.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(payload);
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use logicaffeine_data::crdt::{GCounter, Merge, ORSet};
+
+    fn ring(signers: &[&Signer]) -> Keyring {
+        let mut keyring = Keyring::new();
+        for signer in signers {
+            keyring.trust(signer.replica(), signer.public_key()).unwrap();
+        }
+        keyring
+    }
+
+    fn publish<T: Serialize>(signer: &Signer, topic: &str, state: &T) -> Vec<u8> {
+        wire::encode(&signer.sign(topic, state).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_signed_state_merges() {
+        let alice = Signer::generate(1);
+        let mut counter = GCounter::with_replica_id(1);
+        counter.increment(3);
+
+        let mut received = GCounter::with_replica_id(2);
+        let author = ring(&[&alice]).merge_into("votes", &publish(&alice, "votes", &counter), &mut received);
+        assert_eq!(author, Ok(1));
+        assert_eq!(received.value(), 3);
+    }
+
+    #[test]
+    fn test_untrusted_replicas_are_rejected() {
+        let mallory = Signer::generate(9);
+        let mut counter = GCounter::with_replica_id(9);
+        counter.increment(100);
+
+        let mut received = GCounter::with_replica_id(2);
+        let result = Keyring::new().merge_into("votes", &publish(&mallory, "votes", &counter), &mut received);
+        assert_eq!(result, Err(Rejected::UnknownReplica(9)));
+        assert_eq!(received.value(), 0);
+    }
+
+    #[test]
+    fn test_forged_author_fails_the_signature() {
+        let alice = Signer::generate(1);
+        let mallory = Signer::generate(9);
+        let mut counter = GCounter::with_replica_id(1);
+        counter.increment(100);
+
+        // Mallory signs with her own key but claims to be alice
+        let mut signed = mallory.sign("votes", &counter).unwrap();
+        signed.author = 1;
+        let bytes = wire::encode(&signed).unwrap();
+
+        let mut received = GCounter::with_replica_id(2);
+        let result = ring(&[&alice, &mallory]).merge_into("votes", &bytes, &mut received);
+        assert_eq!(result, Err(Rejected::BadSignature(1)));
+        assert_eq!(received.value(), 0);
+    }
+
+    #[test]
+    fn test_tampered_payloads_and_other_topics_fail() {
+        let alice = Signer::generate(1);
+        let keyring = ring(&[&alice]);
+        let mut counter = GCounter::with_replica_id(1);
+        counter.increment(1);
+
+        let mut signed = alice.sign("votes", &counter).unwrap();
+        assert!(keyring.verify::<GCounter>("scores", &signed).is_err(), "signed for another topic");
+        let last = signed.payload.len() - 1;
+        signed.payload[last] ^= 1;
+        assert_eq!(keyring.verify::<GCounter>("votes", &signed), Err(Rejected::BadSignature(1)));
+    }
+
+    #[test]
+    fn test_trusted_replicas_cannot_write_for_others() {
+        let alice = Signer::generate(1);
+        let bob = Signer::generate(2);
+        let mut local = GCounter::with_replica_id(1);
+        local.increment(2);
+
+        // Bob is trusted, but only for his own increments
+        let mut forged = GCounter::with_replica_id(1);
+        forged.increment(50);
+        let mut bobs = GCounter::with_replica_id(2);
+        bobs.increment(1);
+        forged.merge(&bobs);
+
+        ring(&[&alice, &bob]).merge_into("votes", &publish(&bob, "votes", &forged), &mut local).unwrap();
+        assert_eq!(local.value(), 3);
+    }
+
+    #[test]
+    fn test_trusted_replicas_cannot_forge_removals() {
+        let alice = Signer::generate(1);
+        let bob = Signer::generate(2);
+        let keyring = ring(&[&alice, &bob]);
+
+        let mut alices: ORSet<String> = ORSet::new(1);
+        alices.add("ship".to_string());
+        let mut local: ORSet<String> = ORSet::new(3);
+        keyring.merge_into("board", &publish(&alice, "board", &alices), &mut local).unwrap();
+
+        // Bob claims to have seen, and removed, alice's next ten adds
+        let mut forged: ORSet<String> = ORSet::new(1);
+        for _ in 0..10 {
+            forged.add("decoy".to_string());
+        }
+        forged.remove(&"decoy".to_string());
+        keyring.merge_into("board", &publish(&bob, "board", &forged), &mut local).unwrap();
+
+        alices.add("fleet".to_string());
+        keyring.merge_into("board", &publish(&alice, "board", &alices), &mut local).unwrap();
+        assert!(local.contains(&"fleet".to_string()));
+    }
+
+    #[test]
+    fn test_seeded_signers_keep_their_key() {
+        let a = Signer::from_seed(1, [7; 32]);
+        let b = Signer::from_seed(1, [7; 32]);
+        assert_eq!(a.public_key(), b.public_key());
+        assert!(!format!("{:?}", a).contains("key"));
+    }
+}
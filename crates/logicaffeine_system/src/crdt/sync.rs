@@ -20,6 +20,14 @@
 //! (e.g., cursor positions, typing indicators). Use [`Distributed<T>`](crate::distributed::Distributed)
 //! for state that must persist (e.g., game scores, document content).
 //!
+//! # Signed Operations
+//!
+//! [`Synced::new`] merges whatever any peer on the topic publishes.
+//! [`Synced::new_signed`] signs every published state with the replica's
+//! [`Signer`] and merges only states signed by a replica in its [`Keyring`],
+//! taking just the part each author could have written. It needs a type that
+//! implements [`AuthoredMerge`].
+//!
 //! # Features
 //!
 //! Requires the `networking` feature.
@@ -43,7 +51,8 @@
 //! # }
 //! ```
 
-use logicaffeine_data::crdt::Merge;
+use logicaffeine_data::crdt::{AuthoredMerge, Merge};
+use super::signing::{Keyring, Signer};
 use crate::network::{gossip, wire};
 use serde::{de::DeserializeOwned, Serialize};
 use std::sync::Arc;
@@ -68,6 +77,7 @@ use tokio::sync::Mutex;
 pub struct Synced<T: Merge + Serialize + DeserializeOwned + Clone + Send + 'static> {
     inner: Arc<Mutex<T>>,
     topic: String,
+    signer: Option<Signer>,
 }
 
 impl<T: Merge + Serialize + DeserializeOwned + Clone + Send + 'static> Synced<T> {
@@ -102,6 +112,7 @@ impl<T: Merge + Serialize + DeserializeOwned + Clone + Send + 'static> Synced<T>
         Self {
             inner,
             topic: topic_str,
+            signer: None,
         }
     }
 
@@ -120,7 +131,13 @@ impl<T: Merge + Serialize + DeserializeOwned + Clone + Send + 'static> Synced<T>
         let state = guard.clone();
         drop(guard); // Release lock before async publish
 
-        gossip::publish(&self.topic, &state).await;
+        match &self.signer {
+            Some(signer) => match signer.sign(&self.topic, &state) {
+                Ok(signed) => gossip::publish(&self.topic, &signed).await,
+                Err(e) => eprintln!("[gossip] Signing failed: {}", e),
+            },
+            None => gossip::publish(&self.topic, &state).await,
+        }
 
         result
     }
@@ -139,6 +156,57 @@ impl<T: Merge + Serialize + DeserializeOwned + Clone + Send + 'static> Synced<T>
     }
 }
 
+impl<T: AuthoredMerge + Serialize + DeserializeOwned + Clone + Send + 'static> Synced<T> {
+    /// Create a synced wrapper that signs what it publishes and only merges
+    /// what replicas in `keyring` signed.
+    ///
+    /// Incoming states merge through [`AuthoredMerge::merge_authored`], so a
+    /// trusted replica can only contribute its own writes. Unsigned, unknown,
+    /// or badly signed messages are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use logicaffeine_system::crdt::{Keyring, Signer, Synced};
+    /// # use logicaffeine_data::crdt::GCounter;
+    /// # fn main() {}
+    /// # async fn example(teammate: u64, teammate_key: [u8; 32]) -> Result<(), Box<dyn std::error::Error>> {
+    /// let signer = Signer::generate(1);
+    /// let mut keyring = Keyring::new();
+    /// keyring.trust(teammate, teammate_key)?;
+    ///
+    /// let synced = Synced::new_signed(GCounter::with_replica_id(1), "game-scores", signer, keyring).await;
+    /// synced.mutate(|c| c.increment(5)).await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn new_signed(initial: T, topic: &str, signer: Signer, keyring: Keyring) -> Self {
+        let inner = Arc::new(Mutex::new(initial));
+        let topic_str = topic.to_string();
+
+        // Subscribe FIRST, await completion to ensure mesh membership
+        let mut rx = gossip::subscribe(&topic_str).await;
+
+        // THEN spawn background verify-and-merge task
+        let inner_clone = Arc::clone(&inner);
+        let topic_clone = topic_str.clone();
+        tokio::spawn(async move {
+            while let Some(bytes) = rx.recv().await {
+                let mut guard = inner_clone.lock().await;
+                if let Err(e) = keyring.merge_into(&topic_clone, &bytes, &mut *guard) {
+                    eprintln!("[gossip] Rejected message: {}", e);
+                }
+            }
+        });
+
+        Self {
+            inner,
+            topic: topic_str,
+            signer: Some(signer),
+        }
+    }
+}
+
 // =============================================================================
 // Test infrastructure (compiles out in release)
 // =============================================================================
@@ -182,6 +250,15 @@ mod tests {
         assert_eq!(value.value(), 10);
     }
 
+    #[tokio::test]
+    async fn test_synced_signed_mutate() {
+        let signer = Signer::generate(1);
+        let synced = Synced::new_signed(GCounter::with_replica_id(1), "test-signed", signer, Keyring::new()).await;
+
+        synced.mutate(|c| c.increment(4)).await;
+        assert_eq!(synced.get().await.value(), 4);
+    }
+
     #[tokio::test]
     async fn test_synced_get() {
         let counter = GCounter::with_replica_id(1);
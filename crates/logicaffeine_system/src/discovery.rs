@@ -0,0 +1,174 @@
+//! Peer Discovery Settings
+//!
+//! How a node finds the rest of the swarm, chosen with a [`Discovery`]:
+//!
+//! - **Local**: mDNS finds and dials peers on the same network. On by default
+//! - **Bootstrap**: a fixed list of peers (multiaddrs) dialed when joining
+//! - **Relays**: WebSocket relays (`relay`) for nodes that
+//!   can't be dialed directly
+//!
+//! Projects list their bootstrap peers and relays in `Largo.toml`:
+//!
+//! ```toml
+//! [network]
+//! mdns = true
+//! bootstrap = ["/ip4/203.0.113.7/tcp/8000/p2p/12D3KooW..."]
+//! relays = ["ws://relay.example.org:9000"]
+//! ```
+//!
+//! `largo build` bakes these into the program, and the `LOGOS_MDNS`,
+//! `LOGOS_BOOTSTRAP` and `LOGOS_RELAYS` environment variables override them
+//! when it runs (see [`Discovery::configured`]).
+//!
+//! Only settings live here — no libp2p, no target gating — so the interpreter
+//! reads the same configuration as the compiled path. The mesh joins with
+//! `network::join_swarm`.
+
+/// Which ways of finding peers a node uses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Discovery {
+    local: bool,
+    bootstrap: Vec<String>,
+    relays: Vec<String>,
+}
+
+impl Default for Discovery {
+    /// mDNS only — what a mesh node has always done.
+    fn default() -> Self {
+        Self { local: true, bootstrap: Vec::new(), relays: Vec::new() }
+    }
+}
+
+impl Discovery {
+    /// No discovery at all: peers must be dialed with `Connect to`.
+    pub fn none() -> Self {
+        Self { local: false, bootstrap: Vec::new(), relays: Vec::new() }
+    }
+
+    /// The project's configuration: what `largo build` baked in from
+    /// `Largo.toml`, overridden by `LOGOS_MDNS`, `LOGOS_BOOTSTRAP` and
+    /// `LOGOS_RELAYS` at run time.
+    ///
+    /// Lists are separated by commas or whitespace; `LOGOS_MDNS` turns mDNS
+    /// off with `0`, `false` or `off`.
+    pub fn configured() -> Self {
+        let setting = |name: &str, baked: Option<&'static str>| std::env::var(name).ok().or(baked.map(String::from));
+        let mdns = setting("LOGOS_MDNS", option_env!("LOGOS_MDNS")).map(|v| v.trim().to_ascii_lowercase());
+        Self {
+            local: !matches!(mdns.as_deref(), Some("0" | "false" | "off")),
+            bootstrap: setting("LOGOS_BOOTSTRAP", option_env!("LOGOS_BOOTSTRAP")).map(|v| parse_list(&v)).unwrap_or_default(),
+            relays: setting("LOGOS_RELAYS", option_env!("LOGOS_RELAYS")).map(|v| parse_list(&v)).unwrap_or_default(),
+        }
+    }
+
+    /// Turn mDNS on or off.
+    pub fn with_local(mut self, on: bool) -> Self {
+        self.local = on;
+        self
+    }
+
+    /// Dial `addr` (a multiaddr) when joining.
+    pub fn with_bootstrap(mut self, addr: impl Into<String>) -> Self {
+        self.bootstrap.push(addr.into());
+        self
+    }
+
+    /// Reach the swarm through the relay at `url` (`ws://host:port`, or a
+    /// `/ip4/H/tcp/P` multiaddr).
+    pub fn with_relay(mut self, url: impl Into<String>) -> Self {
+        self.relays.push(url.into());
+        self
+    }
+
+    /// Dial each of `addrs` when joining.
+    pub fn with_bootstrap_peers(mut self, addrs: impl AddressList) -> Self {
+        self.bootstrap.extend(addrs.into_addresses());
+        self
+    }
+
+    /// Reach the swarm through each of the relays at `urls`.
+    pub fn with_relays(mut self, urls: impl AddressList) -> Self {
+        self.relays.extend(urls.into_addresses());
+        self
+    }
+
+    pub fn uses_local(&self) -> bool {
+        self.local
+    }
+
+    pub fn bootstrap_peers(&self) -> &[String] {
+        &self.bootstrap
+    }
+
+    pub fn relays(&self) -> &[String] {
+        &self.relays
+    }
+}
+
+/// One address or a list of them, as a program writes it.
+pub trait AddressList {
+    fn into_addresses(self) -> Vec<String>;
+}
+
+impl AddressList for String {
+    fn into_addresses(self) -> Vec<String> {
+        vec![self]
+    }
+}
+
+impl AddressList for &str {
+    fn into_addresses(self) -> Vec<String> {
+        vec![self.to_string()]
+    }
+}
+
+impl AddressList for &[String] {
+    fn into_addresses(self) -> Vec<String> {
+        self.to_vec()
+    }
+}
+
+impl AddressList for Vec<String> {
+    fn into_addresses(self) -> Vec<String> {
+        self
+    }
+}
+
+impl AddressList for logicaffeine_data::LogosSeq<String> {
+    fn into_addresses(self) -> Vec<String> {
+        self.0.borrow().clone()
+    }
+}
+
+fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_collects_methods() {
+        let discovery = Discovery::none()
+            .with_bootstrap("/ip4/10.0.0.2/tcp/8000")
+            .with_relay("ws://10.0.0.3:9000");
+        assert!(!discovery.uses_local());
+        assert_eq!(discovery.bootstrap_peers(), ["/ip4/10.0.0.2/tcp/8000"]);
+        assert_eq!(discovery.relays(), ["ws://10.0.0.3:9000"]);
+        assert!(Discovery::default().uses_local());
+
+        let listed = Discovery::none().with_relays(vec!["ws://a:1".to_string(), "ws://b:2".to_string()]);
+        assert_eq!(listed.relays(), ["ws://a:1", "ws://b:2"]);
+    }
+
+    #[test]
+    fn test_lists_split_on_commas_and_whitespace() {
+        assert_eq!(parse_list("/ip4/1.2.3.4/tcp/1, /ip4/5.6.7.8/tcp/2\n"), ["/ip4/1.2.3.4/tcp/1", "/ip4/5.6.7.8/tcp/2"]);
+        assert!(parse_list(" , ").is_empty());
+    }
+}
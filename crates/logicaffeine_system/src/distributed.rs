@@ -11,6 +11,32 @@
 //! Remote update:    Network → RAM → Journal
 //! ```
 //!
+//! # Delta Sync
+//!
+//! [`Distributed::mount`] broadcasts the whole state after every mutation. For
+//! a [`DeltaCrdt`], [`Distributed::mount_delta`] runs the
+//! [`DeltaSync`](crate::crdt::DeltaSync) gossip protocol instead: mutations
+//! are batched into deltas, and periodic anti-entropy rounds catch up peers
+//! that missed some, so many peers converge without shipping full state.
+//!
+//! # Signed Sync
+//!
+//! [`Distributed::mount_signed`] signs every published state and merges only
+//! states signed by trusted replicas, taking just what each author could have
+//! written (see [`crate::crdt::Keyring`]). It needs a type implementing
+//! [`AuthoredMerge`] and ships full state: delta gossip is unsigned.
+//!
+//! # Crash Recovery
+//!
+//! The journal is a write-ahead log: every change is appended before it is
+//! broadcast, and compaction replaces it with a snapshot via write-then-rename.
+//! Mounting replays the snapshot and the entries after it. A crash mid-append
+//! leaves a torn last entry (cut short, or failing its checksum); recovery
+//! drops it and truncates the file, so new entries never land behind garbage.
+//! A delta-synced value also journals its replica id, so a restarted node
+//! rejoins as the same replica and, in its first round, gossips the recovered
+//! vector clock — peers send only what it missed while down.
+//!
 //! # Features
 //!
 //! Requires both `networking` and `persistence` features.
@@ -47,7 +73,13 @@
 //! # }
 //! ```
 
-use logicaffeine_data::crdt::Merge;
+use logicaffeine_data::crdt::{Merge, ReplicaId};
+#[cfg(not(target_arch = "wasm32"))]
+use logicaffeine_data::crdt::{AuthoredMerge, DeltaCrdt, VClock};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::crdt::{
+    DeltaSync, Keyring, Signer, SyncMessage, SyncStatus, ANTI_ENTROPY_ROUNDS, ROUND_INTERVAL,
+};
 use crate::fs::{Vfs, VfsResult, VfsError};
 use async_lock::Mutex;
 use serde::{de::DeserializeOwned, Serialize};
@@ -63,6 +95,18 @@ enum JournalOp<T> {
     Snapshot(T),
     /// Delta operation (for incremental updates)
     Delta(T),
+    /// This node's replica id in delta gossip, kept across restarts
+    Replica(ReplicaId),
+}
+
+/// What replaying a journal recovers.
+struct Recovered<T> {
+    state: T,
+    entry_count: u64,
+    /// The journaled delta-gossip replica id, if any.
+    replica: Option<ReplicaId>,
+    /// Where the last whole entry ends; anything after it is a torn write.
+    valid_len: usize,
 }
 
 /// Journal entry header format: [4 bytes: length][4 bytes: crc32][N bytes: payload]
@@ -88,13 +132,15 @@ impl JournalHeader {
 }
 
 /// Replay journal entries to reconstruct state.
-fn replay_journal<T>(data: &[u8]) -> Result<(T, u64), VfsError>
+fn replay_journal<T>(data: &[u8]) -> Result<Recovered<T>, VfsError>
 where
     T: Merge + Serialize + DeserializeOwned + Clone + Default,
 {
     let mut state = T::default();
     let mut entry_count = 0u64;
+    let mut replica = None;
     let mut pos = 0;
+    let mut valid_len = 0;
 
     while pos + JournalHeader::SIZE <= data.len() {
         let header_bytes: [u8; 8] = data[pos..pos + 8].try_into().unwrap();
@@ -111,6 +157,10 @@ where
         let actual_checksum = crc32fast::hash(payload);
 
         if actual_checksum != expected_checksum {
+            if payload_end == data.len() {
+                // Torn last entry - stop replay (WAL semantics)
+                break;
+            }
             return Err(VfsError::JournalCorrupted(
                 format!("Entry {} checksum mismatch", entry_count)
             ));
@@ -122,19 +172,78 @@ where
         match op {
             JournalOp::Snapshot(s) => state = s,
             JournalOp::Delta(d) => state.merge(&d),
+            JournalOp::Replica(id) => replica = Some(id),
         }
 
         pos = payload_end;
+        valid_len = pos;
         entry_count += 1;
     }
 
-    Ok((state, entry_count))
+    Ok(Recovered { state, entry_count, replica, valid_len })
+}
+
+/// Replay the journal at `path`, truncating a torn write off its end.
+async fn recover<T>(vfs: &dyn Vfs, path: &str) -> VfsResult<Recovered<T>>
+where
+    T: Merge + Serialize + DeserializeOwned + Clone + Default,
+{
+    if !vfs.exists(path).await.unwrap_or(false) {
+        return Ok(Recovered { state: T::default(), entry_count: 0, replica: None, valid_len: 0 });
+    }
+    let data = vfs.read(path).await?;
+    let recovered = replay_journal(&data)?;
+    if recovered.valid_len < data.len() {
+        // Atomic: write temp → rename
+        let temp_path = format!("{}.tmp", path);
+        vfs.write(&temp_path, &data[..recovered.valid_len]).await?;
+        vfs.rename(&temp_path, path).await?;
+    }
+    Ok(recovered)
+}
+
+/// Frame a journal operation: header, then payload.
+#[cfg(not(target_arch = "wasm32"))]
+fn journal_entry<T: Serialize>(op: &JournalOp<T>) -> VfsResult<Vec<u8>> {
+    let payload = bincode::serialize(op)
+        .map_err(|e| VfsError::SerializationError(e.to_string()))?;
+    let header = JournalHeader::encode(&payload);
+    let mut entry = Vec::with_capacity(JournalHeader::SIZE + payload.len());
+    entry.extend_from_slice(&header);
+    entry.extend_from_slice(&payload);
+    Ok(entry)
 }
 
 // =============================================================================
 // Native Implementation (with network support)
 // =============================================================================
 
+/// How a delta-synced [`Distributed<T>`] hands local changes to its gossip
+/// task, without `Distributed<T>` itself requiring `T: DeltaCrdt`.
+#[cfg(not(target_arch = "wasm32"))]
+trait DeltaLink<T>: Send + Sync {
+    fn version(&self, state: &T) -> VClock;
+    fn record(&self, state: &T, before: &VClock);
+    fn status(&self, state: &T) -> Option<SyncStatus>;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: DeltaCrdt> DeltaLink<T> for std::sync::Mutex<DeltaSync<T::Delta>> {
+    fn version(&self, state: &T) -> VClock {
+        state.version()
+    }
+
+    fn record(&self, state: &T, before: &VClock) {
+        if let Ok(mut sync) = self.lock() {
+            sync.record(state, before);
+        }
+    }
+
+    fn status(&self, state: &T) -> Option<SyncStatus> {
+        self.lock().ok().map(|sync| sync.status(state))
+    }
+}
+
 /// A distributed, persistent CRDT wrapper.
 ///
 /// Combines:
@@ -144,6 +253,7 @@ where
 ///
 /// When `topic` is Some, the value is synchronized via GossipSub.
 /// When `topic` is None, it behaves identically to `Persistent<T>`.
+/// Values mounted with [`Distributed::mount_delta`] gossip deltas instead.
 #[cfg(not(target_arch = "wasm32"))]
 pub struct Distributed<T>
 where
@@ -155,6 +265,9 @@ where
     topic: Option<String>,
     entry_count: Arc<Mutex<u64>>,
     local_peer_id: Option<String>,
+    link: Option<Arc<dyn DeltaLink<T>>>,
+    replica: Option<ReplicaId>,
+    signer: Option<Signer>,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -192,24 +305,24 @@ where
         topic: Option<String>,
     ) -> VfsResult<Self> {
         // 1. Replay journal to reconstruct state
-        let (state, entry_count) = if vfs.exists(path).await.unwrap_or(false) {
-            let data = vfs.read(path).await?;
-            replay_journal(&data)?
-        } else {
-            (T::default(), 0)
-        };
+        let recovered = recover::<T>(&*vfs, path).await?;
 
         // 2. Get local peer ID for echo detection
         let local_peer_id = crate::network::gossip::local_peer_id().await;
 
         let dist = Self {
-            inner: Arc::new(Mutex::new(state)),
+            inner: Arc::new(Mutex::new(recovered.state)),
             vfs,
             journal_path: path.to_string(),
             topic: topic.clone(),
-            entry_count: Arc::new(Mutex::new(entry_count)),
+            entry_count: Arc::new(Mutex::new(recovered.entry_count)),
             local_peer_id,
+            link: None,
+            replica: recovered.replica,
+            signer: None,
         };
+        // A long replay is snapshotted so the next restart is quick
+        dist.maybe_compact().await?;
 
         // 3. Subscribe to network and spawn receive loop
         if let Some(ref topic_name) = topic {
@@ -231,6 +344,7 @@ where
     {
         // 1. Apply to RAM
         let mut guard = self.inner.lock().await;
+        let before = self.link.as_ref().map(|link| link.version(&guard));
         let result = f(&mut *guard);
         let snapshot = (*guard).clone();
         drop(guard);
@@ -238,12 +352,21 @@ where
         // 2. Persist delta to Journal
         self.append_to_journal(&snapshot).await?;
 
-        // 3. Broadcast to Network (if synced)
-        if let Some(topic) = &self.topic {
-            let bytes = bincode::serialize(&snapshot)
-                .map_err(|e| VfsError::SerializationError(e.to_string()))?;
+        // 3. Broadcast to Network (if synced): delta-synced values queue the
+        //    change for the next gossip round, the rest ship full state now
+        if let (Some(link), Some(before)) = (&self.link, &before) {
+            link.record(&snapshot, before);
+        } else if let Some(topic) = &self.topic {
             // Fire and forget - network failures shouldn't block local operations
-            let _ = crate::network::gossip::publish_raw(topic, bytes).await;
+            if let Some(signer) = &self.signer {
+                let signed = signer.sign(topic, &snapshot)
+                    .map_err(|e| VfsError::SerializationError(e.to_string()))?;
+                crate::network::gossip::publish(topic, &signed).await;
+            } else {
+                let bytes = bincode::serialize(&snapshot)
+                    .map_err(|e| VfsError::SerializationError(e.to_string()))?;
+                let _ = crate::network::gossip::publish_raw(topic, bytes).await;
+            }
         }
 
         // 4. Auto-compact if needed
@@ -350,24 +473,246 @@ where
     }
 
     /// Write snapshot and reset journal.
+    ///
+    /// The replica id, if any, follows the snapshot into the new journal.
     async fn do_compact(&self, snapshot: &T) -> VfsResult<()> {
-        let op = JournalOp::Snapshot(snapshot.clone());
-        let payload = bincode::serialize(&op)
-            .map_err(|e| VfsError::SerializationError(e.to_string()))?;
-
-        let header = JournalHeader::encode(&payload);
-        let mut entry = Vec::with_capacity(JournalHeader::SIZE + payload.len());
-        entry.extend_from_slice(&header);
-        entry.extend_from_slice(&payload);
+        let mut entry = journal_entry(&JournalOp::Snapshot(snapshot.clone()))?;
+        if let Some(id) = self.replica {
+            entry.extend(journal_entry(&JournalOp::<T>::Replica(id))?);
+        }
 
         // Atomic: write temp → rename
         let temp_path = format!("{}.tmp", self.journal_path);
         self.vfs.write(&temp_path, &entry).await?;
         self.vfs.rename(&temp_path, &self.journal_path).await?;
 
-        *self.entry_count.lock().await = 1;
+        *self.entry_count.lock().await = 1 + self.replica.is_some() as u64;
         Ok(())
     }
+
+    /// This node's replica id in delta gossip, once [`Distributed::mount_delta`]
+    /// has assigned or recovered one.
+    pub fn replica(&self) -> Option<ReplicaId> {
+        self.replica
+    }
+
+    /// The delta gossip state of a value mounted with
+    /// [`Distributed::mount_delta`]: its version, the deltas waiting for the
+    /// next round, and the versions its peers have announced. `None` for
+    /// values that sync full state or not at all.
+    pub async fn sync_status(&self) -> Option<SyncStatus> {
+        let link = self.link.as_ref()?;
+        let state = self.inner.lock().await;
+        link.status(&state)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T> Distributed<T>
+where
+    T: AuthoredMerge + Serialize + DeserializeOwned + Clone + Default + Send + 'static,
+{
+    /// Mount from disk and sync to `topic`, signing what this replica
+    /// publishes and merging only what replicas in `keyring` signed.
+    ///
+    /// Like [`Distributed::mount`] with a topic, but incoming states merge
+    /// through [`AuthoredMerge::merge_authored`], so a trusted replica can
+    /// only contribute its own writes. The journal records the merged state,
+    /// never the raw message, so a restart replays nothing forged.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use logicaffeine_system::crdt::{Keyring, Signer};
+    /// # use logicaffeine_system::distributed::Distributed;
+    /// # use logicaffeine_data::crdt::GCounter;
+    /// # use logicaffeine_system::fs::NativeVfs;
+    /// # use std::sync::Arc;
+    /// # fn main() {}
+    /// # async fn example(teammate: u64, teammate_key: [u8; 32]) -> Result<(), Box<dyn std::error::Error>> {
+    /// # let vfs: Arc<dyn logicaffeine_system::fs::Vfs + Send + Sync> = Arc::new(NativeVfs::new("/data"));
+    /// let mut keyring = Keyring::new();
+    /// keyring.trust(teammate, teammate_key)?;
+    /// let signer = Signer::generate(1);
+    ///
+    /// let scores = Distributed::<GCounter>::mount_signed(vfs, "scores.lsf", "scores".into(), signer, keyring).await?;
+    /// scores.mutate(|c| c.increment(1)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn mount_signed(
+        vfs: Arc<dyn Vfs + Send + Sync>,
+        path: &str,
+        topic: String,
+        signer: Signer,
+        keyring: Keyring,
+    ) -> VfsResult<Self> {
+        let mut dist = Self::mount(vfs, path, None).await?;
+        dist.topic = Some(topic.clone());
+        dist.signer = Some(signer);
+        dist.start_signed_loop(topic, keyring);
+        Ok(dist)
+    }
+
+    /// Start the background receive loop for signed network messages.
+    fn start_signed_loop(&self, topic: String, keyring: Keyring) {
+        let inner = self.inner.clone();
+        let vfs = self.vfs.clone();
+        let path = self.journal_path.clone();
+        let entry_count = self.entry_count.clone();
+
+        tokio::spawn(async move {
+            let mut rx = crate::network::gossip::subscribe(&topic).await;
+
+            while let Some(bytes) = rx.recv().await {
+                let mut guard = inner.lock().await;
+                if let Err(e) = keyring.merge_into(&topic, &bytes, &mut *guard) {
+                    eprintln!("[Distributed] Rejected message: {}", e);
+                    continue;
+                }
+                let snapshot = (*guard).clone();
+                drop(guard);
+
+                // Remote changes survive restarts: journal the merged state
+                match journal_entry(&JournalOp::Delta(snapshot)) {
+                    Ok(entry) => match vfs.append(&path, &entry).await {
+                        Ok(()) => *entry_count.lock().await += 1,
+                        Err(e) => eprintln!("[Distributed] Journal append failed: {:?}", e),
+                    },
+                    Err(e) => eprintln!("[Distributed] Serialize failed: {:?}", e),
+                }
+            }
+        });
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T> Distributed<T>
+where
+    T: DeltaCrdt + Serialize + DeserializeOwned + Clone + Default + Send + 'static,
+{
+    /// Mount from disk and sync to `topic` with delta gossip.
+    ///
+    /// Like [`Distributed::mount`] with a topic, but each mutation becomes a
+    /// delta that leaves with the next round's batch, and every
+    /// [`ANTI_ENTROPY_ROUNDS`] rounds — starting with the first — the replica
+    /// gossips its version so peers can fill in whatever it missed. The replica
+    /// id is journaled, so remounting after a restart resumes the same replica.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use logicaffeine_system::distributed::Distributed;
+    /// # use logicaffeine_data::crdt::PNCounter;
+    /// # use logicaffeine_system::fs::NativeVfs;
+    /// # use std::sync::Arc;
+    /// # fn main() {}
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let vfs: Arc<dyn logicaffeine_system::fs::Vfs + Send + Sync> = Arc::new(NativeVfs::new("/data"));
+    /// let votes = Distributed::<PNCounter>::mount_delta(vfs, "votes.lsf", "votes".into()).await?;
+    /// votes.mutate(|c| c.increment(1)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn mount_delta(
+        vfs: Arc<dyn Vfs + Send + Sync>,
+        path: &str,
+        topic: String,
+    ) -> VfsResult<Self> {
+        let mut dist = Self::mount(vfs, path, None).await?;
+        let replica = match dist.replica {
+            Some(id) => id,
+            None => {
+                let id = logicaffeine_data::generate_replica_id();
+                let entry = journal_entry(&JournalOp::<T>::Replica(id))?;
+                dist.vfs.append(&dist.journal_path, &entry).await?;
+                *dist.entry_count.lock().await += 1;
+                dist.replica = Some(id);
+                id
+            }
+        };
+        let sync = Arc::new(std::sync::Mutex::new(DeltaSync::<T::Delta>::new(replica)));
+        dist.topic = Some(topic.clone());
+        dist.link = Some(sync.clone());
+        dist.start_delta_loop(topic, sync);
+        Ok(dist)
+    }
+
+    /// The vector clock this replica syncs from.
+    pub async fn version(&self) -> VClock {
+        self.inner.lock().await.version()
+    }
+
+    /// Start the gossip task: answer peers' messages as they arrive, and run
+    /// a round every [`ROUND_INTERVAL`].
+    fn start_delta_loop(&self, topic: String, sync: Arc<std::sync::Mutex<DeltaSync<T::Delta>>>) {
+        let inner = self.inner.clone();
+        let vfs = self.vfs.clone();
+        let path = self.journal_path.clone();
+        let entry_count = self.entry_count.clone();
+
+        tokio::spawn(async move {
+            let mut rx = crate::network::gossip::subscribe(&topic).await;
+            let mut ticker = tokio::time::interval(ROUND_INTERVAL);
+            let mut rounds = 0u32;
+
+            loop {
+                let outgoing = tokio::select! {
+                    bytes = rx.recv() => {
+                        let Some(bytes) = bytes else { break };
+                        let message: SyncMessage<T, T::Delta> = match bincode::deserialize(&bytes) {
+                            Ok(message) => message,
+                            Err(e) => {
+                                eprintln!("[Distributed] Deserialize failed: {:?}", e);
+                                continue;
+                            }
+                        };
+                        let changes = !matches!(message, SyncMessage::Digest { .. });
+
+                        let mut guard = inner.lock().await;
+                        let reply = match sync.lock() {
+                            Ok(mut sync) => sync.receive(&mut *guard, message),
+                            Err(_) => break,
+                        };
+                        let snapshot = changes.then(|| (*guard).clone());
+                        drop(guard);
+
+                        // Remote changes survive restarts: journal the merged state
+                        if let Some(snapshot) = snapshot {
+                            match journal_entry(&JournalOp::Delta(snapshot)) {
+                                Ok(entry) => match vfs.append(&path, &entry).await {
+                                    Ok(()) => *entry_count.lock().await += 1,
+                                    Err(e) => eprintln!("[Distributed] Journal append failed: {:?}", e),
+                                },
+                                Err(e) => eprintln!("[Distributed] Serialize failed: {:?}", e),
+                            }
+                        }
+                        reply.into_iter().collect::<Vec<_>>()
+                    }
+                    _ = ticker.tick() => {
+                        // The first tick fires at once: announce the (recovered) version
+                        let anti_entropy = rounds % ANTI_ENTROPY_ROUNDS == 0;
+                        rounds = rounds.wrapping_add(1);
+                        let guard = inner.lock().await;
+                        let messages = match sync.lock() {
+                            Ok(mut sync) => sync.round(&*guard, anti_entropy),
+                            Err(_) => break,
+                        };
+                        drop(guard);
+                        messages
+                    }
+                };
+
+                for message in outgoing {
+                    match bincode::serialize(&message) {
+                        // Fire and forget - a lost message is repaired by anti-entropy
+                        Ok(bytes) => {
+                            let _ = crate::network::gossip::publish_raw(&topic, bytes).await;
+                        }
+                        Err(e) => eprintln!("[Distributed] Serialize failed: {:?}", e),
+                    }
+                }
+            }
+        });
+    }
 }
 
 // =============================================================================
@@ -403,19 +748,17 @@ where
         path: &str,
         _topic: Option<String>,  // Ignored on WASM
     ) -> VfsResult<Self> {
-        let (state, entry_count) = if vfs.exists(path).await.unwrap_or(false) {
-            let data = vfs.read(path).await?;
-            replay_journal(&data)?
-        } else {
-            (T::default(), 0)
-        };
+        let recovered = recover::<T>(&*vfs, path).await?;
 
-        Ok(Self {
-            inner: Arc::new(Mutex::new(state)),
+        let dist = Self {
+            inner: Arc::new(Mutex::new(recovered.state)),
             vfs,
             journal_path: path.to_string(),
-            entry_count: Arc::new(Mutex::new(entry_count)),
-        })
+            entry_count: Arc::new(Mutex::new(recovered.entry_count)),
+        };
+        // A long replay is snapshotted so the next restart is quick
+        dist.maybe_compact().await?;
+        Ok(dist)
     }
 
     /// Local mutation: RAM → Journal (no network on WASM).
@@ -574,4 +917,82 @@ mod tests {
         let counter2 = Distributed::<GCounter>::mount(vfs, "counter.lsf", None).await.unwrap();
         assert_eq!(counter2.get().await.value(), 5);
     }
+
+    #[tokio::test]
+    async fn test_distributed_recovers_from_torn_write() {
+        let temp = TempDir::new().unwrap();
+        let vfs: Arc<dyn Vfs + Send + Sync> = Arc::new(crate::fs::NativeVfs::new(temp.path()));
+
+        {
+            let counter = Distributed::<GCounter>::mount(vfs.clone(), "counter.lsf", None).await.unwrap();
+            counter.mutate(|c| c.increment(4)).await.unwrap();
+        }
+
+        // A crash mid-append: the header promises more payload than was written
+        let mut torn = JournalHeader::encode(&[0u8; 64]).to_vec();
+        torn.extend_from_slice(&[0u8; 10]);
+        vfs.append("counter.lsf", &torn).await.unwrap();
+
+        let counter = Distributed::<GCounter>::mount(vfs.clone(), "counter.lsf", None).await.unwrap();
+        assert_eq!(counter.get().await.value(), 4);
+        counter.mutate(|c| c.increment(1)).await.unwrap();
+
+        // The new entry replaced the torn one rather than landing behind it
+        let counter = Distributed::<GCounter>::mount(vfs, "counter.lsf", None).await.unwrap();
+        assert_eq!(counter.get().await.value(), 5);
+        assert_eq!(counter.entry_count().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_distributed_checksum_failures() {
+        let temp = TempDir::new().unwrap();
+        let vfs: Arc<dyn Vfs + Send + Sync> = Arc::new(crate::fs::NativeVfs::new(temp.path()));
+
+        {
+            let counter = Distributed::<GCounter>::mount(vfs.clone(), "counter.lsf", None).await.unwrap();
+            counter.mutate(|c| c.increment(2)).await.unwrap();
+        }
+        let good = vfs.read("counter.lsf").await.unwrap();
+        let mut bad = journal_entry(&JournalOp::Delta(GCounter::new())).unwrap();
+        let last = bad.len() - 1;
+        bad[last] ^= 0xFF;
+
+        // A bad checksum on the last entry is a torn write
+        vfs.append("counter.lsf", &bad).await.unwrap();
+        let counter = Distributed::<GCounter>::mount(vfs.clone(), "counter.lsf", None).await.unwrap();
+        assert_eq!(counter.get().await.value(), 2);
+
+        // Followed by more entries, it is corruption
+        let corrupted = [good.as_slice(), &bad, &good].concat();
+        vfs.write("counter.lsf", &corrupted).await.unwrap();
+        let result = Distributed::<GCounter>::mount(vfs, "counter.lsf", None).await;
+        assert!(matches!(result, Err(VfsError::JournalCorrupted(_))));
+    }
+
+    #[tokio::test]
+    async fn test_delta_sync_resumes_as_the_same_replica() {
+        use logicaffeine_data::crdt::PNCounter;
+
+        let temp = TempDir::new().unwrap();
+        let vfs: Arc<dyn Vfs + Send + Sync> = Arc::new(crate::fs::NativeVfs::new(temp.path()));
+        let topic = "test-delta-resume".to_string();
+
+        let (replica, version) = {
+            let votes = Distributed::<PNCounter>::mount_delta(vfs.clone(), "votes.lsf", topic.clone()).await.unwrap();
+            votes.mutate(|c| c.increment(3)).await.unwrap();
+            (votes.replica(), votes.version().await)
+        };
+        assert!(replica.is_some());
+
+        let votes = Distributed::<PNCounter>::mount_delta(vfs.clone(), "votes.lsf", topic.clone()).await.unwrap();
+        assert_eq!(votes.replica(), replica);
+        assert_eq!(votes.version().await, version);
+        assert_eq!(votes.get().await.value(), 3);
+
+        // Compaction carries the replica id into the new journal
+        votes.compact().await.unwrap();
+        let votes = Distributed::<PNCounter>::mount_delta(vfs, "votes.lsf", topic).await.unwrap();
+        assert_eq!(votes.replica(), replica);
+        assert_eq!(votes.get().await.value(), 3);
+    }
 }
@@ -54,13 +54,12 @@ impl IndexedDbVfs {
     pub async fn new() -> VfsResult<Self> {
         log("[IndexedDbVfs] Initializing...");
 
-        let window = web_sys::window()
-            .ok_or_else(|| VfsError::PermissionDenied("No window object".into()))?;
-
-        let indexed_db = window
-            .indexed_db()
+        // `indexedDB` hangs off the global scope, so this works in a worker as
+        // well as on the page
+        let indexed_db: web_sys::IdbFactory = js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("indexedDB"))
             .map_err(|e| VfsError::PermissionDenied(format!("IndexedDB access failed: {:?}", e)))?
-            .ok_or_else(|| VfsError::PermissionDenied("IndexedDB not available".into()))?;
+            .dyn_into()
+            .map_err(|_| VfsError::PermissionDenied("IndexedDB not available".into()))?;
 
         // Open database with version upgrade handler
         let open_request = indexed_db
@@ -141,6 +141,14 @@ impl Showable for logicaffeine_data::LogosUuid {
     }
 }
 
+// A pattern shows as its source text, as the interpreter does.
+impl Showable for logicaffeine_data::LogosPattern {
+    #[inline(always)]
+    fn format_show(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
 impl Showable for bool {
     #[inline(always)]
     fn format_show(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -16,6 +16,22 @@ pub mod relay_proto;
 // libp2p, no target gating — identical on native and wasm.
 pub mod addr;
 
+// Peer discovery settings (mDNS, bootstrap peers, relays) from `Largo.toml` and
+// the environment. Settings only, no libp2p, so the interpreter and the mesh
+// read the same configuration.
+pub mod discovery;
+
+// Leveled, structured logging (`Log info "…" with id.`): `tracing` events on
+// native, console lines on wasm.
+#[cfg(feature = "log")]
+pub mod log;
+// Typed settings (`## Settings`) loaded from a TOML file and the environment.
+#[cfg(feature = "settings")]
+pub mod settings;
+// Command-line parsing for an `## Arguments` block, built on clap.
+#[cfg(feature = "arguments")]
+pub mod arguments;
+
 // The native WebSocket relay server + client. A LIGHT capability behind its own
 // `relay` feature (tokio-tungstenite, NO libp2p) so the interpreter can network
 // over the relay without the mesh stack. `networking` implies `relay`.
@@ -41,6 +57,12 @@ pub mod env;
 pub mod random;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod text;
+// Function profiler behind `largo run --profile`
+#[cfg(not(target_arch = "wasm32"))]
+pub mod profile;
+// Statement counters behind compiled `largo test --coverage` runs
+#[cfg(not(target_arch = "wasm32"))]
+pub mod coverage;
 
 // === Feature-Gated Modules ===
 
@@ -51,6 +73,14 @@ pub mod file;
 pub mod fs;
 #[cfg(feature = "persistence")]
 pub mod storage;
+// Verified, resumable chunked transfers (`Sip the file …`), independent of
+// the transport; `network` sends them over libp2p.
+#[cfg(feature = "persistence")]
+pub mod sip;
+
+// Process feature: subprocesses for `Run the command …`, under a command policy
+#[cfg(all(not(target_arch = "wasm32"), feature = "process"))]
+pub mod process;
 
 // Networking feature: P2P networking
 #[cfg(feature = "networking")]
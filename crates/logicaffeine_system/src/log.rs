@@ -0,0 +1,297 @@
+//! Structured Logging for LOGOS Programs
+//!
+//! Leveled log events carrying named fields, the runtime behind
+//! `Log info "user joined" with id.`:
+//!
+//! - **Native**: events go through [`tracing`], so any subscriber the host
+//!   installs sees them with their fields intact. Without one, the first event
+//!   installs a plain stderr subscriber filtered by `LOGOS_LOG`
+//!   (`largo run --log-level`)
+//! - **wasm**: events are written to the browser (or node) console, at the
+//!   matching console level
+//!
+//! Generated code logs through [`log_event!`](crate::log_event), which keeps
+//! the field names static so `tracing` records them as real fields. The
+//! interpreter, which only knows field names at run time, uses [`emit`].
+//!
+//! ```no_run
+//! use logicaffeine_system::log_event;
+//!
+//! let id = 42;
+//! log_event!(Info, "user joined", id = id);
+//! ```
+
+use std::fmt::{self, Display};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::io::Showable;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use tracing;
+
+/// The environment variable holding the most verbose level to print:
+/// `trace`, `debug`, `info` (the default), `warn`, `error` or `off`.
+pub const LOG_ENV: &str = "LOGOS_LOG";
+
+/// How severe a log event is, from most to least verbose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Level {
+    /// Parse a level name, case-insensitively. `warning` is accepted for `warn`.
+    pub fn parse(name: &str) -> Option<Level> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "trace" => Some(Level::Trace),
+            "debug" => Some(Level::Debug),
+            "info" => Some(Level::Info),
+            "warn" | "warning" => Some(Level::Warn),
+            "error" => Some(Level::Error),
+            _ => None,
+        }
+    }
+
+    /// The lowercase level name.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Level::Trace => "trace",
+            Level::Debug => "debug",
+            Level::Info => "info",
+            Level::Warn => "warn",
+            Level::Error => "error",
+        }
+    }
+
+    /// The matching `tracing` level, usable in a `static` callsite.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub const fn to_tracing(self) -> tracing::Level {
+        match self {
+            Level::Trace => tracing::Level::TRACE,
+            Level::Debug => tracing::Level::DEBUG,
+            Level::Info => tracing::Level::INFO,
+            Level::Warn => tracing::Level::WARN,
+            Level::Error => tracing::Level::ERROR,
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Level::Trace => "TRACE",
+            Level::Debug => "DEBUG",
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+        })
+    }
+}
+
+// 0 = not chosen yet (read `LOG_ENV` on first use), 1..=5 = Trace..=Error, 6 = off.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+const OFF: u8 = 6;
+
+fn encode(level: Option<Level>) -> u8 {
+    level.map_or(OFF, |l| l as u8 + 1)
+}
+
+fn decode(raw: u8) -> Option<Level> {
+    [Level::Trace, Level::Debug, Level::Info, Level::Warn, Level::Error].get(raw.wrapping_sub(1) as usize).copied()
+}
+
+/// The most verbose level that is printed, or `None` when logging is off.
+///
+/// Unless [`set_max_level`] chose one, this is read from `LOGOS_LOG`,
+/// defaulting to [`Level::Info`].
+pub fn max_level() -> Option<Level> {
+    let raw = MAX_LEVEL.load(Ordering::Relaxed);
+    if raw != 0 {
+        return decode(raw);
+    }
+    let level = match std::env::var(LOG_ENV).ok() {
+        Some(name) if name.trim().eq_ignore_ascii_case("off") => None,
+        Some(name) => Level::parse(&name).or(Some(Level::Info)),
+        None => Some(Level::Info),
+    };
+    MAX_LEVEL.store(encode(level), Ordering::Relaxed);
+    level
+}
+
+/// Choose the most verbose level to print (`None` turns logging off). On
+/// native targets this must happen before the first event, which fixes the
+/// filter of the default subscriber.
+pub fn set_max_level(level: Option<Level>) {
+    MAX_LEVEL.store(encode(level), Ordering::Relaxed);
+}
+
+/// Whether an event at `level` would be printed.
+pub fn enabled(level: Level) -> bool {
+    max_level().is_some_and(|max| level >= max)
+}
+
+/// Install the default subscriber: plain text on stderr, filtered by
+/// [`max_level`]. Does nothing when the host already installed one, and only
+/// the first call does anything at all.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn init() {
+    static INIT: std::sync::Once = std::sync::Once::new();
+    INIT.call_once(|| {
+        use std::io::IsTerminal;
+        use tracing::level_filters::LevelFilter;
+
+        let filter = max_level().map_or(LevelFilter::OFF, |l| LevelFilter::from_level(l.to_tracing()));
+        let _ = tracing_subscriber::fmt()
+            .with_max_level(filter)
+            .with_target(false)
+            .with_ansi(std::io::stderr().is_terminal())
+            .with_writer(std::io::stderr)
+            .try_init();
+    });
+}
+
+/// Nothing to install on wasm: events go straight to the console.
+#[cfg(target_arch = "wasm32")]
+pub fn init() {}
+
+/// Log one event whose field names are only known at run time.
+///
+/// Natively the fields are rendered after the message (`user joined id=42`),
+/// the same text the default subscriber prints for [`log_event!`](crate::log_event).
+pub fn emit(level: Level, message: &str, fields: &[(&str, String)]) {
+    if !enabled(level) {
+        return;
+    }
+    let mut line = message.to_string();
+    for (name, value) in fields {
+        line.push_str(&format!(" {}={}", name, value));
+    }
+    write_line(level, &line);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_line(level: Level, line: &str) {
+    init();
+    match level {
+        Level::Trace => tracing::trace!("{}", line),
+        Level::Debug => tracing::debug!("{}", line),
+        Level::Info => tracing::info!("{}", line),
+        Level::Warn => tracing::warn!("{}", line),
+        Level::Error => tracing::error!("{}", line),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod console {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = console)]
+        pub fn debug(s: &str);
+        #[wasm_bindgen(js_namespace = console)]
+        pub fn info(s: &str);
+        #[wasm_bindgen(js_namespace = console)]
+        pub fn warn(s: &str);
+        #[wasm_bindgen(js_namespace = console)]
+        pub fn error(s: &str);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write_line(level: Level, line: &str) {
+    let line = format!("{} {}", level, line);
+    match level {
+        Level::Trace | Level::Debug => console::debug(&line),
+        Level::Info => console::info(&line),
+        Level::Warn => console::warn(&line),
+        Level::Error => console::error(&line),
+    }
+}
+
+/// Displays a value the way `Show` prints it, so a logged field reads the
+/// same as a shown one.
+pub struct Shown<'a, T: ?Sized>(pub &'a T);
+
+impl<T: Showable + ?Sized> Display for Shown<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.format_show(f)
+    }
+}
+
+/// Log an event at a [`Level`] with named fields.
+///
+/// `log_event!(Info, message, id = id, name = name)`. The message and field
+/// values are printed as `Show` prints them. Natively this is a `tracing`
+/// event with one field per name; on wasm it is a console line.
+#[cfg(not(target_arch = "wasm32"))]
+#[macro_export]
+macro_rules! log_event {
+    ($level:ident, $message:expr $(, $field:ident = $value:expr)* $(,)?) => {{
+        $crate::log::init();
+        $crate::log::tracing::event!(
+            $crate::log::Level::$level.to_tracing(),
+            $($field = %$crate::log::Shown(&$value),)*
+            "{}",
+            $crate::log::Shown(&$message)
+        );
+    }};
+}
+
+/// Log an event at a [`Level`] with named fields.
+///
+/// `log_event!(Info, message, id = id, name = name)`. The message and field
+/// values are printed as `Show` prints them. Natively this is a `tracing`
+/// event with one field per name; on wasm it is a console line.
+#[cfg(target_arch = "wasm32")]
+#[macro_export]
+macro_rules! log_event {
+    ($level:ident, $message:expr $(, $field:ident = $value:expr)* $(,)?) => {{
+        if $crate::log::enabled($crate::log::Level::$level) {
+            $crate::log::emit(
+                $crate::log::Level::$level,
+                &::std::format!("{}", $crate::log::Shown(&$message)),
+                &[$((::std::stringify!($field), ::std::format!("{}", $crate::log::Shown(&$value)))),*],
+            );
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels_parse_and_order() {
+        assert_eq!(Level::parse("INFO"), Some(Level::Info));
+        assert_eq!(Level::parse("warning"), Some(Level::Warn));
+        assert_eq!(Level::parse("loud"), None);
+        assert!(Level::Trace < Level::Debug && Level::Warn < Level::Error);
+        for raw in 1..=5 {
+            assert_eq!(decode(raw).map(|l| encode(Some(l))), Some(raw));
+        }
+        assert_eq!(decode(OFF), None);
+    }
+
+    #[test]
+    fn test_max_level_filters_events() {
+        set_max_level(Some(Level::Warn));
+        assert!(!enabled(Level::Info));
+        assert!(enabled(Level::Warn) && enabled(Level::Error));
+        set_max_level(None);
+        assert!(!enabled(Level::Error));
+        set_max_level(Some(Level::Info));
+    }
+
+    #[test]
+    fn test_shown_matches_show_formatting() {
+        assert_eq!(format!("{}", Shown(&"user joined")), "user joined");
+        assert_eq!(format!("{}", Shown(&vec![1i64, 2])), "[1, 2]");
+        let id = 7i64;
+        crate::log_event!(Debug, "quiet below the filter", id = id);
+    }
+}
@@ -3,7 +3,8 @@
 //! Combines multiple libp2p behaviours into a unified [`MeshBehaviour`]:
 //!
 //! - **Request-Response**: Point-to-point message exchange between agents
-//! - **mDNS**: Automatic local peer discovery on the same network
+//! - **mDNS**: Automatic local peer discovery on the same network (optional,
+//!   see [`Discovery`](crate::network::Discovery))
 //! - **GossipSub**: Pub/sub broadcast messaging for CRDT synchronization
 //!
 //! # Architecture
@@ -21,6 +22,7 @@ use libp2p::gossipsub::{self, IdentTopic, MessageAuthenticity};
 use libp2p::identity::Keypair;
 use libp2p::mdns;
 use libp2p::request_response::{self, ProtocolSupport};
+use libp2p::swarm::behaviour::toggle::Toggle;
 use libp2p::swarm::NetworkBehaviour;
 use std::time::Duration;
 
@@ -49,15 +51,16 @@ use std::time::Duration;
 pub struct MeshBehaviour {
     /// Request-response protocol for direct agent-to-agent communication.
     pub request_response: request_response::Behaviour<LogosCodec>,
-    /// mDNS for automatic local network peer discovery.
-    pub mdns: mdns::tokio::Behaviour,
+    /// mDNS for automatic local network peer discovery, when enabled.
+    pub mdns: Toggle<mdns::tokio::Behaviour>,
     /// GossipSub for pub/sub broadcast messaging.
     pub gossipsub: gossipsub::Behaviour,
 }
 
 impl MeshBehaviour {
-    /// Create a new mesh behaviour with default configuration.
-    pub fn new(local_peer_id: libp2p::PeerId, keypair: &Keypair) -> Self {
+    /// Create a new mesh behaviour, announcing itself over mDNS only when
+    /// `local_discovery` is set.
+    pub fn new(local_peer_id: libp2p::PeerId, keypair: &Keypair, local_discovery: bool) -> Self {
         // Configure request-response
         let rr_config = request_response::Config::default()
             .with_request_timeout(Duration::from_secs(30));
@@ -68,9 +71,10 @@ impl MeshBehaviour {
         );
 
         // Configure mDNS
-        let mdns_config = mdns::Config::default();
-        let mdns = mdns::tokio::Behaviour::new(mdns_config, local_peer_id)
-            .expect("Failed to create mDNS behaviour");
+        let mdns = local_discovery.then(|| {
+            mdns::tokio::Behaviour::new(mdns::Config::default(), local_peer_id)
+                .expect("Failed to create mDNS behaviour")
+        });
 
         // Configure GossipSub with 1s heartbeat for reliable mesh formation
         let gossipsub_config = gossipsub::ConfigBuilder::default()
@@ -86,7 +90,7 @@ impl MeshBehaviour {
 
         Self {
             request_response,
-            mdns,
+            mdns: Toggle::from(mdns),
             gossipsub,
         }
     }
@@ -159,6 +159,11 @@ pub async fn on_message(topic: &str, data: Vec<u8>) {
     }
 }
 
+/// The topics this node is subscribed to.
+pub(crate) async fn topics() -> Vec<String> {
+    SUBSCRIPTIONS.lock().await.keys().cloned().collect()
+}
+
 /// Unsubscribe from a topic.
 ///
 /// This removes the subscription and stops receiving messages.
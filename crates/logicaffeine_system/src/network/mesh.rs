@@ -13,7 +13,8 @@
 //! # Transport
 //!
 //! Supports both TCP+Noise+Yamux and QUIC for flexible connectivity.
-//! mDNS is used for automatic local network discovery.
+//! mDNS is used for automatic local network discovery unless the node was
+//! started with a [`Discovery`] that turns it off.
 //!
 //! # Features
 //!
@@ -43,6 +44,8 @@
 //! ```
 
 use crate::network::behaviour::{MeshBehaviour, MeshBehaviourEvent};
+use crate::discovery::Discovery;
+use crate::network::swarm;
 use crate::network::protocol::{LogosRequest, LogosResponse};
 use crate::network::wire;
 use crate::network::gossip;
@@ -261,11 +264,19 @@ pub struct MeshNode {
 }
 
 impl MeshNode {
-    /// Create and start a new mesh node.
+    /// Create and start a new mesh node with the default [`Discovery`].
     ///
     /// Spawns a background task to run the swarm event loop.
     pub async fn new() -> Result<Self, NetworkError> {
+        Self::with_discovery(&Discovery::default()).await
+    }
+
+    /// Create and start a new mesh node, with mDNS on or off as `discovery`
+    /// says. Bootstrap peers and relays are joined separately, by
+    /// [`join_swarm`](crate::network::join_swarm).
+    pub async fn with_discovery(discovery: &Discovery) -> Result<Self, NetworkError> {
         let (command_tx, command_rx) = mpsc::channel(256);
+        let local_discovery = discovery.uses_local();
 
         // Build the swarm with keypair for GossipSub message signing
         let swarm = libp2p::SwarmBuilder::with_new_identity()
@@ -277,7 +288,7 @@ impl MeshNode {
             )
             .map_err(|e| NetworkError::Internal(format!("TCP setup failed: {}", e)))?
             .with_quic()
-            .with_behaviour(|key| MeshBehaviour::new(key.public().to_peer_id(), key))
+            .with_behaviour(|key| MeshBehaviour::new(key.public().to_peer_id(), key, local_discovery))
             .map_err(|e| NetworkError::Internal(format!("Behaviour setup failed: {}", e)))?
             .build();
 
@@ -327,7 +338,8 @@ impl MeshNode {
                             }
                         }
                         MeshCommand::Dial { addr, response } => {
-                            let addr_str = addr.to_string();
+                            // The connection reports the remote address without its `/p2p/...` suffix
+                            let addr_str = without_peer_id(&addr).to_string();
                             match swarm.dial(addr) {
                                 Ok(_) => {
                                     pending_dials.insert(addr_str, response);
@@ -592,6 +604,15 @@ impl MeshNode {
     }
 }
 
+/// `addr` without a trailing `/p2p/<peer id>`.
+fn without_peer_id(addr: &Multiaddr) -> Multiaddr {
+    let mut addr = addr.clone();
+    if let Some(libp2p::multiaddr::Protocol::P2p(_)) = addr.iter().last() {
+        addr.pop();
+    }
+    addr
+}
+
 // =============================================================================
 // Global MESH instance for simple API
 // =============================================================================
@@ -600,10 +621,15 @@ static MESH: OnceLock<Mutex<Option<MeshNode>>> = OnceLock::new();
 
 /// Initialize the global mesh node.
 async fn ensure_mesh() -> Result<(), NetworkError> {
+    ensure_mesh_with(&Discovery::default()).await
+}
+
+/// Initialize the global mesh node with `discovery`, if it isn't running yet.
+pub(crate) async fn ensure_mesh_with(discovery: &Discovery) -> Result<(), NetworkError> {
     let mutex = MESH.get_or_init(|| Mutex::new(None));
     let mut guard = mutex.lock().await;
     if guard.is_none() {
-        *guard = Some(MeshNode::new().await?);
+        *guard = Some(MeshNode::with_discovery(discovery).await?);
     }
     Ok(())
 }
@@ -658,6 +684,16 @@ pub async fn connect(addr: &str) -> Result<(), NetworkError> {
     mesh.dial(addr).await
 }
 
+/// Dial `addr` on the global mesh, giving up after `timeout`.
+pub(crate) async fn connect_within(addr: &str, timeout: std::time::Duration) -> Result<(), NetworkError> {
+    let guard = get_mesh().await?;
+    let mesh = guard.as_ref().ok_or(NetworkError::NotInitialized)?;
+    match tokio::time::timeout(timeout, mesh.dial(addr)).await {
+        Ok(result) => result,
+        Err(_) => Err(NetworkError::Timeout),
+    }
+}
+
 /// Send a serializable message to a peer agent.
 ///
 /// # Example (LOGOS)
@@ -711,6 +747,9 @@ pub async fn gossip_publish(topic: &str, data: Vec<u8>) {
         return;
     }
 
+    // Peers reached through a relay get it that way too
+    swarm::relay_publish(topic, &data).await;
+
     if let Some(tx) = GOSSIP_TX.get() {
         if tx.send(MeshCommand::GossipPublish {
             topic: topic.to_string(),
@@ -737,6 +776,8 @@ pub async fn gossip_subscribe(topic: &str) {
         return;
     }
 
+    swarm::relay_subscribe(topic).await;
+
     if let Some(tx) = GOSSIP_TX.get() {
         if tx.send(MeshCommand::GossipSubscribe {
             topic: topic.to_string(),
@@ -815,6 +856,14 @@ mod tests {
         assert!(peer.is_err());
     }
 
+    #[test]
+    fn test_dial_key_drops_the_peer_id() {
+        let addr: Multiaddr = "/ip4/10.0.0.2/tcp/9000/p2p/12D3KooWDpJ7As7BWAwRMfu1VU2WCqNjvq387JEYKDBj4kx6nXTN"
+            .parse()
+            .unwrap();
+        assert_eq!(without_peer_id(&addr).to_string(), "/ip4/10.0.0.2/tcp/9000");
+    }
+
     #[test]
     fn test_peer_agent_invalid_fails() {
         let peer = PeerAgent::new("not-a-multiaddr");
@@ -6,9 +6,11 @@
 //! # Architecture
 //!
 //! - **Request-Response**: Point-to-point message exchange between agents
-//! - **mDNS Discovery**: Automatic local network peer discovery
+//! - **Discovery**: mDNS on the local network, bootstrap peers, and relays
+//!   for nodes behind NAT, chosen with [`Discovery`]
 //! - **GossipSub**: Pub/sub broadcast for CRDT replication
-//! - **File Sipping**: Zero-copy file chunking with resumable transfers
+//! - **File Sipping**: Zero-copy file chunking with verified, resumable,
+//!   multi-peer transfers
 //!
 //! # Features
 //!
@@ -45,11 +47,19 @@ pub mod wire;
 mod protocol;
 mod behaviour;
 mod mesh;
+mod swarm;
 pub mod gossip;
 #[cfg(test)]
 mod e2e_tests;
 
 #[cfg(all(feature = "persistence", feature = "concurrency"))]
-pub use sipping::{FileSipper, FileManifest, FileChunk, DEFAULT_CHUNK_SIZE};
+pub use sipping::FileSipper;
+#[cfg(all(feature = "persistence", feature = "concurrency"))]
+pub use crate::sip::{
+    ChunkScheduler, FileChunk, FileManifest, FileReceiver, Progress, SipError, DEFAULT_CHUNK_SIZE,
+    DEFAULT_REQUESTS_PER_PEER,
+};
 pub use mesh::{listen, connect, send, local_peer_id, PeerAgent, MeshNode, NetworkError};
 pub use mesh::{gossip_publish, gossip_subscribe};
+pub use swarm::join_swarm;
+pub use crate::discovery::Discovery;
@@ -2,37 +2,13 @@
 //!
 //! The Sipping protocol slices memory-mapped files into chunks with SHA256 hashes,
 //! enabling resumable, verifiable file transfers over unreliable networks.
+//!
+//! The sender's [`FileSipper`] publishes a [`FileManifest`] and serves chunks.
+//! Receiving them — verification, resuming, scheduling over peers, progress —
+//! lives in [`crate::sip`], which doesn't need the network stack.
 
 use crate::memory::Zone;
-use sha2::{Sha256, Digest};
-use serde::{Serialize, Deserialize};
-
-/// Default chunk size: 1 MB
-pub const DEFAULT_CHUNK_SIZE: usize = 1024 * 1024;
-
-/// Manifest describing a file's chunks for resumable transfer.
-///
-/// The manifest contains:
-/// - A unique file ID for this transfer session
-/// - Total file size and chunk count
-/// - SHA256 hashes for each chunk (enables verification and deduplication)
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FileManifest {
-    pub file_id: String,
-    pub total_size: u64,
-    pub chunk_size: usize,
-    pub chunk_count: usize,
-    pub chunk_hashes: Vec<[u8; 32]>,
-}
-
-/// A single chunk of file data with its hash for verification.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FileChunk {
-    pub file_id: String,
-    pub index: usize,
-    pub data: Vec<u8>,
-    pub hash: [u8; 32],
-}
+use crate::sip::{sha256, FileChunk, FileManifest, DEFAULT_CHUNK_SIZE};
 
 /// Zero-copy file chunking using memory-mapped zones.
 ///
@@ -110,10 +86,7 @@ impl<'a> FileSipper<'a> {
 
     /// Compute SHA256 hash of a specific chunk.
     pub fn hash_chunk(&self, index: usize) -> [u8; 32] {
-        let chunk = self.get_chunk(index);
-        let mut hasher = Sha256::new();
-        hasher.update(chunk);
-        hasher.finalize().into()
+        sha256(self.get_chunk(index))
     }
 
     /// Generate manifest with all chunk hashes.
@@ -157,6 +130,7 @@ impl<'a> FileSipper<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sip::{FileReceiver, SipError};
     use std::io::Write;
     use tempfile::NamedTempFile;
 
@@ -220,4 +194,67 @@ mod tests {
         assert_eq!(manifest.chunk_count, 10);
         assert_eq!(manifest.chunk_hashes.len(), 10);
     }
+
+    #[test]
+    fn test_manifest_verifies_chunks() {
+        let file = create_test_file(250);
+        let zone = Zone::new_mapped(file.path()).unwrap();
+        let sipper = FileSipper::with_chunk_size(&zone, 100);
+        let manifest = sipper.manifest();
+
+        assert_eq!(manifest.chunk_len(2), 50);
+        assert!(manifest.check(&sipper.get_chunk_with_hash(2)).is_ok());
+
+        // A tampered chunk fails even if it carries a matching hash
+        let mut chunk = sipper.get_chunk_with_hash(1);
+        chunk.data[0] ^= 1;
+        chunk.hash = sha256(&chunk.data);
+        assert!(matches!(manifest.check(&chunk), Err(SipError::Corrupt(1))));
+
+        chunk.file_id = "other".to_string();
+        assert!(matches!(manifest.check(&chunk), Err(SipError::WrongFile { .. })));
+    }
+
+    #[test]
+    fn test_receiver_assembles_the_file() {
+        let source = create_test_file(250);
+        let zone = Zone::new_mapped(source.path()).unwrap();
+        let sipper = FileSipper::with_chunk_size(&zone, 100);
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("copy.bin");
+
+        let mut receiver = FileReceiver::open(&dest, sipper.manifest()).unwrap();
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log = seen.clone();
+        receiver.on_progress(move |p| log.lock().unwrap().push(p.chunks_done));
+        for index in [2, 0, 1] {
+            assert!(receiver.accept(&sipper.get_chunk_with_hash(index)).unwrap());
+        }
+        assert!(!receiver.accept(&sipper.get_chunk_with_hash(0)).unwrap(), "already held");
+
+        assert!(receiver.is_complete());
+        assert_eq!(*seen.lock().unwrap(), vec![1, 2, 3]);
+        assert_eq!(std::fs::read(&dest).unwrap(), std::fs::read(source.path()).unwrap());
+    }
+
+    #[test]
+    fn test_receiver_resumes_a_partial_file() {
+        let source = create_test_file(1000);
+        let zone = Zone::new_mapped(source.path()).unwrap();
+        let sipper = FileSipper::with_chunk_size(&zone, 100);
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("copy.bin");
+
+        let mut receiver = FileReceiver::open(&dest, sipper.manifest()).unwrap();
+        for index in [0, 1, 5] {
+            receiver.accept(&sipper.get_chunk_with_hash(index)).unwrap();
+        }
+        drop(receiver);
+
+        // Reconnect: the same chunks are already there
+        let receiver = FileReceiver::open(&dest, sipper.manifest()).unwrap();
+        assert_eq!(receiver.missing(), vec![2, 3, 4, 6, 7, 8, 9]);
+        assert_eq!(receiver.progress().bytes_done, 300);
+        assert_eq!(receiver.progress().to_string(), "3 of 10 chunks, 300 of 1000 bytes (30%)");
+    }
 }
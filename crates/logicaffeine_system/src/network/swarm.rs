@@ -0,0 +1,154 @@
+//! Joining the Swarm
+//!
+//! [`join_swarm`] starts the mesh with the [`Discovery`] it's given, dials the
+//! bootstrap peers, and joins the relays. A node behind NAT dials out to a
+//! relay, and its gossip travels through it — on a bridged relay
+//! ([`serve_bridged`](crate::relay::serve_bridged)), into the relay's own mesh.
+//!
+//! # Example (LOGOS)
+//! ```logos
+//! Join the swarm using local discovery.
+//! Join the swarm using bootstrap peers and relays at ["ws://relay.example.org:9000"].
+//! ```
+
+use crate::discovery::Discovery;
+use crate::network::{gossip, mesh, NetworkError};
+use crate::relay::RelayClient;
+use once_cell::sync::Lazy;
+use std::time::Duration;
+use tokio::sync::{mpsc, Mutex};
+
+/// How long a bootstrap peer gets to accept the connection.
+const BOOTSTRAP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Join the swarm the ways `discovery` allows.
+///
+/// Starts the global mesh node if it isn't running — mDNS is only set up then,
+/// so turning it on or off has no effect on a node that is already up. Every
+/// bootstrap peer is dialed and every relay joined; it's enough for one of
+/// them to answer. Fails only when nothing was reachable: no mDNS, and every
+/// bootstrap peer and relay failed.
+///
+/// # Example (LOGOS)
+/// ```logos
+/// Join the swarm using local discovery.
+/// ```
+pub async fn join_swarm(discovery: &Discovery) -> Result<(), NetworkError> {
+    mesh::ensure_mesh_with(discovery).await?;
+
+    let mut failures = Vec::new();
+    let mut reached = false;
+    for addr in discovery.bootstrap_peers() {
+        match mesh::connect_within(addr, BOOTSTRAP_TIMEOUT).await {
+            Ok(()) => reached = true,
+            Err(e) => failures.push(format!("{}: {}", addr, e)),
+        }
+    }
+    for url in discovery.relays() {
+        match join_relay(url).await {
+            Ok(()) => reached = true,
+            Err(e) => failures.push(format!("{}: {}", url, e)),
+        }
+    }
+
+    if reached || discovery.uses_local() || failures.is_empty() {
+        for failure in &failures {
+            eprintln!("[MESH] Unreachable: {}", failure);
+        }
+        Ok(())
+    } else {
+        Err(NetworkError::ConnectionFailed(failures.join("; ")))
+    }
+}
+
+// =============================================================================
+// Relay links
+// =============================================================================
+
+enum LinkCommand {
+    Subscribe(String),
+    Publish(String, Vec<u8>),
+}
+
+/// One channel per joined relay, into the task that owns its connection.
+static RELAY_LINKS: Lazy<Mutex<Vec<mpsc::UnboundedSender<LinkCommand>>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Dial the relay at `url` and carry this node's gossip over it: every topic
+/// it subscribes to, now or later, and everything it publishes.
+async fn join_relay(url: &str) -> Result<(), NetworkError> {
+    let url = crate::addr::multiaddr_to_ws_url(url).map_err(NetworkError::InvalidAddress)?;
+    let mut client = RelayClient::connect(&url).await.map_err(NetworkError::ConnectionFailed)?;
+    for topic in gossip::topics().await {
+        client.subscribe(&topic).await.map_err(NetworkError::ConnectionFailed)?;
+    }
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                command = rx.recv() => match command {
+                    Some(LinkCommand::Subscribe(topic)) => {
+                        if client.subscribe(&topic).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(LinkCommand::Publish(topic, data)) => {
+                        if client.publish(&topic, data).is_err() {
+                            break;
+                        }
+                    }
+                    None => break,
+                },
+                event = client.next_event() => match event {
+                    Some((topic, data)) => gossip::on_message(&topic, data).await,
+                    None => break,
+                },
+            }
+        }
+        eprintln!("[MESH] Relay {} closed", url);
+    });
+    RELAY_LINKS.lock().await.push(tx);
+    Ok(())
+}
+
+/// Subscribe to `topic` on every joined relay.
+pub(crate) async fn relay_subscribe(topic: &str) {
+    let mut links = RELAY_LINKS.lock().await;
+    links.retain(|link| link.send(LinkCommand::Subscribe(topic.to_string())).is_ok());
+}
+
+/// Publish to `topic` on every joined relay.
+pub(crate) async fn relay_publish(topic: &str, data: &[u8]) {
+    let mut links = RELAY_LINKS.lock().await;
+    links.retain(|link| link.send(LinkCommand::Publish(topic.to_string(), data.to_vec())).is_ok());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_unreachable_relay_alone_fails() {
+        let discovery = Discovery::none().with_relay("ws://127.0.0.1:1");
+        assert!(matches!(join_swarm(&discovery).await, Err(NetworkError::ConnectionFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_gossip_travels_through_a_relay() {
+        let relay = crate::relay::serve("127.0.0.1:0").await.unwrap();
+        join_swarm(&Discovery::none().with_relay(relay.url())).await.unwrap();
+
+        // A peer behind the relay hears what this node publishes...
+        let mut peer = RelayClient::connect(&relay.url()).await.unwrap();
+        peer.subscribe("relay-discovery").await.unwrap();
+        let mut inbox = gossip::subscribe("relay-discovery").await;
+        crate::network::gossip_publish("relay-discovery", b"out".to_vec()).await;
+        let (_, data) = tokio::time::timeout(Duration::from_secs(5), peer.next_event()).await.unwrap().unwrap();
+        assert_eq!(data, b"out");
+
+        // ...and this node hears the peer
+        peer.publish("relay-discovery", b"in".to_vec()).unwrap();
+        let data = tokio::time::timeout(Duration::from_secs(5), inbox.recv()).await.unwrap().unwrap();
+        assert_eq!(data, b"in");
+    }
+}
@@ -0,0 +1,312 @@
+//! Subprocess Execution
+//!
+//! The runtime behind `Run the command "git status" and capture its output into status.`:
+//! a command line is split into a program and its arguments (quotes group
+//! words, there is no shell), run to completion, and its exit code, stdout and
+//! stderr collected. A timeout kills the program if it runs too long.
+//!
+//! Which programs may run is a [`CommandPolicy`]. Nothing may run by default:
+//! a LOGOS program lists the programs it starts in its `## Policy` block, and
+//! the interpreter and the generated code build the policy from that list:
+//!
+//! ```text
+//! ## Policy
+//! A program can run "git" and "make".
+//! ```
+//!
+//! ```no_run
+//! use logicaffeine_system::process::{self, CommandPolicy};
+//! use std::time::Duration;
+//!
+//! let policy = CommandPolicy::allow(["git"]);
+//! let status = process::run_with_policy("git status --short", Some(Duration::from_secs(5)), &policy).unwrap();
+//! assert_eq!(status.exit_code, 0);
+//! print!("{}", status.stdout);
+//! ```
+
+use std::fmt;
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// What a finished command left behind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandOutput {
+    /// The exit code; `-1` when a signal ended the program.
+    pub exit_code: i64,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl CommandOutput {
+    /// `Ok` for exit code 0, otherwise [`CommandError::Failed`] carrying stderr.
+    pub fn require_success(&self, command: &str) -> Result<(), CommandError> {
+        if self.exit_code == 0 {
+            Ok(())
+        } else {
+            Err(CommandError::Failed {
+                command: command.to_string(),
+                exit_code: self.exit_code,
+                stderr: self.stderr.trim_end().to_string(),
+            })
+        }
+    }
+}
+
+/// Why a command did not run to a successful finish.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandError {
+    /// The command line is empty or has an unterminated quote.
+    Malformed { command: String, message: String },
+    /// The [`CommandPolicy`] does not allow the program.
+    NotAllowed { program: String },
+    /// The program could not be started (not found, not executable, …).
+    Spawn { program: String, message: String },
+    /// The program was still running when the timeout ran out, and was killed.
+    TimedOut { command: String, after: Duration },
+    /// The program exited with a non-zero code.
+    Failed { command: String, exit_code: i64, stderr: String },
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CommandError::Malformed { command, message } => write!(f, "cannot run `{}`: {}", command, message),
+            CommandError::NotAllowed { program } => {
+                write!(f, "running `{}` is not allowed: the ## Policy block does not say the program can run it", program)
+            }
+            CommandError::Spawn { program, message } => write!(f, "cannot run `{}`: {}", program, message),
+            CommandError::TimedOut { command, after } => {
+                write!(f, "`{}` was killed after running for {:?}", command, after)
+            }
+            CommandError::Failed { command, exit_code, stderr } => {
+                write!(f, "`{}` exited with code {}", command, exit_code)?;
+                if !stderr.is_empty() {
+                    write!(f, ": {}", stderr)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+/// Which programs a `Run the command` may start.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandPolicy {
+    /// `None` allows every program.
+    allowed: Option<Vec<String>>,
+}
+
+impl Default for CommandPolicy {
+    /// No program may run.
+    fn default() -> Self {
+        Self::deny_all()
+    }
+}
+
+impl CommandPolicy {
+    pub fn allow_all() -> Self {
+        Self { allowed: None }
+    }
+
+    /// No program may run.
+    pub fn deny_all() -> Self {
+        Self { allowed: Some(Vec::new()) }
+    }
+
+    /// Only the named programs may run, matched exactly as written. A bare
+    /// name allows that bare name, which the OS resolves through `PATH`; it
+    /// does not allow `./git` or `/tmp/x/git`, which would let a program run
+    /// any file that happens to share the name. A path allows only itself.
+    pub fn allow<I, S>(programs: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self { allowed: Some(programs.into_iter().map(Into::into).collect()) }
+    }
+
+    /// Whether `program` may run.
+    pub fn permits(&self, program: &str) -> bool {
+        let Some(allowed) = &self.allowed else {
+            return true;
+        };
+        allowed.iter().any(|name| name == program)
+    }
+}
+
+/// Split a command line into a program and its arguments. Whitespace
+/// separates words; single quotes group literally, double quotes group with
+/// `\` escapes, and `\` outside quotes escapes the next character.
+pub fn split_command(command: &str) -> Result<Vec<String>, CommandError> {
+    let malformed = |message: &str| CommandError::Malformed { command: command.to_string(), message: message.to_string() };
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut chars = command.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            '\'' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => word.push(c),
+                        None => return Err(malformed("unterminated ' quote")),
+                    }
+                }
+            }
+            '"' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c) => word.push(c),
+                            None => return Err(malformed("unterminated \" quote")),
+                        },
+                        Some(c) => word.push(c),
+                        None => return Err(malformed("unterminated \" quote")),
+                    }
+                }
+            }
+            '\\' => {
+                in_word = true;
+                if let Some(c) = chars.next() {
+                    word.push(c);
+                }
+            }
+            c => {
+                in_word = true;
+                word.push(c);
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    if words.is_empty() {
+        return Err(malformed("the command is empty"));
+    }
+    Ok(words)
+}
+
+/// Run `command` if `policy` permits its program, killing it after `timeout`.
+/// Any exit code is `Ok`; see [`CommandOutput::require_success`].
+pub fn run_with_policy(
+    command: &str,
+    timeout: Option<Duration>,
+    policy: &CommandPolicy,
+) -> Result<CommandOutput, CommandError> {
+    let words = split_command(command)?;
+    let program = &words[0];
+    if !policy.permits(program) {
+        return Err(CommandError::NotAllowed { program: program.clone() });
+    }
+
+    let mut child = Command::new(program)
+        .args(&words[1..])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| CommandError::Spawn { program: program.clone(), message: e.to_string() })?;
+
+    // Drain both pipes while waiting, so a chatty program can't fill one and block.
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let status = match timeout {
+        None => child.wait(),
+        Some(limit) => {
+            let deadline = Instant::now() + limit;
+            loop {
+                match child.try_wait() {
+                    Ok(Some(status)) => break Ok(status),
+                    Ok(None) if Instant::now() >= deadline => {
+                        let _ = child.kill();
+                        let _ = child.wait();
+                        return Err(CommandError::TimedOut { command: command.to_string(), after: limit });
+                    }
+                    Ok(None) => std::thread::sleep(Duration::from_millis(5)),
+                    Err(e) => break Err(e),
+                }
+            }
+        }
+    }
+    .map_err(|e| CommandError::Spawn { program: program.clone(), message: e.to_string() })?;
+
+    Ok(CommandOutput {
+        exit_code: status.code().map_or(-1, i64::from),
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+fn drain(pipe: Option<impl Read + Send + 'static>) -> std::thread::JoinHandle<String> {
+    std::thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_command_honors_quotes() {
+        assert_eq!(split_command("git status").unwrap(), ["git", "status"]);
+        assert_eq!(
+            split_command(r#"grep -e "two words" 'it''s' a\ b"#).unwrap(),
+            ["grep", "-e", "two words", "its", "a b"]
+        );
+        assert!(matches!(split_command("   "), Err(CommandError::Malformed { .. })));
+        assert!(matches!(split_command("echo \"open"), Err(CommandError::Malformed { .. })));
+    }
+
+    #[test]
+    fn test_captures_output_and_exit_code() {
+        let out = run_with_policy("sh -c 'echo out; echo err >&2; exit 3'", None, &CommandPolicy::allow_all()).unwrap();
+        assert_eq!(out.exit_code, 3);
+        assert_eq!(out.stdout, "out\n");
+        assert_eq!(out.stderr, "err\n");
+        assert_eq!(out.require_success("sh").unwrap_err().to_string(), "`sh` exited with code 3: err");
+    }
+
+    #[test]
+    fn test_timeout_kills_the_program() {
+        let started = Instant::now();
+        let err = run_with_policy("sleep 5", Some(Duration::from_millis(50)), &CommandPolicy::allow_all()).unwrap_err();
+        assert!(matches!(err, CommandError::TimedOut { .. }), "{err}");
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+
+    #[test]
+    fn test_policy_limits_programs() {
+        let policy = CommandPolicy::allow(["git", "/usr/bin/make"]);
+        assert!(policy.permits("git") && policy.permits("/usr/bin/make"));
+        assert!(!policy.permits("rm"));
+        assert!(!policy.permits("./git"));
+        assert!(!policy.permits("/tmp/x/git"));
+        assert!(!policy.permits("/usr/bin/git"));
+        assert!(!policy.permits("make"));
+        assert!(CommandPolicy::allow_all().permits("rm"));
+        assert!(!CommandPolicy::default().permits("echo"));
+        assert_eq!(
+            run_with_policy("rm -rf /tmp/nothing", None, &policy).unwrap_err(),
+            CommandError::NotAllowed { program: "rm".to_string() }
+        );
+    }
+}
@@ -0,0 +1,279 @@
+//! Function Profiler
+//!
+//! Runtime half of `largo run --profile`. The compiler injects an [`enter`]
+//! guard at the top of every LOGOS function (and `Main`), and a [`Session`]
+//! around the program; when the session ends, a flat profile and a call tree
+//! are printed to stderr, named after the LOGOS functions.
+//!
+//! # Accounting
+//!
+//! - **Calls** count every activation.
+//! - **Self** time excludes time spent in callees.
+//! - **Total** time includes callees, counted once per outermost activation,
+//!   so recursion never inflates it.
+//!
+//! Direct recursion collapses into one call-tree node. Each thread keeps its
+//! own call stack; all threads feed one shared tree.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use logicaffeine_system::profile;
+//!
+//! fn work() {
+//!     let _prof = profile::enter("work");
+//!     // ...
+//! }
+//!
+//! let _session = profile::Session::start();
+//! let _prof = profile::enter("Main");
+//! work();
+//! ```
+
+use std::cell::RefCell;
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// One call-tree node: a function reached through a particular chain of callers.
+#[derive(Debug, Clone)]
+struct Node {
+    name: &'static str,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    calls: u64,
+    self_time: Duration,
+    total: Duration,
+}
+
+impl Node {
+    fn new(name: &'static str, parent: Option<usize>) -> Self {
+        Self { name, parent, children: Vec::new(), calls: 0, self_time: Duration::ZERO, total: Duration::ZERO }
+    }
+}
+
+/// The shared call tree; node 0 is the root above every thread's outermost call.
+static TREE: Mutex<Vec<Node>> = Mutex::new(Vec::new());
+
+/// An activation on this thread's stack.
+struct Frame {
+    node: usize,
+    start: Instant,
+    /// Time spent in callees so far.
+    children: Duration,
+    /// A direct recursive call, folded into its caller's node.
+    reentrant: bool,
+}
+
+thread_local! {
+    static STACK: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+}
+
+fn with_tree<R>(f: impl FnOnce(&mut Vec<Node>) -> R) -> R {
+    let mut tree = TREE.lock().unwrap_or_else(|e| e.into_inner());
+    if tree.is_empty() {
+        tree.push(Node::new("<root>", None));
+    }
+    f(&mut tree)
+}
+
+/// Records one activation of `name` until dropped.
+#[must_use = "the activation ends when the guard is dropped"]
+pub struct Guard {
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+/// Start timing an activation of the function `name`.
+pub fn enter(name: &'static str) -> Guard {
+    STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let caller = stack.last().map(|f| f.node);
+        let (node, reentrant) = with_tree(|tree| {
+            let parent = caller.unwrap_or(0);
+            if caller.is_some() && tree[parent].name == name {
+                return (parent, true);
+            }
+            let existing = tree[parent].children.iter().copied().find(|&c| tree[c].name == name);
+            let node = existing.unwrap_or_else(|| {
+                tree.push(Node::new(name, Some(parent)));
+                let id = tree.len() - 1;
+                tree[parent].children.push(id);
+                id
+            });
+            (node, false)
+        });
+        stack.push(Frame { node, start: Instant::now(), children: Duration::ZERO, reentrant });
+    });
+    Guard { _not_send: std::marker::PhantomData }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        let now = Instant::now();
+        STACK.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            let Some(frame) = stack.pop() else { return };
+            let elapsed = now.duration_since(frame.start);
+            if let Some(caller) = stack.last_mut() {
+                caller.children += elapsed;
+            }
+            with_tree(|tree| {
+                let node = &mut tree[frame.node];
+                node.calls += 1;
+                node.self_time += elapsed.saturating_sub(frame.children);
+                if !frame.reentrant {
+                    node.total += elapsed;
+                }
+            });
+        });
+    }
+}
+
+/// One row of the flat profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlatEntry {
+    pub name: &'static str,
+    pub calls: u64,
+    pub self_time: Duration,
+    pub total: Duration,
+}
+
+/// Per-function totals across every call path, sorted by self time.
+pub fn flat() -> Vec<FlatEntry> {
+    let tree = with_tree(|tree| tree.clone());
+    let mut rows: Vec<FlatEntry> = Vec::new();
+    for (id, node) in tree.iter().enumerate().skip(1) {
+        // Indirect recursion: an ancestor already counted this function's total
+        let mut ancestor = node.parent;
+        let mut nested = false;
+        while let Some(a) = ancestor {
+            nested |= tree[a].name == node.name;
+            ancestor = tree[a].parent;
+        }
+        let total = if nested { Duration::ZERO } else { node.total };
+        match rows.iter_mut().find(|r| r.name == node.name) {
+            Some(row) => {
+                row.calls += node.calls;
+                row.self_time += node.self_time;
+                row.total += total;
+            }
+            None => rows.push(FlatEntry { name: tree[id].name, calls: node.calls, self_time: node.self_time, total }),
+        }
+    }
+    rows.sort_by(|a, b| b.self_time.cmp(&a.self_time).then(a.name.cmp(b.name)));
+    rows
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+fn percent(part: Duration, whole: Duration) -> f64 {
+    if whole.is_zero() {
+        0.0
+    } else {
+        100.0 * part.as_secs_f64() / whole.as_secs_f64()
+    }
+}
+
+/// The flat profile and call tree as printed at the end of a profiled run.
+pub fn report() -> String {
+    let tree = with_tree(|tree| tree.clone());
+    let wall: Duration = tree[0].children.iter().map(|&c| tree[c].total).sum();
+    let mut out = String::new();
+
+    let _ = writeln!(out, "Flat profile (by self time):");
+    let _ = writeln!(out, "{:>7} {:>12} {:>12} {:>10}  function", "self %", "self ms", "total ms", "calls");
+    for row in flat() {
+        let _ = writeln!(
+            out,
+            "{:>6.1}% {:>12.3} {:>12.3} {:>10}  {}",
+            percent(row.self_time, wall),
+            millis(row.self_time),
+            millis(row.total),
+            row.calls,
+            row.name
+        );
+    }
+
+    let _ = writeln!(out, "\nCall tree (total ms, calls):");
+    let mut pending: Vec<(usize, usize)> = tree[0].children.iter().rev().map(|&c| (c, 0)).collect();
+    while let Some((id, depth)) = pending.pop() {
+        let node = &tree[id];
+        let _ = writeln!(
+            out,
+            "{:indent$}{} {:.1}%  {:.3} ms  {} call{}",
+            "",
+            node.name,
+            percent(node.total, wall),
+            millis(node.total),
+            node.calls,
+            if node.calls == 1 { "" } else { "s" },
+            indent = depth * 2
+        );
+        let mut children = node.children.clone();
+        children.sort_by(|&a, &b| tree[b].total.cmp(&tree[a].total));
+        pending.extend(children.into_iter().rev().map(|c| (c, depth + 1)));
+    }
+    out
+}
+
+/// Clear everything recorded so far.
+pub fn reset() {
+    TREE.lock().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// A profiled run: starts from a clean slate and prints [`report`] to stderr
+/// when dropped.
+#[must_use = "the report prints when the session is dropped"]
+pub struct Session {
+    _private: (),
+}
+
+impl Session {
+    pub fn start() -> Self {
+        reset();
+        Session { _private: () }
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        eprintln!("\n{}", report());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fib(n: u64) -> u64 {
+        let _prof = enter("fib");
+        if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+    }
+
+    // One test: the tree is process-wide, so parallel tests would interleave.
+    #[test]
+    fn profile_counts_calls_and_folds_recursion() {
+        reset();
+        {
+            let _main = enter("Main");
+            assert_eq!(fib(10), 55);
+            let _other = enter("helper");
+        }
+
+        let rows = flat();
+        let fib_row = rows.iter().find(|r| r.name == "fib").unwrap();
+        assert_eq!(fib_row.calls, 177);
+        let main_row = rows.iter().find(|r| r.name == "Main").unwrap();
+        assert_eq!(main_row.calls, 1);
+        assert!(fib_row.total <= main_row.total);
+        assert!(rows.iter().any(|r| r.name == "helper" && r.calls == 1));
+
+        let text = report();
+        assert!(text.starts_with("Flat profile"));
+        // Direct recursion folds: one `fib` node under `Main`
+        assert_eq!(text.matches("\n  fib ").count(), 1, "{text}");
+        assert!(text.contains("\nMain 100.0%"), "{text}");
+    }
+}
@@ -0,0 +1,344 @@
+//! Typed Settings Loading
+//!
+//! The runtime behind a `## Settings` block and
+//! `Read config from settings "app.toml".`: every declared field is looked up
+//! in the TOML file, then in the environment, and checked against its type.
+//!
+//! - An environment variable overrides the file. Its name is the field name
+//!   in upper snake case: `port` reads `PORT`, `maxConnections` reads
+//!   `MAX_CONNECTIONS`
+//! - A field declared `Option of` a type may be left out; every other field
+//!   is required
+//! - A key in the file that no field declares is an error, so a misspelt
+//!   setting is never silently ignored
+//!
+//! Every problem is reported at once, each against the field it belongs to.
+//!
+//! ```
+//! use logicaffeine_system::settings::{self, SettingField, SettingKind};
+//!
+//! let fields = [
+//!     SettingField { name: "port", kind: SettingKind::Int, optional: false },
+//!     SettingField { name: "host", kind: SettingKind::Text, optional: true },
+//! ];
+//! let values = settings::from_sources(Some(("app.toml", "port = 8080")), &fields, |_| None).unwrap();
+//! assert_eq!(values.int("port"), 8080);
+//! assert_eq!(values.optional_text("host"), None);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// The type of a settings field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingKind {
+    Int,
+    Real,
+    Text,
+    Bool,
+}
+
+impl SettingKind {
+    fn describe(self) -> &'static str {
+        match self {
+            SettingKind::Int => "an Int",
+            SettingKind::Real => "a Real",
+            SettingKind::Text => "a Text",
+            SettingKind::Bool => "a Bool",
+        }
+    }
+}
+
+/// One declared field of a settings schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SettingField<'a> {
+    pub name: &'a str,
+    pub kind: SettingKind,
+    /// Whether the field may be left out (`Option of` a type).
+    pub optional: bool,
+}
+
+/// A loaded, type-checked settings value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingValue {
+    Int(i64),
+    Real(f64),
+    Text(String),
+    Bool(bool),
+}
+
+/// Something wrong with one setting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingProblem {
+    /// The field (or, for an undeclared key, the key) the problem belongs to.
+    pub field: String,
+    pub message: String,
+}
+
+/// Why settings could not be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingsError {
+    /// The settings file could not be read, or is not valid TOML.
+    File { path: String, message: String },
+    /// Fields that are missing, undeclared or of the wrong type.
+    Invalid(Vec<SettingProblem>),
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SettingsError::File { path, message } => write!(f, "cannot read settings from {}: {}", path, message),
+            SettingsError::Invalid(problems) => {
+                write!(f, "invalid settings:")?;
+                for problem in problems {
+                    write!(f, "\n  {}: {}", problem.field, problem.message)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SettingsError {}
+
+/// Settings that passed validation, keyed by field name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SettingValues {
+    values: HashMap<String, SettingValue>,
+}
+
+impl SettingValues {
+    pub(crate) fn insert(&mut self, name: &str, value: SettingValue) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    /// The value of a field, or `None` when an optional field was left out.
+    pub fn get(&self, name: &str) -> Option<&SettingValue> {
+        self.values.get(name)
+    }
+
+    /// A required `Int` field. Panics if the schema did not declare one.
+    pub fn int(&self, name: &str) -> i64 {
+        self.optional_int(name).unwrap_or_else(|| undeclared(name))
+    }
+
+    /// A required `Real` field. Panics if the schema did not declare one.
+    pub fn real(&self, name: &str) -> f64 {
+        self.optional_real(name).unwrap_or_else(|| undeclared(name))
+    }
+
+    /// A required `Text` field. Panics if the schema did not declare one.
+    pub fn text(&self, name: &str) -> String {
+        self.optional_text(name).unwrap_or_else(|| undeclared(name))
+    }
+
+    /// A required `Bool` field. Panics if the schema did not declare one.
+    pub fn bool(&self, name: &str) -> bool {
+        self.optional_bool(name).unwrap_or_else(|| undeclared(name))
+    }
+
+    pub fn optional_int(&self, name: &str) -> Option<i64> {
+        match self.get(name)? {
+            SettingValue::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    pub fn optional_real(&self, name: &str) -> Option<f64> {
+        match self.get(name)? {
+            SettingValue::Real(r) => Some(*r),
+            _ => None,
+        }
+    }
+
+    pub fn optional_text(&self, name: &str) -> Option<String> {
+        match self.get(name)? {
+            SettingValue::Text(t) => Some(t.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn optional_bool(&self, name: &str) -> Option<bool> {
+        match self.get(name)? {
+            SettingValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+}
+
+fn undeclared(name: &str) -> ! {
+    panic!("setting `{}` was not declared with that type", name)
+}
+
+/// The environment variable that overrides a field: `maxConnections` and
+/// `max_connections` both read `MAX_CONNECTIONS`.
+pub fn env_name(field: &str) -> String {
+    let mut out = String::with_capacity(field.len() + 4);
+    let mut prev_lower = false;
+    for c in field.chars() {
+        if c.is_ascii_uppercase() && prev_lower {
+            out.push('_');
+        }
+        prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        if c.is_ascii_alphanumeric() {
+            out.push(c.to_ascii_uppercase());
+        } else {
+            out.push('_');
+        }
+    }
+    out
+}
+
+/// Load settings from the TOML file at `path` (if any) and the process
+/// environment.
+pub fn load(path: Option<&str>, fields: &[SettingField]) -> Result<SettingValues, SettingsError> {
+    let text = match path {
+        Some(path) => Some(std::fs::read_to_string(path).map_err(|e| SettingsError::File {
+            path: path.to_string(),
+            message: e.to_string(),
+        })?),
+        None => None,
+    };
+    from_sources(path.zip(text.as_deref()), fields, |name| std::env::var(name).ok())
+}
+
+/// Validate settings from a file's `(path, TOML text)` and an environment
+/// lookup — [`load`] without the IO, for callers that read files their own way.
+pub fn from_sources(
+    file: Option<(&str, &str)>,
+    fields: &[SettingField],
+    env: impl Fn(&str) -> Option<String>,
+) -> Result<SettingValues, SettingsError> {
+    let mut table = match file {
+        Some((path, text)) => text.parse::<toml::Table>().map_err(|e| SettingsError::File {
+            path: path.to_string(),
+            message: e.message().to_string(),
+        })?,
+        None => toml::Table::new(),
+    };
+    let file_name = file.map(|(path, _)| path);
+
+    let mut values = HashMap::with_capacity(fields.len());
+    let mut problems = Vec::new();
+    for field in fields {
+        let var = env_name(field.name);
+        let found = match (env(&var), table.remove(field.name)) {
+            (Some(raw), _) => Some(from_env(field.kind, &raw).ok_or_else(|| {
+                format!("expected {}, got {:?} (from {})", field.kind.describe(), raw, var)
+            })),
+            (None, Some(value)) => Some(from_toml(field.kind, &value).ok_or_else(|| {
+                format!("expected {}, got a {} (in {})", field.kind.describe(), value.type_str(), file_name.unwrap_or("the file"))
+            })),
+            (None, None) => None,
+        };
+        match found {
+            Some(Ok(value)) => {
+                values.insert(field.name.to_string(), value);
+            }
+            Some(Err(message)) => problems.push(SettingProblem { field: field.name.to_string(), message }),
+            None if field.optional => {}
+            None => problems.push(SettingProblem {
+                field: field.name.to_string(),
+                message: match file_name {
+                    Some(path) => format!("missing; set it in {} or the {} environment variable", path, var),
+                    None => format!("missing; set the {} environment variable", var),
+                },
+            }),
+        }
+    }
+    let mut unknown: Vec<_> = table.keys().cloned().collect();
+    unknown.sort();
+    for key in unknown {
+        problems.push(SettingProblem {
+            message: format!("not a declared setting (in {})", file_name.unwrap_or("the file")),
+            field: key,
+        });
+    }
+
+    if problems.is_empty() {
+        Ok(SettingValues { values })
+    } else {
+        Err(SettingsError::Invalid(problems))
+    }
+}
+
+fn from_toml(kind: SettingKind, value: &toml::Value) -> Option<SettingValue> {
+    match (kind, value) {
+        (SettingKind::Int, toml::Value::Integer(i)) => Some(SettingValue::Int(*i)),
+        (SettingKind::Real, toml::Value::Float(r)) => Some(SettingValue::Real(*r)),
+        (SettingKind::Real, toml::Value::Integer(i)) => Some(SettingValue::Real(*i as f64)),
+        (SettingKind::Text, toml::Value::String(s)) => Some(SettingValue::Text(s.clone())),
+        (SettingKind::Bool, toml::Value::Boolean(b)) => Some(SettingValue::Bool(*b)),
+        _ => None,
+    }
+}
+
+fn from_env(kind: SettingKind, raw: &str) -> Option<SettingValue> {
+    let trimmed = raw.trim();
+    match kind {
+        SettingKind::Int => trimmed.parse().ok().map(SettingValue::Int),
+        SettingKind::Real => trimmed.parse().ok().map(SettingValue::Real),
+        SettingKind::Text => Some(SettingValue::Text(raw.to_string())),
+        SettingKind::Bool => match trimmed.to_ascii_lowercase().as_str() {
+            "true" | "yes" | "on" | "1" => Some(SettingValue::Bool(true)),
+            "false" | "no" | "off" | "0" => Some(SettingValue::Bool(false)),
+            _ => None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIELDS: &[SettingField] = &[
+        SettingField { name: "port", kind: SettingKind::Int, optional: false },
+        SettingField { name: "ratio", kind: SettingKind::Real, optional: false },
+        SettingField { name: "host", kind: SettingKind::Text, optional: true },
+        SettingField { name: "verboseMode", kind: SettingKind::Bool, optional: true },
+    ];
+
+    #[test]
+    fn test_env_name_is_upper_snake_case() {
+        assert_eq!(env_name("port"), "PORT");
+        assert_eq!(env_name("maxConnections"), "MAX_CONNECTIONS");
+        assert_eq!(env_name("max_connections"), "MAX_CONNECTIONS");
+        assert_eq!(env_name("retry2Count"), "RETRY2_COUNT");
+    }
+
+    #[test]
+    fn test_environment_overrides_the_file() {
+        let file = Some(("app.toml", "port = 80\nratio = 1\nhost = \"example.org\""));
+        let env = |name: &str| match name {
+            "PORT" => Some("8080".to_string()),
+            "VERBOSE_MODE" => Some("yes".to_string()),
+            _ => None,
+        };
+        let values = from_sources(file, FIELDS, env).unwrap();
+        assert_eq!(values.int("port"), 8080);
+        assert_eq!(values.real("ratio"), 1.0);
+        assert_eq!(values.optional_text("host").as_deref(), Some("example.org"));
+        assert_eq!(values.optional_bool("verboseMode"), Some(true));
+    }
+
+    #[test]
+    fn test_every_problem_names_its_field() {
+        let file = Some(("app.toml", "port = \"eighty\"\nprot = 80"));
+        let env = |name: &str| (name == "VERBOSE_MODE").then(|| "maybe".to_string());
+        let SettingsError::Invalid(problems) = from_sources(file, FIELDS, env).unwrap_err() else {
+            panic!("expected field problems");
+        };
+        let fields: Vec<&str> = problems.iter().map(|p| p.field.as_str()).collect();
+        assert_eq!(fields, ["port", "ratio", "verboseMode", "prot"]);
+        assert_eq!(problems[0].message, "expected an Int, got a string (in app.toml)");
+        assert_eq!(problems[1].message, "missing; set it in app.toml or the RATIO environment variable");
+        assert_eq!(problems[2].message, "expected a Bool, got \"maybe\" (from VERBOSE_MODE)");
+        assert_eq!(problems[3].message, "not a declared setting (in app.toml)");
+    }
+
+    #[test]
+    fn test_bad_toml_is_a_file_error() {
+        let err = from_sources(Some(("app.toml", "port = ")), FIELDS, |_| None).unwrap_err();
+        assert!(matches!(err, SettingsError::File { ref path, .. } if path == "app.toml"), "{err}");
+    }
+}
@@ -0,0 +1,679 @@
+//! Sipping: verified, resumable, multi-source chunked file transfers.
+//!
+//! A file is cut into chunks described by a [`FileManifest`] of SHA256 hashes.
+//!
+//! - The receiver's [`FileReceiver`] checks every chunk against the manifest
+//!   before writing it. Reopening a partial file re-verifies what's already on
+//!   disk, so a transfer resumes after a disconnect or restart
+//! - A [`ChunkScheduler`] spreads the missing chunks over every peer that has
+//!   the file, a few in flight per peer, and retries failures elsewhere
+//! - [`Progress`] is reported after each chunk through a callback
+//! - [`SipTransfer`] runs the whole loop over copies of a file on this machine;
+//!   it is what `Sip the file … from … .` does in a LOGOS program
+//!
+//! Like `crdt::DeltaSync`, none of this touches the network:
+//! the caller moves manifests, requests and chunks over whatever transport it has.
+//! The sending side over libp2p is `network::FileSipper`.
+
+use sha2::{Sha256, Digest};
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::hash::Hash;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+/// Default chunk size: 1 MB
+pub const DEFAULT_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Manifest describing a file's chunks for resumable transfer.
+///
+/// The manifest contains:
+/// - A unique file ID for this transfer session
+/// - Total file size and chunk count
+/// - SHA256 hashes for each chunk (enables verification and deduplication)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileManifest {
+    pub file_id: String,
+    pub total_size: u64,
+    pub chunk_size: usize,
+    pub chunk_count: usize,
+    pub chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl FileManifest {
+    /// The manifest of the file at `path`, cut into `chunk_size` chunks.
+    ///
+    /// The file ID is the hash of the chunk hashes, so every copy of the same
+    /// bytes has the same manifest.
+    pub fn of_file(path: impl AsRef<Path>, chunk_size: usize) -> Result<Self, SipError> {
+        let chunk_size = chunk_size.max(1);
+        let mut file = File::open(path)?;
+        let total_size = file.metadata()?.len();
+        let mut chunk_hashes = Vec::new();
+        let mut buf = vec![0u8; chunk_size];
+        let mut remaining = total_size;
+        while remaining > 0 {
+            let len = remaining.min(chunk_size as u64) as usize;
+            file.read_exact(&mut buf[..len])?;
+            chunk_hashes.push(sha256(&buf[..len]));
+            remaining -= len as u64;
+        }
+        let file_id = sha256(&chunk_hashes.concat()).iter().map(|b| format!("{:02x}", b)).collect();
+        Ok(Self { file_id, total_size, chunk_size, chunk_count: chunk_hashes.len(), chunk_hashes })
+    }
+
+    /// The length of chunk `index`; only the last chunk may be short.
+    pub fn chunk_len(&self, index: usize) -> usize {
+        let start = (index as u64).saturating_mul(self.chunk_size as u64);
+        self.total_size.saturating_sub(start).min(self.chunk_size as u64) as usize
+    }
+
+    /// Whether `data` is exactly chunk `index` of this file.
+    pub fn verify(&self, index: usize, data: &[u8]) -> bool {
+        index < self.chunk_count
+            && data.len() == self.chunk_len(index)
+            && sha256(data) == self.chunk_hashes[index]
+    }
+
+    /// Check a received chunk against the manifest.
+    ///
+    /// The chunk's own `hash` field isn't trusted: the data is hashed again
+    /// and compared with the manifest.
+    pub fn check(&self, chunk: &FileChunk) -> Result<(), SipError> {
+        if chunk.file_id != self.file_id {
+            return Err(SipError::WrongFile { expected: self.file_id.clone(), got: chunk.file_id.clone() });
+        }
+        if chunk.index >= self.chunk_count {
+            return Err(SipError::OutOfRange(chunk.index));
+        }
+        if !self.verify(chunk.index, &chunk.data) {
+            return Err(SipError::Corrupt(chunk.index));
+        }
+        Ok(())
+    }
+}
+
+/// A single chunk of file data with its hash for verification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileChunk {
+    pub file_id: String,
+    pub index: usize,
+    pub data: Vec<u8>,
+    pub hash: [u8; 32],
+}
+
+pub(crate) fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// Why a chunk or a transfer was refused.
+#[derive(Debug)]
+pub enum SipError {
+    /// The chunk belongs to another transfer.
+    WrongFile { expected: String, got: String },
+    /// The manifest has no chunk at this index.
+    OutOfRange(usize),
+    /// The chunk's data doesn't match the manifest.
+    Corrupt(usize),
+    /// Every source failed to deliver this chunk.
+    Unavailable(usize),
+    /// A transfer was started without any source.
+    NoSources,
+    /// Reading or writing the destination file failed.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for SipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SipError::WrongFile { expected, got } => {
+                write!(f, "chunk of file {} sent to the transfer of {}", got, expected)
+            }
+            SipError::OutOfRange(index) => write!(f, "no chunk {} in the manifest", index),
+            SipError::Corrupt(index) => write!(f, "chunk {} doesn't match its checksum", index),
+            SipError::Unavailable(index) => write!(f, "no source has a good copy of chunk {}", index),
+            SipError::NoSources => write!(f, "no source to sip the file from"),
+            SipError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for SipError {}
+
+impl From<std::io::Error> for SipError {
+    fn from(e: std::io::Error) -> Self {
+        SipError::Io(e)
+    }
+}
+
+/// How far a transfer has got.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    pub chunks_done: usize,
+    pub chunk_count: usize,
+    pub bytes_done: u64,
+    pub total_bytes: u64,
+}
+
+impl Progress {
+    pub fn is_complete(&self) -> bool {
+        self.chunks_done == self.chunk_count
+    }
+
+    /// Whole percent of the bytes received; an empty file is 100% done.
+    pub fn percent(&self) -> u64 {
+        (self.bytes_done * 100).checked_div(self.total_bytes).unwrap_or(100)
+    }
+}
+
+impl fmt::Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} chunks, {} of {} bytes ({}%)",
+            self.chunks_done, self.chunk_count, self.bytes_done, self.total_bytes, self.percent()
+        )
+    }
+}
+
+impl crate::io::Showable for Progress {
+    fn format_show(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl crate::io::Showable for FileManifest {
+    fn format_show(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} bytes in {} chunks", self.total_size, self.chunk_count)
+    }
+}
+
+type ProgressCallback = Box<dyn FnMut(&Progress) + Send>;
+
+/// The receiving end of a transfer: verifies chunks and writes them into
+/// place in the destination file.
+///
+/// Chunks may arrive in any order and from any peer. Nothing records which
+/// chunks arrived except the file itself: [`FileReceiver::open`] hashes the
+/// regions a partial file already holds, so a transfer cut off by a
+/// disconnect or a crash picks up where it stopped.
+///
+/// # Example
+/// ```no_run
+/// # use logicaffeine_system::sip::{FileManifest, FileReceiver};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let manifest: FileManifest = unimplemented!();
+/// let mut receiver = FileReceiver::open("download.bin", manifest)?;
+/// receiver.on_progress(|p| println!("{}", p));
+/// for index in receiver.missing() {
+///     // request chunk `index` from a peer, then:
+///     # let chunk = unimplemented!();
+///     receiver.accept(&chunk)?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct FileReceiver {
+    manifest: FileManifest,
+    file: File,
+    have: Vec<bool>,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl FileReceiver {
+    /// Receive into `path`, keeping every chunk it already holds intact.
+    pub fn open(path: impl AsRef<Path>, manifest: FileManifest) -> Result<Self, SipError> {
+        let mut file = OpenOptions::new().read(true).write(true).create(true).truncate(false).open(path)?;
+        let existing = file.metadata()?.len();
+        if existing != manifest.total_size {
+            file.set_len(manifest.total_size)?;
+        }
+
+        let mut have = vec![false; manifest.chunk_count];
+        let mut buf = vec![0u8; manifest.chunk_size];
+        for (index, held) in have.iter_mut().enumerate() {
+            let start = index as u64 * manifest.chunk_size as u64;
+            if start >= existing {
+                break;
+            }
+            let len = manifest.chunk_len(index);
+            file.seek(SeekFrom::Start(start))?;
+            file.read_exact(&mut buf[..len])?;
+            *held = manifest.verify(index, &buf[..len]);
+        }
+        Ok(Self { manifest, file, have, on_progress: None })
+    }
+
+    /// Call `callback` with the new progress after each accepted chunk.
+    pub fn on_progress(&mut self, callback: impl FnMut(&Progress) + Send + 'static) -> &mut Self {
+        self.on_progress = Some(Box::new(callback));
+        self
+    }
+
+    pub fn manifest(&self) -> &FileManifest {
+        &self.manifest
+    }
+
+    /// Verify `chunk` and write it into place.
+    ///
+    /// Returns `Ok(false)` for a chunk already held. A chunk that fails
+    /// verification is never written.
+    pub fn accept(&mut self, chunk: &FileChunk) -> Result<bool, SipError> {
+        self.manifest.check(chunk)?;
+        if self.have[chunk.index] {
+            return Ok(false);
+        }
+        let start = chunk.index as u64 * self.manifest.chunk_size as u64;
+        self.file.seek(SeekFrom::Start(start))?;
+        self.file.write_all(&chunk.data)?;
+        self.have[chunk.index] = true;
+        if self.is_complete() {
+            self.file.sync_all()?;
+        }
+
+        let progress = self.progress();
+        if let Some(callback) = self.on_progress.as_mut() {
+            callback(&progress);
+        }
+        Ok(true)
+    }
+
+    /// Indices of the chunks still to fetch, in file order.
+    pub fn missing(&self) -> Vec<usize> {
+        self.have.iter().enumerate().filter(|(_, held)| !**held).map(|(index, _)| index).collect()
+    }
+
+    pub fn progress(&self) -> Progress {
+        let held = || self.have.iter().enumerate().filter(|(_, held)| **held);
+        Progress {
+            chunks_done: held().count(),
+            chunk_count: self.manifest.chunk_count,
+            bytes_done: held().map(|(index, _)| self.manifest.chunk_len(index) as u64).sum(),
+            total_bytes: self.manifest.total_size,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.have.iter().all(|held| *held)
+    }
+}
+
+impl fmt::Debug for FileReceiver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileReceiver")
+            .field("file_id", &self.manifest.file_id)
+            .field("progress", &self.progress())
+            .finish_non_exhaustive()
+    }
+}
+
+/// How many requests a peer gets at once by default.
+pub const DEFAULT_REQUESTS_PER_PEER: usize = 4;
+
+/// Decides which peer to ask for which chunk, so a file downloads from every
+/// peer that has it at once.
+///
+/// Each peer gets up to `per_peer` requests in flight. A chunk that failed
+/// (timed out, or didn't verify) goes back to the front of the queue and is
+/// offered to other peers before the one that failed it; a peer that leaves
+/// hands its in-flight chunks back the same way.
+#[derive(Debug, Clone)]
+pub struct ChunkScheduler<P> {
+    queue: VecDeque<usize>,
+    in_flight: HashMap<usize, P>,
+    peers: Vec<P>,
+    per_peer: usize,
+    failed: HashMap<usize, HashSet<P>>,
+}
+
+impl<P: Clone + Eq + Hash> ChunkScheduler<P> {
+    /// Schedule `missing` chunks — usually [`FileReceiver::missing`].
+    pub fn new(missing: impl IntoIterator<Item = usize>) -> Self {
+        Self::with_requests_per_peer(missing, DEFAULT_REQUESTS_PER_PEER)
+    }
+
+    pub fn with_requests_per_peer(missing: impl IntoIterator<Item = usize>, per_peer: usize) -> Self {
+        Self {
+            queue: missing.into_iter().collect(),
+            in_flight: HashMap::new(),
+            peers: Vec::new(),
+            per_peer: per_peer.max(1),
+            failed: HashMap::new(),
+        }
+    }
+
+    /// A peer that has the file.
+    pub fn add_peer(&mut self, peer: P) {
+        if !self.peers.contains(&peer) {
+            self.peers.push(peer);
+        }
+    }
+
+    /// A peer that went away; its in-flight chunks are rescheduled.
+    pub fn remove_peer(&mut self, peer: &P) {
+        self.peers.retain(|p| p != peer);
+        let mut orphaned: Vec<usize> =
+            self.in_flight.iter().filter(|(_, p)| *p == peer).map(|(index, _)| *index).collect();
+        orphaned.sort_unstable();
+        for index in orphaned.into_iter().rev() {
+            self.in_flight.remove(&index);
+            self.queue.push_front(index);
+        }
+    }
+
+    /// The requests to send now, spread round-robin over the peers.
+    pub fn next_requests(&mut self) -> Vec<(P, usize)> {
+        let mut requests = Vec::new();
+        let mut load: Vec<usize> =
+            self.peers.iter().map(|peer| self.in_flight.values().filter(|p| *p == peer).count()).collect();
+        loop {
+            let mut assigned = false;
+            for (slot, peer) in self.peers.iter().enumerate() {
+                if load[slot] >= self.per_peer {
+                    continue;
+                }
+                let Some(position) = self.queue.iter().position(|index| self.may_ask(peer, *index)) else {
+                    continue;
+                };
+                let index = self.queue.remove(position).expect("position is in the queue");
+                self.in_flight.insert(index, peer.clone());
+                load[slot] += 1;
+                requests.push((peer.clone(), index));
+                assigned = true;
+            }
+            if !assigned {
+                return requests;
+            }
+        }
+    }
+
+    /// A peer may be asked for a chunk it hasn't failed, unless every peer has.
+    fn may_ask(&self, peer: &P, index: usize) -> bool {
+        match self.failed.get(&index) {
+            Some(failed) => !failed.contains(peer) || self.peers.iter().all(|p| failed.contains(p)),
+            None => true,
+        }
+    }
+
+    /// Chunk `index` arrived and verified.
+    pub fn completed(&mut self, index: usize) {
+        self.in_flight.remove(&index);
+        self.failed.remove(&index);
+    }
+
+    /// `peer` didn't deliver chunk `index`; someone else should try first.
+    pub fn failed(&mut self, peer: &P, index: usize) {
+        if self.in_flight.get(&index) == Some(peer) {
+            self.in_flight.remove(&index);
+            self.queue.push_front(index);
+        }
+        self.failed.entry(index).or_default().insert(peer.clone());
+    }
+
+    /// Whether every peer has failed chunk `index`, so retrying can't help
+    /// until a new peer joins.
+    pub fn is_unavailable(&self, index: usize) -> bool {
+        self.failed.get(&index).is_some_and(|failed| self.peers.iter().all(|p| failed.contains(p)))
+    }
+
+    /// Requests sent and not yet answered.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    /// Whether every chunk has been delivered.
+    pub fn is_done(&self) -> bool {
+        self.queue.is_empty() && self.in_flight.is_empty()
+    }
+}
+
+/// The sources a compiled `Sip the file … from <sources>.` names: one path,
+/// or a list of them.
+pub trait SipSources {
+    fn sip_paths(&self) -> Vec<String>;
+}
+
+impl SipSources for &str {
+    fn sip_paths(&self) -> Vec<String> {
+        vec![self.to_string()]
+    }
+}
+
+impl SipSources for String {
+    fn sip_paths(&self) -> Vec<String> {
+        vec![self.clone()]
+    }
+}
+
+impl<T: fmt::Display> SipSources for Vec<T> {
+    fn sip_paths(&self) -> Vec<String> {
+        self.iter().map(ToString::to_string).collect()
+    }
+}
+
+impl<T: fmt::Display> SipSources for logicaffeine_data::LogosSeq<T> {
+    fn sip_paths(&self) -> Vec<String> {
+        self.0.borrow().iter().map(ToString::to_string).collect()
+    }
+}
+
+/// A transfer from copies of a file on this machine — a mounted share, a
+/// mirror, a removable drive — each copy standing in for a peer.
+///
+/// The first source is the file itself: every chunk is checked against its
+/// manifest. The others are mirrors that may be partial or damaged; a chunk
+/// one of them gets wrong is fetched from another source instead. Chunks go
+/// through a [`FileReceiver`], so an interrupted transfer resumes, and the
+/// iterator yields the [`Progress`] after each one.
+///
+/// # Example
+/// ```no_run
+/// # use logicaffeine_system::sip::SipTransfer;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// for progress in SipTransfer::open("copy.bin", &["/mnt/origin/data.bin", "/mnt/mirror/data.bin"])? {
+///     println!("{}", progress?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SipTransfer {
+    receiver: FileReceiver,
+    scheduler: ChunkScheduler<usize>,
+    sources: Vec<Option<File>>,
+    requests: VecDeque<(usize, usize)>,
+    failed: bool,
+}
+
+impl SipTransfer {
+    /// Assemble `dest` from `sources` in [`DEFAULT_CHUNK_SIZE`] chunks.
+    pub fn open<S: AsRef<Path>>(dest: impl AsRef<Path>, sources: &[S]) -> Result<Self, SipError> {
+        Self::with_chunk_size(dest, sources, DEFAULT_CHUNK_SIZE)
+    }
+
+    pub fn with_chunk_size<S: AsRef<Path>>(
+        dest: impl AsRef<Path>,
+        sources: &[S],
+        chunk_size: usize,
+    ) -> Result<Self, SipError> {
+        let origin = sources.first().ok_or(SipError::NoSources)?;
+        let manifest = FileManifest::of_file(origin, chunk_size)?;
+        let receiver = FileReceiver::open(dest, manifest)?;
+        let mut scheduler = ChunkScheduler::new(receiver.missing());
+        for peer in 0..sources.len() {
+            scheduler.add_peer(peer);
+        }
+        // A mirror that can't be opened fails every chunk it's asked for.
+        let sources = sources.iter().map(|path| File::open(path).ok()).collect();
+        Ok(Self { receiver, scheduler, sources, requests: VecDeque::new(), failed: false })
+    }
+
+    pub fn progress(&self) -> Progress {
+        self.receiver.progress()
+    }
+
+    fn read_chunk(&mut self, source: usize, index: usize) -> std::io::Result<FileChunk> {
+        let manifest = self.receiver.manifest();
+        let (file_id, start) = (manifest.file_id.clone(), index as u64 * manifest.chunk_size as u64);
+        let mut data = vec![0u8; manifest.chunk_len(index)];
+        let file = self.sources[source]
+            .as_mut()
+            .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound))?;
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut data)?;
+        let hash = sha256(&data);
+        Ok(FileChunk { file_id, index, data, hash })
+    }
+}
+
+impl Iterator for SipTransfer {
+    type Item = Result<Progress, SipError>;
+
+    /// Fetch chunks until one more is written into place.
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.failed && !self.receiver.is_complete() {
+            if self.requests.is_empty() {
+                self.requests.extend(self.scheduler.next_requests());
+            }
+            let (source, index) = self.requests.pop_front()?;
+            let accepted = match self.read_chunk(source, index) {
+                Ok(chunk) => self.receiver.accept(&chunk),
+                Err(_) => Err(SipError::Corrupt(index)),
+            };
+            match accepted {
+                Ok(_) => {
+                    self.scheduler.completed(index);
+                    return Some(Ok(self.receiver.progress()));
+                }
+                Err(SipError::Corrupt(_)) => {
+                    self.scheduler.failed(&source, index);
+                    if self.scheduler.is_unavailable(index) {
+                        self.failed = true;
+                        return Some(Err(SipError::Unavailable(index)));
+                    }
+                }
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_file(path: &Path, size: usize) -> Vec<u8> {
+        let data: Vec<u8> = (0..size).map(|i| (i % 251) as u8).collect();
+        std::fs::write(path, &data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_manifest_of_file_is_content_addressed() {
+        let dir = tempfile::tempdir().unwrap();
+        let (a, b) = (dir.path().join("a.bin"), dir.path().join("b.bin"));
+        write_file(&a, 250);
+        write_file(&b, 250);
+
+        let manifest = FileManifest::of_file(&a, 100).unwrap();
+        assert_eq!(manifest.chunk_count, 3);
+        assert_eq!(manifest.chunk_len(2), 50);
+        assert_eq!(manifest.file_id, FileManifest::of_file(&b, 100).unwrap().file_id);
+    }
+
+    #[test]
+    fn test_scheduler_spreads_requests_over_peers() {
+        let mut scheduler = ChunkScheduler::with_requests_per_peer(0..5, 2);
+        scheduler.add_peer("a");
+        scheduler.add_peer("b");
+        let requests = scheduler.next_requests();
+        assert_eq!(requests, vec![("a", 0), ("b", 1), ("a", 2), ("b", 3)]);
+        assert!(scheduler.next_requests().is_empty(), "both peers are busy");
+
+        scheduler.completed(0);
+        assert_eq!(scheduler.next_requests(), vec![("a", 4)]);
+        for index in 1..5 {
+            scheduler.completed(index);
+        }
+        assert!(scheduler.is_done());
+    }
+
+    #[test]
+    fn test_scheduler_retries_failures_elsewhere() {
+        let mut scheduler = ChunkScheduler::with_requests_per_peer([7], 1);
+        scheduler.add_peer("a");
+        scheduler.add_peer("b");
+        assert_eq!(scheduler.next_requests(), vec![("a", 7)]);
+
+        scheduler.failed(&"a", 7);
+        assert!(!scheduler.is_unavailable(7));
+        assert_eq!(scheduler.next_requests(), vec![("b", 7)]);
+
+        // Once every peer has failed it, anyone may try again
+        scheduler.failed(&"b", 7);
+        assert!(scheduler.is_unavailable(7));
+        assert_eq!(scheduler.next_requests(), vec![("a", 7)]);
+    }
+
+    #[test]
+    fn test_scheduler_reschedules_a_departed_peers_chunks() {
+        let mut scheduler = ChunkScheduler::with_requests_per_peer(0..4, 2);
+        scheduler.add_peer("a");
+        scheduler.add_peer("b");
+        scheduler.next_requests();
+        scheduler.remove_peer(&"b");
+        assert_eq!(scheduler.in_flight(), 2);
+        assert!(scheduler.next_requests().is_empty(), "a is still busy");
+        scheduler.completed(0);
+        scheduler.completed(2);
+        assert_eq!(scheduler.next_requests(), vec![("a", 1), ("a", 3)]);
+    }
+
+    #[test]
+    fn test_transfer_routes_around_a_damaged_mirror() {
+        let dir = tempfile::tempdir().unwrap();
+        let (origin, mirror, dest) = (dir.path().join("origin"), dir.path().join("mirror"), dir.path().join("copy"));
+        let data = write_file(&origin, 1000);
+        let mut damaged = data.clone();
+        for byte in &mut damaged[100..300] {
+            *byte ^= 0xff;
+        }
+        std::fs::write(&mirror, &damaged).unwrap();
+
+        let transfer = SipTransfer::with_chunk_size(&dest, &[&origin, &mirror], 100).unwrap();
+        let steps: Vec<Progress> = transfer.map(Result::unwrap).collect();
+        assert_eq!(steps.len(), 10);
+        assert_eq!(steps.iter().map(Progress::percent).collect::<Vec<_>>(), (1..=10).map(|n| n * 10).collect::<Vec<_>>());
+        assert_eq!(std::fs::read(&dest).unwrap(), data);
+    }
+
+    #[test]
+    fn test_transfer_resumes_and_reports_unavailable_chunks() {
+        let dir = tempfile::tempdir().unwrap();
+        let (origin, dest) = (dir.path().join("origin"), dir.path().join("copy"));
+        let data = write_file(&origin, 1000);
+        let mut partial = data.clone();
+        partial[950] ^= 1;
+        std::fs::write(&dest, &partial).unwrap();
+
+        let mut transfer = SipTransfer::with_chunk_size(&dest, &[&origin], 100).unwrap();
+        assert_eq!(transfer.progress().chunks_done, 9, "only the damaged chunk is fetched again");
+        assert!(transfer.next().unwrap().unwrap().is_complete());
+        assert!(transfer.next().is_none());
+
+        // The origin changed under the transfer: nothing can supply the chunk.
+        let mut transfer = SipTransfer::with_chunk_size(dir.path().join("other"), &[&origin], 100).unwrap();
+        std::fs::write(&origin, &partial).unwrap();
+        let failures: Vec<_> = transfer.by_ref().filter_map(Result::err).collect();
+        assert!(matches!(failures.as_slice(), [SipError::Unavailable(9)]));
+        assert!(transfer.next().is_none());
+    }
+}
@@ -1,6 +1,15 @@
 //! Temporal types for Logicaffeine.
 //!
 //! Provides Date and Moment types that complement std::time::Duration.
+//!
+//! These are the compiled forms of the prose temporal sentences:
+//!
+//! ```text
+//! Let deadline be today plus 14 days.        LogosDate::today() + LogosSpan::new(0, 14)
+//! If deadline is before 2026-06-01:          deadline < LogosDate(20605)
+//! Show deadline formatted as "%d %B %Y".     deadline.format("%d %B %Y")
+//! Show the days between today and deadline.  LogosDate::today().seconds_until(&deadline) / 86400
+//! ```
 
 use std::fmt::{self, Display};
 
@@ -51,6 +60,39 @@ impl LogosDate {
         (self.month() - 1) / 3 + 1
     }
 
+    /// The start of this day (midnight UTC) as a moment.
+    #[inline]
+    pub fn at_midnight(&self) -> LogosMoment {
+        LogosMoment(self.0 as i64 * logicaffeine_base::temporal::NANOS_PER_DAY)
+    }
+
+    /// Whole seconds from `self` to `other`, a Date or Moment (AOT mirror of
+    /// `seconds_between`); a Date counts from its midnight.
+    #[inline]
+    pub fn seconds_until<T: Copy + Into<LogosMoment>>(&self, other: &T) -> i64 {
+        self.at_midnight().seconds_until(other)
+    }
+    #[inline]
+    pub fn months_until<T: Copy + Into<LogosMoment>>(&self, other: &T) -> i64 {
+        self.at_midnight().months_until(other)
+    }
+    #[inline]
+    pub fn years_until<T: Copy + Into<LogosMoment>>(&self, other: &T) -> i64 {
+        self.at_midnight().years_until(other)
+    }
+
+    /// Render with a `strftime`-style pattern — the AOT mirror of `format_as`. Panics on an
+    /// unknown directive, which the interpreter reports as an error.
+    pub fn format(&self, pattern: &str) -> String {
+        self.at_midnight().format(pattern)
+    }
+
+    /// The current date (UTC).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn today() -> Self {
+        LogosMoment::now().date()
+    }
+
     /// Convert to year, month, day using Howard Hinnant's algorithm.
     pub fn to_ymd(&self) -> (i64, i64, i64) {
         let z = self.0 as i64 + 719468; // shift epoch
@@ -67,6 +109,38 @@ impl LogosDate {
     }
 }
 
+impl From<LogosDate> for LogosMoment {
+    #[inline]
+    fn from(date: LogosDate) -> LogosMoment {
+        date.at_midnight()
+    }
+}
+
+// `Date ± Span` is calendar arithmetic on the day number, clamping to the end of the month — the
+// same `base::temporal::add_span` the interpreter's `date_add_span` uses.
+impl LogosDate {
+    #[inline]
+    fn add_span(self, months: i32, days: i32) -> LogosDate {
+        self.at_midnight().add_span(months, days).date()
+    }
+}
+
+impl std::ops::Add<LogosSpan> for LogosDate {
+    type Output = LogosDate;
+    #[inline]
+    fn add(self, rhs: LogosSpan) -> LogosDate {
+        self.add_span(rhs.months, rhs.days)
+    }
+}
+
+impl std::ops::Sub<LogosSpan> for LogosDate {
+    type Output = LogosDate;
+    #[inline]
+    fn sub(self, rhs: LogosSpan) -> LogosDate {
+        self.add_span(-rhs.months, -rhs.days)
+    }
+}
+
 impl Display for LogosDate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let (year, month, day) = self.to_ymd();
@@ -178,21 +252,28 @@ impl LogosMoment {
         Self(self.0 + seconds * 1_000_000_000)
     }
 
-    /// Whole seconds from `self` to `other` (AOT mirror of `seconds_between`).
+    /// Whole seconds from `self` to `other`, a Moment or Date (AOT mirror of `seconds_between`).
     #[inline]
-    pub fn seconds_until(&self, other: &LogosMoment) -> i64 {
-        (other.0 - self.0) / 1_000_000_000
+    pub fn seconds_until<T: Copy + Into<LogosMoment>>(&self, other: &T) -> i64 {
+        ((*other).into().0 - self.0) / 1_000_000_000
     }
 
     /// Complete calendar months / years from `self` to `other` — AOT mirrors of
     /// `months_between` / `years_between` (signed, end-of-month-clamping correct).
     #[inline]
-    pub fn months_until(&self, other: &LogosMoment) -> i64 {
-        logicaffeine_base::temporal::months_between(self.0, other.0)
+    pub fn months_until<T: Copy + Into<LogosMoment>>(&self, other: &T) -> i64 {
+        logicaffeine_base::temporal::months_between(self.0, (*other).into().0)
     }
     #[inline]
-    pub fn years_until(&self, other: &LogosMoment) -> i64 {
-        logicaffeine_base::temporal::years_between(self.0, other.0)
+    pub fn years_until<T: Copy + Into<LogosMoment>>(&self, other: &T) -> i64 {
+        logicaffeine_base::temporal::years_between(self.0, (*other).into().0)
+    }
+
+    /// Render with a `strftime`-style pattern (`"%d %B %Y at %H:%M"`) — the AOT mirror of
+    /// `format_as`. Panics on an unknown directive, which the interpreter reports as an error.
+    pub fn format(&self, pattern: &str) -> String {
+        logicaffeine_base::temporal::format_pattern(self.0, pattern)
+            .unwrap_or_else(|e| panic!("LOGOS runtime error: {e}"))
     }
 
     /// The local wall-clock time (with offset) in a named zone — AOT mirror of `in_zone`. Panics on
@@ -270,12 +351,10 @@ impl std::ops::Sub<LogosSpan> for LogosMoment {
 }
 
 impl Display for LogosMoment {
+    /// `YYYY-MM-DD HH:MM` (UTC), as the interpreter shows a Moment.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // For now, just show as ISO-ish format with nanosecond precision
-        let nanos = self.0;
-        let seconds = nanos / 1_000_000_000;
-        let remainder = nanos % 1_000_000_000;
-        write!(f, "Moment({}s + {}ns)", seconds, remainder)
+        let c = logicaffeine_base::temporal::civil_from_unix_nanos(self.0);
+        write!(f, "{:04}-{:02}-{:02} {:02}:{:02}", c.year, c.month, c.day, c.hour, c.minute)
     }
 }
 
@@ -423,4 +502,22 @@ mod tests {
         let date = LogosDate(20593); // 2026-05-20
         assert_eq!(date.to_string(), "2026-05-20");
     }
+
+    #[test]
+    fn date_span_arithmetic_clamps_to_month_end() {
+        let jan31 = LogosDate(20484); // 2026-01-31
+        assert_eq!((jan31 + LogosSpan::new(1, 0)).to_string(), "2026-02-28");
+        assert_eq!((jan31 + LogosSpan::new(0, 14)).to_string(), "2026-02-14");
+        assert_eq!((jan31 - LogosSpan::new(0, 31)).to_string(), "2025-12-31");
+        assert_eq!(jan31.seconds_until(&LogosDate(20513)) / 86_400, 29);
+        assert_eq!(jan31.months_until(&(LogosDate(20513).at_midnight())), 1);
+    }
+
+    #[test]
+    fn moment_display_and_format() {
+        let m = LogosMoment(1_779_292_800_000_000_000); // 2026-05-20 16:00 UTC
+        assert_eq!(m.to_string(), "2026-05-20 16:00");
+        assert_eq!(m.format("%A %d %B, %I %p"), "Wednesday 20 May, 04 PM");
+        assert_eq!(m.date().format("%Y/%m/%d"), "2026/05/20");
+    }
 }
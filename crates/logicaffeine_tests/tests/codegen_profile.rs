@@ -0,0 +1,76 @@
+//! `largo run --profile`: profiled codegen opens a `logicaffeine_system::profile`
+//! guard in every LOGOS function and wraps `Main` in a reporting session,
+//! while the ordinary build stays untouched.
+
+use logicaffeine_compile::compile::{compile_project, compile_project_profiled};
+
+const PROGRAM: &str = "\
+## To fib (n: Int) -> Int:
+    If n is less than 2:
+        Return n.
+    Return fib(n - 1) + fib(n - 2).
+
+## To total (xs: Seq of Int) -> Int:
+    Let mutable sum be 0.
+    Repeat for x in xs:
+        Set sum to sum + x.
+    Return sum.
+
+## Main
+Let xs be [1, 2, 3].
+Show fib(15).
+Show total(xs).
+";
+
+fn compile(profiled: bool) -> String {
+    let dir = tempfile::tempdir().unwrap();
+    let entry = dir.path().join("main.lg");
+    std::fs::write(&entry, PROGRAM).unwrap();
+    let output = if profiled { compile_project_profiled(&entry) } else { compile_project(&entry) };
+    output.expect("program must compile").rust_code
+}
+
+/// The body of the generated `fn <name>`, up to the next top-level item.
+fn function_body<'a>(rust: &'a str, name: &str) -> &'a str {
+    let start = rust
+        .find(&format!("fn {name}("))
+        .unwrap_or_else(|| panic!("no fn {name} in:\n{rust}"));
+    let rest = &rust[start..];
+    let end = rest.find("\n}\n").map(|i| i + 3).unwrap_or(rest.len());
+    &rest[..end]
+}
+
+#[test]
+fn every_function_opens_a_profiler_guard() {
+    let rust = compile(true);
+    for name in ["fib", "total"] {
+        let body = function_body(&rust, name);
+        let guard = format!("let _logos_prof = logicaffeine_system::profile::enter({name:?});");
+        assert_eq!(body.matches(&guard).count(), 1, "fn {name} must open exactly one guard:\n{body}");
+        // The guard is the first statement, right after the signature
+        assert!(body.lines().nth(1).is_some_and(|l| l.trim() == guard), "guard not first in fn {name}:\n{body}");
+    }
+}
+
+#[test]
+fn main_runs_inside_a_profiling_session() {
+    let rust = compile(true);
+    let session = rust.find("profile::Session::start()").expect("Main must start a session");
+    let main_guard = rust.find("profile::enter(\"Main\")").expect("Main must be profiled");
+    // The session is declared first so it drops last and reports Main's time
+    assert!(session < main_guard);
+    assert_eq!(rust.matches("profile::Session::start()").count(), 1);
+}
+
+#[test]
+fn unprofiled_builds_carry_no_instrumentation() {
+    let rust = compile(false);
+    assert!(!rust.contains("logicaffeine_system::profile"), "plain build was instrumented:\n{rust}");
+    let profiled = compile(true);
+    let stripped: String = profiled
+        .lines()
+        .filter(|l| !l.contains("logicaffeine_system::profile"))
+        .map(|l| format!("{l}\n"))
+        .collect();
+    assert_eq!(stripped, rust, "profiling must only add guard lines");
+}
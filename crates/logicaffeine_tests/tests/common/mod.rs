@@ -8,6 +8,7 @@ use std::process::Command;
 use std::sync::OnceLock;
 use std::sync::atomic::{AtomicU64, Ordering};
 pub use logicaffeine_compile::compile::{compile_to_rust, compile_program_full, compile_program_full_deterministic};
+use logicaffeine_compile::compile::toml_feature_list;
 
 // ============================================================
 // Parse Helper - replaces the logos::parse! macro
@@ -290,6 +291,7 @@ pub fn compile_logos(source: &str) -> CompileResult {
     };
     let rust_code = compile_output.rust_code;
     let user_deps = format_user_deps(&compile_output.dependencies);
+    let system_features = toml_feature_list(&compile_output.system_features);
 
     // 2. Create temp project
     let temp_dir = tempfile::tempdir().expect("temp dir");
@@ -311,7 +313,7 @@ edition = "2021"
 
 [dependencies]
 logicaffeine-data = {{ path = "{}/crates/logicaffeine_data" }}
-logicaffeine-system = {{ path = "{}/crates/logicaffeine_system", features = ["full"] }}
+logicaffeine-system = {{ path = "{}/crates/logicaffeine_system", features = [{}] }}
 tokio = {{ version = "1", features = ["rt-multi-thread", "macros"] }}
 serde = {{ version = "1", features = ["derive"] }}
 rayon = "1"
@@ -319,6 +321,7 @@ rayon = "1"
         pkg_name,
         workspace_root.display(),
         workspace_root.display(),
+        system_features,
         user_deps
     );
 
@@ -366,6 +369,7 @@ pub fn compile_logos_llvm_ir(source: &str) -> String {
         .unwrap_or_else(|e| panic!("LOGOS compile error: {:?}\n\nSource:\n{}", e, source));
     let rust_code = compile_output.rust_code;
     let user_deps = format_user_deps(&compile_output.dependencies);
+    let system_features = toml_feature_list(&compile_output.system_features);
 
     let temp_dir = tempfile::tempdir().expect("temp dir");
     let project_dir = temp_dir.path();
@@ -387,7 +391,7 @@ codegen-units = 1
 
 [dependencies]
 logicaffeine-data = {{ path = "{}/crates/logicaffeine_data" }}
-logicaffeine-system = {{ path = "{}/crates/logicaffeine_system", features = ["full"] }}
+logicaffeine-system = {{ path = "{}/crates/logicaffeine_system", features = [{}] }}
 tokio = {{ version = "1", features = ["rt-multi-thread", "macros"] }}
 serde = {{ version = "1", features = ["derive"] }}
 rayon = "1"
@@ -395,6 +399,7 @@ rayon = "1"
         pkg_name,
         workspace_root.display(),
         workspace_root.display(),
+        system_features,
         user_deps
     );
 
@@ -487,6 +492,7 @@ pub fn run_logos_with_args(source: &str, prog_args: &[&str]) -> E2EResult {
     };
     let rust_code = compile_output.rust_code;
     let user_deps = format_user_deps(&compile_output.dependencies);
+    let system_features = toml_feature_list(&compile_output.system_features);
 
     // 2. Create temp project
     let temp_dir = tempfile::tempdir().expect("temp dir");
@@ -508,7 +514,7 @@ edition = "2021"
 
 [dependencies]
 logicaffeine-data = {{ path = "{}/crates/logicaffeine_data" }}
-logicaffeine-system = {{ path = "{}/crates/logicaffeine_system", features = ["full"] }}
+logicaffeine-system = {{ path = "{}/crates/logicaffeine_system", features = [{}] }}
 tokio = {{ version = "1", features = ["rt-multi-thread", "macros"] }}
 serde = {{ version = "1", features = ["derive"] }}
 rayon = "1"
@@ -516,6 +522,7 @@ rayon = "1"
         pkg_name,
         workspace_root.display(),
         workspace_root.display(),
+        system_features,
         user_deps
     );
 
@@ -784,6 +791,7 @@ pub fn compile_and_link_c(source: &str, c_code: &str) -> CLinkResult {
     };
     let rust_code = compile_output.rust_code;
     let user_deps = format_user_deps(&compile_output.dependencies);
+    let system_features = toml_feature_list(&compile_output.system_features);
 
     // 2. Create temp project configured as staticlib
     let temp_dir = tempfile::tempdir().expect("temp dir");
@@ -807,7 +815,7 @@ crate-type = ["staticlib"]
 
 [dependencies]
 logicaffeine-data = {{ path = "{}/crates/logicaffeine_data" }}
-logicaffeine-system = {{ path = "{}/crates/logicaffeine_system", features = ["full"] }}
+logicaffeine-system = {{ path = "{}/crates/logicaffeine_system", features = [{}] }}
 tokio = {{ version = "1", features = ["rt-multi-thread", "macros"] }}
 serde = {{ version = "1", features = ["derive"] }}
 rayon = "1"
@@ -815,6 +823,7 @@ rayon = "1"
         pkg_name,
         workspace_root.display(),
         workspace_root.display(),
+        system_features,
         user_deps
     );
 
@@ -1046,6 +1055,7 @@ pub fn run_logos_seeded(source: &str, seed: u64) -> E2EResult {
     };
     let rust_code = compile_output.rust_code;
     let user_deps = format_user_deps(&compile_output.dependencies);
+    let system_features = toml_feature_list(&compile_output.system_features);
 
     let temp_dir = tempfile::tempdir().expect("temp dir");
     let project_dir = temp_dir.path();
@@ -1063,7 +1073,7 @@ edition = "2021"
 
 [dependencies]
 logicaffeine-data = {{ path = "{}/crates/logicaffeine_data" }}
-logicaffeine-system = {{ path = "{}/crates/logicaffeine_system", features = ["full"] }}
+logicaffeine-system = {{ path = "{}/crates/logicaffeine_system", features = [{}] }}
 tokio = {{ version = "1", features = ["rt-multi-thread", "macros"] }}
 serde = {{ version = "1", features = ["derive"] }}
 rayon = "1"
@@ -1071,6 +1081,7 @@ rayon = "1"
         pkg_name,
         workspace_root.display(),
         workspace_root.display(),
+        system_features,
         user_deps
     );
 
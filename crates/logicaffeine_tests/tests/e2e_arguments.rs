@@ -0,0 +1,117 @@
+//! E2E Tests: Declared Command-Line Arguments
+//!
+//! An `## Arguments` block declares the program's positionals, flags and
+//! options; `Read cli from arguments.` parses the command line against it,
+//! with `--help` and usage errors generated from the declarations.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod common;
+
+#[cfg(not(target_arch = "wasm32"))]
+use common::{compile_program_full, compile_to_rust, run_logos_with_args};
+
+#[cfg(not(target_arch = "wasm32"))]
+const PROGRAM: &str = r#"## Arguments
+input: Text, "The file to read".
+A flag verbose, "Print every step".
+An option maxCount: Option of Int, "Stop after this many lines".
+
+## Main
+Read cli from arguments.
+Show cli's input.
+Show cli's verbose.
+"#;
+
+#[cfg(not(target_arch = "wasm32"))]
+fn interpret_with_args(source: &str, args: &[&str]) -> logicaffeine_compile::ui_bridge::InterpreterResult {
+    let argv: Vec<String> = std::iter::once("app").chain(args.iter().copied()).map(String::from).collect();
+    futures::executor::block_on(logicaffeine_compile::interpret_for_ui_with_args(source, &argv))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_arguments_codegen_builds_the_parser() {
+    let rust = compile_to_rust(PROGRAM).unwrap();
+    assert!(rust.contains("logicaffeine_system::arguments::parse(&["), "{}", rust);
+    assert!(
+        rust.contains("name: \"verbose\", kind: logicaffeine_system::settings::SettingKind::Bool, optional: false, style: logicaffeine_system::arguments::ArgumentStyle::Flag, help: \"Print every step\""),
+        "{}",
+        rust
+    );
+    assert!(rust.contains("input: __args.text(\"input\")"), "{}", rust);
+    assert!(rust.contains("maxCount: __args.optional_int(\"maxCount\")"), "{}", rust);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_arguments_link_the_arguments_feature_only_when_read() {
+    let reads = compile_program_full(PROGRAM).unwrap();
+    assert_eq!(reads.system_features, ["full", "arguments"]);
+
+    let plain = compile_program_full("## Main\nShow 1.\n").unwrap();
+    assert_eq!(plain.system_features, ["full"]);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_arguments_compiled_parses_the_command_line() {
+    let result = run_logos_with_args(PROGRAM, &["--verbose", "notes.txt", "--max-count", "3"]);
+    assert!(result.success, "{}", result.stderr);
+    assert_eq!(result.stdout.trim(), "notes.txt\ntrue");
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_arguments_compiled_help_and_usage_errors() {
+    let help = run_logos_with_args(PROGRAM, &["--help"]);
+    assert!(help.success, "{}", help.stderr);
+    assert!(help.stdout.contains("<INPUT>") && help.stdout.contains("The file to read"), "{}", help.stdout);
+    assert!(help.stdout.contains("--max-count <MAX_COUNT>"), "{}", help.stdout);
+
+    let bad = run_logos_with_args(PROGRAM, &["notes.txt", "--max-count", "lots"]);
+    assert!(!bad.success, "{}", bad.stdout);
+    assert!(bad.stderr.contains("invalid value 'lots'"), "{}", bad.stderr);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_arguments_interpreter_matches_compiled() {
+    let result = interpret_with_args(PROGRAM, &["notes.txt"]);
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.lines, vec!["notes.txt", "false"]);
+
+    let help = interpret_with_args(PROGRAM, &["--help"]);
+    assert!(help.error.is_none(), "{:?}", help.error);
+    assert!(help.lines.iter().any(|l| l.contains("Print every step")), "{:?}", help.lines);
+    assert!(!help.lines.iter().any(|l| l == "false"), "{:?}", help.lines);
+
+    let missing = interpret_with_args(PROGRAM, &[]);
+    assert!(missing.error.unwrap_or_default().contains("<INPUT>"));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_arguments_flags_must_be_bool() {
+    let err = compile_to_rust(
+        r#"## Arguments
+A flag level: Int.
+
+## Main
+Read cli from arguments.
+"#,
+    )
+    .unwrap_err();
+    assert!(format!("{:?}", err).contains("flag `level` can only be a Bool"), "{:?}", err);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_arguments_need_an_arguments_block() {
+    let err = compile_to_rust(
+        r#"## Main
+Read cli from arguments.
+"#,
+    )
+    .unwrap_err();
+    assert!(format!("{:?}", err).contains("no `## Arguments` block"), "{:?}", err);
+}
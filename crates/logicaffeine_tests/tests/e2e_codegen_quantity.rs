@@ -308,3 +308,58 @@ fn e2e_codegen_natural_golden_in_feet() {
     // And `in <unit>` is exact across other dimensions too.
     assert_exact_output("## Main\nShow 1 kilometer in meters.", "1000 m");
 }
+
+// ---- DECLARED DIMENSIONS ARE CONTRACTS: a value of provably the wrong dimension can't cross into a
+//      typed binding, a later `Set`, a `Quantity of <Dim>` argument or a typed `Return`. ----
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn quantity_wrong_dimension_argument_is_a_compile_error() {
+    common::assert_compile_fails(
+        "## To stretch (q: Quantity of Length) -> Quantity of Length:\n\
+\x20   Return q + q.\n\
+## Main\nShow stretch(3 seconds).",
+        "parameter `q` of `stretch` is a quantity of L, not T",
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn quantity_wrong_dimension_return_is_a_compile_error() {
+    // `w * h` is an area; the function promises a length.
+    common::assert_compile_fails(
+        "## To side_of (w: Quantity of Length) and (h: Quantity of Length) -> Quantity of Length:\n\
+\x20   Return w * h.\n\
+## Main\nShow side_of(2 meters, 3 meters).",
+        "the result of `side_of` is a quantity of L, not L^2",
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn quantity_binding_keeps_its_dimension() {
+    common::assert_compile_fails(
+        "## Main\nLet d: Quantity of Length be 3 seconds.\nShow d.",
+        "`d` is a quantity of L, not T",
+    );
+    common::assert_compile_fails(
+        "## Main\nLet mutable d be 5 meters.\nSet d to 2 grams.\nShow d.",
+        "`d` is a quantity of L, not M",
+    );
+    common::assert_compile_fails(
+        "## Main\nLet mutable price be 9.99 USD.\nSet price to 5 EUR.\nShow price.",
+        "`price` is money in USD, not EUR",
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn quantity_coherent_contracts_compile_and_run() {
+    // Same-dimension reassignment and a derived-dimension return both pass.
+    common::assert_output_lines(
+        "## To area_of (w: Quantity of Length) and (h: Quantity of Length) -> Quantity of Area:\n\
+\x20   Return w * h.\n\
+## Main\nLet mutable d be 5 meters.\nSet d to 2 meters.\nShow d.\nShow area_of(d, 3 meters).",
+        &["2 m", "6 L^2"],
+    );
+}
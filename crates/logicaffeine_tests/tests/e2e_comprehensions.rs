@@ -0,0 +1,89 @@
+//! E2E Tests: Comprehensions
+//!
+//! `every n in numbers where n is even`, `every <e> for <x> in <c>`, `the set of every …`
+//! and `every <k> mapped to <v> for <x> in <c>` — compiled AND interpreted, with identical
+//! output.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod common;
+
+#[cfg(not(target_arch = "wasm32"))]
+use common::run_logos;
+
+#[cfg(not(target_arch = "wasm32"))]
+const COMPREHENSION_PROGRAM: &str = r#"## Main
+Let numbers be [1, 2, 3, 4, 5, 6].
+Let evens be every n in numbers where n is even.
+Show evens.
+Let squares be every n * n for n in numbers.
+Show squares.
+Let words be ["ann", "bob", "ann", "cy"].
+Let distinct be the set of every word for word in words.
+Show length of distinct.
+Let lengths be every word mapped to length of word for word in words where length of word > 2.
+Show item "bob" of lengths.
+Show length of lengths.
+Let big: Seq of Int be every n in numbers where n > 4.
+Show big.
+Show length of numbers.
+"#;
+
+#[cfg(not(target_arch = "wasm32"))]
+const COMPREHENSION_OUTPUT: &str = "[2, 4, 6]\n[1, 4, 9, 16, 25, 36]\n3\n3\n2\n[5, 6]\n6";
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_comprehensions_compile() {
+    let result = run_logos(COMPREHENSION_PROGRAM);
+    assert!(result.success, "Generated Rust:\n{}\n\nstderr: {}", result.rust_code, result.stderr);
+    assert_eq!(result.stdout.trim(), COMPREHENSION_OUTPUT);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn interpreter_comprehensions_match_compiled() {
+    common::assert_interpreter_output(COMPREHENSION_PROGRAM, COMPREHENSION_OUTPUT);
+}
+
+/// Tuple binders over a Map, a Set as the iterable, and a filter that is truthy
+/// rather than Bool.
+#[cfg(not(target_arch = "wasm32"))]
+const PATTERN_PROGRAM: &str = r#"## Main
+Let ages be a new Map of Text to Int.
+Set item "ann" of ages to 31.
+Set item "bob" of ages to 17.
+Set item "cy" of ages to 45.
+Let adults be every name for (name, age) in ages where age >= 18.
+Show adults.
+Let doubled be every k mapped to v * 2 for (k, v) in ages.
+Show item "bob" of doubled.
+Let small be the set of every n in [3, 1, 3, 2, 1] where n < 3.
+Show length of small.
+Let xs be every n + 1 for n in small.
+Show xs.
+Let flags be [0, 3, 0, 7].
+Let nonzero be every f for f in flags where f.
+Show nonzero.
+"#;
+
+#[cfg(not(target_arch = "wasm32"))]
+const PATTERN_OUTPUT: &str = "[ann, cy]\n34\n2\n[2, 3]\n[3, 7]";
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_comprehension_patterns_compile() {
+    let result = run_logos(PATTERN_PROGRAM);
+    assert!(result.success, "Generated Rust:\n{}\n\nstderr: {}", result.rust_code, result.stderr);
+    assert!(
+        result.rust_code.contains(".into_iter().filter("),
+        "a comprehension lowers to an iterator chain:\n{}",
+        result.rust_code
+    );
+    assert_eq!(result.stdout.trim(), PATTERN_OUTPUT);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn interpreter_comprehension_patterns_match_compiled() {
+    common::assert_interpreter_output(PATTERN_PROGRAM, PATTERN_OUTPUT);
+}
@@ -0,0 +1,89 @@
+//! E2E Tests: Structured Logging
+//!
+//! `Log <level> "message" with field, field.` emits a leveled event on stderr
+//! carrying the named fields, through both the interpreter and the compiled
+//! program. Stdout stays the program's own output.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod common;
+
+#[cfg(not(target_arch = "wasm32"))]
+use common::{assert_interpreter_output, compile_program_full, compile_to_rust, run_logos};
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_log_codegen_uses_static_fields() {
+    let rust = compile_to_rust(
+        r#"## To greet (id: Int) and (name: Text):
+    Log info "user joined" with id and name.
+
+## Main
+Call greet with 42 and "ada".
+"#,
+    )
+    .unwrap();
+    assert!(
+        rust.contains("logicaffeine_system::log_event!(Info, String::from(\"user joined\"), id = id, name = name);"),
+        "{}",
+        rust
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_log_links_the_log_feature_only_when_used() {
+    let logs = compile_program_full(
+        r#"## Main
+Repeat for i from 1 to 2:
+    Log debug "tick" with i.
+"#,
+    )
+    .unwrap();
+    assert_eq!(logs.system_features, ["full", "log"]);
+
+    let plain = compile_program_full("## Main\nShow 1.\n").unwrap();
+    assert_eq!(plain.system_features, ["full"]);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_log_field_must_be_a_variable() {
+    let err = compile_to_rust(
+        r#"## Main
+Log info "sum" with 1 + 2.
+"#,
+    )
+    .unwrap_err();
+    assert!(format!("{:?}", err).contains("a logged field must be a variable"), "{:?}", err);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_log_compiled_writes_fields_to_stderr() {
+    let result = run_logos(
+        r#"## Main
+Let id be 42.
+Log info "user joined" with id.
+Log debug "cache warmed".
+Show "done".
+"#,
+    );
+    assert!(result.success, "{}", result.stderr);
+    assert_eq!(result.stdout.trim(), "done");
+    assert!(result.stderr.contains("INFO"), "{}", result.stderr);
+    assert!(result.stderr.contains("user joined id=42"), "{}", result.stderr);
+    assert!(!result.stderr.contains("cache warmed"), "{}", result.stderr);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_log_interpreter_keeps_stdout_clean() {
+    assert_interpreter_output(
+        r#"## Main
+Let id be 42.
+Log warn "disk nearly full" with id.
+Show id.
+"#,
+        "42",
+    );
+}
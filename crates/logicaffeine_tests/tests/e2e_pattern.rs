@@ -0,0 +1,87 @@
+//! E2E Tests: The Pattern Type
+//!
+//! `the pattern "…"` literals (validated at compile time), `<text> matches <pattern>`,
+//! `Capture … in … into …` records with named groups as fields, and pattern-driven
+//! `Replace` / `replaced by` — compiled AND interpreted, with identical output.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod common;
+
+#[cfg(not(target_arch = "wasm32"))]
+use common::run_logos;
+
+#[cfg(not(target_arch = "wasm32"))]
+const PATTERN_PROGRAM: &str = r#"## Main
+Let kv be the pattern "(?<key>\w+)=(?<value>\d+)".
+Let line be "size=42; depth=7".
+If line matches kv:
+    Show "has pairs".
+If "no digits" matches "\d":
+    Show "wrong".
+Capture kv in line into m.
+Show m's matched.
+Show m's key.
+Show m's value.
+Show m's text.
+Capture kv in "nothing here" into miss.
+Show miss's matched.
+Replace the pattern "\d+" with "#" in line.
+Show line.
+Show "a=1, b=22" with the pattern "(\w)=(\d+)" replaced by "$2:$1".
+Show kv.
+Show m.
+"#;
+
+#[cfg(not(target_arch = "wasm32"))]
+const PATTERN_OUTPUT: &str = "has pairs\ntrue\nsize\n42\nsize=42\nfalse\nsize=#; depth=#\n1:a, 22:b\n(?<key>\\w+)=(?<value>\\d+)\nPatternMatch { key: size, matched: true, text: size=42, value: 42 }";
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_pattern_sentences_compile() {
+    let result = run_logos(PATTERN_PROGRAM);
+    assert!(result.success, "Generated Rust:\n{}\n\nstderr: {}", result.rust_code, result.stderr);
+    assert_eq!(result.stdout.trim(), PATTERN_OUTPUT);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn interpreter_pattern_sentences_match_compiled() {
+    common::assert_interpreter_output(PATTERN_PROGRAM, PATTERN_OUTPUT);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_pattern_tested_in_a_loop() {
+    let source = r#"## Main
+Let lines be a new Seq of Text.
+Push "id: 12" to lines.
+Push "# comment" to lines.
+Push "id: 7" to lines.
+Let count be 0.
+Repeat for entry in lines:
+    If entry matches the pattern "^id: \d+$":
+        Set count to count + 1.
+Show count.
+"#;
+    let result = run_logos(source);
+    assert!(result.success, "Generated Rust:\n{}\n\nstderr: {}", result.rust_code, result.stderr);
+    assert_eq!(result.stdout.trim(), "2");
+    common::assert_interpreter_output(source, "2");
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn invalid_pattern_literal_is_a_compile_error() {
+    let result = common::run_interpreter(
+        r#"## Main
+Let p be the pattern "(unclosed".
+Show "unreachable".
+"#,
+    );
+    assert!(
+        result.error.contains("invalid pattern \"(unclosed\": unclosed group (at character 1)"),
+        "{}",
+        result.error
+    );
+    assert!(!result.output.contains("unreachable"), "{}", result.output);
+}
@@ -0,0 +1,169 @@
+//! E2E Tests: Running Commands
+//!
+//! `Run the command "…" and capture its output into x.` starts a program
+//! (no shell), waits for it, and binds what was captured. Output that is not
+//! captured passes through, and a non-zero exit is an error unless the exit
+//! code is captured. Only the programs the `## Policy` block allows may run.
+
+#[cfg(all(unix, not(target_arch = "wasm32")))]
+mod common;
+
+#[cfg(all(unix, not(target_arch = "wasm32")))]
+use common::{assert_interpreter_output, compile_program_full, compile_to_rust, run_interpreter, run_logos};
+
+#[cfg(all(unix, not(target_arch = "wasm32")))]
+#[test]
+fn e2e_run_command_codegen_binds_each_capture() {
+    let rust = compile_to_rust(
+        r#"## Policy
+A program can run "git".
+
+## Main
+Run the command "git status" with a timeout of 5000 and capture its output into status and its exit code into code.
+Show code.
+"#,
+    )
+    .unwrap();
+    assert!(rust.contains("let (status, code) = {"), "{}", rust);
+    assert!(
+        rust.contains("logicaffeine_system::process::run_with_policy(&__command, Some(std::time::Duration::from_millis(5000 as u64)), &__policy)"),
+        "{}",
+        rust
+    );
+    assert!(rust.contains("eprint!(\"{}\", __run.stderr);"), "{}", rust);
+    assert!(!rust.contains("require_success"), "{}", rust);
+    assert!(rust.contains("const __ALLOWED_COMMANDS: &[&str] = &[\"git\"];"), "{}", rust);
+}
+
+#[cfg(all(unix, not(target_arch = "wasm32")))]
+#[test]
+fn e2e_run_command_links_process_only_when_used() {
+    let runs = compile_program_full(
+        r#"## Main
+If true:
+    Run the command "git status".
+"#,
+    )
+    .unwrap();
+    assert_eq!(runs.system_features, ["full", "process"]);
+
+    let plain = compile_program_full("## Main\nShow 1.\n").unwrap();
+    assert_eq!(plain.system_features, ["full"]);
+    assert!(!plain.rust_code.contains("__ALLOWED_COMMANDS"), "{}", plain.rust_code);
+}
+
+#[cfg(all(unix, not(target_arch = "wasm32")))]
+#[test]
+fn e2e_run_command_compiled_captures() {
+    let result = run_logos(
+        r#"## Policy
+A program can run "printf" and "sh".
+
+## Main
+Run the command "printf hello" and capture its output into greeting.
+Show greeting.
+Run the command "sh -c 'printf oops >&2; exit 3'" and capture its errors into problem and its exit code into code.
+Show code.
+Show problem.
+"#,
+    );
+    assert!(result.success, "{}", result.stderr);
+    assert_eq!(result.stdout.trim(), "hello\n3\noops");
+}
+
+#[cfg(all(unix, not(target_arch = "wasm32")))]
+#[test]
+fn e2e_run_command_compiled_failure_stops_the_program() {
+    let result = run_logos(
+        r#"## Policy
+A program can run "sh".
+
+## Main
+Run the command "sh -c 'echo partial; exit 2'".
+Show "unreachable".
+"#,
+    );
+    assert!(!result.success, "{}", result.stdout);
+    assert!(result.stdout.contains("partial"), "{}", result.stdout);
+    assert!(!result.stdout.contains("unreachable"), "{}", result.stdout);
+    assert!(result.stderr.contains("exited with code 2"), "{}", result.stderr);
+}
+
+#[cfg(all(unix, not(target_arch = "wasm32")))]
+#[test]
+fn e2e_run_command_interpreter_matches_compiled() {
+    assert_interpreter_output(
+        r#"## Policy
+A program can run "echo", "printf" and "sh".
+
+## Main
+Run the command "echo passed through".
+Run the command "printf hello" and capture its output into greeting.
+Show greeting.
+Run the command "sh -c 'exit 3'" and capture its exit code into code.
+Show code.
+"#,
+        "passed through\nhello\n3",
+    );
+
+    let failed = run_interpreter(
+        r#"## Policy
+A program can run "sh".
+
+## Main
+Run the command "sh -c 'exit 2'".
+"#,
+    );
+    assert!(failed.error.contains("exited with code 2"), "{}", failed.error);
+}
+
+#[cfg(all(unix, not(target_arch = "wasm32")))]
+#[test]
+fn e2e_run_command_timeout_kills_the_program() {
+    let result = run_interpreter(
+        r#"## Policy
+A program can run "sleep".
+
+## Main
+Run the command "sleep 5" with a timeout of 50 and capture its exit code into code.
+Show code.
+"#,
+    );
+    assert!(result.error.contains("was killed after running for 50ms"), "{}", result.error);
+    assert!(result.output.is_empty(), "{}", result.output);
+}
+
+#[cfg(all(unix, not(target_arch = "wasm32")))]
+#[test]
+fn e2e_run_command_denies_programs_the_policy_does_not_allow() {
+    let unlisted = r#"## Policy
+A program can run "printf".
+
+## Main
+Run the command "echo hi".
+"#;
+    let result = run_interpreter(unlisted);
+    assert!(result.error.contains("running `echo` is not allowed"), "{}", result.error);
+    let result = run_logos(unlisted);
+    assert!(!result.success, "{}", result.stdout);
+    assert!(result.stderr.contains("running `echo` is not allowed"), "{}", result.stderr);
+
+    let result = run_interpreter(
+        r#"## Main
+Run the command "echo hi".
+"#,
+    );
+    assert!(result.error.contains("running `echo` is not allowed"), "{}", result.error);
+}
+
+#[cfg(all(unix, not(target_arch = "wasm32")))]
+#[test]
+fn e2e_run_command_rejects_unknown_captures() {
+    let err = compile_to_rust(
+        r#"## Main
+Run the command "ls" and capture its name into name.
+"#,
+    )
+    .unwrap_err();
+    assert!(format!("{:?}", err).contains("a command can capture"), "{:?}", err);
+}
@@ -0,0 +1,101 @@
+//! E2E Tests: Records
+//!
+//! `a copy of user with age set to 31`, nested `Set user's home's city to …`, records as
+//! Set members (structural hashing) and `Show` of a whole record — compiled AND interpreted,
+//! with identical output.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod common;
+
+#[cfg(not(target_arch = "wasm32"))]
+use common::run_logos;
+
+#[cfg(not(target_arch = "wasm32"))]
+const RECORDS_PROGRAM: &str = r#"## An Address has:
+    A city: Text.
+    A zip: Int.
+
+## A User has:
+    A name: Text.
+    An age: Int.
+    A home: Address.
+
+## Main
+Let oslo be a new Address with city "Oslo" and zip 150.
+Let ann be a new User with name "Ann" and age 30 and home oslo.
+Let elder be a copy of ann with age set to 31.
+Show ann's age.
+Show elder's age.
+Let anna be a copy of elder with name set to "Anna" and age set to elder's age + 1.
+Show anna's name.
+Show anna's age.
+Let mutable twin be a copy of ann.
+Set twin's home's city to "Bergen".
+Show twin's home's city.
+Show ann's home's city.
+Show elder.
+Show twin equals ann.
+Let mutable seen be a new Set of Address.
+Add oslo to seen.
+Add a new Address with city "Oslo" and zip 150 to seen.
+Add twin's home to seen.
+Show length of seen.
+"#;
+
+#[cfg(not(target_arch = "wasm32"))]
+const RECORDS_OUTPUT: &str = "30\n31\nAnna\n32\nBergen\nOslo\nUser { age: 31, home: Address { city: Oslo, zip: 150 }, name: Ann }\nfalse\n2";
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_records_compile() {
+    let result = run_logos(RECORDS_PROGRAM);
+    assert!(result.success, "Generated Rust:\n{}\n\nstderr: {}", result.rust_code, result.stderr);
+    assert_eq!(result.stdout.trim(), RECORDS_OUTPUT);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn interpreter_records_match_compiled() {
+    common::assert_interpreter_output(RECORDS_PROGRAM, RECORDS_OUTPUT);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn records_derive_structural_traits_by_field_type() {
+    let rust = logicaffeine_compile::compile::compile_to_rust(
+        r#"## A Tag has:
+    A label: Text.
+    A slot: Int.
+
+## A Reading has:
+    A tag: Tag.
+    A value: Real.
+
+## Main
+Return.
+"#,
+    )
+    .expect("Compiles");
+    // Every field hashes: Eq + Hash, and a field-by-field order.
+    assert!(rust.contains("#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd)]\n    pub struct Tag"), "{}", rust);
+    // A Real orders but never hashes.
+    assert!(rust.contains("#[derive(Default, Debug, Clone, PartialEq, PartialOrd)]\n    pub struct Reading"), "{}", rust);
+    assert!(rust.contains("impl Showable for Reading"), "{}", rust);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn updating_a_missing_field_is_an_error() {
+    let result = common::run_interpreter(
+        r#"## A Point has:
+    An x: Int.
+
+## Main
+Let origin be a new Point with x 0.
+Let twin be a copy of origin with y set to 2.
+Show "unreachable".
+"#,
+    );
+    assert!(result.error.contains("Cannot set y: a Point has no field y"), "{}", result.error);
+    assert!(!result.output.contains("unreachable"), "{}", result.output);
+}
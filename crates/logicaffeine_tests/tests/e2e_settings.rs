@@ -0,0 +1,141 @@
+//! E2E Tests: Typed Settings
+//!
+//! A `## Settings` block declares the program's configuration as typed
+//! fields; `Read config from settings "app.toml".` loads them from a TOML
+//! file and the environment, reporting every problem against its field.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod common;
+
+#[cfg(not(target_arch = "wasm32"))]
+use common::{compile_program_full, compile_to_rust, run_interpreter, run_logos};
+
+#[cfg(not(target_arch = "wasm32"))]
+fn settings_file(contents: &str) -> (tempfile::TempDir, String) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("app.toml");
+    std::fs::write(&path, contents).unwrap();
+    let path = path.to_str().unwrap().to_string();
+    (dir, path)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn program(path: &str) -> String {
+    format!(
+        r#"## Settings
+port: Int.
+host: Text.
+ratio: Real.
+verbose: Option of Bool.
+
+## Main
+Read config from settings "{}".
+Show config's port.
+Show config's host.
+Show config's ratio.
+"#,
+        path
+    )
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_settings_codegen_loads_the_schema() {
+    let rust = compile_to_rust(&program("app.toml")).unwrap();
+    assert!(rust.contains("logicaffeine_system::settings::load(Some("), "{}", rust);
+    assert!(
+        rust.contains("name: \"verbose\", kind: logicaffeine_system::settings::SettingKind::Bool, optional: true"),
+        "{}",
+        rust
+    );
+    assert!(rust.contains("port: __settings.int(\"port\")"), "{}", rust);
+    assert!(rust.contains("verbose: __settings.optional_bool(\"verbose\")"), "{}", rust);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_settings_link_the_settings_feature_only_when_read() {
+    let reads = compile_program_full(&program("app.toml")).unwrap();
+    assert_eq!(reads.system_features, ["full", "settings"]);
+
+    let declared_only = compile_program_full("## Settings\nport: Int.\n\n## Main\nShow 1.\n").unwrap();
+    assert_eq!(declared_only.system_features, ["full"]);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_settings_compiled_reads_the_file() {
+    let (_dir, path) = settings_file("port = 8080\nhost = \"localhost\"\nratio = 2\n");
+    let result = run_logos(&program(&path));
+    assert!(result.success, "{}", result.stderr);
+    assert_eq!(result.stdout.trim(), "8080\nlocalhost\n2");
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_settings_compiled_reports_every_bad_field() {
+    let (_dir, path) = settings_file("port = \"eighty\"\nhost = \"localhost\"\nprot = 80\n");
+    let result = run_logos(&program(&path));
+    assert!(!result.success, "{}", result.stdout);
+    assert!(result.stderr.contains("port: expected an Int, got a string"), "{}", result.stderr);
+    assert!(result.stderr.contains("ratio: missing"), "{}", result.stderr);
+    assert!(result.stderr.contains("prot: not a declared setting"), "{}", result.stderr);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_settings_interpreter_matches_compiled() {
+    let (_dir, path) = settings_file("port = 8080\nhost = \"localhost\"\nratio = 2.5\nverbose = true\n");
+    let result = run_interpreter(&program(&path));
+    assert!(result.success, "{}", result.error);
+    assert_eq!(result.output.trim(), "8080\nlocalhost\n2.5");
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_settings_interpreter_reads_the_environment() {
+    // A field name no other test or process sets.
+    std::env::set_var("E2E_SETTINGS_TOKEN", "from-the-environment");
+    let result = run_interpreter(
+        r#"## Settings
+e2eSettingsToken: Text.
+
+## Main
+Read config from settings.
+Show config's e2eSettingsToken.
+"#,
+    );
+    assert!(result.success, "{}", result.error);
+    assert_eq!(result.output.trim(), "from-the-environment");
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_settings_reject_unsupported_field_types() {
+    let err = compile_to_rust(
+        r#"## Settings
+ports: Seq of Int.
+
+## Main
+Read config from settings "app.toml".
+"#,
+    )
+    .unwrap_err();
+    assert!(
+        format!("{:?}", err).contains("setting `ports` must be an Int, Real, Text, Bool, or an Option of one of those"),
+        "{:?}",
+        err
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_settings_need_a_settings_block() {
+    let err = compile_to_rust(
+        r#"## Main
+Read config from settings "app.toml".
+"#,
+    )
+    .unwrap_err();
+    assert!(format!("{:?}", err).contains("no `## Settings` block"), "{:?}", err);
+}
@@ -0,0 +1,116 @@
+//! E2E Tests: Sipping Files
+//!
+//! `Sip the file "copy.bin" from mirrors, reporting progress to report.`
+//! assembles a file chunk by chunk from copies of it, checking every chunk
+//! against the first source. A chunk a damaged mirror gets wrong is fetched
+//! from another source, a partial copy resumes, and the progress function is
+//! called with the whole percent done after each chunk.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod common;
+
+#[cfg(not(target_arch = "wasm32"))]
+use common::{compile_to_rust, run_interpreter, run_logos};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+/// 2.5 chunks of data, and a mirror whose second chunk is damaged.
+#[cfg(not(target_arch = "wasm32"))]
+fn write_sources(dir: &Path) -> Vec<u8> {
+    let data: Vec<u8> = (0..2_500_000u32).map(|i| (i % 251) as u8).collect();
+    let mut damaged = data.clone();
+    for byte in &mut damaged[1_100_000..1_200_000] {
+        *byte ^= 0xff;
+    }
+    std::fs::write(dir.join("origin.bin"), &data).unwrap();
+    std::fs::write(dir.join("mirror.bin"), &damaged).unwrap();
+    data
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn sip_program(dir: &Path, dest: &str) -> String {
+    format!(
+        r#"## To report (percent: Int):
+    Show "sipped " + percent + "%".
+
+## Main
+Let mirrors be a new Seq of Text.
+Push "{dir}/origin.bin" to mirrors.
+Push "{dir}/mirror.bin" to mirrors.
+Sip the file "{dir}/{dest}" from mirrors, reporting progress to report.
+Show "done".
+"#,
+        dir = dir.display(),
+        dest = dest,
+    )
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_sip_codegen_drives_a_transfer() {
+    let rust = compile_to_rust(
+        r#"## To report (percent: Int):
+    Show percent.
+
+## Main
+Sip the file "copy.bin" from "origin.bin", reporting progress to report.
+"#,
+    )
+    .unwrap();
+    assert!(rust.contains("logicaffeine_system::sip::SipTransfer::open(&__file, &__sources)"), "{}", rust);
+    assert!(rust.contains("report(__step.percent() as i64);"), "{}", rust);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_sip_interpreter_matches_compiled() {
+    let dir = tempfile::tempdir().unwrap();
+    let data = write_sources(dir.path());
+    // The damaged second chunk is fetched again from the origin, after the third.
+    let expected = "sipped 41%\nsipped 58%\nsipped 100%\ndone";
+
+    let interpreted = run_interpreter(&sip_program(dir.path(), "interpreted.bin"));
+    assert!(interpreted.success, "{}", interpreted.error);
+    assert_eq!(interpreted.output.trim(), expected);
+    assert_eq!(std::fs::read(dir.path().join("interpreted.bin")).unwrap(), data);
+
+    let compiled = run_logos(&sip_program(dir.path(), "compiled.bin"));
+    assert!(compiled.success, "{}\n{}", compiled.stderr, compiled.rust_code);
+    assert_eq!(compiled.stdout.trim(), expected);
+    assert_eq!(std::fs::read(dir.path().join("compiled.bin")).unwrap(), data);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_sip_resumes_a_partial_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let data = write_sources(dir.path());
+    // Only the last chunk is missing.
+    std::fs::write(dir.path().join("partial.bin"), &data[..2_200_000]).unwrap();
+
+    let result = run_interpreter(&sip_program(dir.path(), "partial.bin"));
+    assert!(result.success, "{}", result.error);
+    assert_eq!(result.output.trim(), "sipped 100%\ndone");
+    assert_eq!(std::fs::read(dir.path().join("partial.bin")).unwrap(), data);
+
+    let result = run_interpreter(&sip_program(dir.path(), "partial.bin"));
+    assert_eq!(result.output.trim(), "done", "a complete file has nothing left to fetch");
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_sip_without_a_source_is_an_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let source = format!(
+        "## Main\nSip the file \"{dir}/copy.bin\" from \"{dir}/missing.bin\".\nShow \"unreachable\".\n",
+        dir = dir.path().display()
+    );
+
+    let result = run_interpreter(&source);
+    assert!(result.error.contains("Cannot sip"), "{}", result.error);
+    assert!(result.output.is_empty(), "{}", result.output);
+
+    let result = run_logos(&source);
+    assert!(!result.success, "{}", result.stdout);
+    assert!(result.stderr.contains("Cannot sip"), "{}", result.stderr);
+}
@@ -0,0 +1,89 @@
+//! E2E Tests: Sorting
+//!
+//! `Sort people by age descending.`, `the largest 3 items of scores` and `Comparable`
+//! types — stable in both directions, compiled AND interpreted, with identical output.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod common;
+
+#[cfg(not(target_arch = "wasm32"))]
+use common::run_logos;
+
+#[cfg(not(target_arch = "wasm32"))]
+const SORTING_PROGRAM: &str = r#"## A Player is Comparable by score then name and has:
+    A name: Text.
+    A score: Int.
+
+## Main
+Let scores be [42, 7, 19, 88, 19, 3].
+Sort scores.
+Show scores.
+Sort scores descending.
+Show scores.
+Show the largest 3 items of scores.
+Show the smallest 2 items of [5, 1, 4].
+Let words be ["pear", "apple", "fig"].
+Sort words.
+Show words.
+Let cy be a new Player with name "Cy" and score 30.
+Let ann be a new Player with name "Ann" and score 25.
+Let bo be a new Player with name "Bo" and score 30.
+Let di be a new Player with name "Di" and score 25.
+Let people be a new Seq of Player.
+Push cy to people.
+Push ann to people.
+Push bo to people.
+Push di to people.
+Sort people by name descending.
+Sort people by score.
+Repeat for p in people:
+    Show p's name.
+Sort people.
+Repeat for p in people:
+    Show p's name.
+Let best be the largest 2 items of people by score.
+Repeat for p in best:
+    Show p's name.
+"#;
+
+#[cfg(not(target_arch = "wasm32"))]
+const SORTING_OUTPUT: &str = "[3, 7, 19, 19, 42, 88]\n[88, 42, 19, 19, 7, 3]\n[88, 42, 19]\n[1, 4]\n[apple, fig, pear]\nDi\nAnn\nCy\nBo\nAnn\nDi\nBo\nCy\nBo\nCy";
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_sorting_compile() {
+    let result = run_logos(SORTING_PROGRAM);
+    assert!(result.success, "Generated Rust:\n{}\n\nstderr: {}", result.rust_code, result.stderr);
+    assert_eq!(result.stdout.trim(), SORTING_OUTPUT);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn interpreter_sorting_matches_compiled() {
+    common::assert_interpreter_output(SORTING_PROGRAM, SORTING_OUTPUT);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn sorting_a_struct_needs_a_key_or_comparable() {
+    let result = common::run_interpreter(
+        r#"## A Point has:
+    An x: Int.
+
+## Main
+Let first be a new Point with x 2.
+Let second be a new Point with x 1.
+Let points be a new Seq of Point.
+Push first to points.
+Push second to points.
+Sort points.
+Show "unreachable".
+"#,
+    );
+    assert!(
+        result.error.contains("Cannot sort Point values: sort them by a field, or declare `A Point is Comparable by <field>`"),
+        "{}",
+        result.error
+    );
+    assert!(!result.output.contains("unreachable"), "{}", result.output);
+}
@@ -1080,3 +1080,50 @@ Show "pi={pi:.2} cost={price:$}"."#,
         "pi=3.14 cost=$19.99",
     );
 }
+
+// =============================================================================
+// Interpolated Names Are Checked at Compile Time
+// =============================================================================
+
+#[test]
+fn e2e_interpolation_greeting() {
+    assert_exact_output(
+        r#"## Main
+Let name be "Ada".
+Let count be 12.
+Show "Hello, {name}! You have {count} points."."#,
+        "Hello, Ada! You have 12 points.",
+    );
+}
+
+#[test]
+fn e2e_interpolation_greeting_interp() {
+    assert_interpreter_output(
+        r#"## Main
+Let name be "Ada".
+Let count be 12.
+Let greeting be "Hello, {name}! You have {count} points.".
+Show greeting."#,
+        "Hello, Ada! You have 12 points.",
+    );
+}
+
+#[test]
+fn e2e_interpolation_undeclared_name_fails() {
+    assert_compile_fails(
+        r#"## Main
+Let name be "Ada".
+Show "Hello, {nmae}!"."#,
+        "nothing has declared it",
+    );
+}
+
+#[test]
+fn e2e_interpolation_undeclared_name_fails_interp() {
+    assert_interpreter_fails(
+        r#"## Main
+Let count be 3.
+Show "Total: {count + bonus}"."#,
+        "bonus",
+    );
+}
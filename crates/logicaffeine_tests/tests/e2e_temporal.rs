@@ -181,3 +181,62 @@ Let d be 2026-05-20.
         result.error
     );
 }
+
+// === DATE/TIME SENTENCES (both tiers) ===
+
+#[cfg(not(target_arch = "wasm32"))]
+const CALENDAR_PROGRAM: &str = r#"## Main
+Let start be 2026-01-31.
+Let m be 2026-05-20 at 4pm.
+Show start plus 1 month.
+Show start minus 3 days.
+Show the days between start and 2026-03-01.
+Show m.
+Show m formatted as "%A %d %B %Y, %I:%M %p".
+Show start formatted as "%d/%m/%Y".
+Show m formatted as "%H:%M" in "Asia/Tokyo".
+"#;
+
+#[cfg(not(target_arch = "wasm32"))]
+const CALENDAR_OUTPUT: &str = "2026-02-28\n2026-01-28\n29\n2026-05-20 16:00\nWednesday 20 May 2026, 04:00 PM\n31/01/2026\n01:00";
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_date_arithmetic_and_formatting_compiles() {
+    let result = run_logos(CALENDAR_PROGRAM);
+    assert!(result.success, "Generated Rust:\n{}\n\nstderr: {}", result.rust_code, result.stderr);
+    assert_eq!(result.stdout.trim(), CALENDAR_OUTPUT);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn interpreter_date_arithmetic_and_formatting_matches_compiled() {
+    common::assert_interpreter_output(CALENDAR_PROGRAM, CALENDAR_OUTPUT);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_today_reads_the_clock_in_both_tiers() {
+    let source = r#"## Main
+Let due be today plus 14 days.
+If due is after today:
+    Show "ahead".
+Show the days between today and due.
+"#;
+    let result = run_logos(source);
+    assert!(result.success, "Generated Rust:\n{}\n\nstderr: {}", result.rust_code, result.stderr);
+    assert!(result.rust_code.contains("LogosDate::today()"), "{}", result.rust_code);
+    assert_eq!(result.stdout.trim(), "ahead\n14");
+    common::assert_interpreter_output(source, "ahead\n14");
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn interpreter_format_rejects_unknown_directive() {
+    let result = common::run_interpreter(
+        r#"## Main
+Show 2026-05-20 formatted as "%Q".
+"#,
+    );
+    assert!(result.error.contains("unknown format directive `%Q`"), "{}", result.error);
+}
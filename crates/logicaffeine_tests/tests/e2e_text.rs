@@ -0,0 +1,83 @@
+//! E2E Tests: Text Processing Sentences
+//!
+//! `Split … into …`, `Replace … in …`, the `trimmed` / `joined with` / `replaced by`
+//! postfixes, `the uppercase of`, `the position of … in …` and `starts with` / `ends with`
+//! — compiled AND interpreted, with identical output.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod common;
+
+#[cfg(not(target_arch = "wasm32"))]
+use common::run_logos;
+
+#[cfg(not(target_arch = "wasm32"))]
+const TEXT_PROGRAM: &str = r#"## Main
+Let line be "  alice,30,,paris  ".
+Let clean be line trimmed.
+Split clean by commas into fields.
+Show length of fields.
+Show fields joined with " | ".
+Split "to be   or not" by whitespace into words.
+Show length of words.
+Show the uppercase of item 1 of words.
+Show the lowercase of "MiXeD".
+Show the position of "30" in clean.
+Show the position of "zz" in clean.
+Let path be "docs/guide.md".
+Replace "/" with "::" in path.
+Show path.
+Show path with ".md" replaced by ".html".
+If path ends with ".md":
+    Show "markdown".
+If path starts with "#":
+    Show "comment".
+Split "a-b-c" by "-" into parts.
+Show parts joined by "".
+"#;
+
+#[cfg(not(target_arch = "wasm32"))]
+const TEXT_OUTPUT: &str =
+    "4\nalice | 30 |  | paris\n4\nTO\nmixed\n7\n0\ndocs::guide.md\ndocs::guide.html\nmarkdown\nabc";
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_text_sentences_compile() {
+    let result = run_logos(TEXT_PROGRAM);
+    assert!(result.success, "Generated Rust:\n{}\n\nstderr: {}", result.rust_code, result.stderr);
+    assert_eq!(result.stdout.trim(), TEXT_OUTPUT);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn interpreter_text_sentences_match_compiled() {
+    common::assert_interpreter_output(TEXT_PROGRAM, TEXT_OUTPUT);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn e2e_split_by_lines_and_rebind_in_a_loop() {
+    let source = r#"## Main
+Let body be "x=1\ny=22\n".
+Let total be 0.
+Split body by lines into rows.
+Repeat for row in rows:
+    Split row by "=" into pair.
+    Set total to total + parseInt(item 2 of pair).
+Show total.
+"#;
+    let result = run_logos(source);
+    assert!(result.success, "Generated Rust:\n{}\n\nstderr: {}", result.rust_code, result.stderr);
+    assert_eq!(result.stdout.trim(), "23");
+    common::assert_interpreter_output(source, "23");
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn interpreter_text_builtin_rejects_non_text() {
+    let result = common::run_interpreter(
+        r#"## Main
+Show the uppercase of 42.
+"#,
+    );
+    assert!(result.error.contains("text_uppercase() requires text, got Int"), "{}", result.error);
+}
@@ -542,6 +542,7 @@ fn latex_format_uses_latex_operators() {
     let options = CompileOptions {
         format: OutputFormat::LaTeX,
         pragmatic: false,
+        ..Default::default()
     };
     let result = compile_with_options("All men are mortal.", options).unwrap();
     assert_snapshot!("latex_all_men_mortal", result);
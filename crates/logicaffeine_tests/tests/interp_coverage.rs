@@ -0,0 +1,73 @@
+//! Statement coverage on the tree-walker: every statement counts its
+//! executions, and the counts land on the source lines — nested arms and
+//! uncalled functions included, with a zero. A coverage-instrumented compile
+//! probes the same lines.
+
+use logicaffeine_compile::coverage::compile_with_coverage;
+use logicaffeine_compile::interpret_with_coverage;
+
+#[test]
+fn coverage_counts_loops_and_exposes_the_untaken_arm() {
+    let source = "\
+## To sign (n: Int) -> Int:
+    If n is less than 0:
+        Return 0 - 1.
+    Return 1.
+
+## To unused:
+    Show \"never\".
+
+## Main
+Let mutable i be 0.
+While i is less than 3:
+    Show sign(i).
+    Set i to i + 1.
+";
+    let (result, coverage) = interpret_with_coverage(source);
+    assert_eq!(result.error, None);
+    assert_eq!(result.lines, vec!["1", "1", "1"]);
+
+    let count = |line: usize| coverage.lines.get(&line).copied();
+    assert_eq!(count(1), None, "a function header is not a statement");
+    assert_eq!(count(2), Some(3));
+    assert_eq!(count(3), Some(0), "the negative arm never ran");
+    assert_eq!(count(4), Some(3));
+    assert_eq!(count(7), Some(0), "an uncalled function shows up with a zero");
+    assert_eq!(count(10), Some(1));
+    assert_eq!(count(11), Some(1));
+    assert_eq!(count(12), Some(3));
+    assert_eq!(count(13), Some(3));
+    assert_eq!(coverage.uncovered(), vec![3, 7]);
+}
+
+#[test]
+fn coverage_lines_match_a_bare_script() {
+    // An implicit `## Main` is added before running; lines stay the user's.
+    let (result, coverage) = interpret_with_coverage("Let x be 2.\nIf x is greater than 5:\n    Show x.\n");
+    assert_eq!(result.error, None);
+    assert_eq!(coverage.uncovered(), vec![3]);
+    assert_eq!(coverage.lines.get(&1).copied(), Some(1));
+}
+
+#[test]
+fn compiled_probes_cover_the_interpreted_lines() {
+    let source = "\
+## To sign (n: Int) -> Int:
+    If n is less than 0:
+        Return 0 - 1.
+    Otherwise If n is 0:
+        Return 0.
+    Return 1.
+
+## Main
+Repeat for x in [1, 0]:
+    Show sign(x).
+";
+    let (_, interpreted) = interpret_with_coverage(source);
+    let (output, probes) = compile_with_coverage(source).unwrap();
+    assert!(output.rust_code.contains("__LOGOS_COVERAGE.hit("), "{}", output.rust_code);
+
+    let lines = |report: &logicaffeine_compile::coverage::CoverageReport| report.lines.keys().copied().collect::<Vec<_>>();
+    assert_eq!(lines(&probes.report("")), lines(&interpreted));
+    assert_eq!(lines(&interpreted), vec![2, 3, 4, 5, 6, 9, 10]);
+}
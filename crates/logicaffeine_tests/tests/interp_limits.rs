@@ -0,0 +1,86 @@
+//! Execution limits: a run that crosses its step, time, output or memory bound
+//! stops with `LimitExceeded` and keeps the output produced so far — on the
+//! bytecode VM and on the tree-walker alike. Limits are per thread, and each
+//! test runs on its own thread.
+
+use std::time::Duration;
+
+use logicaffeine_compile::{
+    interpret_for_ui_sync, interpret_with_memory_report, set_execution_limits, ExecutionLimits,
+    LimitExceeded,
+};
+
+const COUNT_FOREVER: &str = "\
+## Main
+Let mutable i be 0.
+While i is at least 0:
+    Set i to i + 1.
+    If i is less than 4:
+        Show i.
+";
+
+#[test]
+fn step_limit_stops_an_infinite_loop_on_the_vm() {
+    set_execution_limits(ExecutionLimits::UNLIMITED.with_max_steps(100_000));
+    let result = interpret_for_ui_sync(COUNT_FOREVER);
+    assert_eq!(result.limit_exceeded, Some(LimitExceeded::Steps(100_000)));
+    assert_eq!(result.lines, vec!["1", "2", "3"], "partial output survives");
+    assert!(result.error.unwrap().contains("100000 steps"));
+}
+
+#[test]
+fn step_limit_stops_an_infinite_loop_on_the_tree_walker() {
+    set_execution_limits(ExecutionLimits::UNLIMITED.with_max_steps(10_000));
+    let result = interpret_with_memory_report(COUNT_FOREVER);
+    assert_eq!(result.limit_exceeded, Some(LimitExceeded::Steps(10_000)));
+    assert_eq!(result.lines, vec!["1", "2", "3"]);
+}
+
+#[test]
+fn wall_clock_limit_stops_a_long_run() {
+    let budget = Duration::from_millis(50);
+    set_execution_limits(ExecutionLimits::UNLIMITED.with_wall_clock(budget));
+    let result = interpret_for_ui_sync(COUNT_FOREVER);
+    assert_eq!(result.limit_exceeded, Some(LimitExceeded::WallClock(budget)));
+    assert_eq!(result.lines.len(), 3);
+}
+
+#[test]
+fn output_cap_truncates_to_the_limit() {
+    let source = "\
+## Main
+Let mutable i be 0.
+While i is less than 100:
+    Set i to i + 1.
+    Show i.
+";
+    set_execution_limits(ExecutionLimits::UNLIMITED.with_max_output_lines(5));
+    for result in [interpret_for_ui_sync(source), interpret_with_memory_report(source)] {
+        assert_eq!(result.limit_exceeded, Some(LimitExceeded::OutputLines(5)));
+        assert_eq!(result.lines, vec!["1", "2", "3", "4", "5"]);
+    }
+}
+
+#[test]
+fn memory_cap_stops_a_runaway_push() {
+    let source = "\
+## Main
+Let items be a new Seq of Text.
+While true:
+    Push \"a fairly long line of text\" to items.
+";
+    let cap = 1024 * 1024;
+    set_execution_limits(ExecutionLimits::UNLIMITED.with_max_memory_bytes(cap));
+    for result in [interpret_for_ui_sync(source), interpret_with_memory_report(source)] {
+        assert_eq!(result.limit_exceeded, Some(LimitExceeded::Memory(cap)), "{:?}", result.error);
+    }
+}
+
+#[test]
+fn programs_within_limits_are_unaffected() {
+    set_execution_limits(ExecutionLimits::browser());
+    let result = interpret_for_ui_sync("## Main\nLet x be 6.\nShow x * 7.\n");
+    assert_eq!(result.lines, vec!["42"]);
+    assert_eq!(result.error, None);
+    assert_eq!(result.limit_exceeded, None);
+}
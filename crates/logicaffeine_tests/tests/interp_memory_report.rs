@@ -0,0 +1,101 @@
+//! Memory reporting on the tree-walker: each top-level statement reports the
+//! `Seq`/`Map`/`Text` values it created and their peak sizes, so space
+//! complexity shows up empirically.
+
+use logicaffeine_compile::{interpret_for_ui_sync, interpret_with_memory_report, StatementMemory};
+
+fn row<'a>(rows: &'a [StatementMemory], label: &str) -> &'a StatementMemory {
+    rows.iter()
+        .find(|r| r.label == label)
+        .unwrap_or_else(|| panic!("no row for {label}: {rows:#?}"))
+}
+
+#[test]
+fn memory_report_tracks_seq_growth_per_statement() {
+    let source = "\
+## Main
+Let items be a new Seq of Int.
+Let mutable i be 0.
+While i is less than 50:
+    Push i to items.
+    Set i to i + 1.
+Show length of items.
+";
+    let result = interpret_with_memory_report(source);
+    assert_eq!(result.error, None);
+    assert_eq!(result.lines, vec!["50"]);
+    let report = result.memory.expect("report requested");
+
+    let created = row(&report.statements, "Let items");
+    assert_eq!(created.seqs.allocations, 1);
+    assert_eq!(created.seqs.peak_len, 0);
+
+    // Pushing grows the same Seq: no new allocation, peak 50
+    let grown = row(&report.statements, "While");
+    assert_eq!(grown.seqs.allocations, 0);
+    assert_eq!(grown.seqs.peak_len, 50);
+
+    let (seqs, maps, _) = report.totals();
+    assert_eq!((seqs.allocations, seqs.peak_len), (1, 50));
+    assert!(maps.is_empty());
+    // `Let mutable i` never touches a collection, so it has no row
+    assert!(report.statements.iter().all(|r| r.label != "Let i"));
+}
+
+#[test]
+fn memory_report_counts_rebuilt_text_and_maps() {
+    let source = "\
+## Main
+Let mutable s be \"\".
+Let mutable i be 0.
+While i is less than 10:
+    Set s to s + \"ab\".
+    Set i to i + 1.
+Let m be a new Map of Text to Int.
+Set item \"k\" of m to 1.
+Show s.
+";
+    let result = interpret_with_memory_report(source);
+    assert_eq!(result.error, None);
+    let report = result.memory.expect("report requested");
+
+    // Every concatenation builds a fresh Text
+    let rebuilt = row(&report.statements, "While");
+    assert_eq!(rebuilt.texts.allocations, 10);
+    assert_eq!(rebuilt.texts.peak_len, 20);
+
+    assert_eq!(row(&report.statements, "Let m").maps.allocations, 1);
+    assert_eq!(row(&report.statements, "SetIndex").maps.peak_len, 1);
+    assert!(report.to_table().contains("Let s"));
+}
+
+#[test]
+fn memory_report_attributes_callee_allocations_to_the_call() {
+    let source = "\
+## To build (n: Int) -> Seq of Int:
+    Let out be a new Seq of Int.
+    Repeat for k from 1 to n:
+        Push k to out.
+    Return out.
+
+## Main
+Let xs be build(5).
+Show length of xs.
+";
+    let result = interpret_with_memory_report(source);
+    assert_eq!(result.error, None);
+    let report = result.memory.expect("report requested");
+    let call = row(&report.statements, "Let xs");
+    assert_eq!(call.seqs.allocations, 1);
+    assert_eq!(call.seqs.peak_len, 5);
+    // Later statements still see the live Seq, but allocate nothing
+    let shown = row(&report.statements, "Show");
+    assert_eq!((shown.seqs.allocations, shown.seqs.peak_len), (0, 5));
+}
+
+#[test]
+fn ordinary_runs_carry_no_memory_report() {
+    let result = interpret_for_ui_sync("## Main\nShow 1.\n");
+    assert_eq!(result.lines, vec!["1"]);
+    assert!(result.memory.is_none());
+}
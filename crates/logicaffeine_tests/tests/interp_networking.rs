@@ -1102,3 +1102,37 @@ async fn interp_sync_without_connect_is_a_clean_offline_noop() {
     let result = interpret_for_ui(program).await;
     assert!(result.error.is_none(), "offline Sync is a clean no-op, got: {:?}", result.error);
 }
+
+#[tokio::test]
+async fn interp_join_the_swarm_through_a_relay() {
+    // `Join the swarm using relays at …` dials the relay exactly as `Connect to` would, so a
+    // following `Sync` reaches a peer on it.
+    let relay = serve("127.0.0.1:0").await.expect("relay binds");
+    let url = relay.url();
+    let mut peer = RelayClient::connect(&url).await.expect("peer dials");
+    peer.subscribe("swarm").await.expect("peer subscribe acked");
+
+    let program = format!(
+        "## Main\n\
+         \x20   Let counter be 3.\n\
+         \x20   Join the swarm using local discovery and relays at [\"ws://127.0.0.1:1\", \"{url}\"].\n\
+         \x20   Sync counter on \"swarm\".\n"
+    );
+    let result = interpret_for_ui(&program).await;
+    assert!(result.error.is_none(), "joined through the second relay: {:?}", result.error);
+
+    let (_topic, data) = tokio::time::timeout(Duration::from_secs(5), peer.next_event())
+        .await
+        .expect("event arrives in time")
+        .expect("event present");
+    assert_eq!(String::from_utf8(data).expect("utf8"), r#"{"":3}"#);
+}
+
+#[tokio::test]
+async fn interp_join_the_swarm_with_no_relay_answering_errors() {
+    let program = "## Main\n\
+        \x20   Join the swarm using bootstrap peers at \"/ip4/127.0.0.1/tcp/1\".\n";
+    let result = interpret_for_ui(program).await;
+    let err = result.error.expect("an unreachable swarm must error");
+    assert!(err.contains("Join the swarm"), "error should name the statement, got: {err}");
+}
@@ -62,6 +62,9 @@ fn every_stmt_has_a_jones_class(s: &Stmt<'_>) -> JonesClass {
         | Stmt::RuntimeAssert { .. }
         | Stmt::Give { .. }
         | Stmt::Show { .. }
+        | Stmt::Log { .. }
+        | Stmt::RunCommand { .. }
+        | Stmt::Sip { .. }
         | Stmt::SetField { .. }
         | Stmt::StructDef { .. }
         | Stmt::FunctionDef { .. }
@@ -89,6 +92,7 @@ fn every_stmt_has_a_jones_class(s: &Stmt<'_>) -> JonesClass {
         | Stmt::Check { .. }
         | Stmt::Listen { .. }
         | Stmt::ConnectTo { .. }
+        | Stmt::JoinSwarm { .. }
         | Stmt::LetPeerAgent { .. }
         | Stmt::Sleep { .. }
         | Stmt::Sync { .. }
@@ -46,3 +46,29 @@ fn ellipsis_with_object() {
         "Eat predicate should be reconstructed. Output: {}", output);
     assert!(output.contains("Apple") || output.contains("A("), "Apple should persist in context");
 }
+
+#[test]
+fn ellipsis_inside_a_conjunction() {
+    let output = compile("John loves Mary and Bill does too.").unwrap();
+    assert!(output.contains("Love(e) ∧ Agent(e, Bill) ∧ Theme(e, Mary)"), "Output: {}", output);
+}
+
+#[test]
+fn negative_ellipsis_with_either() {
+    let output = compile("John didn't run and Mary didn't either.").unwrap();
+    assert_eq!(output.matches("¬").count(), 2, "Output: {}", output);
+    assert!(output.contains("Run(e) ∧ Agent(e, Mary) ∧ Past(e)"), "Output: {}", output);
+}
+
+#[test]
+fn inverted_so_ellipsis() {
+    let output = compile("John ran, and so did Bill.").unwrap();
+    assert!(output.contains("Run(e) ∧ Agent(e, Bill) ∧ Past(e)"), "Output: {}", output);
+}
+
+#[test]
+fn ellipsis_takes_the_auxiliary_tense() {
+    let output = compile("John runs and Mary did too.").unwrap();
+    assert!(output.contains("HAB(∃e(Run(e) ∧ Agent(e, John)))"), "Output: {}", output);
+    assert!(output.contains("Run(e) ∧ Agent(e, Mary) ∧ Past(e)"), "Output: {}", output);
+}
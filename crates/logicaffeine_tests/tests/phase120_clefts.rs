@@ -32,6 +32,28 @@ fn it_cleft_distinct_from_plain_predication() {
     assert!(cleft != plain, "cleft ≠ plain predication: {cleft} vs {plain}");
 }
 
+#[test]
+fn object_cleft_focuses_the_object() {
+    let out = compile("It was the vase that John broke.").unwrap();
+    assert!(out.contains("Agent(e, John) ∧ Theme(e, x)) → x = Vase"), "only the vase: {out}");
+}
+
+#[test]
+fn pseudo_cleft_focuses_the_copular_complement() {
+    let out = compile("What Mary wants is coffee.").unwrap();
+    assert!(out.contains("Cleft(C, ∃e(Want(e) ∧ Agent(e, Mary) ∧ Theme(e, Coffee)))"), "{out}");
+    assert!(out.contains("Theme(e, x)) → x = Coffee"), "nothing else is wanted: {out}");
+
+    let out = compile("Who broke the vase was John.").unwrap();
+    assert!(out.contains("Agent(e, x) ∧ Theme(e, Vase)) → x = John"), "{out}");
+}
+
+#[test]
+fn wh_questions_are_not_pseudo_clefts() {
+    let out = compile("What does Mary want?").unwrap();
+    assert!(!out.contains("Cleft"), "{out}");
+}
+
 // ============================================================================
 // Entailment spec (§8.3): the cleft's exhaustivity is Z3-checkable — anyone
 // else who broke the vase IS John; and the prejacent is asserted.
@@ -27,3 +27,31 @@ fn restrictive_rc_still_restricts() {
     assert!(out.contains("Man"), "the restricted noun: {out}");
     assert!(out.contains('∃') || out.contains('∀'), "a bound restriction variable: {out}");
 }
+
+#[test]
+fn nominal_appositive_is_a_side_assertion() {
+    assert_eq!(
+        compile("Socrates, a philosopher, is mortal.").unwrap(),
+        "1) Mortal(Socrates)\n2) Philosopher(Socrates)"
+    );
+    assert!(compile("John, the baker, runs.").unwrap().contains("Baker(John)"));
+}
+
+#[test]
+fn appositive_on_a_definite_binds_the_head() {
+    let out = compile("The man, which runs, is fit.").unwrap();
+    assert!(out.contains("Fit(x) ∧ ∃e(Run(e) ∧ Agent(e, x))"), "{out}");
+}
+
+#[test]
+fn a_listed_noun_phrase_is_not_an_appositive() {
+    let out = compile("Socrates, a philosopher, and Plato are different men.").unwrap();
+    assert!(out.contains("Plato"), "{out}");
+    assert!(!out.contains("Philosopher(Socrates)"), "{out}");
+}
+
+#[test]
+fn pragmatic_mode_marks_appositives_as_projective() {
+    let out = logicaffeine_language::compile_pragmatic("John, who runs, is not fit.").unwrap();
+    assert_eq!(out, "¬Fit(John) [Presup: ∃e(Run(e) ∧ Agent(e, John))]");
+}
@@ -151,6 +151,69 @@ fn test_stopped_with_gerund_still_presupposition() {
     assert!(output.contains("Presup"), "Should still be presupposition: got {}", output);
 }
 
+// ===== PHRASAL AND LIGHT VERBS =====
+
+#[test]
+fn test_phrasal_verb_particle_order_is_irrelevant() {
+    let joined = compile("John took off his coat.").unwrap();
+    let split = compile("John took his coat off.").unwrap();
+    assert_eq!(joined, split);
+    assert!(joined.contains("Remove(e)"), "took off + object → Remove: got {}", joined);
+    assert_eq!(
+        compile("Mary switched the lamp on.").unwrap(),
+        "∃e(Activate(e) ∧ Agent(e, Mary) ∧ Theme(e, Lamp) ∧ Past(e))"
+    );
+}
+
+#[test]
+fn test_intransitive_phrasal_verb_has_its_own_sense() {
+    let output = compile("The plane took off.").unwrap();
+    assert!(output.contains("Depart(e)"), "took off (no object) → Depart: got {}", output);
+    assert!(!output.contains("Remove"), "Should not use the transitive sense: got {}", output);
+    assert!(compile("John showed up.").unwrap().contains("Arrive(e)"));
+}
+
+#[test]
+fn test_light_verb_is_one_event() {
+    assert_eq!(
+        compile("John made a decision.").unwrap(),
+        "∃e(Decide(e) ∧ Agent(e, John) ∧ Past(e))"
+    );
+    let output = compile("Mary took a walk.").unwrap();
+    assert!(output.contains("Walk(e)"), "took a walk → Walk: got {}", output);
+    assert!(!output.contains("Take"), "No literal Take event: got {}", output);
+}
+
+// ===== IDIOMS: IDIOMATIC AND LITERAL READINGS =====
+
+#[test]
+fn test_idiom_forest_has_both_readings() {
+    use logicaffeine_language::{compile_forest_ranked, IdiomReading};
+
+    let readings = compile_forest_ranked("John kicked the bucket.");
+    assert_eq!(readings.len(), 2, "Idiomatic and literal readings: {:?}", readings);
+    assert_eq!(readings[0].idiom, Some(IdiomReading::Idiomatic), "Idiomatic sense ranks first");
+    assert!(readings[0].fol.contains("Die(e)"), "got {}", readings[0].fol);
+    assert_eq!(readings[1].idiom, Some(IdiomReading::Literal));
+    assert_eq!(readings[1].fol, "∃e(Kick(e) ∧ Agent(e, John) ∧ Theme(e, Bucket) ∧ Past(e))");
+}
+
+#[test]
+fn test_compile_commits_to_the_idiomatic_reading() {
+    let output = compile("Mary spilled the beans.").unwrap();
+    assert_eq!(output, "∃e(Reveal(e) ∧ Agent(e, Mary) ∧ Past(e))");
+    let forest = logicaffeine_language::compile_forest("Mary spilled the beans.");
+    assert!(forest.iter().any(|r| r.contains("Spill(e)")), "literal reading in forest: {:?}", forest);
+}
+
+#[test]
+fn test_non_idioms_carry_no_reading_label() {
+    let readings = logicaffeine_language::compile_forest_ranked("John kicked the ball.");
+    assert!(readings.iter().all(|r| r.idiom.is_none()), "{:?}", readings);
+    let readings = logicaffeine_language::compile_forest_ranked("The fire engine arrived.");
+    assert!(readings.iter().all(|r| r.idiom.is_none()), "compounds are not idioms: {:?}", readings);
+}
+
 // ===== NON-MWE SENTENCES (REGRESSION) =====
 
 #[test]
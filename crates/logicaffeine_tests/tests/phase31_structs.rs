@@ -98,5 +98,5 @@ A Point has:
 Return.
 "#;
     let rust = compile_to_rust(source).expect("Compiles");
-    assert!(rust.contains("#[derive(Default, Debug, Clone, PartialEq)]"), "Should have derive macros: {}", rust);
+    assert!(rust.contains("#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd)]"), "Should have derive macros: {}", rust);
 }
@@ -188,3 +188,81 @@ Let p be a new Point from Geometry.
     // The codegen should produce struct usage
     assert!(output.rust_code.contains("fn main()"), "Should have main function");
 }
+
+#[tokio::test]
+async fn test_interpret_project_from_virtual_files() {
+    use logicaffeine_compile::interpret_streaming_project;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    // The Studio hands over its browser files as an in-memory map; no disk involved
+    let mut files = HashMap::new();
+    files.insert(PathBuf::from("/project/geo.md"), r#"# Geometry
+
+## Definition
+A Point has:
+    an x, which is Int.
+    a y, which is Int.
+"#.to_string());
+    files.insert(PathBuf::from("/project/main.md"), r#"# Main
+
+Uses [Geometry](file:./geo.md) for points.
+
+## Main
+Let p be a new Point from Geometry with x 3 and y 4.
+Show p's x.
+"#.to_string());
+
+    let callback = Rc::new(RefCell::new(|_: String| {}));
+    let result = interpret_streaming_project("/project/main.md", files, callback, None).await;
+    assert!(result.error.is_none(), "project should run: {:?}", result.error);
+    assert_eq!(result.lines, vec!["3".to_string()]);
+}
+
+#[test]
+fn test_import_cycle_is_reported_not_recursed() {
+    let temp_dir = tempdir().unwrap();
+    fs::write(temp_dir.path().join("a.md"), "# A\n\nUses [B](file:./b.md).\n").unwrap();
+    fs::write(temp_dir.path().join("b.md"), "# B\n\nUses [A](file:./a.md).\n").unwrap();
+
+    let main_source = "# Main\n\nUses [A](file:./a.md).\n\n## Main\nShow 1.\n";
+    let mut interner = Interner::new();
+    let mut loader = Loader::new(temp_dir.path().to_path_buf());
+    let err = discover_with_imports(
+        &temp_dir.path().join("main.md"),
+        main_source,
+        &mut loader,
+        &mut interner
+    ).expect_err("a cycle must fail");
+
+    assert!(err.starts_with("import cycle: "), "{err}");
+    assert!(err.contains("a.md → ") && err.contains("b.md → "), "should name the cycle's modules: {err}");
+    assert!(err.contains("imports [A](file:./a.md)"), "should name the closing link: {err}");
+}
+
+#[test]
+fn test_public_types_limit_what_a_module_exports() {
+    let temp_dir = tempdir().unwrap();
+    fs::write(temp_dir.path().join("geo.md"), r#"# Geometry
+
+## Definition
+A public Point has:
+    an x, which is Int.
+A Grid has:
+    a size, which is Int.
+"#).unwrap();
+
+    let main_source = "# Main\n\nUses [Geometry](file:./geo.md).\n\n## Main\nShow 1.\n";
+    let mut interner = Interner::new();
+    let mut loader = Loader::new(temp_dir.path().to_path_buf());
+    let registry = discover_with_imports(
+        &temp_dir.path().join("main.md"),
+        main_source,
+        &mut loader,
+        &mut interner
+    ).expect("Should discover imports");
+
+    assert!(registry.is_type(interner.intern("Geometry::Point")), "public Point is exported");
+    assert!(!registry.is_type(interner.intern("Geometry::Grid")), "Grid is private to Geometry");
+}
@@ -134,3 +134,21 @@ fn four_sentence_chain() {
         result
     );
 }
+
+// ═══════════════════════════════════════════════════════════════════
+// TENSE-AWARE TIMELINE
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn past_perfect_is_a_flashback() {
+    // The arriving happened before the leaving, and the story resumes after it.
+    let result = compile_discourse(&["John left.", "Mary had arrived.", "She sat."]).unwrap();
+    assert!(result.ends_with("Precedes(e2, e1) ∧ Precedes(e1, e3)"), "got '{}'", result);
+}
+
+#[test]
+fn progressive_is_background() {
+    let result = compile_discourse(&["John entered.", "Mary was reading.", "He sat."]).unwrap();
+    assert!(result.ends_with("Includes(e2, e1) ∧ Precedes(e1, e3)"), "got '{}'", result);
+    assert!(!result.contains("Precedes(e2, e3)"), "background does not advance the story: got '{}'", result);
+}
@@ -102,3 +102,17 @@ fn intransitive_gapping_preserved() {
         output
     );
 }
+
+#[test]
+fn gapping_without_a_comma() {
+    let output = compile("John ate rice and Mary beans.").unwrap();
+    assert!(output.contains("Eat(e) ∧ Agent(e, Mary) ∧ Theme(e, Beans)"), "Got: {}", output);
+}
+
+#[test]
+fn a_new_subject_ends_the_object_list() {
+    let output = compile("John ate rice and Mary ate beans.").unwrap();
+    assert!(output.contains("Theme(e, Rice)") && output.contains("Agent(e, Mary)"), "Got: {}", output);
+    let output = compile("John didn't run and Mary ran.").unwrap();
+    assert!(output.contains("Run(e) ∧ Agent(e, Mary)"), "Got: {}", output);
+}
@@ -4,6 +4,7 @@
 //! - Listen on [address] - bind to network address
 //! - Connect to [address] - dial a peer
 //! - Let x be a PeerAgent at [address] - create remote handle
+//! - Join the swarm [using ...] - find peers by mDNS, bootstrap list, or relay
 //! - Result-returning Send for network operations
 //!
 //! Architecture decisions (AAA Council):
@@ -80,6 +81,56 @@ Connect to peer_addr."#;
     );
 }
 
+// =============================================================================
+// Join the Swarm Tests
+// =============================================================================
+
+#[test]
+fn test_join_swarm_uses_project_configuration() {
+    let source = r#"## Main
+Join the swarm."#;
+    let rust = compile_to_rust(source).expect("Should compile");
+    assert!(
+        rust.contains("network::join_swarm(&logicaffeine_system::discovery::Discovery::configured())"),
+        "Bare join should use the configured discovery. Got:\n{}",
+        rust
+    );
+}
+
+#[test]
+fn test_join_swarm_using_local_discovery() {
+    let source = r#"## Main
+Join the swarm using local discovery."#;
+    let rust = compile_to_rust(source).expect("Should compile");
+    assert!(
+        rust.contains("Discovery::none().with_local(true)"),
+        "Should enable mDNS only. Got:\n{}",
+        rust
+    );
+}
+
+#[test]
+fn test_join_swarm_with_listed_peers_and_relays() {
+    let source = r#"## Main
+Let seeds be ["/ip4/10.0.0.2/tcp/8000"].
+Join the swarm using bootstrap peers at seeds and relays and local discovery."#;
+    let rust = compile_to_rust(source).expect("Should compile");
+    assert!(rust.contains(".with_bootstrap_peers((seeds).clone())"), "Should dial the listed peers. Got:\n{}", rust);
+    assert!(
+        rust.contains(".with_relays(logicaffeine_system::discovery::Discovery::configured().relays())"),
+        "Relays without a list come from the project. Got:\n{}",
+        rust
+    );
+    assert!(rust.contains(".with_local(true)"), "Should enable mDNS. Got:\n{}", rust);
+}
+
+#[test]
+fn test_join_swarm_unknown_method_fails() {
+    let source = r#"## Main
+Join the swarm using carrier pigeons."#;
+    assert!(compile_to_rust(source).is_err(), "Should reject an unknown discovery method");
+}
+
 // =============================================================================
 // PeerAgent at Address Tests
 // =============================================================================
@@ -77,3 +77,38 @@ fn simple_future_constraints() {
         "Future: S < R: got '{}'", output
     );
 }
+
+#[test]
+fn future_perfect_locates_the_reference_time_not_the_event() {
+    // The finishing may already be over; only the reference point is future.
+    assert_eq!(
+        compile("John will have finished.").unwrap(),
+        "F(Perf(∃e(Finish(e) ∧ Agent(e, John)))) ∧ Precedes(S, r1) ∧ Precedes(e1, r1)"
+    );
+}
+
+#[test]
+fn progressive_event_includes_the_reference_time() {
+    assert_eq!(
+        compile("John was running.").unwrap(),
+        "P(Prog(Run(John))) ∧ Precedes(r1, S) ∧ Includes(e1, r1)"
+    );
+    assert_eq!(
+        compile("John will be running.").unwrap(),
+        "F(Prog(∃e(Run(e) ∧ Agent(e, John)))) ∧ Precedes(S, r1) ∧ Includes(e1, r1)"
+    );
+    assert!(compile("John is running.").unwrap().ends_with("∧ Includes(e1, S)"));
+}
+
+#[test]
+fn perfect_progressive_is_anterior() {
+    let output = compile("John had been running.").unwrap();
+    assert!(output.starts_with("P(Perf(Prog("), "got '{}'", output);
+    assert!(output.ends_with("Precedes(r1, S) ∧ Precedes(e1, r1)"), "got '{}'", output);
+}
+
+#[test]
+fn relative_clause_perfect_is_constrained_once() {
+    let output = compile("The skydiver who has done 49 jumps is happy.").unwrap();
+    assert_eq!(output.matches("Precedes(e1, S)").count(), 1, "got '{}'", output);
+}
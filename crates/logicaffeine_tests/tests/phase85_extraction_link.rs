@@ -0,0 +1,105 @@
+//! Linking verified kernel functions into project builds.
+//!
+//! A LOGOS project may define and prove functions in the kernel — in its entry
+//! file or in a module it imports — and call them from ordinary imperative
+//! code. The project compile (`largo build`) extracts that math into
+//! `mod proven`, and through an [`ExtractionCache`] re-extracts it only when
+//! the math changes.
+
+use logicaffeine_compile::compile::{compile_project, compile_project_cached};
+use logicaffeine_compile::extraction::ExtractionCache;
+use logicaffeine_compile::loader::CompileTarget;
+use std::fs;
+
+const DOUBLE: &str = "Definition double : Int -> Int := fun n : Int => add n n.";
+
+const PROOFS: &str = "\
+# Arithmetic
+
+Definition double : Int -> Int := fun n : Int => add n n.
+
+## Theorem: DoubleTwo
+    Statement: (Eq (double 2) 4).
+    Proof: auto.
+";
+
+const MAIN: &str = "\
+# Main
+
+[Proofs](file:./proofs.md)
+
+## Main
+Show double(21).
+";
+
+/// A project with `main.md` importing `proofs.md`; returns the tempdir and entry.
+fn project() -> (tempfile::TempDir, std::path::PathBuf) {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("proofs.md"), PROOFS).unwrap();
+    let entry = dir.path().join("main.md");
+    fs::write(&entry, MAIN).unwrap();
+    (dir, entry)
+}
+
+// --- Project builds link verified functions -----------------------------------
+
+#[test]
+fn imported_proof_module_is_linked() {
+    let (_dir, entry) = project();
+    let rust = compile_project(&entry).expect("project compiles").rust_code;
+    assert!(rust.contains("pub mod proven {"), "proven module bundled:\n{rust}");
+    assert!(rust.contains("pub fn double"), "imported double extracted:\n{rust}");
+    assert!(rust.contains("double("), "imperative call emitted:\n{rust}");
+}
+
+#[test]
+fn mixed_entry_file_is_linked() {
+    let dir = tempfile::tempdir().unwrap();
+    let entry = dir.path().join("main.lg");
+    fs::write(&entry, format!("{DOUBLE}\n\n## Main\nShow double(21).\n")).unwrap();
+    let rust = compile_project(&entry).expect("mixed entry compiles").rust_code;
+    assert!(rust.contains("pub fn double"), "entry math extracted:\n{rust}");
+}
+
+#[test]
+fn pure_imperative_project_has_no_proven_module() {
+    let dir = tempfile::tempdir().unwrap();
+    let entry = dir.path().join("main.lg");
+    fs::write(&entry, "## Main\nShow 1.\n").unwrap();
+    let rust = compile_project(&entry).expect("compiles").rust_code;
+    assert!(!rust.contains("mod proven"), "nothing to link:\n{rust}");
+}
+
+// --- The extraction cache ------------------------------------------------------
+
+#[test]
+fn cached_build_reuses_the_artifact() {
+    let (dir, entry) = project();
+    let cache = ExtractionCache::new(dir.path().join("target/extracted"));
+    let first = compile_project_cached(&entry, &CompileTarget::host(), &cache).unwrap().rust_code;
+    assert!(first.contains("pub fn double"), "{first}");
+
+    let artifacts: Vec<_> = fs::read_dir(cache.dir()).unwrap().flatten().collect();
+    assert_eq!(artifacts.len(), 1, "one artifact per distinct math source");
+
+    // A planted artifact is linked as-is: the unchanged proof is not replayed.
+    fs::write(artifacts[0].path(), "pub fn double(x: i64) -> i64 { x * 2 }\n").unwrap();
+    let second = compile_project_cached(&entry, &CompileTarget::host(), &cache).unwrap().rust_code;
+    assert!(second.contains("{ x * 2 }"), "cached artifact linked:\n{second}");
+}
+
+#[test]
+fn edited_proof_is_extracted_afresh() {
+    let cache = ExtractionCache::new(tempfile::tempdir().unwrap().path().join("extracted"));
+    let edited = DOUBLE.replace("add n n", "mul n 2");
+    assert_ne!(cache.artifact_path(DOUBLE), cache.artifact_path(&edited));
+}
+
+#[test]
+fn nothing_to_extract_is_cached_as_empty() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = ExtractionCache::new(dir.path());
+    let vacuous = "Definition triv : True := I.";
+    assert!(cache.proven_module(vacuous).is_none());
+    assert_eq!(fs::read_to_string(cache.artifact_path(vacuous)).unwrap(), "");
+}
@@ -0,0 +1,101 @@
+//! Phase: Argument Analysis
+//!
+//! `analyze_argument` compiles premises and conclusion as one discourse,
+//! decides validity (kernel-certified proof or re-checked countermodel),
+//! reports the premises a valid proof needs, and names the fallacy an
+//! invalid argument commits.
+
+use logicaffeine_compile::{analyze_argument, ArgumentReport, ArgumentVerdict, Fallacy};
+
+fn fallacies(report: &ArgumentReport) -> Vec<Fallacy> {
+    report.fallacies.iter().map(|m| m.fallacy).collect()
+}
+
+#[test]
+fn a_valid_syllogism_is_certified() {
+    let report = analyze_argument(&["Socrates is a man.", "Every man is mortal."], "Socrates is mortal.");
+    assert_eq!(report.verdict, ArgumentVerdict::Valid);
+    assert!(report.certified);
+    assert!(report.fallacies.is_empty());
+}
+
+#[test]
+fn idle_premises_are_not_needed() {
+    let report = analyze_argument(
+        &["Every man is mortal.", "John is tall.", "Socrates is a man."],
+        "Socrates is mortal.",
+    );
+    assert_eq!(report.verdict, ArgumentVerdict::Valid);
+    assert_eq!(report.needed_premises, vec![0, 2]);
+}
+
+#[test]
+fn premises_share_one_discourse() {
+    let report = analyze_argument(&["Socrates is a man.", "Every man is mortal."], "He is mortal.");
+    assert_eq!(report.verdict, ArgumentVerdict::Valid, "{:?}", report.error);
+}
+
+#[test]
+fn modus_tollens_over_events_is_valid() {
+    let report = analyze_argument(&["If John runs, Mary smiles.", "Mary does not smile."], "John does not run.");
+    assert_eq!(report.verdict, ArgumentVerdict::Valid);
+}
+
+#[test]
+fn affirming_the_consequent_is_named() {
+    let report = analyze_argument(&["If it rains, the ground is wet.", "The ground is wet."], "It rains.");
+    assert_eq!(report.verdict, ArgumentVerdict::Invalid);
+    assert!(report.countermodel.is_some());
+    assert_eq!(fallacies(&report), vec![Fallacy::AffirmingTheConsequent]);
+}
+
+#[test]
+fn affirming_the_consequent_of_a_universal_is_named() {
+    let report = analyze_argument(&["Every man is mortal.", "Socrates is mortal."], "Socrates is a man.");
+    assert_eq!(report.verdict, ArgumentVerdict::Invalid);
+    assert_eq!(fallacies(&report), vec![Fallacy::AffirmingTheConsequent]);
+}
+
+#[test]
+fn denying_the_antecedent_is_named() {
+    let report = analyze_argument(&["If John runs, Mary smiles.", "John does not run."], "Mary does not smile.");
+    assert_eq!(report.verdict, ArgumentVerdict::Invalid);
+    assert_eq!(fallacies(&report), vec![Fallacy::DenyingTheAntecedent]);
+}
+
+#[test]
+fn undistributed_middle_is_named() {
+    let report = analyze_argument(&["Every cat is an animal.", "Every dog is an animal."], "Every cat is a dog.");
+    assert_eq!(report.verdict, ArgumentVerdict::Invalid);
+    assert_eq!(fallacies(&report), vec![Fallacy::UndistributedMiddle]);
+}
+
+#[test]
+fn illicit_conversion_is_named() {
+    let report = analyze_argument(&["Every man is mortal."], "Every mortal is a man.");
+    assert_eq!(report.verdict, ArgumentVerdict::Invalid);
+    assert_eq!(fallacies(&report), vec![Fallacy::IllicitConversion]);
+}
+
+#[test]
+fn unparsable_input_reports_an_error() {
+    let report = analyze_argument(&["Every man is mortal."], "He is mortal.");
+    assert!(report.error.is_some());
+    assert_eq!(report.verdict, ArgumentVerdict::Unknown);
+}
+
+#[test]
+fn quantifier_shift_is_named() {
+    let report = analyze_argument(&["Every boy loves a girl."], "Some girl is loved by every boy.");
+    assert_eq!(report.verdict, ArgumentVerdict::Invalid);
+    assert_eq!(fallacies(&report), vec![Fallacy::QuantifierShift]);
+    assert_eq!(report.fallacies[0].premises, vec![0]);
+}
+
+#[test]
+fn fallacies_come_with_an_explanation() {
+    let report = analyze_argument(&["Every man is mortal.", "Socrates is mortal."], "Socrates is a man.");
+    let found = &report.fallacies[0];
+    assert_eq!(found.premises, vec![0, 1]);
+    assert!(found.explanation.contains("Socrates"), "{}", found.explanation);
+}
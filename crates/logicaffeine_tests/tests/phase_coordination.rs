@@ -0,0 +1,108 @@
+//! Phase: Coordination
+//!
+//! Verb phrases coordinated under one subject ("John sang and danced"),
+//! quantified subjects coordinated under one verb phrase ("Every boy and
+//! every girl smiled"), and the collective / distributive split for
+//! coordinated names ("John and Mary met" vs "John and Mary slept").
+
+use logicaffeine_language::{compile, compile_forest};
+
+// =============================================================================
+// Verb-phrase coordination
+// =============================================================================
+
+#[test]
+fn coordinated_verbs_share_the_subject() {
+    let fol = compile("John sang and danced.").unwrap();
+    assert!(fol.contains("Sing(e) ∧ Agent(e, John)"), "{fol}");
+    assert!(fol.contains("Dance(e) ∧ Agent(e, John)"), "{fol}");
+}
+
+#[test]
+fn a_verb_after_and_is_not_a_coordinated_object() {
+    let fol = compile("John loves Mary and hates Bill.").unwrap();
+    assert!(fol.contains("Love(e) ∧ Agent(e, John) ∧ Theme(e, Mary)"), "{fol}");
+    assert!(fol.contains("Hate(e) ∧ Agent(e, John) ∧ Theme(e, Bill)"), "{fol}");
+}
+
+#[test]
+fn verb_lists() {
+    let fol = compile("John sang, danced and laughed.").unwrap();
+    for verb in ["Sing", "Dance", "Laugh"] {
+        assert!(fol.contains(&format!("{verb}(e) ∧ Agent(e, John)")), "{fol}");
+    }
+}
+
+#[test]
+fn coordinated_verbs_stay_in_the_quantifier_scope() {
+    let fol = compile("Every boy loves Mary and hates Bill.").unwrap();
+    assert_eq!(
+        fol,
+        "∀x((Boy(x) → (∃e(Love(e) ∧ Agent(e, x) ∧ Theme(e, Mary)) ∧ ∃e(Hate(e) ∧ Agent(e, x) ∧ Theme(e, Bill)))))"
+    );
+}
+
+#[test]
+fn a_new_subject_still_starts_a_new_clause() {
+    let fol = compile("John sang and Mary danced and laughed.").unwrap();
+    assert!(fol.contains("Dance(e) ∧ Agent(e, Mary)"), "{fol}");
+    assert!(fol.contains("Laugh(e) ∧ Agent(e, Mary)"), "{fol}");
+}
+
+// =============================================================================
+// Quantifier coordination
+// =============================================================================
+
+#[test]
+fn coordinated_quantifiers_each_take_the_verb_phrase() {
+    let fol = compile("Every boy and every girl smiled.").unwrap();
+    assert!(fol.contains("∀x((Boy(x) → ∃e(Smile(e) ∧ Agent(e, x))))"), "{fol}");
+    assert!(fol.contains("∀y((Girl(y) → ∃e(Smile(e) ∧ Agent(e, y))))"), "{fol}");
+}
+
+#[test]
+fn a_name_coordinates_with_a_quantifier() {
+    let fol = compile("John and no girl smiled.").unwrap();
+    assert!(fol.contains("Agent(e, John)"), "{fol}");
+    assert!(fol.contains("Girl(x) → ¬∃e(Smile(e)"), "{fol}");
+}
+
+// =============================================================================
+// Collective and distributive readings
+// =============================================================================
+
+#[test]
+fn collective_verbs_keep_the_group() {
+    assert_eq!(compile("John and Mary met.").unwrap(), "P(Meet(John ⊕ Mary))");
+    assert_eq!(
+        compile("John and Mary met and talked.").unwrap(),
+        "1) P(Meet(John ⊕ Mary))\n2) P(Talk(John ⊕ Mary))"
+    );
+}
+
+#[test]
+fn distributive_verbs_apply_to_each_member() {
+    let fol = compile("John and Mary slept.").unwrap();
+    assert!(!fol.contains('⊕'), "{fol}");
+    assert!(fol.contains("Sleep(e) ∧ Agent(e, John)") && fol.contains("Sleep(e) ∧ Agent(e, Mary)"), "{fol}");
+}
+
+#[test]
+fn each_member_does_the_whole_verb_phrase() {
+    let fol = compile("John and Mary ate an apple and slept.").unwrap();
+    assert!(fol.contains("Sleep(e) ∧ Agent(e, John)") && fol.contains("Sleep(e) ∧ Agent(e, Mary)"), "{fol}");
+}
+
+#[test]
+fn floated_each_distributes_a_mixed_verb() {
+    assert!(compile("John and Mary lifted the piano.").unwrap().contains('⊕'));
+    let fol = compile("John and Mary each lifted the piano.").unwrap();
+    assert!(fol.contains("Lift(e) ∧ Agent(e, John)") && fol.contains("Lift(e) ∧ Agent(e, Mary)"), "{fol}");
+}
+
+#[test]
+fn mixed_verbs_with_coordinated_names_fork() {
+    let readings = compile_forest("John and Mary lifted the piano.");
+    assert!(readings.iter().any(|r| r.contains('⊕')), "{readings:?}");
+    assert!(readings.iter().any(|r| r.contains("Agent(e, Mary)")), "{readings:?}");
+}
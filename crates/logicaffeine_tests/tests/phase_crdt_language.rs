@@ -375,3 +375,47 @@ Show p's title.
         output
     );
 }
+
+#[test]
+fn e2e_test_shared_document_is_rejected_when_compiled() {
+    // Parts of a shared Document write through to it, which only the
+    // interpreter and VM model; compiling must refuse rather than copy.
+    let source = r#"## Definition
+A Board is Shared and has:
+    a notes, which is a shared Document.
+
+## Main
+Let mutable b be a new Board.
+Set item "title" of b's notes to "Plan".
+Show item "title" of b's notes.
+"#;
+
+    let err = compile_to_rust(source).expect_err("a shared Document field must not compile to Rust");
+    let message = format!("{:?}", err);
+    assert!(
+        message.contains("'notes' of Board is a shared Document"),
+        "Expected a diagnostic naming the field, got: {}",
+        message
+    );
+}
+
+#[test]
+fn e2e_test_sync_status_is_rejected_when_compiled() {
+    let source = r#"## Definition
+A Party is Shared and has:
+    a guests, which is a SharedSet of Text.
+
+## Main
+Let mutable p be a new Party.
+Add "Alice" to p's guests.
+Show the sync status of p's guests.
+"#;
+
+    let err = compile_to_rust(source).expect_err("`the sync status of` has no compiled lowering");
+    let message = format!("{:?}", err);
+    assert!(
+        message.contains("the sync status of"),
+        "Expected a diagnostic naming the phrase, got: {}",
+        message
+    );
+}
@@ -0,0 +1,53 @@
+//! Phase: Embedded Questions
+//!
+//! Question-embedding verbs take an interrogative complement as an
+//! intensional argument: a polar "whether/if" clause ("John wonders whether
+//! Mary left" → `Theme(e, [?φ])`) or a wh-clause ("John knows who came" →
+//! `Theme(e, [λx.φ])`).
+
+use logicaffeine_language::compile;
+
+#[test]
+fn whether_clauses_are_polar_questions() {
+    let fol = compile("John wonders whether Mary left.").unwrap();
+    assert_eq!(
+        fol,
+        "∃e(Wonder(e) ∧ Agent(e, John) ∧ Theme(e, [?∃e(Leave(e) ∧ Agent(e, Mary) ∧ Past(e))]))"
+    );
+}
+
+#[test]
+fn if_after_a_question_embedding_verb_is_whether() {
+    assert_eq!(
+        compile("John wonders if Mary left.").unwrap(),
+        compile("John wonders whether Mary left.").unwrap()
+    );
+}
+
+#[test]
+fn the_embedding_verb_keeps_its_tense() {
+    let fol = compile("John asked whether Mary left.").unwrap();
+    assert!(fol.contains("Theme(e, [?∃e(Leave(e)"), "{fol}");
+    assert!(fol.ends_with("∧ Past(e))"), "{fol}");
+}
+
+#[test]
+fn wh_clauses_abstract_over_the_gap() {
+    let fol = compile("John knows who came.").unwrap();
+    assert!(fol.contains("Theme(e, [λx.∃e(Come(e) ∧ Agent(e, x))])"), "{fol}");
+    let fol = compile("I know what John ate.").unwrap();
+    assert!(fol.contains("[λx.∃e(Eat(e) ∧ Agent(e, John) ∧ Theme(e, x))]"), "{fol}");
+}
+
+#[test]
+fn object_control_verbs_embed_questions() {
+    let fol = compile("Mary asks who came.").unwrap();
+    assert!(fol.contains("Ask(e) ∧ Agent(e, Mary) ∧ Theme(e, [λx."), "{fol}");
+}
+
+#[test]
+fn conditional_with_a_command_consequent() {
+    let fol = compile("If Mary asks who came, tell her.").unwrap();
+    assert!(fol.contains("Theme(e, [λx.∃e(Come(e)"), "{fol}");
+    assert!(fol.contains("→ Directive(Addressee"), "{fol}");
+}
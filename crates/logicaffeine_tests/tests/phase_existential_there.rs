@@ -0,0 +1,51 @@
+//! Phase: Existential "there"
+//!
+//! An expletive "there" with a copula asserts only that its pivot exists:
+//! "There is a cat on the mat." → ∃x(Cat(x) ∧ On(x, Mat)). The pivot's
+//! determiner picks the quantifier ("three", "at least two", "no"), and its
+//! PPs and relative clauses are part of the restriction.
+
+use logicaffeine_language::compile;
+
+#[test]
+fn a_locative_pivot_is_one_existential() {
+    assert_eq!(
+        compile("There is a cat on the mat.").unwrap(),
+        "∃x((((Cat(x) ∧ Animal(x)) ∧ Mammal(x)) ∧ On(x, Mat)))"
+    );
+    assert_eq!(compile("There are cats in the garden.").unwrap(), "∃x((Cats(x) ∧ In(x, Garden)))");
+}
+
+#[test]
+fn the_determiner_picks_the_quantifier() {
+    assert_eq!(compile("There are three solutions.").unwrap(), "∃=3.x(Solutions(x))");
+    assert_eq!(compile("There are at least two dogs.").unwrap(), "∃≥2x(Dogs(x))");
+}
+
+#[test]
+fn negative_existentials() {
+    assert_eq!(compile("There are no solutions.").unwrap(), "¬∃x(Solutions(x))");
+    assert_eq!(
+        compile("There is no dog in the house.").unwrap(),
+        compile("There is not a dog in the house.").unwrap()
+    );
+}
+
+#[test]
+fn past_tense_and_relative_clauses() {
+    assert_eq!(
+        compile("There was a man who ran.").unwrap(),
+        "P(∃x((Man(x) ∧ ∃e(Run(e) ∧ Agent(e, x)))))"
+    );
+}
+
+#[test]
+fn there_exists() {
+    assert_eq!(compile("There exists a prime.").unwrap(), "∃x(Prime(x))");
+}
+
+#[test]
+fn the_pivot_is_a_discourse_referent() {
+    let fol = compile("There is a cat. It sleeps.").unwrap();
+    assert!(fol.contains("Agent(e, x)"), "{fol}");
+}
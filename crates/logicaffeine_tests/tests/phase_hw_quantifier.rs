@@ -6,7 +6,8 @@
 #![cfg(feature = "verification")]
 
 use logicaffeine_verify::{
-    check_equivalence, EquivalenceResult, VerificationSession, VerifyExpr, VerifyOp, VerifyType,
+    check_equivalence, EquivalenceResult, VerificationErrorKind, VerificationSession, VerifyExpr,
+    VerifyOp, VerifyType,
 };
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -349,3 +350,39 @@ fn solver_forall_tautology_valid() {
     assert!(result.is_ok(),
         "forall x. (x>0 -> x>=0) should be valid through solver path: {:?}", result);
 }
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TRIGGERED QUANTIFIERS
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn f_of(arg: VerifyExpr) -> VerifyExpr {
+    VerifyExpr::apply_int("f", vec![arg])
+}
+
+#[test]
+fn solver_triggered_axiom_instantiates_on_matching_terms() {
+    // Assume forall x:Int. f(x) > x  {trigger: f(x)}; then f(3) > 3 follows
+    let mut session = VerificationSession::new();
+    session.assume(&VerifyExpr::forall_triggered(
+        vec![("x".into(), VerifyType::Int)],
+        vec![vec![f_of(VerifyExpr::var("x"))]],
+        VerifyExpr::gt(f_of(VerifyExpr::var("x")), VerifyExpr::var("x")),
+    ));
+    let result = session.verify(&VerifyExpr::gt(f_of(VerifyExpr::int(3)), VerifyExpr::int(3)));
+    assert!(result.is_ok(), "f(3) > 3 should follow from the triggered axiom: {:?}", result);
+}
+
+#[test]
+fn solver_rejects_trigger_missing_a_bound_variable() {
+    let mut session = VerificationSession::new();
+    session.assume(&VerifyExpr::forall_triggered(
+        vec![("x".into(), VerifyType::Int), ("y".into(), VerifyType::Int)],
+        vec![vec![f_of(VerifyExpr::var("x"))]],
+        VerifyExpr::gt(f_of(VerifyExpr::var("x")), VerifyExpr::var("y")),
+    ));
+    let err = session.verify(&VerifyExpr::bool(true)).unwrap_err();
+    assert!(
+        matches!(&err.kind, VerificationErrorKind::SolverError { message } if message.contains("'y'")),
+        "expected a trigger error naming y, got: {:?}", err
+    );
+}
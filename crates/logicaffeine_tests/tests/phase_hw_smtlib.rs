@@ -49,6 +49,15 @@ fn smtlib_array_formula() {
     assert!(smt.contains("(select a 0)"));
 }
 
+#[test]
+fn smtlib_sequence_length() {
+    let expr = VerifyExpr::gt(VerifyExpr::length(VerifyExpr::var("s")), VerifyExpr::int(0));
+    let smt = to_smtlib2(&expr, &[("s", VerifyType::SeqOf(Box::new(VerifyType::Int)))]);
+    assert!(smt.contains("(declare-fun s () (Array Int Int))"));
+    assert!(smt.contains("(declare-fun length ((Array Int Int)) Int)"));
+    assert!(smt.contains("(> (length s) 0)"));
+}
+
 #[test]
 fn smtlib_quantifier() {
     let expr = VerifyExpr::forall(
@@ -112,3 +121,15 @@ fn smtlib_store_select() {
     assert!(smt.contains("select"));
     assert!(smt.contains("store"));
 }
+
+#[test]
+fn smtlib_triggered_forall_emits_patterns() {
+    let f_x = VerifyExpr::apply_int("f", vec![VerifyExpr::var("x")]);
+    let expr = VerifyExpr::forall_triggered(
+        vec![("x".into(), VerifyType::Int)],
+        vec![vec![f_x.clone()]],
+        VerifyExpr::gt(f_x, VerifyExpr::var("x")),
+    );
+    let smt = to_smtlib2(&expr, &[]);
+    assert!(smt.contains("(forall ((x Int)) (! (> (f x) x) :pattern ((f x))))"), "{}", smt);
+}
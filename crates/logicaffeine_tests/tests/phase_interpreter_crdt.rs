@@ -190,3 +190,136 @@ Show s's penalties.
     assert!(result.output.contains("100"), "Should output points 100, got: {}", result.output);
     assert!(result.output.contains("10"), "Should output penalties 10, got: {}", result.output);
 }
+
+// =============================================================================
+// Shared Document — nested maps and lists with per-field convergence
+// =============================================================================
+
+#[test]
+fn interpreter_shared_document_set_and_get_fields() {
+    let source = r#"## Definition
+A Board is Shared and has:
+    a notes, which is a shared Document.
+
+## Main
+Let mutable b be a new Board.
+Set item "title" of b's notes to "Plan".
+Set item "open" of b's notes to true.
+Show item "title" of b's notes.
+Show length of b's notes.
+"#;
+    let result = run_interpreter(source);
+    assert!(result.success, "Should run without error: {}", result.error);
+    assert!(result.output.contains("Plan"), "Should read the title back, got: {}", result.output);
+    assert!(result.output.contains("2"), "Document has two fields, got: {}", result.output);
+}
+
+#[test]
+fn interpreter_shared_document_edits_nested_list_of_structs() {
+    let source = r#"## Definition
+A Card is a Structure and has:
+    a name: Text.
+    a done: Bool.
+
+A Board is Shared and has:
+    a notes, which is a shared Document.
+
+## Main
+Let mutable b be a new Board.
+Set item "cards" of b's notes to [].
+Let cards be item "cards" of b's notes.
+Push a new Card with name "Write" and done false to cards.
+Push a new Card with name "Ship" and done false to cards.
+Let card be item 2 of cards.
+Set item "done" of card to true.
+Show length of item "cards" of b's notes.
+Show item "done" of item 2 of item "cards" of b's notes.
+"#;
+    let result = run_interpreter(source);
+    assert!(result.success, "Should run without error: {}", result.error);
+    let lines: Vec<&str> = result.output.lines().collect();
+    assert_eq!(lines, vec!["2", "true"], "Edits write through to the document, got: {}", result.output);
+}
+
+#[test]
+fn interpreter_shared_document_concurrent_fields_both_survive() {
+    let source = r#"## Definition
+A Board is Shared and has:
+    a notes, which is a shared Document.
+
+## Main
+Let mutable a be a new Board.
+Let mutable b be a new Board.
+Set item "title" of a's notes to "Plan".
+Set item "owner" of b's notes to "Ada".
+Merge b into a.
+Show item "title" of a's notes.
+Show item "owner" of a's notes.
+"#;
+    let result = run_interpreter(source);
+    assert!(result.success, "Should run without error: {}", result.error);
+    assert!(result.output.contains("Plan"), "a's field survives the merge, got: {}", result.output);
+    assert!(result.output.contains("Ada"), "b's field arrives with the merge, got: {}", result.output);
+}
+
+// =============================================================================
+// Sync status — versions, unpublished changes, peers, and concurrent writes
+// =============================================================================
+
+#[test]
+fn interpreter_sync_status_shows_version_and_peers() {
+    let source = r#"## Definition
+A Party is Shared and has:
+    a guests, which is a SharedSet of Text.
+
+## Main
+Let mutable a be a new Party.
+Let mutable b be a new Party.
+Add "Alice" to a's guests.
+Add "Bob" to b's guests.
+Add "Carol" to b's guests.
+Merge b into a.
+Show the sync status of a's guests.
+"#;
+    let result = run_interpreter(source);
+    assert!(result.success, "Should run without error: {}", result.error);
+    assert_eq!(
+        result.output,
+        "SyncStatus { conflicts: [], peers: [2], pending: true, replica: 1, version: {1: 1, 2: 2} }",
+    );
+}
+
+#[test]
+fn interpreter_sync_status_clears_pending_on_sync() {
+    let source = r#"## Definition
+A Party is Shared and has:
+    a guests, which is a SharedSet of Text.
+
+## Main
+Let mutable p be a new Party.
+Add "Alice" to p's guests.
+Let waiting be the sync status of p's guests.
+Show waiting's pending.
+Sync p on "party".
+Let published be the sync status of p's guests.
+Show published's pending.
+Add "Bob" to p's guests.
+Show the sync status of p.
+"#;
+    let result = run_interpreter(source);
+    assert!(result.success, "Should run without error: {}", result.error);
+    let lines: Vec<&str> = result.output.lines().collect();
+    assert_eq!(lines[..2], ["true", "false"], "got: {}", result.output);
+    assert_eq!(
+        lines[2],
+        "{guests: SyncStatus { conflicts: [], peers: [], pending: true, replica: 1, version: {1: 2} }}",
+    );
+}
+
+#[test]
+fn interpreter_sync_status_needs_a_shared_value() {
+    let source = "## Main\nLet n be 3.\nShow the sync status of n.\n";
+    let result = run_interpreter(source);
+    assert!(!result.success, "A plain Int has no sync status");
+    assert!(result.error.contains("sync status"), "got: {}", result.error);
+}
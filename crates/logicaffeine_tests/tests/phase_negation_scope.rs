@@ -0,0 +1,56 @@
+//! Phase: Negation Scope
+//!
+//! Clausal "not" scopes against a universal subject ("Everyone didn't
+//! leave": ∀¬ or ¬∀), a modal ("must not": □¬ or ¬□), and a neg-raising
+//! verb ("I don't think he came": ¬Think or Think(¬)). `compile_forest`
+//! enumerates both scopings; `CompileOptions::negative_scope` picks one.
+
+use logicaffeine_language::{compile, compile_forest, compile_with_options, CompileOptions, NegativeScopeMode};
+
+fn with_scope(input: &str, negative_scope: NegativeScopeMode) -> String {
+    compile_with_options(input, CompileOptions { negative_scope, ..Default::default() }).unwrap()
+}
+
+#[test]
+fn everyone_expands_to_a_universal_over_persons() {
+    let fol = compile("Everyone didn't leave.").unwrap();
+    assert_eq!(fol, "∀x((Person(x) → ¬∃e(Leave(e) ∧ Agent(e, x) ∧ Past(e))))");
+}
+
+#[test]
+fn forest_has_both_scopings_of_a_negated_universal() {
+    let readings = compile_forest("Everyone didn't leave.");
+    assert!(readings.iter().any(|r| r.starts_with("∀x((Person(x) → ¬")), "{readings:?}");
+    assert!(readings.iter().any(|r| r.starts_with("¬∀x((Person(x) → ∃e(Leave(e)")), "{readings:?}");
+}
+
+#[test]
+fn wide_scope_puts_negation_over_the_universal() {
+    let fol = with_scope("Every student is not happy.", NegativeScopeMode::Wide);
+    assert_eq!(fol, "¬∀x((Student(x) → Happy(x)))");
+}
+
+#[test]
+fn wide_scope_puts_negation_over_the_modal() {
+    assert!(with_scope("John must not leave.", NegativeScopeMode::Surface).starts_with("□"));
+    assert!(with_scope("John must not leave.", NegativeScopeMode::Wide).starts_with("¬□"));
+}
+
+#[test]
+fn forest_has_the_neg_raised_reading_of_think() {
+    let readings = compile_forest("I don't think he came.");
+    assert!(readings.iter().any(|r| r.starts_with("¬Think(")), "{readings:?}");
+    assert!(readings.iter().any(|r| r.starts_with("Think(") && r.contains("[¬∃e(Come(e)")), "{readings:?}");
+}
+
+#[test]
+fn narrow_scope_lowers_negation_into_the_complement() {
+    let fol = with_scope("John doesn't believe that Mary left.", NegativeScopeMode::Narrow);
+    assert_eq!(fol, "Believe(John, [¬∃e(Leave(e) ∧ Agent(e, Mary) ∧ Past(e))])");
+}
+
+#[test]
+fn only_neg_raising_verbs_lower_negation() {
+    let fol = with_scope("John doesn't know that Mary left.", NegativeScopeMode::Narrow);
+    assert!(!fol.contains("[¬"), "{fol}");
+}
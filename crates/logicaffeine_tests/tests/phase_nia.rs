@@ -0,0 +1,138 @@
+//! Phase Nia: Nonlinear Integer Arithmetic
+//!
+//! Tests for the nia tactic, which proves simple nonlinear goals — products
+//! and squares — by interval reasoning, products of hypotheses and sign case
+//! splits, where omega and lia reject the goal as nonlinear.
+
+use logicaffeine_kernel::interface::Repl;
+
+/// Prove `statement` in literate mode with `tactic` and return what the
+/// derivation concludes.
+fn conclude(statement: &str, tactic: &str) -> String {
+    let mut repl = Repl::new();
+    repl.execute(&format!("## Theorem: Goal\n    Statement: {statement}.\n    Proof: {tactic}."))
+        .unwrap();
+    repl.execute("Definition result : Syntax := concludes Goal.")
+        .unwrap();
+    repl.execute("Eval result.").unwrap()
+}
+
+// =============================================================================
+// TYPE CHECKS
+// =============================================================================
+
+#[test]
+fn test_dniasolve_type() {
+    let mut repl = Repl::new();
+    let result = repl.execute("Check DNiaSolve.");
+    assert!(result.is_ok(), "DNiaSolve should exist: {:?}", result);
+}
+
+#[test]
+fn test_try_nia_type() {
+    let mut repl = Repl::new();
+    let result = repl.execute("Check try_nia.");
+    assert!(result.is_ok(), "try_nia should exist: {:?}", result);
+}
+
+// =============================================================================
+// SQUARES AND PRODUCTS
+// =============================================================================
+
+#[test]
+fn test_nia_square_nonnegative() {
+    let mut repl = Repl::new();
+    // x * x >= 0
+    repl.execute("Definition x : Syntax := SVar 0.").unwrap();
+    repl.execute("Definition xx : Syntax := SApp (SApp (SName \"mul\") x) x.")
+        .unwrap();
+    repl.execute("Definition goal : Syntax := SApp (SApp (SName \"Ge\") xx) (SLit 0).")
+        .unwrap();
+    repl.execute("Definition d : Derivation := try_nia goal.")
+        .unwrap();
+    repl.execute("Definition result : Syntax := concludes d.")
+        .unwrap();
+
+    let concluded = repl.execute("Eval result.").unwrap();
+    let original = repl.execute("Eval goal.").unwrap();
+    assert_eq!(concluded, original, "nia should prove x*x >= 0");
+}
+
+#[test]
+fn test_literate_nia_square_plus_one_positive() {
+    let result = conclude("(Gt (add (mul x x) 1) 0)", "nia");
+    assert!(!result.contains("Error"), "nia should prove x*x + 1 > 0: {result}");
+}
+
+#[test]
+fn test_literate_nia_product_of_nonnegatives() {
+    let result = conclude("(implies (Le 0 x) (implies (Le 0 y) (Ge (mul x y) 0)))", "nia");
+    assert!(!result.contains("Error"), "nia should prove 0<=x -> 0<=y -> x*y >= 0: {result}");
+}
+
+#[test]
+fn test_literate_nia_needs_a_case_split() {
+    // x * (x + 1) >= 0: no single interval for x shows it, each sign case does.
+    let result = conclude("(Ge (mul x (add x 1)) 0)", "nia");
+    assert!(!result.contains("Error"), "nia should prove x*(x+1) >= 0: {result}");
+}
+
+// =============================================================================
+// MONOTONICITY
+// =============================================================================
+
+#[test]
+fn test_literate_nia_multiplication_monotone() {
+    // x <= y -> 0 <= z -> x*z <= y*z
+    let result = conclude("(implies (Le x y) (implies (Le 0 z) (Le (mul x z) (mul y z))))", "nia");
+    assert!(!result.contains("Error"), "nia should prove monotonicity of *: {result}");
+}
+
+#[test]
+fn test_literate_nia_square_monotone() {
+    // 0 <= x -> x <= y -> x*x <= y*y
+    let result = conclude("(implies (Le 0 x) (implies (Le x y) (Le (mul x x) (mul y y))))", "nia");
+    assert!(!result.contains("Error"), "nia should prove monotonicity of squaring: {result}");
+}
+
+#[test]
+fn test_literate_nia_strict_bounds() {
+    // 1 < x -> 1 < y -> 1 < x*y
+    let result = conclude("(implies (Lt 1 x) (implies (Lt 1 y) (Lt 1 (mul x y))))", "nia");
+    assert!(!result.contains("Error"), "nia should prove 1<x -> 1<y -> 1<x*y: {result}");
+}
+
+// =============================================================================
+// AUTO
+// =============================================================================
+
+#[test]
+fn test_literate_auto_closes_a_square() {
+    let result = conclude("(Ge (mul x x) 0)", "auto");
+    assert!(!result.contains("Error"), "auto should fall through to nia: {result}");
+}
+
+// =============================================================================
+// FAILURE CASES
+// =============================================================================
+
+#[test]
+fn test_literate_nia_fails_unsigned_product() {
+    // x*y >= 0 is false for x = 1, y = -1
+    let result = conclude("(Ge (mul x y) 0)", "nia");
+    assert!(result.contains("Error"), "nia should fail on x*y >= 0: {result}");
+}
+
+#[test]
+fn test_literate_nia_fails_square_positive() {
+    // x*x > 0 is false for x = 0
+    let result = conclude("(Gt (mul x x) 0)", "nia");
+    assert!(result.contains("Error"), "nia should fail on x*x > 0: {result}");
+}
+
+#[test]
+fn test_literate_nia_fails_monotone_without_sign() {
+    // x <= y -> x*z <= y*z is false for z = -1
+    let result = conclude("(implies (Le x y) (Le (mul x z) (mul y z)))", "nia");
+    assert!(result.contains("Error"), "nia should fail without 0 <= z: {result}");
+}
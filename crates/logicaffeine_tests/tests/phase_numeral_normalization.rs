@@ -0,0 +1,118 @@
+//! Phase: Numeral Normalization
+//!
+//! Prose spells out the values the lexer already reads as literals: dates
+//! ("March 5th, 2024"), clock times ("14:30", "5 o'clock"), written numbers
+//! ("twenty-three") and ordinals ("3rd", "third"). Each normalizes to the
+//! same token its literal form produces.
+
+use logicaffeine_base::Interner;
+use logicaffeine_language::compile;
+use logicaffeine_language::lexer::Lexer;
+use logicaffeine_language::token::TokenType;
+use logicaffeine_system::temporal::LogosDate;
+
+fn kinds(input: &str) -> Vec<TokenType> {
+    let mut interner = Interner::new();
+    let mut lexer = Lexer::new(input, &mut interner);
+    lexer.tokenize().into_iter().map(|t| t.kind).collect()
+}
+
+fn date_days(input: &str) -> Option<i32> {
+    kinds(input).into_iter().find_map(|k| match k {
+        TokenType::DateLiteral { days } => Some(days),
+        _ => None,
+    })
+}
+
+fn time_nanos(input: &str) -> Option<i64> {
+    kinds(input).into_iter().find_map(|k| match k {
+        TokenType::TimeLiteral { nanos_from_midnight } => Some(nanos_from_midnight),
+        _ => None,
+    })
+}
+
+// =============================================================================
+// Dates
+// =============================================================================
+
+#[test]
+fn written_dates_match_their_iso_form() {
+    let iso = date_days("2024-03-05");
+    assert!(iso.is_some());
+    for written in ["March 5th, 2024", "March 5, 2024", "March 5 2024", "5 March 2024", "March fifth, 2024"] {
+        assert_eq!(date_days(written), iso, "{written}");
+    }
+}
+
+#[test]
+fn written_dates_feed_logos_date() {
+    let days = date_days("Let d be December 25th, 2026.").unwrap();
+    assert_eq!(LogosDate::new(days).to_ymd(), (2026, 12, 25));
+}
+
+#[test]
+fn a_date_without_a_year_stays_a_month_and_day() {
+    assert_eq!(date_days("John left on March 5th."), None);
+    assert!(compile("John left on March 5th.").unwrap().contains("March_5"));
+}
+
+#[test]
+fn impossible_dates_are_not_dates() {
+    assert_eq!(date_days("February 30, 2024"), None);
+    assert_eq!(date_days("March 3th, 2024"), None);
+}
+
+#[test]
+fn dates_are_temporal_references() {
+    let fol = compile("John left before March 5th, 2024.").unwrap();
+    let days = date_days("2024-03-05").unwrap();
+    assert!(fol.contains(&format!("Before(e, {days})")), "{fol}");
+}
+
+// =============================================================================
+// Clock times
+// =============================================================================
+
+#[test]
+fn twenty_four_hour_times() {
+    let minutes = |input: &str| time_nanos(input).map(|n| n / 60_000_000_000);
+    assert_eq!(minutes("at 14:30."), Some(14 * 60 + 30));
+    assert_eq!(minutes("at 09:05"), Some(9 * 60 + 5));
+    assert_eq!(minutes("at 24:00"), None);
+    assert_eq!(minutes("##[10:15] ack"), None);
+}
+
+#[test]
+fn oclock_times() {
+    let five = Some(5 * 3600 * 1_000_000_000);
+    assert_eq!(time_nanos("at 5 o'clock"), five);
+    assert_eq!(time_nanos("at five o'clock"), five);
+    assert_eq!(compile("The meeting is at 5 o'clock.").unwrap(), compile("The meeting is at 5:00am.").unwrap());
+}
+
+// =============================================================================
+// Written numbers and ordinals
+// =============================================================================
+
+#[test]
+fn written_numbers_are_cardinals() {
+    assert_eq!(kinds("Twenty-three")[0], TokenType::Cardinal(23));
+    assert_eq!(kinds("eleven")[0], TokenType::Cardinal(11));
+    assert_eq!(compile("Eleven dogs bark.").unwrap(), "∃=11.x((Dogs(x) ∧ ∃e(Bark(e) ∧ Agent(e, x))))");
+    assert_eq!(
+        compile("The temperature is ninety.").unwrap(),
+        compile("The temperature is 90.").unwrap()
+    );
+}
+
+#[test]
+fn numeric_and_written_ordinals_agree() {
+    assert_eq!(compile("John was the 3rd man.").unwrap(), compile("John was the third man.").unwrap());
+    assert_eq!(compile("The 21st dog barks.").unwrap(), compile("The twenty-first dog barks.").unwrap());
+}
+
+#[test]
+fn second_is_an_ordinal_only_after_an_article() {
+    assert!(compile("The second man runs.").unwrap().contains("Second(x)"));
+    assert!(matches!(kinds("It took one second.")[3], TokenType::Noun(_) | TokenType::CalendarUnit(_)));
+}
@@ -86,6 +86,7 @@ fn simple_tier_has_two_scope_readings() {
         logicaffeine_language::CompileOptions {
             format: logicaffeine_language::OutputFormat::SimpleFOL,
             pragmatic: false,
+            ..Default::default()
         },
     );
 
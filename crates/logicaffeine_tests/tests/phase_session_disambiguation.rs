@@ -0,0 +1,113 @@
+//! Session disambiguation: with a `DisambiguationPolicy` installed, a session
+//! asks instead of silently choosing — between the readings of an ambiguous
+//! sentence and between the possible antecedents of a pronoun — and replays
+//! each answer when the same sentence comes round again.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use logicaffeine_language::parser::AntecedentAmbiguity;
+use logicaffeine_language::session::{DisambiguationPolicy, Session};
+
+/// Answers from a script and logs every question it was asked.
+#[derive(Clone, Default)]
+struct Scripted {
+    reading: usize,
+    antecedent: usize,
+    asked: Rc<RefCell<Vec<String>>>,
+}
+
+impl DisambiguationPolicy for Scripted {
+    fn choose_reading(&mut self, sentence: &str, readings: &[String]) -> usize {
+        assert!(readings.len() > 1, "asked about an unambiguous sentence");
+        self.asked.borrow_mut().push(format!("reading: {sentence}"));
+        self.reading
+    }
+
+    fn choose_antecedent(&mut self, sentence: &str, ambiguity: &AntecedentAmbiguity) -> usize {
+        assert!(ambiguity.candidates.len() > 1);
+        self.asked.borrow_mut().push(format!("{}: {sentence}", ambiguity.pronoun));
+        self.antecedent
+    }
+}
+
+#[test]
+fn unambiguous_sentences_never_ask() {
+    let policy = Scripted::default();
+    let mut session = Session::new();
+    session.set_disambiguation_policy(policy.clone());
+    let out = session.eval("John walked.").unwrap();
+    assert!(out.contains("Walk"), "{out}");
+    assert!(policy.asked.borrow().is_empty());
+}
+
+#[test]
+fn answering_zero_matches_a_session_without_a_policy() {
+    let sentences = ["A farmer met a lawyer.", "He smiled.", "I saw the man with the telescope."];
+    let mut silent = Session::new();
+    let mut asking = Session::new();
+    asking.set_disambiguation_policy(Scripted::default());
+    for sentence in sentences {
+        assert_eq!(asking.eval(sentence).unwrap(), silent.eval(sentence).unwrap(), "{sentence}");
+    }
+}
+
+#[test]
+fn the_chosen_reading_is_used() {
+    let mut default = Session::new();
+    let first = default.eval("I saw the man with the telescope.").unwrap();
+
+    let policy = Scripted { reading: 1, ..Scripted::default() };
+    let mut session = Session::new();
+    session.set_disambiguation_policy(policy.clone());
+    let second = session.eval("I saw the man with the telescope.").unwrap();
+
+    assert_ne!(first, second);
+    assert_eq!(*policy.asked.borrow(), ["reading: I saw the man with the telescope."]);
+    assert_eq!(session.disambiguation("I saw the man with the telescope.").unwrap().reading, Some(1));
+}
+
+#[test]
+fn the_chosen_antecedent_is_used() {
+    let policy = Scripted { antecedent: 1, ..Scripted::default() };
+    let mut session = Session::new();
+    session.set_disambiguation_policy(policy.clone());
+    session.eval("A farmer met a lawyer.").unwrap();
+    let out = session.eval("He smiled.").unwrap();
+
+    let mut default = Session::new();
+    default.eval("A farmer met a lawyer.").unwrap();
+    assert_ne!(out, default.eval("He smiled.").unwrap());
+
+    assert_eq!(*policy.asked.borrow(), ["He: He smiled."]);
+    let choice = session.disambiguation("He smiled.").unwrap();
+    assert_eq!(choice.antecedents.len(), 1);
+    assert_eq!(choice.antecedents[0].1, "lawyer");
+}
+
+#[test]
+fn repeated_sentences_resolve_without_asking_again() {
+    let policy = Scripted { reading: 1, ..Scripted::default() };
+    let mut session = Session::new();
+    session.set_disambiguation_policy(policy.clone());
+    let first = session.eval("I saw the man with the telescope.").unwrap();
+    session.reset();
+    let again = session.eval("I  saw the man with the telescope.").unwrap();
+
+    assert_eq!(first, again);
+    assert_eq!(policy.asked.borrow().len(), 1);
+}
+
+#[test]
+fn choices_are_replayed_after_the_policy_is_removed() {
+    let mut session = Session::new();
+    session.set_disambiguation_policy(Scripted { reading: 1, ..Scripted::default() });
+    let chosen = session.eval("I saw the man with the telescope.").unwrap();
+    session.clear_disambiguation_policy();
+    session.reset();
+    assert_eq!(session.eval("I saw the man with the telescope.").unwrap(), chosen);
+
+    session.forget_choices();
+    session.reset();
+    assert_ne!(session.eval("I saw the man with the telescope.").unwrap(), chosen);
+}
@@ -0,0 +1,150 @@
+//! Phase Simp Sets: User-Extensible Rewrite Databases
+//!
+//! Tests for simp rules declared in LOGOS: a proved equation followed by
+//! "Mark this equation as a simplification rule." joins a simp set, which
+//! `simp` (default set) or `simp using <Set>` then rewrites with. Rules that
+//! obviously loop, or overlap an equal-priority rule without rejoining, are
+//! refused.
+
+use logicaffeine_kernel::interface::Repl;
+
+/// Declare `name : statement` proved by `tactic`, followed by `mark`.
+fn rule(repl: &mut Repl, name: &str, statement: &str, tactic: &str, mark: &str) -> Result<String, String> {
+    repl.execute(&format!(
+        "## Theorem: {name}\n    Statement: {statement}.\n    Proof: {tactic}.\n    {mark}"
+    ))
+    .map_err(|e| e.to_string())
+}
+
+const MARK: &str = "Mark this equation as a simplification rule.";
+
+/// Prove `statement` with `tactic` and return what the derivation concludes.
+fn conclude(repl: &mut Repl, statement: &str, tactic: &str) -> String {
+    repl.execute(&format!("## Theorem: Goal\n    Statement: {statement}.\n    Proof: {tactic}."))
+        .unwrap();
+    repl.execute("Definition result : Syntax := concludes Goal.")
+        .unwrap();
+    repl.execute("Eval result.").unwrap()
+}
+
+// =============================================================================
+// TYPE CHECKS
+// =============================================================================
+
+#[test]
+fn test_dsimpusingsolve_type() {
+    let mut repl = Repl::new();
+    let result = repl.execute("Check DSimpUsingSolve.");
+    assert!(result.is_ok(), "DSimpUsingSolve should exist: {:?}", result);
+}
+
+#[test]
+fn test_try_simp_using_type() {
+    let mut repl = Repl::new();
+    let result = repl.execute("Check try_simp_using.");
+    assert!(result.is_ok(), "try_simp_using should exist: {:?}", result);
+}
+
+// =============================================================================
+// DECLARING AND USING RULES
+// =============================================================================
+
+#[test]
+fn test_marked_rule_extends_default_simp() {
+    let mut repl = Repl::new();
+    let before = conclude(&mut repl, "(Eq (add (add y 0) 0) y)", "simp");
+    assert!(before.contains("Error"), "simp has no rule for x + 0 yet: {before}");
+
+    rule(&mut repl, "AddZero", "(add x 0) equals x", "ring", MARK).unwrap();
+    let after = conclude(&mut repl, "(Eq (add (add y 0) 0) y)", "simp");
+    assert!(!after.contains("Error"), "simp should rewrite with AddZero: {after}");
+}
+
+#[test]
+fn test_named_set_is_used_only_when_asked() {
+    let mut repl = Repl::new();
+    rule(
+        &mut repl,
+        "MulOne",
+        "(mul x 1) equals x",
+        "ring",
+        "Mark this equation as a simplification rule in arith.",
+    )
+    .unwrap();
+
+    let plain = conclude(&mut repl, "(Eq (mul y 1) y)", "simp");
+    assert!(plain.contains("Error"), "the default set does not hold MulOne: {plain}");
+    let using = conclude(&mut repl, "(Eq (mul y 1) y)", "simp using arith");
+    assert!(!using.contains("Error"), "simp using arith should prove it: {using}");
+}
+
+#[test]
+fn test_simp_using_unknown_set_fails() {
+    let mut repl = Repl::new();
+    let result = conclude(&mut repl, "(Eq 2 2)", "simp using nowhere");
+    assert!(result.contains("Error"), "an unknown simp set proves nothing: {result}");
+}
+
+#[test]
+fn test_rules_combine_with_arithmetic() {
+    let mut repl = Repl::new();
+    rule(&mut repl, "MulZero", "(mul x 0) equals 0", "ring", MARK).unwrap();
+    // (y * (1 - 1)) + 2 = 2: arithmetic folds 1 - 1, the rule kills the product.
+    let result = conclude(&mut repl, "(Eq (add (mul y (sub 1 1)) 2) 2)", "simp");
+    assert!(!result.contains("Error"), "simp should fold then rewrite: {result}");
+}
+
+// =============================================================================
+// SANITY CHECKS
+// =============================================================================
+
+#[test]
+fn test_commutativity_is_refused_as_a_loop() {
+    let mut repl = Repl::new();
+    let err = rule(&mut repl, "AddComm", "(add x y) equals (add y x)", "ring", MARK).unwrap_err();
+    assert!(err.contains("loops"), "x + y = y + x rewrites forever: {err}");
+    assert!(repl.execute("Check AddComm.").is_err(), "a refused rule adds no theorem");
+}
+
+#[test]
+fn test_rules_rewriting_into_each_other_are_refused() {
+    let mut repl = Repl::new();
+    rule(&mut repl, "AssocL", "(add x (add y z)) equals (add (add x y) z)", "ring", MARK).unwrap();
+    let err = rule(&mut repl, "AssocR", "(add (add x y) z) equals (add x (add y z))", "ring", MARK)
+        .unwrap_err();
+    assert!(err.contains("loops with 'AssocL'"), "{err}");
+}
+
+#[test]
+fn test_non_joinable_overlap_needs_a_priority() {
+    let mut repl = Repl::new();
+    let left = "(mul (add x 1) y) equals (add (mul x y) y)";
+    let right = "(mul x (add y 1)) equals (add (mul x y) x)";
+    rule(&mut repl, "SuccMulL", left, "ring", MARK).unwrap();
+    let err = rule(&mut repl, "SuccMulR", right, "ring", MARK).unwrap_err();
+    assert!(err.contains("overlaps 'SuccMulL'") && err.contains("priority"), "{err}");
+
+    let ranked = "Mark this equation as a simplification rule with priority 1.";
+    rule(&mut repl, "SuccMulR", right, "ring", ranked).unwrap();
+}
+
+#[test]
+fn test_unproved_equation_is_refused() {
+    let mut repl = Repl::new();
+    let err = rule(&mut repl, "Bogus", "(add x 1) equals x", "ring", MARK).unwrap_err();
+    assert!(err.contains("not proved"), "{err}");
+}
+
+#[test]
+fn test_non_equation_is_refused() {
+    let mut repl = Repl::new();
+    let err = rule(&mut repl, "Below", "(Le x (add x 1))", "omega", MARK).unwrap_err();
+    assert!(err.contains("not an equation"), "{err}");
+}
+
+#[test]
+fn test_unbound_right_hand_variable_is_refused() {
+    let mut repl = Repl::new();
+    let err = rule(&mut repl, "SubSelf", "0 equals (sub x x)", "ring", MARK).unwrap_err();
+    assert!(err.contains("x occurs on the right but not on the left"), "{err}");
+}
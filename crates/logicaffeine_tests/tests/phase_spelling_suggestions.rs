@@ -0,0 +1,57 @@
+//! Spelling diagnosis: a misspelled word that breaks the parse is reported as
+//! `UnknownWord` with ranked, keyboard-aware corrections instead of the
+//! structural error the guessed role happened to trip. Unknown words that
+//! parse fine (names, novel nouns) are never second-guessed.
+
+use logicaffeine_compile::compile::compile_to_rust;
+use logicaffeine_language::compile;
+use logicaffeine_language::error::ParseErrorKind;
+
+fn unknown_word(result: Result<String, logicaffeine_language::error::ParseError>) -> (String, Vec<String>) {
+    match result {
+        Err(e) => match e.kind {
+            ParseErrorKind::UnknownWord { word, suggestions } => (word, suggestions),
+            other => panic!("expected UnknownWord, got {:?}", other),
+        },
+        Ok(out) => panic!("expected an UnknownWord error, got OK: {}", out),
+    }
+}
+
+// ── Logic mode ───────────────────────────────────────────────────────────────
+
+#[test]
+fn misspelled_verb_is_diagnosed_with_the_intended_word_first() {
+    let (word, suggestions) = unknown_word(compile("John lovse Mary."));
+    assert_eq!(word, "lovse");
+    assert_eq!(suggestions.first().map(String::as_str), Some("loves"), "{:?}", suggestions);
+    assert!(suggestions.len() <= 3);
+}
+
+#[test]
+fn error_span_covers_the_misspelled_word() {
+    let source = "John lovse Mary.";
+    let err = compile(source).unwrap_err();
+    assert_eq!(&source[err.span.start..err.span.end], "lovse");
+}
+
+#[test]
+fn unknown_words_that_parse_are_left_alone() {
+    assert!(compile("Jonh loves Mary.").is_ok(), "a novel name is not a typo");
+    assert!(compile("Every dgo barks.").is_ok(), "a novel noun in noun position parses");
+}
+
+// ── Imperative mode ──────────────────────────────────────────────────────────
+
+#[test]
+fn misspelled_statement_keyword_suggests_the_keyword() {
+    let (word, suggestions) = unknown_word(compile_to_rust("## Main\nLet x be 5.\nShwo x.\n"));
+    assert_eq!(word, "Shwo");
+    assert_eq!(suggestions.first().map(String::as_str), Some("show"), "{:?}", suggestions);
+}
+
+#[test]
+fn transposed_return_suggests_return() {
+    let source = "## To f (x: Int) -> Int:\n    Retrun x.\n\n## Main\nShow f(1).\n";
+    let (_, suggestions) = unknown_word(compile_to_rust(source));
+    assert_eq!(suggestions.first().map(String::as_str), Some("return"), "{:?}", suggestions);
+}
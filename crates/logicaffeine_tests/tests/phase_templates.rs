@@ -0,0 +1,61 @@
+//! `## For each of …` declaration templates: one block stamped out per item
+//! before discovery, so the copies behave exactly like hand-written ones.
+
+mod common;
+use common::assert_interpreter_output;
+
+use std::fs;
+use tempfile::tempdir;
+
+use logicaffeine_compile::compile::{compile_project, CompileError};
+
+#[test]
+fn template_types_are_discovered_like_written_ones() {
+    assert_interpreter_output(
+        r#"## For each of Dog, Cat and Bird:
+## A {Animal} has:
+    A name: Text.
+    A legs: Int.
+
+## Main
+Let rex be a new Dog with name "Rex" and legs 4.
+Let tweety be a new Bird with name "Tweety" and legs 2.
+Show rex's name.
+Show tweety's legs.
+"#,
+        "Rex\n2",
+    );
+}
+
+#[test]
+fn template_functions_get_one_name_per_copy() {
+    assert_interpreter_output(
+        r#"## For each of Dog, Cat:
+## A {Animal} has:
+    A name: Text.
+
+## For each of Dog, Cat:
+## To describe (a: {Animal}) -> Text:
+    Return a's name.
+
+## Main
+Let rex be a new Dog with name "Rex".
+Let tom be a new Cat with name "Tom".
+Show describeDog(rex).
+Show describeCat(tom).
+"#,
+        "Rex\nTom",
+    );
+}
+
+#[test]
+fn malformed_templates_fail_the_project_build() {
+    let dir = tempdir().unwrap();
+    let entry = dir.path().join("main.md");
+    fs::write(&entry, "## For each of Dog, Cat:\n## A Pet has:\n    A name: Text.\n\n## Main\nShow 1.\n").unwrap();
+
+    match compile_project(&entry) {
+        Err(CompileError::Io(message)) => assert!(message.contains("never uses a placeholder"), "{message}"),
+        other => panic!("expected a template error, got {:?}", other.map(|output| output.rust_code)),
+    }
+}
@@ -0,0 +1,102 @@
+//! Bounded model checking of temporal policies
+
+#![cfg(feature = "verification")]
+
+use logicaffeine_verify::bmc::{always, check_policy, eventually, next, until, BmcResult};
+use logicaffeine_verify::{SignalValue, VerifyExpr, VerifyType};
+
+fn bools(names: &[&str]) -> Vec<(String, VerifyType)> {
+    names.iter().map(|n| (n.to_string(), VerifyType::Bool)).collect()
+}
+
+/// "After a request, a response eventually occurs."
+fn response_policy() -> VerifyExpr {
+    always(VerifyExpr::implies(
+        VerifyExpr::var("request"),
+        eventually(VerifyExpr::var("response")),
+    ))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// VIOLATIONS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn bmc_finds_a_request_that_is_never_answered() {
+    let result = check_policy(&response_policy(), &[], &bools(&["request", "response"]), 5);
+    let BmcResult::Violated { trace, loop_point } = result else {
+        panic!("an unconstrained world can ignore requests, got: {:?}", result);
+    };
+
+    // Some step in the loop-closed trace issues a request, and no step the
+    // execution reaches afterwards ever responds.
+    let value = |step: usize, name: &str| trace.cycles[step].signals[name] == SignalValue::Bool(true);
+    let last = trace.cycles.len() - 1;
+    let unanswered = (0..=last).any(|i| {
+        value(i, "request") && (i.min(loop_point)..=last).all(|j| !value(j, "response"))
+    });
+    assert!(unanswered, "trace does not show an unanswered request: {:?}", trace);
+}
+
+#[test]
+fn bmc_reports_a_shortest_violation() {
+    // G(!alarm) fails as soon as the alarm can go off — in the first state.
+    let policy = always(VerifyExpr::not(VerifyExpr::var("alarm")));
+    let result = check_policy(&policy, &[], &bools(&["alarm"]), 5);
+    let BmcResult::Violated { trace, .. } = result else {
+        panic!("expected a violation, got: {:?}", result);
+    };
+    assert_eq!(trace.cycles.len(), 1);
+    assert_eq!(trace.cycles[0].signals["alarm"], SignalValue::Bool(true));
+}
+
+#[test]
+fn bmc_world_state_includes_ground_predicates() {
+    // Every request by alice is eventually granted.
+    let request = VerifyExpr::apply("Request", vec![VerifyExpr::var("alice")]);
+    let grant = VerifyExpr::apply("Grant", vec![VerifyExpr::var("alice")]);
+    let policy = always(VerifyExpr::implies(request, eventually(grant)));
+    let result = check_policy(&policy, &[], &[], 3);
+    let BmcResult::Violated { trace, .. } = result else {
+        panic!("expected a violation, got: {:?}", result);
+    };
+    for state in &trace.cycles {
+        assert!(state.signals.contains_key("Request(alice)"), "{:?}", state);
+        assert!(state.signals.contains_key("Grant(alice)"), "{:?}", state);
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// POLICIES THAT HOLD
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn bmc_policy_holds_when_every_request_is_answered_next() {
+    let assumption = always(VerifyExpr::implies(
+        VerifyExpr::var("request"),
+        next(VerifyExpr::var("response")),
+    ));
+    let result = check_policy(&response_policy(), &[assumption], &bools(&["request", "response"]), 4);
+    assert!(matches!(result, BmcResult::Holds { bound: 4 }), "got: {:?}", result);
+}
+
+#[test]
+fn bmc_until_follows_from_its_witness() {
+    // busy U done, assuming busy now and done next
+    let policy = until(VerifyExpr::var("busy"), VerifyExpr::var("done"));
+    let assumptions = [
+        VerifyExpr::var("busy"),
+        next(VerifyExpr::var("done")),
+    ];
+    let result = check_policy(&policy, &assumptions, &bools(&["busy", "done"]), 3);
+    assert!(matches!(result, BmcResult::Holds { .. }), "got: {:?}", result);
+}
+
+#[test]
+fn bmc_rigid_variables_do_not_change_between_states() {
+    // An undeclared variable names the same value in every state, so
+    // "limit > 0 now" implies "limit > 0 always".
+    let positive = VerifyExpr::gt(VerifyExpr::var("limit"), VerifyExpr::int(0));
+    let result = check_policy(&always(positive.clone()), &[positive], &[], 3);
+    assert!(matches!(result, BmcResult::Holds { .. }), "got: {:?}", result);
+}
@@ -0,0 +1,105 @@
+//! Phase User Induction: Induction over Declared Types
+//!
+//! Tests for the induction principles derived for user inductives
+//! (`Tree_ind` next to `Tree_rec`) and for the literate `induction` tactic,
+//! which splits a goal into one bullet per constructor. Each bullet's tactics
+//! see the case with its arguments introduced and its induction hypotheses
+//! as `implies` premises.
+
+use logicaffeine_kernel::interface::Repl;
+
+const TREE: &str = "Inductive Tree := Leaf : Tree | Node : Tree -> Tree -> Tree.";
+
+/// Prove `statement` with the multi-line `proof` and return what the
+/// derivation concludes.
+fn conclude(repl: &mut Repl, statement: &str, proof: &[&str]) -> String {
+    let steps = proof.join("\n        ");
+    repl.execute(&format!("## Theorem: Goal\n    Statement: {statement}.\n    Proof:\n        {steps}"))
+        .unwrap();
+    repl.execute("Definition result : Syntax := concludes Goal.")
+        .unwrap();
+    repl.execute("Eval result.").unwrap()
+}
+
+fn tree_repl() -> Repl {
+    let mut repl = Repl::new();
+    repl.execute(TREE).unwrap();
+    repl
+}
+
+// =============================================================================
+// DERIVED INDUCTION PRINCIPLES
+// =============================================================================
+
+#[test]
+fn test_inductive_declares_induction_principle() {
+    let mut repl = tree_repl();
+    let result = repl.execute("Check Tree_ind.");
+    assert!(result.is_ok(), "Tree_ind should exist: {:?}", result);
+    let ty = result.unwrap();
+    assert!(ty.contains("Prop"), "Tree_ind's motive is a Prop: {ty}");
+}
+
+#[test]
+fn test_mutual_inductives_declare_induction_principles() {
+    let mut repl = Repl::new();
+    let block = vec![
+        "Inductive Even := EZero : Even | ESucc : Odd -> Even.".to_string(),
+        "Inductive Odd := OSucc : Even -> Odd.".to_string(),
+    ];
+    assert!(repl.execute_batch(&block).iter().all(|r| r.is_ok()));
+    assert!(repl.execute("Check Even_ind.").is_ok());
+    assert!(repl.execute("Check Odd_ind.").is_ok());
+}
+
+// =============================================================================
+// THE INDUCTION TACTIC
+// =============================================================================
+
+#[test]
+fn test_nat_induction_proves_forall() {
+    let mut repl = Repl::new();
+    let result = conclude(&mut repl, "(implies (Eq a b) (Eq (f a n) (f b n)))", &["induction n.", "- cc.", "- cc."]);
+    assert!(!result.contains("Error"), "both Nat cases close by cc: {result}");
+    assert!(result.contains("Forall") && result.contains("Nat"), "proves the statement for every n: {result}");
+}
+
+#[test]
+fn test_tree_induction_splits_per_constructor() {
+    let mut repl = tree_repl();
+    let result = conclude(
+        &mut repl,
+        "(implies (Eq a b) (Eq (g a t) (g b t)))",
+        &["induction t : Tree.", "- cc.", "- cc."],
+    );
+    assert!(!result.contains("Error"), "the Leaf and Node cases close by cc: {result}");
+    assert!(result.contains("Tree"), "proves the statement for every tree: {result}");
+}
+
+#[test]
+fn test_failed_case_fails_induction() {
+    let mut repl = tree_repl();
+    // cc cannot show g a t = g b t without a = b.
+    let result = conclude(&mut repl, "(Eq (g a t) (g b t))", &["induction t : Tree.", "- cc.", "- cc."]);
+    assert!(result.contains("Error"), "an unproved case sinks the induction: {result}");
+}
+
+#[test]
+fn test_missing_case_fails_induction() {
+    let mut repl = tree_repl();
+    let result = conclude(
+        &mut repl,
+        "(implies (Eq a b) (Eq (g a t) (g b t)))",
+        &["induction t : Tree.", "- cc."],
+    );
+    assert!(result.contains("Error"), "Node has no case: {result}");
+}
+
+#[test]
+fn test_induction_variable_must_occur() {
+    let mut repl = tree_repl();
+    let result = repl.execute(
+        "## Theorem: Goal\n    Statement: (Eq (g a) (g a)).\n    Proof:\n        induction t : Tree.\n        - cc.\n        - cc.",
+    );
+    assert!(result.is_err(), "t does not occur in the statement: {:?}", result);
+}
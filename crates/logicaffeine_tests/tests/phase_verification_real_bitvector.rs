@@ -0,0 +1,135 @@
+//! Real arithmetic and bitwise operations are verified, not declined.
+//!
+//! Float literals lower to exact rationals (`0.1` is one tenth) over Z3 reals, and
+//! arithmetic mixing `Int` and `Real` is promoted to real arithmetic. Bitwise
+//! operators on `Int` lower to 64-bit bitvectors with the runtime's `i64` semantics
+//! (shift counts wrap modulo 64, `>>` is arithmetic); a bitvector compared against
+//! an integer stands for the signed value it holds.
+//!
+//! Requires the `verification` feature (Z3).
+
+#![cfg(feature = "verification")]
+
+use logicaffeine_compile::compile::compile_to_rust_verified;
+use logicaffeine_verify::{BitVecOp, VerificationSession, VerifyExpr, VerifyOp, VerifyType};
+
+fn add(a: VerifyExpr, b: VerifyExpr) -> VerifyExpr {
+    VerifyExpr::binary(VerifyOp::Add, a, b)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REALS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn real_literals_are_exact_decimals() {
+    let session = VerificationSession::new();
+    let tenth = VerifyExpr::from_f64(0.1).unwrap();
+    let fifth = VerifyExpr::from_f64(0.2).unwrap();
+    let sum = add(tenth, fifth);
+    assert!(
+        session.verify(&VerifyExpr::eq(sum.clone(), VerifyExpr::from_f64(0.3).unwrap())).is_ok(),
+        "0.1 + 0.2 == 0.3 over the reals"
+    );
+    assert!(session.verify(&VerifyExpr::gt(sum, VerifyExpr::real(3, 10))).is_err());
+}
+
+#[test]
+fn real_variables_use_real_division() {
+    let mut session = VerificationSession::new();
+    session.declare("x", VerifyType::Real);
+    session.assume(&VerifyExpr::eq(VerifyExpr::var("x"), VerifyExpr::int(3)));
+    let half = VerifyExpr::binary(VerifyOp::Div, VerifyExpr::var("x"), VerifyExpr::int(2));
+    assert!(
+        session.verify(&VerifyExpr::eq(half.clone(), VerifyExpr::real(3, 2))).is_ok(),
+        "x / 2 is 1.5, not the integer quotient"
+    );
+    assert!(session.verify(&VerifyExpr::eq(half, VerifyExpr::int(1))).is_err());
+}
+
+#[test]
+fn integers_are_promoted_when_mixed_with_reals() {
+    let mut session = VerificationSession::new();
+    session.declare("n", VerifyType::Int);
+    session.declare("r", VerifyType::Real);
+    session.assume(&VerifyExpr::gt(VerifyExpr::var("n"), VerifyExpr::int(0)));
+    session.assume(&VerifyExpr::eq(VerifyExpr::var("r"), VerifyExpr::real(1, 2)));
+    let goal = VerifyExpr::gt(add(VerifyExpr::var("n"), VerifyExpr::var("r")), VerifyExpr::int(1));
+    assert!(session.verify(&goal).is_ok(), "n >= 1 and r = 0.5 give n + r > 1");
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// BITVECTORS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn bitvectors_compare_with_integers_as_signed_values() {
+    let mut session = VerificationSession::new();
+    session.declare("b", VerifyType::BitVector(8));
+    session.assume(&VerifyExpr::eq(VerifyExpr::var("b"), VerifyExpr::bv_const(8, 0xFF)));
+    assert!(session.verify(&VerifyExpr::lt(VerifyExpr::var("b"), VerifyExpr::int(0))).is_ok());
+    assert!(session.verify(&VerifyExpr::eq(VerifyExpr::var("b"), VerifyExpr::int(-1))).is_ok());
+}
+
+#[test]
+fn bitwise_ops_on_integers_use_64_bit_words() {
+    let session = VerificationSession::new();
+    let masked = VerifyExpr::bv_binary(BitVecOp::And, VerifyExpr::int(12), VerifyExpr::int(10));
+    assert!(session.verify(&VerifyExpr::eq(masked, VerifyExpr::int(8))).is_ok());
+
+    let mut session = VerificationSession::new();
+    session.declare("x", VerifyType::Int);
+    let low_nibble = VerifyExpr::bv_binary(BitVecOp::And, VerifyExpr::var("x"), VerifyExpr::int(15));
+    assert!(session.verify(&VerifyExpr::lt(low_nibble.clone(), VerifyExpr::int(16))).is_ok());
+    assert!(session.verify(&VerifyExpr::lt(low_nibble, VerifyExpr::int(15))).is_err());
+}
+
+#[test]
+fn bitvector_arithmetic_wraps() {
+    let mut session = VerificationSession::new();
+    session.declare("b", VerifyType::BitVector(8));
+    session.assume(&VerifyExpr::eq(VerifyExpr::var("b"), VerifyExpr::bv_const(8, 0x7F)));
+    let next = add(VerifyExpr::var("b"), VerifyExpr::bv_const(8, 1));
+    assert!(session.verify(&VerifyExpr::eq(next, VerifyExpr::bv_const(8, 0x80))).is_ok());
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// END TO END
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn real_refinements_are_verified() {
+    let ok = compile_to_rust_verified("## Main\nLet x: Real where it > 2 be 2.5.");
+    assert!(ok.is_ok(), "2.5 > 2: {:?}", ok.err());
+    let bad = compile_to_rust_verified("## Main\nLet x: Real where it > 3 be 2.5.");
+    assert!(bad.is_err(), "2.5 is not > 3");
+}
+
+#[test]
+fn real_variables_flow_into_refinements() {
+    let source = "## Main\nLet a be 0.5.\nLet b: Real where it < 1 be a + a.";
+    assert!(compile_to_rust_verified(source).is_err(), "0.5 + 0.5 is not < 1");
+    let source = "## Main\nLet a be 0.25.\nLet b: Real where it < 1 be a + a.";
+    let result = compile_to_rust_verified(source);
+    assert!(result.is_ok(), "0.25 + 0.25 < 1: {:?}", result.err());
+}
+
+#[test]
+fn bitwise_refinements_are_verified() {
+    let result = compile_to_rust_verified("## Main\nLet a be 1000.\nLet m: Int where it < 16 be a & 15.");
+    assert!(result.is_ok(), "a & 15 < 16: {:?}", result.err());
+    let result = compile_to_rust_verified("## Main\nLet a be 1000.\nLet m: Int where it < 8 be a & 15.");
+    assert!(result.is_err(), "1000 & 15 is 8");
+}
+
+#[test]
+fn shifts_follow_runtime_semantics() {
+    // `>>` is arithmetic: -8 >> 1 stays negative.
+    let source = "## Main\nLet a be 0 - 8.\nLet h: Int where it < 0 be a shifted right by 1.";
+    let result = compile_to_rust_verified(source);
+    assert!(result.is_ok(), "-8 >> 1 is -4: {:?}", result.err());
+    // Shift counts wrap modulo 64: 1 << 65 is 2.
+    let source = "## Main\nLet s: Int where it > 1 and it < 3 be 1 shifted left by 65.";
+    let result = compile_to_rust_verified(source);
+    assert!(result.is_ok(), "1 << 65 is 2: {:?}", result.err());
+}
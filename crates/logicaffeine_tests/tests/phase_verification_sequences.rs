@@ -0,0 +1,103 @@
+//! Sequence contents are verified.
+//!
+//! A `SeqOf(T)` is a Z3 array from `Int` to `T` plus a length, with positions
+//! running from 1 as in LOGOS `Seq`. A list literal fixes the length and every
+//! item, so claims such as "every item of results is positive" are provable.
+//!
+//! Requires the `verification` feature (Z3).
+
+#![cfg(feature = "verification")]
+
+use logicaffeine_compile::compile::compile_to_rust_verified;
+use logicaffeine_verify::{VerificationSession, VerifyExpr, VerifyType};
+
+fn ints() -> VerifyType {
+    VerifyType::SeqOf(Box::new(VerifyType::Int))
+}
+
+/// A session holding `results` with the given items.
+fn session_with(items: &[i64]) -> VerificationSession {
+    let mut session = VerificationSession::new();
+    session.declare("results", ints());
+    let results = VerifyExpr::var("results");
+    session.assume(&VerifyExpr::eq(
+        VerifyExpr::length(results.clone()),
+        VerifyExpr::int(items.len() as i64),
+    ));
+    for (position, item) in (1..).zip(items) {
+        let selected = VerifyExpr::select(results.clone(), VerifyExpr::int(position));
+        session.assume(&VerifyExpr::eq(selected, VerifyExpr::int(*item)));
+    }
+    session
+}
+
+fn every_item_positive() -> VerifyExpr {
+    VerifyExpr::every_item(VerifyExpr::var("results"), |item| VerifyExpr::gt(item, VerifyExpr::int(0)))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SESSION
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn every_item_of_a_known_sequence_is_proven() {
+    let session = session_with(&[1, 2, 3]);
+    assert!(session.verify(&every_item_positive()).is_ok());
+}
+
+#[test]
+fn every_item_is_refuted_by_one_bad_item() {
+    let session = session_with(&[1, 0, 3]);
+    assert!(session.verify(&every_item_positive()).is_err());
+}
+
+#[test]
+fn positions_outside_the_length_are_unconstrained() {
+    // Item 4 of a three-item sequence is unknown, not positive.
+    let session = session_with(&[1, 2, 3]);
+    let item_4 = VerifyExpr::select(VerifyExpr::var("results"), VerifyExpr::int(4));
+    assert!(session.verify(&VerifyExpr::gt(item_4, VerifyExpr::int(0))).is_err());
+}
+
+#[test]
+fn lengths_are_never_negative() {
+    let mut session = VerificationSession::new();
+    session.declare("results", ints());
+    let length = VerifyExpr::length(VerifyExpr::var("results"));
+    assert!(session.verify(&VerifyExpr::gte(length, VerifyExpr::int(0))).is_ok());
+}
+
+#[test]
+fn store_keeps_the_length_and_replaces_one_item() {
+    let session = session_with(&[1, 2, 3]);
+    let results = VerifyExpr::var("results");
+    let updated = VerifyExpr::store(results.clone(), VerifyExpr::int(2), VerifyExpr::int(9));
+    assert!(session
+        .verify(&VerifyExpr::eq(VerifyExpr::length(updated.clone()), VerifyExpr::int(3)))
+        .is_ok());
+    let item = |seq: VerifyExpr, i| VerifyExpr::select(seq, VerifyExpr::int(i));
+    assert!(session.verify(&VerifyExpr::eq(item(updated.clone(), 2), VerifyExpr::int(9))).is_ok());
+    assert!(session.verify(&VerifyExpr::eq(item(updated, 3), item(results, 3))).is_ok());
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// END TO END
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn list_literals_fix_length_and_items() {
+    let source = "## Main\nLet results be [1, 2, 3].\nLet n: Int where it > 2 be length of results.";
+    let result = compile_to_rust_verified(source);
+    assert!(result.is_ok(), "three items: {:?}", result.err());
+    let source = "## Main\nLet results be [1, 2, 3].\nLet n: Int where it > 3 be length of results.";
+    assert!(compile_to_rust_verified(source).is_err(), "only three items");
+}
+
+#[test]
+fn items_flow_into_refinements() {
+    let source = "## Main\nLet results be [5, 6, 7].\nLet first: Int where it > 4 be item 1 of results.";
+    let result = compile_to_rust_verified(source);
+    assert!(result.is_ok(), "item 1 is 5: {:?}", result.err());
+    let source = "## Main\nLet results be [5, 6, 7].\nLet last: Int where it < 7 be item 3 of results.";
+    assert!(compile_to_rust_verified(source).is_err(), "item 3 is 7");
+}
@@ -192,3 +192,50 @@ fn rustc_check_reports_nothing_for_a_clean_program() {
     let findings = rustc_check(GIVE_PROGRAM, &dir).expect("warm cargo check must run");
     assert!(findings.is_empty());
 }
+
+// ═══════════════════════════════════════════════════════════════════
+// Studio Rust preview
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn rust_preview_matches_compile_and_maps_sentences_to_lines() {
+    use logicaffeine_compile::{generate_rust_code, generate_rust_preview};
+
+    let preview = generate_rust_preview(GIVE_PROGRAM).expect("program must compile");
+    assert_eq!(preview.rust, generate_rust_code(GIVE_PROGRAM).unwrap());
+
+    let rust_lines: Vec<&str> = preview.rust.lines().collect();
+    // LOGOS line 5 is `Let a be 5.`, line 8 is `Show c.`
+    for (logos_line, needle) in [(5, "let a"), (8, "c")] {
+        let lines = preview.rust_lines_for(logos_line);
+        assert!(!lines.is_empty(), "LOGOS line {logos_line} maps to no Rust: {:?}", preview.origins);
+        assert!(
+            lines.iter().any(|&l| rust_lines[l as usize - 1].contains(needle)),
+            "LOGOS line {logos_line} maps to {lines:?}, none containing {needle:?}"
+        );
+        for line in lines {
+            let (first, last) = preview.logos_lines_for(line).unwrap();
+            assert!(first <= logos_line && logos_line <= last);
+        }
+    }
+    // The function definition owns its whole body: lines 1-2.
+    assert!(preview.origins.iter().any(|o| o.logos_first == 1 && o.logos_last == 2), "{:?}", preview.origins);
+    assert!(preview.rust_lines_for(4).is_empty(), "`## Main` itself emits nothing mapped");
+}
+
+#[test]
+fn rust_preview_lines_refer_to_the_unwrapped_source() {
+    use logicaffeine_compile::generate_rust_preview;
+
+    // No `## Main`: the source is wrapped before parsing, but the map still
+    // speaks in the lines the user typed.
+    let preview = generate_rust_preview("Let x be 41.\nShow x + 1.").expect("program must compile");
+    let rust_lines: Vec<&str> = preview.rust.lines().collect();
+    let show = preview.rust_lines_for(2);
+    assert!(
+        show.iter().any(|&l| rust_lines[l as usize - 1].contains("show(")),
+        "line 2 maps to {show:?}: {:?}",
+        show.iter().map(|&l| rust_lines[l as usize - 1]).collect::<Vec<_>>()
+    );
+    assert!(preview.origins.iter().all(|o| o.logos_last <= 2));
+}
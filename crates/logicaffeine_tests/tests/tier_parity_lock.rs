@@ -50,7 +50,8 @@ enum TierDisposition {
     /// Compile-time / verification / declaration — no runtime effect (struct & function
     /// declarations, `Assert`/`Trust`/`Require`/`Theorem`/`Definition`).
     DeclarationOrMeta,
-    /// Refused on the VM for soundness (escape analysis); tree-walker only.
+    /// Refused on the VM — for soundness (escape analysis), or because the VM doesn't drive it
+    /// yet (`Join the swarm`, `Log`, `Run the command`, `Sip the file`); tree-walker only.
     TreeWalkerOnly,
 }
 
@@ -88,8 +89,10 @@ fn every_statement_has_a_tier_disposition(s: &Stmt<'_>) -> TierDisposition {
         | Stmt::Axiom(_)
         | Stmt::Theory(_) => TierDisposition::DeclarationOrMeta,
 
-        // ── refused on the VM for soundness ──
-        Stmt::Escape { .. } => TierDisposition::TreeWalkerOnly,
+        // ── refused on the VM: for soundness, or not yet driven by the VM net runner ──
+        Stmt::Escape { .. } | Stmt::JoinSwarm { .. } | Stmt::Log { .. } | Stmt::RunCommand { .. } | Stmt::Sip { .. } => {
+            TierDisposition::TreeWalkerOnly
+        }
 
         // ── everything else: portable across tree-walker + VM ──
         // (`Splice` is parser-desugar output — a scope-transparent sequence of
@@ -179,11 +179,20 @@ fn replace_t_with_t1(expr: &VerifyExpr) -> VerifyExpr {
         VerifyExpr::Not(inner) => VerifyExpr::not(replace_t_with_t1(inner)),
         VerifyExpr::Bool(b) => VerifyExpr::Bool(*b),
         VerifyExpr::Int(n) => VerifyExpr::Int(*n),
+        VerifyExpr::RealConst { .. } => expr.clone(),
         VerifyExpr::Iff(l, r) => VerifyExpr::iff(replace_t_with_t1(l), replace_t_with_t1(r)),
         VerifyExpr::ForAll { vars, body } => VerifyExpr::forall(
             vars.clone(),
             replace_t_with_t1(body),
         ),
+        VerifyExpr::ForAllTriggered { vars, triggers, body } => VerifyExpr::forall_triggered(
+            vars.clone(),
+            triggers
+                .iter()
+                .map(|terms| terms.iter().map(|t| replace_t_with_t1(t)).collect())
+                .collect(),
+            replace_t_with_t1(body),
+        ),
         VerifyExpr::Exists { vars, body } => VerifyExpr::exists(
             vars.clone(),
             replace_t_with_t1(body),
@@ -220,6 +229,7 @@ fn replace_t_with_t1(expr: &VerifyExpr) -> VerifyExpr {
             index: Box::new(replace_t_with_t1(index)),
             value: Box::new(replace_t_with_t1(value)),
         },
+        VerifyExpr::Length(seq) => VerifyExpr::length(replace_t_with_t1(seq)),
         VerifyExpr::AtState { state, expr } => VerifyExpr::AtState {
             state: Box::new(replace_t_with_t1(state)),
             expr: Box::new(replace_t_with_t1(expr)),
@@ -424,7 +434,9 @@ fn collect_comparison_predicates(expr: &VerifyExpr, out: &mut Vec<VerifyExpr>) {
             collect_comparison_predicates(l, out);
             collect_comparison_predicates(r, out);
         }
-        VerifyExpr::ForAll { body, .. } | VerifyExpr::Exists { body, .. } => {
+        VerifyExpr::ForAll { body, .. }
+        | VerifyExpr::ForAllTriggered { body, .. }
+        | VerifyExpr::Exists { body, .. } => {
             collect_comparison_predicates(body, out);
         }
         _ => {}
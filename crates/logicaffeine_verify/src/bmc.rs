@@ -0,0 +1,396 @@
+//! Bounded Model Checking for Temporal Policies
+//!
+//! The verification pass maps temporal operators to uninterpreted functions
+//! (`Always(p)`, `Eventually(p)`, ...), so Z3 only ever sees them as opaque
+//! symbols. This module gives them their LTL meaning over bounded executions:
+//! [`check_policy`] unrolls a policy over traces of up to `bound + 1` world
+//! states and asks Z3 for one that violates it.
+//!
+//! A world state fixes every declared state variable and every predicate
+//! (`Apply`/`ApplyInt`) at one step; other free variables are rigid and name
+//! the same thing in every state. Traces are lasso-shaped — states `0..=k`,
+//! after which the execution returns to `loop_point` and repeats forever — so
+//! a reported violation of an "eventually" obligation is a genuine infinite
+//! run, not an artefact of stopping early.
+//!
+//! Temporal operators are recognised by name, in the shape the compiler
+//! produces:
+//!
+//! | Operator | Holds at step i when |
+//! |----------|----------------------|
+//! | `Always(p)` | p holds at every step from i on |
+//! | `Eventually(p)` | p holds at some step from i on |
+//! | `Future(p)` | p holds at some step after i |
+//! | `Next(p)` | p holds at the step after i |
+//! | `Past(p)` | p held at some step before i |
+//! | `Until(p, q)` | q holds at some step from i on, and p at every step before it |
+//! | `WeakUntil(p, q)` | `Until(p, q)`, or `Always(p)` |
+//! | `Release(p, q)` | q holds up to and including the first step where p holds, or forever |
+//!
+//! Shorter traces are tried first, so a reported trace is a shortest one.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use logicaffeine_verify::bmc::{always, check_policy, eventually, BmcResult};
+//! use logicaffeine_verify::{VerifyExpr, VerifyType};
+//!
+//! // After a request, a response eventually occurs.
+//! let policy = always(VerifyExpr::implies(
+//!     VerifyExpr::var("request"),
+//!     eventually(VerifyExpr::var("response")),
+//! ));
+//! let state = [("request".to_string(), VerifyType::Bool), ("response".to_string(), VerifyType::Bool)];
+//! match check_policy(&policy, &[], &state, 5) {
+//!     BmcResult::Violated { trace, loop_point } => {
+//!         // e.g. a request at step 0, then no response ever again
+//!         assert!(loop_point < trace.cycles.len());
+//!     }
+//!     other => panic!("expected a violation, got {:?}", other),
+//! }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use z3::SatResult;
+
+use crate::equivalence::{CycleState, SignalValue, Trace};
+use crate::ir::{VerifyExpr, VerifyType};
+use crate::solver::Encoder;
+
+/// Result of bounded model checking.
+#[derive(Debug)]
+pub enum BmcResult {
+    /// No trace of at most `bound + 1` states violates the policy.
+    Holds { bound: u32 },
+    /// A violating trace: the world states at steps `0..trace.cycles.len()`,
+    /// after which execution returns to `loop_point` and repeats forever.
+    Violated { trace: Trace, loop_point: usize },
+    /// Z3 could not decide one of the unrollings.
+    Unknown,
+}
+
+/// `Always(p)`: p holds from now on.
+pub fn always(p: VerifyExpr) -> VerifyExpr {
+    VerifyExpr::apply("Always", vec![p])
+}
+
+/// `Eventually(p)`: p holds now or at some later step.
+pub fn eventually(p: VerifyExpr) -> VerifyExpr {
+    VerifyExpr::apply("Eventually", vec![p])
+}
+
+/// `Next(p)`: p holds at the next step.
+pub fn next(p: VerifyExpr) -> VerifyExpr {
+    VerifyExpr::apply("Next", vec![p])
+}
+
+/// `Until(p, q)`: q eventually holds, and p holds until it does.
+pub fn until(p: VerifyExpr, q: VerifyExpr) -> VerifyExpr {
+    VerifyExpr::apply("Until", vec![p, q])
+}
+
+/// Search for a trace of at most `bound + 1` world states on which every
+/// assumption holds but `policy` does not.
+///
+/// Policy and assumptions are evaluated at step 0. `state` declares the
+/// variables that may change from one step to the next; undeclared free
+/// variables are rigid.
+pub fn check_policy(
+    policy: &VerifyExpr,
+    assumptions: &[VerifyExpr],
+    state: &[(String, VerifyType)],
+    bound: u32,
+) -> BmcResult {
+    let flexible: HashSet<&str> = state.iter().map(|(name, _)| name.as_str()).collect();
+
+    let mut atoms = Vec::new();
+    for expr in assumptions.iter().chain([policy]) {
+        collect_ground_atoms(expr, &HashSet::new(), &mut atoms);
+    }
+
+    for last in 0..=bound as usize {
+        let mut vars: HashMap<String, VerifyType> = HashMap::new();
+        for step in 0..=last {
+            for (name, ty) in state {
+                vars.insert(at_step(name, step), ty.clone());
+            }
+        }
+        let encoder = Encoder::new(&vars);
+        let solver = crate::solver::new_solver();
+
+        for loop_point in 0..=last {
+            let lasso = Lasso { last, loop_point, flexible: &flexible };
+            solver.push();
+            for assumption in assumptions {
+                if let Some(b) = encoder.encode(&lasso.unroll(assumption, 0)).as_bool() {
+                    solver.assert(&b);
+                }
+            }
+            if let Some(b) = encoder.encode(&lasso.unroll(policy, 0)).as_bool() {
+                solver.assert(&b.not());
+            }
+            match solver.check() {
+                SatResult::Sat => {
+                    let trace = extract_trace(&solver, &encoder, &lasso, state, &atoms);
+                    return BmcResult::Violated { trace, loop_point };
+                }
+                SatResult::Unknown => return BmcResult::Unknown,
+                SatResult::Unsat => {}
+            }
+            solver.pop(1);
+        }
+    }
+
+    BmcResult::Holds { bound }
+}
+
+/// A lasso-shaped execution: steps `0..=last`, then back to `loop_point`.
+struct Lasso<'a> {
+    last: usize,
+    loop_point: usize,
+    flexible: &'a HashSet<&'a str>,
+}
+
+impl Lasso<'_> {
+    fn successor(&self, step: usize) -> usize {
+        if step < self.last { step + 1 } else { self.loop_point }
+    }
+
+    /// Every step reachable from `step`, in the order the execution visits them.
+    fn path_from(&self, step: usize) -> Vec<usize> {
+        let mut path: Vec<usize> = (step..=self.last).collect();
+        path.extend(self.loop_point..step);
+        path
+    }
+
+    /// `expr` evaluated at `step`, with temporal operators expanded and
+    /// flexible symbols renamed to their copy for that step.
+    fn unroll(&self, expr: &VerifyExpr, step: usize) -> VerifyExpr {
+        self.unroll_bound(expr, step, &HashSet::new())
+    }
+
+    fn unroll_bound(&self, expr: &VerifyExpr, step: usize, bound: &HashSet<String>) -> VerifyExpr {
+        let u = |e: &VerifyExpr| self.unroll_bound(e, step, bound);
+        match expr {
+            VerifyExpr::Var(name) => {
+                if self.flexible.contains(name.as_str()) && !bound.contains(name) {
+                    VerifyExpr::var(at_step(name, step))
+                } else {
+                    expr.clone()
+                }
+            }
+            VerifyExpr::Int(_)
+            | VerifyExpr::RealConst { .. }
+            | VerifyExpr::Bool(_)
+            | VerifyExpr::BitVecConst { .. } => expr.clone(),
+
+            VerifyExpr::Apply { name, args } => {
+                if let Some(unrolled) = self.temporal(name, args, step, bound) {
+                    return unrolled;
+                }
+                VerifyExpr::apply(at_step(name, step), args.iter().map(u).collect())
+            }
+            VerifyExpr::ApplyInt { name, args } => {
+                VerifyExpr::apply_int(at_step(name, step), args.iter().map(u).collect())
+            }
+
+            VerifyExpr::Binary { op, left, right } => VerifyExpr::binary(*op, u(left), u(right)),
+            VerifyExpr::Not(inner) => VerifyExpr::not(u(inner)),
+            VerifyExpr::Iff(l, r) => VerifyExpr::iff(u(l), u(r)),
+
+            VerifyExpr::ForAll { vars, body } => {
+                VerifyExpr::forall(vars.clone(), self.unroll_bound(body, step, &bind(bound, vars)))
+            }
+            VerifyExpr::ForAllTriggered { vars, triggers, body } => {
+                let inner = bind(bound, vars);
+                let v = |e: &VerifyExpr| self.unroll_bound(e, step, &inner);
+                VerifyExpr::forall_triggered(
+                    vars.clone(),
+                    triggers.iter().map(|terms| terms.iter().map(v).collect()).collect(),
+                    v(body),
+                )
+            }
+            VerifyExpr::Exists { vars, body } => {
+                VerifyExpr::exists(vars.clone(), self.unroll_bound(body, step, &bind(bound, vars)))
+            }
+
+            VerifyExpr::BitVecBinary { op, left, right } => VerifyExpr::bv_binary(*op, u(left), u(right)),
+            VerifyExpr::BitVecExtract { high, low, operand } => VerifyExpr::BitVecExtract {
+                high: *high,
+                low: *low,
+                operand: Box::new(u(operand)),
+            },
+            VerifyExpr::BitVecConcat(l, r) => VerifyExpr::BitVecConcat(Box::new(u(l)), Box::new(u(r))),
+            VerifyExpr::AtState { state, expr } => VerifyExpr::AtState {
+                state: Box::new(u(state)),
+                expr: Box::new(u(expr)),
+            },
+            VerifyExpr::Transition { from, to } => VerifyExpr::Transition {
+                from: Box::new(u(from)),
+                to: Box::new(u(to)),
+            },
+            VerifyExpr::Select { array, index } => VerifyExpr::Select {
+                array: Box::new(u(array)),
+                index: Box::new(u(index)),
+            },
+            VerifyExpr::Store { array, index, value } => VerifyExpr::Store {
+                array: Box::new(u(array)),
+                index: Box::new(u(index)),
+                value: Box::new(u(value)),
+            },
+            VerifyExpr::Length(seq) => VerifyExpr::length(u(seq)),
+        }
+    }
+
+    /// Expand a temporal operator at `step`, or `None` if `name`/`args`
+    /// isn't one.
+    fn temporal(
+        &self,
+        name: &str,
+        args: &[VerifyExpr],
+        step: usize,
+        bound: &HashSet<String>,
+    ) -> Option<VerifyExpr> {
+        let at = |e: &VerifyExpr, s: usize| self.unroll_bound(e, s, bound);
+        let expanded = match (name, args) {
+            ("Always", [p]) => all(self.path_from(step).into_iter().map(|s| at(p, s))),
+            ("Eventually", [p]) => any(self.path_from(step).into_iter().map(|s| at(p, s))),
+            ("Future", [p]) => any(self.path_from(self.successor(step)).into_iter().map(|s| at(p, s))),
+            ("Next", [p]) => at(p, self.successor(step)),
+            ("Past", [p]) => any((0..step).map(|s| at(p, s))),
+            ("Until", [p, q]) => self.until(p, q, step, bound),
+            ("WeakUntil", [p, q]) => VerifyExpr::or(
+                self.until(p, q, step, bound),
+                all(self.path_from(step).into_iter().map(|s| at(p, s))),
+            ),
+            ("Release", [p, q]) => {
+                // Every q-step is covered, unless p released it earlier.
+                let path = self.path_from(step);
+                all((0..path.len()).map(|n| {
+                    VerifyExpr::or(at(q, path[n]), any(path[..n].iter().map(|&s| at(p, s))))
+                }))
+            }
+            _ => return None,
+        };
+        Some(expanded)
+    }
+
+    fn until(&self, p: &VerifyExpr, q: &VerifyExpr, step: usize, bound: &HashSet<String>) -> VerifyExpr {
+        let at = |e: &VerifyExpr, s: usize| self.unroll_bound(e, s, bound);
+        let path = self.path_from(step);
+        any((0..path.len()).map(|n| {
+            VerifyExpr::and(at(q, path[n]), all(path[..n].iter().map(|&s| at(p, s))))
+        }))
+    }
+}
+
+/// Name of `symbol`'s copy at `step`.
+fn at_step(symbol: &str, step: usize) -> String {
+    format!("{}@{}", symbol, step)
+}
+
+fn bind(bound: &HashSet<String>, vars: &[(String, VerifyType)]) -> HashSet<String> {
+    let mut inner = bound.clone();
+    inner.extend(vars.iter().map(|(name, _)| name.clone()));
+    inner
+}
+
+fn all(exprs: impl Iterator<Item = VerifyExpr>) -> VerifyExpr {
+    exprs.reduce(VerifyExpr::and).unwrap_or(VerifyExpr::bool(true))
+}
+
+fn any(exprs: impl Iterator<Item = VerifyExpr>) -> VerifyExpr {
+    exprs.reduce(VerifyExpr::or).unwrap_or(VerifyExpr::bool(false))
+}
+
+/// A predicate applied to constants, reportable as part of a world state.
+struct GroundAtom {
+    label: String,
+    name: String,
+    args: Vec<VerifyExpr>,
+}
+
+const TEMPORAL_OPERATORS: &[&str] =
+    &["Always", "Eventually", "Future", "Next", "Past", "Until", "WeakUntil", "Release"];
+
+/// Collect the predicate applications whose arguments mention no bound
+/// variable, e.g. `Request(alice)`.
+fn collect_ground_atoms(expr: &VerifyExpr, bound: &HashSet<String>, atoms: &mut Vec<GroundAtom>) {
+    match expr {
+        VerifyExpr::Apply { name, args } if !TEMPORAL_OPERATORS.contains(&name.as_str()) => {
+            let rendered: Option<Vec<String>> = args
+                .iter()
+                .map(|arg| match arg {
+                    VerifyExpr::Var(v) if !bound.contains(v) => Some(v.clone()),
+                    VerifyExpr::Int(n) => Some(n.to_string()),
+                    VerifyExpr::Bool(b) => Some(b.to_string()),
+                    _ => None,
+                })
+                .collect();
+            if let Some(rendered) = rendered {
+                let label = format!("{}({})", name, rendered.join(", "));
+                if !atoms.iter().any(|a| a.label == label) {
+                    atoms.push(GroundAtom { label, name: name.clone(), args: args.clone() });
+                }
+            }
+        }
+        VerifyExpr::Apply { args, .. } => {
+            for arg in args {
+                collect_ground_atoms(arg, bound, atoms);
+            }
+        }
+        VerifyExpr::Binary { left, right, .. } | VerifyExpr::Iff(left, right) => {
+            collect_ground_atoms(left, bound, atoms);
+            collect_ground_atoms(right, bound, atoms);
+        }
+        VerifyExpr::Not(inner) => collect_ground_atoms(inner, bound, atoms),
+        VerifyExpr::ForAll { vars, body }
+        | VerifyExpr::ForAllTriggered { vars, body, .. }
+        | VerifyExpr::Exists { vars, body } => collect_ground_atoms(body, &bind(bound, vars), atoms),
+        _ => {}
+    }
+}
+
+/// Read each step's world state out of the model.
+fn extract_trace(
+    solver: &z3::Solver,
+    encoder: &Encoder,
+    lasso: &Lasso,
+    state: &[(String, VerifyType)],
+    atoms: &[GroundAtom],
+) -> Trace {
+    let Some(model) = solver.get_model() else {
+        return Trace { cycles: vec![] };
+    };
+    let value_of = |expr: &VerifyExpr| {
+        let encoded = encoder.encode(expr);
+        match model.eval(&encoded, true) {
+            Some(v) => {
+                if let Some(b) = v.as_bool().and_then(|b| b.as_bool()) {
+                    SignalValue::Bool(b)
+                } else if let Some(n) = v.as_int().and_then(|n| n.as_i64()) {
+                    SignalValue::Int(n)
+                } else {
+                    SignalValue::Unknown
+                }
+            }
+            None => SignalValue::Unknown,
+        }
+    };
+
+    let cycles = (0..=lasso.last)
+        .map(|step| {
+            let mut signals = HashMap::new();
+            for (name, _) in state {
+                signals.insert(name.clone(), value_of(&VerifyExpr::var(at_step(name, step))));
+            }
+            for atom in atoms {
+                let args: Vec<VerifyExpr> = atom.args.iter().map(|a| lasso.unroll(a, step)).collect();
+                signals.insert(atom.label.clone(), value_of(&VerifyExpr::apply(at_step(&atom.name, step), args)));
+            }
+            CycleState { cycle: step, signals }
+        })
+        .collect();
+    Trace { cycles }
+}
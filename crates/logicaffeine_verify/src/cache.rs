@@ -0,0 +1,229 @@
+//! Persistent cache of proven obligations.
+//!
+//! A proof obligation is everything the solver sees for one check: the
+//! declared variables, the accumulated assumptions, an optional refinement
+//! binding, and the goal. [`obligation_key`] hashes that (plus this crate's
+//! version, so an encoder change invalidates old entries) with SHA-256, and an
+//! [`ObligationCache`] remembers which keys Z3 has already proven.
+//!
+//! Only proofs are cached. A failing or unknown obligation is re-checked on
+//! every run so its diagnostic (and any counterexample) is always current.
+//!
+//! # Examples
+//!
+//! ```
+//! use logicaffeine_verify::cache::{obligation_key, ObligationCache};
+//! use logicaffeine_verify::VerifyExpr;
+//!
+//! let goal = VerifyExpr::gt(VerifyExpr::var("x"), VerifyExpr::int(0));
+//! let key = obligation_key(&Default::default(), &[], None, &goal);
+//!
+//! let cache = ObligationCache::in_memory();
+//! assert!(!cache.is_proven(&key));
+//! cache.record_proven(&key);
+//! assert!(cache.is_proven(&key));
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::ir::{VerifyExpr, VerifyType};
+
+/// Bumped whenever the key derivation or file layout changes.
+const FORMAT_VERSION: u32 = 1;
+
+/// File name of the cache inside its directory.
+const CACHE_FILE: &str = "obligations.json";
+
+/// Hex SHA-256 identifying one proof obligation.
+pub type ObligationKey = String;
+
+/// Everything that determines an obligation's verdict, in a stable order.
+#[derive(Serialize)]
+struct Obligation<'a> {
+    format: u32,
+    encoder: &'static str,
+    vars: BTreeMap<&'a str, &'a VerifyType>,
+    assumptions: &'a [VerifyExpr],
+    binding: Option<(&'a str, &'a VerifyType, &'a VerifyExpr)>,
+    goal: &'a VerifyExpr,
+}
+
+/// Hash an obligation: declarations, assumptions, optional `name = value`
+/// binding, and goal.
+pub fn obligation_key(
+    vars: &HashMap<String, VerifyType>,
+    assumptions: &[VerifyExpr],
+    binding: Option<(&str, &VerifyType, &VerifyExpr)>,
+    goal: &VerifyExpr,
+) -> ObligationKey {
+    let obligation = Obligation {
+        format: FORMAT_VERSION,
+        encoder: env!("CARGO_PKG_VERSION"),
+        vars: vars.iter().map(|(name, ty)| (name.as_str(), ty)).collect(),
+        assumptions,
+        binding,
+        goal,
+    };
+    let bytes = serde_json::to_vec(&obligation).expect("verification IR always serializes");
+    Sha256::digest(&bytes).iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// On-disk form of the cache.
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    format: u32,
+    /// Obligation key → Unix time it was proven.
+    proven: BTreeMap<ObligationKey, u64>,
+}
+
+/// How a run used the cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Obligations answered from the cache.
+    pub hits: usize,
+    /// Obligations sent to the solver.
+    pub misses: usize,
+}
+
+/// Proven obligations, shared by every check in a run and optionally
+/// persisted to a directory between runs.
+///
+/// All methods take `&self`, so one cache can be shared (`Arc`) across
+/// sessions.
+#[derive(Debug, Default)]
+pub struct ObligationCache {
+    path: Option<PathBuf>,
+    proven: Mutex<BTreeMap<ObligationKey, u64>>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl ObligationCache {
+    /// A cache that lives only as long as the value.
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Open the cache stored in `dir`.
+    ///
+    /// A missing, unreadable or outdated file yields an empty cache — the
+    /// worst case is re-proving everything, never a wrong verdict.
+    pub fn open(dir: &Path) -> Self {
+        let path = dir.join(CACHE_FILE);
+        let proven = fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| serde_json::from_str::<CacheFile>(&text).ok())
+            .filter(|file| file.format == FORMAT_VERSION)
+            .map(|file| file.proven)
+            .unwrap_or_default();
+        Self { path: Some(path), proven: Mutex::new(proven), ..Self::default() }
+    }
+
+    /// Open the cache in `dir`, discarding whatever it held.
+    pub fn fresh(dir: &Path) -> Self {
+        Self { path: Some(dir.join(CACHE_FILE)), ..Self::default() }
+    }
+
+    /// Whether `key` was proven before. Counts towards [`stats`](Self::stats).
+    pub fn is_proven(&self, key: &str) -> bool {
+        let hit = self.proven.lock().unwrap().contains_key(key);
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        hit
+    }
+
+    /// Remember that `key` is proven.
+    pub fn record_proven(&self, key: &str) {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        self.proven.lock().unwrap().insert(key.to_string(), now);
+    }
+
+    /// Number of proven obligations held.
+    pub fn len(&self) -> usize {
+        self.proven.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Hits and misses since the cache was opened.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Write the cache back to its directory. A no-op for in-memory caches.
+    pub fn save(&self) -> std::io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let file = CacheFile { format: FORMAT_VERSION, proven: self.proven.lock().unwrap().clone() };
+        let json = serde_json::to_string(&file).map_err(std::io::Error::other)?;
+        // Write-then-rename so an interrupted run never leaves a torn file.
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn x_positive() -> VerifyExpr {
+        VerifyExpr::gt(VerifyExpr::var("x"), VerifyExpr::int(0))
+    }
+
+    #[test]
+    fn key_covers_assumptions_bindings_and_declarations() {
+        let vars: HashMap<_, _> = [("x".to_string(), VerifyType::Int)].into();
+        let assume = [VerifyExpr::eq(VerifyExpr::var("x"), VerifyExpr::int(3))];
+        let base = obligation_key(&vars, &assume, None, &x_positive());
+
+        assert_eq!(base, obligation_key(&vars, &assume, None, &x_positive()));
+        assert_ne!(base, obligation_key(&vars, &[], None, &x_positive()));
+        assert_ne!(base, obligation_key(&HashMap::new(), &assume, None, &x_positive()));
+        let five = VerifyExpr::int(5);
+        assert_ne!(base, obligation_key(&vars, &assume, Some(("x", &VerifyType::Int, &five)), &x_positive()));
+    }
+
+    #[test]
+    fn proofs_survive_a_save_and_fresh_forgets_them() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = obligation_key(&HashMap::new(), &[], None, &x_positive());
+
+        let cache = ObligationCache::open(dir.path());
+        assert!(!cache.is_proven(&key));
+        cache.record_proven(&key);
+        cache.save().unwrap();
+
+        let reopened = ObligationCache::open(dir.path());
+        assert!(reopened.is_proven(&key));
+        assert_eq!(reopened.stats(), CacheStats { hits: 1, misses: 0 });
+
+        assert!(ObligationCache::fresh(dir.path()).is_empty());
+    }
+
+    #[test]
+    fn a_corrupt_file_is_an_empty_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(CACHE_FILE), "{ not json").unwrap();
+        assert!(ObligationCache::open(dir.path()).is_empty());
+    }
+}
@@ -357,7 +357,7 @@ fn extract_mus(
 fn extract_antecedent(expr: &VerifyExpr) -> Option<&VerifyExpr> {
     match expr {
         VerifyExpr::Binary { op: VerifyOp::Implies, left, .. } => Some(left),
-        VerifyExpr::ForAll { body, .. } => extract_antecedent(body),
+        VerifyExpr::ForAll { body, .. } | VerifyExpr::ForAllTriggered { body, .. } => extract_antecedent(body),
         _ => None,
     }
 }
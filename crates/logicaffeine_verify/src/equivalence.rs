@@ -255,7 +255,7 @@ fn collect_vars(expr: &VerifyExpr, vars: &mut HashSet<String>) {
             collect_vars(l, vars);
             collect_vars(r, vars);
         }
-        VerifyExpr::ForAll { body, .. } => collect_vars(body, vars),
+        VerifyExpr::ForAll { body, .. } | VerifyExpr::ForAllTriggered { body, .. } => collect_vars(body, vars),
         VerifyExpr::Exists { body, .. } => collect_vars(body, vars),
         VerifyExpr::Apply { args, .. } | VerifyExpr::ApplyInt { args, .. } => {
             for arg in args { collect_vars(arg, vars); }
@@ -279,6 +279,7 @@ fn collect_vars(expr: &VerifyExpr, vars: &mut HashSet<String>) {
             collect_vars(index, vars);
             collect_vars(value, vars);
         }
+        VerifyExpr::Length(seq) => collect_vars(seq, vars),
         VerifyExpr::AtState { state, expr } => {
             collect_vars(state, vars);
             collect_vars(expr, vars);
@@ -288,7 +289,7 @@ fn collect_vars(expr: &VerifyExpr, vars: &mut HashSet<String>) {
             collect_vars(to, vars);
         }
         // Literals have no variables
-        VerifyExpr::Int(_) | VerifyExpr::Bool(_) | VerifyExpr::BitVecConst { .. } => {}
+        VerifyExpr::Int(_) | VerifyExpr::RealConst { .. } | VerifyExpr::Bool(_) | VerifyExpr::BitVecConst { .. } => {}
     }
 }
 
@@ -327,7 +328,9 @@ fn collect_int_vars(expr: &VerifyExpr, int_vars: &mut HashMap<String, Int>) {
             collect_int_vars(l, int_vars);
             collect_int_vars(r, int_vars);
         }
-        VerifyExpr::ForAll { body, .. } | VerifyExpr::Exists { body, .. } => {
+        VerifyExpr::ForAll { body, .. }
+        | VerifyExpr::ForAllTriggered { body, .. }
+        | VerifyExpr::Exists { body, .. } => {
             collect_int_vars(body, int_vars);
         }
         VerifyExpr::Apply { args, .. } | VerifyExpr::ApplyInt { args, .. } => {
@@ -381,7 +384,9 @@ fn collect_bv_vars(expr: &VerifyExpr, bv_vars: &mut HashMap<String, z3::ast::BV>
             collect_bv_vars(l, bv_vars);
             collect_bv_vars(r, bv_vars);
         }
-        VerifyExpr::ForAll { body, .. } | VerifyExpr::Exists { body, .. } => {
+        VerifyExpr::ForAll { body, .. }
+        | VerifyExpr::ForAllTriggered { body, .. }
+        | VerifyExpr::Exists { body, .. } => {
             collect_bv_vars(body, bv_vars);
         }
         VerifyExpr::Apply { args, .. } | VerifyExpr::ApplyInt { args, .. } => {
@@ -509,6 +514,18 @@ fn collect_array_vars_inner(expr: &VerifyExpr, array_vars: &mut HashMap<String,
             collect_array_vars_inner(index, array_vars);
             collect_array_vars_inner(value, array_vars);
         }
+        VerifyExpr::Length(seq) => {
+            if let VerifyExpr::Var(name) = seq.as_ref() {
+                if !array_vars.contains_key(name) {
+                    let int_sort = z3::Sort::int();
+                    array_vars.insert(
+                        name.clone(),
+                        z3::ast::Array::new_const(name.as_str(), &int_sort, &int_sort),
+                    );
+                }
+            }
+            collect_array_vars_inner(seq, array_vars);
+        }
         VerifyExpr::Binary { left, right, .. } => {
             collect_array_vars_inner(left, array_vars);
             collect_array_vars_inner(right, array_vars);
@@ -518,7 +535,9 @@ fn collect_array_vars_inner(expr: &VerifyExpr, array_vars: &mut HashMap<String,
             collect_array_vars_inner(l, array_vars);
             collect_array_vars_inner(r, array_vars);
         }
-        VerifyExpr::ForAll { body, .. } | VerifyExpr::Exists { body, .. } => {
+        VerifyExpr::ForAll { body, .. }
+        | VerifyExpr::ForAllTriggered { body, .. }
+        | VerifyExpr::Exists { body, .. } => {
             collect_array_vars_inner(body, array_vars);
         }
         _ => {}
@@ -549,7 +568,9 @@ fn collect_array_index_vars(expr: &VerifyExpr, int_vars: &mut HashMap<String, In
             collect_array_index_vars(l, int_vars);
             collect_array_index_vars(r, int_vars);
         }
-        VerifyExpr::ForAll { body, .. } | VerifyExpr::Exists { body, .. } => {
+        VerifyExpr::ForAll { body, .. }
+        | VerifyExpr::ForAllTriggered { body, .. }
+        | VerifyExpr::Exists { body, .. } => {
             collect_array_index_vars(body, int_vars);
         }
         _ => {}
@@ -564,7 +585,9 @@ fn collect_quantifier_bound_vars(
     array_vars: &mut HashMap<String, z3::ast::Array>,
 ) {
     match expr {
-        VerifyExpr::ForAll { vars, body } | VerifyExpr::Exists { vars, body } => {
+        VerifyExpr::ForAll { vars, body }
+        | VerifyExpr::ForAllTriggered { vars, body, .. }
+        | VerifyExpr::Exists { vars, body } => {
             for (name, ty) in vars {
                 match ty {
                     VerifyType::Int | VerifyType::Object | VerifyType::Real => {
@@ -577,10 +600,11 @@ fn collect_quantifier_bound_vars(
                             bv_vars.insert(name.clone(), z3::ast::BV::new_const(name.as_str(), *w));
                         }
                     }
-                    VerifyType::Array(idx, elem) => {
+                    VerifyType::Array(..) | VerifyType::SeqOf(_) => {
                         if !array_vars.contains_key(name) {
-                            let idx_sort = type_to_z3_sort(idx);
-                            let elem_sort = type_to_z3_sort(elem);
+                            let (idx, elem) = array_parts(ty);
+                            let idx_sort = type_to_z3_sort(&idx);
+                            let elem_sort = type_to_z3_sort(&elem);
                             array_vars.insert(
                                 name.clone(),
                                 z3::ast::Array::new_const(name.as_str(), &idx_sort, &elem_sort),
@@ -609,6 +633,16 @@ fn collect_quantifier_bound_vars(
     }
 }
 
+/// Index and element types of an array-like type (a sequence is an
+/// `Int`-indexed array).
+fn array_parts(ty: &VerifyType) -> (VerifyType, VerifyType) {
+    match ty {
+        VerifyType::Array(idx, elem) => ((**idx).clone(), (**elem).clone()),
+        VerifyType::SeqOf(elem) => (VerifyType::Int, (**elem).clone()),
+        _ => unreachable!("{:?} is not array-like", ty),
+    }
+}
+
 /// Convert a VerifyType to a Z3 Sort (standalone function).
 fn type_to_z3_sort(ty: &VerifyType) -> z3::Sort {
     match ty {
@@ -622,6 +656,7 @@ fn type_to_z3_sort(ty: &VerifyType) -> z3::Sort {
             let elem_sort = type_to_z3_sort(elem);
             z3::Sort::array(&idx_sort, &elem_sort)
         }
+        VerifyType::SeqOf(elem) => z3::Sort::array(&z3::Sort::int(), &type_to_z3_sort(elem)),
     }
 }
 
@@ -811,8 +846,9 @@ impl<'a> EquivEncoder<'a> {
                 func_decl.apply(&arg_refs)
             }
 
-            // Quantifiers: proper Z3 quantifier encoding
-            VerifyExpr::ForAll { vars, body } => {
+            // Quantifiers: proper Z3 quantifier encoding. Triggers are only
+            // instantiation hints, so a triggered forall encodes as a plain one here.
+            VerifyExpr::ForAll { vars, body } | VerifyExpr::ForAllTriggered { vars, body, .. } => {
                 if vars.is_empty() {
                     return self.encode(body);
                 }
@@ -890,6 +926,14 @@ impl<'a> EquivEncoder<'a> {
                 }
             }
 
+            VerifyExpr::Length(seq) => {
+                let mut root = seq.as_ref();
+                while let VerifyExpr::Store { array, .. } = root {
+                    root = array;
+                }
+                crate::solver::seq_length(&self.encode(root))
+            }
+
             // ---- Temporal (BMC) ----
 
             VerifyExpr::AtState { expr, .. } => {
@@ -921,6 +965,10 @@ impl<'a> EquivEncoder<'a> {
                 let elem_sort = self.type_to_sort(elem);
                 Dynamic::from_ast(&z3::ast::Array::new_const(name, &idx_sort, &elem_sort))
             }
+            VerifyType::SeqOf(elem) => {
+                let elem_sort = self.type_to_sort(elem);
+                Dynamic::from_ast(&z3::ast::Array::new_const(name, &z3::Sort::int(), &elem_sort))
+            }
         }
     }
 
@@ -937,6 +985,7 @@ impl<'a> EquivEncoder<'a> {
                 let elem_sort = self.type_to_sort(elem);
                 z3::Sort::array(&idx_sort, &elem_sort)
             }
+            VerifyType::SeqOf(elem) => z3::Sort::array(&z3::Sort::int(), &self.type_to_sort(elem)),
         }
     }
 
@@ -19,6 +19,7 @@
 //! | Solver | `SolverUnknown`, `SolverError` | Simplify or restructure |
 //! | Termination | `TerminationViolation` | Add decreasing variant |
 
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// Result type for verification operations.
@@ -35,6 +36,7 @@ pub type VerificationResult<T = ()> = Result<T, VerificationError>;
 /// - `span`: Source location (byte offsets) where the error occurred
 /// - `explanation`: Human-readable context explaining why verification failed
 /// - `counterexample`: Concrete variable assignments that demonstrate the failure
+/// - `statistics`: Solver counters, attached when Z3 answers unknown
 #[derive(Debug)]
 pub struct VerificationError {
     /// The category of verification error.
@@ -45,6 +47,8 @@ pub struct VerificationError {
     pub explanation: String,
     /// Concrete witness showing a failing case, when available.
     pub counterexample: Option<CounterExample>,
+    /// Z3's statistics for the failed check, when it answered unknown.
+    pub statistics: Option<SolverStatistics>,
 }
 
 /// The category of verification error.
@@ -181,6 +185,38 @@ impl fmt::Display for CounterExample {
     }
 }
 
+/// Z3's counters for one check, by name (`"quant instantiations"`,
+/// `"conflicts"`, `"time"`, ...).
+///
+/// Attached to [`VerificationErrorKind::SolverUnknown`] errors: a large
+/// instantiation count usually means a quantified axiom is matching too
+/// eagerly and wants a selective trigger (see
+/// [`VerifyExpr::forall_triggered`](crate::VerifyExpr::forall_triggered)).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SolverStatistics {
+    pub entries: BTreeMap<String, f64>,
+}
+
+impl SolverStatistics {
+    /// How many times Z3 instantiated quantified axioms.
+    pub fn quantifier_instantiations(&self) -> u64 {
+        self.entries.get("quant instantiations").copied().unwrap_or_default() as u64
+    }
+}
+
+impl fmt::Display for SolverStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (key, value) in &self.entries {
+            if value.fract() == 0.0 {
+                writeln!(f, "  {:<24} {}", key, value)?;
+            } else {
+                writeln!(f, "  {:<24} {:.3}", key, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl fmt::Display for VerificationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.kind {
@@ -223,6 +259,19 @@ impl fmt::Display for VerificationError {
                 writeln!(f, "The solver could not determine if the assertion is valid.")?;
                 writeln!(f)?;
                 writeln!(f, "This may be due to complexity or timeout.")?;
+                if let Some(stats) = &self.statistics {
+                    writeln!(f)?;
+                    writeln!(f, "Solver statistics:")?;
+                    write!(f, "{}", stats)?;
+                    if stats.quantifier_instantiations() > 0 {
+                        writeln!(f)?;
+                        writeln!(
+                            f,
+                            "{} quantifier instantiations: consider giving quantified axioms explicit triggers.",
+                            stats.quantifier_instantiations()
+                        )?;
+                    }
+                }
             }
             VerificationErrorKind::SolverError { message } => {
                 writeln!(f, "Solver error: {}", message)?;
@@ -251,6 +300,7 @@ impl VerificationError {
             span: None,
             explanation: String::new(),
             counterexample: None,
+            statistics: None,
         }
     }
 
@@ -265,6 +315,7 @@ impl VerificationError {
             span: None,
             explanation: String::new(),
             counterexample: None,
+            statistics: None,
         }
     }
 
@@ -279,6 +330,7 @@ impl VerificationError {
             span: None,
             explanation: String::new(),
             counterexample: None,
+            statistics: None,
         }
     }
 
@@ -293,6 +345,7 @@ impl VerificationError {
             span: None,
             explanation: explanation.into(),
             counterexample,
+            statistics: None,
         }
     }
 
@@ -313,6 +366,7 @@ impl VerificationError {
             span: None,
             explanation: String::new(),
             counterexample: None,
+            statistics: None,
         }
     }
 
@@ -327,6 +381,7 @@ impl VerificationError {
             span: None,
             explanation: explanation.into(),
             counterexample: None,
+            statistics: None,
         }
     }
 
@@ -341,6 +396,7 @@ impl VerificationError {
             span: None,
             explanation: String::new(),
             counterexample: None,
+            statistics: None,
         }
     }
 
@@ -355,6 +411,7 @@ impl VerificationError {
             span: None,
             explanation: String::new(),
             counterexample: None,
+            statistics: None,
         }
     }
 
@@ -372,6 +429,7 @@ impl VerificationError {
             span: None,
             explanation: String::new(),
             counterexample: None,
+            statistics: None,
         }
     }
 
@@ -384,4 +442,10 @@ impl VerificationError {
         self.span = Some((start, end));
         self
     }
+
+    /// Attach the solver statistics gathered for this check.
+    pub fn with_statistics(mut self, statistics: SolverStatistics) -> Self {
+        self.statistics = Some(statistics);
+        self
+    }
 }
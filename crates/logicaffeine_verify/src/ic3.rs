@@ -208,7 +208,9 @@ fn collect_all_vars(expr: &VerifyExpr, vars: &mut HashSet<String>) {
             collect_all_vars(l, vars);
             collect_all_vars(r, vars);
         }
-        VerifyExpr::ForAll { body, .. } | VerifyExpr::Exists { body, .. } => {
+        VerifyExpr::ForAll { body, .. }
+        | VerifyExpr::ForAllTriggered { body, .. }
+        | VerifyExpr::Exists { body, .. } => {
             collect_all_vars(body, vars);
         }
         _ => {}
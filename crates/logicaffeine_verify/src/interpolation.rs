@@ -199,7 +199,9 @@ fn collect_vars_recursive(expr: &VerifyExpr, vars: &mut HashSet<String>) {
             collect_vars_recursive(l, vars);
             collect_vars_recursive(r, vars);
         }
-        VerifyExpr::ForAll { body, .. } | VerifyExpr::Exists { body, .. } => {
+        VerifyExpr::ForAll { body, .. }
+        | VerifyExpr::ForAllTriggered { body, .. }
+        | VerifyExpr::Exists { body, .. } => {
             collect_vars_recursive(body, vars);
         }
         _ => {}
@@ -50,6 +50,9 @@ pub enum VerifyType {
     Array(Box<VerifyType>, Box<VerifyType>),
     /// Real number type, maps to Z3 `RealSort` (IEEE 1800-2023).
     Real,
+    /// Finite sequence of elements, maps to Z3 `ArraySort(Int, T)` plus a
+    /// length. Positions run from 1 to the length, as in LOGOS `Seq`.
+    SeqOf(Box<VerifyType>),
 }
 
 /// Binary operations in the verification IR.
@@ -58,6 +61,10 @@ pub enum VerifyType {
 /// - **Arithmetic**: `Add`, `Sub`, `Mul`, `Div` (Int × Int → Int)
 /// - **Comparison**: `Eq`, `Neq`, `Gt`, `Lt`, `Gte`, `Lte` (Int × Int → Bool)
 /// - **Logic**: `And`, `Or`, `Implies` (Bool × Bool → Bool)
+///
+/// Arithmetic and comparison also accept `Real` and bitvector operands. Mixed
+/// operands meet in the wider sort: `Int` with `Real` is real arithmetic, and
+/// a bitvector beside an `Int` stands for the signed integer it holds.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum VerifyOp {
     // ---- Arithmetic (Int × Int → Int) ----
@@ -140,6 +147,9 @@ pub enum BitVecOp {
     Eq,
 }
 
+/// Bound position variable of [`VerifyExpr::every_item`].
+const ITEM_INDEX: &str = "__item";
+
 /// Expression AST for verification.
 ///
 /// This IR is designed to be easily encodable into Z3 ASTs.
@@ -148,6 +158,9 @@ pub enum VerifyExpr {
     /// Integer literal
     Int(i64),
 
+    /// Real literal `num / den` (exact rational, `den > 0`)
+    RealConst { num: i64, den: i64 },
+
     /// Boolean literal
     Bool(bool),
 
@@ -170,6 +183,19 @@ pub enum VerifyExpr {
         body: Box<VerifyExpr>,
     },
 
+    /// Universal quantifier with instantiation triggers: forall x: T. P(x),
+    /// instantiated only for ground terms matching one of `triggers`.
+    ///
+    /// Each trigger is a multi-pattern: function applications over the bound
+    /// variables that together mention every one of them. Z3 instantiates
+    /// the axiom once per match, so a selective trigger keeps quantified
+    /// axioms from flooding the solver. See [`VerifyExpr::forall_triggered`].
+    ForAllTriggered {
+        vars: Vec<(String, VerifyType)>,
+        triggers: Vec<Vec<VerifyExpr>>,
+        body: Box<VerifyExpr>,
+    },
+
     /// Existential quantifier: exists x: T. P(x)
     Exists {
         vars: Vec<(String, VerifyType)>,
@@ -251,6 +277,10 @@ pub enum VerifyExpr {
         value: Box<VerifyExpr>,
     },
 
+    /// Number of elements of a [`VerifyType::SeqOf`] sequence. Never
+    /// negative, and unchanged by [`Store`](VerifyExpr::Store).
+    Length(Box<VerifyExpr>),
+
     // ---- Biconditional (equivalence checking) ----
 
     /// Biconditional: left ↔ right. Used for Z3 equivalence queries.
@@ -286,6 +316,54 @@ impl VerifyExpr {
         VerifyExpr::Int(n)
     }
 
+    /// Create a real literal `num / den`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use logicaffeine_verify::VerifyExpr;
+    ///
+    /// let half = VerifyExpr::real(1, 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero.
+    pub fn real(num: i64, den: i64) -> Self {
+        assert!(den != 0, "real literal with zero denominator");
+        if den < 0 {
+            VerifyExpr::RealConst { num: -num, den: -den }
+        } else {
+            VerifyExpr::RealConst { num, den }
+        }
+    }
+
+    /// Create the real literal a float denotes, read as the decimal it
+    /// prints as — `0.1` is exactly one tenth, not the nearest binary
+    /// fraction.
+    ///
+    /// Returns `None` for NaN, infinities, and values whose decimal doesn't
+    /// fit an `i64` numerator and power-of-ten denominator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use logicaffeine_verify::VerifyExpr;
+    ///
+    /// assert_eq!(VerifyExpr::from_f64(2.5), Some(VerifyExpr::real(25, 10)));
+    /// assert_eq!(VerifyExpr::from_f64(f64::NAN), None);
+    /// ```
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        let printed = value.abs().to_string();
+        let (whole, frac) = printed.split_once('.').unwrap_or((&printed, ""));
+        let den = 10i64.checked_pow(frac.len() as u32)?;
+        let num: i64 = format!("{whole}{frac}").parse().ok()?;
+        Some(VerifyExpr::real(if value < 0.0 { -num } else { num }, den))
+    }
+
     /// Create a boolean literal.
     ///
     /// # Examples
@@ -407,6 +485,41 @@ impl VerifyExpr {
         }
     }
 
+    /// Create a universal quantifier that Z3 instantiates only on terms
+    /// matching one of `triggers`.
+    ///
+    /// Use it for axioms whose plain [`forall`](Self::forall) form makes the
+    /// solver answer unknown: the trigger says which ground terms are worth
+    /// instantiating the axiom for. Each trigger is a list of function
+    /// applications that together mention every bound variable;
+    /// [`trigger_error`](Self::trigger_error) reports ones that don't.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use logicaffeine_verify::{VerifyExpr, VerifyType};
+    ///
+    /// // ∀x: Int. f(x) > x, instantiated for every f(t) the proof mentions
+    /// let f_x = VerifyExpr::apply_int("f", vec![VerifyExpr::var("x")]);
+    /// let grows = VerifyExpr::forall_triggered(
+    ///     vec![("x".to_string(), VerifyType::Int)],
+    ///     vec![vec![f_x.clone()]],
+    ///     VerifyExpr::gt(f_x, VerifyExpr::var("x")),
+    /// );
+    /// assert!(grows.trigger_error().is_none());
+    /// ```
+    pub fn forall_triggered(
+        vars: Vec<(String, VerifyType)>,
+        triggers: Vec<Vec<VerifyExpr>>,
+        body: VerifyExpr,
+    ) -> Self {
+        VerifyExpr::ForAllTriggered {
+            vars,
+            triggers,
+            body: Box::new(body),
+        }
+    }
+
     /// Create an existential quantifier.
     ///
     /// # Examples
@@ -558,6 +671,55 @@ impl VerifyExpr {
         Self::binary(VerifyOp::Implies, left, right)
     }
 
+    // ---- Array and sequence convenience constructors ----
+
+    /// Create an array or sequence read: `array[index]`.
+    pub fn select(array: VerifyExpr, index: VerifyExpr) -> Self {
+        VerifyExpr::Select {
+            array: Box::new(array),
+            index: Box::new(index),
+        }
+    }
+
+    /// Create an array or sequence update: `array` with `index` set to `value`.
+    pub fn store(array: VerifyExpr, index: VerifyExpr, value: VerifyExpr) -> Self {
+        VerifyExpr::Store {
+            array: Box::new(array),
+            index: Box::new(index),
+            value: Box::new(value),
+        }
+    }
+
+    /// Create the length of a sequence.
+    pub fn length(seq: VerifyExpr) -> Self {
+        VerifyExpr::Length(Box::new(seq))
+    }
+
+    /// Every element of `seq` satisfies `predicate`:
+    /// `forall i: Int. 1 <= i <= length(seq) -> predicate(seq[i])`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use logicaffeine_verify::VerifyExpr;
+    ///
+    /// // every item of results is positive
+    /// let all_positive = VerifyExpr::every_item(VerifyExpr::var("results"), |item| {
+    ///     VerifyExpr::gt(item, VerifyExpr::int(0))
+    /// });
+    /// ```
+    pub fn every_item(seq: VerifyExpr, predicate: impl FnOnce(VerifyExpr) -> VerifyExpr) -> Self {
+        let i = VerifyExpr::var(ITEM_INDEX);
+        let in_bounds = VerifyExpr::and(
+            VerifyExpr::gte(i.clone(), VerifyExpr::int(1)),
+            VerifyExpr::lte(i.clone(), VerifyExpr::length(seq.clone())),
+        );
+        VerifyExpr::forall(
+            vec![(ITEM_INDEX.to_string(), VerifyType::Int)],
+            VerifyExpr::implies(in_bounds, predicate(VerifyExpr::select(seq, i))),
+        )
+    }
+
     // ---- Bitvector convenience constructors ----
 
     /// Create a bitvector constant.
@@ -578,6 +740,98 @@ impl VerifyExpr {
     pub fn iff(left: VerifyExpr, right: VerifyExpr) -> Self {
         VerifyExpr::Iff(Box::new(left), Box::new(right))
     }
+
+    // ---- Trigger validation ----
+
+    /// Why a trigger anywhere in this expression can't be used, if one can't.
+    ///
+    /// Z3 only accepts patterns that are function applications ([`Apply`],
+    /// [`ApplyInt`] or [`Select`]) and, taken together, mention every bound
+    /// variable of their quantifier.
+    ///
+    /// [`Apply`]: VerifyExpr::Apply
+    /// [`ApplyInt`]: VerifyExpr::ApplyInt
+    /// [`Select`]: VerifyExpr::Select
+    pub fn trigger_error(&self) -> Option<String> {
+        if let VerifyExpr::ForAllTriggered { vars, triggers, .. } = self {
+            if triggers.is_empty() {
+                return Some("a triggered quantifier needs at least one trigger".to_string());
+            }
+            for trigger in triggers {
+                if let Some(term) = trigger.iter().find(|t| {
+                    !matches!(t, VerifyExpr::Apply { .. } | VerifyExpr::ApplyInt { .. } | VerifyExpr::Select { .. })
+                }) {
+                    return Some(format!("trigger term {:?} is not a function application", term));
+                }
+                if let Some((name, _)) = vars.iter().find(|(name, _)| !trigger.iter().any(|t| t.mentions(name))) {
+                    return Some(format!("trigger {:?} does not mention bound variable '{}'", trigger, name));
+                }
+            }
+        }
+        let mut error = None;
+        self.for_each_child(&mut |child| {
+            if error.is_none() {
+                error = child.trigger_error();
+            }
+        });
+        error
+    }
+
+    /// Whether the variable `name` occurs anywhere in this expression.
+    fn mentions(&self, name: &str) -> bool {
+        if matches!(self, VerifyExpr::Var(v) if v == name) {
+            return true;
+        }
+        let mut found = false;
+        self.for_each_child(&mut |child| found = found || child.mentions(name));
+        found
+    }
+
+    /// Call `f` on each direct subexpression (trigger terms included).
+    fn for_each_child(&self, f: &mut impl FnMut(&VerifyExpr)) {
+        match self {
+            VerifyExpr::Int(_)
+            | VerifyExpr::RealConst { .. }
+            | VerifyExpr::Bool(_)
+            | VerifyExpr::Var(_)
+            | VerifyExpr::BitVecConst { .. } => {}
+            VerifyExpr::Binary { left, right, .. }
+            | VerifyExpr::BitVecBinary { left, right, .. }
+            | VerifyExpr::BitVecConcat(left, right)
+            | VerifyExpr::Iff(left, right) => {
+                f(left);
+                f(right);
+            }
+            VerifyExpr::Not(inner)
+            | VerifyExpr::BitVecExtract { operand: inner, .. }
+            | VerifyExpr::Length(inner) => f(inner),
+            VerifyExpr::ForAll { body, .. } | VerifyExpr::Exists { body, .. } => f(body),
+            VerifyExpr::ForAllTriggered { triggers, body, .. } => {
+                triggers.iter().flatten().for_each(&mut *f);
+                f(body);
+            }
+            VerifyExpr::Apply { args, .. } | VerifyExpr::ApplyInt { args, .. } => {
+                args.iter().for_each(f)
+            }
+            VerifyExpr::AtState { state, expr } => {
+                f(state);
+                f(expr);
+            }
+            VerifyExpr::Transition { from, to } => {
+                f(from);
+                f(to);
+            }
+            VerifyExpr::Select { array, index } => {
+                f(array);
+                f(index);
+            }
+            VerifyExpr::Store { array, index, value } => {
+                f(array);
+                f(index);
+                f(value);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -631,4 +885,22 @@ mod tests {
 
         assert!(matches!(forall, VerifyExpr::ForAll { vars, .. } if vars.len() == 1));
     }
+
+    #[test]
+    fn triggers_must_be_applications_covering_every_bound_variable() {
+        let vars = vec![("x".to_string(), VerifyType::Int), ("y".to_string(), VerifyType::Int)];
+        let f_x = VerifyExpr::apply_int("f", vec![VerifyExpr::var("x")]);
+        let g_xy = VerifyExpr::apply("g", vec![VerifyExpr::var("x"), VerifyExpr::var("y")]);
+        let body = VerifyExpr::bool(true);
+
+        let covered = VerifyExpr::forall_triggered(vars.clone(), vec![vec![g_xy]], body.clone());
+        assert_eq!(covered.trigger_error(), None);
+
+        let partial = VerifyExpr::forall_triggered(vars.clone(), vec![vec![f_x]], body.clone());
+        assert!(partial.trigger_error().unwrap().contains("'y'"));
+
+        let bare = VerifyExpr::forall_triggered(vars, vec![vec![VerifyExpr::var("x")]], body);
+        let nested = VerifyExpr::not(bare);
+        assert!(nested.trigger_error().unwrap().contains("not a function application"));
+    }
 }
@@ -184,6 +184,7 @@ fn rename_timestep(expr: &VerifyExpr, suffix: &str, step: u32) -> VerifyExpr {
         VerifyExpr::Not(inner) => VerifyExpr::not(rename_timestep(inner, suffix, step)),
         VerifyExpr::Bool(b) => VerifyExpr::Bool(*b),
         VerifyExpr::Int(n) => VerifyExpr::Int(*n),
+        VerifyExpr::RealConst { .. } => expr.clone(),
         VerifyExpr::Iff(l, r) => VerifyExpr::iff(
             rename_timestep(l, suffix, step),
             rename_timestep(r, suffix, step),
@@ -192,6 +193,14 @@ fn rename_timestep(expr: &VerifyExpr, suffix: &str, step: u32) -> VerifyExpr {
             vars.clone(),
             rename_timestep(body, suffix, step),
         ),
+        VerifyExpr::ForAllTriggered { vars, triggers, body } => VerifyExpr::forall_triggered(
+            vars.clone(),
+            triggers
+                .iter()
+                .map(|terms| terms.iter().map(|t| rename_timestep(t, suffix, step)).collect())
+                .collect(),
+            rename_timestep(body, suffix, step),
+        ),
         VerifyExpr::Exists { vars, body } => VerifyExpr::exists(
             vars.clone(),
             rename_timestep(body, suffix, step),
@@ -228,6 +237,7 @@ fn rename_timestep(expr: &VerifyExpr, suffix: &str, step: u32) -> VerifyExpr {
             index: Box::new(rename_timestep(index, suffix, step)),
             value: Box::new(rename_timestep(value, suffix, step)),
         },
+        VerifyExpr::Length(seq) => VerifyExpr::length(rename_timestep(seq, suffix, step)),
         VerifyExpr::AtState { state, expr } => VerifyExpr::AtState {
             state: Box::new(rename_timestep(state, suffix, step)),
             expr: Box::new(rename_timestep(expr, suffix, step)),
@@ -345,7 +355,7 @@ pub fn encode_expr_bool(
             let rb = encode_expr_bool(r, bool_vars, int_vars);
             lb.iff(&rb)
         }
-        VerifyExpr::ForAll { vars, body } => {
+        VerifyExpr::ForAll { vars, body } | VerifyExpr::ForAllTriggered { vars, body, .. } => {
             if vars.is_empty() {
                 return encode_expr_bool(body, bool_vars, int_vars);
             }
@@ -18,11 +18,14 @@ pub mod security;
 pub mod multiclock;
 pub mod parameterized;
 pub mod smtlib;
+pub mod cache;
+pub mod schedule;
 pub mod certificate;
 pub mod incremental;
 pub mod abstraction;
 pub mod automata;
 pub mod synthesis;
+pub mod bmc;
 
 pub use equivalence::{check_equivalence, EquivalenceResult, Trace, CycleState, SignalValue};
 pub use consistency::{
@@ -31,7 +34,8 @@ pub use consistency::{
     LabeledFormula, SatisfiabilityResult,
     VacuityFinding, RedundancyFinding, PairwiseConflict,
 };
-pub use error::{VerificationError, VerificationErrorKind, VerificationResult};
+pub use error::{SolverStatistics, VerificationError, VerificationErrorKind, VerificationResult};
 pub use ir::{BitVecOp, VerifyExpr, VerifyOp, VerifyType};
 pub use license::{LicensePlan, LicenseValidator};
+pub use schedule::{verify_obligations, Obligation, ObligationOutcome, ObligationReport, ScheduleConfig};
 pub use solver::{rename_var_in_expr, Verifier, VerificationSession};
@@ -1,7 +1,8 @@
 //! License validation for Logicaffeine verification.
 //!
 //! Uses the Stripe-based license system. License keys are Stripe subscription
-//! IDs (`sub_*` format) validated against the Logicaffeine API.
+//! IDs (`sub_*` format), or payment intent IDs (`pi_*`) for lifetime
+//! purchases, validated against the Logicaffeine API.
 //!
 //! ## Usage
 //!
@@ -28,30 +29,68 @@
 //!
 //! ## Validation Flow
 //!
-//! 1. **Format check**: Key must start with `sub_`
-//! 2. **Cache check**: Return cached result if fresh (< 24 hours)
-//! 3. **API validation**: Call `api.logicaffeine.com/validate`
-//! 4. **Fallback**: Use stale cache if network fails
+//! 1. **Format check**: Key must start with `sub_` or `pi_`
+//! 2. **License file**: An activated, signed license file for the key is
+//!    checked offline (signature, machine, expiry); one past its grace
+//!    period defers to the API, which may find the license renewed
+//! 3. **Cache check**: Return cached result if fresh (< 24 hours)
+//! 4. **API validation**: Call `api.logicaffeine.com/validate`
+//! 5. **Fallback**: Use stale cache if network fails
 //!
 //! ## Caching
 //!
 //! Results are cached for 24 hours at:
 //! - **macOS/Linux**: `~/.cache/logos/verification_license.json`
 //! - **Windows**: `%LOCALAPPDATA%\logos\verification_license.json`
+//!
+//! ## Offline License Files
+//!
+//! [`LicenseValidator::activate`] registers this machine against a key and
+//! stores the Ed25519-signed [`LicenseFile`] the API returns at
+//! `~/.config/logos/license.json` (or `$LOGOS_LICENSE_FILE`). From then on
+//! verification needs no network: the file's signature is checked against
+//! the public key baked into release builds, and its claims say which plan
+//! it grants, until when, and on which machine.
+//!
+//! A license keeps working for a grace period (14 days unless the file
+//! says otherwise) past its paid-through date, with a warning, so a laptop
+//! on a long flight or a CI runner behind a firewall is not cut off the day
+//! a renewal lands. Files issued without a machine binding (from the
+//! dashboard, for air-gapped CI fleets) are valid on any machine.
+//!
+//! Each key has a limit on concurrently activated machines;
+//! [`LicenseValidator::deactivate`] frees this machine's seat, proving the
+//! activation with the token the server issued alongside the file. So that
+//! a released seat cannot keep working, machine-bound files are issued
+//! short-lived and re-activated automatically once they lapse; offline,
+//! the grace period applies as usual.
 
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use ed25519_dalek::{Signature, VerifyingKey};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::error::{VerificationError, VerificationResult};
 
-/// The license validation API endpoint.
-const LICENSE_API: &str = "https://api.logicaffeine.com/validate";
+/// The license API, serving `/validate`, `/activate` and `/deactivate`.
+const LICENSE_API: &str = "https://api.logicaffeine.com";
 
 /// Cache duration in seconds (24 hours).
 const CACHE_DURATION_SECS: u64 = 24 * 60 * 60;
 
+/// Default offline grace period in days, past a license file's expiry.
+const DEFAULT_GRACE_DAYS: u64 = 14;
+
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Base64 Ed25519 public key license files are signed with. Release builds
+/// set it at compile time; without it, only online validation is available.
+const LICENSE_PUBLIC_KEY: Option<&str> = option_env!("LOGOS_LICENSE_PUBLIC_KEY");
+
 /// License plan tiers.
 ///
 /// Each plan has different feature access. Only Pro, Premium, Lifetime,
@@ -154,13 +193,254 @@ struct LicenseResponse {
     error: Option<String>,
 }
 
+/// Response from the activation API: a signed license file, or the reason
+/// activation was refused.
+#[derive(Debug, Deserialize)]
+struct ActivationResponse {
+    #[serde(default)]
+    license: Option<LicenseFile>,
+    #[serde(default)]
+    error: Option<String>,
+    #[serde(default, rename = "activeMachines")]
+    active_machines: Option<u32>,
+    #[serde(default, rename = "maxMachines")]
+    max_machines: Option<u32>,
+}
+
+/// What a signed license file grants.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LicenseClaims {
+    /// The license key (Stripe subscription ID) the file was issued for.
+    pub key: String,
+    /// The plan the license grants.
+    pub plan: LicensePlan,
+    /// Who the license was issued to.
+    #[serde(default)]
+    pub licensee: Option<String>,
+    /// Issue time, in seconds since the Unix epoch.
+    pub issued_at: u64,
+    /// When the file lapses, in seconds since the Unix epoch: the
+    /// paid-through time, or sooner for machine-bound files, which the
+    /// activation server issues short-lived so a released seat stops
+    /// working. [`LicenseValidator`] re-activates lapsed ones.
+    pub expires_at: u64,
+    /// Days the license keeps working past `expires_at`.
+    #[serde(default = "default_grace_days")]
+    pub grace_days: u64,
+    /// The [`machine_id`] the file is bound to; `None` for floating
+    /// licenses valid on any machine.
+    #[serde(default)]
+    pub machine_id: Option<String>,
+    /// Machines activated on this key when the file was issued.
+    #[serde(default)]
+    pub active_machines: u32,
+    /// Machines the key may have activated at once.
+    #[serde(default)]
+    pub max_machines: u32,
+}
+
+fn default_grace_days() -> u64 {
+    DEFAULT_GRACE_DAYS
+}
+
+/// Where a license stands at a point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LicenseStanding {
+    /// Within the paid-through period.
+    Active,
+    /// Past expiry, but within the grace period ending at `ends_at`.
+    Grace {
+        /// End of the grace period, in seconds since the Unix epoch.
+        ends_at: u64,
+    },
+    /// Past the grace period, which ended at `ended_at`.
+    Expired {
+        /// End of the grace period, in seconds since the Unix epoch.
+        ended_at: u64,
+    },
+}
+
+impl LicenseClaims {
+    /// Where the license stands at `now` (seconds since the Unix epoch).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use logicaffeine_verify::license::{LicenseClaims, LicensePlan, LicenseStanding};
+    ///
+    /// let claims = LicenseClaims {
+    ///     key: "sub_abc".into(), plan: LicensePlan::Pro, licensee: None,
+    ///     issued_at: 0, expires_at: 100, grace_days: 1,
+    ///     machine_id: None, active_machines: 1, max_machines: 3,
+    /// };
+    /// assert_eq!(claims.standing(50), LicenseStanding::Active);
+    /// assert_eq!(claims.standing(200), LicenseStanding::Grace { ends_at: 86_500 });
+    /// assert_eq!(claims.standing(90_000), LicenseStanding::Expired { ended_at: 86_500 });
+    /// ```
+    pub fn standing(&self, now: u64) -> LicenseStanding {
+        let grace_end = self
+            .expires_at
+            .saturating_add(self.grace_days.saturating_mul(SECS_PER_DAY));
+        if now < self.expires_at {
+            LicenseStanding::Active
+        } else if now < grace_end {
+            LicenseStanding::Grace { ends_at: grace_end }
+        } else {
+            LicenseStanding::Expired { ended_at: grace_end }
+        }
+    }
+}
+
+/// A signed license file, as stored by [`LicenseValidator::activate`].
+///
+/// The claims are serialized to JSON and base64-encoded into `payload`, so
+/// the signed bytes survive any reformatting of the file itself.
+///
+/// ```json
+/// { "payload": "eyJrZXkiOiJzdWJfLi4uIn0=", "signature": "3q2+7w==" }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicenseFile {
+    /// Base64 of the JSON-encoded [`LicenseClaims`].
+    pub payload: String,
+    /// Base64 Ed25519 signature over the decoded payload bytes.
+    pub signature: String,
+    /// Proves this machine holds the activation when releasing its seat.
+    /// Not signed; dashboard-issued files have none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activation_token: Option<String>,
+}
+
+impl LicenseFile {
+    /// Parse a license file's JSON.
+    pub fn parse(text: &str) -> VerificationResult<Self> {
+        serde_json::from_str(text).map_err(|e| {
+            VerificationError::license_invalid(format!("License file is malformed: {}", e))
+        })
+    }
+
+    /// Check the signature and decode the claims.
+    ///
+    /// # Errors
+    ///
+    /// `LicenseInvalid` if the file was not signed by `public_key` or was
+    /// altered after signing.
+    pub fn verify(&self, public_key: &VerifyingKey) -> VerificationResult<LicenseClaims> {
+        let tampered = || VerificationError::license_invalid("License file signature is invalid");
+        let payload = BASE64.decode(&self.payload).map_err(|_| tampered())?;
+        let signature = BASE64.decode(&self.signature).map_err(|_| tampered())?;
+        let signature = Signature::from_slice(&signature).map_err(|_| tampered())?;
+        public_key
+            .verify_strict(&payload, &signature)
+            .map_err(|_| tampered())?;
+        serde_json::from_slice(&payload).map_err(|e| {
+            VerificationError::license_invalid(format!("License file claims are malformed: {}", e))
+        })
+    }
+}
+
+/// This machine's activation fingerprint.
+///
+/// A SHA-256 digest of the OS machine ID (`/etc/machine-id` on Linux) or,
+/// failing that, a random ID generated once and kept next to the license
+/// file. Only the digest leaves the machine. There is deliberately no
+/// override: a settable identity would let one activation be copied onto
+/// any number of machines.
+pub fn machine_id() -> String {
+    let raw = os_machine_id().unwrap_or_else(generated_machine_id);
+    let digest = Sha256::digest(format!("logicaffeine-machine:{}", raw.trim()).as_bytes());
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn os_machine_id() -> Option<String> {
+    ["/etc/machine-id", "/var/lib/dbus/machine-id"]
+        .iter()
+        .find_map(|path| fs::read_to_string(path).ok())
+        .filter(|id| !id.trim().is_empty())
+}
+
+fn generated_machine_id() -> String {
+    use std::hash::{BuildHasher, Hasher};
+
+    let path = config_dir().join("machine-id");
+    if let Ok(id) = fs::read_to_string(&path) {
+        if !id.trim().is_empty() {
+            return id;
+        }
+    }
+    let id: String = (0..2)
+        .map(|_| {
+            let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+            hasher.write_u128(now_nanos());
+            format!("{:016x}", hasher.finish())
+        })
+        .collect();
+    let _ = fs::create_dir_all(config_dir());
+    let _ = fs::write(&path, &id);
+    id
+}
+
+fn config_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("logos")
+}
+
+/// Where the activated license file lives: `$LOGOS_LICENSE_FILE` if set,
+/// else `<config dir>/logos/license.json`.
+pub fn license_file_path() -> PathBuf {
+    match std::env::var("LOGOS_LICENSE_FILE") {
+        Ok(path) if !path.is_empty() => PathBuf::from(path),
+        _ => config_dir().join("license.json"),
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn now_nanos() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos()
+}
+
+/// Format seconds since the Unix epoch as a `YYYY-MM-DD` UTC date.
+///
+/// # Examples
+///
+/// ```
+/// use logicaffeine_verify::license::format_date;
+///
+/// assert_eq!(format_date(0), "1970-01-01");
+/// assert_eq!(format_date(1_709_164_800), "2024-02-29");
+/// ```
+pub fn format_date(secs: u64) -> String {
+    // Howard Hinnant's days-to-civil algorithm.
+    let days = (secs / SECS_PER_DAY) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
 /// License validator that checks keys against the API with caching.
 ///
 /// The validator implements a multi-tier validation strategy:
-/// 1. Check key format (must start with `sub_`)
-/// 2. Check local cache for fresh result
-/// 3. Call API if cache is stale or missing
-/// 4. Fall back to stale cache if network fails
+/// 1. Check key format (must start with `sub_` or `pi_`)
+/// 2. Check an activated license file for the key, offline
+/// 3. Check local cache for fresh result
+/// 4. Call API if cache is stale or missing
+/// 5. Fall back to stale cache if network fails
 ///
 /// # Examples
 ///
@@ -181,6 +461,9 @@ struct LicenseResponse {
 /// ```
 pub struct LicenseValidator {
     cache_path: PathBuf,
+    license_path: PathBuf,
+    public_key: Option<VerifyingKey>,
+    api: String,
 }
 
 impl LicenseValidator {
@@ -206,9 +489,36 @@ impl LicenseValidator {
 
         Self {
             cache_path: cache_dir.join("verification_license.json"),
+            license_path: license_file_path(),
+            public_key: LICENSE_PUBLIC_KEY.and_then(parse_public_key),
+            api: LICENSE_API.to_string(),
         }
     }
 
+    /// Use `path` for the activated license file instead of
+    /// [`license_file_path`].
+    pub fn with_license_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.license_path = path.into();
+        self
+    }
+
+    /// Check license files against `key` instead of the built-in public key.
+    pub fn with_public_key(mut self, key: VerifyingKey) -> Self {
+        self.public_key = Some(key);
+        self
+    }
+
+    /// Talk to the license API at `url` instead of `api.logicaffeine.com`.
+    pub fn with_api(mut self, url: impl Into<String>) -> Self {
+        self.api = url.into();
+        self
+    }
+
+    /// The activated license file this validator reads.
+    pub fn license_path(&self) -> &Path {
+        &self.license_path
+    }
+
     /// Validate a license key.
     ///
     /// Returns the license plan if valid and verification-capable.
@@ -217,10 +527,13 @@ impl LicenseValidator {
     ///
     /// # Validation Steps
     ///
-    /// 1. **Format check**: Key must start with `sub_`
-    /// 2. **Cache check**: Return cached result if < 24 hours old
-    /// 3. **API call**: Validate against `api.logicaffeine.com`
-    /// 4. **Fallback**: Use stale cache if network fails
+    /// 1. **Format check**: Key must start with `sub_` or `pi_`
+    /// 2. **License file**: Check an activated license file for this key,
+    ///    re-activating it first if it has lapsed, unless it is past its
+    ///    grace period
+    /// 3. **Cache check**: Return cached result if < 24 hours old
+    /// 4. **API call**: Validate against `api.logicaffeine.com`
+    /// 5. **Fallback**: Use stale cache if network fails
     ///
     /// # Errors
     ///
@@ -241,11 +554,16 @@ impl LicenseValidator {
     /// let result = validator.validate("sub_abc123");
     /// ```
     pub fn validate(&self, key: &str) -> VerificationResult<LicensePlan> {
-        // Check key format
-        if !key.starts_with("sub_") {
-            return Err(VerificationError::license_invalid(
-                "Invalid license key format. Keys should start with 'sub_'.",
-            ));
+        check_key_format(key)?;
+
+        // An activated license file for this key needs no network. Once it
+        // is past its grace period the API decides: the subscription may
+        // have been renewed since the file was issued.
+        if let Ok(Some(claims)) = self.activated() {
+            let claims = if claims.key == key { self.refreshed(claims) } else { claims };
+            if answers_offline(&claims, key, now_secs()) {
+                return check_claims(&claims, now_secs());
+            }
         }
 
         // Check cache first
@@ -298,9 +616,213 @@ impl LicenseValidator {
         }
     }
 
+    /// Validate the activated license file, with no key and no network.
+    ///
+    /// This is how machines without a key in the environment — air-gapped
+    /// CI runners given a license file — are licensed.
+    ///
+    /// # Errors
+    ///
+    /// - `LicenseRequired` - No license file is activated
+    /// - `LicenseInvalid` - The file is unsigned, bound to another machine,
+    ///   or past its grace period
+    /// - `LicenseInsufficientPlan` - The plan lacks verification
+    pub fn validate_activated(&self) -> VerificationResult<LicensePlan> {
+        let claims = self.activated()?.ok_or_else(VerificationError::license_required)?;
+        check_claims(&self.refreshed(claims), now_secs())
+    }
+
+    /// The claims of the activated license file, if there is one.
+    ///
+    /// The signature and machine binding are checked; expiry is not, so
+    /// callers can report on a lapsed license.
+    pub fn activated(&self) -> VerificationResult<Option<LicenseClaims>> {
+        match self.read_file()? {
+            Some(file) => self.check_file(&file).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// The activated license file, unchecked, if there is one.
+    fn read_file(&self) -> VerificationResult<Option<LicenseFile>> {
+        let text = match fs::read_to_string(&self.license_path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(VerificationError::license_invalid(format!(
+                    "Could not read license file {}: {}",
+                    self.license_path.display(),
+                    e
+                )))
+            }
+        };
+        LicenseFile::parse(&text).map(Some)
+    }
+
+    /// Re-activate a lapsed machine-bound file. If the server cannot be
+    /// reached or refuses (the seat was released), the old file stands and
+    /// its grace period runs out as usual.
+    fn refreshed(&self, claims: LicenseClaims) -> LicenseClaims {
+        if claims.machine_id.is_none() || now_secs() < claims.expires_at {
+            return claims;
+        }
+        self.activate(&claims.key).unwrap_or(claims)
+    }
+
+    /// Activate this machine for `key` and store the signed license file.
+    ///
+    /// # Errors
+    ///
+    /// `LicenseInvalid` if the key is rejected, its machine limit is
+    /// reached, or the API cannot be reached.
+    pub fn activate(&self, key: &str) -> VerificationResult<LicenseClaims> {
+        check_key_format(key)?;
+        let machine = machine_id();
+        let response = ureq::post(&format!("{}/activate", self.api))
+            .set("Content-Type", "application/json")
+            .send_json(ureq::json!({ "licenseKey": key, "machineId": machine }));
+        let body: ActivationResponse = match response {
+            Ok(r) => r.into_json(),
+            Err(ureq::Error::Status(_, r)) => r.into_json(),
+            Err(e) => {
+                return Err(VerificationError::license_invalid(format!(
+                    "Could not reach the activation server: {}. \
+                     Offline machines can install a license file from the dashboard \
+                     with `largo license activate --file <path>`.",
+                    e
+                )))
+            }
+        }
+        .map_err(|e| VerificationError::license_invalid(format!("Invalid response: {}", e)))?;
+
+        let file = match body {
+            ActivationResponse { license: Some(file), .. } => file,
+            ActivationResponse { active_machines: Some(used), max_machines: Some(max), .. }
+                if used >= max =>
+            {
+                return Err(VerificationError::license_invalid(format!(
+                    "This key is already activated on {} of {} machines. \
+                     Run `largo license deactivate` on a machine you no longer use, \
+                     or manage activations at https://logicaffeine.com/account.",
+                    used, max
+                )))
+            }
+            ActivationResponse { error, .. } => {
+                return Err(VerificationError::license_invalid(
+                    error.unwrap_or_else(|| "Activation was refused".to_string()),
+                ))
+            }
+        };
+        let claims = self.check_file(&file)?;
+        if claims.key != key {
+            return Err(VerificationError::license_invalid(
+                "The activation server returned a license for a different key",
+            ));
+        }
+        self.store(&file)?;
+        Ok(claims)
+    }
+
+    /// Install a license file obtained out of band (e.g. downloaded from
+    /// the dashboard for an air-gapped machine) after checking it.
+    pub fn install(&self, text: &str) -> VerificationResult<LicenseClaims> {
+        let file = LicenseFile::parse(text)?;
+        let claims = self.check_file(&file)?;
+        self.store(&file)?;
+        Ok(claims)
+    }
+
+    /// Release this machine's activation and delete the license file.
+    ///
+    /// Floating (machine-unbound) files hold no seat and are only deleted.
+    /// Returns the claims of the file that was removed, if any.
+    ///
+    /// # Errors
+    ///
+    /// `LicenseInvalid` if the server cannot be told or refuses the file's
+    /// activation token; the file is kept so the seat is not silently
+    /// leaked, unless `force` is set.
+    pub fn deactivate(&self, force: bool) -> VerificationResult<Option<LicenseClaims>> {
+        let Some(file) = self.read_file()? else {
+            return Ok(None);
+        };
+        let claims = self.check_file(&file)?;
+        if let Some(machine) = &claims.machine_id {
+            let released = ureq::post(&format!("{}/deactivate", self.api))
+                .set("Content-Type", "application/json")
+                .send_json(ureq::json!({
+                    "licenseKey": claims.key,
+                    "machineId": machine,
+                    "activationToken": file.activation_token,
+                }));
+            if let Err(e) = released {
+                if !force {
+                    return Err(VerificationError::license_invalid(format!(
+                        "Could not release this machine's activation: {}. \
+                         Retry when online, or pass --force to delete the local license file \
+                         and free the seat from https://logicaffeine.com/account.",
+                        e
+                    )));
+                }
+            }
+        }
+        fs::remove_file(&self.license_path).map_err(|e| {
+            VerificationError::license_invalid(format!(
+                "Could not remove {}: {}",
+                self.license_path.display(),
+                e
+            ))
+        })?;
+        Ok(Some(claims))
+    }
+
+    /// Check a license file's signature and machine binding.
+    fn check_file(&self, file: &LicenseFile) -> VerificationResult<LicenseClaims> {
+        let public_key = self.public_key.as_ref().ok_or_else(|| {
+            VerificationError::license_invalid(
+                "This build cannot check license files (it was built without \
+                 LOGOS_LICENSE_PUBLIC_KEY). Use --license <key> to validate online.",
+            )
+        })?;
+        let claims = file.verify(public_key)?;
+        if let Some(bound) = &claims.machine_id {
+            if *bound != machine_id() {
+                return Err(VerificationError::license_invalid(
+                    "This license file is activated for a different machine. \
+                     Run `largo license activate` on this machine.",
+                ));
+            }
+        }
+        Ok(claims)
+    }
+
+    /// Write a license file with owner-only permissions.
+    fn store(&self, file: &LicenseFile) -> VerificationResult<()> {
+        let io = |e: std::io::Error| {
+            VerificationError::license_invalid(format!(
+                "Could not write {}: {}",
+                self.license_path.display(),
+                e
+            ))
+        };
+        if let Some(parent) = self.license_path.parent() {
+            fs::create_dir_all(parent).map_err(io)?;
+        }
+        let json = serde_json::to_string_pretty(file)
+            .map_err(|e| VerificationError::license_invalid(e.to_string()))?;
+        fs::write(&self.license_path, json).map_err(io)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&self.license_path, fs::Permissions::from_mode(0o600))
+                .map_err(io)?;
+        }
+        Ok(())
+    }
+
     /// Validate the key against the API.
     fn validate_with_api(&self, key: &str) -> Result<(bool, LicensePlan), String> {
-        let response = ureq::post(LICENSE_API)
+        let response = ureq::post(&format!("{}/validate", self.api))
             .set("Content-Type", "application/json")
             .send_json(ureq::json!({ "licenseKey": key }))
             .map_err(|e| format!("Network error: {}", e))?;
@@ -329,10 +851,7 @@ impl LicenseValidator {
 
     /// Save license validation to cache.
     fn save_cache(&self, key: &str, plan: &str, valid: bool) {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
+        let now = now_secs();
 
         let cached = CachedLicense {
             key: key.to_string(),
@@ -348,15 +867,64 @@ impl LicenseValidator {
 
     /// Check if the cache is still fresh (< 24 hours).
     fn is_cache_fresh(&self, cached: &CachedLicense) -> bool {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
+        let now = now_secs();
 
         now.saturating_sub(cached.validated_at) < CACHE_DURATION_SECS
     }
 }
 
+/// Check that `key` looks like a license key: a Stripe subscription
+/// (`sub_…`) or a one-time lifetime purchase (`pi_…`).
+fn check_key_format(key: &str) -> VerificationResult<()> {
+    if key.starts_with("sub_") || key.starts_with("pi_") {
+        Ok(())
+    } else {
+        Err(VerificationError::license_invalid(
+            "Invalid license key format. Keys should start with 'sub_' or 'pi_'.",
+        ))
+    }
+}
+
+/// Whether an activated file with `claims` settles validation of `key` at
+/// `now` without the API: it must be for that key and not yet past its
+/// grace period.
+fn answers_offline(claims: &LicenseClaims, key: &str, now: u64) -> bool {
+    claims.key == key && !matches!(claims.standing(now), LicenseStanding::Expired { .. })
+}
+
+/// Decide whether activated `claims` permit verification at `now`.
+///
+/// Warns on stderr during the grace period.
+fn check_claims(claims: &LicenseClaims, now: u64) -> VerificationResult<LicensePlan> {
+    if !claims.plan.can_verify() {
+        return Err(VerificationError::insufficient_plan(claims.plan.to_string()));
+    }
+    match claims.standing(now) {
+        LicenseStanding::Active => Ok(claims.plan),
+        LicenseStanding::Grace { ends_at } => {
+            eprintln!(
+                "Warning: License expired on {}; the offline grace period ends on {}.\n\
+                 Reconnect and run `largo license activate` to refresh it.",
+                format_date(claims.expires_at),
+                format_date(ends_at)
+            );
+            Ok(claims.plan)
+        }
+        LicenseStanding::Expired { ended_at } => Err(VerificationError::license_invalid(format!(
+            "License expired on {} and its grace period ended on {}. \
+             Renew at https://logicaffeine.com/account, then run `largo license activate`.",
+            format_date(claims.expires_at),
+            format_date(ended_at)
+        ))),
+    }
+}
+
+/// Decode a base64 Ed25519 public key.
+fn parse_public_key(encoded: &str) -> Option<VerifyingKey> {
+    let bytes: [u8; 32] = BASE64.decode(encoded.trim()).ok()?.try_into().ok()?;
+    VerifyingKey::from_bytes(&bytes).ok()
+}
+
 impl Default for LicenseValidator {
     fn default() -> Self {
         Self::new()
@@ -383,5 +951,234 @@ mod tests {
         let validator = LicenseValidator::new();
         let result = validator.validate("invalid_key");
         assert!(result.is_err());
+        assert!(validator.activate("invalid_key").is_err());
+        assert!(check_key_format("sub_abc").is_ok());
+        assert!(check_key_format("pi_abc").is_ok());
+    }
+
+    use crate::error::VerificationErrorKind;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    const DAY: u64 = SECS_PER_DAY;
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[7; 32])
+    }
+
+    fn claims(machine_id: Option<String>, expires_at: u64) -> LicenseClaims {
+        LicenseClaims {
+            key: "sub_test".to_string(),
+            plan: LicensePlan::Pro,
+            licensee: Some("Acme".to_string()),
+            issued_at: 0,
+            expires_at,
+            grace_days: DEFAULT_GRACE_DAYS,
+            machine_id,
+            active_machines: 1,
+            max_machines: 3,
+        }
+    }
+
+    fn sign(claims: &LicenseClaims, key: &SigningKey) -> LicenseFile {
+        let payload = serde_json::to_vec(claims).unwrap();
+        LicenseFile {
+            payload: BASE64.encode(&payload),
+            signature: BASE64.encode(key.sign(&payload).to_bytes()),
+            activation_token: None,
+        }
+    }
+
+    /// Answer one HTTP request with the JSON `response`. The handle yields
+    /// the request's body.
+    fn serve_once(response: serde_json::Value) -> (String, std::thread::JoinHandle<String>) {
+        use std::io::{BufRead, BufReader, Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            let response = response.to_string();
+            write!(
+                reader.into_inner(),
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                 Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                response.len(),
+                response
+            )
+            .unwrap();
+            String::from_utf8(body).unwrap()
+        });
+        (url, handle)
+    }
+
+    fn validator(dir: &tempfile::TempDir) -> LicenseValidator {
+        LicenseValidator::new()
+            .with_license_path(dir.path().join("license.json"))
+            .with_public_key(signing_key().verifying_key())
+    }
+
+    #[test]
+    fn test_signed_file_roundtrips_and_rejects_tampering() {
+        let claims = claims(None, 1_000);
+        let file = sign(&claims, &signing_key());
+        let public_key = signing_key().verifying_key();
+        assert_eq!(file.verify(&public_key).unwrap(), claims);
+
+        let mut forged = claims.clone();
+        forged.plan = LicensePlan::Enterprise;
+        let tampered = LicenseFile { payload: sign(&forged, &signing_key()).payload, ..file.clone() };
+        assert!(tampered.verify(&public_key).is_err());
+
+        let other_signer = sign(&claims, &SigningKey::from_bytes(&[8; 32]));
+        assert!(other_signer.verify(&public_key).is_err());
+    }
+
+    #[test]
+    fn test_grace_period_warns_then_expires() {
+        let expires_at = 100 * DAY;
+        let claims = claims(None, expires_at);
+        assert_eq!(check_claims(&claims, expires_at - 1).unwrap(), LicensePlan::Pro);
+        assert_eq!(check_claims(&claims, expires_at + DAY).unwrap(), LicensePlan::Pro);
+
+        let err = check_claims(&claims, expires_at + (DEFAULT_GRACE_DAYS + 1) * DAY).unwrap_err();
+        assert!(matches!(err.kind, VerificationErrorKind::LicenseInvalid { .. }));
+        assert!(err.to_string().contains("grace period ended"), "{}", err);
+    }
+
+    #[test]
+    fn test_expired_file_defers_to_the_api() {
+        let expires_at = 100 * DAY;
+        let claims = claims(None, expires_at);
+        assert!(answers_offline(&claims, "sub_test", expires_at + DAY));
+        assert!(!answers_offline(&claims, "sub_other", expires_at + DAY));
+        assert!(!answers_offline(&claims, "sub_test", expires_at + (DEFAULT_GRACE_DAYS + 1) * DAY));
+    }
+
+    #[test]
+    fn test_insufficient_plan_in_file() {
+        let mut claims = claims(None, u64::MAX / 2);
+        claims.plan = LicensePlan::Supporter;
+        let err = check_claims(&claims, 0).unwrap_err();
+        assert!(matches!(err.kind, VerificationErrorKind::LicenseInsufficientPlan { .. }));
+    }
+
+    #[test]
+    fn test_installed_file_validates_offline() {
+        let dir = tempfile::tempdir().unwrap();
+        let validator = validator(&dir);
+        assert!(matches!(
+            validator.validate_activated().unwrap_err().kind,
+            VerificationErrorKind::LicenseRequired
+        ));
+
+        let claims = claims(Some(machine_id()), now_secs() + 30 * DAY);
+        let text = serde_json::to_string(&sign(&claims, &signing_key())).unwrap();
+        assert_eq!(validator.install(&text).unwrap(), claims);
+
+        // Neither path touches the network: the file answers for its key.
+        assert_eq!(validator.validate_activated().unwrap(), LicensePlan::Pro);
+        assert_eq!(validator.validate("sub_test").unwrap(), LicensePlan::Pro);
+        assert_eq!(validator.activated().unwrap(), Some(claims));
+    }
+
+    #[test]
+    fn test_file_bound_to_another_machine_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let validator = validator(&dir);
+        let claims = claims(Some("another-machine".to_string()), now_secs() + DAY);
+        let text = serde_json::to_string(&sign(&claims, &signing_key())).unwrap();
+        let err = validator.install(&text).unwrap_err();
+        assert!(err.to_string().contains("different machine"), "{}", err);
+        assert!(!validator.license_path().exists());
+    }
+
+    #[test]
+    fn test_floating_file_deactivates_locally() {
+        let dir = tempfile::tempdir().unwrap();
+        let validator = validator(&dir);
+        assert_eq!(validator.deactivate(false).unwrap(), None);
+
+        let claims = claims(None, now_secs() + DAY);
+        let text = serde_json::to_string(&sign(&claims, &signing_key())).unwrap();
+        validator.install(&text).unwrap();
+        assert_eq!(validator.deactivate(false).unwrap(), Some(claims));
+        assert!(!validator.license_path().exists());
+    }
+
+    #[test]
+    fn test_lifetime_key_activates() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut claims = claims(Some(machine_id()), now_secs() + 7 * DAY);
+        claims.key = "pi_test".to_string();
+        claims.plan = LicensePlan::Lifetime;
+        let file = LicenseFile {
+            activation_token: Some("token".to_string()),
+            ..sign(&claims, &signing_key())
+        };
+        let (url, request) = serve_once(serde_json::json!({ "license": file }));
+
+        let validator = validator(&dir).with_api(url);
+        assert_eq!(validator.activate("pi_test").unwrap(), claims);
+        let request: serde_json::Value = serde_json::from_str(&request.join().unwrap()).unwrap();
+        assert_eq!(request["licenseKey"], "pi_test");
+        assert_eq!(request["machineId"], machine_id());
+        assert_eq!(validator.validate("pi_test").unwrap(), LicensePlan::Lifetime);
+    }
+
+    #[test]
+    fn test_deactivation_proves_the_activation() {
+        let dir = tempfile::tempdir().unwrap();
+        let claims = claims(Some(machine_id()), now_secs() + 7 * DAY);
+        let file = LicenseFile {
+            activation_token: Some("token".to_string()),
+            ..sign(&claims, &signing_key())
+        };
+        validator(&dir).install(&serde_json::to_string(&file).unwrap()).unwrap();
+
+        let (url, request) = serve_once(serde_json::json!({ "released": true, "activeMachines": 0 }));
+        let validator = validator(&dir).with_api(url);
+        assert_eq!(validator.deactivate(false).unwrap(), Some(claims));
+        let request: serde_json::Value = serde_json::from_str(&request.join().unwrap()).unwrap();
+        assert_eq!(request["activationToken"], "token");
+        assert!(!validator.license_path().exists());
+    }
+
+    #[test]
+    fn test_lapsed_file_is_reactivated() {
+        let dir = tempfile::tempdir().unwrap();
+        let lapsed = claims(Some(machine_id()), now_secs() - DAY);
+        validator(&dir)
+            .install(&serde_json::to_string(&sign(&lapsed, &signing_key())).unwrap())
+            .unwrap();
+
+        let fresh = LicenseClaims { expires_at: now_secs() + 7 * DAY, ..lapsed.clone() };
+        let (url, request) = serve_once(serde_json::json!({ "license": sign(&fresh, &signing_key()) }));
+        let validator = validator(&dir).with_api(url);
+        assert_eq!(validator.validate_activated().unwrap(), LicensePlan::Pro);
+        request.join().unwrap();
+        assert_eq!(validator.activated().unwrap(), Some(fresh));
+    }
+
+    #[test]
+    fn test_huge_grace_period_saturates() {
+        let mut claims = claims(None, 100);
+        claims.grace_days = u64::MAX;
+        assert_eq!(claims.standing(200), LicenseStanding::Grace { ends_at: u64::MAX });
     }
 }
@@ -231,7 +231,9 @@ fn collect_vars(expr: &VerifyExpr, vars: &mut HashSet<String>) {
             collect_vars(l, vars);
             collect_vars(r, vars);
         }
-        VerifyExpr::ForAll { body, .. } | VerifyExpr::Exists { body, .. } => {
+        VerifyExpr::ForAll { body, .. }
+        | VerifyExpr::ForAllTriggered { body, .. }
+        | VerifyExpr::Exists { body, .. } => {
             collect_vars(body, vars);
         }
         _ => {}
@@ -100,6 +100,14 @@ fn substitute_param(expr: &VerifyExpr, param: &str, value: i64) -> VerifyExpr {
             vars.clone(),
             substitute_param(body, param, value),
         ),
+        VerifyExpr::ForAllTriggered { vars, triggers, body } => VerifyExpr::forall_triggered(
+            vars.clone(),
+            triggers
+                .iter()
+                .map(|terms| terms.iter().map(|t| substitute_param(t, param, value)).collect())
+                .collect(),
+            substitute_param(body, param, value),
+        ),
         VerifyExpr::Exists { vars, body } => VerifyExpr::exists(
             vars.clone(),
             substitute_param(body, param, value),
@@ -0,0 +1,302 @@
+//! Parallel verification of independent proof obligations.
+//!
+//! A [`VerificationSession`](crate::VerificationSession) checks one goal at a
+//! time on the calling thread. Programs with many assertions or large policy
+//! sets instead capture each check as an [`Obligation`] — a self-contained
+//! snapshot of declarations, assumptions and goal — and hand the batch to
+//! [`verify_obligations`], which proves them on a pool of worker threads, each
+//! with its own Z3 solvers.
+//!
+//! Scheduling is longest-first: obligations with the most assumptions start
+//! first so one expensive check doesn't trail the rest. Each check gets the
+//! per-obligation timeout, clipped to whatever remains of the global budget;
+//! once the budget is spent, remaining obligations are reported as
+//! [`ObligationOutcome::OverBudget`] without being sent to the solver.
+//!
+//! Reports always come back in input order, whatever order the workers
+//! finished in.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use logicaffeine_verify::schedule::{verify_obligations, ScheduleConfig};
+//! use logicaffeine_verify::{VerificationSession, VerifyExpr, VerifyType};
+//!
+//! let mut session = VerificationSession::new();
+//! session.declare("x", VerifyType::Int);
+//! session.assume(&VerifyExpr::eq(VerifyExpr::var("x"), VerifyExpr::int(10)));
+//! let obligations = vec![
+//!     session.obligation(&VerifyExpr::gt(VerifyExpr::var("x"), VerifyExpr::int(5))).labeled("x > 5"),
+//!     session.obligation(&VerifyExpr::lt(VerifyExpr::var("x"), VerifyExpr::int(5))).labeled("x < 5"),
+//! ];
+//! let reports = verify_obligations(&obligations, &ScheduleConfig::default(), None);
+//! assert!(reports[0].outcome.is_success());
+//! assert!(!reports[1].outcome.is_success());
+//! ```
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::cache::{obligation_key, ObligationCache, ObligationKey};
+use crate::error::{VerificationError, VerificationErrorKind, VerificationResult};
+use crate::ir::{VerifyExpr, VerifyType};
+
+/// One self-contained validity check.
+#[derive(Debug, Clone)]
+pub struct Obligation {
+    /// What the check is for, as shown in reports.
+    pub label: String,
+    vars: HashMap<String, VerifyType>,
+    assumptions: Vec<VerifyExpr>,
+    binding: Option<(String, VerifyType, VerifyExpr)>,
+    goal: VerifyExpr,
+}
+
+impl Obligation {
+    pub(crate) fn new(
+        vars: HashMap<String, VerifyType>,
+        assumptions: Vec<VerifyExpr>,
+        binding: Option<(String, VerifyType, VerifyExpr)>,
+        goal: VerifyExpr,
+    ) -> Self {
+        Self { label: String::new(), vars, assumptions, binding, goal }
+    }
+
+    /// Attach the label reports show for this obligation.
+    pub fn labeled(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// The obligation's [`ObligationCache`] key.
+    pub fn key(&self) -> ObligationKey {
+        obligation_key(&self.vars, &self.assumptions, self.binding(), &self.goal)
+    }
+
+    /// Prove the obligation on the calling thread.
+    pub fn check(&self, timeout_ms: u64) -> VerificationResult {
+        crate::solver::prove(&self.vars, &self.assumptions, self.binding(), &self.goal, timeout_ms)
+    }
+
+    fn binding(&self) -> Option<(&str, &VerifyType, &VerifyExpr)> {
+        self.binding.as_ref().map(|(name, ty, value)| (name.as_str(), ty, value))
+    }
+
+    /// Rough solver cost, for longest-first scheduling.
+    fn weight(&self) -> usize {
+        self.assumptions.len() + usize::from(self.binding.is_some())
+    }
+}
+
+/// How [`verify_obligations`] spreads work and bounds time.
+#[derive(Debug, Clone)]
+pub struct ScheduleConfig {
+    /// Worker threads, each with its own solvers. At least 1.
+    pub jobs: usize,
+    /// Z3 timeout per obligation in milliseconds.
+    pub timeout_ms: u64,
+    /// Wall-clock budget for the whole batch in milliseconds, if any.
+    pub budget_ms: Option<u64>,
+}
+
+impl Default for ScheduleConfig {
+    fn default() -> Self {
+        Self {
+            jobs: std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+            timeout_ms: crate::solver::DEFAULT_TIMEOUT_MS,
+            budget_ms: None,
+        }
+    }
+}
+
+/// How one obligation ended.
+#[derive(Debug)]
+pub enum ObligationOutcome {
+    /// Z3 proved it.
+    Proven,
+    /// An earlier run proved it; answered from the cache.
+    Cached,
+    /// Z3 refuted it or could not decide it.
+    Failed(VerificationError),
+    /// Z3 gave no answer within the time it was allowed.
+    TimedOut {
+        /// The timeout the check ran with.
+        timeout_ms: u64,
+    },
+    /// Never started: the global budget ran out first.
+    OverBudget,
+}
+
+impl ObligationOutcome {
+    /// Whether the obligation is known to hold.
+    pub fn is_success(&self) -> bool {
+        matches!(self, ObligationOutcome::Proven | ObligationOutcome::Cached)
+    }
+}
+
+/// The result of one obligation, in the position it was submitted.
+#[derive(Debug)]
+pub struct ObligationReport {
+    pub label: String,
+    pub outcome: ObligationOutcome,
+    /// Time spent on this obligation (zero when cached or over budget).
+    pub elapsed: Duration,
+}
+
+/// Prove every obligation, in parallel, and report in input order.
+///
+/// With a `cache`, obligations it already holds are answered without Z3 and
+/// new proofs are recorded in it.
+pub fn verify_obligations(
+    obligations: &[Obligation],
+    config: &ScheduleConfig,
+    cache: Option<&ObligationCache>,
+) -> Vec<ObligationReport> {
+    let started = Instant::now();
+    let mut order: Vec<usize> = (0..obligations.len()).collect();
+    // Stable sort: equal weights keep their input order.
+    order.sort_by_key(|&i| std::cmp::Reverse(obligations[i].weight()));
+
+    let next = AtomicUsize::new(0);
+    let worker = || {
+        let mut done = Vec::new();
+        while let Some(&index) = order.get(next.fetch_add(1, Ordering::Relaxed)) {
+            done.push((index, run_one(&obligations[index], config, cache, started)));
+        }
+        done
+    };
+
+    let jobs = config.jobs.clamp(1, obligations.len().max(1));
+    let mut finished: Vec<(usize, ObligationReport)> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs).map(|_| scope.spawn(worker)).collect();
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().expect("verification worker panicked"))
+            .collect()
+    });
+    finished.sort_by_key(|(index, _)| *index);
+    finished.into_iter().map(|(_, report)| report).collect()
+}
+
+fn run_one(
+    obligation: &Obligation,
+    config: &ScheduleConfig,
+    cache: Option<&ObligationCache>,
+    started: Instant,
+) -> ObligationReport {
+    let report = |outcome, elapsed| ObligationReport {
+        label: obligation.label.clone(),
+        outcome,
+        elapsed,
+    };
+
+    let key = cache.map(|_| obligation.key());
+    if let (Some(cache), Some(key)) = (cache, &key) {
+        if cache.is_proven(key) {
+            return report(ObligationOutcome::Cached, Duration::ZERO);
+        }
+    }
+
+    let timeout_ms = match config.budget_ms {
+        Some(budget) => {
+            let spent = started.elapsed().as_millis() as u64;
+            if spent >= budget {
+                return report(ObligationOutcome::OverBudget, Duration::ZERO);
+            }
+            config.timeout_ms.min(budget - spent)
+        }
+        None => config.timeout_ms,
+    };
+
+    let began = Instant::now();
+    let result = obligation.check(timeout_ms);
+    let elapsed = began.elapsed();
+    let outcome = match result {
+        Ok(()) => {
+            if let (Some(cache), Some(key)) = (cache, &key) {
+                cache.record_proven(key);
+            }
+            ObligationOutcome::Proven
+        }
+        // Z3 reports a timeout as unknown; the clock tells the two apart.
+        Err(e) if e.kind == VerificationErrorKind::SolverUnknown
+            && elapsed >= Duration::from_millis(timeout_ms) =>
+        {
+            ObligationOutcome::TimedOut { timeout_ms }
+        }
+        Err(e) => ObligationOutcome::Failed(e),
+    };
+    report(outcome, elapsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VerificationSession;
+
+    fn session() -> VerificationSession {
+        let mut session = VerificationSession::new();
+        session.declare("x", VerifyType::Int);
+        session.assume(&VerifyExpr::eq(VerifyExpr::var("x"), VerifyExpr::int(10)));
+        session
+    }
+
+    fn x_gt(n: i64) -> VerifyExpr {
+        VerifyExpr::gt(VerifyExpr::var("x"), VerifyExpr::int(n))
+    }
+
+    #[test]
+    fn reports_follow_input_order_across_workers() {
+        let session = session();
+        let obligations: Vec<_> = (0..16)
+            .map(|n| session.obligation(&x_gt(n)).labeled(format!("x > {n}")))
+            .collect();
+        let config = ScheduleConfig { jobs: 4, ..ScheduleConfig::default() };
+        let reports = verify_obligations(&obligations, &config, None);
+
+        let labels: Vec<_> = reports.iter().map(|r| r.label.clone()).collect();
+        let expected: Vec<_> = (0..16).map(|n| format!("x > {n}")).collect();
+        assert_eq!(labels, expected);
+        for (n, report) in reports.iter().enumerate() {
+            assert_eq!(report.outcome.is_success(), n < 10, "{}", report.label);
+        }
+    }
+
+    #[test]
+    fn refinement_bindings_are_scoped_to_their_obligation() {
+        let session = VerificationSession::new();
+        let positive = VerifyExpr::gt(VerifyExpr::var("it"), VerifyExpr::int(0));
+        let obligations = [
+            session.obligation_with_binding("it", VerifyType::Int, &VerifyExpr::int(3), &positive),
+            session.obligation_with_binding("it", VerifyType::Int, &VerifyExpr::int(-3), &positive),
+        ];
+        let reports = verify_obligations(&obligations, &ScheduleConfig::default(), None);
+        assert!(reports[0].outcome.is_success());
+        assert!(matches!(
+            &reports[1].outcome,
+            ObligationOutcome::Failed(e) if matches!(e.kind, VerificationErrorKind::RefinementViolation { .. })
+        ));
+    }
+
+    #[test]
+    fn a_spent_budget_skips_the_solver() {
+        let obligations = [session().obligation(&x_gt(0))];
+        let config = ScheduleConfig { budget_ms: Some(0), ..ScheduleConfig::default() };
+        let reports = verify_obligations(&obligations, &config, None);
+        assert!(matches!(reports[0].outcome, ObligationOutcome::OverBudget));
+    }
+
+    #[test]
+    fn proofs_are_cached_and_reused() {
+        let cache = ObligationCache::in_memory();
+        let obligations = [session().obligation(&x_gt(0)), session().obligation(&x_gt(20))];
+        let config = ScheduleConfig::default();
+
+        let first = verify_obligations(&obligations, &config, Some(&cache));
+        assert!(matches!(first[0].outcome, ObligationOutcome::Proven));
+        let second = verify_obligations(&obligations, &config, Some(&cache));
+        assert!(matches!(second[0].outcome, ObligationOutcome::Cached));
+        assert!(matches!(second[1].outcome, ObligationOutcome::Failed(_)));
+    }
+}
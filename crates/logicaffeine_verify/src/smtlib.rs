@@ -16,6 +16,17 @@ pub fn to_smtlib2(expr: &VerifyExpr, declarations: &[(&str, VerifyType)]) -> Str
         output.push_str(&format!("(declare-fun {} () {})\n", name, sort));
     }
 
+    // Sequence lengths are one uninterpreted function per sequence sort
+    let mut length_sorts = HashSet::new();
+    for (_, ty) in declarations {
+        if let VerifyType::SeqOf(_) = ty {
+            let sort = type_to_smtlib(ty);
+            if length_sorts.insert(sort.clone()) {
+                output.push_str(&format!("(declare-fun length ({}) Int)\n", sort));
+            }
+        }
+    }
+
     // Auto-declare any variables not in declarations
     let mut vars = HashSet::new();
     crate::equivalence::collect_vars_pub(expr, &mut vars);
@@ -61,6 +72,7 @@ fn type_to_smtlib(ty: &VerifyType) -> String {
         VerifyType::Array(idx, elem) => {
             format!("(Array {} {})", type_to_smtlib(idx), type_to_smtlib(elem))
         }
+        VerifyType::SeqOf(elem) => format!("(Array Int {})", type_to_smtlib(elem)),
     }
 }
 
@@ -71,6 +83,10 @@ fn expr_to_smtlib(expr: &VerifyExpr) -> String {
         VerifyExpr::Int(n) => {
             if *n < 0 { format!("(- {})", -n) } else { n.to_string() }
         }
+        VerifyExpr::RealConst { num, den } => {
+            let ratio = format!("(/ {}.0 {}.0)", num.unsigned_abs(), den);
+            if *num < 0 { format!("(- {})", ratio) } else { ratio }
+        }
         VerifyExpr::Var(name) => name.clone(),
         VerifyExpr::Binary { op, left, right } => {
             let l = expr_to_smtlib(left);
@@ -79,6 +95,7 @@ fn expr_to_smtlib(expr: &VerifyExpr) -> String {
                 VerifyOp::Add => "+",
                 VerifyOp::Sub => "-",
                 VerifyOp::Mul => "*",
+                VerifyOp::Div if crate::type_infer::expr_suggests_real(expr) => "/",
                 VerifyOp::Div => "div",
                 // Floor division: real division then floor (`to_int` is floor) — exact toward -inf.
                 VerifyOp::FloorDiv => return format!("(to_int (/ (to_real {}) (to_real {})))", l, r),
@@ -102,6 +119,18 @@ fn expr_to_smtlib(expr: &VerifyExpr) -> String {
                 .collect();
             format!("(forall ({}) {})", bindings.join(" "), expr_to_smtlib(body))
         }
+        VerifyExpr::ForAllTriggered { vars, triggers, body } => {
+            let bindings: Vec<String> = vars.iter()
+                .map(|(n, t)| format!("({} {})", n, type_to_smtlib(t)))
+                .collect();
+            let patterns: Vec<String> = triggers.iter()
+                .map(|terms| {
+                    let terms: Vec<String> = terms.iter().map(expr_to_smtlib).collect();
+                    format!(":pattern ({})", terms.join(" "))
+                })
+                .collect();
+            format!("(forall ({}) (! {} {}))", bindings.join(" "), expr_to_smtlib(body), patterns.join(" "))
+        }
         VerifyExpr::Exists { vars, body } => {
             let bindings: Vec<String> = vars.iter()
                 .map(|(n, t)| format!("({} {})", n, type_to_smtlib(t)))
@@ -155,6 +184,7 @@ fn expr_to_smtlib(expr: &VerifyExpr) -> String {
         VerifyExpr::Store { array, index, value } => {
             format!("(store {} {} {})", expr_to_smtlib(array), expr_to_smtlib(index), expr_to_smtlib(value))
         }
+        VerifyExpr::Length(seq) => format!("(length {})", expr_to_smtlib(seq)),
         VerifyExpr::AtState { expr, .. } => expr_to_smtlib(expr),
         VerifyExpr::Transition { from, to } => {
             format!("(and {} {})", expr_to_smtlib(from), expr_to_smtlib(to))
@@ -30,12 +30,15 @@
 //! ```
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
-use z3::ast::{Ast, Bool, Dynamic, Int};
-use z3::{FuncDecl, Params, SatResult, Solver, Sort};
+use z3::ast::{Ast, Bool, Dynamic, Int, Real, BV};
+use z3::{FuncDecl, Params, Pattern, SatResult, Solver, Sort, StatisticsValue};
 
-use crate::error::{CounterExample, VerificationError, VerificationResult};
+use crate::cache::ObligationCache;
+use crate::error::{CounterExample, SolverStatistics, VerificationError, VerificationResult};
 use crate::ir::{VerifyExpr, VerifyOp, VerifyType};
+use crate::schedule::Obligation;
 
 /// A solver carrying the crate-wide default timeout.
 ///
@@ -127,7 +130,9 @@ impl Verifier {
                     None,
                 ))
             }
-            SatResult::Unknown => Err(VerificationError::solver_unknown()),
+            SatResult::Unknown => {
+                Err(VerificationError::solver_unknown().with_statistics(statistics(&solver)))
+            }
         }
     }
 
@@ -161,7 +166,9 @@ impl Verifier {
                     format!("{}", value),
                 ))
             }
-            SatResult::Unknown => Err(VerificationError::solver_unknown()),
+            SatResult::Unknown => {
+                Err(VerificationError::solver_unknown().with_statistics(statistics(&solver)))
+            }
         }
     }
 
@@ -192,7 +199,9 @@ impl Verifier {
                 format!("< {}", bound),
                 format!("{}", value),
             )),
-            SatResult::Unknown => Err(VerificationError::solver_unknown()),
+            SatResult::Unknown => {
+                Err(VerificationError::solver_unknown().with_statistics(statistics(&solver)))
+            }
         }
     }
 
@@ -227,7 +236,9 @@ impl Verifier {
                     ],
                 }),
             )),
-            SatResult::Unknown => Err(VerificationError::solver_unknown()),
+            SatResult::Unknown => {
+                Err(VerificationError::solver_unknown().with_statistics(statistics(&solver)))
+            }
         }
     }
 
@@ -323,7 +334,9 @@ impl VerificationContext {
                     None,
                 ))
             }
-            SatResult::Unknown => Err(VerificationError::solver_unknown()),
+            SatResult::Unknown => {
+                Err(VerificationError::solver_unknown().with_statistics(statistics(solver)))
+            }
         };
 
         solver.pop(1);
@@ -384,6 +397,7 @@ impl VerificationContext {
 pub struct VerificationSession {
     vars: HashMap<String, VerifyType>,
     assumptions: Vec<VerifyExpr>,
+    cache: Option<Arc<ObligationCache>>,
 }
 
 impl VerificationSession {
@@ -400,7 +414,53 @@ impl VerificationSession {
         Self {
             vars: HashMap::new(),
             assumptions: Vec::new(),
+            cache: None,
+        }
+    }
+
+    /// Answer already-proven obligations from `cache` instead of Z3.
+    ///
+    /// [`verify`](Self::verify) and [`verify_with_binding`](Self::verify_with_binding)
+    /// look each obligation up by [`obligation_key`](crate::cache::obligation_key)
+    /// and record new proofs; failures are never cached.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use std::sync::Arc;
+    /// use logicaffeine_verify::cache::ObligationCache;
+    /// use logicaffeine_verify::{VerificationSession, VerifyExpr};
+    ///
+    /// let cache = Arc::new(ObligationCache::in_memory());
+    /// let session = VerificationSession::new().with_cache(Arc::clone(&cache));
+    /// assert!(session.verify(&VerifyExpr::bool(true)).is_ok());
+    /// assert!(session.verify(&VerifyExpr::bool(true)).is_ok());
+    /// assert_eq!(cache.stats().hits, 1);
+    /// ```
+    pub fn with_cache(mut self, cache: Arc<ObligationCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Run `check` unless `cache` already holds this obligation's proof.
+    fn cached(
+        &self,
+        binding: Option<(&str, &VerifyType, &VerifyExpr)>,
+        goal: &VerifyExpr,
+        check: impl FnOnce() -> VerificationResult,
+    ) -> VerificationResult {
+        let Some(cache) = &self.cache else {
+            return check();
+        };
+        let key = crate::cache::obligation_key(&self.vars, &self.assumptions, binding, goal);
+        if cache.is_proven(&key) {
+            return Ok(());
+        }
+        let result = check();
+        if result.is_ok() {
+            cache.record_proven(&key);
         }
+        result
     }
 
     /// Declare a variable with a type.
@@ -422,6 +482,11 @@ impl VerificationSession {
         self.vars.insert(name.to_string(), ty);
     }
 
+    /// The type `name` was declared with, if any.
+    pub fn type_of(&self, name: &str) -> Option<&VerifyType> {
+        self.vars.get(name)
+    }
+
     /// Add an assumption (constraint) to the session.
     ///
     /// Assumptions constrain the verification context. Subsequent calls to
@@ -473,53 +538,10 @@ impl VerificationSession {
         value: &VerifyExpr,
         predicate: &VerifyExpr,
     ) -> VerificationResult {
-        // Create a fresh solver carrying the standard timeout
-        let solver = timed_solver();
-
-        // Copy existing vars and add the bound variable
-        let mut vars = self.vars.clone();
-        vars.insert(var_name.to_string(), var_type);
-
-        let encoder = Encoder::new(&vars);
-
-        // Add all existing assumptions
-        for assumption in &self.assumptions {
-            let ast = encoder.encode(assumption);
-            if let Some(b) = ast.as_bool() {
-                solver.assert(&b);
-            }
-        }
-
-        // Add the binding: var_name == value
-        let binding = VerifyExpr::eq(
-            VerifyExpr::var(var_name),
-            value.clone(),
-        );
-        let binding_ast = encoder.encode(&binding);
-        if let Some(b) = binding_ast.as_bool() {
-            solver.assert(&b);
-        }
-
-        // Verify the predicate
-        let pred_ast = encoder.encode(predicate);
-        let assertion = pred_ast.as_bool().ok_or_else(|| {
-            VerificationError::solver_error("Refinement predicate must be boolean")
-        })?;
-
-        solver.push();
-        solver.assert(&assertion.not());
-
-        let result = match solver.check() {
-            SatResult::Unsat => Ok(()),
-            SatResult::Sat => Err(VerificationError::refinement_violation(
-                var_name,
-                "The value does not satisfy the refinement predicate.",
-            )),
-            SatResult::Unknown => Err(VerificationError::solver_unknown()),
-        };
-
-        solver.pop(1);
-        result
+        let binding = Some((var_name, &var_type, value));
+        self.cached(binding, predicate, || {
+            prove(&self.vars, &self.assumptions, binding, predicate, DEFAULT_TIMEOUT_MS)
+        })
     }
 
     /// Verify that an assertion is valid given current assumptions.
@@ -543,43 +565,31 @@ impl VerificationSession {
     /// assert!(session.verify(&VerifyExpr::lt(VerifyExpr::var("x"), VerifyExpr::int(5))).is_err());
     /// ```
     pub fn verify(&self, expr: &VerifyExpr) -> VerificationResult {
-        // Create a fresh solver carrying the standard timeout
-        let solver = timed_solver();
-
-        // Create an encoder for this verification
-        let encoder = Encoder::new(&self.vars);
-
-        // Add all assumptions
-        for assumption in &self.assumptions {
-            let ast = encoder.encode(assumption);
-            if let Some(b) = ast.as_bool() {
-                solver.assert(&b);
-            }
-        }
-
-        // Encode the assertion we want to verify
-        let ast = encoder.encode(expr);
-        let assertion = ast.as_bool().ok_or_else(|| {
-            VerificationError::solver_error("Assertion must be boolean")
-        })?;
-
-        // To prove P is valid: check if NOT(P) is UNSAT
-        solver.push();
-        solver.assert(&assertion.not());
+        self.cached(None, expr, || {
+            prove(&self.vars, &self.assumptions, None, expr, DEFAULT_TIMEOUT_MS)
+        })
+    }
 
-        let result = match solver.check() {
-            SatResult::Unsat => Ok(()),
-            SatResult::Sat => {
-                Err(VerificationError::contradiction(
-                    "Assertion cannot be proven valid",
-                    None,
-                ))
-            }
-            SatResult::Unknown => Err(VerificationError::solver_unknown()),
-        };
+    /// Capture "`goal` holds under the current declarations and assumptions"
+    /// as a standalone [`Obligation`] for [`verify_obligations`](crate::schedule::verify_obligations).
+    pub fn obligation(&self, goal: &VerifyExpr) -> Obligation {
+        Obligation::new(self.vars.clone(), self.assumptions.clone(), None, goal.clone())
+    }
 
-        solver.pop(1);
-        result
+    /// The deferred form of [`verify_with_binding`](Self::verify_with_binding).
+    pub fn obligation_with_binding(
+        &self,
+        var_name: &str,
+        var_type: VerifyType,
+        value: &VerifyExpr,
+        predicate: &VerifyExpr,
+    ) -> Obligation {
+        Obligation::new(
+            self.vars.clone(),
+            self.assumptions.clone(),
+            Some((var_name.to_string(), var_type, value.clone())),
+            predicate.clone(),
+        )
     }
 
     /// Check the joint satisfiability of all assumptions (no goal).
@@ -591,6 +601,9 @@ impl VerificationSession {
         let solver = timed_solver();
 
         let encoder = Encoder::new(&self.vars);
+        for axiom in encoder.declaration_axioms() {
+            solver.assert(&axiom);
+        }
         for assumption in &self.assumptions {
             let ast = encoder.encode(assumption);
             if let Some(b) = ast.as_bool() {
@@ -601,7 +614,9 @@ impl VerificationSession {
         match solver.check() {
             SatResult::Sat => Ok(true),
             SatResult::Unsat => Ok(false),
-            SatResult::Unknown => Err(VerificationError::solver_unknown()),
+            SatResult::Unknown => {
+                Err(VerificationError::solver_unknown().with_statistics(statistics(&solver)))
+            }
         }
     }
 
@@ -694,7 +709,7 @@ pub fn rename_var_in_expr(expr: &VerifyExpr, from: &str, to: &str) -> VerifyExpr
             if name == from { VerifyExpr::Var(to.to_string()) } else { expr.clone() }
         }
         // Leaves: literals — no variables to rename
-        VerifyExpr::Int(_) | VerifyExpr::Bool(_) | VerifyExpr::BitVecConst { .. } => expr.clone(),
+        VerifyExpr::Int(_) | VerifyExpr::RealConst { .. } | VerifyExpr::Bool(_) | VerifyExpr::BitVecConst { .. } => expr.clone(),
 
         // Binary: recurse both sides
         VerifyExpr::Binary { op, left, right } => VerifyExpr::Binary {
@@ -710,6 +725,11 @@ pub fn rename_var_in_expr(expr: &VerifyExpr, from: &str, to: &str) -> VerifyExpr
             vars: vars.clone(),
             body: Box::new(r(body)),
         },
+        VerifyExpr::ForAllTriggered { vars, triggers, body } => VerifyExpr::ForAllTriggered {
+            vars: vars.clone(),
+            triggers: triggers.iter().map(|terms| terms.iter().map(r).collect()).collect(),
+            body: Box::new(r(body)),
+        },
         VerifyExpr::Exists { vars, body } => VerifyExpr::Exists {
             vars: vars.clone(),
             body: Box::new(r(body)),
@@ -747,6 +767,7 @@ pub fn rename_var_in_expr(expr: &VerifyExpr, from: &str, to: &str) -> VerifyExpr
             index: Box::new(r(index)),
             value: Box::new(r(value)),
         },
+        VerifyExpr::Length(seq) => VerifyExpr::length(r(seq)),
 
         // Temporal BMC: recurse sub-expressions
         VerifyExpr::AtState { state, expr: e } => VerifyExpr::AtState {
@@ -766,28 +787,124 @@ impl Default for VerificationSession {
     }
 }
 
+/// Per-check timeout for [`VerificationSession`] queries.
+pub(crate) const DEFAULT_TIMEOUT_MS: u64 = 10_000;
+
 /// Build a fresh solver carrying the standard 10-second timeout.
 fn timed_solver() -> Solver {
+    solver_with_timeout(DEFAULT_TIMEOUT_MS)
+}
+
+fn solver_with_timeout(timeout_ms: u64) -> Solver {
     let solver = crate::solver::new_solver();
     let mut params = Params::new();
-    params.set_u32("timeout", 10000);
+    params.set_u32("timeout", timeout_ms.min(u32::MAX as u64) as u32);
     solver.set_params(&params);
     solver
 }
 
+/// Prove `goal` valid under `assumptions`, with the optional refinement
+/// binding `name = value` in scope: P is valid iff ¬P is unsatisfiable.
+///
+/// Every check runs on a fresh solver, so independent obligations can be
+/// proved on different threads.
+pub(crate) fn prove(
+    vars: &HashMap<String, VerifyType>,
+    assumptions: &[VerifyExpr],
+    binding: Option<(&str, &VerifyType, &VerifyExpr)>,
+    goal: &VerifyExpr,
+    timeout_ms: u64,
+) -> VerificationResult {
+    // Z3 aborts on malformed patterns; catch them while the message can still
+    // name the offending trigger.
+    let exprs = assumptions.iter().chain(binding.map(|(_, _, value)| value)).chain([goal]);
+    for expr in exprs {
+        if let Some(message) = expr.trigger_error() {
+            return Err(VerificationError::solver_error(message));
+        }
+    }
+
+    let solver = solver_with_timeout(timeout_ms);
+
+    // The bound variable joins the declarations for this check only
+    let mut vars = std::borrow::Cow::Borrowed(vars);
+    if let Some((name, ty, _)) = binding {
+        vars.to_mut().insert(name.to_string(), ty.clone());
+    }
+    let encoder = Encoder::new(&vars);
+
+    for axiom in encoder.declaration_axioms() {
+        solver.assert(&axiom);
+    }
+    for assumption in assumptions {
+        if let Some(b) = encoder.encode(assumption).as_bool() {
+            solver.assert(&b);
+        }
+    }
+    if let Some((name, _, value)) = binding {
+        let eq = VerifyExpr::eq(VerifyExpr::var(name), value.clone());
+        if let Some(b) = encoder.encode(&eq).as_bool() {
+            solver.assert(&b);
+        }
+    }
+
+    let assertion = encoder.encode(goal).as_bool().ok_or_else(|| {
+        VerificationError::solver_error(if binding.is_some() {
+            "Refinement predicate must be boolean"
+        } else {
+            "Assertion must be boolean"
+        })
+    })?;
+    solver.assert(&assertion.not());
+
+    match (solver.check(), binding) {
+        (SatResult::Unsat, _) => Ok(()),
+        (SatResult::Sat, Some((name, _, _))) => Err(VerificationError::refinement_violation(
+            name,
+            "The value does not satisfy the refinement predicate.",
+        )),
+        (SatResult::Sat, None) => Err(VerificationError::contradiction(
+            "Assertion cannot be proven valid",
+            None,
+        )),
+        (SatResult::Unknown, _) => {
+            Err(VerificationError::solver_unknown().with_statistics(statistics(&solver)))
+        }
+    }
+}
+
+/// Snapshot Z3's counters after a check, for diagnosing unknown results.
+fn statistics(solver: &Solver) -> SolverStatistics {
+    let entries = solver
+        .get_statistics()
+        .entries()
+        .map(|entry| {
+            let value = match entry.value {
+                StatisticsValue::UInt(n) => f64::from(n),
+                StatisticsValue::Double(x) => x,
+            };
+            (entry.key, value)
+        })
+        .collect();
+    SolverStatistics { entries }
+}
+
 /// Internal encoder that converts VerifyExpr to Z3 AST.
-struct Encoder<'a> {
+pub(crate) struct Encoder<'a> {
     vars: &'a HashMap<String, VerifyType>,
 }
 
 impl<'a> Encoder<'a> {
-    fn new(vars: &'a HashMap<String, VerifyType>) -> Self {
+    pub(crate) fn new(vars: &'a HashMap<String, VerifyType>) -> Self {
         Self { vars }
     }
 
-    fn encode(&self, expr: &VerifyExpr) -> Dynamic {
+    pub(crate) fn encode(&self, expr: &VerifyExpr) -> Dynamic {
         match expr {
             VerifyExpr::Int(n) => Dynamic::from_ast(&Int::from_i64(*n)),
+            VerifyExpr::RealConst { num, den } => {
+                Dynamic::from_ast(&(Int::from_i64(*num).to_real() / Int::from_i64(*den).to_real()))
+            }
             VerifyExpr::Bool(b) => Dynamic::from_ast(&Bool::from_bool(*b)),
 
             VerifyExpr::Var(name) => {
@@ -809,6 +926,10 @@ impl<'a> Encoder<'a> {
                         let elem_sort = self.type_to_sort(elem_ty);
                         Dynamic::from_ast(&z3::ast::Array::new_const(name.as_str(), &idx_sort, &elem_sort))
                     }
+                    VerifyType::SeqOf(ref elem_ty) => {
+                        let elem_sort = self.type_to_sort(elem_ty);
+                        Dynamic::from_ast(&z3::ast::Array::new_const(name.as_str(), &Sort::int(), &elem_sort))
+                    }
                 }
             }
 
@@ -857,6 +978,29 @@ impl<'a> Encoder<'a> {
                 Dynamic::from_ast(&z3::ast::forall_const(&bound_refs, &[], &body_encoded))
             }
 
+            VerifyExpr::ForAllTriggered { vars, triggers, body } => {
+                if vars.is_empty() {
+                    return self.encode(body);
+                }
+                let body_encoded = {
+                    let b = self.encode(body);
+                    b.as_bool().unwrap_or_else(|| Bool::from_bool(true))
+                };
+                let bound_consts: Vec<Dynamic> = vars.iter().map(|(name, ty)| {
+                    self.make_quantifier_var(name, ty)
+                }).collect();
+                let bound_refs: Vec<&dyn Ast> = bound_consts.iter().map(|d| d as &dyn Ast).collect();
+                // Bound variables are plain constants, so encoded trigger terms
+                // mention them exactly as the body does.
+                let patterns: Vec<Pattern> = triggers.iter().map(|terms| {
+                    let encoded: Vec<Dynamic> = terms.iter().map(|t| self.encode(t)).collect();
+                    let term_refs: Vec<&dyn Ast> = encoded.iter().map(|d| d as &dyn Ast).collect();
+                    Pattern::new(&term_refs)
+                }).collect();
+                let pattern_refs: Vec<&Pattern> = patterns.iter().collect();
+                Dynamic::from_ast(&z3::ast::forall_const(&bound_refs, &pattern_refs, &body_encoded))
+            }
+
             VerifyExpr::Exists { vars, body } => {
                 if vars.is_empty() {
                     return self.encode(body);
@@ -926,6 +1070,15 @@ impl<'a> Encoder<'a> {
                 }
             }
 
+            VerifyExpr::Length(seq) => {
+                // A store replaces an element; the length is the original's.
+                let mut root = seq.as_ref();
+                while let VerifyExpr::Store { array, .. } = root {
+                    root = array;
+                }
+                seq_length(&self.encode(root))
+            }
+
             // ---- Temporal (BMC) ----
 
             VerifyExpr::AtState { state: _, expr } => {
@@ -971,9 +1124,23 @@ impl<'a> Encoder<'a> {
                 let elem_sort = self.type_to_sort(elem);
                 z3::Sort::array(&idx_sort, &elem_sort)
             }
+            VerifyType::SeqOf(elem) => z3::Sort::array(&z3::Sort::int(), &self.type_to_sort(elem)),
         }
     }
 
+    /// Facts every declared variable satisfies by its type: sequences have
+    /// non-negative length.
+    pub(crate) fn declaration_axioms(&self) -> Vec<Bool> {
+        self.vars
+            .iter()
+            .filter(|(_, ty)| matches!(ty, VerifyType::SeqOf(_)))
+            .filter_map(|(name, _)| {
+                let length = VerifyExpr::length(VerifyExpr::var(name.as_str()));
+                self.encode(&VerifyExpr::gte(length, VerifyExpr::int(0))).as_bool()
+            })
+            .collect()
+    }
+
     fn make_quantifier_var(&self, name: &str, ty: &VerifyType) -> Dynamic {
         match ty {
             VerifyType::Int => Dynamic::from_ast(&Int::new_const(name)),
@@ -986,84 +1153,47 @@ impl<'a> Encoder<'a> {
                 let elem_sort = self.type_to_sort(elem);
                 Dynamic::from_ast(&z3::ast::Array::new_const(name, &idx_sort, &elem_sort))
             }
+            VerifyType::SeqOf(elem) => {
+                let elem_sort = self.type_to_sort(elem);
+                Dynamic::from_ast(&z3::ast::Array::new_const(name, &Sort::int(), &elem_sort))
+            }
         }
     }
 
     fn encode_binary(&self, op: &VerifyOp, l: Dynamic, r: Dynamic) -> Dynamic {
         match op {
-            // Arithmetic
-            VerifyOp::Add => {
-                if let (Some(li), Some(ri)) = (l.as_int(), r.as_int()) {
-                    Dynamic::from_ast(&(li + ri))
-                } else {
-                    l
-                }
-            }
-            VerifyOp::Sub => {
-                if let (Some(li), Some(ri)) = (l.as_int(), r.as_int()) {
-                    Dynamic::from_ast(&(li - ri))
-                } else {
-                    l
-                }
-            }
-            VerifyOp::Mul => {
-                if let (Some(li), Some(ri)) = (l.as_int(), r.as_int()) {
-                    Dynamic::from_ast(&(li * ri))
-                } else {
-                    l
-                }
-            }
-            VerifyOp::Div => {
-                if let (Some(li), Some(ri)) = (l.as_int(), r.as_int()) {
-                    Dynamic::from_ast(&(li / ri))
-                } else {
-                    l
-                }
-            }
-            // Floor division `a // b`: `to_int(to_real(a) / to_real(b))` — real division then the
-            // floor (`Real::to_int` is floor), exact toward -inf for every sign.
-            VerifyOp::FloorDiv => {
-                if let (Some(li), Some(ri)) = (l.as_int(), r.as_int()) {
-                    Dynamic::from_ast(&(li.to_real() / ri.to_real()).to_int())
-                } else {
-                    l
-                }
-            }
-
-            // Comparison
-            VerifyOp::Gt => {
-                if let (Some(li), Some(ri)) = (l.as_int(), r.as_int()) {
-                    Dynamic::from_ast(&li.gt(&ri))
-                } else {
-                    Dynamic::from_ast(&Bool::from_bool(false))
-                }
-            }
-            VerifyOp::Lt => {
-                if let (Some(li), Some(ri)) = (l.as_int(), r.as_int()) {
-                    Dynamic::from_ast(&li.lt(&ri))
-                } else {
-                    Dynamic::from_ast(&Bool::from_bool(false))
-                }
-            }
-            VerifyOp::Gte => {
-                if let (Some(li), Some(ri)) = (l.as_int(), r.as_int()) {
-                    Dynamic::from_ast(&li.ge(&ri))
-                } else {
+            // Arithmetic and comparison, on whatever sort the operands share
+            VerifyOp::Add
+            | VerifyOp::Sub
+            | VerifyOp::Mul
+            | VerifyOp::Div
+            | VerifyOp::FloorDiv
+            | VerifyOp::Gt
+            | VerifyOp::Lt
+            | VerifyOp::Gte
+            | VerifyOp::Lte => match numeric_operands(&l, &r) {
+                Some(operands) => encode_numeric(op, operands),
+                None if matches!(op, VerifyOp::Gt | VerifyOp::Lt | VerifyOp::Gte | VerifyOp::Lte) => {
                     Dynamic::from_ast(&Bool::from_bool(false))
                 }
-            }
-            VerifyOp::Lte => {
-                if let (Some(li), Some(ri)) = (l.as_int(), r.as_int()) {
-                    Dynamic::from_ast(&li.le(&ri))
+                None => l,
+            },
+
+            // Equality: numbers of different sorts compare as numbers
+            VerifyOp::Eq | VerifyOp::Neq => {
+                let equal = match numeric_operands(&l, &r) {
+                    Some(Operands::Int(a, b)) => a.eq(&b),
+                    Some(Operands::Real(a, b)) => a.eq(&b),
+                    Some(Operands::BitVec(a, b)) => a.eq(&b),
+                    None => l.eq(&r),
+                };
+                if *op == VerifyOp::Eq {
+                    Dynamic::from_ast(&equal)
                 } else {
-                    Dynamic::from_ast(&Bool::from_bool(false))
+                    Dynamic::from_ast(&equal.not())
                 }
             }
 
-            // Equality
-            VerifyOp::Eq => Dynamic::from_ast(&l.eq(&r)),
-            VerifyOp::Neq => Dynamic::from_ast(&l.eq(&r).not()),
-
             // Logic
             VerifyOp::And => {
                 if let (Some(lb), Some(rb)) = (l.as_bool(), r.as_bool()) {
@@ -1091,7 +1221,11 @@ impl<'a> Encoder<'a> {
 
     fn encode_bv_binary(&self, op: &crate::ir::BitVecOp, l: Dynamic, r: Dynamic) -> Dynamic {
         use crate::ir::BitVecOp;
-        if let (Some(lb), Some(rb)) = (l.as_bv(), r.as_bv()) {
+        // An integer operand takes the width of the other side — or 64 bits,
+        // LOGOS's `Int`, when both are integers — in two's complement.
+        let width = l.as_bv().or_else(|| r.as_bv()).map_or(64, |bv| bv.get_size());
+        let as_bv = |d: &Dynamic| d.as_bv().or_else(|| d.as_int().map(|i| BV::from_int(&i, width)));
+        if let (Some(lb), Some(rb)) = (as_bv(&l), as_bv(&r)) {
             match op {
                 BitVecOp::And => Dynamic::from_ast(&lb.bvand(&rb)),
                 BitVecOp::Or => Dynamic::from_ast(&lb.bvor(&rb)),
@@ -1130,6 +1264,88 @@ impl<'a> Encoder<'a> {
     }
 }
 
+/// The length of an encoded sequence: an uninterpreted `Int`-valued
+/// function of the underlying array, one per element sort, so equal
+/// sequences have equal lengths.
+pub(crate) fn seq_length(seq: &Dynamic) -> Dynamic {
+    let sort = seq.get_sort();
+    let length = FuncDecl::new(format!("length<{}>", sort), &[&sort], &Sort::int());
+    Dynamic::from_ast(&length.apply(&[seq as &dyn Ast]))
+}
+
+/// Both operands of an arithmetic or comparison operator, in one sort.
+enum Operands {
+    Int(Int, Int),
+    Real(Real, Real),
+    BitVec(BV, BV),
+}
+
+/// Bring two numeric operands to a common sort.
+///
+/// Equal-width bitvectors stay bitvectors. Otherwise the wider sort wins:
+/// an `Int` meeting a `Real` is promoted to `Real`, and a bitvector meeting
+/// anything else is read as the signed integer it holds. `None` if either
+/// side isn't a number.
+fn numeric_operands(l: &Dynamic, r: &Dynamic) -> Option<Operands> {
+    if let (Some(a), Some(b)) = (l.as_bv(), r.as_bv()) {
+        if a.get_size() == b.get_size() {
+            return Some(Operands::BitVec(a, b));
+        }
+    }
+    let as_int = |d: &Dynamic| d.as_int().or_else(|| d.as_bv().map(|bv| bv.to_int(true)));
+    if l.as_real().is_some() || r.as_real().is_some() {
+        let as_real = |d: &Dynamic| d.as_real().or_else(|| as_int(d).map(|i| i.to_real()));
+        return Some(Operands::Real(as_real(l)?, as_real(r)?));
+    }
+    Some(Operands::Int(as_int(l)?, as_int(r)?))
+}
+
+/// Encode an arithmetic or comparison operator over operands of one sort.
+///
+/// Bitvector arithmetic wraps and compares signed; floor division of
+/// bitvectors goes through their integer values.
+fn encode_numeric(op: &VerifyOp, operands: Operands) -> Dynamic {
+    match (op, operands) {
+        (VerifyOp::Add, Operands::Int(a, b)) => Dynamic::from_ast(&(a + b)),
+        (VerifyOp::Sub, Operands::Int(a, b)) => Dynamic::from_ast(&(a - b)),
+        (VerifyOp::Mul, Operands::Int(a, b)) => Dynamic::from_ast(&(a * b)),
+        (VerifyOp::Div, Operands::Int(a, b)) => Dynamic::from_ast(&(a / b)),
+        // Floor division `a // b`: `to_int(to_real(a) / to_real(b))` — real division then the
+        // floor (`Real::to_int` is floor), exact toward -inf for every sign.
+        (VerifyOp::FloorDiv, Operands::Int(a, b)) => {
+            Dynamic::from_ast(&(a.to_real() / b.to_real()).to_int())
+        }
+        (VerifyOp::Gt, Operands::Int(a, b)) => Dynamic::from_ast(&a.gt(&b)),
+        (VerifyOp::Lt, Operands::Int(a, b)) => Dynamic::from_ast(&a.lt(&b)),
+        (VerifyOp::Gte, Operands::Int(a, b)) => Dynamic::from_ast(&a.ge(&b)),
+        (VerifyOp::Lte, Operands::Int(a, b)) => Dynamic::from_ast(&a.le(&b)),
+
+        (VerifyOp::Add, Operands::Real(a, b)) => Dynamic::from_ast(&(a + b)),
+        (VerifyOp::Sub, Operands::Real(a, b)) => Dynamic::from_ast(&(a - b)),
+        (VerifyOp::Mul, Operands::Real(a, b)) => Dynamic::from_ast(&(a * b)),
+        (VerifyOp::Div, Operands::Real(a, b)) => Dynamic::from_ast(&(a / b)),
+        (VerifyOp::FloorDiv, Operands::Real(a, b)) => Dynamic::from_ast(&(a / b).to_int().to_real()),
+        (VerifyOp::Gt, Operands::Real(a, b)) => Dynamic::from_ast(&a.gt(&b)),
+        (VerifyOp::Lt, Operands::Real(a, b)) => Dynamic::from_ast(&a.lt(&b)),
+        (VerifyOp::Gte, Operands::Real(a, b)) => Dynamic::from_ast(&a.ge(&b)),
+        (VerifyOp::Lte, Operands::Real(a, b)) => Dynamic::from_ast(&a.le(&b)),
+
+        (VerifyOp::Add, Operands::BitVec(a, b)) => Dynamic::from_ast(&a.bvadd(&b)),
+        (VerifyOp::Sub, Operands::BitVec(a, b)) => Dynamic::from_ast(&a.bvsub(&b)),
+        (VerifyOp::Mul, Operands::BitVec(a, b)) => Dynamic::from_ast(&a.bvmul(&b)),
+        (VerifyOp::Div, Operands::BitVec(a, b)) => Dynamic::from_ast(&a.bvsdiv(&b)),
+        (VerifyOp::FloorDiv, Operands::BitVec(a, b)) => {
+            encode_numeric(op, Operands::Int(a.to_int(true), b.to_int(true)))
+        }
+        (VerifyOp::Gt, Operands::BitVec(a, b)) => Dynamic::from_ast(&a.bvsgt(&b)),
+        (VerifyOp::Lt, Operands::BitVec(a, b)) => Dynamic::from_ast(&a.bvslt(&b)),
+        (VerifyOp::Gte, Operands::BitVec(a, b)) => Dynamic::from_ast(&a.bvsge(&b)),
+        (VerifyOp::Lte, Operands::BitVec(a, b)) => Dynamic::from_ast(&a.bvsle(&b)),
+
+        (op, _) => unreachable!("{:?} is not an arithmetic or comparison operator", op),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,18 +174,21 @@ fn is_small_property(expr: &VerifyExpr) -> bool {
 
 fn expr_size(expr: &VerifyExpr) -> usize {
     match expr {
-        VerifyExpr::Bool(_) | VerifyExpr::Int(_) | VerifyExpr::Var(_) => 1,
+        VerifyExpr::Bool(_) | VerifyExpr::Int(_) | VerifyExpr::RealConst { .. } | VerifyExpr::Var(_) => 1,
         VerifyExpr::BitVecConst { .. } => 1,
         VerifyExpr::Binary { left, right, .. } => 1 + expr_size(left) + expr_size(right),
         VerifyExpr::Not(inner) => 1 + expr_size(inner),
         VerifyExpr::Iff(l, r) => 1 + expr_size(l) + expr_size(r),
-        VerifyExpr::ForAll { body, .. } | VerifyExpr::Exists { body, .. } => 1 + expr_size(body),
+        VerifyExpr::ForAll { body, .. }
+        | VerifyExpr::ForAllTriggered { body, .. }
+        | VerifyExpr::Exists { body, .. } => 1 + expr_size(body),
         VerifyExpr::Apply { args, .. } | VerifyExpr::ApplyInt { args, .. } => 1 + args.iter().map(expr_size).sum::<usize>(),
         VerifyExpr::BitVecBinary { left, right, .. } => 1 + expr_size(left) + expr_size(right),
         VerifyExpr::BitVecExtract { operand, .. } => 1 + expr_size(operand),
         VerifyExpr::BitVecConcat(l, r) => 1 + expr_size(l) + expr_size(r),
         VerifyExpr::Select { array, index } => 1 + expr_size(array) + expr_size(index),
         VerifyExpr::Store { array, index, value } => 1 + expr_size(array) + expr_size(index) + expr_size(value),
+        VerifyExpr::Length(seq) => 1 + expr_size(seq),
         VerifyExpr::AtState { state, expr } => 1 + expr_size(state) + expr_size(expr),
         VerifyExpr::Transition { from, to } => 1 + expr_size(from) + expr_size(to),
     }
@@ -43,7 +43,7 @@ fn collect_constraints(
     constraints: &mut HashMap<String, VerifyType>,
 ) -> Result<(), TypeError> {
     match expr {
-        VerifyExpr::Bool(_) | VerifyExpr::Int(_) => Ok(()),
+        VerifyExpr::Bool(_) | VerifyExpr::Int(_) | VerifyExpr::RealConst { .. } => Ok(()),
 
         VerifyExpr::Var(_) => Ok(()),
 
@@ -54,22 +54,21 @@ fn collect_constraints(
                     constrain_as_bool(left, constraints)?;
                     constrain_as_bool(right, constraints)?;
                 }
-                // Arithmetic operators: operands must be Int
-                VerifyOp::Add | VerifyOp::Sub | VerifyOp::Mul | VerifyOp::Div | VerifyOp::FloorDiv => {
-                    constrain_as_int(left, constraints)?;
-                    constrain_as_int(right, constraints)?;
-                }
-                // Comparison operators: operands must be Int
-                VerifyOp::Gt | VerifyOp::Lt | VerifyOp::Gte | VerifyOp::Lte => {
-                    constrain_as_int(left, constraints)?;
-                    constrain_as_int(right, constraints)?;
+                // Arithmetic and comparison operators: operands must be
+                // numbers — Real if either side is, Int otherwise
+                VerifyOp::Add | VerifyOp::Sub | VerifyOp::Mul | VerifyOp::Div | VerifyOp::FloorDiv
+                | VerifyOp::Gt | VerifyOp::Lt | VerifyOp::Gte | VerifyOp::Lte => {
+                    let sort = numeric_sort(left, right);
+                    constrain_as_number(left, &sort, constraints)?;
+                    constrain_as_number(right, &sort, constraints)?;
                 }
                 // Equality: operands must match but could be any sort
                 VerifyOp::Eq | VerifyOp::Neq => {
-                    // If either side is clearly Int, constrain both
+                    // If either side is clearly numeric, constrain both
                     if expr_suggests_int(left) || expr_suggests_int(right) {
-                        constrain_as_int(left, constraints)?;
-                        constrain_as_int(right, constraints)?;
+                        let sort = numeric_sort(left, right);
+                        constrain_as_number(left, &sort, constraints)?;
+                        constrain_as_number(right, &sort, constraints)?;
                     }
                 }
             }
@@ -88,7 +87,9 @@ fn collect_constraints(
             collect_constraints(r, constraints)
         }
 
-        VerifyExpr::ForAll { body, vars } | VerifyExpr::Exists { body, vars } => {
+        VerifyExpr::ForAll { body, vars }
+        | VerifyExpr::ForAllTriggered { body, vars, .. }
+        | VerifyExpr::Exists { body, vars } => {
             for (name, ty) in vars {
                 add_constraint(name, ty.clone(), constraints)?;
             }
@@ -149,6 +150,18 @@ fn collect_constraints(
             collect_constraints(value, constraints)
         }
 
+        VerifyExpr::Length(seq) => {
+            if let VerifyExpr::Var(name) = seq.as_ref() {
+                // Keep an element type already learned from a store
+                let elem = match constraints.get(name) {
+                    Some(VerifyType::Array(_, elem) | VerifyType::SeqOf(elem)) => (**elem).clone(),
+                    _ => VerifyType::Int, // default
+                };
+                add_constraint(name, VerifyType::SeqOf(Box::new(elem)), constraints)?;
+            }
+            collect_constraints(seq, constraints)
+        }
+
         VerifyExpr::AtState { state, expr } => {
             collect_constraints(state, constraints)?;
             collect_constraints(expr, constraints)
@@ -171,17 +184,18 @@ fn constrain_as_bool(
     Ok(())
 }
 
-fn constrain_as_int(
+fn constrain_as_number(
     expr: &VerifyExpr,
+    sort: &VerifyType,
     constraints: &mut HashMap<String, VerifyType>,
 ) -> Result<(), TypeError> {
     if let VerifyExpr::Var(name) = expr {
-        add_constraint(name, VerifyType::Int, constraints)?;
+        add_constraint(name, sort.clone(), constraints)?;
     }
     // Also handle nested binary expressions with Var leaves
     if let VerifyExpr::Binary { left, right, .. } = expr {
-        constrain_as_int(left, constraints)?;
-        constrain_as_int(right, constraints)?;
+        constrain_as_number(left, sort, constraints)?;
+        constrain_as_number(right, sort, constraints)?;
     }
     Ok(())
 }
@@ -230,6 +244,12 @@ fn types_compatible(a: &VerifyType, b: &VerifyType) -> bool {
         (VerifyType::Array(i1, e1), VerifyType::Array(i2, e2)) => {
             types_compatible(i1, i2) && types_compatible(e1, e2)
         }
+        (VerifyType::SeqOf(e1), VerifyType::SeqOf(e2)) => types_compatible(e1, e2),
+        // A sequence is an Int-indexed array
+        (VerifyType::Array(i, e1), VerifyType::SeqOf(e2))
+        | (VerifyType::SeqOf(e2), VerifyType::Array(i, e1)) => {
+            types_compatible(i, &VerifyType::Int) && types_compatible(e1, e2)
+        }
         _ => false,
     }
 }
@@ -242,6 +262,7 @@ fn type_specificity(ty: &VerifyType) -> u8 {
         VerifyType::Real => 1,
         VerifyType::BitVector(_) => 2,
         VerifyType::Array(_, _) => 2,
+        VerifyType::SeqOf(_) => 3,
     }
 }
 
@@ -260,6 +281,7 @@ fn expr_suggests_int(expr: &VerifyExpr) -> bool {
     matches!(
         expr,
         VerifyExpr::Int(_)
+            | VerifyExpr::RealConst { .. }
             | VerifyExpr::Binary {
                 op: VerifyOp::Add | VerifyOp::Sub | VerifyOp::Mul | VerifyOp::Div | VerifyOp::FloorDiv,
                 ..
@@ -267,7 +289,33 @@ fn expr_suggests_int(expr: &VerifyExpr) -> bool {
     )
 }
 
+/// Whether an arithmetic expression is real-valued: it contains a real
+/// literal somewhere in its arithmetic.
+pub(crate) fn expr_suggests_real(expr: &VerifyExpr) -> bool {
+    match expr {
+        VerifyExpr::RealConst { .. } => true,
+        VerifyExpr::Binary {
+            op: VerifyOp::Add | VerifyOp::Sub | VerifyOp::Mul | VerifyOp::Div | VerifyOp::FloorDiv,
+            left,
+            right,
+        } => expr_suggests_real(left) || expr_suggests_real(right),
+        _ => false,
+    }
+}
+
+/// The sort both operands of an arithmetic or comparison operator take.
+fn numeric_sort(left: &VerifyExpr, right: &VerifyExpr) -> VerifyType {
+    if expr_suggests_real(left) || expr_suggests_real(right) {
+        VerifyType::Real
+    } else {
+        VerifyType::Int
+    }
+}
+
 fn infer_sort_of(expr: &VerifyExpr) -> VerifyType {
+    if expr_suggests_real(expr) {
+        return VerifyType::Real;
+    }
     match expr {
         VerifyExpr::Int(_) => VerifyType::Int,
         VerifyExpr::Bool(_) => VerifyType::Bool,
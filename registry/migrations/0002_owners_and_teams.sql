@@ -0,0 +1,47 @@
+-- LOGOS Package Registry Schema
+-- Package owners and teams (`largo owner`, `largo login --team`)
+
+-- Teams: named groups of users that can own packages
+CREATE TABLE IF NOT EXISTS teams (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE,        -- Team name (same charset as logins)
+    created_by TEXT NOT NULL,
+    created_at TEXT DEFAULT (datetime('now')),
+    FOREIGN KEY (created_by) REFERENCES users(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_teams_name ON teams(name);
+
+-- Team membership
+CREATE TABLE IF NOT EXISTS team_members (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    team_id INTEGER NOT NULL,
+    user_id TEXT NOT NULL,
+    role TEXT DEFAULT 'member',       -- admin (manages members), member
+    created_at TEXT DEFAULT (datetime('now')),
+    FOREIGN KEY (team_id) REFERENCES teams(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE(team_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_team_members_user ON team_members(user_id);
+
+-- Teams that co-own a package (the team counterpart of collaborators)
+CREATE TABLE IF NOT EXISTS package_teams (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    package_id INTEGER NOT NULL,
+    team_id INTEGER NOT NULL,
+    role TEXT DEFAULT 'maintainer',   -- owner, maintainer
+    created_at TEXT DEFAULT (datetime('now')),
+    FOREIGN KEY (package_id) REFERENCES packages(id) ON DELETE CASCADE,
+    FOREIGN KEY (team_id) REFERENCES teams(id) ON DELETE CASCADE,
+    UNIQUE(package_id, team_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_package_teams_package ON package_teams(package_id);
+
+-- A token issued to a team acts for the team, not for its creator
+ALTER TABLE api_tokens ADD COLUMN team_id INTEGER REFERENCES teams(id);
+
+-- A team that is a package's primary owner (NULL = owner_id is)
+ALTER TABLE packages ADD COLUMN owner_team_id INTEGER REFERENCES teams(id);
@@ -6,6 +6,8 @@
 import { handlePackagesList, handlePackageInfo, handleVersionInfo } from './routes/packages.js';
 import { handlePublish } from './routes/publish.js';
 import { handleDownload } from './routes/download.js';
+import { handleYank, handleListOwners, handleAddOwners, handleRemoveOwners, handleTransferOwnership } from './routes/owners.js';
+import { handleCreateTeam, handleTeamInfo, handleAddMembers, handleRemoveMembers } from './routes/teams.js';
 import { handleGitHubAuth, handleGitHubCallback, handleMe, handleCreateToken, handleListTokens, handleRevokeToken } from './routes/auth.js';
 
 const CORS_HEADERS = {
@@ -68,6 +70,23 @@ export default {
         return handlePackageInfo(request, env, packageMatch[1]);
       }
 
+      // ========== Owner Routes (listing public, changes auth required) ==========
+      // Matched before /packages/:name/:version, which would take `owners` as a version
+      const ownersMatch = path.match(/^\/packages\/([a-z0-9_-]+)\/owners$/i);
+      if (ownersMatch && request.method === 'GET') {
+        return handleListOwners(request, env, ownersMatch[1]);
+      }
+      if (ownersMatch && request.method === 'PUT') {
+        return withAuth(request, env, (req, e, user) => handleAddOwners(req, e, user, ownersMatch[1]));
+      }
+      if (ownersMatch && request.method === 'DELETE') {
+        return withAuth(request, env, (req, e, user) => handleRemoveOwners(req, e, user, ownersMatch[1]));
+      }
+      const transferMatch = path.match(/^\/packages\/([a-z0-9_-]+)\/owners\/transfer$/i);
+      if (transferMatch && request.method === 'POST') {
+        return withAuth(request, env, (req, e, user) => handleTransferOwnership(req, e, user, transferMatch[1]));
+      }
+
       // Match /packages/:name/:version
       const versionMatch = path.match(/^\/packages\/([a-z0-9_-]+)\/([a-z0-9._-]+)$/i);
       if (versionMatch && request.method === 'GET') {
@@ -85,6 +104,32 @@ export default {
         return withAuth(request, env, handlePublish);
       }
 
+      // ========== Yank Routes (auth required) ==========
+      const yankMatch = path.match(/^\/packages\/([a-z0-9_-]+)\/([a-z0-9._-]+)\/yank$/i);
+      if (yankMatch && request.method === 'DELETE') {
+        return withAuth(request, env, (req, e, user) => handleYank(req, e, user, yankMatch[1], yankMatch[2], true));
+      }
+      const unyankMatch = path.match(/^\/packages\/([a-z0-9_-]+)\/([a-z0-9._-]+)\/unyank$/i);
+      if (unyankMatch && request.method === 'PUT') {
+        return withAuth(request, env, (req, e, user) => handleYank(req, e, user, unyankMatch[1], unyankMatch[2], false));
+      }
+
+      // ========== Team Routes ==========
+      if (path === '/teams' && request.method === 'POST') {
+        return withAuth(request, env, handleCreateTeam);
+      }
+      const teamMatch = path.match(/^\/teams\/([a-z0-9_-]+)$/i);
+      if (teamMatch && request.method === 'GET') {
+        return handleTeamInfo(request, env, teamMatch[1]);
+      }
+      const membersMatch = path.match(/^\/teams\/([a-z0-9_-]+)\/members$/i);
+      if (membersMatch && request.method === 'PUT') {
+        return withAuth(request, env, (req, e, user) => handleAddMembers(req, e, user, membersMatch[1]));
+      }
+      if (membersMatch && request.method === 'DELETE') {
+        return withAuth(request, env, (req, e, user) => handleRemoveMembers(req, e, user, membersMatch[1]));
+      }
+
       return jsonResponse({ error: 'Not found' }, 404);
     } catch (error) {
       console.error('Unhandled error:', error);
@@ -137,11 +182,15 @@ async function authenticateApiToken(token, env) {
   const tokenHash = await sha256(token);
 
   const result = await env.DB.prepare(`
-    SELECT u.*, t.scopes, t.id as token_id
+    SELECT u.*, t.scopes, t.id as token_id, tm.id as team_id, tm.name as team_name
     FROM api_tokens t
     JOIN users u ON t.user_id = u.id
+    LEFT JOIN teams tm ON t.team_id = tm.id
     WHERE t.token_hash = ?
     AND (t.expires_at IS NULL OR t.expires_at > datetime('now'))
+    AND (t.team_id IS NULL OR EXISTS (
+      SELECT 1 FROM team_members m WHERE m.team_id = t.team_id AND m.user_id = t.user_id
+    ))
   `).bind(tokenHash).first();
 
   if (!result) {
@@ -162,6 +211,8 @@ async function authenticateApiToken(token, env) {
     is_banned: result.is_banned === 1,
     ban_reason: result.ban_reason,
     scopes: result.scopes ? result.scopes.split(',') : ['publish'],
+    // Set for team tokens, which act for the team rather than the user
+    team: result.team_id != null ? { id: result.team_id, name: result.team_name } : null,
   };
 }
 
@@ -212,6 +263,7 @@ async function authenticateJwt(token, env) {
       is_banned: user.is_banned === 1,
       ban_reason: user.ban_reason,
       scopes: ['publish', 'yank'], // Session tokens have full access
+      team: null,
     };
   } catch {
     return null;
@@ -110,6 +110,82 @@ describe('Registry API', () => {
     });
   });
 
+  describe('Owner Routes', () => {
+    it('GET /packages/:name/owners is not taken for a version', async () => {
+      const response = await SELF.fetch('https://registry.logicaffeine.com/packages/nonexistent/owners');
+      expect(response.status).toBe(404);
+
+      const data = await response.json();
+      expect(data.error).toBe('Package not found');
+    });
+
+    it('PUT /packages/:name/owners requires authentication', async () => {
+      const response = await SELF.fetch('https://registry.logicaffeine.com/packages/test/owners', {
+        method: 'PUT',
+        body: JSON.stringify({ owners: ['alice'] }),
+      });
+      expect(response.status).toBe(401);
+    });
+
+    it('DELETE /packages/:name/owners requires authentication', async () => {
+      const response = await SELF.fetch('https://registry.logicaffeine.com/packages/test/owners', {
+        method: 'DELETE',
+        body: JSON.stringify({ owners: ['alice'] }),
+      });
+      expect(response.status).toBe(401);
+    });
+
+    it('POST /packages/:name/owners/transfer requires authentication', async () => {
+      const response = await SELF.fetch('https://registry.logicaffeine.com/packages/test/owners/transfer', {
+        method: 'POST',
+        body: JSON.stringify({ owner: 'team:core' }),
+      });
+      expect(response.status).toBe(401);
+    });
+  });
+
+  describe('Yank Routes', () => {
+    it('DELETE /packages/:name/:version/yank requires authentication', async () => {
+      const response = await SELF.fetch('https://registry.logicaffeine.com/packages/test/1.0.0/yank', {
+        method: 'DELETE',
+      });
+      expect(response.status).toBe(401);
+    });
+
+    it('PUT /packages/:name/:version/unyank requires authentication', async () => {
+      const response = await SELF.fetch('https://registry.logicaffeine.com/packages/test/1.0.0/unyank', {
+        method: 'PUT',
+      });
+      expect(response.status).toBe(401);
+    });
+  });
+
+  describe('Team Routes', () => {
+    it('GET /teams/:name returns 404 for nonexistent team', async () => {
+      const response = await SELF.fetch('https://registry.logicaffeine.com/teams/nonexistent');
+      expect(response.status).toBe(404);
+
+      const data = await response.json();
+      expect(data.error).toBe('Team not found');
+    });
+
+    it('POST /teams requires authentication', async () => {
+      const response = await SELF.fetch('https://registry.logicaffeine.com/teams', {
+        method: 'POST',
+        body: JSON.stringify({ name: 'core' }),
+      });
+      expect(response.status).toBe(401);
+    });
+
+    it('PUT /teams/:name/members requires authentication', async () => {
+      const response = await SELF.fetch('https://registry.logicaffeine.com/teams/core/members', {
+        method: 'PUT',
+        body: JSON.stringify({ logins: ['alice'] }),
+      });
+      expect(response.status).toBe(401);
+    });
+  });
+
   describe('404 Handling', () => {
     it('Unknown routes return 404', async () => {
       const response = await SELF.fetch('https://registry.logicaffeine.com/unknown/route');
@@ -156,9 +156,10 @@ export async function handleMe(request, env, user) {
  */
 export async function handleListTokens(request, env, user) {
   const tokens = await env.DB.prepare(`
-    SELECT id, name, scopes, last_used_at, expires_at, created_at
-    FROM api_tokens WHERE user_id = ?
-    ORDER BY created_at DESC
+    SELECT t.id, t.name, t.scopes, t.last_used_at, t.expires_at, t.created_at, tm.name AS team
+    FROM api_tokens t LEFT JOIN teams tm ON t.team_id = tm.id
+    WHERE t.user_id = ?
+    ORDER BY t.created_at DESC
   `).bind(user.id).all();
 
   return jsonResponse({
@@ -166,6 +167,7 @@ export async function handleListTokens(request, env, user) {
       id: t.id,
       name: t.name,
       scopes: t.scopes ? t.scopes.split(',') : ['publish'],
+      team: t.team,
       last_used_at: t.last_used_at,
       expires_at: t.expires_at,
       created_at: t.created_at,
@@ -175,7 +177,10 @@ export async function handleListTokens(request, env, user) {
 
 /**
  * POST /auth/tokens - Create new API token
- * Body: { name: string, expires_in_days?: number }
+ * Body: { name: string, expires_in_days?: number, team?: string }
+ *
+ * With `team`, the token acts for that team (which the user must belong to)
+ * rather than for the user, and stops working if they leave it.
  */
 export async function handleCreateToken(request, env, user) {
   let body;
@@ -192,6 +197,24 @@ export async function handleCreateToken(request, env, user) {
     return jsonResponse({ error: 'Token name too long (max 100 chars)' }, 400);
   }
 
+  let team = null;
+  if (body.team !== undefined) {
+    if (user.team) {
+      return jsonResponse({ error: 'Team tokens cannot create tokens' }, 403);
+    }
+    team = await env.DB.prepare(`
+      SELECT tm.id, tm.name FROM teams tm
+      JOIN team_members m ON m.team_id = tm.id
+      WHERE tm.name = ? AND m.user_id = ?
+    `).bind(String(body.team).toLowerCase(), user.id).first();
+    if (!team) {
+      return jsonResponse({
+        error: 'Team not found',
+        message: `You are not a member of a team named '${body.team}'`,
+      }, 404);
+    }
+  }
+
   // Generate secure random token
   const tokenBytes = new Uint8Array(32);
   crypto.getRandomValues(tokenBytes);
@@ -210,15 +233,16 @@ export async function handleCreateToken(request, env, user) {
   }
 
   await env.DB.prepare(`
-    INSERT INTO api_tokens (id, user_id, name, token_hash, scopes, expires_at)
-    VALUES (?, ?, ?, ?, 'publish', ?)
-  `).bind(tokenId, user.id, name, tokenHash, expiresAt).run();
+    INSERT INTO api_tokens (id, user_id, name, token_hash, scopes, expires_at, team_id)
+    VALUES (?, ?, ?, ?, 'publish', ?, ?)
+  `).bind(tokenId, user.id, name, tokenHash, expiresAt, team ? team.id : null).run();
 
   return jsonResponse({
     token, // Only returned once! User must save it.
     token_id: tokenId,
     name,
     scopes: ['publish'],
+    team: team ? team.name : null,
     expires_at: expiresAt,
     warning: 'Save this token now. It will not be shown again.',
   }, 201);
@@ -0,0 +1,332 @@
+/**
+ * LOGOS Package Registry - Ownership Routes
+ * DELETE /packages/:name/:version/yank - Yank a version
+ * PUT /packages/:name/:version/unyank - Undo a yank
+ * GET /packages/:name/owners - List owners
+ * PUT /packages/:name/owners - Add owners
+ * DELETE /packages/:name/owners - Remove owners
+ * POST /packages/:name/owners/transfer - Change the primary owner
+ *
+ * Owners are users (`alice`) or teams (`team:core`). A package has one
+ * primary owner — the user in `packages.owner_id`, or the team in
+ * `packages.owner_team_id` when a team holds it — plus users in
+ * `collaborators` and teams in `package_teams`. Role `owner` may manage the
+ * owners; every owner may publish and yank.
+ */
+
+import { jsonResponse } from '../index.js';
+
+/**
+ * What `user` may do with `pkg`: 'owner', 'maintainer', or null.
+ *
+ * A team token (`user.team`) acts only through its team's ownership; a
+ * personal token through the user's own, or through the teams they belong to.
+ */
+export async function packageAccess(env, pkg, user) {
+  if (user.team) {
+    if (pkg.owner_team_id === user.team.id) return 'owner';
+    const row = await env.DB.prepare(
+      'SELECT role FROM package_teams WHERE package_id = ? AND team_id = ?'
+    ).bind(pkg.id, user.team.id).first();
+    return row ? accessFor(row.role) : null;
+  }
+
+  if (pkg.owner_team_id == null && pkg.owner_id === user.id) return 'owner';
+
+  const rows = await env.DB.prepare(`
+    SELECT role FROM collaborators WHERE package_id = ? AND user_id = ?
+    UNION ALL
+    SELECT pt.role FROM package_teams pt
+    JOIN team_members m ON m.team_id = pt.team_id
+    WHERE pt.package_id = ? AND m.user_id = ?
+    UNION ALL
+    SELECT 'owner' FROM team_members
+    WHERE team_id = ? AND user_id = ?
+  `).bind(pkg.id, user.id, pkg.id, user.id, pkg.owner_team_id, user.id).all();
+
+  const roles = rows.results.map(r => accessFor(r.role));
+  if (roles.includes('owner')) return 'owner';
+  return roles.length > 0 ? 'maintainer' : null;
+}
+
+/**
+ * DELETE /packages/:name/:version/yank and PUT /packages/:name/:version/unyank
+ *
+ * A yanked version stays downloadable; it is only hidden from new resolution.
+ */
+export async function handleYank(request, env, user, name, version, yanked) {
+  const pkg = await findPackage(env, name);
+  if (!pkg) {
+    return jsonResponse({ error: 'Package not found' }, 404);
+  }
+  if (!await packageAccess(env, pkg, user)) {
+    return permissionDenied(pkg.name, 'yank versions of');
+  }
+
+  const result = await env.DB.prepare(
+    'UPDATE versions SET yanked = ? WHERE package_id = ? AND version = ?'
+  ).bind(yanked ? 1 : 0, pkg.id, version).run();
+
+  if (!result.meta.changes) {
+    return jsonResponse({ error: 'Version not found' }, 404);
+  }
+
+  return jsonResponse({ success: true, package: pkg.name, version, yanked });
+}
+
+/**
+ * GET /packages/:name/owners - List owners
+ * Response: { owners: [{ login, kind: 'user'|'team', role }] }, primary first
+ */
+export async function handleListOwners(request, env, name) {
+  const pkg = await findPackage(env, name);
+  if (!pkg) {
+    return jsonResponse({ error: 'Package not found' }, 404);
+  }
+
+  return jsonResponse({ owners: await listOwners(env, pkg) });
+}
+
+/**
+ * PUT /packages/:name/owners - Add owners
+ * Body: { owners: string[] } — logins, or `team:<name>` for teams
+ *
+ * Users become owners; teams become maintainers, so every token issued to
+ * the team can publish and yank.
+ */
+export async function handleAddOwners(request, env, user, name) {
+  const checked = await checkOwnerRequest(request, env, user, name);
+  if (checked.response) return checked.response;
+  const { pkg, owners } = checked;
+
+  const statements = owners
+    .filter(owner => !isPrimary(pkg, owner))
+    .map(owner => owner.kind === 'team'
+      ? env.DB.prepare(`
+          INSERT INTO package_teams (package_id, team_id, role) VALUES (?, ?, 'maintainer')
+          ON CONFLICT(package_id, team_id) DO NOTHING
+        `).bind(pkg.id, owner.id)
+      : env.DB.prepare(`
+          INSERT INTO collaborators (package_id, user_id, role) VALUES (?, ?, 'owner')
+          ON CONFLICT(package_id, user_id) DO UPDATE SET role = 'owner'
+        `).bind(pkg.id, owner.id));
+  if (statements.length > 0) {
+    await env.DB.batch(statements);
+  }
+
+  return jsonResponse({ success: true, owners: await listOwners(env, pkg) });
+}
+
+/**
+ * DELETE /packages/:name/owners - Remove owners
+ * Body: { owners: string[] }
+ *
+ * The primary owner cannot be removed, so a package always keeps one;
+ * transfer ownership first.
+ */
+export async function handleRemoveOwners(request, env, user, name) {
+  const checked = await checkOwnerRequest(request, env, user, name);
+  if (checked.response) return checked.response;
+  const { pkg, owners } = checked;
+
+  const primary = owners.find(owner => isPrimary(pkg, owner));
+  if (primary) {
+    return jsonResponse({
+      error: 'Cannot remove the primary owner',
+      message: `${ownerSpec(primary)} is the primary owner of ${pkg.name}; transfer ownership first`,
+    }, 400);
+  }
+
+  const current = (await listOwners(env, pkg)).map(o => ownerSpec(o));
+  const stranger = owners.find(owner => !current.includes(ownerSpec(owner)));
+  if (stranger) {
+    return jsonResponse({
+      error: 'Not an owner',
+      message: `${ownerSpec(stranger)} is not an owner of ${pkg.name}`,
+    }, 400);
+  }
+
+  await env.DB.batch(owners.map(owner => owner.kind === 'team'
+    ? env.DB.prepare('DELETE FROM package_teams WHERE package_id = ? AND team_id = ?')
+      .bind(pkg.id, owner.id)
+    : env.DB.prepare('DELETE FROM collaborators WHERE package_id = ? AND user_id = ?')
+      .bind(pkg.id, owner.id)));
+
+  return jsonResponse({ success: true, owners: await listOwners(env, pkg) });
+}
+
+/**
+ * POST /packages/:name/owners/transfer - Change the primary owner
+ * Body: { owner: string }
+ *
+ * The previous primary owner stays on as a maintainer.
+ */
+export async function handleTransferOwnership(request, env, user, name) {
+  let body;
+  try {
+    body = await request.json();
+  } catch {
+    return jsonResponse({ error: 'Invalid JSON body' }, 400);
+  }
+
+  const checked = await checkOwnerRequest(null, env, user, name, [body?.owner]);
+  if (checked.response) return checked.response;
+  const { pkg, owners: [owner] } = checked;
+
+  if (isPrimary(pkg, owner)) {
+    return jsonResponse({ success: true, owners: await listOwners(env, pkg) });
+  }
+
+  const statements = [];
+
+  // Demote the previous primary owner to maintainer
+  if (pkg.owner_team_id != null) {
+    statements.push(env.DB.prepare(`
+      INSERT INTO package_teams (package_id, team_id, role) VALUES (?, ?, 'maintainer')
+      ON CONFLICT(package_id, team_id) DO UPDATE SET role = 'maintainer'
+    `).bind(pkg.id, pkg.owner_team_id));
+  } else {
+    statements.push(env.DB.prepare(`
+      INSERT INTO collaborators (package_id, user_id, role) VALUES (?, ?, 'maintainer')
+      ON CONFLICT(package_id, user_id) DO UPDATE SET role = 'maintainer'
+    `).bind(pkg.id, pkg.owner_id));
+  }
+
+  // Promote the new one, who no longer needs a secondary entry
+  if (owner.kind === 'team') {
+    statements.push(
+      env.DB.prepare(`UPDATE packages SET owner_team_id = ?, updated_at = datetime('now') WHERE id = ?`)
+        .bind(owner.id, pkg.id),
+      env.DB.prepare('DELETE FROM package_teams WHERE package_id = ? AND team_id = ?')
+        .bind(pkg.id, owner.id),
+    );
+  } else {
+    statements.push(
+      env.DB.prepare(`
+        UPDATE packages SET owner_id = ?, owner_team_id = NULL, updated_at = datetime('now') WHERE id = ?
+      `).bind(owner.id, pkg.id),
+      env.DB.prepare('DELETE FROM collaborators WHERE package_id = ? AND user_id = ?')
+        .bind(pkg.id, owner.id),
+    );
+  }
+
+  await env.DB.batch(statements);
+
+  const updated = await findPackage(env, pkg.name);
+  return jsonResponse({ success: true, owners: await listOwners(env, updated) });
+}
+
+// ========== Helpers ==========
+
+async function findPackage(env, name) {
+  return env.DB.prepare('SELECT * FROM packages WHERE name = ?')
+    .bind(name.toLowerCase()).first();
+}
+
+/**
+ * The shared checks of the owner-changing routes: the package exists, the
+ * caller may manage its owners, and every owner named exists. Answers
+ * `{ response }` on failure, else `{ pkg, owners }`.
+ */
+async function checkOwnerRequest(request, env, user, name, specs) {
+  if (!specs) {
+    let body;
+    try {
+      body = await request.json();
+    } catch {
+      return { response: jsonResponse({ error: 'Invalid JSON body' }, 400) };
+    }
+    specs = body?.owners;
+  }
+
+  if (!Array.isArray(specs) || specs.length === 0 || !specs.every(s => typeof s === 'string')) {
+    return { response: jsonResponse({ error: 'Missing owners' }, 400) };
+  }
+
+  const pkg = await findPackage(env, name);
+  if (!pkg) {
+    return { response: jsonResponse({ error: 'Package not found' }, 404) };
+  }
+  if (await packageAccess(env, pkg, user) !== 'owner') {
+    return { response: permissionDenied(pkg.name, 'manage the owners of') };
+  }
+
+  const owners = [];
+  for (const spec of specs) {
+    const owner = await resolveOwner(env, spec);
+    if (!owner) {
+      return {
+        response: jsonResponse({
+          error: 'Owner not found',
+          message: spec.startsWith('team:')
+            ? `No registry team '${spec.slice('team:'.length)}'`
+            : `No registry user '${spec}'; they must sign in to the registry once first`,
+        }, 404),
+      };
+    }
+    owners.push(owner);
+  }
+
+  return { pkg, owners };
+}
+
+/**
+ * A user login or `team:<name>`, as the CLI sends it, resolved to
+ * `{ kind, id, login }`; null when there is no such user or team.
+ */
+async function resolveOwner(env, spec) {
+  if (spec.startsWith('team:')) {
+    const team = await env.DB.prepare('SELECT id, name FROM teams WHERE name = ?')
+      .bind(spec.slice('team:'.length).toLowerCase()).first();
+    return team && { kind: 'team', id: team.id, login: team.name };
+  }
+  const user = await env.DB.prepare('SELECT id, github_login FROM users WHERE github_login = ? COLLATE NOCASE')
+    .bind(spec).first();
+  return user && { kind: 'user', id: user.id, login: user.github_login };
+}
+
+async function listOwners(env, pkg) {
+  const primary = pkg.owner_team_id != null
+    ? await env.DB.prepare(`SELECT name AS login, 'team' AS kind FROM teams WHERE id = ?`)
+      .bind(pkg.owner_team_id).first()
+    : await env.DB.prepare(`SELECT github_login AS login, 'user' AS kind FROM users WHERE id = ?`)
+      .bind(pkg.owner_id).first();
+
+  const others = await env.DB.prepare(`
+    SELECT u.github_login AS login, 'user' AS kind, c.role, c.created_at AS created_at
+    FROM collaborators c JOIN users u ON c.user_id = u.id
+    WHERE c.package_id = ?
+    UNION ALL
+    SELECT t.name AS login, 'team' AS kind, pt.role, pt.created_at AS created_at
+    FROM package_teams pt JOIN teams t ON pt.team_id = t.id
+    WHERE pt.package_id = ?
+    ORDER BY created_at
+  `).bind(pkg.id, pkg.id).all();
+
+  return [
+    { login: primary.login, kind: primary.kind, role: 'owner' },
+    ...others.results.map(o => ({ login: o.login, kind: o.kind, role: o.role })),
+  ];
+}
+
+function isPrimary(pkg, owner) {
+  return owner.kind === 'team'
+    ? pkg.owner_team_id === owner.id
+    : pkg.owner_team_id == null && pkg.owner_id === owner.id;
+}
+
+function ownerSpec(owner) {
+  return owner.kind === 'team' ? `team:${owner.login}` : owner.login;
+}
+
+// `owner` manages owners; `maintainer` and `publisher` publish and yank.
+function accessFor(role) {
+  return role === 'owner' ? 'owner' : 'maintainer';
+}
+
+function permissionDenied(name, action) {
+  return jsonResponse({
+    error: 'Permission denied',
+    message: `You do not have permission to ${action} ${name}`,
+  }, 403);
+}
@@ -4,6 +4,7 @@
  */
 
 import { jsonResponse, sha256 } from '../index.js';
+import { packageAccess } from './owners.js';
 
 // Reserved namespaces - only admins can publish
 const RESERVED_PREFIXES = ['std', 'core', 'logos', 'sys'];
@@ -145,13 +146,8 @@ export async function handlePublish(request, env, user) {
     .bind(normalizedName).first();
 
   if (pkg) {
-    // Verify ownership or collaborator status
-    const isOwner = pkg.owner_id === user.id;
-    const collab = await env.DB.prepare(
-      'SELECT * FROM collaborators WHERE package_id = ? AND user_id = ?'
-    ).bind(pkg.id, user.id).first();
-
-    if (!isOwner && !collab) {
+    // Verify ownership, collaborator, or owning-team status
+    if (!await packageAccess(env, pkg, user)) {
       return jsonResponse({
         error: 'Permission denied',
         message: 'You do not have permission to publish to this package'
@@ -193,11 +189,12 @@ export async function handlePublish(request, env, user) {
   } else {
     // Create new package
     const result = await env.DB.prepare(`
-      INSERT INTO packages (name, owner_id, description, repository, homepage, license, keywords)
-      VALUES (?, ?, ?, ?, ?, ?, ?)
+      INSERT INTO packages (name, owner_id, owner_team_id, description, repository, homepage, license, keywords)
+      VALUES (?, ?, ?, ?, ?, ?, ?, ?)
     `).bind(
       normalizedName,
       user.id,
+      user.team ? user.team.id : null,
       description || null,
       repository || null,
       homepage || null,
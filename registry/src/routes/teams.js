@@ -0,0 +1,186 @@
+/**
+ * LOGOS Package Registry - Team Routes
+ * POST /teams - Create a team
+ * GET /teams/:name - Team info and members
+ * PUT /teams/:name/members - Add members
+ * DELETE /teams/:name/members - Remove members
+ *
+ * Teams own packages as `team:<name>` and hold their own API tokens
+ * (`POST /auth/tokens` with `team`). Admins manage the membership.
+ */
+
+import { jsonResponse } from '../index.js';
+
+const TEAM_NAME = /^[a-z0-9][a-z0-9_-]{0,38}$/;
+
+/**
+ * POST /teams - Create a team
+ * Body: { name: string }
+ * The creator becomes its first admin.
+ */
+export async function handleCreateTeam(request, env, user) {
+  if (user.team) {
+    return jsonResponse({ error: 'Team tokens cannot create teams' }, 403);
+  }
+
+  let body;
+  try {
+    body = await request.json();
+  } catch {
+    return jsonResponse({ error: 'Invalid JSON body' }, 400);
+  }
+
+  const name = typeof body?.name === 'string' ? body.name.toLowerCase() : '';
+  if (!TEAM_NAME.test(name)) {
+    return jsonResponse({
+      error: 'Invalid team name',
+      message: 'Team names are 1-39 lowercase letters, digits, hyphens or underscores',
+    }, 400);
+  }
+
+  const existing = await env.DB.prepare('SELECT id FROM teams WHERE name = ?').bind(name).first();
+  if (existing) {
+    return jsonResponse({ error: 'Team exists', message: `A team named '${name}' already exists` }, 409);
+  }
+
+  const result = await env.DB.prepare(
+    'INSERT INTO teams (name, created_by) VALUES (?, ?)'
+  ).bind(name, user.id).run();
+  await env.DB.prepare(
+    `INSERT INTO team_members (team_id, user_id, role) VALUES (?, ?, 'admin')`
+  ).bind(result.meta.last_row_id, user.id).run();
+
+  return jsonResponse({
+    name,
+    members: [{ login: user.github_login, role: 'admin' }],
+  }, 201);
+}
+
+/**
+ * GET /teams/:name - Team info and members
+ */
+export async function handleTeamInfo(request, env, name) {
+  const team = await findTeam(env, name);
+  if (!team) {
+    return jsonResponse({ error: 'Team not found' }, 404);
+  }
+
+  return jsonResponse({ name: team.name, members: await listMembers(env, team) });
+}
+
+/**
+ * PUT /teams/:name/members - Add members
+ * Body: { logins: string[], role?: 'admin'|'member' }
+ */
+export async function handleAddMembers(request, env, user, name) {
+  const checked = await checkMemberRequest(request, env, user, name);
+  if (checked.response) return checked.response;
+  const { team, members, body } = checked;
+
+  const role = body.role === 'admin' ? 'admin' : 'member';
+  await env.DB.batch(members.map(member => env.DB.prepare(`
+    INSERT INTO team_members (team_id, user_id, role) VALUES (?, ?, ?)
+    ON CONFLICT(team_id, user_id) DO UPDATE SET role = excluded.role
+  `).bind(team.id, member.id, role)));
+
+  return jsonResponse({ success: true, members: await listMembers(env, team) });
+}
+
+/**
+ * DELETE /teams/:name/members - Remove members
+ * Body: { logins: string[] }
+ * A team always keeps at least one admin.
+ */
+export async function handleRemoveMembers(request, env, user, name) {
+  const checked = await checkMemberRequest(request, env, user, name);
+  if (checked.response) return checked.response;
+  const { team, members } = checked;
+
+  const removing = new Set(members.map(m => m.id));
+  const admins = await env.DB.prepare(
+    `SELECT user_id FROM team_members WHERE team_id = ? AND role = 'admin'`
+  ).bind(team.id).all();
+  if (admins.results.every(a => removing.has(a.user_id))) {
+    return jsonResponse({
+      error: 'Cannot remove the last admin',
+      message: `Team '${team.name}' must keep at least one admin`,
+    }, 400);
+  }
+
+  await env.DB.batch(members.map(member => env.DB.prepare(
+    'DELETE FROM team_members WHERE team_id = ? AND user_id = ?'
+  ).bind(team.id, member.id)));
+
+  return jsonResponse({ success: true, members: await listMembers(env, team) });
+}
+
+// ========== Helpers ==========
+
+async function findTeam(env, name) {
+  return env.DB.prepare('SELECT * FROM teams WHERE name = ?')
+    .bind(name.toLowerCase()).first();
+}
+
+async function listMembers(env, team) {
+  const members = await env.DB.prepare(`
+    SELECT u.github_login AS login, m.role
+    FROM team_members m JOIN users u ON m.user_id = u.id
+    WHERE m.team_id = ?
+    ORDER BY m.created_at
+  `).bind(team.id).all();
+  return members.results.map(m => ({ login: m.login, role: m.role }));
+}
+
+/**
+ * The shared checks of the membership routes: the team exists, the caller
+ * is one of its admins (with a personal token), and every login names a
+ * registry user. Answers `{ response }` on failure, else `{ team, members, body }`.
+ */
+async function checkMemberRequest(request, env, user, name) {
+  let body;
+  try {
+    body = await request.json();
+  } catch {
+    return { response: jsonResponse({ error: 'Invalid JSON body' }, 400) };
+  }
+
+  const logins = body?.logins;
+  if (!Array.isArray(logins) || logins.length === 0 || !logins.every(l => typeof l === 'string')) {
+    return { response: jsonResponse({ error: 'Missing logins' }, 400) };
+  }
+
+  const team = await findTeam(env, name);
+  if (!team) {
+    return { response: jsonResponse({ error: 'Team not found' }, 404) };
+  }
+
+  const admin = user.team ? null : await env.DB.prepare(
+    `SELECT 1 FROM team_members WHERE team_id = ? AND user_id = ? AND role = 'admin'`
+  ).bind(team.id, user.id).first();
+  if (!admin) {
+    return {
+      response: jsonResponse({
+        error: 'Permission denied',
+        message: `Only admins of team '${team.name}' can change its members`,
+      }, 403),
+    };
+  }
+
+  const members = [];
+  for (const login of logins) {
+    const member = await env.DB.prepare(
+      'SELECT id, github_login FROM users WHERE github_login = ? COLLATE NOCASE'
+    ).bind(login).first();
+    if (!member) {
+      return {
+        response: jsonResponse({
+          error: 'User not found',
+          message: `No registry user '${login}'; they must sign in to the registry once first`,
+        }, 404),
+      };
+    }
+    members.push(member);
+  }
+
+  return { team, members, body };
+}
@@ -8,6 +8,7 @@
 #   benchmarks/results/{latest,solvers,latest-codec,latest-interp}.json
 #   benchmarks/programs/<id>/*            -> bench-sources.json (11 languages per benchmark)
 #   apps/logicaffeine_web/{privacy,terms}.html
+#   apps/logicaffeine_web/curriculum-pack.json
 #   crates/logicaffeine_language/assets/lexicon.json
 #
 # The output dir is generated fresh on every run and is gitignored, so it can never
@@ -33,6 +34,7 @@ cp benchmarks/results/latest.json \
    "$DATA/"
 cp apps/logicaffeine_web/privacy.html "$DATA/privacy.html"
 cp apps/logicaffeine_web/terms.html "$DATA/terms.html"
+cp apps/logicaffeine_web/curriculum-pack.json "$DATA/curriculum-pack.json"
 cp crates/logicaffeine_language/assets/lexicon.json "$DATA/lexicon.json"
 
 python3 - "$DATA" <<'PY'
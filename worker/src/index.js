@@ -18,10 +18,34 @@ export default {
       return handleValidate(request, env);
     }
 
+    if (url.pathname === '/activate' && request.method === 'POST') {
+      return handleActivate(request, env);
+    }
+
+    if (url.pathname === '/deactivate' && request.method === 'POST') {
+      return handleDeactivate(request, env);
+    }
+
     if (url.pathname === '/session' && request.method === 'POST') {
       return handleSession(request, env);
     }
 
+    if (url.pathname === '/telemetry' && request.method === 'POST') {
+      return handleTelemetry(request);
+    }
+
+    if (url.pathname === '/paste' && request.method === 'POST') {
+      return handlePasteCreate(request, env);
+    }
+
+    if (url.pathname.startsWith('/paste/') && request.method === 'GET') {
+      return handlePasteGet(url.pathname.slice('/paste/'.length), env);
+    }
+
+    if (url.pathname.startsWith('/collab/')) {
+      return handleCollab(request, url.pathname.slice('/collab/'.length), env);
+    }
+
     if (url.pathname === '/health') {
       return new Response(JSON.stringify({ status: 'ok' }), {
         headers: { ...CORS_HEADERS, 'Content-Type': 'application/json' },
@@ -120,6 +144,230 @@ function determinePlan(priceData, productData) {
   return 'unknown';
 }
 
+const LICENSE_PLANS = ['free', 'supporter', 'pro', 'premium', 'lifetime', 'enterprise'];
+const LICENSE_GRACE_DAYS = 14;
+const DEFAULT_MAX_MACHINES = 3;
+// Paid-through date for one-time (lifetime) purchases: 2100-01-01.
+const LIFETIME_EXPIRES_AT = 4102444800;
+// Machine-bound files lapse this soon, paid-through or not, and the CLI
+// re-activates them. A released seat therefore stops working within this
+// plus the grace period, instead of at the paid-through date.
+const LICENSE_FILE_SECS = 7 * 24 * 60 * 60;
+
+// Registers a machine against a license key and returns a signed license file
+// (`largo license activate`). The claims mirror `LicenseClaims` in
+// crates/logicaffeine_verify/src/license.rs; the CLI verifies the signature
+// against the public half of LICENSE_SIGNING_KEY, compiled in as
+// LOGOS_LICENSE_PUBLIC_KEY. Activations live in the ACTIVATIONS KV namespace,
+// one JSON array of `{ machineId, activatedAt, tokenHash }` per key. Each
+// activation (and re-activation) issues a fresh token, returned beside the
+// signed payload, that `/deactivate` requires; only its hash is stored.
+async function handleActivate(request, env) {
+  const body = await readActivation(request);
+  if (body.error) {
+    return jsonResponse({ error: body.error }, 400);
+  }
+  const { licenseKey, machineId } = body;
+
+  if (!env.ACTIVATIONS) {
+    return jsonResponse({ error: 'Activation storage not configured' }, 503);
+  }
+  if (!env.LICENSE_SIGNING_KEY) {
+    return jsonResponse({ error: 'License signing not configured' }, 503);
+  }
+
+  let license;
+  try {
+    const stripe = new Stripe(env.STRIPE_SECRET_KEY, {
+      apiVersion: '2023-10-16',
+      httpClient: Stripe.createFetchHttpClient(),
+    });
+    license = await lookupLicense(stripe, licenseKey);
+  } catch (error) {
+    if (error.type === 'StripeInvalidRequestError') {
+      return jsonResponse({ error: 'License key not found' }, 404);
+    }
+    console.error('Activation lookup error:', error);
+    return jsonResponse({ error: 'Activation failed' }, 500);
+  }
+
+  if (!license.active) {
+    return jsonResponse({ error: 'License is not active' }, 403);
+  }
+
+  const now = Math.floor(Date.now() / 1000);
+  const machines = await loadActivations(env, licenseKey);
+  let machine = machines.find((m) => m.machineId === machineId);
+  if (!machine) {
+    if (machines.length >= license.maxMachines) {
+      return jsonResponse({
+        error: 'Machine limit reached',
+        activeMachines: machines.length,
+        maxMachines: license.maxMachines,
+      }, 409);
+    }
+    machine = { machineId, activatedAt: now };
+    machines.push(machine);
+  }
+  const activationToken = randomHex(32);
+  machine.tokenHash = await sha256Hex(activationToken);
+  await env.ACTIVATIONS.put(licenseKey, JSON.stringify(machines));
+
+  const claims = {
+    key: licenseKey,
+    plan: license.plan,
+    licensee: license.licensee,
+    issued_at: now,
+    expires_at: Math.min(license.expiresAt, now + LICENSE_FILE_SECS),
+    grace_days: LICENSE_GRACE_DAYS,
+    machine_id: machineId,
+    active_machines: machines.length,
+    max_machines: license.maxMachines,
+  };
+
+  try {
+    const file = await signClaims(claims, env.LICENSE_SIGNING_KEY);
+    return jsonResponse({ license: { ...file, activation_token: activationToken } });
+  } catch (error) {
+    console.error('License signing error:', error);
+    return jsonResponse({ error: 'License signing failed' }, 500);
+  }
+}
+
+// Frees a machine's activation seat (`largo license deactivate`). The caller
+// proves it holds the activation with the token issued alongside its file.
+async function handleDeactivate(request, env) {
+  const body = await readActivation(request);
+  if (body.error) {
+    return jsonResponse({ error: body.error }, 400);
+  }
+  const { licenseKey, machineId, activationToken } = body;
+
+  if (!env.ACTIVATIONS) {
+    return jsonResponse({ error: 'Activation storage not configured' }, 503);
+  }
+
+  const machines = await loadActivations(env, licenseKey);
+  const machine = machines.find((m) => m.machineId === machineId);
+  if (!machine) {
+    return jsonResponse({ released: false, activeMachines: machines.length });
+  }
+  if (typeof activationToken !== 'string' || !machine.tokenHash
+      || machine.tokenHash !== await sha256Hex(activationToken)) {
+    return jsonResponse({ error: 'Activation token does not match' }, 403);
+  }
+
+  const remaining = machines.filter((m) => m !== machine);
+  await env.ACTIVATIONS.put(licenseKey, JSON.stringify(remaining));
+  return jsonResponse({ released: true, activeMachines: remaining.length });
+}
+
+async function readActivation(request) {
+  let body;
+  try {
+    body = await request.json();
+  } catch {
+    return { error: 'Invalid JSON' };
+  }
+  const { licenseKey, machineId, activationToken } = body ?? {};
+  if (!licenseKey) return { error: 'No license key provided' };
+  if (typeof licenseKey !== 'string' || !/^(sub|pi)_/.test(licenseKey)) {
+    return { error: 'Invalid license key format' };
+  }
+  if (!machineId) return { error: 'No machine ID provided' };
+  // `machine_id()` in the CLI: a hex SHA-256 digest.
+  if (!/^[a-f0-9]{64}$/.test(machineId)) return { error: 'Invalid machine ID' };
+  return { licenseKey, machineId, activationToken };
+}
+
+async function loadActivations(env, licenseKey) {
+  const stored = await env.ACTIVATIONS.get(licenseKey);
+  return stored ? JSON.parse(stored) : [];
+}
+
+// What a license key grants, for signing into a license file.
+async function lookupLicense(stripe, licenseKey) {
+  if (licenseKey.startsWith('sub_')) {
+    const subscription = await stripe.subscriptions.retrieve(licenseKey, {
+      expand: ['items.data.price.product', 'customer'],
+    });
+    const priceData = subscription.items.data[0]?.price;
+    return {
+      active: subscription.status === 'active' || subscription.status === 'trialing',
+      plan: licensePlan(determinePlan(priceData, priceData?.product)),
+      licensee: customerName(subscription.customer),
+      expiresAt: subscription.current_period_end,
+      maxMachines: maxMachines(subscription.metadata),
+    };
+  }
+
+  const paymentIntent = await stripe.paymentIntents.retrieve(licenseKey, { expand: ['customer'] });
+  return {
+    active: paymentIntent.status === 'succeeded',
+    plan: 'lifetime',
+    licensee: customerName(paymentIntent.customer),
+    expiresAt: LIFETIME_EXPIRES_AT,
+    maxMachines: maxMachines(paymentIntent.metadata),
+  };
+}
+
+// `determinePlan` can answer with a price lookup key such as `pro_monthly`;
+// license files only carry the plan names the CLI knows.
+function licensePlan(plan) {
+  return LICENSE_PLANS.find((name) => plan === name)
+    ?? LICENSE_PLANS.find((name) => plan.includes(name))
+    ?? 'none';
+}
+
+function customerName(customer) {
+  if (!customer || typeof customer === 'string') return null;
+  return customer.name || customer.email || null;
+}
+
+// Seats per key: `max_machines` in the Stripe metadata, for enterprise
+// agreements, else the default.
+function maxMachines(metadata) {
+  const max = Number.parseInt(metadata?.max_machines, 10);
+  return Number.isInteger(max) && max > 0 ? max : DEFAULT_MAX_MACHINES;
+}
+
+// Signs `claims` the way `LicenseFile::verify` checks them: Ed25519 over the
+// JSON bytes, both base64-encoded. `signingKey` is a base64 PKCS#8 Ed25519
+// private key.
+export async function signClaims(claims, signingKey) {
+  const key = await crypto.subtle.importKey(
+    'pkcs8',
+    base64ToBytes(signingKey),
+    { name: 'Ed25519' },
+    false,
+    ['sign'],
+  );
+  const payload = new TextEncoder().encode(JSON.stringify(claims));
+  const signature = await crypto.subtle.sign({ name: 'Ed25519' }, key, payload);
+  return { payload: bytesToBase64(payload), signature: bytesToBase64(new Uint8Array(signature)) };
+}
+
+function randomHex(length) {
+  return bytesToHex(crypto.getRandomValues(new Uint8Array(length)));
+}
+
+export async function sha256Hex(text) {
+  const digest = await crypto.subtle.digest('SHA-256', new TextEncoder().encode(text));
+  return bytesToHex(new Uint8Array(digest));
+}
+
+function bytesToHex(bytes) {
+  return Array.from(bytes, (b) => b.toString(16).padStart(2, '0')).join('');
+}
+
+function base64ToBytes(text) {
+  return Uint8Array.from(atob(text.trim()), (c) => c.charCodeAt(0));
+}
+
+function bytesToBase64(bytes) {
+  return btoa(String.fromCharCode(...bytes));
+}
+
 async function handleSession(request, env) {
   try {
     const { sessionId } = await request.json();
@@ -193,6 +441,153 @@ async function handleSession(request, env) {
   }
 }
 
+const TELEMETRY_SCHEMA = 1;
+const TELEMETRY_COUNTERS = ['exercises_completed', 'errors', 'compile_latency_ms'];
+
+// Opt-in usage counts from the web app. Only aggregate counters are accepted;
+// anything else is rejected so free text can't slip into the logs.
+async function handleTelemetry(request) {
+  let report;
+  try {
+    report = await request.json();
+  } catch {
+    return jsonResponse({ error: 'Invalid JSON' }, 400);
+  }
+
+  if (report?.schema !== TELEMETRY_SCHEMA) {
+    return jsonResponse({ error: 'Unsupported telemetry schema' }, 400);
+  }
+
+  for (const name of TELEMETRY_COUNTERS) {
+    if (!isCounterMap(report[name])) {
+      return jsonResponse({ error: `Invalid counters: ${name}` }, 400);
+    }
+  }
+
+  console.log('Telemetry:', JSON.stringify(report));
+  return jsonResponse({ accepted: true }, 202);
+}
+
+function isCounterMap(value) {
+  if (typeof value !== 'object' || value === null || Array.isArray(value)) return false;
+  return Object.entries(value).every(
+    ([key, count]) => /^[a-z0-9_]{1,64}$/.test(key) && Number.isInteger(count) && count >= 0
+  );
+}
+
+const PASTE_MAX_BYTES = 256 * 1024;
+const PASTE_TTL_SECONDS = 60 * 60 * 24 * 365;
+
+// Studio snippets too long for a URL fragment. The body is the web app's
+// compressed base64url payload, stored as-is under a content hash, so the same
+// snippet always gets the same link.
+async function handlePasteCreate(request, env) {
+  const payload = (await request.text()).trim();
+
+  if (payload.length === 0 || !/^[A-Za-z0-9_-]+$/.test(payload)) {
+    return jsonResponse({ error: 'Invalid snippet payload' }, 400);
+  }
+
+  if (payload.length > PASTE_MAX_BYTES) {
+    return jsonResponse({ error: 'Snippet too large' }, 413);
+  }
+
+  if (!env.PASTES) {
+    return jsonResponse({ error: 'Paste storage not configured' }, 503);
+  }
+
+  const digest = await crypto.subtle.digest('SHA-256', new TextEncoder().encode(payload));
+  const id = [...new Uint8Array(digest).slice(0, 8)]
+    .map((b) => b.toString(16).padStart(2, '0'))
+    .join('');
+
+  await env.PASTES.put(id, payload, { expirationTtl: PASTE_TTL_SECONDS });
+  return jsonResponse({ id }, 201);
+}
+
+async function handlePasteGet(id, env) {
+  if (!/^[a-f0-9]{16}$/.test(id)) {
+    return jsonResponse({ error: 'Snippet not found' }, 404);
+  }
+
+  if (!env.PASTES) {
+    return jsonResponse({ error: 'Paste storage not configured' }, 503);
+  }
+
+  const payload = await env.PASTES.get(id);
+  if (payload === null) {
+    return jsonResponse({ error: 'Snippet not found' }, 404);
+  }
+
+  return new Response(payload, {
+    headers: { ...CORS_HEADERS, 'Content-Type': 'text/plain' },
+  });
+}
+
+const COLLAB_MAX_PEERS = 32;
+
+// Live Studio collaboration. Each room is one CollabRoom Durable Object, so
+// every socket in a room lands on the same instance; the name rules match the
+// web app's `Room::parse`.
+async function handleCollab(request, room, env) {
+  if (!/^[A-Za-z0-9_-]{1,64}$/.test(room)) {
+    return jsonResponse({ error: 'Not found' }, 404);
+  }
+
+  if (request.headers.get('Upgrade')?.toLowerCase() !== 'websocket') {
+    return jsonResponse({ error: 'Expected a WebSocket upgrade' }, 426);
+  }
+
+  if (!env.COLLAB) {
+    return jsonResponse({ error: 'Collaboration relay not configured' }, 503);
+  }
+
+  return env.COLLAB.get(env.COLLAB.idFromName(room)).fetch(request);
+}
+
+// A collaboration room: forwards every text frame to the other sockets in the
+// room and interprets none of them. Peers converge through the CRDT messages
+// they exchange, so the room keeps no state beyond its open sockets, and uses
+// the hibernation API so an idle room costs nothing.
+export class CollabRoom {
+  constructor(state) {
+    this.state = state;
+  }
+
+  async fetch() {
+    if (this.state.getWebSockets().length >= COLLAB_MAX_PEERS) {
+      return jsonResponse({ error: 'Room is full' }, 503);
+    }
+
+    const [client, server] = Object.values(new WebSocketPair());
+    this.state.acceptWebSocket(server);
+    return new Response(null, { status: 101, webSocket: client });
+  }
+
+  webSocketMessage(socket, message) {
+    if (typeof message !== 'string') {
+      return;
+    }
+    for (const peer of this.state.getWebSockets()) {
+      if (peer !== socket) {
+        try {
+          peer.send(message);
+        } catch {
+          // The peer is already closing; its own close event cleans it up
+        }
+      }
+    }
+  }
+
+  webSocketClose(socket, code, reason) {
+    socket.close(code, reason);
+  }
+
+  webSocketError(socket) {
+    socket.close(1011, 'WebSocket error');
+  }
+}
+
 function jsonResponse(data, status = 200) {
   return new Response(JSON.stringify(data), {
     status,
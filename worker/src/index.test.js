@@ -1,6 +1,6 @@
 import { describe, it, expect, vi, beforeEach } from 'vitest';
 import { env, createExecutionContext, waitOnExecutionContext } from 'cloudflare:test';
-import worker from './index.js';
+import worker, { signClaims, sha256Hex } from './index.js';
 
 describe('License Validator Worker', () => {
   describe('CORS', () => {
@@ -78,6 +78,117 @@ describe('License Validator Worker', () => {
     });
   });
 
+  describe('/activate and /deactivate endpoints', () => {
+    const machineId = 'a'.repeat(64);
+    const kv = (entries = {}) => {
+      const store = new Map(Object.entries(entries));
+      return {
+        put: async (key, value) => { store.set(key, value); },
+        get: async (key) => store.get(key) ?? null,
+      };
+    };
+    const post = (path, body) => new Request(`https://api.logicaffeine.com${path}`, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      body: JSON.stringify(body),
+    });
+
+    it('rejects a missing machine ID', async () => {
+      const ctx = createExecutionContext();
+      const response = await worker.fetch(post('/activate', { licenseKey: 'sub_abc' }), env, ctx);
+      await waitOnExecutionContext(ctx);
+
+      expect(response.status).toBe(400);
+      const data = await response.json();
+      expect(data.error).toBe('No machine ID provided');
+    });
+
+    it('rejects a machine ID that is not a fingerprint', async () => {
+      const ctx = createExecutionContext();
+      const response = await worker.fetch(post('/activate', { licenseKey: 'sub_abc', machineId: 'my-laptop' }), env, ctx);
+      await waitOnExecutionContext(ctx);
+
+      expect(response.status).toBe(400);
+      const data = await response.json();
+      expect(data.error).toBe('Invalid machine ID');
+    });
+
+    it('rejects invalid license key format', async () => {
+      const ctx = createExecutionContext();
+      const response = await worker.fetch(post('/activate', { licenseKey: 'invalid_key', machineId }), env, ctx);
+      await waitOnExecutionContext(ctx);
+
+      expect(response.status).toBe(400);
+      const data = await response.json();
+      expect(data.error).toBe('Invalid license key format');
+    });
+
+    it('refuses to activate without a signing key', async () => {
+      const ctx = createExecutionContext();
+      const response = await worker.fetch(
+        post('/activate', { licenseKey: 'sub_abc', machineId }),
+        { ...env, ACTIVATIONS: kv(), LICENSE_SIGNING_KEY: undefined },
+        ctx,
+      );
+      await waitOnExecutionContext(ctx);
+
+      expect(response.status).toBe(503);
+      const data = await response.json();
+      expect(data.error).toBe('License signing not configured');
+    });
+
+    it('frees the seat of a deactivated machine', async () => {
+      const other = { machineId: 'b'.repeat(64), activatedAt: 2, tokenHash: await sha256Hex('other') };
+      const activations = kv({
+        sub_abc: JSON.stringify([{ machineId, activatedAt: 1, tokenHash: await sha256Hex('token') }, other]),
+      });
+      const ctx = createExecutionContext();
+      const response = await worker.fetch(
+        post('/deactivate', { licenseKey: 'sub_abc', machineId, activationToken: 'token' }),
+        { ...env, ACTIVATIONS: activations },
+        ctx,
+      );
+      await waitOnExecutionContext(ctx);
+
+      expect(response.status).toBe(200);
+      const data = await response.json();
+      expect(data).toEqual({ released: true, activeMachines: 1 });
+      expect(JSON.parse(await activations.get('sub_abc'))).toEqual([other]);
+    });
+
+    it('keeps the seat without the activation token', async () => {
+      const machines = [{ machineId, activatedAt: 1, tokenHash: await sha256Hex('token') }];
+      const activations = kv({ sub_abc: JSON.stringify(machines) });
+      for (const activationToken of [undefined, 'guess']) {
+        const ctx = createExecutionContext();
+        const response = await worker.fetch(
+          post('/deactivate', { licenseKey: 'sub_abc', machineId, activationToken }),
+          { ...env, ACTIVATIONS: activations },
+          ctx,
+        );
+        await waitOnExecutionContext(ctx);
+
+        expect(response.status).toBe(403);
+        const data = await response.json();
+        expect(data.error).toBe('Activation token does not match');
+      }
+      expect(JSON.parse(await activations.get('sub_abc'))).toEqual(machines);
+    });
+
+    it('signs claims so the public key verifies them', async () => {
+      const keys = await crypto.subtle.generateKey({ name: 'Ed25519' }, true, ['sign', 'verify']);
+      const pkcs8 = new Uint8Array(await crypto.subtle.exportKey('pkcs8', keys.privateKey));
+      const claims = { key: 'sub_abc', plan: 'pro', machine_id: machineId };
+
+      const file = await signClaims(claims, btoa(String.fromCharCode(...pkcs8)));
+      const decode = (text) => Uint8Array.from(atob(text), (c) => c.charCodeAt(0));
+      const payload = decode(file.payload);
+
+      expect(JSON.parse(new TextDecoder().decode(payload))).toEqual(claims);
+      expect(await crypto.subtle.verify({ name: 'Ed25519' }, keys.publicKey, decode(file.signature), payload)).toBe(true);
+    });
+  });
+
   describe('/session endpoint', () => {
     it('rejects missing session ID', async () => {
       const request = new Request('https://api.logicaffeine.com/session', {
@@ -109,4 +220,163 @@ describe('License Validator Worker', () => {
       expect(data.error).toBe('Invalid session ID format');
     });
   });
+
+  describe('/telemetry endpoint', () => {
+    const post = (body) => new Request('https://api.logicaffeine.com/telemetry', {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      body: JSON.stringify(body),
+    });
+
+    it('accepts a counters-only report', async () => {
+      const request = post({
+        schema: 1,
+        exercises_completed: { free_form: 3 },
+        errors: { wrong_quantifier: 1 },
+        compile_latency_ms: { lt_50: 2 },
+      });
+      const ctx = createExecutionContext();
+      const response = await worker.fetch(request, env, ctx);
+      await waitOnExecutionContext(ctx);
+
+      expect(response.status).toBe(202);
+      const data = await response.json();
+      expect(data.accepted).toBe(true);
+    });
+
+    it('rejects an unknown schema', async () => {
+      const request = post({ schema: 2, exercises_completed: {}, errors: {}, compile_latency_ms: {} });
+      const ctx = createExecutionContext();
+      const response = await worker.fetch(request, env, ctx);
+      await waitOnExecutionContext(ctx);
+
+      expect(response.status).toBe(400);
+      const data = await response.json();
+      expect(data.error).toBe('Unsupported telemetry schema');
+    });
+
+    it('rejects free-text counter keys', async () => {
+      const request = post({
+        schema: 1,
+        exercises_completed: {},
+        errors: { 'Every man is mortal.': 1 },
+        compile_latency_ms: {},
+      });
+      const ctx = createExecutionContext();
+      const response = await worker.fetch(request, env, ctx);
+      await waitOnExecutionContext(ctx);
+
+      expect(response.status).toBe(400);
+      const data = await response.json();
+      expect(data.error).toBe('Invalid counters: errors');
+    });
+  });
+
+  describe('/collab relay', () => {
+    const connect = async (room) => {
+      const ctx = createExecutionContext();
+      const response = await worker.fetch(
+        new Request(`https://api.logicaffeine.com/collab/${room}`, { headers: { Upgrade: 'websocket' } }),
+        env,
+        ctx,
+      );
+      await waitOnExecutionContext(ctx);
+      expect(response.status).toBe(101);
+      const socket = response.webSocket;
+      socket.accept();
+      return socket;
+    };
+    const nextMessage = (socket) => new Promise((resolve) => {
+      socket.addEventListener('message', (event) => resolve(event.data), { once: true });
+    });
+
+    it('requires a WebSocket upgrade', async () => {
+      const ctx = createExecutionContext();
+      const response = await worker.fetch(new Request('https://api.logicaffeine.com/collab/abc123'), env, ctx);
+      await waitOnExecutionContext(ctx);
+
+      expect(response.status).toBe(426);
+    });
+
+    it('rejects room names the web app would not generate', async () => {
+      const ctx = createExecutionContext();
+      const response = await worker.fetch(
+        new Request('https://api.logicaffeine.com/collab/a.b', { headers: { Upgrade: 'websocket' } }),
+        env,
+        ctx,
+      );
+      await waitOnExecutionContext(ctx);
+
+      expect(response.status).toBe(404);
+    });
+
+    it('forwards messages to the other peers in the room only', async () => {
+      const teacher = await connect('lesson');
+      const student = await connect('lesson');
+      const stranger = await connect('elsewhere');
+      let strayMessage = null;
+      stranger.addEventListener('message', (event) => { strayMessage = event.data; });
+
+      const received = nextMessage(student);
+      teacher.send('{"type":"hello","peer":1}');
+
+      expect(await received).toBe('{"type":"hello","peer":1}');
+      expect(strayMessage).toBe(null);
+    });
+  });
+
+  describe('/paste endpoint', () => {
+    const kv = () => {
+      const store = new Map();
+      return {
+        put: async (key, value) => { store.set(key, value); },
+        get: async (key) => store.get(key) ?? null,
+      };
+    };
+    const create = (body) => new Request('https://api.logicaffeine.com/paste', {
+      method: 'POST',
+      headers: { 'Content-Type': 'text/plain' },
+      body,
+    });
+
+    it('stores a snippet and serves it back by id', async () => {
+      const pasteEnv = { ...env, PASTES: kv() };
+      let ctx = createExecutionContext();
+      const created = await worker.fetch(create('eJzLSM3JyVcozy_KSQEAGgQEXQ'), pasteEnv, ctx);
+      await waitOnExecutionContext(ctx);
+
+      expect(created.status).toBe(201);
+      const { id } = await created.json();
+      expect(id).toMatch(/^[a-f0-9]{16}$/);
+
+      ctx = createExecutionContext();
+      const fetched = await worker.fetch(new Request(`https://api.logicaffeine.com/paste/${id}`), pasteEnv, ctx);
+      await waitOnExecutionContext(ctx);
+
+      expect(fetched.status).toBe(200);
+      expect(await fetched.text()).toBe('eJzLSM3JyVcozy_KSQEAGgQEXQ');
+    });
+
+    it('rejects payloads that are not base64url', async () => {
+      const ctx = createExecutionContext();
+      const response = await worker.fetch(create('<script>alert(1)</script>'), { ...env, PASTES: kv() }, ctx);
+      await waitOnExecutionContext(ctx);
+
+      expect(response.status).toBe(400);
+      const data = await response.json();
+      expect(data.error).toBe('Invalid snippet payload');
+    });
+
+    it('returns 404 for unknown snippets', async () => {
+      const ctx = createExecutionContext();
+      const response = await worker.fetch(
+        new Request('https://api.logicaffeine.com/paste/0123456789abcdef'),
+        { ...env, PASTES: kv() },
+        ctx,
+      );
+      await waitOnExecutionContext(ctx);
+
+      expect(response.status).toBe(404);
+    });
+  });
 });